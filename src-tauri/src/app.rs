@@ -2,52 +2,94 @@ use std::sync::Arc;
 
 use tauri::{AppHandle, Emitter, Manager};
 
+use crate::app::job_manager::JobManager;
+use crate::app::startup_report::StartupTimings;
 use crate::application::services::agent_profile_diagnostic_service::AgentProfileDiagnosticService;
 use crate::application::services::agent_profile_service::AgentProfileService;
 use crate::application::services::agent_run_history_service::AgentRunHistoryService;
 use crate::application::services::agent_run_retention_automation_service::AgentRunRetentionAutomationService;
 use crate::application::services::agent_runtime_service::AgentRuntimeService;
 use crate::application::services::asset_service::AssetService;
+use crate::application::services::author_note_service::AuthorNoteService;
 use crate::application::services::avatar_service::AvatarService;
 use crate::application::services::background_service::BackgroundService;
+use crate::application::services::backup_schedule_service::BackupScheduleService;
+use crate::application::services::changelog_service::ChangelogService;
+use crate::application::services::character_asset_service::CharacterAssetService;
+use crate::application::services::character_generation_overrides_service::CharacterGenerationOverridesService;
+use crate::application::services::character_note_service::CharacterNoteService;
+use crate::application::services::character_prompt_overrides_service::CharacterPromptOverridesService;
 use crate::application::services::character_service::CharacterService;
 use crate::application::services::chat_completion_service::ChatCompletionService;
+use crate::application::services::chat_metadata_service::ChatMetadataService;
 use crate::application::services::chat_service::ChatService;
+use crate::application::services::connection_monitor_service::ConnectionMonitorService;
+use crate::application::services::connection_profile_service::ConnectionProfileService;
 use crate::application::services::content_service::ContentService;
+use crate::application::services::expression_service::ExpressionService;
 use crate::application::services::extension_service::ExtensionService;
 use crate::application::services::extension_store_service::ExtensionStoreService;
+use crate::application::services::file_attachment_service::FileAttachmentService;
+use crate::application::services::file_ingest_service::FileIngestService;
+use crate::application::services::generation_record_service::GenerationRecordService;
+use crate::application::services::global_search_service::GlobalSearchService;
 use crate::application::services::group_chat_service::GroupChatService;
 use crate::application::services::group_service::GroupService;
+use crate::application::services::horde_service::HordeService;
+use crate::application::services::idle_generation_service::IdleGenerationService;
 use crate::application::services::image_metadata_service::ImageMetadataService;
+use crate::application::services::koboldcpp_service::KoboldCppService;
 use crate::application::services::lan_sync_service::LanSyncService;
 use crate::application::services::llm_connection_service::LlmConnectionService;
+use crate::application::services::macro_engine::MacroEngine;
+use crate::application::services::memory_cache_service::MemoryCacheService;
+use crate::application::services::model_capability_service::ModelCapabilityService;
 use crate::application::services::native_regex_service::NativeRegexService;
+use crate::application::services::notification_service::NotificationService;
+use crate::application::services::novelai_service::NovelAiService;
 use crate::application::services::preset_service::PresetService;
 use crate::application::services::prompt_assembly_service::PromptAssemblyService;
 use crate::application::services::provider_metadata_service::ProviderMetadataService;
 use crate::application::services::quick_reply_service::QuickReplyService;
+use crate::application::services::recent_items_service::RecentItemsService;
 use crate::application::services::secret_service::SecretService;
+use crate::application::services::settings_profile_service::SettingsProfileService;
 use crate::application::services::settings_service::SettingsService;
 use crate::application::services::skill_service::SkillService;
+use crate::application::services::sound_service::SoundService;
+use crate::application::services::sprite_service::SpriteService;
 use crate::application::services::stable_diffusion_service::StableDiffusionService;
+use crate::application::services::summarize_service::SummarizeService;
 use crate::application::services::sync_automation_service::SyncAutomationService;
+use crate::application::services::tag_service::TagService;
 use crate::application::services::theme_service::ThemeService;
 use crate::application::services::tokenization_service::TokenizationService;
 use crate::application::services::translate_service::TranslateService;
 use crate::application::services::tt_sync_service::TtSyncService;
 use crate::application::services::tts_service::TtsService;
 use crate::application::services::update_service::UpdateService;
+use crate::application::services::user_customization_service::UserCustomizationService;
 use crate::application::services::user_directory_service::UserDirectoryService;
 use crate::application::services::user_service::UserService;
+use crate::application::services::variable_service::VariableService;
 use crate::application::services::world_info_service::WorldInfoService;
 use crate::domain::errors::DomainError;
+use crate::infrastructure::bridge_server_store::BridgeServerStore;
+use crate::infrastructure::logging::crash_reports::{APP_CRASH_DETECTED_EVENT, CrashReportStore};
 use crate::infrastructure::logging::logger;
 use crate::infrastructure::paths::RuntimePaths;
+use crate::infrastructure::persistence::file_system::DataDirectory;
+use crate::presentation::bridge_server::BridgeServer;
 
 mod bootstrap;
+pub mod job_manager;
+pub mod startup_report;
 
 pub struct AppState {
     pub character_service: Arc<CharacterService>,
+    pub character_asset_service: Arc<CharacterAssetService>,
+    pub sound_service: Arc<SoundService>,
+    pub sprite_service: Arc<SpriteService>,
     pub chat_service: Arc<ChatService>,
     pub group_chat_service: Arc<GroupChatService>,
     pub user_service: Arc<UserService>,
@@ -62,10 +104,15 @@ pub struct AppState {
     pub avatar_service: Arc<AvatarService>,
     pub group_service: Arc<GroupService>,
     pub background_service: Arc<BackgroundService>,
+    pub file_attachment_service: Arc<FileAttachmentService>,
+    pub file_ingest_service: Arc<FileIngestService>,
     pub image_metadata_service: Arc<ImageMetadataService>,
     pub theme_service: Arc<ThemeService>,
+    pub user_customization_service: Arc<UserCustomizationService>,
     pub preset_service: Arc<PresetService>,
     pub quick_reply_service: Arc<QuickReplyService>,
+    pub recent_items_service: Arc<RecentItemsService>,
+    pub tag_service: Arc<TagService>,
     pub agent_profile_service: Arc<AgentProfileService>,
     pub agent_profile_diagnostic_service: Arc<AgentProfileDiagnosticService>,
     pub prompt_assembly_service: Arc<PromptAssemblyService>,
@@ -73,18 +120,42 @@ pub struct AppState {
     pub agent_run_retention_automation_service: Arc<AgentRunRetentionAutomationService>,
     pub agent_runtime_service: Arc<AgentRuntimeService>,
     pub chat_completion_service: Arc<ChatCompletionService>,
+    pub model_capability_service: Arc<ModelCapabilityService>,
     pub llm_connection_service: Arc<LlmConnectionService>,
+    pub variable_service: Arc<VariableService>,
+    pub macro_engine: Arc<MacroEngine>,
+    pub memory_cache_service: Arc<MemoryCacheService>,
+    pub author_note_service: Arc<AuthorNoteService>,
+    pub character_prompt_overrides_service: Arc<CharacterPromptOverridesService>,
+    pub character_generation_overrides_service: Arc<CharacterGenerationOverridesService>,
+    pub character_note_service: Arc<CharacterNoteService>,
+    pub global_search_service: Arc<GlobalSearchService>,
+    pub connection_profile_service: Arc<ConnectionProfileService>,
+    pub settings_profile_service: Arc<SettingsProfileService>,
+    pub connection_monitor_service: Arc<ConnectionMonitorService>,
+    pub summarize_service: Arc<SummarizeService>,
+    pub idle_generation_service: Arc<IdleGenerationService>,
+    pub notification_service: Arc<NotificationService>,
+    pub generation_record_service: Arc<GenerationRecordService>,
+    pub chat_metadata_service: Arc<ChatMetadataService>,
     pub provider_metadata_service: Arc<ProviderMetadataService>,
     pub tokenization_service: Arc<TokenizationService>,
     pub stable_diffusion_service: Arc<StableDiffusionService>,
+    pub horde_service: Arc<HordeService>,
+    pub novelai_service: Arc<NovelAiService>,
+    pub koboldcpp_service: Arc<KoboldCppService>,
     pub translate_service: Arc<TranslateService>,
+    pub expression_service: Arc<ExpressionService>,
     pub tts_service: Arc<TtsService>,
     pub world_info_service: Arc<WorldInfoService>,
     pub lan_sync_service: Arc<LanSyncService>,
     pub tt_sync_service: Arc<TtSyncService>,
     pub sync_automation_service: Arc<SyncAutomationService>,
+    pub backup_schedule_service: Arc<BackupScheduleService>,
     pub update_service: Arc<UpdateService>,
+    pub changelog_service: Arc<ChangelogService>,
     pub native_regex_service: Arc<NativeRegexService>,
+    pub job_manager: Arc<JobManager>,
     pub ios_policy: crate::domain::ios_policy::IosPolicyActivationReport,
 }
 
@@ -92,6 +163,7 @@ impl AppState {
     pub async fn new(
         app_handle: AppHandle,
         runtime_paths: RuntimePaths,
+        timings: &StartupTimings,
     ) -> Result<Self, DomainError> {
         tracing::info!(
             "Initializing application in {:?} mode with data root: {:?}",
@@ -99,14 +171,23 @@ impl AppState {
             runtime_paths.data_root
         );
 
-        let data_directory = bootstrap::initialize_data_directory(&runtime_paths.data_root).await?;
+        let data_directory =
+            bootstrap::initialize_data_directory(&runtime_paths.data_root, timings).await?;
 
-        let services = bootstrap::build_services(&app_handle, &data_directory).await?;
+        let services = timings
+            .measure(
+                "services",
+                bootstrap::build_services(&app_handle, &data_directory, timings),
+            )
+            .await?;
 
         tracing::info!("Application initialized successfully");
 
         Ok(Self {
             character_service: services.character_service,
+            character_asset_service: services.character_asset_service,
+            sound_service: services.sound_service,
+            sprite_service: services.sprite_service,
             chat_service: services.chat_service,
             group_chat_service: services.group_chat_service,
             user_service: services.user_service,
@@ -121,10 +202,15 @@ impl AppState {
             avatar_service: services.avatar_service,
             group_service: services.group_service,
             background_service: services.background_service,
+            file_attachment_service: services.file_attachment_service,
+            file_ingest_service: services.file_ingest_service,
             image_metadata_service: services.image_metadata_service,
             theme_service: services.theme_service,
+            user_customization_service: services.user_customization_service,
             preset_service: services.preset_service,
             quick_reply_service: services.quick_reply_service,
+            recent_items_service: services.recent_items_service,
+            tag_service: services.tag_service,
             agent_profile_service: services.agent_profile_service,
             agent_profile_diagnostic_service: services.agent_profile_diagnostic_service,
             prompt_assembly_service: services.prompt_assembly_service,
@@ -132,18 +218,42 @@ impl AppState {
             agent_run_retention_automation_service: services.agent_run_retention_automation_service,
             agent_runtime_service: services.agent_runtime_service,
             chat_completion_service: services.chat_completion_service,
+            model_capability_service: services.model_capability_service,
             llm_connection_service: services.llm_connection_service,
+            variable_service: services.variable_service,
+            macro_engine: services.macro_engine,
+            memory_cache_service: services.memory_cache_service,
+            author_note_service: services.author_note_service,
+            character_prompt_overrides_service: services.character_prompt_overrides_service,
+            character_generation_overrides_service: services.character_generation_overrides_service,
+            character_note_service: services.character_note_service,
+            global_search_service: services.global_search_service,
+            connection_profile_service: services.connection_profile_service,
+            settings_profile_service: services.settings_profile_service,
+            connection_monitor_service: services.connection_monitor_service,
+            summarize_service: services.summarize_service,
+            idle_generation_service: services.idle_generation_service,
+            notification_service: services.notification_service,
+            generation_record_service: services.generation_record_service,
+            chat_metadata_service: services.chat_metadata_service,
             provider_metadata_service: services.provider_metadata_service,
             tokenization_service: services.tokenization_service,
             stable_diffusion_service: services.stable_diffusion_service,
+            horde_service: services.horde_service,
+            novelai_service: services.novelai_service,
+            koboldcpp_service: services.koboldcpp_service,
             translate_service: services.translate_service,
+            expression_service: services.expression_service,
             tts_service: services.tts_service,
             world_info_service: services.world_info_service,
             lan_sync_service: services.lan_sync_service,
             tt_sync_service: services.tt_sync_service,
             sync_automation_service: services.sync_automation_service,
+            backup_schedule_service: services.backup_schedule_service,
             update_service: services.update_service,
+            changelog_service: services.changelog_service,
             native_regex_service: services.native_regex_service,
+            job_manager: services.job_manager,
             ios_policy: services.ios_policy,
         })
     }
@@ -162,24 +272,53 @@ impl AppState {
         self.group_chat_service.clear_cache().await?;
         self.group_service.clear_cache().await?;
         self.secret_service.clear_cache().await?;
+        self.chat_completion_service.invalidate_status_cache().await;
 
         Ok(())
     }
 }
 
 pub fn spawn_initialization(app_handle: AppHandle, runtime_paths: RuntimePaths) {
+    let timings = Arc::new(StartupTimings::new());
+    app_handle.manage(timings.clone());
+
     tauri::async_runtime::spawn(async move {
-        match AppState::new(app_handle.clone(), runtime_paths).await {
+        #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+        let data_root = runtime_paths.data_root.clone();
+        let default_user_dir = DataDirectory::new(runtime_paths.data_root.clone())
+            .default_user()
+            .to_path_buf();
+        match AppState::new(app_handle.clone(), runtime_paths, &timings).await {
             Ok(state) => {
                 app_handle.manage(Arc::new(state));
 
+                #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+                start_settings_file_watcher(&app_handle, &data_root);
+                #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+                start_library_watcher(&app_handle, &data_root);
+
+                // Messages journaled by `add_message` before the app was killed must be
+                // in their chats before the frontend reads them.
+                let chat_service = app_handle.state::<Arc<AppState>>().chat_service.clone();
+                if let Err(error) = chat_service.replay_chat_journals().await {
+                    tracing::warn!("Failed to replay chat journals: {}", error);
+                }
+
+                // A first launch needs the default settings and presets before the frontend
+                // loads. Later launches only pick up content added by updates, which can
+                // wait until after `app-ready`.
                 let content_service = app_handle.state::<Arc<AppState>>().content_service.clone();
-                match content_service
-                    .initialize_default_content("default-user")
+                let content_initialized = content_service
+                    .is_default_content_initialized("default-user")
                     .await
-                {
-                    Ok(_) => tracing::debug!("Successfully initialized default content"),
-                    Err(error) => tracing::warn!("Failed to initialize default content: {}", error),
+                    .unwrap_or(false);
+                if !content_initialized {
+                    timings
+                        .measure(
+                            "default_content",
+                            initialize_default_content(&content_service),
+                        )
+                        .await;
                 }
 
                 let sync_automation_service = app_handle
@@ -188,16 +327,57 @@ pub fn spawn_initialization(app_handle: AppHandle, runtime_paths: RuntimePaths)
                     .clone();
                 sync_automation_service.start();
 
+                let backup_schedule_service = app_handle
+                    .state::<Arc<AppState>>()
+                    .backup_schedule_service
+                    .clone();
+                backup_schedule_service.start();
+
                 let agent_run_retention_automation_service = app_handle
                     .state::<Arc<AppState>>()
                     .agent_run_retention_automation_service
                     .clone();
                 agent_run_retention_automation_service.start();
 
+                let connection_monitor_service = app_handle
+                    .state::<Arc<AppState>>()
+                    .connection_monitor_service
+                    .clone();
+                connection_monitor_service.start();
+
+                let bridge_server = timings
+                    .measure(
+                        "bridge_server",
+                        load_bridge_server(&app_handle, default_user_dir),
+                    )
+                    .await;
+
+                timings.mark_ready();
                 match app_handle.emit("app-ready", ()) {
                     Ok(_) => tracing::debug!("Application is ready"),
                     Err(error) => tracing::error!("Failed to emit app-ready event: {}", error),
                 }
+                emit_pending_crash_reports(&app_handle);
+
+                if content_initialized {
+                    timings
+                        .measure_deferred(
+                            "default_content",
+                            initialize_default_content(&content_service),
+                        )
+                        .await;
+                }
+                if let Some(bridge_server) = bridge_server {
+                    timings
+                        .measure_deferred("bridge_server_start", async {
+                            if let Err(error) = bridge_server.start_if_enabled().await {
+                                tracing::warn!("Failed to start bridge server: {}", error);
+                            }
+                            #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+                            sync_tray_bridge_server_item(&app_handle, &bridge_server).await;
+                        })
+                        .await;
+                }
             }
             Err(error) => {
                 logger::error(&format!(
@@ -215,3 +395,133 @@ pub fn spawn_initialization(app_handle: AppHandle, runtime_paths: RuntimePaths)
         }
     });
 }
+
+async fn initialize_default_content(content_service: &ContentService) {
+    match content_service
+        .initialize_default_content("default-user")
+        .await
+    {
+        Ok(_) => tracing::debug!("Successfully initialized default content"),
+        Err(error) => tracing::warn!("Failed to initialize default content: {}", error),
+    }
+}
+
+/// Tells the frontend about crash reports written since the previous launch.
+fn emit_pending_crash_reports(app_handle: &AppHandle) {
+    let Some(crash_reports) = app_handle.try_state::<Arc<CrashReportStore>>() else {
+        return;
+    };
+
+    let pending = match crash_reports.take_pending() {
+        Ok(pending) => pending,
+        Err(error) => {
+            tracing::warn!("Failed to read pending crash reports: {}", error);
+            return;
+        }
+    };
+    if pending.is_empty() {
+        return;
+    }
+
+    tracing::warn!(
+        "{} crash report(s) recorded since last launch",
+        pending.len()
+    );
+    if let Err(error) = app_handle.emit(APP_CRASH_DETECTED_EVENT, pending) {
+        tracing::error!("Failed to emit crash report event: {}", error);
+    }
+}
+
+/// Loads the LAN bridge server so its commands are available at `app-ready`; starting it
+/// is left to the caller. Failures only leave the bridge unavailable, so they are logged
+/// and ignored.
+async fn load_bridge_server(
+    app_handle: &AppHandle,
+    default_user_dir: std::path::PathBuf,
+) -> Option<Arc<BridgeServer>> {
+    let app_state = app_handle.state::<Arc<AppState>>().inner().clone();
+    let bridge_server =
+        match BridgeServer::load(app_state, BridgeServerStore::new(default_user_dir)).await {
+            Ok(bridge_server) => Arc::new(bridge_server),
+            Err(error) => {
+                tracing::warn!("Bridge server is unavailable: {}", error);
+                return None;
+            }
+        };
+    app_handle.manage(bridge_server.clone());
+    Some(bridge_server)
+}
+
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+async fn sync_tray_bridge_server_item(app_handle: &AppHandle, bridge_server: &BridgeServer) {
+    use crate::presentation::desktop_tray::DesktopTrayState;
+
+    let Some(tray_state) = app_handle.try_state::<Arc<DesktopTrayState>>() else {
+        return;
+    };
+    if let Ok(status) = bridge_server.get_status().await {
+        tray_state.set_bridge_server_enabled(status.enabled);
+    }
+}
+
+/// Forwards external edits of `settings.json` and themes (e.g. from a sync tool) to the
+/// frontend. A watcher failure only disables hot-reload, so it is logged and ignored.
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+fn start_settings_file_watcher(app_handle: &AppHandle, data_root: &std::path::Path) {
+    use crate::infrastructure::settings_watcher::SettingsFileWatcher;
+
+    let data_directory = DataDirectory::new(data_root.to_path_buf());
+    match SettingsFileWatcher::start(
+        app_handle.clone(),
+        data_directory.settings().join("settings.json"),
+        data_directory.default_user().join("themes"),
+    ) {
+        Ok(watcher) => {
+            app_handle.manage(watcher);
+        }
+        Err(error) => tracing::warn!("Settings hot-reload is unavailable: {}", error),
+    }
+}
+
+/// Invalidates the character and chat caches when cards or chats are added or removed
+/// outside the app, then lets the frontend reload its character list.
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+fn start_library_watcher(app_handle: &AppHandle, data_root: &std::path::Path) {
+    use crate::infrastructure::library_watcher::{CHARACTERS_CHANGED_EVENT, LibraryWatcher};
+
+    let data_directory = DataDirectory::new(data_root.to_path_buf());
+    let (watcher, mut changes) =
+        match LibraryWatcher::start(data_directory.characters(), data_directory.chats()) {
+            Ok(started) => started,
+            Err(error) => {
+                tracing::warn!("Character library watching is unavailable: {}", error);
+                return;
+            }
+        };
+    app_handle.manage(watcher);
+
+    let app_handle = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        while let Some(change) = changes.recv().await {
+            let app_state = app_handle.state::<Arc<AppState>>();
+            // Cached characters carry chat statistics, so any change invalidates them.
+            if let Err(error) = app_state.character_service.clear_cache().await {
+                tracing::warn!("Failed to clear character cache: {}", error);
+            }
+            if !change.chat_folders.is_empty() {
+                let cleared = app_state.chat_service.clear_cache().await;
+                if let Err(error) = cleared {
+                    tracing::warn!("Failed to clear chat cache: {}", error);
+                }
+            }
+
+            if let Err(error) = app_handle.emit(CHARACTERS_CHANGED_EVENT, change) {
+                tracing::error!(
+                    "Failed to emit {} event: {}",
+                    CHARACTERS_CHANGED_EVENT,
+                    error
+                );
+            }
+        }
+    });
+}
@@ -1,9 +1,11 @@
 use std::path::Path;
 use std::sync::Arc;
 
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 use tokio::sync::Semaphore;
 
+use crate::app::job_manager::{JOB_UPDATED_EVENT, JobManager};
+use crate::app::startup_report::StartupTimings;
 use crate::application::services::agent_model_gateway::ChatCompletionAgentModelGateway;
 use crate::application::services::agent_profile_diagnostic_service::AgentProfileDiagnosticService;
 use crate::application::services::agent_profile_service::AgentProfileService;
@@ -14,77 +16,132 @@ use crate::application::services::agent_workspace_lifecycle_service::{
     AgentRunActivity, AgentWorkspaceLifecycleService,
 };
 use crate::application::services::asset_service::AssetService;
+use crate::application::services::author_note_service::AuthorNoteService;
 use crate::application::services::avatar_service::AvatarService;
 use crate::application::services::background_service::BackgroundService;
+use crate::application::services::backup_schedule_service::BackupScheduleService;
+use crate::application::services::changelog_service::ChangelogService;
+use crate::application::services::character_asset_service::CharacterAssetService;
+use crate::application::services::character_generation_overrides_service::CharacterGenerationOverridesService;
+use crate::application::services::character_note_service::CharacterNoteService;
+use crate::application::services::character_prompt_overrides_service::CharacterPromptOverridesService;
 use crate::application::services::character_service::CharacterService;
 use crate::application::services::chat_completion_service::ChatCompletionService;
+use crate::application::services::chat_metadata_service::ChatMetadataService;
 use crate::application::services::chat_service::ChatService;
+use crate::application::services::connection_monitor_service::ConnectionMonitorService;
+use crate::application::services::connection_profile_service::ConnectionProfileService;
 use crate::application::services::content_service::ContentService;
+use crate::application::services::expression_service::ExpressionService;
 use crate::application::services::extension_service::ExtensionService;
 use crate::application::services::extension_store_service::ExtensionStoreService;
+use crate::application::services::file_attachment_service::FileAttachmentService;
+use crate::application::services::file_ingest_service::FileIngestService;
+use crate::application::services::generation_record_service::GenerationRecordService;
+use crate::application::services::global_search_service::GlobalSearchService;
 use crate::application::services::group_chat_service::GroupChatService;
 use crate::application::services::group_service::GroupService;
+use crate::application::services::horde_service::HordeService;
+use crate::application::services::idle_generation_service::IdleGenerationService;
 use crate::application::services::image_metadata_service::ImageMetadataService;
+use crate::application::services::inline_image_service::InlineImageService;
+use crate::application::services::koboldcpp_service::KoboldCppService;
 use crate::application::services::lan_sync_service::LanSyncService;
 use crate::application::services::llm_connection_service::LlmConnectionService;
+use crate::application::services::macro_engine::MacroEngine;
+use crate::application::services::memory_cache_service::MemoryCacheService;
+use crate::application::services::model_capability_service::ModelCapabilityService;
 use crate::application::services::native_regex_service::NativeRegexService;
+use crate::application::services::notification_service::NotificationService;
+use crate::application::services::novelai_service::NovelAiService;
 use crate::application::services::preset_service::PresetService;
 use crate::application::services::prompt_assembly_service::PromptAssemblyService;
 use crate::application::services::provider_metadata_service::ProviderMetadataService;
 use crate::application::services::quick_reply_service::QuickReplyService;
+use crate::application::services::recent_items_service::RecentItemsService;
 use crate::application::services::secret_service::SecretService;
+use crate::application::services::settings_profile_service::SettingsProfileService;
 use crate::application::services::settings_service::SettingsService;
 use crate::application::services::skill_service::SkillService;
+use crate::application::services::sound_service::SoundService;
+use crate::application::services::sprite_service::SpriteService;
 use crate::application::services::stable_diffusion_service::StableDiffusionService;
+use crate::application::services::summarize_service::SummarizeService;
 use crate::application::services::sync_automation_service::SyncAutomationService;
+use crate::application::services::tag_service::TagService;
 use crate::application::services::theme_service::ThemeService;
 use crate::application::services::tokenization_service::TokenizationService;
 use crate::application::services::translate_service::TranslateService;
 use crate::application::services::tt_sync_service::TtSyncService;
 use crate::application::services::tts_service::TtsService;
 use crate::application::services::update_service::UpdateService;
+use crate::application::services::user_customization_service::UserCustomizationService;
 use crate::application::services::user_directory_service::UserDirectoryService;
 use crate::application::services::user_service::UserService;
+use crate::application::services::variable_service::VariableService;
 use crate::application::services::world_info_service::WorldInfoService;
 use crate::domain::errors::DomainError;
+use crate::domain::models::settings::TauriTavernSettings;
 use crate::domain::repositories::agent_invocation_repository::AgentInvocationRepository;
 use crate::domain::repositories::agent_profile_repository::AgentProfileRepository;
 use crate::domain::repositories::agent_profile_storage_health_repository::AgentProfileStorageHealthRepository;
 use crate::domain::repositories::agent_run_repository::AgentRunRepository;
 use crate::domain::repositories::agent_workspace_lifecycle_repository::AgentWorkspaceLifecycleRepository;
 use crate::domain::repositories::asset_repository::AssetRepository;
+use crate::domain::repositories::author_note_repository::AuthorNoteRepository;
 use crate::domain::repositories::avatar_repository::AvatarRepository;
 use crate::domain::repositories::background_repository::BackgroundRepository;
+use crate::domain::repositories::changelog_repository::ChangelogRepository;
+use crate::domain::repositories::character_asset_repository::CharacterAssetRepository;
+use crate::domain::repositories::character_generation_overrides_repository::CharacterGenerationOverridesRepository;
+use crate::domain::repositories::character_note_repository::CharacterNoteRepository;
+use crate::domain::repositories::character_prompt_overrides_repository::CharacterPromptOverridesRepository;
 use crate::domain::repositories::character_repository::CharacterRepository;
 use crate::domain::repositories::chat_completion_repository::ChatCompletionRepository;
 use crate::domain::repositories::chat_repository::ChatRepository;
 use crate::domain::repositories::checkpoint_repository::CheckpointRepository;
 use crate::domain::repositories::content_repository::ContentRepository;
+use crate::domain::repositories::expression_classifier_repository::ExpressionClassifierRepository;
 use crate::domain::repositories::extension_repository::ExtensionRepository;
 use crate::domain::repositories::extension_store_repository::ExtensionStoreRepository;
+use crate::domain::repositories::file_attachment_repository::FileAttachmentRepository;
+use crate::domain::repositories::global_variable_repository::GlobalVariableRepository;
 use crate::domain::repositories::group_chat_repository::GroupChatRepository;
 use crate::domain::repositories::group_repository::GroupRepository;
+use crate::domain::repositories::horde_repository::HordeRepository;
 use crate::domain::repositories::image_metadata_repository::ImageMetadataRepository;
+use crate::domain::repositories::inline_image_repository::InlineImageRepository;
+use crate::domain::repositories::koboldcpp_repository::KoboldCppRepository;
 use crate::domain::repositories::llm_connection_repository::LlmConnectionRepository;
+use crate::domain::repositories::novelai_repository::NovelAiRepository;
 use crate::domain::repositories::preset_repository::PresetRepository;
 use crate::domain::repositories::prompt_cache_repository::PromptCacheRepository;
 use crate::domain::repositories::provider_metadata_repository::ProviderMetadataRepository;
 use crate::domain::repositories::quick_reply_repository::QuickReplyRepository;
+use crate::domain::repositories::recent_items_repository::RecentItemsRepository;
 use crate::domain::repositories::secret_repository::SecretRepository;
 use crate::domain::repositories::settings_repository::SettingsRepository;
 use crate::domain::repositories::skill_repository::SkillRepository;
+use crate::domain::repositories::sound_repository::SoundRepository;
+use crate::domain::repositories::sprite_repository::SpriteRepository;
 use crate::domain::repositories::stable_diffusion_repository::StableDiffusionRepository;
+use crate::domain::repositories::tag_repository::TagRepository;
 use crate::domain::repositories::theme_repository::ThemeRepository;
 use crate::domain::repositories::tokenizer_repository::TokenizerRepository;
 use crate::domain::repositories::translate_repository::TranslateRepository;
 use crate::domain::repositories::tts_repository::TtsRepository;
 use crate::domain::repositories::update_repository::UpdateRepository;
+use crate::domain::repositories::user_customization_repository::UserCustomizationRepository;
 use crate::domain::repositories::user_directory_repository::UserDirectoryRepository;
 use crate::domain::repositories::user_repository::UserRepository;
 use crate::domain::repositories::workspace_repository::WorkspaceRepository;
 use crate::domain::repositories::world_info_repository::WorldInfoRepository;
 use crate::infrastructure::apis::github_update_repository::GitHubUpdateRepository;
 use crate::infrastructure::apis::http_chat_completion_repository::HttpChatCompletionRepository;
+use crate::infrastructure::apis::http_expression_classifier_repository::HttpExpressionClassifierRepository;
+use crate::infrastructure::apis::http_horde_repository::HttpHordeRepository;
+use crate::infrastructure::apis::http_koboldcpp_repository::HttpKoboldCppRepository;
+use crate::infrastructure::apis::http_novelai_repository::HttpNovelAiRepository;
 use crate::infrastructure::apis::http_provider_metadata_repository::HttpProviderMetadataRepository;
 use crate::infrastructure::apis::http_stable_diffusion_repository::HttpStableDiffusionRepository;
 use crate::infrastructure::apis::http_translate_repository::HttpTranslateRepository;
@@ -94,34 +151,52 @@ use crate::infrastructure::http_client_pool::HttpClientPool;
 use crate::infrastructure::logging::llm_api_logs::{
     LlmApiLogStore, LoggingChatCompletionRepository,
 };
-use crate::infrastructure::persistence::file_system::DataDirectory;
+use crate::infrastructure::persistence::file_system::{DataDirectory, recover_interrupted_writes};
 use crate::infrastructure::repositories::chat_directory_identity::new_shared_chat_alias_store_for_user_dir;
 use crate::infrastructure::repositories::file_agent_profile_repository::FileAgentProfileRepository;
 use crate::infrastructure::repositories::file_agent_repository::FileAgentRepository;
 use crate::infrastructure::repositories::file_asset_repository::FileAssetRepository;
+use crate::infrastructure::repositories::file_author_note_repository::FileAuthorNoteRepository;
 use crate::infrastructure::repositories::file_avatar_repository::FileAvatarRepository;
 use crate::infrastructure::repositories::file_background_repository::FileBackgroundRepository;
+use crate::infrastructure::repositories::file_changelog_repository::FileChangelogRepository;
+use crate::infrastructure::repositories::file_character_asset_repository::FileCharacterAssetRepository;
+use crate::infrastructure::repositories::file_character_generation_overrides_repository::FileCharacterGenerationOverridesRepository;
+use crate::infrastructure::repositories::file_character_note_repository::FileCharacterNoteRepository;
+use crate::infrastructure::repositories::file_character_prompt_overrides_repository::FileCharacterPromptOverridesRepository;
 use crate::infrastructure::repositories::file_character_repository::FileCharacterRepository;
 use crate::infrastructure::repositories::file_chat_repository::FileChatRepository;
 use crate::infrastructure::repositories::file_content_repository::FileContentRepository;
+use crate::infrastructure::repositories::file_data_bank_repository::FileDataBankRepository;
 use crate::infrastructure::repositories::file_extension_repository::FileExtensionRepository;
 use crate::infrastructure::repositories::file_extension_store_repository::FileExtensionStoreRepository;
+use crate::infrastructure::repositories::file_global_variable_repository::FileGlobalVariableRepository;
 use crate::infrastructure::repositories::file_group_repository::FileGroupRepository;
 use crate::infrastructure::repositories::file_image_metadata_repository::FileImageMetadataRepository;
+use crate::infrastructure::repositories::file_inline_image_repository::FileInlineImageRepository;
 use crate::infrastructure::repositories::file_llm_connection_repository::FileLlmConnectionRepository;
 use crate::infrastructure::repositories::file_preset_repository::FilePresetRepository;
 use crate::infrastructure::repositories::file_prompt_cache_repository::FilePromptCacheRepository;
 use crate::infrastructure::repositories::file_quick_reply_repository::FileQuickReplyRepository;
+use crate::infrastructure::repositories::file_recent_items_repository::FileRecentItemsRepository;
 use crate::infrastructure::repositories::file_secret_repository::FileSecretRepository;
 use crate::infrastructure::repositories::file_settings_repository::FileSettingsRepository;
 use crate::infrastructure::repositories::file_skill_repository::FileSkillRepository;
+use crate::infrastructure::repositories::file_sound_repository::FileSoundRepository;
+use crate::infrastructure::repositories::file_sprite_repository::FileSpriteRepository;
+use crate::infrastructure::repositories::file_tag_repository::FileTagRepository;
 use crate::infrastructure::repositories::file_theme_repository::FileThemeRepository;
+use crate::infrastructure::repositories::file_user_customization_repository::FileUserCustomizationRepository;
 use crate::infrastructure::repositories::file_user_directory_repository::FileUserDirectoryRepository;
 use crate::infrastructure::repositories::file_user_repository::FileUserRepository;
 use crate::infrastructure::repositories::file_world_info_repository::FileWorldInfoRepository;
+use crate::infrastructure::user_data_dirs::DefaultUserWebDirs;
 
 pub(super) struct AppServices {
     pub character_service: Arc<CharacterService>,
+    pub character_asset_service: Arc<CharacterAssetService>,
+    pub sound_service: Arc<SoundService>,
+    pub sprite_service: Arc<SpriteService>,
     pub chat_service: Arc<ChatService>,
     pub group_chat_service: Arc<GroupChatService>,
     pub user_service: Arc<UserService>,
@@ -136,10 +211,15 @@ pub(super) struct AppServices {
     pub avatar_service: Arc<AvatarService>,
     pub group_service: Arc<GroupService>,
     pub background_service: Arc<BackgroundService>,
+    pub file_attachment_service: Arc<FileAttachmentService>,
+    pub file_ingest_service: Arc<FileIngestService>,
     pub image_metadata_service: Arc<ImageMetadataService>,
     pub theme_service: Arc<ThemeService>,
+    pub user_customization_service: Arc<UserCustomizationService>,
     pub preset_service: Arc<PresetService>,
     pub quick_reply_service: Arc<QuickReplyService>,
+    pub recent_items_service: Arc<RecentItemsService>,
+    pub tag_service: Arc<TagService>,
     pub agent_profile_service: Arc<AgentProfileService>,
     pub agent_profile_diagnostic_service: Arc<AgentProfileDiagnosticService>,
     pub prompt_assembly_service: Arc<PromptAssemblyService>,
@@ -147,25 +227,57 @@ pub(super) struct AppServices {
     pub agent_run_retention_automation_service: Arc<AgentRunRetentionAutomationService>,
     pub agent_runtime_service: Arc<AgentRuntimeService>,
     pub chat_completion_service: Arc<ChatCompletionService>,
+    pub model_capability_service: Arc<ModelCapabilityService>,
     pub llm_connection_service: Arc<LlmConnectionService>,
+    pub variable_service: Arc<VariableService>,
+    pub macro_engine: Arc<MacroEngine>,
+    pub memory_cache_service: Arc<MemoryCacheService>,
+    pub author_note_service: Arc<AuthorNoteService>,
+    pub character_prompt_overrides_service: Arc<CharacterPromptOverridesService>,
+    pub character_generation_overrides_service: Arc<CharacterGenerationOverridesService>,
+    pub character_note_service: Arc<CharacterNoteService>,
+    pub global_search_service: Arc<GlobalSearchService>,
+    pub connection_profile_service: Arc<ConnectionProfileService>,
+    pub settings_profile_service: Arc<SettingsProfileService>,
+    pub connection_monitor_service: Arc<ConnectionMonitorService>,
+    pub summarize_service: Arc<SummarizeService>,
+    pub idle_generation_service: Arc<IdleGenerationService>,
+    pub notification_service: Arc<NotificationService>,
+    pub generation_record_service: Arc<GenerationRecordService>,
+    pub chat_metadata_service: Arc<ChatMetadataService>,
     pub provider_metadata_service: Arc<ProviderMetadataService>,
     pub tokenization_service: Arc<TokenizationService>,
     pub stable_diffusion_service: Arc<StableDiffusionService>,
+    pub horde_service: Arc<HordeService>,
+    pub novelai_service: Arc<NovelAiService>,
+    pub koboldcpp_service: Arc<KoboldCppService>,
     pub translate_service: Arc<TranslateService>,
+    pub expression_service: Arc<ExpressionService>,
     pub tts_service: Arc<TtsService>,
     pub world_info_service: Arc<WorldInfoService>,
     pub lan_sync_service: Arc<LanSyncService>,
     pub tt_sync_service: Arc<TtSyncService>,
     pub sync_automation_service: Arc<SyncAutomationService>,
+    pub backup_schedule_service: Arc<BackupScheduleService>,
     pub update_service: Arc<UpdateService>,
+    pub changelog_service: Arc<ChangelogService>,
     pub native_regex_service: Arc<NativeRegexService>,
+    pub job_manager: Arc<JobManager>,
     pub ios_policy: crate::domain::ios_policy::IosPolicyActivationReport,
 }
 
 struct AppRepositories {
     character_repository: Arc<dyn CharacterRepository>,
+    character_asset_repository: Arc<dyn CharacterAssetRepository>,
+    sound_repository: Arc<dyn SoundRepository>,
+    sprite_repository: Arc<dyn SpriteRepository>,
     chat_repository: Arc<dyn ChatRepository>,
     group_chat_repository: Arc<dyn GroupChatRepository>,
+    global_variable_repository: Arc<dyn GlobalVariableRepository>,
+    author_note_repository: Arc<dyn AuthorNoteRepository>,
+    character_prompt_overrides_repository: Arc<dyn CharacterPromptOverridesRepository>,
+    character_generation_overrides_repository: Arc<dyn CharacterGenerationOverridesRepository>,
+    character_note_repository: Arc<dyn CharacterNoteRepository>,
     user_repository: Arc<dyn UserRepository>,
     settings_repository: Arc<dyn SettingsRepository>,
     prompt_cache_repository: Arc<dyn PromptCacheRepository>,
@@ -179,10 +291,15 @@ struct AppRepositories {
     avatar_repository: Arc<dyn AvatarRepository>,
     group_repository: Arc<dyn GroupRepository>,
     background_repository: Arc<dyn BackgroundRepository>,
+    file_attachment_repository: Arc<dyn FileAttachmentRepository>,
     image_metadata_repository: Arc<dyn ImageMetadataRepository>,
+    inline_image_repository: Arc<dyn InlineImageRepository>,
     theme_repository: Arc<dyn ThemeRepository>,
+    user_customization_repository: Arc<dyn UserCustomizationRepository>,
     preset_repository: Arc<dyn PresetRepository>,
     quick_reply_repository: Arc<dyn QuickReplyRepository>,
+    recent_items_repository: Arc<dyn RecentItemsRepository>,
+    tag_repository: Arc<dyn TagRepository>,
     agent_profile_repository: Arc<dyn AgentProfileRepository>,
     agent_profile_storage_health_repository: Arc<dyn AgentProfileStorageHealthRepository>,
     agent_run_repository: Arc<dyn AgentRunRepository>,
@@ -195,46 +312,67 @@ struct AppRepositories {
     provider_metadata_repository: Arc<dyn ProviderMetadataRepository>,
     tokenizer_repository: Arc<dyn TokenizerRepository>,
     stable_diffusion_repository: Arc<dyn StableDiffusionRepository>,
+    horde_repository: Arc<dyn HordeRepository>,
+    novelai_repository: Arc<dyn NovelAiRepository>,
+    koboldcpp_repository: Arc<dyn KoboldCppRepository>,
     translate_repository: Arc<dyn TranslateRepository>,
+    expression_classifier_repository: Arc<dyn ExpressionClassifierRepository>,
     tts_repository: Arc<dyn TtsRepository>,
     world_info_repository: Arc<dyn WorldInfoRepository>,
     update_repository: Arc<dyn UpdateRepository>,
+    changelog_repository: Arc<dyn ChangelogRepository>,
 }
 
 pub(super) async fn initialize_data_directory(
     data_root: &Path,
+    timings: &StartupTimings,
 ) -> Result<DataDirectory, DomainError> {
     let data_directory = DataDirectory::new(data_root.to_path_buf());
-    data_directory.initialize().await?;
+    timings
+        .measure("data_directory", data_directory.initialize())
+        .await?;
+
+    // A save cut short by a crash leaves its temp file next to the untouched target.
+    let recovery = timings
+        .measure(
+            "recover_interrupted_writes",
+            recover_interrupted_writes(&data_directory.interrupted_write_scope()),
+        )
+        .await;
+    match recovery {
+        Ok(recovery) if !recovery.restored.is_empty() || !recovery.removed.is_empty() => {
+            tracing::warn!(
+                restored = recovery.restored.len(),
+                removed = recovery.removed.len(),
+                "Recovered interrupted file writes"
+            );
+        }
+        Ok(_) => {}
+        Err(error) => tracing::warn!("Failed to recover interrupted file writes: {}", error),
+    }
+
     Ok(data_directory)
 }
 
 pub(super) async fn build_services(
     app_handle: &AppHandle,
     data_directory: &DataDirectory,
+    timings: &StartupTimings,
 ) -> Result<AppServices, DomainError> {
-    let repositories = build_repositories(app_handle, data_directory)?;
-    let tauritavern_settings = repositories
-        .settings_repository
-        .load_tauritavern_settings()
-        .await?;
-    let ios_policy_scope = crate::domain::ios_policy::IosPolicyScope::for_current_platform();
-    let ios_policy = if ios_policy_scope == crate::domain::ios_policy::IosPolicyScope::Ios {
-        let raw_policy = crate::infrastructure::ios_policy_cache::resolve_effective_raw_policy(
-            data_directory.root(),
-            tauritavern_settings.ios_policy.as_ref(),
+    let (repositories, tauritavern_settings) = timings
+        .measure(
+            "repositories",
+            build_repositories(app_handle, data_directory),
         )
         .await?;
-        crate::domain::ios_policy::resolve_ios_policy_activation_report(
-            ios_policy_scope,
-            raw_policy.as_ref(),
-        )?
-    } else {
-        crate::domain::ios_policy::resolve_ios_policy_activation_report(
-            ios_policy_scope,
-            tauritavern_settings.ios_policy.as_ref(),
-        )?
-    };
+    let ios_policy = resolve_ios_policy(data_directory, &tauritavern_settings).await?;
+    let memory_cache_service = Arc::new(MemoryCacheService::new(
+        repositories.character_repository.clone(),
+        repositories.chat_repository.clone(),
+    ));
+    memory_cache_service
+        .apply_settings(&tauritavern_settings.memory_cache)
+        .await;
 
     let content_service = Arc::new(ContentService::new(repositories.content_repository.clone()));
     let asset_service = Arc::new(AssetService::new(repositories.asset_repository.clone()));
@@ -252,15 +390,33 @@ pub(super) async fn build_services(
         repositories.background_repository.clone(),
         repositories.image_metadata_repository.clone(),
     ));
+    let file_attachment_service = Arc::new(FileAttachmentService::new(
+        repositories.file_attachment_repository.clone(),
+    ));
     let theme_service = Arc::new(ThemeService::new(repositories.theme_repository.clone()));
+    let user_customization_service = Arc::new(UserCustomizationService::new(
+        repositories.user_customization_repository,
+    ));
     let preset_service = Arc::new(PresetService::new(repositories.preset_repository.clone()));
     let quick_reply_service = Arc::new(QuickReplyService::new(
         repositories.quick_reply_repository.clone(),
     ));
+    let recent_items_service = Arc::new(RecentItemsService::new(
+        repositories.recent_items_repository,
+    ));
+    let tag_service = Arc::new(TagService::new(
+        repositories.tag_repository.clone(),
+        repositories.settings_repository.clone(),
+    ));
     let skill_service = Arc::new(SkillService::new(repositories.skill_repository.clone()));
     let llm_connection_service = Arc::new(LlmConnectionService::new(
         repositories.llm_connection_repository.clone(),
     ));
+    let connection_profile_service = Arc::new(ConnectionProfileService::new(
+        repositories.settings_repository.clone(),
+        repositories.secret_repository.clone(),
+        llm_connection_service.clone(),
+    ));
     let agent_profile_service = Arc::new(AgentProfileService::new(
         repositories.agent_profile_repository.clone(),
         repositories.agent_profile_storage_health_repository.clone(),
@@ -276,13 +432,85 @@ pub(super) async fn build_services(
         repositories.preset_repository.clone(),
         llm_connection_service.clone(),
     ));
+    let model_capability_service = Arc::new(ModelCapabilityService::new(app_handle.clone()));
+    let inline_image_service = Arc::new(InlineImageService::new(
+        repositories.inline_image_repository,
+    ));
+    let variable_service = Arc::new(VariableService::new(
+        repositories.chat_repository.clone(),
+        repositories.group_chat_repository.clone(),
+        repositories.global_variable_repository,
+    ));
+    let macro_engine = Arc::new(MacroEngine::new(variable_service.clone()));
+    let author_note_service = Arc::new(AuthorNoteService::new(
+        repositories.author_note_repository,
+        repositories.chat_repository.clone(),
+        repositories.group_chat_repository.clone(),
+    ));
+    let character_prompt_overrides_service = Arc::new(CharacterPromptOverridesService::new(
+        repositories.character_prompt_overrides_repository,
+    ));
+    let character_generation_overrides_service =
+        Arc::new(CharacterGenerationOverridesService::new(
+            repositories
+                .character_generation_overrides_repository
+                .clone(),
+        ));
+    let character_note_service = Arc::new(CharacterNoteService::new(
+        repositories.character_note_repository,
+    ));
+    let global_search_service = Arc::new(GlobalSearchService::new(
+        repositories.character_repository.clone(),
+        character_note_service.clone(),
+        repositories.world_info_repository.clone(),
+        repositories.preset_repository.clone(),
+        repositories.chat_repository.clone(),
+    ));
+    let tokenization_service =
+        Arc::new(TokenizationService::new(repositories.tokenizer_repository));
     let chat_completion_service = Arc::new(ChatCompletionService::new(
+        app_handle.clone(),
         repositories.chat_completion_repository,
         repositories.secret_repository.clone(),
         repositories.settings_repository.clone(),
         repositories.prompt_cache_repository.clone(),
+        model_capability_service.clone(),
+        inline_image_service,
+        macro_engine.clone(),
+        tokenization_service.clone(),
+        repositories.character_generation_overrides_repository,
         ios_policy.clone(),
     ));
+    let connection_monitor_service = Arc::new(ConnectionMonitorService::new(
+        app_handle.clone(),
+        repositories.settings_repository.clone(),
+        connection_profile_service.clone(),
+        chat_completion_service.clone(),
+    ));
+    let summarize_service = Arc::new(SummarizeService::new(
+        repositories.settings_repository.clone(),
+        repositories.chat_repository.clone(),
+        repositories.group_chat_repository.clone(),
+        llm_connection_service.clone(),
+        chat_completion_service.clone(),
+    ));
+    let idle_generation_service = Arc::new(IdleGenerationService::new(
+        app_handle.clone(),
+        repositories.settings_repository.clone(),
+        chat_completion_service.clone(),
+    ));
+    let notification_service = Arc::new(NotificationService::new(
+        app_handle.clone(),
+        repositories.settings_repository.clone(),
+    ));
+    let generation_record_service = Arc::new(GenerationRecordService::new(
+        repositories.chat_repository.clone(),
+        repositories.group_chat_repository.clone(),
+    ));
+    let chat_metadata_service = Arc::new(ChatMetadataService::new(
+        repositories.chat_repository.clone(),
+        repositories.group_chat_repository.clone(),
+    ));
     let provider_metadata_service = Arc::new(ProviderMetadataService::new(
         repositories.provider_metadata_repository,
         repositories.secret_repository.clone(),
@@ -316,17 +544,31 @@ pub(super) async fn build_services(
         repositories.agent_workspace_lifecycle_repository.clone(),
         agent_runtime_service.clone() as Arc<dyn AgentRunActivity>,
     ));
-    let tokenization_service =
-        Arc::new(TokenizationService::new(repositories.tokenizer_repository));
     let native_regex_service = Arc::new(NativeRegexService::new());
     let stable_diffusion_service = Arc::new(StableDiffusionService::new(
         repositories.stable_diffusion_repository,
         repositories.secret_repository.clone(),
     ));
+    let horde_service = Arc::new(HordeService::new(
+        repositories.horde_repository,
+        repositories.secret_repository.clone(),
+    ));
+    let novelai_service = Arc::new(NovelAiService::new(
+        repositories.novelai_repository,
+        repositories.secret_repository.clone(),
+    ));
+    let koboldcpp_service = Arc::new(KoboldCppService::new(
+        repositories.koboldcpp_repository,
+        repositories.secret_repository.clone(),
+    ));
     let translate_service = Arc::new(TranslateService::new(
         repositories.translate_repository,
         repositories.secret_repository.clone(),
     ));
+    let expression_service = Arc::new(ExpressionService::new(
+        repositories.expression_classifier_repository,
+        repositories.secret_repository.clone(),
+    ));
     let tts_service = Arc::new(TtsService::new(
         repositories.tts_repository,
         repositories.secret_repository.clone(),
@@ -336,6 +578,7 @@ pub(super) async fn build_services(
     ));
 
     let update_service = Arc::new(UpdateService::new(repositories.update_repository));
+    let changelog_service = Arc::new(ChangelogService::new(repositories.changelog_repository));
 
     let group_service = Arc::new(GroupService::new(
         repositories.group_repository.clone(),
@@ -347,21 +590,40 @@ pub(super) async fn build_services(
         repositories.world_info_repository.clone(),
         agent_workspace_lifecycle_service.clone(),
     ));
+    let character_asset_service = Arc::new(CharacterAssetService::new(
+        repositories.character_asset_repository.clone(),
+    ));
+    let sound_service = Arc::new(SoundService::new(repositories.sound_repository));
+    let sprite_service = Arc::new(SpriteService::new(repositories.sprite_repository));
     let chat_service = Arc::new(ChatService::new(
         repositories.chat_repository,
         repositories.character_repository.clone(),
         agent_workspace_lifecycle_service.clone(),
     ));
+    let file_ingest_service = Arc::new(FileIngestService::new(
+        character_service.clone(),
+        chat_service.clone(),
+        world_info_service.clone(),
+        preset_service.clone(),
+        theme_service.clone(),
+    ));
     let group_chat_service = Arc::new(GroupChatService::new(
         repositories.group_chat_repository,
         agent_workspace_lifecycle_service,
     ));
     let user_service = Arc::new(UserService::new(repositories.user_repository));
-    let settings_service = Arc::new(SettingsService::new(repositories.settings_repository));
+    let settings_service = Arc::new(SettingsService::new(
+        repositories.settings_repository.clone(),
+    ));
     let user_directory_service = Arc::new(UserDirectoryService::new(
         repositories.user_directory_repository,
     ));
     let http_client_pool = app_handle.state::<Arc<HttpClientPool>>().inner().clone();
+    let settings_profile_service = Arc::new(SettingsProfileService::new(
+        app_handle.clone(),
+        repositories.settings_repository,
+        http_client_pool.clone(),
+    ));
     let sync_permit = Arc::new(Semaphore::new(1));
     let lan_sync_service = Arc::new(LanSyncService::new(
         app_handle.clone(),
@@ -376,6 +638,15 @@ pub(super) async fn build_services(
         data_directory.default_user().to_path_buf(),
         sync_permit,
     ));
+    let job_app_handle = app_handle.clone();
+    let job_manager = Arc::new(JobManager::load(
+        data_directory.root().join("_tauritavern").join("jobs.json"),
+        move |status| {
+            if let Err(error) = job_app_handle.emit(JOB_UPDATED_EVENT, status) {
+                tracing::warn!("Failed to emit job update: {}", error);
+            }
+        },
+    ));
     let sync_automation_service = Arc::new(SyncAutomationService::new(
         app_handle.clone(),
         data_directory.default_user().to_path_buf(),
@@ -383,6 +654,11 @@ pub(super) async fn build_services(
         tt_sync_service.clone(),
         ios_policy.capabilities.sync.lan,
     ));
+    let backup_schedule_service = Arc::new(BackupScheduleService::new(
+        app_handle.clone(),
+        data_directory.root().to_path_buf(),
+        data_directory.default_user().to_path_buf(),
+    ));
 
     let secret_service = Arc::new(SecretService::new(
         repositories.secret_repository,
@@ -391,6 +667,9 @@ pub(super) async fn build_services(
 
     Ok(AppServices {
         character_service,
+        character_asset_service,
+        sound_service,
+        sprite_service,
         chat_service,
         group_chat_service,
         user_service,
@@ -405,10 +684,15 @@ pub(super) async fn build_services(
         avatar_service,
         group_service,
         background_service,
+        file_attachment_service,
+        file_ingest_service,
         image_metadata_service,
         theme_service,
+        user_customization_service,
         preset_service,
         quick_reply_service,
+        recent_items_service,
+        tag_service,
         agent_profile_service,
         agent_profile_diagnostic_service,
         prompt_assembly_service,
@@ -416,31 +700,104 @@ pub(super) async fn build_services(
         agent_run_retention_automation_service,
         agent_runtime_service,
         chat_completion_service,
+        model_capability_service,
         llm_connection_service,
+        variable_service,
+        macro_engine,
+        memory_cache_service,
+        author_note_service,
+        character_prompt_overrides_service,
+        character_generation_overrides_service,
+        character_note_service,
+        global_search_service,
+        connection_profile_service,
+        settings_profile_service,
+        connection_monitor_service,
+        summarize_service,
+        idle_generation_service,
+        notification_service,
+        generation_record_service,
+        chat_metadata_service,
         provider_metadata_service,
         tokenization_service,
         stable_diffusion_service,
+        horde_service,
+        novelai_service,
+        koboldcpp_service,
         translate_service,
+        expression_service,
         tts_service,
         world_info_service,
         lan_sync_service,
         tt_sync_service,
         sync_automation_service,
+        backup_schedule_service,
         update_service,
+        changelog_service,
         native_regex_service,
+        job_manager,
         ios_policy,
     })
 }
 
-fn build_repositories(
+async fn resolve_ios_policy(
+    data_directory: &DataDirectory,
+    tauritavern_settings: &TauriTavernSettings,
+) -> Result<crate::domain::ios_policy::IosPolicyActivationReport, DomainError> {
+    let ios_policy_scope = crate::domain::ios_policy::IosPolicyScope::for_current_platform();
+    if ios_policy_scope == crate::domain::ios_policy::IosPolicyScope::Ios {
+        let raw_policy = crate::infrastructure::ios_policy_cache::resolve_effective_raw_policy(
+            data_directory.root(),
+            tauritavern_settings.ios_policy.as_ref(),
+        )
+        .await?;
+        crate::domain::ios_policy::resolve_ios_policy_activation_report(
+            ios_policy_scope,
+            raw_policy.as_ref(),
+        )
+    } else {
+        crate::domain::ios_policy::resolve_ios_policy_activation_report(
+            ios_policy_scope,
+            tauritavern_settings.ios_policy.as_ref(),
+        )
+    }
+}
+
+/// Builds every repository and loads the TauriTavern settings, which are read while
+/// extension sources migrate.
+async fn build_repositories(
     app_handle: &AppHandle,
     data_directory: &DataDirectory,
-) -> Result<AppRepositories, DomainError> {
+) -> Result<(AppRepositories, TauriTavernSettings), DomainError> {
     let http_client_pool = app_handle.state::<Arc<HttpClientPool>>().inner().clone();
     let data_root = data_directory.root().to_path_buf();
     let default_user_dir = data_directory.default_user().to_path_buf();
     let chat_aliases = new_shared_chat_alias_store_for_user_dir(data_directory.default_user());
 
+    let secret_repository: Arc<dyn SecretRepository> = Arc::new(
+        FileSecretRepository::new(default_user_dir.join("secrets.json"))
+            .with_migration_ledger(data_root.clone()),
+    );
+
+    // Migrating extension sources walks every installed extension, so it runs on a
+    // blocking thread while the remaining repositories are constructed and settings load.
+    let extension_repository_task = {
+        let user_extensions_dir = default_user_dir.join("extensions");
+        let global_extensions_dir = data_directory.global_extensions().to_path_buf();
+        let extension_sources_dir = data_directory.extension_sources().to_path_buf();
+        let http_client_pool = http_client_pool.clone();
+        let secret_repository = secret_repository.clone();
+        tauri::async_runtime::spawn_blocking(move || {
+            FileExtensionRepository::new(
+                user_extensions_dir,
+                global_extensions_dir,
+                extension_sources_dir,
+                http_client_pool,
+                secret_repository,
+            )
+        })
+    };
+
     let character_repository: Arc<dyn CharacterRepository> =
         Arc::new(FileCharacterRepository::with_chat_aliases(
             data_directory.characters().to_path_buf(),
@@ -452,6 +809,15 @@ fn build_repositories(
             data_directory.default_avatar().to_path_buf(),
             chat_aliases.clone(),
         ));
+    let character_asset_repository: Arc<dyn CharacterAssetRepository> = Arc::new(
+        FileCharacterAssetRepository::new(data_directory.characters().to_path_buf()),
+    );
+    let sound_repository: Arc<dyn SoundRepository> = Arc::new(FileSoundRepository::new(
+        default_user_dir.join("user").join("sounds"),
+    ));
+    let sprite_repository: Arc<dyn SpriteRepository> = Arc::new(FileSpriteRepository::new(
+        data_directory.characters().to_path_buf(),
+    ));
 
     let file_chat_repository = Arc::new(FileChatRepository::with_chat_aliases(
         data_directory.characters().to_path_buf(),
@@ -462,6 +828,25 @@ fn build_repositories(
     ));
     let chat_repository: Arc<dyn ChatRepository> = file_chat_repository.clone();
     let group_chat_repository: Arc<dyn GroupChatRepository> = file_chat_repository;
+    let global_variable_repository: Arc<dyn GlobalVariableRepository> = Arc::new(
+        FileGlobalVariableRepository::new(default_user_dir.join("global_variables.json")),
+    );
+    let author_note_repository: Arc<dyn AuthorNoteRepository> = Arc::new(
+        FileAuthorNoteRepository::new(default_user_dir.join("author-notes")),
+    );
+    let character_prompt_overrides_repository: Arc<dyn CharacterPromptOverridesRepository> =
+        Arc::new(FileCharacterPromptOverridesRepository::new(
+            default_user_dir.join("user").join("character-overrides"),
+        ));
+    let character_generation_overrides_repository: Arc<dyn CharacterGenerationOverridesRepository> =
+        Arc::new(FileCharacterGenerationOverridesRepository::new(
+            default_user_dir
+                .join("user")
+                .join("character-generation-overrides"),
+        ));
+    let character_note_repository: Arc<dyn CharacterNoteRepository> = Arc::new(
+        FileCharacterNoteRepository::new(default_user_dir.join("user").join("character-notes")),
+    );
 
     let user_repository: Arc<dyn UserRepository> = Arc::new(FileUserRepository::new(
         data_directory.user_data().to_path_buf(),
@@ -478,9 +863,6 @@ fn build_repositories(
     let user_directory_repository: Arc<dyn UserDirectoryRepository> =
         Arc::new(FileUserDirectoryRepository::new(data_root.clone()));
 
-    let secret_repository: Arc<dyn SecretRepository> = Arc::new(FileSecretRepository::new(
-        default_user_dir.join("secrets.json"),
-    ));
     let skill_repository: Arc<dyn SkillRepository> = Arc::new(FileSkillRepository::new(
         data_root.join("_tauritavern").join("skills"),
     ));
@@ -497,14 +879,6 @@ fn build_repositories(
         default_user_dir.join("characters"),
     ));
 
-    let extension_repository: Arc<dyn ExtensionRepository> =
-        Arc::new(FileExtensionRepository::new(
-            default_user_dir.join("extensions"),
-            data_directory.global_extensions().to_path_buf(),
-            data_directory.extension_sources().to_path_buf(),
-            http_client_pool.clone(),
-        )?);
-
     let extension_store_repository: Arc<dyn ExtensionStoreRepository> = Arc::new(
         FileExtensionStoreRepository::new(data_root.join("_tauritavern").join("extension-store")),
     );
@@ -528,9 +902,19 @@ fn build_repositories(
             default_user_dir.clone(),
             data_directory.default_user().join("backgrounds"),
         ));
+    let file_attachment_repository: Arc<dyn FileAttachmentRepository> = Arc::new(
+        FileDataBankRepository::new(data_directory.default_user().join("files")),
+    );
+    let inline_image_repository: Arc<dyn InlineImageRepository> =
+        Arc::new(FileInlineImageRepository::new(default_user_dir.clone()));
 
-    let theme_repository: Arc<dyn ThemeRepository> =
-        Arc::new(FileThemeRepository::new(default_user_dir.join("themes")));
+    let theme_repository: Arc<dyn ThemeRepository> = Arc::new(FileThemeRepository::new(
+        default_user_dir.join("themes"),
+        DefaultUserWebDirs::from_data_root(&data_root),
+    ));
+    let user_customization_repository: Arc<dyn UserCustomizationRepository> = Arc::new(
+        FileUserCustomizationRepository::new(default_user_dir.join("user")),
+    );
 
     let preset_repository: Arc<dyn PresetRepository> = Arc::new(FilePresetRepository::new(
         app_handle.clone(),
@@ -540,6 +924,17 @@ fn build_repositories(
     let quick_reply_repository: Arc<dyn QuickReplyRepository> = Arc::new(
         FileQuickReplyRepository::new(data_directory.default_user().join("QuickReplies")),
     );
+    let recent_items_repository: Arc<dyn RecentItemsRepository> =
+        Arc::new(FileRecentItemsRepository::new(
+            data_directory
+                .default_user()
+                .join("user")
+                .join("cache")
+                .join("recents.json"),
+        ));
+    let tag_repository: Arc<dyn TagRepository> = Arc::new(FileTagRepository::new(
+        data_directory.default_user().join("tags.json"),
+    ));
     let agent_profile_file_repository = Arc::new(FileAgentProfileRepository::new(
         data_root.join("_tauritavern").join("agent-profiles"),
     ));
@@ -582,8 +977,17 @@ fn build_repositories(
             default_user_dir.join("user").join("workflows"),
         ));
 
+    let horde_repository: Arc<dyn HordeRepository> =
+        Arc::new(HttpHordeRepository::new(http_client_pool.clone()));
+    let novelai_repository: Arc<dyn NovelAiRepository> =
+        Arc::new(HttpNovelAiRepository::new(http_client_pool.clone()));
+    let koboldcpp_repository: Arc<dyn KoboldCppRepository> =
+        Arc::new(HttpKoboldCppRepository::new(http_client_pool.clone()));
     let translate_repository: Arc<dyn TranslateRepository> =
         Arc::new(HttpTranslateRepository::new(http_client_pool.clone()));
+    let expression_classifier_repository: Arc<dyn ExpressionClassifierRepository> = Arc::new(
+        HttpExpressionClassifierRepository::new(http_client_pool.clone()),
+    );
     let tts_repository: Arc<dyn TtsRepository> =
         Arc::new(HttpTtsRepository::new(http_client_pool.clone()));
 
@@ -593,11 +997,34 @@ fn build_repositories(
 
     let update_repository: Arc<dyn UpdateRepository> =
         Arc::new(GitHubUpdateRepository::new(http_client_pool.clone()));
+    let changelog_repository: Arc<dyn ChangelogRepository> =
+        Arc::new(FileChangelogRepository::new(data_root.clone()));
+
+    let (extension_repository, tauritavern_settings) = tokio::try_join!(
+        async {
+            extension_repository_task.await.map_err(|error| {
+                DomainError::InternalError(format!(
+                    "Extension repository initialization failed: {}",
+                    error
+                ))
+            })?
+        },
+        settings_repository.load_tauritavern_settings(),
+    )?;
+    let extension_repository: Arc<dyn ExtensionRepository> = Arc::new(extension_repository);
 
-    Ok(AppRepositories {
+    let repositories = AppRepositories {
         character_repository,
+        character_asset_repository,
+        sound_repository,
+        sprite_repository,
         chat_repository,
         group_chat_repository,
+        global_variable_repository,
+        author_note_repository,
+        character_prompt_overrides_repository,
+        character_generation_overrides_repository,
+        character_note_repository,
         user_repository,
         settings_repository,
         prompt_cache_repository,
@@ -611,10 +1038,15 @@ fn build_repositories(
         avatar_repository,
         group_repository,
         background_repository,
+        file_attachment_repository,
         image_metadata_repository,
+        inline_image_repository,
         theme_repository,
+        user_customization_repository,
         preset_repository,
         quick_reply_repository,
+        recent_items_repository,
+        tag_repository,
         agent_profile_repository,
         agent_profile_storage_health_repository,
         agent_run_repository,
@@ -627,9 +1059,15 @@ fn build_repositories(
         provider_metadata_repository,
         tokenizer_repository,
         stable_diffusion_repository,
+        horde_repository,
+        novelai_repository,
+        koboldcpp_repository,
         translate_repository,
+        expression_classifier_repository,
         tts_repository,
         world_info_repository,
         update_repository,
-    })
+        changelog_repository,
+    };
+    Ok((repositories, tauritavern_settings))
 }
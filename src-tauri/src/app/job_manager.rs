@@ -0,0 +1,460 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::file_system::atomic_write_sync;
+
+pub const JOB_UPDATED_EVENT: &str = "job:updated";
+
+const MAX_RETAINED_FINISHED_JOBS: usize = 100;
+const INTERRUPTED_JOB_ERROR: &str = "Interrupted by application restart";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl JobState {
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(Self::Pending),
+            "running" => Some(Self::Running),
+            "completed" => Some(Self::Completed),
+            "failed" => Some(Self::Failed),
+            "cancelled" => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobStatus {
+    pub job_id: String,
+    pub kind: String,
+    pub state: JobState,
+    pub stage: String,
+    pub progress_percent: f32,
+    pub message: String,
+    pub result: Option<Value>,
+    pub error: Option<String>,
+    #[serde(default)]
+    pub cancel_requested: bool,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+}
+
+impl JobStatus {
+    fn new(job_id: String, kind: &str) -> Self {
+        Self {
+            job_id,
+            kind: kind.to_string(),
+            state: JobState::Pending,
+            stage: "queued".to_string(),
+            progress_percent: 0.0,
+            message: "Job queued".to_string(),
+            result: None,
+            error: None,
+            cancel_requested: false,
+            started_at: Utc::now().to_rfc3339(),
+            finished_at: None,
+        }
+    }
+}
+
+type JobNotifier = Box<dyn Fn(&JobStatus) + Send + Sync>;
+
+struct TrackedJob {
+    status: Mutex<JobStatus>,
+    cancel_requested: AtomicBool,
+}
+
+struct JobManagerInner {
+    state_path: PathBuf,
+    notifier: JobNotifier,
+    jobs: Mutex<HashMap<String, Arc<TrackedJob>>>,
+    persist_lock: Mutex<()>,
+}
+
+/// Tracks long-running background jobs (imports, vectorization, thumbnail regeneration, ...)
+/// with progress, cooperative cancellation and a persisted history.
+///
+/// Job state is written to disk on every state transition. Jobs that were still pending or
+/// running when the app last exited are reported as failed on the next launch, since their
+/// workers did not survive the restart.
+#[derive(Clone)]
+pub struct JobManager {
+    inner: Arc<JobManagerInner>,
+}
+
+impl JobManager {
+    pub fn load(
+        state_path: PathBuf,
+        notifier: impl Fn(&JobStatus) + Send + Sync + 'static,
+    ) -> Self {
+        let jobs = read_persisted_jobs(&state_path)
+            .into_iter()
+            .map(|mut status| {
+                if !status.state.is_finished() {
+                    status.state = JobState::Failed;
+                    status.stage = "failed".to_string();
+                    status.message = "Job failed".to_string();
+                    status.error = Some(INTERRUPTED_JOB_ERROR.to_string());
+                    status.finished_at = Some(Utc::now().to_rfc3339());
+                }
+                let job = Arc::new(TrackedJob {
+                    cancel_requested: AtomicBool::new(status.cancel_requested),
+                    status: Mutex::new(status.clone()),
+                });
+                (status.job_id, job)
+            })
+            .collect();
+
+        let manager = Self {
+            inner: Arc::new(JobManagerInner {
+                state_path,
+                notifier: Box::new(notifier),
+                jobs: Mutex::new(jobs),
+                persist_lock: Mutex::new(()),
+            }),
+        };
+        manager.inner.persist();
+        manager
+    }
+
+    /// Registers a new pending job of `kind` and returns the handle its worker reports through.
+    pub fn start(&self, kind: &str) -> Result<JobHandle, DomainError> {
+        let job_id = Uuid::new_v4().simple().to_string();
+        let job = Arc::new(TrackedJob {
+            status: Mutex::new(JobStatus::new(job_id.clone(), kind)),
+            cancel_requested: AtomicBool::new(false),
+        });
+
+        self.inner
+            .jobs
+            .lock()
+            .map_err(|_| lock_error())?
+            .insert(job_id.clone(), job.clone());
+
+        let handle = JobHandle {
+            job_id,
+            job,
+            manager: self.inner.clone(),
+        };
+        handle.publish(true);
+        Ok(handle)
+    }
+
+    /// Returns all known jobs, newest first.
+    pub fn list(&self) -> Result<Vec<JobStatus>, DomainError> {
+        let mut statuses = self.inner.snapshots()?;
+        statuses.sort_by(|left, right| right.started_at.cmp(&left.started_at));
+        Ok(statuses)
+    }
+
+    pub fn get(&self, job_id: &str) -> Result<JobStatus, DomainError> {
+        let job = self.inner.job(job_id)?;
+        let status = job.status.lock().map_err(|_| lock_error())?;
+        Ok(status.clone())
+    }
+
+    /// Requests cooperative cancellation; the worker decides when to stop.
+    pub fn cancel(&self, job_id: &str) -> Result<JobStatus, DomainError> {
+        let job = self.inner.job(job_id)?;
+        let handle = JobHandle {
+            job_id: job_id.to_string(),
+            job,
+            manager: self.inner.clone(),
+        };
+
+        if handle.snapshot()?.state.is_finished() {
+            return Err(DomainError::InvalidData(format!(
+                "Job already finished: {}",
+                job_id
+            )));
+        }
+
+        handle.job.cancel_requested.store(true, Ordering::Relaxed);
+        handle.update(|status| {
+            status.cancel_requested = true;
+            status.message = "Cancellation requested".to_string();
+        });
+        handle.publish(true);
+        handle.snapshot()
+    }
+}
+
+impl JobManagerInner {
+    fn job(&self, job_id: &str) -> Result<Arc<TrackedJob>, DomainError> {
+        self.jobs
+            .lock()
+            .map_err(|_| lock_error())?
+            .get(job_id)
+            .cloned()
+            .ok_or_else(|| DomainError::NotFound(format!("Job not found: {}", job_id)))
+    }
+
+    fn snapshots(&self) -> Result<Vec<JobStatus>, DomainError> {
+        let jobs = self.jobs.lock().map_err(|_| lock_error())?;
+        jobs.values()
+            .map(|job| {
+                job.status
+                    .lock()
+                    .map(|status| status.clone())
+                    .map_err(|_| lock_error())
+            })
+            .collect()
+    }
+
+    fn prune_finished(&self) {
+        let Ok(statuses) = self.snapshots() else {
+            return;
+        };
+        let mut finished = statuses
+            .into_iter()
+            .filter(|status| status.state.is_finished())
+            .collect::<Vec<_>>();
+        if finished.len() <= MAX_RETAINED_FINISHED_JOBS {
+            return;
+        }
+
+        finished.sort_by(|left, right| right.started_at.cmp(&left.started_at));
+        if let Ok(mut jobs) = self.jobs.lock() {
+            for status in finished.into_iter().skip(MAX_RETAINED_FINISHED_JOBS) {
+                jobs.remove(&status.job_id);
+            }
+        }
+    }
+
+    fn persist(&self) {
+        self.prune_finished();
+        let Ok(_guard) = self.persist_lock.lock() else {
+            return;
+        };
+        let Ok(mut statuses) = self.snapshots() else {
+            return;
+        };
+        statuses.sort_by(|left, right| left.started_at.cmp(&right.started_at));
+
+        if let Err(error) = write_persisted_jobs(&self.state_path, &statuses) {
+            tracing::warn!("Failed to persist job state: {}", error);
+        }
+    }
+}
+
+/// Worker-side handle for reporting progress on a tracked job.
+#[derive(Clone)]
+pub struct JobHandle {
+    job_id: String,
+    job: Arc<TrackedJob>,
+    manager: Arc<JobManagerInner>,
+}
+
+impl JobHandle {
+    pub fn job_id(&self) -> &str {
+        &self.job_id
+    }
+
+    pub fn is_cancel_requested(&self) -> bool {
+        self.job.cancel_requested.load(Ordering::Relaxed)
+    }
+
+    pub fn snapshot(&self) -> Result<JobStatus, DomainError> {
+        let status = self.job.status.lock().map_err(|_| lock_error())?;
+        Ok(status.clone())
+    }
+
+    pub fn mark_running(&self, stage: &str, message: &str) {
+        self.transition(|status| {
+            status.state = JobState::Running;
+            status.stage = stage.to_string();
+            status.message = message.to_string();
+        });
+    }
+
+    /// Reports progress. Emits an update event but only persists when the job leaves
+    /// the pending state, keeping disk writes off the hot path.
+    pub fn update_progress(&self, stage: &str, progress_percent: f32, message: &str) {
+        let mut started = false;
+        self.update(|status| {
+            if status.state == JobState::Pending {
+                status.state = JobState::Running;
+                started = true;
+            }
+            if status.state != JobState::Running {
+                return;
+            }
+            status.stage = stage.to_string();
+            status.progress_percent = progress_percent.clamp(0.0, 100.0);
+            status.message = message.to_string();
+        });
+        self.publish(started);
+    }
+
+    pub fn complete(&self, message: &str, result: Option<Value>) {
+        self.transition(|status| {
+            status.state = JobState::Completed;
+            status.stage = "completed".to_string();
+            status.progress_percent = 100.0;
+            status.message = message.to_string();
+            status.result = result;
+            status.error = None;
+            status.finished_at = Some(Utc::now().to_rfc3339());
+        });
+    }
+
+    pub fn fail(&self, error: &str) {
+        self.transition(|status| {
+            status.state = JobState::Failed;
+            status.stage = "failed".to_string();
+            status.message = "Job failed".to_string();
+            status.error = Some(error.to_string());
+            status.finished_at = Some(Utc::now().to_rfc3339());
+        });
+    }
+
+    pub fn mark_cancelled(&self) {
+        self.transition(|status| {
+            status.state = JobState::Cancelled;
+            status.stage = "cancelled".to_string();
+            status.message = "Job cancelled".to_string();
+            status.finished_at = Some(Utc::now().to_rfc3339());
+        });
+    }
+
+    /// Applies an arbitrary update, for workers that keep their own richer status and
+    /// mirror it here. Persists when the job state changes.
+    pub fn mirror(&self, update: impl FnOnce(&mut JobStatus)) {
+        let mut state_changed = false;
+        self.update(|status| {
+            let previous = status.state;
+            update(status);
+            state_changed = previous != status.state;
+        });
+        self.publish(state_changed);
+    }
+
+    fn transition(&self, update: impl FnOnce(&mut JobStatus)) {
+        self.update(update);
+        self.publish(true);
+    }
+
+    fn update(&self, update: impl FnOnce(&mut JobStatus)) {
+        if let Ok(mut status) = self.job.status.lock() {
+            update(&mut status);
+        }
+    }
+
+    fn publish(&self, persist: bool) {
+        if persist {
+            self.manager.persist();
+        }
+        if let Ok(snapshot) = self.snapshot() {
+            (self.manager.notifier)(&snapshot);
+        }
+    }
+}
+
+fn lock_error() -> DomainError {
+    DomainError::InternalError("Failed to lock job registry".to_string())
+}
+
+fn read_persisted_jobs(path: &Path) -> Vec<JobStatus> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+        Err(error) => {
+            tracing::warn!("Failed to read job state {}: {}", path.display(), error);
+            return Vec::new();
+        }
+    };
+
+    serde_json::from_slice(&bytes).unwrap_or_else(|error| {
+        tracing::warn!("Ignoring malformed job state {}: {}", path.display(), error);
+        Vec::new()
+    })
+}
+
+fn write_persisted_jobs(path: &Path, statuses: &[JobStatus]) -> Result<(), DomainError> {
+    let bytes = serde_json::to_vec_pretty(statuses).map_err(|error| {
+        DomainError::InternalError(format!("Failed to serialize job state: {}", error))
+    })?;
+    atomic_write_sync(path, &bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::json;
+
+    use super::{JobManager, JobState, JobStatus};
+
+    fn temp_state_path() -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("tauritavern-jobs-{}", uuid::Uuid::new_v4()))
+            .join("jobs.json")
+    }
+
+    #[test]
+    fn tracks_progress_cancellation_and_completion() {
+        let events = Arc::new(Mutex::new(Vec::<JobStatus>::new()));
+        let sink = events.clone();
+        let manager = JobManager::load(temp_state_path(), move |status| {
+            sink.lock().unwrap().push(status.clone());
+        });
+
+        let handle = manager.start("vectorization").expect("start");
+        handle.update_progress("embedding", 40.0, "Embedding chunks");
+        assert_eq!(
+            manager.get(handle.job_id()).expect("get").state,
+            JobState::Running
+        );
+
+        let cancelled = manager.cancel(handle.job_id()).expect("cancel");
+        assert!(cancelled.cancel_requested);
+        assert!(handle.is_cancel_requested());
+
+        handle.complete("Done", Some(json!({ "chunks": 3 })));
+        let status = manager.get(handle.job_id()).expect("get");
+        assert_eq!(status.state, JobState::Completed);
+        assert_eq!(status.progress_percent, 100.0);
+        assert!(manager.cancel(handle.job_id()).is_err());
+        assert!(events.lock().unwrap().len() >= 4);
+    }
+
+    #[test]
+    fn unfinished_jobs_are_marked_failed_after_restart() {
+        let path = temp_state_path();
+        let job_id = {
+            let manager = JobManager::load(path.clone(), |_| {});
+            let handle = manager.start("thumbnail_regeneration").expect("start");
+            handle.mark_running("scanning", "Scanning thumbnails");
+            handle.job_id().to_string()
+        };
+
+        let manager = JobManager::load(path.clone(), |_| {});
+        let status = manager.get(&job_id).expect("persisted job");
+        assert_eq!(status.state, JobState::Failed);
+        assert!(status.finished_at.is_some());
+        assert_eq!(manager.list().expect("list").len(), 1);
+
+        let _ = std::fs::remove_dir_all(path.parent().expect("parent"));
+    }
+}
@@ -0,0 +1,119 @@
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use serde::Serialize;
+
+/// Timing of one startup stage. Deferred stages run after `app-ready` was emitted.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupStage {
+    pub name: String,
+    pub started_at_ms: u64,
+    pub duration_ms: u64,
+    pub deferred: bool,
+}
+
+/// Timings of the current launch, measured from the start of initialization.
+/// `ready_at_ms` is unset until `app-ready` has been emitted.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupReport {
+    pub ready_at_ms: Option<u64>,
+    pub stages: Vec<StartupStage>,
+}
+
+/// Collects startup stage timings. Managed as Tauri state before initialization starts,
+/// so the report is available even when initialization fails.
+pub struct StartupTimings {
+    started_at: Instant,
+    report: Mutex<StartupReport>,
+}
+
+impl Default for StartupTimings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StartupTimings {
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            report: Mutex::new(StartupReport::default()),
+        }
+    }
+
+    /// Awaits `future` and records how long it took as a stage on the ready path.
+    pub async fn measure<T>(&self, name: &str, future: impl Future<Output = T>) -> T {
+        self.measure_stage(name, false, future).await
+    }
+
+    /// Like [`Self::measure`], for work that runs after `app-ready`.
+    pub async fn measure_deferred<T>(&self, name: &str, future: impl Future<Output = T>) -> T {
+        self.measure_stage(name, true, future).await
+    }
+
+    pub fn mark_ready(&self) {
+        let ready_at_ms = self.elapsed_ms(Instant::now());
+        self.report.lock().unwrap().ready_at_ms = Some(ready_at_ms);
+        tracing::info!(ready_at_ms, "Application ready");
+    }
+
+    pub fn report(&self) -> StartupReport {
+        self.report.lock().unwrap().clone()
+    }
+
+    async fn measure_stage<T>(
+        &self,
+        name: &str,
+        deferred: bool,
+        future: impl Future<Output = T>,
+    ) -> T {
+        let started = Instant::now();
+        let output = future.await;
+        let duration_ms = started.elapsed().as_millis() as u64;
+
+        tracing::debug!(
+            stage = name,
+            duration_ms,
+            deferred,
+            "Startup stage finished"
+        );
+        self.report.lock().unwrap().stages.push(StartupStage {
+            name: name.to_string(),
+            started_at_ms: self.elapsed_ms(started),
+            duration_ms,
+            deferred,
+        });
+        output
+    }
+
+    fn elapsed_ms(&self, instant: Instant) -> u64 {
+        instant.duration_since(self.started_at).as_millis() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StartupTimings;
+
+    #[tokio::test]
+    async fn records_stages_in_completion_order() {
+        let timings = StartupTimings::new();
+
+        let value = timings.measure("services", async { 7 }).await;
+        timings.mark_ready();
+        timings.measure_deferred("default_content", async {}).await;
+
+        let report = timings.report();
+        assert_eq!(value, 7);
+        assert!(report.ready_at_ms.is_some());
+        let stages: Vec<(&str, bool)> = report
+            .stages
+            .iter()
+            .map(|stage| (stage.name.as_str(), stage.deferred))
+            .collect();
+        assert_eq!(stages, vec![("services", false), ("default_content", true)]);
+    }
+}
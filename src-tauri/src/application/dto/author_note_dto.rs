@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+use crate::domain::models::author_note::AuthorNote;
+
+/// Author's Note with SillyTavern's numeric `position` and `role` codes. Omitted fields
+/// fall back to SillyTavern's defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AuthorNoteDto {
+    pub content: String,
+    pub depth: u32,
+    pub interval: u32,
+    pub position: u32,
+    pub role: u32,
+}
+
+impl Default for AuthorNoteDto {
+    fn default() -> Self {
+        AuthorNote::default().into()
+    }
+}
+
+impl From<AuthorNote> for AuthorNoteDto {
+    fn from(note: AuthorNote) -> Self {
+        Self {
+            content: note.content,
+            depth: note.depth,
+            interval: note.interval,
+            position: note.position,
+            role: note.role,
+        }
+    }
+}
+
+impl From<AuthorNoteDto> for AuthorNote {
+    fn from(dto: AuthorNoteDto) -> Self {
+        Self {
+            content: dto.content,
+            depth: dto.depth,
+            interval: dto.interval,
+            position: dto.position,
+            role: dto.role,
+        }
+    }
+}
+
+/// Where an Author's Note is stored. Chat notes live in the chat's `chat_metadata`,
+/// character and global notes in the user's `author-notes` directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase",
+    tag = "kind"
+)]
+pub enum AuthorNoteScopeDto {
+    Global,
+    Character { avatar: String },
+    Chat { chat: ChatMetadataTargetDto },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorNoteSourceDto {
+    Global,
+    Character,
+    Chat,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorNoteRequestDto {
+    pub scope: AuthorNoteScopeDto,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetAuthorNoteDto {
+    pub scope: AuthorNoteScopeDto,
+    pub note: AuthorNoteDto,
+}
+
+/// Inputs for picking the note a prompt builder should inject. `message_count` is the
+/// number of messages in the chat; without it the note is treated as due.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveAuthorNoteDto {
+    #[serde(default)]
+    pub chat: Option<ChatMetadataTargetDto>,
+    #[serde(default)]
+    pub avatar: Option<String>,
+    #[serde(default)]
+    pub message_count: Option<usize>,
+}
+
+/// The effective note and whether it should be injected into the next prompt. `source`
+/// is `None` when no scope has a note with content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedAuthorNoteDto {
+    pub source: Option<AuthorNoteSourceDto>,
+    pub note: AuthorNoteDto,
+    pub inject: bool,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// DTO for uploading an asset into a character gallery
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadCharacterAssetDto {
+    /// Avatar file name or stem of the character
+    pub character: String,
+    pub name: String,
+    pub data_base64: String,
+}
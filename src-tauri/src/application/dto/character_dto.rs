@@ -49,6 +49,160 @@ pub struct CreateCharacterWithAvatarResultDto {
     pub warnings: Vec<CharacterCreateWarningDto>,
 }
 
+/// Sort key for paginated character listing
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CharacterSortKey {
+    #[default]
+    Name,
+    DateAdded,
+    DateLastChat,
+    /// Seeded shuffle; `order` is ignored
+    Random,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Paginated character listing DTO
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListCharactersDto {
+    #[serde(default)]
+    pub offset: usize,
+    /// Page size; `None` returns everything after `offset`
+    #[serde(default)]
+    pub limit: Option<usize>,
+    #[serde(default)]
+    pub sort: CharacterSortKey,
+    #[serde(default)]
+    pub order: SortOrder,
+    /// Shuffle seed for `random` sorting; pass the seed returned with the first page to
+    /// page through the same order
+    #[serde(default)]
+    pub seed: Option<u32>,
+    /// Keep only characters carrying every one of these tags (case-insensitive)
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Drop characters carrying any of these tags (case-insensitive)
+    #[serde(default)]
+    pub exclude_tags: Vec<String>,
+    #[serde(default)]
+    pub favorites_only: bool,
+    /// Extra `CharacterDto` fields to load for each entry, e.g. `description`
+    #[serde(default)]
+    pub fields: Vec<String>,
+}
+
+/// Shallow character list entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterSummaryDto {
+    pub name: String,
+    pub avatar: String,
+    pub tags: Vec<String>,
+    pub fav: bool,
+    pub create_date: String,
+    pub date_added: i64,
+    pub date_last_chat: i64,
+    pub chat_size: u64,
+    /// Requested extra fields, keyed by `CharacterDto` field name
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub fields: serde_json::Map<String, Value>,
+}
+
+/// One page of the character list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterPageDto {
+    /// Number of characters matching the filters across all pages
+    pub total: usize,
+    pub offset: usize,
+    /// Shuffle seed used for `random` sorting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u32>,
+    pub characters: Vec<CharacterSummaryDto>,
+}
+
+/// On-demand character field loading DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetCharacterFieldsDto {
+    pub name: String,
+    pub fields: Vec<String>,
+}
+
+impl From<&CharacterDto> for CharacterSummaryDto {
+    fn from(character: &CharacterDto) -> Self {
+        Self {
+            name: character.name.clone(),
+            avatar: character.avatar.clone(),
+            tags: character.tags.clone(),
+            fav: character.fav,
+            create_date: character.create_date.clone(),
+            date_added: character.date_added,
+            date_last_chat: character.date_last_chat,
+            chat_size: character.chat_size,
+            fields: serde_json::Map::new(),
+        }
+    }
+}
+
+/// Duplicate character scan DTO
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FindDuplicateCharactersDto {
+    /// Also group cards with similar names and descriptions, not just identical cards
+    #[serde(default)]
+    pub fuzzy: bool,
+    /// Minimum description similarity (0-1) for fuzzy matches
+    #[serde(default)]
+    pub threshold: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateMatchKind {
+    /// Card data is identical apart from file-specific fields
+    Identical,
+    /// Names match and descriptions are above the similarity threshold
+    Similar,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateCharacterEntryDto {
+    pub avatar: String,
+    pub name: String,
+    pub file_size: u64,
+    pub chat_count: usize,
+    pub date_added: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateCharacterGroupDto {
+    pub kind: DuplicateMatchKind,
+    /// Lowest pairwise description similarity within the group, for similar matches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub similarity: Option<f64>,
+    pub characters: Vec<DuplicateCharacterEntryDto>,
+}
+
+/// Merge duplicate characters DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeCharactersDto {
+    /// Character (file stem) that receives the chats
+    pub keep: String,
+    /// Characters (file stems) whose chats are moved and whose cards are deleted
+    pub merge: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeCharactersResultDto {
+    pub kept: String,
+    pub moved_chats: Vec<String>,
+    pub deleted: Vec<String>,
+}
+
 fn format_timestamp_millis(timestamp_millis: i64) -> Option<String> {
     Utc.timestamp_millis_opt(timestamp_millis)
         .single()
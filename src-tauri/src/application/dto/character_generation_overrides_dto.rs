@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::character_generation_overrides::CharacterGenerationOverrides;
+
+/// Sampler overrides for one character. Omitted or `null` fields keep the preset value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CharacterGenerationOverridesDto {
+    pub temperature: Option<f64>,
+    pub top_p: Option<f64>,
+    pub max_tokens: Option<u32>,
+    pub model: Option<String>,
+}
+
+impl From<CharacterGenerationOverrides> for CharacterGenerationOverridesDto {
+    fn from(overrides: CharacterGenerationOverrides) -> Self {
+        Self {
+            temperature: overrides.temperature,
+            top_p: overrides.top_p,
+            max_tokens: overrides.max_tokens,
+            model: overrides.model,
+        }
+    }
+}
+
+impl From<CharacterGenerationOverridesDto> for CharacterGenerationOverrides {
+    fn from(dto: CharacterGenerationOverridesDto) -> Self {
+        Self {
+            temperature: dto.temperature,
+            top_p: dto.top_p,
+            max_tokens: dto.max_tokens,
+            model: dto.model,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterGenerationOverridesRequestDto {
+    pub avatar: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCharacterGenerationOverridesDto {
+    pub avatar: String,
+    pub overrides: CharacterGenerationOverridesDto,
+}
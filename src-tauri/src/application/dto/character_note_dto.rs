@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::character_note::CharacterNote;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterNoteDto {
+    pub avatar: String,
+    pub content: String,
+    pub updated_at: i64,
+}
+
+impl From<CharacterNote> for CharacterNoteDto {
+    fn from(note: CharacterNote) -> Self {
+        Self {
+            avatar: note.avatar,
+            content: note.content,
+            updated_at: note.updated_at,
+        }
+    }
+}
+
+/// A note matching a search, with a short excerpt around the first hit.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterNoteSearchResultDto {
+    pub avatar: String,
+    pub snippet: String,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterNoteRequestDto {
+    pub avatar: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveCharacterNoteDto {
+    pub avatar: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchCharacterNotesDto {
+    pub query: String,
+}
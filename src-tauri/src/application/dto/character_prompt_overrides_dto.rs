@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::character_prompt_overrides::CharacterPromptOverrides;
+
+/// Prompt overrides for one character. Omitted or `null` fields fall back to the card.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CharacterPromptOverridesDto {
+    pub system_prompt: Option<String>,
+    #[serde(alias = "jailbreak")]
+    pub post_history_instructions: Option<String>,
+}
+
+impl From<CharacterPromptOverrides> for CharacterPromptOverridesDto {
+    fn from(overrides: CharacterPromptOverrides) -> Self {
+        Self {
+            system_prompt: overrides.system_prompt,
+            post_history_instructions: overrides.post_history_instructions,
+        }
+    }
+}
+
+impl From<CharacterPromptOverridesDto> for CharacterPromptOverrides {
+    fn from(dto: CharacterPromptOverridesDto) -> Self {
+        Self {
+            system_prompt: dto.system_prompt,
+            post_history_instructions: dto.post_history_instructions,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterPromptOverridesRequestDto {
+    pub avatar: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCharacterPromptOverridesDto {
+    pub avatar: String,
+    pub overrides: CharacterPromptOverridesDto,
+}
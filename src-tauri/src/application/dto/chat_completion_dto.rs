@@ -27,6 +27,9 @@ pub struct ChatCompletionStatusRequestDto {
     pub secret_id: Option<String>,
     #[serde(default)]
     pub bypass_status_check: bool,
+    /// Skip the cached model list and query the provider again.
+    #[serde(default)]
+    pub force_refresh: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -40,3 +43,311 @@ impl ChatCompletionGenerateRequestDto {
         self.payload.get(key).and_then(Value::as_str)
     }
 }
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct OpenRouterGenerationCostDto {
+    pub id: String,
+    pub total_cost: f64,
+    pub model: Option<String>,
+    pub provider_name: Option<String>,
+    pub native_tokens_prompt: Option<u64>,
+    pub native_tokens_completion: Option<u64>,
+    pub native_tokens_reasoning: Option<u64>,
+    pub cache_discount: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LocalToolHandlerDto {
+    Builtin { builtin: String },
+    Extension { extension: String },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RegisterLocalToolDto {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub parameters: Value,
+    pub handler: LocalToolHandlerDto,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LocalToolDefinitionDto {
+    pub name: String,
+    pub description: String,
+    pub parameters: Value,
+    pub handler: LocalToolHandlerDto,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatCompletionToolRunRequestDto {
+    pub request: ChatCompletionGenerateRequestDto,
+    /// Restricts the run to these registered tools; all registered tools are offered when omitted.
+    #[serde(default)]
+    pub tool_names: Option<Vec<String>>,
+    #[serde(default)]
+    pub max_rounds: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatCompletionToolRunEventDto {
+    RoundStarted {
+        round: u32,
+    },
+    AssistantMessage {
+        round: u32,
+        message: Map<String, Value>,
+    },
+    /// Sent for every tool call before it runs, builtin or extension.
+    ToolCallStarted {
+        call_id: String,
+        name: String,
+        arguments: Value,
+    },
+    /// Asks the owning extension to run the call and answer with `submit_tool_result`.
+    ToolCallRequested {
+        call_id: String,
+        name: String,
+        arguments: Value,
+        extension: String,
+    },
+    ToolCallCompleted {
+        call_id: String,
+        name: String,
+        content: String,
+        is_error: bool,
+    },
+    /// The call ended without a result (cancelled or dropped); the run stops with it.
+    ToolCallFailed {
+        call_id: String,
+        name: String,
+        message: String,
+    },
+    Completed {
+        response: Value,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// One prompt fanned out to several sources/models for side-by-side comparison.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatCompletionMultiGenerateRequestDto {
+    pub request: ChatCompletionGenerateRequestDto,
+    pub targets: Vec<ChatCompletionMultiTargetDto>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatCompletionMultiTargetDto {
+    pub stream_id: String,
+    /// Payload fields (`chat_completion_source`, `model`, endpoint settings, ...) that
+    /// replace the shared request's values for this target.
+    #[serde(default)]
+    pub overrides: Map<String, Value>,
+}
+
+impl ChatCompletionMultiGenerateRequestDto {
+    pub fn target_request(
+        &self,
+        target: &ChatCompletionMultiTargetDto,
+    ) -> ChatCompletionGenerateRequestDto {
+        let mut payload = self.request.payload.clone();
+        payload.extend(
+            target
+                .overrides
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+        ChatCompletionGenerateRequestDto { payload }
+    }
+}
+
+/// Frontend annotations for the prompt itemizer, sent in the request's
+/// `_tauritavern_prompt_itemization` field. `items` are segments the frontend merged into
+/// larger messages (world info entries, author's note, ...), counted on their own.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptItemizationHintsDto {
+    /// Generation request id or stream id the report is stored under.
+    pub request_id: String,
+    #[serde(default)]
+    pub items: Vec<PromptItemHintDto>,
+    /// Chat history messages dropped to fit the context.
+    #[serde(default)]
+    pub trimmed_history_messages: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptItemHintDto {
+    pub kind: String,
+    #[serde(default)]
+    pub label: String,
+    pub text: String,
+}
+
+/// Upstream request a generation would send. Secrets and header values are omitted.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatCompletionPayloadPreviewDto {
+    pub source: String,
+    pub provider_format: String,
+    pub base_url: String,
+    pub endpoint_path: String,
+    /// Names of the extra headers that would be sent, sorted.
+    pub header_names: Vec<String>,
+    pub payload: Value,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptItemizationDto {
+    pub request_id: String,
+    pub model: String,
+    pub messages: Vec<PromptItemizedMessageDto>,
+    pub items: Vec<PromptItemizedSegmentDto>,
+    pub system_tokens: usize,
+    pub history_tokens: usize,
+    pub history_messages: usize,
+    pub trimmed_history_messages: usize,
+    pub total_tokens: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptItemizedMessageDto {
+    pub index: usize,
+    pub role: String,
+    pub tokens: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptItemizedSegmentDto {
+    pub kind: String,
+    pub label: String,
+    pub tokens: usize,
+}
+
+/// Backend history truncation, sent in the request's `_tauritavern_context_trimming`
+/// field. System messages, the last message and `pinned_messages` are never dropped.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextTrimmingOptionsDto {
+    /// Prompt budget in tokens.
+    pub max_tokens: usize,
+    #[serde(default)]
+    pub strategy: ContextTrimmingStrategy,
+    /// Indices into `messages` that must be kept.
+    #[serde(default)]
+    pub pinned_messages: Vec<usize>,
+    /// Tokenizer model to count with; the request's `model` when omitted.
+    #[serde(default)]
+    pub tokenizer: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextTrimmingStrategy {
+    #[default]
+    DropOldest,
+    /// Drops from the middle of the history outwards and, when a history summary hook
+    /// is registered, replaces the dropped span with its summary.
+    MiddleOut,
+}
+
+/// Point in a generation where an extension hook runs. Request hooks receive the
+/// frontend request payload, response hooks the provider response, stream chunk
+/// hooks each raw SSE data chunk, and history summary hooks `{ "messages": [...] }`
+/// with the messages context trimming dropped, answering `{ "summary": "..." }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatCompletionHookPhase {
+    Request,
+    Response,
+    StreamChunk,
+    HistorySummary,
+}
+
+impl ChatCompletionHookPhase {
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Request => "request",
+            Self::Response => "response",
+            Self::StreamChunk => "stream_chunk",
+            Self::HistorySummary => "history_summary",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterChatCompletionHookDto {
+    pub extension: String,
+    pub phase: ChatCompletionHookPhase,
+    /// Lower priorities run first.
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatCompletionHookDefinitionDto {
+    pub extension: String,
+    pub phase: ChatCompletionHookPhase,
+    pub priority: i32,
+    pub timeout_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatCompletionHookInvocationDto {
+    pub invocation_id: String,
+    pub extension: String,
+    pub phase: ChatCompletionHookPhase,
+    pub payload: Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::ChatCompletionMultiGenerateRequestDto;
+
+    #[test]
+    fn multi_target_request_overrides_shared_payload_fields() {
+        let dto: ChatCompletionMultiGenerateRequestDto = serde_json::from_value(json!({
+            "request": {
+                "chat_completion_source": "openai",
+                "model": "gpt-4o",
+                "messages": [{ "role": "user", "content": "Hi" }],
+                "stream": true,
+            },
+            "targets": [
+                { "streamId": "a" },
+                { "streamId": "b", "overrides": { "chat_completion_source": "claude", "model": "claude-sonnet-4-5" } },
+            ],
+        }))
+        .unwrap();
+
+        let first = dto.target_request(&dto.targets[0]);
+        assert_eq!(first.get_string("model"), Some("gpt-4o"));
+
+        let second = dto.target_request(&dto.targets[1]);
+        assert_eq!(second.get_string("chat_completion_source"), Some("claude"));
+        assert_eq!(second.get_string("model"), Some("claude-sonnet-4-5"));
+        assert_eq!(
+            second.payload.get("messages"),
+            dto.request.payload.get("messages")
+        );
+    }
+}
@@ -95,6 +95,8 @@ pub struct ChatSearchResultDto {
     pub chat_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub chat_metadata: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uncompressed_size: Option<u64>,
 }
 
 /// DTO for pinned character chat references in recent-chat queries.
@@ -257,6 +259,33 @@ pub struct ImportCharacterChatsDto {
     pub file_type: String,
 }
 
+/// DTO for detecting the format of a chat export file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectChatFormatDto {
+    pub file_path: String,
+}
+
+/// Detected chat export format. `file_type` is the value to pass to
+/// `import_character_chats`; both are `None` when the file is not recognized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatFormatDetectionDto {
+    pub format: Option<String>,
+    pub file_type: Option<String>,
+}
+
+impl From<Option<ChatImportFormat>> for ChatFormatDetectionDto {
+    fn from(format: Option<ChatImportFormat>) -> Self {
+        let file_type = format.as_ref().map(|format| match format {
+            ChatImportFormat::SillyTavern | ChatImportFormat::TavernAI => "jsonl".to_string(),
+            _ => "json".to_string(),
+        });
+        Self {
+            format: format.map(String::from),
+            file_type,
+        }
+    }
+}
+
 /// DTO for importing group chats from uploaded files
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportGroupChatDto {
@@ -383,6 +412,7 @@ impl From<ChatSearchResult> for ChatSearchResultDto {
             date: result.date,
             chat_id: result.chat_id,
             chat_metadata: result.chat_metadata,
+            uncompressed_size: result.uncompressed_size,
         }
     }
 }
@@ -413,11 +443,27 @@ impl From<String> for ChatImportFormat {
             "caitools" => ChatImportFormat::CAITools,
             "koboldlite" => ChatImportFormat::KoboldLite,
             "risuai" => ChatImportFormat::RisuAI,
+            "tavernai" => ChatImportFormat::TavernAI,
             _ => ChatImportFormat::SillyTavern,
         }
     }
 }
 
+impl From<ChatImportFormat> for String {
+    fn from(format: ChatImportFormat) -> Self {
+        match format {
+            ChatImportFormat::SillyTavern => "sillytavern",
+            ChatImportFormat::Ooba => "ooba",
+            ChatImportFormat::Agnai => "agnai",
+            ChatImportFormat::CAITools => "caitools",
+            ChatImportFormat::KoboldLite => "koboldlite",
+            ChatImportFormat::RisuAI => "risuai",
+            ChatImportFormat::TavernAI => "tavernai",
+        }
+        .to_string()
+    }
+}
+
 impl From<String> for ChatExportFormat {
     fn from(s: String) -> Self {
         match s.to_lowercase().as_str() {
@@ -426,3 +472,20 @@ impl From<String> for ChatExportFormat {
         }
     }
 }
+
+/// Character or group chat whose `chat_metadata` is read or updated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase",
+    tag = "kind"
+)]
+pub enum ChatMetadataTargetDto {
+    Character {
+        character_name: String,
+        file_name: String,
+    },
+    Group {
+        chat_id: String,
+    },
+}
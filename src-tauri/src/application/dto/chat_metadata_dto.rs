@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMetadataDto {
+    pub chat: ChatMetadataTargetDto,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateChatMetadataDto {
+    pub chat: ChatMetadataTargetDto,
+    /// Top-level `chat_metadata` fields to set; `null` removes the field.
+    pub fields: Map<String, Value>,
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+
+/// Summarizes the messages after the stored summary, up to `end` (exclusive). Without
+/// `end` the configured number of recent messages is left out.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SummarizeChatSegmentDto {
+    pub chat: ChatMetadataTargetDto,
+    #[serde(default)]
+    pub end: Option<usize>,
+}
+
+/// Rolling summary of a chat; it covers messages `0..covered_messages`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatSummaryDto {
+    pub text: String,
+    pub covered_messages: usize,
+}
+
+/// Value of the `_tauritavern_chat_summary` request field: the chat whose summary is
+/// injected into the prompt.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatSummaryInjectionDto {
+    pub chat: ChatMetadataTargetDto,
+}
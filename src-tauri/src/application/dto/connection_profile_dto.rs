@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::domain::models::settings::ConnectionProfile;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionProfileDto {
+    pub id: String,
+    pub display_name: String,
+    pub connection_ref: String,
+    pub default_model: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionProfileIdDto {
+    pub profile_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveConnectionProfileDto {
+    pub profile: ConnectionProfileDto,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListConnectionProfilesResultDto {
+    pub active_profile_id: Option<String>,
+    pub profiles: Vec<ConnectionProfileDto>,
+}
+
+/// `settings` holds the `oai_settings` fields the frontend assigns to switch the
+/// connection; the profile's secret is already active when this is returned.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivateConnectionProfileResultDto {
+    pub profile: ConnectionProfileDto,
+    pub settings: Map<String, Value>,
+}
+
+impl From<ConnectionProfile> for ConnectionProfileDto {
+    fn from(profile: ConnectionProfile) -> Self {
+        Self {
+            id: profile.id,
+            display_name: profile.display_name,
+            connection_ref: profile.connection_ref,
+            default_model: profile.default_model,
+        }
+    }
+}
+
+impl From<ConnectionProfileDto> for ConnectionProfile {
+    fn from(dto: ConnectionProfileDto) -> Self {
+        Self {
+            id: dto.id,
+            display_name: dto.display_name,
+            connection_ref: dto.connection_ref,
+            default_model: dto.default_model,
+        }
+    }
+}
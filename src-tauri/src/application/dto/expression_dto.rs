@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::repositories::expression_classifier_repository::ExpressionScore;
+
+/// Options for classifying the expression of a message
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ClassifyExpressionOptionsDto {
+    /// Hugging Face `owner/name` model id; defaults to the go_emotions model
+    pub model: Option<String>,
+    /// Custom labels accepted next to the default expressions
+    pub custom_labels: Vec<String>,
+    /// Label returned when no known label was scored
+    pub fallback: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpressionClassificationDto {
+    /// Best scoring known expression label, or the fallback
+    pub label: Option<String>,
+    /// Raw model scores, highest first
+    pub scores: Vec<ExpressionScore>,
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::file_attachment::FileAttachmentScope;
+
+/// DTO for uploading a Data Bank document. Exactly one of `data_base64` and
+/// `file_path` must be set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadFileAttachmentDto {
+    pub scope: FileAttachmentScope,
+    pub name: String,
+    #[serde(default)]
+    pub data_base64: Option<String>,
+    #[serde(default)]
+    pub file_path: Option<String>,
+}
+
+/// Extracted plain text of a Data Bank document, ready for chunking and vector insertion.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileAttachmentTextDto {
+    pub url: String,
+    pub text: String,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// DTO for importing dropped files
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestFilesDto {
+    /// Absolute paths of the dropped files
+    pub paths: Vec<String>,
+
+    /// Character that dropped chat files are imported into. Chat files are rejected
+    /// without one.
+    #[serde(default)]
+    pub character_name: Option<String>,
+
+    #[serde(default)]
+    pub character_display_name: Option<String>,
+
+    #[serde(default)]
+    pub user_name: Option<String>,
+}
+
+/// What a dropped file was recognized as
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IngestedFileKind {
+    Character,
+    Chat,
+    WorldInfo,
+    Preset,
+    Theme,
+}
+
+/// Outcome of importing one dropped file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestFileResultDto {
+    pub path: String,
+
+    /// `None` when the file was not recognized
+    pub kind: Option<IngestedFileKind>,
+
+    /// Names of the imported items: the character avatar, chat files, world name,
+    /// preset names or theme name
+    pub imported: Vec<String>,
+
+    /// Why the file was not imported
+    pub error: Option<String>,
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::application::dto::chat_completion_dto::ChatCompletionGenerateRequestDto;
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+
+/// A chat message by 0-based index, optionally narrowed to one of its swipes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMessageRefDto {
+    pub chat: ChatMetadataTargetDto,
+    pub message_index: usize,
+    #[serde(default)]
+    pub swipe_id: Option<usize>,
+}
+
+/// Settings a message was generated with, kept in its `extra.tauritavern_generation`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationSnapshotDto {
+    pub source: String,
+    pub model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i64>,
+    /// Sampler fields of the request, by their request key.
+    #[serde(default)]
+    pub sampler: Map<String, Value>,
+}
+
+/// Generates `request` again with the source, model, seed and sampler recorded on
+/// `message`; the prompt itself comes from `request`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegenerateWithSameSettingsDto {
+    pub message: ChatMessageRefDto,
+    pub request: ChatCompletionGenerateRequestDto,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Kind of item a global search hit points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GlobalSearchKind {
+    Character,
+    CharacterNote,
+    World,
+    WorldInfoEntry,
+    Preset,
+    Chat,
+}
+
+/// One global search hit. `id` is what the frontend opens: the avatar filename for
+/// characters and notes, the world name, the entry uid, the preset name or the chat
+/// file name. `parent` names the owning item where one exists: the world of an entry,
+/// the API id of a preset or the character of a chat.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlobalSearchHitDto {
+    pub kind: GlobalSearchKind,
+    pub id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+    /// Relevance between 0 and 1; hits are returned in descending order.
+    pub score: f32,
+}
@@ -53,7 +53,7 @@ pub struct GroupDto {
     pub chats: Vec<String>,
 
     /// Delay in seconds for auto mode
-    #[serde(default)]
+    #[serde(default = "default_auto_mode_delay")]
     pub auto_mode_delay: i32,
 
     /// Prefix for joining messages in APPEND mode
@@ -155,6 +155,10 @@ pub struct CreateGroupDto {
     #[serde(default, rename = "hideMutedSprites", alias = "hide_muted_sprites")]
     pub hide_muted_sprites: Option<bool>,
 
+    /// Metadata for past chats
+    #[serde(default)]
+    pub past_metadata: HashMap<String, HashMap<String, serde_json::Value>>,
+
     /// Preserve unknown group JSON fields (payload-first).
     #[serde(default, flatten)]
     pub additional: HashMap<String, Value>,
@@ -166,6 +170,10 @@ pub struct CreateGroupDto {
 /// fidelity, we accept the full `GroupDto` as the update DTO.
 pub type UpdateGroupDto = GroupDto;
 
+fn default_auto_mode_delay() -> i32 {
+    5
+}
+
 /// DTO for deleting a group
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeleteGroupDto {
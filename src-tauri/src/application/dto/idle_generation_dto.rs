@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+use crate::application::dto::chat_completion_dto::ChatCompletionGenerateRequestDto;
+
+/// Arms (or re-arms) the idle timer of a chat. `request` is the fully built prompt to
+/// send when the timer fires; the character's idle prompt is appended to it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArmIdleGenerationDto {
+    pub chat_id: String,
+    pub avatar: String,
+    pub request: ChatCompletionGenerateRequestDto,
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+
+/// Names and chat used to resolve `{{user}}`, `{{char}}`, `{{group}}` and the variable
+/// macros. Without `chat`, chat-local variables read as empty and writes are dropped.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MacroContextDto {
+    #[serde(default)]
+    pub user: String,
+    #[serde(default)]
+    pub char: String,
+    #[serde(default)]
+    pub group: Option<String>,
+    #[serde(default)]
+    pub chat: Option<ChatMetadataTargetDto>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubstituteMacrosDto {
+    pub text: String,
+    #[serde(default)]
+    pub context: MacroContextDto,
+}
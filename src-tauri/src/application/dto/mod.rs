@@ -1,22 +1,42 @@
 // Data Transfer Objects
 pub mod agent_dto;
+pub mod author_note_dto;
 pub mod background_dto;
 pub mod bootstrap_dto;
+pub mod character_asset_dto;
 pub mod character_dto;
+pub mod character_generation_overrides_dto;
+pub mod character_note_dto;
+pub mod character_prompt_overrides_dto;
 pub mod chat_completion_dto;
 pub mod chat_dto;
+pub mod chat_metadata_dto;
+pub mod chat_summary_dto;
+pub mod connection_profile_dto;
+pub mod expression_dto;
+pub mod file_attachment_dto;
+pub mod file_ingest_dto;
+pub mod generation_record_dto;
+pub mod global_search_dto;
 pub mod group_dto;
+pub mod idle_generation_dto;
 pub mod image_metadata_dto;
 pub mod llm_connection_dto;
+pub mod macro_dto;
+pub mod model_capability_dto;
 pub mod native_regex_dto;
 pub mod preset_dto;
 pub mod provider_metadata_dto;
+pub mod recent_items_dto;
 pub mod secret_dto;
 pub mod settings_dto;
+pub mod settings_profile_dto;
+pub mod sound_dto;
 pub mod stable_diffusion_dto;
 pub mod theme_dto;
 pub mod tokenization_dto;
 pub mod tts_dto;
 pub mod user_directory_dto;
 pub mod user_dto;
+pub mod variable_dto;
 pub mod world_info_dto;
@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct ModelCapabilitiesDto {
+    pub source: String,
+    pub model: String,
+    pub max_context: Option<u64>,
+    pub supports_vision: Option<bool>,
+    pub supports_tools: Option<bool>,
+    /// Request body parameters the upstream accepts; `None` when the provider does not
+    /// publish this, in which case nothing is stripped.
+    pub supported_parameters: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelParameterWarningDto {
+    pub source: String,
+    pub model: String,
+    pub removed_parameters: Vec<String>,
+    pub message: String,
+}
@@ -77,6 +77,46 @@ pub struct DeleteOpenAIPresetResponseDto {
     pub error: Option<bool>,
 }
 
+/// Reference to a stored preset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresetReferenceDto {
+    /// Name of the preset
+    pub name: String,
+    /// API ID (e.g., "openai", "kobold", "novel")
+    #[serde(rename = "apiId")]
+    pub api_id: String,
+}
+
+/// DTO for exporting presets as a bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportPresetBundleDto {
+    /// Presets to include in the bundle
+    pub presets: Vec<PresetReferenceDto>,
+}
+
+/// DTO for importing a preset bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportPresetBundleDto {
+    /// Bundle JSON as produced by `export_preset_bundle`
+    pub bundle: Value,
+    /// Replace existing presets instead of importing under a new name
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+/// A preset written by a bundle import
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportedPresetDto {
+    /// Name the preset was saved under
+    pub name: String,
+    /// Name the preset had inside the bundle
+    #[serde(rename = "originalName")]
+    pub original_name: String,
+    /// API ID of the preset
+    #[serde(rename = "apiId")]
+    pub api_id: String,
+}
+
 impl TryFrom<SavePresetDto> for Preset {
     type Error = String;
 
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+use crate::domain::models::recent_items::RecentItemKind;
+
+/// Identifies a home screen item. `name` is an optional display label kept with it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentItemTargetDto {
+    pub kind: RecentItemKind,
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+}
@@ -66,3 +66,64 @@ pub struct RenameSecretDto {
     pub id: String,
     pub label: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSecretsDto {
+    pub include_values: bool,
+    /// Encrypts the values; required when `include_values` is set.
+    #[serde(default)]
+    pub passphrase: Option<String>,
+}
+
+/// Portable secrets bundle. A redacted bundle lists keys and labels only; a full bundle
+/// carries the secrets encrypted with a passphrase.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretBundleDto {
+    pub format: String,
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub redacted: HashMap<String, Vec<RedactedSecretDto>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypted: Option<EncryptedSecretsDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactedSecretDto {
+    pub label: String,
+    pub active: bool,
+}
+
+/// Base64 fields of a passphrase-encrypted `secrets.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedSecretsDto {
+    pub version: u32,
+    pub kdf: String,
+    pub cipher: String,
+    pub iterations: u32,
+    pub salt: String,
+    pub nonce: String,
+    pub ciphertext: String,
+    pub tag: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSecretsDto {
+    pub bundle: SecretBundleDto,
+    #[serde(default)]
+    pub passphrase: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretImportReportDto {
+    pub imported: usize,
+    /// Entries whose value this device already holds under the same key
+    pub duplicates: usize,
+    /// Keys listed without values by a redacted bundle; they have to be entered again
+    pub redacted_keys: Vec<String>,
+}
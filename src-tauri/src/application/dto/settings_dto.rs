@@ -1,7 +1,12 @@
+use crate::domain::models::global_hotkeys::GlobalHotkeySettings;
+use crate::domain::models::memory_cache::MemoryCacheSettings;
 use crate::domain::models::settings::{
-    AgentRunRetentionSettings, AgentSettings, ChatHistoryMode, ClaudeModelSettings,
-    DevLoggingSettings, DynamicThemeSettings, ModelSettings, PromptCacheTtl, RequestProxySettings,
-    SettingsSnapshot, StartupUpdatePopupSettings, TauriTavernSettings, TauriTavernUpdateSettings,
+    AgentRunRetentionSettings, AgentSettings, ChatCompletionQueueSettings,
+    ChatCompletionTimeoutSettings, ChatHistoryMode, ChatSummarySettings, ClaudeModelSettings,
+    ConnectionMonitorSettings, DevLoggingSettings, DynamicThemeSettings,
+    GenerationNotificationSettings, HttpClientTuningSettings, IdleGenerationSettings,
+    ModelSettings, PromptCacheTtl, RequestProxySettings, SettingsSnapshot,
+    StartupUpdatePopupSettings, StopBiasSettings, TauriTavernSettings, TauriTavernUpdateSettings,
     UserSettings,
 };
 use serde::{Deserialize, Serialize};
@@ -15,7 +20,9 @@ pub struct TauriTavernSettingsDto {
     pub embedded_runtime_profile: String,
     pub chat_history_mode: ChatHistoryMode,
     pub close_to_tray_on_close: bool,
+    pub minimize_to_tray: bool,
     pub request_proxy: RequestProxySettingsDto,
+    pub http_client: HttpClientTuningSettings,
     pub allow_keys_exposure: bool,
     pub avatar_persona_original_images_enabled: bool,
     pub native_regex_backend_enabled: bool,
@@ -23,6 +30,15 @@ pub struct TauriTavernSettingsDto {
     pub dynamic_theme: DynamicThemeSettingsDto,
     pub models: ModelSettingsDto,
     pub agent: AgentSettingsDto,
+    pub connection_monitor: ConnectionMonitorSettingsDto,
+    pub chat_completion_timeouts: ChatCompletionTimeoutSettings,
+    pub chat_completion_queue: ChatCompletionQueueSettings,
+    pub chat_summary: ChatSummarySettings,
+    pub idle_generation: IdleGenerationSettings,
+    pub stop_bias: StopBiasSettings,
+    pub memory_cache: MemoryCacheSettings,
+    pub generation_notifications: GenerationNotificationSettings,
+    pub global_hotkeys: GlobalHotkeySettings,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,7 +59,10 @@ pub struct UpdateTauriTavernSettingsDto {
     pub embedded_runtime_profile: Option<String>,
     pub chat_history_mode: Option<ChatHistoryMode>,
     pub close_to_tray_on_close: Option<bool>,
+    pub minimize_to_tray: Option<bool>,
     pub request_proxy: Option<RequestProxySettingsDto>,
+    /// Replaces every HTTP client tuning option.
+    pub http_client: Option<HttpClientTuningSettings>,
     pub allow_keys_exposure: Option<bool>,
     pub avatar_persona_original_images_enabled: Option<bool>,
     pub native_regex_backend_enabled: Option<bool>,
@@ -51,6 +70,21 @@ pub struct UpdateTauriTavernSettingsDto {
     pub dynamic_theme: Option<UpdateDynamicThemeSettingsDto>,
     pub models: Option<UpdateModelSettingsDto>,
     pub agent: Option<UpdateAgentSettingsDto>,
+    pub connection_monitor: Option<UpdateConnectionMonitorSettingsDto>,
+    /// Replaces the defaults and every per-source override.
+    pub chat_completion_timeouts: Option<ChatCompletionTimeoutSettings>,
+    /// Replaces every per-source queue limit.
+    pub chat_completion_queue: Option<ChatCompletionQueueSettings>,
+    /// Replaces every chat summary option.
+    pub chat_summary: Option<ChatSummarySettings>,
+    /// Replaces every per-character idle prompt.
+    pub idle_generation: Option<IdleGenerationSettings>,
+    /// Replaces the global, per-character and per-model stop and bias rules.
+    pub stop_bias: Option<StopBiasSettings>,
+    /// Replaces both cache configurations.
+    pub memory_cache: Option<MemoryCacheSettings>,
+    /// Replaces the notification switches and muted characters.
+    pub generation_notifications: Option<GenerationNotificationSettings>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,16 +111,32 @@ pub struct UpdateAgentRunRetentionSettingsDto {
     pub keep_full_recent_runs: Option<u32>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionMonitorSettingsDto {
+    pub enabled: bool,
+    pub interval_secs: u64,
+    pub slow_threshold_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateConnectionMonitorSettingsDto {
+    pub enabled: Option<bool>,
+    pub interval_secs: Option<u64>,
+    pub slow_threshold_ms: Option<u64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DevLoggingSettingsDto {
     pub frontend_console_capture: bool,
     pub llm_api_keep: u32,
+    pub performance_tracing: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateDevLoggingSettingsDto {
     pub frontend_console_capture: Option<bool>,
     pub llm_api_keep: Option<u32>,
+    pub performance_tracing: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -124,6 +174,7 @@ pub struct ClaudeModelSettingsDto {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelSettingsDto {
     pub claude: ClaudeModelSettingsDto,
+    pub model_list_cache_ttl_secs: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -134,6 +185,7 @@ pub struct UpdateClaudeModelSettingsDto {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateModelSettingsDto {
     pub claude: Option<UpdateClaudeModelSettingsDto>,
+    pub model_list_cache_ttl_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -149,6 +201,34 @@ pub struct SettingsSnapshotDto {
     pub size: u64,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingsDiffKind {
+    /// Present in both with different values
+    Changed,
+    /// Present only in the snapshot
+    SnapshotOnly,
+    /// Present only in the current settings
+    CurrentOnly,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SettingsDiffEntryDto {
+    /// JSON Pointer (RFC 6901) to the differing key
+    pub path: String,
+    pub kind: SettingsDiffKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsSnapshotDiffDto {
+    pub name: String,
+    pub changes: Vec<SettingsDiffEntryDto>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SillyTavernSettingsResponseDto {
     pub settings: String,
@@ -208,7 +288,9 @@ impl From<TauriTavernSettings> for TauriTavernSettingsDto {
             embedded_runtime_profile: settings.embedded_runtime_profile,
             chat_history_mode: settings.chat_history_mode,
             close_to_tray_on_close: settings.close_to_tray_on_close,
+            minimize_to_tray: settings.minimize_to_tray,
             request_proxy: RequestProxySettingsDto::from(settings.request_proxy),
+            http_client: settings.http_client,
             allow_keys_exposure: settings.allow_keys_exposure,
             avatar_persona_original_images_enabled: settings.avatar_persona_original_images_enabled,
             native_regex_backend_enabled: settings.native_regex_backend_enabled,
@@ -216,6 +298,25 @@ impl From<TauriTavernSettings> for TauriTavernSettingsDto {
             dynamic_theme: DynamicThemeSettingsDto::from(settings.dynamic_theme),
             models: ModelSettingsDto::from(settings.models),
             agent: AgentSettingsDto::from(settings.agent),
+            connection_monitor: ConnectionMonitorSettingsDto::from(settings.connection_monitor),
+            chat_completion_timeouts: settings.chat_completion_timeouts,
+            chat_completion_queue: settings.chat_completion_queue,
+            chat_summary: settings.chat_summary,
+            idle_generation: settings.idle_generation,
+            stop_bias: settings.stop_bias,
+            memory_cache: settings.memory_cache,
+            generation_notifications: settings.generation_notifications,
+            global_hotkeys: settings.global_hotkeys,
+        }
+    }
+}
+
+impl From<ConnectionMonitorSettings> for ConnectionMonitorSettingsDto {
+    fn from(settings: ConnectionMonitorSettings) -> Self {
+        Self {
+            enabled: settings.enabled,
+            interval_secs: settings.interval_secs,
+            slow_threshold_ms: settings.slow_threshold_ms,
         }
     }
 }
@@ -243,6 +344,7 @@ impl From<DevLoggingSettings> for DevLoggingSettingsDto {
         Self {
             frontend_console_capture: settings.frontend_console_capture,
             llm_api_keep: settings.effective_llm_api_keep(),
+            performance_tracing: settings.performance_tracing,
         }
     }
 }
@@ -292,6 +394,7 @@ impl From<ModelSettings> for ModelSettingsDto {
     fn from(settings: ModelSettings) -> Self {
         Self {
             claude: ClaudeModelSettingsDto::from(settings.claude),
+            model_list_cache_ttl_secs: settings.model_list_cache_ttl_secs,
         }
     }
 }
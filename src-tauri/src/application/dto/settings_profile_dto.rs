@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::settings_dto::{RequestProxySettingsDto, UserSettingsDto};
+use crate::domain::models::settings::SettingsProfile;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsProfileDto {
+    pub id: String,
+    pub display_name: String,
+    pub request_proxy: RequestProxySettingsDto,
+    pub paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveSettingsProfileDto {
+    pub id: String,
+    pub display_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsProfileIdDto {
+    pub profile_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListSettingsProfilesResultDto {
+    pub active_profile_id: Option<String>,
+    pub profiles: Vec<SettingsProfileDto>,
+}
+
+/// `settings` is the frontend `settings.json` after the switch, for the frontend to
+/// reload from.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivateSettingsProfileResultDto {
+    pub profile: SettingsProfileDto,
+    pub settings: UserSettingsDto,
+}
+
+impl From<SettingsProfile> for SettingsProfileDto {
+    fn from(profile: SettingsProfile) -> Self {
+        Self {
+            id: profile.id,
+            display_name: profile.display_name,
+            request_proxy: profile.request_proxy.into(),
+            paths: profile.paths,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// DTO for uploading a notification or character message sound
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadSoundDto {
+    /// Avatar file name or stem of the character; omit for notification sounds
+    #[serde(default)]
+    pub character: Option<String>,
+    pub name: String,
+    pub data_base64: String,
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::domain::models::theme::Theme;
+
 /// DTO for saving a theme
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SaveThemeDto {
@@ -18,3 +20,66 @@ pub struct DeleteThemeDto {
     /// The name of the theme to delete
     pub name: String,
 }
+
+/// Metadata shown in the theme picker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeSummaryDto {
+    /// The name of the theme
+    pub name: String,
+
+    /// The theme author, when the theme declares one
+    pub author: Option<String>,
+
+    /// Distinct colors taken from the theme's color settings
+    pub swatches: Vec<String>,
+
+    /// Whether the theme ships custom CSS
+    pub has_custom_css: bool,
+}
+
+/// Color settings sampled for theme preview swatches
+const SWATCH_KEYS: &[&str] = &[
+    "main_text_color",
+    "italics_text_color",
+    "underline_text_color",
+    "quote_text_color",
+    "blur_tint_color",
+    "chat_tint_color",
+    "user_mes_blur_tint_color",
+    "bot_mes_blur_tint_color",
+    "shadow_color",
+    "border_color",
+];
+
+impl From<&Theme> for ThemeSummaryDto {
+    fn from(theme: &Theme) -> Self {
+        let mut swatches: Vec<String> = Vec::new();
+        for key in SWATCH_KEYS {
+            let Some(color) = theme.data.get(*key).and_then(Value::as_str) else {
+                continue;
+            };
+            let color = color.trim();
+            if !color.is_empty() && !swatches.iter().any(|existing| existing == color) {
+                swatches.push(color.to_string());
+            }
+        }
+
+        Self {
+            name: theme.name.clone(),
+            author: theme
+                .data
+                .get("author")
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|author| !author.is_empty())
+                .map(str::to_string),
+            swatches,
+            has_custom_css: theme
+                .data
+                .get("custom_css")
+                .and_then(Value::as_str)
+                .is_some_and(|css| !css.trim().is_empty()),
+        }
+    }
+}
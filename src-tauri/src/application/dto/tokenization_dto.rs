@@ -81,3 +81,18 @@ pub struct OpenAiLogitBiasRequestDto {
 }
 
 pub type OpenAiLogitBiasResponseDto = HashMap<String, f32>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CharacterFieldTokensDto {
+    pub field: String,
+    pub tokens: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CharacterTokenBudgetDto {
+    pub tokenizer: String,
+    /// Per-field counts in card order; empty fields are reported with 0 tokens.
+    pub fields: Vec<CharacterFieldTokensDto>,
+    pub lorebook_entries: usize,
+    pub total_tokens: usize,
+}
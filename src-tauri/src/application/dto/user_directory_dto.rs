@@ -31,6 +31,7 @@ pub struct UserDirectoryDto {
     pub assets: String,
     pub comfy_workflows: String,
     pub files: String,
+    pub sounds: String,
     pub vectors: String,
     pub backups: String,
     pub sysprompt: String,
@@ -68,6 +69,7 @@ impl From<UserDirectory> for UserDirectoryDto {
             assets: directory.assets.to_string_lossy().to_string(),
             comfy_workflows: directory.comfy_workflows.to_string_lossy().to_string(),
             files: directory.files.to_string_lossy().to_string(),
+            sounds: directory.sounds.to_string_lossy().to_string(),
             vectors: directory.vectors.to_string_lossy().to_string(),
             backups: directory.backups.to_string_lossy().to_string(),
             sysprompt: directory.sysprompt.to_string_lossy().to_string(),
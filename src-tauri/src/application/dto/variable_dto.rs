@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatVariablesDto {
+    pub chat: ChatMetadataTargetDto,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatVariableDto {
+    pub chat: ChatMetadataTargetDto,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetChatVariableDto {
+    pub chat: ChatMetadataTargetDto,
+    pub name: String,
+    pub value: Value,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetGlobalVariableDto {
+    pub name: String,
+    pub value: Value,
+}
@@ -0,0 +1,330 @@
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
+
+use crate::application::dto::author_note_dto::{
+    AuthorNoteDto, AuthorNoteScopeDto, AuthorNoteSourceDto, ResolveAuthorNoteDto,
+    ResolvedAuthorNoteDto,
+};
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+use crate::application::errors::ApplicationError;
+use crate::application::services::chat_file_validation::validate_avatar_file_name;
+use crate::domain::models::author_note::{
+    AuthorNote, DEFAULT_AUTHOR_NOTE_DEPTH, DEFAULT_AUTHOR_NOTE_INTERVAL,
+    DEFAULT_AUTHOR_NOTE_POSITION, DEFAULT_AUTHOR_NOTE_ROLE,
+};
+use crate::domain::repositories::author_note_repository::AuthorNoteRepository;
+use crate::domain::repositories::chat_repository::ChatRepository;
+use crate::domain::repositories::group_chat_repository::GroupChatRepository;
+
+const NOTE_PROMPT_FIELD: &str = "note_prompt";
+const NOTE_DEPTH_FIELD: &str = "note_depth";
+const NOTE_INTERVAL_FIELD: &str = "note_interval";
+const NOTE_POSITION_FIELD: &str = "note_position";
+const NOTE_ROLE_FIELD: &str = "note_role";
+
+/// Highest `extension_prompt_types` / `extension_prompt_roles` code SillyTavern accepts.
+const MAX_NOTE_POSITION: u32 = 2;
+const MAX_NOTE_ROLE: u32 = 2;
+
+/// Author's Notes at global, character and chat scope. Chat notes use the `note_*`
+/// fields SillyTavern already keeps in `chat_metadata`.
+pub struct AuthorNoteService {
+    author_note_repository: Arc<dyn AuthorNoteRepository>,
+    chat_repository: Arc<dyn ChatRepository>,
+    group_chat_repository: Arc<dyn GroupChatRepository>,
+}
+
+impl AuthorNoteService {
+    pub fn new(
+        author_note_repository: Arc<dyn AuthorNoteRepository>,
+        chat_repository: Arc<dyn ChatRepository>,
+        group_chat_repository: Arc<dyn GroupChatRepository>,
+    ) -> Self {
+        Self {
+            author_note_repository,
+            chat_repository,
+            group_chat_repository,
+        }
+    }
+
+    /// Returns `None` when the scope has no stored note.
+    pub async fn get_author_note(
+        &self,
+        scope: &AuthorNoteScopeDto,
+    ) -> Result<Option<AuthorNoteDto>, ApplicationError> {
+        Ok(self.load_note(scope).await?.map(AuthorNoteDto::from))
+    }
+
+    pub async fn set_author_note(
+        &self,
+        scope: &AuthorNoteScopeDto,
+        note: AuthorNoteDto,
+    ) -> Result<AuthorNoteDto, ApplicationError> {
+        let note = validate_note(note.into())?;
+
+        match scope {
+            AuthorNoteScopeDto::Global => {
+                self.author_note_repository.save_global_note(&note).await?
+            }
+            AuthorNoteScopeDto::Character { avatar } => {
+                let avatar = validate_avatar_file_name(avatar)?;
+                self.author_note_repository
+                    .save_character_note(&avatar, &note)
+                    .await?
+            }
+            AuthorNoteScopeDto::Chat { chat } => {
+                self.update_chat_metadata(chat, chat_note_fields(Some(&note)))
+                    .await?
+            }
+        }
+
+        Ok(note.into())
+    }
+
+    /// Removes the scope's note; returns whether one was stored.
+    pub async fn clear_author_note(
+        &self,
+        scope: &AuthorNoteScopeDto,
+    ) -> Result<bool, ApplicationError> {
+        let cleared = match scope {
+            AuthorNoteScopeDto::Global => self.author_note_repository.delete_global_note().await?,
+            AuthorNoteScopeDto::Character { avatar } => {
+                let avatar = validate_avatar_file_name(avatar)?;
+                self.author_note_repository
+                    .delete_character_note(&avatar)
+                    .await?
+            }
+            AuthorNoteScopeDto::Chat { chat } => {
+                let existed = self.load_chat_note(chat).await?.is_some();
+                if existed {
+                    self.update_chat_metadata(chat, chat_note_fields(None))
+                        .await?;
+                }
+                existed
+            }
+        };
+
+        Ok(cleared)
+    }
+
+    /// Picks the most specific note with content: chat, then character, then the global
+    /// default. Its depth, interval, position and role come from the same scope.
+    pub async fn resolve_author_note(
+        &self,
+        dto: &ResolveAuthorNoteDto,
+    ) -> Result<ResolvedAuthorNoteDto, ApplicationError> {
+        let mut candidates = Vec::with_capacity(3);
+        if let Some(chat) = &dto.chat {
+            candidates.push((
+                AuthorNoteSourceDto::Chat,
+                AuthorNoteScopeDto::Chat { chat: chat.clone() },
+            ));
+        }
+        if let Some(avatar) = &dto.avatar {
+            candidates.push((
+                AuthorNoteSourceDto::Character,
+                AuthorNoteScopeDto::Character {
+                    avatar: avatar.clone(),
+                },
+            ));
+        }
+        candidates.push((AuthorNoteSourceDto::Global, AuthorNoteScopeDto::Global));
+
+        for (source, scope) in candidates {
+            let Some(note) = self.load_note(&scope).await? else {
+                continue;
+            };
+            if !note.has_content() {
+                continue;
+            }
+
+            let inject = match dto.message_count {
+                Some(message_count) => note.is_due(message_count),
+                None => note.interval > 0,
+            };
+            return Ok(ResolvedAuthorNoteDto {
+                source: Some(source),
+                note: note.into(),
+                inject,
+            });
+        }
+
+        Ok(ResolvedAuthorNoteDto {
+            source: None,
+            note: AuthorNoteDto::default(),
+            inject: false,
+        })
+    }
+
+    async fn load_note(
+        &self,
+        scope: &AuthorNoteScopeDto,
+    ) -> Result<Option<AuthorNote>, ApplicationError> {
+        let note = match scope {
+            AuthorNoteScopeDto::Global => self.author_note_repository.load_global_note().await?,
+            AuthorNoteScopeDto::Character { avatar } => {
+                let avatar = validate_avatar_file_name(avatar)?;
+                self.author_note_repository
+                    .load_character_note(&avatar)
+                    .await?
+            }
+            AuthorNoteScopeDto::Chat { chat } => self.load_chat_note(chat).await?,
+        };
+        Ok(note)
+    }
+
+    async fn load_chat_note(
+        &self,
+        chat: &ChatMetadataTargetDto,
+    ) -> Result<Option<AuthorNote>, ApplicationError> {
+        let metadata = match chat {
+            ChatMetadataTargetDto::Character {
+                character_name,
+                file_name,
+            } => {
+                self.chat_repository
+                    .get_character_chat_metadata(character_name, file_name)
+                    .await?
+            }
+            ChatMetadataTargetDto::Group { chat_id } => {
+                self.group_chat_repository
+                    .get_group_chat_metadata(chat_id)
+                    .await?
+            }
+        };
+        Ok(chat_note_from_metadata(&metadata))
+    }
+
+    async fn update_chat_metadata(
+        &self,
+        chat: &ChatMetadataTargetDto,
+        fields: Map<String, Value>,
+    ) -> Result<(), ApplicationError> {
+        match chat {
+            ChatMetadataTargetDto::Character {
+                character_name,
+                file_name,
+            } => {
+                self.chat_repository
+                    .update_character_chat_metadata(character_name, file_name, fields)
+                    .await?
+            }
+            ChatMetadataTargetDto::Group { chat_id } => {
+                self.group_chat_repository
+                    .update_group_chat_metadata(chat_id, fields)
+                    .await?
+            }
+        }
+        Ok(())
+    }
+}
+
+fn validate_note(note: AuthorNote) -> Result<AuthorNote, ApplicationError> {
+    if note.position > MAX_NOTE_POSITION {
+        return Err(ApplicationError::ValidationError(format!(
+            "author_note.invalid_position: position must be between 0 and {MAX_NOTE_POSITION}"
+        )));
+    }
+    if note.role > MAX_NOTE_ROLE {
+        return Err(ApplicationError::ValidationError(format!(
+            "author_note.invalid_role: role must be between 0 and {MAX_NOTE_ROLE}"
+        )));
+    }
+    Ok(note)
+}
+
+/// A chat has a note once SillyTavern (or this service) wrote `note_prompt`; the other
+/// fields fall back to SillyTavern's defaults.
+fn chat_note_from_metadata(metadata: &Value) -> Option<AuthorNote> {
+    let content = metadata.get(NOTE_PROMPT_FIELD)?.as_str()?;
+    let number = |field: &str, default: u32| {
+        metadata
+            .get(field)
+            .and_then(Value::as_u64)
+            .and_then(|value| u32::try_from(value).ok())
+            .unwrap_or(default)
+    };
+
+    Some(AuthorNote {
+        content: content.to_string(),
+        depth: number(NOTE_DEPTH_FIELD, DEFAULT_AUTHOR_NOTE_DEPTH),
+        interval: number(NOTE_INTERVAL_FIELD, DEFAULT_AUTHOR_NOTE_INTERVAL),
+        position: number(NOTE_POSITION_FIELD, DEFAULT_AUTHOR_NOTE_POSITION),
+        role: number(NOTE_ROLE_FIELD, DEFAULT_AUTHOR_NOTE_ROLE),
+    })
+}
+
+/// `chat_metadata` fields for the note; `None` produces `null`s that remove them.
+fn chat_note_fields(note: Option<&AuthorNote>) -> Map<String, Value> {
+    let value = |select: fn(&AuthorNote) -> Value| note.map(select).unwrap_or(Value::Null);
+
+    let mut fields = Map::new();
+    fields.insert(
+        NOTE_PROMPT_FIELD.to_string(),
+        value(|note| Value::from(note.content.clone())),
+    );
+    fields.insert(
+        NOTE_DEPTH_FIELD.to_string(),
+        value(|note| Value::from(note.depth)),
+    );
+    fields.insert(
+        NOTE_INTERVAL_FIELD.to_string(),
+        value(|note| Value::from(note.interval)),
+    );
+    fields.insert(
+        NOTE_POSITION_FIELD.to_string(),
+        value(|note| Value::from(note.position)),
+    );
+    fields.insert(
+        NOTE_ROLE_FIELD.to_string(),
+        value(|note| Value::from(note.role)),
+    );
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{chat_note_fields, chat_note_from_metadata, validate_note};
+    use crate::domain::models::author_note::AuthorNote;
+
+    #[test]
+    fn chat_note_roundtrips_through_metadata_fields() {
+        let note = AuthorNote {
+            content: "Mention the storm.".to_string(),
+            depth: 2,
+            interval: 3,
+            position: 1,
+            role: 1,
+        };
+
+        let metadata = Value::Object(chat_note_fields(Some(&note)));
+        assert_eq!(chat_note_from_metadata(&metadata), Some(note));
+
+        let cleared = chat_note_fields(None);
+        assert_eq!(cleared.len(), 5);
+        assert!(cleared.values().all(Value::is_null));
+    }
+
+    #[test]
+    fn chat_note_requires_prompt_and_defaults_missing_fields() {
+        assert_eq!(chat_note_from_metadata(&json!({ "note_depth": 2 })), None);
+
+        let note =
+            chat_note_from_metadata(&json!({ "note_prompt": "", "note_depth": 6 })).expect("note");
+        assert_eq!(note.depth, 6);
+        assert_eq!(note.interval, 1);
+        assert!(!note.has_content());
+    }
+
+    #[test]
+    fn rejects_unknown_role_codes() {
+        let note = AuthorNote {
+            role: 3,
+            ..AuthorNote::default()
+        };
+        assert!(validate_note(note).is_err());
+    }
+}
@@ -0,0 +1,346 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::Utc;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{Mutex, Notify};
+use tokio::time::{Duration, sleep};
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::backup_schedule::{
+    BACKUP_SCHEDULE_COLD_START_DELAY_SECS, BACKUP_SCHEDULE_RETRY_DELAY_SECS, BackupScheduleConfig,
+    BackupScheduleFrequency, BackupScheduleResult, BackupScheduleStatus, BackupScheduleTrigger,
+};
+use crate::infrastructure::backup_schedule_store::{
+    BackupScheduleStore, list_scheduled_backups, prune_scheduled_backups,
+    scheduled_backup_file_name, validate_config,
+};
+use crate::infrastructure::persistence::data_archive::run_export_data_archive;
+
+pub const BACKUP_SCHEDULE_STATUS_EVENT: &str = "backup_schedule:status";
+pub const BACKUP_SCHEDULE_FINISHED_EVENT: &str = "backup_schedule:finished";
+
+/// Writes full data archives to a user-chosen directory on a daily or weekly schedule and
+/// keeps only the newest `keep_last` of them. The schedule is anchored to the newest
+/// archive already in the directory, so restarts do not trigger extra backups.
+pub struct BackupScheduleService {
+    app_handle: AppHandle,
+    data_root: PathBuf,
+    store: BackupScheduleStore,
+    status: Mutex<BackupScheduleStatus>,
+    run_lock: Mutex<()>,
+    notify: Notify,
+    started: AtomicBool,
+}
+
+impl BackupScheduleService {
+    pub fn new(app_handle: AppHandle, data_root: PathBuf, default_user_dir: PathBuf) -> Self {
+        Self {
+            app_handle,
+            data_root,
+            store: BackupScheduleStore::new(default_user_dir),
+            status: Mutex::new(BackupScheduleStatus::default()),
+            run_lock: Mutex::new(()),
+            notify: Notify::new(),
+            started: AtomicBool::new(false),
+        }
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        if self.started.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let service = self.clone();
+        tauri::async_runtime::spawn(async move {
+            service.scheduler_loop().await;
+        });
+    }
+
+    pub async fn get_config(&self) -> Result<BackupScheduleConfig, DomainError> {
+        self.store.load_or_create_config().await
+    }
+
+    pub async fn update_config(
+        &self,
+        config: BackupScheduleConfig,
+    ) -> Result<BackupScheduleConfig, DomainError> {
+        validate_config(&config, &self.data_root)?;
+        self.store.save_config(&config).await?;
+        self.notify.notify_waiters();
+        Ok(config)
+    }
+
+    pub async fn get_status(&self) -> BackupScheduleStatus {
+        self.status.lock().await.clone()
+    }
+
+    /// Runs a backup immediately with the saved config, whether or not the schedule is
+    /// enabled.
+    pub async fn run_now(&self) -> Result<BackupScheduleResult, DomainError> {
+        let config = self.store.load_or_create_config().await?;
+        let directory = validate_config(&config, &self.data_root)?.ok_or_else(|| {
+            DomainError::InvalidData("Backup directory is not configured".to_string())
+        })?;
+
+        let result = self
+            .run_backup(BackupScheduleTrigger::Manual, &config, directory)
+            .await?;
+        // The newest archive moved, so let the scheduler recompute its next run.
+        self.notify.notify_waiters();
+        match &result.error {
+            Some(error) => Err(DomainError::InternalError(error.clone())),
+            None => Ok(result),
+        }
+    }
+
+    async fn scheduler_loop(self: Arc<Self>) {
+        let mut earliest_at_ms = now_ms() + BACKUP_SCHEDULE_COLD_START_DELAY_SECS * 1000;
+
+        loop {
+            let config = match self.store.load_or_create_config().await {
+                Ok(config) => config,
+                Err(error) => {
+                    self.record_error(error.to_string()).await;
+                    sleep(Duration::from_secs(60)).await;
+                    continue;
+                }
+            };
+
+            let directory = match validate_config(&config, &self.data_root) {
+                Ok(Some(directory)) if config.enabled => directory,
+                Ok(_) => {
+                    self.set_next_run(None).await;
+                    self.notify.notified().await;
+                    continue;
+                }
+                Err(error) => {
+                    self.record_error(error.to_string()).await;
+                    self.set_next_run(None).await;
+                    self.notify.notified().await;
+                    continue;
+                }
+            };
+
+            let latest_backup_at_ms = match latest_backup_at_ms(&directory) {
+                Ok(latest_backup_at_ms) => latest_backup_at_ms,
+                Err(error) => {
+                    self.record_error(error.to_string()).await;
+                    None
+                }
+            };
+            let next_run_at_ms =
+                next_run_at_ms(latest_backup_at_ms, config.frequency, earliest_at_ms);
+            self.set_next_run(Some(next_run_at_ms)).await;
+
+            let wait = sleep(Duration::from_millis(
+                next_run_at_ms.saturating_sub(now_ms()),
+            ));
+            tokio::pin!(wait);
+
+            tokio::select! {
+                _ = &mut wait => {}
+                _ = self.notify.notified() => continue,
+            }
+
+            if let Err(error) = self
+                .run_backup(BackupScheduleTrigger::Scheduled, &config, directory)
+                .await
+            {
+                tracing::warn!("Scheduled backup skipped: {}", error);
+            }
+            earliest_at_ms = now_ms() + BACKUP_SCHEDULE_RETRY_DELAY_SECS * 1000;
+        }
+    }
+
+    /// Runs one backup and emits `backup_schedule:finished`. Only fails when another
+    /// backup is already running; export errors are reported in the result.
+    async fn run_backup(
+        &self,
+        trigger: BackupScheduleTrigger,
+        config: &BackupScheduleConfig,
+        directory: PathBuf,
+    ) -> Result<BackupScheduleResult, DomainError> {
+        let Ok(_run_guard) = self.run_lock.try_lock() else {
+            return Err(DomainError::InvalidData(
+                "A backup is already running".to_string(),
+            ));
+        };
+
+        let started_at_ms = now_ms();
+        self.update_status(|status| {
+            status.running = true;
+            status.next_run_at_ms = None;
+            status.last_attempt_at_ms = Some(started_at_ms);
+            status.last_error = None;
+        })
+        .await;
+
+        let data_root = self.data_root.clone();
+        let keep_last = config.keep_last;
+        let outcome = tauri::async_runtime::spawn_blocking(move || {
+            write_backup(&data_root, &directory, keep_last)
+        })
+        .await
+        .map_err(|error| DomainError::InternalError(format!("Backup task join error: {}", error)))
+        .and_then(|outcome| outcome);
+
+        let finished_at_ms = now_ms();
+        let result = match outcome {
+            Ok((archive_path, pruned_count)) => {
+                let archive_path = archive_path.to_string_lossy().to_string();
+                self.update_status(|status| {
+                    status.running = false;
+                    status.last_success_at_ms = Some(finished_at_ms);
+                    status.last_archive_path = Some(archive_path.clone());
+                })
+                .await;
+                BackupScheduleResult {
+                    trigger,
+                    success: true,
+                    finished_at_ms,
+                    archive_path: Some(archive_path),
+                    pruned_count,
+                    error: None,
+                }
+            }
+            Err(error) => {
+                let message = error.to_string();
+                tracing::warn!("Backup failed: {}", message);
+                self.record_error(message.clone()).await;
+                BackupScheduleResult {
+                    trigger,
+                    success: false,
+                    finished_at_ms,
+                    archive_path: None,
+                    pruned_count: 0,
+                    error: Some(message),
+                }
+            }
+        };
+
+        if let Err(error) = self
+            .app_handle
+            .emit(BACKUP_SCHEDULE_FINISHED_EVENT, result.clone())
+        {
+            tracing::warn!("Failed to emit backup result: {}", error);
+        }
+        Ok(result)
+    }
+
+    async fn set_next_run(&self, next_run_at_ms: Option<u64>) {
+        self.update_status(|status| {
+            status.next_run_at_ms = next_run_at_ms;
+        })
+        .await;
+    }
+
+    async fn record_error(&self, message: String) {
+        self.update_status(|status| {
+            status.running = false;
+            status.last_error = Some(message);
+        })
+        .await;
+    }
+
+    async fn update_status(&self, update: impl FnOnce(&mut BackupScheduleStatus)) {
+        let snapshot = {
+            let mut status = self.status.lock().await;
+            update(&mut status);
+            status.clone()
+        };
+        if let Err(error) = self.app_handle.emit(BACKUP_SCHEDULE_STATUS_EVENT, snapshot) {
+            tracing::warn!("Failed to emit backup schedule status: {}", error);
+        }
+    }
+}
+
+/// Exports into a hidden partial file first so an interrupted export is never mistaken
+/// for a backup, then prunes old archives.
+fn write_backup(
+    data_root: &Path,
+    directory: &Path,
+    keep_last: u16,
+) -> Result<(PathBuf, usize), DomainError> {
+    let file_name = scheduled_backup_file_name(Utc::now());
+    let archive_path = directory.join(&file_name);
+    let partial_path = directory.join(format!(".{}.partial", file_name));
+
+    let mut report_progress = |_stage: &str, _progress_percent: f32, _message: &str| {};
+    let is_cancelled = || false;
+    let exported = run_export_data_archive(
+        data_root,
+        &partial_path,
+        &mut report_progress,
+        &is_cancelled,
+    )
+    .and_then(|_| {
+        fs::rename(&partial_path, &archive_path).map_err(|error| {
+            DomainError::InternalError(format!("Failed to finalize backup archive: {}", error))
+        })
+    });
+    if let Err(error) = exported {
+        let _ = fs::remove_file(&partial_path);
+        return Err(error);
+    }
+
+    let pruned_count = prune_scheduled_backups(directory, keep_last)?;
+    Ok((archive_path, pruned_count))
+}
+
+fn latest_backup_at_ms(directory: &Path) -> Result<Option<u64>, DomainError> {
+    Ok(list_scheduled_backups(directory)?
+        .first()
+        .map(|(timestamp, _)| timestamp.timestamp_millis().max(0) as u64))
+}
+
+fn next_run_at_ms(
+    latest_backup_at_ms: Option<u64>,
+    frequency: BackupScheduleFrequency,
+    earliest_at_ms: u64,
+) -> u64 {
+    latest_backup_at_ms
+        .map(|latest| latest + frequency.interval_ms())
+        .unwrap_or(0)
+        .max(earliest_at_ms)
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::next_run_at_ms;
+    use crate::domain::models::backup_schedule::BackupScheduleFrequency;
+
+    const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+    #[test]
+    fn next_run_follows_latest_backup_but_never_before_earliest() {
+        let latest = 10 * DAY_MS;
+
+        assert_eq!(
+            next_run_at_ms(Some(latest), BackupScheduleFrequency::Daily, 0),
+            11 * DAY_MS
+        );
+        assert_eq!(
+            next_run_at_ms(Some(latest), BackupScheduleFrequency::Weekly, 0),
+            17 * DAY_MS
+        );
+        assert_eq!(
+            next_run_at_ms(Some(latest), BackupScheduleFrequency::Daily, 12 * DAY_MS),
+            12 * DAY_MS
+        );
+        assert_eq!(
+            next_run_at_ms(None, BackupScheduleFrequency::Weekly, 42),
+            42
+        );
+    }
+}
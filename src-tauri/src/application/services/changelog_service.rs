@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use crate::application::errors::ApplicationError;
+use crate::application::services::update_service::compare_versions;
+use crate::domain::models::changelog::{ChangelogSince, MigrationRecord, ReleaseNotes};
+use crate::domain::repositories::changelog_repository::ChangelogRepository;
+
+/// Combines the bundled release notes with the migrations ledger so the frontend can show
+/// what changed since the version the user ran last.
+pub struct ChangelogService {
+    repository: Arc<dyn ChangelogRepository>,
+}
+
+impl ChangelogService {
+    pub fn new(repository: Arc<dyn ChangelogRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Release notes and migrations of every version after `since_version`, up to the
+    /// running one.
+    pub async fn get_changelog_since(
+        &self,
+        since_version: &str,
+    ) -> Result<ChangelogSince, ApplicationError> {
+        let since_version = since_version.trim();
+        if since_version.is_empty() || !since_version.starts_with(|ch: char| ch.is_ascii_digit()) {
+            return Err(ApplicationError::ValidationError(format!(
+                "changelog.version_invalid: `{since_version}` is not a version number"
+            )));
+        }
+
+        let current_version = env!("CARGO_PKG_VERSION");
+        let releases = self.repository.bundled_release_notes().await?;
+        let migrations = self.repository.list_migrations().await?;
+
+        Ok(ChangelogSince {
+            since_version: since_version.to_string(),
+            current_version: current_version.to_string(),
+            releases: releases_between(releases, since_version, current_version),
+            migrations: migrations_after(migrations, since_version),
+        })
+    }
+
+    pub async fn record_migration(&self, record: MigrationRecord) -> Result<(), ApplicationError> {
+        Ok(self.repository.record_migration(record).await?)
+    }
+}
+
+fn releases_between(
+    mut releases: Vec<ReleaseNotes>,
+    since_version: &str,
+    current_version: &str,
+) -> Vec<ReleaseNotes> {
+    releases.retain(|release| {
+        compare_versions(&release.version, since_version) == Ordering::Greater
+            && compare_versions(&release.version, current_version) != Ordering::Greater
+    });
+    releases.sort_by(|a, b| compare_versions(&b.version, &a.version));
+    releases
+}
+
+fn migrations_after(
+    mut migrations: Vec<MigrationRecord>,
+    since_version: &str,
+) -> Vec<MigrationRecord> {
+    migrations.retain(|migration| {
+        compare_versions(&migration.app_version, since_version) == Ordering::Greater
+    });
+    migrations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{migrations_after, releases_between};
+    use crate::domain::models::changelog::{MigrationRecord, ReleaseNotes};
+
+    fn release(version: &str) -> ReleaseNotes {
+        ReleaseNotes {
+            version: version.to_string(),
+            date: None,
+            notes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn changelog_covers_versions_after_since_up_to_current() {
+        let releases = releases_between(
+            vec![
+                release("2.0.0"),
+                release("2.2.0"),
+                release("2.1.1"),
+                release("2.1.0"),
+            ],
+            "2.0.0",
+            "2.1.1",
+        );
+        let versions: Vec<_> = releases.iter().map(|r| r.version.as_str()).collect();
+        assert_eq!(versions, ["2.1.1", "2.1.0"]);
+
+        let mut old = MigrationRecord::now("old", "", Vec::new());
+        old.app_version = "1.9.0".to_string();
+        let new = MigrationRecord::now("new", "", Vec::new());
+        let migrations = migrations_after(vec![old, new], "2.0.0");
+        assert_eq!(migrations.len(), 1);
+        assert_eq!(migrations[0].id, "new");
+    }
+}
@@ -0,0 +1,59 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use std::sync::Arc;
+
+use crate::application::dto::character_asset_dto::UploadCharacterAssetDto;
+use crate::domain::errors::DomainError;
+use crate::domain::models::character_asset::CharacterAsset;
+use crate::domain::repositories::character_asset_repository::CharacterAssetRepository;
+use crate::infrastructure::logging::logger;
+
+/// Service for character gallery images
+pub struct CharacterAssetService {
+    repository: Arc<dyn CharacterAssetRepository>,
+}
+
+impl CharacterAssetService {
+    /// Create a new CharacterAssetService instance
+    pub fn new(repository: Arc<dyn CharacterAssetRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// List the gallery of a character
+    pub async fn list_assets(&self, character: &str) -> Result<Vec<CharacterAsset>, DomainError> {
+        self.repository.list_assets(character).await
+    }
+
+    /// Upload a base64 encoded asset into a character gallery
+    pub async fn upload_asset(
+        &self,
+        dto: UploadCharacterAssetDto,
+    ) -> Result<CharacterAsset, DomainError> {
+        logger::debug(&format!(
+            "CharacterAssetService: Uploading asset '{}' for {}",
+            dto.name, dto.character
+        ));
+
+        let bytes = BASE64_STANDARD
+            .decode(dto.data_base64.trim())
+            .map_err(|error| DomainError::InvalidData(format!("Invalid asset data: {}", error)))?;
+        if bytes.is_empty() {
+            return Err(DomainError::InvalidData(
+                "Asset data cannot be empty".to_string(),
+            ));
+        }
+
+        self.repository
+            .save_asset(&dto.character, &dto.name, &bytes)
+            .await
+    }
+
+    /// Delete an asset from a character gallery
+    pub async fn delete_asset(&self, character: &str, file_name: &str) -> Result<(), DomainError> {
+        logger::debug(&format!(
+            "CharacterAssetService: Deleting asset '{}' for {}",
+            file_name, character
+        ));
+        self.repository.delete_asset(character, file_name).await
+    }
+}
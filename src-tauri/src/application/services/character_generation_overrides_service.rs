@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use crate::application::dto::character_generation_overrides_dto::CharacterGenerationOverridesDto;
+use crate::application::errors::ApplicationError;
+use crate::application::services::chat_file_validation::validate_avatar_file_name;
+use crate::domain::models::character_generation_overrides::CharacterGenerationOverrides;
+use crate::domain::repositories::character_generation_overrides_repository::CharacterGenerationOverridesRepository;
+
+const MAX_TEMPERATURE: f64 = 2.0;
+
+/// Validates and stores the sampler settings a user pins to one character. Only the
+/// fields that are set take effect: when a chat completion request is tagged with the
+/// character's avatar, they replace the preset's temperature, top-p, max tokens or model
+/// for that request alone, leaving the preset itself untouched.
+pub struct CharacterGenerationOverridesService {
+    repository: Arc<dyn CharacterGenerationOverridesRepository>,
+}
+
+impl CharacterGenerationOverridesService {
+    pub fn new(repository: Arc<dyn CharacterGenerationOverridesRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Returns `None` when the character has no overrides.
+    pub async fn get_overrides(
+        &self,
+        avatar: &str,
+    ) -> Result<Option<CharacterGenerationOverridesDto>, ApplicationError> {
+        let avatar = validate_avatar_file_name(avatar)?;
+        Ok(self
+            .repository
+            .load_overrides(&avatar)
+            .await?
+            .map(CharacterGenerationOverridesDto::from))
+    }
+
+    /// Replaces the character's overrides. Setting no fields removes the stored file.
+    pub async fn set_overrides(
+        &self,
+        avatar: &str,
+        overrides: CharacterGenerationOverridesDto,
+    ) -> Result<CharacterGenerationOverridesDto, ApplicationError> {
+        let avatar = validate_avatar_file_name(avatar)?;
+        let overrides = validate_overrides(overrides.into())?;
+
+        if overrides.is_empty() {
+            self.repository.delete_overrides(&avatar).await?;
+        } else {
+            self.repository.save_overrides(&avatar, &overrides).await?;
+        }
+
+        Ok(overrides.into())
+    }
+
+    /// Removes the character's overrides; returns whether any were stored.
+    pub async fn clear_overrides(&self, avatar: &str) -> Result<bool, ApplicationError> {
+        let avatar = validate_avatar_file_name(avatar)?;
+        Ok(self.repository.delete_overrides(&avatar).await?)
+    }
+}
+
+/// Blank models count as unset.
+fn validate_overrides(
+    mut overrides: CharacterGenerationOverrides,
+) -> Result<CharacterGenerationOverrides, ApplicationError> {
+    if overrides
+        .temperature
+        .is_some_and(|temperature| !(0.0..=MAX_TEMPERATURE).contains(&temperature))
+    {
+        return Err(ApplicationError::ValidationError(format!(
+            "character_generation_overrides.invalid_temperature: temperature must be between 0 and {MAX_TEMPERATURE}"
+        )));
+    }
+    if overrides
+        .top_p
+        .is_some_and(|top_p| !(0.0..=1.0).contains(&top_p))
+    {
+        return Err(ApplicationError::ValidationError(
+            "character_generation_overrides.invalid_top_p: topP must be between 0 and 1"
+                .to_string(),
+        ));
+    }
+    if overrides.max_tokens == Some(0) {
+        return Err(ApplicationError::ValidationError(
+            "character_generation_overrides.invalid_max_tokens: maxTokens must be positive"
+                .to_string(),
+        ));
+    }
+
+    overrides.model = overrides
+        .model
+        .map(|model| model.trim().to_string())
+        .filter(|model| !model.is_empty());
+    Ok(overrides)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_overrides;
+    use crate::domain::models::character_generation_overrides::CharacterGenerationOverrides;
+
+    #[test]
+    fn rejects_out_of_range_samplers_and_drops_blank_model() {
+        let overrides = validate_overrides(CharacterGenerationOverrides {
+            temperature: Some(0.7),
+            model: Some("  ".to_string()),
+            ..Default::default()
+        })
+        .expect("valid");
+        assert_eq!(overrides.model, None);
+        assert!(!overrides.is_empty());
+
+        for invalid in [
+            CharacterGenerationOverrides {
+                temperature: Some(2.5),
+                ..Default::default()
+            },
+            CharacterGenerationOverrides {
+                top_p: Some(-0.1),
+                ..Default::default()
+            },
+            CharacterGenerationOverrides {
+                max_tokens: Some(0),
+                ..Default::default()
+            },
+        ] {
+            assert!(validate_overrides(invalid).is_err());
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use crate::application::dto::character_note_dto::{CharacterNoteDto, CharacterNoteSearchResultDto};
+use crate::application::errors::ApplicationError;
+use crate::application::services::chat_file_validation::validate_avatar_file_name;
+use crate::domain::models::character_note::MAX_CHARACTER_NOTE_BYTES;
+use crate::domain::repositories::character_note_repository::CharacterNoteRepository;
+
+/// Characters of context kept on each side of the first hit in a search snippet.
+const SNIPPET_CONTEXT_CHARS: usize = 60;
+
+/// Private notes a user keeps about a character. They are stored beside the user's
+/// data rather than in the card's `creator_notes`, so exporting or sharing the card
+/// never carries them along.
+pub struct CharacterNoteService {
+    repository: Arc<dyn CharacterNoteRepository>,
+}
+
+impl CharacterNoteService {
+    pub fn new(repository: Arc<dyn CharacterNoteRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Returns `None` when the character has no note.
+    pub async fn get_note(
+        &self,
+        avatar: &str,
+    ) -> Result<Option<CharacterNoteDto>, ApplicationError> {
+        let avatar = validate_avatar_file_name(avatar)?;
+        Ok(self
+            .repository
+            .load_note(&avatar)
+            .await?
+            .map(CharacterNoteDto::from))
+    }
+
+    /// Replaces the character's note. Saving blank content removes the stored note
+    /// and returns `None`.
+    pub async fn save_note(
+        &self,
+        avatar: &str,
+        content: &str,
+    ) -> Result<Option<CharacterNoteDto>, ApplicationError> {
+        let avatar = validate_avatar_file_name(avatar)?;
+        if content.len() > MAX_CHARACTER_NOTE_BYTES {
+            return Err(ApplicationError::ValidationError(format!(
+                "Character note must be <= {} bytes",
+                MAX_CHARACTER_NOTE_BYTES
+            )));
+        }
+
+        if content.trim().is_empty() {
+            self.repository.delete_note(&avatar).await?;
+            return Ok(None);
+        }
+
+        Ok(Some(
+            self.repository.save_note(&avatar, content).await?.into(),
+        ))
+    }
+
+    /// Finds notes containing every whitespace-separated term of `query`,
+    /// ignoring case. Results are ordered by avatar filename.
+    pub async fn search_notes(
+        &self,
+        query: &str,
+    ) -> Result<Vec<CharacterNoteSearchResultDto>, ApplicationError> {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let results = self
+            .repository
+            .list_notes()
+            .await?
+            .into_iter()
+            .filter_map(|note| {
+                let haystack = note.content.to_lowercase();
+                if !terms.iter().all(|term| haystack.contains(term.as_str())) {
+                    return None;
+                }
+                Some(CharacterNoteSearchResultDto {
+                    snippet: snippet_around(&note.content, &terms[0]),
+                    avatar: note.avatar,
+                    updated_at: note.updated_at,
+                })
+            })
+            .collect();
+
+        Ok(results)
+    }
+}
+
+/// Excerpt of `content` centred on the first case-insensitive occurrence of `term`.
+pub(crate) fn snippet_around(content: &str, term: &str) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let lowered: Vec<char> = chars
+        .iter()
+        .map(|ch| ch.to_lowercase().next().unwrap_or(*ch))
+        .collect();
+    let needle: Vec<char> = term.chars().collect();
+    let hit = lowered
+        .windows(needle.len().max(1))
+        .position(|window| window == needle.as_slice())
+        .unwrap_or(0);
+
+    let start = hit.saturating_sub(SNIPPET_CONTEXT_CHARS);
+    let end = (hit + needle.len() + SNIPPET_CONTEXT_CHARS).min(chars.len());
+    let mut snippet: String = chars[start..end].iter().collect();
+    snippet = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+    if start > 0 {
+        snippet.insert(0, '…');
+    }
+    if end < chars.len() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::snippet_around;
+
+    #[test]
+    fn snippet_is_centred_on_the_first_hit() {
+        let content = format!("{}Needs a NEW greeting{}", "a".repeat(100), "b".repeat(100));
+        let snippet = snippet_around(&content, "new");
+
+        assert!(snippet.starts_with('…'));
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.contains("NEW greeting"));
+        assert_eq!(snippet_around("short note", "note"), "short note");
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::application::dto::character_prompt_overrides_dto::CharacterPromptOverridesDto;
+use crate::application::errors::ApplicationError;
+use crate::application::services::chat_file_validation::validate_avatar_file_name;
+use crate::domain::models::character_prompt_overrides::CharacterPromptOverrides;
+use crate::domain::repositories::character_prompt_overrides_repository::CharacterPromptOverridesRepository;
+
+/// Per-character system prompt and post-history instruction overrides. They are stored
+/// beside the user's data rather than in the card, so exporting or sharing the card
+/// never carries them along.
+pub struct CharacterPromptOverridesService {
+    repository: Arc<dyn CharacterPromptOverridesRepository>,
+}
+
+impl CharacterPromptOverridesService {
+    pub fn new(repository: Arc<dyn CharacterPromptOverridesRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Returns `None` when the character has no overrides.
+    pub async fn get_overrides(
+        &self,
+        avatar: &str,
+    ) -> Result<Option<CharacterPromptOverridesDto>, ApplicationError> {
+        let avatar = validate_avatar_file_name(avatar)?;
+        Ok(self
+            .repository
+            .load_overrides(&avatar)
+            .await?
+            .map(CharacterPromptOverridesDto::from))
+    }
+
+    /// Replaces the character's overrides. Setting no fields removes the stored file.
+    pub async fn set_overrides(
+        &self,
+        avatar: &str,
+        overrides: CharacterPromptOverridesDto,
+    ) -> Result<CharacterPromptOverridesDto, ApplicationError> {
+        let avatar = validate_avatar_file_name(avatar)?;
+        let overrides = CharacterPromptOverrides::from(overrides);
+
+        if overrides.is_empty() {
+            self.repository.delete_overrides(&avatar).await?;
+        } else {
+            self.repository.save_overrides(&avatar, &overrides).await?;
+        }
+
+        Ok(overrides.into())
+    }
+
+    /// Removes the character's overrides; returns whether any were stored.
+    pub async fn clear_overrides(&self, avatar: &str) -> Result<bool, ApplicationError> {
+        let avatar = validate_avatar_file_name(avatar)?;
+        Ok(self.repository.delete_overrides(&avatar).await?)
+    }
+}
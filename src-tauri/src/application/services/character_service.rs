@@ -1,4 +1,6 @@
 mod card_contract;
+mod duplicates;
+mod listing;
 mod lorebook_codec;
 
 use crate::application::dto::character_dto::{
@@ -0,0 +1,311 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::CharacterService;
+use crate::application::dto::character_dto::{
+    DeleteCharacterDto, DuplicateCharacterEntryDto, DuplicateCharacterGroupDto, DuplicateMatchKind,
+    FindDuplicateCharactersDto, MergeCharactersDto, MergeCharactersResultDto,
+};
+use crate::application::errors::ApplicationError;
+use crate::domain::models::character::Character;
+use crate::infrastructure::logging::logger;
+
+const DEFAULT_SIMILARITY_THRESHOLD: f64 = 0.85;
+
+/// Card keys that differ between copies of the same character file.
+const FILE_SPECIFIC_KEYS: &[&str] = &["avatar", "chat", "create_date", "fav", "json_data"];
+
+impl CharacterService {
+    /// Group characters whose cards are identical, or with `fuzzy`, whose names match and
+    /// whose descriptions are similar.
+    pub async fn find_duplicate_characters(
+        &self,
+        dto: FindDuplicateCharactersDto,
+    ) -> Result<Vec<DuplicateCharacterGroupDto>, ApplicationError> {
+        let threshold = dto.threshold.unwrap_or(DEFAULT_SIMILARITY_THRESHOLD);
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(ApplicationError::ValidationError(
+                "Similarity threshold must be between 0 and 1".to_string(),
+            ));
+        }
+
+        let characters = self.repository.find_all(!dto.fuzzy).await?;
+
+        let mut by_hash: BTreeMap<String, Vec<&Character>> = BTreeMap::new();
+        for character in &characters {
+            let file_stem = avatar_file_stem(&character.avatar);
+            match self.repository.read_character_card_json(file_stem).await {
+                Ok(card_json) => by_hash
+                    .entry(card_fingerprint(&card_json))
+                    .or_default()
+                    .push(character),
+                Err(error) => logger::warn(&format!(
+                    "Skipping {} in duplicate scan: {}",
+                    character.avatar, error
+                )),
+            }
+        }
+
+        let mut groups = Vec::new();
+        let mut grouped = HashSet::new();
+        for members in by_hash.into_values().filter(|members| members.len() > 1) {
+            grouped.extend(members.iter().map(|character| character.avatar.clone()));
+            groups.push(
+                self.duplicate_group(DuplicateMatchKind::Identical, None, &members)
+                    .await?,
+            );
+        }
+
+        if dto.fuzzy {
+            let remaining: Vec<&Character> = characters
+                .iter()
+                .filter(|character| !grouped.contains(&character.avatar))
+                .collect();
+            for (members, similarity) in similar_groups(&remaining, threshold) {
+                groups.push(
+                    self.duplicate_group(DuplicateMatchKind::Similar, Some(similarity), &members)
+                        .await?,
+                );
+            }
+        }
+
+        Ok(groups)
+    }
+
+    /// Move the chats of every `merge` character onto `keep`, then delete their cards.
+    pub async fn merge_characters(
+        &self,
+        dto: MergeCharactersDto,
+    ) -> Result<MergeCharactersResultDto, ApplicationError> {
+        if dto.merge.is_empty() {
+            return Err(ApplicationError::ValidationError(
+                "No characters to merge".to_string(),
+            ));
+        }
+        if dto.merge.iter().any(|name| name == &dto.keep) {
+            return Err(ApplicationError::ValidationError(
+                "The kept character cannot be merged into itself".to_string(),
+            ));
+        }
+
+        // Fail before moving anything if a card is missing or a chat is in use.
+        self.repository.find_by_name(&dto.keep).await?;
+        for name in &dto.merge {
+            self.repository.find_by_name(name).await?;
+            let workspace_targets = self
+                .agent_workspace_targets_for_character_chats(name)
+                .await?;
+            self.agent_workspace_lifecycle_service
+                .ensure_chat_workspaces_inactive(&workspace_targets)
+                .await?;
+        }
+
+        let mut moved_chats = Vec::new();
+        for name in &dto.merge {
+            moved_chats.extend(self.repository.move_chats(name, &dto.keep).await?);
+            self.delete_character(DeleteCharacterDto {
+                name: name.clone(),
+                delete_chats: false,
+            })
+            .await?;
+        }
+        self.chat_repository.clear_cache().await?;
+        self.repository.clear_cache().await?;
+
+        logger::info(&format!(
+            "Merged {} character(s) into {} ({} chat(s) moved)",
+            dto.merge.len(),
+            dto.keep,
+            moved_chats.len()
+        ));
+
+        Ok(MergeCharactersResultDto {
+            kept: dto.keep,
+            moved_chats,
+            deleted: dto.merge,
+        })
+    }
+
+    async fn duplicate_group(
+        &self,
+        kind: DuplicateMatchKind,
+        similarity: Option<f64>,
+        members: &[&Character],
+    ) -> Result<DuplicateCharacterGroupDto, ApplicationError> {
+        let mut characters = Vec::with_capacity(members.len());
+        for character in members {
+            let file_stem = avatar_file_stem(&character.avatar);
+            characters.push(DuplicateCharacterEntryDto {
+                avatar: character.avatar.clone(),
+                name: character.name.clone(),
+                file_size: self.repository.get_character_file_size(file_stem).await?,
+                chat_count: self
+                    .repository
+                    .get_character_chats(file_stem, true)
+                    .await?
+                    .len(),
+                date_added: character.date_added,
+            });
+        }
+        characters.sort_by(|left, right| left.avatar.cmp(&right.avatar));
+
+        Ok(DuplicateCharacterGroupDto {
+            kind,
+            similarity,
+            characters,
+        })
+    }
+}
+
+/// Hash of the card with file-specific keys removed. Object keys serialize sorted, so
+/// key order in the file does not matter.
+fn card_fingerprint(card_json: &str) -> String {
+    let canonical = match serde_json::from_str::<Value>(card_json) {
+        Ok(mut value) => {
+            if let Some(card) = value.as_object_mut() {
+                for key in FILE_SPECIFIC_KEYS {
+                    card.remove(*key);
+                }
+            }
+            if let Some(extensions) = value
+                .pointer_mut("/data/extensions")
+                .and_then(Value::as_object_mut)
+            {
+                extensions.remove("fav");
+            }
+            value.to_string()
+        }
+        Err(_) => card_json.to_string(),
+    };
+
+    let digest = Sha256::digest(canonical.as_bytes());
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Groups characters whose normalized names match and whose descriptions are at least
+/// `threshold` similar to every other member.
+fn similar_groups<'a>(
+    characters: &[&'a Character],
+    threshold: f64,
+) -> Vec<(Vec<&'a Character>, f64)> {
+    let mut by_name: BTreeMap<String, Vec<&Character>> = BTreeMap::new();
+    for character in characters {
+        let key = normalized_name(&character.name);
+        if !key.is_empty() {
+            by_name.entry(key).or_default().push(character);
+        }
+    }
+
+    let mut groups = Vec::new();
+    for candidates in by_name.into_values().filter(|group| group.len() > 1) {
+        let words: Vec<HashSet<String>> = candidates
+            .iter()
+            .map(|character| description_words(&character.description))
+            .collect();
+
+        let mut assigned = vec![false; candidates.len()];
+        for first in 0..candidates.len() {
+            if assigned[first] {
+                continue;
+            }
+
+            let mut members = vec![first];
+            let mut lowest = 1.0_f64;
+            for other in first + 1..candidates.len() {
+                if assigned[other] {
+                    continue;
+                }
+                let similarities: Vec<f64> = members
+                    .iter()
+                    .map(|member| jaccard_similarity(&words[*member], &words[other]))
+                    .collect();
+                if similarities
+                    .iter()
+                    .all(|similarity| *similarity >= threshold)
+                {
+                    lowest = similarities.into_iter().fold(lowest, f64::min);
+                    members.push(other);
+                }
+            }
+
+            if members.len() > 1 {
+                for member in &members {
+                    assigned[*member] = true;
+                }
+                groups.push((
+                    members.iter().map(|member| candidates[*member]).collect(),
+                    lowest,
+                ));
+            }
+        }
+    }
+
+    groups
+}
+
+/// Lowercased alphanumerics, ignoring copy markers such as `(2)` or `v2` at the end.
+fn normalized_name(name: &str) -> String {
+    let mut words: Vec<String> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    while words.len() > 1 {
+        let last = words.last().expect("checked length");
+        let is_copy_marker = last.chars().all(|c| c.is_ascii_digit())
+            || (last.starts_with('v') && last[1..].chars().all(|c| c.is_ascii_digit()))
+            || last == "copy";
+        if !is_copy_marker {
+            break;
+        }
+        words.pop();
+    }
+    words.join(" ")
+}
+
+fn description_words(description: &str) -> HashSet<String> {
+    description
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn jaccard_similarity(left: &HashSet<String>, right: &HashSet<String>) -> f64 {
+    if left.is_empty() && right.is_empty() {
+        return 1.0;
+    }
+    let intersection = left.intersection(right).count();
+    let union = left.len() + right.len() - intersection;
+    intersection as f64 / union as f64
+}
+
+fn avatar_file_stem(avatar: &str) -> &str {
+    avatar.strip_suffix(".png").unwrap_or(avatar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{card_fingerprint, normalized_name};
+
+    #[test]
+    fn fingerprint_ignores_file_specific_fields_and_key_order() {
+        let original = r#"{"name":"Alice","avatar":"Alice.png","create_date":"1","data":{"name":"Alice","extensions":{"fav":true}}}"#;
+        let copy = r#"{"data":{"extensions":{"fav":false},"name":"Alice"},"avatar":"Alice (2).png","name":"Alice"}"#;
+        let different = r#"{"name":"Alice","data":{"name":"Alice","description":"other"}}"#;
+
+        assert_eq!(card_fingerprint(original), card_fingerprint(copy));
+        assert_ne!(card_fingerprint(original), card_fingerprint(different));
+    }
+
+    #[test]
+    fn normalized_names_drop_copy_markers() {
+        assert_eq!(normalized_name("Alice (2)"), "alice");
+        assert_eq!(normalized_name("alice_v3"), "alice");
+        assert_eq!(normalized_name("Alice - Copy"), "alice");
+        assert_eq!(normalized_name("Agent 47"), "agent");
+        assert_eq!(normalized_name("2B"), "2b");
+    }
+}
@@ -0,0 +1,204 @@
+use std::cmp::Ordering;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use serde_json::{Map, Value};
+
+use super::CharacterService;
+use crate::application::dto::character_dto::{
+    CharacterDto, CharacterPageDto, CharacterSortKey, CharacterSummaryDto, GetCharacterFieldsDto,
+    ListCharactersDto, SortOrder,
+};
+use crate::application::errors::ApplicationError;
+use crate::infrastructure::logging::logger;
+use crate::infrastructure::sillytavern_sorting::compare_sillytavern_name;
+
+/// `CharacterDto` fields kept by the shallow projection; requesting only these never reads
+/// the full card.
+const SHALLOW_FIELDS: &[&str] = &[
+    "name",
+    "avatar",
+    "chat",
+    "creator",
+    "creator_notes",
+    "character_version",
+    "tags",
+    "create_date",
+    "talkativeness",
+    "fav",
+    "chat_size",
+    "date_added",
+    "date_last_chat",
+];
+
+/// `CharacterDto` fields dropped by the shallow projection and loaded on demand.
+const HEAVY_FIELDS: &[&str] = &[
+    "description",
+    "personality",
+    "scenario",
+    "first_mes",
+    "mes_example",
+    "alternate_greetings",
+    "system_prompt",
+    "post_history_instructions",
+    "extensions",
+    "character_book",
+    "json_data",
+];
+
+impl CharacterService {
+    /// List one page of shallow character summaries, filtered and sorted against the cached
+    /// character index. Heavy fields named in `dto.fields` are loaded for the returned page
+    /// only.
+    pub async fn list_characters(
+        &self,
+        dto: ListCharactersDto,
+    ) -> Result<CharacterPageDto, ApplicationError> {
+        logger::debug(&format!(
+            "Listing characters (offset: {}, limit: {:?})",
+            dto.offset, dto.limit
+        ));
+        validate_field_names(&dto.fields)?;
+
+        let mut characters: Vec<CharacterDto> = self
+            .repository
+            .find_all(true)
+            .await?
+            .into_iter()
+            .map(CharacterDto::from)
+            .filter(|character| matches_filters(character, &dto))
+            .collect();
+
+        let seed = match dto.sort {
+            CharacterSortKey::Random => {
+                let seed = dto.seed.unwrap_or_else(rand::random);
+                shuffle_characters(&mut characters, seed);
+                Some(seed)
+            }
+            sort => {
+                sort_characters(&mut characters, sort, dto.order);
+                None
+            }
+        };
+
+        let total = characters.len();
+        let needs_full_card = dto
+            .fields
+            .iter()
+            .any(|field| HEAVY_FIELDS.contains(&field.as_str()));
+
+        let page = characters
+            .into_iter()
+            .skip(dto.offset)
+            .take(dto.limit.unwrap_or(usize::MAX));
+        let mut summaries = Vec::new();
+        for character in page {
+            let mut summary = CharacterSummaryDto::from(&character);
+            if !dto.fields.is_empty() {
+                let source = if needs_full_card {
+                    self.get_character(avatar_file_stem(&character.avatar))
+                        .await?
+                } else {
+                    character
+                };
+                summary.fields = select_fields(&source, &dto.fields)?;
+            }
+            summaries.push(summary);
+        }
+
+        Ok(CharacterPageDto {
+            total,
+            offset: dto.offset,
+            seed,
+            characters: summaries,
+        })
+    }
+
+    /// Load selected fields of a single character, e.g. `description` for a hovered card.
+    pub async fn get_character_fields(
+        &self,
+        dto: GetCharacterFieldsDto,
+    ) -> Result<Map<String, Value>, ApplicationError> {
+        validate_field_names(&dto.fields)?;
+        let character = self.get_character(&dto.name).await?;
+        select_fields(&character, &dto.fields)
+    }
+}
+
+fn validate_field_names(fields: &[String]) -> Result<(), ApplicationError> {
+    let unknown: Vec<&str> = fields
+        .iter()
+        .map(String::as_str)
+        .filter(|field| !SHALLOW_FIELDS.contains(field) && !HEAVY_FIELDS.contains(field))
+        .collect();
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    Err(ApplicationError::ValidationError(format!(
+        "Unknown character fields: {}",
+        unknown.join(", ")
+    )))
+}
+
+fn select_fields(
+    character: &CharacterDto,
+    fields: &[String],
+) -> Result<Map<String, Value>, ApplicationError> {
+    let Value::Object(mut all_fields) = serde_json::to_value(character).map_err(|error| {
+        ApplicationError::InternalError(format!("Failed to serialize character: {}", error))
+    })?
+    else {
+        return Err(ApplicationError::InternalError(
+            "Character did not serialize to an object".to_string(),
+        ));
+    };
+
+    Ok(fields
+        .iter()
+        .filter_map(|field| all_fields.remove(field).map(|value| (field.clone(), value)))
+        .collect())
+}
+
+fn matches_filters(character: &CharacterDto, dto: &ListCharactersDto) -> bool {
+    if dto.favorites_only && !character.fav {
+        return false;
+    }
+
+    let has_tag = |wanted: &String| {
+        character
+            .tags
+            .iter()
+            .any(|tag| tag.trim().eq_ignore_ascii_case(wanted.trim()))
+    };
+    dto.tags.iter().all(has_tag) && !dto.exclude_tags.iter().any(has_tag)
+}
+
+fn sort_characters(characters: &mut [CharacterDto], sort: CharacterSortKey, order: SortOrder) {
+    characters.sort_by(|left, right| {
+        let ordering = match sort {
+            CharacterSortKey::Name => compare_sillytavern_name(&left.name, &right.name),
+            CharacterSortKey::DateAdded => left.date_added.cmp(&right.date_added),
+            CharacterSortKey::DateLastChat => left.date_last_chat.cmp(&right.date_last_chat),
+            CharacterSortKey::Random => Ordering::Equal,
+        };
+        let ordering = match order {
+            SortOrder::Asc => ordering,
+            SortOrder::Desc => ordering.reverse(),
+        };
+        // Avatar file names are unique, which keeps pages stable between requests.
+        ordering.then_with(|| left.avatar.cmp(&right.avatar))
+    });
+}
+
+/// Shuffles deterministically for a given seed and set of characters, so pages requested
+/// with the same seed do not overlap.
+fn shuffle_characters(characters: &mut [CharacterDto], seed: u32) {
+    characters.sort_by(|left, right| left.avatar.cmp(&right.avatar));
+    characters.shuffle(&mut StdRng::seed_from_u64(u64::from(seed)));
+}
+
+fn avatar_file_stem(avatar: &str) -> &str {
+    avatar.strip_suffix(".png").unwrap_or(avatar)
+}
@@ -1,10 +1,12 @@
 use super::CharacterService;
 use crate::application::dto::character_dto::{
     BulkMergeCharacterCardDataDto, BulkMergeCharacterCardDataFilterDto,
-    CharacterLorebookConflictResolution, CheckCharacterLorebookConflictDto, CreateCharacterDto,
-    ExportCharacterContentDto, ExportCharacterDto, ImportCharacterDto, MergeCharacterCardDataDto,
-    ResolveCharacterLorebookConflictDto, UpdateAvatarDto, UpdateCharacterCardDataDto,
-    UpdateCharacterDto,
+    CharacterLorebookConflictResolution, CharacterPageDto, CharacterSortKey,
+    CheckCharacterLorebookConflictDto, CreateCharacterDto, DuplicateMatchKind,
+    ExportCharacterContentDto, ExportCharacterDto, FindDuplicateCharactersDto,
+    GetCharacterFieldsDto, ImportCharacterDto, ListCharactersDto, MergeCharacterCardDataDto,
+    MergeCharactersDto, ResolveCharacterLorebookConflictDto, SortOrder, UpdateAvatarDto,
+    UpdateCharacterCardDataDto, UpdateCharacterDto,
 };
 use crate::application::errors::ApplicationError;
 use crate::application::services::agent_workspace_lifecycle_service::{
@@ -2361,3 +2363,231 @@ async fn lorebook_conflict_embedded_resolution_overwrites_local_world() {
 
     let _ = fs::remove_dir_all(&root).await;
 }
+
+#[tokio::test]
+async fn list_characters_pages_shallow_summaries_and_loads_requested_fields() {
+    let (service, _character_repository, _world_info_repository, root) = setup_service().await;
+
+    for name in ["Charlie", "alice", "Bob"] {
+        write_character_png(
+            &root,
+            name,
+            &json!({
+                "name": name,
+                "description": format!("{} description", name),
+                "first_mes": "Hello",
+                "tags": ["tag"],
+            }),
+        )
+        .await;
+    }
+
+    let page = service
+        .list_characters(ListCharactersDto {
+            offset: 1,
+            limit: Some(1),
+            fields: vec!["description".to_string()],
+            ..ListCharactersDto::default()
+        })
+        .await
+        .expect("list characters");
+
+    assert_eq!(page.total, 3);
+    assert_eq!(page.characters.len(), 1);
+    let summary = &page.characters[0];
+    assert_eq!(summary.avatar, "Bob.png");
+    assert_eq!(summary.tags, vec!["tag".to_string()]);
+    assert_eq!(summary.fields["description"], "Bob description");
+    assert!(!summary.fields.contains_key("first_mes"));
+
+    let error = service
+        .list_characters(ListCharactersDto {
+            fields: vec!["not_a_field".to_string()],
+            ..ListCharactersDto::default()
+        })
+        .await
+        .expect_err("unknown field should be rejected");
+    assert!(matches!(error, ApplicationError::ValidationError(_)));
+
+    let fields = service
+        .get_character_fields(GetCharacterFieldsDto {
+            name: "Charlie".to_string(),
+            fields: vec!["first_mes".to_string()],
+        })
+        .await
+        .expect("get character fields");
+    assert_eq!(fields["first_mes"], "Hello");
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
+#[tokio::test]
+async fn list_characters_filters_by_tags_and_favorites_and_keeps_random_order_per_seed() {
+    let (service, _character_repository, _world_info_repository, root) = setup_service().await;
+
+    let cards = [
+        ("Alice", vec!["Fantasy", "Elf"], true),
+        ("Bob", vec!["fantasy"], false),
+        ("Carol", vec!["Sci-Fi"], true),
+        ("Dave", vec!["Fantasy", "NSFW"], false),
+    ];
+    for (name, tags, fav) in &cards {
+        write_character_png(
+            &root,
+            name,
+            &json!({ "name": name, "tags": tags, "fav": fav }),
+        )
+        .await;
+    }
+
+    let avatars = |page: &CharacterPageDto| {
+        page.characters
+            .iter()
+            .map(|character| character.avatar.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let fantasy = service
+        .list_characters(ListCharactersDto {
+            tags: vec!["FANTASY".to_string()],
+            exclude_tags: vec!["nsfw".to_string()],
+            ..ListCharactersDto::default()
+        })
+        .await
+        .expect("list fantasy characters");
+    assert_eq!(fantasy.total, 2);
+    assert_eq!(avatars(&fantasy), vec!["Alice.png", "Bob.png"]);
+
+    let favorites = service
+        .list_characters(ListCharactersDto {
+            favorites_only: true,
+            order: SortOrder::Desc,
+            ..ListCharactersDto::default()
+        })
+        .await
+        .expect("list favorites");
+    assert_eq!(avatars(&favorites), vec!["Carol.png", "Alice.png"]);
+
+    let first = service
+        .list_characters(ListCharactersDto {
+            sort: CharacterSortKey::Random,
+            limit: Some(2),
+            ..ListCharactersDto::default()
+        })
+        .await
+        .expect("list first random page");
+    let seed = first.seed.expect("random sort should report its seed");
+    let second = service
+        .list_characters(ListCharactersDto {
+            sort: CharacterSortKey::Random,
+            offset: 2,
+            seed: Some(seed),
+            ..ListCharactersDto::default()
+        })
+        .await
+        .expect("list second random page");
+
+    let mut seen = avatars(&first);
+    seen.extend(avatars(&second));
+    seen.sort();
+    assert_eq!(seen, vec!["Alice.png", "Bob.png", "Carol.png", "Dave.png"]);
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
+#[tokio::test]
+async fn find_duplicate_characters_groups_identical_cards_and_merge_moves_chats() {
+    let (service, _character_repository, _world_info_repository, root) = setup_service().await;
+
+    let card = json!({
+        "name": "Alice",
+        "description": "A curious elf who loves maps",
+        "data": { "name": "Alice", "description": "A curious elf who loves maps" }
+    });
+    write_character_png(&root, "Alice", &card).await;
+    write_character_png(&root, "Alice (2)", &card).await;
+    write_character_png(
+        &root,
+        "Alice v2",
+        &json!({
+            "name": "Alice v2",
+            "description": "A curious elf who loves old maps",
+            "data": { "name": "Alice v2", "description": "A curious elf who loves old maps" }
+        }),
+    )
+    .await;
+
+    let header = json!({
+        "user_name": "User",
+        "character_name": "Alice",
+        "create_date": "2024-01-01@00h00m00s",
+        "chat_metadata": {}
+    });
+    for (folder, file) in [("Alice", "chat.jsonl"), ("Alice (2)", "chat.jsonl")] {
+        let dir = root.join("chats").join(folder);
+        fs::create_dir_all(&dir).await.expect("create chat dir");
+        fs::write(dir.join(file), format!("{}\n", header))
+            .await
+            .expect("write chat");
+    }
+
+    let identical = service
+        .find_duplicate_characters(FindDuplicateCharactersDto::default())
+        .await
+        .expect("find identical duplicates");
+    assert_eq!(identical.len(), 1);
+    assert!(matches!(identical[0].kind, DuplicateMatchKind::Identical));
+    let avatars: Vec<&str> = identical[0]
+        .characters
+        .iter()
+        .map(|entry| entry.avatar.as_str())
+        .collect();
+    assert_eq!(avatars, vec!["Alice (2).png", "Alice.png"]);
+    assert!(
+        identical[0]
+            .characters
+            .iter()
+            .all(|entry| entry.chat_count == 1)
+    );
+    assert!(
+        identical[0]
+            .characters
+            .iter()
+            .all(|entry| entry.file_size > 0)
+    );
+
+    let fuzzy = service
+        .find_duplicate_characters(FindDuplicateCharactersDto {
+            fuzzy: true,
+            threshold: Some(0.8),
+        })
+        .await
+        .expect("find similar duplicates");
+    assert_eq!(
+        fuzzy.len(),
+        1,
+        "cards already grouped as identical are not re-reported"
+    );
+
+    let result = service
+        .merge_characters(MergeCharactersDto {
+            keep: "Alice".to_string(),
+            merge: vec!["Alice (2)".to_string()],
+        })
+        .await
+        .expect("merge characters");
+    assert_eq!(result.moved_chats, vec!["chat (2).jsonl".to_string()]);
+    assert!(!root.join("characters/Alice (2).png").exists());
+    assert!(root.join("chats/Alice/chat (2).jsonl").exists());
+    assert!(!root.join("chats/Alice (2)").join("chat.jsonl").exists());
+
+    let invalid = service
+        .merge_characters(MergeCharactersDto {
+            keep: "Alice".to_string(),
+            merge: vec!["Alice".to_string()],
+        })
+        .await;
+    assert!(matches!(invalid, Err(ApplicationError::ValidationError(_))));
+
+    let _ = fs::remove_dir_all(&root).await;
+}
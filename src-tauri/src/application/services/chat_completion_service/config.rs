@@ -10,7 +10,7 @@ use crate::application::dto::chat_completion_dto::{
 use crate::application::errors::ApplicationError;
 use crate::domain::models::secret::SecretKeys;
 use crate::domain::repositories::chat_completion_repository::{
-    AnthropicBetaHeaderMode, ChatCompletionApiConfig, ChatCompletionSource,
+    AnthropicBetaHeaderMode, ChatCompletionApiConfig, ChatCompletionSource, ChatCompletionTimeouts,
 };
 use crate::domain::repositories::provider_metadata_repository::SiliconFlowEndpoint;
 use crate::domain::repositories::secret_repository::SecretRepository;
@@ -210,6 +210,8 @@ async fn resolve_api_config(
                 anthropic_beta_header_mode: AnthropicBetaHeaderMode::None,
                 aws_bedrock_custom_response_path: None,
                 aws_bedrock_custom_stream_path: None,
+                timeouts: ChatCompletionTimeouts::default(),
+                accept_invalid_certs: false,
             })
         }
         _ => {
@@ -252,6 +254,8 @@ async fn resolve_api_config(
                 anthropic_beta_header_mode: source_anthropic_beta_header_mode(source),
                 aws_bedrock_custom_response_path,
                 aws_bedrock_custom_stream_path,
+                timeouts: ChatCompletionTimeouts::default(),
+                accept_invalid_certs: false,
             })
         }
     }
@@ -557,6 +561,8 @@ async fn resolve_vertexai_generate_api_config(
             anthropic_beta_header_mode: AnthropicBetaHeaderMode::None,
             aws_bedrock_custom_response_path: None,
             aws_bedrock_custom_stream_path: None,
+            timeouts: ChatCompletionTimeouts::default(),
+            accept_invalid_certs: false,
         });
     }
 
@@ -606,6 +612,8 @@ async fn resolve_vertexai_generate_api_config(
                 anthropic_beta_header_mode: AnthropicBetaHeaderMode::None,
                 aws_bedrock_custom_response_path: None,
                 aws_bedrock_custom_stream_path: None,
+                timeouts: ChatCompletionTimeouts::default(),
+                accept_invalid_certs: false,
             })
         }
         "full" => {
@@ -633,6 +641,8 @@ async fn resolve_vertexai_generate_api_config(
                 anthropic_beta_header_mode: AnthropicBetaHeaderMode::None,
                 aws_bedrock_custom_response_path: None,
                 aws_bedrock_custom_stream_path: None,
+                timeouts: ChatCompletionTimeouts::default(),
+                accept_invalid_certs: false,
             })
         }
         other => Err(ApplicationError::ValidationError(format!(
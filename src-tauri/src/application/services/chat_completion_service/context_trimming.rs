@@ -0,0 +1,171 @@
+use std::collections::BTreeSet;
+
+use serde_json::{Map, Value};
+
+use crate::application::dto::chat_completion_dto::{
+    ContextTrimmingOptionsDto, ContextTrimmingStrategy,
+};
+use crate::application::errors::ApplicationError;
+
+/// Request field carrying trimming options. Its presence opts the request into backend
+/// history truncation; it is always stripped before the provider payload is built.
+pub(super) const CONTEXT_TRIMMING_FIELD: &str = "_tauritavern_context_trimming";
+/// Role and separator tokens each chat message costs on top of its text.
+pub(super) const MESSAGE_TOKEN_OVERHEAD: usize = 4;
+
+pub(super) fn take_context_trimming_options(
+    payload: &mut Map<String, Value>,
+) -> Result<Option<ContextTrimmingOptionsDto>, ApplicationError> {
+    let Some(options) = payload.remove(CONTEXT_TRIMMING_FIELD) else {
+        return Ok(None);
+    };
+
+    let options: ContextTrimmingOptionsDto = serde_json::from_value(options).map_err(|error| {
+        ApplicationError::ValidationError(format!(
+            "Invalid chat completion request field {CONTEXT_TRIMMING_FIELD}: {error}"
+        ))
+    })?;
+    if options.max_tokens == 0 {
+        return Err(ApplicationError::ValidationError(format!(
+            "{CONTEXT_TRIMMING_FIELD}.maxTokens must be positive"
+        )));
+    }
+    Ok(Some(options))
+}
+
+/// Indices of the messages to drop, ascending, so the rest fits `options.max_tokens`.
+/// System messages, the last message and pinned messages are kept even when the budget
+/// cannot be met without them.
+pub(super) fn plan_trim(
+    roles: &[String],
+    token_counts: &[usize],
+    options: &ContextTrimmingOptionsDto,
+) -> Vec<usize> {
+    let mut total: usize = token_counts.iter().sum();
+    if total <= options.max_tokens {
+        return Vec::new();
+    }
+
+    let last = roles.len().saturating_sub(1);
+    let candidates: Vec<usize> = (0..roles.len())
+        .filter(|index| {
+            *index != last
+                && !matches!(roles[*index].as_str(), "system" | "developer")
+                && !options.pinned_messages.contains(index)
+        })
+        .collect();
+
+    let order: Vec<usize> = match options.strategy {
+        ContextTrimmingStrategy::DropOldest => candidates,
+        ContextTrimmingStrategy::MiddleOut => {
+            let middle = candidates.len() / 2;
+            let mut order = Vec::with_capacity(candidates.len());
+            for offset in 0..candidates.len() {
+                let position = if offset % 2 == 0 {
+                    middle + offset / 2
+                } else {
+                    middle - offset.div_ceil(2)
+                };
+                order.push(candidates[position]);
+            }
+            order
+        }
+    };
+
+    let mut dropped = BTreeSet::new();
+    for index in order {
+        if total <= options.max_tokens {
+            break;
+        }
+        total -= token_counts[index];
+        dropped.insert(index);
+    }
+    dropped.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{CONTEXT_TRIMMING_FIELD, plan_trim, take_context_trimming_options};
+    use crate::application::dto::chat_completion_dto::{
+        ContextTrimmingOptionsDto, ContextTrimmingStrategy,
+    };
+
+    fn roles(roles: &[&str]) -> Vec<String> {
+        roles.iter().map(|role| role.to_string()).collect()
+    }
+
+    fn options(max_tokens: usize, strategy: ContextTrimmingStrategy) -> ContextTrimmingOptionsDto {
+        ContextTrimmingOptionsDto {
+            max_tokens,
+            strategy,
+            pinned_messages: Vec::new(),
+            tokenizer: None,
+        }
+    }
+
+    #[test]
+    fn takes_options_and_rejects_zero_budget() {
+        let Value::Object(mut payload) = json!({
+            CONTEXT_TRIMMING_FIELD: { "maxTokens": 100, "strategy": "middle_out", "pinnedMessages": [1] },
+        }) else {
+            unreachable!();
+        };
+        let options = take_context_trimming_options(&mut payload)
+            .unwrap()
+            .expect("options present");
+        assert_eq!(options.strategy, ContextTrimmingStrategy::MiddleOut);
+        assert!(payload.is_empty());
+
+        let Value::Object(mut payload) = json!({ CONTEXT_TRIMMING_FIELD: { "maxTokens": 0 } })
+        else {
+            unreachable!();
+        };
+        assert!(take_context_trimming_options(&mut payload).is_err());
+    }
+
+    #[test]
+    fn drop_oldest_keeps_system_pinned_and_last_messages() {
+        let roles = roles(&["system", "user", "assistant", "user", "assistant", "user"]);
+        let counts = [10, 10, 10, 10, 10, 10];
+
+        let mut oldest = options(35, ContextTrimmingStrategy::DropOldest);
+        assert_eq!(plan_trim(&roles, &counts, &oldest), vec![1, 2, 3]);
+
+        oldest.pinned_messages = vec![1];
+        assert_eq!(plan_trim(&roles, &counts, &oldest), vec![2, 3, 4]);
+
+        assert_eq!(
+            plan_trim(
+                &roles,
+                &counts,
+                &options(5, ContextTrimmingStrategy::DropOldest)
+            ),
+            vec![1, 2, 3, 4]
+        );
+        assert!(
+            plan_trim(
+                &roles,
+                &counts,
+                &options(60, ContextTrimmingStrategy::DropOldest)
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn middle_out_drops_from_the_center_of_the_history() {
+        let roles = roles(&["system", "user", "assistant", "user", "assistant", "user"]);
+        let counts = [10, 10, 10, 10, 10, 10];
+
+        assert_eq!(
+            plan_trim(
+                &roles,
+                &counts,
+                &options(45, ContextTrimmingStrategy::MiddleOut)
+            ),
+            vec![2, 3]
+        );
+    }
+}
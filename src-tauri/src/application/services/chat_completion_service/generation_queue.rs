@@ -0,0 +1,284 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio::sync::Notify;
+
+use crate::domain::models::settings::ChatCompletionQueueLimits;
+use crate::domain::repositories::chat_completion_repository::ChatCompletionSource;
+
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Requests of one source that are running and waiting for a slot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationQueueDepth {
+    pub source: &'static str,
+    pub active: usize,
+    pub waiting: usize,
+}
+
+type DepthListener = Arc<dyn Fn(GenerationQueueDepth) + Send + Sync>;
+
+#[derive(Default)]
+struct LaneState {
+    active: usize,
+    waiting: usize,
+    recent_starts: VecDeque<Instant>,
+}
+
+impl LaneState {
+    /// Starts a request if `limits` allow it at `now`. Otherwise returns how long until
+    /// the rate window frees a slot, or `None` when only a finishing request can.
+    fn try_start(
+        &mut self,
+        limits: ChatCompletionQueueLimits,
+        now: Instant,
+    ) -> Result<(), Option<Duration>> {
+        while self
+            .recent_starts
+            .front()
+            .is_some_and(|start| now.duration_since(*start) >= RATE_WINDOW)
+        {
+            self.recent_starts.pop_front();
+        }
+
+        if limits.max_concurrent > 0 && self.active >= limits.max_concurrent as usize {
+            return Err(None);
+        }
+        if limits.requests_per_minute > 0
+            && self.recent_starts.len() >= limits.requests_per_minute as usize
+            && let Some(oldest) = self.recent_starts.front()
+        {
+            return Err(Some(
+                RATE_WINDOW.saturating_sub(now.duration_since(*oldest)),
+            ));
+        }
+
+        self.active += 1;
+        self.recent_starts.push_back(now);
+        Ok(())
+    }
+}
+
+struct QueueLane {
+    source: &'static str,
+    state: Mutex<LaneState>,
+    released: Notify,
+    listener: DepthListener,
+}
+
+impl QueueLane {
+    fn lock(&self) -> MutexGuard<'_, LaneState> {
+        self.state.lock().unwrap()
+    }
+
+    fn depth(&self, state: &LaneState) -> GenerationQueueDepth {
+        GenerationQueueDepth {
+            source: self.source,
+            active: state.active,
+            waiting: state.waiting,
+        }
+    }
+
+    fn publish(&self, depth: GenerationQueueDepth) {
+        (self.listener)(depth);
+    }
+}
+
+/// Per-source limiter for upstream generations: at most `max_concurrent` running at
+/// once and `requests_per_minute` started in any sliding minute. Depth changes are
+/// reported to the listener so the UI can show queued requests.
+pub(super) struct GenerationQueue {
+    lanes: Mutex<HashMap<&'static str, Arc<QueueLane>>>,
+    listener: DepthListener,
+}
+
+impl GenerationQueue {
+    pub(super) fn new(listener: impl Fn(GenerationQueueDepth) + Send + Sync + 'static) -> Self {
+        Self {
+            lanes: Mutex::new(HashMap::new()),
+            listener: Arc::new(listener),
+        }
+    }
+
+    fn lane(&self, source: ChatCompletionSource) -> Arc<QueueLane> {
+        let mut lanes = self.lanes.lock().unwrap();
+        lanes
+            .entry(source.key())
+            .or_insert_with(|| {
+                Arc::new(QueueLane {
+                    source: source.key(),
+                    state: Mutex::new(LaneState::default()),
+                    released: Notify::new(),
+                    listener: self.listener.clone(),
+                })
+            })
+            .clone()
+    }
+
+    /// Waits until `limits` allow another request for `source`. The slot is held until
+    /// the returned permit is dropped; dropping the future leaves the queue.
+    pub(super) async fn acquire(
+        &self,
+        source: ChatCompletionSource,
+        limits: ChatCompletionQueueLimits,
+    ) -> GenerationPermit {
+        let lane = self.lane(source);
+        let mut ticket: Option<WaitingTicket> = None;
+
+        loop {
+            let released = lane.released.notified();
+            let wait = {
+                let mut state = lane.lock();
+                match state.try_start(limits, Instant::now()) {
+                    Ok(()) => {
+                        if let Some(mut ticket) = ticket.take() {
+                            ticket.armed = false;
+                            state.waiting -= 1;
+                        }
+                        let depth = lane.depth(&state);
+                        drop(state);
+                        lane.publish(depth);
+                        return GenerationPermit { lane: lane.clone() };
+                    }
+                    Err(wait) => wait,
+                }
+            };
+
+            if ticket.is_none() {
+                ticket = Some(WaitingTicket::enter(lane.clone()));
+            }
+            match wait {
+                Some(delay) => {
+                    tokio::select! {
+                        _ = released => {}
+                        _ = tokio::time::sleep(delay) => {}
+                    }
+                }
+                None => released.await,
+            }
+        }
+    }
+}
+
+/// Counts a request as waiting until it starts or gives up.
+struct WaitingTicket {
+    lane: Arc<QueueLane>,
+    armed: bool,
+}
+
+impl WaitingTicket {
+    fn enter(lane: Arc<QueueLane>) -> Self {
+        let depth = {
+            let mut state = lane.lock();
+            state.waiting += 1;
+            lane.depth(&state)
+        };
+        lane.publish(depth);
+        Self { lane, armed: true }
+    }
+}
+
+impl Drop for WaitingTicket {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+
+        let depth = {
+            let mut state = self.lane.lock();
+            state.waiting -= 1;
+            self.lane.depth(&state)
+        };
+        self.lane.publish(depth);
+    }
+}
+
+pub(super) struct GenerationPermit {
+    lane: Arc<QueueLane>,
+}
+
+impl Drop for GenerationPermit {
+    fn drop(&mut self) {
+        let depth = {
+            let mut state = self.lane.lock();
+            state.active -= 1;
+            self.lane.depth(&state)
+        };
+        self.lane.publish(depth);
+        self.lane.released.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use super::{GenerationQueue, GenerationQueueDepth, LaneState};
+    use crate::domain::models::settings::ChatCompletionQueueLimits;
+    use crate::domain::repositories::chat_completion_repository::ChatCompletionSource;
+
+    #[test]
+    fn rate_limit_reports_time_until_oldest_start_expires() {
+        let limits = ChatCompletionQueueLimits {
+            max_concurrent: 0,
+            requests_per_minute: 2,
+        };
+        let mut state = LaneState::default();
+        let start = Instant::now();
+
+        assert_eq!(state.try_start(limits, start), Ok(()));
+        assert_eq!(
+            state.try_start(limits, start + Duration::from_secs(20)),
+            Ok(())
+        );
+        assert_eq!(
+            state.try_start(limits, start + Duration::from_secs(30)),
+            Err(Some(Duration::from_secs(30)))
+        );
+        assert_eq!(
+            state.try_start(limits, start + Duration::from_secs(60)),
+            Ok(())
+        );
+    }
+
+    #[tokio::test]
+    async fn concurrency_limit_queues_until_a_permit_is_dropped() {
+        let depths = Arc::new(Mutex::new(Vec::<GenerationQueueDepth>::new()));
+        let queue = Arc::new(GenerationQueue::new({
+            let depths = depths.clone();
+            move |depth| depths.lock().unwrap().push(depth)
+        }));
+        let limits = ChatCompletionQueueLimits {
+            max_concurrent: 1,
+            requests_per_minute: 0,
+        };
+        let source = ChatCompletionSource::OpenAi;
+
+        let first = queue.acquire(source, limits).await;
+        let waiter = tokio::spawn({
+            let queue = queue.clone();
+            async move {
+                let _second = queue.acquire(source, limits).await;
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiter.is_finished());
+        assert_eq!(
+            depths.lock().unwrap().last().map(|depth| depth.waiting),
+            Some(1)
+        );
+
+        drop(first);
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("queued request starts once the slot frees")
+            .unwrap();
+
+        let last = depths.lock().unwrap().last().cloned().unwrap();
+        assert_eq!((last.active, last.waiting), (0, 0));
+    }
+}
@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde_json::{Map, Value};
+use tokio::sync::{Mutex, RwLock, oneshot};
+
+use crate::application::dto::chat_completion_dto::{
+    ChatCompletionHookDefinitionDto, ChatCompletionHookInvocationDto, ChatCompletionHookPhase,
+    RegisterChatCompletionHookDto,
+};
+use crate::application::errors::ApplicationError;
+
+pub const CHAT_COMPLETION_HOOK_EVENT: &str = "chat-completion-hook-requested";
+
+const DEFAULT_HOOK_TIMEOUT_MS: u64 = 5_000;
+const MAX_HOOK_TIMEOUT_MS: u64 = 60_000;
+
+type HookOutcome = Result<Value, String>;
+type HookDispatcher =
+    Box<dyn Fn(ChatCompletionHookInvocationDto) -> Result<(), String> + Send + Sync>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ChatCompletionHook {
+    extension: String,
+    phase: ChatCompletionHookPhase,
+    priority: i32,
+    timeout_ms: u64,
+}
+
+impl ChatCompletionHook {
+    fn from_dto(dto: RegisterChatCompletionHookDto) -> Result<Self, ApplicationError> {
+        let extension = dto.extension.trim().to_string();
+        if extension.is_empty() {
+            return Err(ApplicationError::ValidationError(
+                "Chat completion hook must name the extension that runs it".to_string(),
+            ));
+        }
+
+        let timeout_ms = dto.timeout_ms.unwrap_or(DEFAULT_HOOK_TIMEOUT_MS);
+        if timeout_ms == 0 || timeout_ms > MAX_HOOK_TIMEOUT_MS {
+            return Err(ApplicationError::ValidationError(format!(
+                "Chat completion hook timeoutMs must be between 1 and {MAX_HOOK_TIMEOUT_MS}"
+            )));
+        }
+
+        Ok(Self {
+            extension,
+            phase: dto.phase,
+            priority: dto.priority,
+            timeout_ms,
+        })
+    }
+
+    fn to_dto(&self) -> ChatCompletionHookDefinitionDto {
+        ChatCompletionHookDefinitionDto {
+            extension: self.extension.clone(),
+            phase: self.phase,
+            priority: self.priority,
+            timeout_ms: self.timeout_ms,
+        }
+    }
+}
+
+/// Prompt and response transforms declared by frontend extensions. Each invocation is
+/// handed to the dispatcher (the webview's `chat-completion-hook-requested` event) and
+/// completed through `submit_result`; a hook that fails or times out leaves the payload
+/// unchanged.
+pub(super) struct HookOrchestrator {
+    dispatcher: HookDispatcher,
+    hooks: RwLock<Vec<ChatCompletionHook>>,
+    pending_results: Mutex<HashMap<String, oneshot::Sender<HookOutcome>>>,
+}
+
+impl HookOrchestrator {
+    pub(super) fn new(
+        dispatcher: impl Fn(ChatCompletionHookInvocationDto) -> Result<(), String>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        Self {
+            dispatcher: Box::new(dispatcher),
+            hooks: RwLock::new(Vec::new()),
+            pending_results: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Registers or replaces the extension's hook for the phase.
+    pub(super) async fn register(
+        &self,
+        dto: RegisterChatCompletionHookDto,
+    ) -> Result<ChatCompletionHookDefinitionDto, ApplicationError> {
+        let hook = ChatCompletionHook::from_dto(dto)?;
+        let dto = hook.to_dto();
+        let mut hooks = self.hooks.write().await;
+        hooks.retain(|existing| {
+            !(existing.extension == hook.extension && existing.phase == hook.phase)
+        });
+        hooks.push(hook);
+        sort_hooks(&mut hooks);
+        Ok(dto)
+    }
+
+    pub(super) async fn unregister(&self, extension: &str, phase: ChatCompletionHookPhase) -> bool {
+        let extension = extension.trim();
+        let mut hooks = self.hooks.write().await;
+        let before = hooks.len();
+        hooks.retain(|hook| !(hook.extension == extension && hook.phase == phase));
+        hooks.len() != before
+    }
+
+    pub(super) async fn list(&self) -> Vec<ChatCompletionHookDefinitionDto> {
+        self.hooks
+            .read()
+            .await
+            .iter()
+            .map(ChatCompletionHook::to_dto)
+            .collect()
+    }
+
+    pub(super) async fn has_hooks(&self, phase: ChatCompletionHookPhase) -> bool {
+        self.hooks
+            .read()
+            .await
+            .iter()
+            .any(|hook| hook.phase == phase)
+    }
+
+    pub(super) async fn submit_result(
+        &self,
+        invocation_id: &str,
+        outcome: HookOutcome,
+    ) -> Result<(), ApplicationError> {
+        let sender = self
+            .pending_results
+            .lock()
+            .await
+            .remove(invocation_id)
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "No pending chat completion hook invocation {invocation_id}"
+                ))
+            })?;
+
+        sender.send(outcome).map_err(|_| {
+            ApplicationError::Cancelled(format!(
+                "Chat completion hook invocation {invocation_id} is no longer waiting"
+            ))
+        })
+    }
+
+    pub(super) async fn transform_request(
+        &self,
+        payload: Map<String, Value>,
+    ) -> Map<String, Value> {
+        match self
+            .apply(ChatCompletionHookPhase::Request, Value::Object(payload))
+            .await
+        {
+            Value::Object(payload) => payload,
+            _ => unreachable!("hook output keeps the payload kind"),
+        }
+    }
+
+    pub(super) async fn transform_response(&self, body: Value) -> Value {
+        self.apply(ChatCompletionHookPhase::Response, body).await
+    }
+
+    /// Summary of messages dropped by context trimming, from the history summary hooks.
+    /// `None` when no hook produced a non-empty `summary`.
+    pub(super) async fn summarize_history(&self, messages: Vec<Value>) -> Option<String> {
+        let mut input = Map::new();
+        input.insert("messages".to_string(), Value::Array(messages));
+        let output = self
+            .apply(
+                ChatCompletionHookPhase::HistorySummary,
+                Value::Object(input),
+            )
+            .await;
+
+        output
+            .get("summary")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|summary| !summary.is_empty())
+            .map(str::to_string)
+    }
+
+    /// Empty keep-alive chunks and the `[DONE]` sentinel pass through untouched.
+    pub(super) async fn transform_stream_chunk(&self, chunk: String) -> String {
+        if chunk.is_empty() || chunk.trim() == "[DONE]" {
+            return chunk;
+        }
+
+        match self
+            .apply(ChatCompletionHookPhase::StreamChunk, Value::String(chunk))
+            .await
+        {
+            Value::String(chunk) => chunk,
+            _ => unreachable!("hook output keeps the payload kind"),
+        }
+    }
+
+    /// Runs every hook registered for the phase in priority order, feeding each one the
+    /// previous hook's output.
+    async fn apply(&self, phase: ChatCompletionHookPhase, payload: Value) -> Value {
+        let hooks: Vec<ChatCompletionHook> = self
+            .hooks
+            .read()
+            .await
+            .iter()
+            .filter(|hook| hook.phase == phase)
+            .cloned()
+            .collect();
+
+        let mut payload = payload;
+        for hook in &hooks {
+            let Some(output) = self.invoke(hook, &payload).await else {
+                continue;
+            };
+            match accept_hook_output(&payload, output) {
+                Some(output) => payload = output,
+                None => tracing::warn!(
+                    "Chat completion hook from `{}` returned a {} payload of the wrong shape; ignoring it",
+                    hook.extension,
+                    phase.key()
+                ),
+            }
+        }
+
+        payload
+    }
+
+    async fn invoke(&self, hook: &ChatCompletionHook, payload: &Value) -> Option<Value> {
+        let invocation_id = uuid::Uuid::new_v4().to_string();
+        let (sender, receiver) = oneshot::channel();
+        self.pending_results
+            .lock()
+            .await
+            .insert(invocation_id.clone(), sender);
+
+        let invocation = ChatCompletionHookInvocationDto {
+            invocation_id: invocation_id.clone(),
+            extension: hook.extension.clone(),
+            phase: hook.phase,
+            payload: payload.clone(),
+        };
+        if let Err(error) = (self.dispatcher)(invocation) {
+            self.pending_results.lock().await.remove(&invocation_id);
+            tracing::warn!(
+                "Failed to dispatch chat completion hook invocation: {}",
+                error
+            );
+            return None;
+        }
+
+        let outcome = tokio::time::timeout(Duration::from_millis(hook.timeout_ms), receiver).await;
+        match outcome {
+            Ok(Ok(Ok(output))) => Some(output),
+            Ok(Ok(Err(message))) => {
+                tracing::warn!(
+                    "Chat completion hook from `{}` failed: {}",
+                    hook.extension,
+                    message
+                );
+                None
+            }
+            Ok(Err(_)) => None,
+            Err(_) => {
+                self.pending_results.lock().await.remove(&invocation_id);
+                tracing::warn!(
+                    "Chat completion hook from `{}` timed out after {} ms",
+                    hook.extension,
+                    hook.timeout_ms
+                );
+                None
+            }
+        }
+    }
+}
+
+fn sort_hooks(hooks: &mut [ChatCompletionHook]) {
+    hooks.sort_by(|left, right| {
+        left.phase
+            .cmp(&right.phase)
+            .then(left.priority.cmp(&right.priority))
+            .then_with(|| left.extension.cmp(&right.extension))
+    });
+}
+
+/// A hook may rewrite the payload but not change its JSON kind: request, response and
+/// history summary hooks must return objects, stream chunk hooks must return strings.
+fn accept_hook_output(input: &Value, output: Value) -> Option<Value> {
+    let same_kind = matches!(
+        (input, &output),
+        (Value::Object(_), Value::Object(_)) | (Value::String(_), Value::String(_))
+    );
+    same_kind.then_some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{ChatCompletionHook, accept_hook_output, sort_hooks};
+    use crate::application::dto::chat_completion_dto::{
+        ChatCompletionHookPhase, RegisterChatCompletionHookDto,
+    };
+
+    fn hook(extension: &str, phase: ChatCompletionHookPhase, priority: i32) -> ChatCompletionHook {
+        ChatCompletionHook::from_dto(RegisterChatCompletionHookDto {
+            extension: extension.to_string(),
+            phase,
+            priority,
+            timeout_ms: None,
+        })
+        .expect("valid hook")
+    }
+
+    #[test]
+    fn hooks_run_by_phase_then_priority_then_extension() {
+        let mut hooks = vec![
+            hook(
+                "third-party/summarize",
+                ChatCompletionHookPhase::Response,
+                0,
+            ),
+            hook("third-party/regex", ChatCompletionHookPhase::Request, 10),
+            hook(
+                "third-party/author-note",
+                ChatCompletionHookPhase::Request,
+                0,
+            ),
+            hook("third-party/censor", ChatCompletionHookPhase::Request, 0),
+        ];
+        sort_hooks(&mut hooks);
+
+        let order: Vec<&str> = hooks.iter().map(|hook| hook.extension.as_str()).collect();
+        assert_eq!(
+            order,
+            vec![
+                "third-party/author-note",
+                "third-party/censor",
+                "third-party/regex",
+                "third-party/summarize",
+            ]
+        );
+    }
+
+    #[test]
+    fn hook_registration_rejects_out_of_range_timeouts() {
+        let error = ChatCompletionHook::from_dto(RegisterChatCompletionHookDto {
+            extension: "third-party/regex".to_string(),
+            phase: ChatCompletionHookPhase::StreamChunk,
+            priority: 0,
+            timeout_ms: Some(0),
+        });
+        assert!(error.is_err());
+    }
+
+    #[test]
+    fn hook_output_must_keep_payload_kind() {
+        let request = json!({ "messages": [] });
+        assert!(
+            accept_hook_output(&request, json!({ "messages": [{ "role": "system" }] })).is_some()
+        );
+        assert!(accept_hook_output(&request, json!("rewritten")).is_none());
+
+        let chunk = json!("{\"choices\":[]}");
+        assert!(accept_hook_output(&chunk, json!("{\"choices\":[{}]}")).is_some());
+        assert!(accept_hook_output(&chunk, json!(null)).is_none());
+    }
+}
@@ -0,0 +1,78 @@
+use serde_json::{Map, Value};
+
+use crate::application::dto::macro_dto::MacroContextDto;
+use crate::application::errors::ApplicationError;
+
+/// Request field carrying the macro context. Its presence opts the request into
+/// backend macro substitution; it is always stripped before the provider payload is built.
+pub(super) const MACRO_CONTEXT_FIELD: &str = "_tauritavern_macro_context";
+
+pub(super) fn take_macro_context(
+    payload: &mut Map<String, Value>,
+) -> Result<Option<MacroContextDto>, ApplicationError> {
+    let Some(context) = payload.remove(MACRO_CONTEXT_FIELD) else {
+        return Ok(None);
+    };
+
+    serde_json::from_value(context).map(Some).map_err(|error| {
+        ApplicationError::ValidationError(format!(
+            "Invalid chat completion request field {MACRO_CONTEXT_FIELD}: {error}"
+        ))
+    })
+}
+
+/// Message text in request order: string contents and the `text` of content parts.
+pub(super) fn message_texts_mut(payload: &mut Map<String, Value>) -> Vec<&mut String> {
+    let Some(messages) = payload.get_mut("messages").and_then(Value::as_array_mut) else {
+        return Vec::new();
+    };
+
+    let mut texts = Vec::new();
+    for message in messages {
+        match message.get_mut("content") {
+            Some(Value::String(content)) => texts.push(content),
+            Some(Value::Array(parts)) => {
+                for part in parts {
+                    if let Some(Value::String(text)) = part.get_mut("text") {
+                        texts.push(text);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    texts
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{MACRO_CONTEXT_FIELD, message_texts_mut, take_macro_context};
+
+    #[test]
+    fn takes_macro_context_and_collects_message_text() {
+        let Value::Object(mut payload) = json!({
+            "messages": [
+                { "role": "system", "content": "You are {{char}}." },
+                { "role": "user", "content": [
+                    { "type": "text", "text": "Hi, I'm {{user}}." },
+                    { "type": "image_url", "image_url": { "url": "data:image/png;base64,AA==" } }
+                ] }
+            ],
+            MACRO_CONTEXT_FIELD: { "user": "Alice", "char": "Seraphina" }
+        }) else {
+            unreachable!();
+        };
+
+        let context = take_macro_context(&mut payload)
+            .expect("valid context")
+            .expect("context present");
+        assert_eq!(context.user, "Alice");
+        assert!(!payload.contains_key(MACRO_CONTEXT_FIELD));
+
+        let texts = message_texts_mut(&mut payload);
+        let texts: Vec<&str> = texts.iter().map(|text| text.as_str()).collect();
+        assert_eq!(texts, vec!["You are {{char}}.", "Hi, I'm {{user}}."]);
+    }
+}
@@ -1,20 +1,33 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use serde_json::{Map, Value, json};
-use tokio::sync::{RwLock, watch};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{RwLock, mpsc, watch};
 
 use crate::application::dto::chat_completion_dto::{
-    ChatCompletionGenerateRequestDto, ChatCompletionStatusRequestDto,
+    ChatCompletionGenerateRequestDto, ChatCompletionHookDefinitionDto, ChatCompletionHookPhase,
+    ChatCompletionPayloadPreviewDto, ChatCompletionStatusRequestDto, ChatCompletionToolRunEventDto,
+    ChatCompletionToolRunRequestDto, ContextTrimmingOptionsDto, ContextTrimmingStrategy,
+    LocalToolDefinitionDto, OpenRouterGenerationCostDto, PromptItemizationDto,
+    PromptItemizationHintsDto, RegisterChatCompletionHookDto, RegisterLocalToolDto,
 };
+use crate::application::dto::tokenization_dto::OpenAiLogitBiasRequestDto;
 use crate::application::errors::ApplicationError;
+use crate::application::services::inline_image_service::InlineImageService;
+use crate::application::services::macro_engine::MacroEngine;
+use crate::application::services::model_capability_service::ModelCapabilityService;
+use crate::application::services::tokenization_service::TokenizationService;
 use crate::domain::errors::DomainError;
 use crate::domain::ios_policy::{IosPolicyActivationReport, IosPolicyScope};
+use crate::domain::models::filename::normalize_avatar_filename;
 use crate::domain::models::settings::{PromptCacheTtl, TauriTavernSettings};
+use crate::domain::repositories::character_generation_overrides_repository::CharacterGenerationOverridesRepository;
 use crate::domain::repositories::chat_completion_repository::{
     CHAT_COMPLETION_PROVIDER_STATE_FIELD, ChatCompletionApiConfig, ChatCompletionCancelReceiver,
     ChatCompletionNormalizationReport, ChatCompletionRepository, ChatCompletionSource,
-    ChatCompletionStreamSender,
+    ChatCompletionStreamSender, ChatCompletionTimeouts,
 };
 use crate::domain::repositories::prompt_cache_repository::PromptCacheRepository;
 use crate::domain::repositories::secret_repository::SecretRepository;
@@ -22,19 +35,38 @@ use crate::domain::repositories::settings_repository::SettingsRepository;
 
 mod additional_parameters;
 mod config;
+mod context_trimming;
 mod custom_api_format;
 mod custom_parameters;
 pub(crate) mod exchange;
+mod generation_queue;
+mod hook_orchestrator;
+mod macros;
 mod model_capabilities;
 mod payload;
 mod prompt_caching;
 mod prompt_caching_plan;
+mod prompt_itemization;
+mod status_cache;
+mod stop_bias;
+mod tool_orchestrator;
 mod vertexai_auth;
 
 use self::additional_parameters::AdditionalParameters;
 use self::exchange::{
     ChatCompletionExchange, ChatCompletionProviderFormat, NormalizedChatCompletionResponse,
 };
+use self::generation_queue::{GenerationPermit, GenerationQueue};
+use self::hook_orchestrator::{CHAT_COMPLETION_HOOK_EVENT, HookOrchestrator};
+use self::prompt_itemization::PromptItemizationStore;
+use self::status_cache::StatusCache;
+pub use self::tool_orchestrator::ChatCompletionToolRunEventSender;
+use self::tool_orchestrator::ToolCallOrchestrator;
+
+pub const CHAT_COMPLETION_QUEUE_EVENT: &str = "chat_completion:queue";
+/// Request field naming the character (by avatar) whose sampler overrides and stop and
+/// bias rules apply; it is always stripped before the provider payload is built.
+const CHARACTER_OVERRIDES_FIELD: &str = "_tauritavern_character";
 
 const OPENAI_SOURCE: &str = ChatCompletionSource::OpenAi.key();
 const AGENT_STRUCTURAL_BODY_OVERRIDE_KEYS: &[&str] = &[
@@ -46,6 +78,38 @@ const AGENT_STRUCTURAL_BODY_OVERRIDE_KEYS: &[&str] = &[
     CHAT_COMPLETION_PROVIDER_STATE_FIELD,
 ];
 
+struct UpstreamRequest {
+    source: ChatCompletionSource,
+    provider_format: ChatCompletionProviderFormat,
+    settings: TauriTavernSettings,
+    config: ChatCompletionApiConfig,
+    endpoint_path: String,
+    payload: Value,
+}
+
+impl UpstreamRequest {
+    fn into_preview(self) -> ChatCompletionPayloadPreviewDto {
+        let mut header_names: Vec<String> = self
+            .config
+            .extra_headers
+            .keys()
+            .chain(self.config.additional_headers.keys())
+            .cloned()
+            .collect();
+        header_names.sort();
+        header_names.dedup();
+
+        ChatCompletionPayloadPreviewDto {
+            source: self.source.key().to_string(),
+            provider_format: self.provider_format.key().to_string(),
+            base_url: self.config.base_url,
+            endpoint_path: self.endpoint_path,
+            header_names,
+            payload: self.payload,
+        }
+    }
+}
+
 struct ChatCompletionExecution {
     source: ChatCompletionSource,
     provider_format: ChatCompletionProviderFormat,
@@ -58,17 +122,33 @@ pub struct ChatCompletionService {
     secret_repository: Arc<dyn SecretRepository>,
     settings_repository: Arc<dyn SettingsRepository>,
     prompt_cache_repository: Arc<dyn PromptCacheRepository>,
+    model_capability_service: Arc<ModelCapabilityService>,
+    inline_image_service: Arc<InlineImageService>,
+    macro_engine: Arc<MacroEngine>,
+    tokenization_service: Arc<TokenizationService>,
+    character_generation_overrides_repository: Arc<dyn CharacterGenerationOverridesRepository>,
     ios_policy: IosPolicyActivationReport,
     active_streams: CancellationRegistry,
     active_generations: CancellationRegistry,
+    tool_orchestrator: ToolCallOrchestrator,
+    hook_orchestrator: HookOrchestrator,
+    status_cache: StatusCache,
+    generation_queue: GenerationQueue,
+    prompt_itemizations: Arc<PromptItemizationStore>,
 }
 
 impl ChatCompletionService {
     pub fn new(
+        app_handle: AppHandle,
         chat_completion_repository: Arc<dyn ChatCompletionRepository>,
         secret_repository: Arc<dyn SecretRepository>,
         settings_repository: Arc<dyn SettingsRepository>,
         prompt_cache_repository: Arc<dyn PromptCacheRepository>,
+        model_capability_service: Arc<ModelCapabilityService>,
+        inline_image_service: Arc<InlineImageService>,
+        macro_engine: Arc<MacroEngine>,
+        tokenization_service: Arc<TokenizationService>,
+        character_generation_overrides_repository: Arc<dyn CharacterGenerationOverridesRepository>,
         ios_policy: IosPolicyActivationReport,
     ) -> Self {
         Self {
@@ -76,9 +156,30 @@ impl ChatCompletionService {
             secret_repository,
             settings_repository,
             prompt_cache_repository,
+            model_capability_service,
+            inline_image_service,
+            macro_engine,
+            tokenization_service,
+            character_generation_overrides_repository,
             ios_policy,
             active_streams: CancellationRegistry::default(),
             active_generations: CancellationRegistry::default(),
+            tool_orchestrator: ToolCallOrchestrator::default(),
+            hook_orchestrator: HookOrchestrator::new({
+                let app_handle = app_handle.clone();
+                move |invocation| {
+                    app_handle
+                        .emit(CHAT_COMPLETION_HOOK_EVENT, invocation)
+                        .map_err(|error| error.to_string())
+                }
+            }),
+            status_cache: StatusCache::default(),
+            generation_queue: GenerationQueue::new(move |depth| {
+                if let Err(error) = app_handle.emit(CHAT_COMPLETION_QUEUE_EVENT, depth) {
+                    tracing::warn!("Failed to emit chat completion queue depth: {}", error);
+                }
+            }),
+            prompt_itemizations: Arc::new(PromptItemizationStore::default()),
         }
     }
 
@@ -332,19 +433,297 @@ impl ChatCompletionService {
                 "data": []
             }));
         }
-        let config =
+        let settings = self.load_tauritavern_settings().await?;
+        let mut config =
             config::resolve_status_api_config(source, &dto, &self.secret_repository).await?;
+        Self::apply_transport_settings(&mut config, &settings, source);
+
+        let cache_ttl = Duration::from_secs(settings.models.model_list_cache_ttl_secs);
+        let cached = if dto.force_refresh {
+            None
+        } else {
+            self.status_cache
+                .get(source, model_list_source, &config.base_url, cache_ttl)
+                .await
+        };
+        if let Some(models) = cached {
+            return Ok(models);
+        }
 
-        self.chat_completion_repository
+        let models = self
+            .chat_completion_repository
             .list_models(model_list_source, &config)
             .await
-            .map_err(ApplicationError::from)
+            .map_err(ApplicationError::from)?;
+        self.model_capability_service
+            .refresh_from_model_list(source, &models)
+            .await;
+        self.status_cache
+            .insert(
+                source,
+                model_list_source,
+                &config.base_url,
+                cache_ttl,
+                &models,
+            )
+            .await;
+
+        Ok(models)
     }
 
-    async fn execute_generate(
+    /// Drop cached model lists, e.g. after an API key was written, rotated or deleted.
+    pub async fn invalidate_status_cache(&self) {
+        self.status_cache.clear().await;
+    }
+
+    pub async fn get_openrouter_generation_cost(
+        &self,
+        dto: ChatCompletionStatusRequestDto,
+        generation_id: &str,
+    ) -> Result<OpenRouterGenerationCostDto, ApplicationError> {
+        let generation_id = generation_id.trim();
+        if generation_id.is_empty() {
+            return Err(ApplicationError::ValidationError(
+                "OpenRouter generation id is required".to_string(),
+            ));
+        }
+
+        let source = ChatCompletionSource::OpenRouter;
+        self.ensure_chat_completion_source_allowed(source)?;
+        self.ensure_endpoint_overrides_allowed_for_status(source, &dto)?;
+        let config =
+            config::resolve_status_api_config(source, &dto, &self.secret_repository).await?;
+
+        let body = self
+            .chat_completion_repository
+            .get_generation_metadata(source, &config, generation_id)
+            .await
+            .map_err(ApplicationError::from)?;
+
+        parse_openrouter_generation_cost(generation_id, &body)
+    }
+
+    /// Applies the tagged character's overrides and the merged stop strings and logit
+    /// bias, then runs extension request hooks, expands macros, trims context when asked
+    /// and records the prompt itemization.
+    async fn prepare_request(
         &self,
         dto: ChatCompletionGenerateRequestDto,
-    ) -> Result<ChatCompletionExecution, ApplicationError> {
+    ) -> Result<ChatCompletionGenerateRequestDto, ApplicationError> {
+        let mut payload = dto.payload;
+        let character = self.apply_character_overrides(&mut payload).await?;
+        self.apply_stop_bias(&mut payload, character.as_deref())
+            .await?;
+        let payload = self.hook_orchestrator.transform_request(payload).await;
+        let mut payload = self.substitute_request_macros(payload).await?;
+        let itemization_hints = prompt_itemization::take_prompt_itemization_hints(&mut payload)?;
+        let mut trimmed_messages = 0;
+        if let Some(options) = context_trimming::take_context_trimming_options(&mut payload)? {
+            trimmed_messages = self.trim_context(&mut payload, options).await?;
+        }
+        if let Some(mut hints) = itemization_hints {
+            hints.trimmed_history_messages += trimmed_messages;
+            self.spawn_prompt_itemization(hints, &payload);
+        }
+        Ok(ChatCompletionGenerateRequestDto { payload })
+    }
+
+    /// Avatar of the character `dto` is tagged with, read before the tag is stripped.
+    pub fn tagged_character(dto: &ChatCompletionGenerateRequestDto) -> Option<String> {
+        dto.payload
+            .get(CHARACTER_OVERRIDES_FIELD)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|avatar| !avatar.is_empty())
+            .map(str::to_string)
+    }
+
+    /// Merges the tagged character's sampler overrides over the preset values the
+    /// request was built with and returns the character's avatar.
+    async fn apply_character_overrides(
+        &self,
+        payload: &mut Map<String, Value>,
+    ) -> Result<Option<String>, ApplicationError> {
+        let Some(character) = payload.remove(CHARACTER_OVERRIDES_FIELD) else {
+            return Ok(None);
+        };
+        let Some(avatar) = character.as_str().and_then(normalize_avatar_filename) else {
+            return Err(ApplicationError::ValidationError(format!(
+                "Invalid chat completion request field {CHARACTER_OVERRIDES_FIELD}: expected an avatar filename"
+            )));
+        };
+
+        if let Some(overrides) = self
+            .character_generation_overrides_repository
+            .load_overrides(avatar)
+            .await?
+        {
+            overrides.apply_to(payload);
+        }
+        Ok(Some(avatar.to_string()))
+    }
+
+    /// Merges the configured global, per-model and per-character stop strings and logit
+    /// bias into the request, mapped to what the target provider accepts.
+    async fn apply_stop_bias(
+        &self,
+        payload: &mut Map<String, Value>,
+        avatar: Option<&str>,
+    ) -> Result<(), ApplicationError> {
+        let settings = self.load_tauritavern_settings().await?.stop_bias;
+        let model = payload
+            .get("model")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let rules = settings.rules_for(&model, avatar);
+        if rules.iter().all(|rules| rules.is_empty()) {
+            return Ok(());
+        }
+
+        let source = self.resolve_source(
+            payload
+                .get("chat_completion_source")
+                .and_then(Value::as_str)
+                .unwrap_or(OPENAI_SOURCE),
+        )?;
+        stop_bias::merge_stop_strings(payload, &rules, stop_bias::stop_sequence_limit(source));
+
+        let entries = stop_bias::logit_bias_entries(&rules);
+        if entries.is_empty() || !stop_bias::supports_logit_bias(source) {
+            return Ok(());
+        }
+        let bias = self
+            .tokenization_service
+            .build_openai_logit_bias(OpenAiLogitBiasRequestDto { model, entries })
+            .await?;
+        stop_bias::merge_logit_bias(payload, bias);
+        Ok(())
+    }
+
+    /// Drops history messages until the prompt fits the budget and returns how many were
+    /// removed. Middle-out trimming puts the history summary hook's output in their place.
+    async fn trim_context(
+        &self,
+        payload: &mut Map<String, Value>,
+        options: ContextTrimmingOptionsDto,
+    ) -> Result<usize, ApplicationError> {
+        let model = options
+            .tokenizer
+            .clone()
+            .or_else(|| {
+                payload
+                    .get("model")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            })
+            .unwrap_or_default();
+        let (roles, texts): (Vec<_>, Vec<_>) = prompt_itemization::message_roles_and_texts(payload)
+            .into_iter()
+            .unzip();
+        if roles.is_empty() {
+            return Ok(0);
+        }
+
+        let token_counts: Vec<usize> = self
+            .tokenization_service
+            .count_text_tokens(&model, texts)
+            .await?
+            .into_iter()
+            .map(|tokens| tokens + context_trimming::MESSAGE_TOKEN_OVERHEAD)
+            .collect();
+        let dropped = context_trimming::plan_trim(&roles, &token_counts, &options);
+        let Some(Value::Array(messages)) = payload.get_mut("messages") else {
+            return Ok(0);
+        };
+        if dropped.is_empty() {
+            return Ok(0);
+        }
+
+        let mut removed: Vec<Value> = dropped
+            .iter()
+            .rev()
+            .map(|index| messages.remove(*index))
+            .collect();
+        removed.reverse();
+        if options.strategy == ContextTrimmingStrategy::MiddleOut
+            && let Some(summary) = self.hook_orchestrator.summarize_history(removed).await
+        {
+            messages.insert(dropped[0], json!({ "role": "system", "content": summary }));
+        }
+
+        tracing::debug!(
+            dropped = dropped.len(),
+            max_tokens = options.max_tokens,
+            "Trimmed chat completion history"
+        );
+        Ok(dropped.len())
+    }
+
+    /// Counts the final request messages and hinted segments in the background and
+    /// stores the report under the hinted request id.
+    fn spawn_prompt_itemization(
+        &self,
+        hints: PromptItemizationHintsDto,
+        payload: &Map<String, Value>,
+    ) {
+        let model = payload
+            .get("model")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let (roles, mut texts): (Vec<_>, Vec<_>) =
+            prompt_itemization::message_roles_and_texts(payload)
+                .into_iter()
+                .unzip();
+        texts.extend(hints.items.iter().map(|item| item.text.clone()));
+
+        let tokenization_service = self.tokenization_service.clone();
+        let store = self.prompt_itemizations.clone();
+        tokio::spawn(async move {
+            match tokenization_service.count_text_tokens(&model, texts).await {
+                Ok(token_counts) => {
+                    let itemization =
+                        prompt_itemization::build_itemization(hints, model, roles, &token_counts);
+                    store.insert(itemization).await;
+                }
+                Err(error) => {
+                    tracing::warn!(
+                        request_id = hints.request_id.as_str(),
+                        "Failed to itemize chat completion prompt: {}",
+                        error
+                    );
+                }
+            }
+        });
+    }
+
+    pub async fn get_prompt_itemization(&self, request_id: &str) -> Option<PromptItemizationDto> {
+        self.prompt_itemizations.get(request_id).await
+    }
+
+    async fn substitute_request_macros(
+        &self,
+        mut payload: Map<String, Value>,
+    ) -> Result<Map<String, Value>, ApplicationError> {
+        let Some(context) = macros::take_macro_context(&mut payload)? else {
+            return Ok(payload);
+        };
+
+        self.macro_engine
+            .substitute_in_place(&mut macros::message_texts_mut(&mut payload), &context)
+            .await?;
+        Ok(payload)
+    }
+
+    /// Runs request preparation, config resolution and payload building: everything up
+    /// to the HTTP call. A dry run leaves the stored prompt cache digests untouched.
+    async fn build_upstream_request(
+        &self,
+        dto: ChatCompletionGenerateRequestDto,
+        dry_run: bool,
+    ) -> Result<UpstreamRequest, ApplicationError> {
+        let dto = self.prepare_request(dto).await?;
         let source = self.resolve_source(
             dto.get_string("chat_completion_source")
                 .unwrap_or(OPENAI_SOURCE),
@@ -367,7 +746,12 @@ impl ChatCompletionService {
             &self.secret_repository,
         )
         .await?;
-        let payload = dto.payload;
+        Self::apply_transport_settings(&mut config, &settings, source);
+        let model = dto.get_string("model").unwrap_or_default().to_string();
+        let mut payload = dto.payload;
+        self.inline_image_service
+            .inline_message_images(source, &mut payload)
+            .await?;
         let (endpoint_path, mut upstream_payload) = payload::build_payload(source, payload)?;
         self.apply_tauritavern_prompt_caching(
             source,
@@ -376,21 +760,63 @@ impl ChatCompletionService {
             &settings,
             &mut upstream_payload,
             prompt_caching_hints,
+            !dry_run,
         )
         .await?;
-        additional_parameters.apply_body_overrides(&mut upstream_payload)?;
-        payload::validate_upstream_tool_transcript(&endpoint_path, &upstream_payload)?;
+        self.model_capability_service
+            .strip_unsupported_parameters(source, &model, &mut upstream_payload)
+            .await;
+        finish_upstream_payload(
+            &endpoint_path,
+            &mut upstream_payload,
+            &additional_parameters,
+        )?;
+
+        Ok(UpstreamRequest {
+            source,
+            provider_format,
+            settings,
+            config,
+            endpoint_path,
+            payload: upstream_payload,
+        })
+    }
+
+    /// Builds the exact upstream request for `dto` without sending it. Credentials are
+    /// left out of the preview.
+    pub async fn preview_generate_payload(
+        &self,
+        dto: ChatCompletionGenerateRequestDto,
+    ) -> Result<ChatCompletionPayloadPreviewDto, ApplicationError> {
+        Ok(self.build_upstream_request(dto, true).await?.into_preview())
+    }
 
+    async fn execute_generate(
+        &self,
+        dto: ChatCompletionGenerateRequestDto,
+    ) -> Result<ChatCompletionExecution, ApplicationError> {
+        let request = self.build_upstream_request(dto, false).await?;
+        let _queue_permit = self
+            .acquire_generation_slot(request.source, &request.settings)
+            .await;
         let response = self
             .chat_completion_repository
-            .generate(source, &config, &endpoint_path, &upstream_payload)
+            .generate(
+                request.source,
+                &request.config,
+                &request.endpoint_path,
+                &request.payload,
+            )
             .await
             .map_err(ApplicationError::from)?;
 
         Ok(ChatCompletionExecution {
-            source,
-            provider_format,
-            body: response.body,
+            source: request.source,
+            provider_format: request.provider_format,
+            body: self
+                .hook_orchestrator
+                .transform_response(response.body)
+                .await,
             normalization_report: response.normalization_report,
         })
     }
@@ -458,52 +884,55 @@ impl ChatCompletionService {
         sender: ChatCompletionStreamSender,
         cancel: ChatCompletionCancelReceiver,
     ) -> Result<(), ApplicationError> {
-        let source = self.resolve_source(
-            dto.get_string("chat_completion_source")
-                .unwrap_or(OPENAI_SOURCE),
-        )?;
-        self.ensure_chat_completion_source_allowed(source)?;
-        self.ensure_endpoint_overrides_allowed_for_payload(source, &dto.payload)?;
-        self.ensure_chat_completion_features_allowed(&dto.payload)?;
-        let additional_parameters = AdditionalParameters::from_payload(&dto.payload)?;
-        Self::ensure_agent_body_overrides_allowed(&dto.payload, &additional_parameters)?;
+        let request = self.build_upstream_request(dto, false).await?;
 
-        let settings = self.load_tauritavern_settings().await?;
-        let prompt_caching_hints =
-            prompt_caching_plan::PromptCachingRequestHints::from_payload(&dto.payload)?;
-
-        let mut config = config::resolve_generate_api_config(
-            source,
-            &dto,
-            &additional_parameters,
-            &self.secret_repository,
-        )
-        .await?;
-        let payload = dto.payload;
-        let (endpoint_path, mut upstream_payload) = payload::build_payload(source, payload)?;
-        self.apply_tauritavern_prompt_caching(
-            source,
-            &endpoint_path,
-            &mut config,
-            &settings,
-            &mut upstream_payload,
-            prompt_caching_hints,
-        )
-        .await?;
-        additional_parameters.apply_body_overrides(&mut upstream_payload)?;
-        payload::validate_upstream_tool_transcript(&endpoint_path, &upstream_payload)?;
+        let _queue_permit = tokio::select! {
+            permit = self.acquire_generation_slot(request.source, &request.settings) => permit,
+            () = wait_for_cancellation(cancel.clone()) => {
+                return Err(DomainError::generation_cancelled_by_user().into());
+            }
+        };
 
-        self.chat_completion_repository
-            .generate_stream(
-                source,
-                &config,
-                &endpoint_path,
-                &upstream_payload,
-                sender,
-                cancel,
-            )
+        if !self
+            .hook_orchestrator
+            .has_hooks(ChatCompletionHookPhase::StreamChunk)
             .await
-            .map_err(ApplicationError::from)
+        {
+            return self
+                .chat_completion_repository
+                .generate_stream(
+                    request.source,
+                    &request.config,
+                    &request.endpoint_path,
+                    &request.payload,
+                    sender,
+                    cancel,
+                )
+                .await
+                .map_err(ApplicationError::from);
+        }
+
+        let (hooked_sender, mut hooked_receiver) = mpsc::unbounded_channel::<String>();
+        let generation = self.chat_completion_repository.generate_stream(
+            request.source,
+            &request.config,
+            &request.endpoint_path,
+            &request.payload,
+            hooked_sender,
+            cancel,
+        );
+        let hook_orchestrator = &self.hook_orchestrator;
+        let forwarding = async move {
+            while let Some(chunk) = hooked_receiver.recv().await {
+                let chunk = hook_orchestrator.transform_stream_chunk(chunk).await;
+                if sender.send(chunk).is_err() {
+                    break;
+                }
+            }
+        };
+
+        let (result, ()) = tokio::join!(generation, forwarding);
+        result.map_err(ApplicationError::from)
     }
 
     pub async fn register_stream(&self, stream_id: &str) -> watch::Receiver<bool> {
@@ -530,6 +959,133 @@ impl ChatCompletionService {
         self.active_generations.complete(request_id).await;
     }
 
+    pub async fn register_local_tool(
+        &self,
+        dto: RegisterLocalToolDto,
+    ) -> Result<LocalToolDefinitionDto, ApplicationError> {
+        self.tool_orchestrator.register(dto).await
+    }
+
+    pub async fn unregister_local_tool(&self, name: &str) -> bool {
+        self.tool_orchestrator.unregister(name).await
+    }
+
+    pub async fn list_local_tools(&self) -> Vec<LocalToolDefinitionDto> {
+        self.tool_orchestrator.list().await
+    }
+
+    pub async fn submit_local_tool_result(
+        &self,
+        run_id: &str,
+        call_id: &str,
+        content: String,
+        is_error: bool,
+    ) -> Result<(), ApplicationError> {
+        self.tool_orchestrator
+            .submit_result(run_id, call_id, content, is_error)
+            .await
+    }
+
+    pub async fn register_chat_completion_hook(
+        &self,
+        dto: RegisterChatCompletionHookDto,
+    ) -> Result<ChatCompletionHookDefinitionDto, ApplicationError> {
+        self.hook_orchestrator.register(dto).await
+    }
+
+    pub async fn unregister_chat_completion_hook(
+        &self,
+        extension: &str,
+        phase: ChatCompletionHookPhase,
+    ) -> bool {
+        self.hook_orchestrator.unregister(extension, phase).await
+    }
+
+    pub async fn list_chat_completion_hooks(&self) -> Vec<ChatCompletionHookDefinitionDto> {
+        self.hook_orchestrator.list().await
+    }
+
+    /// Completes a pending hook invocation with the transformed payload, or with the
+    /// extension's error message, which leaves the payload unchanged.
+    pub async fn submit_chat_completion_hook_result(
+        &self,
+        invocation_id: &str,
+        outcome: Result<Value, String>,
+    ) -> Result<(), ApplicationError> {
+        self.hook_orchestrator
+            .submit_result(invocation_id, outcome)
+            .await
+    }
+
+    /// Runs the model/tool loop server-side: every round that only calls
+    /// registered local tools is executed and fed back, while a round that
+    /// calls an unknown tool (or none) is returned to the caller unchanged.
+    pub async fn generate_with_local_tools(
+        &self,
+        run_id: &str,
+        dto: ChatCompletionToolRunRequestDto,
+        events: ChatCompletionToolRunEventSender,
+        mut cancel: ChatCompletionCancelReceiver,
+    ) -> Result<Value, ApplicationError> {
+        let max_rounds = tool_orchestrator::resolve_max_rounds(dto.max_rounds)?;
+        let tools = self
+            .tool_orchestrator
+            .resolve_tools(dto.tool_names.as_deref())
+            .await?;
+        // Expanded once up front so variable macros do not re-run on every round.
+        let mut payload = self.substitute_request_macros(dto.request.payload).await?;
+        tool_orchestrator::inject_local_tools(&mut payload, &tools)?;
+
+        let result: Result<Value, ApplicationError> = async {
+            for round in 1..=max_rounds {
+                let _ = events.send(ChatCompletionToolRunEventDto::RoundStarted { round });
+
+                let exchange = self
+                    .generate_exchange_with_cancel(
+                        ChatCompletionGenerateRequestDto {
+                            payload: payload.clone(),
+                        },
+                        cancel.clone(),
+                    )
+                    .await?;
+                let assistant_message = exchange.normalized_response.assistant_message().clone();
+                let _ = events.send(ChatCompletionToolRunEventDto::AssistantMessage {
+                    round,
+                    message: assistant_message.clone(),
+                });
+
+                let calls = tool_orchestrator::extract_requested_tool_calls(&assistant_message);
+                let all_local = calls
+                    .iter()
+                    .all(|call| tools.iter().any(|tool| tool.name() == call.name));
+                if calls.is_empty() || !all_local {
+                    return Ok(exchange.normalized_response.raw().clone());
+                }
+
+                let results = self
+                    .tool_orchestrator
+                    .run_round(run_id, &tools, calls, &events, &mut cancel)
+                    .await?;
+
+                tool_orchestrator::append_tool_round(&mut payload, &assistant_message, results)?;
+            }
+
+            Err(ApplicationError::ValidationError(format!(
+                "Tool run exceeded {max_rounds} rounds without a final answer"
+            )))
+        }
+        .await;
+
+        self.tool_orchestrator.discard_pending(run_id).await;
+        if let Ok(response) = &result {
+            let _ = events.send(ChatCompletionToolRunEventDto::Completed {
+                response: response.clone(),
+            });
+        }
+
+        result
+    }
+
     pub async fn close_provider_session(&self, session_id: &str) {
         self.chat_completion_repository
             .close_provider_session(session_id)
@@ -545,6 +1101,38 @@ impl ChatCompletionService {
         })
     }
 
+    fn apply_transport_settings(
+        config: &mut ChatCompletionApiConfig,
+        settings: &TauriTavernSettings,
+        source: ChatCompletionSource,
+    ) {
+        config.timeouts =
+            ChatCompletionTimeouts::for_source(&settings.chat_completion_timeouts, source);
+        config.accept_invalid_certs = settings
+            .http_client
+            .insecure_tls_sources
+            .contains(source.key());
+        if config.accept_invalid_certs {
+            tracing::warn!(
+                source = source.key(),
+                base_url = %config.base_url,
+                "TLS certificate verification is DISABLED for this chat completion source; \
+                 the connection can be intercepted",
+            );
+        }
+    }
+
+    /// Waits for the source's queue when it has limits configured; the returned permit
+    /// holds the slot until the upstream request finishes.
+    async fn acquire_generation_slot(
+        &self,
+        source: ChatCompletionSource,
+        settings: &TauriTavernSettings,
+    ) -> Option<GenerationPermit> {
+        let limits = settings.chat_completion_queue.for_source(source.key())?;
+        Some(self.generation_queue.acquire(source, limits).await)
+    }
+
     async fn load_tauritavern_settings(&self) -> Result<TauriTavernSettings, ApplicationError> {
         self.settings_repository
             .load_tauritavern_settings()
@@ -572,11 +1160,13 @@ impl ChatCompletionService {
         settings: &TauriTavernSettings,
         upstream_payload: &mut Value,
         hints: prompt_caching_plan::PromptCachingRequestHints,
+        persist_digests: bool,
     ) -> Result<(), ApplicationError> {
-        let cache_ttl = settings.models.claude.prompt_cache_ttl;
+        let cache_ttl = hints.effective_ttl(settings.models.claude.prompt_cache_ttl);
         if cache_ttl == PromptCacheTtl::Off {
             return Ok(());
         }
+        let depth = hints.depth;
 
         let ttl = match cache_ttl {
             PromptCacheTtl::Off => return Ok(()),
@@ -597,7 +1187,7 @@ impl ChatCompletionService {
 
         if prompt_caching::contains_cache_control(upstream_payload) {
             return Err(ApplicationError::ValidationError(
-                "Claude prompt caching cannot be combined with manually supplied cache_control fields"
+                "Prompt caching cannot be combined with manually supplied cache_control fields"
                     .to_string(),
             ));
         }
@@ -616,11 +1206,14 @@ impl ChatCompletionService {
                     upstream_payload,
                     previous.as_ref(),
                     ttl,
+                    depth,
                 );
-                self.prompt_cache_repository
-                    .save_prompt_digests(key, snapshot)
-                    .await
-                    .map_err(ApplicationError::from)?;
+                if persist_digests {
+                    self.prompt_cache_repository
+                        .save_prompt_digests(key, snapshot)
+                        .await
+                        .map_err(ApplicationError::from)?;
+                }
                 config.anthropic_beta_header_mode = anthropic_beta_header_mode;
             }
             prompt_caching_plan::PromptCachingPlan::OpenRouterClaude { key } => {
@@ -633,21 +1226,38 @@ impl ChatCompletionService {
                     upstream_payload,
                     previous.as_ref(),
                     ttl,
+                    depth,
                 );
-                self.prompt_cache_repository
-                    .save_prompt_digests(key, snapshot)
-                    .await
-                    .map_err(ApplicationError::from)?;
+                if persist_digests {
+                    self.prompt_cache_repository
+                        .save_prompt_digests(key, snapshot)
+                        .await
+                        .map_err(ApplicationError::from)?;
+                }
             }
             prompt_caching_plan::PromptCachingPlan::NanoGptClaude => {
                 apply_nanogpt_claude_cache_control(upstream_payload, ttl);
             }
+            prompt_caching_plan::PromptCachingPlan::OpenRouterGemini => {
+                prompt_caching::apply_openrouter_gemini_prompt_caching(upstream_payload, depth);
+            }
         }
 
         Ok(())
     }
 }
 
+/// Last step of payload building: the user's body overrides win over everything else,
+/// and the result must still be a valid tool transcript.
+fn finish_upstream_payload(
+    endpoint_path: &str,
+    upstream_payload: &mut Value,
+    additional_parameters: &AdditionalParameters,
+) -> Result<(), ApplicationError> {
+    additional_parameters.apply_body_overrides(upstream_payload)?;
+    payload::validate_upstream_tool_transcript(endpoint_path, upstream_payload)
+}
+
 fn resolve_status_model_list_source(
     source: ChatCompletionSource,
     custom_api_format: &str,
@@ -659,6 +1269,38 @@ fn resolve_status_model_list_source(
     Ok(custom_api_format::CustomApiFormat::parse(custom_api_format)?.model_list_source())
 }
 
+fn parse_openrouter_generation_cost(
+    generation_id: &str,
+    body: &Value,
+) -> Result<OpenRouterGenerationCostDto, ApplicationError> {
+    let data = body.get("data").unwrap_or(body);
+    let total_cost = data
+        .get("total_cost")
+        .and_then(Value::as_f64)
+        .ok_or_else(|| {
+            ApplicationError::InternalError(format!(
+                "OpenRouter generation {generation_id} response is missing total_cost"
+            ))
+        })?;
+    let string_field = |key: &str| {
+        data.get(key)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .filter(|value| !value.is_empty())
+    };
+
+    Ok(OpenRouterGenerationCostDto {
+        id: string_field("id").unwrap_or_else(|| generation_id.to_string()),
+        total_cost,
+        model: string_field("model"),
+        provider_name: string_field("provider_name"),
+        native_tokens_prompt: data.get("native_tokens_prompt").and_then(Value::as_u64),
+        native_tokens_completion: data.get("native_tokens_completion").and_then(Value::as_u64),
+        native_tokens_reasoning: data.get("native_tokens_reasoning").and_then(Value::as_u64),
+        cache_discount: data.get("cache_discount").and_then(Value::as_f64),
+    })
+}
+
 fn apply_nanogpt_claude_cache_control(payload: &mut Value, ttl: &str) -> bool {
     let is_claude = payload
         .as_object()
@@ -687,11 +1329,100 @@ fn apply_nanogpt_claude_cache_control(payload: &mut Value, ttl: &str) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use serde_json::{Value, json};
 
+    use super::additional_parameters::AdditionalParameters;
     use super::apply_nanogpt_claude_cache_control;
+    use super::exchange::ChatCompletionProviderFormat;
     use super::resolve_status_model_list_source;
-    use crate::domain::repositories::chat_completion_repository::ChatCompletionSource;
+    use super::{UpstreamRequest, finish_upstream_payload};
+    use crate::application::errors::ApplicationError;
+    use crate::domain::models::settings::TauriTavernSettings;
+    use crate::domain::repositories::chat_completion_repository::{
+        AnthropicBetaHeaderMode, ChatCompletionApiConfig, ChatCompletionSource,
+        ChatCompletionTimeouts,
+    };
+
+    #[test]
+    fn payload_preview_lists_header_names_without_credentials() {
+        let request = UpstreamRequest {
+            source: ChatCompletionSource::OpenRouter,
+            provider_format: ChatCompletionProviderFormat::OpenAiCompatible,
+            settings: TauriTavernSettings::default(),
+            config: ChatCompletionApiConfig {
+                base_url: "https://openrouter.ai/api/v1".to_string(),
+                api_key: "sk-or-secret".to_string(),
+                authorization_header: Some("Bearer sk-or-secret".to_string()),
+                extra_headers: HashMap::from([("X-Title".to_string(), "TauriTavern".to_string())]),
+                additional_headers: HashMap::from([
+                    ("X-Title".to_string(), "Custom".to_string()),
+                    (
+                        "Authorization".to_string(),
+                        "Bearer header-secret".to_string(),
+                    ),
+                ]),
+                anthropic_beta_header_mode: AnthropicBetaHeaderMode::None,
+                aws_bedrock_custom_response_path: None,
+                aws_bedrock_custom_stream_path: None,
+                timeouts: ChatCompletionTimeouts::default(),
+                accept_invalid_certs: false,
+            },
+            endpoint_path: "/chat/completions".to_string(),
+            payload: json!({ "model": "openai/gpt-4.1", "messages": [] }),
+        };
+
+        let preview = request.into_preview();
+        assert_eq!(preview.source, "openrouter");
+        assert_eq!(preview.endpoint_path, "/chat/completions");
+        assert_eq!(preview.header_names, ["Authorization", "X-Title"]);
+
+        let serialized = serde_json::to_string(&preview).expect("serialize preview");
+        assert!(!serialized.contains("secret"));
+        assert!(!serialized.contains("Custom"));
+    }
+
+    #[test]
+    fn finished_payload_applies_body_overrides_and_rejects_broken_tool_transcripts() {
+        let parameters = AdditionalParameters::from_payload(
+            json!({ "custom_include_body": "temperature: 0.3" })
+                .as_object()
+                .expect("object"),
+        )
+        .expect("parameters");
+
+        let mut payload = json!({
+            "model": "gpt-4.1",
+            "temperature": 1.0,
+            "messages": [{ "role": "user", "content": "hi" }]
+        });
+        finish_upstream_payload("/chat/completions", &mut payload, &parameters)
+            .expect("valid payload");
+        assert_eq!(payload["temperature"], json!(0.3));
+
+        let mut orphan_tool_result = json!({
+            "model": "gpt-4.1",
+            "messages": [
+                { "role": "user", "content": "weather" },
+                { "role": "tool", "tool_call_id": "call_1", "content": "sunny" }
+            ]
+        });
+        assert!(matches!(
+            finish_upstream_payload("/chat/completions", &mut orphan_tool_result, &parameters),
+            Err(ApplicationError::ValidationError(_))
+        ));
+
+        let broken_overrides = AdditionalParameters::from_payload(
+            json!({ "custom_include_body": "not-a-map-format" })
+                .as_object()
+                .expect("object"),
+        )
+        .expect("parameters");
+        assert!(
+            finish_upstream_payload("/chat/completions", &mut payload, &broken_overrides).is_err()
+        );
+    }
 
     #[test]
     fn nanogpt_claude_cache_control_is_inserted_for_claude_models() {
@@ -748,6 +1479,14 @@ mod tests {
     }
 }
 
+async fn wait_for_cancellation(mut cancel: ChatCompletionCancelReceiver) {
+    while !*cancel.borrow_and_update() {
+        if cancel.changed().await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
 #[derive(Default)]
 struct CancellationRegistry {
     active: RwLock<HashMap<String, watch::Sender<bool>>>,
@@ -1,3 +1,5 @@
+use serde_json::Value;
+
 use crate::application::errors::ApplicationError;
 
 const GEMINI_FLASH_MAX_THINKING_BUDGET: i64 = 24_576;
@@ -62,6 +64,36 @@ pub(super) fn unsupported_reasoning_effort(provider: &str, value: &str) -> Appli
     ))
 }
 
+/// Reads the explicit `thinking_budget_tokens` request option. It overrides the
+/// effort-derived budget for providers that accept a numeric thinking budget.
+pub(super) fn parse_thinking_budget_tokens(
+    value: Option<&Value>,
+) -> Result<Option<i64>, ApplicationError> {
+    let Some(value) = value.filter(|value| !value.is_null()) else {
+        return Ok(None);
+    };
+
+    let budget = value
+        .as_i64()
+        .or_else(|| {
+            value
+                .as_str()
+                .and_then(|raw| raw.trim().parse::<i64>().ok())
+        })
+        .ok_or_else(|| {
+            ApplicationError::ValidationError(
+                "thinking_budget_tokens must be an integer".to_string(),
+            )
+        })?;
+    if budget < 0 {
+        return Err(ApplicationError::ValidationError(
+            "thinking_budget_tokens must not be negative".to_string(),
+        ));
+    }
+
+    Ok(Some(budget))
+}
+
 pub(super) fn is_openrouter_claude_model_name(model: &str) -> bool {
     model
         .trim()
@@ -69,6 +101,13 @@ pub(super) fn is_openrouter_claude_model_name(model: &str) -> bool {
         .starts_with("anthropic/claude")
 }
 
+pub(super) fn is_openrouter_gemini_model_name(model: &str) -> bool {
+    model
+        .trim()
+        .to_ascii_lowercase()
+        .starts_with("google/gemini")
+}
+
 pub(super) fn map_openrouter_reasoning_effort(
     value: &str,
 ) -> Result<Option<&'static str>, ApplicationError> {
@@ -288,12 +327,30 @@ fn gemini_3_pro_medium_level(effort: RequestedReasoningEffort) -> Option<&'stati
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::{
         GeminiThinkingControl, RequestedReasoningEffort, is_gemini_thinking_config_model,
         is_openrouter_claude_model_name, is_zai_reasoning_effort_model,
         map_gemini_thinking_control, map_openrouter_reasoning_effort, map_zai_reasoning_effort,
+        parse_thinking_budget_tokens,
     };
 
+    #[test]
+    fn thinking_budget_parser_accepts_numbers_and_numeric_strings() {
+        assert_eq!(parse_thinking_budget_tokens(None).unwrap(), None);
+        assert_eq!(
+            parse_thinking_budget_tokens(Some(&json!(4096))).unwrap(),
+            Some(4096)
+        );
+        assert_eq!(
+            parse_thinking_budget_tokens(Some(&json!(" 2048 "))).unwrap(),
+            Some(2048)
+        );
+        assert!(parse_thinking_budget_tokens(Some(&json!(-1))).is_err());
+        assert!(parse_thinking_budget_tokens(Some(&json!("lots"))).is_err());
+    }
+
     #[test]
     fn requested_reasoning_effort_parser_normalizes_project_aliases() {
         for (input, expected) in [
@@ -3,7 +3,8 @@ use serde_json::{Map, Number, Value, json};
 use crate::application::errors::ApplicationError;
 
 use super::super::super::model_capabilities::{
-    RequestedReasoningEffort, parse_known_reasoning_effort, unsupported_reasoning_effort,
+    RequestedReasoningEffort, parse_known_reasoning_effort, parse_thinking_budget_tokens,
+    unsupported_reasoning_effort,
 };
 use super::super::shared::insert_if_present;
 use super::contract::{ClaudeModelContract, ClaudeSamplingMode, ClaudeThinkingMode};
@@ -48,6 +49,12 @@ fn build_claude_payload_inner(
     } else {
         None
     };
+    let thinking_budget_tokens = if contract.is_some() {
+        parse_thinking_budget_tokens(payload.get("thinking_budget_tokens"))?
+            .filter(|budget| *budget > 0)
+    } else {
+        None
+    };
 
     let use_system_prompt = payload
         .get("use_sysprompt")
@@ -188,19 +195,48 @@ fn build_claude_payload_inner(
     }
 
     if let Some(contract) = contract {
-        match contract.thinking {
+        // An explicit budget opts into manual thinking even on models that also accept adaptive.
+        let thinking = match contract.thinking {
+            ClaudeThinkingMode::ManualOrAdaptive if thinking_budget_tokens.is_some() => {
+                ClaudeThinkingMode::ManualOnly
+            }
+            thinking => thinking,
+        };
+        match thinking {
             ClaudeThinkingMode::Unsupported => {
                 if reasoning_effort.is_some() {
                     return Err(ApplicationError::ValidationError(format!(
                         "Claude model `{model}` does not support reasoning_effort"
                     )));
                 }
+                if thinking_budget_tokens.is_some() {
+                    return Err(ApplicationError::ValidationError(format!(
+                        "Claude model `{model}` does not support thinking_budget_tokens"
+                    )));
+                }
+            }
+            ClaudeThinkingMode::AdaptiveOnly if thinking_budget_tokens.is_some() => {
+                return Err(ApplicationError::ValidationError(format!(
+                    "Claude model `{model}` only supports adaptive thinking; thinking_budget_tokens is not accepted"
+                )));
             }
             ClaudeThinkingMode::ManualOnly => {
-                if let Some(reasoning_effort) = reasoning_effort {
-                    let budget_tokens =
-                        calculate_claude_budget_tokens(reasoning_effort, max_tokens, stream);
-                    if max_tokens <= CLAUDE_THINKING_MIN_TOKENS {
+                let budget_tokens = match (thinking_budget_tokens, reasoning_effort) {
+                    (Some(budget_tokens), _) => Some(budget_tokens.max(CLAUDE_THINKING_MIN_TOKENS)),
+                    (None, Some(reasoning_effort)) => Some(calculate_claude_budget_tokens(
+                        reasoning_effort,
+                        max_tokens,
+                        stream,
+                    )),
+                    (None, None) => None,
+                };
+
+                if let Some(budget_tokens) = budget_tokens {
+                    if thinking_budget_tokens.is_some() {
+                        if max_tokens <= budget_tokens {
+                            max_tokens = budget_tokens + CLAUDE_THINKING_MIN_TOKENS;
+                        }
+                    } else if max_tokens <= CLAUDE_THINKING_MIN_TOKENS {
                         max_tokens += CLAUDE_THINKING_MIN_TOKENS;
                     }
 
@@ -214,7 +250,9 @@ fn build_claude_payload_inner(
                     request.remove("temperature");
                     request.remove("top_p");
                     request.remove("top_k");
-                    if contract.supports_output_effort {
+                    if let Some(reasoning_effort) =
+                        reasoning_effort.filter(|_| contract.supports_output_effort)
+                    {
                         request.insert(
                             "output_config".to_string(),
                             json!({
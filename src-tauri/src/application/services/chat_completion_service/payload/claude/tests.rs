@@ -66,6 +66,27 @@ fn claude_reasoning_accepts_shared_minimal_alias() {
     );
 }
 
+#[test]
+fn claude_explicit_thinking_budget_raises_max_tokens() {
+    let mut payload = claude_payload("claude-sonnet-4-5");
+    payload.insert("max_tokens".to_string(), json!(2000));
+    payload.insert("thinking_budget_tokens".to_string(), json!(8000));
+
+    let (_, upstream) = build(payload).expect("build should succeed");
+
+    assert_eq!(
+        upstream
+            .pointer("/thinking/budget_tokens")
+            .and_then(Value::as_i64),
+        Some(8000)
+    );
+    assert_eq!(
+        upstream.get("max_tokens").and_then(Value::as_i64),
+        Some(9024)
+    );
+    assert!(upstream.get("output_config").is_none());
+}
+
 #[test]
 fn claude_opus_4_5_uses_legacy_thinking_with_output_effort() {
     let mut payload = claude_payload("claude-opus-4-5");
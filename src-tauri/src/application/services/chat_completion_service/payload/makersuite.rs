@@ -6,7 +6,7 @@ use crate::application::errors::ApplicationError;
 
 use super::super::model_capabilities::{
     GeminiThinkingControl, RequestedReasoningEffort, is_gemini_thinking_config_model,
-    map_gemini_thinking_control, parse_known_reasoning_effort,
+    map_gemini_thinking_control, parse_known_reasoning_effort, parse_thinking_budget_tokens,
 };
 use super::shared::{message_content_to_text, parse_data_url};
 use super::tool_calls::{
@@ -767,6 +767,7 @@ fn inject_google_thinking_config(
         Some(value) => parse_known_reasoning_effort(value, "Gemini")?,
         None => RequestedReasoningEffort::Auto,
     };
+    let explicit_budget = parse_thinking_budget_tokens(payload.get("thinking_budget_tokens"))?;
 
     if !is_gemini_thinking_config_model(model) {
         return Ok(());
@@ -788,6 +789,7 @@ fn inject_google_thinking_config(
     {
         match control {
             GeminiThinkingControl::BudgetTokens(tokens) => {
+                let tokens = explicit_budget.unwrap_or(tokens);
                 thinking_config.insert(
                     "thinkingBudget".to_string(),
                     Value::Number(serde_json::Number::from(tokens)),
@@ -909,6 +911,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn makersuite_explicit_thinking_budget_overrides_effort_mapping() {
+        let payload = json!({
+            "model": "gemini-2.5-pro",
+            "messages": [{"role": "user", "content": "hello"}],
+            "max_tokens": 4000,
+            "reasoning_effort": "low",
+            "thinking_budget_tokens": 3072
+        })
+        .as_object()
+        .cloned()
+        .expect("payload must be object");
+
+        let (_, upstream) = build(payload).expect("build should succeed");
+        assert_eq!(
+            upstream
+                .pointer("/generationConfig/thinkingConfig/thinkingBudget")
+                .and_then(Value::as_i64),
+            Some(3072)
+        );
+    }
+
     #[test]
     fn makersuite_25_flash_accepts_shared_minimal_alias() {
         let payload = json!({
@@ -56,6 +56,10 @@ pub(super) fn strip_internal_fields(payload: &mut Map<String, Value>) {
         "custom_exclude_body",
         "custom_include_headers",
         "custom_claude_prompt_caching",
+        "prompt_caching",
+        "prompt_cache_ttl",
+        "prompt_cache_depth",
+        "thinking_budget_tokens",
         "custom_url",
         "secret_id",
         "bypass_status_check",
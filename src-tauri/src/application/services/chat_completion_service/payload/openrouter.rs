@@ -47,10 +47,21 @@ fn apply_openrouter_overrides(
         body.insert("plugins".to_string(), json!([{ "id": "web" }]));
     }
 
-    if let Some(provider) = map_provider_preferences(source_payload) {
+    if let Some(provider) = map_provider_preferences(source_payload)? {
         body.insert("provider".to_string(), provider);
     }
 
+    // Ask OpenRouter to report the charged cost inline so the UI can show it per message,
+    // keeping any usage options the caller already set.
+    let mut usage = body
+        .get("usage")
+        .or_else(|| source_payload.get("usage"))
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default();
+    usage.entry("include").or_insert(Value::Bool(true));
+    body.insert("usage".to_string(), Value::Object(usage));
+
     if source_payload
         .get("use_fallback")
         .and_then(Value::as_bool)
@@ -92,36 +103,69 @@ fn map_middleout_transforms(value: Option<&Value>) -> Option<Value> {
     }
 }
 
-fn map_provider_preferences(source_payload: &Map<String, Value>) -> Option<Value> {
-    let order = source_payload
-        .get("provider")
-        .and_then(Value::as_array)
-        .filter(|providers| !providers.is_empty())
-        .cloned();
-    let quantizations = source_payload
-        .get("quantizations")
-        .and_then(Value::as_array)
-        .filter(|items| !items.is_empty())
-        .cloned();
+fn map_provider_preferences(
+    source_payload: &Map<String, Value>,
+) -> Result<Option<Value>, ApplicationError> {
+    let mut provider = Map::new();
 
-    if order.is_none() && quantizations.is_none() {
-        return None;
+    let order = non_empty_array(source_payload, "provider");
+    let allow_fallbacks = source_payload
+        .get("allow_fallbacks")
+        .and_then(Value::as_bool);
+    if order.is_some() || allow_fallbacks.is_some() {
+        provider.insert(
+            "allow_fallbacks".to_string(),
+            Value::Bool(allow_fallbacks.unwrap_or(true)),
+        );
     }
-
-    let mut provider = Map::new();
     if let Some(order) = order {
-        let allow_fallbacks = source_payload
-            .get("allow_fallbacks")
-            .and_then(Value::as_bool)
-            .unwrap_or(true);
-        provider.insert("allow_fallbacks".to_string(), Value::Bool(allow_fallbacks));
-        provider.insert("order".to_string(), Value::Array(order));
+        provider.insert("order".to_string(), order);
     }
-    if let Some(quantizations) = quantizations {
-        provider.insert("quantizations".to_string(), Value::Array(quantizations));
+
+    for (source_key, target_key) in [
+        ("quantizations", "quantizations"),
+        ("provider_only", "only"),
+        ("provider_ignore", "ignore"),
+    ] {
+        if let Some(items) = non_empty_array(source_payload, source_key) {
+            provider.insert(target_key.to_string(), items);
+        }
     }
 
-    Some(Value::Object(provider))
+    if let Some(sort) = source_payload
+        .get("provider_sort")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        if !matches!(sort, "price" | "throughput" | "latency") {
+            return Err(ApplicationError::ValidationError(format!(
+                "Unsupported OpenRouter provider sort: {sort}"
+            )));
+        }
+        provider.insert("sort".to_string(), Value::String(sort.to_string()));
+    }
+
+    if let Some(require_parameters) = source_payload
+        .get("require_parameters")
+        .and_then(Value::as_bool)
+    {
+        provider.insert(
+            "require_parameters".to_string(),
+            Value::Bool(require_parameters),
+        );
+    }
+
+    Ok((!provider.is_empty()).then_some(Value::Object(provider)))
+}
+
+fn non_empty_array(source_payload: &Map<String, Value>, key: &str) -> Option<Value> {
+    source_payload
+        .get(key)
+        .and_then(Value::as_array)
+        .filter(|items| !items.is_empty())
+        .cloned()
+        .map(Value::Array)
 }
 
 #[cfg(test)]
@@ -320,6 +364,49 @@ mod tests {
         assert_eq!(transforms_len, 0);
     }
 
+    #[test]
+    fn openrouter_provider_routing_preferences_are_mapped() {
+        let payload = json!({
+            "chat_completion_source": "openrouter",
+            "model": "openai/gpt-4.1-mini",
+            "messages": [{"role": "user", "content": "hello"}],
+            "allow_fallbacks": false,
+            "provider_ignore": ["azure"],
+            "provider_sort": "throughput",
+            "require_parameters": true
+        })
+        .as_object()
+        .cloned()
+        .expect("payload must be object");
+
+        let (_, upstream) = build(payload).expect("payload should build");
+        assert_eq!(
+            upstream.get("provider"),
+            Some(&json!({
+                "allow_fallbacks": false,
+                "ignore": ["azure"],
+                "sort": "throughput",
+                "require_parameters": true
+            }))
+        );
+        assert_eq!(upstream.get("usage"), Some(&json!({ "include": true })));
+    }
+
+    #[test]
+    fn openrouter_rejects_unknown_provider_sort() {
+        let payload = json!({
+            "chat_completion_source": "openrouter",
+            "model": "openai/gpt-4.1-mini",
+            "messages": [{"role": "user", "content": "hello"}],
+            "provider_sort": "cheapest"
+        })
+        .as_object()
+        .cloned()
+        .expect("payload must be object");
+
+        assert!(build(payload).is_err());
+    }
+
     #[test]
     fn openrouter_quantizations_are_forwarded_without_provider_order() {
         let payload = json!({
@@ -349,4 +436,23 @@ mod tests {
 
         assert_eq!(quantizations, vec!["int8", "fp16"]);
     }
+
+    #[test]
+    fn openrouter_keeps_caller_usage_options() {
+        let payload = json!({
+            "chat_completion_source": "openrouter",
+            "model": "openai/gpt-4.1-mini",
+            "messages": [{"role": "user", "content": "hello"}],
+            "usage": { "include": false, "detail": "full" }
+        })
+        .as_object()
+        .cloned()
+        .expect("payload must be object");
+
+        let (_, upstream) = build(payload).expect("payload should build");
+        assert_eq!(
+            upstream.get("usage"),
+            Some(&json!({ "include": false, "detail": "full" }))
+        );
+    }
 }
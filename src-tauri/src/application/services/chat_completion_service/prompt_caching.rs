@@ -34,6 +34,7 @@ pub(super) fn apply_claude_prompt_caching(
     payload: &mut Value,
     previous: Option<&PromptDigestSnapshot>,
     ttl: &str,
+    depth: Option<usize>,
 ) -> PromptDigestSnapshot {
     let (locations, digests) = collect_claude_digests(payload);
     let last_message_location = last_claude_message_location(&locations);
@@ -64,7 +65,31 @@ pub(super) fn apply_claude_prompt_caching(
         }
     }
 
-    if let Some(previous) = previous.filter(|snapshot| snapshot.version == PROMPT_CACHE_VERSION) {
+    if let Some(depth) = depth {
+        let message_count = payload
+            .get("messages")
+            .and_then(Value::as_array)
+            .map_or(0, Vec::len);
+        let candidate = depth_location(
+            &locations,
+            message_count,
+            depth,
+            |location| match location {
+                ClaudeDigestLocation::Message { message_index, .. } => Some(*message_index),
+                _ => None,
+            },
+        );
+        if let Some(candidate) = candidate {
+            let is_duplicate = Some(candidate) == system_location
+                || Some(candidate) == pre_history_location
+                || Some(candidate) == last_message_location;
+            if !is_duplicate {
+                insert_cache_control_claude(payload, candidate, ttl);
+            }
+        }
+    } else if let Some(previous) =
+        previous.filter(|snapshot| snapshot.version == PROMPT_CACHE_VERSION)
+    {
         let lcp_len = common_prefix_len(&previous.digests, &snapshot.digests);
         if lcp_len > 0 {
             let candidate = locations.get(lcp_len - 1).copied();
@@ -88,6 +113,7 @@ pub(super) fn apply_openrouter_claude_prompt_caching(
     payload: &mut Value,
     previous: Option<&PromptDigestSnapshot>,
     ttl: &str,
+    depth: Option<usize>,
 ) -> PromptDigestSnapshot {
     let (locations, snapshot) = collect_openrouter_digests(payload);
     if snapshot.digests.is_empty() {
@@ -120,7 +146,27 @@ pub(super) fn apply_openrouter_claude_prompt_caching(
         }
     }
 
-    if let Some(previous) = previous.filter(|snapshot| snapshot.version == PROMPT_CACHE_VERSION) {
+    if let Some(depth) = depth {
+        let candidate = depth_location(
+            &locations,
+            messages.len(),
+            depth,
+            |location| match location {
+                OpenRouterDigestLocation::Message { message_index, .. } => Some(*message_index),
+                _ => None,
+            },
+        );
+        if let Some(candidate) = candidate {
+            let is_duplicate = Some(candidate) == system_location
+                || Some(candidate) == pre_history_location
+                || Some(candidate) == last_location;
+            if !is_duplicate {
+                insert_cache_control_openrouter(messages, candidate, ttl);
+            }
+        }
+    } else if let Some(previous) =
+        previous.filter(|snapshot| snapshot.version == PROMPT_CACHE_VERSION)
+    {
         let lcp_len = common_prefix_len(&previous.digests, &snapshot.digests);
         if lcp_len > 0 {
             let candidate = locations.get(lcp_len - 1).copied();
@@ -139,6 +185,61 @@ pub(super) fn apply_openrouter_claude_prompt_caching(
     snapshot
 }
 
+/// Places one `cache_control` breakpoint for an OpenRouter Gemini request, at the end of
+/// the stable prefix or `depth` turns from the end. OpenRouter only reads the last
+/// breakpoint for Gemini and applies Gemini's own cache lifetime, so no TTL is sent.
+pub(super) fn apply_openrouter_gemini_prompt_caching(payload: &mut Value, depth: Option<usize>) {
+    let (locations, snapshot) = collect_openrouter_digests(payload);
+    if snapshot.digests.is_empty() {
+        return;
+    }
+
+    let Some(messages) = payload
+        .as_object_mut()
+        .and_then(|object| object.get_mut("messages"))
+        .and_then(Value::as_array_mut)
+    else {
+        return;
+    };
+
+    let last_location = last_openrouter_message_location(messages);
+    let candidate = match depth {
+        Some(depth) => depth_location(
+            &locations,
+            messages.len(),
+            depth,
+            |location| match location {
+                OpenRouterDigestLocation::Message { message_index, .. } => Some(*message_index),
+                _ => None,
+            },
+        ),
+        None => {
+            let system_location = find_openrouter_system_break_location(messages);
+            find_openrouter_pre_history_break_location(messages, system_location)
+                .or(system_location)
+        }
+    };
+
+    if let Some(candidate) = candidate.filter(|candidate| Some(*candidate) != last_location) {
+        let OpenRouterDigestLocation::Message {
+            message_index,
+            part_index,
+        } = candidate
+        else {
+            return;
+        };
+        if let Some(block) = messages
+            .get_mut(message_index)
+            .and_then(|message| message.get_mut("content"))
+            .and_then(Value::as_array_mut)
+            .and_then(|parts| parts.get_mut(part_index))
+            .and_then(Value::as_object_mut)
+        {
+            block.insert("cache_control".to_string(), json!({ "type": "ephemeral" }));
+        }
+    }
+}
+
 fn collect_claude_digests(payload: &Value) -> (Vec<ClaudeDigestLocation>, Vec<String>) {
     let mut locations = Vec::new();
     let mut digests = Vec::new();
@@ -536,6 +637,21 @@ fn ensure_openrouter_message_content_parts(message: &mut Map<String, Value>) {
     }
 }
 
+/// Finds the last cacheable block of the message `depth` turns before the final message.
+fn depth_location<L: Copy>(
+    locations: &[L],
+    message_count: usize,
+    depth: usize,
+    message_index: impl Fn(&L) -> Option<usize>,
+) -> Option<L> {
+    let target = message_count.checked_sub(depth + 1)?;
+    locations
+        .iter()
+        .rev()
+        .find(|location| message_index(location) == Some(target))
+        .copied()
+}
+
 fn common_prefix_len(previous: &[String], current: &[String]) -> usize {
     let max_len = previous.len().min(current.len());
 
@@ -623,7 +739,10 @@ fn cache_control(ttl: &str) -> Value {
 mod tests {
     use serde_json::{Value, json};
 
-    use super::{apply_claude_prompt_caching, apply_openrouter_claude_prompt_caching};
+    use super::{
+        apply_claude_prompt_caching, apply_openrouter_claude_prompt_caching,
+        apply_openrouter_gemini_prompt_caching,
+    };
 
     fn has_cache_control(value: &Value) -> bool {
         value
@@ -646,7 +765,7 @@ mod tests {
             ]
         });
 
-        let _snapshot = apply_claude_prompt_caching(&mut payload, None, "5m");
+        let _snapshot = apply_claude_prompt_caching(&mut payload, None, "5m", None);
 
         let root = payload.as_object().expect("payload must be object");
         assert!(
@@ -701,7 +820,7 @@ mod tests {
                 { "role": "user", "content": [{ "type": "text", "text": "u1" }] }
             ]
         });
-        let snapshot = apply_claude_prompt_caching(&mut payload1, None, "5m");
+        let snapshot = apply_claude_prompt_caching(&mut payload1, None, "5m", None);
 
         let mut payload2 = json!({
             "model": "claude-3-5-sonnet-latest",
@@ -713,7 +832,7 @@ mod tests {
             ]
         });
 
-        let _snapshot2 = apply_claude_prompt_caching(&mut payload2, Some(&snapshot), "5m");
+        let _snapshot2 = apply_claude_prompt_caching(&mut payload2, Some(&snapshot), "5m", None);
 
         let messages = payload2
             .as_object()
@@ -740,6 +859,36 @@ mod tests {
         assert!(!has_cache_control(last_block));
     }
 
+    #[test]
+    fn claude_prompt_caching_depth_marks_message_from_end() {
+        let mut payload = json!({
+            "model": "claude-3-5-sonnet-latest",
+            "messages": [
+                { "role": "user", "content": [{ "type": "text", "text": "prehistory" }] },
+                { "role": "assistant", "content": [{ "type": "text", "text": "a1" }] },
+                { "role": "user", "content": [{ "type": "text", "text": "u1" }] },
+                { "role": "assistant", "content": [{ "type": "text", "text": "a2" }] },
+                { "role": "user", "content": [{ "type": "text", "text": "u2" }] }
+            ]
+        });
+
+        let _snapshot = apply_claude_prompt_caching(&mut payload, None, "1h", Some(2));
+
+        let block = payload
+            .pointer("/messages/2/content/0")
+            .expect("depth target block must exist");
+        assert!(has_cache_control(block));
+        assert_eq!(
+            block.pointer("/cache_control/ttl").and_then(Value::as_str),
+            Some("1h")
+        );
+        assert!(!has_cache_control(
+            payload
+                .pointer("/messages/3/content/0")
+                .expect("assistant block must exist")
+        ));
+    }
+
     #[test]
     fn openrouter_prompt_caching_inserts_expected_breakpoints() {
         let mut payload = json!({
@@ -752,7 +901,7 @@ mod tests {
             ]
         });
 
-        let _snapshot = apply_openrouter_claude_prompt_caching(&mut payload, None, "5m");
+        let _snapshot = apply_openrouter_claude_prompt_caching(&mut payload, None, "5m", None);
 
         let root = payload.as_object().expect("payload must be object");
         assert!(
@@ -801,7 +950,7 @@ mod tests {
                 { "role": "user", "content": "u1" }
             ]
         });
-        let snapshot = apply_openrouter_claude_prompt_caching(&mut payload1, None, "5m");
+        let snapshot = apply_openrouter_claude_prompt_caching(&mut payload1, None, "5m", None);
 
         let mut payload2 = json!({
             "model": "anthropic/claude-3.5-sonnet",
@@ -814,7 +963,7 @@ mod tests {
         });
 
         let _snapshot2 =
-            apply_openrouter_claude_prompt_caching(&mut payload2, Some(&snapshot), "5m");
+            apply_openrouter_claude_prompt_caching(&mut payload2, Some(&snapshot), "5m", None);
 
         let messages = payload2
             .as_object()
@@ -831,4 +980,35 @@ mod tests {
             .expect("assistant block must exist");
         assert!(has_cache_control(last_common_block));
     }
+
+    #[test]
+    fn openrouter_gemini_prompt_caching_marks_only_the_stable_prefix() {
+        let mut payload = json!({
+            "model": "google/gemini-2.5-pro",
+            "messages": [
+                { "role": "system", "content": "sys" },
+                { "role": "user", "content": "prehistory" },
+                { "role": "assistant", "content": "a1" },
+                { "role": "user", "content": "u1" }
+            ]
+        });
+
+        apply_openrouter_gemini_prompt_caching(&mut payload, None);
+
+        let root = payload.as_object().expect("payload must be object");
+        assert!(!root.contains_key("cache_control"));
+        let messages = root
+            .get("messages")
+            .and_then(Value::as_array)
+            .expect("messages must be array");
+        let marked = messages
+            .iter()
+            .map(|message| has_cache_control(&message["content"][0]))
+            .collect::<Vec<_>>();
+        assert_eq!(marked, vec![false, true, false, false]);
+        assert_eq!(
+            messages[1]["content"][0]["cache_control"],
+            json!({ "type": "ephemeral" })
+        );
+    }
 }
@@ -2,41 +2,110 @@ use serde_json::{Map, Value};
 use sha2::{Digest, Sha256};
 
 use crate::application::errors::ApplicationError;
+use crate::domain::models::settings::PromptCacheTtl;
 use crate::domain::repositories::chat_completion_repository::{
     AnthropicBetaHeaderMode, ChatCompletionApiConfig, ChatCompletionSource,
 };
 use crate::domain::repositories::prompt_cache_repository::PromptCacheKey;
 
-use super::model_capabilities::is_openrouter_claude_model_name;
+use super::model_capabilities::{is_openrouter_claude_model_name, is_openrouter_gemini_model_name};
 
 const CUSTOM_CLAUDE_PROMPT_CACHING_FIELD: &str = "custom_claude_prompt_caching";
+pub(super) const PROMPT_CACHING_FIELD: &str = "prompt_caching";
+pub(super) const PROMPT_CACHE_TTL_FIELD: &str = "prompt_cache_ttl";
+pub(super) const PROMPT_CACHE_DEPTH_FIELD: &str = "prompt_cache_depth";
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub(super) struct PromptCachingRequestHints {
     pub custom_claude_prompt_caching: bool,
+    /// Per-request toggle. `Some(false)` skips caching even when the settings TTL is on;
+    /// `Some(true)` enables it with the default TTL when the settings TTL is off.
+    pub enabled: Option<bool>,
+    /// Per-request TTL override for the settings value.
+    pub ttl: Option<PromptCacheTtl>,
+    /// Places an explicit breakpoint on the message this many turns from the end,
+    /// replacing the digest-derived history breakpoint.
+    pub depth: Option<usize>,
 }
 
 impl PromptCachingRequestHints {
     pub(super) fn from_payload(payload: &Map<String, Value>) -> Result<Self, ApplicationError> {
-        let custom_claude_prompt_caching = payload
-            .get(CUSTOM_CLAUDE_PROMPT_CACHING_FIELD)
+        let custom_claude_prompt_caching =
+            parse_bool_field(payload, CUSTOM_CLAUDE_PROMPT_CACHING_FIELD)?.unwrap_or(false);
+        let enabled = parse_bool_field(payload, PROMPT_CACHING_FIELD)?;
+
+        let ttl = payload
+            .get(PROMPT_CACHE_TTL_FIELD)
+            .filter(|value| !value.is_null())
             .map(|value| {
-                value.as_bool().ok_or_else(|| {
+                serde_json::from_value::<PromptCacheTtl>(value.clone()).map_err(|_| {
                     ApplicationError::ValidationError(format!(
-                        "Chat completion request field must be a boolean: {}",
-                        CUSTOM_CLAUDE_PROMPT_CACHING_FIELD
+                        "Chat completion request field must be one of off, 5m, 1h: {}",
+                        PROMPT_CACHE_TTL_FIELD
                     ))
                 })
             })
-            .transpose()?
-            .unwrap_or(false);
+            .transpose()?;
+
+        let depth = payload
+            .get(PROMPT_CACHE_DEPTH_FIELD)
+            .filter(|value| !value.is_null())
+            .map(|value| {
+                value
+                    .as_u64()
+                    .and_then(|depth| usize::try_from(depth).ok())
+                    .ok_or_else(|| {
+                        ApplicationError::ValidationError(format!(
+                            "Chat completion request field must be a non-negative integer: {}",
+                            PROMPT_CACHE_DEPTH_FIELD
+                        ))
+                    })
+            })
+            .transpose()?;
 
         Ok(Self {
             custom_claude_prompt_caching,
+            enabled,
+            ttl,
+            depth,
         })
     }
+
+    /// Resolves the TTL for this request from the request overrides and the settings value.
+    pub(super) fn effective_ttl(&self, settings_ttl: PromptCacheTtl) -> PromptCacheTtl {
+        if self.enabled == Some(false) {
+            return PromptCacheTtl::Off;
+        }
+
+        let ttl = self.ttl.unwrap_or(settings_ttl);
+        if ttl == PromptCacheTtl::Off && self.enabled == Some(true) && self.ttl.is_none() {
+            return PromptCacheTtl::FiveMinutes;
+        }
+
+        ttl
+    }
+}
+
+fn parse_bool_field(
+    payload: &Map<String, Value>,
+    field: &str,
+) -> Result<Option<bool>, ApplicationError> {
+    payload
+        .get(field)
+        .map(|value| {
+            value.as_bool().ok_or_else(|| {
+                ApplicationError::ValidationError(format!(
+                    "Chat completion request field must be a boolean: {}",
+                    field
+                ))
+            })
+        })
+        .transpose()
 }
 
+/// How cache markers are placed for a request. Google AI Studio and Vertex AI have no
+/// per-request markers: Gemini caches repeated prefixes implicitly there, so those
+/// sources get no plan and only report `cachedContentTokenCount` as cached tokens.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) enum PromptCachingPlan {
     Claude {
@@ -47,6 +116,8 @@ pub(super) enum PromptCachingPlan {
         key: PromptCacheKey,
     },
     NanoGptClaude,
+    /// OpenRouter honours only the last `cache_control` breakpoint for Gemini models.
+    OpenRouterGemini,
 }
 
 pub(super) fn resolve_prompt_caching_plan(
@@ -61,10 +132,18 @@ pub(super) fn resolve_prompt_caching_plan(
             key: PromptCacheKey::Claude,
             anthropic_beta_header_mode: AnthropicBetaHeaderMode::ClaudeDefaults,
         })),
-        ChatCompletionSource::OpenRouter => Ok(is_openrouter_claude_model(upstream_payload)
-            .then_some(PromptCachingPlan::OpenRouterClaude {
-                key: PromptCacheKey::OpenRouterClaude,
-            })),
+        ChatCompletionSource::OpenRouter => {
+            let model = payload_model(upstream_payload);
+            if model.is_some_and(is_openrouter_claude_model_name) {
+                Ok(Some(PromptCachingPlan::OpenRouterClaude {
+                    key: PromptCacheKey::OpenRouterClaude,
+                }))
+            } else if model.is_some_and(is_openrouter_gemini_model_name) {
+                Ok(Some(PromptCachingPlan::OpenRouterGemini))
+            } else {
+                Ok(None)
+            }
+        }
         ChatCompletionSource::NanoGpt => {
             Ok(is_nanogpt_claude_payload(upstream_payload)
                 .then_some(PromptCachingPlan::NanoGptClaude))
@@ -106,12 +185,11 @@ fn resolve_custom_claude_prompt_caching_plan(
     }))
 }
 
-fn is_openrouter_claude_model(payload: &Value) -> bool {
+fn payload_model(payload: &Value) -> Option<&str> {
     payload
         .as_object()
         .and_then(|object| object.get("model"))
         .and_then(Value::as_str)
-        .is_some_and(is_openrouter_claude_model_name)
 }
 
 fn is_nanogpt_claude_payload(payload: &Value) -> bool {
@@ -148,8 +226,10 @@ mod tests {
         PromptCachingPlan, PromptCachingRequestHints, custom_prompt_cache_scope,
         resolve_prompt_caching_plan,
     };
+    use crate::domain::models::settings::PromptCacheTtl;
     use crate::domain::repositories::chat_completion_repository::{
         AnthropicBetaHeaderMode, ChatCompletionApiConfig, ChatCompletionSource,
+        ChatCompletionTimeouts,
     };
     use crate::domain::repositories::prompt_cache_repository::PromptCacheKey;
     use serde_json::{Map, json};
@@ -164,6 +244,8 @@ mod tests {
             anthropic_beta_header_mode: AnthropicBetaHeaderMode::None,
             aws_bedrock_custom_response_path: None,
             aws_bedrock_custom_stream_path: None,
+            timeouts: ChatCompletionTimeouts::default(),
+            accept_invalid_certs: false,
         }
     }
 
@@ -175,11 +257,47 @@ mod tests {
         assert!(hints.custom_claude_prompt_caching);
     }
 
+    #[test]
+    fn per_request_prompt_cache_overrides_resolve_against_settings() {
+        let payload = Map::from_iter([
+            ("prompt_cache_ttl".to_string(), json!("1h")),
+            ("prompt_cache_depth".to_string(), json!(2)),
+        ]);
+        let hints = PromptCachingRequestHints::from_payload(&payload).expect("hints should parse");
+        assert_eq!(hints.depth, Some(2));
+        assert_eq!(
+            hints.effective_ttl(PromptCacheTtl::Off),
+            PromptCacheTtl::OneHour
+        );
+
+        let disabled = PromptCachingRequestHints {
+            enabled: Some(false),
+            ..PromptCachingRequestHints::default()
+        };
+        assert_eq!(
+            disabled.effective_ttl(PromptCacheTtl::OneHour),
+            PromptCacheTtl::Off
+        );
+
+        let enabled = PromptCachingRequestHints {
+            enabled: Some(true),
+            ..PromptCachingRequestHints::default()
+        };
+        assert_eq!(
+            enabled.effective_ttl(PromptCacheTtl::Off),
+            PromptCacheTtl::FiveMinutes
+        );
+
+        let invalid = Map::from_iter([("prompt_cache_ttl".to_string(), json!("2h"))]);
+        assert!(PromptCachingRequestHints::from_payload(&invalid).is_err());
+    }
+
     #[test]
     fn custom_claude_prompt_caching_requires_messages_endpoint() {
         let config = custom_config("https://example.com/v1");
         let hints = PromptCachingRequestHints {
             custom_claude_prompt_caching: true,
+            ..PromptCachingRequestHints::default()
         };
 
         let error = resolve_prompt_caching_plan(
@@ -204,6 +322,7 @@ mod tests {
         let config = custom_config(base_url);
         let hints = PromptCachingRequestHints {
             custom_claude_prompt_caching: true,
+            ..PromptCachingRequestHints::default()
         };
 
         let plan = resolve_prompt_caching_plan(
@@ -249,4 +368,22 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn openrouter_gemini_models_use_single_breakpoint_strategy() {
+        let config = custom_config("https://openrouter.ai/api/v1");
+
+        let plan = resolve_prompt_caching_plan(
+            ChatCompletionSource::OpenRouter,
+            "/chat/completions",
+            &config,
+            &json!({
+                "model": "google/gemini-2.5-pro"
+            }),
+            PromptCachingRequestHints::default(),
+        )
+        .expect("resolution should succeed");
+
+        assert_eq!(plan, Some(PromptCachingPlan::OpenRouterGemini));
+    }
 }
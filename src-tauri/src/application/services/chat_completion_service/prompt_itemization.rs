@@ -0,0 +1,177 @@
+use std::collections::VecDeque;
+
+use serde_json::{Map, Value};
+use tokio::sync::RwLock;
+
+use crate::application::dto::chat_completion_dto::{
+    PromptItemizationDto, PromptItemizationHintsDto, PromptItemizedMessageDto,
+    PromptItemizedSegmentDto,
+};
+use crate::application::errors::ApplicationError;
+
+/// Request field carrying itemizer annotations. Its presence opts the request into
+/// itemization; it is always stripped before the provider payload is built.
+pub(super) const PROMPT_ITEMIZATION_FIELD: &str = "_tauritavern_prompt_itemization";
+const MAX_STORED_ITEMIZATIONS: usize = 32;
+
+pub(super) fn take_prompt_itemization_hints(
+    payload: &mut Map<String, Value>,
+) -> Result<Option<PromptItemizationHintsDto>, ApplicationError> {
+    let Some(hints) = payload.remove(PROMPT_ITEMIZATION_FIELD) else {
+        return Ok(None);
+    };
+
+    serde_json::from_value(hints).map(Some).map_err(|error| {
+        ApplicationError::ValidationError(format!(
+            "Invalid chat completion request field {PROMPT_ITEMIZATION_FIELD}: {error}"
+        ))
+    })
+}
+
+/// Role and text of each request message, joining the text parts of multi-part content.
+pub(super) fn message_roles_and_texts(payload: &Map<String, Value>) -> Vec<(String, String)> {
+    let Some(messages) = payload.get("messages").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+
+    messages
+        .iter()
+        .map(|message| {
+            let role = message
+                .get("role")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let text = match message.get("content") {
+                Some(Value::String(content)) => content.clone(),
+                Some(Value::Array(parts)) => parts
+                    .iter()
+                    .filter_map(|part| part.get("text").and_then(Value::as_str))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                _ => String::new(),
+            };
+            (role, text)
+        })
+        .collect()
+}
+
+/// Builds the report from per-message token counts followed by per-item counts, in the
+/// order of `messages` and `hints.items`.
+pub(super) fn build_itemization(
+    hints: PromptItemizationHintsDto,
+    model: String,
+    roles: Vec<String>,
+    token_counts: &[usize],
+) -> PromptItemizationDto {
+    let (message_tokens, item_tokens) = token_counts.split_at(roles.len().min(token_counts.len()));
+
+    let mut itemization = PromptItemizationDto {
+        request_id: hints.request_id,
+        model,
+        messages: Vec::with_capacity(roles.len()),
+        items: Vec::with_capacity(hints.items.len()),
+        system_tokens: 0,
+        history_tokens: 0,
+        history_messages: 0,
+        trimmed_history_messages: hints.trimmed_history_messages,
+        total_tokens: 0,
+    };
+
+    for (index, (role, tokens)) in roles.into_iter().zip(message_tokens).enumerate() {
+        if matches!(role.as_str(), "system" | "developer") {
+            itemization.system_tokens += tokens;
+        } else {
+            itemization.history_tokens += tokens;
+            itemization.history_messages += 1;
+        }
+        itemization.total_tokens += tokens;
+        itemization.messages.push(PromptItemizedMessageDto {
+            index,
+            role,
+            tokens: *tokens,
+        });
+    }
+
+    for (item, tokens) in hints.items.into_iter().zip(item_tokens) {
+        itemization.items.push(PromptItemizedSegmentDto {
+            kind: item.kind,
+            label: item.label,
+            tokens: *tokens,
+        });
+    }
+
+    itemization
+}
+
+/// Most recent itemization reports, oldest evicted first.
+#[derive(Default)]
+pub(super) struct PromptItemizationStore {
+    entries: RwLock<VecDeque<PromptItemizationDto>>,
+}
+
+impl PromptItemizationStore {
+    pub(super) async fn insert(&self, itemization: PromptItemizationDto) {
+        let mut entries = self.entries.write().await;
+        entries.retain(|entry| entry.request_id != itemization.request_id);
+        if entries.len() >= MAX_STORED_ITEMIZATIONS {
+            entries.pop_front();
+        }
+        entries.push_back(itemization);
+    }
+
+    pub(super) async fn get(&self, request_id: &str) -> Option<PromptItemizationDto> {
+        let entries = self.entries.read().await;
+        entries
+            .iter()
+            .find(|entry| entry.request_id == request_id)
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{
+        PROMPT_ITEMIZATION_FIELD, build_itemization, message_roles_and_texts,
+        take_prompt_itemization_hints,
+    };
+
+    #[test]
+    fn itemizes_messages_and_hinted_segments() {
+        let Value::Object(mut payload) = json!({
+            "messages": [
+                { "role": "system", "content": "Main prompt\nWorld info" },
+                { "role": "user", "content": [{ "type": "text", "text": "Hello" }] },
+                { "role": "assistant", "content": "Hi there" },
+            ],
+            PROMPT_ITEMIZATION_FIELD: {
+                "requestId": "gen-1",
+                "items": [{ "kind": "world_info", "label": "Castle", "text": "World info" }],
+                "trimmedHistoryMessages": 4,
+            },
+        }) else {
+            unreachable!();
+        };
+
+        let hints = take_prompt_itemization_hints(&mut payload)
+            .unwrap()
+            .expect("hints present");
+        assert!(!payload.contains_key(PROMPT_ITEMIZATION_FIELD));
+
+        let (roles, texts): (Vec<_>, Vec<_>) =
+            message_roles_and_texts(&payload).into_iter().unzip();
+        assert_eq!(texts[1], "Hello");
+
+        let itemization = build_itemization(hints, "gpt-4o".to_string(), roles, &[12, 3, 4, 5]);
+        assert_eq!(itemization.request_id, "gen-1");
+        assert_eq!(itemization.system_tokens, 12);
+        assert_eq!(itemization.history_tokens, 7);
+        assert_eq!(itemization.history_messages, 2);
+        assert_eq!(itemization.trimmed_history_messages, 4);
+        assert_eq!(itemization.total_tokens, 19);
+        assert_eq!(itemization.items[0].label, "Castle");
+        assert_eq!(itemization.items[0].tokens, 5);
+    }
+}
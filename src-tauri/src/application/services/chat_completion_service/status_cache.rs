@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use tokio::sync::RwLock;
+
+use crate::domain::repositories::chat_completion_repository::ChatCompletionSource;
+
+/// Model lists are keyed by the requested source, the transport used to list them
+/// (custom sources pick it from their API format) and the resolved base URL.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct StatusCacheKey {
+    source: &'static str,
+    model_list_source: &'static str,
+    base_url: String,
+}
+
+struct CachedStatus {
+    stored_at: Instant,
+    models: Value,
+}
+
+/// Short-lived cache of `get_status` results so reopening the connection panel does
+/// not hit the provider's models endpoint every time.
+#[derive(Default)]
+pub(super) struct StatusCache {
+    entries: RwLock<HashMap<StatusCacheKey, CachedStatus>>,
+}
+
+impl StatusCache {
+    fn key(
+        source: ChatCompletionSource,
+        model_list_source: ChatCompletionSource,
+        base_url: &str,
+    ) -> StatusCacheKey {
+        StatusCacheKey {
+            source: source.key(),
+            model_list_source: model_list_source.key(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub(super) async fn get(
+        &self,
+        source: ChatCompletionSource,
+        model_list_source: ChatCompletionSource,
+        base_url: &str,
+        ttl: Duration,
+    ) -> Option<Value> {
+        if ttl.is_zero() {
+            return None;
+        }
+
+        let key = Self::key(source, model_list_source, base_url);
+        let entries = self.entries.read().await;
+        let cached = entries.get(&key)?;
+        (cached.stored_at.elapsed() < ttl).then(|| cached.models.clone())
+    }
+
+    pub(super) async fn insert(
+        &self,
+        source: ChatCompletionSource,
+        model_list_source: ChatCompletionSource,
+        base_url: &str,
+        ttl: Duration,
+        models: &Value,
+    ) {
+        let mut entries = self.entries.write().await;
+        entries.retain(|_, cached| cached.stored_at.elapsed() < ttl);
+        if ttl.is_zero() {
+            return;
+        }
+
+        entries.insert(
+            Self::key(source, model_list_source, base_url),
+            CachedStatus {
+                stored_at: Instant::now(),
+                models: models.clone(),
+            },
+        );
+    }
+
+    pub(super) async fn clear(&self) {
+        self.entries.write().await.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::StatusCache;
+    use crate::domain::repositories::chat_completion_repository::ChatCompletionSource;
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    #[tokio::test]
+    async fn cached_models_are_keyed_by_source_and_base_url() {
+        let cache = StatusCache::default();
+        let source = ChatCompletionSource::OpenAi;
+        let models = json!({ "data": [{ "id": "gpt-4o" }] });
+
+        cache
+            .insert(source, source, "https://api.openai.com/v1/", TTL, &models)
+            .await;
+
+        assert_eq!(
+            cache
+                .get(source, source, "https://api.openai.com/v1", TTL)
+                .await,
+            Some(models)
+        );
+        assert!(
+            cache
+                .get(source, source, "https://proxy.example/v1", TTL)
+                .await
+                .is_none()
+        );
+        assert!(
+            cache
+                .get(
+                    ChatCompletionSource::Custom,
+                    source,
+                    "https://api.openai.com/v1",
+                    TTL
+                )
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn zero_ttl_and_clear_drop_cached_models() {
+        let cache = StatusCache::default();
+        let source = ChatCompletionSource::OpenRouter;
+        let base_url = "https://openrouter.ai/api/v1";
+        let models = json!({ "data": [] });
+
+        cache.insert(source, source, base_url, TTL, &models).await;
+        assert!(
+            cache
+                .get(source, source, base_url, Duration::ZERO)
+                .await
+                .is_none()
+        );
+
+        cache.clear().await;
+        assert!(cache.get(source, source, base_url, TTL).await.is_none());
+    }
+}
@@ -0,0 +1,144 @@
+use serde_json::{Map, Value};
+
+use crate::application::dto::tokenization_dto::{LogitBiasEntryDto, OpenAiLogitBiasResponseDto};
+use crate::domain::models::settings::StopBiasRules;
+use crate::domain::repositories::chat_completion_repository::ChatCompletionSource;
+
+/// Most stop sequences the provider accepts per request, when it enforces a limit.
+pub(super) fn stop_sequence_limit(source: ChatCompletionSource) -> Option<usize> {
+    match source {
+        ChatCompletionSource::OpenAi | ChatCompletionSource::Claude => Some(4),
+        ChatCompletionSource::Makersuite | ChatCompletionSource::VertexAi => Some(5),
+        _ => None,
+    }
+}
+
+/// Only OpenAI takes `logit_bias`, and it is keyed by token ids of the model's tokenizer.
+pub(super) fn supports_logit_bias(source: ChatCompletionSource) -> bool {
+    matches!(source, ChatCompletionSource::OpenAi)
+}
+
+/// Puts the request's own stop strings first, then the rules from most to least
+/// specific, dropping duplicates and whatever exceeds `limit`.
+pub(super) fn merge_stop_strings(
+    payload: &mut Map<String, Value>,
+    rules: &[&StopBiasRules],
+    limit: Option<usize>,
+) {
+    let mut stops: Vec<String> = match payload.get("stop") {
+        Some(Value::String(stop)) => vec![stop.clone()],
+        Some(Value::Array(stops)) => stops
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    };
+    for stop in rules.iter().rev().flat_map(|rules| &rules.stop_strings) {
+        if !stops.contains(stop) {
+            stops.push(stop.clone());
+        }
+    }
+    if let Some(limit) = limit {
+        stops.truncate(limit);
+    }
+
+    if stops.is_empty() {
+        return;
+    }
+    payload.insert(
+        "stop".to_string(),
+        Value::Array(stops.into_iter().map(Value::String).collect()),
+    );
+}
+
+/// Bias entries from the least to the most specific rules, so later entries win when
+/// they resolve to the same token.
+pub(super) fn logit_bias_entries(rules: &[&StopBiasRules]) -> Vec<LogitBiasEntryDto> {
+    rules
+        .iter()
+        .flat_map(|rules| &rules.logit_bias)
+        .map(|rule| LogitBiasEntryDto {
+            text: rule.text.clone(),
+            value: rule.value,
+        })
+        .collect()
+}
+
+/// Adds the resolved token biases under the request's `logit_bias`; tokens the request
+/// already biases keep the request's value.
+pub(super) fn merge_logit_bias(payload: &mut Map<String, Value>, bias: OpenAiLogitBiasResponseDto) {
+    if bias.is_empty() {
+        return;
+    }
+    if !payload.get("logit_bias").is_some_and(Value::is_object) {
+        payload.insert("logit_bias".to_string(), Value::Object(Map::new()));
+    }
+    let Some(Value::Object(request_bias)) = payload.get_mut("logit_bias") else {
+        return;
+    };
+
+    for (token, value) in bias {
+        request_bias
+            .entry(token)
+            .or_insert_with(|| Value::from(value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::{Value, json};
+
+    use super::{logit_bias_entries, merge_logit_bias, merge_stop_strings};
+    use crate::domain::models::settings::{LogitBiasRule, StopBiasRules};
+
+    fn rules(stops: &[&str], bias: &[(&str, f32)]) -> StopBiasRules {
+        StopBiasRules {
+            stop_strings: stops.iter().map(|stop| stop.to_string()).collect(),
+            logit_bias: bias
+                .iter()
+                .map(|(text, value)| LogitBiasRule {
+                    text: text.to_string(),
+                    value: *value,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn stop_strings_keep_request_and_specific_rules_within_the_limit() {
+        let global = rules(&["\nUser:", "###"], &[]);
+        let character = rules(&["\nAlice:", "###"], &[]);
+        let Value::Object(mut payload) = json!({ "stop": "\nUser:" }) else {
+            unreachable!();
+        };
+
+        merge_stop_strings(&mut payload, &[&global, &character], Some(3));
+        assert_eq!(payload["stop"], json!(["\nUser:", "\nAlice:", "###"]));
+
+        let Value::Object(mut payload) = json!({}) else {
+            unreachable!();
+        };
+        merge_stop_strings(&mut payload, &[&StopBiasRules::default()], None);
+        assert!(!payload.contains_key("stop"));
+    }
+
+    #[test]
+    fn logit_bias_keeps_request_values_and_orders_specific_rules_last() {
+        let global = rules(&[], &[("sorry", -50.0)]);
+        let model = rules(&[], &[("sorry", -100.0)]);
+        let entries = logit_bias_entries(&[&global, &model]);
+        assert_eq!(entries.last().unwrap().value, -100.0);
+
+        let Value::Object(mut payload) = json!({ "logit_bias": { "42": 5 } }) else {
+            unreachable!();
+        };
+        merge_logit_bias(
+            &mut payload,
+            HashMap::from([("42".to_string(), -100.0), ("7".to_string(), 10.0)]),
+        );
+        assert_eq!(payload["logit_bias"], json!({ "42": 5, "7": 10.0 }));
+    }
+}
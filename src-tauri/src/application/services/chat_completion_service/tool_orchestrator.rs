@@ -0,0 +1,752 @@
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+use serde_json::{Map, Value, json};
+use tokio::sync::{Mutex, RwLock, mpsc::UnboundedSender, oneshot};
+
+use crate::application::dto::chat_completion_dto::{
+    ChatCompletionToolRunEventDto, LocalToolDefinitionDto, LocalToolHandlerDto,
+    RegisterLocalToolDto,
+};
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::chat_completion_repository::ChatCompletionCancelReceiver;
+
+const DEFAULT_MAX_TOOL_ROUNDS: u32 = 8;
+const MAX_TOOL_ROUNDS_LIMIT: u32 = 32;
+const EXTENSION_TOOL_TIMEOUT: Duration = Duration::from_secs(120);
+const MAX_TOOL_NAME_LENGTH: usize = 64;
+
+pub type ChatCompletionToolRunEventSender = UnboundedSender<ChatCompletionToolRunEventDto>;
+
+/// Tools implemented natively by the backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BuiltinLocalTool {
+    CurrentTime,
+}
+
+impl BuiltinLocalTool {
+    fn parse(raw: &str) -> Option<Self> {
+        match raw.trim() {
+            "current_time" => Some(Self::CurrentTime),
+            _ => None,
+        }
+    }
+
+    const fn key(self) -> &'static str {
+        match self {
+            Self::CurrentTime => "current_time",
+        }
+    }
+
+    fn execute(self, _arguments: &Value) -> Result<String, String> {
+        match self {
+            Self::CurrentTime => {
+                let now = chrono::Local::now();
+                Ok(json!({
+                    "local": now.to_rfc3339(),
+                    "utc": now.with_timezone(&chrono::Utc).to_rfc3339(),
+                    "unix": now.timestamp(),
+                })
+                .to_string())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum LocalToolHandler {
+    Builtin(BuiltinLocalTool),
+    /// Executed by the owning frontend extension; the orchestrator waits for
+    /// `submit_tool_result` to deliver the output.
+    ExtensionScript {
+        extension: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct LocalToolDefinition {
+    name: String,
+    description: String,
+    parameters: Value,
+    handler: LocalToolHandler,
+}
+
+impl LocalToolDefinition {
+    pub(super) fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn from_dto(dto: RegisterLocalToolDto) -> Result<Self, ApplicationError> {
+        let name = dto.name.trim().to_string();
+        validate_tool_name(&name)?;
+
+        let parameters = match dto.parameters {
+            Value::Null => json!({ "type": "object", "properties": {} }),
+            Value::Object(object) => Value::Object(object),
+            _ => {
+                return Err(ApplicationError::ValidationError(format!(
+                    "Tool `{name}` parameters must be a JSON schema object"
+                )));
+            }
+        };
+
+        let handler = match dto.handler {
+            LocalToolHandlerDto::Builtin { builtin } => BuiltinLocalTool::parse(&builtin)
+                .map(LocalToolHandler::Builtin)
+                .ok_or_else(|| {
+                    ApplicationError::ValidationError(format!(
+                        "Unknown builtin tool: {}",
+                        builtin.trim()
+                    ))
+                })?,
+            LocalToolHandlerDto::Extension { extension } => {
+                let extension = extension.trim().to_string();
+                if extension.is_empty() {
+                    return Err(ApplicationError::ValidationError(format!(
+                        "Tool `{name}` must name the extension that executes it"
+                    )));
+                }
+                LocalToolHandler::ExtensionScript { extension }
+            }
+        };
+
+        Ok(Self {
+            name,
+            description: dto.description.trim().to_string(),
+            parameters,
+            handler,
+        })
+    }
+
+    fn to_openai_tool(&self) -> Value {
+        json!({
+            "type": "function",
+            "function": {
+                "name": self.name,
+                "description": self.description,
+                "parameters": self.parameters,
+            }
+        })
+    }
+
+    fn to_dto(&self) -> LocalToolDefinitionDto {
+        let handler = match &self.handler {
+            LocalToolHandler::Builtin(builtin) => LocalToolHandlerDto::Builtin {
+                builtin: builtin.key().to_string(),
+            },
+            LocalToolHandler::ExtensionScript { extension } => LocalToolHandlerDto::Extension {
+                extension: extension.clone(),
+            },
+        };
+
+        LocalToolDefinitionDto {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            parameters: self.parameters.clone(),
+            handler,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct RequestedToolCall {
+    pub id: String,
+    pub name: String,
+    pub arguments: Value,
+}
+
+#[derive(Debug, Clone)]
+struct ToolCallOutcome {
+    content: String,
+    is_error: bool,
+}
+
+/// Registry of backend-visible local tools plus the pending handshakes for
+/// tools that are executed by frontend extension scripts.
+#[derive(Default)]
+pub(super) struct ToolCallOrchestrator {
+    tools: RwLock<BTreeMap<String, LocalToolDefinition>>,
+    pending_results: Mutex<HashMap<String, oneshot::Sender<ToolCallOutcome>>>,
+}
+
+impl ToolCallOrchestrator {
+    pub(super) async fn register(
+        &self,
+        dto: RegisterLocalToolDto,
+    ) -> Result<LocalToolDefinitionDto, ApplicationError> {
+        let definition = LocalToolDefinition::from_dto(dto)?;
+        let dto = definition.to_dto();
+        self.tools
+            .write()
+            .await
+            .insert(definition.name.clone(), definition);
+        Ok(dto)
+    }
+
+    pub(super) async fn unregister(&self, name: &str) -> bool {
+        self.tools.write().await.remove(name.trim()).is_some()
+    }
+
+    pub(super) async fn list(&self) -> Vec<LocalToolDefinitionDto> {
+        self.tools
+            .read()
+            .await
+            .values()
+            .map(LocalToolDefinition::to_dto)
+            .collect()
+    }
+
+    pub(super) async fn resolve_tools(
+        &self,
+        tool_names: Option<&[String]>,
+    ) -> Result<Vec<LocalToolDefinition>, ApplicationError> {
+        let tools = self.tools.read().await;
+        let Some(tool_names) = tool_names else {
+            return Ok(tools.values().cloned().collect());
+        };
+
+        tool_names
+            .iter()
+            .map(|name| {
+                tools.get(name.trim()).cloned().ok_or_else(|| {
+                    ApplicationError::NotFound(format!("Local tool is not registered: {name}"))
+                })
+            })
+            .collect()
+    }
+
+    pub(super) async fn submit_result(
+        &self,
+        run_id: &str,
+        call_id: &str,
+        content: String,
+        is_error: bool,
+    ) -> Result<(), ApplicationError> {
+        let key = pending_key(run_id, call_id);
+        let sender = self
+            .pending_results
+            .lock()
+            .await
+            .remove(&key)
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "No pending tool call {call_id} for run {run_id}"
+                ))
+            })?;
+
+        sender
+            .send(ToolCallOutcome { content, is_error })
+            .map_err(|_| {
+                ApplicationError::Cancelled(format!("Tool run {run_id} is no longer waiting"))
+            })
+    }
+
+    /// Runs one round of tool calls in order and returns their outputs. Each call sends
+    /// `ToolCallStarted`, then `ToolCallCompleted` or, when it ends without a result,
+    /// `ToolCallFailed`; the first failure stops the round.
+    pub(super) async fn run_round(
+        &self,
+        run_id: &str,
+        tools: &[LocalToolDefinition],
+        calls: Vec<RequestedToolCall>,
+        events: &ChatCompletionToolRunEventSender,
+        cancel: &mut ChatCompletionCancelReceiver,
+    ) -> Result<Vec<(RequestedToolCall, String)>, ApplicationError> {
+        let mut results = Vec::with_capacity(calls.len());
+        for call in calls {
+            let tool = tools
+                .iter()
+                .find(|tool| tool.name() == call.name)
+                .ok_or_else(|| {
+                    ApplicationError::NotFound(format!(
+                        "Local tool is not registered: {}",
+                        call.name
+                    ))
+                })?;
+            let content = self.execute(run_id, tool, &call, events, cancel).await?;
+            results.push((call, content));
+        }
+
+        Ok(results)
+    }
+
+    async fn execute(
+        &self,
+        run_id: &str,
+        tool: &LocalToolDefinition,
+        call: &RequestedToolCall,
+        events: &ChatCompletionToolRunEventSender,
+        cancel: &mut ChatCompletionCancelReceiver,
+    ) -> Result<String, ApplicationError> {
+        let _ = events.send(ChatCompletionToolRunEventDto::ToolCallStarted {
+            call_id: call.id.clone(),
+            name: call.name.clone(),
+            arguments: call.arguments.clone(),
+        });
+
+        match self.invoke(run_id, tool, call, events, cancel).await {
+            Ok((content, is_error)) => {
+                let _ = events.send(ChatCompletionToolRunEventDto::ToolCallCompleted {
+                    call_id: call.id.clone(),
+                    name: call.name.clone(),
+                    content: content.clone(),
+                    is_error,
+                });
+                Ok(content)
+            }
+            Err(error) => {
+                let _ = events.send(ChatCompletionToolRunEventDto::ToolCallFailed {
+                    call_id: call.id.clone(),
+                    name: call.name.clone(),
+                    message: error.to_string(),
+                });
+                Err(error)
+            }
+        }
+    }
+
+    async fn invoke(
+        &self,
+        run_id: &str,
+        tool: &LocalToolDefinition,
+        call: &RequestedToolCall,
+        events: &ChatCompletionToolRunEventSender,
+        cancel: &mut ChatCompletionCancelReceiver,
+    ) -> Result<(String, bool), ApplicationError> {
+        match &tool.handler {
+            LocalToolHandler::Builtin(builtin) => Ok(match builtin.execute(&call.arguments) {
+                Ok(content) => (content, false),
+                Err(message) => (message, true),
+            }),
+            LocalToolHandler::ExtensionScript { extension } => {
+                let key = pending_key(run_id, &call.id);
+                let (sender, receiver) = oneshot::channel();
+                self.pending_results
+                    .lock()
+                    .await
+                    .insert(key.clone(), sender);
+
+                let _ = events.send(ChatCompletionToolRunEventDto::ToolCallRequested {
+                    call_id: call.id.clone(),
+                    name: call.name.clone(),
+                    arguments: call.arguments.clone(),
+                    extension: extension.clone(),
+                });
+
+                let outcome = tokio::select! {
+                    outcome = tokio::time::timeout(EXTENSION_TOOL_TIMEOUT, receiver) => outcome,
+                    _ = wait_for_cancel(cancel) => {
+                        self.pending_results.lock().await.remove(&key);
+                        return Err(DomainError::generation_cancelled_by_user().into());
+                    }
+                };
+
+                match outcome {
+                    Ok(Ok(outcome)) => Ok((outcome.content, outcome.is_error)),
+                    Ok(Err(_)) => Err(ApplicationError::InternalError(format!(
+                        "Tool call {} was dropped before a result arrived",
+                        call.id
+                    ))),
+                    Err(_) => {
+                        self.pending_results.lock().await.remove(&key);
+                        Ok((
+                            format!(
+                                "Tool `{}` timed out after {} seconds",
+                                call.name,
+                                EXTENSION_TOOL_TIMEOUT.as_secs()
+                            ),
+                            true,
+                        ))
+                    }
+                }
+            }
+        }
+    }
+
+    pub(super) async fn discard_pending(&self, run_id: &str) {
+        let prefix = format!("{run_id}:");
+        self.pending_results
+            .lock()
+            .await
+            .retain(|key, _| !key.starts_with(&prefix));
+    }
+}
+
+pub(super) fn resolve_max_rounds(requested: Option<u32>) -> Result<u32, ApplicationError> {
+    let rounds = requested.unwrap_or(DEFAULT_MAX_TOOL_ROUNDS);
+    if rounds == 0 || rounds > MAX_TOOL_ROUNDS_LIMIT {
+        return Err(ApplicationError::ValidationError(format!(
+            "max_rounds must be between 1 and {MAX_TOOL_ROUNDS_LIMIT}"
+        )));
+    }
+
+    Ok(rounds)
+}
+
+/// Appends the registered tools to the request `tools` array, skipping names
+/// the caller already supplied so frontend-provided definitions win.
+pub(super) fn inject_local_tools(
+    payload: &mut Map<String, Value>,
+    tools: &[LocalToolDefinition],
+) -> Result<(), ApplicationError> {
+    if tools.is_empty() {
+        return Ok(());
+    }
+
+    let entry = payload
+        .entry("tools".to_string())
+        .or_insert_with(|| Value::Array(Vec::new()));
+    let Some(existing) = entry.as_array_mut() else {
+        return Err(ApplicationError::ValidationError(
+            "Chat completion request field must be an array: tools".to_string(),
+        ));
+    };
+
+    for tool in tools {
+        let already_declared = existing.iter().any(|value| {
+            value.pointer("/function/name").and_then(Value::as_str) == Some(tool.name.as_str())
+        });
+        if !already_declared {
+            existing.push(tool.to_openai_tool());
+        }
+    }
+
+    Ok(())
+}
+
+pub(super) fn extract_requested_tool_calls(
+    assistant_message: &Map<String, Value>,
+) -> Vec<RequestedToolCall> {
+    let Some(calls) = assistant_message
+        .get("tool_calls")
+        .and_then(Value::as_array)
+    else {
+        return Vec::new();
+    };
+
+    calls
+        .iter()
+        .filter_map(|call| {
+            let id = call.get("id").and_then(Value::as_str)?.trim().to_string();
+            let name = call
+                .pointer("/function/name")
+                .and_then(Value::as_str)?
+                .trim()
+                .to_string();
+            if id.is_empty() || name.is_empty() {
+                return None;
+            }
+
+            let arguments = match call.pointer("/function/arguments") {
+                Some(Value::String(raw)) if raw.trim().is_empty() => json!({}),
+                Some(Value::String(raw)) => {
+                    serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.clone()))
+                }
+                Some(value) => value.clone(),
+                None => json!({}),
+            };
+
+            Some(RequestedToolCall {
+                id,
+                name,
+                arguments,
+            })
+        })
+        .collect()
+}
+
+/// Records the assistant turn and tool outputs in OpenAI chat format; the
+/// provider payload builders translate them for Claude and Gemini.
+pub(super) fn append_tool_round(
+    payload: &mut Map<String, Value>,
+    assistant_message: &Map<String, Value>,
+    results: Vec<(RequestedToolCall, String)>,
+) -> Result<(), ApplicationError> {
+    let Some(messages) = payload.get_mut("messages").and_then(Value::as_array_mut) else {
+        return Err(ApplicationError::ValidationError(
+            "Tool orchestration requires a messages array".to_string(),
+        ));
+    };
+
+    let mut assistant = assistant_message.clone();
+    assistant.insert("role".to_string(), Value::String("assistant".to_string()));
+    if assistant.get("content").is_none_or(Value::is_null) {
+        assistant.insert("content".to_string(), Value::String(String::new()));
+    }
+    messages.push(Value::Object(assistant));
+
+    for (call, content) in results {
+        messages.push(json!({
+            "role": "tool",
+            "tool_call_id": call.id,
+            "name": call.name,
+            "content": content,
+        }));
+    }
+
+    Ok(())
+}
+
+async fn wait_for_cancel(cancel: &mut ChatCompletionCancelReceiver) {
+    loop {
+        if *cancel.borrow() {
+            return;
+        }
+        if cancel.changed().await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+fn pending_key(run_id: &str, call_id: &str) -> String {
+    format!("{run_id}:{call_id}")
+}
+
+fn validate_tool_name(name: &str) -> Result<(), ApplicationError> {
+    if name.is_empty() || name.len() > MAX_TOOL_NAME_LENGTH {
+        return Err(ApplicationError::ValidationError(format!(
+            "Tool name must be 1-{MAX_TOOL_NAME_LENGTH} characters"
+        )));
+    }
+
+    if !name
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-')
+    {
+        return Err(ApplicationError::ValidationError(format!(
+            "Tool name may only contain ASCII letters, digits, '_' and '-': {name}"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::{Map, Value, json};
+    use tokio::sync::{mpsc::unbounded_channel, watch};
+
+    use super::*;
+
+    fn extension_tool(name: &str) -> LocalToolDefinition {
+        LocalToolDefinition::from_dto(RegisterLocalToolDto {
+            name: name.to_string(),
+            description: "Looks things up".to_string(),
+            parameters: json!({"type": "object", "properties": {"q": {"type": "string"}}}),
+            handler: LocalToolHandlerDto::Extension {
+                extension: "third-party/search".to_string(),
+            },
+        })
+        .expect("tool should be valid")
+    }
+
+    fn builtin_tool(builtin: &str) -> LocalToolDefinition {
+        LocalToolDefinition::from_dto(RegisterLocalToolDto {
+            name: builtin.to_string(),
+            description: String::new(),
+            parameters: Value::Null,
+            handler: LocalToolHandlerDto::Builtin {
+                builtin: builtin.to_string(),
+            },
+        })
+        .expect("tool should be valid")
+    }
+
+    fn requested_call(id: &str, name: &str) -> RequestedToolCall {
+        RequestedToolCall {
+            id: id.to_string(),
+            name: name.to_string(),
+            arguments: json!({"q": "tea"}),
+        }
+    }
+
+    fn event_summary(event: &ChatCompletionToolRunEventDto) -> (String, String) {
+        let event = serde_json::to_value(event).expect("event should serialize");
+        (
+            event["type"].as_str().unwrap_or_default().to_string(),
+            event["call_id"].as_str().unwrap_or_default().to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn run_round_reports_every_call_in_order() {
+        let orchestrator = Arc::new(ToolCallOrchestrator::default());
+        let tools = [builtin_tool("current_time"), extension_tool("lookup")];
+        let (events, mut receiver) = unbounded_channel();
+        let (_cancel_sender, mut cancel) = watch::channel(false);
+
+        // Plays the extension: answers each request the way `submit_tool_result` does.
+        let extension = tokio::spawn({
+            let orchestrator = orchestrator.clone();
+            async move {
+                let mut seen = Vec::new();
+                while let Some(event) = receiver.recv().await {
+                    if let ChatCompletionToolRunEventDto::ToolCallRequested { call_id, .. } = &event
+                    {
+                        orchestrator
+                            .submit_result("run-1", call_id, "green tea".to_string(), false)
+                            .await
+                            .expect("result should be accepted");
+                    }
+                    seen.push(event_summary(&event));
+                }
+                seen
+            }
+        });
+
+        let results = orchestrator
+            .run_round(
+                "run-1",
+                &tools,
+                vec![
+                    requested_call("call_1", "current_time"),
+                    requested_call("call_2", "lookup"),
+                ],
+                &events,
+                &mut cancel,
+            )
+            .await
+            .expect("round should complete");
+        drop(events);
+        let seen = extension.await.expect("extension task");
+
+        let expected = [
+            ("tool_call_started", "call_1"),
+            ("tool_call_completed", "call_1"),
+            ("tool_call_started", "call_2"),
+            ("tool_call_requested", "call_2"),
+            ("tool_call_completed", "call_2"),
+        ]
+        .map(|(kind, call_id)| (kind.to_string(), call_id.to_string()));
+        assert_eq!(seen, expected);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].1.contains("\"utc\""));
+        assert_eq!(results[1].1, "green tea");
+    }
+
+    #[tokio::test]
+    async fn cancelled_extension_call_reports_failure() {
+        let orchestrator = ToolCallOrchestrator::default();
+        let (events, mut receiver) = unbounded_channel();
+        let (cancel_sender, mut cancel) = watch::channel(false);
+        cancel_sender.send(true).expect("cancel receiver alive");
+
+        let result = orchestrator
+            .run_round(
+                "run-1",
+                &[extension_tool("lookup")],
+                vec![requested_call("call_1", "lookup")],
+                &events,
+                &mut cancel,
+            )
+            .await;
+        drop(events);
+
+        assert!(result.is_err());
+        let mut seen = Vec::new();
+        while let Some(event) = receiver.recv().await {
+            seen.push(event_summary(&event).0);
+        }
+        assert_eq!(
+            seen,
+            [
+                "tool_call_started",
+                "tool_call_requested",
+                "tool_call_failed"
+            ]
+        );
+        assert!(matches!(
+            orchestrator
+                .submit_result("run-1", "call_1", String::new(), false)
+                .await,
+            Err(ApplicationError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn register_rejects_invalid_names() {
+        let result = LocalToolDefinition::from_dto(RegisterLocalToolDto {
+            name: "bad name".to_string(),
+            description: String::new(),
+            parameters: Value::Null,
+            handler: LocalToolHandlerDto::Builtin {
+                builtin: "current_time".to_string(),
+            },
+        });
+
+        assert!(matches!(result, Err(ApplicationError::ValidationError(_))));
+    }
+
+    #[test]
+    fn inject_local_tools_keeps_caller_declared_definitions() {
+        let mut payload = Map::new();
+        payload.insert(
+            "tools".to_string(),
+            json!([{"type": "function", "function": {"name": "lookup", "parameters": {}}}]),
+        );
+
+        inject_local_tools(
+            &mut payload,
+            &[extension_tool("lookup"), extension_tool("other")],
+        )
+        .expect("inject should succeed");
+
+        let tools = payload["tools"].as_array().expect("tools array");
+        assert_eq!(tools.len(), 2);
+        assert_eq!(tools[1]["function"]["name"], "other");
+    }
+
+    #[test]
+    fn extract_requested_tool_calls_parses_string_arguments() {
+        let message = json!({
+            "role": "assistant",
+            "tool_calls": [
+                {"id": "call_1", "type": "function", "function": {"name": "lookup", "arguments": "{\"q\":\"tea\"}"}},
+                {"id": "", "type": "function", "function": {"name": "ignored", "arguments": "{}"}}
+            ]
+        });
+
+        let calls = extract_requested_tool_calls(message.as_object().unwrap());
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].arguments, json!({"q": "tea"}));
+    }
+
+    #[test]
+    fn append_tool_round_emits_assistant_then_tool_messages() {
+        let mut payload = Map::new();
+        payload.insert(
+            "messages".to_string(),
+            json!([{"role": "user", "content": "hi"}]),
+        );
+        let assistant = json!({"content": null, "tool_calls": []});
+        let call = RequestedToolCall {
+            id: "call_1".to_string(),
+            name: "lookup".to_string(),
+            arguments: json!({}),
+        };
+
+        append_tool_round(
+            &mut payload,
+            assistant.as_object().unwrap(),
+            vec![(call, "result".to_string())],
+        )
+        .expect("append should succeed");
+
+        let messages = payload["messages"].as_array().unwrap();
+        assert_eq!(messages[1]["role"], "assistant");
+        assert_eq!(messages[1]["content"], "");
+        assert_eq!(messages[2]["role"], "tool");
+        assert_eq!(messages[2]["tool_call_id"], "call_1");
+    }
+
+    #[test]
+    fn resolve_max_rounds_enforces_bounds() {
+        assert_eq!(resolve_max_rounds(None).unwrap(), DEFAULT_MAX_TOOL_ROUNDS);
+        assert!(resolve_max_rounds(Some(0)).is_err());
+        assert!(resolve_max_rounds(Some(MAX_TOOL_ROUNDS_LIMIT + 1)).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::application::errors::ApplicationError;
 use crate::domain::models::chat::normalize_chat_file_stem;
-use crate::domain::models::filename::sanitize_filename;
+use crate::domain::models::filename::{normalize_avatar_filename, sanitize_filename};
 
 pub(super) fn validate_character_path_component(value: &str) -> Result<(), ApplicationError> {
     if value.is_empty() || sanitize_filename(value).is_empty() {
@@ -22,9 +22,23 @@ pub(super) fn validate_chat_file_name(value: &str, label: &str) -> Result<(), Ap
     Ok(())
 }
 
+/// Trimmed avatar filename, the key per-character data is stored under.
+pub(super) fn validate_avatar_file_name(avatar: &str) -> Result<String, ApplicationError> {
+    normalize_avatar_filename(avatar)
+        .map(str::to_string)
+        .ok_or_else(|| {
+            ApplicationError::ValidationError(format!(
+                "character.invalid_avatar: invalid avatar filename: {}",
+                avatar
+            ))
+        })
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{validate_character_path_component, validate_chat_file_name};
+    use super::{
+        validate_avatar_file_name, validate_character_path_component, validate_chat_file_name,
+    };
 
     #[test]
     fn rejects_path_components_that_sanitize_to_empty() {
@@ -41,4 +55,14 @@ mod tests {
         assert!(validate_chat_file_name("session.JSONL", "Chat file name").is_ok());
         assert!(validate_chat_file_name("中文会话.jsonl", "Chat file name").is_ok());
     }
+
+    #[test]
+    fn avatar_file_names_are_trimmed_and_must_stay_in_the_directory() {
+        assert_eq!(
+            validate_avatar_file_name(" Alice.png ").expect("valid"),
+            "Alice.png"
+        );
+        assert!(validate_avatar_file_name("../Alice.png").is_err());
+        assert!(validate_avatar_file_name("..").is_err());
+    }
 }
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
+
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::chat_repository::ChatRepository;
+use crate::domain::repositories::group_chat_repository::GroupChatRepository;
+
+/// Fields the editor may not touch: `integrity` guards saves against concurrent writers.
+const RESERVED_METADATA_FIELDS: &[&str] = &["integrity"];
+
+/// Reads and patches the `chat_metadata` header of a chat. Updates rewrite the header
+/// line only, so the frontend does not have to resave the whole payload.
+pub struct ChatMetadataService {
+    chat_repository: Arc<dyn ChatRepository>,
+    group_chat_repository: Arc<dyn GroupChatRepository>,
+}
+
+impl ChatMetadataService {
+    pub fn new(
+        chat_repository: Arc<dyn ChatRepository>,
+        group_chat_repository: Arc<dyn GroupChatRepository>,
+    ) -> Self {
+        Self {
+            chat_repository,
+            group_chat_repository,
+        }
+    }
+
+    pub async fn get_chat_metadata(
+        &self,
+        chat: &ChatMetadataTargetDto,
+    ) -> Result<Value, ApplicationError> {
+        let metadata = match chat {
+            ChatMetadataTargetDto::Character {
+                character_name,
+                file_name,
+            } => {
+                self.chat_repository
+                    .get_character_chat_metadata(character_name, file_name)
+                    .await?
+            }
+            ChatMetadataTargetDto::Group { chat_id } => {
+                self.group_chat_repository
+                    .get_group_chat_metadata(chat_id)
+                    .await?
+            }
+        };
+        Ok(metadata)
+    }
+
+    /// Merges `fields` into `chat_metadata` and returns the updated metadata.
+    pub async fn update_chat_metadata(
+        &self,
+        chat: &ChatMetadataTargetDto,
+        fields: Map<String, Value>,
+    ) -> Result<Value, ApplicationError> {
+        let fields = validate_fields(fields)?;
+        if !fields.is_empty() {
+            match chat {
+                ChatMetadataTargetDto::Character {
+                    character_name,
+                    file_name,
+                } => {
+                    self.chat_repository
+                        .update_character_chat_metadata(character_name, file_name, fields)
+                        .await?
+                }
+                ChatMetadataTargetDto::Group { chat_id } => {
+                    self.group_chat_repository
+                        .update_group_chat_metadata(chat_id, fields)
+                        .await?
+                }
+            }
+        }
+        self.get_chat_metadata(chat).await
+    }
+}
+
+fn validate_fields(fields: Map<String, Value>) -> Result<Map<String, Value>, ApplicationError> {
+    let mut validated = Map::with_capacity(fields.len());
+    for (key, value) in fields {
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(ApplicationError::ValidationError(
+                "chat_metadata.invalid_field: field names cannot be empty".to_string(),
+            ));
+        }
+        if RESERVED_METADATA_FIELDS.contains(&key) {
+            return Err(ApplicationError::ValidationError(format!(
+                "chat_metadata.reserved_field: {key} cannot be edited"
+            )));
+        }
+        validated.insert(key.to_string(), value);
+    }
+    Ok(validated)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::validate_fields;
+    use crate::application::errors::ApplicationError;
+
+    #[test]
+    fn validate_fields_trims_names_and_rejects_integrity() {
+        let Value::Object(fields) = json!({ " title ": "Beach trip", "note": null }) else {
+            unreachable!();
+        };
+        let fields = validate_fields(fields).expect("valid fields");
+        assert_eq!(fields["title"], "Beach trip");
+        assert!(fields["note"].is_null());
+
+        let Value::Object(fields) = json!({ "integrity": "forged" }) else {
+            unreachable!();
+        };
+        assert!(matches!(
+            validate_fields(fields),
+            Err(ApplicationError::ValidationError(_))
+        ));
+    }
+}
@@ -4,8 +4,9 @@ use std::sync::Arc;
 use serde_json::Value;
 
 use crate::application::dto::chat_dto::{
-    AddMessageDto, ChatDto, ChatSearchResultDto, CreateChatDto, ExportChatDto,
-    ImportCharacterChatsDto, ImportChatDto, RenameChatDto, SaveChatFromFileDto,
+    AddMessageDto, ChatDto, ChatFormatDetectionDto, ChatSearchResultDto, CreateChatDto,
+    DetectChatFormatDto, ExportChatDto, ImportCharacterChatsDto, ImportChatDto, RenameChatDto,
+    SaveChatFromFileDto,
 };
 use crate::application::errors::ApplicationError;
 use crate::application::services::agent_workspace_lifecycle_service::{
@@ -21,12 +22,14 @@ use crate::domain::repositories::agent_workspace_lifecycle_repository::{
 };
 use crate::domain::repositories::character_repository::CharacterRepository;
 use crate::domain::repositories::chat_repository::{
-    ChatExportFormat, ChatImportFormat, ChatRepository,
+    CharacterChatStats, ChatArchiveReport, ChatCompressionOptions, ChatCompressionReport,
+    ChatExportFormat, ChatImportFormat, ChatIndexRebuildReport, ChatRepairReport, ChatRepository,
 };
 use crate::domain::repositories::chat_types::{
-    ChatMessageSearchHit, ChatMessageSearchQuery, ChatPayloadChunk, ChatPayloadCursor,
-    ChatPayloadPatchOp, ChatPayloadTail, FindLastMessageQuery, LocatedChatMessage,
-    PinnedCharacterChat,
+    ChatMessageCursor, ChatMessageLocateQuery, ChatMessageSearchHit, ChatMessageSearchQuery,
+    ChatPayloadChunk, ChatPayloadCursor, ChatPayloadForwardChunk, ChatPayloadPatchOp,
+    ChatPayloadTail, ChatSearchMatchResult, ChatSearchOptions, FindLastMessageQuery,
+    LocatedChatMessage, PinnedCharacterChat,
 };
 
 /// Service for managing chats
@@ -235,6 +238,31 @@ impl ChatService {
         Ok(results.into_iter().map(ChatSearchResultDto::from).collect())
     }
 
+    /// Aggregate message statistics over all chats of a character
+    pub async fn get_character_chat_stats(
+        &self,
+        character_name: &str,
+    ) -> Result<CharacterChatStats, ApplicationError> {
+        Ok(self
+            .chat_repository
+            .get_character_chat_stats(character_name)
+            .await?)
+    }
+
+    /// Search messages across character chats with structured filters
+    pub async fn search_chats_with_options(
+        &self,
+        options: ChatSearchOptions,
+        character_filter: Option<&str>,
+    ) -> Result<Vec<ChatSearchMatchResult>, ApplicationError> {
+        tracing::info!("Searching chat messages for: {}", options.query);
+
+        Ok(self
+            .chat_repository
+            .search_chats_with_options(&options, character_filter)
+            .await?)
+    }
+
     /// List chat summaries without loading full chat payloads.
     pub async fn list_chat_summaries(
         &self,
@@ -514,6 +542,111 @@ impl ChatService {
             .await?)
     }
 
+    /// Compress idle character and group chats at rest
+    pub async fn compress_chats(
+        &self,
+        options: ChatCompressionOptions,
+    ) -> Result<ChatCompressionReport, ApplicationError> {
+        tracing::info!(
+            "Compressing chats larger than {} bytes idle for {}h",
+            options.min_size_bytes,
+            options.min_idle_hours
+        );
+        Ok(self.chat_repository.compress_chat_payloads(options).await?)
+    }
+
+    /// Archive character chats not modified for `older_than_days`, keeping each
+    /// character's most recent chat
+    pub async fn archive_old_chats(
+        &self,
+        character_filter: Option<&str>,
+        older_than_days: u32,
+    ) -> Result<ChatArchiveReport, ApplicationError> {
+        if let Some(character_name) = character_filter {
+            validate_character_path_component(character_name)?;
+        }
+        if older_than_days == 0 {
+            return Err(ApplicationError::ValidationError(
+                "older_than_days must be at least 1".to_string(),
+            ));
+        }
+
+        tracing::info!("Archiving chats idle for {} days", older_than_days);
+        Ok(self
+            .chat_repository
+            .archive_old_chats(character_filter, older_than_days)
+            .await?)
+    }
+
+    /// Move an archived character chat back to the active chats
+    pub async fn unarchive_chat(
+        &self,
+        character_name: &str,
+        file_name: &str,
+    ) -> Result<(), ApplicationError> {
+        validate_character_path_component(character_name)?;
+        validate_chat_file_name(file_name, "Chat file name")?;
+
+        tracing::info!("Unarchiving chat {}/{}", character_name, file_name);
+        Ok(self
+            .chat_repository
+            .unarchive_chat(character_name, file_name)
+            .await?)
+    }
+
+    /// List archived character chat summaries
+    pub async fn list_archived_chats(
+        &self,
+        character_filter: Option<&str>,
+    ) -> Result<Vec<ChatSearchResultDto>, ApplicationError> {
+        if let Some(character_name) = character_filter {
+            validate_character_path_component(character_name)?;
+        }
+
+        let results = self
+            .chat_repository
+            .list_archived_chats(character_filter)
+            .await?;
+        Ok(results.into_iter().map(ChatSearchResultDto::from).collect())
+    }
+
+    /// Rebuild the chat summary index from the chat files
+    pub async fn rebuild_chat_index(
+        &self,
+        on_progress: &(dyn Fn(usize, usize) + Send + Sync),
+    ) -> Result<ChatIndexRebuildReport, ApplicationError> {
+        tracing::info!("Rebuilding chat summary index");
+        Ok(self
+            .chat_repository
+            .rebuild_summary_index(on_progress)
+            .await?)
+    }
+
+    /// Repair a corrupted character chat payload
+    pub async fn repair_chat(
+        &self,
+        character_name: &str,
+        file_name: &str,
+    ) -> Result<ChatRepairReport, ApplicationError> {
+        validate_character_path_component(character_name)?;
+        validate_chat_file_name(file_name, "Chat file name")?;
+
+        tracing::info!("Repairing chat {}/{}", character_name, file_name);
+        Ok(self
+            .chat_repository
+            .repair_chat(character_name, file_name)
+            .await?)
+    }
+
+    /// Replay pending `add_message` journals left by an interrupted session
+    pub async fn replay_chat_journals(&self) -> Result<usize, ApplicationError> {
+        let replayed = self.chat_repository.replay_chat_journals().await?;
+        if replayed > 0 {
+            tracing::info!("Replayed {} pending chat journal(s)", replayed);
+        }
+        Ok(replayed)
+    }
+
     /// Clear the chat cache
     pub async fn clear_cache(&self) -> Result<(), DomainError> {
         tracing::info!("Clearing chat cache");
@@ -581,6 +714,33 @@ impl ChatService {
             .map_err(Into::into)
     }
 
+    /// Get JSONL lines starting at a character chat window cursor, paging forward.
+    pub async fn get_chat_payload_after_lines(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        cursor: ChatPayloadCursor,
+        max_lines: usize,
+    ) -> Result<ChatPayloadForwardChunk, ApplicationError> {
+        self.chat_repository
+            .get_chat_payload_after_lines(character_name, file_name, cursor, max_lines)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Locate a character chat message and return a cursor at its line.
+    pub async fn locate_chat_message(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        query: &ChatMessageLocateQuery,
+    ) -> Result<Option<ChatMessageCursor>, ApplicationError> {
+        self.chat_repository
+            .locate_chat_message(character_name, file_name, query)
+            .await
+            .map_err(Into::into)
+    }
+
     /// Get multiple windows of JSONL lines before the current character chat window cursor.
     ///
     /// This is equivalent to calling `get_chat_payload_before_lines` repeatedly, but returns
@@ -722,6 +882,19 @@ impl ChatService {
             .map_err(Into::into)
     }
 
+    /// Detect which supported format a chat export file is in.
+    pub async fn detect_chat_format(
+        &self,
+        dto: DetectChatFormatDto,
+    ) -> Result<ChatFormatDetectionDto, ApplicationError> {
+        let format = self
+            .chat_repository
+            .detect_chat_import_format(Path::new(&dto.file_path))
+            .await?;
+
+        Ok(ChatFormatDetectionDto::from(format))
+    }
+
     /// Import one or more character chats from an uploaded file.
     pub async fn import_character_chats(
         &self,
@@ -0,0 +1,248 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+
+use serde_json::Value;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{Mutex, Notify};
+use tokio::time::{Duration, sleep};
+
+use crate::application::errors::ApplicationError;
+use crate::application::services::chat_completion_service::ChatCompletionService;
+use crate::application::services::connection_profile_service::ConnectionProfileService;
+use crate::domain::models::connection_health::{ConnectionHealth, ConnectionHealthStatus};
+use crate::domain::models::settings::ConnectionMonitorSettings;
+use crate::domain::repositories::settings_repository::SettingsRepository;
+
+pub const API_STATUS_CHANGED_EVENT: &str = "api-status-changed";
+
+const CONNECTION_MONITOR_COLD_START_DELAY_SECS: u64 = 10;
+const CONNECTION_MONITOR_RETRY_DELAY_SECS: u64 = 60;
+
+/// Periodically lists the active connection profile's models and reports whether the
+/// provider is online, slow or offline. Status transitions are emitted as
+/// `api-status-changed`.
+pub struct ConnectionMonitorService {
+    app_handle: AppHandle,
+    settings_repository: Arc<dyn SettingsRepository>,
+    connection_profile_service: Arc<ConnectionProfileService>,
+    chat_completion_service: Arc<ChatCompletionService>,
+    health: Mutex<ConnectionHealth>,
+    notify: Notify,
+    started: AtomicBool,
+}
+
+impl ConnectionMonitorService {
+    pub fn new(
+        app_handle: AppHandle,
+        settings_repository: Arc<dyn SettingsRepository>,
+        connection_profile_service: Arc<ConnectionProfileService>,
+        chat_completion_service: Arc<ChatCompletionService>,
+    ) -> Self {
+        Self {
+            app_handle,
+            settings_repository,
+            connection_profile_service,
+            chat_completion_service,
+            health: Mutex::new(ConnectionHealth::default()),
+            notify: Notify::new(),
+            started: AtomicBool::new(false),
+        }
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        if self.started.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let service = self.clone();
+        tauri::async_runtime::spawn(async move {
+            service.scheduler_loop().await;
+        });
+    }
+
+    /// Wakes the scheduler after the monitor settings or the active profile change.
+    pub fn notify_settings_changed(&self) {
+        self.notify.notify_waiters();
+    }
+
+    pub async fn get_health(&self, refresh: bool) -> Result<ConnectionHealth, ApplicationError> {
+        if refresh {
+            let settings = self.load_settings().await?;
+            self.check_once(&settings).await;
+        }
+
+        Ok(self.health.lock().await.clone())
+    }
+
+    async fn scheduler_loop(self: Arc<Self>) {
+        sleep(Duration::from_secs(
+            CONNECTION_MONITOR_COLD_START_DELAY_SECS,
+        ))
+        .await;
+
+        loop {
+            let settings = match self.load_settings().await {
+                Ok(settings) => settings,
+                Err(error) => {
+                    tracing::warn!("Failed to load connection monitor settings: {}", error);
+                    sleep(Duration::from_secs(CONNECTION_MONITOR_RETRY_DELAY_SECS)).await;
+                    continue;
+                }
+            };
+
+            if !settings.enabled {
+                self.record(ConnectionHealth::default()).await;
+                self.notify.notified().await;
+                continue;
+            }
+
+            self.check_once(&settings).await;
+
+            tokio::select! {
+                _ = sleep(Duration::from_secs(settings.interval_secs)) => {}
+                _ = self.notify.notified() => {}
+            }
+        }
+    }
+
+    async fn check_once(&self, settings: &ConnectionMonitorSettings) {
+        let target = match self.connection_profile_service.active_target().await {
+            Ok(Some(target)) => target,
+            Ok(None) => {
+                self.record(ConnectionHealth::default()).await;
+                return;
+            }
+            Err(error) => {
+                self.record(ConnectionHealth {
+                    last_error: Some(error.to_string()),
+                    ..ConnectionHealth::default()
+                })
+                .await;
+                return;
+            }
+        };
+
+        let started_at = Instant::now();
+        let result = self
+            .chat_completion_service
+            .get_status(target.status_request)
+            .await;
+        let latency_ms = u64::try_from(started_at.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        let previous_failures = {
+            let health = self.health.lock().await;
+            if health.profile_id.as_deref() == Some(target.profile_id.as_str()) {
+                health.consecutive_failures
+            } else {
+                0
+            }
+        };
+
+        let mut next = evaluate_check(
+            result.as_ref().map_err(ToString::to_string),
+            latency_ms,
+            settings.slow_threshold_ms,
+            previous_failures,
+        );
+        next.profile_id = Some(target.profile_id);
+        next.checked_at_ms = Some(chrono::Utc::now().timestamp_millis());
+        self.record(next).await;
+    }
+
+    async fn record(&self, next: ConnectionHealth) {
+        let changed = {
+            let mut health = self.health.lock().await;
+            let changed = health.status != next.status || health.profile_id != next.profile_id;
+            *health = next.clone();
+            changed
+        };
+
+        if !changed {
+            return;
+        }
+
+        if let Err(error) = self.app_handle.emit(API_STATUS_CHANGED_EVENT, next) {
+            tracing::warn!("Failed to emit connection status: {}", error);
+        }
+    }
+
+    async fn load_settings(&self) -> Result<ConnectionMonitorSettings, ApplicationError> {
+        Ok(self
+            .settings_repository
+            .load_tauritavern_settings()
+            .await?
+            .connection_monitor)
+    }
+}
+
+/// Classifies one models-endpoint check. Sources that bypass status checks stay unknown.
+fn evaluate_check(
+    result: Result<&Value, String>,
+    latency_ms: u64,
+    slow_threshold_ms: u64,
+    previous_failures: u32,
+) -> ConnectionHealth {
+    let models = match result {
+        Ok(models) => models,
+        Err(error) => {
+            return ConnectionHealth {
+                status: ConnectionHealthStatus::Offline,
+                latency_ms: Some(latency_ms),
+                consecutive_failures: previous_failures.saturating_add(1),
+                last_error: Some(error),
+                ..ConnectionHealth::default()
+            };
+        }
+    };
+
+    if models.get("bypass").and_then(Value::as_bool) == Some(true) {
+        return ConnectionHealth::default();
+    }
+
+    let status = if latency_ms > slow_threshold_ms {
+        ConnectionHealthStatus::Slow
+    } else {
+        ConnectionHealthStatus::Online
+    };
+
+    ConnectionHealth {
+        status,
+        latency_ms: Some(latency_ms),
+        ..ConnectionHealth::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::evaluate_check;
+    use crate::domain::models::connection_health::ConnectionHealthStatus;
+
+    #[test]
+    fn evaluate_check_grades_latency_and_counts_failures() {
+        let models = json!({ "data": [{ "id": "qwen2.5-14b" }] });
+
+        let online = evaluate_check(Ok(&models), 120, 3000, 2);
+        assert_eq!(online.status, ConnectionHealthStatus::Online);
+        assert_eq!(online.consecutive_failures, 0);
+
+        let slow = evaluate_check(Ok(&models), 4500, 3000, 0);
+        assert_eq!(slow.status, ConnectionHealthStatus::Slow);
+
+        let offline = evaluate_check(Err("connection refused".to_string()), 20, 3000, 2);
+        assert_eq!(offline.status, ConnectionHealthStatus::Offline);
+        assert_eq!(offline.consecutive_failures, 3);
+        assert_eq!(offline.last_error.as_deref(), Some("connection refused"));
+    }
+
+    #[test]
+    fn evaluate_check_leaves_bypassed_sources_unknown() {
+        let bypass = json!({ "bypass": true, "data": [] });
+
+        let health = evaluate_check(Ok(&bypass), 5, 3000, 0);
+        assert_eq!(health.status, ConnectionHealthStatus::Unknown);
+        assert_eq!(health.latency_ms, None);
+    }
+}
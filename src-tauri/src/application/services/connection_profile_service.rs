@@ -0,0 +1,251 @@
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
+
+use crate::application::dto::chat_completion_dto::ChatCompletionStatusRequestDto;
+use crate::application::dto::connection_profile_dto::{
+    ActivateConnectionProfileResultDto, ConnectionProfileDto, ListConnectionProfilesResultDto,
+};
+use crate::application::errors::ApplicationError;
+use crate::application::services::llm_connection_service::{
+    LlmConnectionService, settings_model_key,
+};
+use crate::domain::models::llm_connection::LlmConnectionId;
+use crate::domain::models::settings::ConnectionProfile;
+use crate::domain::repositories::secret_repository::SecretRepository;
+use crate::domain::repositories::settings_repository::SettingsRepository;
+
+/// Named connection profiles kept in `tauritavern-settings.json`. A profile points at an
+/// LLM connection, which owns the source, base URL and secret reference.
+/// Status-check request for the active profile's connection.
+pub struct ActiveConnectionTarget {
+    pub profile_id: String,
+    pub status_request: ChatCompletionStatusRequestDto,
+}
+
+pub struct ConnectionProfileService {
+    settings_repository: Arc<dyn SettingsRepository>,
+    secret_repository: Arc<dyn SecretRepository>,
+    llm_connection_service: Arc<LlmConnectionService>,
+}
+
+impl ConnectionProfileService {
+    pub fn new(
+        settings_repository: Arc<dyn SettingsRepository>,
+        secret_repository: Arc<dyn SecretRepository>,
+        llm_connection_service: Arc<LlmConnectionService>,
+    ) -> Self {
+        Self {
+            settings_repository,
+            secret_repository,
+            llm_connection_service,
+        }
+    }
+
+    pub async fn list_profiles(&self) -> Result<ListConnectionProfilesResultDto, ApplicationError> {
+        let settings = self.settings_repository.load_tauritavern_settings().await?;
+        let profiles = settings.connection_profiles;
+
+        Ok(ListConnectionProfilesResultDto {
+            active_profile_id: profiles.active_profile_id,
+            profiles: profiles
+                .profiles
+                .into_iter()
+                .map(ConnectionProfileDto::from)
+                .collect(),
+        })
+    }
+
+    /// Creates or replaces the profile with the same id.
+    pub async fn save_profile(
+        &self,
+        profile: ConnectionProfileDto,
+    ) -> Result<(), ApplicationError> {
+        let profile = normalize_profile(profile.into())?;
+        self.llm_connection_service
+            .resolve_model_binding(&profile.connection_ref, &profile.default_model)
+            .await?;
+
+        let mut settings = self.settings_repository.load_tauritavern_settings().await?;
+        let profiles = &mut settings.connection_profiles.profiles;
+        match profiles
+            .iter_mut()
+            .find(|existing| existing.id == profile.id)
+        {
+            Some(existing) => *existing = profile,
+            None => profiles.push(profile),
+        }
+
+        self.settings_repository
+            .save_tauritavern_settings(&settings)
+            .await?;
+        Ok(())
+    }
+
+    /// Resolves the active profile into a status request, or `None` when no profile is
+    /// active.
+    pub async fn active_target(&self) -> Result<Option<ActiveConnectionTarget>, ApplicationError> {
+        let settings = self.settings_repository.load_tauritavern_settings().await?;
+        let profiles = settings.connection_profiles;
+        let Some(profile_id) = profiles.active_profile_id else {
+            return Ok(None);
+        };
+        let Some(profile) = profiles
+            .profiles
+            .into_iter()
+            .find(|profile| profile.id == profile_id)
+        else {
+            return Ok(None);
+        };
+
+        let mut payload = Map::new();
+        self.llm_connection_service
+            .apply_connection_to_payload(
+                &profile.connection_ref,
+                &profile.default_model,
+                &mut payload,
+            )
+            .await?;
+        let status_request = serde_json::from_value(Value::Object(payload)).map_err(|error| {
+            ApplicationError::InternalError(format!(
+                "connection_profile.status_request_invalid: {error}"
+            ))
+        })?;
+
+        Ok(Some(ActiveConnectionTarget {
+            profile_id,
+            status_request,
+        }))
+    }
+
+    /// Selects the profile's secret and marks the profile active.
+    pub async fn activate_profile(
+        &self,
+        profile_id: &str,
+    ) -> Result<ActivateConnectionProfileResultDto, ApplicationError> {
+        let profile_id = profile_id.trim();
+        let mut settings = self.settings_repository.load_tauritavern_settings().await?;
+        let profile = settings
+            .connection_profiles
+            .profiles
+            .iter()
+            .find(|profile| profile.id == profile_id)
+            .cloned()
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "connection_profile.not_found: connection profile `{profile_id}` does not exist"
+                ))
+            })?;
+
+        let mut payload = Map::new();
+        let binding = self
+            .llm_connection_service
+            .apply_connection_to_payload(
+                &profile.connection_ref,
+                &profile.default_model,
+                &mut payload,
+            )
+            .await?;
+
+        let secret_ref = &binding.secret_ref;
+        if self
+            .secret_repository
+            .read_secret(&secret_ref.key, Some(&secret_ref.id))
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::NotFound(format!(
+                "connection_profile.secret_missing: secret `{}` for `{}` does not exist",
+                secret_ref.id, secret_ref.key
+            )));
+        }
+        self.secret_repository
+            .rotate_secret(&secret_ref.key, &secret_ref.id)
+            .await?;
+
+        settings.connection_profiles.active_profile_id = Some(profile.id.clone());
+        self.settings_repository
+            .save_tauritavern_settings(&settings)
+            .await?;
+
+        Ok(ActivateConnectionProfileResultDto {
+            settings: settings_patch(payload, &binding.chat_completion_source)?,
+            profile: profile.into(),
+        })
+    }
+}
+
+fn normalize_profile(profile: ConnectionProfile) -> Result<ConnectionProfile, ApplicationError> {
+    let id = LlmConnectionId::parse(&profile.id).map_err(ApplicationError::ValidationError)?;
+    let display_name = profile.display_name.trim();
+    if display_name.is_empty() {
+        return Err(ApplicationError::ValidationError(
+            "connection_profile.display_name_required: displayName cannot be empty".to_string(),
+        ));
+    }
+
+    Ok(ConnectionProfile {
+        id: id.as_str().to_string(),
+        display_name: display_name.to_string(),
+        connection_ref: profile.connection_ref.trim().to_string(),
+        default_model: profile.default_model.trim().to_string(),
+    })
+}
+
+/// Turns a connection payload into `oai_settings` fields: the model moves to the
+/// source-specific key and the request-only `secret_id` is dropped.
+fn settings_patch(
+    mut payload: Map<String, Value>,
+    source: &str,
+) -> Result<Map<String, Value>, ApplicationError> {
+    payload.remove("secret_id");
+    if let Some(model) = payload.remove("model") {
+        payload.insert(settings_model_key(source)?.to_string(), model);
+    }
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Map, Value, json};
+
+    use super::{normalize_profile, settings_patch};
+    use crate::application::errors::ApplicationError;
+    use crate::domain::models::settings::ConnectionProfile;
+
+    #[test]
+    fn settings_patch_moves_model_to_source_key() {
+        let Value::Object(payload) = json!({
+            "chat_completion_source": "custom",
+            "model": "qwen2.5-14b",
+            "custom_url": "http://127.0.0.1:1234/v1",
+            "secret_id": "lm-studio",
+        }) else {
+            unreachable!();
+        };
+
+        let patch = settings_patch(payload, "custom").expect("patch");
+        let mut expected = Map::new();
+        expected.insert("chat_completion_source".to_string(), json!("custom"));
+        expected.insert("custom_model".to_string(), json!("qwen2.5-14b"));
+        expected.insert("custom_url".to_string(), json!("http://127.0.0.1:1234/v1"));
+        assert_eq!(patch, expected);
+    }
+
+    #[test]
+    fn normalize_profile_rejects_invalid_ids() {
+        let profile = ConnectionProfile {
+            id: "LM Studio".to_string(),
+            display_name: "LM Studio".to_string(),
+            connection_ref: "lm-studio".to_string(),
+            default_model: "qwen2.5-14b".to_string(),
+        };
+
+        let error = normalize_profile(profile).expect_err("invalid id");
+        assert!(matches!(
+            error,
+            ApplicationError::ValidationError(message)
+                if message.starts_with("llm_connection.id_invalid")
+        ));
+    }
+}
@@ -0,0 +1,427 @@
+use std::sync::Arc;
+
+use crate::application::dto::expression_dto::{
+    ClassifyExpressionOptionsDto, ExpressionClassificationDto,
+};
+use crate::application::errors::ApplicationError;
+use crate::domain::models::secret::SecretKeys;
+use crate::domain::models::sprite::DEFAULT_EXPRESSION_LABELS;
+use crate::domain::repositories::expression_classifier_repository::{
+    DEFAULT_EXPRESSION_CLASSIFIER_MODEL, ExpressionClassifierRepository,
+    ExpressionClassifierRequest, ExpressionScore,
+};
+use crate::domain::repositories::secret_repository::SecretRepository;
+
+/// Longest message excerpt sent for classification, in characters. Emotion models
+/// only read the first few hundred tokens, so the tail is what gets kept.
+const MAX_CLASSIFY_CHARS: usize = 2000;
+
+/// Words that mark an expression in the offline keyword classifier. Only labels the
+/// lexicon covers can be picked without the remote model.
+const EXPRESSION_KEYWORDS: &[(&str, &[&str])] = &[
+    (
+        "amusement",
+        &["haha", "hehe", "lol", "laugh", "giggle", "funny"],
+    ),
+    (
+        "anger",
+        &["angry", "furious", "rage", "hate", "damn", "shut up"],
+    ),
+    (
+        "annoyance",
+        &["annoying", "annoyed", "ugh", "whatever", "irritat"],
+    ),
+    (
+        "confusion",
+        &["confused", "huh", "what do you mean", "don't understand"],
+    ),
+    (
+        "curiosity",
+        &["curious", "wonder", "tell me more", "i'd like to know"],
+    ),
+    ("disgust", &["gross", "disgusting", "eww", "yuck"]),
+    ("embarrassment", &["embarrass", "blush", "awkward"]),
+    (
+        "excitement",
+        &["excited", "can't wait", "awesome", "amazing"],
+    ),
+    ("fear", &["afraid", "scared", "terrified", "frighten"]),
+    ("gratitude", &["thank", "grateful", "appreciate"]),
+    ("joy", &["happy", "glad", "joy", "delight", "smile"]),
+    ("love", &["love", "adore", "darling", "sweetheart"]),
+    ("nervousness", &["nervous", "anxious", "worried", "uneasy"]),
+    (
+        "sadness",
+        &["sad", "cry", "tears", "sorrow", "miss you", "lonely"],
+    ),
+    (
+        "surprise",
+        &["surprised", "wow", "whoa", "no way", "unexpected"],
+    ),
+];
+
+/// Picks an expression label for a message so the expressions extension does not
+/// need a classify server.
+///
+/// Classification falls back in this order:
+/// 1. the Hugging Face model, when a `HUGGINGFACE` key is saved;
+/// 2. the offline keyword classifier, when there is no key or the remote call fails;
+/// 3. `options.fallback`, when neither scores a known label.
+pub struct ExpressionService {
+    classifier_repository: Arc<dyn ExpressionClassifierRepository>,
+    secret_repository: Arc<dyn SecretRepository>,
+}
+
+impl ExpressionService {
+    pub fn new(
+        classifier_repository: Arc<dyn ExpressionClassifierRepository>,
+        secret_repository: Arc<dyn SecretRepository>,
+    ) -> Self {
+        Self {
+            classifier_repository,
+            secret_repository,
+        }
+    }
+
+    pub async fn classify_expression(
+        &self,
+        text: &str,
+        options: ClassifyExpressionOptionsDto,
+    ) -> Result<ExpressionClassificationDto, ApplicationError> {
+        let model = match options.model.as_deref().map(str::trim) {
+            Some(model) if !model.is_empty() => validate_model_id(model)?.to_string(),
+            _ => DEFAULT_EXPRESSION_CLASSIFIER_MODEL.to_string(),
+        };
+
+        let text = text.trim();
+        if text.is_empty() {
+            return Ok(ExpressionClassificationDto {
+                label: options.fallback,
+                scores: Vec::new(),
+            });
+        }
+        let text = tail_chars(text, MAX_CLASSIFY_CHARS);
+
+        let api_key = self
+            .secret_repository
+            .read_secret(SecretKeys::HUGGINGFACE, None)
+            .await?
+            .filter(|key| !key.trim().is_empty());
+        let scores = match api_key {
+            Some(api_key) => {
+                let request = ExpressionClassifierRequest {
+                    model,
+                    api_key,
+                    text: text.to_string(),
+                };
+                match self.classifier_repository.classify(request).await {
+                    Ok(scores) => scores,
+                    Err(error) => {
+                        tracing::warn!(
+                            "Remote expression classification failed, using keywords: {}",
+                            error
+                        );
+                        keyword_scores(text)
+                    }
+                }
+            }
+            None => keyword_scores(text),
+        };
+
+        let label = scores
+            .iter()
+            .map(|score| score.label.to_lowercase())
+            .find(|label| {
+                DEFAULT_EXPRESSION_LABELS.contains(&label.as_str())
+                    || options
+                        .custom_labels
+                        .iter()
+                        .any(|custom| custom.trim().eq_ignore_ascii_case(label))
+            })
+            .or(options.fallback);
+
+        Ok(ExpressionClassificationDto { label, scores })
+    }
+}
+
+/// Accepts Hugging Face `owner/name` ids only, so the id cannot add path segments,
+/// a query or a fragment to the inference URL.
+fn validate_model_id(model: &str) -> Result<&str, ApplicationError> {
+    let is_segment = |segment: &str| {
+        !segment.is_empty()
+            && segment != "."
+            && segment != ".."
+            && segment
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '.' | '_' | '-'))
+    };
+    match model.split_once('/') {
+        Some((owner, name)) if is_segment(owner) && is_segment(name) => Ok(model),
+        _ => Err(ApplicationError::ValidationError(format!(
+            "expression.invalid_model: expected a Hugging Face owner/name model id: {}",
+            model
+        ))),
+    }
+}
+
+/// Offline scores from `EXPRESSION_KEYWORDS`: each label's share of the keyword hits,
+/// highest first. Empty when no keyword matches.
+fn keyword_scores(text: &str) -> Vec<ExpressionScore> {
+    let text = text.to_lowercase();
+    let hits: Vec<(&str, usize)> = EXPRESSION_KEYWORDS
+        .iter()
+        .map(|(label, keywords)| {
+            let count = keywords
+                .iter()
+                .map(|keyword| text.matches(keyword).count())
+                .sum();
+            (*label, count)
+        })
+        .filter(|(_, count)| *count > 0)
+        .collect();
+    let total: usize = hits.iter().map(|(_, count)| count).sum();
+
+    let mut scores: Vec<ExpressionScore> = hits
+        .into_iter()
+        .map(|(label, count)| ExpressionScore {
+            label: label.to_string(),
+            score: count as f64 / total as f64,
+        })
+        .collect();
+    scores.sort_by(|left, right| right.score.total_cmp(&left.score));
+    scores
+}
+
+fn tail_chars(text: &str, max_chars: usize) -> &str {
+    let count = text.chars().count();
+    if count <= max_chars {
+        return text;
+    }
+    let start = text
+        .char_indices()
+        .nth(count - max_chars)
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+    &text[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use async_trait::async_trait;
+
+    use super::{ExpressionService, keyword_scores, validate_model_id};
+    use crate::application::dto::expression_dto::ClassifyExpressionOptionsDto;
+    use crate::domain::errors::DomainError;
+    use crate::domain::models::secret::{SecretKeys, Secrets};
+    use crate::domain::repositories::expression_classifier_repository::{
+        ExpressionClassifierRepository, ExpressionClassifierRequest, ExpressionScore,
+    };
+    use crate::domain::repositories::secret_repository::SecretRepository;
+
+    struct TestClassifier {
+        result: Result<Vec<ExpressionScore>, String>,
+        calls: AtomicUsize,
+    }
+
+    impl TestClassifier {
+        fn new(result: Result<Vec<(&str, f64)>, &str>) -> Arc<Self> {
+            Arc::new(Self {
+                result: result
+                    .map(|scores| {
+                        scores
+                            .into_iter()
+                            .map(|(label, score)| ExpressionScore {
+                                label: label.to_string(),
+                                score,
+                            })
+                            .collect()
+                    })
+                    .map_err(str::to_string),
+                calls: AtomicUsize::new(0),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl ExpressionClassifierRepository for TestClassifier {
+        async fn classify(
+            &self,
+            request: ExpressionClassifierRequest,
+        ) -> Result<Vec<ExpressionScore>, DomainError> {
+            assert_eq!(request.api_key, "hf-key");
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.result.clone().map_err(DomainError::InternalError)
+        }
+    }
+
+    struct TestSecretRepository {
+        huggingface_key: Option<&'static str>,
+    }
+
+    #[async_trait]
+    impl SecretRepository for TestSecretRepository {
+        async fn save(&self, _secrets: &Secrets) -> Result<(), DomainError> {
+            unimplemented!()
+        }
+
+        async fn load(&self) -> Result<Secrets, DomainError> {
+            unimplemented!()
+        }
+
+        async fn clear_cache(&self) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn write_secret(
+            &self,
+            _key: &str,
+            _value: &str,
+            _label: &str,
+        ) -> Result<String, DomainError> {
+            unimplemented!()
+        }
+
+        async fn read_secret(
+            &self,
+            key: &str,
+            _id: Option<&str>,
+        ) -> Result<Option<String>, DomainError> {
+            assert_eq!(key, SecretKeys::HUGGINGFACE);
+            Ok(self.huggingface_key.map(str::to_string))
+        }
+
+        async fn delete_secret(&self, _key: &str, _id: Option<&str>) -> Result<(), DomainError> {
+            unimplemented!()
+        }
+
+        async fn rotate_secret(&self, _key: &str, _id: &str) -> Result<(), DomainError> {
+            unimplemented!()
+        }
+
+        async fn rename_secret(
+            &self,
+            _key: &str,
+            _id: &str,
+            _label: &str,
+        ) -> Result<(), DomainError> {
+            unimplemented!()
+        }
+    }
+
+    fn service(
+        classifier: Arc<TestClassifier>,
+        huggingface_key: Option<&'static str>,
+    ) -> ExpressionService {
+        ExpressionService::new(
+            classifier,
+            Arc::new(TestSecretRepository { huggingface_key }),
+        )
+    }
+
+    fn options(fallback: &str, custom_labels: &[&str]) -> ClassifyExpressionOptionsDto {
+        ClassifyExpressionOptionsDto {
+            model: None,
+            custom_labels: custom_labels
+                .iter()
+                .map(|label| label.to_string())
+                .collect(),
+            fallback: Some(fallback.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn missing_secret_uses_keywords_without_calling_the_remote_model() {
+        let classifier = TestClassifier::new(Ok(vec![("anger", 0.9)]));
+        let service = service(classifier.clone(), None);
+
+        let result = service
+            .classify_expression("Thank you, I'm so happy and glad!", options("neutral", &[]))
+            .await
+            .expect("classify");
+
+        assert_eq!(result.label.as_deref(), Some("joy"));
+        assert_eq!(classifier.calls.load(Ordering::SeqCst), 0);
+
+        let result = service
+            .classify_expression("The train leaves at noon.", options("neutral", &[]))
+            .await
+            .expect("classify");
+        assert_eq!(result.label.as_deref(), Some("neutral"));
+        assert!(result.scores.is_empty());
+    }
+
+    #[tokio::test]
+    async fn remote_failure_falls_back_to_keywords() {
+        let classifier = TestClassifier::new(Err("HTTP 503"));
+        let service = service(classifier.clone(), Some("hf-key"));
+
+        let result = service
+            .classify_expression("I'm scared of the dark.", options("neutral", &[]))
+            .await
+            .expect("classify");
+
+        assert_eq!(classifier.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(result.label.as_deref(), Some("fear"));
+    }
+
+    #[tokio::test]
+    async fn remote_labels_map_to_known_and_custom_labels() {
+        let classifier =
+            TestClassifier::new(Ok(vec![("LABEL_0", 0.5), ("Smug", 0.3), ("joy", 0.2)]));
+        let service = service(classifier.clone(), Some("hf-key"));
+
+        let result = service
+            .classify_expression("Fine.", options("neutral", &[" smug "]))
+            .await
+            .expect("classify");
+        assert_eq!(result.label.as_deref(), Some("smug"));
+        assert_eq!(result.scores.len(), 3);
+
+        let result = service
+            .classify_expression("Fine.", options("neutral", &[]))
+            .await
+            .expect("classify");
+        assert_eq!(result.label.as_deref(), Some("joy"));
+    }
+
+    #[tokio::test]
+    async fn invalid_model_ids_are_rejected_before_any_request() {
+        let classifier = TestClassifier::new(Ok(Vec::new()));
+        let service = service(classifier.clone(), Some("hf-key"));
+
+        let mut invalid = options("neutral", &[]);
+        invalid.model = Some("../../v1/evil?x=#".to_string());
+        assert!(service.classify_expression("Hi", invalid).await.is_err());
+        assert_eq!(classifier.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn model_ids_must_be_owner_and_name() {
+        assert!(validate_model_id("SamLowe/roberta-base-go_emotions").is_ok());
+        assert!(validate_model_id("owner/model.v2").is_ok());
+        for invalid in [
+            "roberta",
+            "owner/",
+            "../model",
+            "owner/..",
+            "owner/name/extra",
+            "owner/name?wait=1",
+            "owner/name#frag",
+            "owner /name",
+        ] {
+            assert!(validate_model_id(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn keyword_scores_are_shares_of_the_hits() {
+        let scores = keyword_scores("Haha, that's funny. I'm happy.");
+        assert_eq!(scores[0].label, "amusement");
+        assert_eq!(scores.len(), 2);
+        let total: f64 = scores.iter().map(|score| score.score).sum();
+        assert!((total - 1.0).abs() < f64::EPSILON);
+    }
+}
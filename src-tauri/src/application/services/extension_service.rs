@@ -1,8 +1,10 @@
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::domain::errors::DomainError;
 use crate::domain::models::extension::{
-    Extension, ExtensionInstallResult, ExtensionUpdateResult, ExtensionVersion,
+    Extension, ExtensionBatchUpdateResult, ExtensionInstallResult, ExtensionUpdateResult,
+    ExtensionVersion,
 };
 use crate::domain::repositories::extension_repository::ExtensionRepository;
 use crate::infrastructure::logging::logger;
@@ -39,6 +41,21 @@ impl ExtensionService {
             .await
     }
 
+    /// Install an extension from a local .zip file or folder
+    pub async fn install_extension_from_path(
+        &self,
+        source_path: &Path,
+        global: bool,
+    ) -> Result<ExtensionInstallResult, DomainError> {
+        logger::debug(&format!(
+            "Installing extension from {}",
+            source_path.display()
+        ));
+        self.extension_repository
+            .install_extension_from_path(source_path, global)
+            .await
+    }
+
     /// Update an extension
     pub async fn update_extension(
         &self,
@@ -51,6 +68,17 @@ impl ExtensionService {
             .await
     }
 
+    /// Update all managed third-party extensions
+    pub async fn update_all_extensions(
+        &self,
+        include_global: bool,
+    ) -> Result<Vec<ExtensionBatchUpdateResult>, DomainError> {
+        logger::debug("Updating all extensions");
+        self.extension_repository
+            .update_all_extensions(include_global)
+            .await
+    }
+
     /// Delete an extension
     pub async fn delete_extension(
         &self,
@@ -0,0 +1,89 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::application::dto::file_attachment_dto::{
+    FileAttachmentTextDto, UploadFileAttachmentDto,
+};
+use crate::domain::errors::DomainError;
+use crate::domain::models::file_attachment::{FileAttachment, FileAttachmentScope};
+use crate::domain::repositories::file_attachment_repository::FileAttachmentRepository;
+use crate::infrastructure::logging::logger;
+
+/// Service for Data Bank documents attached globally, to a character or to a chat
+pub struct FileAttachmentService {
+    repository: Arc<dyn FileAttachmentRepository>,
+}
+
+impl FileAttachmentService {
+    /// Create a new FileAttachmentService instance
+    pub fn new(repository: Arc<dyn FileAttachmentRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Upload a document from inline base64 data or a local file path
+    pub async fn upload_attachment(
+        &self,
+        dto: UploadFileAttachmentDto,
+    ) -> Result<FileAttachment, DomainError> {
+        logger::debug(&format!(
+            "FileAttachmentService: Uploading attachment: {}",
+            dto.name
+        ));
+
+        if dto.name.trim().is_empty() {
+            return Err(DomainError::InvalidData(
+                "Attachment name cannot be empty".to_string(),
+            ));
+        }
+
+        match (dto.data_base64.as_deref(), dto.file_path.as_deref()) {
+            (Some(data), None) => {
+                let bytes = BASE64_STANDARD.decode(data.trim()).map_err(|error| {
+                    DomainError::InvalidData(format!("Invalid attachment data: {}", error))
+                })?;
+                self.repository
+                    .save_attachment(&dto.scope, &dto.name, &bytes)
+                    .await
+            }
+            (None, Some(file_path)) => {
+                self.repository
+                    .save_attachment_from_path(&dto.scope, &dto.name, Path::new(file_path))
+                    .await
+            }
+            _ => Err(DomainError::InvalidData(
+                "Exactly one of dataBase64 or filePath must be provided".to_string(),
+            )),
+        }
+    }
+
+    /// List documents attached to a scope
+    pub async fn list_attachments(
+        &self,
+        scope: &FileAttachmentScope,
+    ) -> Result<Vec<FileAttachment>, DomainError> {
+        self.repository.list_attachments(scope).await
+    }
+
+    /// Delete a document by url
+    pub async fn delete_attachment(&self, url: &str) -> Result<(), DomainError> {
+        logger::debug(&format!(
+            "FileAttachmentService: Deleting attachment: {}",
+            url
+        ));
+        self.repository.delete_attachment(url).await
+    }
+
+    /// Extract the plain text of a document for the vector storage pipeline
+    pub async fn extract_attachment_text(
+        &self,
+        url: &str,
+    ) -> Result<FileAttachmentTextDto, DomainError> {
+        let text = self.repository.extract_attachment_text(url).await?;
+        Ok(FileAttachmentTextDto {
+            url: url.to_string(),
+            text,
+        })
+    }
+}
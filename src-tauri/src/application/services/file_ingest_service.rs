@@ -0,0 +1,454 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
+
+use crate::application::dto::character_dto::ImportCharacterDto;
+use crate::application::dto::chat_dto::{DetectChatFormatDto, ImportCharacterChatsDto};
+use crate::application::dto::file_ingest_dto::{
+    IngestFileResultDto, IngestFilesDto, IngestedFileKind,
+};
+use crate::application::errors::ApplicationError;
+use crate::application::services::character_service::CharacterService;
+use crate::application::services::chat_service::ChatService;
+use crate::application::services::preset_service::PresetService;
+use crate::application::services::theme_service::ThemeService;
+use crate::application::services::world_info_service::WorldInfoService;
+use crate::domain::models::preset::{
+    PRESET_BUNDLE_FORMAT, PRESET_BUNDLE_VERSION, PresetBundle, PresetBundleEntry, PresetType,
+};
+
+/// Files larger than this are rejected before they are read.
+const MAX_INGEST_FILE_BYTES: u64 = 128 * 1024 * 1024;
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+
+/// Keys of a SillyTavern "master export" holding one preset per template kind.
+const MASTER_EXPORT_SECTIONS: [(&str, PresetType); 4] = [
+    ("instruct", PresetType::Instruct),
+    ("context", PresetType::Context),
+    ("sysprompt", PresetType::SysPrompt),
+    ("reasoning", PresetType::Reasoning),
+];
+
+/// Keys only found in theme files.
+const THEME_MARKER_KEYS: [&str; 4] = [
+    "main_text_color",
+    "blur_strength",
+    "chat_display",
+    "font_scale",
+];
+
+#[derive(Debug)]
+enum SniffedFile {
+    Character,
+    /// A chat export candidate; the chat importer confirms the exact format.
+    Chat,
+    WorldInfo,
+    PresetBundle(Value),
+    Presets(Vec<PresetBundleEntry>),
+    Theme,
+    Unknown,
+}
+
+impl SniffedFile {
+    fn kind(&self) -> Option<IngestedFileKind> {
+        match self {
+            SniffedFile::Character => Some(IngestedFileKind::Character),
+            SniffedFile::Chat => Some(IngestedFileKind::Chat),
+            SniffedFile::WorldInfo => Some(IngestedFileKind::WorldInfo),
+            SniffedFile::PresetBundle(_) | SniffedFile::Presets(_) => {
+                Some(IngestedFileKind::Preset)
+            }
+            SniffedFile::Theme => Some(IngestedFileKind::Theme),
+            SniffedFile::Unknown => None,
+        }
+    }
+}
+
+/// Routes dropped files to the importer matching their content
+pub struct FileIngestService {
+    character_service: Arc<CharacterService>,
+    chat_service: Arc<ChatService>,
+    world_info_service: Arc<WorldInfoService>,
+    preset_service: Arc<PresetService>,
+    theme_service: Arc<ThemeService>,
+}
+
+impl FileIngestService {
+    pub fn new(
+        character_service: Arc<CharacterService>,
+        chat_service: Arc<ChatService>,
+        world_info_service: Arc<WorldInfoService>,
+        preset_service: Arc<PresetService>,
+        theme_service: Arc<ThemeService>,
+    ) -> Self {
+        Self {
+            character_service,
+            chat_service,
+            world_info_service,
+            preset_service,
+            theme_service,
+        }
+    }
+
+    /// Import every file independently. A file that fails does not stop the others;
+    /// its error is reported in its result.
+    pub async fn ingest_files(&self, dto: IngestFilesDto) -> Vec<IngestFileResultDto> {
+        let mut results = Vec::with_capacity(dto.paths.len());
+        for path in &dto.paths {
+            let (kind, outcome) = match read_and_sniff(Path::new(path)).await {
+                Ok(sniffed) => (sniffed.kind(), self.ingest_file(path, sniffed, &dto).await),
+                Err(error) => (None, Err(error)),
+            };
+            let (imported, error) = match outcome {
+                Ok(imported) => (imported, None),
+                Err(error) => {
+                    tracing::warn!("Failed to ingest {}: {}", path, error);
+                    (Vec::new(), Some(error.to_string()))
+                }
+            };
+            results.push(IngestFileResultDto {
+                path: path.clone(),
+                kind,
+                imported,
+                error,
+            });
+        }
+        results
+    }
+
+    async fn ingest_file(
+        &self,
+        path: &str,
+        sniffed: SniffedFile,
+        dto: &IngestFilesDto,
+    ) -> Result<Vec<String>, ApplicationError> {
+        match sniffed {
+            SniffedFile::Character => {
+                let character = self
+                    .character_service
+                    .import_character(ImportCharacterDto {
+                        file_path: path.to_string(),
+                        preserve_file_name: None,
+                    })
+                    .await?;
+                Ok(vec![character.avatar])
+            }
+            SniffedFile::Chat => self.ingest_chat(path, dto).await,
+            SniffedFile::WorldInfo => {
+                let original_filename = Path::new(path)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let name = self
+                    .world_info_service
+                    .import_world_info(path, &original_filename, None)
+                    .await?;
+                Ok(vec![name])
+            }
+            SniffedFile::PresetBundle(bundle) => self.import_presets(bundle).await,
+            SniffedFile::Presets(presets) => {
+                let bundle = serde_json::to_value(PresetBundle {
+                    format: PRESET_BUNDLE_FORMAT.to_string(),
+                    version: PRESET_BUNDLE_VERSION,
+                    generator: String::new(),
+                    exported_at: String::new(),
+                    presets,
+                })
+                .map_err(|error| ApplicationError::InternalError(error.to_string()))?;
+                self.import_presets(bundle).await
+            }
+            SniffedFile::Theme => {
+                let theme = self
+                    .theme_service
+                    .import_theme(Path::new(path), false)
+                    .await?;
+                Ok(vec![theme.name])
+            }
+            SniffedFile::Unknown => Err(ApplicationError::ValidationError(
+                "Unrecognized file type".to_string(),
+            )),
+        }
+    }
+
+    async fn ingest_chat(
+        &self,
+        path: &str,
+        dto: &IngestFilesDto,
+    ) -> Result<Vec<String>, ApplicationError> {
+        let detection = self
+            .chat_service
+            .detect_chat_format(DetectChatFormatDto {
+                file_path: path.to_string(),
+            })
+            .await?;
+        let file_type = detection.file_type.ok_or_else(|| {
+            ApplicationError::ValidationError("Unrecognized chat format".to_string())
+        })?;
+        let character_name = dto
+            .character_name
+            .as_deref()
+            .filter(|name| !name.trim().is_empty())
+            .ok_or_else(|| {
+                ApplicationError::ValidationError(
+                    "Select a character before importing chats".to_string(),
+                )
+            })?;
+
+        self.chat_service
+            .import_character_chats(ImportCharacterChatsDto {
+                character_name: character_name.to_string(),
+                character_display_name: dto.character_display_name.clone(),
+                user_name: dto.user_name.clone(),
+                file_path: path.to_string(),
+                file_type,
+            })
+            .await
+    }
+
+    async fn import_presets(&self, bundle: Value) -> Result<Vec<String>, ApplicationError> {
+        let imported = self
+            .preset_service
+            .import_preset_bundle(bundle, false)
+            .await?;
+        Ok(imported.into_iter().map(|preset| preset.name).collect())
+    }
+}
+
+async fn read_and_sniff(path: &Path) -> Result<SniffedFile, ApplicationError> {
+    let metadata = tokio::fs::metadata(path).await.map_err(|error| {
+        ApplicationError::NotFound(format!("Cannot read {}: {}", path.display(), error))
+    })?;
+    if !metadata.is_file() {
+        return Err(ApplicationError::ValidationError(format!(
+            "{} is not a file",
+            path.display()
+        )));
+    }
+    if metadata.len() > MAX_INGEST_FILE_BYTES {
+        return Err(ApplicationError::ValidationError(format!(
+            "File exceeds {} bytes",
+            MAX_INGEST_FILE_BYTES
+        )));
+    }
+
+    let bytes = tokio::fs::read(path).await.map_err(|error| {
+        ApplicationError::InternalError(format!("Failed to read {}: {}", path.display(), error))
+    })?;
+    Ok(sniff_file(path, &bytes))
+}
+
+fn sniff_file(path: &Path, bytes: &[u8]) -> SniffedFile {
+    if bytes.starts_with(PNG_SIGNATURE) {
+        return SniffedFile::Character;
+    }
+    // Exported theme archives are the only zip files the importers accept.
+    if bytes.starts_with(ZIP_SIGNATURE) {
+        return SniffedFile::Theme;
+    }
+
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    if extension == "jsonl" {
+        return SniffedFile::Chat;
+    }
+
+    match serde_json::from_slice::<Value>(bytes) {
+        Ok(Value::Object(object)) => sniff_json_object(path, object),
+        // Several chat exports are JSON arrays.
+        Ok(Value::Array(_)) => SniffedFile::Chat,
+        Ok(_) => SniffedFile::Unknown,
+        // JSONL without the extension: the first line parses on its own.
+        Err(_) => {
+            let first_line = bytes
+                .split(|byte| *byte == b'\n')
+                .next()
+                .unwrap_or_default();
+            if serde_json::from_slice::<Map<String, Value>>(first_line).is_ok() {
+                SniffedFile::Chat
+            } else {
+                SniffedFile::Unknown
+            }
+        }
+    }
+}
+
+fn sniff_json_object(path: &Path, object: Map<String, Value>) -> SniffedFile {
+    let has = |key: &str| object.contains_key(key);
+
+    if object.get("format").and_then(Value::as_str) == Some(PRESET_BUNDLE_FORMAT) {
+        return SniffedFile::PresetBundle(Value::Object(object));
+    }
+    if object.get("entries").is_some_and(Value::is_object) {
+        return SniffedFile::WorldInfo;
+    }
+    let is_card_spec = object
+        .get("spec")
+        .and_then(Value::as_str)
+        .is_some_and(|spec| spec.starts_with("chara_card"));
+    if is_card_spec || (has("name") && (has("first_mes") || has("char_greeting"))) {
+        return SniffedFile::Character;
+    }
+    if THEME_MARKER_KEYS.iter().any(|key| has(key)) {
+        return SniffedFile::Theme;
+    }
+
+    let master_export: Vec<PresetBundleEntry> = MASTER_EXPORT_SECTIONS
+        .iter()
+        .filter_map(|(key, preset_type)| {
+            let data = object.get(*key).filter(|value| value.is_object())?;
+            Some(preset_entry(path, preset_type, data.clone()))
+        })
+        .collect();
+    if !master_export.is_empty() {
+        return SniffedFile::Presets(master_export);
+    }
+
+    match single_preset_type(&object) {
+        Some(preset_type) => SniffedFile::Presets(vec![preset_entry(
+            path,
+            &preset_type,
+            Value::Object(object),
+        )]),
+        // Remaining JSON objects may be chat exports from other frontends.
+        None => SniffedFile::Chat,
+    }
+}
+
+/// Text completion presets (Kobold, NovelAI, TextGen) share their sampler keys and
+/// cannot be told apart, so only prompt-level presets are recognized.
+fn single_preset_type(object: &Map<String, Value>) -> Option<PresetType> {
+    let has = |key: &str| object.contains_key(key);
+
+    if has("prompts") || has("prompt_order") || has("chat_completion_source") {
+        Some(PresetType::OpenAI)
+    } else if has("input_sequence") && has("output_sequence") {
+        Some(PresetType::Instruct)
+    } else if has("story_string") {
+        Some(PresetType::Context)
+    } else if has("prefix") && has("suffix") && has("separator") {
+        Some(PresetType::Reasoning)
+    } else if has("name")
+        && has("content")
+        && object
+            .keys()
+            .all(|key| matches!(key.as_str(), "name" | "content" | "post_history"))
+    {
+        Some(PresetType::SysPrompt)
+    } else {
+        None
+    }
+}
+
+fn preset_entry(path: &Path, preset_type: &PresetType, data: Value) -> PresetBundleEntry {
+    let name = data
+        .get("name")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .or_else(|| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().trim().to_string())
+        })
+        .unwrap_or_default();
+
+    PresetBundleEntry {
+        name,
+        api_id: preset_type.to_api_id().to_string(),
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::{SniffedFile, sniff_file};
+
+    fn sniff(file_name: &str, value: serde_json::Value) -> SniffedFile {
+        sniff_file(Path::new(file_name), value.to_string().as_bytes())
+    }
+
+    fn preset_api_ids(sniffed: SniffedFile) -> Vec<(String, String)> {
+        match sniffed {
+            SniffedFile::Presets(presets) => presets
+                .into_iter()
+                .map(|preset| (preset.name, preset.api_id))
+                .collect(),
+            other => panic!("expected presets, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn sniffs_files_by_content() {
+        assert!(matches!(
+            sniff_file(Path::new("card.bin"), b"\x89PNG\r\n\x1a\nrest"),
+            SniffedFile::Character
+        ));
+        assert!(matches!(
+            sniff_file(Path::new("theme.zip"), b"PK\x03\x04rest"),
+            SniffedFile::Theme
+        ));
+        assert!(matches!(
+            sniff("card.json", json!({"spec": "chara_card_v3", "data": {}})),
+            SniffedFile::Character
+        ));
+        assert!(matches!(
+            sniff("lore.json", json!({"entries": {"0": {"key": ["a"]}}})),
+            SniffedFile::WorldInfo
+        ));
+        assert!(matches!(
+            sniff("dark.json", json!({"name": "Dark", "blur_strength": 10})),
+            SniffedFile::Theme
+        ));
+        assert!(matches!(
+            sniff_file(
+                Path::new("chat.txt"),
+                b"{\"user_name\":\"User\"}\n{\"mes\":\"hi\"}\n"
+            ),
+            SniffedFile::Chat
+        ));
+        assert!(matches!(
+            sniff_file(Path::new("notes.txt"), b"hello"),
+            SniffedFile::Unknown
+        ));
+    }
+
+    #[test]
+    fn sniffs_prompt_presets_and_master_exports() {
+        assert_eq!(
+            preset_api_ids(sniff(
+                "My Preset.json",
+                json!({"prompts": [], "temperature": 1})
+            )),
+            vec![("My Preset".to_string(), "openai".to_string())]
+        );
+        assert_eq!(
+            preset_api_ids(sniff(
+                "export.json",
+                json!({
+                    "instruct": {"name": "ChatML", "input_sequence": "", "output_sequence": ""},
+                    "context": {"name": "ChatML", "story_string": ""}
+                })
+            )),
+            vec![
+                ("ChatML".to_string(), "instruct".to_string()),
+                ("ChatML".to_string(), "context".to_string())
+            ]
+        );
+        assert!(matches!(
+            sniff(
+                "bundle.json",
+                json!({"format": "tauritavern-preset-bundle", "version": 1, "presets": []})
+            ),
+            SniffedFile::PresetBundle(_)
+        ));
+    }
+}
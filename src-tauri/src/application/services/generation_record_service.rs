@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
+
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+use crate::application::dto::generation_record_dto::{ChatMessageRefDto, GenerationSnapshotDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::chat_completion_repository::ChatCompletionSource;
+use crate::domain::repositories::chat_repository::ChatRepository;
+use crate::domain::repositories::group_chat_repository::GroupChatRepository;
+
+/// Request field naming the message (or swipe) the generation is recorded on; stripped
+/// before generation.
+pub const GENERATION_RECORD_REQUEST_FIELD: &str = "_tauritavern_message_ref";
+/// Message `extra` field holding the generation snapshot.
+const GENERATION_EXTRA_FIELD: &str = "tauritavern_generation";
+const SAMPLER_FIELDS: &[&str] = &[
+    "temperature",
+    "top_p",
+    "top_k",
+    "top_a",
+    "min_p",
+    "max_tokens",
+    "max_completion_tokens",
+    "presence_penalty",
+    "frequency_penalty",
+    "repetition_penalty",
+    "stop",
+    "logit_bias",
+    "reasoning_effort",
+];
+
+/// Snapshot of a generation waiting for the generation to succeed.
+pub struct PendingGenerationRecord {
+    message: ChatMessageRefDto,
+    snapshot: GenerationSnapshotDto,
+}
+
+/// Records the provider, model, seed and sampler a message was generated with into the
+/// message's `extra`, and replays them for reproducible swipes. Backend-side overrides
+/// (character sampler overrides, stop and bias rules) are applied again on replay.
+pub struct GenerationRecordService {
+    chat_repository: Arc<dyn ChatRepository>,
+    group_chat_repository: Arc<dyn GroupChatRepository>,
+}
+
+impl GenerationRecordService {
+    pub fn new(
+        chat_repository: Arc<dyn ChatRepository>,
+        group_chat_repository: Arc<dyn GroupChatRepository>,
+    ) -> Self {
+        Self {
+            chat_repository,
+            group_chat_repository,
+        }
+    }
+
+    /// Takes the `_tauritavern_message_ref` field and snapshots the request. Sources that
+    /// honor seeds get one pinned when the request leaves it random.
+    pub fn prepare(
+        &self,
+        payload: &mut Map<String, Value>,
+    ) -> Result<Option<PendingGenerationRecord>, ApplicationError> {
+        let Some(message) = payload.remove(GENERATION_RECORD_REQUEST_FIELD) else {
+            return Ok(None);
+        };
+        let message: ChatMessageRefDto = serde_json::from_value(message).map_err(|error| {
+            ApplicationError::ValidationError(format!(
+                "Invalid chat completion request field {GENERATION_RECORD_REQUEST_FIELD}: {error}"
+            ))
+        })?;
+
+        if request_seed(payload).is_none() && source_supports_seed(payload) {
+            payload.insert("seed".to_string(), Value::from(rand::random::<u32>()));
+        }
+        Ok(Some(PendingGenerationRecord {
+            message,
+            snapshot: capture_snapshot(payload),
+        }))
+    }
+
+    /// Stores the snapshot on the message once its generation succeeded.
+    pub async fn record(&self, pending: PendingGenerationRecord) -> Result<(), ApplicationError> {
+        let value = serde_json::to_value(&pending.snapshot).map_err(|error| {
+            ApplicationError::InternalError(format!(
+                "Failed to serialize generation snapshot: {error}"
+            ))
+        })?;
+        let fields = Map::from_iter([(GENERATION_EXTRA_FIELD.to_string(), value)]);
+        let message = &pending.message;
+
+        match &message.chat {
+            ChatMetadataTargetDto::Character {
+                character_name,
+                file_name,
+            } => {
+                self.chat_repository
+                    .update_character_chat_message_extra(
+                        character_name,
+                        file_name,
+                        message.message_index,
+                        message.swipe_id,
+                        fields,
+                    )
+                    .await?
+            }
+            ChatMetadataTargetDto::Group { chat_id } => {
+                self.group_chat_repository
+                    .update_group_chat_message_extra(
+                        chat_id,
+                        message.message_index,
+                        message.swipe_id,
+                        fields,
+                    )
+                    .await?
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn get_generation_snapshot(
+        &self,
+        message: &ChatMessageRefDto,
+    ) -> Result<GenerationSnapshotDto, ApplicationError> {
+        let value = match &message.chat {
+            ChatMetadataTargetDto::Character {
+                character_name,
+                file_name,
+            } => {
+                self.chat_repository
+                    .get_character_chat_message(character_name, file_name, message.message_index)
+                    .await?
+            }
+            ChatMetadataTargetDto::Group { chat_id } => {
+                self.group_chat_repository
+                    .get_group_chat_message(chat_id, message.message_index)
+                    .await?
+            }
+        };
+
+        snapshot_from_message(&value, message.swipe_id).ok_or_else(|| {
+            ApplicationError::NotFound(format!(
+                "generation_record.not_recorded: message {} has no recorded generation settings",
+                message.message_index
+            ))
+        })
+    }
+
+    /// Overwrites the request's source, model, seed and sampler with the ones recorded
+    /// on `message`.
+    pub async fn apply_recorded_settings(
+        &self,
+        message: &ChatMessageRefDto,
+        payload: &mut Map<String, Value>,
+    ) -> Result<(), ApplicationError> {
+        let snapshot = self.get_generation_snapshot(message).await?;
+        apply_snapshot(&snapshot, payload);
+        Ok(())
+    }
+}
+
+fn request_seed(payload: &Map<String, Value>) -> Option<i64> {
+    payload
+        .get("seed")
+        .and_then(Value::as_i64)
+        .filter(|seed| *seed >= 0)
+}
+
+fn source_supports_seed(payload: &Map<String, Value>) -> bool {
+    let source = payload
+        .get("chat_completion_source")
+        .and_then(Value::as_str)
+        .unwrap_or(ChatCompletionSource::OpenAi.key());
+    matches!(
+        ChatCompletionSource::parse(source),
+        Some(
+            ChatCompletionSource::OpenAi
+                | ChatCompletionSource::Custom
+                | ChatCompletionSource::Makersuite
+                | ChatCompletionSource::VertexAi
+                | ChatCompletionSource::Cohere
+        )
+    )
+}
+
+fn capture_snapshot(payload: &Map<String, Value>) -> GenerationSnapshotDto {
+    let string_field = |key: &str| {
+        payload
+            .get(key)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    GenerationSnapshotDto {
+        source: string_field("chat_completion_source"),
+        model: string_field("model"),
+        seed: request_seed(payload),
+        sampler: SAMPLER_FIELDS
+            .iter()
+            .filter_map(|key| {
+                payload
+                    .get(*key)
+                    .filter(|value| !value.is_null())
+                    .map(|value| (key.to_string(), value.clone()))
+            })
+            .collect(),
+    }
+}
+
+fn apply_snapshot(snapshot: &GenerationSnapshotDto, payload: &mut Map<String, Value>) {
+    if !snapshot.source.is_empty() {
+        payload.insert(
+            "chat_completion_source".to_string(),
+            Value::String(snapshot.source.clone()),
+        );
+    }
+    if !snapshot.model.is_empty() {
+        payload.insert("model".to_string(), Value::String(snapshot.model.clone()));
+    }
+    match snapshot.seed {
+        Some(seed) => payload.insert("seed".to_string(), Value::from(seed)),
+        None => payload.remove("seed"),
+    };
+    for key in SAMPLER_FIELDS {
+        match snapshot.sampler.get(*key) {
+            Some(value) => payload.insert(key.to_string(), value.clone()),
+            None => payload.remove(*key),
+        };
+    }
+}
+
+/// The swipe's snapshot when `swipe_id` is given, otherwise the message's.
+fn snapshot_from_message(
+    message: &Value,
+    swipe_id: Option<usize>,
+) -> Option<GenerationSnapshotDto> {
+    let holder = match swipe_id {
+        Some(swipe_id) => message.get("swipe_info")?.get(swipe_id)?,
+        None => message,
+    };
+    let snapshot = holder.get("extra")?.get(GENERATION_EXTRA_FIELD)?;
+    serde_json::from_value(snapshot.clone()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{apply_snapshot, capture_snapshot, snapshot_from_message};
+
+    #[test]
+    fn snapshot_round_trips_through_swipe_extra() {
+        let Value::Object(request) = json!({
+            "chat_completion_source": "openai",
+            "model": "gpt-4o",
+            "seed": 42,
+            "temperature": 0.7,
+            "top_p": null,
+            "messages": [],
+        }) else {
+            unreachable!();
+        };
+        let snapshot = capture_snapshot(&request);
+        assert_eq!(snapshot.seed, Some(42));
+        assert_eq!(snapshot.sampler.len(), 1);
+
+        let message = json!({
+            "swipe_info": [
+                {},
+                { "extra": { "tauritavern_generation": serde_json::to_value(&snapshot).unwrap() } },
+            ],
+        });
+        assert!(snapshot_from_message(&message, Some(0)).is_none());
+        let recorded = snapshot_from_message(&message, Some(1)).expect("swipe snapshot");
+        assert_eq!(recorded, snapshot);
+
+        let Value::Object(mut replay) = json!({
+            "chat_completion_source": "claude",
+            "model": "claude-sonnet",
+            "temperature": 1.0,
+            "top_k": 40,
+            "messages": [{ "role": "user", "content": "Hi" }],
+        }) else {
+            unreachable!();
+        };
+        apply_snapshot(&recorded, &mut replay);
+        assert_eq!(replay["chat_completion_source"], "openai");
+        assert_eq!(replay["model"], "gpt-4o");
+        assert_eq!(replay["seed"], 42);
+        assert_eq!(replay["temperature"], 0.7);
+        assert!(!replay.contains_key("top_k"));
+        assert_eq!(replay["messages"][0]["content"], "Hi");
+    }
+}
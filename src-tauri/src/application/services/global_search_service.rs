@@ -0,0 +1,385 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::application::dto::global_search_dto::{GlobalSearchHitDto, GlobalSearchKind};
+use crate::application::errors::ApplicationError;
+use crate::application::services::character_note_service::{CharacterNoteService, snippet_around};
+use crate::domain::models::preset::PresetType;
+use crate::domain::repositories::character_repository::CharacterRepository;
+use crate::domain::repositories::chat_repository::ChatRepository;
+use crate::domain::repositories::preset_repository::PresetRepository;
+use crate::domain::repositories::world_info_repository::WorldInfoRepository;
+
+const MAX_HITS: usize = 50;
+const MAX_HITS_PER_KIND: usize = 20;
+const BODY_MATCH_SCORE: f32 = 0.5;
+const CHAT_CONTENT_SCORE: f32 = 0.4;
+
+const PRESET_TYPES: [PresetType; 8] = [
+    PresetType::OpenAI,
+    PresetType::TextGen,
+    PresetType::Kobold,
+    PresetType::Novel,
+    PresetType::Instruct,
+    PresetType::Context,
+    PresetType::SysPrompt,
+    PresetType::Reasoning,
+];
+
+/// Lowercased query plus its whitespace-separated terms.
+struct SearchQuery {
+    text: String,
+    terms: Vec<String>,
+}
+
+impl SearchQuery {
+    fn new(query: &str) -> Self {
+        let text = query
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        let terms = text.split(' ').map(str::to_string).collect();
+        Self { text, terms }
+    }
+
+    /// Scores a name-like field: exact, prefix and substring matches rank above
+    /// names that merely contain every term.
+    fn title_score(&self, title: &str) -> Option<f32> {
+        let title = title.to_lowercase();
+        if title == self.text {
+            Some(1.0)
+        } else if title.starts_with(&self.text) {
+            Some(0.9)
+        } else if title.contains(&self.text) {
+            Some(0.8)
+        } else if self.contains_all(&title) {
+            Some(0.7)
+        } else {
+            None
+        }
+    }
+
+    fn contains_all(&self, lowered: &str) -> bool {
+        self.terms
+            .iter()
+            .all(|term| lowered.contains(term.as_str()))
+    }
+
+    /// Snippet of a body field containing every term.
+    fn body_snippet(&self, body: &str) -> Option<String> {
+        if body.is_empty() || !self.contains_all(&body.to_lowercase()) {
+            return None;
+        }
+        Some(snippet_around(body, &self.terms[0]))
+    }
+}
+
+/// Spotlight-style search fanning out to characters, character notes, world info,
+/// presets and chats. Each source contributes at most [`MAX_HITS_PER_KIND`] hits; a
+/// source that fails is logged and skipped so the others still answer.
+pub struct GlobalSearchService {
+    character_repository: Arc<dyn CharacterRepository>,
+    character_note_service: Arc<CharacterNoteService>,
+    world_info_repository: Arc<dyn WorldInfoRepository>,
+    preset_repository: Arc<dyn PresetRepository>,
+    chat_repository: Arc<dyn ChatRepository>,
+}
+
+impl GlobalSearchService {
+    pub fn new(
+        character_repository: Arc<dyn CharacterRepository>,
+        character_note_service: Arc<CharacterNoteService>,
+        world_info_repository: Arc<dyn WorldInfoRepository>,
+        preset_repository: Arc<dyn PresetRepository>,
+        chat_repository: Arc<dyn ChatRepository>,
+    ) -> Self {
+        Self {
+            character_repository,
+            character_note_service,
+            world_info_repository,
+            preset_repository,
+            chat_repository,
+        }
+    }
+
+    pub async fn search(&self, query: &str) -> Result<Vec<GlobalSearchHitDto>, ApplicationError> {
+        let query = SearchQuery::new(query);
+        if query.text.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let (characters, notes, worlds, presets, chats) = tokio::join!(
+            self.search_characters(&query),
+            self.search_character_notes(&query),
+            self.search_world_info(&query),
+            self.search_presets(&query),
+            self.search_chats(&query),
+        );
+
+        let mut hits = Vec::new();
+        for (source, result) in [
+            ("characters", characters),
+            ("character notes", notes),
+            ("world info", worlds),
+            ("presets", presets),
+            ("chats", chats),
+        ] {
+            match result {
+                Ok(mut source_hits) => {
+                    sort_hits(&mut source_hits);
+                    source_hits.truncate(MAX_HITS_PER_KIND);
+                    hits.extend(source_hits);
+                }
+                Err(error) => tracing::warn!("Global search skipped {}: {}", source, error),
+            }
+        }
+
+        sort_hits(&mut hits);
+        hits.truncate(MAX_HITS);
+        Ok(hits)
+    }
+
+    async fn search_characters(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<Vec<GlobalSearchHitDto>, ApplicationError> {
+        let characters = self.character_repository.find_all(false).await?;
+        let hits = characters
+            .into_iter()
+            .filter_map(|character| {
+                let name = if character.name.trim().is_empty() {
+                    character.data.name.clone()
+                } else {
+                    character.name.clone()
+                };
+                let description = if character.description.trim().is_empty() {
+                    &character.data.description
+                } else {
+                    &character.description
+                };
+
+                let (score, snippet) = match query.title_score(&name) {
+                    Some(score) => (score, None),
+                    None => (BODY_MATCH_SCORE, Some(query.body_snippet(description)?)),
+                };
+                Some(GlobalSearchHitDto {
+                    kind: GlobalSearchKind::Character,
+                    id: character.avatar,
+                    title: name,
+                    parent: None,
+                    snippet,
+                    score,
+                })
+            })
+            .collect();
+
+        Ok(hits)
+    }
+
+    async fn search_character_notes(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<Vec<GlobalSearchHitDto>, ApplicationError> {
+        let results = self
+            .character_note_service
+            .search_notes(&query.text)
+            .await?;
+        Ok(results
+            .into_iter()
+            .map(|result| GlobalSearchHitDto {
+                kind: GlobalSearchKind::CharacterNote,
+                title: result
+                    .avatar
+                    .strip_suffix(".png")
+                    .unwrap_or(&result.avatar)
+                    .to_string(),
+                id: result.avatar,
+                parent: None,
+                snippet: Some(result.snippet),
+                score: BODY_MATCH_SCORE,
+            })
+            .collect())
+    }
+
+    async fn search_world_info(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<Vec<GlobalSearchHitDto>, ApplicationError> {
+        let mut hits = Vec::new();
+        for world in self.world_info_repository.list_world_names().await? {
+            if let Some(score) = query.title_score(&world) {
+                hits.push(GlobalSearchHitDto {
+                    kind: GlobalSearchKind::World,
+                    id: world.clone(),
+                    title: world.clone(),
+                    parent: None,
+                    snippet: None,
+                    score,
+                });
+            }
+
+            let Some(data) = self
+                .world_info_repository
+                .get_world_info(&world, false)
+                .await?
+            else {
+                continue;
+            };
+            for entry in world_info_entries(&data) {
+                if let Some(hit) = world_info_entry_hit(query, &world, entry) {
+                    hits.push(hit);
+                }
+            }
+        }
+
+        Ok(hits)
+    }
+
+    async fn search_presets(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<Vec<GlobalSearchHitDto>, ApplicationError> {
+        let mut hits = Vec::new();
+        for preset_type in &PRESET_TYPES {
+            for name in self.preset_repository.list_presets(preset_type).await? {
+                let Some(score) = query.title_score(&name) else {
+                    continue;
+                };
+                hits.push(GlobalSearchHitDto {
+                    kind: GlobalSearchKind::Preset,
+                    id: name.clone(),
+                    title: name,
+                    parent: Some(preset_type.to_api_id().to_string()),
+                    snippet: None,
+                    score,
+                });
+            }
+        }
+
+        Ok(hits)
+    }
+
+    /// Chat content is matched through the chat summary index, the same path the
+    /// chat browser's search uses.
+    async fn search_chats(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<Vec<GlobalSearchHitDto>, ApplicationError> {
+        let results = self.chat_repository.search_chats(&query.text, None).await?;
+        Ok(results
+            .into_iter()
+            .map(|result| {
+                let title = result
+                    .file_name
+                    .strip_suffix(".jsonl")
+                    .unwrap_or(&result.file_name)
+                    .to_string();
+                GlobalSearchHitDto {
+                    kind: GlobalSearchKind::Chat,
+                    score: query.title_score(&title).unwrap_or(CHAT_CONTENT_SCORE),
+                    snippet: (!result.preview.is_empty()).then_some(result.preview),
+                    id: result.file_name,
+                    title,
+                    parent: Some(result.character_name),
+                }
+            })
+            .collect())
+    }
+}
+
+/// World info entries are stored either as an object keyed by uid or as an array.
+fn world_info_entries(data: &Value) -> Vec<&Value> {
+    match data.get("entries") {
+        Some(Value::Object(entries)) => entries.values().collect(),
+        Some(Value::Array(entries)) => entries.iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn world_info_entry_hit(
+    query: &SearchQuery,
+    world: &str,
+    entry: &Value,
+) -> Option<GlobalSearchHitDto> {
+    let uid = entry.get("uid").map(|uid| match uid {
+        Value::String(uid) => uid.clone(),
+        other => other.to_string(),
+    })?;
+    let comment = entry.get("comment").and_then(Value::as_str).unwrap_or("");
+    let keys = entry
+        .get("key")
+        .and_then(Value::as_array)
+        .map(|keys| {
+            keys.iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .unwrap_or_default();
+    let content = entry.get("content").and_then(Value::as_str).unwrap_or("");
+    let title = if comment.trim().is_empty() {
+        keys.clone()
+    } else {
+        comment.to_string()
+    };
+
+    let (score, snippet) = match query
+        .title_score(&title)
+        .or_else(|| query.title_score(&keys))
+    {
+        Some(score) => (score * 0.95, None),
+        None => (BODY_MATCH_SCORE, Some(query.body_snippet(content)?)),
+    };
+    Some(GlobalSearchHitDto {
+        kind: GlobalSearchKind::WorldInfoEntry,
+        id: uid,
+        title,
+        parent: Some(world.to_string()),
+        snippet,
+        score,
+    })
+}
+
+fn sort_hits(hits: &mut [GlobalSearchHitDto]) {
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.kind.cmp(&b.kind))
+            .then_with(|| a.title.to_lowercase().cmp(&b.title.to_lowercase()))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{SearchQuery, world_info_entries, world_info_entry_hit};
+
+    #[test]
+    fn names_outrank_body_matches() {
+        let query = SearchQuery::new("  Dragon  Hill ");
+        assert_eq!(query.title_score("dragon hill"), Some(1.0));
+        assert_eq!(query.title_score("Dragon Hill Keep"), Some(0.9));
+        assert_eq!(query.title_score("Hill of the Dragon"), Some(0.7));
+        assert_eq!(query.title_score("Dragon"), None);
+
+        let data = json!({
+            "entries": {
+                "3": { "uid": 3, "comment": "Lore", "key": ["keep"], "content": "A dragon sleeps on the hill." },
+                "4": { "uid": 4, "comment": "Other", "key": ["sea"], "content": "Waves." },
+            }
+        });
+        let hits: Vec<_> = world_info_entries(&data)
+            .into_iter()
+            .filter_map(|entry| world_info_entry_hit(&query, "World", entry))
+            .collect();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, "3");
+        assert_eq!(hits[0].parent.as_deref(), Some("World"));
+        assert!(hits[0].snippet.is_some());
+    }
+}
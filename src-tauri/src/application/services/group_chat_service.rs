@@ -12,8 +12,9 @@ use crate::application::services::agent_workspace_lifecycle_service::AgentWorksp
 use crate::application::services::chat_file_validation::validate_chat_file_name;
 use crate::domain::errors::DomainError;
 use crate::domain::repositories::chat_types::{
-    ChatMessageSearchHit, ChatMessageSearchQuery, ChatPayloadChunk, ChatPayloadCursor,
-    ChatPayloadPatchOp, ChatPayloadTail, FindLastMessageQuery, LocatedChatMessage, PinnedGroupChat,
+    ChatMessageCursor, ChatMessageLocateQuery, ChatMessageSearchHit, ChatMessageSearchQuery,
+    ChatPayloadChunk, ChatPayloadCursor, ChatPayloadForwardChunk, ChatPayloadPatchOp,
+    ChatPayloadTail, FindLastMessageQuery, LocatedChatMessage, PinnedGroupChat,
 };
 use crate::domain::repositories::group_chat_repository::GroupChatRepository;
 
@@ -253,6 +254,31 @@ impl GroupChatService {
             .map_err(Into::into)
     }
 
+    /// Get JSONL lines starting at a group chat window cursor, paging forward.
+    pub async fn get_group_chat_payload_after_lines(
+        &self,
+        chat_id: &str,
+        cursor: ChatPayloadCursor,
+        max_lines: usize,
+    ) -> Result<ChatPayloadForwardChunk, ApplicationError> {
+        self.group_chat_repository
+            .get_group_chat_payload_after_lines(chat_id, cursor, max_lines)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Locate a group chat message and return a cursor at its line.
+    pub async fn locate_group_chat_message(
+        &self,
+        chat_id: &str,
+        query: &ChatMessageLocateQuery,
+    ) -> Result<Option<ChatMessageCursor>, ApplicationError> {
+        self.group_chat_repository
+            .locate_group_chat_message(chat_id, query)
+            .await
+            .map_err(Into::into)
+    }
+
     /// Get multiple windows of JSONL lines before the current group chat window cursor.
     ///
     /// This is equivalent to calling `get_group_chat_payload_before_lines` repeatedly, but returns
@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -5,6 +6,7 @@ use crate::application::dto::group_dto::{CreateGroupDto, DeleteGroupDto, UpdateG
 use crate::application::errors::ApplicationError;
 use crate::application::services::agent_workspace_lifecycle_service::AgentWorkspaceLifecycleService;
 use crate::domain::errors::DomainError;
+use crate::domain::models::avatar::CropInfo;
 use crate::domain::models::group::Group;
 use crate::domain::repositories::group_repository::GroupRepository;
 use crate::infrastructure::logging::logger;
@@ -77,7 +79,7 @@ impl GroupService {
             generation_mode_join_prefix: dto.generation_mode_join_prefix.unwrap_or_default(),
             generation_mode_join_suffix: dto.generation_mode_join_suffix.unwrap_or_default(),
             hide_muted_sprites: dto.hide_muted_sprites.unwrap_or(false),
-            past_metadata: Default::default(),
+            past_metadata: dto.past_metadata,
             date_added: None,
             create_date: None,
             chat_size: None,
@@ -97,6 +99,25 @@ impl GroupService {
         self.repository.update_group(&group).await
     }
 
+    /// Replace the group avatar with the image at `file_path`
+    pub async fn update_group_avatar(
+        &self,
+        id: &str,
+        file_path: &Path,
+        crop: Option<CropInfo>,
+    ) -> Result<Group, DomainError> {
+        logger::debug(&format!("GroupService: Updating avatar of group {}", id));
+        self.repository
+            .update_group_avatar(id, file_path, crop)
+            .await
+    }
+
+    /// Remove the group avatar
+    pub async fn delete_group_avatar(&self, id: &str) -> Result<Group, DomainError> {
+        logger::debug(&format!("GroupService: Deleting avatar of group {}", id));
+        self.repository.delete_group_avatar(id).await
+    }
+
     /// Delete a group
     pub async fn delete_group(&self, dto: DeleteGroupDto) -> Result<(), ApplicationError> {
         logger::debug(&format!("GroupService: Deleting group {}", dto.id));
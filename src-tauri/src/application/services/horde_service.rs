@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde_json::{Value, json};
+use tokio::sync::{Mutex, RwLock, watch};
+
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+use crate::domain::models::secret::SecretKeys;
+use crate::domain::repositories::horde_repository::{HORDE_ANONYMOUS_API_KEY, HordeRepository};
+use crate::domain::repositories::secret_repository::SecretRepository;
+
+const HORDE_LIST_CACHE_TTL: Duration = Duration::from_secs(60);
+const HORDE_POLL_INTERVAL: Duration = Duration::from_millis(2500);
+const HORDE_MAX_POLL_ATTEMPTS: usize = 480;
+
+struct CachedList {
+    fetched_at: Instant,
+    items: Vec<Value>,
+}
+
+pub struct HordeService {
+    horde_repository: Arc<dyn HordeRepository>,
+    secret_repository: Arc<dyn SecretRepository>,
+    models_cache: Mutex<Option<CachedList>>,
+    workers_cache: Mutex<Option<CachedList>>,
+    generation_cancellations: CancellationRegistry,
+}
+
+impl HordeService {
+    pub fn new(
+        horde_repository: Arc<dyn HordeRepository>,
+        secret_repository: Arc<dyn SecretRepository>,
+    ) -> Self {
+        Self {
+            horde_repository,
+            secret_repository,
+            models_cache: Mutex::new(None),
+            workers_cache: Mutex::new(None),
+            generation_cancellations: CancellationRegistry::default(),
+        }
+    }
+
+    pub async fn get_status(&self) -> Result<bool, ApplicationError> {
+        Ok(self.horde_repository.heartbeat().await?)
+    }
+
+    /// Text models, each tagged with the cluster it came from.
+    pub async fn get_text_models(&self, force: bool) -> Result<Vec<Value>, ApplicationError> {
+        let mut cache = self.models_cache.lock().await;
+        if let Some(items) = fresh_items(&cache, force) {
+            return Ok(items);
+        }
+
+        let items = self.tag_with_cluster(self.horde_repository.text_models().await?);
+        *cache = Some(CachedList {
+            fetched_at: Instant::now(),
+            items: items.clone(),
+        });
+        Ok(items)
+    }
+
+    /// Online text workers, each tagged with the cluster it came from.
+    pub async fn get_text_workers(&self, force: bool) -> Result<Vec<Value>, ApplicationError> {
+        let mut cache = self.workers_cache.lock().await;
+        if let Some(items) = fresh_items(&cache, force) {
+            return Ok(items);
+        }
+
+        let items = self.tag_with_cluster(self.horde_repository.text_workers().await?);
+        *cache = Some(CachedList {
+            fetched_at: Instant::now(),
+            items: items.clone(),
+        });
+        Ok(items)
+    }
+
+    /// Horde account (username, kudos, ...) for the configured key, or
+    /// `{ "anonymous": true }` without one.
+    pub async fn get_user_info(&self) -> Result<Value, ApplicationError> {
+        let Some(api_key) = self.read_api_key().await? else {
+            return Ok(json!({ "anonymous": true }));
+        };
+
+        let user = self.horde_repository.find_user(&api_key).await?;
+        Ok(json!({ "anonymous": false, "user": user }))
+    }
+
+    /// Queues a generation and returns the Horde task (`{ "id": ... }`).
+    pub async fn submit_text(&self, payload: Value) -> Result<Value, ApplicationError> {
+        let payload = select_cluster_models(payload, self.horde_repository.cluster())?;
+        let api_key = self
+            .read_api_key()
+            .await?
+            .unwrap_or_else(|| HORDE_ANONYMOUS_API_KEY.to_string());
+        Ok(self.horde_repository.submit_text(&api_key, payload).await?)
+    }
+
+    pub async fn get_task_status(&self, task_id: &str) -> Result<Value, ApplicationError> {
+        Ok(self.horde_repository.text_status(task_id).await?)
+    }
+
+    pub async fn cancel_task(&self, task_id: &str) -> Result<Value, ApplicationError> {
+        Ok(self.horde_repository.cancel_text(task_id).await?)
+    }
+
+    /// Submits a generation and polls it to completion. Cancelling `request_id` withdraws the
+    /// Horde task so no kudos are spent on an unwanted result.
+    pub async fn generate_text(
+        &self,
+        request_id: &str,
+        payload: Value,
+    ) -> Result<Value, ApplicationError> {
+        let cancel = self.generation_cancellations.register(request_id).await;
+        let result = self.run_generation(payload, cancel).await;
+        self.generation_cancellations.complete(request_id).await;
+        result
+    }
+
+    pub async fn cancel_generation(&self, request_id: &str) -> bool {
+        self.generation_cancellations.cancel(request_id).await
+    }
+
+    async fn run_generation(
+        &self,
+        payload: Value,
+        mut cancel: watch::Receiver<bool>,
+    ) -> Result<Value, ApplicationError> {
+        let task = self.submit_text(payload).await?;
+        let task_id = task
+            .get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                ApplicationError::InternalError("Horde did not return a task id".to_string())
+            })?;
+
+        for _ in 0..HORDE_MAX_POLL_ATTEMPTS {
+            if *cancel.borrow() {
+                return Err(self.withdraw_task(&task_id).await);
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(HORDE_POLL_INTERVAL) => {}
+                _ = cancel.changed() => {
+                    return Err(self.withdraw_task(&task_id).await);
+                }
+            }
+
+            let status = self.horde_repository.text_status(&task_id).await?;
+            if status.get("faulted").and_then(Value::as_bool) == Some(true) {
+                return Err(ApplicationError::InternalError(
+                    "Horde generation failed: the worker faulted".to_string(),
+                ));
+            }
+            if status.get("is_possible").and_then(Value::as_bool) == Some(false) {
+                let _ = self.horde_repository.cancel_text(&task_id).await;
+                return Err(ApplicationError::ValidationError(
+                    "No Horde worker can serve this request. Select other models or lower the response length."
+                        .to_string(),
+                ));
+            }
+            if status.get("done").and_then(Value::as_bool) == Some(true) {
+                return Ok(status);
+            }
+        }
+
+        let _ = self.horde_repository.cancel_text(&task_id).await;
+        Err(ApplicationError::InternalError(
+            "Horde generation timed out".to_string(),
+        ))
+    }
+
+    async fn withdraw_task(&self, task_id: &str) -> ApplicationError {
+        if let Err(error) = self.horde_repository.cancel_text(task_id).await {
+            tracing::warn!("Failed to cancel Horde task {}: {}", task_id, error);
+        }
+        DomainError::generation_cancelled_by_user().into()
+    }
+
+    async fn read_api_key(&self) -> Result<Option<String>, ApplicationError> {
+        Ok(self
+            .secret_repository
+            .read_secret(SecretKeys::HORDE, None)
+            .await?
+            .map(|secret| secret.trim().to_string())
+            .filter(|secret| !secret.is_empty()))
+    }
+
+    fn tag_with_cluster(&self, items: Vec<Value>) -> Vec<Value> {
+        let cluster = self.horde_repository.cluster();
+        items
+            .into_iter()
+            .map(|mut item| {
+                if let Value::Object(map) = &mut item {
+                    map.insert("cluster".to_string(), Value::String(cluster.to_string()));
+                }
+                item
+            })
+            .collect()
+    }
+}
+
+fn fresh_items(cache: &Option<CachedList>, force: bool) -> Option<Vec<Value>> {
+    if force {
+        return None;
+    }
+    cache
+        .as_ref()
+        .filter(|cached| cached.fetched_at.elapsed() < HORDE_LIST_CACHE_TTL)
+        .map(|cached| cached.items.clone())
+}
+
+/// `models` may hold plain names or `{ "name", "cluster" }` entries picked from several
+/// clusters; the Horde only accepts names, so keep the ones that belong to `cluster`.
+fn select_cluster_models(mut payload: Value, cluster: &str) -> Result<Value, ApplicationError> {
+    let Some(object) = payload.as_object_mut() else {
+        return Err(ApplicationError::ValidationError(
+            "Horde payload must be an object".to_string(),
+        ));
+    };
+    let Some(models) = object.get("models").and_then(Value::as_array) else {
+        return Ok(payload);
+    };
+
+    let names = models
+        .iter()
+        .filter_map(|model| match model {
+            Value::String(name) => Some(name.clone()),
+            Value::Object(entry) => {
+                let in_cluster = entry
+                    .get("cluster")
+                    .and_then(Value::as_str)
+                    .is_none_or(|entry_cluster| entry_cluster == cluster);
+                if !in_cluster {
+                    return None;
+                }
+                entry
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            }
+            _ => None,
+        })
+        .map(Value::String)
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        return Err(ApplicationError::ValidationError(
+            "No Horde models selected for this cluster".to_string(),
+        ));
+    }
+
+    object.insert("models".to_string(), Value::Array(names));
+    Ok(payload)
+}
+
+#[derive(Default)]
+struct CancellationRegistry {
+    active: RwLock<HashMap<String, watch::Sender<bool>>>,
+}
+
+impl CancellationRegistry {
+    async fn register(&self, request_id: &str) -> watch::Receiver<bool> {
+        let (sender, receiver) = watch::channel(false);
+        let mut active = self.active.write().await;
+
+        if let Some(previous_sender) = active.insert(request_id.to_string(), sender) {
+            let _ = previous_sender.send(true);
+        }
+
+        receiver
+    }
+
+    async fn cancel(&self, request_id: &str) -> bool {
+        let mut active = self.active.write().await;
+        let Some(sender) = active.remove(request_id) else {
+            return false;
+        };
+
+        let _ = sender.send(true);
+        true
+    }
+
+    async fn complete(&self, request_id: &str) {
+        let mut active = self.active.write().await;
+        active.remove(request_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::select_cluster_models;
+    use crate::application::errors::ApplicationError;
+
+    #[test]
+    fn selected_models_are_reduced_to_names_of_the_current_cluster() {
+        let payload = json!({
+            "prompt": "Hi",
+            "models": [
+                "plain-model",
+                { "name": "koboldcpp/a", "cluster": "https://aihorde.net" },
+                { "name": "koboldcpp/b", "cluster": "https://other.example" },
+            ],
+        });
+
+        let payload = select_cluster_models(payload, "https://aihorde.net").expect("payload");
+        assert_eq!(payload["models"], json!(["plain-model", "koboldcpp/a"]));
+        assert_eq!(payload["prompt"], "Hi");
+    }
+
+    #[test]
+    fn models_from_other_clusters_only_are_rejected() {
+        let payload = json!({
+            "models": [{ "name": "koboldcpp/b", "cluster": "https://other.example" }],
+        });
+
+        assert!(matches!(
+            select_cluster_models(payload, "https://aihorde.net"),
+            Err(ApplicationError::ValidationError(_))
+        ));
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter};
+
+use crate::application::dto::chat_completion_dto::ChatCompletionGenerateRequestDto;
+use crate::application::dto::idle_generation_dto::ArmIdleGenerationDto;
+use crate::application::errors::ApplicationError;
+use crate::application::services::chat_completion_service::ChatCompletionService;
+use crate::domain::repositories::settings_repository::SettingsRepository;
+
+pub const IDLE_GENERATION_EVENT: &str = "chat_completion:idle";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IdleGenerationEventKind {
+    /// The timer fired; the stream can be cancelled through `streamId`.
+    Started,
+    Chunk {
+        data: String,
+    },
+    Done,
+    Error {
+        message: String,
+    },
+}
+
+/// Stream event of an idle generation, shaped like a regular chat completion stream
+/// event plus the chat it belongs to and the window that armed it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdleGenerationEvent {
+    chat_id: String,
+    avatar: String,
+    stream_id: String,
+    window_label: String,
+    #[serde(flatten)]
+    kind: IdleGenerationEventKind,
+}
+
+struct IdleTimer {
+    token: u64,
+    handle: JoinHandle<()>,
+}
+
+/// Per-chat inactivity timers. The frontend re-arms a chat's timer on every user
+/// action; once it elapses the armed request is streamed and its chunks are emitted as
+/// `chat_completion:idle`. Timers live in the backend so they keep running while a
+/// mobile webview is suspended.
+pub struct IdleGenerationService {
+    app_handle: AppHandle,
+    settings_repository: Arc<dyn SettingsRepository>,
+    chat_completion_service: Arc<ChatCompletionService>,
+    timers: Mutex<HashMap<String, IdleTimer>>,
+    next_token: AtomicU64,
+}
+
+impl IdleGenerationService {
+    pub fn new(
+        app_handle: AppHandle,
+        settings_repository: Arc<dyn SettingsRepository>,
+        chat_completion_service: Arc<ChatCompletionService>,
+    ) -> Self {
+        Self {
+            app_handle,
+            settings_repository,
+            chat_completion_service,
+            timers: Mutex::new(HashMap::new()),
+            next_token: AtomicU64::new(0),
+        }
+    }
+
+    /// Replaces the chat's timer. Returns `false` (leaving no timer) when idle
+    /// generation is disabled for the character. Events go to `window_label`, the window
+    /// showing the chat.
+    pub async fn arm(
+        self: &Arc<Self>,
+        dto: ArmIdleGenerationDto,
+        window_label: String,
+    ) -> Result<bool, ApplicationError> {
+        let chat_id = dto.chat_id.trim().to_string();
+        if chat_id.is_empty() {
+            return Err(ApplicationError::ValidationError(
+                "idle_generation.chat_id_required: chatId cannot be empty".to_string(),
+            ));
+        }
+
+        let settings = self
+            .settings_repository
+            .load_tauritavern_settings()
+            .await?
+            .idle_generation;
+        let Some(character) = settings.for_character(&dto.avatar).cloned() else {
+            self.cancel(&chat_id);
+            return Ok(false);
+        };
+
+        let mut request = dto.request;
+        append_idle_prompt(&mut request.payload, &character.prompt);
+        let delay = Duration::from_secs(u64::from(character.idle_minutes) * 60);
+
+        let token = self.next_token.fetch_add(1, Ordering::Relaxed);
+        let service = self.clone();
+        let avatar = dto.avatar;
+        let handle = tauri::async_runtime::spawn({
+            let chat_id = chat_id.clone();
+            async move {
+                tokio::time::sleep(delay).await;
+                if !service.take_timer(&chat_id, token) {
+                    return;
+                }
+                service.run(chat_id, avatar, window_label, request).await;
+            }
+        });
+
+        let previous = self
+            .timers
+            .lock()
+            .unwrap()
+            .insert(chat_id, IdleTimer { token, handle });
+        if let Some(previous) = previous {
+            previous.handle.abort();
+        }
+        Ok(true)
+    }
+
+    /// Stops the chat's pending timer; a generation that already started is cancelled
+    /// through its stream id instead. Returns whether a timer was pending.
+    pub fn cancel(&self, chat_id: &str) -> bool {
+        let timer = self.timers.lock().unwrap().remove(chat_id.trim());
+        match timer {
+            Some(timer) => {
+                timer.handle.abort();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the elapsed timer unless it was re-armed in the meantime.
+    fn take_timer(&self, chat_id: &str, token: u64) -> bool {
+        let mut timers = self.timers.lock().unwrap();
+        if timers
+            .get(chat_id)
+            .is_some_and(|timer| timer.token == token)
+        {
+            timers.remove(chat_id);
+            return true;
+        }
+        false
+    }
+
+    async fn run(
+        &self,
+        chat_id: String,
+        avatar: String,
+        window_label: String,
+        request: ChatCompletionGenerateRequestDto,
+    ) {
+        let stream_id = format!("idle-{}", uuid::Uuid::new_v4());
+        let emit = |kind: IdleGenerationEventKind| {
+            let event = IdleGenerationEvent {
+                chat_id: chat_id.clone(),
+                avatar: avatar.clone(),
+                stream_id: stream_id.clone(),
+                window_label: window_label.clone(),
+                kind,
+            };
+            if let Err(error) =
+                self.app_handle
+                    .emit_to(window_label.as_str(), IDLE_GENERATION_EVENT, event)
+            {
+                tracing::warn!("Failed to emit idle generation event: {}", error);
+            }
+        };
+
+        let cancel = self
+            .chat_completion_service
+            .register_stream(&stream_id)
+            .await;
+        emit(IdleGenerationEventKind::Started);
+
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<String>();
+        let generation = self
+            .chat_completion_service
+            .generate_stream(request, sender, cancel);
+        let forwarding = async {
+            while let Some(chunk) = receiver.recv().await {
+                if !chunk.is_empty() {
+                    emit(IdleGenerationEventKind::Chunk { data: chunk });
+                }
+            }
+        };
+        let (result, ()) = tokio::join!(generation, forwarding);
+        self.chat_completion_service
+            .complete_stream(&stream_id)
+            .await;
+
+        match result {
+            Ok(()) => emit(IdleGenerationEventKind::Done),
+            Err(error) => emit(IdleGenerationEventKind::Error {
+                message: error.to_string(),
+            }),
+        }
+    }
+}
+
+fn append_idle_prompt(payload: &mut Map<String, Value>, prompt: &str) {
+    let prompt = prompt.trim();
+    if prompt.is_empty() {
+        return;
+    }
+
+    if let Some(messages) = payload.get_mut("messages").and_then(Value::as_array_mut) {
+        messages.push(json!({ "role": "system", "content": prompt }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::append_idle_prompt;
+
+    #[test]
+    fn idle_prompt_is_appended_as_last_system_message() {
+        let Value::Object(mut payload) = json!({
+            "messages": [{ "role": "user", "content": "Hello" }],
+        }) else {
+            unreachable!();
+        };
+
+        append_idle_prompt(&mut payload, "  ");
+        assert_eq!(payload["messages"].as_array().unwrap().len(), 1);
+
+        append_idle_prompt(&mut payload, "Continue the scene on your own.");
+        assert_eq!(
+            payload["messages"][1],
+            json!({ "role": "system", "content": "Continue the scene on your own." })
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use serde_json::{Map, Value};
+
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::chat_completion_repository::ChatCompletionSource;
+use crate::domain::repositories::inline_image_repository::{
+    InlineImageLimits, InlineImageRepository,
+};
+
+const MIB: usize = 1024 * 1024;
+
+/// Turns local image references in OpenAI-style `image_url` parts into size-limited
+/// data URLs before the provider payload builders run, so every builder only ever sees
+/// inline images. Compression keeps each image below the provider's inline ceiling,
+/// which is why no provider-side file upload is needed.
+pub struct InlineImageService {
+    repository: Arc<dyn InlineImageRepository>,
+}
+
+impl InlineImageService {
+    pub fn new(repository: Arc<dyn InlineImageRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Returns the number of images that were inlined.
+    pub async fn inline_message_images(
+        &self,
+        source: ChatCompletionSource,
+        payload: &mut Map<String, Value>,
+    ) -> Result<usize, ApplicationError> {
+        let Some(messages) = payload.get_mut("messages").and_then(Value::as_array_mut) else {
+            return Ok(0);
+        };
+
+        let limits = provider_limits(source);
+        let mut inlined = 0;
+        for message in messages {
+            let Some(parts) = message.get_mut("content").and_then(Value::as_array_mut) else {
+                continue;
+            };
+
+            for part in parts {
+                let Some(url_value) = local_image_url_mut(part) else {
+                    continue;
+                };
+                let reference = url_value.as_str().unwrap_or_default().to_string();
+
+                let image = self
+                    .repository
+                    .load_inline_image(&reference, limits)
+                    .await?;
+                *url_value = Value::String(format!(
+                    "data:{};base64,{}",
+                    image.mime_type,
+                    BASE64_STANDARD.encode(&image.bytes)
+                ));
+                inlined += 1;
+            }
+        }
+
+        Ok(inlined)
+    }
+}
+
+fn provider_limits(source: ChatCompletionSource) -> InlineImageLimits {
+    match source {
+        ChatCompletionSource::Claude | ChatCompletionSource::AwsBedrock => InlineImageLimits {
+            max_dimension: 1568,
+            max_bytes: 5 * MIB,
+        },
+        ChatCompletionSource::Makersuite | ChatCompletionSource::VertexAi => InlineImageLimits {
+            max_dimension: 3072,
+            max_bytes: 7 * MIB,
+        },
+        _ => InlineImageLimits {
+            max_dimension: 2048,
+            max_bytes: 10 * MIB,
+        },
+    }
+}
+
+/// Returns the `image_url.url` slot of a part when it points at a local file rather
+/// than a data URL or a remote URL.
+fn local_image_url_mut(part: &mut Value) -> Option<&mut Value> {
+    if part.get("type").and_then(Value::as_str) != Some("image_url") {
+        return None;
+    }
+
+    let url = part.get_mut("image_url")?.get_mut("url")?;
+    let reference = url.as_str()?.trim();
+    if reference.is_empty() || is_inline_or_remote_url(reference) {
+        return None;
+    }
+
+    Some(url)
+}
+
+fn is_inline_or_remote_url(reference: &str) -> bool {
+    let lower = reference.get(..8).unwrap_or(reference).to_ascii_lowercase();
+    lower.starts_with("data:") || lower.starts_with("http://") || lower.starts_with("https://")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::local_image_url_mut;
+
+    #[test]
+    fn only_local_image_references_are_selected() {
+        let mut local =
+            json!({ "type": "image_url", "image_url": { "url": "/user/images/a.png" } });
+        let mut inline =
+            json!({ "type": "image_url", "image_url": { "url": "data:image/png;base64,AA==" } });
+        let mut remote =
+            json!({ "type": "image_url", "image_url": { "url": "HTTPS://example.com/a.png" } });
+        let mut text = json!({ "type": "text", "text": "/user/images/a.png" });
+
+        assert!(local_image_url_mut(&mut local).is_some());
+        assert!(local_image_url_mut(&mut inline).is_none());
+        assert!(local_image_url_mut(&mut remote).is_none());
+        assert!(local_image_url_mut(&mut text).is_none());
+    }
+}
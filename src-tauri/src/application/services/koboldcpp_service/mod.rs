@@ -0,0 +1,325 @@
+mod payload;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::{Value, json};
+use tokio::sync::{RwLock, mpsc, watch};
+use url::Url;
+
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+use crate::domain::models::secret::SecretKeys;
+use crate::domain::repositories::chat_completion_repository::ChatCompletionStreamSender;
+use crate::domain::repositories::koboldcpp_repository::{KoboldCppEndpoint, KoboldCppRepository};
+use crate::domain::repositories::secret_repository::SecretRepository;
+
+use self::payload::{build_generate_payload, clamp_context_length};
+
+/// KoboldCpp text completions. Responses are reshaped into the OpenAI completions format
+/// (`choices[0].text`) that the text-completion frontend already parses.
+pub struct KoboldCppService {
+    koboldcpp_repository: Arc<dyn KoboldCppRepository>,
+    secret_repository: Arc<dyn SecretRepository>,
+    cancellations: CancellationRegistry,
+}
+
+impl KoboldCppService {
+    pub fn new(
+        koboldcpp_repository: Arc<dyn KoboldCppRepository>,
+        secret_repository: Arc<dyn SecretRepository>,
+    ) -> Self {
+        Self {
+            koboldcpp_repository,
+            secret_repository,
+            cancellations: CancellationRegistry::default(),
+        }
+    }
+
+    /// Loaded model and the context size the server was started with.
+    pub async fn get_status(&self, api_server: &str) -> Result<Value, ApplicationError> {
+        let endpoint = self.endpoint(api_server).await?;
+        let model = self.koboldcpp_repository.model(&endpoint).await?;
+        let context_length = self
+            .koboldcpp_repository
+            .true_max_context_length(&endpoint)
+            .await
+            .ok();
+
+        Ok(json!({ "result": model, "context_length": context_length }))
+    }
+
+    /// llama.cpp-style `/props` answer so the UI can adopt the server context size.
+    pub async fn get_props(&self, api_server: &str) -> Result<Value, ApplicationError> {
+        let endpoint = self.endpoint(api_server).await?;
+        let context_length = self
+            .koboldcpp_repository
+            .true_max_context_length(&endpoint)
+            .await?;
+
+        Ok(json!({
+            "chat_template": "",
+            "chat_template_hash": "",
+            "default_generation_settings": { "n_ctx": context_length },
+        }))
+    }
+
+    pub async fn generate(&self, request_id: &str, body: Value) -> Result<Value, ApplicationError> {
+        let endpoint = self.endpoint(api_server(&body)?).await?;
+        let payload = self.prepare_payload(&endpoint, &body, request_id).await?;
+
+        let mut cancel = self.cancellations.register(request_id).await;
+        let result = tokio::select! {
+            result = self.koboldcpp_repository.generate(&endpoint, &payload) => {
+                result.map(|response| completion_from_response(&response)).map_err(ApplicationError::from)
+            }
+            _ = cancel.changed() => Err(self.abort(&endpoint, request_id).await),
+        };
+        self.cancellations.complete(request_id).await;
+        result
+    }
+
+    pub async fn register_stream(&self, stream_id: &str) -> watch::Receiver<bool> {
+        self.cancellations.register(stream_id).await
+    }
+
+    /// Streams completion chunks followed by `[DONE]`. Cancelling aborts the generation on
+    /// the server too, so the model stops instead of finishing in the background.
+    pub async fn generate_stream(
+        &self,
+        stream_id: &str,
+        body: Value,
+        sender: ChatCompletionStreamSender,
+        cancel: watch::Receiver<bool>,
+    ) -> Result<(), ApplicationError> {
+        let endpoint = self.endpoint(api_server(&body)?).await?;
+        let payload = self.prepare_payload(&endpoint, &body, stream_id).await?;
+
+        let (event_sender, mut event_receiver) = mpsc::unbounded_channel::<String>();
+        let forward = async {
+            while let Some(event) = event_receiver.recv().await {
+                let Some(chunk) = completion_chunk_from_event(&event) else {
+                    continue;
+                };
+                if sender.send(chunk).is_err() {
+                    break;
+                }
+            }
+        };
+        let (result, ()) = tokio::join!(
+            self.koboldcpp_repository.generate_stream(
+                &endpoint,
+                &payload,
+                event_sender,
+                cancel.clone()
+            ),
+            forward
+        );
+        result?;
+
+        if *cancel.borrow() {
+            return Err(self.abort(&endpoint, stream_id).await);
+        }
+        let _ = sender.send("[DONE]".to_string());
+        Ok(())
+    }
+
+    pub async fn complete_stream(&self, stream_id: &str) {
+        self.cancellations.complete(stream_id).await;
+    }
+
+    /// Cancels a pending generation or stream.
+    pub async fn cancel_request(&self, request_id: &str) -> bool {
+        self.cancellations.cancel(request_id).await
+    }
+
+    /// Token ids for `text` from the loaded model (`{ "count", "ids" }`).
+    pub async fn count_tokens(
+        &self,
+        api_server: &str,
+        text: &str,
+    ) -> Result<Value, ApplicationError> {
+        let endpoint = self.endpoint(api_server).await?;
+        let response = self
+            .koboldcpp_repository
+            .token_count(&endpoint, text)
+            .await?;
+
+        Ok(json!({
+            "count": response.get("value").cloned().unwrap_or(json!(0)),
+            "ids": response.get("ids").cloned().unwrap_or(json!([])),
+        }))
+    }
+
+    async fn prepare_payload(
+        &self,
+        endpoint: &KoboldCppEndpoint,
+        body: &Value,
+        genkey: &str,
+    ) -> Result<Value, ApplicationError> {
+        let mut payload = build_generate_payload(body, genkey)?;
+        if payload.get("max_context_length").is_none() {
+            return Ok(payload);
+        }
+
+        match self
+            .koboldcpp_repository
+            .true_max_context_length(endpoint)
+            .await
+        {
+            Ok(limit) => {
+                if clamp_context_length(&mut payload, limit) {
+                    tracing::debug!("Clamped KoboldCpp context length to {}", limit);
+                }
+            }
+            Err(error) => tracing::debug!("KoboldCpp context length unavailable: {}", error),
+        }
+        Ok(payload)
+    }
+
+    async fn abort(&self, endpoint: &KoboldCppEndpoint, genkey: &str) -> ApplicationError {
+        if let Err(error) = self.koboldcpp_repository.abort(endpoint, genkey).await {
+            tracing::warn!("Failed to abort KoboldCpp generation {}: {}", genkey, error);
+        }
+        DomainError::generation_cancelled_by_user().into()
+    }
+
+    async fn endpoint(&self, api_server: &str) -> Result<KoboldCppEndpoint, ApplicationError> {
+        let base_url = normalize_base_url(api_server)?;
+        let api_key = self
+            .secret_repository
+            .read_secret(SecretKeys::KOBOLDCPP, None)
+            .await?
+            .map(|secret| secret.trim().to_string())
+            .filter(|secret| !secret.is_empty());
+
+        Ok(KoboldCppEndpoint { base_url, api_key })
+    }
+}
+
+fn api_server(body: &Value) -> Result<&str, ApplicationError> {
+    body.get("api_server")
+        .and_then(Value::as_str)
+        .ok_or_else(|| {
+            ApplicationError::ValidationError(
+                "KoboldCpp request is missing 'api_server'".to_string(),
+            )
+        })
+}
+
+/// Accepts the server root with or without a trailing `/api`.
+fn normalize_base_url(api_server: &str) -> Result<String, ApplicationError> {
+    let trimmed = api_server.trim().trim_end_matches('/');
+    let trimmed = trimmed.strip_suffix("/api").unwrap_or(trimmed);
+    let url = Url::parse(trimmed).map_err(|error| {
+        ApplicationError::ValidationError(format!("Invalid KoboldCpp server URL: {error}"))
+    })?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(ApplicationError::ValidationError(
+            "KoboldCpp server URL must use http or https".to_string(),
+        ));
+    }
+
+    Ok(trimmed.to_string())
+}
+
+fn completion_from_response(response: &Value) -> Value {
+    let result = response.pointer("/results/0");
+    let text = result
+        .and_then(|result| result.get("text"))
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let finish_reason = result
+        .and_then(|result| result.get("finish_reason"))
+        .cloned()
+        .unwrap_or(Value::Null);
+
+    json!({
+        "choices": [{ "index": 0, "text": text, "finish_reason": finish_reason }],
+    })
+}
+
+fn completion_chunk_from_event(event: &str) -> Option<String> {
+    let event = serde_json::from_str::<Value>(event).ok()?;
+    let token = event
+        .get("token")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let finish_reason = event.get("finish_reason").cloned().unwrap_or(Value::Null);
+    if token.is_empty() && finish_reason.is_null() {
+        return None;
+    }
+
+    Some(
+        json!({
+            "choices": [{ "index": 0, "text": token, "finish_reason": finish_reason }],
+        })
+        .to_string(),
+    )
+}
+
+#[derive(Default)]
+struct CancellationRegistry {
+    active: RwLock<HashMap<String, watch::Sender<bool>>>,
+}
+
+impl CancellationRegistry {
+    async fn register(&self, request_id: &str) -> watch::Receiver<bool> {
+        let (sender, receiver) = watch::channel(false);
+        let mut active = self.active.write().await;
+
+        if let Some(previous_sender) = active.insert(request_id.to_string(), sender) {
+            let _ = previous_sender.send(true);
+        }
+
+        receiver
+    }
+
+    async fn cancel(&self, request_id: &str) -> bool {
+        let mut active = self.active.write().await;
+        let Some(sender) = active.remove(request_id) else {
+            return false;
+        };
+
+        let _ = sender.send(true);
+        true
+    }
+
+    async fn complete(&self, request_id: &str) {
+        let mut active = self.active.write().await;
+        active.remove(request_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{completion_chunk_from_event, completion_from_response, normalize_base_url};
+
+    #[test]
+    fn base_url_drops_trailing_api_segment() {
+        assert_eq!(
+            normalize_base_url("http://127.0.0.1:5001/api/").expect("url"),
+            "http://127.0.0.1:5001"
+        );
+        assert!(normalize_base_url("file:///tmp").is_err());
+    }
+
+    #[test]
+    fn native_responses_are_reshaped_as_completions() {
+        let response = completion_from_response(&json!({
+            "results": [{ "text": "Hi there", "finish_reason": "stop" }],
+        }));
+        assert_eq!(response["choices"][0]["text"], "Hi there");
+        assert_eq!(response["choices"][0]["finish_reason"], "stop");
+
+        let chunk =
+            completion_chunk_from_event(r#"{"token":"Hi","finish_reason":null}"#).expect("chunk");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&chunk).expect("json")["choices"][0]["text"],
+            "Hi"
+        );
+        assert!(completion_chunk_from_event(r#"{"token":""}"#).is_none());
+    }
+}
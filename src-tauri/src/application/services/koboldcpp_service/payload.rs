@@ -0,0 +1,176 @@
+use serde_json::{Map, Value};
+
+use crate::application::errors::ApplicationError;
+
+/// SillyTavern text-completion names mapped to their KoboldCpp equivalents. When several
+/// aliases are present, the first one wins.
+const RENAMED_FIELDS: &[(&str, &str)] = &[
+    ("max_new_tokens", "max_length"),
+    ("max_tokens", "max_length"),
+    ("truncation_length", "max_context_length"),
+    ("repetition_penalty", "rep_pen"),
+    ("repetition_penalty_range", "rep_pen_range"),
+    ("typical_p", "typical"),
+    ("stopping_strings", "stop_sequence"),
+    ("stop", "stop_sequence"),
+    ("mirostat_mode", "mirostat"),
+];
+
+/// Fields KoboldCpp understands under the same name.
+const NATIVE_FIELDS: &[&str] = &[
+    "max_length",
+    "max_context_length",
+    "temperature",
+    "top_p",
+    "top_k",
+    "top_a",
+    "min_p",
+    "tfs",
+    "rep_pen",
+    "rep_pen_range",
+    "rep_pen_slope",
+    "typical",
+    "sampler_order",
+    "stop_sequence",
+    "mirostat",
+    "mirostat_tau",
+    "mirostat_eta",
+    "grammar",
+    "trim_stop",
+    "seed",
+    "smoothing_factor",
+    "presence_penalty",
+    "nsigma",
+    "dry_multiplier",
+    "dry_base",
+    "dry_allowed_length",
+    "dry_penalty_last_n",
+    "dry_sequence_breakers",
+    "xtc_threshold",
+    "xtc_probability",
+    "banned_tokens",
+    "logit_bias",
+    "images",
+];
+
+/// Builds a `/api/v1/generate` payload. `genkey` tags the generation so it can be aborted.
+pub(super) fn build_generate_payload(
+    body: &Value,
+    genkey: &str,
+) -> Result<Value, ApplicationError> {
+    let prompt = body.get("prompt").and_then(Value::as_str).ok_or_else(|| {
+        ApplicationError::ValidationError("KoboldCpp request is missing 'prompt'".to_string())
+    })?;
+
+    let mut payload = Map::new();
+    payload.insert("prompt".to_string(), Value::String(prompt.to_string()));
+    for key in NATIVE_FIELDS {
+        if let Some(value) = present(body, key) {
+            payload.insert((*key).to_string(), value.clone());
+        }
+    }
+    for (alias, key) in RENAMED_FIELDS {
+        if payload.contains_key(*key) {
+            continue;
+        }
+        if let Some(value) = present(body, alias) {
+            payload.insert((*key).to_string(), value.clone());
+        }
+    }
+
+    // SillyTavern sends the breakers as a JSON-encoded string.
+    if let Some(Value::String(raw)) = payload.get("dry_sequence_breakers") {
+        let breakers = serde_json::from_str::<Value>(raw).unwrap_or(Value::Null);
+        payload.insert("dry_sequence_breakers".to_string(), breakers);
+    }
+
+    apply_dynamic_temperature(body, &mut payload);
+    payload.insert("genkey".to_string(), Value::String(genkey.to_string()));
+
+    Ok(Value::Object(payload))
+}
+
+/// Keeps the requested context within what the server was launched with. Asking for more
+/// makes KoboldCpp truncate on its own and defeats context shifting.
+pub(super) fn clamp_context_length(payload: &mut Value, true_max_context_length: u64) -> bool {
+    let Some(requested) = payload.get("max_context_length").and_then(Value::as_u64) else {
+        return false;
+    };
+    if requested <= true_max_context_length {
+        return false;
+    }
+
+    payload["max_context_length"] = Value::from(true_max_context_length);
+    true
+}
+
+fn apply_dynamic_temperature(body: &Value, payload: &mut Map<String, Value>) {
+    if body.get("dynamic_temperature").and_then(Value::as_bool) != Some(true) {
+        return;
+    }
+    let (Some(low), Some(high)) = (
+        body.get("dynatemp_low").and_then(Value::as_f64),
+        body.get("dynatemp_high").and_then(Value::as_f64),
+    ) else {
+        return;
+    };
+
+    payload.insert("temperature".to_string(), Value::from((low + high) / 2.0));
+    payload.insert(
+        "dynatemp_range".to_string(),
+        Value::from((high - low) / 2.0),
+    );
+    if let Some(exponent) = present(body, "dynatemp_exponent") {
+        payload.insert("dynatemp_exponent".to_string(), exponent.clone());
+    }
+}
+
+fn present<'a>(body: &'a Value, key: &str) -> Option<&'a Value> {
+    body.get(key).filter(|value| !value.is_null())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{build_generate_payload, clamp_context_length};
+
+    #[test]
+    fn generate_payload_maps_sillytavern_names() {
+        let payload = build_generate_payload(
+            &json!({
+                "prompt": "Hello",
+                "max_new_tokens": 200,
+                "max_tokens": 999,
+                "truncation_length": 8192,
+                "repetition_penalty": 1.1,
+                "stopping_strings": ["\nUser:"],
+                "dry_sequence_breakers": "[\"\\n\", \":\"]",
+                "dynamic_temperature": true,
+                "dynatemp_low": 0.5,
+                "dynatemp_high": 1.5,
+                "api_server": "http://127.0.0.1:5001",
+            }),
+            "req-1",
+        )
+        .expect("payload");
+
+        assert_eq!(payload["max_length"], 200);
+        assert_eq!(payload["max_context_length"], 8192);
+        assert_eq!(payload["rep_pen"], 1.1);
+        assert_eq!(payload["stop_sequence"], json!(["\nUser:"]));
+        assert_eq!(payload["dry_sequence_breakers"], json!(["\n", ":"]));
+        assert_eq!(payload["temperature"], 1.0);
+        assert_eq!(payload["dynatemp_range"], 0.5);
+        assert_eq!(payload["genkey"], "req-1");
+        assert!(payload.get("api_server").is_none());
+    }
+
+    #[test]
+    fn context_length_is_clamped_to_the_server_limit() {
+        let mut payload = json!({ "max_context_length": 16384 });
+        assert!(clamp_context_length(&mut payload, 8192));
+        assert_eq!(payload["max_context_length"], 8192);
+        assert!(!clamp_context_length(&mut payload, 8192));
+    }
+}
@@ -383,6 +383,31 @@ impl LlmConnectionService {
     }
 }
 
+/// Settings key SillyTavern reads the selected model from for `source`.
+pub fn settings_model_key(source: &str) -> Result<&'static str, ApplicationError> {
+    match source {
+        "openai" => Ok("openai_model"),
+        "openrouter" => Ok("openrouter_model"),
+        "custom" => Ok("custom_model"),
+        "claude" => Ok("claude_model"),
+        "makersuite" => Ok("google_model"),
+        "vertexai" => Ok("vertexai_model"),
+        "deepseek" => Ok("deepseek_model"),
+        "cohere" => Ok("cohere_model"),
+        "groq" => Ok("groq_model"),
+        "moonshot" => Ok("moonshot_model"),
+        "nanogpt" => Ok("nanogpt_model"),
+        "chutes" => Ok("chutes_model"),
+        "siliconflow" => Ok("siliconflow_model"),
+        "workers_ai" => Ok("workers_ai_model"),
+        "zai" => Ok("zai_model"),
+        "minimax" => Ok("minimax_model"),
+        other => Err(ApplicationError::InternalError(format!(
+            "llm_connection.model_source_unmapped: no settings model key for source `{other}`"
+        ))),
+    }
+}
+
 fn validate_connection(
     connection: &LlmConnectionDefinition,
 ) -> Result<ChatCompletionSource, ApplicationError> {
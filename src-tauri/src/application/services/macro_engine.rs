@@ -0,0 +1,375 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Local};
+use rand::Rng;
+use serde_json::{Map, Value};
+
+use crate::application::dto::macro_dto::MacroContextDto;
+use crate::application::errors::ApplicationError;
+use crate::application::services::variable_service::{VariableService, VariableSet};
+
+const MAX_ROLL_DICE: u32 = 1000;
+
+/// Evaluates SillyTavern `{{...}}` macros. Nested macros are expanded innermost first and
+/// unknown macros are left in place so the frontend can still handle them.
+pub struct MacroEngine {
+    variable_service: Arc<VariableService>,
+}
+
+impl MacroEngine {
+    pub fn new(variable_service: Arc<VariableService>) -> Self {
+        Self { variable_service }
+    }
+
+    pub async fn substitute(
+        &self,
+        text: &str,
+        context: &MacroContextDto,
+    ) -> Result<String, ApplicationError> {
+        let mut text = text.to_string();
+        self.substitute_in_place(&mut [&mut text], context).await?;
+        Ok(text)
+    }
+
+    /// Substitutes the texts in order within a single variable update, so a `setvar` in
+    /// one text is visible to the texts after it.
+    pub async fn substitute_in_place(
+        &self,
+        texts: &mut [&mut String],
+        context: &MacroContextDto,
+    ) -> Result<(), ApplicationError> {
+        if !texts.iter().any(|text| text.contains("{{")) {
+            return Ok(());
+        }
+
+        let uses_variables = texts.iter().any(|text| text.contains("var::"));
+        let now = Local::now();
+        let mut apply = |variables: &mut VariableSet| {
+            for text in texts.iter_mut() {
+                **text = substitute_text(text, context, now, variables);
+            }
+        };
+
+        if uses_variables {
+            self.variable_service
+                .update_variables(context.chat.as_ref(), apply)
+                .await
+        } else {
+            apply(&mut VariableSet::default());
+            Ok(())
+        }
+    }
+}
+
+fn substitute_text(
+    text: &str,
+    context: &MacroContextDto,
+    now: DateTime<Local>,
+    variables: &mut VariableSet,
+) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+        let body = &rest[start + 2..];
+        let Some(end) = find_macro_end(body) else {
+            output.push_str(&rest[start..]);
+            return output;
+        };
+
+        let inner = substitute_text(&body[..end], context, now, variables);
+        match evaluate_macro(&inner, context, now, variables) {
+            Some(replacement) => output.push_str(&replacement),
+            None => {
+                output.push_str("{{");
+                output.push_str(&inner);
+                output.push_str("}}");
+            }
+        }
+        rest = &body[end + 2..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// Byte offset of the `}}` that closes the macro, skipping over nested macros.
+fn find_macro_end(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    let mut index = 0;
+
+    while index + 1 < bytes.len() {
+        match (bytes[index], bytes[index + 1]) {
+            (b'{', b'{') => {
+                depth += 1;
+                index += 2;
+            }
+            (b'}', b'}') => {
+                if depth == 0 {
+                    return Some(index);
+                }
+                depth -= 1;
+                index += 2;
+            }
+            _ => index += 1,
+        }
+    }
+
+    None
+}
+
+fn evaluate_macro(
+    inner: &str,
+    context: &MacroContextDto,
+    now: DateTime<Local>,
+    variables: &mut VariableSet,
+) -> Option<String> {
+    let trimmed = inner.trim();
+    if trimmed.starts_with("//") {
+        return Some(String::new());
+    }
+
+    match trimmed.to_ascii_lowercase().as_str() {
+        "user" => return Some(context.user.clone()),
+        "char" => return Some(context.char.clone()),
+        "group" => {
+            return Some(
+                context
+                    .group
+                    .clone()
+                    .unwrap_or_else(|| context.char.clone()),
+            );
+        }
+        "time" => return Some(now.format("%-I:%M %p").to_string()),
+        "date" => return Some(now.format("%B %-d, %Y").to_string()),
+        "weekday" => return Some(now.format("%A").to_string()),
+        "isotime" => return Some(now.format("%H:%M").to_string()),
+        "isodate" => return Some(now.format("%Y-%m-%d").to_string()),
+        "newline" => return Some("\n".to_string()),
+        "noop" => return Some(String::new()),
+        _ => {}
+    }
+
+    let (name, args, separator) = match trimmed.split_once("::") {
+        Some((name, args)) => (name, args, "::"),
+        None => {
+            let (name, args) = trimmed.split_once(':')?;
+            (name, args, ",")
+        }
+    };
+
+    match name.trim().to_ascii_lowercase().as_str() {
+        "random" => pick_random(args, separator),
+        "roll" => roll_dice(args),
+        "getvar" => Some(read_variable(&variables.chat, args)),
+        "setvar" => set_variable(&mut variables.chat, args),
+        "addvar" => add_variable(&mut variables.chat, args),
+        "incvar" => Some(step_variable(&mut variables.chat, args, 1.0)),
+        "decvar" => Some(step_variable(&mut variables.chat, args, -1.0)),
+        "getglobalvar" => Some(read_variable(&variables.global, args)),
+        "setglobalvar" => set_variable(&mut variables.global, args),
+        "addglobalvar" => add_variable(&mut variables.global, args),
+        "incglobalvar" => Some(step_variable(&mut variables.global, args, 1.0)),
+        "decglobalvar" => Some(step_variable(&mut variables.global, args, -1.0)),
+        _ => None,
+    }
+}
+
+fn pick_random(args: &str, separator: &str) -> Option<String> {
+    let options: Vec<&str> = args.split(separator).collect();
+    let index = rand::rng().random_range(0..options.len());
+    Some(options[index].trim().to_string())
+}
+
+/// Supports `NdM`, `dM` and `M` with an optional `+K` / `-K` modifier.
+fn roll_dice(formula: &str) -> Option<String> {
+    let formula = formula.trim().to_ascii_lowercase();
+    let (dice, modifier) = match formula.find(['+', '-']) {
+        Some(index) => (&formula[..index], formula[index..].parse::<i64>().ok()?),
+        None => (formula.as_str(), 0),
+    };
+    let (count, sides) = match dice.split_once('d') {
+        Some(("", sides)) => (1, sides.trim().parse::<u32>().ok()?),
+        Some((count, sides)) => (
+            count.trim().parse::<u32>().ok()?,
+            sides.trim().parse::<u32>().ok()?,
+        ),
+        None => (1, dice.trim().parse::<u32>().ok()?),
+    };
+    if count == 0 || count > MAX_ROLL_DICE || sides == 0 {
+        return None;
+    }
+
+    let mut rng = rand::rng();
+    let total: i64 = (0..count)
+        .map(|_| i64::from(rng.random_range(1..=sides)))
+        .sum();
+    Some((total + modifier).to_string())
+}
+
+fn read_variable(scope: &Map<String, Value>, name: &str) -> String {
+    scope
+        .get(name.trim())
+        .map(variable_to_string)
+        .unwrap_or_default()
+}
+
+fn set_variable(scope: &mut Map<String, Value>, args: &str) -> Option<String> {
+    let (name, value) = args.split_once("::")?;
+    scope.insert(name.trim().to_string(), Value::String(value.to_string()));
+    Some(String::new())
+}
+
+/// Adds numerically when both sides are numbers, otherwise appends the text.
+fn add_variable(scope: &mut Map<String, Value>, args: &str) -> Option<String> {
+    let (name, value) = args.split_once("::")?;
+    let name = name.trim();
+    let current = scope.get(name);
+
+    let next = match (variable_to_number(current), value.trim().parse::<f64>()) {
+        (Some(current), Ok(increment)) => number_value(current + increment),
+        _ => Value::String(format!(
+            "{}{}",
+            current.map(variable_to_string).unwrap_or_default(),
+            value
+        )),
+    };
+    scope.insert(name.to_string(), next);
+    Some(String::new())
+}
+
+/// Increments or decrements the variable and returns the new value. Non-numeric values
+/// restart from zero.
+fn step_variable(scope: &mut Map<String, Value>, name: &str, delta: f64) -> String {
+    let name = name.trim();
+    let next = number_value(variable_to_number(scope.get(name)).unwrap_or(0.0) + delta);
+    let rendered = variable_to_string(&next);
+    scope.insert(name.to_string(), next);
+    rendered
+}
+
+fn variable_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn variable_to_number(value: Option<&Value>) -> Option<f64> {
+    match value {
+        None | Some(Value::Null) => Some(0.0),
+        Some(Value::Number(number)) => number.as_f64(),
+        Some(Value::String(text)) if text.trim().is_empty() => Some(0.0),
+        Some(Value::String(text)) => text.trim().parse().ok(),
+        Some(_) => None,
+    }
+}
+
+fn number_value(number: f64) -> Value {
+    if number.fract() == 0.0 && number.abs() < 9.0e15 {
+        return Value::from(number as i64);
+    }
+
+    serde_json::Number::from_f64(number)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, TimeZone};
+    use serde_json::json;
+
+    use super::substitute_text;
+    use crate::application::dto::macro_dto::MacroContextDto;
+    use crate::application::services::variable_service::VariableSet;
+
+    fn context() -> MacroContextDto {
+        MacroContextDto {
+            user: "Alice".to_string(),
+            char: "Seraphina".to_string(),
+            ..MacroContextDto::default()
+        }
+    }
+
+    fn render(text: &str, variables: &mut VariableSet) -> String {
+        let now = Local.with_ymd_and_hms(2026, 10, 16, 15, 4, 0).unwrap();
+        substitute_text(text, &context(), now, variables)
+    }
+
+    #[test]
+    fn substitutes_names_and_time_and_keeps_unknown_macros() {
+        let mut variables = VariableSet::default();
+
+        assert_eq!(
+            render("{{char}} greets {{USER}}.{{// hidden}}", &mut variables),
+            "Seraphina greets Alice."
+        );
+        assert_eq!(
+            render(
+                "{{isodate}} {{isotime}} {{weekday}} {{time}}",
+                &mut variables
+            ),
+            "2026-10-16 15:04 Friday 3:04 PM"
+        );
+        assert_eq!(
+            render("{{lastMessage}} and {{unclosed", &mut variables),
+            "{{lastMessage}} and {{unclosed"
+        );
+    }
+
+    #[test]
+    fn variable_macros_update_their_scope() {
+        let mut variables = VariableSet::default();
+
+        let rendered = render(
+            "{{setvar::mood::calm}}{{addvar::turns::4}}{{incvar::turns}} {{getvar::mood}} {{incglobalvar::visits}}",
+            &mut variables,
+        );
+        assert_eq!(rendered, "5 calm 1");
+        assert_eq!(variables.chat.get("mood"), Some(&json!("calm")));
+        assert_eq!(variables.chat.get("turns"), Some(&json!(5)));
+        assert_eq!(variables.global.get("visits"), Some(&json!(1)));
+
+        assert_eq!(
+            render(
+                "{{addvar::mood:: and alert}}{{getvar::mood}}",
+                &mut variables
+            ),
+            "calm and alert"
+        );
+    }
+
+    #[test]
+    fn nested_macros_expand_innermost_first() {
+        let mut variables = VariableSet::default();
+        variables
+            .chat
+            .insert("Alice_affinity".to_string(), json!(7));
+
+        assert_eq!(render("{{getvar::{{user}}_affinity}}", &mut variables), "7");
+    }
+
+    #[test]
+    fn random_and_roll_stay_in_range() {
+        let mut variables = VariableSet::default();
+
+        for _ in 0..32 {
+            let picked = render("{{random::red::green::blue}}", &mut variables);
+            assert!(["red", "green", "blue"].contains(&picked.as_str()));
+
+            let picked = render("{{random:1,2}}", &mut variables);
+            assert!(["1", "2"].contains(&picked.as_str()));
+
+            let rolled: i64 = render("{{roll:2d6+1}}", &mut variables)
+                .parse()
+                .expect("numeric roll");
+            assert!((3..=13).contains(&rolled));
+        }
+
+        assert_eq!(render("{{roll:0d6}}", &mut variables), "{{roll:0d6}}");
+    }
+}
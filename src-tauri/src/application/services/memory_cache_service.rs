@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::domain::models::memory_cache::{MemoryCacheSettings, MemoryCacheStats};
+use crate::domain::repositories::character_repository::CharacterRepository;
+use crate::domain::repositories::chat_repository::ChatRepository;
+
+/// Applies the configured sizes to the repositories' in-memory caches and collects their
+/// usage counters for diagnostics.
+pub struct MemoryCacheService {
+    character_repository: Arc<dyn CharacterRepository>,
+    chat_repository: Arc<dyn ChatRepository>,
+}
+
+impl MemoryCacheService {
+    pub fn new(
+        character_repository: Arc<dyn CharacterRepository>,
+        chat_repository: Arc<dyn ChatRepository>,
+    ) -> Self {
+        Self {
+            character_repository,
+            chat_repository,
+        }
+    }
+
+    pub async fn apply_settings(&self, settings: &MemoryCacheSettings) {
+        self.character_repository
+            .configure_memory_cache(settings.characters)
+            .await;
+        self.chat_repository
+            .configure_memory_cache(settings.chats)
+            .await;
+    }
+
+    pub async fn get_cache_stats(&self) -> Vec<MemoryCacheStats> {
+        vec![
+            self.character_repository.memory_cache_stats().await,
+            self.chat_repository.memory_cache_stats().await,
+        ]
+    }
+}
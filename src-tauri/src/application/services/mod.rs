@@ -11,37 +11,70 @@ pub mod agent_tools;
 pub mod agent_workspace_lifecycle_service;
 pub mod agent_workspace_scope;
 pub mod asset_service;
+pub mod author_note_service;
 pub mod avatar_service;
 pub mod background_service;
+pub mod backup_schedule_service;
+pub mod changelog_service;
+pub mod character_asset_service;
+pub mod character_generation_overrides_service;
+pub mod character_note_service;
+pub mod character_prompt_overrides_service;
 pub mod character_service;
 pub mod chat_completion_service;
 mod chat_file_validation;
+pub mod chat_metadata_service;
 pub mod chat_service;
+pub mod connection_monitor_service;
+pub mod connection_profile_service;
 pub mod content_service;
+pub mod expression_service;
 pub mod extension_service;
 pub mod extension_store_service;
+pub mod file_attachment_service;
+pub mod file_ingest_service;
+pub mod generation_record_service;
+pub mod global_search_service;
 pub mod group_chat_service;
 pub mod group_service;
+pub mod horde_service;
+pub mod idle_generation_service;
 pub mod image_metadata_service;
+pub mod inline_image_service;
+pub mod koboldcpp_service;
 pub mod lan_sync_service;
 pub mod llm_connection_service;
+pub mod macro_engine;
+pub mod memory_cache_service;
+pub mod model_capability_service;
 pub mod native_regex_service;
+pub mod notification_service;
+pub mod novelai_service;
 pub mod preset_service;
 pub mod prompt_assembly_service;
 pub mod provider_metadata_service;
 pub mod quick_reply_service;
+pub mod recent_items_service;
 pub mod secret_service;
+mod settings_diff;
+pub mod settings_profile_service;
 mod settings_repair;
 pub mod settings_service;
 pub mod skill_service;
+pub mod sound_service;
+pub mod sprite_service;
 pub mod stable_diffusion_service;
+pub mod summarize_service;
 pub mod sync_automation_service;
+pub mod tag_service;
 pub mod theme_service;
 pub mod tokenization_service;
 pub mod translate_service;
 pub mod tt_sync_service;
 pub mod tts_service;
 pub mod update_service;
+pub mod user_customization_service;
 pub mod user_directory_service;
 pub mod user_service;
+pub mod variable_service;
 pub mod world_info_service;
@@ -0,0 +1,344 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde_json::{Map, Value};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::RwLock;
+
+use crate::application::dto::model_capability_dto::{
+    ModelCapabilitiesDto, ModelParameterWarningDto,
+};
+use crate::domain::repositories::chat_completion_repository::ChatCompletionSource;
+
+pub const MODEL_PARAMETER_WARNING_EVENT: &str = "chat_completion:parameter_warning";
+
+/// Body keys that are only checked against a published `supported_parameters` list.
+/// Structural keys (`model`, `messages`, `stream`, ...) are never stripped.
+const STRIPPABLE_PARAMETERS: &[&str] = &[
+    "temperature",
+    "top_p",
+    "top_k",
+    "min_p",
+    "top_a",
+    "repetition_penalty",
+    "frequency_penalty",
+    "presence_penalty",
+    "seed",
+    "logit_bias",
+    "logprobs",
+    "top_logprobs",
+    "stop",
+    "reasoning",
+    "include_reasoning",
+    "response_format",
+    "tools",
+    "tool_choice",
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct ModelCapabilities {
+    max_context: Option<u64>,
+    supports_vision: Option<bool>,
+    supports_tools: Option<bool>,
+    supported_parameters: Option<BTreeSet<String>>,
+}
+
+/// Per-source, per-model capability table populated from provider model lists.
+pub struct ModelCapabilityService {
+    app_handle: AppHandle,
+    table: RwLock<HashMap<&'static str, BTreeMap<String, ModelCapabilities>>>,
+}
+
+impl ModelCapabilityService {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            table: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Replaces the table for `source` with the entries parsed from a `list_models` body.
+    /// Returns the number of models recorded.
+    pub async fn refresh_from_model_list(
+        &self,
+        source: ChatCompletionSource,
+        model_list: &Value,
+    ) -> usize {
+        let entries = parse_model_list(model_list);
+        let count = entries.len();
+        if count > 0 {
+            self.table.write().await.insert(source.key(), entries);
+        }
+        count
+    }
+
+    pub async fn get(
+        &self,
+        source: ChatCompletionSource,
+        model: &str,
+    ) -> Option<ModelCapabilitiesDto> {
+        let table = self.table.read().await;
+        let model = model.trim();
+        table
+            .get(source.key())
+            .and_then(|entries| entries.get(model))
+            .map(|capabilities| to_dto(source, model, capabilities))
+    }
+
+    pub async fn list(&self, source: ChatCompletionSource) -> Vec<ModelCapabilitiesDto> {
+        let table = self.table.read().await;
+        table
+            .get(source.key())
+            .map(|entries| {
+                entries
+                    .iter()
+                    .map(|(model, capabilities)| to_dto(source, model, capabilities))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Removes body parameters the model is known not to accept and emits a warning
+    /// event listing them. Unknown models are left untouched.
+    pub async fn strip_unsupported_parameters(
+        &self,
+        source: ChatCompletionSource,
+        model: &str,
+        body: &mut Value,
+    ) -> Vec<String> {
+        let model = model.trim();
+        let removed = {
+            let table = self.table.read().await;
+            let Some(capabilities) = table
+                .get(source.key())
+                .and_then(|entries| entries.get(model))
+            else {
+                return Vec::new();
+            };
+            let Some(body) = body.as_object_mut() else {
+                return Vec::new();
+            };
+            strip_parameters(capabilities, body)
+        };
+
+        if !removed.is_empty() {
+            let warning = ModelParameterWarningDto {
+                source: source.key().to_string(),
+                model: model.to_string(),
+                message: format!(
+                    "{} model `{model}` does not support: {}",
+                    source.display_name(),
+                    removed.join(", ")
+                ),
+                removed_parameters: removed.clone(),
+            };
+            tracing::warn!("{}", warning.message);
+            if let Err(error) = self.app_handle.emit(MODEL_PARAMETER_WARNING_EVENT, warning) {
+                tracing::warn!("Failed to emit model parameter warning: {}", error);
+            }
+        }
+
+        removed
+    }
+}
+
+fn to_dto(
+    source: ChatCompletionSource,
+    model: &str,
+    capabilities: &ModelCapabilities,
+) -> ModelCapabilitiesDto {
+    ModelCapabilitiesDto {
+        source: source.key().to_string(),
+        model: model.to_string(),
+        max_context: capabilities.max_context,
+        supports_vision: capabilities.supports_vision,
+        supports_tools: capabilities.supports_tools,
+        supported_parameters: capabilities
+            .supported_parameters
+            .as_ref()
+            .map(|parameters| parameters.iter().cloned().collect()),
+    }
+}
+
+fn strip_parameters(
+    capabilities: &ModelCapabilities,
+    body: &mut Map<String, Value>,
+) -> Vec<String> {
+    let mut removed = Vec::new();
+
+    if capabilities.supports_tools == Some(false) {
+        for key in ["tools", "tool_choice"] {
+            if body.remove(key).is_some() {
+                removed.push(key.to_string());
+            }
+        }
+    }
+
+    if let Some(supported) = capabilities.supported_parameters.as_ref() {
+        for key in STRIPPABLE_PARAMETERS {
+            if !supported.contains(*key) && body.remove(*key).is_some() {
+                removed.push((*key).to_string());
+            }
+        }
+    }
+
+    removed
+}
+
+fn parse_model_list(model_list: &Value) -> BTreeMap<String, ModelCapabilities> {
+    let items = model_list
+        .get("data")
+        .or_else(|| model_list.get("models"))
+        .and_then(Value::as_array)
+        .or_else(|| model_list.as_array());
+
+    items
+        .into_iter()
+        .flatten()
+        .filter_map(parse_model_entry)
+        .collect()
+}
+
+fn parse_model_entry(entry: &Value) -> Option<(String, ModelCapabilities)> {
+    let object = entry.as_object()?;
+    let id = object
+        .get("id")
+        .and_then(Value::as_str)
+        .or_else(|| {
+            object
+                .get("name")
+                .and_then(Value::as_str)
+                .map(|name| name.strip_prefix("models/").unwrap_or(name))
+        })?
+        .trim();
+    if id.is_empty() {
+        return None;
+    }
+
+    let max_context = [
+        "context_length",
+        "context_window",
+        "max_context_length",
+        "inputTokenLimit",
+    ]
+    .iter()
+    .find_map(|key| object.get(*key).and_then(Value::as_u64))
+    .or_else(|| {
+        entry
+            .pointer("/top_provider/context_length")
+            .and_then(Value::as_u64)
+    });
+
+    let supports_vision = entry
+        .pointer("/architecture/input_modalities")
+        .and_then(Value::as_array)
+        .map(|modalities| modalities.iter().any(|item| item.as_str() == Some("image")))
+        .or_else(|| {
+            entry
+                .pointer("/capabilities/vision")
+                .and_then(Value::as_bool)
+        });
+
+    let supported_parameters = object
+        .get("supported_parameters")
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect::<BTreeSet<_>>()
+        });
+
+    let supports_tools = supported_parameters
+        .as_ref()
+        .map(|parameters| parameters.contains("tools"))
+        .or_else(|| {
+            entry
+                .pointer("/capabilities/function_calling")
+                .and_then(Value::as_bool)
+        });
+
+    Some((
+        id.to_string(),
+        ModelCapabilities {
+            max_context,
+            supports_vision,
+            supports_tools,
+            supported_parameters,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{parse_model_list, strip_parameters};
+
+    #[test]
+    fn parses_openrouter_model_list_capabilities() {
+        let entries = parse_model_list(&json!({
+            "data": [{
+                "id": "openai/gpt-4.1-mini",
+                "context_length": 1047576,
+                "architecture": { "input_modalities": ["text", "image"] },
+                "supported_parameters": ["temperature", "tools", "tool_choice"]
+            }, {
+                "id": "meta/llama"
+            }]
+        }));
+
+        let gpt = entries
+            .get("openai/gpt-4.1-mini")
+            .expect("model must be parsed");
+        assert_eq!(gpt.max_context, Some(1047576));
+        assert_eq!(gpt.supports_vision, Some(true));
+        assert_eq!(gpt.supports_tools, Some(true));
+
+        let llama = entries.get("meta/llama").expect("model must be parsed");
+        assert_eq!(llama.supported_parameters, None);
+    }
+
+    #[test]
+    fn parses_gemini_model_names() {
+        let entries = parse_model_list(&json!({
+            "models": [{ "name": "models/gemini-2.5-pro", "inputTokenLimit": 1048576 }]
+        }));
+
+        assert_eq!(
+            entries
+                .get("gemini-2.5-pro")
+                .and_then(|capabilities| capabilities.max_context),
+            Some(1048576)
+        );
+    }
+
+    #[test]
+    fn strips_only_parameters_outside_the_published_list() {
+        let entries = parse_model_list(&json!({
+            "data": [{
+                "id": "deepseek/deepseek-r1",
+                "supported_parameters": ["temperature", "max_tokens"]
+            }]
+        }));
+        let capabilities = entries
+            .get("deepseek/deepseek-r1")
+            .expect("model must be parsed");
+
+        let mut body = json!({
+            "model": "deepseek/deepseek-r1",
+            "messages": [],
+            "temperature": 0.7,
+            "top_k": 40,
+            "tools": []
+        });
+        let removed = strip_parameters(
+            capabilities,
+            body.as_object_mut().expect("body must be object"),
+        );
+
+        assert_eq!(removed, vec!["tools".to_string(), "top_k".to_string()]);
+        assert!(body.get("temperature").is_some());
+        assert!(body.get("messages").is_some());
+    }
+}
@@ -0,0 +1,164 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::{NotificationExt, PermissionState};
+
+use crate::application::errors::ApplicationError;
+use crate::domain::models::settings::GenerationNotificationSettings;
+use crate::domain::repositories::settings_repository::SettingsRepository;
+
+const MAX_NOTIFICATION_BODY_CHARS: usize = 200;
+
+/// How a chat completion stream ended. Cancelled streams are not reported.
+#[derive(Debug, Clone, Copy)]
+pub enum GenerationOutcome<'a> {
+    Completed,
+    Failed(&'a str),
+}
+
+/// Native notifications for chat completion streams that end while the main window is
+/// unfocused or minimized, so a long generation can be left running in the background.
+pub struct NotificationService {
+    app_handle: AppHandle,
+    settings_repository: Arc<dyn SettingsRepository>,
+}
+
+impl NotificationService {
+    pub fn new(app_handle: AppHandle, settings_repository: Arc<dyn SettingsRepository>) -> Self {
+        Self {
+            app_handle,
+            settings_repository,
+        }
+    }
+
+    /// Reports a finished stream for the character tagged with `avatar`. Failures are
+    /// only logged, so a notification problem never affects the stream itself.
+    pub async fn notify_generation(&self, avatar: Option<&str>, outcome: GenerationOutcome<'_>) {
+        if self.is_main_window_in_front() {
+            return;
+        }
+
+        let settings = match self.settings_repository.load_tauritavern_settings().await {
+            Ok(settings) => settings.generation_notifications,
+            Err(error) => {
+                tracing::warn!("Failed to load notification settings: {}", error);
+                return;
+            }
+        };
+        let Some((title, body)) = generation_notification(&settings, avatar, outcome) else {
+            return;
+        };
+
+        if let Err(error) = self.show(&title, &body) {
+            tracing::warn!("Failed to show generation notification: {}", error);
+        }
+    }
+
+    /// Shows a sample notification regardless of the settings and window state.
+    pub fn send_test_notification(&self) -> Result<(), ApplicationError> {
+        self.show("TauriTavern", "Generation notifications are working")
+    }
+
+    fn show(&self, title: &str, body: &str) -> Result<(), ApplicationError> {
+        let notification = self.app_handle.notification();
+        let permission = notification.permission_state().map_err(|error| {
+            ApplicationError::InternalError(format!(
+                "Failed to query notification permission state: {}",
+                error
+            ))
+        })?;
+        if permission != PermissionState::Granted {
+            return Err(ApplicationError::PermissionDenied(
+                "Notification permission is not granted".to_string(),
+            ));
+        }
+
+        notification
+            .builder()
+            .title(title)
+            .body(body)
+            .show()
+            .map_err(|error| {
+                ApplicationError::InternalError(format!("Failed to show notification: {}", error))
+            })
+    }
+
+    /// Treats an unreadable window state as in front, so platforms without focus
+    /// tracking never get a notification for every reply.
+    fn is_main_window_in_front(&self) -> bool {
+        let Some(window) = self.app_handle.get_webview_window("main") else {
+            return false;
+        };
+        window.is_visible().unwrap_or(true)
+            && window.is_focused().unwrap_or(true)
+            && !window.is_minimized().unwrap_or(false)
+    }
+}
+
+/// Title and body for a finished generation, or `None` when it should stay silent.
+fn generation_notification(
+    settings: &GenerationNotificationSettings,
+    avatar: Option<&str>,
+    outcome: GenerationOutcome<'_>,
+) -> Option<(String, String)> {
+    if !settings.allows(avatar) {
+        return None;
+    }
+
+    // Avatars are named after the character, which is close enough for a notification.
+    let name = avatar.and_then(|avatar| Path::new(avatar).file_stem()?.to_str());
+    match outcome {
+        GenerationOutcome::Completed => Some((
+            name.map_or_else(
+                || "Generation finished".to_string(),
+                |name| format!("{} replied", name),
+            ),
+            "The reply is ready.".to_string(),
+        )),
+        GenerationOutcome::Failed(message) if settings.on_error => Some((
+            name.map_or_else(
+                || "Generation failed".to_string(),
+                |name| format!("Generation for {} failed", name),
+            ),
+            message.chars().take(MAX_NOTIFICATION_BODY_CHARS).collect(),
+        )),
+        GenerationOutcome::Failed(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GenerationOutcome, generation_notification};
+    use crate::domain::models::settings::GenerationNotificationSettings;
+
+    #[test]
+    fn respects_switches_and_muted_characters() {
+        let mut settings = GenerationNotificationSettings::default();
+        assert!(
+            generation_notification(&settings, Some("Alice.png"), GenerationOutcome::Completed)
+                .is_none()
+        );
+
+        settings.enabled = true;
+        settings.muted_characters.insert("Bob.png".to_string());
+        let (title, _) =
+            generation_notification(&settings, Some("Alice.png"), GenerationOutcome::Completed)
+                .unwrap();
+        assert_eq!(title, "Alice replied");
+        assert!(
+            generation_notification(&settings, Some("Bob.png"), GenerationOutcome::Completed)
+                .is_none()
+        );
+        assert!(
+            generation_notification(&settings, None, GenerationOutcome::Failed("timeout"))
+                .is_some()
+        );
+
+        settings.on_error = false;
+        assert!(
+            generation_notification(&settings, None, GenerationOutcome::Failed("timeout"))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,147 @@
+mod payload;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use serde_json::Value;
+use tokio::sync::{RwLock, watch};
+
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+use crate::domain::models::secret::SecretKeys;
+use crate::domain::repositories::chat_completion_repository::ChatCompletionStreamSender;
+use crate::domain::repositories::novelai_repository::NovelAiRepository;
+use crate::domain::repositories::secret_repository::SecretRepository;
+
+use self::payload::{build_image_payload, build_text_payload};
+
+pub struct NovelAiService {
+    novelai_repository: Arc<dyn NovelAiRepository>,
+    secret_repository: Arc<dyn SecretRepository>,
+    cancellations: CancellationRegistry,
+}
+
+impl NovelAiService {
+    pub fn new(
+        novelai_repository: Arc<dyn NovelAiRepository>,
+        secret_repository: Arc<dyn SecretRepository>,
+    ) -> Self {
+        Self {
+            novelai_repository,
+            secret_repository,
+            cancellations: CancellationRegistry::default(),
+        }
+    }
+
+    /// Subscription data (tier, perks, Anlas) for the configured key.
+    pub async fn get_status(&self) -> Result<Value, ApplicationError> {
+        let api_key = self.read_api_key().await?;
+        Ok(self.novelai_repository.get_subscription(&api_key).await?)
+    }
+
+    pub async fn generate_text(
+        &self,
+        request_id: &str,
+        body: Value,
+    ) -> Result<Value, ApplicationError> {
+        let payload = build_text_payload(&body)?;
+        let api_key = self.read_api_key().await?;
+
+        let mut cancel = self.cancellations.register(request_id).await;
+        let result = tokio::select! {
+            result = self.novelai_repository.generate_text(&api_key, &payload) => {
+                result.map_err(ApplicationError::from)
+            }
+            _ = cancel.changed() => Err(DomainError::generation_cancelled_by_user().into()),
+        };
+        self.cancellations.complete(request_id).await;
+        result
+    }
+
+    pub async fn register_stream(&self, stream_id: &str) -> watch::Receiver<bool> {
+        self.cancellations.register(stream_id).await
+    }
+
+    /// Streams the NovelAI token events for `body` into `sender`.
+    pub async fn generate_text_stream(
+        &self,
+        body: Value,
+        sender: ChatCompletionStreamSender,
+        cancel: watch::Receiver<bool>,
+    ) -> Result<(), ApplicationError> {
+        let payload = build_text_payload(&body)?;
+        let api_key = self.read_api_key().await?;
+
+        Ok(self
+            .novelai_repository
+            .generate_text_stream(&api_key, &payload, sender, cancel)
+            .await?)
+    }
+
+    pub async fn complete_stream(&self, stream_id: &str) {
+        self.cancellations.complete(stream_id).await;
+    }
+
+    /// Cancels a pending generation or stream.
+    pub async fn cancel_request(&self, request_id: &str) -> bool {
+        self.cancellations.cancel(request_id).await
+    }
+
+    /// Generates an image and returns it base64-encoded.
+    pub async fn generate_image(&self, body: Value) -> Result<String, ApplicationError> {
+        let payload = build_image_payload(&body)?;
+        let api_key = self.read_api_key().await?;
+
+        let image = self
+            .novelai_repository
+            .generate_image(&api_key, &payload)
+            .await?;
+        Ok(BASE64_STANDARD.encode(image))
+    }
+
+    async fn read_api_key(&self) -> Result<String, ApplicationError> {
+        self.secret_repository
+            .read_secret(SecretKeys::NOVEL, None)
+            .await?
+            .map(|secret| secret.trim().to_string())
+            .filter(|secret| !secret.is_empty())
+            .ok_or_else(|| {
+                ApplicationError::Unauthorized("NovelAI API key is not configured".to_string())
+            })
+    }
+}
+
+#[derive(Default)]
+struct CancellationRegistry {
+    active: RwLock<HashMap<String, watch::Sender<bool>>>,
+}
+
+impl CancellationRegistry {
+    async fn register(&self, request_id: &str) -> watch::Receiver<bool> {
+        let (sender, receiver) = watch::channel(false);
+        let mut active = self.active.write().await;
+
+        if let Some(previous_sender) = active.insert(request_id.to_string(), sender) {
+            let _ = previous_sender.send(true);
+        }
+
+        receiver
+    }
+
+    async fn cancel(&self, request_id: &str) -> bool {
+        let mut active = self.active.write().await;
+        let Some(sender) = active.remove(request_id) else {
+            return false;
+        };
+
+        let _ = sender.send(true);
+        true
+    }
+
+    async fn complete(&self, request_id: &str) {
+        let mut active = self.active.write().await;
+        active.remove(request_id);
+    }
+}
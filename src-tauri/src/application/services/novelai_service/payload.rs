@@ -0,0 +1,194 @@
+use rand::Rng;
+use serde_json::{Map, Value, json};
+
+use crate::application::errors::ApplicationError;
+
+const DEFAULT_TEXT_MODULE: &str = "vanilla";
+const DEFAULT_IMAGE_MODEL: &str = "nai-diffusion-4-5-full";
+
+/// Sampler and preset settings forwarded to `parameters` as-is; unset values are dropped.
+const TEXT_PARAMETER_KEYS: &[&str] = &[
+    "temperature",
+    "max_length",
+    "min_length",
+    "tail_free_sampling",
+    "repetition_penalty",
+    "repetition_penalty_range",
+    "repetition_penalty_slope",
+    "repetition_penalty_frequency",
+    "repetition_penalty_presence",
+    "repetition_penalty_whitelist",
+    "top_a",
+    "top_p",
+    "top_k",
+    "min_p",
+    "typical_p",
+    "math1_temp",
+    "math1_quad",
+    "math1_quad_entropy_scale",
+    "mirostat_lr",
+    "mirostat_tau",
+    "phrase_rep_pen",
+    "stop_sequences",
+    "bad_words_ids",
+    "logit_bias_exp",
+    "generate_until_sentence",
+    "use_cache",
+    "return_full_text",
+    "order",
+    "num_logprobs",
+];
+
+/// Builds a NovelAI text request from the flat SillyTavern request body. `prefix` selects
+/// the module (`vanilla`, `special_instruct`, ...).
+pub(super) fn build_text_payload(body: &Value) -> Result<Value, ApplicationError> {
+    let input = require_string(body, "input")?;
+    let model = require_string(body, "model")?;
+
+    let mut parameters = Map::new();
+    parameters.insert(
+        "use_string".to_string(),
+        body.get("use_string").cloned().unwrap_or(Value::Bool(true)),
+    );
+    for key in TEXT_PARAMETER_KEYS {
+        if let Some(value) = body.get(*key).filter(|value| !value.is_null()) {
+            parameters.insert((*key).to_string(), value.clone());
+        }
+    }
+    let module = optional_string(body, "prefix").unwrap_or(DEFAULT_TEXT_MODULE);
+    parameters.insert("prefix".to_string(), Value::String(module.to_string()));
+
+    Ok(json!({
+        "input": input,
+        "model": model,
+        "parameters": parameters,
+    }))
+}
+
+/// Builds a NovelAI image request from the Stable Diffusion extension body.
+pub(super) fn build_image_payload(body: &Value) -> Result<Value, ApplicationError> {
+    let prompt = require_string(body, "prompt")?;
+    let negative_prompt = optional_string(body, "negative_prompt").unwrap_or_default();
+    let model = optional_string(body, "model").unwrap_or(DEFAULT_IMAGE_MODEL);
+    // V4 models reject SMEA.
+    let is_v4 = model.contains("diffusion-4");
+    let seed = body
+        .get("seed")
+        .and_then(Value::as_u64)
+        .unwrap_or_else(|| rand::rng().random_range(0..u64::from(u32::MAX)));
+    let flag = |key: &str| body.get(key).and_then(Value::as_bool).unwrap_or(false);
+    let skip_cfg_above_sigma = if flag("variety_boost") {
+        json!(19)
+    } else {
+        Value::Null
+    };
+
+    Ok(json!({
+        "action": "generate",
+        "input": prompt,
+        "model": model,
+        "parameters": {
+            "params_version": 3,
+            "prefer_brownian": true,
+            "negative_prompt": negative_prompt,
+            "width": body.get("width").cloned().unwrap_or(json!(832)),
+            "height": body.get("height").cloned().unwrap_or(json!(1216)),
+            "scale": body.get("scale").cloned().unwrap_or(json!(5)),
+            "steps": body.get("steps").cloned().unwrap_or(json!(28)),
+            "seed": seed,
+            "sampler": optional_string(body, "sampler").unwrap_or("k_euler_ancestral"),
+            "noise_schedule": optional_string(body, "scheduler").unwrap_or("karras"),
+            "n_samples": 1,
+            "ucPreset": 0,
+            "qualityToggle": false,
+            "dynamic_thresholding": flag("decrisper"),
+            "skip_cfg_above_sigma": skip_cfg_above_sigma,
+            "sm": !is_v4 && flag("sm"),
+            "sm_dyn": !is_v4 && flag("sm_dyn"),
+            "legacy": false,
+            "legacy_v3_extend": false,
+            "add_original_image": false,
+            "use_coords": false,
+            "characterPrompts": [],
+            "v4_prompt": {
+                "caption": { "base_caption": prompt, "char_captions": [] },
+                "use_coords": false,
+                "use_order": true,
+            },
+            "v4_negative_prompt": {
+                "caption": { "base_caption": negative_prompt, "char_captions": [] },
+            },
+        },
+    }))
+}
+
+fn require_string<'a>(body: &'a Value, key: &str) -> Result<&'a str, ApplicationError> {
+    optional_string(body, key).ok_or_else(|| {
+        ApplicationError::ValidationError(format!("NovelAI request is missing '{key}'"))
+    })
+}
+
+fn optional_string<'a>(body: &'a Value, key: &str) -> Option<&'a str> {
+    body.get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{build_image_payload, build_text_payload};
+    use crate::application::errors::ApplicationError;
+
+    #[test]
+    fn text_payload_nests_parameters_and_keeps_the_module() {
+        let payload = build_text_payload(&json!({
+            "input": "Once upon a time",
+            "model": "llama-3-erato-v1",
+            "streaming": true,
+            "temperature": 1.1,
+            "max_length": 150,
+            "num_logprobs": null,
+            "prefix": "special_instruct",
+            "order": [2, 3, 0],
+        }))
+        .expect("payload");
+
+        assert_eq!(payload["input"], "Once upon a time");
+        assert_eq!(payload["model"], "llama-3-erato-v1");
+        assert_eq!(payload["parameters"]["temperature"], 1.1);
+        assert_eq!(payload["parameters"]["max_length"], 150);
+        assert_eq!(payload["parameters"]["prefix"], "special_instruct");
+        assert_eq!(payload["parameters"]["use_string"], true);
+        assert!(payload["parameters"].get("num_logprobs").is_none());
+        assert!(payload["parameters"].get("streaming").is_none());
+    }
+
+    #[test]
+    fn text_payload_requires_input_and_model() {
+        assert!(matches!(
+            build_text_payload(&json!({ "input": "Hi" })),
+            Err(ApplicationError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn image_payload_disables_smea_for_v4_models() {
+        let payload = build_image_payload(&json!({
+            "prompt": "a cat",
+            "model": "nai-diffusion-4-full",
+            "sm": true,
+            "seed": 42,
+        }))
+        .expect("payload");
+
+        assert_eq!(payload["parameters"]["sm"], false);
+        assert_eq!(payload["parameters"]["seed"], 42);
+        assert_eq!(
+            payload["parameters"]["v4_prompt"]["caption"]["base_caption"],
+            "a cat"
+        );
+    }
+}
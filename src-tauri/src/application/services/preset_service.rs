@@ -1,7 +1,12 @@
+use crate::application::dto::preset_dto::{ImportedPresetDto, PresetReferenceDto};
 use crate::domain::errors::DomainError;
-use crate::domain::models::preset::{DefaultPreset, Preset, PresetType};
+use crate::domain::models::preset::{
+    DefaultPreset, PRESET_BUNDLE_FORMAT, PRESET_BUNDLE_VERSION, Preset, PresetBundle,
+    PresetBundleEntry, PresetType,
+};
 use crate::domain::repositories::preset_repository::PresetRepository;
 use crate::infrastructure::logging::logger;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 /// Service for managing presets
@@ -236,6 +241,176 @@ impl PresetService {
 
         Ok(preset)
     }
+
+    /// Package stored presets into a shareable bundle
+    ///
+    /// # Arguments
+    ///
+    /// * `references` - Presets to include, in bundle order
+    ///
+    /// # Returns
+    ///
+    /// * `Result<PresetBundle, DomainError>` - The bundle, or NotFound if any preset is missing
+    pub async fn export_preset_bundle(
+        &self,
+        references: &[PresetReferenceDto],
+    ) -> Result<PresetBundle, DomainError> {
+        logger::debug(&format!(
+            "Exporting {} preset(s) as bundle",
+            references.len()
+        ));
+
+        if references.is_empty() {
+            return Err(DomainError::InvalidData(
+                "Preset bundle must contain at least one preset".to_string(),
+            ));
+        }
+
+        let mut presets = Vec::with_capacity(references.len());
+        for reference in references {
+            let preset_type = PresetType::from_api_id(&reference.api_id).ok_or_else(|| {
+                DomainError::InvalidData(format!("Unknown API ID: {}", reference.api_id))
+            })?;
+            let preset = self
+                .preset_repository
+                .get_preset(&reference.name, &preset_type)
+                .await?
+                .ok_or_else(|| {
+                    DomainError::NotFound(format!(
+                        "Preset not found: {} ({})",
+                        reference.name, reference.api_id
+                    ))
+                })?;
+
+            presets.push(PresetBundleEntry {
+                name: preset.name,
+                api_id: preset_type.to_api_id().to_string(),
+                data: preset.data,
+            });
+        }
+
+        Ok(PresetBundle {
+            format: PRESET_BUNDLE_FORMAT.to_string(),
+            version: PRESET_BUNDLE_VERSION,
+            generator: format!("TauriTavern {}", env!("CARGO_PKG_VERSION")),
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            presets,
+        })
+    }
+
+    /// Import every preset in a bundle
+    ///
+    /// The whole bundle is validated before anything is written. Without `overwrite`,
+    /// presets whose name is already taken are saved as "Name (2)", "Name (3)", ...
+    ///
+    /// # Arguments
+    ///
+    /// * `bundle` - Raw bundle JSON
+    /// * `overwrite` - Replace existing presets with the same name
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<ImportedPresetDto>, DomainError>` - The presets that were saved
+    pub async fn import_preset_bundle(
+        &self,
+        bundle: serde_json::Value,
+        overwrite: bool,
+    ) -> Result<Vec<ImportedPresetDto>, DomainError> {
+        let bundle: PresetBundle = serde_json::from_value(bundle)
+            .map_err(|e| DomainError::InvalidData(format!("Invalid preset bundle: {}", e)))?;
+
+        if bundle.format != PRESET_BUNDLE_FORMAT {
+            return Err(DomainError::InvalidData(format!(
+                "Unsupported preset bundle format: {}",
+                bundle.format
+            )));
+        }
+        if bundle.version == 0 || bundle.version > PRESET_BUNDLE_VERSION {
+            return Err(DomainError::InvalidData(format!(
+                "Unsupported preset bundle version: {} (supported: {})",
+                bundle.version, PRESET_BUNDLE_VERSION
+            )));
+        }
+        if bundle.presets.is_empty() {
+            return Err(DomainError::InvalidData(
+                "Preset bundle contains no presets".to_string(),
+            ));
+        }
+
+        logger::debug(&format!(
+            "Importing preset bundle: {} preset(s), generator: {}, exported at: {}",
+            bundle.presets.len(),
+            bundle.generator,
+            bundle.exported_at
+        ));
+
+        let mut presets = Vec::with_capacity(bundle.presets.len());
+        for entry in bundle.presets {
+            let preset_type = PresetType::from_api_id(&entry.api_id).ok_or_else(|| {
+                DomainError::InvalidData(format!(
+                    "Unknown API ID in preset bundle: {}",
+                    entry.api_id
+                ))
+            })?;
+            let preset = Preset::new(entry.name.trim().to_string(), preset_type, entry.data);
+            preset.validate().map_err(|e| {
+                DomainError::InvalidData(format!("Invalid preset '{}': {}", entry.name, e))
+            })?;
+            presets.push(preset);
+        }
+
+        let mut claimed = HashSet::new();
+        let mut imported = Vec::with_capacity(presets.len());
+        for mut preset in presets {
+            let original_name = preset.name.clone();
+            preset.name = self
+                .resolve_import_name(&original_name, &preset.preset_type, overwrite, &claimed)
+                .await?;
+            claimed.insert((preset.name.clone(), preset.preset_type.clone()));
+
+            self.preset_repository.save_preset(&preset).await?;
+            imported.push(ImportedPresetDto {
+                name: preset.name,
+                original_name,
+                api_id: preset.preset_type.to_api_id().to_string(),
+            });
+        }
+
+        logger::info(&format!(
+            "Imported {} preset(s) from bundle",
+            imported.len()
+        ));
+        Ok(imported)
+    }
+
+    async fn resolve_import_name(
+        &self,
+        name: &str,
+        preset_type: &PresetType,
+        overwrite: bool,
+        claimed: &HashSet<(String, PresetType)>,
+    ) -> Result<String, DomainError> {
+        let is_taken =
+            |candidate: &str| claimed.contains(&(candidate.to_string(), preset_type.clone()));
+
+        if overwrite && !is_taken(name) {
+            return Ok(name.to_string());
+        }
+
+        let mut candidate = name.to_string();
+        let mut suffix = 2;
+        while is_taken(&candidate)
+            || self
+                .preset_repository
+                .preset_exists(&candidate, preset_type)
+                .await?
+        {
+            candidate = format!("{} ({})", name, suffix);
+            suffix += 1;
+        }
+
+        Ok(candidate)
+    }
 }
 
 #[cfg(test)]
@@ -399,4 +574,102 @@ mod tests {
         assert_eq!(preset.preset_type, PresetType::OpenAI);
         assert_eq!(preset.data["temperature"], 0.7);
     }
+
+    #[tokio::test]
+    async fn test_preset_bundle_round_trip_renames_collisions() {
+        let repository = Arc::new(MockPresetRepository::new());
+        let service = PresetService::new(repository);
+
+        service
+            .save_preset(&Preset::new(
+                "Shared".to_string(),
+                PresetType::OpenAI,
+                json!({"temperature": 0.7}),
+            ))
+            .await
+            .unwrap();
+        service
+            .save_preset(&Preset::new(
+                "Shared".to_string(),
+                PresetType::Instruct,
+                json!({"input_sequence": "### Instruction:"}),
+            ))
+            .await
+            .unwrap();
+
+        let bundle = service
+            .export_preset_bundle(&[
+                PresetReferenceDto {
+                    name: "Shared".to_string(),
+                    api_id: "openai".to_string(),
+                },
+                PresetReferenceDto {
+                    name: "Shared".to_string(),
+                    api_id: "instruct".to_string(),
+                },
+            ])
+            .await
+            .unwrap();
+        assert_eq!(bundle.format, PRESET_BUNDLE_FORMAT);
+        assert_eq!(bundle.presets.len(), 2);
+
+        let bundle_json = serde_json::to_value(&bundle).unwrap();
+        let imported = service
+            .import_preset_bundle(bundle_json.clone(), false)
+            .await
+            .unwrap();
+        assert_eq!(imported[0].name, "Shared (2)");
+        assert_eq!(imported[0].original_name, "Shared");
+        assert_eq!(imported[1].name, "Shared (2)");
+        assert_eq!(imported[1].api_id, "instruct");
+
+        let overwritten = service
+            .import_preset_bundle(bundle_json, true)
+            .await
+            .unwrap();
+        assert_eq!(overwritten[0].name, "Shared");
+    }
+
+    #[tokio::test]
+    async fn test_import_preset_bundle_rejects_invalid_bundles() {
+        let repository = Arc::new(MockPresetRepository::new());
+        let service = PresetService::new(repository);
+
+        let wrong_format = json!({"format": "other", "version": 1, "presets": []});
+        assert!(matches!(
+            service.import_preset_bundle(wrong_format, false).await,
+            Err(DomainError::InvalidData(_))
+        ));
+
+        let future_version = json!({
+            "format": PRESET_BUNDLE_FORMAT,
+            "version": PRESET_BUNDLE_VERSION + 1,
+            "presets": [{"name": "A", "apiId": "openai", "data": {}}],
+        });
+        assert!(matches!(
+            service.import_preset_bundle(future_version, false).await,
+            Err(DomainError::InvalidData(_))
+        ));
+
+        let partially_invalid = json!({
+            "format": PRESET_BUNDLE_FORMAT,
+            "version": PRESET_BUNDLE_VERSION,
+            "presets": [
+                {"name": "Good", "apiId": "openai", "data": {}},
+                {"name": "Bad", "apiId": "unknown", "data": {}},
+            ],
+        });
+        assert!(
+            service
+                .import_preset_bundle(partially_invalid, false)
+                .await
+                .is_err()
+        );
+        assert!(
+            !service
+                .preset_exists("Good", &PresetType::OpenAI)
+                .await
+                .unwrap()
+        );
+    }
 }
@@ -14,7 +14,7 @@ use crate::application::services::agent_profile_service::{
     materialize_agent_system_prompt,
 };
 use crate::application::services::llm_connection_service::{
-    LlmConnectionService, ResolvedLlmModelBinding,
+    LlmConnectionService, ResolvedLlmModelBinding, settings_model_key,
 };
 use crate::domain::models::agent::AgentToolSpec;
 use crate::domain::models::agent::profile::{
@@ -487,7 +487,7 @@ fn apply_model_binding_to_prompt_settings(
         Value::String(binding.chat_completion_source.clone()),
     );
     object.insert(
-        prompt_model_setting_key(&binding.chat_completion_source)?.to_string(),
+        settings_model_key(&binding.chat_completion_source)?.to_string(),
         Value::String(binding.model_id.clone()),
     );
 
@@ -506,30 +506,6 @@ fn apply_model_binding_to_prompt_settings(
     Ok(())
 }
 
-fn prompt_model_setting_key(source: &str) -> Result<&'static str, ApplicationError> {
-    match source {
-        "openai" => Ok("openai_model"),
-        "openrouter" => Ok("openrouter_model"),
-        "custom" => Ok("custom_model"),
-        "claude" => Ok("claude_model"),
-        "makersuite" => Ok("google_model"),
-        "vertexai" => Ok("vertexai_model"),
-        "deepseek" => Ok("deepseek_model"),
-        "cohere" => Ok("cohere_model"),
-        "groq" => Ok("groq_model"),
-        "moonshot" => Ok("moonshot_model"),
-        "nanogpt" => Ok("nanogpt_model"),
-        "chutes" => Ok("chutes_model"),
-        "siliconflow" => Ok("siliconflow_model"),
-        "workers_ai" => Ok("workers_ai_model"),
-        "zai" => Ok("zai_model"),
-        "minimax" => Ok("minimax_model"),
-        other => Err(ApplicationError::InternalError(format!(
-            "prompt_assembly.model_source_unmapped: no prompt settings model key for source `{other}`"
-        ))),
-    }
-}
-
 fn string_field<'a>(
     object: &'a Map<String, Value>,
     field: &str,
@@ -34,6 +34,65 @@ impl QuickReplyService {
         Ok(())
     }
 
+    pub async fn list_quick_reply_sets(&self) -> Result<Vec<Value>, ApplicationError> {
+        let sets = self.quick_reply_repository.list_quick_reply_sets().await?;
+        Ok(sets.into_iter().map(|set| set.data).collect())
+    }
+
+    /// Returns the set as pretty-printed SillyTavern-compatible JSON.
+    pub async fn export_quick_reply_set(&self, name: &str) -> Result<String, ApplicationError> {
+        let set = self
+            .quick_reply_repository
+            .get_quick_reply_set(name)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Quick Reply set not found: {}", name))
+            })?;
+
+        serde_json::to_string_pretty(&set.data).map_err(|error| {
+            ApplicationError::InternalError(format!(
+                "Failed to serialize Quick Reply set: {}",
+                error
+            ))
+        })
+    }
+
+    /// Imports a set from exported JSON. Unless `overwrite` is set, a name that is
+    /// already taken gets a numeric suffix. Returns the stored payload.
+    pub async fn import_quick_reply_set(
+        &self,
+        content: &str,
+        overwrite: bool,
+    ) -> Result<Value, ApplicationError> {
+        let payload: Value = serde_json::from_str(content).map_err(|error| {
+            ApplicationError::ValidationError(format!("Invalid Quick Reply file: {}", error))
+        })?;
+        let mut set = Self::parse_set(payload)?;
+        set.validate().map_err(ApplicationError::ValidationError)?;
+
+        if !overwrite {
+            let base_name = set.name.clone();
+            let mut suffix = 1;
+            while self
+                .quick_reply_repository
+                .get_quick_reply_set(&set.name)
+                .await?
+                .is_some()
+            {
+                suffix += 1;
+                set.name = format!("{} ({})", base_name, suffix);
+            }
+            if let Some(object) = set.data.as_object_mut() {
+                object.insert("name".to_string(), Value::String(set.name.clone()));
+            }
+        }
+
+        self.quick_reply_repository
+            .save_quick_reply_set(&set)
+            .await?;
+        Ok(set.data)
+    }
+
     fn parse_set(payload: Value) -> Result<QuickReplySet, ApplicationError> {
         if !payload.is_object() {
             return Err(ApplicationError::ValidationError(
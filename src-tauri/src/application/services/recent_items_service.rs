@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::application::dto::recent_items_dto::RecentItemTargetDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::models::recent_items::{RecentItem, RecentItems};
+use crate::domain::repositories::recent_items_repository::RecentItemsRepository;
+
+/// Recently opened characters, chats and groups plus pinned favorites, so the home
+/// screen does not depend on browser storage.
+pub struct RecentItemsService {
+    repository: Arc<dyn RecentItemsRepository>,
+    update_lock: Mutex<()>,
+}
+
+impl RecentItemsService {
+    pub fn new(repository: Arc<dyn RecentItemsRepository>) -> Self {
+        Self {
+            repository,
+            update_lock: Mutex::new(()),
+        }
+    }
+
+    pub async fn get_recent_items(&self) -> Result<RecentItems, ApplicationError> {
+        Ok(self.repository.load_recent_items().await?)
+    }
+
+    pub async fn record_recent_item(
+        &self,
+        target: RecentItemTargetDto,
+    ) -> Result<RecentItems, ApplicationError> {
+        let item = item_from_target(target)?;
+        self.update(|items| {
+            items.record_opened(item);
+        })
+        .await
+    }
+
+    pub async fn pin_favorite(
+        &self,
+        target: RecentItemTargetDto,
+    ) -> Result<RecentItems, ApplicationError> {
+        let item = item_from_target(target)?;
+        self.update(|items| {
+            items.pin(item);
+        })
+        .await
+    }
+
+    pub async fn unpin_favorite(
+        &self,
+        target: RecentItemTargetDto,
+    ) -> Result<RecentItems, ApplicationError> {
+        let item = item_from_target(target)?;
+        self.update(|items| {
+            items.unpin(item.kind, &item.id);
+        })
+        .await
+    }
+
+    async fn update(
+        &self,
+        apply: impl FnOnce(&mut RecentItems),
+    ) -> Result<RecentItems, ApplicationError> {
+        let _guard = self.update_lock.lock().await;
+        let mut items = self.repository.load_recent_items().await?;
+        apply(&mut items);
+        self.repository.save_recent_items(&items).await?;
+        Ok(items)
+    }
+}
+
+fn item_from_target(target: RecentItemTargetDto) -> Result<RecentItem, ApplicationError> {
+    let id = target.id.trim();
+    if id.is_empty() {
+        return Err(ApplicationError::ValidationError(
+            "recent_items.invalid_id: item id must not be empty".to_string(),
+        ));
+    }
+
+    Ok(RecentItem {
+        kind: target.kind,
+        id: id.to_string(),
+        name: target
+            .name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty()),
+        timestamp_ms: chrono::Utc::now().timestamp_millis(),
+    })
+}
@@ -1,12 +1,22 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+
 use crate::application::dto::secret_dto::{
-    AllSecretsDto, FindSecretResponseDto, SecretSettingsDto, SecretStateDto, SecretStateItemDto,
+    AllSecretsDto, EncryptedSecretsDto, FindSecretResponseDto, RedactedSecretDto, SecretBundleDto,
+    SecretImportReportDto, SecretSettingsDto, SecretStateDto, SecretStateItemDto,
 };
 use crate::application::errors::ApplicationError;
 use crate::domain::errors::DomainError;
-use crate::domain::models::secret::SecretKeys;
+use crate::domain::models::secret::{SecretKeys, Secrets};
 use crate::domain::repositories::secret_repository::SecretRepository;
+use crate::infrastructure::secret_bundle_crypto::{self, SealedBytes};
+
+const SECRET_BUNDLE_FORMAT: &str = "tauritavern-secrets";
+const SECRET_BUNDLE_VERSION: u32 = 1;
+const MIN_PASSPHRASE_CHARS: usize = 8;
 
 pub struct SecretService {
     secret_repository: Arc<dyn SecretRepository>,
@@ -148,6 +158,118 @@ impl SecretService {
         Ok(())
     }
 
+    /// 导出密钥包：`include_values` 为 false 时只包含键名与标签；为 true 时用口令加密全部密钥，
+    /// 且需要 `allowKeysExposure`
+    pub async fn export_secrets(
+        &self,
+        include_values: bool,
+        passphrase: Option<&str>,
+    ) -> Result<SecretBundleDto, ApplicationError> {
+        tracing::info!("Exporting secrets (values: {})", include_values);
+
+        let mut secrets = self.secret_repository.load().await?;
+        secrets.secrets.remove(SecretKeys::MIGRATED);
+        let mut bundle = SecretBundleDto {
+            format: SECRET_BUNDLE_FORMAT.to_string(),
+            version: SECRET_BUNDLE_VERSION,
+            redacted: HashMap::new(),
+            encrypted: None,
+        };
+
+        if !include_values {
+            bundle.redacted = secrets
+                .secrets
+                .into_iter()
+                .filter(|(_, entries)| !entries.is_empty())
+                .map(|(key, entries)| {
+                    let entries = entries
+                        .into_iter()
+                        .map(|entry| RedactedSecretDto {
+                            label: entry.label,
+                            active: entry.active,
+                        })
+                        .collect();
+                    (key, entries)
+                })
+                .collect();
+            return Ok(bundle);
+        }
+
+        if !self.allow_keys_exposure {
+            return Err(ApplicationError::PermissionDenied(
+                "Keys exposure not allowed".to_string(),
+            ));
+        }
+        let passphrase = validate_passphrase(passphrase)?;
+        let plaintext = serde_json::to_vec(&secrets).map_err(|error| {
+            ApplicationError::InternalError(format!("Failed to serialize secrets: {error}"))
+        })?;
+        let sealed = tokio::task::spawn_blocking(move || {
+            secret_bundle_crypto::seal(&passphrase, &plaintext)
+        })
+        .await
+        .map_err(|error| {
+            ApplicationError::InternalError(format!("Secret encryption task failed: {error}"))
+        })?;
+
+        bundle.encrypted = Some(EncryptedSecretsDto {
+            version: sealed.version,
+            kdf: secret_bundle_crypto::KDF.to_string(),
+            cipher: secret_bundle_crypto::CIPHER.to_string(),
+            iterations: sealed.iterations,
+            salt: BASE64_STANDARD.encode(&sealed.salt),
+            nonce: BASE64_STANDARD.encode(&sealed.nonce),
+            ciphertext: BASE64_STANDARD.encode(&sealed.ciphertext),
+            tag: BASE64_STANDARD.encode(&sealed.tag),
+        });
+        Ok(bundle)
+    }
+
+    /// 导入密钥包并与现有密钥合并：已存在的相同值会被跳过，现有的 active 密钥保持不变
+    pub async fn import_secrets(
+        &self,
+        bundle: SecretBundleDto,
+        passphrase: Option<&str>,
+    ) -> Result<SecretImportReportDto, ApplicationError> {
+        tracing::info!("Importing secrets");
+
+        if bundle.format != SECRET_BUNDLE_FORMAT || bundle.version > SECRET_BUNDLE_VERSION {
+            return Err(ApplicationError::ValidationError(format!(
+                "Unsupported secrets bundle: {} v{}",
+                bundle.format, bundle.version
+            )));
+        }
+
+        let mut report = SecretImportReportDto {
+            redacted_keys: bundle.redacted.into_keys().collect(),
+            ..SecretImportReportDto::default()
+        };
+        report.redacted_keys.sort();
+        let Some(encrypted) = bundle.encrypted else {
+            return Ok(report);
+        };
+
+        let imported = decrypt_bundle(encrypted, validate_passphrase(passphrase)?).await?;
+        let mut secrets = self.secret_repository.load().await?;
+        for (key, entries) in imported.secrets {
+            if key == SecretKeys::MIGRATED {
+                continue;
+            }
+            for entry in entries {
+                if secrets.import_entry(key.clone(), entry.value, entry.label, entry.active) {
+                    report.imported += 1;
+                } else {
+                    report.duplicates += 1;
+                }
+            }
+        }
+
+        if report.imported > 0 {
+            self.secret_repository.save(&secrets).await?;
+        }
+        Ok(report)
+    }
+
     fn mask_secret_value(value: &str, can_expose: bool) -> String {
         if can_expose {
             return value.to_string();
@@ -166,3 +288,53 @@ impl SecretService {
         format!("{}{}", "*".repeat(THRESHOLD - EXPOSED_SUFFIX), suffix)
     }
 }
+
+fn validate_passphrase(passphrase: Option<&str>) -> Result<String, ApplicationError> {
+    match passphrase {
+        Some(passphrase) if passphrase.chars().count() >= MIN_PASSPHRASE_CHARS => {
+            Ok(passphrase.to_string())
+        }
+        _ => Err(ApplicationError::ValidationError(format!(
+            "A passphrase of at least {MIN_PASSPHRASE_CHARS} characters is required"
+        ))),
+    }
+}
+
+async fn decrypt_bundle(
+    encrypted: EncryptedSecretsDto,
+    passphrase: String,
+) -> Result<Secrets, ApplicationError> {
+    if encrypted.kdf != secret_bundle_crypto::KDF
+        || encrypted.cipher != secret_bundle_crypto::CIPHER
+    {
+        return Err(ApplicationError::ValidationError(format!(
+            "Unsupported secrets bundle encryption: {} with {}",
+            encrypted.kdf, encrypted.cipher
+        )));
+    }
+    let decode = |label: &str, value: &str| {
+        BASE64_STANDARD.decode(value).map_err(|error| {
+            ApplicationError::ValidationError(format!(
+                "Invalid secrets bundle field {label}: {error}"
+            ))
+        })
+    };
+    let sealed = SealedBytes {
+        version: encrypted.version,
+        iterations: encrypted.iterations,
+        salt: decode("salt", &encrypted.salt)?,
+        nonce: decode("nonce", &encrypted.nonce)?,
+        ciphertext: decode("ciphertext", &encrypted.ciphertext)?,
+        tag: decode("tag", &encrypted.tag)?,
+    };
+
+    let plaintext =
+        tokio::task::spawn_blocking(move || secret_bundle_crypto::open(&passphrase, &sealed))
+            .await
+            .map_err(|error| {
+                ApplicationError::InternalError(format!("Secret decryption task failed: {error}"))
+            })??;
+    serde_json::from_slice(&plaintext).map_err(|error| {
+        ApplicationError::ValidationError(format!("Invalid secrets bundle content: {error}"))
+    })
+}
@@ -0,0 +1,285 @@
+use serde_json::{Map, Value};
+
+use crate::application::dto::settings_dto::{SettingsDiffEntryDto, SettingsDiffKind};
+
+/// Structural diff between two settings documents.
+///
+/// Objects are compared key by key; arrays and scalars are compared as whole values, since
+/// index-wise diffs of lists like `prompt_order` would not be meaningful to restore.
+pub(crate) fn diff_settings(snapshot: &Value, current: &Value) -> Vec<SettingsDiffEntryDto> {
+    let mut changes = Vec::new();
+    diff_values(String::new(), snapshot, current, &mut changes);
+    changes
+}
+
+fn diff_values(
+    path: String,
+    snapshot: &Value,
+    current: &Value,
+    changes: &mut Vec<SettingsDiffEntryDto>,
+) {
+    match (snapshot, current) {
+        (Value::Object(snapshot), Value::Object(current)) => {
+            diff_objects(&path, snapshot, current, changes);
+        }
+        _ if snapshot != current => changes.push(SettingsDiffEntryDto {
+            path,
+            kind: SettingsDiffKind::Changed,
+            snapshot: Some(snapshot.clone()),
+            current: Some(current.clone()),
+        }),
+        _ => {}
+    }
+}
+
+fn diff_objects(
+    path: &str,
+    snapshot: &Map<String, Value>,
+    current: &Map<String, Value>,
+    changes: &mut Vec<SettingsDiffEntryDto>,
+) {
+    let mut keys: Vec<&String> = snapshot.keys().chain(current.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    for key in keys {
+        let child_path = format!("{}/{}", path, escape_pointer_token(key));
+        match (snapshot.get(key), current.get(key)) {
+            (Some(snapshot), Some(current)) => diff_values(child_path, snapshot, current, changes),
+            (Some(snapshot), None) => changes.push(SettingsDiffEntryDto {
+                path: child_path,
+                kind: SettingsDiffKind::SnapshotOnly,
+                snapshot: Some(snapshot.clone()),
+                current: None,
+            }),
+            (None, Some(current)) => changes.push(SettingsDiffEntryDto {
+                path: child_path,
+                kind: SettingsDiffKind::CurrentOnly,
+                snapshot: None,
+                current: Some(current.clone()),
+            }),
+            (None, None) => {}
+        }
+    }
+}
+
+/// Copies the value at each JSON Pointer from `snapshot` into `current`, or removes it from
+/// `current` when the snapshot does not have it. Everything else in `current` is left as is.
+pub(crate) fn apply_settings_paths(
+    current: &mut Value,
+    snapshot: &Value,
+    paths: &[String],
+) -> Result<(), String> {
+    for path in paths {
+        let tokens = parse_pointer(path)?;
+        match snapshot.pointer(path) {
+            Some(value) => set_path(current, snapshot, &tokens, value),
+            None => remove_path(current, &tokens),
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies only the values at `paths` out of `source` into a new document; paths missing
+/// from `source` stay missing.
+pub(crate) fn extract_settings_paths(source: &Value, paths: &[String]) -> Result<Value, String> {
+    let mut extracted = Value::Object(Map::new());
+    for path in paths {
+        let tokens = parse_pointer(path)?;
+        let Some(value) = source.pointer(path) else {
+            continue;
+        };
+
+        let mut target = &mut extracted;
+        for token in &tokens {
+            if !target.is_object() {
+                *target = Value::Object(Map::new());
+            }
+            target = target
+                .as_object_mut()
+                .expect("target is an object")
+                .entry(token.clone())
+                .or_insert(Value::Null);
+        }
+        *target = value.clone();
+    }
+
+    Ok(extracted)
+}
+
+fn set_path(current: &mut Value, snapshot: &Value, tokens: &[String], value: &Value) {
+    let mut target = current;
+    let mut snapshot_node = snapshot;
+
+    for (index, token) in tokens.iter().enumerate() {
+        snapshot_node = &snapshot_node[token.as_str()];
+        if !target.is_object() {
+            *target = Value::Object(Map::new());
+        }
+        let object = target.as_object_mut().expect("target is an object");
+
+        if index + 1 == tokens.len() {
+            object.insert(token.clone(), value.clone());
+            return;
+        }
+
+        // A missing or non-object parent in the current settings is restored wholesale.
+        if !object.get(token).is_some_and(Value::is_object) {
+            object.insert(token.clone(), snapshot_node.clone());
+            return;
+        }
+        target = object.get_mut(token).expect("parent exists");
+    }
+}
+
+fn remove_path(current: &mut Value, tokens: &[String]) {
+    let Some((last, parents)) = tokens.split_last() else {
+        return;
+    };
+
+    let mut target = current;
+    for token in parents {
+        match target.get_mut(token.as_str()) {
+            Some(next) => target = next,
+            None => return,
+        }
+    }
+
+    if let Some(object) = target.as_object_mut() {
+        object.remove(last);
+    }
+}
+
+fn parse_pointer(path: &str) -> Result<Vec<String>, String> {
+    let Some(rest) = path.strip_prefix('/') else {
+        return Err(format!(
+            "Invalid settings path '{}': must start with '/'",
+            path
+        ));
+    };
+
+    Ok(rest.split('/').map(unescape_pointer_token).collect())
+}
+
+fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+fn unescape_pointer_token(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{apply_settings_paths, diff_settings, extract_settings_paths};
+    use crate::application::dto::settings_dto::SettingsDiffKind;
+
+    #[test]
+    fn diff_reports_nested_changes_with_json_pointers() {
+        let snapshot = json!({
+            "power_user": { "theme": "Dark", "font_scale": 1 },
+            "a/b": true,
+            "removed_later": 1,
+        });
+        let current = json!({
+            "power_user": { "theme": "Light", "font_scale": 1 },
+            "a/b": true,
+            "added_later": [1, 2],
+        });
+
+        let changes = diff_settings(&snapshot, &current);
+        let summary: Vec<(&str, SettingsDiffKind)> = changes
+            .iter()
+            .map(|change| (change.path.as_str(), change.kind))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("/added_later", SettingsDiffKind::CurrentOnly),
+                ("/power_user/theme", SettingsDiffKind::Changed),
+                ("/removed_later", SettingsDiffKind::SnapshotOnly),
+            ]
+        );
+        assert_eq!(changes[1].snapshot, Some(json!("Dark")));
+        assert_eq!(changes[1].current, Some(json!("Light")));
+    }
+
+    #[test]
+    fn apply_restores_only_selected_paths() {
+        let snapshot = json!({
+            "power_user": { "theme": "Dark", "font_scale": 1 },
+            "context": { "preset": "Default" },
+            "main_api": "openai",
+        });
+        let mut current = json!({
+            "power_user": { "theme": "Light", "font_scale": 2 },
+            "main_api": "kobold",
+            "added_later": true,
+        });
+
+        apply_settings_paths(
+            &mut current,
+            &snapshot,
+            &[
+                "/power_user/theme".to_string(),
+                "/context/preset".to_string(),
+                "/added_later".to_string(),
+            ],
+        )
+        .expect("apply patch");
+
+        assert_eq!(
+            current,
+            json!({
+                "power_user": { "theme": "Dark", "font_scale": 2 },
+                "context": { "preset": "Default" },
+                "main_api": "kobold",
+            })
+        );
+    }
+
+    #[test]
+    fn apply_rejects_relative_paths() {
+        let mut current = json!({});
+        assert!(apply_settings_paths(&mut current, &json!({}), &["theme".to_string()]).is_err());
+    }
+
+    #[test]
+    fn extract_copies_only_the_requested_leaves() {
+        let settings = json!({
+            "main_api": "openai",
+            "oai_settings": { "chat_completion_source": "claude", "temperature": 1 },
+            "power_user": { "theme": "Dark" },
+        });
+        let paths = vec![
+            "/oai_settings/chat_completion_source".to_string(),
+            "/oai_settings/reverse_proxy".to_string(),
+            "/power_user/theme".to_string(),
+        ];
+
+        let extracted = extract_settings_paths(&settings, &paths).expect("extract");
+        assert_eq!(
+            extracted,
+            json!({
+                "oai_settings": { "chat_completion_source": "claude" },
+                "power_user": { "theme": "Dark" },
+            })
+        );
+
+        let mut current = json!({
+            "oai_settings": { "chat_completion_source": "openai", "reverse_proxy": "http://x" },
+        });
+        apply_settings_paths(&mut current, &extracted, &paths).expect("apply");
+        assert_eq!(
+            current,
+            json!({
+                "oai_settings": { "chat_completion_source": "claude" },
+                "power_user": { "theme": "Dark" },
+            })
+        );
+    }
+}
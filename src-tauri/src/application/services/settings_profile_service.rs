@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use tauri::{AppHandle, Emitter};
+
+use super::settings_diff::{apply_settings_paths, extract_settings_paths};
+use crate::application::dto::settings_dto::UserSettingsDto;
+use crate::application::dto::settings_profile_dto::{
+    ActivateSettingsProfileResultDto, ListSettingsProfilesResultDto, SettingsProfileDto,
+};
+use crate::application::errors::ApplicationError;
+use crate::application::services::llm_connection_service::settings_model_key;
+use crate::domain::models::settings::SettingsProfile;
+use crate::domain::repositories::settings_repository::SettingsRepository;
+use crate::infrastructure::http_client_pool::HttpClientPool;
+
+pub const SETTINGS_PROFILE_ACTIVATED_EVENT: &str = "settings_profile:activated";
+
+const MAX_PROFILE_ID_LEN: usize = 64;
+/// `settings.json` fields a profile snapshots, besides the model of the active source.
+const PROFILE_SETTINGS_PATHS: &[&str] = &[
+    "/main_api",
+    "/oai_settings/chat_completion_source",
+    "/oai_settings/reverse_proxy",
+    "/oai_settings/proxy_password",
+    "/oai_settings/custom_url",
+    "/power_user/theme",
+];
+
+/// Named settings profiles kept in `tauritavern-settings.json`. A profile snapshots the
+/// request proxy and the API source, model, reverse proxy and theme from `settings.json`;
+/// activating it writes them back and emits `settings_profile:activated`.
+pub struct SettingsProfileService {
+    app_handle: AppHandle,
+    settings_repository: Arc<dyn SettingsRepository>,
+    http_client_pool: Arc<HttpClientPool>,
+}
+
+impl SettingsProfileService {
+    pub fn new(
+        app_handle: AppHandle,
+        settings_repository: Arc<dyn SettingsRepository>,
+        http_client_pool: Arc<HttpClientPool>,
+    ) -> Self {
+        Self {
+            app_handle,
+            settings_repository,
+            http_client_pool,
+        }
+    }
+
+    pub async fn list_profiles(&self) -> Result<ListSettingsProfilesResultDto, ApplicationError> {
+        let settings = self.settings_repository.load_tauritavern_settings().await?;
+        let profiles = settings.settings_profiles;
+
+        Ok(ListSettingsProfilesResultDto {
+            active_profile_id: profiles.active_profile_id,
+            profiles: profiles
+                .profiles
+                .into_iter()
+                .map(SettingsProfileDto::from)
+                .collect(),
+        })
+    }
+
+    /// Snapshots the current settings into the profile with `id`, replacing it if it
+    /// exists.
+    pub async fn save_profile(
+        &self,
+        id: &str,
+        display_name: &str,
+    ) -> Result<SettingsProfileDto, ApplicationError> {
+        let id = normalize_profile_id(id)?;
+        let display_name = display_name.trim();
+        if display_name.is_empty() {
+            return Err(ApplicationError::ValidationError(
+                "settings_profile.display_name_required: displayName cannot be empty".to_string(),
+            ));
+        }
+
+        let user_settings = self.settings_repository.load_user_settings().await?;
+        let mut settings = self.settings_repository.load_tauritavern_settings().await?;
+        let paths = profile_paths(&user_settings.data);
+        let profile = SettingsProfile {
+            id,
+            display_name: display_name.to_string(),
+            request_proxy: settings.request_proxy.clone(),
+            user_settings: extract_settings_paths(&user_settings.data, &paths)
+                .map_err(ApplicationError::ValidationError)?,
+            paths,
+        };
+
+        let profiles = &mut settings.settings_profiles.profiles;
+        match profiles
+            .iter_mut()
+            .find(|existing| existing.id == profile.id)
+        {
+            Some(existing) => *existing = profile.clone(),
+            None => profiles.push(profile.clone()),
+        }
+
+        self.settings_repository
+            .save_tauritavern_settings(&settings)
+            .await?;
+        tracing::info!("Saved settings profile {}", profile.id);
+        Ok(profile.into())
+    }
+
+    /// Writes the profile's settings back, switches the request proxy and marks the
+    /// profile active.
+    pub async fn activate_profile(
+        &self,
+        profile_id: &str,
+    ) -> Result<ActivateSettingsProfileResultDto, ApplicationError> {
+        let profile_id = profile_id.trim();
+        let mut settings = self.settings_repository.load_tauritavern_settings().await?;
+        let profile = settings
+            .settings_profiles
+            .profiles
+            .iter()
+            .find(|profile| profile.id == profile_id)
+            .cloned()
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "settings_profile.not_found: settings profile `{profile_id}` does not exist"
+                ))
+            })?;
+        HttpClientPool::validate_request_proxy_settings(&profile.request_proxy)?;
+
+        let mut user_settings = self.settings_repository.load_user_settings().await?;
+        apply_settings_paths(
+            &mut user_settings.data,
+            &profile.user_settings,
+            &profile.paths,
+        )
+        .map_err(ApplicationError::ValidationError)?;
+        self.settings_repository
+            .save_user_settings(&user_settings)
+            .await?;
+
+        settings.request_proxy = profile.request_proxy.clone();
+        settings.settings_profiles.active_profile_id = Some(profile.id.clone());
+        self.settings_repository
+            .save_tauritavern_settings(&settings)
+            .await?;
+        self.http_client_pool
+            .apply_request_proxy_settings(&settings.request_proxy)?;
+
+        let profile = SettingsProfileDto::from(profile);
+        tracing::info!("Activated settings profile {}", profile.id);
+        if let Err(error) = self
+            .app_handle
+            .emit(SETTINGS_PROFILE_ACTIVATED_EVENT, &profile)
+        {
+            tracing::warn!("Failed to emit settings profile activation: {}", error);
+        }
+
+        Ok(ActivateSettingsProfileResultDto {
+            profile,
+            settings: UserSettingsDto::from(user_settings),
+        })
+    }
+}
+
+fn normalize_profile_id(id: &str) -> Result<String, ApplicationError> {
+    let id = id.trim();
+    let valid = !id.is_empty()
+        && id.len() <= MAX_PROFILE_ID_LEN
+        && id
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    if !valid {
+        return Err(ApplicationError::ValidationError(format!(
+            "settings_profile.id_invalid: ids use 1-{MAX_PROFILE_ID_LEN} letters, digits, '-' or '_'"
+        )));
+    }
+    Ok(id.to_string())
+}
+
+/// The fixed profile paths plus the model key of the selected chat completion source.
+fn profile_paths(user_settings: &Value) -> Vec<String> {
+    let mut paths: Vec<String> = PROFILE_SETTINGS_PATHS
+        .iter()
+        .map(|path| path.to_string())
+        .collect();
+    let model_key = user_settings
+        .pointer("/oai_settings/chat_completion_source")
+        .and_then(Value::as_str)
+        .and_then(|source| settings_model_key(source).ok());
+    if let Some(model_key) = model_key {
+        paths.push(format!("/oai_settings/{model_key}"));
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{normalize_profile_id, profile_paths};
+
+    #[test]
+    fn profile_paths_include_the_active_source_model() {
+        let paths = profile_paths(&json!({
+            "oai_settings": { "chat_completion_source": "claude" },
+        }));
+        assert!(paths.contains(&"/oai_settings/claude_model".to_string()));
+        assert!(paths.contains(&"/power_user/theme".to_string()));
+
+        let paths = profile_paths(&json!({}));
+        assert!(!paths.iter().any(|path| path.ends_with("_model")));
+    }
+
+    #[test]
+    fn normalize_profile_id_rejects_spaces() {
+        assert_eq!(normalize_profile_id(" home ").unwrap(), "home");
+        assert!(normalize_profile_id("work laptop").is_err());
+        assert!(normalize_profile_id("").is_err());
+    }
+}
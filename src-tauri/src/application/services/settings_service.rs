@@ -5,15 +5,31 @@ use std::sync::{
 };
 use std::time::Duration;
 
+use super::settings_diff::{apply_settings_paths, diff_settings};
 use super::settings_repair::repair_sillytavern_prompt_manager_settings;
 use crate::application::dto::settings_dto::{
-    SettingsSnapshotDto, SillyTavernSettingsResponseDto, TauriTavernSettingsDto,
-    UpdateAgentSettingsDto, UpdateTauriTavernSettingsDto, UserSettingsDto,
+    SettingsSnapshotDiffDto, SettingsSnapshotDto, SillyTavernSettingsResponseDto,
+    TauriTavernSettingsDto, UpdateAgentSettingsDto, UpdateConnectionMonitorSettingsDto,
+    UpdateTauriTavernSettingsDto, UserSettingsDto,
 };
 use crate::application::errors::ApplicationError;
+use crate::domain::models::global_hotkeys::GlobalHotkeySettings;
+use crate::domain::models::memory_cache::{
+    MAX_MEMORY_CACHE_CAPACITY, MAX_MEMORY_CACHE_TTL_SECS, MIN_MEMORY_CACHE_TTL_SECS,
+    MemoryCacheSettings,
+};
 use crate::domain::models::settings::{
-    AgentRunRetentionSettings, AgentSettings, DevLoggingSettings,
+    AgentRunRetentionSettings, AgentSettings, ChatCompletionQueueSettings,
+    ChatCompletionTimeoutSettings, ChatSummarySettings, ConnectionMonitorSettings,
+    DevLoggingSettings, GenerationNotificationSettings, IdleGenerationSettings,
+    MAX_CHAT_COMPLETION_QUEUE_CONCURRENCY, MAX_CHAT_COMPLETION_QUEUE_REQUESTS_PER_MINUTE,
+    MAX_CHAT_COMPLETION_TIMEOUT_SECS, MAX_CHAT_SUMMARY_MESSAGES, MAX_CHAT_SUMMARY_TOKENS,
+    MAX_CONNECTION_MONITOR_INTERVAL_SECS, MAX_IDLE_GENERATION_MINUTES,
+    MAX_MODEL_LIST_CACHE_TTL_SECS, MAX_STOP_BIAS_LOGIT_BIAS_VALUE, MAX_STOP_BIAS_STOP_STRINGS,
+    MIN_CONNECTION_MONITOR_INTERVAL_SECS, StopBiasSettings,
 };
+use crate::domain::models::settings_schema::SettingsValidationReport;
+use crate::domain::repositories::chat_completion_repository::ChatCompletionSource;
 use crate::domain::repositories::settings_repository::SettingsRepository;
 
 pub struct SettingsService {
@@ -116,10 +132,18 @@ impl SettingsService {
             settings.close_to_tray_on_close = close_to_tray_on_close;
         }
 
+        if let Some(minimize_to_tray) = dto.minimize_to_tray {
+            settings.minimize_to_tray = minimize_to_tray;
+        }
+
         if let Some(request_proxy) = dto.request_proxy {
             settings.request_proxy = request_proxy.into();
         }
 
+        if let Some(http_client) = dto.http_client {
+            settings.http_client = http_client;
+        }
+
         if let Some(allow_keys_exposure) = dto.allow_keys_exposure {
             settings.allow_keys_exposure = allow_keys_exposure;
         }
@@ -148,6 +172,10 @@ impl SettingsService {
                 }
                 settings.dev.llm_api_keep = llm_api_keep;
             }
+
+            if let Some(performance_tracing) = dev.performance_tracing {
+                settings.dev.performance_tracing = performance_tracing;
+            }
         }
 
         if let Some(dynamic_theme) = dto.dynamic_theme {
@@ -210,12 +238,64 @@ impl SettingsService {
                     settings.models.claude.prompt_cache_ttl = prompt_cache_ttl;
                 }
             }
+
+            if let Some(ttl_secs) = models.model_list_cache_ttl_secs {
+                if ttl_secs > MAX_MODEL_LIST_CACHE_TTL_SECS {
+                    return Err(ApplicationError::ValidationError(format!(
+                        "Model list cache TTL must be at most {} seconds",
+                        MAX_MODEL_LIST_CACHE_TTL_SECS
+                    )));
+                }
+                settings.models.model_list_cache_ttl_secs = ttl_secs;
+            }
         }
 
         if let Some(agent) = dto.agent {
             Self::apply_agent_settings_update(&mut settings.agent, agent)?;
         }
 
+        if let Some(connection_monitor) = dto.connection_monitor {
+            Self::apply_connection_monitor_settings_update(
+                &mut settings.connection_monitor,
+                connection_monitor,
+            )?;
+        }
+
+        if let Some(chat_completion_timeouts) = dto.chat_completion_timeouts {
+            validate_chat_completion_timeout_settings(&chat_completion_timeouts)?;
+            settings.chat_completion_timeouts = chat_completion_timeouts;
+        }
+
+        if let Some(chat_completion_queue) = dto.chat_completion_queue {
+            validate_chat_completion_queue_settings(&chat_completion_queue)?;
+            settings.chat_completion_queue = chat_completion_queue;
+        }
+
+        if let Some(chat_summary) = dto.chat_summary {
+            validate_chat_summary_settings(&chat_summary)?;
+            settings.chat_summary = chat_summary;
+        }
+
+        if let Some(idle_generation) = dto.idle_generation {
+            validate_idle_generation_settings(&idle_generation)?;
+            settings.idle_generation = idle_generation;
+        }
+
+        if let Some(stop_bias) = dto.stop_bias {
+            validate_stop_bias_settings(&stop_bias)?;
+            settings.stop_bias = stop_bias;
+        }
+
+        if let Some(memory_cache) = dto.memory_cache {
+            validate_memory_cache_settings(&memory_cache)?;
+            settings.memory_cache = memory_cache;
+        }
+
+        if let Some(generation_notifications) = dto.generation_notifications {
+            validate_generation_notification_settings(&generation_notifications)?;
+            settings.generation_notifications = generation_notifications;
+        }
+
         self.settings_repository
             .save_tauritavern_settings(&settings)
             .await?;
@@ -223,6 +303,53 @@ impl SettingsService {
         Ok(TauriTavernSettingsDto::from(settings))
     }
 
+    /// Persists global hotkeys. Callers register them with the OS first, so only
+    /// bindings that are known to work are saved.
+    pub async fn update_global_hotkeys(
+        &self,
+        global_hotkeys: GlobalHotkeySettings,
+    ) -> Result<GlobalHotkeySettings, ApplicationError> {
+        let mut settings = self.settings_repository.load_tauritavern_settings().await?;
+        settings.global_hotkeys = global_hotkeys;
+        self.settings_repository
+            .save_tauritavern_settings(&settings)
+            .await?;
+
+        Ok(settings.global_hotkeys)
+    }
+
+    fn apply_connection_monitor_settings_update(
+        settings: &mut ConnectionMonitorSettings,
+        dto: UpdateConnectionMonitorSettingsDto,
+    ) -> Result<(), ApplicationError> {
+        if let Some(interval_secs) = dto.interval_secs {
+            if !(MIN_CONNECTION_MONITOR_INTERVAL_SECS..=MAX_CONNECTION_MONITOR_INTERVAL_SECS)
+                .contains(&interval_secs)
+            {
+                return Err(ApplicationError::ValidationError(format!(
+                    "Connection monitor interval must be between {} and {} seconds",
+                    MIN_CONNECTION_MONITOR_INTERVAL_SECS, MAX_CONNECTION_MONITOR_INTERVAL_SECS
+                )));
+            }
+            settings.interval_secs = interval_secs;
+        }
+
+        if let Some(slow_threshold_ms) = dto.slow_threshold_ms {
+            if slow_threshold_ms == 0 {
+                return Err(ApplicationError::ValidationError(
+                    "Connection monitor slow threshold must be greater than zero".to_string(),
+                ));
+            }
+            settings.slow_threshold_ms = slow_threshold_ms;
+        }
+
+        if let Some(enabled) = dto.enabled {
+            settings.enabled = enabled;
+        }
+
+        Ok(())
+    }
+
     fn apply_agent_settings_update(
         settings: &mut AgentSettings,
         dto: UpdateAgentSettingsDto,
@@ -357,6 +484,15 @@ impl SettingsService {
         Ok(response)
     }
 
+    pub async fn validate_settings(
+        &self,
+        repair: bool,
+    ) -> Result<Vec<SettingsValidationReport>, ApplicationError> {
+        tracing::info!("Validating settings files (repair: {})", repair);
+
+        Ok(self.settings_repository.validate_settings(repair).await?)
+    }
+
     pub async fn create_snapshot(&self) -> Result<(), ApplicationError> {
         tracing::info!("Creating settings snapshot");
 
@@ -392,6 +528,62 @@ impl SettingsService {
 
         Ok(())
     }
+
+    pub async fn diff_snapshot(
+        &self,
+        name: &str,
+    ) -> Result<SettingsSnapshotDiffDto, ApplicationError> {
+        tracing::info!(
+            "Diffing settings snapshot against current settings: {}",
+            name
+        );
+
+        let snapshot = self.settings_repository.load_snapshot(name).await?;
+        let current = self.settings_repository.load_user_settings().await?;
+
+        Ok(SettingsSnapshotDiffDto {
+            name: name.to_string(),
+            changes: diff_settings(&snapshot.data, &current.data),
+        })
+    }
+
+    pub async fn apply_snapshot_patch(
+        &self,
+        name: &str,
+        paths: &[String],
+    ) -> Result<UserSettingsDto, ApplicationError> {
+        tracing::info!(
+            "Restoring {} key(s) from settings snapshot: {}",
+            paths.len(),
+            name
+        );
+
+        if paths.is_empty() {
+            return Err(ApplicationError::ValidationError(
+                "Select at least one setting to restore".to_string(),
+            ));
+        }
+
+        let snapshot = self.settings_repository.load_snapshot(name).await?;
+        let mut current = self.settings_repository.load_user_settings().await?;
+
+        apply_settings_paths(&mut current.data, &snapshot.data, paths)
+            .map_err(ApplicationError::ValidationError)?;
+
+        let repair_report = repair_sillytavern_prompt_manager_settings(&mut current);
+        if repair_report.changed() {
+            tracing::warn!(
+                "Repaired SillyTavern PromptManager settings after snapshot patch: {}",
+                repair_report
+            );
+        }
+
+        self.settings_repository
+            .save_user_settings(&current)
+            .await?;
+
+        Ok(UserSettingsDto::from(current))
+    }
 }
 
 fn validate_agent_retention_settings(
@@ -402,10 +594,252 @@ fn validate_agent_retention_settings(
         .map_err(|error| ApplicationError::ValidationError(error.message()))
 }
 
+fn validate_chat_completion_timeout_settings(
+    settings: &ChatCompletionTimeoutSettings,
+) -> Result<(), ApplicationError> {
+    if settings.is_valid() {
+        return Ok(());
+    }
+
+    Err(ApplicationError::ValidationError(format!(
+        "Chat completion connect and request timeouts must be positive and every timeout at most {} seconds",
+        MAX_CHAT_COMPLETION_TIMEOUT_SECS
+    )))
+}
+
+fn validate_chat_completion_queue_settings(
+    settings: &ChatCompletionQueueSettings,
+) -> Result<(), ApplicationError> {
+    if let Some(source_key) = settings
+        .sources
+        .keys()
+        .find(|source_key| ChatCompletionSource::parse(source_key).is_none())
+    {
+        return Err(ApplicationError::ValidationError(format!(
+            "Unsupported chat completion source in queue settings: {}",
+            source_key
+        )));
+    }
+    if settings.is_valid() {
+        return Ok(());
+    }
+
+    Err(ApplicationError::ValidationError(format!(
+        "Chat completion queue concurrency must be at most {} and requests per minute at most {}",
+        MAX_CHAT_COMPLETION_QUEUE_CONCURRENCY, MAX_CHAT_COMPLETION_QUEUE_REQUESTS_PER_MINUTE
+    )))
+}
+
+fn validate_chat_summary_settings(settings: &ChatSummarySettings) -> Result<(), ApplicationError> {
+    if settings.is_valid() {
+        return Ok(());
+    }
+
+    Err(ApplicationError::ValidationError(format!(
+        "Chat summary interval must be between 1 and {0} messages, kept messages at most {0} and max tokens between 1 and {1}; the prompt cannot be empty and the injection template must contain {{{{summary}}}}",
+        MAX_CHAT_SUMMARY_MESSAGES, MAX_CHAT_SUMMARY_TOKENS
+    )))
+}
+
+fn validate_idle_generation_settings(
+    settings: &IdleGenerationSettings,
+) -> Result<(), ApplicationError> {
+    if settings.is_valid() {
+        return Ok(());
+    }
+
+    Err(ApplicationError::ValidationError(format!(
+        "Idle generation needs a character avatar and an idle time between 1 and {} minutes",
+        MAX_IDLE_GENERATION_MINUTES
+    )))
+}
+
+fn validate_stop_bias_settings(settings: &StopBiasSettings) -> Result<(), ApplicationError> {
+    if settings.is_valid() {
+        return Ok(());
+    }
+
+    Err(ApplicationError::ValidationError(format!(
+        "Stop and bias rules need a character or model key, at most {} non-empty stop strings per rule set and logit bias entries with text and a value between -{1} and {1}",
+        MAX_STOP_BIAS_STOP_STRINGS, MAX_STOP_BIAS_LOGIT_BIAS_VALUE
+    )))
+}
+
+fn validate_generation_notification_settings(
+    settings: &GenerationNotificationSettings,
+) -> Result<(), ApplicationError> {
+    if settings.is_valid() {
+        return Ok(());
+    }
+
+    Err(ApplicationError::ValidationError(
+        "Muted notification characters need a character avatar".to_string(),
+    ))
+}
+
+fn validate_memory_cache_settings(settings: &MemoryCacheSettings) -> Result<(), ApplicationError> {
+    if settings.characters.is_valid() && settings.chats.is_valid() {
+        return Ok(());
+    }
+
+    Err(ApplicationError::ValidationError(format!(
+        "Memory cache capacity must be at most {} and TTL between {} and {} seconds",
+        MAX_MEMORY_CACHE_CAPACITY, MIN_MEMORY_CACHE_TTL_SECS, MAX_MEMORY_CACHE_TTL_SECS
+    )))
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use serde_json::json;
+
     use super::*;
     use crate::application::dto::settings_dto::UpdateAgentRunRetentionSettingsDto;
+    use crate::domain::errors::DomainError;
+    use crate::domain::models::settings::{SettingsSnapshot, TauriTavernSettings, UserSettings};
+
+    /// Keeps TauriTavern settings in memory; the SillyTavern side is not used here.
+    #[derive(Default)]
+    struct MemorySettingsRepository {
+        tauritavern: Mutex<TauriTavernSettings>,
+    }
+
+    #[async_trait]
+    impl SettingsRepository for MemorySettingsRepository {
+        async fn save_tauritavern_settings(
+            &self,
+            settings: &TauriTavernSettings,
+        ) -> Result<(), DomainError> {
+            *self.tauritavern.lock().unwrap() = settings.clone();
+            Ok(())
+        }
+
+        async fn load_tauritavern_settings(&self) -> Result<TauriTavernSettings, DomainError> {
+            Ok(self.tauritavern.lock().unwrap().clone())
+        }
+
+        async fn save_user_settings(&self, _settings: &UserSettings) -> Result<(), DomainError> {
+            unreachable!()
+        }
+
+        async fn load_user_settings(&self) -> Result<UserSettings, DomainError> {
+            unreachable!()
+        }
+
+        async fn validate_settings(
+            &self,
+            _repair: bool,
+        ) -> Result<Vec<SettingsValidationReport>, DomainError> {
+            unreachable!()
+        }
+
+        async fn create_snapshot(&self) -> Result<(), DomainError> {
+            unreachable!()
+        }
+
+        async fn get_snapshots(&self) -> Result<Vec<SettingsSnapshot>, DomainError> {
+            unreachable!()
+        }
+
+        async fn load_snapshot(&self, _name: &str) -> Result<UserSettings, DomainError> {
+            unreachable!()
+        }
+
+        async fn restore_snapshot(&self, _name: &str) -> Result<(), DomainError> {
+            unreachable!()
+        }
+
+        async fn get_themes(&self) -> Result<Vec<UserSettings>, DomainError> {
+            unreachable!()
+        }
+
+        async fn get_moving_ui_presets(&self) -> Result<Vec<UserSettings>, DomainError> {
+            unreachable!()
+        }
+
+        async fn get_quick_reply_presets(&self) -> Result<Vec<UserSettings>, DomainError> {
+            unreachable!()
+        }
+
+        async fn get_instruct_presets(&self) -> Result<Vec<UserSettings>, DomainError> {
+            unreachable!()
+        }
+
+        async fn get_context_presets(&self) -> Result<Vec<UserSettings>, DomainError> {
+            unreachable!()
+        }
+
+        async fn get_sysprompt_presets(&self) -> Result<Vec<UserSettings>, DomainError> {
+            unreachable!()
+        }
+
+        async fn get_reasoning_presets(&self) -> Result<Vec<UserSettings>, DomainError> {
+            unreachable!()
+        }
+
+        async fn get_koboldai_settings(&self) -> Result<(Vec<String>, Vec<String>), DomainError> {
+            unreachable!()
+        }
+
+        async fn get_novelai_settings(&self) -> Result<(Vec<String>, Vec<String>), DomainError> {
+            unreachable!()
+        }
+
+        async fn get_openai_settings(&self) -> Result<(Vec<String>, Vec<String>), DomainError> {
+            unreachable!()
+        }
+
+        async fn get_textgen_settings(&self) -> Result<(Vec<String>, Vec<String>), DomainError> {
+            unreachable!()
+        }
+
+        async fn get_world_names(&self) -> Result<Vec<String>, DomainError> {
+            unreachable!()
+        }
+    }
+
+    fn tauritavern_update(value: Value) -> UpdateTauriTavernSettingsDto {
+        serde_json::from_value(value).expect("update dto")
+    }
+
+    #[tokio::test]
+    async fn tray_settings_update_is_saved() {
+        let repository = Arc::new(MemorySettingsRepository::default());
+        let service = SettingsService::new(repository.clone());
+
+        let settings = service
+            .update_tauritavern_settings(tauritavern_update(json!({
+                "close_to_tray_on_close": false,
+                "minimize_to_tray": true,
+            })))
+            .await
+            .expect("update settings");
+
+        assert!(settings.minimize_to_tray);
+        assert!(!settings.close_to_tray_on_close);
+        let saved = repository.tauritavern.lock().unwrap().clone();
+        assert!(saved.minimize_to_tray);
+        assert!(!saved.close_to_tray_on_close);
+    }
+
+    #[tokio::test]
+    async fn rejected_update_leaves_tray_settings_unsaved() {
+        let repository = Arc::new(MemorySettingsRepository::default());
+        let service = SettingsService::new(repository.clone());
+
+        let error = service
+            .update_tauritavern_settings(tauritavern_update(json!({
+                "minimize_to_tray": true,
+                "dev": { "llm_api_keep": 0 },
+            })))
+            .await
+            .expect_err("reject invalid dev settings");
+
+        assert!(matches!(error, ApplicationError::ValidationError(_)));
+        assert!(!repository.tauritavern.lock().unwrap().minimize_to_tray);
+    }
 
     #[test]
     fn agent_retention_update_applies_partial_settings() {
@@ -489,4 +923,39 @@ mod tests {
                 if message.contains("agent.retention_keep_full_recent_runs_invalid")
         ));
     }
+
+    #[test]
+    fn connection_monitor_update_rejects_out_of_range_interval() {
+        let mut settings = ConnectionMonitorSettings::default();
+
+        let error = SettingsService::apply_connection_monitor_settings_update(
+            &mut settings,
+            UpdateConnectionMonitorSettingsDto {
+                enabled: Some(false),
+                interval_secs: Some(1),
+                slow_threshold_ms: None,
+            },
+        )
+        .expect_err("reject short interval");
+
+        assert!(matches!(error, ApplicationError::ValidationError(_)));
+        assert!(settings.enabled);
+        assert_eq!(settings.interval_secs, 60);
+    }
+
+    #[test]
+    fn memory_cache_settings_reject_zero_ttl_and_oversized_capacity() {
+        let mut settings = MemoryCacheSettings::default();
+        assert!(validate_memory_cache_settings(&settings).is_ok());
+
+        settings.chats.capacity = 0;
+        assert!(validate_memory_cache_settings(&settings).is_ok());
+
+        settings.chats.ttl_secs = 0;
+        assert!(validate_memory_cache_settings(&settings).is_err());
+
+        settings.chats.ttl_secs = 60;
+        settings.characters.capacity = MAX_MEMORY_CACHE_CAPACITY + 1;
+        assert!(validate_memory_cache_settings(&settings).is_err());
+    }
 }
@@ -0,0 +1,71 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use std::sync::Arc;
+
+use crate::application::dto::sound_dto::UploadSoundDto;
+use crate::domain::errors::DomainError;
+use crate::domain::models::sound_asset::{MAX_SOUND_BYTES, SoundAsset};
+use crate::domain::repositories::sound_repository::SoundRepository;
+use crate::infrastructure::logging::logger;
+
+/// Service for notification and character message sounds
+pub struct SoundService {
+    repository: Arc<dyn SoundRepository>,
+}
+
+impl SoundService {
+    /// Create a new SoundService instance
+    pub fn new(repository: Arc<dyn SoundRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// List notification sounds, or the message sounds of a character
+    pub async fn list_sounds(
+        &self,
+        character: Option<&str>,
+    ) -> Result<Vec<SoundAsset>, DomainError> {
+        self.repository.list_sounds(character).await
+    }
+
+    /// Upload a base64 encoded sound
+    pub async fn upload_sound(&self, dto: UploadSoundDto) -> Result<SoundAsset, DomainError> {
+        logger::debug(&format!(
+            "SoundService: Uploading sound '{}' for {}",
+            dto.name,
+            dto.character.as_deref().unwrap_or("notifications")
+        ));
+
+        let bytes = BASE64_STANDARD
+            .decode(dto.data_base64.trim())
+            .map_err(|error| DomainError::InvalidData(format!("Invalid sound data: {}", error)))?;
+        if bytes.is_empty() {
+            return Err(DomainError::InvalidData(
+                "Sound data cannot be empty".to_string(),
+            ));
+        }
+        if bytes.len() > MAX_SOUND_BYTES {
+            return Err(DomainError::InvalidData(format!(
+                "Sound must be <= {} bytes",
+                MAX_SOUND_BYTES
+            )));
+        }
+
+        self.repository
+            .save_sound(dto.character.as_deref(), &dto.name, &bytes)
+            .await
+    }
+
+    /// Delete a notification or character sound
+    pub async fn delete_sound(
+        &self,
+        character: Option<&str>,
+        file_name: &str,
+    ) -> Result<(), DomainError> {
+        logger::debug(&format!(
+            "SoundService: Deleting sound '{}' for {}",
+            file_name,
+            character.unwrap_or("notifications")
+        ));
+        self.repository.delete_sound(character, file_name).await
+    }
+}
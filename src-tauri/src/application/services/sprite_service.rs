@@ -0,0 +1,97 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::sprite::{
+    DEFAULT_EXPRESSION_LABELS, Sprite, SpritePackImport, is_valid_expression_label,
+};
+use crate::domain::repositories::sprite_repository::SpriteRepository;
+use crate::infrastructure::logging::logger;
+
+/// Service for character expression sprites
+pub struct SpriteService {
+    repository: Arc<dyn SpriteRepository>,
+}
+
+impl SpriteService {
+    /// Create a new SpriteService instance
+    pub fn new(repository: Arc<dyn SpriteRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// List the sprites of a character
+    pub async fn list_sprites(&self, character: &str) -> Result<Vec<Sprite>, DomainError> {
+        self.repository.list_sprites(character).await
+    }
+
+    /// Import a zipped expression pack. Images are accepted when their label is one of
+    /// the default expressions or `custom_labels`.
+    pub async fn upload_sprite_pack(
+        &self,
+        character: &str,
+        archive_path: &Path,
+        custom_labels: &[String],
+    ) -> Result<SpritePackImport, DomainError> {
+        logger::debug(&format!(
+            "SpriteService: Importing sprite pack {} for {}",
+            archive_path.display(),
+            character
+        ));
+
+        let mut labels: Vec<String> = DEFAULT_EXPRESSION_LABELS
+            .iter()
+            .map(|label| label.to_string())
+            .collect();
+        for label in custom_labels {
+            let label = label.trim().to_lowercase();
+            if !is_valid_expression_label(&label) {
+                return Err(DomainError::InvalidData(format!(
+                    "Invalid expression label: {}",
+                    label
+                )));
+            }
+            if !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+
+        self.repository
+            .import_sprite_pack(character, archive_path, &labels)
+            .await
+    }
+
+    /// Delete a sprite of a character
+    pub async fn delete_sprite(&self, character: &str, file_name: &str) -> Result<(), DomainError> {
+        logger::debug(&format!(
+            "SpriteService: Deleting sprite '{}' for {}",
+            file_name, character
+        ));
+        self.repository.delete_sprite(character, file_name).await
+    }
+
+    /// Pick the sprite for a classification label, falling back to `fallback_label`.
+    /// A file named exactly after the label wins over its numbered variants.
+    pub async fn resolve_sprite(
+        &self,
+        character: &str,
+        label: &str,
+        fallback_label: Option<&str>,
+    ) -> Result<Option<Sprite>, DomainError> {
+        let sprites = self.repository.list_sprites(character).await?;
+        let find = |label: &str| {
+            let label = label.trim().to_lowercase();
+            let mut matches = sprites.iter().filter(|sprite| sprite.label == label);
+            let first = matches.clone().next();
+            matches
+                .find(|sprite| {
+                    Path::new(&sprite.name)
+                        .file_stem()
+                        .is_some_and(|stem| stem.eq_ignore_ascii_case(&label))
+                })
+                .or(first)
+                .cloned()
+        };
+
+        Ok(find(label).or_else(|| fallback_label.and_then(find)))
+    }
+}
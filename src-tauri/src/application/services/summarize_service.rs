@@ -0,0 +1,450 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use serde_json::{Map, Value, json};
+use tokio::sync::Mutex;
+
+use crate::application::dto::chat_completion_dto::ChatCompletionGenerateRequestDto;
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+use crate::application::dto::chat_summary_dto::{
+    ChatSummaryDto, ChatSummaryInjectionDto, SummarizeChatSegmentDto,
+};
+use crate::application::errors::ApplicationError;
+use crate::application::services::chat_completion_service::ChatCompletionService;
+use crate::application::services::llm_connection_service::LlmConnectionService;
+use crate::domain::models::settings::ChatSummarySettings;
+use crate::domain::repositories::chat_repository::ChatRepository;
+use crate::domain::repositories::chat_types::{ChatMessageReadItem, ChatMessageRole};
+use crate::domain::repositories::group_chat_repository::GroupChatRepository;
+use crate::domain::repositories::settings_repository::SettingsRepository;
+
+/// `chat_metadata` field holding the rolling summary.
+const SUMMARY_METADATA_FIELD: &str = "tauritavern_summary";
+/// Request field naming the chat whose summary is injected; stripped before generation.
+pub const CHAT_SUMMARY_REQUEST_FIELD: &str = "_tauritavern_chat_summary";
+
+/// Rolling summaries of older chat messages. Each run folds the messages after the
+/// stored summary into it and records how many messages the summary now covers.
+pub struct SummarizeService {
+    settings_repository: Arc<dyn SettingsRepository>,
+    chat_repository: Arc<dyn ChatRepository>,
+    group_chat_repository: Arc<dyn GroupChatRepository>,
+    llm_connection_service: Arc<LlmConnectionService>,
+    chat_completion_service: Arc<ChatCompletionService>,
+    /// Chats with a summary in flight, so automatic runs do not pile up.
+    running: Mutex<HashSet<String>>,
+}
+
+impl SummarizeService {
+    pub fn new(
+        settings_repository: Arc<dyn SettingsRepository>,
+        chat_repository: Arc<dyn ChatRepository>,
+        group_chat_repository: Arc<dyn GroupChatRepository>,
+        llm_connection_service: Arc<LlmConnectionService>,
+        chat_completion_service: Arc<ChatCompletionService>,
+    ) -> Self {
+        Self {
+            settings_repository,
+            chat_repository,
+            group_chat_repository,
+            llm_connection_service,
+            chat_completion_service,
+            running: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub async fn get_chat_summary(
+        &self,
+        chat: &ChatMetadataTargetDto,
+    ) -> Result<Option<ChatSummaryDto>, ApplicationError> {
+        self.load_summary(chat).await
+    }
+
+    pub async fn summarize_chat_segment(
+        &self,
+        dto: SummarizeChatSegmentDto,
+    ) -> Result<ChatSummaryDto, ApplicationError> {
+        let key = chat_key(&dto.chat);
+        if !self.running.lock().await.insert(key.clone()) {
+            return Err(ApplicationError::ValidationError(
+                "chat_summary.in_progress: the chat is already being summarized".to_string(),
+            ));
+        }
+
+        let result = self.run_summary(&dto.chat, dto.end).await;
+        self.running.lock().await.remove(&key);
+        result
+    }
+
+    /// Replaces the `_tauritavern_chat_summary` field with a system message carrying the
+    /// chat's summary, placed after the leading system prompt. With auto-summarize on,
+    /// a due summary is refreshed in the background for the next request.
+    pub async fn apply_chat_summary(
+        self: &Arc<Self>,
+        payload: &mut Map<String, Value>,
+    ) -> Result<(), ApplicationError> {
+        let Some((chat, settings)) = self.inject_chat_summary(payload).await? else {
+            return Ok(());
+        };
+
+        if settings.auto_enabled {
+            let service = self.clone();
+            tauri::async_runtime::spawn(async move {
+                service.auto_summarize(chat, settings).await;
+            });
+        }
+        Ok(())
+    }
+
+    /// Injects the summary like [`Self::apply_chat_summary`] without scheduling an
+    /// automatic run. Returns the chat named by the request field, if any.
+    pub async fn inject_chat_summary(
+        &self,
+        payload: &mut Map<String, Value>,
+    ) -> Result<Option<(ChatMetadataTargetDto, ChatSummarySettings)>, ApplicationError> {
+        let Some(field) = payload.remove(CHAT_SUMMARY_REQUEST_FIELD) else {
+            return Ok(None);
+        };
+        let injection: ChatSummaryInjectionDto =
+            serde_json::from_value(field).map_err(|error| {
+                ApplicationError::ValidationError(format!(
+                    "Invalid chat completion request field {CHAT_SUMMARY_REQUEST_FIELD}: {error}"
+                ))
+            })?;
+
+        let settings = self.load_settings().await?;
+        if let Some(summary) = self.load_summary(&injection.chat).await? {
+            let content = settings
+                .injection_template
+                .replace("{{summary}}", &summary.text);
+            inject_summary_message(payload, content);
+        }
+        Ok(Some((injection.chat, settings)))
+    }
+
+    async fn auto_summarize(&self, chat: ChatMetadataTargetDto, settings: ChatSummarySettings) {
+        let key = chat_key(&chat);
+        if !self.running.lock().await.insert(key.clone()) {
+            return;
+        }
+
+        let result: Result<Option<ChatSummaryDto>, ApplicationError> = async {
+            let covered = self
+                .load_summary(&chat)
+                .await?
+                .map_or(0, |summary| summary.covered_messages);
+            let total = self.read_messages(&chat, &[]).await?.0;
+            let end = total.saturating_sub(settings.keep_recent_messages as usize);
+            if end.saturating_sub(covered) < settings.interval_messages as usize {
+                return Ok(None);
+            }
+            self.run_summary(&chat, Some(end)).await.map(Some)
+        }
+        .await;
+        self.running.lock().await.remove(&key);
+
+        if let Err(error) = result {
+            tracing::warn!("Automatic chat summary failed for {}: {}", key, error);
+        }
+    }
+
+    async fn run_summary(
+        &self,
+        chat: &ChatMetadataTargetDto,
+        end: Option<usize>,
+    ) -> Result<ChatSummaryDto, ApplicationError> {
+        let settings = self.load_settings().await?;
+        let previous = self.load_summary(chat).await?;
+        let start = previous
+            .as_ref()
+            .map_or(0, |summary| summary.covered_messages);
+
+        let total = self.read_messages(chat, &[]).await?.0;
+        let end = end
+            .unwrap_or_else(|| total.saturating_sub(settings.keep_recent_messages as usize))
+            .min(total);
+        if end <= start {
+            return Err(ApplicationError::ValidationError(format!(
+                "chat_summary.nothing_to_summarize: messages before {end} are already summarized"
+            )));
+        }
+
+        let indices: Vec<usize> = (start..end).collect();
+        let (_, messages) = self.read_messages(chat, &indices).await?;
+        let transcript = summary_transcript(previous.as_ref(), &messages);
+        let text = self.generate_summary(&settings, transcript).await?;
+
+        let summary = ChatSummaryDto {
+            text,
+            covered_messages: end,
+        };
+        self.store_summary(chat, &summary).await?;
+        Ok(summary)
+    }
+
+    async fn generate_summary(
+        &self,
+        settings: &ChatSummarySettings,
+        transcript: String,
+    ) -> Result<String, ApplicationError> {
+        let tauritavern_settings = self.settings_repository.load_tauritavern_settings().await?;
+        let profiles = tauritavern_settings.connection_profiles;
+        let profile_id = settings
+            .connection_profile_id
+            .clone()
+            .or(profiles.active_profile_id)
+            .ok_or_else(|| {
+                ApplicationError::ValidationError(
+                    "chat_summary.no_connection_profile: select a connection profile for summaries"
+                        .to_string(),
+                )
+            })?;
+        let profile = profiles
+            .profiles
+            .into_iter()
+            .find(|profile| profile.id == profile_id)
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "connection_profile.not_found: connection profile `{profile_id}` does not exist"
+                ))
+            })?;
+
+        let mut payload = Map::new();
+        self.llm_connection_service
+            .apply_connection_to_payload(
+                &profile.connection_ref,
+                &profile.default_model,
+                &mut payload,
+            )
+            .await?;
+        payload.insert(
+            "messages".to_string(),
+            json!([
+                { "role": "system", "content": settings.prompt },
+                { "role": "user", "content": transcript },
+            ]),
+        );
+        payload.insert("max_tokens".to_string(), Value::from(settings.max_tokens));
+        payload.insert("stream".to_string(), Value::Bool(false));
+
+        let exchange = self
+            .chat_completion_service
+            .generate_exchange(ChatCompletionGenerateRequestDto { payload })
+            .await?;
+        let text = exchange
+            .normalized_response
+            .assistant_message()
+            .get("content")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .unwrap_or_default();
+        if text.is_empty() {
+            return Err(ApplicationError::InternalError(
+                "chat_summary.empty_response: the model returned no summary".to_string(),
+            ));
+        }
+        Ok(text.to_string())
+    }
+
+    async fn load_settings(&self) -> Result<ChatSummarySettings, ApplicationError> {
+        Ok(self
+            .settings_repository
+            .load_tauritavern_settings()
+            .await?
+            .chat_summary)
+    }
+
+    async fn read_messages(
+        &self,
+        chat: &ChatMetadataTargetDto,
+        indices: &[usize],
+    ) -> Result<(usize, Vec<ChatMessageReadItem>), ApplicationError> {
+        let result = match chat {
+            ChatMetadataTargetDto::Character {
+                character_name,
+                file_name,
+            } => {
+                self.chat_repository
+                    .read_character_chat_messages(character_name, file_name, indices)
+                    .await?
+            }
+            ChatMetadataTargetDto::Group { chat_id } => {
+                self.group_chat_repository
+                    .read_group_chat_messages(chat_id, indices)
+                    .await?
+            }
+        };
+        Ok((result.total_messages, result.messages))
+    }
+
+    async fn load_summary(
+        &self,
+        chat: &ChatMetadataTargetDto,
+    ) -> Result<Option<ChatSummaryDto>, ApplicationError> {
+        let metadata = match chat {
+            ChatMetadataTargetDto::Character {
+                character_name,
+                file_name,
+            } => {
+                self.chat_repository
+                    .get_character_chat_metadata(character_name, file_name)
+                    .await?
+            }
+            ChatMetadataTargetDto::Group { chat_id } => {
+                self.group_chat_repository
+                    .get_group_chat_metadata(chat_id)
+                    .await?
+            }
+        };
+        Ok(summary_from_metadata(&metadata))
+    }
+
+    async fn store_summary(
+        &self,
+        chat: &ChatMetadataTargetDto,
+        summary: &ChatSummaryDto,
+    ) -> Result<(), ApplicationError> {
+        let value = serde_json::to_value(summary).map_err(|error| {
+            ApplicationError::InternalError(format!("Failed to serialize chat summary: {error}"))
+        })?;
+        let mut fields = Map::new();
+        fields.insert(SUMMARY_METADATA_FIELD.to_string(), value);
+
+        match chat {
+            ChatMetadataTargetDto::Character {
+                character_name,
+                file_name,
+            } => {
+                self.chat_repository
+                    .update_character_chat_metadata(character_name, file_name, fields)
+                    .await?
+            }
+            ChatMetadataTargetDto::Group { chat_id } => {
+                self.group_chat_repository
+                    .update_group_chat_metadata(chat_id, fields)
+                    .await?
+            }
+        }
+        Ok(())
+    }
+}
+
+fn chat_key(chat: &ChatMetadataTargetDto) -> String {
+    match chat {
+        ChatMetadataTargetDto::Character {
+            character_name,
+            file_name,
+        } => format!("character:{character_name}/{file_name}"),
+        ChatMetadataTargetDto::Group { chat_id } => format!("group:{chat_id}"),
+    }
+}
+
+fn summary_from_metadata(metadata: &Value) -> Option<ChatSummaryDto> {
+    let summary = metadata.get(SUMMARY_METADATA_FIELD)?;
+    serde_json::from_value::<ChatSummaryDto>(summary.clone())
+        .ok()
+        .filter(|summary| !summary.text.trim().is_empty())
+}
+
+/// User turn of the summarization request: the previous summary, then one line per
+/// message prefixed with the speaker.
+fn summary_transcript(
+    previous: Option<&ChatSummaryDto>,
+    messages: &[ChatMessageReadItem],
+) -> String {
+    let mut transcript = String::new();
+    if let Some(previous) = previous {
+        transcript.push_str("Previous summary:\n");
+        transcript.push_str(&previous.text);
+        transcript.push_str("\n\nNew messages:\n");
+    }
+
+    for message in messages {
+        let speaker = message.name.as_deref().unwrap_or(match message.role {
+            ChatMessageRole::User => "User",
+            ChatMessageRole::Assistant => "Assistant",
+            ChatMessageRole::System => "System",
+        });
+        transcript.push_str(speaker);
+        transcript.push_str(": ");
+        transcript.push_str(message.text.trim());
+        transcript.push('\n');
+    }
+    transcript
+}
+
+fn inject_summary_message(payload: &mut Map<String, Value>, content: String) {
+    let Some(messages) = payload.get_mut("messages").and_then(Value::as_array_mut) else {
+        return;
+    };
+
+    let position = messages
+        .iter()
+        .position(|message| {
+            !matches!(
+                message.get("role").and_then(Value::as_str),
+                Some("system" | "developer")
+            )
+        })
+        .unwrap_or(messages.len());
+    messages.insert(position, json!({ "role": "system", "content": content }));
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{inject_summary_message, summary_from_metadata, summary_transcript};
+    use crate::application::dto::chat_summary_dto::ChatSummaryDto;
+    use crate::domain::repositories::chat_types::{ChatMessageReadItem, ChatMessageRole};
+
+    #[test]
+    fn summary_is_read_from_metadata_and_injected_after_system_prompt() {
+        let metadata =
+            json!({ "tauritavern_summary": { "text": "They met.", "coveredMessages": 12 } });
+        let summary = summary_from_metadata(&metadata).expect("summary");
+        assert_eq!(summary.covered_messages, 12);
+        assert_eq!(summary_from_metadata(&json!({})), None);
+
+        let Value::Object(mut payload) = json!({
+            "messages": [
+                { "role": "system", "content": "Main prompt" },
+                { "role": "user", "content": "Hello" },
+            ],
+        }) else {
+            unreachable!();
+        };
+        inject_summary_message(&mut payload, summary.text);
+        assert_eq!(payload["messages"][1]["content"], "They met.");
+        assert_eq!(payload["messages"][2]["content"], "Hello");
+    }
+
+    #[test]
+    fn transcript_folds_previous_summary_and_named_messages() {
+        let previous = ChatSummaryDto {
+            text: "Alice arrived.".to_string(),
+            covered_messages: 2,
+        };
+        let messages = vec![
+            ChatMessageReadItem {
+                index: 2,
+                role: ChatMessageRole::Assistant,
+                name: Some("Alice".to_string()),
+                send_date: None,
+                text: "Hi! ".to_string(),
+            },
+            ChatMessageReadItem {
+                index: 3,
+                role: ChatMessageRole::User,
+                name: None,
+                send_date: None,
+                text: "Hello".to_string(),
+            },
+        ];
+
+        assert_eq!(
+            summary_transcript(Some(&previous), &messages),
+            "Previous summary:\nAlice arrived.\n\nNew messages:\nAlice: Hi!\nUser: Hello\n"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::application::errors::ApplicationError;
+use crate::domain::models::tag::{Tag, TagImportSummary, TagLibrary};
+use crate::domain::repositories::settings_repository::SettingsRepository;
+use crate::domain::repositories::tag_repository::TagRepository;
+
+pub struct TagService {
+    tag_repository: Arc<dyn TagRepository>,
+    settings_repository: Arc<dyn SettingsRepository>,
+    /// Serializes load-modify-save cycles on the tag file
+    write_lock: Mutex<()>,
+}
+
+impl TagService {
+    pub fn new(
+        tag_repository: Arc<dyn TagRepository>,
+        settings_repository: Arc<dyn SettingsRepository>,
+    ) -> Self {
+        Self {
+            tag_repository,
+            settings_repository,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    pub async fn list_tags(&self) -> Result<TagLibrary, ApplicationError> {
+        Ok(self.tag_repository.load_tags().await?)
+    }
+
+    pub async fn create_tag(
+        &self,
+        name: &str,
+        color: Option<String>,
+        color2: Option<String>,
+    ) -> Result<Tag, ApplicationError> {
+        let tag = Tag {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            color,
+            color2,
+            create_date: Some(chrono::Utc::now().timestamp_millis()),
+            extra: Map::new(),
+        };
+
+        self.update(|library| {
+            library
+                .create(tag)
+                .cloned()
+                .map_err(ApplicationError::ValidationError)
+        })
+        .await
+    }
+
+    pub async fn rename_tag(&self, id: &str, name: &str) -> Result<Tag, ApplicationError> {
+        self.update(|library| {
+            ensure_tag_exists(library, id)?;
+            library
+                .rename(id, name)
+                .cloned()
+                .map_err(ApplicationError::ValidationError)
+        })
+        .await
+    }
+
+    pub async fn delete_tag(&self, id: &str) -> Result<(), ApplicationError> {
+        self.update(|library| {
+            ensure_tag_exists(library, id)?;
+            library.delete(id);
+            Ok(())
+        })
+        .await
+    }
+
+    /// Tags a character (by avatar file name) or a group (by id).
+    pub async fn assign_tag(&self, entity_id: &str, tag_id: &str) -> Result<(), ApplicationError> {
+        let entity_id = validate_entity_id(entity_id)?;
+        self.update(|library| {
+            ensure_tag_exists(library, tag_id)?;
+            library
+                .assign(entity_id, tag_id)
+                .map(|_| ())
+                .map_err(ApplicationError::ValidationError)
+        })
+        .await
+    }
+
+    pub async fn unassign_tag(
+        &self,
+        entity_id: &str,
+        tag_id: &str,
+    ) -> Result<(), ApplicationError> {
+        let entity_id = validate_entity_id(entity_id)?;
+        self.update(|library| {
+            library.unassign(entity_id, tag_id);
+            Ok(())
+        })
+        .await
+    }
+
+    /// Merges the `tags` / `tag_map` kept by the frontend in `settings.json`.
+    pub async fn import_tags_from_settings(&self) -> Result<TagImportSummary, ApplicationError> {
+        let settings = self.settings_repository.load_user_settings().await?;
+        let tags = settings.data.get("tags").cloned().unwrap_or(Value::Null);
+        let tag_map = settings.data.get("tag_map").cloned().unwrap_or(Value::Null);
+
+        self.update(|library| Ok(library.merge_from_settings(&tags, &tag_map)))
+            .await
+    }
+
+    async fn update<T>(
+        &self,
+        apply: impl FnOnce(&mut TagLibrary) -> Result<T, ApplicationError>,
+    ) -> Result<T, ApplicationError> {
+        let _guard = self.write_lock.lock().await;
+        let mut library = self.tag_repository.load_tags().await?;
+        let result = apply(&mut library)?;
+        self.tag_repository.save_tags(&library).await?;
+        Ok(result)
+    }
+}
+
+fn ensure_tag_exists(library: &TagLibrary, id: &str) -> Result<(), ApplicationError> {
+    if library.get(id).is_none() {
+        return Err(ApplicationError::NotFound(format!("Tag not found: {}", id)));
+    }
+    Ok(())
+}
+
+fn validate_entity_id(entity_id: &str) -> Result<&str, ApplicationError> {
+    let entity_id = entity_id.trim();
+    if entity_id.is_empty() {
+        return Err(ApplicationError::ValidationError(
+            "Tag target cannot be empty".to_string(),
+        ));
+    }
+    Ok(entity_id)
+}
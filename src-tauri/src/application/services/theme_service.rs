@@ -1,9 +1,11 @@
 use serde_json::Value;
+use std::path::Path;
 use std::sync::Arc;
 
+use crate::application::dto::theme_dto::ThemeSummaryDto;
 use crate::application::errors::ApplicationError;
 use crate::domain::errors::DomainError;
-use crate::domain::models::theme::Theme;
+use crate::domain::models::theme::{Theme, ThemeArchive};
 use crate::domain::repositories::theme_repository::ThemeRepository;
 
 /// Service for managing themes
@@ -65,4 +67,42 @@ impl ThemeService {
             }
         })
     }
+
+    /// List saved themes with preview metadata
+    pub async fn list_themes(&self) -> Result<Vec<ThemeSummaryDto>, ApplicationError> {
+        let themes = self.theme_repository.list_themes().await?;
+        Ok(themes.iter().map(ThemeSummaryDto::from).collect())
+    }
+
+    /// Export a theme together with the local assets its custom CSS uses
+    pub async fn export_theme(&self, name: &str) -> Result<ThemeArchive, ApplicationError> {
+        tracing::info!("Exporting theme: {}", name);
+
+        if name.is_empty() {
+            return Err(ApplicationError::ValidationError(
+                "Theme name is required".to_string(),
+            ));
+        }
+
+        Ok(self.theme_repository.export_theme(name).await?)
+    }
+
+    /// Import a theme from a `.json` file or an exported `.zip` archive
+    pub async fn import_theme(
+        &self,
+        file_path: &Path,
+        overwrite: bool,
+    ) -> Result<ThemeSummaryDto, ApplicationError> {
+        tracing::info!("Importing theme from: {}", file_path.display());
+
+        let theme = self
+            .theme_repository
+            .import_theme(file_path, overwrite)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to import theme {}: {}", file_path.display(), e);
+                ApplicationError::from(e)
+            })?;
+        Ok(ThemeSummaryDto::from(&theme))
+    }
 }
@@ -2,11 +2,14 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use serde_json::Value;
+
+use crate::application::dto::character_dto::CharacterDto;
 use crate::application::dto::tokenization_dto::{
-    LogitBiasEntryDto, OpenAiDecodeRequestDto, OpenAiDecodeResponseDto, OpenAiEncodeRequestDto,
-    OpenAiEncodeResponseDto, OpenAiLogitBiasRequestDto, OpenAiLogitBiasResponseDto,
-    OpenAiTokenCountBatchRequestDto, OpenAiTokenCountBatchResponseDto, OpenAiTokenCountRequestDto,
-    OpenAiTokenCountResponseDto,
+    CharacterFieldTokensDto, CharacterTokenBudgetDto, LogitBiasEntryDto, OpenAiDecodeRequestDto,
+    OpenAiDecodeResponseDto, OpenAiEncodeRequestDto, OpenAiEncodeResponseDto,
+    OpenAiLogitBiasRequestDto, OpenAiLogitBiasResponseDto, OpenAiTokenCountBatchRequestDto,
+    OpenAiTokenCountBatchResponseDto, OpenAiTokenCountRequestDto, OpenAiTokenCountResponseDto,
 };
 use crate::application::errors::ApplicationError;
 use crate::domain::repositories::tokenizer_repository::TokenizerRepository;
@@ -133,6 +136,91 @@ impl TokenizationService {
         Ok(bias)
     }
 
+    /// Token count of each text with `model`'s tokenizer, counted off the async runtime.
+    pub async fn count_text_tokens(
+        &self,
+        model: &str,
+        texts: Vec<String>,
+    ) -> Result<Vec<usize>, ApplicationError> {
+        let model = self.normalize_model(model).into_owned();
+        self.tokenizer_repository.ensure_model_ready(&model).await?;
+
+        let tokenizer_repository = Arc::clone(&self.tokenizer_repository);
+        tokio::task::spawn_blocking(move || {
+            texts
+                .iter()
+                .map(|text| {
+                    tokenizer_repository
+                        .encode(&model, text)
+                        .map(|ids| ids.len())
+                        .map_err(ApplicationError::from)
+                })
+                .collect()
+        })
+        .await
+        .map_err(|error| {
+            ApplicationError::InternalError(format!("Token count task failed: {error}"))
+        })?
+    }
+
+    /// Token count of every card field that ends up in a prompt, plus the embedded
+    /// lorebook's entry contents.
+    pub async fn analyze_character_tokens(
+        &self,
+        character: &CharacterDto,
+        tokenizer: &str,
+    ) -> Result<CharacterTokenBudgetDto, ApplicationError> {
+        let model = self.normalize_model(tokenizer);
+        self.tokenizer_repository
+            .ensure_model_ready(model.as_ref())
+            .await?;
+
+        let lorebook = lorebook_entry_contents(character.character_book.as_ref());
+        let fields: [(&str, Vec<&str>); 9] = [
+            ("description", vec![character.description.as_str()]),
+            ("personality", vec![character.personality.as_str()]),
+            ("scenario", vec![character.scenario.as_str()]),
+            ("first_mes", vec![character.first_mes.as_str()]),
+            (
+                "alternate_greetings",
+                character
+                    .alternate_greetings
+                    .iter()
+                    .map(String::as_str)
+                    .collect(),
+            ),
+            ("mes_example", vec![character.mes_example.as_str()]),
+            ("system_prompt", vec![character.system_prompt.as_str()]),
+            (
+                "post_history_instructions",
+                vec![character.post_history_instructions.as_str()],
+            ),
+            ("character_book", lorebook.clone()),
+        ];
+
+        let mut budget = CharacterTokenBudgetDto {
+            tokenizer: model.into_owned(),
+            lorebook_entries: lorebook.len(),
+            ..Default::default()
+        };
+        for (field, texts) in fields {
+            let mut tokens = 0;
+            for text in texts.into_iter().filter(|text| !text.trim().is_empty()) {
+                tokens += self
+                    .tokenizer_repository
+                    .encode(&budget.tokenizer, text)?
+                    .len();
+            }
+            budget.total_tokens += tokens;
+            budget.fields.push(CharacterFieldTokensDto {
+                field: field.to_string(),
+                tokens,
+            });
+        }
+
+        Ok(budget)
+    }
+
     fn resolve_entry_tokens(
         &self,
         model: &str,
@@ -178,3 +266,146 @@ impl TokenizationService {
         }
     }
 }
+
+/// Contents of a V2 `character_book`; `entries` is an array in the spec, but some
+/// editors write an object keyed by uid.
+fn lorebook_entry_contents(book: Option<&Value>) -> Vec<&str> {
+    let Some(entries) = book.and_then(|book| book.get("entries")) else {
+        return Vec::new();
+    };
+    let entries: Vec<&Value> = match entries {
+        Value::Array(entries) => entries.iter().collect(),
+        Value::Object(entries) => entries.values().collect(),
+        _ => return Vec::new(),
+    };
+
+    entries
+        .into_iter()
+        .filter_map(|entry| entry.get("content").and_then(Value::as_str))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use serde_json::{Value, json};
+
+    use super::{TokenizationService, lorebook_entry_contents};
+    use crate::application::dto::character_dto::CharacterDto;
+    use crate::application::errors::ApplicationError;
+    use crate::domain::errors::DomainError;
+    use crate::domain::models::character::Character;
+    use crate::domain::repositories::tokenizer_repository::TokenizerRepository;
+
+    /// One token per whitespace-separated word; `unknown` models are not available.
+    struct WordTokenizer;
+
+    #[async_trait]
+    impl TokenizerRepository for WordTokenizer {
+        async fn ensure_model_ready(&self, model: &str) -> Result<(), DomainError> {
+            if model == "unknown" {
+                return Err(DomainError::NotFound(format!(
+                    "Tokenizer not found: {model}"
+                )));
+            }
+            Ok(())
+        }
+
+        fn encode(&self, _model: &str, text: &str) -> Result<Vec<u32>, DomainError> {
+            if text.contains('\u{0}') {
+                return Err(DomainError::InvalidData("NUL in text".to_string()));
+            }
+            Ok(text.split_whitespace().map(|_| 0).collect())
+        }
+
+        fn decode(&self, _model: &str, _token_ids: &[u32]) -> Result<String, DomainError> {
+            Ok(String::new())
+        }
+
+        fn count_messages(&self, _model: &str, _messages: &[Value]) -> Result<usize, DomainError> {
+            Ok(0)
+        }
+    }
+
+    fn service() -> TokenizationService {
+        TokenizationService::new(Arc::new(WordTokenizer))
+    }
+
+    fn character() -> CharacterDto {
+        let mut character = CharacterDto::from(Character::new(
+            "Alice".to_string(),
+            "a tall ranger".to_string(),
+            String::new(),
+            "hi there".to_string(),
+        ));
+        character.alternate_greetings = vec!["hello".to_string(), "good morning".to_string()];
+        character.character_book = Some(json!({
+            "entries": { "1": { "content": "the old forest" }, "2": { "content": "" } }
+        }));
+        character
+    }
+
+    #[tokio::test]
+    async fn character_tokens_are_counted_per_field() {
+        let budget = service()
+            .analyze_character_tokens(&character(), " ")
+            .await
+            .expect("analyze");
+
+        assert_eq!(budget.tokenizer, "gpt-4o");
+        let counts = budget
+            .fields
+            .iter()
+            .map(|field| (field.field.as_str(), field.tokens))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            counts,
+            [
+                ("description", 3),
+                ("personality", 0),
+                ("scenario", 0),
+                ("first_mes", 2),
+                ("alternate_greetings", 3),
+                ("mes_example", 0),
+                ("system_prompt", 0),
+                ("post_history_instructions", 0),
+                ("character_book", 3),
+            ]
+        );
+        assert_eq!(budget.lorebook_entries, 2);
+        assert_eq!(budget.total_tokens, 11);
+    }
+
+    #[tokio::test]
+    async fn character_token_analysis_reports_tokenizer_errors() {
+        assert!(matches!(
+            service()
+                .analyze_character_tokens(&character(), "unknown")
+                .await,
+            Err(ApplicationError::NotFound(_))
+        ));
+
+        let mut character = character();
+        character.scenario = "bad\u{0}text".to_string();
+        assert!(matches!(
+            service()
+                .analyze_character_tokens(&character, "gpt-4o")
+                .await,
+            Err(ApplicationError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn lorebook_entries_may_be_an_array_or_an_object() {
+        let array = json!({ "entries": [{ "content": "a" }, { "keys": [] }] });
+        assert_eq!(lorebook_entry_contents(Some(&array)), ["a"]);
+
+        let object = json!({ "entries": { "0": { "content": "b" } } });
+        assert_eq!(lorebook_entry_contents(Some(&object)), ["b"]);
+
+        assert!(lorebook_entry_contents(Some(&json!({ "entries": "none" }))).is_empty());
+        assert!(lorebook_entry_contents(None).is_empty());
+    }
+}
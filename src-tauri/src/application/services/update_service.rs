@@ -1,65 +1,217 @@
+use std::cmp::Ordering;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
 
 use crate::domain::errors::DomainError;
-use crate::domain::models::update::UpdateCheckResult;
+use crate::domain::models::update::{ReleaseInfo, UpdateCheckResult};
 use crate::domain::repositories::update_repository::UpdateRepository;
 
+const UPDATE_CHECK_CACHE_TTL: Duration = Duration::from_secs(30 * 60);
+
+struct CachedUpdateCheck {
+    fetched_at: Instant,
+    include_prerelease: bool,
+    result: UpdateCheckResult,
+}
+
 pub struct UpdateService {
     repository: Arc<dyn UpdateRepository>,
+    cache: Mutex<Option<CachedUpdateCheck>>,
 }
 
 impl UpdateService {
     pub fn new(repository: Arc<dyn UpdateRepository>) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            cache: Mutex::new(None),
+        }
     }
 
     pub async fn check_for_update(&self) -> Result<UpdateCheckResult, DomainError> {
-        let current_version = env!("CARGO_PKG_VERSION");
-        let latest_release = self.repository.get_latest_release().await?;
+        self.check_for_updates(false, false).await
+    }
+
+    /// 将最新 Release 与当前版本按语义化版本比较。结果缓存 30 分钟，`force_refresh`
+    /// 跳过缓存；`include_prerelease` 时在最近的 Release（含预发布）中取最高版本。
+    pub async fn check_for_updates(
+        &self,
+        include_prerelease: bool,
+        force_refresh: bool,
+    ) -> Result<UpdateCheckResult, DomainError> {
+        let mut cache = self.cache.lock().await;
+        if !force_refresh
+            && let Some(cached) = cache.as_ref().filter(|cached| {
+                cached.include_prerelease == include_prerelease
+                    && cached.fetched_at.elapsed() < UPDATE_CHECK_CACHE_TTL
+            })
+        {
+            return Ok(cached.result.clone());
+        }
 
-        let has_update = is_newer_version(current_version, &latest_release.version);
+        let current_version = env!("CARGO_PKG_VERSION");
+        let latest_release = if include_prerelease {
+            newest_release(self.repository.list_recent_releases().await?).ok_or_else(|| {
+                DomainError::NotFound("No published release was found".to_string())
+            })?
+        } else {
+            self.repository.get_latest_release().await?
+        };
 
-        Ok(UpdateCheckResult {
+        let has_update = is_newer_version(current_version, &release_version(&latest_release));
+        let result = UpdateCheckResult {
             has_update,
             current_version: current_version.to_string(),
-            latest_release: if has_update {
-                Some(latest_release)
-            } else {
-                None
-            },
-        })
+            download_url: has_update
+                .then(|| platform_download_url(&latest_release, std::env::consts::OS))
+                .flatten(),
+            latest_release: has_update.then_some(latest_release),
+            checked_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        *cache = Some(CachedUpdateCheck {
+            fetched_at: Instant::now(),
+            include_prerelease,
+            result: result.clone(),
+        });
+        Ok(result)
     }
 }
 
+/// `version` 只保留版本号核心部分；预发布 Release 的 tag 中紧随其后的 `-beta.1` 之类后缀
+/// 作为预发布标识参与比较。
+fn release_version(release: &ReleaseInfo) -> String {
+    if !release.prerelease {
+        return release.version.clone();
+    }
+    let suffix = release
+        .tag_name
+        .split_once(release.version.as_str())
+        .map(|(_, suffix)| suffix)
+        .filter(|suffix| suffix.starts_with('-') || suffix.starts_with('+'))
+        .unwrap_or_default();
+    format!("{}{}", release.version, suffix)
+}
+
+fn newest_release(releases: Vec<ReleaseInfo>) -> Option<ReleaseInfo> {
+    releases.into_iter().reduce(|newest, release| {
+        if is_newer_version(&release_version(&newest), &release_version(&release)) {
+            release
+        } else {
+            newest
+        }
+    })
+}
+
+/// 按平台常见的安装包后缀挑选下载地址，优先匹配当前 CPU 架构。
+fn platform_download_url(release: &ReleaseInfo, os: &str) -> Option<String> {
+    let extensions: &[&str] = match os {
+        "windows" => &["-setup.exe", ".msi"],
+        "macos" => &[".dmg"],
+        "linux" => &[".AppImage", ".deb", ".rpm"],
+        "android" => &[".apk"],
+        _ => &[],
+    };
+    let arch_markers: &[&str] = match std::env::consts::ARCH {
+        "x86_64" => &["x64", "x86_64", "amd64"],
+        "aarch64" => &["aarch64", "arm64"],
+        _ => &[],
+    };
+
+    extensions.iter().find_map(|extension| {
+        let candidates: Vec<_> = release
+            .assets
+            .iter()
+            .filter(|asset| asset.name.ends_with(extension))
+            .collect();
+        candidates
+            .iter()
+            .find(|asset| arch_markers.iter().any(|arch| asset.name.contains(arch)))
+            .or(candidates.first())
+            .map(|asset| asset.download_url.clone())
+    })
+}
+
 fn is_newer_version(local: &str, remote: &str) -> bool {
-    let parse = |value: &str| -> Vec<u64> {
-        value
+    compare_versions(remote, local) == Ordering::Greater
+}
+
+/// 语义化版本比较：缺失的版本段视为 0，带预发布标识的版本低于对应的正式版本，构建元数据
+/// （`+...`）不参与比较。
+pub(crate) fn compare_versions(left: &str, right: &str) -> Ordering {
+    let split = |value: &str| -> (Vec<u64>, Option<String>) {
+        let value = value.trim();
+        let value = value.split_once('+').map_or(value, |(version, _)| version);
+        let (core, prerelease) = match value.split_once('-') {
+            Some((core, prerelease)) => (core, Some(prerelease.to_string())),
+            None => (value, None),
+        };
+        let core = core
             .split('.')
             .map(|part| part.parse::<u64>().unwrap_or(0))
-            .collect()
+            .collect();
+        (core, prerelease)
     };
 
-    let local_parts = parse(local);
-    let remote_parts = parse(remote);
-
-    for index in 0..local_parts.len().max(remote_parts.len()) {
-        let left = local_parts.get(index).copied().unwrap_or(0);
-        let right = remote_parts.get(index).copied().unwrap_or(0);
+    let (left_core, left_prerelease) = split(left);
+    let (right_core, right_prerelease) = split(right);
 
-        if right > left {
-            return true;
-        }
-        if right < left {
-            return false;
+    for index in 0..left_core.len().max(right_core.len()) {
+        let left = left_core.get(index).copied().unwrap_or(0);
+        let right = right_core.get(index).copied().unwrap_or(0);
+        match left.cmp(&right) {
+            Ordering::Equal => {}
+            ordering => return ordering,
         }
     }
 
-    false
+    match (left_prerelease, right_prerelease) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(left), Some(right)) => compare_prerelease(&left, &right),
+    }
+}
+
+fn compare_prerelease(left: &str, right: &str) -> Ordering {
+    let mut left_parts = left.split('.');
+    let mut right_parts = right.split('.');
+    loop {
+        let ordering = match (left_parts.next(), right_parts.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(left), Some(right)) => match (left.parse::<u64>(), right.parse::<u64>()) {
+                (Ok(left), Ok(right)) => left.cmp(&right),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => left.cmp(right),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::is_newer_version;
+    use super::{is_newer_version, newest_release, platform_download_url, release_version};
+    use crate::domain::models::update::{ReleaseAsset, ReleaseInfo};
+
+    fn release(tag_name: &str, version: &str, prerelease: bool) -> ReleaseInfo {
+        ReleaseInfo {
+            tag_name: tag_name.to_string(),
+            version: version.to_string(),
+            name: String::new(),
+            body: String::new(),
+            html_url: String::new(),
+            prerelease,
+            published_at: String::new(),
+            assets: Vec::new(),
+        }
+    }
 
     #[test]
     fn newer_patch_version() {
@@ -91,4 +243,53 @@ mod tests {
         assert!(is_newer_version("1.3", "1.3.1"));
         assert!(!is_newer_version("1.3.1", "1.3"));
     }
+
+    #[test]
+    fn prerelease_versions_follow_semver_precedence() {
+        assert!(is_newer_version("1.4.0-beta.1", "1.4.0"));
+        assert!(!is_newer_version("1.4.0", "1.4.0-beta.2"));
+        assert!(is_newer_version("1.4.0-beta.1", "1.4.0-beta.2"));
+        assert!(is_newer_version("1.4.0-beta.2", "1.4.0-beta.11"));
+        assert!(is_newer_version("1.4.0-alpha", "1.4.0-beta"));
+        assert!(is_newer_version("1.3.0", "1.4.0-beta.1"));
+        assert!(!is_newer_version("1.4.0", "1.4.0+build.5"));
+    }
+
+    #[test]
+    fn newest_release_keeps_the_tag_prerelease_suffix() {
+        let beta = release("v1.5.0-beta.2", "1.5.0", true);
+        assert_eq!(release_version(&beta), "1.5.0-beta.2");
+
+        let newest = newest_release(vec![
+            release("v1.4.1", "1.4.1", false),
+            beta,
+            release("v1.5.0-beta.1", "1.5.0", true),
+        ])
+        .expect("newest release");
+        assert_eq!(newest.tag_name, "v1.5.0-beta.2");
+        assert!(newest_release(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn download_url_matches_the_platform_installer() {
+        let mut release = release("v1.4.0", "1.4.0", false);
+        release.assets = ["TauriTavern_1.4.0_x64-setup.exe", "TauriTavern_1.4.0.apk"]
+            .into_iter()
+            .map(|name| ReleaseAsset {
+                name: name.to_string(),
+                download_url: format!("https://example.com/{name}"),
+                size: 1,
+            })
+            .collect();
+
+        assert_eq!(
+            platform_download_url(&release, "android").as_deref(),
+            Some("https://example.com/TauriTavern_1.4.0.apk")
+        );
+        assert_eq!(
+            platform_download_url(&release, "windows").as_deref(),
+            Some("https://example.com/TauriTavern_1.4.0_x64-setup.exe")
+        );
+        assert!(platform_download_url(&release, "ios").is_none());
+    }
 }
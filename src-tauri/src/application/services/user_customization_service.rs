@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use crate::application::errors::ApplicationError;
+use crate::domain::models::user_customization::{
+    MAX_CUSTOM_CSS_BYTES, MAX_USER_SCRIPT_BYTES, MAX_USER_SCRIPTS, UserScript,
+};
+use crate::domain::repositories::user_customization_repository::UserCustomizationRepository;
+
+/// Custom CSS and user scripts kept under the user's `user/` directory.
+pub struct UserCustomizationService {
+    repository: Arc<dyn UserCustomizationRepository>,
+}
+
+impl UserCustomizationService {
+    pub fn new(repository: Arc<dyn UserCustomizationRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn get_custom_css(&self) -> Result<String, ApplicationError> {
+        Ok(self.repository.load_custom_css().await?)
+    }
+
+    pub async fn save_custom_css(&self, css: &str) -> Result<(), ApplicationError> {
+        if css.len() > MAX_CUSTOM_CSS_BYTES {
+            return Err(ApplicationError::ValidationError(format!(
+                "Custom CSS must be <= {} bytes",
+                MAX_CUSTOM_CSS_BYTES
+            )));
+        }
+
+        Ok(self.repository.save_custom_css(css).await?)
+    }
+
+    pub async fn list_user_scripts(&self) -> Result<Vec<UserScript>, ApplicationError> {
+        Ok(self.repository.list_user_scripts().await?)
+    }
+
+    pub async fn save_user_script(&self, script: UserScript) -> Result<(), ApplicationError> {
+        let name = script.name.trim();
+        if name.is_empty() {
+            return Err(ApplicationError::ValidationError(
+                "User script name is required".to_string(),
+            ));
+        }
+        if script.content.len() > MAX_USER_SCRIPT_BYTES {
+            return Err(ApplicationError::ValidationError(format!(
+                "User script must be <= {} bytes",
+                MAX_USER_SCRIPT_BYTES
+            )));
+        }
+
+        let existing = self.repository.list_user_scripts().await?;
+        let is_new = !existing.iter().any(|existing| existing.name == name);
+        if is_new && existing.len() >= MAX_USER_SCRIPTS {
+            return Err(ApplicationError::ValidationError(format!(
+                "At most {} user scripts can be stored",
+                MAX_USER_SCRIPTS
+            )));
+        }
+
+        Ok(self
+            .repository
+            .save_user_script(&UserScript {
+                name: name.to_string(),
+                content: script.content,
+            })
+            .await?)
+    }
+}
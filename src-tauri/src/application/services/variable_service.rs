@@ -0,0 +1,225 @@
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
+use tokio::sync::Mutex;
+
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::chat_repository::ChatRepository;
+use crate::domain::repositories::global_variable_repository::GlobalVariableRepository;
+use crate::domain::repositories::group_chat_repository::GroupChatRepository;
+
+/// Chat-local and global variables loaded for one read-modify-write cycle.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VariableSet {
+    pub chat: Map<String, Value>,
+    pub global: Map<String, Value>,
+}
+
+/// STscript variable storage: chat-local variables live in `chat_metadata.variables`,
+/// global variables in `global_variables.json`.
+pub struct VariableService {
+    chat_repository: Arc<dyn ChatRepository>,
+    group_chat_repository: Arc<dyn GroupChatRepository>,
+    global_variable_repository: Arc<dyn GlobalVariableRepository>,
+    update_lock: Mutex<()>,
+}
+
+impl VariableService {
+    pub fn new(
+        chat_repository: Arc<dyn ChatRepository>,
+        group_chat_repository: Arc<dyn GroupChatRepository>,
+        global_variable_repository: Arc<dyn GlobalVariableRepository>,
+    ) -> Self {
+        Self {
+            chat_repository,
+            group_chat_repository,
+            global_variable_repository,
+            update_lock: Mutex::new(()),
+        }
+    }
+
+    pub async fn list_chat_variables(
+        &self,
+        chat: &ChatMetadataTargetDto,
+    ) -> Result<Map<String, Value>, ApplicationError> {
+        self.load_chat_variables(chat).await
+    }
+
+    /// Returns `null` for variables that were never set.
+    pub async fn get_chat_variable(
+        &self,
+        chat: &ChatMetadataTargetDto,
+        name: &str,
+    ) -> Result<Value, ApplicationError> {
+        let mut variables = self.load_chat_variables(chat).await?;
+        Ok(variables.remove(name.trim()).unwrap_or(Value::Null))
+    }
+
+    pub async fn set_chat_variable(
+        &self,
+        chat: &ChatMetadataTargetDto,
+        name: &str,
+        value: Value,
+    ) -> Result<(), ApplicationError> {
+        let name = normalize_variable_name(name)?;
+        self.update_variables(Some(chat), |variables| {
+            variables.chat.insert(name, value);
+        })
+        .await
+    }
+
+    /// Deletes the variable; returns whether it existed.
+    pub async fn flush_chat_variable(
+        &self,
+        chat: &ChatMetadataTargetDto,
+        name: &str,
+    ) -> Result<bool, ApplicationError> {
+        self.update_variables(Some(chat), |variables| {
+            variables.chat.remove(name.trim()).is_some()
+        })
+        .await
+    }
+
+    pub async fn list_global_variables(&self) -> Result<Map<String, Value>, ApplicationError> {
+        Ok(self
+            .global_variable_repository
+            .load_global_variables()
+            .await?)
+    }
+
+    /// Returns `null` for variables that were never set.
+    pub async fn get_global_variable(&self, name: &str) -> Result<Value, ApplicationError> {
+        let mut variables = self.list_global_variables().await?;
+        Ok(variables.remove(name.trim()).unwrap_or(Value::Null))
+    }
+
+    pub async fn set_global_variable(
+        &self,
+        name: &str,
+        value: Value,
+    ) -> Result<(), ApplicationError> {
+        let name = normalize_variable_name(name)?;
+        self.update_variables(None, |variables| {
+            variables.global.insert(name, value);
+        })
+        .await
+    }
+
+    /// Deletes the variable; returns whether it existed.
+    pub async fn flush_global_variable(&self, name: &str) -> Result<bool, ApplicationError> {
+        self.update_variables(None, |variables| {
+            variables.global.remove(name.trim()).is_some()
+        })
+        .await
+    }
+
+    /// Loads both scopes, applies `update`, and persists whichever scope changed. Updates
+    /// are serialized so concurrent writers never drop each other's changes. Chat-local
+    /// changes are discarded when `chat` is `None`.
+    pub async fn update_variables<R>(
+        &self,
+        chat: Option<&ChatMetadataTargetDto>,
+        update: impl FnOnce(&mut VariableSet) -> R,
+    ) -> Result<R, ApplicationError> {
+        let _guard = self.update_lock.lock().await;
+
+        let original = VariableSet {
+            chat: match chat {
+                Some(chat) => self.load_chat_variables(chat).await?,
+                None => Map::new(),
+            },
+            global: self
+                .global_variable_repository
+                .load_global_variables()
+                .await?,
+        };
+        let mut variables = original.clone();
+        let result = update(&mut variables);
+
+        match chat {
+            Some(chat) if variables.chat != original.chat => {
+                self.save_chat_variables(chat, variables.chat).await?;
+            }
+            _ => {}
+        }
+        if variables.global != original.global {
+            self.global_variable_repository
+                .save_global_variables(&variables.global)
+                .await?;
+        }
+
+        Ok(result)
+    }
+
+    async fn load_chat_variables(
+        &self,
+        chat: &ChatMetadataTargetDto,
+    ) -> Result<Map<String, Value>, ApplicationError> {
+        let variables = match chat {
+            ChatMetadataTargetDto::Character {
+                character_name,
+                file_name,
+            } => {
+                self.chat_repository
+                    .get_character_chat_variables(character_name, file_name)
+                    .await?
+            }
+            ChatMetadataTargetDto::Group { chat_id } => {
+                self.group_chat_repository
+                    .get_group_chat_variables(chat_id)
+                    .await?
+            }
+        };
+        Ok(variables)
+    }
+
+    async fn save_chat_variables(
+        &self,
+        chat: &ChatMetadataTargetDto,
+        variables: Map<String, Value>,
+    ) -> Result<(), ApplicationError> {
+        match chat {
+            ChatMetadataTargetDto::Character {
+                character_name,
+                file_name,
+            } => {
+                self.chat_repository
+                    .set_character_chat_variables(character_name, file_name, variables)
+                    .await?
+            }
+            ChatMetadataTargetDto::Group { chat_id } => {
+                self.group_chat_repository
+                    .set_group_chat_variables(chat_id, variables)
+                    .await?
+            }
+        }
+        Ok(())
+    }
+}
+
+fn normalize_variable_name(name: &str) -> Result<String, ApplicationError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ApplicationError::ValidationError(
+            "variable.name_required: variable name cannot be empty".to_string(),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_variable_name;
+    use crate::application::errors::ApplicationError;
+
+    #[test]
+    fn normalize_variable_name_trims_and_rejects_blank_names() {
+        assert_eq!(normalize_variable_name("  mood ").expect("valid"), "mood");
+        assert!(matches!(
+            normalize_variable_name("   "),
+            Err(ApplicationError::ValidationError(message))
+                if message.starts_with("variable.name_required")
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+/// Depth, interval, position and role defaults SillyTavern uses for a fresh Author's Note.
+pub const DEFAULT_AUTHOR_NOTE_DEPTH: u32 = 4;
+pub const DEFAULT_AUTHOR_NOTE_INTERVAL: u32 = 1;
+/// `extension_prompt_types.IN_CHAT`: inject at `depth` messages from the end.
+pub const DEFAULT_AUTHOR_NOTE_POSITION: u32 = 1;
+/// `extension_prompt_roles.SYSTEM`.
+pub const DEFAULT_AUTHOR_NOTE_ROLE: u32 = 0;
+
+/// Author's Note injection settings. `position` and `role` keep SillyTavern's numeric
+/// extension prompt codes so chat metadata stays compatible with the frontend.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuthorNote {
+    pub content: String,
+    pub depth: u32,
+    pub interval: u32,
+    pub position: u32,
+    pub role: u32,
+}
+
+impl Default for AuthorNote {
+    fn default() -> Self {
+        Self {
+            content: String::new(),
+            depth: DEFAULT_AUTHOR_NOTE_DEPTH,
+            interval: DEFAULT_AUTHOR_NOTE_INTERVAL,
+            position: DEFAULT_AUTHOR_NOTE_POSITION,
+            role: DEFAULT_AUTHOR_NOTE_ROLE,
+        }
+    }
+}
+
+impl AuthorNote {
+    pub fn has_content(&self) -> bool {
+        !self.content.trim().is_empty()
+    }
+
+    /// Whether the note is injected for a chat with `message_count` messages. Mirrors
+    /// SillyTavern: an interval of 0 disables the note, otherwise it is injected every
+    /// `interval` messages.
+    pub fn is_due(&self, message_count: usize) -> bool {
+        if !self.has_content() || self.interval == 0 {
+            return false;
+        }
+
+        let interval = self.interval as usize;
+        let messages_till_insertion = if message_count >= interval {
+            message_count % interval
+        } else {
+            interval - message_count
+        };
+        messages_till_insertion == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuthorNote;
+
+    fn note(interval: u32) -> AuthorNote {
+        AuthorNote {
+            content: "Keep the tone light.".to_string(),
+            interval,
+            ..AuthorNote::default()
+        }
+    }
+
+    #[test]
+    fn is_due_follows_interval() {
+        assert!(note(1).is_due(1));
+        assert!(note(1).is_due(7));
+        assert!(!note(3).is_due(2));
+        assert!(note(3).is_due(3));
+        assert!(note(3).is_due(6));
+        assert!(!note(3).is_due(7));
+        assert!(!note(0).is_due(3));
+    }
+
+    #[test]
+    fn blank_note_is_never_due() {
+        let blank = AuthorNote {
+            content: "  ".to_string(),
+            ..AuthorNote::default()
+        };
+        assert!(!blank.is_due(1));
+    }
+}
@@ -1,10 +1,40 @@
 use serde::{Deserialize, Serialize};
 
+/// Largest video background accepted on upload.
+pub const MAX_BACKGROUND_VIDEO_BYTES: u64 = 200 * 1024 * 1024;
+
+const VIDEO_BACKGROUND_EXTENSIONS: &[&str] = &["mp4", "webm"];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum BackgroundMediaType {
+    #[default]
+    Image,
+    Video,
+}
+
+impl BackgroundMediaType {
+    pub fn from_filename(filename: &str) -> Self {
+        let is_video = filename
+            .rsplit_once('.')
+            .map(|(_, extension)| {
+                VIDEO_BACKGROUND_EXTENSIONS
+                    .iter()
+                    .any(|candidate| extension.eq_ignore_ascii_case(candidate))
+            })
+            .unwrap_or(false);
+
+        if is_video { Self::Video } else { Self::Image }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct BackgroundListEntry {
     pub filename: String,
     pub is_animated: bool,
+    #[serde(rename = "type", default)]
+    pub media_type: BackgroundMediaType,
 }
 
 #[derive(Debug, Clone)]
@@ -12,3 +42,28 @@ pub struct BackgroundAsset {
     pub bytes: Vec<u8>,
     pub mime_type: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BackgroundMediaType;
+
+    #[test]
+    fn media_type_follows_video_extensions() {
+        assert_eq!(
+            BackgroundMediaType::from_filename("beach.MP4"),
+            BackgroundMediaType::Video
+        );
+        assert_eq!(
+            BackgroundMediaType::from_filename("rain.webm"),
+            BackgroundMediaType::Video
+        );
+        assert_eq!(
+            BackgroundMediaType::from_filename("city.gif"),
+            BackgroundMediaType::Image
+        );
+        assert_eq!(
+            BackgroundMediaType::from_filename("mp4"),
+            BackgroundMediaType::Image
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+pub const BACKUP_SCHEDULE_COLD_START_DELAY_SECS: u64 = 120;
+pub const BACKUP_SCHEDULE_RETRY_DELAY_SECS: u64 = 15 * 60;
+pub const BACKUP_SCHEDULE_MIN_KEEP_LAST: u16 = 1;
+pub const BACKUP_SCHEDULE_MAX_KEEP_LAST: u16 = 365;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupScheduleFrequency {
+    #[default]
+    Daily,
+    Weekly,
+}
+
+impl BackupScheduleFrequency {
+    pub fn interval_ms(self) -> u64 {
+        const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+        match self {
+            Self::Daily => DAY_MS,
+            Self::Weekly => 7 * DAY_MS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupScheduleConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub frequency: BackupScheduleFrequency,
+    /// Absolute directory the archives are written to; must be outside the data root.
+    #[serde(default)]
+    pub directory: Option<String>,
+    #[serde(default = "default_keep_last")]
+    pub keep_last: u16,
+}
+
+impl Default for BackupScheduleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            frequency: BackupScheduleFrequency::default(),
+            directory: None,
+            keep_last: default_keep_last(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BackupScheduleStatus {
+    pub running: bool,
+    pub next_run_at_ms: Option<u64>,
+    pub last_attempt_at_ms: Option<u64>,
+    pub last_success_at_ms: Option<u64>,
+    pub last_archive_path: Option<String>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupScheduleTrigger {
+    Scheduled,
+    Manual,
+}
+
+/// Emitted as `backup_schedule:finished` after every backup attempt.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupScheduleResult {
+    pub trigger: BackupScheduleTrigger,
+    pub success: bool,
+    pub finished_at_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archive_path: Option<String>,
+    pub pruned_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn default_keep_last() -> u16 {
+    7
+}
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+/// Listen on all interfaces so other devices on the LAN can connect.
+pub const DEFAULT_BRIDGE_SERVER_BIND_ADDRESS: &str = "0.0.0.0:8000";
+pub const BRIDGE_SERVER_MIN_AUTH_TOKEN_LEN: usize = 16;
+pub const BRIDGE_PAIRING_CODE_TTL_MS: u64 = 5 * 60 * 1000;
+/// Wrong codes allowed before the pairing session is closed.
+pub const BRIDGE_PAIRING_MAX_FAILED_ATTEMPTS: u32 = 5;
+/// Wrong codes one peer address may send before it is locked out of the session.
+pub const BRIDGE_PAIRING_MAX_FAILED_ATTEMPTS_PER_PEER: u32 = 3;
+/// DNS-SD service type advertised while the bridge server is running.
+pub const BRIDGE_MDNS_SERVICE_TYPE: &str = "_tauritavern._tcp.local.";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeServerConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_bind_address")]
+    pub bind_address: String,
+    pub auth_token: String,
+    /// Browser origins, such as `https://tablet.local:8443`, allowed to call the
+    /// authenticated routes in addition to the origins devices paired from.
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BridgeServerStatus {
+    pub enabled: bool,
+    pub running: bool,
+    pub bind_address: String,
+    /// Port the server is listening on, which differs from `bind_address` when it asks for
+    /// port 0.
+    pub port: Option<u16>,
+    /// `https://<ip>:<port>` URLs reachable from other devices.
+    pub available_addresses: Vec<String>,
+    /// SHA-256 of the server certificate's public key, for clients to pin the
+    /// self-signed certificate.
+    pub spki_sha256: Option<String>,
+    pub auth_token: String,
+    pub pairing_enabled: bool,
+    pub pairing_expires_at_ms: Option<u64>,
+    pub mdns_advertised: bool,
+}
+
+/// A companion device paired through a pairing code. Only the SHA-256 of its token is
+/// kept; the token itself is returned to the device once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgePairedDevice {
+    pub device_id: String,
+    pub device_name: String,
+    pub token_sha256: String,
+    pub paired_at_ms: u64,
+    #[serde(default)]
+    pub last_seen_ms: Option<u64>,
+    /// `Origin` the device paired from. Browsers on that origin may call the
+    /// authenticated routes.
+    #[serde(default)]
+    pub origin: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BridgePairedDeviceSummary {
+    pub device_id: String,
+    pub device_name: String,
+    pub paired_at_ms: u64,
+    pub last_seen_ms: Option<u64>,
+}
+
+impl From<BridgePairedDevice> for BridgePairedDeviceSummary {
+    fn from(device: BridgePairedDevice) -> Self {
+        Self {
+            device_id: device.device_id,
+            device_name: device.device_name,
+            paired_at_ms: device.paired_at_ms,
+            last_seen_ms: device.last_seen_ms,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BridgePairingInfo {
+    pub pair_code: String,
+    pub expires_at_ms: u64,
+    pub address: Option<String>,
+    pub pair_uri: Option<String>,
+    pub qr_svg: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BridgePairRequest {
+    pub pair_code: String,
+    pub device_name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BridgePairResponse {
+    pub device_id: String,
+    pub token: String,
+}
+
+fn default_bind_address() -> String {
+    DEFAULT_BRIDGE_SERVER_BIND_ADDRESS.to_string()
+}
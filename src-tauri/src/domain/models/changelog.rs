@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// Release notes of one version, as bundled with the app.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReleaseNotes {
+    pub version: String,
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default)]
+    pub notes: Vec<String>,
+}
+
+/// One entry of the data migrations ledger.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MigrationRecord {
+    /// Stable identifier of the migration, e.g. `secrets.legacy_format`.
+    pub id: String,
+    /// App version that performed the migration.
+    pub app_version: String,
+    /// RFC 3339 timestamp.
+    pub performed_at: String,
+    pub summary: String,
+    /// Data files or directories the migration rewrote, relative to the data root when
+    /// they live inside it.
+    #[serde(default)]
+    pub files: Vec<String>,
+}
+
+impl MigrationRecord {
+    pub fn now(id: &str, summary: impl Into<String>, files: Vec<String>) -> Self {
+        Self {
+            id: id.to_string(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            performed_at: chrono::Utc::now().to_rfc3339(),
+            summary: summary.into(),
+            files,
+        }
+    }
+}
+
+/// What changed between a previously used version and the running one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangelogSince {
+    pub since_version: String,
+    pub current_version: String,
+    /// Bundled release notes newer than `since_version`, newest first.
+    pub releases: Vec<ReleaseNotes>,
+    /// Migrations performed by versions newer than `since_version`, oldest first.
+    pub migrations: Vec<MigrationRecord>,
+}
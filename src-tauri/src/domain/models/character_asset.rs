@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// An image or clip in a character's gallery (`characters/<avatar stem>/assets/`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterAsset {
+    pub name: String,
+    /// Web path served by the user data asset route, e.g. `/characters/Alice/assets/a.png`
+    pub url: String,
+    pub mime_type: String,
+    pub size: u64,
+    /// Last modification time in milliseconds since the Unix epoch
+    pub modified: i64,
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Sampler settings a user keeps for one character. `Some` values replace the active
+/// preset's when generating for that character; `None` keeps the preset's value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterGenerationOverrides {
+    pub temperature: Option<f64>,
+    pub top_p: Option<f64>,
+    pub max_tokens: Option<u32>,
+    pub model: Option<String>,
+}
+
+impl CharacterGenerationOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.temperature.is_none()
+            && self.top_p.is_none()
+            && self.max_tokens.is_none()
+            && self.model.is_none()
+    }
+
+    /// Writes the set fields over a chat completion request payload.
+    pub fn apply_to(&self, payload: &mut Map<String, Value>) {
+        if let Some(temperature) = self.temperature {
+            payload.insert("temperature".to_string(), Value::from(temperature));
+        }
+        if let Some(top_p) = self.top_p {
+            payload.insert("top_p".to_string(), Value::from(top_p));
+        }
+        if let Some(max_tokens) = self.max_tokens {
+            payload.insert("max_tokens".to_string(), Value::from(max_tokens));
+        }
+        if let Some(model) = &self.model {
+            payload.insert("model".to_string(), Value::String(model.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::CharacterGenerationOverrides;
+
+    #[test]
+    fn set_fields_replace_preset_values() {
+        let Value::Object(mut payload) = json!({
+            "model": "gpt-4o",
+            "temperature": 1.0,
+            "top_p": 0.9,
+            "max_tokens": 300,
+        }) else {
+            unreachable!();
+        };
+        let overrides = CharacterGenerationOverrides {
+            temperature: Some(0.5),
+            model: Some("gpt-4o-mini".to_string()),
+            ..Default::default()
+        };
+
+        overrides.apply_to(&mut payload);
+        assert_eq!(payload["temperature"], 0.5);
+        assert_eq!(payload["model"], "gpt-4o-mini");
+        assert_eq!(payload["top_p"], 0.9);
+        assert_eq!(payload["max_tokens"], 300);
+        assert!(CharacterGenerationOverrides::default().is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Largest private note accepted for one character, in bytes.
+pub const MAX_CHARACTER_NOTE_BYTES: usize = 256 * 1024;
+
+/// A private note a user keeps about one character. Unlike the card's
+/// `creator_notes`, it is never written into the card or shared with it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CharacterNote {
+    /// Avatar filename of the character the note belongs to.
+    pub avatar: String,
+    pub content: String,
+    /// Last modification time in milliseconds since the Unix epoch.
+    pub updated_at: i64,
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// Prompt overrides a user keeps for one character without editing the shareable card.
+/// A `None` field falls back to the card (and then to the preset); `Some("")` blanks
+/// the prompt for this character.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterPromptOverrides {
+    pub system_prompt: Option<String>,
+    /// SillyTavern's post-history instructions, formerly called the jailbreak prompt.
+    #[serde(alias = "jailbreak")]
+    pub post_history_instructions: Option<String>,
+}
+
+impl CharacterPromptOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.system_prompt.is_none() && self.post_history_instructions.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CharacterPromptOverrides;
+
+    #[test]
+    fn reads_legacy_jailbreak_field() {
+        let overrides: CharacterPromptOverrides =
+            serde_json::from_str(r#"{ "jailbreak": "Stay in character." }"#).expect("parse");
+
+        assert_eq!(overrides.system_prompt, None);
+        assert_eq!(
+            overrides.post_history_instructions.as_deref(),
+            Some("Stay in character.")
+        );
+        assert!(!overrides.is_empty());
+        assert!(CharacterPromptOverrides::default().is_empty());
+    }
+}
@@ -97,7 +97,7 @@ pub struct ChatMetadata {
     pub timed_world_info: TimedWorldInfo,
 
     #[serde(default)]
-    pub variables: HashMap<String, String>,
+    pub variables: HashMap<String, serde_json::Value>,
 
     #[serde(default)]
     pub tainted: bool,
@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionHealthStatus {
+    /// No check has run, monitoring is off, or the source cannot be probed.
+    #[default]
+    Unknown,
+    Online,
+    Slow,
+    Offline,
+}
+
+/// Latest connection monitor result for the active connection profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionHealth {
+    pub status: ConnectionHealthStatus,
+    pub profile_id: Option<String>,
+    pub latency_ms: Option<u64>,
+    pub checked_at_ms: Option<i64>,
+    pub consecutive_failures: u32,
+    pub last_error: Option<String>,
+}
@@ -1,6 +1,11 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 use std::path::PathBuf;
 
+/// SillyTavern release the embedded frontend tracks. Extensions compare their
+/// `minimum_client_version` against this.
+pub const SILLYTAVERN_COMPAT_VERSION: &str = "1.18.0";
+
 /// Extension type enum
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ExtensionType {
@@ -32,12 +37,51 @@ pub struct ExtensionManifestMetadata {
     /// Loading order
     #[serde(default = "default_loading_order")]
     pub loading_order: i32,
+    /// Names of extensions that must be installed and enabled, e.g. `third-party/Foo` or `vectors`
+    #[serde(default, deserialize_with = "deserialize_lenient_string_list")]
+    pub dependencies: Vec<String>,
+    /// Oldest SillyTavern version the extension supports
+    #[serde(default, deserialize_with = "deserialize_lenient_string")]
+    pub minimum_client_version: Option<String>,
 }
 
 fn default_loading_order() -> i32 {
     100
 }
 
+// Upstream only warns about malformed optional manifest fields, so they must never make
+// discovery fail here either.
+fn deserialize_lenient_string_list<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+    Ok(value
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+fn deserialize_lenient_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+    Ok(value
+        .as_str()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string))
+}
+
 /// Extension struct
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Extension {
@@ -88,6 +132,51 @@ pub struct ExtensionInstallResult {
     pub display_name: String,
     /// Path to the extension
     pub extension_path: String,
+    /// Manifest checks performed before the extension was installed
+    pub compatibility: ExtensionCompatibilityReport,
+}
+
+/// Outcome of one extension in a batch update
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtensionBatchUpdateStatus {
+    Updated,
+    UpToDate,
+    Failed,
+}
+
+/// Per-extension entry returned by a batch update
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtensionBatchUpdateResult {
+    /// Extension identifier, e.g. `third-party/Foo`
+    pub extension_name: String,
+    /// Whether the extension is installed globally
+    pub global: bool,
+    /// What happened to the extension
+    pub status: ExtensionBatchUpdateStatus,
+    /// Short commit hash after the update, when the check succeeded
+    pub short_commit_hash: Option<String>,
+    /// Error message when the check or update failed
+    pub error: Option<String>,
+}
+
+/// Result of checking an extension manifest against this client and the installed extensions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtensionCompatibilityReport {
+    /// Whether the extension can be installed on this client
+    pub compatible: bool,
+    /// SillyTavern version this client reports to extensions
+    pub client_version: String,
+    /// `minimum_client_version` declared by the manifest
+    pub minimum_client_version: Option<String>,
+    /// `loading_order` declared by the manifest
+    pub loading_order: i32,
+    /// Declared dependencies that are already installed
+    pub satisfied_dependencies: Vec<String>,
+    /// Declared dependencies that still need to be installed
+    pub missing_dependencies: Vec<String>,
+    /// Human readable reasons for `compatible == false`
+    pub errors: Vec<String>,
 }
 
 /// Extension update result
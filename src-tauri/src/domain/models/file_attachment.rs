@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Data Bank scope a document is attached to, mirroring SillyTavern's global,
+/// character and chat attachment lists.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "snake_case")]
+pub enum FileAttachmentScope {
+    Global,
+    /// Keyed by the character avatar file name.
+    Character(String),
+    /// Keyed by the chat file name.
+    Chat(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileAttachment {
+    pub name: String,
+    /// Web path under `/user/files/`, usable with the user data endpoint.
+    pub url: String,
+    pub scope: FileAttachmentScope,
+    pub size: u64,
+    /// Last modification time in milliseconds since the Unix epoch.
+    pub created: i64,
+    pub mime_type: String,
+}
@@ -69,9 +69,31 @@ pub fn sanitize_filename(name: &str) -> String {
     truncate_utf8_bytes(&sanitized, MAX_SANITIZED_FILENAME_BYTES).to_string()
 }
 
+/// Trims a character's avatar filename, the key per-character data is stored under.
+/// Returns `None` for names that are empty or could leave the storage directory.
+pub fn normalize_avatar_filename(avatar: &str) -> Option<&str> {
+    let value = avatar.trim();
+    let invalid = value.is_empty()
+        || value.contains(['/', '\\'])
+        || value.chars().any(char::is_control)
+        || value == "."
+        || value == "..";
+    (!invalid).then_some(value)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::sanitize_filename;
+    use super::{normalize_avatar_filename, sanitize_filename};
+
+    #[test]
+    fn normalize_avatar_filename_trims_and_rejects_paths() {
+        assert_eq!(normalize_avatar_filename(" Alice.png "), Some("Alice.png"));
+        assert_eq!(normalize_avatar_filename("../Alice.png"), None);
+        assert_eq!(normalize_avatar_filename("a\\b.png"), None);
+        assert_eq!(normalize_avatar_filename("a\nb.png"), None);
+        assert_eq!(normalize_avatar_filename(".."), None);
+        assert_eq!(normalize_avatar_filename("  "), None);
+    }
 
     #[test]
     fn sanitize_filename_removes_illegal_characters() {
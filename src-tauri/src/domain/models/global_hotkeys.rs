@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// Actions that can be bound to a system-wide shortcut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GlobalHotkeyAction {
+    /// Shows and focuses the main window, or hides it when it already has focus.
+    ToggleWindow,
+    /// Stops the generation in progress.
+    StopGeneration,
+}
+
+/// Accelerators such as `CommandOrControl+Shift+T`, one per action. Unset or empty
+/// entries are not registered; nothing is bound by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GlobalHotkeySettings {
+    #[serde(default)]
+    pub toggle_window: Option<String>,
+    #[serde(default)]
+    pub stop_generation: Option<String>,
+}
+
+impl GlobalHotkeySettings {
+    /// The configured bindings, with surrounding whitespace removed.
+    pub fn bindings(&self) -> Vec<(GlobalHotkeyAction, &str)> {
+        [
+            (GlobalHotkeyAction::ToggleWindow, &self.toggle_window),
+            (GlobalHotkeyAction::StopGeneration, &self.stop_generation),
+        ]
+        .into_iter()
+        .filter_map(|(action, accelerator)| {
+            let accelerator = accelerator.as_deref()?.trim();
+            (!accelerator.is_empty()).then_some((action, accelerator))
+        })
+        .collect()
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_MEMORY_CACHE_CAPACITY: usize = 100;
+pub const DEFAULT_MEMORY_CACHE_TTL_SECS: u64 = 30 * 60;
+/// A capacity of 0 disables the cache.
+pub const MAX_MEMORY_CACHE_CAPACITY: usize = 10_000;
+pub const MIN_MEMORY_CACHE_TTL_SECS: u64 = 1;
+pub const MAX_MEMORY_CACHE_TTL_SECS: u64 = 24 * 60 * 60;
+
+/// Which entry makes room when a full cache receives a new key. Entries expire after
+/// their TTL under both policies.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheEvictionPolicy {
+    /// Evicts the least recently read or written entry.
+    #[default]
+    Lru,
+    /// Ignores reads and evicts the entry that was written first, i.e. the one closest
+    /// to expiring.
+    TtlOnly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryCacheConfig {
+    #[serde(default = "default_memory_cache_capacity")]
+    pub capacity: usize,
+    #[serde(default = "default_memory_cache_ttl_secs")]
+    pub ttl_secs: u64,
+    #[serde(default)]
+    pub eviction: CacheEvictionPolicy,
+}
+
+impl Default for MemoryCacheConfig {
+    fn default() -> Self {
+        Self {
+            capacity: default_memory_cache_capacity(),
+            ttl_secs: default_memory_cache_ttl_secs(),
+            eviction: CacheEvictionPolicy::default(),
+        }
+    }
+}
+
+impl MemoryCacheConfig {
+    pub fn is_valid(&self) -> bool {
+        self.capacity <= MAX_MEMORY_CACHE_CAPACITY
+            && (MIN_MEMORY_CACHE_TTL_SECS..=MAX_MEMORY_CACHE_TTL_SECS).contains(&self.ttl_secs)
+    }
+}
+
+/// Sizes and eviction of the in-memory character and chat caches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryCacheSettings {
+    #[serde(default)]
+    pub characters: MemoryCacheConfig,
+    #[serde(default)]
+    pub chats: MemoryCacheConfig,
+}
+
+/// Counters since launch (or since the cache was last reconfigured) plus the current
+/// configuration of one cache.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MemoryCacheStats {
+    pub name: String,
+    pub entries: usize,
+    pub capacity: usize,
+    pub ttl_secs: u64,
+    pub eviction: CacheEvictionPolicy,
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries dropped to make room for new keys.
+    pub evictions: u64,
+    /// Entries dropped because their TTL had passed.
+    pub expirations: u64,
+}
+
+fn default_memory_cache_capacity() -> usize {
+    DEFAULT_MEMORY_CACHE_CAPACITY
+}
+
+fn default_memory_cache_ttl_secs() -> u64 {
+    DEFAULT_MEMORY_CACHE_TTL_SECS
+}
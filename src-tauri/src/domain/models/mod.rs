@@ -1,27 +1,45 @@
 // Domain models
 pub mod agent;
 pub mod asset;
+pub mod author_note;
 pub mod avatar;
 pub mod background;
+pub mod backup_schedule;
 pub mod bedrock_model;
+pub mod bridge_server;
+pub mod changelog;
 pub mod character;
+pub mod character_asset;
+pub mod character_generation_overrides;
+pub mod character_note;
+pub mod character_prompt_overrides;
 pub mod chat;
+pub mod connection_health;
 pub mod extension;
+pub mod file_attachment;
 pub mod filename;
+pub mod global_hotkeys;
 pub mod group;
 pub mod image_metadata;
 pub mod lan_sync;
 pub mod llm_connection;
+pub mod memory_cache;
 pub mod preset;
 pub mod quick_reply;
+pub mod recent_items;
 pub mod secret;
 pub mod settings;
+pub mod settings_schema;
 pub mod skill;
+pub mod sound_asset;
+pub mod sprite;
 pub mod sync_automation;
+pub mod tag;
 pub mod theme;
 pub mod tt_sync;
 pub mod update;
 pub mod upstream_failure;
 pub mod user;
+pub mod user_customization;
 pub mod user_directory;
 pub mod world_info;
@@ -148,6 +148,41 @@ pub struct DefaultPreset {
     pub data: Value,
 }
 
+/// Format identifier written into every preset bundle
+pub const PRESET_BUNDLE_FORMAT: &str = "tauritavern-preset-bundle";
+
+/// Highest preset bundle version this build can read
+pub const PRESET_BUNDLE_VERSION: u32 = 1;
+
+/// A shareable package of one or more presets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresetBundle {
+    /// Always `PRESET_BUNDLE_FORMAT`
+    pub format: String,
+    /// Bundle schema version
+    pub version: u32,
+    /// Application that produced the bundle (e.g. "TauriTavern 1.2.0")
+    #[serde(default)]
+    pub generator: String,
+    /// RFC 3339 timestamp of the export
+    #[serde(default, rename = "exportedAt")]
+    pub exported_at: String,
+    /// Bundled presets
+    pub presets: Vec<PresetBundleEntry>,
+}
+
+/// A single preset inside a bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresetBundleEntry {
+    /// Name of the preset
+    pub name: String,
+    /// API ID (e.g., "openai", "instruct", "textgenerationwebui")
+    #[serde(rename = "apiId")]
+    pub api_id: String,
+    /// Preset data
+    pub data: Value,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+
+/// How many recently opened items are remembered, across all kinds.
+pub const MAX_RECENT_ITEMS: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecentItemKind {
+    Character,
+    Chat,
+    Group,
+}
+
+/// An item on the home screen. `id` is the key the frontend opens it by: the avatar
+/// filename for characters, the group id for groups and the chat file name for chats.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentItem {
+    pub kind: RecentItemKind,
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// When the item was last opened, or pinned for favorites.
+    pub timestamp_ms: i64,
+}
+
+impl RecentItem {
+    fn is_same(&self, kind: RecentItemKind, id: &str) -> bool {
+        self.kind == kind && self.id == id
+    }
+}
+
+/// Recently opened items, newest first, and the user's pinned favorites in pin order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecentItems {
+    pub recent: Vec<RecentItem>,
+    pub favorites: Vec<RecentItem>,
+}
+
+impl RecentItems {
+    /// Moves the item to the front of the recent list, dropping the oldest entries past
+    /// [`MAX_RECENT_ITEMS`].
+    pub fn record_opened(&mut self, item: RecentItem) {
+        self.recent
+            .retain(|existing| !existing.is_same(item.kind, &item.id));
+        self.recent.insert(0, item);
+        self.recent.truncate(MAX_RECENT_ITEMS);
+    }
+
+    /// Adds a favorite; returns `false` when it was already pinned. Pinning again keeps
+    /// the original position but refreshes the display name.
+    pub fn pin(&mut self, item: RecentItem) -> bool {
+        match self
+            .favorites
+            .iter_mut()
+            .find(|existing| existing.is_same(item.kind, &item.id))
+        {
+            Some(existing) => {
+                if item.name.is_some() {
+                    existing.name = item.name;
+                }
+                false
+            }
+            None => {
+                self.favorites.push(item);
+                true
+            }
+        }
+    }
+
+    /// Returns whether the item was pinned.
+    pub fn unpin(&mut self, kind: RecentItemKind, id: &str) -> bool {
+        let before = self.favorites.len();
+        self.favorites
+            .retain(|existing| !existing.is_same(kind, id));
+        self.favorites.len() != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_RECENT_ITEMS, RecentItem, RecentItemKind, RecentItems};
+
+    fn item(kind: RecentItemKind, id: &str, timestamp_ms: i64) -> RecentItem {
+        RecentItem {
+            kind,
+            id: id.to_string(),
+            name: None,
+            timestamp_ms,
+        }
+    }
+
+    #[test]
+    fn record_opened_moves_item_to_front_and_caps_list() {
+        let mut items = RecentItems::default();
+        items.record_opened(item(RecentItemKind::Character, "Alice.png", 1));
+        items.record_opened(item(RecentItemKind::Group, "Alice.png", 2));
+        items.record_opened(item(RecentItemKind::Character, "Alice.png", 3));
+
+        assert_eq!(
+            items.recent,
+            vec![
+                item(RecentItemKind::Character, "Alice.png", 3),
+                item(RecentItemKind::Group, "Alice.png", 2),
+            ]
+        );
+
+        for index in 0..MAX_RECENT_ITEMS + 5 {
+            items.record_opened(item(RecentItemKind::Chat, &format!("chat-{index}"), 10));
+        }
+        assert_eq!(items.recent.len(), MAX_RECENT_ITEMS);
+        assert_eq!(items.recent[0].id, format!("chat-{}", MAX_RECENT_ITEMS + 4));
+    }
+
+    #[test]
+    fn pin_is_idempotent_and_unpin_reports_removal() {
+        let mut items = RecentItems::default();
+        assert!(items.pin(item(RecentItemKind::Character, "Alice.png", 1)));
+        assert!(items.pin(item(RecentItemKind::Group, "1700000000000", 2)));
+
+        let renamed = RecentItem {
+            name: Some("Alice".to_string()),
+            ..item(RecentItemKind::Character, "Alice.png", 5)
+        };
+        assert!(!items.pin(renamed));
+        assert_eq!(items.favorites[0].name.as_deref(), Some("Alice"));
+        assert_eq!(items.favorites[0].timestamp_ms, 1);
+
+        assert!(items.unpin(RecentItemKind::Character, "Alice.png"));
+        assert!(!items.unpin(RecentItemKind::Character, "Alice.png"));
+        assert_eq!(items.favorites.len(), 1);
+    }
+}
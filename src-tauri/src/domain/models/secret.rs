@@ -109,6 +109,28 @@ impl Secrets {
         true
     }
 
+    /// Adds an imported entry unless the key already holds the same value. The entry is
+    /// only kept active when the key had no entries yet, so imports never switch the
+    /// key in use. Returns whether the entry was added.
+    pub fn import_entry(
+        &mut self,
+        key: String,
+        value: String,
+        label: String,
+        active: bool,
+    ) -> bool {
+        let entries = self.secrets.entry(key).or_default();
+        if entries.iter().any(|entry| entry.value == value) {
+            return false;
+        }
+
+        let mut entry = SecretEntry::new(value, label);
+        entry.active = active && entries.iter().all(|entry| !entry.active);
+        entries.push(entry);
+        Self::normalize_active(entries);
+        true
+    }
+
     pub fn active_secret_values(&self) -> HashMap<String, String> {
         let mut result = HashMap::new();
 
@@ -228,6 +250,7 @@ impl SecretKeys {
     pub const POLLINATIONS: &'static str = "api_key_pollinations";
     pub const VOLCENGINE_APP_ID: &'static str = "volcengine_app_id";
     pub const VOLCENGINE_ACCESS_KEY: &'static str = "volcengine_access_key";
+    pub const GITHUB_TOKEN: &'static str = "github_token";
 
     pub fn known_keys() -> &'static [&'static str] {
         &[
@@ -295,6 +318,7 @@ impl SecretKeys {
             Self::POLLINATIONS,
             Self::VOLCENGINE_APP_ID,
             Self::VOLCENGINE_ACCESS_KEY,
+            Self::GITHUB_TOKEN,
         ]
     }
 
@@ -390,4 +414,42 @@ mod tests {
         assert!(secrets.delete_secret("api_key_openai", Some(&second_id)));
         assert!(!secrets.secrets.contains_key("api_key_openai"));
     }
+
+    #[test]
+    fn import_entry_skips_duplicates_and_keeps_local_active() {
+        let mut secrets = Secrets::new();
+        secrets.write_secret(
+            "api_key_openai".to_string(),
+            "local".to_string(),
+            "local".to_string(),
+        );
+
+        assert!(!secrets.import_entry(
+            "api_key_openai".to_string(),
+            "local".to_string(),
+            "copy".to_string(),
+            true,
+        ));
+        assert!(secrets.import_entry(
+            "api_key_openai".to_string(),
+            "imported".to_string(),
+            "imported".to_string(),
+            true,
+        ));
+        assert_eq!(
+            secrets.read_secret("api_key_openai", None),
+            Some("local".to_string())
+        );
+
+        assert!(secrets.import_entry(
+            "api_key_claude".to_string(),
+            "claude".to_string(),
+            "imported".to_string(),
+            false,
+        ));
+        assert_eq!(
+            secrets.read_secret("api_key_claude", None),
+            Some("claude".to_string())
+        );
+    }
 }
@@ -1,6 +1,11 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value, json};
 
+use crate::domain::models::global_hotkeys::GlobalHotkeySettings;
+use crate::domain::models::memory_cache::MemoryCacheSettings;
+
 fn default_ios_policy_seed() -> Option<Value> {
     if !cfg!(target_os = "ios") {
         return None;
@@ -50,10 +55,83 @@ fn default_model_settings() -> ModelSettings {
 }
 
 pub const MIN_LLM_API_KEEP: u32 = 1;
+pub const DEFAULT_MODEL_LIST_CACHE_TTL_SECS: u64 = 60;
+pub const MAX_MODEL_LIST_CACHE_TTL_SECS: u64 = 3600;
+pub const DEFAULT_CHAT_COMPLETION_CONNECT_TIMEOUT_SECS: u64 = 3 * 60;
+pub const DEFAULT_CHAT_COMPLETION_REQUEST_TIMEOUT_SECS: u64 = 10 * 60;
+pub const MAX_CHAT_COMPLETION_TIMEOUT_SECS: u64 = 24 * 60 * 60;
+pub const MAX_CHAT_COMPLETION_QUEUE_CONCURRENCY: u32 = 64;
+pub const MAX_CHAT_COMPLETION_QUEUE_REQUESTS_PER_MINUTE: u32 = 10_000;
+pub const DEFAULT_CHAT_SUMMARY_INTERVAL_MESSAGES: u32 = 20;
+pub const DEFAULT_CHAT_SUMMARY_KEEP_RECENT_MESSAGES: u32 = 10;
+pub const DEFAULT_CHAT_SUMMARY_MAX_TOKENS: u32 = 400;
+pub const MAX_CHAT_SUMMARY_MESSAGES: u32 = 1000;
+pub const MAX_CHAT_SUMMARY_TOKENS: u32 = 8192;
+pub const DEFAULT_CHAT_SUMMARY_PROMPT: &str = "Summarize the roleplay so far in a few concise paragraphs. Keep names, relationships, unresolved plot threads and important facts. If a previous summary is given, merge the new messages into it.";
+pub const DEFAULT_CHAT_SUMMARY_INJECTION_TEMPLATE: &str =
+    "[Summary of earlier events: {{summary}}]";
+pub const DEFAULT_IDLE_GENERATION_MINUTES: u32 = 5;
+pub const MAX_IDLE_GENERATION_MINUTES: u32 = 24 * 60;
+pub const MAX_STOP_BIAS_STOP_STRINGS: usize = 64;
+pub const MAX_STOP_BIAS_LOGIT_BIAS_VALUE: f32 = 100.0;
+pub const MIN_CONNECTION_MONITOR_INTERVAL_SECS: u64 = 15;
+pub const MAX_CONNECTION_MONITOR_INTERVAL_SECS: u64 = 3600;
 pub const DEFAULT_AGENT_RETENTION_KEEP_RECENT_TERMINAL_RUNS: u32 = 100;
 pub const DEFAULT_AGENT_RETENTION_KEEP_FULL_RECENT_RUNS: u32 = 20;
 pub const MAX_AGENT_RETENTION_KEEP_RUNS: u32 = 10_000;
 
+fn default_model_list_cache_ttl_secs() -> u64 {
+    DEFAULT_MODEL_LIST_CACHE_TTL_SECS
+}
+
+fn default_chat_summary_interval_messages() -> u32 {
+    DEFAULT_CHAT_SUMMARY_INTERVAL_MESSAGES
+}
+
+fn default_chat_summary_keep_recent_messages() -> u32 {
+    DEFAULT_CHAT_SUMMARY_KEEP_RECENT_MESSAGES
+}
+
+fn default_chat_summary_prompt() -> String {
+    DEFAULT_CHAT_SUMMARY_PROMPT.to_string()
+}
+
+fn default_chat_summary_injection_template() -> String {
+    DEFAULT_CHAT_SUMMARY_INJECTION_TEMPLATE.to_string()
+}
+
+fn default_chat_summary_max_tokens() -> u32 {
+    DEFAULT_CHAT_SUMMARY_MAX_TOKENS
+}
+
+fn default_idle_generation_minutes() -> u32 {
+    DEFAULT_IDLE_GENERATION_MINUTES
+}
+
+fn default_generation_notifications_on_error() -> bool {
+    true
+}
+
+fn default_chat_completion_connect_timeout_secs() -> u64 {
+    DEFAULT_CHAT_COMPLETION_CONNECT_TIMEOUT_SECS
+}
+
+fn default_chat_completion_request_timeout_secs() -> u64 {
+    DEFAULT_CHAT_COMPLETION_REQUEST_TIMEOUT_SECS
+}
+
+fn default_connection_monitor_enabled() -> bool {
+    true
+}
+
+fn default_connection_monitor_interval_secs() -> u64 {
+    60
+}
+
+fn default_connection_monitor_slow_threshold_ms() -> u64 {
+    3000
+}
+
 fn default_agent_retention_keep_recent_terminal_runs() -> u32 {
     DEFAULT_AGENT_RETENTION_KEEP_RECENT_TERMINAL_RUNS
 }
@@ -96,12 +174,16 @@ impl Default for ClaudeModelSettings {
 pub struct ModelSettings {
     #[serde(default)]
     pub claude: ClaudeModelSettings,
+    /// How long a provider's model list is reused by status checks; 0 disables caching.
+    #[serde(default = "default_model_list_cache_ttl_secs")]
+    pub model_list_cache_ttl_secs: u64,
 }
 
 impl Default for ModelSettings {
     fn default() -> Self {
         Self {
             claude: ClaudeModelSettings::default(),
+            model_list_cache_ttl_secs: default_model_list_cache_ttl_secs(),
         }
     }
 }
@@ -179,12 +261,46 @@ impl Default for RequestProxySettings {
     }
 }
 
+/// Low-level tuning of the shared HTTP clients for networks where the defaults cause
+/// connection resets (corporate proxies, broken IPv6, filtered DNS).
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct HttpClientTuningSettings {
+    /// Never negotiate HTTP/2; some proxies mishandle it.
+    #[serde(default)]
+    pub http1_only: bool,
+    /// How long an idle pooled connection is kept; 0 keeps the client default.
+    #[serde(default)]
+    pub pool_idle_timeout_secs: u64,
+    /// Idle connections kept per host; `None` keeps the client default.
+    #[serde(default)]
+    pub pool_max_idle_per_host: Option<usize>,
+    /// Connect over IPv4 only, skipping AAAA records.
+    #[serde(default)]
+    pub ipv4_only: bool,
+    /// DNS-over-HTTPS endpoint answering `application/dns-json` queries
+    /// (e.g. `https://cloudflare-dns.com/dns-query`); empty uses the system resolver.
+    #[serde(default)]
+    pub dns_over_https_url: String,
+    /// Fixed host to IP address mappings that skip DNS entirely.
+    #[serde(default)]
+    pub dns_overrides: BTreeMap<String, String>,
+    /// PEM bundle of extra trusted CA certificates, e.g. a LAN backend's private CA.
+    #[serde(default)]
+    pub ca_bundle_path: String,
+    /// Chat completion source keys whose TLS certificates are not verified at all.
+    #[serde(default)]
+    pub insecure_tls_sources: BTreeSet<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DevLoggingSettings {
     #[serde(default)]
     pub frontend_console_capture: bool,
     #[serde(default = "default_llm_api_keep")]
     pub llm_api_keep: u32,
+    /// Records local command timings for `get_performance_trace`. Never sent anywhere.
+    #[serde(default)]
+    pub performance_tracing: bool,
 }
 
 impl Default for DevLoggingSettings {
@@ -192,6 +308,7 @@ impl Default for DevLoggingSettings {
         Self {
             frontend_console_capture: false,
             llm_api_keep: default_llm_api_keep(),
+            performance_tracing: false,
         }
     }
 }
@@ -220,6 +337,389 @@ impl Default for AgentSettings {
     }
 }
 
+/// Background health checks against the active connection profile's models endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionMonitorSettings {
+    #[serde(default = "default_connection_monitor_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_connection_monitor_interval_secs")]
+    pub interval_secs: u64,
+    /// Checks slower than this report the connection as slow rather than online.
+    #[serde(default = "default_connection_monitor_slow_threshold_ms")]
+    pub slow_threshold_ms: u64,
+}
+
+impl Default for ConnectionMonitorSettings {
+    fn default() -> Self {
+        Self {
+            enabled: default_connection_monitor_enabled(),
+            interval_secs: default_connection_monitor_interval_secs(),
+            slow_threshold_ms: default_connection_monitor_slow_threshold_ms(),
+        }
+    }
+}
+
+/// HTTP timeouts for chat completion requests. `sources` overrides individual values per
+/// chat completion source key (e.g. `custom`, `koboldcpp`) for slow local backends.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChatCompletionTimeoutSettings {
+    #[serde(default = "default_chat_completion_connect_timeout_secs")]
+    pub connect_timeout_secs: u64,
+    /// Upper bound for a whole non-streaming request.
+    #[serde(default = "default_chat_completion_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+    /// Longest silence tolerated between two chunks of a stream; 0 waits indefinitely.
+    #[serde(default)]
+    pub stream_idle_timeout_secs: u64,
+    /// TCP keepalive interval for upstream connections; 0 leaves it to the OS.
+    #[serde(default)]
+    pub keepalive_secs: u64,
+    #[serde(default)]
+    pub sources: BTreeMap<String, ChatCompletionTimeoutOverride>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct ChatCompletionTimeoutOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream_idle_timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keepalive_secs: Option<u64>,
+}
+
+impl Default for ChatCompletionTimeoutSettings {
+    fn default() -> Self {
+        Self {
+            connect_timeout_secs: default_chat_completion_connect_timeout_secs(),
+            request_timeout_secs: default_chat_completion_request_timeout_secs(),
+            stream_idle_timeout_secs: 0,
+            keepalive_secs: 0,
+            sources: BTreeMap::new(),
+        }
+    }
+}
+
+impl ChatCompletionTimeoutSettings {
+    /// Effective values for `source_key`: its override where set, the defaults otherwise.
+    pub fn for_source(&self, source_key: &str) -> ChatCompletionTimeoutSettings {
+        let source = self.sources.get(source_key).cloned().unwrap_or_default();
+        Self {
+            connect_timeout_secs: source
+                .connect_timeout_secs
+                .unwrap_or(self.connect_timeout_secs),
+            request_timeout_secs: source
+                .request_timeout_secs
+                .unwrap_or(self.request_timeout_secs),
+            stream_idle_timeout_secs: source
+                .stream_idle_timeout_secs
+                .unwrap_or(self.stream_idle_timeout_secs),
+            keepalive_secs: source.keepalive_secs.unwrap_or(self.keepalive_secs),
+            sources: BTreeMap::new(),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.values_are_valid()
+            && self
+                .sources
+                .keys()
+                .all(|source_key| self.for_source(source_key).values_are_valid())
+    }
+
+    fn values_are_valid(&self) -> bool {
+        self.connect_timeout_secs > 0
+            && self.request_timeout_secs > 0
+            && [
+                self.connect_timeout_secs,
+                self.request_timeout_secs,
+                self.stream_idle_timeout_secs,
+                self.keepalive_secs,
+            ]
+            .into_iter()
+            .all(|secs| secs <= MAX_CHAT_COMPLETION_TIMEOUT_SECS)
+    }
+}
+
+/// Per-source generation limits keyed by chat completion source key. Sources without an
+/// entry are not queued.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct ChatCompletionQueueSettings {
+    #[serde(default)]
+    pub sources: BTreeMap<String, ChatCompletionQueueLimits>,
+}
+
+/// A limit of 0 leaves that dimension unlimited.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct ChatCompletionQueueLimits {
+    #[serde(default)]
+    pub max_concurrent: u32,
+    #[serde(default)]
+    pub requests_per_minute: u32,
+}
+
+impl ChatCompletionQueueSettings {
+    /// Limits for `source_key`, or `None` when its requests bypass the queue.
+    pub fn for_source(&self, source_key: &str) -> Option<ChatCompletionQueueLimits> {
+        self.sources
+            .get(source_key)
+            .copied()
+            .filter(|limits| !limits.is_unlimited())
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.sources.values().all(|limits| {
+            limits.max_concurrent <= MAX_CHAT_COMPLETION_QUEUE_CONCURRENCY
+                && limits.requests_per_minute <= MAX_CHAT_COMPLETION_QUEUE_REQUESTS_PER_MINUTE
+        })
+    }
+}
+
+impl ChatCompletionQueueLimits {
+    pub fn is_unlimited(&self) -> bool {
+        self.max_concurrent == 0 && self.requests_per_minute == 0
+    }
+}
+
+/// Rolling summaries of older chat messages. Summaries live in `chat_metadata` and are
+/// injected into prompts that opt in with the chat they belong to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChatSummarySettings {
+    /// Summarize in the background once enough messages are past the kept window.
+    #[serde(default)]
+    pub auto_enabled: bool,
+    /// Unsummarized messages required before an automatic summary runs.
+    #[serde(default = "default_chat_summary_interval_messages")]
+    pub interval_messages: u32,
+    /// Most recent messages never folded into the summary.
+    #[serde(default = "default_chat_summary_keep_recent_messages")]
+    pub keep_recent_messages: u32,
+    /// Connection profile that writes summaries; `None` uses the active profile.
+    #[serde(default)]
+    pub connection_profile_id: Option<String>,
+    #[serde(default = "default_chat_summary_prompt")]
+    pub prompt: String,
+    /// Text injected into prompts; `{{summary}}` is replaced with the stored summary.
+    #[serde(default = "default_chat_summary_injection_template")]
+    pub injection_template: String,
+    #[serde(default = "default_chat_summary_max_tokens")]
+    pub max_tokens: u32,
+}
+
+impl Default for ChatSummarySettings {
+    fn default() -> Self {
+        Self {
+            auto_enabled: false,
+            interval_messages: default_chat_summary_interval_messages(),
+            keep_recent_messages: default_chat_summary_keep_recent_messages(),
+            connection_profile_id: None,
+            prompt: default_chat_summary_prompt(),
+            injection_template: default_chat_summary_injection_template(),
+            max_tokens: default_chat_summary_max_tokens(),
+        }
+    }
+}
+
+impl ChatSummarySettings {
+    pub fn is_valid(&self) -> bool {
+        (1..=MAX_CHAT_SUMMARY_MESSAGES).contains(&self.interval_messages)
+            && self.keep_recent_messages <= MAX_CHAT_SUMMARY_MESSAGES
+            && (1..=MAX_CHAT_SUMMARY_TOKENS).contains(&self.max_tokens)
+            && !self.prompt.trim().is_empty()
+            && self.injection_template.contains("{{summary}}")
+    }
+}
+
+/// Idle prompts keyed by character avatar. Characters without an enabled entry never
+/// get idle generations.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct IdleGenerationSettings {
+    #[serde(default)]
+    pub characters: BTreeMap<String, IdleCharacterSettings>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IdleCharacterSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Minutes without user activity before the idle prompt fires.
+    #[serde(default = "default_idle_generation_minutes")]
+    pub idle_minutes: u32,
+    /// Instruction appended to the armed request as a system message; empty sends the
+    /// request unchanged.
+    #[serde(default)]
+    pub prompt: String,
+}
+
+impl Default for IdleCharacterSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            idle_minutes: default_idle_generation_minutes(),
+            prompt: String::new(),
+        }
+    }
+}
+
+impl IdleGenerationSettings {
+    /// Settings for `avatar`, or `None` when idle generation is off for it.
+    pub fn for_character(&self, avatar: &str) -> Option<&IdleCharacterSettings> {
+        self.characters
+            .get(avatar)
+            .filter(|character| character.enabled)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.characters.iter().all(|(avatar, character)| {
+            !avatar.trim().is_empty()
+                && (1..=MAX_IDLE_GENERATION_MINUTES).contains(&character.idle_minutes)
+        })
+    }
+}
+
+/// Native notifications for chat completion streams that end while the window is in the
+/// background. Muted characters are keyed by avatar.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GenerationNotificationSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Also notify when a generation fails. Cancelled generations never notify.
+    #[serde(default = "default_generation_notifications_on_error")]
+    pub on_error: bool,
+    #[serde(default)]
+    pub muted_characters: BTreeSet<String>,
+}
+
+impl Default for GenerationNotificationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            on_error: default_generation_notifications_on_error(),
+            muted_characters: BTreeSet::new(),
+        }
+    }
+}
+
+impl GenerationNotificationSettings {
+    /// Whether a generation for `avatar` may notify; untagged requests are never muted.
+    pub fn allows(&self, avatar: Option<&str>) -> bool {
+        self.enabled && avatar.is_none_or(|avatar| !self.muted_characters.contains(avatar))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.muted_characters
+            .iter()
+            .all(|avatar| !avatar.trim().is_empty())
+    }
+}
+
+/// Stop strings and logit bias entries merged into every chat completion request, so
+/// presets no longer have to repeat them. Character rules are keyed by avatar, model
+/// rules by model id.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct StopBiasSettings {
+    #[serde(default)]
+    pub global: StopBiasRules,
+    #[serde(default)]
+    pub characters: BTreeMap<String, StopBiasRules>,
+    #[serde(default)]
+    pub models: BTreeMap<String, StopBiasRules>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct StopBiasRules {
+    #[serde(default)]
+    pub stop_strings: Vec<String>,
+    #[serde(default)]
+    pub logit_bias: Vec<LogitBiasRule>,
+}
+
+/// Bias applied to every token of `text`; inline `[id, ...]` lists name token ids
+/// directly.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LogitBiasRule {
+    pub text: String,
+    pub value: f32,
+}
+
+impl StopBiasRules {
+    pub fn is_empty(&self) -> bool {
+        self.stop_strings.is_empty() && self.logit_bias.is_empty()
+    }
+
+    fn is_valid(&self) -> bool {
+        self.stop_strings.len() <= MAX_STOP_BIAS_STOP_STRINGS
+            && self.stop_strings.iter().all(|stop| !stop.is_empty())
+            && self.logit_bias.iter().all(|rule| {
+                !rule.text.trim().is_empty() && rule.value.abs() <= MAX_STOP_BIAS_LOGIT_BIAS_VALUE
+            })
+    }
+}
+
+impl StopBiasSettings {
+    /// Rules that apply to a request, least specific first: global, model, character.
+    pub fn rules_for(&self, model: &str, avatar: Option<&str>) -> Vec<&StopBiasRules> {
+        let mut rules = vec![&self.global];
+        rules.extend(self.models.get(model));
+        rules.extend(avatar.and_then(|avatar| self.characters.get(avatar)));
+        rules
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.global.is_valid()
+            && self
+                .characters
+                .iter()
+                .chain(&self.models)
+                .all(|(key, rules)| !key.trim().is_empty() && rules.is_valid())
+    }
+}
+
+/// Named chat-completion setups: an LLM connection (source, URL, secret) plus the model
+/// to select when the profile is activated.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ConnectionProfileSettings {
+    #[serde(default)]
+    pub active_profile_id: Option<String>,
+    #[serde(default)]
+    pub profiles: Vec<ConnectionProfile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionProfile {
+    pub id: String,
+    pub display_name: String,
+    pub connection_ref: String,
+    pub default_model: String,
+}
+
+/// Named snapshots of the settings that change between setups, such as a work and a
+/// home network, switched as a whole.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SettingsProfileSettings {
+    #[serde(default)]
+    pub active_profile_id: Option<String>,
+    #[serde(default)]
+    pub profiles: Vec<SettingsProfile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsProfile {
+    pub id: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub request_proxy: RequestProxySettings,
+    /// JSON pointers into the frontend `settings.json` the profile owns.
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// The values at `paths` when the profile was saved; paths missing here are removed
+    /// on activation.
+    #[serde(default)]
+    pub user_settings: Value,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AgentRunRetentionSettings {
     #[serde(default)]
@@ -301,9 +801,14 @@ pub struct TauriTavernSettings {
     pub chat_history_mode: ChatHistoryMode,
     #[serde(default = "default_close_to_tray_on_close")]
     pub close_to_tray_on_close: bool,
+    /// Hides the main window to the desktop tray when it is minimized.
+    #[serde(default)]
+    pub minimize_to_tray: bool,
     #[serde(default)]
     pub request_proxy: RequestProxySettings,
     #[serde(default)]
+    pub http_client: HttpClientTuningSettings,
+    #[serde(default)]
     pub allow_keys_exposure: bool,
     /// When enabled, `/thumbnail?type=avatar|persona` serves original images instead of
     /// cached/generated thumbnails. Background thumbnails are intentionally unaffected.
@@ -319,6 +824,28 @@ pub struct TauriTavernSettings {
     pub models: ModelSettings,
     #[serde(default)]
     pub agent: AgentSettings,
+    #[serde(default)]
+    pub connection_profiles: ConnectionProfileSettings,
+    #[serde(default)]
+    pub settings_profiles: SettingsProfileSettings,
+    #[serde(default)]
+    pub connection_monitor: ConnectionMonitorSettings,
+    #[serde(default)]
+    pub chat_completion_timeouts: ChatCompletionTimeoutSettings,
+    #[serde(default)]
+    pub chat_completion_queue: ChatCompletionQueueSettings,
+    #[serde(default)]
+    pub chat_summary: ChatSummarySettings,
+    #[serde(default)]
+    pub idle_generation: IdleGenerationSettings,
+    #[serde(default)]
+    pub stop_bias: StopBiasSettings,
+    #[serde(default)]
+    pub memory_cache: MemoryCacheSettings,
+    #[serde(default)]
+    pub generation_notifications: GenerationNotificationSettings,
+    #[serde(default)]
+    pub global_hotkeys: GlobalHotkeySettings,
     /// iOS-only distribution policy (profile + capability overrides).
     ///
     /// NOTE: This field is intentionally stored as raw JSON to ensure:
@@ -338,7 +865,9 @@ impl Default for TauriTavernSettings {
             embedded_runtime_profile: default_embedded_runtime_profile(),
             chat_history_mode: default_chat_history_mode(),
             close_to_tray_on_close: default_close_to_tray_on_close(),
+            minimize_to_tray: false,
             request_proxy: RequestProxySettings::default(),
+            http_client: HttpClientTuningSettings::default(),
             allow_keys_exposure: false,
             avatar_persona_original_images_enabled: default_avatar_persona_original_images_enabled(
             ),
@@ -347,6 +876,17 @@ impl Default for TauriTavernSettings {
             dynamic_theme: DynamicThemeSettings::default(),
             models: default_model_settings(),
             agent: AgentSettings::default(),
+            connection_profiles: ConnectionProfileSettings::default(),
+            settings_profiles: SettingsProfileSettings::default(),
+            connection_monitor: ConnectionMonitorSettings::default(),
+            chat_completion_timeouts: ChatCompletionTimeoutSettings::default(),
+            chat_completion_queue: ChatCompletionQueueSettings::default(),
+            chat_summary: ChatSummarySettings::default(),
+            idle_generation: IdleGenerationSettings::default(),
+            stop_bias: StopBiasSettings::default(),
+            memory_cache: MemoryCacheSettings::default(),
+            generation_notifications: GenerationNotificationSettings::default(),
+            global_hotkeys: GlobalHotkeySettings::default(),
             ios_policy: default_ios_policy_seed(),
         }
     }
@@ -357,14 +897,20 @@ impl TauriTavernSettings {
     /// `tauritavern-settings.json` schemas.
     pub fn from_json_str_with_compat(raw: &str) -> Result<Self, serde_json::Error> {
         let mut value: Value = serde_json::from_str(raw)?;
+        Self::apply_compat_migrations(&mut value)?;
+        serde_json::from_value(value)
+    }
 
-        if let Value::Object(map) = &mut value {
+    /// Rewrites legacy keys in a raw settings document in place. Legacy keys are kept so
+    /// older builds reading the same file still see them.
+    pub(super) fn apply_compat_migrations(value: &mut Value) -> Result<(), serde_json::Error> {
+        if let Value::Object(map) = value {
             // Migration: `avatar_persona_thumbnails_enabled` (legacy, default true) ->
             // `avatar_persona_original_images_enabled` (current, default false).
             //
             // The meaning is inverted: originals_enabled = !thumbnails_enabled.
             if !map.contains_key("avatar_persona_original_images_enabled") {
-                let legacy_value = map.get("avatar_persona_thumbnails_enabled").cloned();
+                let legacy_value = map.get(LEGACY_AVATAR_PERSONA_THUMBNAILS_KEY).cloned();
                 if let Some(legacy_value) = legacy_value {
                     let thumbnails_enabled: bool = serde_json::from_value(legacy_value)?;
                     map.insert(
@@ -375,10 +921,12 @@ impl TauriTavernSettings {
             }
         }
 
-        serde_json::from_value(value)
+        Ok(())
     }
 }
 
+pub(super) const LEGACY_AVATAR_PERSONA_THUMBNAILS_KEY: &str = "avatar_persona_thumbnails_enabled";
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TauriTavernUpdateSettings {
     pub startup_popup: StartupUpdatePopupSettings,
@@ -413,8 +961,10 @@ impl Default for UserSettings {
 #[cfg(test)]
 mod tests {
     use super::{
-        AgentRunRetentionSettings, DEFAULT_AGENT_RETENTION_KEEP_FULL_RECENT_RUNS,
-        DEFAULT_AGENT_RETENTION_KEEP_RECENT_TERMINAL_RUNS, DevLoggingSettings,
+        AgentRunRetentionSettings, ChatCompletionTimeoutOverride, ChatCompletionTimeoutSettings,
+        DEFAULT_AGENT_RETENTION_KEEP_FULL_RECENT_RUNS,
+        DEFAULT_AGENT_RETENTION_KEEP_RECENT_TERMINAL_RUNS,
+        DEFAULT_CHAT_COMPLETION_CONNECT_TIMEOUT_SECS, DevLoggingSettings,
         MAX_AGENT_RETENTION_KEEP_RUNS, TauriTavernSettings,
     };
 
@@ -423,6 +973,7 @@ mod tests {
         let settings = DevLoggingSettings {
             frontend_console_capture: false,
             llm_api_keep: 0,
+            performance_tracing: false,
         };
 
         assert_eq!(settings.effective_llm_api_keep(), 1);
@@ -501,4 +1052,39 @@ mod tests {
         assert!(AgentRunRetentionSettings::is_valid_full_retention(0, 0));
         assert!(!AgentRunRetentionSettings::is_valid_full_retention(21, 20));
     }
+
+    #[test]
+    fn chat_completion_timeouts_apply_source_overrides() {
+        let mut settings = ChatCompletionTimeoutSettings::default();
+        settings.sources.insert(
+            "koboldcpp".to_string(),
+            ChatCompletionTimeoutOverride {
+                request_timeout_secs: Some(3600),
+                stream_idle_timeout_secs: Some(300),
+                ..Default::default()
+            },
+        );
+
+        let kobold = settings.for_source("koboldcpp");
+        assert_eq!(
+            kobold.connect_timeout_secs,
+            DEFAULT_CHAT_COMPLETION_CONNECT_TIMEOUT_SECS
+        );
+        assert_eq!(kobold.request_timeout_secs, 3600);
+        assert_eq!(kobold.stream_idle_timeout_secs, 300);
+        assert_eq!(
+            settings.for_source("openai").request_timeout_secs,
+            settings.request_timeout_secs
+        );
+        assert!(settings.is_valid());
+
+        settings.sources.insert(
+            "custom".to_string(),
+            ChatCompletionTimeoutOverride {
+                connect_timeout_secs: Some(0),
+                ..Default::default()
+            },
+        );
+        assert!(!settings.is_valid());
+    }
 }
@@ -0,0 +1,334 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::settings::{LEGACY_AVATAR_PERSONA_THUMBNAILS_KEY, TauriTavernSettings, UserSettings};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingsIssueKind {
+    /// The file is not a JSON object and had to be replaced entirely
+    Unreadable,
+    /// The key is not part of the schema; it is reported and kept as is
+    UnknownField,
+    /// The value does not match the schema; it is reset to its default on repair
+    InvalidValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SettingsIssue {
+    /// JSON Pointer (RFC 6901) to the offending key, empty for the whole document
+    pub path: String,
+    pub kind: SettingsIssueKind,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingsValidationReport {
+    pub file: String,
+    pub issues: Vec<SettingsIssue>,
+    pub repaired: bool,
+    pub backup_file: Option<String>,
+}
+
+/// Outcome of checking a raw settings document against its schema
+#[derive(Debug, Clone)]
+pub struct SettingsSchemaCheck {
+    /// The document with every issue fixed
+    pub repaired: Value,
+    pub issues: Vec<SettingsIssue>,
+}
+
+impl SettingsSchemaCheck {
+    /// Whether the document needs rewriting to load cleanly. Unknown fields alone are
+    /// ignored by serde, so they never force a repair.
+    pub fn needs_repair(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|issue| issue.kind != SettingsIssueKind::UnknownField)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum JsonKind {
+    Object,
+    Array,
+    String,
+    Bool,
+}
+
+impl JsonKind {
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::Object => value.is_object(),
+            Self::Array => value.is_array(),
+            Self::String => value.is_string(),
+            Self::Bool => value.is_boolean(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Object => "an object",
+            Self::Array => "an array",
+            Self::String => "a string",
+            Self::Bool => "a boolean",
+        }
+    }
+}
+
+/// Top-level keys of the SillyTavern `settings.json` the app depends on. The rest of the file
+/// belongs to the frontend and extensions, so unknown keys are never reported there.
+const USER_SETTINGS_SCHEMA: &[(&str, JsonKind)] = &[
+    ("firstRun", JsonKind::Bool),
+    ("main_api", JsonKind::String),
+    ("power_user", JsonKind::Object),
+    ("extension_settings", JsonKind::Object),
+    ("oai_settings", JsonKind::Object),
+    ("textgenerationwebui_settings", JsonKind::Object),
+    ("world_info_settings", JsonKind::Object),
+    ("tags", JsonKind::Array),
+    ("tag_map", JsonKind::Object),
+];
+
+/// Checks `tauritavern-settings.json` against [`TauriTavernSettings`], field by field.
+pub fn check_tauritavern_settings(raw: &str) -> SettingsSchemaCheck {
+    let defaults =
+        serde_json::to_value(TauriTavernSettings::default()).expect("default settings serialize");
+
+    let mut value = match serde_json::from_str::<Value>(raw) {
+        Ok(value @ Value::Object(_)) => value,
+        Ok(_) => return unreadable(defaults, "expected a JSON object".to_string()),
+        Err(error) => return unreadable(defaults, format!("invalid JSON: {}", error)),
+    };
+
+    let mut issues = Vec::new();
+    if TauriTavernSettings::apply_compat_migrations(&mut value).is_err() {
+        issues.push(SettingsIssue {
+            path: format!("/{}", LEGACY_AVATAR_PERSONA_THUMBNAILS_KEY),
+            kind: SettingsIssueKind::InvalidValue,
+            message: "expected a boolean".to_string(),
+        });
+    }
+
+    let mut raw = match value {
+        Value::Object(map) => map,
+        _ => unreachable!("checked above"),
+    };
+    let legacy_value = raw
+        .remove(LEGACY_AVATAR_PERSONA_THUMBNAILS_KEY)
+        .filter(Value::is_boolean);
+
+    let accepts = |path: &str, value: &Value| -> Result<(), String> {
+        let mut candidate = defaults.clone();
+        if let Some(slot) = candidate.pointer_mut(path) {
+            *slot = value.clone();
+        }
+        serde_json::from_value::<TauriTavernSettings>(candidate)
+            .map(|_| ())
+            .map_err(|error| error.to_string())
+    };
+
+    let defaults_map = defaults
+        .as_object()
+        .expect("settings serialize to an object");
+    let mut repaired = repair_object(&raw, defaults_map, "", &accepts, &mut issues);
+    if let Some(legacy_value) = legacy_value {
+        repaired.insert(
+            LEGACY_AVATAR_PERSONA_THUMBNAILS_KEY.to_string(),
+            legacy_value,
+        );
+    }
+
+    let repaired = Value::Object(repaired);
+    if let Err(error) = serde_json::from_value::<TauriTavernSettings>(repaired.clone()) {
+        return unreadable(defaults, error.to_string());
+    }
+
+    SettingsSchemaCheck { repaired, issues }
+}
+
+/// Checks the SillyTavern `settings.json` document.
+pub fn check_user_settings(raw: &str) -> SettingsSchemaCheck {
+    let defaults = UserSettings::default().data;
+
+    let mut map = match serde_json::from_str::<Value>(raw) {
+        Ok(Value::Object(map)) => map,
+        Ok(_) => return unreadable(defaults, "expected a JSON object".to_string()),
+        Err(error) => return unreadable(defaults, format!("invalid JSON: {}", error)),
+    };
+
+    let mut issues = Vec::new();
+    for (key, kind) in USER_SETTINGS_SCHEMA {
+        let is_valid = map.get(*key).is_none_or(|value| kind.matches(value));
+        if !is_valid {
+            // Dropping the key lets the frontend fall back to its own defaults.
+            map.remove(*key);
+            issues.push(SettingsIssue {
+                path: format!("/{}", escape_pointer_token(key)),
+                kind: SettingsIssueKind::InvalidValue,
+                message: format!("expected {}", kind.name()),
+            });
+        }
+    }
+
+    SettingsSchemaCheck {
+        repaired: Value::Object(map),
+        issues,
+    }
+}
+
+fn repair_object(
+    raw: &Map<String, Value>,
+    defaults: &Map<String, Value>,
+    path: &str,
+    accepts: &dyn Fn(&str, &Value) -> Result<(), String>,
+    issues: &mut Vec<SettingsIssue>,
+) -> Map<String, Value> {
+    let mut repaired = Map::new();
+
+    for (key, value) in raw {
+        let child_path = format!("{}/{}", path, escape_pointer_token(key));
+        let Some(default) = defaults.get(key) else {
+            issues.push(SettingsIssue {
+                path: child_path,
+                kind: SettingsIssueKind::UnknownField,
+                message: "not a known setting".to_string(),
+            });
+            repaired.insert(key.clone(), value.clone());
+            continue;
+        };
+
+        let repaired_value = match (value, default) {
+            (Value::Object(raw_child), Value::Object(default_child)) => Value::Object(
+                repair_object(raw_child, default_child, &child_path, accepts, issues),
+            ),
+            _ => match accepts(&child_path, value) {
+                Ok(()) => value.clone(),
+                Err(message) => {
+                    issues.push(SettingsIssue {
+                        path: child_path,
+                        kind: SettingsIssueKind::InvalidValue,
+                        message,
+                    });
+                    default.clone()
+                }
+            },
+        };
+        repaired.insert(key.clone(), repaired_value);
+    }
+
+    for (key, default) in defaults {
+        if !repaired.contains_key(key) {
+            repaired.insert(key.clone(), default.clone());
+        }
+    }
+
+    repaired
+}
+
+fn unreadable(defaults: Value, message: String) -> SettingsSchemaCheck {
+    SettingsSchemaCheck {
+        repaired: defaults,
+        issues: vec![SettingsIssue {
+            path: String::new(),
+            kind: SettingsIssueKind::Unreadable,
+            message,
+        }],
+    }
+}
+
+fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SettingsIssueKind, check_tauritavern_settings, check_user_settings};
+    use crate::domain::models::settings::{ChatHistoryMode, TauriTavernSettings};
+
+    #[test]
+    fn tauritavern_check_resets_invalid_fields_and_keeps_unknown_ones() {
+        let check = check_tauritavern_settings(
+            r#"{
+                "updates": {"startup_popup": {"dismissed_release_token": null}},
+                "chat_history_mode": "forever",
+                "dev": {"llm_api_keep": "ten", "frontend_console_capture": true},
+                "mystery": 1
+            }"#,
+        );
+
+        let issues: Vec<(&str, SettingsIssueKind)> = check
+            .issues
+            .iter()
+            .map(|issue| (issue.path.as_str(), issue.kind))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                ("/chat_history_mode", SettingsIssueKind::InvalidValue),
+                ("/dev/llm_api_keep", SettingsIssueKind::InvalidValue),
+                ("/mystery", SettingsIssueKind::UnknownField),
+            ]
+        );
+        assert!(check.needs_repair());
+        assert_eq!(check.repaired["mystery"], 1);
+
+        let settings: TauriTavernSettings =
+            serde_json::from_value(check.repaired).expect("repaired settings parse");
+        assert_eq!(settings.chat_history_mode, ChatHistoryMode::Windowed);
+        assert!(settings.dev.frontend_console_capture);
+        assert_eq!(
+            settings.dev.llm_api_keep,
+            TauriTavernSettings::default().dev.llm_api_keep
+        );
+    }
+
+    #[test]
+    fn tauritavern_check_keeps_legacy_keys_and_accepts_older_files() {
+        let check = check_tauritavern_settings(
+            r#"{"updates":{"startup_popup":{"dismissed_release_token":null}},"avatar_persona_thumbnails_enabled":false}"#,
+        );
+
+        assert!(check.issues.is_empty());
+        assert_eq!(check.repaired["avatar_persona_thumbnails_enabled"], false);
+        assert_eq!(
+            check.repaired["avatar_persona_original_images_enabled"],
+            true
+        );
+    }
+
+    #[test]
+    fn unreadable_documents_fall_back_to_defaults() {
+        let check = check_tauritavern_settings("{\"updates\": ");
+        assert_eq!(check.issues[0].kind, SettingsIssueKind::Unreadable);
+        assert!(serde_json::from_value::<TauriTavernSettings>(check.repaired).is_ok());
+
+        let check = check_user_settings("[]");
+        assert_eq!(check.issues[0].kind, SettingsIssueKind::Unreadable);
+        assert!(check.repaired.as_object().is_some_and(|map| map.is_empty()));
+    }
+
+    #[test]
+    fn user_settings_check_drops_wrongly_typed_sections_only() {
+        let check = check_user_settings(
+            r#"{"power_user": "oops", "main_api": "openai", "my_extension_key": 3}"#,
+        );
+
+        assert_eq!(check.issues.len(), 1);
+        assert_eq!(check.issues[0].path, "/power_user");
+        assert!(check.repaired.get("power_user").is_none());
+        assert_eq!(check.repaired["main_api"], "openai");
+        assert_eq!(check.repaired["my_extension_key"], 3);
+    }
+
+    #[test]
+    fn unknown_fields_alone_do_not_need_repair() {
+        let check = check_tauritavern_settings(
+            r#"{"updates":{"startup_popup":{"dismissed_release_token":null}},"mystery":1}"#,
+        );
+
+        assert_eq!(check.issues.len(), 1);
+        assert_eq!(check.issues[0].kind, SettingsIssueKind::UnknownField);
+        assert!(!check.needs_repair());
+    }
+}
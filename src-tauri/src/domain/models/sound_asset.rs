@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Largest sound file accepted, in bytes.
+pub const MAX_SOUND_BYTES: usize = 10 * 1024 * 1024;
+
+/// A notification sound (`user/sounds/`) or a per-character message sound
+/// (`user/sounds/characters/<avatar stem>/`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SoundAsset {
+    pub name: String,
+    /// Avatar file stem of the owning character, `None` for notification sounds
+    pub character: Option<String>,
+    /// Web path served by the user data asset route, e.g. `/user/sounds/ping.mp3`
+    pub url: String,
+    pub mime_type: String,
+    pub size: u64,
+    /// Last modification time in milliseconds since the Unix epoch
+    pub modified: i64,
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+/// Classification labels the expressions extension ships with.
+pub const DEFAULT_EXPRESSION_LABELS: &[&str] = &[
+    "admiration",
+    "amusement",
+    "anger",
+    "annoyance",
+    "approval",
+    "caring",
+    "confusion",
+    "curiosity",
+    "desire",
+    "disappointment",
+    "disapproval",
+    "disgust",
+    "embarrassment",
+    "excitement",
+    "fear",
+    "gratitude",
+    "grief",
+    "joy",
+    "love",
+    "nervousness",
+    "optimism",
+    "pride",
+    "realization",
+    "relief",
+    "remorse",
+    "sadness",
+    "surprise",
+    "neutral",
+];
+
+/// A character expression image. Matches the `{ label, path }` shape the
+/// expressions extension reads from `/api/sprites/get`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Sprite {
+    pub label: String,
+    pub name: String,
+    /// Web path served by the user data asset route, e.g. `/characters/Alice/sprites/joy.png`
+    pub path: String,
+}
+
+/// Outcome of importing a zipped expression pack
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpritePackImport {
+    pub imported: Vec<Sprite>,
+    /// Archive entries left out, with the reason
+    pub skipped: Vec<String>,
+}
+
+/// The expression label of a sprite file: the file stem up to the first `-` or `.`,
+/// so `joy.png`, `joy-2.png` and `joy.alt.png` all map to `joy`.
+pub fn sprite_label(file_name: &str) -> Option<String> {
+    let stem = file_name
+        .rsplit_once('.')
+        .map(|(stem, _)| stem)
+        .unwrap_or(file_name);
+    let label = stem.split(['-', '.']).next().unwrap_or_default().trim();
+    if label.is_empty() {
+        None
+    } else {
+        Some(label.to_lowercase())
+    }
+}
+
+/// Labels are lowercase ASCII letters, digits and underscores.
+pub fn is_valid_expression_label(label: &str) -> bool {
+    !label.is_empty()
+        && label
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sprite_label_uses_stem_prefix() {
+        assert_eq!(sprite_label("joy.png").as_deref(), Some("joy"));
+        assert_eq!(sprite_label("Joy-2.webp").as_deref(), Some("joy"));
+        assert_eq!(sprite_label("joy.alt.png").as_deref(), Some("joy"));
+        assert_eq!(sprite_label("-1.png"), None);
+        assert!(is_valid_expression_label("smug_grin"));
+        assert!(!is_valid_expression_label("smug grin"));
+    }
+}
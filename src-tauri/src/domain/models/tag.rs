@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A SillyTavern tag. Frontend-only keys are kept in `extra` so they survive round trips.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tag {
+    pub id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_date: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// All tags and their assignments, mirroring the `tags` / `tag_map` pair of the
+/// SillyTavern settings. `tag_map` is keyed by character avatar file name or group id.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TagLibrary {
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    #[serde(default)]
+    pub tag_map: BTreeMap<String, Vec<String>>,
+}
+
+/// Counts reported after merging tags from the settings file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TagImportSummary {
+    pub imported_tags: usize,
+    pub merged_tags: usize,
+    pub assignments: usize,
+}
+
+impl TagLibrary {
+    pub fn get(&self, id: &str) -> Option<&Tag> {
+        self.tags.iter().find(|tag| tag.id == id)
+    }
+
+    /// Tag names are unique ignoring case, as in the SillyTavern tag manager.
+    pub fn find_by_name(&self, name: &str) -> Option<&Tag> {
+        let name = name.trim();
+        self.tags
+            .iter()
+            .find(|tag| tag.name.trim().eq_ignore_ascii_case(name))
+    }
+
+    pub fn create(&mut self, tag: Tag) -> Result<&Tag, String> {
+        let name = tag.name.trim();
+        if name.is_empty() {
+            return Err("Tag name cannot be empty".to_string());
+        }
+        if self.find_by_name(name).is_some() {
+            return Err(format!("Tag already exists: {}", name));
+        }
+        if self.get(&tag.id).is_some() {
+            return Err(format!("Tag id already exists: {}", tag.id));
+        }
+
+        self.tags.push(Tag {
+            name: name.to_string(),
+            ..tag
+        });
+        Ok(self.tags.last().expect("tag was just pushed"))
+    }
+
+    pub fn rename(&mut self, id: &str, new_name: &str) -> Result<&Tag, String> {
+        let new_name = new_name.trim();
+        if new_name.is_empty() {
+            return Err("Tag name cannot be empty".to_string());
+        }
+        if self
+            .find_by_name(new_name)
+            .is_some_and(|existing| existing.id != id)
+        {
+            return Err(format!("Tag already exists: {}", new_name));
+        }
+
+        let tag = self
+            .tags
+            .iter_mut()
+            .find(|tag| tag.id == id)
+            .ok_or_else(|| format!("Tag not found: {}", id))?;
+        tag.name = new_name.to_string();
+        Ok(tag)
+    }
+
+    /// Removes the tag and every assignment of it. Returns `false` if it did not exist.
+    pub fn delete(&mut self, id: &str) -> bool {
+        let before = self.tags.len();
+        self.tags.retain(|tag| tag.id != id);
+        if self.tags.len() == before {
+            return false;
+        }
+
+        for tag_ids in self.tag_map.values_mut() {
+            tag_ids.retain(|tag_id| tag_id != id);
+        }
+        self.tag_map.retain(|_, tag_ids| !tag_ids.is_empty());
+        true
+    }
+
+    /// Returns `false` if the entity already carried the tag.
+    pub fn assign(&mut self, entity_id: &str, tag_id: &str) -> Result<bool, String> {
+        if self.get(tag_id).is_none() {
+            return Err(format!("Tag not found: {}", tag_id));
+        }
+
+        let tag_ids = self.tag_map.entry(entity_id.to_string()).or_default();
+        if tag_ids.iter().any(|existing| existing == tag_id) {
+            return Ok(false);
+        }
+        tag_ids.push(tag_id.to_string());
+        Ok(true)
+    }
+
+    /// Returns `false` if the entity did not carry the tag.
+    pub fn unassign(&mut self, entity_id: &str, tag_id: &str) -> bool {
+        let Some(tag_ids) = self.tag_map.get_mut(entity_id) else {
+            return false;
+        };
+
+        let before = tag_ids.len();
+        tag_ids.retain(|existing| existing != tag_id);
+        let removed = tag_ids.len() != before;
+        if tag_ids.is_empty() {
+            self.tag_map.remove(entity_id);
+        }
+        removed
+    }
+
+    pub fn tags_for(&self, entity_id: &str) -> Vec<&Tag> {
+        self.tag_map
+            .get(entity_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|tag_id| self.get(tag_id))
+            .collect()
+    }
+
+    /// Merges the `tags` and `tag_map` values of a SillyTavern settings file. Tags whose
+    /// name already exists are merged into the existing tag and their assignments remapped.
+    pub fn merge_from_settings(&mut self, tags: &Value, tag_map: &Value) -> TagImportSummary {
+        let mut summary = TagImportSummary::default();
+        let mut remapped_ids = BTreeMap::new();
+
+        for value in tags.as_array().into_iter().flatten() {
+            let Ok(tag) = serde_json::from_value::<Tag>(value.clone()) else {
+                continue;
+            };
+
+            let existing_id = self
+                .get(&tag.id)
+                .or_else(|| self.find_by_name(&tag.name))
+                .map(|existing| existing.id.clone());
+            match existing_id {
+                Some(existing_id) => {
+                    remapped_ids.insert(tag.id, existing_id);
+                    summary.merged_tags += 1;
+                }
+                None => {
+                    let id = tag.id.clone();
+                    if self.create(tag).is_ok() {
+                        remapped_ids.insert(id.clone(), id);
+                        summary.imported_tags += 1;
+                    }
+                }
+            }
+        }
+
+        for (entity_id, tag_ids) in tag_map.as_object().into_iter().flatten() {
+            for tag_id in tag_ids.as_array().into_iter().flatten() {
+                let Some(tag_id) = tag_id.as_str().and_then(|id| remapped_ids.get(id)) else {
+                    continue;
+                };
+                if self.assign(entity_id, tag_id) == Ok(true) {
+                    summary.assignments += 1;
+                }
+            }
+        }
+
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Map, json};
+
+    use super::{Tag, TagLibrary};
+
+    fn tag(id: &str, name: &str) -> Tag {
+        Tag {
+            id: id.to_string(),
+            name: name.to_string(),
+            color: None,
+            color2: None,
+            create_date: None,
+            extra: Map::new(),
+        }
+    }
+
+    #[test]
+    fn names_are_unique_ignoring_case_and_deletion_clears_assignments() {
+        let mut library = TagLibrary::default();
+        library.create(tag("1", "Fantasy")).expect("create tag");
+        assert!(library.create(tag("2", " fantasy ")).is_err());
+        library.create(tag("2", "Sci-Fi")).expect("create tag");
+        assert!(library.rename("2", "FANTASY").is_err());
+
+        assert_eq!(library.assign("Alice.png", "1"), Ok(true));
+        assert_eq!(library.assign("Alice.png", "1"), Ok(false));
+        assert!(library.assign("Alice.png", "missing").is_err());
+
+        assert!(library.delete("1"));
+        assert!(library.tag_map.is_empty());
+        assert!(!library.delete("1"));
+    }
+
+    #[test]
+    fn settings_import_merges_duplicate_names_and_remaps_assignments() {
+        let mut library = TagLibrary::default();
+        library.create(tag("local", "Fantasy")).expect("create tag");
+
+        let summary = library.merge_from_settings(
+            &json!([
+                {"id": "st-1", "name": "fantasy", "color": "#f00"},
+                {"id": "st-2", "name": "Favorite", "folder_type": "OPEN"},
+                {"name": "missing id"}
+            ]),
+            &json!({
+                "Alice.png": ["st-1", "st-2"],
+                "group-1": ["st-2", "unknown"]
+            }),
+        );
+
+        assert_eq!(summary.imported_tags, 1);
+        assert_eq!(summary.merged_tags, 1);
+        assert_eq!(summary.assignments, 3);
+        assert_eq!(library.tag_map["Alice.png"], vec!["local", "st-2"]);
+        assert_eq!(
+            library.get("st-2").expect("imported").extra["folder_type"],
+            "OPEN"
+        );
+    }
+}
@@ -17,3 +17,10 @@ impl Theme {
         Self { name, data }
     }
 }
+
+/// A theme packaged as a zip with the local assets its custom CSS refers to
+#[derive(Debug, Clone)]
+pub struct ThemeArchive {
+    pub file_name: String,
+    pub bytes: Vec<u8>,
+}
@@ -17,6 +17,20 @@ pub struct ReleaseInfo {
     pub prerelease: bool,
     /// 发布时间（ISO 8601）。
     pub published_at: String,
+    /// Release 附带的安装包。
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+/// Release 附带的单个下载文件。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseAsset {
+    /// 文件名，例如 "TauriTavern_1.4.0_x64-setup.exe"。
+    pub name: String,
+    /// 浏览器下载地址。
+    pub download_url: String,
+    /// 文件大小（字节）。
+    pub size: u64,
 }
 
 /// 更新检查结果。
@@ -28,4 +42,10 @@ pub struct UpdateCheckResult {
     pub current_version: String,
     /// 最新版本的 Release 信息，仅当 has_update 为 true 时有值。
     pub latest_release: Option<ReleaseInfo>,
+    /// 与当前平台匹配的安装包下载地址，找不到时为 None。
+    #[serde(default)]
+    pub download_url: Option<String>,
+    /// 本次检查访问 GitHub 的时间（RFC 3339），命中缓存时保持不变。
+    #[serde(default)]
+    pub checked_at: String,
 }
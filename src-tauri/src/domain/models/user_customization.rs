@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Largest custom stylesheet accepted, in bytes.
+pub const MAX_CUSTOM_CSS_BYTES: usize = 1024 * 1024;
+/// Largest single user script accepted, in bytes.
+pub const MAX_USER_SCRIPT_BYTES: usize = 512 * 1024;
+/// Number of user scripts that can be stored at once.
+pub const MAX_USER_SCRIPTS: usize = 100;
+
+/// A JavaScript snippet the frontend runs on load. `name` is the file stem under
+/// `user/scripts/`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserScript {
+    pub name: String,
+    pub content: String,
+}
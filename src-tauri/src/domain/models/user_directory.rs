@@ -32,6 +32,7 @@ pub struct UserDirectory {
     pub assets: PathBuf,
     pub comfy_workflows: PathBuf,
     pub files: PathBuf,
+    pub sounds: PathBuf,
     pub vectors: PathBuf,
     pub backups: PathBuf,
     pub sysprompt: PathBuf,
@@ -72,6 +73,7 @@ impl UserDirectory {
             assets: root.join("assets"),
             comfy_workflows: root.join("user/workflows"),
             files: root.join("user/files"),
+            sounds: root.join("user/sounds"),
             vectors: root.join("vectors"),
             backups: root.join("backups"),
             sysprompt: root.join("sysprompt"),
@@ -114,6 +116,7 @@ impl UserDirectory {
             &self.assets,
             &self.comfy_workflows,
             &self.files,
+            &self.sounds,
             &self.vectors,
             &self.backups,
             &self.sysprompt,
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::author_note::AuthorNote;
+
+/// Author's Notes stored outside of chats: the global default and one note per
+/// character, keyed by the character's avatar filename.
+#[async_trait]
+pub trait AuthorNoteRepository: Send + Sync {
+    async fn load_global_note(&self) -> Result<Option<AuthorNote>, DomainError>;
+
+    async fn save_global_note(&self, note: &AuthorNote) -> Result<(), DomainError>;
+
+    /// Returns whether a note was removed.
+    async fn delete_global_note(&self) -> Result<bool, DomainError>;
+
+    async fn load_character_note(&self, avatar: &str) -> Result<Option<AuthorNote>, DomainError>;
+
+    async fn save_character_note(&self, avatar: &str, note: &AuthorNote)
+    -> Result<(), DomainError>;
+
+    /// Returns whether a note was removed.
+    async fn delete_character_note(&self, avatar: &str) -> Result<bool, DomainError>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::changelog::{MigrationRecord, ReleaseNotes};
+
+#[async_trait]
+pub trait ChangelogRepository: Send + Sync {
+    /// Release notes bundled with this build.
+    async fn bundled_release_notes(&self) -> Result<Vec<ReleaseNotes>, DomainError>;
+
+    /// Entries of the data migrations ledger, oldest first.
+    async fn list_migrations(&self) -> Result<Vec<MigrationRecord>, DomainError>;
+
+    /// Appends a migration to the ledger.
+    async fn record_migration(&self, record: MigrationRecord) -> Result<(), DomainError>;
+}
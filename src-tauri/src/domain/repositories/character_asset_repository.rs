@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::character_asset::CharacterAsset;
+
+/// Repository interface for per-character gallery assets
+#[async_trait]
+pub trait CharacterAssetRepository: Send + Sync {
+    /// List the gallery of `character` (avatar file stem), newest first.
+    async fn list_assets(&self, character: &str) -> Result<Vec<CharacterAsset>, DomainError>;
+
+    /// Store an asset. The content type is sniffed from `data` and decides the extension;
+    /// name clashes get a numeric suffix.
+    async fn save_asset(
+        &self,
+        character: &str,
+        file_name: &str,
+        data: &[u8],
+    ) -> Result<CharacterAsset, DomainError>;
+
+    /// Delete an asset by file name.
+    async fn delete_asset(&self, character: &str, file_name: &str) -> Result<(), DomainError>;
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::character_generation_overrides::CharacterGenerationOverrides;
+
+/// Stores the temperature, top-p, max tokens and model a character generates with in
+/// place of the active preset's, one record per avatar filename. Loaded for every
+/// tagged chat completion request, so a missing record is `None` rather than an error.
+#[async_trait]
+pub trait CharacterGenerationOverridesRepository: Send + Sync {
+    async fn load_overrides(
+        &self,
+        avatar: &str,
+    ) -> Result<Option<CharacterGenerationOverrides>, DomainError>;
+
+    async fn save_overrides(
+        &self,
+        avatar: &str,
+        overrides: &CharacterGenerationOverrides,
+    ) -> Result<(), DomainError>;
+
+    /// Returns whether overrides were removed.
+    async fn delete_overrides(&self, avatar: &str) -> Result<bool, DomainError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::character_note::CharacterNote;
+
+/// Private per-character notes, keyed by the character's avatar filename and kept
+/// apart from the character card.
+#[async_trait]
+pub trait CharacterNoteRepository: Send + Sync {
+    async fn load_note(&self, avatar: &str) -> Result<Option<CharacterNote>, DomainError>;
+
+    async fn save_note(&self, avatar: &str, content: &str) -> Result<CharacterNote, DomainError>;
+
+    /// Returns whether a note was removed.
+    async fn delete_note(&self, avatar: &str) -> Result<bool, DomainError>;
+
+    async fn list_notes(&self) -> Result<Vec<CharacterNote>, DomainError>;
+}
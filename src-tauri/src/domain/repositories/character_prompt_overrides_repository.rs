@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::character_prompt_overrides::CharacterPromptOverrides;
+
+/// Per-character prompt overrides, keyed by the character's avatar filename and kept
+/// apart from the character card.
+#[async_trait]
+pub trait CharacterPromptOverridesRepository: Send + Sync {
+    async fn load_overrides(
+        &self,
+        avatar: &str,
+    ) -> Result<Option<CharacterPromptOverrides>, DomainError>;
+
+    async fn save_overrides(
+        &self,
+        avatar: &str,
+        overrides: &CharacterPromptOverrides,
+    ) -> Result<(), DomainError>;
+
+    /// Returns whether overrides were removed.
+    async fn delete_overrides(&self, avatar: &str) -> Result<bool, DomainError>;
+}
@@ -1,5 +1,6 @@
 use crate::domain::errors::DomainError;
 use crate::domain::models::character::Character;
+use crate::domain::models::memory_cache::{MemoryCacheConfig, MemoryCacheStats};
 use async_trait::async_trait;
 use std::path::Path;
 
@@ -101,8 +102,21 @@ pub trait CharacterRepository: Send + Sync {
         simple: bool,
     ) -> Result<Vec<CharacterChat>, DomainError>;
 
+    /// Size in bytes of the stored character card
+    async fn get_character_file_size(&self, name: &str) -> Result<u64, DomainError>;
+
+    /// Move every chat of `source` into the chat directory of `target`, suffixing names that
+    /// are already taken. Returns the moved chats' new file names.
+    async fn move_chats(&self, source: &str, target: &str) -> Result<Vec<String>, DomainError>;
+
     /// Clear the character cache
     async fn clear_cache(&self) -> Result<(), DomainError>;
+
+    /// Resize the in-memory character cache and change its eviction policy
+    async fn configure_memory_cache(&self, config: MemoryCacheConfig);
+
+    /// Usage counters of the in-memory character cache
+    async fn memory_cache_stats(&self) -> MemoryCacheStats;
 }
 
 /// Image crop parameters
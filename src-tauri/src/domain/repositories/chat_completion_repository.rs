@@ -1,9 +1,11 @@
 use async_trait::async_trait;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::time::Duration;
 use tokio::sync::{mpsc::UnboundedSender, watch};
 
 use crate::domain::errors::DomainError;
+use crate::domain::models::settings::ChatCompletionTimeoutSettings;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChatCompletionSource {
@@ -105,6 +107,42 @@ pub enum AnthropicBetaHeaderMode {
     ClaudeDefaults,
 }
 
+/// Transport timeouts for one chat completion request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChatCompletionTimeouts {
+    pub connect: Duration,
+    /// Whole-request bound for non-streaming calls.
+    pub request: Duration,
+    /// Longest gap between two stream reads; `None` waits indefinitely.
+    pub stream_idle: Option<Duration>,
+    pub keepalive: Option<Duration>,
+}
+
+impl ChatCompletionTimeouts {
+    pub fn for_source(
+        settings: &ChatCompletionTimeoutSettings,
+        source: ChatCompletionSource,
+    ) -> Self {
+        let settings = settings.for_source(source.key());
+        let optional = |secs: u64| (secs > 0).then(|| Duration::from_secs(secs));
+        Self {
+            connect: Duration::from_secs(settings.connect_timeout_secs),
+            request: Duration::from_secs(settings.request_timeout_secs),
+            stream_idle: optional(settings.stream_idle_timeout_secs),
+            keepalive: optional(settings.keepalive_secs),
+        }
+    }
+}
+
+impl Default for ChatCompletionTimeouts {
+    fn default() -> Self {
+        Self::for_source(
+            &ChatCompletionTimeoutSettings::default(),
+            ChatCompletionSource::OpenAi,
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ChatCompletionApiConfig {
     pub base_url: String,
@@ -123,6 +161,10 @@ pub struct ChatCompletionApiConfig {
     /// streaming chunk JSON. Empty / missing chunks are silently dropped so
     /// terminal sentinel events don't surface as blank deltas.
     pub aws_bedrock_custom_stream_path: Option<String>,
+    pub timeouts: ChatCompletionTimeouts,
+    /// Skip TLS certificate verification for this request; set only for sources the
+    /// user explicitly marked as trusted self-signed backends.
+    pub accept_invalid_certs: bool,
 }
 
 pub type ChatCompletionStreamSender = UnboundedSender<String>;
@@ -190,6 +232,15 @@ pub trait ChatCompletionRepository: Send + Sync {
     ) -> Result<(), DomainError>;
 
     async fn close_provider_session(&self, session_id: &str);
+
+    /// Fetches post-hoc generation metadata (cost, native token counts) by upstream
+    /// generation id. Only sources with a generation lookup endpoint support this.
+    async fn get_generation_metadata(
+        &self,
+        source: ChatCompletionSource,
+        config: &ChatCompletionApiConfig,
+        generation_id: &str,
+    ) -> Result<Value, DomainError>;
 }
 
 #[cfg(test)]
@@ -1,15 +1,19 @@
 use crate::domain::errors::DomainError;
 use crate::domain::models::chat::{Chat, ChatMessage};
+use crate::domain::models::memory_cache::{MemoryCacheConfig, MemoryCacheStats};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
 use std::path::{Path, PathBuf};
 
 pub use super::chat_types::{
-    ChatMessageReadItem, ChatMessageRole, ChatMessageSearchFilters, ChatMessageSearchHit,
-    ChatMessageSearchQuery, ChatMessagesReadResult, ChatPayloadChunk, ChatPayloadCursor,
-    ChatPayloadPatchOp, ChatPayloadTail, ChatSearchResult, FindLastMessageQuery,
-    LocatedChatMessage, PinnedCharacterChat, PinnedGroupChat,
+    CharacterChatStats, ChatArchiveReport, ChatCompressionOptions, ChatCompressionReport,
+    ChatIndexRebuildReport, ChatMessageCursor, ChatMessageLocateQuery, ChatMessageReadItem,
+    ChatMessageRole, ChatMessageSearchFilters, ChatMessageSearchHit, ChatMessageSearchQuery,
+    ChatMessagesReadResult, ChatPayloadChunk, ChatPayloadCursor, ChatPayloadForwardChunk,
+    ChatPayloadPatchOp, ChatPayloadTail, ChatRepairReport, ChatSearchHighlight,
+    ChatSearchMatchResult, ChatSearchMessageMatch, ChatSearchOptions, ChatSearchResult,
+    FindLastMessageQuery, LocatedChatMessage, PinnedCharacterChat, PinnedGroupChat,
 };
 
 /// Chat import format
@@ -21,6 +25,7 @@ pub enum ChatImportFormat {
     CAITools,
     KoboldLite,
     RisuAI,
+    TavernAI,
 }
 
 /// Chat export format
@@ -77,6 +82,20 @@ pub trait ChatRepository: Send + Sync {
         character_filter: Option<&str>,
     ) -> Result<Vec<ChatSearchResult>, DomainError>;
 
+    /// Search messages across character chats with structured filters, returning the
+    /// matching messages of each chat with highlight ranges.
+    async fn search_chats_with_options(
+        &self,
+        options: &ChatSearchOptions,
+        character_filter: Option<&str>,
+    ) -> Result<Vec<ChatSearchMatchResult>, DomainError>;
+
+    /// Aggregate message statistics over all chats of a character.
+    async fn get_character_chat_stats(
+        &self,
+        character_name: &str,
+    ) -> Result<CharacterChatStats, DomainError>;
+
     /// List character chat summaries without loading full payloads.
     async fn list_chat_summaries(
         &self,
@@ -160,6 +179,24 @@ pub trait ChatRepository: Send + Sync {
         max_lines: usize,
     ) -> Result<ChatPayloadChunk, DomainError>;
 
+    /// Get JSONL lines starting at a window cursor, paging toward the end of the payload.
+    async fn get_chat_payload_after_lines(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        cursor: ChatPayloadCursor,
+        max_lines: usize,
+    ) -> Result<ChatPayloadForwardChunk, DomainError>;
+
+    /// Locate a message by index or send date and return a cursor at its line, so
+    /// callers can page around it without loading the messages before it.
+    async fn locate_chat_message(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        query: &ChatMessageLocateQuery,
+    ) -> Result<Option<ChatMessageCursor>, DomainError>;
+
     /// Save a windowed character chat payload by preserving bytes before cursor.offset and
     /// overwriting the tail from cursor.offset using the provided JSONL lines.
     async fn save_chat_payload_windowed(
@@ -207,7 +244,14 @@ pub trait ChatRepository: Send + Sync {
         force: bool,
     ) -> Result<(), DomainError>;
 
-    /// Import character chat file(s) and return created JSONL file names.
+    /// Detect the format of a chat export file; `None` when it is not recognized.
+    async fn detect_chat_import_format(
+        &self,
+        file_path: &Path,
+    ) -> Result<Option<ChatImportFormat>, DomainError>;
+
+    /// Import character chat file(s) and return created JSONL file names. `format` is
+    /// `jsonl`, `json`, or `auto` to detect it from the content.
     async fn import_chat_payload(
         &self,
         character_name: &str,
@@ -241,6 +285,30 @@ pub trait ChatRepository: Send + Sync {
         value: Value,
     ) -> Result<(), DomainError>;
 
+    /// Merge top-level `chat_metadata` fields for a character chat (header-only rewrite).
+    /// `null` values remove the field.
+    async fn update_character_chat_metadata(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        fields: Map<String, Value>,
+    ) -> Result<(), DomainError>;
+
+    /// Read `chat_metadata.variables` for a character chat (header only).
+    async fn get_character_chat_variables(
+        &self,
+        character_name: &str,
+        file_name: &str,
+    ) -> Result<Map<String, Value>, DomainError>;
+
+    /// Replace `chat_metadata.variables` for a character chat (header-only rewrite).
+    async fn set_character_chat_variables(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        variables: Map<String, Value>,
+    ) -> Result<(), DomainError>;
+
     /// Read a JSON value from the character chat extension store.
     async fn get_character_chat_store_json(
         &self,
@@ -313,6 +381,25 @@ pub trait ChatRepository: Send + Sync {
         indices: &[usize],
     ) -> Result<ChatMessagesReadResult, DomainError>;
 
+    /// Read the raw JSON object of the message at a 0-based message index.
+    async fn get_character_chat_message(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        index: usize,
+    ) -> Result<Value, DomainError>;
+
+    /// Merge top-level fields into a message's `extra` (and into the `swipe_info` entry
+    /// of `swipe_id` when given); null values remove keys.
+    async fn update_character_chat_message_extra(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        index: usize,
+        swipe_id: Option<usize>,
+        fields: Map<String, Value>,
+    ) -> Result<(), DomainError>;
+
     /// Search messages inside a character chat payload.
     async fn search_character_chat_messages(
         &self,
@@ -321,8 +408,64 @@ pub trait ChatRepository: Send + Sync {
         query: ChatMessageSearchQuery,
     ) -> Result<Vec<ChatMessageSearchHit>, DomainError>;
 
+    /// Compress idle character and group chats to `.jsonl.zst`. Compressed chats are
+    /// inflated again transparently when they are next opened.
+    async fn compress_chat_payloads(
+        &self,
+        options: ChatCompressionOptions,
+    ) -> Result<ChatCompressionReport, DomainError>;
+
+    /// Move character chats not modified for `older_than_days` into their character's
+    /// `archive/` directory. Each character keeps its most recently modified chat.
+    async fn archive_old_chats(
+        &self,
+        character_filter: Option<&str>,
+        older_than_days: u32,
+    ) -> Result<ChatArchiveReport, DomainError>;
+
+    /// Move an archived chat back into its character's chat list.
+    async fn unarchive_chat(
+        &self,
+        character_name: &str,
+        file_name: &str,
+    ) -> Result<(), DomainError>;
+
+    /// List archived character chats, newest first.
+    async fn list_archived_chats(
+        &self,
+        character_filter: Option<&str>,
+    ) -> Result<Vec<ChatSearchResult>, DomainError>;
+
+    /// Drop the chat summary index and rebuild it from every character and group chat file.
+    /// `on_progress` receives `(scanned, total)`.
+    async fn rebuild_summary_index(
+        &self,
+        on_progress: &(dyn Fn(usize, usize) + Send + Sync),
+    ) -> Result<ChatIndexRebuildReport, DomainError>;
+
+    /// Repair a corrupted character chat in place: unparseable lines are moved to a
+    /// `.bad` file beside it, consecutive duplicate messages are dropped and missing
+    /// `send_date` fields are filled in. The payload is only rewritten when something
+    /// changed.
+    async fn repair_chat(
+        &self,
+        character_name: &str,
+        file_name: &str,
+    ) -> Result<ChatRepairReport, DomainError>;
+
+    /// Replay `add_message` journal entries that never reached their chat payload, e.g.
+    /// because the app was killed before the deferred rewrite. Returns how many chat
+    /// journals were found.
+    async fn replay_chat_journals(&self) -> Result<usize, DomainError>;
+
     /// Clear the chat cache
     async fn clear_cache(&self) -> Result<(), DomainError>;
+
+    /// Resize the in-memory chat cache and change its eviction policy
+    async fn configure_memory_cache(&self, config: MemoryCacheConfig);
+
+    /// Usage counters of the in-memory chat cache
+    async fn memory_cache_stats(&self) -> MemoryCacheStats;
 }
 
 #[cfg(test)]
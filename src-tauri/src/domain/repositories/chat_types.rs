@@ -13,6 +13,10 @@ pub struct ChatSearchResult {
     pub chat_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub chat_metadata: Option<Value>,
+    /// Payload size before compression, set only for chats stored as `.jsonl.zst`.
+    /// `file_size` is always the size on disk.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uncompressed_size: Option<u64>,
 }
 
 /// Pinned character chat reference used by recent-chat queries.
@@ -56,6 +60,36 @@ pub struct ChatPayloadChunk {
     pub has_more_before: bool,
 }
 
+/// Window chunk returned when paging forward. `cursor.offset` is the end of the last
+/// returned line, ready to be passed to the next forward request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatPayloadForwardChunk {
+    pub lines: Vec<String>,
+    pub cursor: ChatPayloadCursor,
+    pub has_more_after: bool,
+}
+
+/// Message to jump to in a chat payload. `message_id` is the 0-based message index;
+/// `send_date` (epoch millis) selects the first message sent at or after it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMessageLocateQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_date: Option<i64>,
+}
+
+/// Cursor positioned at the start of a located message line, usable with both
+/// backward and forward paging.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMessageCursor {
+    pub index: usize,
+    pub cursor: ChatPayloadCursor,
+}
+
 /// Operation-based patch for windowed JSONL payload writes.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "kind")]
@@ -158,3 +192,166 @@ pub struct ChatMessageSearchHit {
     pub role: ChatMessageRole,
     pub text: String,
 }
+
+/// Structured options for searching messages across character chats.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChatSearchOptions {
+    pub query: String,
+    /// Treat `query` as a case-insensitive JavaScript-style regular expression
+    /// instead of whitespace-separated fragments that must all appear.
+    pub regex: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<ChatMessageRole>,
+    /// Inclusive lower bound on message send dates, in epoch milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_from: Option<i64>,
+    /// Inclusive upper bound on message send dates, in epoch milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_to: Option<i64>,
+    /// Chats with fewer matching messages are left out. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_match_count: Option<usize>,
+    /// Matching messages returned per chat; `match_count` still counts all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_matches_per_chat: Option<usize>,
+    /// Also search chats moved to the archive by `archive_old_chats`.
+    pub include_archived: bool,
+}
+
+/// Highlight range in a snippet, as character offsets (start inclusive, end exclusive).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatSearchHighlight {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// One message matched by a cross-chat search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatSearchMessageMatch {
+    /// 0-based absolute message index.
+    pub index: usize,
+    pub role: ChatMessageRole,
+    /// Send date in epoch milliseconds, or 0 when it could not be parsed.
+    pub send_date: i64,
+    pub snippet: String,
+    pub highlights: Vec<ChatSearchHighlight>,
+}
+
+/// A chat matched by a cross-chat search, with its matching messages.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatSearchMatchResult {
+    pub chat: ChatSearchResult,
+    pub match_count: usize,
+    pub matches: Vec<ChatSearchMessageMatch>,
+    /// The chat is in the archive; `unarchive_chat` restores it.
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// Aggregate statistics over all chats of one character.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterChatStats {
+    pub character_name: String,
+    pub chat_count: usize,
+    pub total_messages: usize,
+    pub user_messages: usize,
+    pub assistant_messages: usize,
+    pub user_words: u64,
+    pub assistant_words: u64,
+    /// Distinct local calendar days with at least one dated message.
+    pub days_chatted: usize,
+    pub longest_streak_days: usize,
+    /// Consecutive days chatted ending today or yesterday; 0 otherwise.
+    pub current_streak_days: usize,
+    /// Earliest and latest message send dates, in epoch milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_message_date: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_message_date: Option<i64>,
+    /// Chat with the most messages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub longest_chat: Option<ChatSearchResult>,
+}
+
+/// Options for compressing idle chat payloads at rest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChatCompressionOptions {
+    /// Chats smaller than this stay plain JSONL.
+    pub min_size_bytes: u64,
+    /// Chats modified within this many hours stay plain JSONL.
+    pub min_idle_hours: u64,
+}
+
+impl Default for ChatCompressionOptions {
+    fn default() -> Self {
+        Self {
+            min_size_bytes: 64 * 1024,
+            min_idle_hours: 24,
+        }
+    }
+}
+
+/// Result of a chat compression run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatCompressionReport {
+    pub scanned: usize,
+    pub compressed: usize,
+    pub skipped: usize,
+    /// Plain size of the chats that were compressed
+    pub bytes_before: u64,
+    /// Compressed size of the same chats
+    pub bytes_after: u64,
+}
+
+/// Result of a chat archival run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatArchiveReport {
+    pub scanned: usize,
+    pub archived: usize,
+    /// Old chats left in place because an archived chat has the same name
+    pub skipped: usize,
+}
+
+/// What `repair_chat` changed in a chat payload.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatRepairReport {
+    /// Messages left in the repaired payload
+    pub messages: usize,
+    pub invalid_lines_removed: usize,
+    pub blank_lines_removed: usize,
+    /// Messages dropped for being identical to the message before them
+    pub duplicates_removed: usize,
+    pub send_dates_fixed: usize,
+    /// File name the removed invalid lines were appended to, next to the chat
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bad_lines_file: Option<String>,
+}
+
+impl ChatRepairReport {
+    pub fn changed(&self) -> bool {
+        self.invalid_lines_removed > 0
+            || self.blank_lines_removed > 0
+            || self.duplicates_removed > 0
+            || self.send_dates_fixed > 0
+    }
+}
+
+/// Result of rebuilding the chat summary index from the chat files.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatIndexRebuildReport {
+    pub character_chats: usize,
+    pub group_chats: usize,
+    /// Chat files that could not be scanned; they are left out of the index
+    pub failed: usize,
+    pub duration_ms: u64,
+}
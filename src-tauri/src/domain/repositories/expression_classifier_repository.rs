@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::DomainError;
+
+/// Emotion model SillyTavern's classify module uses; its labels are the default
+/// expression labels.
+pub const DEFAULT_EXPRESSION_CLASSIFIER_MODEL: &str = "SamLowe/roberta-base-go_emotions";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExpressionScore {
+    pub label: String,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExpressionClassifierRequest {
+    pub model: String,
+    pub api_key: String,
+    pub text: String,
+}
+
+/// Text emotion classification for the expressions extension.
+#[async_trait]
+pub trait ExpressionClassifierRepository: Send + Sync {
+    /// Scores for every label the model knows, highest first.
+    async fn classify(
+        &self,
+        request: ExpressionClassifierRequest,
+    ) -> Result<Vec<ExpressionScore>, DomainError>;
+}
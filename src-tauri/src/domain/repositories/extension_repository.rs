@@ -3,7 +3,8 @@ use std::path::Path;
 
 use crate::domain::errors::DomainError;
 use crate::domain::models::extension::{
-    Extension, ExtensionInstallResult, ExtensionUpdateResult, ExtensionVersion,
+    Extension, ExtensionBatchUpdateResult, ExtensionInstallResult, ExtensionUpdateResult,
+    ExtensionVersion,
 };
 
 #[async_trait]
@@ -25,6 +26,13 @@ pub trait ExtensionRepository: Send + Sync {
         branch: Option<String>,
     ) -> Result<ExtensionInstallResult, DomainError>;
 
+    /// Install an extension from a local .zip file or folder
+    async fn install_extension_from_path(
+        &self,
+        source_path: &Path,
+        global: bool,
+    ) -> Result<ExtensionInstallResult, DomainError>;
+
     /// Update an extension
     async fn update_extension(
         &self,
@@ -32,6 +40,13 @@ pub trait ExtensionRepository: Send + Sync {
         global: bool,
     ) -> Result<ExtensionUpdateResult, DomainError>;
 
+    /// Update every managed third-party extension that has a newer commit; global
+    /// extensions only when `include_global` is set
+    async fn update_all_extensions(
+        &self,
+        include_global: bool,
+    ) -> Result<Vec<ExtensionBatchUpdateResult>, DomainError>;
+
     /// Delete an extension
     async fn delete_extension(&self, extension_name: &str, global: bool)
     -> Result<(), DomainError>;
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use std::path::Path;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::file_attachment::{FileAttachment, FileAttachmentScope};
+
+/// Repository interface for Data Bank documents
+#[async_trait]
+pub trait FileAttachmentRepository: Send + Sync {
+    /// Store a document in `scope`. Name clashes get a numeric suffix.
+    async fn save_attachment(
+        &self,
+        scope: &FileAttachmentScope,
+        file_name: &str,
+        data: &[u8],
+    ) -> Result<FileAttachment, DomainError>;
+
+    /// Store a document in `scope` by copying a local file.
+    async fn save_attachment_from_path(
+        &self,
+        scope: &FileAttachmentScope,
+        file_name: &str,
+        source_path: &Path,
+    ) -> Result<FileAttachment, DomainError>;
+
+    /// List documents attached to `scope`, newest first.
+    async fn list_attachments(
+        &self,
+        scope: &FileAttachmentScope,
+    ) -> Result<Vec<FileAttachment>, DomainError>;
+
+    /// Delete a document by its `/user/files/` url.
+    async fn delete_attachment(&self, url: &str) -> Result<(), DomainError>;
+
+    /// Extract plain text from a txt, md or pdf document by its `/user/files/` url.
+    async fn extract_attachment_text(&self, url: &str) -> Result<String, DomainError>;
+}
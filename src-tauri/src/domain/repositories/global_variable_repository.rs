@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+
+use crate::domain::errors::DomainError;
+
+/// Global STscript variables shared by every chat.
+#[async_trait]
+pub trait GlobalVariableRepository: Send + Sync {
+    async fn load_global_variables(&self) -> Result<Map<String, Value>, DomainError>;
+
+    async fn save_global_variables(
+        &self,
+        variables: &Map<String, Value>,
+    ) -> Result<(), DomainError>;
+}
@@ -1,12 +1,13 @@
 use async_trait::async_trait;
-use serde_json::Value;
+use serde_json::{Map, Value};
 use std::path::{Path, PathBuf};
 
 use crate::domain::errors::DomainError;
 
 use super::chat_types::{
-    ChatMessageSearchHit, ChatMessageSearchQuery, ChatMessagesReadResult, ChatPayloadChunk,
-    ChatPayloadCursor, ChatPayloadPatchOp, ChatPayloadTail, ChatSearchResult, FindLastMessageQuery,
+    ChatMessageCursor, ChatMessageLocateQuery, ChatMessageSearchHit, ChatMessageSearchQuery,
+    ChatMessagesReadResult, ChatPayloadChunk, ChatPayloadCursor, ChatPayloadForwardChunk,
+    ChatPayloadPatchOp, ChatPayloadTail, ChatSearchResult, FindLastMessageQuery,
     LocatedChatMessage, PinnedGroupChat,
 };
 
@@ -54,6 +55,21 @@ pub trait GroupChatRepository: Send + Sync {
         max_lines: usize,
     ) -> Result<ChatPayloadChunk, DomainError>;
 
+    /// Get JSONL lines starting at a group chat window cursor, paging toward the end.
+    async fn get_group_chat_payload_after_lines(
+        &self,
+        chat_id: &str,
+        cursor: ChatPayloadCursor,
+        max_lines: usize,
+    ) -> Result<ChatPayloadForwardChunk, DomainError>;
+
+    /// Locate a group chat message by index or send date and return a cursor at its line.
+    async fn locate_group_chat_message(
+        &self,
+        chat_id: &str,
+        query: &ChatMessageLocateQuery,
+    ) -> Result<Option<ChatMessageCursor>, DomainError>;
+
     /// Save a windowed group chat payload by preserving bytes before cursor.offset and
     /// overwriting the tail from cursor.offset using the provided JSONL lines.
     async fn save_group_chat_payload_windowed(
@@ -128,6 +144,27 @@ pub trait GroupChatRepository: Send + Sync {
         value: Value,
     ) -> Result<(), DomainError>;
 
+    /// Merge top-level `chat_metadata` fields for a group chat (header-only rewrite).
+    /// `null` values remove the field.
+    async fn update_group_chat_metadata(
+        &self,
+        chat_id: &str,
+        fields: Map<String, Value>,
+    ) -> Result<(), DomainError>;
+
+    /// Read `chat_metadata.variables` for a group chat (header only).
+    async fn get_group_chat_variables(
+        &self,
+        chat_id: &str,
+    ) -> Result<Map<String, Value>, DomainError>;
+
+    /// Replace `chat_metadata.variables` for a group chat (header-only rewrite).
+    async fn set_group_chat_variables(
+        &self,
+        chat_id: &str,
+        variables: Map<String, Value>,
+    ) -> Result<(), DomainError>;
+
     /// Read a JSON value from the group chat extension store.
     async fn get_group_chat_store_json(
         &self,
@@ -192,6 +229,23 @@ pub trait GroupChatRepository: Send + Sync {
         indices: &[usize],
     ) -> Result<ChatMessagesReadResult, DomainError>;
 
+    /// Read the raw JSON object of the message at a 0-based message index.
+    async fn get_group_chat_message(
+        &self,
+        chat_id: &str,
+        index: usize,
+    ) -> Result<Value, DomainError>;
+
+    /// Merge top-level fields into a message's `extra` (and into the `swipe_info` entry
+    /// of `swipe_id` when given); null values remove keys.
+    async fn update_group_chat_message_extra(
+        &self,
+        chat_id: &str,
+        index: usize,
+        swipe_id: Option<usize>,
+        fields: Map<String, Value>,
+    ) -> Result<(), DomainError>;
+
     /// Search messages inside a group chat payload.
     async fn search_group_chat_messages(
         &self,
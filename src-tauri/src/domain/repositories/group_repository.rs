@@ -1,6 +1,8 @@
 use crate::domain::errors::DomainError;
+use crate::domain::models::avatar::CropInfo;
 use crate::domain::models::group::Group;
 use async_trait::async_trait;
+use std::path::Path;
 
 /// Repository interface for Group entities
 #[async_trait]
@@ -20,6 +22,17 @@ pub trait GroupRepository: Send + Sync {
     /// Delete a group by ID
     async fn delete_group(&self, id: &str) -> Result<(), DomainError>;
 
+    /// Replace the group avatar with the image at `file_path`
+    async fn update_group_avatar(
+        &self,
+        id: &str,
+        file_path: &Path,
+        crop: Option<CropInfo>,
+    ) -> Result<Group, DomainError>;
+
+    /// Remove the group avatar
+    async fn delete_group_avatar(&self, id: &str) -> Result<Group, DomainError>;
+
     /// Get group chat file paths
     async fn get_group_chat_paths(&self) -> Result<Vec<String>, DomainError>;
 
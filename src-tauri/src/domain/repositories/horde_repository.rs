@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::domain::errors::DomainError;
+
+/// Key the AI Horde accepts for anonymous (lowest priority) requests.
+pub const HORDE_ANONYMOUS_API_KEY: &str = "0000000000";
+
+/// Text generation endpoints of an AI Horde cluster. Payloads and responses use the
+/// Horde v2 JSON shapes, which the frontend already understands.
+#[async_trait]
+pub trait HordeRepository: Send + Sync {
+    /// Base URL of the cluster, used to tag models and workers.
+    fn cluster(&self) -> &str;
+
+    async fn heartbeat(&self) -> Result<bool, DomainError>;
+
+    async fn text_models(&self) -> Result<Vec<Value>, DomainError>;
+
+    async fn text_workers(&self) -> Result<Vec<Value>, DomainError>;
+
+    /// Queues an async text generation and returns the Horde response (`{ "id": ... }`).
+    async fn submit_text(&self, api_key: &str, payload: Value) -> Result<Value, DomainError>;
+
+    async fn text_status(&self, task_id: &str) -> Result<Value, DomainError>;
+
+    async fn cancel_text(&self, task_id: &str) -> Result<Value, DomainError>;
+
+    async fn find_user(&self, api_key: &str) -> Result<Value, DomainError>;
+}
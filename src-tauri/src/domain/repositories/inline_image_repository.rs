@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InlineImageLimits {
+    /// Longest edge in pixels; larger images are downscaled preserving aspect ratio.
+    pub max_dimension: u32,
+    /// Encoded size ceiling in bytes, before base64 expansion.
+    pub max_bytes: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct InlineImage {
+    pub mime_type: String,
+    pub bytes: Vec<u8>,
+}
+
+#[async_trait]
+pub trait InlineImageRepository: Send + Sync {
+    /// Loads a local image reference (absolute path or a path relative to the user
+    /// directory such as `user/images/...`) and fits it within `limits`.
+    async fn load_inline_image(
+        &self,
+        reference: &str,
+        limits: InlineImageLimits,
+    ) -> Result<InlineImage, DomainError>;
+}
@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::chat_completion_repository::{
+    ChatCompletionCancelReceiver, ChatCompletionStreamSender,
+};
+
+/// A KoboldCpp server; `base_url` is the server root without `/api`.
+#[derive(Debug, Clone)]
+pub struct KoboldCppEndpoint {
+    pub base_url: String,
+    pub api_key: Option<String>,
+}
+
+/// KoboldCpp native API (`/api/v1` and `/api/extra`). Generation payloads use the native
+/// field names (`max_length`, `rep_pen`, `genkey`, ...).
+#[async_trait]
+pub trait KoboldCppRepository: Send + Sync {
+    /// Name of the loaded model, e.g. `koboldcpp/Mistral-7B`.
+    async fn model(&self, endpoint: &KoboldCppEndpoint) -> Result<String, DomainError>;
+
+    /// Context size the server was launched with, which may differ from what the UI
+    /// requests.
+    async fn true_max_context_length(
+        &self,
+        endpoint: &KoboldCppEndpoint,
+    ) -> Result<u64, DomainError>;
+
+    async fn generate(
+        &self,
+        endpoint: &KoboldCppEndpoint,
+        payload: &Value,
+    ) -> Result<Value, DomainError>;
+
+    /// Streams the `data` payload of every server-sent event (`{ "token", "finish_reason" }`).
+    async fn generate_stream(
+        &self,
+        endpoint: &KoboldCppEndpoint,
+        payload: &Value,
+        sender: ChatCompletionStreamSender,
+        cancel: ChatCompletionCancelReceiver,
+    ) -> Result<(), DomainError>;
+
+    /// Stops the generation started with `genkey`.
+    async fn abort(&self, endpoint: &KoboldCppEndpoint, genkey: &str) -> Result<(), DomainError>;
+
+    /// Tokenizes `text` with the loaded model (`{ "value", "ids" }`).
+    async fn token_count(
+        &self,
+        endpoint: &KoboldCppEndpoint,
+        text: &str,
+    ) -> Result<Value, DomainError>;
+}
@@ -5,33 +5,51 @@ pub mod agent_profile_storage_health_repository;
 pub mod agent_run_repository;
 pub mod agent_workspace_lifecycle_repository;
 pub mod asset_repository;
+pub mod author_note_repository;
 pub mod avatar_repository;
 pub mod background_repository;
+pub mod changelog_repository;
+pub mod character_asset_repository;
+pub mod character_generation_overrides_repository;
+pub mod character_note_repository;
+pub mod character_prompt_overrides_repository;
 pub mod character_repository;
 pub mod chat_completion_repository;
 pub mod chat_repository;
 pub mod chat_types;
 pub mod checkpoint_repository;
 pub mod content_repository;
+pub mod expression_classifier_repository;
 pub mod extension_repository;
 pub mod extension_store_repository;
+pub mod file_attachment_repository;
+pub mod global_variable_repository;
 pub mod group_chat_repository;
 pub mod group_repository;
+pub mod horde_repository;
 pub mod image_metadata_repository;
+pub mod inline_image_repository;
+pub mod koboldcpp_repository;
 pub mod llm_connection_repository;
+pub mod novelai_repository;
 pub mod preset_repository;
 pub mod prompt_cache_repository;
 pub mod provider_metadata_repository;
 pub mod quick_reply_repository;
+pub mod recent_items_repository;
 pub mod secret_repository;
 pub mod settings_repository;
 pub mod skill_repository;
+pub mod sound_repository;
+pub mod sprite_repository;
 pub mod stable_diffusion_repository;
+pub mod tag_repository;
 pub mod theme_repository;
 pub mod tokenizer_repository;
 pub mod translate_repository;
 pub mod tts_repository;
 pub mod update_repository;
+pub mod user_customization_repository;
 pub mod user_directory_repository;
 pub mod user_repository;
 pub mod workspace_repository;
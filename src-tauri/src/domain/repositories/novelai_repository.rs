@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::chat_completion_repository::{
+    ChatCompletionCancelReceiver, ChatCompletionStreamSender,
+};
+
+/// NovelAI text and image endpoints. Payloads use the NovelAI JSON shapes
+/// (`{ "input", "model", "parameters" }`); building them is up to the caller.
+#[async_trait]
+pub trait NovelAiRepository: Send + Sync {
+    /// Subscription tier, perks and remaining Anlas of the account.
+    async fn get_subscription(&self, api_key: &str) -> Result<Value, DomainError>;
+
+    async fn generate_text(&self, api_key: &str, payload: &Value) -> Result<Value, DomainError>;
+
+    /// Streams the `data` payload of every server-sent event (`{ "token", "ptr", "final" }`).
+    async fn generate_text_stream(
+        &self,
+        api_key: &str,
+        payload: &Value,
+        sender: ChatCompletionStreamSender,
+        cancel: ChatCompletionCancelReceiver,
+    ) -> Result<(), DomainError>;
+
+    /// Returns the bytes of the first generated image.
+    async fn generate_image(&self, api_key: &str, payload: &Value) -> Result<Vec<u8>, DomainError>;
+}
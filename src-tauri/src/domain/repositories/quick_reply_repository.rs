@@ -5,6 +5,8 @@ use crate::domain::models::quick_reply::QuickReplySet;
 
 #[async_trait]
 pub trait QuickReplyRepository: Send + Sync {
+    async fn list_quick_reply_sets(&self) -> Result<Vec<QuickReplySet>, DomainError>;
+    async fn get_quick_reply_set(&self, name: &str) -> Result<Option<QuickReplySet>, DomainError>;
     async fn save_quick_reply_set(&self, set: &QuickReplySet) -> Result<(), DomainError>;
     async fn delete_quick_reply_set(&self, name: &str) -> Result<(), DomainError>;
 }
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::recent_items::RecentItems;
+
+/// Recently opened items and favorites shown on the home screen.
+#[async_trait]
+pub trait RecentItemsRepository: Send + Sync {
+    async fn load_recent_items(&self) -> Result<RecentItems, DomainError>;
+
+    async fn save_recent_items(&self, items: &RecentItems) -> Result<(), DomainError>;
+}
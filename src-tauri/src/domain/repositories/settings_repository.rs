@@ -1,5 +1,6 @@
 use crate::domain::errors::DomainError;
 use crate::domain::models::settings::{SettingsSnapshot, TauriTavernSettings, UserSettings};
+use crate::domain::models::settings_schema::SettingsValidationReport;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -13,6 +14,12 @@ pub trait SettingsRepository: Send + Sync {
     async fn save_user_settings(&self, settings: &UserSettings) -> Result<(), DomainError>;
     async fn load_user_settings(&self) -> Result<UserSettings, DomainError>;
 
+    /// Check both settings files against their schema, rewriting them when `repair` is set.
+    async fn validate_settings(
+        &self,
+        repair: bool,
+    ) -> Result<Vec<SettingsValidationReport>, DomainError>;
+
     async fn create_snapshot(&self) -> Result<(), DomainError>;
     async fn get_snapshots(&self) -> Result<Vec<SettingsSnapshot>, DomainError>;
     async fn load_snapshot(&self, name: &str) -> Result<UserSettings, DomainError>;
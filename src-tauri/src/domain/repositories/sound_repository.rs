@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::sound_asset::SoundAsset;
+
+/// Repository interface for notification and per-character message sounds.
+/// `character` is an avatar file name or stem; `None` addresses notification sounds.
+#[async_trait]
+pub trait SoundRepository: Send + Sync {
+    /// List sounds sorted by name.
+    async fn list_sounds(&self, character: Option<&str>) -> Result<Vec<SoundAsset>, DomainError>;
+
+    /// Store a sound. The audio format is sniffed from `data` and decides the extension;
+    /// name clashes get a numeric suffix.
+    async fn save_sound(
+        &self,
+        character: Option<&str>,
+        file_name: &str,
+        data: &[u8],
+    ) -> Result<SoundAsset, DomainError>;
+
+    /// Delete a sound by file name.
+    async fn delete_sound(
+        &self,
+        character: Option<&str>,
+        file_name: &str,
+    ) -> Result<(), DomainError>;
+}
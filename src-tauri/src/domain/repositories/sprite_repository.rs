@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use std::path::Path;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::sprite::{Sprite, SpritePackImport};
+
+/// Repository interface for character expression sprites.
+/// `character` is an avatar file name or stem.
+#[async_trait]
+pub trait SpriteRepository: Send + Sync {
+    /// List sprites sorted by label, then file name.
+    async fn list_sprites(&self, character: &str) -> Result<Vec<Sprite>, DomainError>;
+
+    /// Import the images of a zip archive whose labels are in `allowed_labels`.
+    /// Existing sprites with the same file name are replaced.
+    async fn import_sprite_pack(
+        &self,
+        character: &str,
+        archive_path: &Path,
+        allowed_labels: &[String],
+    ) -> Result<SpritePackImport, DomainError>;
+
+    /// Delete a sprite by file name.
+    async fn delete_sprite(&self, character: &str, file_name: &str) -> Result<(), DomainError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::tag::TagLibrary;
+
+/// Repository interface for tags and their character/group assignments
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    /// Load all tags; empty when none were saved yet
+    async fn load_tags(&self) -> Result<TagLibrary, DomainError>;
+
+    /// Replace all tags and assignments
+    async fn save_tags(&self, library: &TagLibrary) -> Result<(), DomainError>;
+}
@@ -1,6 +1,7 @@
 use crate::domain::errors::DomainError;
-use crate::domain::models::theme::Theme;
+use crate::domain::models::theme::{Theme, ThemeArchive};
 use async_trait::async_trait;
+use std::path::Path;
 
 /// Repository interface for managing themes
 #[async_trait]
@@ -10,4 +11,13 @@ pub trait ThemeRepository: Send + Sync {
 
     /// Delete a theme
     async fn delete_theme(&self, name: &str) -> Result<(), DomainError>;
+
+    /// List all themes, sorted by name
+    async fn list_themes(&self) -> Result<Vec<Theme>, DomainError>;
+
+    /// Package a theme with the assets referenced by its custom CSS
+    async fn export_theme(&self, name: &str) -> Result<ThemeArchive, DomainError>;
+
+    /// Import a theme `.json` file or a zip created by `export_theme`
+    async fn import_theme(&self, file_path: &Path, overwrite: bool) -> Result<Theme, DomainError>;
 }
@@ -7,4 +7,7 @@ use crate::domain::models::update::ReleaseInfo;
 pub trait UpdateRepository: Send + Sync {
     /// 获取指定 GitHub 仓库的最新 Release。
     async fn get_latest_release(&self) -> Result<ReleaseInfo, DomainError>;
+
+    /// 获取最近的 Release（包含预发布，不包含草稿），按发布时间倒序。
+    async fn list_recent_releases(&self) -> Result<Vec<ReleaseInfo>, DomainError>;
 }
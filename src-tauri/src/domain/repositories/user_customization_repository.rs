@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::user_customization::UserScript;
+
+/// The user's custom CSS and JS snippets. Overwriting either keeps a backup of the
+/// previous content.
+#[async_trait]
+pub trait UserCustomizationRepository: Send + Sync {
+    /// Returns an empty string when no custom CSS has been saved.
+    async fn load_custom_css(&self) -> Result<String, DomainError>;
+
+    async fn save_custom_css(&self, css: &str) -> Result<(), DomainError>;
+
+    /// Scripts sorted by name.
+    async fn list_user_scripts(&self) -> Result<Vec<UserScript>, DomainError>;
+
+    async fn save_user_script(&self, script: &UserScript) -> Result<(), DomainError>;
+}
@@ -1,15 +1,18 @@
 use async_trait::async_trait;
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use std::sync::Arc;
 
 use crate::domain::errors::DomainError;
-use crate::domain::models::update::ReleaseInfo;
+use crate::domain::models::update::{ReleaseAsset, ReleaseInfo};
 use crate::domain::repositories::update_repository::UpdateRepository;
 use crate::infrastructure::github::classify_github_rate_limit;
 use crate::infrastructure::http_client_pool::{HttpClientPool, HttpClientProfile};
 
 const GITHUB_API_LATEST_RELEASE: &str =
     "https://api.github.com/repos/Darkatse/TauriTavern/releases/latest";
+const GITHUB_API_RECENT_RELEASES: &str =
+    "https://api.github.com/repos/Darkatse/TauriTavern/releases?per_page=20";
 
 #[derive(Debug, Deserialize)]
 struct GitHubRelease {
@@ -18,7 +21,44 @@ struct GitHubRelease {
     body: Option<String>,
     html_url: String,
     prerelease: bool,
+    #[serde(default)]
+    draft: bool,
     published_at: Option<String>,
+    #[serde(default)]
+    assets: Vec<GitHubReleaseAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubReleaseAsset {
+    name: String,
+    browser_download_url: String,
+    #[serde(default)]
+    size: u64,
+}
+
+impl From<GitHubRelease> for ReleaseInfo {
+    fn from(release: GitHubRelease) -> Self {
+        let version = parse_version_from_tag(&release.tag_name);
+
+        Self {
+            tag_name: release.tag_name,
+            version,
+            name: release.name.unwrap_or_default(),
+            body: release.body.unwrap_or_default(),
+            html_url: release.html_url,
+            prerelease: release.prerelease,
+            published_at: release.published_at.unwrap_or_default(),
+            assets: release
+                .assets
+                .into_iter()
+                .map(|asset| ReleaseAsset {
+                    name: asset.name,
+                    download_url: asset.browser_download_url,
+                    size: asset.size,
+                })
+                .collect(),
+        }
+    }
 }
 
 pub struct GitHubUpdateRepository {
@@ -29,14 +69,11 @@ impl GitHubUpdateRepository {
     pub fn new(http_clients: Arc<HttpClientPool>) -> Self {
         Self { http_clients }
     }
-}
 
-#[async_trait]
-impl UpdateRepository for GitHubUpdateRepository {
-    async fn get_latest_release(&self) -> Result<ReleaseInfo, DomainError> {
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, DomainError> {
         let client = self.http_clients.client(HttpClientProfile::Default)?;
         let response = client
-            .get(GITHUB_API_LATEST_RELEASE)
+            .get(url)
             .header("Accept", "application/vnd.github+json")
             .send()
             .await
@@ -64,21 +101,26 @@ impl UpdateRepository for GitHubUpdateRepository {
             )));
         }
 
-        let response: GitHubRelease = response.json().await.map_err(|error| {
+        response.json().await.map_err(|error| {
             DomainError::InternalError(format!("Failed to parse GitHub response: {error}"))
-        })?;
+        })
+    }
+}
 
-        let version = parse_version_from_tag(&response.tag_name);
+#[async_trait]
+impl UpdateRepository for GitHubUpdateRepository {
+    async fn get_latest_release(&self) -> Result<ReleaseInfo, DomainError> {
+        let release: GitHubRelease = self.get_json(GITHUB_API_LATEST_RELEASE).await?;
+        Ok(release.into())
+    }
 
-        Ok(ReleaseInfo {
-            tag_name: response.tag_name,
-            version,
-            name: response.name.unwrap_or_default(),
-            body: response.body.unwrap_or_default(),
-            html_url: response.html_url,
-            prerelease: response.prerelease,
-            published_at: response.published_at.unwrap_or_default(),
-        })
+    async fn list_recent_releases(&self) -> Result<Vec<ReleaseInfo>, DomainError> {
+        let releases: Vec<GitHubRelease> = self.get_json(GITHUB_API_RECENT_RELEASES).await?;
+        Ok(releases
+            .into_iter()
+            .filter(|release| !release.draft)
+            .map(ReleaseInfo::from)
+            .collect())
     }
 }
 
@@ -26,7 +26,7 @@ pub(super) async fn list_models(
 ) -> Result<Value, DomainError> {
     let url = HttpChatCompletionRepository::build_url(&config.base_url, "/models");
 
-    let client = repository.client()?;
+    let client = repository.client(config)?;
     let request = client
         .get(url)
         .header(ACCEPT, "application/json")
@@ -67,7 +67,7 @@ pub(super) async fn generate(
 
     let url = HttpChatCompletionRepository::build_url(&config.base_url, endpoint_path);
 
-    let client = repository.client()?;
+    let client = repository.client(config)?;
     let request = client
         .post(url)
         .header(CONTENT_TYPE, "application/json")
@@ -119,7 +119,7 @@ pub(super) async fn generate_stream(
 
     let url = HttpChatCompletionRepository::build_url(&config.base_url, endpoint_path);
 
-    let client = repository.stream_client()?;
+    let client = repository.stream_client(config)?;
     let request = client
         .post(url)
         .header(CONTENT_TYPE, "application/json")
@@ -15,7 +15,7 @@ pub(super) async fn list_models(
 ) -> Result<Value, DomainError> {
     let url = HttpChatCompletionRepository::build_url(&config.base_url, "/models");
 
-    let client = repository.client()?;
+    let client = repository.client(config)?;
     let request = client.get(url).header(ACCEPT, "application/json");
     let request = HttpChatCompletionRepository::apply_bearer_auth(request, &config.api_key);
     let request = HttpChatCompletionRepository::apply_extra_headers(request, &config.extra_headers);
@@ -48,7 +48,7 @@ pub(super) async fn generate(
     let endpoint_path = normalize_endpoint_path(endpoint_path);
     let url = HttpChatCompletionRepository::build_url(&config.base_url, endpoint_path);
 
-    let client = repository.client()?;
+    let client = repository.client(config)?;
     let request = client
         .post(url)
         .header(CONTENT_TYPE, "application/json")
@@ -86,7 +86,7 @@ pub(super) async fn generate_stream(
     let endpoint_path = normalize_endpoint_path(endpoint_path);
     let url = HttpChatCompletionRepository::build_url(&config.base_url, endpoint_path);
 
-    let client = repository.stream_client()?;
+    let client = repository.stream_client(config)?;
     let request = client
         .post(url)
         .header(CONTENT_TYPE, "application/json")
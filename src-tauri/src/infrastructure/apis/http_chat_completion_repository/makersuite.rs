@@ -19,7 +19,7 @@ pub(super) async fn list_models(
 ) -> Result<Value, DomainError> {
     let url = build_gemini_url(&config.base_url, "models");
 
-    let client = repository.client()?;
+    let client = repository.client(config)?;
     let request = client.get(url).header(ACCEPT, "application/json");
     let request = apply_gemini_auth(request, config);
     let request = HttpChatCompletionRepository::apply_extra_headers(request, &config.extra_headers);
@@ -97,7 +97,7 @@ pub(super) async fn generate(
     let model_path = format!("{}:{method}", normalize_gemini_model(model));
     let url = build_gemini_url(&config.base_url, &model_path);
 
-    let client = repository.client()?;
+    let client = repository.client(config)?;
     let request = client
         .post(url)
         .header(CONTENT_TYPE, "application/json")
@@ -152,7 +152,7 @@ pub(super) async fn generate_stream(
     let model_path = format!("{}:{method}", normalize_gemini_model(model));
     let url = build_gemini_url(&config.base_url, &model_path);
 
-    let client = repository.stream_client()?;
+    let client = repository.stream_client(config)?;
     let request = client
         .post(url)
         .header(CONTENT_TYPE, "application/json")
@@ -279,7 +279,7 @@ mod tests {
 
     use super::apply_gemini_auth;
     use crate::domain::repositories::chat_completion_repository::{
-        AnthropicBetaHeaderMode, ChatCompletionApiConfig,
+        AnthropicBetaHeaderMode, ChatCompletionApiConfig, ChatCompletionTimeouts,
     };
 
     #[test]
@@ -293,6 +293,8 @@ mod tests {
             anthropic_beta_header_mode: AnthropicBetaHeaderMode::None,
             aws_bedrock_custom_response_path: None,
             aws_bedrock_custom_stream_path: None,
+            timeouts: ChatCompletionTimeouts::default(),
+            accept_invalid_certs: false,
         };
 
         let request = Client::new().get("https://example.com");
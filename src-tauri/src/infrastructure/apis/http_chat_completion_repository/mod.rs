@@ -11,7 +11,9 @@ use crate::domain::repositories::chat_completion_repository::{
     ChatCompletionApiConfig, ChatCompletionCancelReceiver, ChatCompletionRepository,
     ChatCompletionRepositoryGenerateResponse, ChatCompletionSource, ChatCompletionStreamSender,
 };
-use crate::infrastructure::http_client_pool::{HttpClientPool, HttpClientProfile};
+use crate::infrastructure::http_client_pool::{
+    HttpClientOptions, HttpClientPool, HttpClientProfile, HttpClientTimeouts,
+};
 
 mod aws_bedrock;
 mod claude;
@@ -37,9 +39,20 @@ pub struct HttpChatCompletionRepository {
     openai_responses_ws_sessions: openai_responses::ResponsesWsSessionPool,
 }
 
+const SSE_DEFAULT_EVENT_TYPE: &[u8] = b"message";
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Incremental `text/event-stream` parser following the WHATWG field rules: `data`
+/// lines accumulate into one payload, `event` names the event, `id` and `retry` are
+/// tracked for diagnostics, and comment lines (heartbeats) are ignored.
 #[derive(Default)]
 struct SseEventAccumulator {
     data: Vec<u8>,
+    has_data: bool,
+    event_type: Vec<u8>,
+    last_event_id: String,
+    retry_ms: Option<u64>,
+    started: bool,
 }
 
 impl SseEventAccumulator {
@@ -49,6 +62,12 @@ impl SseEventAccumulator {
         sender: &ChatCompletionStreamSender,
         hook: &mut F,
     ) -> Result<(), DomainError> {
+        let mut line = line;
+        if !self.started {
+            self.started = true;
+            line = line.strip_prefix(UTF8_BOM).unwrap_or(line);
+        }
+
         if line.is_empty() {
             return self.dispatch(sender, hook);
         }
@@ -58,11 +77,31 @@ impl SseEventAccumulator {
         }
 
         let (field, value) = split_sse_field(line);
-        if field == b"data" {
-            if !self.data.is_empty() {
-                self.data.push(b'\n');
+        match field {
+            b"data" => {
+                if self.has_data {
+                    self.data.push(b'\n');
+                }
+                self.data.extend_from_slice(value);
+                self.has_data = true;
             }
-            self.data.extend_from_slice(value);
+            b"event" => {
+                self.event_type.clear();
+                self.event_type.extend_from_slice(value);
+            }
+            b"id" => {
+                if !value.contains(&0) {
+                    self.last_event_id = String::from_utf8_lossy(value).into_owned();
+                }
+            }
+            b"retry" => {
+                if !value.is_empty() && value.iter().all(u8::is_ascii_digit) {
+                    self.retry_ms = std::str::from_utf8(value)
+                        .ok()
+                        .and_then(|value| value.parse().ok());
+                }
+            }
+            _ => {}
         }
 
         Ok(())
@@ -81,18 +120,28 @@ impl SseEventAccumulator {
         sender: &ChatCompletionStreamSender,
         hook: &mut F,
     ) -> Result<(), DomainError> {
-        if self.data.is_empty() {
+        let event_type = std::mem::take(&mut self.event_type);
+        if !std::mem::take(&mut self.has_data) {
             return Ok(());
         }
 
         let payload = std::mem::take(&mut self.data);
+        // Spec-wise an empty `data:` still dispatches, but consumers expect JSON or
+        // `[DONE]`, so there is nothing useful to forward.
+        if payload.is_empty() {
+            return Ok(());
+        }
         hook(payload.as_slice());
 
         let payload = std::str::from_utf8(payload.as_slice()).map_err(|error| {
             DomainError::InternalError(format!("SSE payload is not valid UTF-8: {error}"))
         })?;
+        let typed_payload = with_sse_event_type(payload, &event_type);
+        let payload = typed_payload.as_deref().unwrap_or(payload);
 
-        if sender.send(payload.to_string()).is_err() {
+        let payload =
+            normalizers::annotate_stream_reasoning(payload).unwrap_or_else(|| payload.to_string());
+        if sender.send(payload).is_err() {
             return Ok(());
         }
 
@@ -100,20 +149,33 @@ impl SseEventAccumulator {
     }
 }
 
+/// Some gateways carry the event kind only in the `event:` field. Copy it into a JSON
+/// object payload's `type` so downstream parsers see the same shape as from providers
+/// that repeat it in the data.
+fn with_sse_event_type(payload: &str, event_type: &[u8]) -> Option<String> {
+    if event_type.is_empty() || event_type == SSE_DEFAULT_EVENT_TYPE {
+        return None;
+    }
+    let event_type = std::str::from_utf8(event_type).ok()?;
+    let Ok(Value::Object(mut object)) = serde_json::from_str::<Value>(payload) else {
+        return None;
+    };
+    if object.contains_key("type") {
+        return None;
+    }
+
+    object.insert("type".to_string(), Value::String(event_type.to_string()));
+    Some(Value::Object(object).to_string())
+}
+
 fn split_sse_field(line: &[u8]) -> (&[u8], &[u8]) {
     let Some(colon_index) = line.iter().position(|byte| *byte == b':') else {
         return (line, b"");
     };
 
     let field = &line[..colon_index];
-    let mut value = &line[colon_index + 1..];
-    let value_start = value
-        .iter()
-        .position(|byte| *byte != b' ' && *byte != b'\t')
-        .unwrap_or(value.len());
-    value = &value[value_start..];
-
-    (field, value)
+    let value = &line[colon_index + 1..];
+    (field, value.strip_prefix(b" ").unwrap_or(value))
 }
 
 impl HttpChatCompletionRepository {
@@ -124,18 +186,60 @@ impl HttpChatCompletionRepository {
         }
     }
 
-    fn client(&self) -> Result<Client, DomainError> {
-        self.http_clients.client(HttpClientProfile::ChatCompletion)
+    fn client(&self, config: &ChatCompletionApiConfig) -> Result<Client, DomainError> {
+        let timeouts = config.timeouts;
+        self.http_clients
+            .client_with_options(
+                HttpClientProfile::ChatCompletion,
+                HttpClientOptions {
+                    timeouts: Some(HttpClientTimeouts {
+                        connect: timeouts.connect,
+                        request: Some(timeouts.request),
+                        read: None,
+                        tcp_keepalive: timeouts.keepalive,
+                    }),
+                    accept_invalid_certs: config.accept_invalid_certs,
+                },
+            )
+            .map(|(client, _revision)| client)
     }
 
-    fn stream_client(&self) -> Result<Client, DomainError> {
+    /// Streams have no overall deadline; the idle timeout bounds each body read instead.
+    fn stream_client(&self, config: &ChatCompletionApiConfig) -> Result<Client, DomainError> {
+        let timeouts = config.timeouts;
         self.http_clients
-            .client(HttpClientProfile::ChatCompletionStream)
+            .client_with_options(
+                HttpClientProfile::ChatCompletionStream,
+                HttpClientOptions {
+                    timeouts: Some(HttpClientTimeouts {
+                        connect: timeouts.connect,
+                        request: None,
+                        read: timeouts.stream_idle,
+                        tcp_keepalive: timeouts.keepalive,
+                    }),
+                    accept_invalid_certs: config.accept_invalid_certs,
+                },
+            )
+            .map(|(client, _revision)| client)
     }
 
-    fn websocket_client(&self) -> Result<(Client, u64), DomainError> {
-        self.http_clients
-            .client_with_revision(HttpClientProfile::ChatCompletionWebSocket)
+    fn websocket_client(
+        &self,
+        config: &ChatCompletionApiConfig,
+    ) -> Result<(Client, u64), DomainError> {
+        let timeouts = config.timeouts;
+        self.http_clients.client_with_options(
+            HttpClientProfile::ChatCompletionWebSocket,
+            HttpClientOptions {
+                timeouts: Some(HttpClientTimeouts {
+                    connect: timeouts.connect,
+                    request: None,
+                    read: None,
+                    tcp_keepalive: timeouts.keepalive,
+                }),
+                accept_invalid_certs: config.accept_invalid_certs,
+            },
+        )
     }
 
     fn build_url(base_url: &str, path: &str) -> String {
@@ -332,6 +436,8 @@ impl HttpChatCompletionRepository {
                             connect = error.is_connect(),
                             body = error.is_body(),
                             request = error.is_request(),
+                            last_event_id = accumulator.last_event_id.as_str(),
+                            retry_ms = ?accumulator.retry_ms,
                             "upstream stream read failed",
                         );
                         DomainError::upstream_failure(failure)
@@ -357,6 +463,8 @@ impl HttpChatCompletionRepository {
         Ok(())
     }
 
+    /// Feeds every complete line in `buffer` to the accumulator. Lines end in LF, CRLF or
+    /// a lone CR; a CR at the very end stays buffered until the next byte shows which.
     fn forward_sse_events<F: FnMut(&[u8])>(
         buffer: &mut Vec<u8>,
         accumulator: &mut SseEventAccumulator,
@@ -364,25 +472,29 @@ impl HttpChatCompletionRepository {
         hook: &mut F,
     ) -> Result<(), DomainError> {
         let mut line_start = 0_usize;
-        let mut consumed = 0_usize;
-
-        for (index, byte) in buffer.iter().enumerate() {
-            if *byte != b'\n' {
-                continue;
-            }
-
-            let mut line = &buffer[line_start..index];
-            if line.last().is_some_and(|byte| *byte == b'\r') {
-                line = &line[..line.len() - 1];
-            }
+        let mut index = 0_usize;
+
+        while index < buffer.len() {
+            let line_end = match buffer[index] {
+                b'\n' => index + 1,
+                b'\r' => match buffer.get(index + 1) {
+                    Some(b'\n') => index + 2,
+                    Some(_) => index + 1,
+                    None => break,
+                },
+                _ => {
+                    index += 1;
+                    continue;
+                }
+            };
 
-            accumulator.on_line(line, sender, hook)?;
-            consumed = index + 1;
-            line_start = consumed;
+            accumulator.on_line(&buffer[line_start..index], sender, hook)?;
+            line_start = line_end;
+            index = line_end;
         }
 
-        if consumed > 0 {
-            buffer.drain(..consumed);
+        if line_start > 0 {
+            buffer.drain(..line_start);
         }
 
         Ok(())
@@ -552,7 +664,7 @@ impl ChatCompletionRepository for HttpChatCompletionRepository {
     ) -> Result<ChatCompletionRepositoryGenerateResponse, DomainError> {
         let source_name = source.display_name();
 
-        match source {
+        let mut response = match source {
             ChatCompletionSource::OpenAi
             | ChatCompletionSource::OpenRouter
             | ChatCompletionSource::DeepSeek
@@ -617,7 +729,10 @@ impl ChatCompletionRepository for HttpChatCompletionRepository {
             ChatCompletionSource::VertexAi => {
                 vertexai::generate(self, config, endpoint_path, payload).await
             }
-        }
+        }?;
+
+        normalizers::attach_reasoning_field(&mut response.body);
+        Ok(response)
     }
 
     async fn generate_stream(
@@ -734,6 +849,23 @@ impl ChatCompletionRepository for HttpChatCompletionRepository {
     async fn close_provider_session(&self, session_id: &str) {
         self.openai_responses_ws_sessions.close(session_id).await;
     }
+
+    async fn get_generation_metadata(
+        &self,
+        source: ChatCompletionSource,
+        config: &ChatCompletionApiConfig,
+        generation_id: &str,
+    ) -> Result<Value, DomainError> {
+        match source {
+            ChatCompletionSource::OpenRouter => {
+                openai::get_generation(self, config, generation_id, source.display_name()).await
+            }
+            _ => Err(DomainError::InvalidData(format!(
+                "{} does not expose generation metadata",
+                source.display_name()
+            ))),
+        }
+    }
 }
 
 fn extract_error_message(body: &str, default_message: &str) -> String {
@@ -776,7 +908,9 @@ mod tests {
     use tokio::sync::mpsc;
 
     use crate::domain::errors::DomainError;
-    use crate::domain::repositories::chat_completion_repository::ChatCompletionApiConfig;
+    use crate::domain::repositories::chat_completion_repository::{
+        ChatCompletionApiConfig, ChatCompletionTimeouts,
+    };
 
     use super::HttpChatCompletionRepository;
 
@@ -838,6 +972,8 @@ mod tests {
                 crate::domain::repositories::chat_completion_repository::AnthropicBetaHeaderMode::None,
             aws_bedrock_custom_response_path: None,
             aws_bedrock_custom_stream_path: None,
+            timeouts: ChatCompletionTimeouts::default(),
+            accept_invalid_certs: false,
         };
 
         let request = Client::new().get("https://example.com");
@@ -869,6 +1005,8 @@ mod tests {
                 crate::domain::repositories::chat_completion_repository::AnthropicBetaHeaderMode::None,
             aws_bedrock_custom_response_path: None,
             aws_bedrock_custom_stream_path: None,
+            timeouts: ChatCompletionTimeouts::default(),
+            accept_invalid_certs: false,
         };
 
         let request = Client::new().get("https://example.com");
@@ -1000,4 +1138,64 @@ mod tests {
         assert_eq!(receiver.try_recv().ok(), Some("tail".to_string()));
         assert!(receiver.try_recv().is_err());
     }
+
+    #[test]
+    fn forward_sse_events_tracks_fields_and_types_event_payloads() {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<String>();
+        let mut buffer = b"\xEF\xBB\xBFid: 7\rretry: 3000\revent: content_block_delta\r\ndata: {\"index\":0}\r\n\r\n:heartbeat\n\ndata:  indented\n\n".to_vec();
+
+        fn noop(_: &[u8]) {}
+        let mut hook = noop;
+        let mut accumulator = super::SseEventAccumulator::default();
+        HttpChatCompletionRepository::forward_sse_events(
+            &mut buffer,
+            &mut accumulator,
+            &sender,
+            &mut hook,
+        )
+        .unwrap();
+
+        let typed: serde_json::Value = serde_json::from_str(&receiver.try_recv().unwrap()).unwrap();
+        assert_eq!(
+            typed,
+            serde_json::json!({ "index": 0, "type": "content_block_delta" })
+        );
+        // The event name applies to one event only, and only one leading space is stripped.
+        assert_eq!(receiver.try_recv().ok(), Some(" indented".to_string()));
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(accumulator.last_event_id, "7");
+        assert_eq!(accumulator.retry_ms, Some(3000));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn forward_sse_events_waits_for_byte_after_trailing_carriage_return() {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<String>();
+        let mut buffer = b"data: one\r".to_vec();
+
+        fn noop(_: &[u8]) {}
+        let mut hook = noop;
+        let mut accumulator = super::SseEventAccumulator::default();
+        HttpChatCompletionRepository::forward_sse_events(
+            &mut buffer,
+            &mut accumulator,
+            &sender,
+            &mut hook,
+        )
+        .unwrap();
+        assert_eq!(buffer, b"data: one\r".to_vec());
+
+        buffer.extend_from_slice(b"\n\r\n");
+        HttpChatCompletionRepository::forward_sse_events(
+            &mut buffer,
+            &mut accumulator,
+            &sender,
+            &mut hook,
+        )
+        .unwrap();
+
+        assert_eq!(receiver.try_recv().ok(), Some("one".to_string()));
+        assert!(receiver.try_recv().is_err());
+        assert!(buffer.is_empty());
+    }
 }
@@ -596,6 +596,90 @@ pub(super) fn normalize_gemini_interactions_response(
     ChatCompletionRepositoryGenerateResponse::new(Value::Object(normalized), report)
 }
 
+/// Mirrors provider-specific reasoning (`reasoning_content` from the native normalizers,
+/// `reasoning` from OpenAI-compatible upstreams) so every non-stream response exposes both.
+pub(super) fn attach_reasoning_field(body: &mut Value) {
+    let Some(choices) = body.get_mut("choices").and_then(Value::as_array_mut) else {
+        return;
+    };
+
+    for choice in choices {
+        let Some(message) = choice.get_mut("message").and_then(Value::as_object_mut) else {
+            continue;
+        };
+
+        let reasoning = as_non_empty_str(message.get("reasoning_content"))
+            .or_else(|| as_non_empty_str(message.get("reasoning")))
+            .map(str::to_string);
+        if let Some(reasoning) = reasoning {
+            message
+                .entry("reasoning_content")
+                .or_insert_with(|| Value::String(reasoning.clone()));
+            message
+                .entry("reasoning")
+                .or_insert_with(|| Value::String(reasoning));
+        }
+    }
+}
+
+/// Adds a top-level `reasoning` delta to stream chunks that carry thinking text, so the
+/// frontend can read reasoning without knowing each provider's event shape.
+/// Returns `None` when the chunk carries no reasoning and should be forwarded untouched.
+pub(super) fn annotate_stream_reasoning(data: &str) -> Option<String> {
+    if !(data.contains("thinking") || data.contains("reasoning") || data.contains("thought")) {
+        return None;
+    }
+
+    let mut value = serde_json::from_str::<Value>(data).ok()?;
+    let object = value.as_object_mut()?;
+    if object.contains_key("reasoning") {
+        return None;
+    }
+
+    let reasoning = stream_reasoning_delta(object)?;
+    object.insert("reasoning".to_string(), Value::String(reasoning));
+    serde_json::to_string(&value).ok()
+}
+
+fn stream_reasoning_delta(object: &Map<String, Value>) -> Option<String> {
+    match object.get("type").and_then(Value::as_str) {
+        Some("content_block_delta") => {
+            let delta = object.get("delta")?;
+            if delta.get("type").and_then(Value::as_str) != Some("thinking_delta") {
+                return None;
+            }
+            return as_non_empty_str(delta.get("thinking")).map(str::to_string);
+        }
+        Some("response.reasoning_summary_text.delta" | "response.reasoning_text.delta") => {
+            return as_non_empty_str(object.get("delta")).map(str::to_string);
+        }
+        _ => {}
+    }
+
+    let choices = object.get("choices").and_then(Value::as_array);
+    if let Some(delta) = choices
+        .and_then(|choices| choices.first())
+        .and_then(|choice| choice.get("delta"))
+    {
+        return as_non_empty_str(delta.get("reasoning_content"))
+            .or_else(|| as_non_empty_str(delta.get("reasoning")))
+            .map(str::to_string);
+    }
+
+    let parts = object
+        .get("candidates")
+        .and_then(Value::as_array)
+        .and_then(|candidates| candidates.first())
+        .and_then(|candidate| candidate.pointer("/content/parts"))
+        .and_then(Value::as_array)?;
+    let text = parts
+        .iter()
+        .filter(|part| part.get("thought").and_then(Value::as_bool) == Some(true))
+        .filter_map(|part| part.get("text").and_then(Value::as_str))
+        .collect::<String>();
+    (!text.is_empty()).then_some(text)
+}
+
 fn synthetic_tool_call_id(report: &mut ChatCompletionNormalizationReport, index: usize) -> String {
     let id = format!("tool_call_{index}");
     report.record_synthetic_tool_call_id(id.clone());
@@ -625,12 +709,53 @@ fn map_claude_usage(raw_usage: Option<&Value>) -> Option<Value> {
         .get("output_tokens")
         .and_then(Value::as_u64)
         .unwrap_or_default();
+    let cache_read_tokens = usage
+        .get("cache_read_input_tokens")
+        .and_then(Value::as_u64)
+        .unwrap_or_default();
+    let cache_creation_tokens = usage
+        .get("cache_creation_input_tokens")
+        .and_then(Value::as_u64)
+        .unwrap_or_default();
 
-    Some(json!({
+    let mut mapped = json!({
         "prompt_tokens": prompt_tokens,
         "completion_tokens": completion_tokens,
         "total_tokens": prompt_tokens + completion_tokens,
-    }))
+    });
+    if usage.contains_key("cache_read_input_tokens")
+        || usage.contains_key("cache_creation_input_tokens")
+    {
+        attach_prompt_cache_usage(&mut mapped, cache_read_tokens, Some(cache_creation_tokens));
+    }
+    Some(mapped)
+}
+
+/// Reports cache hits in the OpenAI `prompt_tokens_details.cached_tokens` shape, keeping
+/// the Claude-style counters alongside so cache writes are visible too.
+fn attach_prompt_cache_usage(
+    usage: &mut Value,
+    cache_read_tokens: u64,
+    cache_creation_tokens: Option<u64>,
+) {
+    let Some(usage) = usage.as_object_mut() else {
+        return;
+    };
+
+    usage.insert(
+        "prompt_tokens_details".to_string(),
+        json!({ "cached_tokens": cache_read_tokens }),
+    );
+    usage.insert(
+        "cache_read_input_tokens".to_string(),
+        json!(cache_read_tokens),
+    );
+    if let Some(cache_creation_tokens) = cache_creation_tokens {
+        usage.insert(
+            "cache_creation_input_tokens".to_string(),
+            json!(cache_creation_tokens),
+        );
+    }
 }
 
 fn map_gemini_finish_reason(finish_reason: Option<&str>, has_tool_calls: bool) -> String {
@@ -667,11 +792,15 @@ fn map_gemini_usage(response: &Value) -> Option<Value> {
         .and_then(Value::as_u64)
         .unwrap_or(prompt_tokens + completion_tokens);
 
-    Some(json!({
+    let mut mapped = json!({
         "prompt_tokens": prompt_tokens,
         "completion_tokens": completion_tokens,
         "total_tokens": total_tokens,
-    }))
+    });
+    if let Some(cached_tokens) = usage.get("cachedContentTokenCount").and_then(Value::as_u64) {
+        attach_prompt_cache_usage(&mut mapped, cached_tokens, None);
+    }
+    Some(mapped)
 }
 
 fn map_openai_responses_usage(raw_usage: Option<&Value>) -> Option<Value> {
@@ -690,11 +819,19 @@ fn map_openai_responses_usage(raw_usage: Option<&Value>) -> Option<Value> {
         .and_then(Value::as_u64)
         .unwrap_or(prompt_tokens + completion_tokens);
 
-    Some(json!({
+    let mut mapped = json!({
         "prompt_tokens": prompt_tokens,
         "completion_tokens": completion_tokens,
         "total_tokens": total_tokens,
-    }))
+    });
+    if let Some(cached_tokens) = usage
+        .get("input_tokens_details")
+        .and_then(|details| details.get("cached_tokens"))
+        .and_then(Value::as_u64)
+    {
+        attach_prompt_cache_usage(&mut mapped, cached_tokens, None);
+    }
+    Some(mapped)
 }
 
 fn map_gemini_interactions_usage(raw_usage: Option<&Value>) -> Option<Value> {
@@ -716,11 +853,19 @@ fn map_gemini_interactions_usage(raw_usage: Option<&Value>) -> Option<Value> {
         .and_then(Value::as_u64)
         .unwrap_or(prompt_tokens + completion_tokens);
 
-    Some(json!({
+    let mut mapped = json!({
         "prompt_tokens": prompt_tokens,
         "completion_tokens": completion_tokens,
         "total_tokens": total_tokens,
-    }))
+    });
+    if let Some(cached_tokens) = usage
+        .get("total_cached_tokens")
+        .or_else(|| usage.get("cached_tokens"))
+        .and_then(Value::as_u64)
+    {
+        attach_prompt_cache_usage(&mut mapped, cached_tokens, None);
+    }
+    Some(mapped)
 }
 
 fn current_unix_timestamp() -> u64 {
@@ -813,10 +958,82 @@ mod tests {
     use serde_json::{Value, json};
 
     use super::{
-        normalize_claude_response, normalize_gemini_interactions_response,
-        normalize_gemini_response, normalize_openai_responses_response,
+        annotate_stream_reasoning, attach_reasoning_field, normalize_claude_response,
+        normalize_gemini_interactions_response, normalize_gemini_response,
+        normalize_openai_responses_response,
     };
 
+    #[test]
+    fn normalize_claude_usage_reports_prompt_cache_tokens() {
+        let response = json!({
+            "id": "claude-response",
+            "model": "claude-sonnet-4-5",
+            "content": [{ "type": "text", "text": "hi" }],
+            "stop_reason": "end_turn",
+            "usage": {
+                "input_tokens": 12,
+                "output_tokens": 3,
+                "cache_read_input_tokens": 2048,
+                "cache_creation_input_tokens": 0
+            }
+        });
+
+        let normalized = normalize_claude_response(response);
+        let usage = normalized.body.get("usage").expect("usage must exist");
+        assert_eq!(
+            usage
+                .pointer("/prompt_tokens_details/cached_tokens")
+                .and_then(Value::as_u64),
+            Some(2048)
+        );
+        assert_eq!(
+            usage
+                .get("cache_creation_input_tokens")
+                .and_then(Value::as_u64),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn attach_reasoning_field_mirrors_reasoning_content() {
+        let mut body = json!({
+            "choices": [{ "message": { "role": "assistant", "content": "hi", "reasoning_content": "think" } }]
+        });
+        attach_reasoning_field(&mut body);
+        assert_eq!(
+            body.pointer("/choices/0/message/reasoning")
+                .and_then(Value::as_str),
+            Some("think")
+        );
+
+        let mut body = json!({
+            "choices": [{ "message": { "role": "assistant", "content": "hi", "reasoning": "plan" } }]
+        });
+        attach_reasoning_field(&mut body);
+        assert_eq!(
+            body.pointer("/choices/0/message/reasoning_content")
+                .and_then(Value::as_str),
+            Some("plan")
+        );
+    }
+
+    #[test]
+    fn annotate_stream_reasoning_handles_provider_delta_shapes() {
+        let claude = r#"{"type":"content_block_delta","index":0,"delta":{"type":"thinking_delta","thinking":"step"}}"#;
+        let openai = r#"{"choices":[{"index":0,"delta":{"reasoning_content":"step"}}]}"#;
+        let responses = r#"{"type":"response.reasoning_summary_text.delta","delta":"step"}"#;
+        let gemini = r#"{"candidates":[{"content":{"parts":[{"text":"step","thought":true},{"text":"answer"}]}}]}"#;
+
+        for chunk in [claude, openai, responses, gemini] {
+            let annotated = annotate_stream_reasoning(chunk).expect("chunk should be annotated");
+            let value: Value = serde_json::from_str(&annotated).expect("valid json");
+            assert_eq!(value.get("reasoning").and_then(Value::as_str), Some("step"));
+        }
+
+        assert!(annotate_stream_reasoning(r#"{"choices":[{"delta":{"content":"hi"}}]}"#).is_none());
+        assert!(annotate_stream_reasoning("[DONE]").is_none());
+    }
+
     #[test]
     fn normalize_claude_tool_use_preserves_signature() {
         let response = json!({
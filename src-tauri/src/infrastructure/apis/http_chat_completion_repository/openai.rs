@@ -1,5 +1,5 @@
 use reqwest::header::{ACCEPT, CONTENT_TYPE};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 use crate::domain::errors::DomainError;
 use crate::domain::repositories::chat_completion_repository::{
@@ -9,6 +9,8 @@ use crate::domain::repositories::chat_completion_repository::{
 use super::HttpChatCompletionRepository;
 use super::response_body::read_upstream_json_body;
 
+const GENERATION_ID_HEADER: &str = "x-generation-id";
+
 pub(super) async fn list_models(
     repository: &HttpChatCompletionRepository,
     config: &ChatCompletionApiConfig,
@@ -25,7 +27,7 @@ pub(super) async fn list_models_with_path(
 ) -> Result<Value, DomainError> {
     let url = HttpChatCompletionRepository::build_url(&config.base_url, path);
 
-    let client = repository.client()?;
+    let client = repository.client(config)?;
     let request = client.get(url).header(ACCEPT, "application/json");
     let request = HttpChatCompletionRepository::apply_openai_auth(request, config);
     let request = HttpChatCompletionRepository::apply_extra_headers(request, &config.extra_headers);
@@ -47,6 +49,40 @@ pub(super) async fn list_models_with_path(
     read_upstream_json_body(provider_name, "list_models", response).await
 }
 
+/// Looks up a finished generation on OpenRouter-style `/generation?id=` endpoints.
+pub(super) async fn get_generation(
+    repository: &HttpChatCompletionRepository,
+    config: &ChatCompletionApiConfig,
+    generation_id: &str,
+    provider_name: &str,
+) -> Result<Value, DomainError> {
+    let url = HttpChatCompletionRepository::build_url(&config.base_url, "/generation");
+
+    let client = repository.client(config)?;
+    let request = client
+        .get(url)
+        .query(&[("id", generation_id)])
+        .header(ACCEPT, "application/json");
+    let request = HttpChatCompletionRepository::apply_openai_auth(request, config);
+    let request = HttpChatCompletionRepository::apply_extra_headers(request, &config.extra_headers);
+    let request = HttpChatCompletionRepository::apply_additional_headers(request, config);
+
+    let response = request.send().await.map_err(|error| {
+        HttpChatCompletionRepository::map_transport_error("Generation lookup failed", error)
+    })?;
+
+    if !response.status().is_success() {
+        return Err(HttpChatCompletionRepository::map_error_response(
+            provider_name,
+            response,
+            "Generation lookup failed",
+        )
+        .await);
+    }
+
+    read_upstream_json_body(provider_name, "get_generation", response).await
+}
+
 pub(super) async fn generate(
     repository: &HttpChatCompletionRepository,
     config: &ChatCompletionApiConfig,
@@ -56,7 +92,7 @@ pub(super) async fn generate(
 ) -> Result<Value, DomainError> {
     let url = HttpChatCompletionRepository::build_url(&config.base_url, endpoint_path);
 
-    let client = repository.client()?;
+    let client = repository.client(config)?;
     let request = client
         .post(url)
         .header(CONTENT_TYPE, "application/json")
@@ -80,7 +116,13 @@ pub(super) async fn generate(
         .await);
     }
 
-    let body = read_upstream_json_body(provider_name, "generate", response).await?;
+    let generation_id = generation_id_header(&response);
+    let mut body = read_upstream_json_body(provider_name, "generate", response).await?;
+    if let (Some(generation_id), Some(object)) = (generation_id, body.as_object_mut()) {
+        object
+            .entry("id")
+            .or_insert_with(|| Value::String(generation_id));
+    }
 
     if super::payload_contains_cache_control(payload) {
         let model = payload.get("model").and_then(Value::as_str);
@@ -101,7 +143,7 @@ pub(super) async fn generate_stream(
 ) -> Result<(), DomainError> {
     let url = HttpChatCompletionRepository::build_url(&config.base_url, endpoint_path);
 
-    let client = repository.stream_client()?;
+    let client = repository.stream_client(config)?;
     let request = client
         .post(url)
         .header(CONTENT_TYPE, "application/json")
@@ -125,6 +167,17 @@ pub(super) async fn generate_stream(
         .await);
     }
 
+    // Streamed chunks may omit the id, so surface the header as a leading empty-choices
+    // chunk, the shape providers already use for usage-only chunks.
+    if let Some(generation_id) = generation_id_header(&response) {
+        let chunk = json!({
+            "id": generation_id,
+            "object": "chat.completion.chunk",
+            "choices": [],
+        });
+        let _ = sender.send(chunk.to_string());
+    }
+
     if super::payload_contains_cache_control(payload) {
         let model = payload
             .get("model")
@@ -170,3 +223,13 @@ pub(super) async fn generate_stream(
             .await
     }
 }
+
+fn generation_id_header(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(GENERATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
@@ -46,7 +46,7 @@ impl ResponsesWsSessionPool {
         endpoint_path: &str,
         session_id: &str,
     ) -> Result<Arc<Mutex<ResponsesWsSession>>, DomainError> {
-        let (client, transport_revision) = repository.websocket_client()?;
+        let (client, transport_revision) = repository.websocket_client(config)?;
         let connection_key = ws_connection_key(config, endpoint_path, transport_revision)?;
         if let Some(session) = self.sessions.lock().await.get(session_id).cloned() {
             if session.lock().await.connection_key == connection_key {
@@ -444,7 +444,7 @@ async fn generate_http(
 ) -> Result<ChatCompletionRepositoryGenerateResponse, DomainError> {
     let url = HttpChatCompletionRepository::build_url(&config.base_url, endpoint_path);
 
-    let client = repository.client()?;
+    let client = repository.client(config)?;
     let http_payload = upstream_payload(payload)?;
     let request = client
         .post(url)
@@ -527,7 +527,7 @@ async fn generate_stream_http(
 ) -> Result<(), DomainError> {
     let url = HttpChatCompletionRepository::build_url(&config.base_url, endpoint_path);
 
-    let client = repository.stream_client()?;
+    let client = repository.stream_client(config)?;
     let http_payload = upstream_payload(payload)?;
     let request = client
         .post(url)
@@ -674,7 +674,7 @@ async fn generate_ws(
     endpoint_path: &str,
     payload: &Value,
 ) -> Result<ChatCompletionRepositoryGenerateResponse, DomainError> {
-    let (client, _transport_revision) = repository.websocket_client()?;
+    let (client, _transport_revision) = repository.websocket_client(config)?;
     let mut socket = connect_responses_ws(client, config, endpoint_path).await?;
     let event = response_create_event(payload)?;
     socket
@@ -736,7 +736,7 @@ async fn generate_stream_ws(
 ) -> Result<(), ResponsesWsStreamError> {
     let (client, _transport_revision) =
         repository
-            .websocket_client()
+            .websocket_client(config)
             .map_err(|error| ResponsesWsStreamError {
                 error,
                 emitted: false,
@@ -971,11 +971,12 @@ fn ws_connection_key(
     headers.sort_unstable();
 
     Ok(format!(
-        "{}\n{}\n{}\n{}",
+        "{}\n{}\n{}\n{}\n{}",
         responses_ws_url(&config.base_url, endpoint_path)?,
         transport_revision,
         websocket_authorization_header(config).unwrap_or_default(),
-        headers.join("\n")
+        headers.join("\n"),
+        config.accept_invalid_certs
     ))
 }
 
@@ -1156,7 +1157,9 @@ mod tests {
     use serde_json::json;
 
     use super::*;
-    use crate::domain::repositories::chat_completion_repository::AnthropicBetaHeaderMode;
+    use crate::domain::repositories::chat_completion_repository::{
+        AnthropicBetaHeaderMode, ChatCompletionTimeouts,
+    };
 
     #[test]
     fn responses_ws_url_maps_http_schemes() {
@@ -1216,6 +1219,8 @@ mod tests {
             anthropic_beta_header_mode: AnthropicBetaHeaderMode::None,
             aws_bedrock_custom_response_path: None,
             aws_bedrock_custom_stream_path: None,
+            timeouts: ChatCompletionTimeouts::default(),
+            accept_invalid_certs: false,
         };
 
         let client = Client::new();
@@ -1248,6 +1253,8 @@ mod tests {
             anthropic_beta_header_mode: AnthropicBetaHeaderMode::None,
             aws_bedrock_custom_response_path: None,
             aws_bedrock_custom_stream_path: None,
+            timeouts: ChatCompletionTimeouts::default(),
+            accept_invalid_certs: false,
         };
 
         let first = ws_connection_key(&config, "/responses", 1).unwrap();
@@ -46,7 +46,7 @@ pub(super) async fn generate(
         &format!("/publishers/google/models/{model}:{method}"),
     );
 
-    let client = repository.client()?;
+    let client = repository.client(config)?;
     let request = client
         .post(url)
         .header(CONTENT_TYPE, "application/json")
@@ -108,7 +108,7 @@ pub(super) async fn generate_stream(
         &format!("/publishers/google/models/{model}:{method}"),
     );
 
-    let client = repository.stream_client()?;
+    let client = repository.stream_client(config)?;
     let request = client
         .post(url)
         .header(CONTENT_TYPE, "application/json")
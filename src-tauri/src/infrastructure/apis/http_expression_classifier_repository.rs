@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use serde_json::{Value, json};
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::expression_classifier_repository::{
+    ExpressionClassifierRepository, ExpressionClassifierRequest, ExpressionScore,
+};
+use crate::infrastructure::http_client_pool::{HttpClientPool, HttpClientProfile};
+
+const HUGGING_FACE_INFERENCE_URL: &str = "https://router.huggingface.co/hf-inference/models";
+
+/// Classifies text with a Hugging Face hosted text-classification model.
+pub struct HttpExpressionClassifierRepository {
+    http_clients: Arc<HttpClientPool>,
+}
+
+impl HttpExpressionClassifierRepository {
+    pub fn new(http_clients: Arc<HttpClientPool>) -> Self {
+        Self { http_clients }
+    }
+}
+
+#[async_trait]
+impl ExpressionClassifierRepository for HttpExpressionClassifierRepository {
+    async fn classify(
+        &self,
+        request: ExpressionClassifierRequest,
+    ) -> Result<Vec<ExpressionScore>, DomainError> {
+        let client = self.http_clients.client(HttpClientProfile::Default)?;
+        let url = format!(
+            "{}/{}",
+            HUGGING_FACE_INFERENCE_URL,
+            request.model.trim_matches('/')
+        );
+
+        let response = client
+            .post(url)
+            .header(AUTHORIZATION, format!("Bearer {}", request.api_key))
+            .header(ACCEPT, "application/json")
+            .header(CONTENT_TYPE, "application/json")
+            .json(&json!({
+                "inputs": request.text,
+                "parameters": { "top_k": null, "truncation": true },
+                "options": { "wait_for_model": true },
+            }))
+            .send()
+            .await
+            .map_err(|error| {
+                DomainError::InternalError(format!("Classification request failed: {error}"))
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(DomainError::InternalError(format!(
+                "Classification error: HTTP {status} {body}"
+            )));
+        }
+
+        let json: Value = response.json().await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Classification response is not valid JSON: {error}"
+            ))
+        })?;
+
+        parse_classification(json)
+    }
+}
+
+/// Accepts both `[{label, score}]` and the batched `[[{label, score}]]` shape.
+fn parse_classification(json: Value) -> Result<Vec<ExpressionScore>, DomainError> {
+    let entries = match json {
+        Value::Array(items) if items.first().is_some_and(Value::is_array) => {
+            items.into_iter().next().unwrap_or_default()
+        }
+        other => other,
+    };
+
+    let mut scores: Vec<ExpressionScore> = serde_json::from_value(entries).map_err(|error| {
+        DomainError::InternalError(format!("Unexpected classification response: {error}"))
+    })?;
+    scores.sort_by(|left, right| right.score.total_cmp(&left.score));
+    Ok(scores)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_classification;
+    use serde_json::json;
+
+    #[test]
+    fn parses_batched_and_flat_responses() {
+        let flat = json!([{ "label": "joy", "score": 0.2 }, { "label": "anger", "score": 0.7 }]);
+        let scores = parse_classification(flat.clone()).expect("flat");
+        assert_eq!(scores[0].label, "anger");
+
+        let batched = parse_classification(json!([flat])).expect("batched");
+        assert_eq!(batched, scores);
+        assert!(parse_classification(json!({ "error": "loading" })).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use reqwest::{Method, RequestBuilder, StatusCode};
+use serde_json::Value;
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::horde_repository::HordeRepository;
+use crate::infrastructure::http_client_pool::{HttpClientPool, HttpClientProfile};
+
+pub const AI_HORDE_CLUSTER: &str = "https://aihorde.net";
+const HORDE_CLIENT_AGENT: &str = concat!(
+    "TauriTavern:",
+    env!("CARGO_PKG_VERSION"),
+    ":https://github.com/Darkatse/TauriTavern"
+);
+
+pub struct HttpHordeRepository {
+    http_clients: Arc<HttpClientPool>,
+    cluster: String,
+}
+
+impl HttpHordeRepository {
+    pub fn new(http_clients: Arc<HttpClientPool>) -> Self {
+        Self {
+            http_clients,
+            cluster: AI_HORDE_CLUSTER.to_string(),
+        }
+    }
+
+    fn request(&self, method: Method, path: &str) -> Result<RequestBuilder, DomainError> {
+        let client = self.http_clients.client(HttpClientProfile::Default)?;
+        Ok(client
+            .request(method, format!("{}/api/v2/{}", self.cluster, path))
+            .header("Client-Agent", HORDE_CLIENT_AGENT))
+    }
+
+    async fn send_json(&self, request: RequestBuilder, action: &str) -> Result<Value, DomainError> {
+        let response = request.send().await.map_err(|error| {
+            DomainError::InternalError(format!("Horde {action} request failed: {error}"))
+        })?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            return Err(horde_error(status, &body, action));
+        }
+
+        serde_json::from_str(&body).map_err(|error| {
+            DomainError::InternalError(format!(
+                "Horde {action} response is not valid JSON: {error}"
+            ))
+        })
+    }
+
+    async fn get_array(&self, path: &str, action: &str) -> Result<Vec<Value>, DomainError> {
+        match self
+            .send_json(self.request(Method::GET, path)?, action)
+            .await?
+        {
+            Value::Array(items) => Ok(items),
+            _ => Err(DomainError::InternalError(format!(
+                "Horde {action} response is not an array"
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl HordeRepository for HttpHordeRepository {
+    fn cluster(&self) -> &str {
+        &self.cluster
+    }
+
+    async fn heartbeat(&self) -> Result<bool, DomainError> {
+        let response = self
+            .request(Method::GET, "status/heartbeat")?
+            .send()
+            .await
+            .map_err(|error| {
+                DomainError::InternalError(format!("Horde heartbeat request failed: {error}"))
+            })?;
+        Ok(response.status().is_success())
+    }
+
+    async fn text_models(&self) -> Result<Vec<Value>, DomainError> {
+        self.get_array("status/models?type=text", "models").await
+    }
+
+    async fn text_workers(&self) -> Result<Vec<Value>, DomainError> {
+        self.get_array("workers?type=text", "workers").await
+    }
+
+    async fn submit_text(&self, api_key: &str, payload: Value) -> Result<Value, DomainError> {
+        let request = self
+            .request(Method::POST, "generate/text/async")?
+            .header("apikey", api_key)
+            .json(&payload);
+        self.send_json(request, "generation").await
+    }
+
+    async fn text_status(&self, task_id: &str) -> Result<Value, DomainError> {
+        let path = format!("generate/text/status/{}", encode_path_segment(task_id));
+        self.send_json(self.request(Method::GET, &path)?, "task status")
+            .await
+    }
+
+    async fn cancel_text(&self, task_id: &str) -> Result<Value, DomainError> {
+        let path = format!("generate/text/status/{}", encode_path_segment(task_id));
+        self.send_json(self.request(Method::DELETE, &path)?, "cancel")
+            .await
+    }
+
+    async fn find_user(&self, api_key: &str) -> Result<Value, DomainError> {
+        let request = self
+            .request(Method::GET, "find_user")?
+            .header("apikey", api_key);
+        self.send_json(request, "user info").await
+    }
+}
+
+fn encode_path_segment(value: &str) -> String {
+    utf8_percent_encode(value, NON_ALPHANUMERIC).to_string()
+}
+
+/// Horde errors carry `{ "message": ..., "rc": ... }`; keep the message for the user.
+fn horde_error(status: StatusCode, body: &str, action: &str) -> DomainError {
+    let message = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|json| {
+            json.get("message")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| body.trim().to_string());
+    let message = format!("Horde {action} failed: HTTP {status} {message}");
+
+    match status {
+        StatusCode::BAD_REQUEST => DomainError::InvalidData(message),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            DomainError::AuthenticationError(message)
+        }
+        StatusCode::NOT_FOUND => DomainError::NotFound(message),
+        StatusCode::TOO_MANY_REQUESTS => DomainError::RateLimited { message },
+        _ => DomainError::InternalError(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+
+    use super::{encode_path_segment, horde_error};
+    use crate::domain::errors::DomainError;
+
+    #[test]
+    fn horde_error_keeps_api_message_and_maps_status() {
+        let error = horde_error(
+            StatusCode::UNAUTHORIZED,
+            r#"{"message":"Wrong API key","rc":"InvalidAPIKey"}"#,
+            "generation",
+        );
+        assert!(
+            matches!(error, DomainError::AuthenticationError(message) if message.contains("Wrong API key"))
+        );
+
+        let error = horde_error(StatusCode::TOO_MANY_REQUESTS, "slow down", "generation");
+        assert!(matches!(error, DomainError::RateLimited { .. }));
+    }
+
+    #[test]
+    fn task_ids_are_encoded_as_a_single_path_segment() {
+        assert_eq!(encode_path_segment("abc-123"), "abc%2D123");
+        assert_eq!(encode_path_segment("../x"), "%2E%2E%2Fx");
+    }
+}
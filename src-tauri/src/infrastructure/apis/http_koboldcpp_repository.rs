@@ -0,0 +1,244 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::{Method, RequestBuilder, StatusCode};
+use serde_json::{Value, json};
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::chat_completion_repository::{
+    ChatCompletionCancelReceiver, ChatCompletionStreamSender,
+};
+use crate::domain::repositories::koboldcpp_repository::{KoboldCppEndpoint, KoboldCppRepository};
+use crate::infrastructure::apis::sse_data_decoder::SseDataDecoder;
+use crate::infrastructure::http_client_pool::{HttpClientPool, HttpClientProfile};
+
+pub struct HttpKoboldCppRepository {
+    http_clients: Arc<HttpClientPool>,
+}
+
+impl HttpKoboldCppRepository {
+    pub fn new(http_clients: Arc<HttpClientPool>) -> Self {
+        Self { http_clients }
+    }
+
+    fn request(
+        &self,
+        profile: HttpClientProfile,
+        method: Method,
+        endpoint: &KoboldCppEndpoint,
+        path: &str,
+    ) -> Result<RequestBuilder, DomainError> {
+        let client = self.http_clients.client(profile)?;
+        let request = client.request(method, format!("{}{}", endpoint.base_url, path));
+        Ok(match endpoint.api_key.as_deref() {
+            Some(api_key) => request.bearer_auth(api_key),
+            None => request,
+        })
+    }
+
+    async fn send(request: RequestBuilder, action: &str) -> Result<reqwest::Response, DomainError> {
+        let response = request.send().await.map_err(|error| {
+            DomainError::InternalError(format!("KoboldCpp {action} request failed: {error}"))
+        })?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let body = response.text().await.unwrap_or_default();
+        Err(koboldcpp_error(status, &body, action))
+    }
+
+    async fn send_json(request: RequestBuilder, action: &str) -> Result<Value, DomainError> {
+        Self::send(request, action)
+            .await?
+            .json()
+            .await
+            .map_err(|error| {
+                DomainError::InternalError(format!(
+                    "KoboldCpp {action} response is not valid JSON: {error}"
+                ))
+            })
+    }
+}
+
+#[async_trait]
+impl KoboldCppRepository for HttpKoboldCppRepository {
+    async fn model(&self, endpoint: &KoboldCppEndpoint) -> Result<String, DomainError> {
+        let request = self.request(
+            HttpClientProfile::Default,
+            Method::GET,
+            endpoint,
+            "/api/v1/model",
+        )?;
+        let response = Self::send_json(request, "model").await?;
+        response
+            .get("result")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                DomainError::InternalError("KoboldCpp model response has no result".to_string())
+            })
+    }
+
+    async fn true_max_context_length(
+        &self,
+        endpoint: &KoboldCppEndpoint,
+    ) -> Result<u64, DomainError> {
+        let request = self.request(
+            HttpClientProfile::Default,
+            Method::GET,
+            endpoint,
+            "/api/extra/true_max_context_length",
+        )?;
+        let response = Self::send_json(request, "context length").await?;
+        response
+            .get("value")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| {
+                DomainError::InternalError(
+                    "KoboldCpp context length response has no value".to_string(),
+                )
+            })
+    }
+
+    async fn generate(
+        &self,
+        endpoint: &KoboldCppEndpoint,
+        payload: &Value,
+    ) -> Result<Value, DomainError> {
+        let request = self
+            .request(
+                HttpClientProfile::ChatCompletion,
+                Method::POST,
+                endpoint,
+                "/api/v1/generate",
+            )?
+            .json(payload);
+        Self::send_json(request, "generation").await
+    }
+
+    async fn generate_stream(
+        &self,
+        endpoint: &KoboldCppEndpoint,
+        payload: &Value,
+        sender: ChatCompletionStreamSender,
+        mut cancel: ChatCompletionCancelReceiver,
+    ) -> Result<(), DomainError> {
+        let request = self
+            .request(
+                HttpClientProfile::ChatCompletionStream,
+                Method::POST,
+                endpoint,
+                "/api/extra/generate/stream",
+            )?
+            .json(payload);
+        let mut response = tokio::select! {
+            response = Self::send(request, "generation") => response?,
+            _ = cancel.changed() => return Ok(()),
+        };
+
+        let mut decoder = SseDataDecoder::default();
+        loop {
+            if *cancel.borrow() {
+                return Ok(());
+            }
+
+            let chunk = tokio::select! {
+                chunk = response.chunk() => chunk.map_err(|error| {
+                    DomainError::InternalError(format!("KoboldCpp stream read failed: {error}"))
+                })?,
+                _ = cancel.changed() => continue,
+            };
+            let Some(chunk) = chunk else {
+                break;
+            };
+
+            for data in decoder.push(&chunk) {
+                if sender.send(data).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+
+        if let Some(data) = decoder.finish() {
+            let _ = sender.send(data);
+        }
+        Ok(())
+    }
+
+    async fn abort(&self, endpoint: &KoboldCppEndpoint, genkey: &str) -> Result<(), DomainError> {
+        let request = self
+            .request(
+                HttpClientProfile::Default,
+                Method::POST,
+                endpoint,
+                "/api/extra/abort",
+            )?
+            .json(&json!({ "genkey": genkey }));
+        Self::send(request, "abort").await?;
+        Ok(())
+    }
+
+    async fn token_count(
+        &self,
+        endpoint: &KoboldCppEndpoint,
+        text: &str,
+    ) -> Result<Value, DomainError> {
+        let request = self
+            .request(
+                HttpClientProfile::Default,
+                Method::POST,
+                endpoint,
+                "/api/extra/tokencount",
+            )?
+            .json(&json!({ "prompt": text }));
+        Self::send_json(request, "token count").await
+    }
+}
+
+/// KoboldCpp reports errors as `{ "detail": { "msg": ... } }`.
+fn koboldcpp_error(status: StatusCode, body: &str, action: &str) -> DomainError {
+    let message = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|json| {
+            json.pointer("/detail/msg")
+                .or_else(|| json.get("error"))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| body.trim().to_string());
+    let message = format!("KoboldCpp {action} failed: HTTP {status} {message}");
+
+    match status {
+        StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => {
+            DomainError::InvalidData(message)
+        }
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            DomainError::AuthenticationError(message)
+        }
+        StatusCode::SERVICE_UNAVAILABLE => DomainError::Transient(message),
+        _ => DomainError::InternalError(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+
+    use super::koboldcpp_error;
+    use crate::domain::errors::DomainError;
+
+    #[test]
+    fn koboldcpp_error_reads_detail_message() {
+        let error = koboldcpp_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            r#"{"detail":{"msg":"Server is busy","type":"service_unavailable"}}"#,
+            "generation",
+        );
+        assert!(
+            matches!(error, DomainError::Transient(message) if message.contains("Server is busy"))
+        );
+    }
+}
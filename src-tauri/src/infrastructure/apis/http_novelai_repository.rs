@@ -0,0 +1,206 @@
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::{RequestBuilder, StatusCode};
+use serde_json::Value;
+use zip::ZipArchive;
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::chat_completion_repository::{
+    ChatCompletionCancelReceiver, ChatCompletionStreamSender,
+};
+use crate::domain::repositories::novelai_repository::NovelAiRepository;
+use crate::infrastructure::apis::sse_data_decoder::SseDataDecoder;
+use crate::infrastructure::http_client_pool::{HttpClientPool, HttpClientProfile};
+
+const NOVELAI_API_URL: &str = "https://api.novelai.net";
+const NOVELAI_TEXT_URL: &str = "https://text.novelai.net";
+const NOVELAI_IMAGE_URL: &str = "https://image.novelai.net";
+
+pub struct HttpNovelAiRepository {
+    http_clients: Arc<HttpClientPool>,
+}
+
+impl HttpNovelAiRepository {
+    pub fn new(http_clients: Arc<HttpClientPool>) -> Self {
+        Self { http_clients }
+    }
+
+    fn post(
+        &self,
+        profile: HttpClientProfile,
+        url: String,
+        api_key: &str,
+        payload: &Value,
+    ) -> Result<RequestBuilder, DomainError> {
+        let client = self.http_clients.client(profile)?;
+        Ok(client.post(url).bearer_auth(api_key).json(payload))
+    }
+
+    async fn send(request: RequestBuilder, action: &str) -> Result<reqwest::Response, DomainError> {
+        let response = request.send().await.map_err(|error| {
+            DomainError::InternalError(format!("NovelAI {action} request failed: {error}"))
+        })?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let body = response.text().await.unwrap_or_default();
+        Err(novelai_error(status, &body, action))
+    }
+}
+
+#[async_trait]
+impl NovelAiRepository for HttpNovelAiRepository {
+    async fn get_subscription(&self, api_key: &str) -> Result<Value, DomainError> {
+        let request = self
+            .http_clients
+            .client(HttpClientProfile::Default)?
+            .get(format!("{NOVELAI_API_URL}/user/subscription"))
+            .bearer_auth(api_key);
+        let response = Self::send(request, "subscription").await?;
+        response.json().await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "NovelAI subscription response is not valid JSON: {error}"
+            ))
+        })
+    }
+
+    async fn generate_text(&self, api_key: &str, payload: &Value) -> Result<Value, DomainError> {
+        let request = self.post(
+            HttpClientProfile::ChatCompletion,
+            format!("{NOVELAI_TEXT_URL}/ai/generate"),
+            api_key,
+            payload,
+        )?;
+        let response = Self::send(request, "generation").await?;
+        response.json().await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "NovelAI generation response is not valid JSON: {error}"
+            ))
+        })
+    }
+
+    async fn generate_text_stream(
+        &self,
+        api_key: &str,
+        payload: &Value,
+        sender: ChatCompletionStreamSender,
+        mut cancel: ChatCompletionCancelReceiver,
+    ) -> Result<(), DomainError> {
+        let request = self.post(
+            HttpClientProfile::ChatCompletionStream,
+            format!("{NOVELAI_TEXT_URL}/ai/generate-stream"),
+            api_key,
+            payload,
+        )?;
+        let mut response = tokio::select! {
+            response = Self::send(request, "generation") => response?,
+            _ = cancel.changed() => return Ok(()),
+        };
+
+        let mut decoder = SseDataDecoder::default();
+        loop {
+            if *cancel.borrow() {
+                return Ok(());
+            }
+
+            let chunk = tokio::select! {
+                chunk = response.chunk() => chunk.map_err(|error| {
+                    DomainError::InternalError(format!("NovelAI stream read failed: {error}"))
+                })?,
+                _ = cancel.changed() => continue,
+            };
+            let Some(chunk) = chunk else {
+                break;
+            };
+
+            for data in decoder.push(&chunk) {
+                if sender.send(data).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+
+        if let Some(data) = decoder.finish() {
+            let _ = sender.send(data);
+        }
+        Ok(())
+    }
+
+    async fn generate_image(&self, api_key: &str, payload: &Value) -> Result<Vec<u8>, DomainError> {
+        let request = self.post(
+            HttpClientProfile::ImageGeneration,
+            format!("{NOVELAI_IMAGE_URL}/ai/generate-image"),
+            api_key,
+            payload,
+        )?;
+        let response = Self::send(request, "image generation").await?;
+        let archive = response.bytes().await.map_err(|error| {
+            DomainError::InternalError(format!("NovelAI image download failed: {error}"))
+        })?;
+
+        first_zip_entry(&archive)
+    }
+}
+
+/// NovelAI answers image requests with a zip holding one image per sample.
+fn first_zip_entry(archive: &[u8]) -> Result<Vec<u8>, DomainError> {
+    let invalid = |error: &dyn std::fmt::Display| {
+        DomainError::InternalError(format!("NovelAI image archive is invalid: {error}"))
+    };
+
+    let mut archive = ZipArchive::new(Cursor::new(archive)).map_err(|error| invalid(&error))?;
+    let mut entry = archive.by_index(0).map_err(|error| invalid(&error))?;
+    let mut image = Vec::new();
+    entry
+        .read_to_end(&mut image)
+        .map_err(|error| invalid(&error))?;
+    Ok(image)
+}
+
+fn novelai_error(status: StatusCode, body: &str, action: &str) -> DomainError {
+    let message = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|json| {
+            json.get("message")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| body.trim().to_string());
+    let message = format!("NovelAI {action} failed: HTTP {status} {message}");
+
+    match status {
+        StatusCode::BAD_REQUEST => DomainError::InvalidData(message),
+        StatusCode::UNAUTHORIZED | StatusCode::PAYMENT_REQUIRED | StatusCode::FORBIDDEN => {
+            DomainError::AuthenticationError(message)
+        }
+        StatusCode::TOO_MANY_REQUESTS => DomainError::RateLimited { message },
+        _ => DomainError::InternalError(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use zip::write::SimpleFileOptions;
+
+    use super::first_zip_entry;
+
+    #[test]
+    fn first_zip_entry_returns_image_bytes() {
+        let mut archive = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        archive
+            .start_file("image_0.png", SimpleFileOptions::default())
+            .expect("start file");
+        archive.write_all(b"png-bytes").expect("write");
+        let archive = archive.finish().expect("finish").into_inner();
+
+        assert_eq!(first_zip_entry(&archive).expect("entry"), b"png-bytes");
+        assert!(first_zip_entry(b"not a zip").is_err());
+    }
+}
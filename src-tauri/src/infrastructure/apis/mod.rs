@@ -1,10 +1,15 @@
 pub mod endpoint_url;
 pub mod github_update_repository;
 pub mod http_chat_completion_repository;
+pub mod http_expression_classifier_repository;
+pub mod http_horde_repository;
+pub mod http_koboldcpp_repository;
+pub mod http_novelai_repository;
 pub mod http_provider_metadata_repository;
 pub mod http_stable_diffusion_repository;
 pub mod http_translate_repository;
 pub mod http_tts_repository;
 pub mod miktik_tokenizer_repository;
+pub(crate) mod sse_data_decoder;
 pub mod workers_ai_endpoint;
 pub mod workers_ai_models;
@@ -0,0 +1,70 @@
+/// Incremental decoder for providers whose server-sent events carry one JSON payload in
+/// `data`; other fields (`event`, `id`) are dropped.
+#[derive(Default)]
+pub(crate) struct SseDataDecoder {
+    buffer: Vec<u8>,
+    data: Vec<String>,
+}
+
+impl SseDataDecoder {
+    /// Feeds a body chunk and returns the events it completed.
+    pub(crate) fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = Vec::new();
+        while let Some(newline) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let line = self.buffer.drain(..=newline).collect::<Vec<_>>();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            if line.is_empty() {
+                if let Some(event) = self.take_event() {
+                    events.push(event);
+                }
+                continue;
+            }
+
+            if let Some(data) = line.strip_prefix("data:") {
+                self.data
+                    .push(data.strip_prefix(' ').unwrap_or(data).to_string());
+            }
+        }
+
+        events
+    }
+
+    /// Flushes an event left open when the body ended without a blank line.
+    pub(crate) fn finish(&mut self) -> Option<String> {
+        if !self.buffer.is_empty() {
+            self.push(b"\n");
+        }
+        self.take_event()
+    }
+
+    fn take_event(&mut self) -> Option<String> {
+        if self.data.is_empty() {
+            return None;
+        }
+        Some(std::mem::take(&mut self.data).join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SseDataDecoder;
+
+    #[test]
+    fn sse_decoder_emits_data_across_chunk_boundaries() {
+        let mut decoder = SseDataDecoder::default();
+
+        let first = decoder.push(b"event: newtoken\nid: 1\ndata: {\"token\":\"He\",");
+        assert!(first.is_empty());
+
+        let second =
+            decoder.push(b"\"final\":false}\r\n\r\nevent: newtoken\ndata: {\"token\":\"llo\"}\n");
+        assert_eq!(second, vec![r#"{"token":"He","final":false}"#.to_string()]);
+
+        assert_eq!(decoder.finish().as_deref(), Some(r#"{"token":"llo"}"#));
+        assert_eq!(decoder.finish(), None);
+    }
+}
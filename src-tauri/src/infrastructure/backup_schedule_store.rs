@@ -0,0 +1,247 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::backup_schedule::{
+    BACKUP_SCHEDULE_MAX_KEEP_LAST, BACKUP_SCHEDULE_MIN_KEEP_LAST, BackupScheduleConfig,
+};
+use crate::infrastructure::persistence::file_system::{read_json_file, write_json_file};
+
+const BACKUP_FILE_PREFIX: &str = "tauritavern-auto-backup-";
+const BACKUP_FILE_SUFFIX: &str = ".zip";
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+pub struct BackupScheduleStore {
+    config_path: PathBuf,
+}
+
+impl BackupScheduleStore {
+    pub fn new(default_user_dir: PathBuf) -> Self {
+        Self {
+            config_path: default_user_dir.join("user").join("backup-schedule.json"),
+        }
+    }
+
+    pub async fn load_or_create_config(&self) -> Result<BackupScheduleConfig, DomainError> {
+        if self.config_path.is_file() {
+            return read_json_file(&self.config_path).await;
+        }
+
+        let config = BackupScheduleConfig::default();
+        write_json_file(&self.config_path, &config).await?;
+        Ok(config)
+    }
+
+    pub async fn save_config(&self, config: &BackupScheduleConfig) -> Result<(), DomainError> {
+        write_json_file(&self.config_path, config).await
+    }
+}
+
+/// Validates the config and returns the backup directory when one is set. Backups must
+/// not land inside the data root, or every archive would contain the previous ones.
+pub fn validate_config(
+    config: &BackupScheduleConfig,
+    data_root: &Path,
+) -> Result<Option<PathBuf>, DomainError> {
+    if !(BACKUP_SCHEDULE_MIN_KEEP_LAST..=BACKUP_SCHEDULE_MAX_KEEP_LAST).contains(&config.keep_last)
+    {
+        return Err(DomainError::InvalidData(format!(
+            "Backups to keep must be between {} and {}",
+            BACKUP_SCHEDULE_MIN_KEEP_LAST, BACKUP_SCHEDULE_MAX_KEEP_LAST
+        )));
+    }
+
+    let directory = config
+        .directory
+        .as_deref()
+        .map(str::trim)
+        .filter(|directory| !directory.is_empty())
+        .map(PathBuf::from);
+    let Some(directory) = directory else {
+        if config.enabled {
+            return Err(DomainError::InvalidData(
+                "Backup directory is required when scheduled backups are enabled".to_string(),
+            ));
+        }
+        return Ok(None);
+    };
+
+    if !directory.is_absolute() {
+        return Err(DomainError::InvalidData(
+            "Backup directory must be an absolute path".to_string(),
+        ));
+    }
+    let resolved_data_root =
+        fs::canonicalize(data_root).unwrap_or_else(|_| data_root.to_path_buf());
+    let resolved_directory = fs::canonicalize(&directory).unwrap_or_else(|_| directory.clone());
+    if resolved_directory.starts_with(&resolved_data_root) {
+        return Err(DomainError::InvalidData(
+            "Backup directory must be outside the TauriTavern data directory".to_string(),
+        ));
+    }
+
+    Ok(Some(directory))
+}
+
+pub fn scheduled_backup_file_name(now: DateTime<Utc>) -> String {
+    format!(
+        "{BACKUP_FILE_PREFIX}{}{BACKUP_FILE_SUFFIX}",
+        now.format(BACKUP_TIMESTAMP_FORMAT)
+    )
+}
+
+fn scheduled_backup_timestamp(file_name: &str) -> Option<DateTime<Utc>> {
+    let timestamp = file_name
+        .strip_prefix(BACKUP_FILE_PREFIX)?
+        .strip_suffix(BACKUP_FILE_SUFFIX)?;
+    NaiveDateTime::parse_from_str(timestamp, BACKUP_TIMESTAMP_FORMAT)
+        .ok()
+        .map(|timestamp| timestamp.and_utc())
+}
+
+/// Scheduled backups in `directory`, newest first. Other files are ignored.
+pub fn list_scheduled_backups(
+    directory: &Path,
+) -> Result<Vec<(DateTime<Utc>, PathBuf)>, DomainError> {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => {
+            return Err(DomainError::InternalError(format!(
+                "Failed to read backup directory {:?}: {}",
+                directory, error
+            )));
+        }
+    };
+
+    let mut backups: Vec<(DateTime<Utc>, PathBuf)> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_file()))
+        .filter_map(|entry| {
+            let timestamp = scheduled_backup_timestamp(entry.file_name().to_str()?)?;
+            Some((timestamp, entry.path()))
+        })
+        .collect();
+    backups.sort_by(|left, right| right.0.cmp(&left.0));
+    Ok(backups)
+}
+
+/// Deletes all but the newest `keep_last` scheduled backups; returns how many were removed.
+pub fn prune_scheduled_backups(directory: &Path, keep_last: u16) -> Result<usize, DomainError> {
+    let mut removed = 0;
+    for (_, path) in list_scheduled_backups(directory)?
+        .into_iter()
+        .skip(usize::from(keep_last))
+    {
+        match fs::remove_file(&path) {
+            Ok(()) => removed += 1,
+            Err(error) => {
+                tracing::warn!(
+                    "Failed to remove old scheduled backup {:?}: {}",
+                    path,
+                    error
+                )
+            }
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use chrono::{TimeZone, Utc};
+
+    use super::{
+        list_scheduled_backups, prune_scheduled_backups, scheduled_backup_file_name,
+        validate_config,
+    };
+    use crate::domain::models::backup_schedule::BackupScheduleConfig;
+
+    fn temp_dir(label: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "tauritavern-backup-schedule-{label}-{}",
+            uuid::Uuid::new_v4()
+        ))
+    }
+
+    #[test]
+    fn prune_keeps_newest_backups_and_ignores_other_files() {
+        let directory = temp_dir("prune");
+        fs::create_dir_all(&directory).expect("create backup dir");
+        for day in 1..=4 {
+            let timestamp = Utc.with_ymd_and_hms(2026, 10, day, 3, 0, 0).unwrap();
+            fs::write(
+                directory.join(scheduled_backup_file_name(timestamp)),
+                b"zip",
+            )
+            .expect("write backup");
+        }
+        fs::write(
+            directory.join("tauritavern-data-20261001-030000.zip"),
+            b"zip",
+        )
+        .expect("write manual export");
+
+        assert_eq!(prune_scheduled_backups(&directory, 2).expect("prune"), 2);
+
+        let remaining: Vec<String> = list_scheduled_backups(&directory)
+            .expect("list")
+            .into_iter()
+            .map(|(_, path)| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            remaining,
+            vec![
+                "tauritavern-auto-backup-20261004-030000.zip",
+                "tauritavern-auto-backup-20261003-030000.zip",
+            ]
+        );
+        assert!(
+            directory
+                .join("tauritavern-data-20261001-030000.zip")
+                .is_file()
+        );
+
+        let _ = fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn validation_requires_absolute_directory_outside_data_root() {
+        let data_root = temp_dir("data-root");
+        let enabled = |directory: Option<String>| BackupScheduleConfig {
+            enabled: true,
+            directory,
+            ..BackupScheduleConfig::default()
+        };
+
+        assert!(validate_config(&enabled(None), &data_root).is_err());
+        assert!(validate_config(&enabled(Some("backups".to_string())), &data_root).is_err());
+        assert!(
+            validate_config(
+                &enabled(Some(
+                    data_root.join("backups").to_string_lossy().to_string()
+                )),
+                &data_root
+            )
+            .is_err()
+        );
+
+        let outside = temp_dir("outside");
+        assert_eq!(
+            validate_config(
+                &enabled(Some(outside.to_string_lossy().to_string())),
+                &data_root
+            )
+            .expect("valid directory"),
+            Some(outside)
+        );
+        assert_eq!(
+            validate_config(&BackupScheduleConfig::default(), &data_root).expect("disabled"),
+            None
+        );
+    }
+}
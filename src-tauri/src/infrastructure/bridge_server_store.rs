@@ -0,0 +1,122 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use url::Url;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::bridge_server::{
+    BRIDGE_SERVER_MIN_AUTH_TOKEN_LEN, BridgePairedDevice, BridgeServerConfig,
+    DEFAULT_BRIDGE_SERVER_BIND_ADDRESS,
+};
+use crate::infrastructure::lan_sync::crypto::random_base64url;
+use crate::infrastructure::persistence::file_system::{read_json_file, write_json_file};
+
+const AUTH_TOKEN_BYTES: usize = 24;
+
+pub struct BridgeServerStore {
+    bridge_server_dir: PathBuf,
+}
+
+impl BridgeServerStore {
+    pub fn new(default_user_dir: PathBuf) -> Self {
+        Self {
+            bridge_server_dir: default_user_dir.join("user").join("bridge-server"),
+        }
+    }
+
+    fn config_path(&self) -> PathBuf {
+        self.bridge_server_dir.join("config.json")
+    }
+
+    fn paired_devices_path(&self) -> PathBuf {
+        self.bridge_server_dir.join("paired-devices.json")
+    }
+
+    /// Directory of the self-signed certificate the server is served with.
+    pub fn tls_dir(&self) -> PathBuf {
+        self.bridge_server_dir.join("tls")
+    }
+
+    pub async fn load_or_create_config(&self) -> Result<BridgeServerConfig, DomainError> {
+        let path = self.config_path();
+        if path.is_file() {
+            let config = read_json_file(&path).await?;
+            validate_config(&config)?;
+            return Ok(config);
+        }
+
+        let config = BridgeServerConfig {
+            enabled: false,
+            bind_address: DEFAULT_BRIDGE_SERVER_BIND_ADDRESS.to_string(),
+            auth_token: generate_auth_token(),
+            allowed_origins: Vec::new(),
+        };
+        write_json_file(&path, &config).await?;
+        Ok(config)
+    }
+
+    pub async fn save_config(&self, config: &BridgeServerConfig) -> Result<(), DomainError> {
+        validate_config(config)?;
+        write_json_file(&self.config_path(), config).await
+    }
+
+    pub async fn load_paired_devices(&self) -> Result<Vec<BridgePairedDevice>, DomainError> {
+        let path = self.paired_devices_path();
+        if !path.is_file() {
+            return Ok(Vec::new());
+        }
+        read_json_file(&path).await
+    }
+
+    pub async fn save_paired_devices(
+        &self,
+        devices: &[BridgePairedDevice],
+    ) -> Result<(), DomainError> {
+        write_json_file(&self.paired_devices_path(), devices).await
+    }
+}
+
+pub fn generate_auth_token() -> String {
+    random_base64url(AUTH_TOKEN_BYTES)
+}
+
+pub fn parse_bind_address(bind_address: &str) -> Result<SocketAddr, DomainError> {
+    bind_address.trim().parse().map_err(|_| {
+        DomainError::InvalidData(format!(
+            "Invalid bridge server bind address: {}",
+            bind_address
+        ))
+    })
+}
+
+pub fn validate_config(config: &BridgeServerConfig) -> Result<(), DomainError> {
+    parse_bind_address(&config.bind_address)?;
+
+    if config.auth_token.trim().len() < BRIDGE_SERVER_MIN_AUTH_TOKEN_LEN {
+        return Err(DomainError::InvalidData(format!(
+            "Bridge server auth token must be at least {} characters",
+            BRIDGE_SERVER_MIN_AUTH_TOKEN_LEN
+        )));
+    }
+
+    for origin in &config.allowed_origins {
+        if normalize_origin(origin).as_deref() != Some(origin.as_str()) {
+            return Err(DomainError::InvalidData(format!(
+                "Invalid bridge server allowed origin: {}",
+                origin
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// `scheme://host[:port]` form of an `http(s)` origin, as browsers send it in the
+/// `Origin` header.
+pub fn normalize_origin(origin: &str) -> Option<String> {
+    let url = Url::parse(origin.trim()).ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    Some(url.origin().ascii_serialization())
+}
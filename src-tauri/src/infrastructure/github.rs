@@ -1,4 +1,6 @@
+use chrono::{DateTime, Duration, Utc};
 use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
 use serde::Deserialize;
 
 use crate::domain::errors::DomainError;
@@ -7,6 +9,62 @@ pub const GITHUB_RATE_LIMIT_MESSAGE: &str = "GitHub has rate-limited your reques
 
 const GITHUB_RATE_LIMIT_TOKENS: [&str; 2] = ["rate limit", "abuse detection"];
 
+/// Fallback back-off when GitHub rate-limits a request without reset headers.
+const GITHUB_RATE_LIMIT_FALLBACK_SECONDS: i64 = 60;
+
+/// Rate-limit state reported by the `x-ratelimit-*` / `retry-after` response headers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GithubRateLimit {
+    pub remaining: Option<u64>,
+    pub reset_at: Option<DateTime<Utc>>,
+}
+
+impl GithubRateLimit {
+    pub fn from_headers(headers: &HeaderMap, now: DateTime<Utc>) -> Self {
+        let header_number = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<i64>().ok())
+        };
+
+        let remaining =
+            header_number("x-ratelimit-remaining").and_then(|value| u64::try_from(value).ok());
+        let reset_at = header_number("x-ratelimit-reset")
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0));
+        let retry_at = header_number("retry-after").map(|seconds| now + Duration::seconds(seconds));
+
+        Self {
+            remaining,
+            reset_at: reset_at.max(retry_at),
+        }
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    /// When to retry; falls back to a short fixed delay when GitHub sent no reset time.
+    pub fn retry_at(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        self.reset_at
+            .filter(|reset_at| *reset_at > now)
+            .unwrap_or_else(|| now + Duration::seconds(GITHUB_RATE_LIMIT_FALLBACK_SECONDS))
+    }
+}
+
+pub fn github_rate_limited_until_message(until: DateTime<Utc>, authenticated: bool) -> String {
+    let hint = if authenticated {
+        ""
+    } else {
+        " Add a GitHub token (github_token) in API connections to raise the limit."
+    };
+    format!(
+        "GitHub has rate-limited your requests until {}.{}",
+        until.format("%Y-%m-%d %H:%M:%S UTC"),
+        hint
+    )
+}
+
 #[derive(Debug, Deserialize)]
 struct GithubApiErrorResponse {
     message: Option<String>,
@@ -41,9 +99,40 @@ pub fn classify_github_rate_limit(status: StatusCode, body: &str) -> Option<Doma
 
 #[cfg(test)]
 mod tests {
-    use super::{GITHUB_RATE_LIMIT_MESSAGE, classify_github_rate_limit};
+    use super::{
+        GITHUB_RATE_LIMIT_MESSAGE, GithubRateLimit, classify_github_rate_limit,
+        github_rate_limited_until_message,
+    };
     use crate::domain::errors::DomainError;
+    use chrono::{DateTime, Duration};
     use reqwest::StatusCode;
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    #[test]
+    fn reads_rate_limit_headers_and_prefers_later_retry_time() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).expect("valid timestamp");
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("1700000030"));
+        headers.insert("retry-after", HeaderValue::from_static("90"));
+
+        let rate_limit = GithubRateLimit::from_headers(&headers, now);
+        assert!(rate_limit.is_exhausted());
+        assert_eq!(rate_limit.retry_at(now), now + Duration::seconds(90));
+        assert!(
+            github_rate_limited_until_message(rate_limit.retry_at(now), false)
+                .contains("until 2023-11-14 22:14:50 UTC")
+        );
+    }
+
+    #[test]
+    fn missing_rate_limit_headers_fall_back_to_short_delay() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).expect("valid timestamp");
+        let rate_limit = GithubRateLimit::from_headers(&HeaderMap::new(), now);
+
+        assert_eq!(rate_limit, GithubRateLimit::default());
+        assert_eq!(rate_limit.retry_at(now), now + Duration::seconds(60));
+    }
 
     #[test]
     fn classifies_primary_github_rate_limit_as_domain_rate_limit() {
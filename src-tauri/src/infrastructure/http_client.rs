@@ -1,5 +1,7 @@
 use reqwest::{Client, ClientBuilder, Error};
 
+use crate::infrastructure::http_client_tuning::HttpClientTuning;
+
 /// Keep a stable product token so upstream API gateways can whitelist requests.
 pub const APP_USER_AGENT: &str = concat!("TauriTavern/", env!("CARGO_PKG_VERSION"));
 
@@ -8,10 +10,18 @@ pub fn apply_default_user_agent(builder: ClientBuilder) -> ClientBuilder {
 }
 
 #[cfg(target_os = "android")]
-fn apply_android_tls(builder: ClientBuilder) -> ClientBuilder {
-    let root_store = rustls::RootCertStore {
+fn apply_android_tls(builder: ClientBuilder, extra_roots_pem: Option<&[u8]>) -> ClientBuilder {
+    use rustls::pki_types::CertificateDer;
+    use rustls::pki_types::pem::PemObject;
+
+    let mut root_store = rustls::RootCertStore {
         roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
     };
+    if let Some(pem) = extra_roots_pem {
+        for certificate in CertificateDer::pem_slice_iter(pem).flatten() {
+            let _ = root_store.add(certificate);
+        }
+    }
 
     let mut tls_config = rustls::ClientConfig::builder()
         .with_root_certificates(root_store)
@@ -22,10 +32,24 @@ fn apply_android_tls(builder: ClientBuilder) -> ClientBuilder {
     builder.use_preconfigured_tls(tls_config)
 }
 
-pub fn build_http_client(builder: ClientBuilder) -> Result<Client, Error> {
+/// Builds a client with the app user agent and the shared tuning. `accept_invalid_certs`
+/// disables certificate verification entirely and must only reflect an explicit opt-in.
+pub fn build_http_client(
+    builder: ClientBuilder,
+    tuning: Option<&HttpClientTuning>,
+    accept_invalid_certs: bool,
+) -> Result<Client, Error> {
     let builder = apply_default_user_agent(builder);
+    let builder = match tuning {
+        Some(tuning) => tuning.apply(builder),
+        None => builder,
+    };
+    if accept_invalid_certs {
+        tracing::warn!("Building an HTTP client with TLS certificate verification disabled");
+        return builder.danger_accept_invalid_certs(true).build();
+    }
     #[cfg(target_os = "android")]
-    let builder = apply_android_tls(builder);
+    let builder = apply_android_tls(builder, tuning.and_then(HttpClientTuning::ca_bundle_pem));
     builder.build()
 }
 
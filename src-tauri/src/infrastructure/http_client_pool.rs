@@ -1,14 +1,15 @@
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 use reqwest::redirect::Policy;
 use reqwest::{Client, NoProxy, Proxy};
 
 use crate::domain::errors::DomainError;
-use crate::domain::models::settings::RequestProxySettings;
+use crate::domain::models::settings::{HttpClientTuningSettings, RequestProxySettings};
 use crate::infrastructure::http_client::build_http_client;
+use crate::infrastructure::http_client_tuning::HttpClientTuning;
 
 pub const CHAT_COMPLETION_CONNECT_TIMEOUT: Duration = Duration::from_secs(3 * 60);
 pub const CHAT_COMPLETION_NON_STREAM_REQUEST_TIMEOUT: Duration = Duration::from_secs(10 * 60);
@@ -36,11 +37,34 @@ pub enum HttpClientProfile {
     Tts,
 }
 
+/// Caller-supplied overrides of a profile's timeouts. Clients are cached per distinct
+/// set, so callers should pass stable values (e.g. from settings) rather than ad-hoc ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HttpClientTimeouts {
+    pub connect: Duration,
+    /// Whole-request timeout; `None` keeps the profile's.
+    pub request: Option<Duration>,
+    /// Per-read timeout, i.e. the longest idle gap while reading the body.
+    pub read: Option<Duration>,
+    pub tcp_keepalive: Option<Duration>,
+}
+
+/// Per-caller client settings layered over a profile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct HttpClientOptions {
+    pub timeouts: Option<HttpClientTimeouts>,
+    /// Skip TLS certificate verification; only for backends the user explicitly opted in.
+    pub accept_invalid_certs: bool,
+}
+
+type HttpClientKey = (HttpClientProfile, HttpClientOptions);
+
 #[derive(Default)]
 struct HttpClientPoolState {
     revision: u64,
     proxy: Option<Proxy>,
-    clients: HashMap<HttpClientProfile, Client>,
+    tuning: Option<Arc<HttpClientTuning>>,
+    clients: HashMap<HttpClientKey, Client>,
 }
 
 pub struct HttpClientPool {
@@ -80,6 +104,32 @@ impl HttpClientPool {
         Ok(())
     }
 
+    pub fn validate_http_client_tuning_settings(
+        settings: &HttpClientTuningSettings,
+    ) -> Result<(), DomainError> {
+        let _ = HttpClientTuning::from_settings(settings)?;
+        Ok(())
+    }
+
+    pub fn apply_http_client_tuning_settings(
+        &self,
+        settings: &HttpClientTuningSettings,
+    ) -> Result<(), DomainError> {
+        let tuning = HttpClientTuning::from_settings(settings)?.map(Arc::new);
+        if !settings.insecure_tls_sources.is_empty() {
+            tracing::warn!(
+                sources = ?settings.insecure_tls_sources,
+                "TLS certificate verification is DISABLED for these chat completion sources",
+            );
+        }
+
+        let mut state = self.state.write().unwrap();
+        state.tuning = tuning;
+        state.clients.clear();
+        state.revision += 1;
+        Ok(())
+    }
+
     pub fn client(&self, profile: HttpClientProfile) -> Result<Client, DomainError> {
         self.client_with_revision(profile)
             .map(|(client, _revision)| client)
@@ -89,24 +139,34 @@ impl HttpClientPool {
         &self,
         profile: HttpClientProfile,
     ) -> Result<(Client, u64), DomainError> {
+        self.client_with_options(profile, HttpClientOptions::default())
+    }
+
+    /// Like `client_with_revision`, with `options` layered over the profile's defaults.
+    pub(crate) fn client_with_options(
+        &self,
+        profile: HttpClientProfile,
+        options: HttpClientOptions,
+    ) -> Result<(Client, u64), DomainError> {
+        let key = (profile, options);
         loop {
-            let (revision, proxy) = {
+            let (revision, proxy, tuning) = {
                 let state = self.state.read().unwrap();
-                if let Some(client) = state.clients.get(&profile) {
+                if let Some(client) = state.clients.get(&key) {
                     return Ok((client.clone(), state.revision));
                 }
 
-                (state.revision, state.proxy.clone())
+                (state.revision, state.proxy.clone(), state.tuning.clone())
             };
 
-            let client = build_profile_client(profile, proxy)?;
+            let client = build_profile_client(profile, options, proxy, tuning.as_deref())?;
 
             let mut state = self.state.write().unwrap();
             if state.revision != revision {
                 continue;
             }
 
-            match state.clients.entry(profile) {
+            match state.clients.entry(key) {
                 Entry::Occupied(entry) => return Ok((entry.get().clone(), state.revision)),
                 Entry::Vacant(entry) => {
                     entry.insert(client.clone());
@@ -151,7 +211,9 @@ fn normalized_bypass_csv(entries: &[String]) -> String {
 
 fn build_profile_client(
     profile: HttpClientProfile,
+    options: HttpClientOptions,
     proxy: Option<Proxy>,
+    tuning: Option<&HttpClientTuning>,
 ) -> Result<Client, DomainError> {
     let mut builder = Client::builder().no_proxy();
 
@@ -184,19 +246,34 @@ fn build_profile_client(
             .timeout(TTS_REQUEST_TIMEOUT),
     };
 
+    if let Some(timeouts) = options.timeouts {
+        builder = builder.connect_timeout(timeouts.connect);
+        if let Some(request) = timeouts.request {
+            builder = builder.timeout(request);
+        }
+        if let Some(read) = timeouts.read {
+            builder = builder.read_timeout(read);
+        }
+        if let Some(tcp_keepalive) = timeouts.tcp_keepalive {
+            builder = builder.tcp_keepalive(tcp_keepalive);
+        }
+    }
+
     if let Some(proxy) = proxy {
         builder = builder.proxy(proxy);
     }
 
-    build_http_client(builder).map_err(|error| {
+    build_http_client(builder, tuning, options.accept_invalid_certs).map_err(|error| {
         DomainError::InternalError(format!("Failed to build HTTP client: {error}"))
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{HttpClientPool, HttpClientProfile};
-    use crate::domain::models::settings::RequestProxySettings;
+    use std::time::Duration;
+
+    use super::{HttpClientOptions, HttpClientPool, HttpClientProfile, HttpClientTimeouts};
+    use crate::domain::models::settings::{HttpClientTuningSettings, RequestProxySettings};
 
     #[test]
     fn disabled_proxy_is_valid() {
@@ -257,6 +334,33 @@ mod tests {
         assert_eq!(pool.state.read().unwrap().clients.len(), 2);
     }
 
+    #[test]
+    fn clients_are_cached_per_options() {
+        let pool = HttpClientPool::new();
+        let options = HttpClientOptions {
+            timeouts: Some(HttpClientTimeouts {
+                connect: Duration::from_secs(30),
+                request: Some(Duration::from_secs(3600)),
+                read: None,
+                tcp_keepalive: Some(Duration::from_secs(60)),
+            }),
+            accept_invalid_certs: false,
+        };
+        let insecure = HttpClientOptions {
+            accept_invalid_certs: true,
+            ..options
+        };
+
+        pool.client(HttpClientProfile::ChatCompletion).unwrap();
+        pool.client_with_options(HttpClientProfile::ChatCompletion, options)
+            .unwrap();
+        pool.client_with_options(HttpClientProfile::ChatCompletion, options)
+            .unwrap();
+        pool.client_with_options(HttpClientProfile::ChatCompletion, insecure)
+            .unwrap();
+        assert_eq!(pool.state.read().unwrap().clients.len(), 3);
+    }
+
     #[test]
     fn apply_clears_cached_clients() {
         let pool = HttpClientPool::new();
@@ -291,6 +395,26 @@ mod tests {
         assert_eq!(next_revision, initial_revision + 1);
     }
 
+    #[test]
+    fn apply_tuning_rebuilds_clients() {
+        let pool = HttpClientPool::new();
+        pool.client(HttpClientProfile::Default).unwrap();
+
+        let settings = HttpClientTuningSettings {
+            http1_only: true,
+            ipv4_only: true,
+            ..Default::default()
+        };
+        pool.apply_http_client_tuning_settings(&settings).unwrap();
+        assert!(pool.state.read().unwrap().tuning.is_some());
+        assert_eq!(pool.state.read().unwrap().clients.len(), 0);
+
+        pool.client(HttpClientProfile::Default).unwrap();
+        pool.apply_http_client_tuning_settings(&HttpClientTuningSettings::default())
+            .unwrap();
+        assert!(pool.state.read().unwrap().tuning.is_none());
+    }
+
     #[test]
     fn apply_sets_and_clears_proxy() {
         let pool = HttpClientPool::new();
@@ -0,0 +1,359 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::header::ACCEPT;
+use reqwest::{Certificate, Client, ClientBuilder, Url};
+use serde::Deserialize;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::settings::HttpClientTuningSettings;
+use crate::infrastructure::http_client::apply_default_user_agent;
+
+const DOH_TIMEOUT: Duration = Duration::from_secs(10);
+const DNS_CACHE_MIN_TTL: Duration = Duration::from_secs(30);
+const DNS_CACHE_MAX_TTL: Duration = Duration::from_secs(60 * 60);
+const DNS_RECORD_A: u16 = 1;
+const DNS_RECORD_AAAA: u16 = 28;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Parsed form of [`HttpClientTuningSettings`], applied to every pooled client.
+pub struct HttpClientTuning {
+    http1_only: bool,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    overrides: Vec<(String, SocketAddr)>,
+    resolver: Option<Arc<TunedDnsResolver>>,
+    ca_bundle_pem: Option<Vec<u8>>,
+    ca_certificates: Vec<Certificate>,
+}
+
+impl HttpClientTuning {
+    /// Returns `None` when every setting is at its default, so clients stay untouched.
+    pub fn from_settings(settings: &HttpClientTuningSettings) -> Result<Option<Self>, DomainError> {
+        if *settings == HttpClientTuningSettings::default() {
+            return Ok(None);
+        }
+
+        let mut overrides = Vec::with_capacity(settings.dns_overrides.len());
+        for (host, address) in &settings.dns_overrides {
+            let host = host.trim();
+            if host.is_empty() {
+                return Err(DomainError::InvalidData(
+                    "DNS override host is required".to_string(),
+                ));
+            }
+            let ip = address.trim().parse::<IpAddr>().map_err(|_| {
+                DomainError::InvalidData(format!(
+                    "Invalid DNS override address for {host}: {address}"
+                ))
+            })?;
+            overrides.push((host.to_ascii_lowercase(), SocketAddr::new(ip, 0)));
+        }
+
+        let doh_url = settings.dns_over_https_url.trim();
+        let doh = if doh_url.is_empty() {
+            None
+        } else {
+            Some(DohClient::new(doh_url)?)
+        };
+        let resolver = (doh.is_some() || settings.ipv4_only).then(|| {
+            Arc::new(TunedDnsResolver(Arc::new(DnsLookupState {
+                doh,
+                ipv4_only: settings.ipv4_only,
+                cache: Mutex::new(HashMap::new()),
+            })))
+        });
+
+        let ca_bundle_path = settings.ca_bundle_path.trim();
+        let (ca_bundle_pem, ca_certificates) = if ca_bundle_path.is_empty() {
+            (None, Vec::new())
+        } else {
+            let (pem, certificates) = load_ca_bundle(ca_bundle_path)?;
+            (Some(pem), certificates)
+        };
+
+        Ok(Some(Self {
+            http1_only: settings.http1_only,
+            pool_idle_timeout: (settings.pool_idle_timeout_secs > 0)
+                .then(|| Duration::from_secs(settings.pool_idle_timeout_secs)),
+            pool_max_idle_per_host: settings.pool_max_idle_per_host,
+            overrides,
+            resolver,
+            ca_bundle_pem,
+            ca_certificates,
+        }))
+    }
+
+    /// Raw PEM of the custom CA bundle, for TLS stacks configured outside reqwest.
+    pub fn ca_bundle_pem(&self) -> Option<&[u8]> {
+        self.ca_bundle_pem.as_deref()
+    }
+
+    pub fn apply(&self, mut builder: ClientBuilder) -> ClientBuilder {
+        if self.http1_only {
+            builder = builder.http1_only();
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(max_idle) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(resolver) = &self.resolver {
+            builder = builder.dns_resolver(resolver.clone());
+        }
+        for (host, address) in &self.overrides {
+            builder = builder.resolve(host, *address);
+        }
+        for certificate in &self.ca_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        builder
+    }
+}
+
+fn load_ca_bundle(path: &str) -> Result<(Vec<u8>, Vec<Certificate>), DomainError> {
+    let pem = std::fs::read(path).map_err(|error| {
+        DomainError::InvalidData(format!("Failed to read CA bundle {path}: {error}"))
+    })?;
+    let certificates = Certificate::from_pem_bundle(&pem)
+        .map_err(|error| DomainError::InvalidData(format!("Invalid CA bundle {path}: {error}")))?;
+    if certificates.is_empty() {
+        return Err(DomainError::InvalidData(format!(
+            "CA bundle {path} contains no certificates"
+        )));
+    }
+    Ok((pem, certificates))
+}
+
+#[derive(Deserialize)]
+struct DohResponse {
+    #[serde(rename = "Status")]
+    status: u32,
+    #[serde(rename = "Answer", default)]
+    answer: Vec<DohAnswer>,
+}
+
+#[derive(Deserialize)]
+struct DohAnswer {
+    #[serde(rename = "type")]
+    record_type: u16,
+    #[serde(rename = "TTL", default)]
+    ttl: u64,
+    data: String,
+}
+
+/// JSON DNS-over-HTTPS client. Lookups go direct through the system resolver, so the
+/// endpoint itself must stay reachable without the request proxy.
+struct DohClient {
+    url: Url,
+    client: Client,
+}
+
+impl DohClient {
+    fn new(url: &str) -> Result<Self, DomainError> {
+        let url = Url::parse(url).map_err(|error| {
+            DomainError::InvalidData(format!("Invalid DNS-over-HTTPS URL: {error}"))
+        })?;
+        if url.scheme() != "https" {
+            return Err(DomainError::InvalidData(
+                "DNS-over-HTTPS URL must use https".to_string(),
+            ));
+        }
+
+        let builder = Client::builder()
+            .no_proxy()
+            .connect_timeout(DOH_TIMEOUT)
+            .timeout(DOH_TIMEOUT);
+        let client = apply_default_user_agent(builder).build().map_err(|error| {
+            DomainError::InternalError(format!("Failed to build DNS-over-HTTPS client: {error}"))
+        })?;
+        Ok(Self { url, client })
+    }
+
+    async fn query(&self, host: &str, record_type: u16) -> Result<(Vec<IpAddr>, u64), BoxError> {
+        let response = self
+            .client
+            .get(self.url.clone())
+            .query(&[("name", host), ("type", &record_type.to_string())])
+            .header(ACCEPT, "application/dns-json")
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<DohResponse>()
+            .await?;
+        if response.status != 0 {
+            return Err(format!(
+                "DNS-over-HTTPS lookup for {host} failed: rcode {}",
+                response.status
+            )
+            .into());
+        }
+
+        let mut ttl = u64::MAX;
+        let mut addresses = Vec::new();
+        for answer in response.answer {
+            if answer.record_type != record_type {
+                continue;
+            }
+            if let Ok(ip) = answer.data.parse::<IpAddr>() {
+                ttl = ttl.min(answer.ttl);
+                addresses.push(ip);
+            }
+        }
+        Ok((addresses, ttl))
+    }
+}
+
+struct CachedLookup {
+    expires_at: Instant,
+    addresses: Vec<IpAddr>,
+}
+
+struct DnsLookupState {
+    doh: Option<DohClient>,
+    ipv4_only: bool,
+    cache: Mutex<HashMap<String, CachedLookup>>,
+}
+
+/// Resolver used when DNS-over-HTTPS or IPv4-only is configured. Answers are cached
+/// for their record TTL, clamped to a sane range.
+struct TunedDnsResolver(Arc<DnsLookupState>);
+
+impl DnsLookupState {
+    fn cached(&self, host: &str) -> Option<Vec<IpAddr>> {
+        let cache = self.cache.lock().unwrap();
+        cache
+            .get(host)
+            .filter(|cached| cached.expires_at > Instant::now())
+            .map(|cached| cached.addresses.clone())
+    }
+
+    fn store(&self, host: String, addresses: Vec<IpAddr>, ttl: Duration) {
+        let ttl = ttl.clamp(DNS_CACHE_MIN_TTL, DNS_CACHE_MAX_TTL);
+        let now = Instant::now();
+        let mut cache = self.cache.lock().unwrap();
+        cache.retain(|_, cached| cached.expires_at > now);
+        cache.insert(
+            host,
+            CachedLookup {
+                expires_at: now + ttl,
+                addresses,
+            },
+        );
+    }
+
+    async fn lookup(&self, host: &str) -> Result<(Vec<IpAddr>, Duration), BoxError> {
+        let Some(doh) = &self.doh else {
+            let addresses = tokio::net::lookup_host((host, 0))
+                .await?
+                .map(|address| address.ip())
+                .collect();
+            return Ok((addresses, DNS_CACHE_MIN_TTL));
+        };
+
+        let (mut addresses, mut ttl) = doh.query(host, DNS_RECORD_A).await?;
+        if !self.ipv4_only {
+            let (v6, v6_ttl) = doh.query(host, DNS_RECORD_AAAA).await?;
+            addresses.extend(v6);
+            ttl = ttl.min(v6_ttl);
+        }
+        Ok((addresses, Duration::from_secs(ttl)))
+    }
+}
+
+impl Resolve for TunedDnsResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_ascii_lowercase();
+        let resolver = self.0.clone();
+        Box::pin(async move {
+            let addresses = match resolver.cached(&host) {
+                Some(addresses) => addresses,
+                None => {
+                    let (addresses, ttl) = resolver.lookup(&host).await?;
+                    let addresses = filter_addresses(addresses, resolver.ipv4_only);
+                    if !addresses.is_empty() {
+                        resolver.store(host.clone(), addresses.clone(), ttl);
+                    }
+                    addresses
+                }
+            };
+            if addresses.is_empty() {
+                return Err(format!("No usable address found for {host}").into());
+            }
+
+            let addrs: Addrs = Box::new(
+                addresses
+                    .into_iter()
+                    .map(|ip| SocketAddr::new(ip, 0))
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            );
+            Ok(addrs)
+        })
+    }
+}
+
+fn filter_addresses(addresses: Vec<IpAddr>, ipv4_only: bool) -> Vec<IpAddr> {
+    if !ipv4_only {
+        return addresses;
+    }
+    addresses.into_iter().filter(IpAddr::is_ipv4).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use super::{HttpClientTuning, filter_addresses};
+    use crate::domain::models::settings::HttpClientTuningSettings;
+
+    #[test]
+    fn default_settings_leave_clients_untouched() {
+        let tuning = HttpClientTuning::from_settings(&HttpClientTuningSettings::default())
+            .expect("default settings are valid");
+        assert!(tuning.is_none());
+    }
+
+    #[test]
+    fn invalid_dns_settings_are_rejected() {
+        let mut settings = HttpClientTuningSettings::default();
+        settings
+            .dns_overrides
+            .insert("api.example.com".to_string(), "not-an-ip".to_string());
+        assert!(HttpClientTuning::from_settings(&settings).is_err());
+
+        let settings = HttpClientTuningSettings {
+            dns_over_https_url: "http://dns.example/dns-query".to_string(),
+            ..Default::default()
+        };
+        assert!(HttpClientTuning::from_settings(&settings).is_err());
+    }
+
+    #[test]
+    fn missing_ca_bundle_is_rejected() {
+        let settings = HttpClientTuningSettings {
+            ca_bundle_path: "/nonexistent/tauritavern-ca.pem".to_string(),
+            ..Default::default()
+        };
+        let error = HttpClientTuning::from_settings(&settings)
+            .err()
+            .expect("missing bundle must fail");
+        assert!(error.to_string().contains("Failed to read CA bundle"));
+    }
+
+    #[test]
+    fn ipv4_only_drops_ipv6_addresses() {
+        let addresses: Vec<IpAddr> = vec!["::1".parse().unwrap(), "127.0.0.1".parse().unwrap()];
+
+        assert_eq!(
+            filter_addresses(addresses.clone(), true),
+            vec!["127.0.0.1".parse::<IpAddr>().unwrap()]
+        );
+        assert_eq!(filter_addresses(addresses.clone(), false), addresses);
+    }
+}
@@ -0,0 +1,329 @@
+//! Watches the `characters/` and `chats/` directories for cards and chats added or removed
+//! outside the app (PNG cards dropped into the folder, sync tools) so the in-memory caches
+//! can be invalidated and the frontend told to reload its character list.
+//!
+//! Only membership changes are reported; edits to existing files are picked up by the
+//! caches' own modification checks. Changes made by the app itself are recognized through
+//! [`internal_writes`](crate::infrastructure::persistence::internal_writes).
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::infrastructure::persistence::internal_writes::is_recent_internal_write;
+
+/// Card and chat files that appeared or disappeared since the previous scan
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LibraryChange {
+    /// Avatar file names (`Alice.png`) of cards that appeared
+    pub added_characters: Vec<String>,
+    /// Avatar file names of cards that disappeared
+    pub removed_characters: Vec<String>,
+    /// Chat folders (one per character) whose chat files were added or removed
+    pub chat_folders: Vec<String>,
+}
+
+impl LibraryChange {
+    pub fn is_empty(&self) -> bool {
+        self.added_characters.is_empty()
+            && self.removed_characters.is_empty()
+            && self.chat_folders.is_empty()
+    }
+}
+
+/// Returns the names in `current` missing from `known` and vice versa, sorted, leaving out
+/// files the app changed itself.
+fn external_membership_diff(
+    directory: &Path,
+    known: &HashSet<String>,
+    current: &HashSet<String>,
+) -> (Vec<String>, Vec<String>) {
+    let external = |name: &&String| !is_recent_internal_write(&directory.join(name.as_str()));
+
+    let mut added: Vec<String> = current
+        .difference(known)
+        .filter(external)
+        .cloned()
+        .collect();
+    let mut removed: Vec<String> = known
+        .difference(current)
+        .filter(external)
+        .cloned()
+        .collect();
+    added.sort();
+    removed.sort();
+    (added, removed)
+}
+
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+pub use watcher::{CHARACTERS_CHANGED_EVENT, LibraryWatcher};
+
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+mod watcher {
+    use std::collections::{HashMap, HashSet};
+    use std::path::{Component, Path, PathBuf};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use notify::event::ModifyKind;
+    use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+    use tokio::sync::mpsc;
+
+    use super::{LibraryChange, external_membership_diff};
+    use crate::domain::errors::DomainError;
+
+    pub const CHARACTERS_CHANGED_EVENT: &str = "characters-changed";
+
+    const CHARACTER_EXTENSION: &str = "png";
+    const CHAT_EXTENSION: &str = "jsonl";
+
+    /// Dropping a batch of cards produces a burst of events; wait for it to settle.
+    const DEBOUNCE: Duration = Duration::from_millis(750);
+
+    /// Keeps the underlying OS watcher alive; dropping it stops watching.
+    pub struct LibraryWatcher {
+        _watcher: Mutex<RecommendedWatcher>,
+    }
+
+    impl LibraryWatcher {
+        /// Starts watching and returns the stream of detected changes.
+        pub fn start(
+            characters_dir: &Path,
+            chats_dir: &Path,
+        ) -> Result<(Self, mpsc::UnboundedReceiver<LibraryChange>), DomainError> {
+            // Watch canonical paths so event paths can be matched with `strip_prefix`.
+            let characters_dir = canonical_directory(characters_dir)?;
+            let chats_dir = canonical_directory(chats_dir)?;
+
+            let (event_sender, event_receiver) = mpsc::unbounded_channel::<PathBuf>();
+            let mut watcher =
+                notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+                    match result {
+                        Ok(event) if is_membership_event(&event.kind) => {
+                            for path in event.paths {
+                                let _ = event_sender.send(path);
+                            }
+                        }
+                        Ok(_) => {}
+                        Err(error) => tracing::warn!("Library watcher error: {}", error),
+                    }
+                })
+                .map_err(|error| {
+                    DomainError::InternalError(format!(
+                        "Failed to create library watcher: {}",
+                        error
+                    ))
+                })?;
+
+            for (directory, mode) in [
+                (&characters_dir, RecursiveMode::NonRecursive),
+                (&chats_dir, RecursiveMode::Recursive),
+            ] {
+                watcher.watch(directory, mode).map_err(|error| {
+                    DomainError::InternalError(format!(
+                        "Failed to watch {}: {}",
+                        directory.display(),
+                        error
+                    ))
+                })?;
+            }
+
+            let (change_sender, change_receiver) = mpsc::unbounded_channel();
+            tauri::async_runtime::spawn(track_changes(
+                event_receiver,
+                change_sender,
+                characters_dir,
+                chats_dir,
+            ));
+
+            Ok((
+                Self {
+                    _watcher: Mutex::new(watcher),
+                },
+                change_receiver,
+            ))
+        }
+    }
+
+    fn canonical_directory(path: &Path) -> Result<PathBuf, DomainError> {
+        dunce::canonicalize(path).map_err(|error| {
+            DomainError::InternalError(format!("Failed to resolve {}: {}", path.display(), error))
+        })
+    }
+
+    fn is_membership_event(kind: &EventKind) -> bool {
+        match kind {
+            EventKind::Create(_) | EventKind::Remove(_) => true,
+            EventKind::Modify(ModifyKind::Name(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Last known membership of the watched directories
+    struct LibrarySnapshot {
+        characters_dir: PathBuf,
+        chats_dir: PathBuf,
+        characters: HashSet<String>,
+        chat_folders: HashMap<String, HashSet<String>>,
+    }
+
+    impl LibrarySnapshot {
+        async fn scan(characters_dir: PathBuf, chats_dir: PathBuf) -> Self {
+            let characters = list_file_names(&characters_dir, CHARACTER_EXTENSION).await;
+
+            let mut chat_folders = HashMap::new();
+            if let Ok(mut entries) = tokio::fs::read_dir(&chats_dir).await {
+                while let Ok(Some(entry)) = entries.next_entry().await {
+                    let is_dir = entry
+                        .file_type()
+                        .await
+                        .is_ok_and(|file_type| file_type.is_dir());
+                    if !is_dir {
+                        continue;
+                    }
+                    if let Some(folder) = entry.file_name().to_str() {
+                        let chats = list_file_names(&entry.path(), CHAT_EXTENSION).await;
+                        chat_folders.insert(folder.to_string(), chats);
+                    }
+                }
+            }
+
+            Self {
+                characters_dir,
+                chats_dir,
+                characters,
+                chat_folders,
+            }
+        }
+
+        /// Rescans the directories touched by `paths` and reports external changes.
+        async fn refresh(&mut self, paths: HashSet<PathBuf>) -> LibraryChange {
+            let mut rescan_characters = false;
+            let mut touched_folders = HashSet::new();
+            for path in &paths {
+                if path.parent() == Some(self.characters_dir.as_path()) {
+                    rescan_characters = true;
+                } else if let Some(folder) = chat_folder_name(&self.chats_dir, path) {
+                    touched_folders.insert(folder);
+                }
+            }
+
+            let mut change = LibraryChange::default();
+
+            if rescan_characters {
+                let current = list_file_names(&self.characters_dir, CHARACTER_EXTENSION).await;
+                let (added, removed) =
+                    external_membership_diff(&self.characters_dir, &self.characters, &current);
+                change.added_characters = added;
+                change.removed_characters = removed;
+                self.characters = current;
+            }
+
+            for folder in touched_folders {
+                let folder_dir = self.chats_dir.join(&folder);
+                let current = list_file_names(&folder_dir, CHAT_EXTENSION).await;
+                let known = self.chat_folders.remove(&folder).unwrap_or_default();
+                let (added, removed) = external_membership_diff(&folder_dir, &known, &current);
+                if !added.is_empty() || !removed.is_empty() {
+                    change.chat_folders.push(folder.clone());
+                }
+                if !current.is_empty() {
+                    self.chat_folders.insert(folder, current);
+                }
+            }
+            change.chat_folders.sort();
+
+            change
+        }
+    }
+
+    /// The character folder below `chats/` that `path` belongs to.
+    fn chat_folder_name(chats_dir: &Path, path: &Path) -> Option<String> {
+        match path.strip_prefix(chats_dir).ok()?.components().next()? {
+            Component::Normal(folder) => folder.to_str().map(str::to_string),
+            _ => None,
+        }
+    }
+
+    async fn list_file_names(directory: &Path, extension: &str) -> HashSet<String> {
+        let mut names = HashSet::new();
+        let Ok(mut entries) = tokio::fs::read_dir(directory).await else {
+            return names;
+        };
+
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if !path
+                .extension()
+                .is_some_and(|value| value.eq_ignore_ascii_case(extension))
+            {
+                continue;
+            }
+            if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+                names.insert(name.to_string());
+            }
+        }
+
+        names
+    }
+
+    async fn track_changes(
+        mut events: mpsc::UnboundedReceiver<PathBuf>,
+        changes: mpsc::UnboundedSender<LibraryChange>,
+        characters_dir: PathBuf,
+        chats_dir: PathBuf,
+    ) {
+        let mut snapshot = LibrarySnapshot::scan(characters_dir, chats_dir).await;
+
+        while let Some(first) = events.recv().await {
+            let mut pending = HashSet::from([first]);
+            while let Ok(Some(path)) = tokio::time::timeout(DEBOUNCE, events.recv()).await {
+                pending.insert(path);
+            }
+
+            let change = snapshot.refresh(pending).await;
+            if change.is_empty() {
+                continue;
+            }
+
+            tracing::info!(
+                added = change.added_characters.len(),
+                removed = change.removed_characters.len(),
+                chat_folders = change.chat_folders.len(),
+                "Detected external character library change"
+            );
+            if changes.send(change).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::external_membership_diff;
+    use crate::infrastructure::persistence::internal_writes::record_internal_write;
+
+    fn names(values: &[&str]) -> HashSet<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn membership_diff_ignores_files_written_by_the_app() {
+        let dir = std::env::temp_dir().join(format!("tt-library-watch-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        record_internal_write(&dir.join("Created In App.png"));
+
+        let known = names(&["Alice.png", "Bob.png"]);
+        let current = names(&["Bob.png", "Dropped.png", "Created In App.png"]);
+        let (added, removed) = external_membership_diff(&dir, &known, &current);
+
+        assert_eq!(added, vec!["Dropped.png".to_string()]);
+        assert_eq!(removed, vec!["Alice.png".to_string()]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
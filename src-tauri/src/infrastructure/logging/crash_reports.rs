@@ -0,0 +1,282 @@
+use std::backtrace::Backtrace;
+use std::fs;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::DomainError;
+
+pub const APP_CRASH_DETECTED_EVENT: &str = "app-crash-detected";
+
+const CRASH_REPORTS_DIR_NAME: &str = "crashes";
+const CRASH_REPORT_PREFIX: &str = "crash-";
+/// Ids of reports written since the last launch consumed them, one per line.
+const PENDING_MARKER_FILE_NAME: &str = "pending";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashReport {
+    pub id: String,
+    pub created_at: String,
+    pub message: String,
+    pub location: Option<String>,
+    pub thread: Option<String>,
+    pub backtrace: String,
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+}
+
+impl CrashReport {
+    fn from_panic(info: &PanicHookInfo<'_>) -> Self {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|value| value.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Box<dyn Any>".to_string());
+
+        let now = Utc::now();
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+        Self {
+            id: format!(
+                "{}{}-{}",
+                CRASH_REPORT_PREFIX,
+                now.format("%Y%m%d-%H%M%S"),
+                &suffix[..8]
+            ),
+            created_at: now.to_rfc3339(),
+            message,
+            location: info
+                .location()
+                .map(|location| format!("{}:{}", location.file(), location.line())),
+            thread: std::thread::current().name().map(str::to_string),
+            backtrace: Backtrace::force_capture().to_string(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+        }
+    }
+}
+
+/// Crash reports stored as one JSON file each under `<log_root>/crashes`.
+pub struct CrashReportStore {
+    root: PathBuf,
+}
+
+impl CrashReportStore {
+    pub fn new(log_root: &Path) -> Self {
+        Self {
+            root: log_root.join(CRASH_REPORTS_DIR_NAME),
+        }
+    }
+
+    /// Writes `report` and marks it pending. Runs inside the panic hook, so it sticks to
+    /// blocking std I/O and reports failures to the caller instead of logging.
+    pub fn write(&self, report: &CrashReport) -> std::io::Result<PathBuf> {
+        fs::create_dir_all(&self.root)?;
+
+        let path = self.report_path(&report.id);
+        let json = serde_json::to_vec_pretty(report).map_err(std::io::Error::other)?;
+        fs::write(&path, json)?;
+
+        let marker_path = self.root.join(PENDING_MARKER_FILE_NAME);
+        let mut pending = fs::read_to_string(&marker_path).unwrap_or_default();
+        pending.push_str(&report.id);
+        pending.push('\n');
+        fs::write(marker_path, pending)?;
+
+        Ok(path)
+    }
+
+    /// Returns all stored reports, newest first.
+    pub fn list(&self) -> Result<Vec<CrashReport>, DomainError> {
+        if !self.root.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(&self.root).map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to read crash report directory {}: {}",
+                self.root.display(),
+                error
+            ))
+        })?;
+
+        let mut reports = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_report = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with(CRASH_REPORT_PREFIX) && name.ends_with(".json")
+                });
+            if !is_report {
+                continue;
+            }
+
+            match read_report(&path) {
+                Ok(report) => reports.push(report),
+                Err(error) => tracing::warn!("Skipping unreadable crash report: {}", error),
+            }
+        }
+
+        reports.sort_by(|left, right| right.created_at.cmp(&left.created_at));
+        Ok(reports)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<(), DomainError> {
+        validate_report_id(id)?;
+
+        let path = self.report_path(id);
+        if !path.is_file() {
+            return Err(DomainError::NotFound(format!(
+                "Crash report not found: {id}"
+            )));
+        }
+
+        fs::remove_file(&path).map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to delete crash report {}: {}",
+                path.display(),
+                error
+            ))
+        })
+    }
+
+    /// Returns reports written since the previous call and clears the pending marker.
+    /// Reports deleted in the meantime are skipped.
+    pub fn take_pending(&self) -> Result<Vec<CrashReport>, DomainError> {
+        let marker_path = self.root.join(PENDING_MARKER_FILE_NAME);
+        if !marker_path.is_file() {
+            return Ok(Vec::new());
+        }
+
+        let pending = fs::read_to_string(&marker_path).map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to read crash report marker {}: {}",
+                marker_path.display(),
+                error
+            ))
+        })?;
+        fs::remove_file(&marker_path).map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to clear crash report marker {}: {}",
+                marker_path.display(),
+                error
+            ))
+        })?;
+
+        Ok(pending
+            .lines()
+            .map(str::trim)
+            .filter(|id| validate_report_id(id).is_ok())
+            .filter_map(|id| read_report(&self.report_path(id)).ok())
+            .collect())
+    }
+
+    fn report_path(&self, id: &str) -> PathBuf {
+        self.root.join(format!("{id}.json"))
+    }
+}
+
+/// Records every panic as a crash report before handing over to the previous hook.
+pub fn install_panic_hook(store: Arc<CrashReportStore>) {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let report = CrashReport::from_panic(info);
+        if let Err(error) = store.write(&report) {
+            eprintln!("Failed to write crash report: {}", error);
+        }
+        previous_hook(info);
+    }));
+}
+
+fn validate_report_id(id: &str) -> Result<(), DomainError> {
+    let valid = id.starts_with(CRASH_REPORT_PREFIX)
+        && id
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || character == '-');
+    if valid {
+        Ok(())
+    } else {
+        Err(DomainError::InvalidData(format!(
+            "Invalid crash report id: {id}"
+        )))
+    }
+}
+
+fn read_report(path: &Path) -> Result<CrashReport, DomainError> {
+    let bytes = fs::read(path).map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to read crash report {}: {}",
+            path.display(),
+            error
+        ))
+    })?;
+    serde_json::from_slice(&bytes).map_err(|error| {
+        DomainError::InvalidData(format!(
+            "Failed to parse crash report {}: {}",
+            path.display(),
+            error
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CrashReport, CrashReportStore};
+
+    fn report(id: &str, created_at: &str) -> CrashReport {
+        CrashReport {
+            id: id.to_string(),
+            created_at: created_at.to_string(),
+            message: "boom".to_string(),
+            location: Some("src/lib.rs:1".to_string()),
+            thread: Some("main".to_string()),
+            backtrace: String::new(),
+            app_version: "1.0.0".to_string(),
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+        }
+    }
+
+    #[test]
+    fn pending_reports_are_reported_once_and_can_be_deleted() {
+        let log_root =
+            std::env::temp_dir().join(format!("tauritavern-crashes-{}", uuid::Uuid::new_v4()));
+        let store = CrashReportStore::new(&log_root);
+
+        store
+            .write(&report(
+                "crash-20240101-000000-aaaaaaaa",
+                "2024-01-01T00:00:00Z",
+            ))
+            .expect("write first report");
+        store
+            .write(&report(
+                "crash-20240102-000000-bbbbbbbb",
+                "2024-01-02T00:00:00Z",
+            ))
+            .expect("write second report");
+
+        let pending = store.take_pending().expect("take pending");
+        assert_eq!(pending.len(), 2);
+        assert!(store.take_pending().expect("take pending again").is_empty());
+
+        let listed = store.list().expect("list reports");
+        assert_eq!(listed[0].id, "crash-20240102-000000-bbbbbbbb");
+
+        assert!(store.delete("../pending").is_err());
+        store
+            .delete("crash-20240101-000000-aaaaaaaa")
+            .expect("delete report");
+        assert_eq!(store.list().expect("list after delete").len(), 1);
+
+        let _ = std::fs::remove_dir_all(&log_root);
+    }
+}
@@ -10,7 +10,10 @@ use crate::domain::errors::DomainError;
 use crate::infrastructure::paths::{IOS_EXPORT_STAGING_ROOT_NAME, RuntimePaths};
 use crate::infrastructure::zipkit::export_file_options;
 
+use super::log_query::BACKEND_LOG_FILE_PREFIX;
+
 const BUNDLE_ROOT_DIR: &str = "tauritavern-dev-bundle";
+const LOGS_BUNDLE_ROOT_DIR: &str = "tauritavern-logs";
 
 const COPY_BUFFER_BYTES: usize = 1024 * 1024;
 const FILE_IO_BUFFER_BYTES: usize = 1024 * 1024;
@@ -117,7 +120,7 @@ pub fn export_dev_log_bundle(
     }
 
     for source_path in list_log_root_files(&runtime_paths.log_root, |name| {
-        name.starts_with("tauritavern.log")
+        name.starts_with(BACKEND_LOG_FILE_PREFIX)
     })? {
         let file_name = source_path
             .file_name()
@@ -170,6 +173,65 @@ pub fn export_dev_log_bundle(
     Ok(output_path)
 }
 
+/// Exports only the rotated backend log files. Unlike the dev bundle it carries no
+/// settings or LLM API payloads, so it is safe to attach to public issues.
+pub fn export_backend_logs_bundle(
+    app_handle: &tauri::AppHandle,
+    runtime_paths: &RuntimePaths,
+    readme_text: &str,
+) -> Result<PathBuf, DomainError> {
+    let output_dir = resolve_bundle_output_dir(app_handle)?;
+    fs::create_dir_all(&output_dir).map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to create export output directory {}: {}",
+            output_dir.display(),
+            error
+        ))
+    })?;
+
+    let output_path = output_dir.join(timestamped_file_name("tauritavern-logs"));
+    let output_file = File::create(&output_path).map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to create logs bundle file {}: {}",
+            output_path.display(),
+            error
+        ))
+    })?;
+    let buffered_output = BufWriter::with_capacity(FILE_IO_BUFFER_BYTES, output_file);
+    let mut writer = ZipWriter::new(buffered_output);
+
+    add_text_file(
+        &mut writer,
+        &format!("{}/README.txt", LOGS_BUNDLE_ROOT_DIR),
+        readme_text,
+    )?;
+
+    let mut copy_buffer = vec![0u8; COPY_BUFFER_BYTES];
+    for source_path in list_log_root_files(&runtime_paths.log_root, |name| {
+        name.starts_with(BACKEND_LOG_FILE_PREFIX)
+    })? {
+        let Some(file_name) = source_path.file_name().and_then(|value| value.to_str()) else {
+            continue;
+        };
+
+        let zip_path = format!("{}/{}", LOGS_BUNDLE_ROOT_DIR, file_name);
+        add_file_from_disk(&mut writer, &source_path, &zip_path, &mut copy_buffer)?;
+    }
+
+    let mut buffered_output = writer.finish().map_err(|error| {
+        DomainError::InternalError(format!("Failed to finalize logs bundle: {error}"))
+    })?;
+    buffered_output.flush().map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to flush logs bundle {}: {}",
+            output_path.display(),
+            error
+        ))
+    })?;
+
+    Ok(output_path)
+}
+
 #[cfg(target_os = "ios")]
 fn resolve_bundle_output_dir(app_handle: &tauri::AppHandle) -> Result<PathBuf, DomainError> {
     let path_resolver = app_handle.path();
@@ -205,9 +267,13 @@ fn resolve_bundle_output_dir(app_handle: &tauri::AppHandle) -> Result<PathBuf, D
 }
 
 fn default_bundle_file_name() -> String {
+    timestamped_file_name("tauritavern-dev-bundle")
+}
+
+fn timestamped_file_name(prefix: &str) -> String {
     let ts = Utc::now().format("%Y%m%d-%H%M%S").to_string();
     let suffix = uuid::Uuid::new_v4().simple().to_string();
-    format!("tauritavern-dev-bundle-{}-{}.zip", ts, &suffix[..8])
+    format!("{}-{}-{}.zip", prefix, ts, &suffix[..8])
 }
 
 fn add_text_file(
@@ -272,7 +338,7 @@ fn add_file_from_disk(
     Ok(())
 }
 
-fn list_log_root_files(
+pub(crate) fn list_log_root_files(
     log_root: &Path,
     predicate: impl Fn(&str) -> bool,
 ) -> Result<Vec<PathBuf>, DomainError> {
@@ -235,4 +235,15 @@ impl ChatCompletionRepository for LoggingChatCompletionRepository {
     async fn close_provider_session(&self, session_id: &str) {
         self.inner.close_provider_session(session_id).await;
     }
+
+    async fn get_generation_metadata(
+        &self,
+        source: ChatCompletionSource,
+        config: &ChatCompletionApiConfig,
+        generation_id: &str,
+    ) -> Result<Value, DomainError> {
+        self.inner
+            .get_generation_metadata(source, config, generation_id)
+            .await
+    }
 }
@@ -0,0 +1,219 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tracing::Level;
+
+use crate::domain::errors::DomainError;
+
+use super::dev_bundle::list_log_root_files;
+
+pub const BACKEND_LOG_FILE_PREFIX: &str = "tauritavern.log";
+
+const DEFAULT_QUERY_LIMIT: usize = 500;
+const MAX_QUERY_LIMIT: usize = 5000;
+
+/// One record parsed from the rotated backend log files. Continuation lines of
+/// multi-line messages are folded into the record they belong to.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogRecord {
+    pub timestamp: String,
+    pub timestamp_ms: i64,
+    pub level: String,
+    pub message: String,
+    pub file: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LogQuery {
+    /// Minimum severity; `warn` returns warnings and errors.
+    pub level: Option<Level>,
+    pub since: Option<DateTime<Utc>>,
+    /// Case-insensitive substring matched against the message.
+    pub contains: Option<String>,
+    pub limit: Option<usize>,
+}
+
+impl LogQuery {
+    fn effective_limit(&self) -> usize {
+        self.limit
+            .unwrap_or(DEFAULT_QUERY_LIMIT)
+            .clamp(1, MAX_QUERY_LIMIT)
+    }
+
+    fn matches(&self, record: &LogRecord, needle: Option<&str>) -> bool {
+        if let Some(level) = self.level {
+            match record.level.parse::<Level>() {
+                // `Level` orders by verbosity: ERROR < WARN < ... < TRACE.
+                Ok(record_level) if record_level <= level => {}
+                _ => return false,
+            }
+        }
+
+        if self
+            .since
+            .is_some_and(|since| record.timestamp_ms < since.timestamp_millis())
+        {
+            return false;
+        }
+
+        match needle {
+            Some(needle) => record.message.to_lowercase().contains(needle),
+            None => true,
+        }
+    }
+}
+
+/// Returns the newest records matching `query` in chronological order.
+pub fn query_log_files(log_root: &Path, query: &LogQuery) -> Result<Vec<LogRecord>, DomainError> {
+    let limit = query.effective_limit();
+    let needle = query
+        .contains
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_lowercase);
+
+    let mut results = VecDeque::with_capacity(limit);
+    let mut push = |record: LogRecord| {
+        if !query.matches(&record, needle.as_deref()) {
+            return;
+        }
+        if results.len() == limit {
+            results.pop_front();
+        }
+        results.push_back(record);
+    };
+
+    // Daily rotation suffixes file names with the date, so name order is chronological.
+    for path in list_log_root_files(log_root, |name| name.starts_with(BACKEND_LOG_FILE_PREFIX))? {
+        let file_name = path
+            .file_name()
+            .map(|value| value.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let file = File::open(&path).map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to open log file {}: {}",
+                path.display(),
+                error
+            ))
+        })?;
+
+        let mut pending: Option<LogRecord> = None;
+        for line in BufReader::new(file).lines() {
+            let line = line.map_err(|error| {
+                DomainError::InternalError(format!(
+                    "Failed to read log file {}: {}",
+                    path.display(),
+                    error
+                ))
+            })?;
+
+            match parse_log_line(&line, &file_name) {
+                Some(record) => {
+                    if let Some(previous) = pending.replace(record) {
+                        push(previous);
+                    }
+                }
+                None => {
+                    if let Some(record) = pending.as_mut() {
+                        record.message.push('\n');
+                        record.message.push_str(&line);
+                    }
+                }
+            }
+        }
+
+        if let Some(record) = pending {
+            push(record);
+        }
+    }
+
+    Ok(results.into())
+}
+
+/// Parses a line written by the file `fmt` layer:
+/// `2024-01-01T00:00:00.000000Z  INFO target: message`.
+fn parse_log_line(line: &str, file_name: &str) -> Option<LogRecord> {
+    let (timestamp, rest) = line.split_once(' ')?;
+    let parsed = DateTime::parse_from_rfc3339(timestamp).ok()?;
+
+    let rest = rest.trim_start();
+    let (level, message) = rest.split_once(' ').unwrap_or((rest, ""));
+    let level = level.parse::<Level>().ok()?;
+
+    Some(LogRecord {
+        timestamp: timestamp.to_string(),
+        timestamp_ms: parsed.timestamp_millis(),
+        level: level.to_string(),
+        message: message.to_string(),
+        file: file_name.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LogQuery, query_log_files};
+    use chrono::{DateTime, Utc};
+    use tracing::Level;
+
+    #[test]
+    fn query_filters_by_level_time_and_text_and_keeps_newest() {
+        let root = std::env::temp_dir().join(format!("tauritavern-logs-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&root).expect("create log root");
+        std::fs::write(
+            root.join("tauritavern.log.2024-01-01"),
+            "2024-01-01T10:00:00.000000Z ERROR app: old failure\n",
+        )
+        .expect("write old log");
+        std::fs::write(
+            root.join("tauritavern.log.2024-01-02"),
+            concat!(
+                "2024-01-02T10:00:00.000000Z  INFO app: Chat saved\n",
+                "2024-01-02T10:00:01.000000Z  WARN app: Slow save\n",
+                "2024-01-02T10:00:02.000000Z ERROR app: Save failed\n",
+                "caused by: disk full\n",
+            ),
+        )
+        .expect("write new log");
+        std::fs::write(root.join("llm-api-1.json"), "{}").expect("write unrelated log");
+
+        let since = "2024-01-02T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let records = query_log_files(
+            &root,
+            &LogQuery {
+                level: Some(Level::WARN),
+                since: Some(since),
+                contains: Some("SAVE".to_string()),
+                limit: None,
+            },
+        )
+        .expect("query logs");
+        let messages: Vec<&str> = records
+            .iter()
+            .map(|record| record.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec!["app: Slow save", "app: Save failed\ncaused by: disk full"]
+        );
+
+        let newest = query_log_files(
+            &root,
+            &LogQuery {
+                limit: Some(1),
+                ..LogQuery::default()
+            },
+        )
+        .expect("query newest");
+        assert_eq!(newest.len(), 1);
+        assert_eq!(newest[0].level, "ERROR");
+        assert_eq!(newest[0].file, "tauritavern.log.2024-01-02");
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -5,15 +5,18 @@ use serde::Serialize;
 use tauri::{AppHandle, Emitter};
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_subscriber::{
-    EnvFilter,
+    EnvFilter, Registry,
     fmt::{self, format::FmtSpan},
     prelude::*,
+    reload,
 };
 
 use super::devtools::BackendLogStore;
+use super::log_query::BACKEND_LOG_FILE_PREFIX;
 
 static INIT: Once = Once::new();
 static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
 
 pub const BACKEND_ERROR_EVENT: &str = "tauritavern-backend-error";
 
@@ -35,7 +38,8 @@ pub fn init_logger(
         .map_err(|error| format!("Failed to create log directory {:?}: {}", log_dir, error))?;
 
     INIT.call_once(|| {
-        let file_appender = RollingFileAppender::new(Rotation::DAILY, log_dir, "tauritavern.log");
+        let file_appender =
+            RollingFileAppender::new(Rotation::DAILY, log_dir, BACKEND_LOG_FILE_PREFIX);
 
         let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
 
@@ -45,6 +49,8 @@ pub fn init_logger(
 
         let env_filter =
             EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+        let (env_filter, filter_handle) = reload::Layer::new(env_filter);
+        let _ = FILTER_HANDLE.set(filter_handle);
 
         let subscriber = tracing_subscriber::registry()
             .with(env_filter)
@@ -74,6 +80,27 @@ pub fn init_logger(
     Ok(())
 }
 
+/// Replace the active log filter, e.g. `debug` or `info,tauritavern=trace`.
+/// Applies to console, file and devtools output until the next restart.
+pub fn set_log_level(directives: &str) -> Result<(), String> {
+    let directives = directives.trim();
+    if directives.is_empty() {
+        return Err("Log level must not be empty".to_string());
+    }
+
+    let filter = EnvFilter::try_new(directives)
+        .map_err(|error| format!("Invalid log level {:?}: {}", directives, error))?;
+    let handle = FILTER_HANDLE
+        .get()
+        .ok_or_else(|| "Logger is not initialized".to_string())?;
+
+    handle
+        .reload(filter)
+        .map_err(|error| format!("Failed to apply log level: {}", error))?;
+    tracing::info!("Log level set to {}", directives);
+    Ok(())
+}
+
 /// Log a debug message
 pub fn debug(message: &str) {
     tracing::debug!("{}", message);
@@ -1,5 +1,8 @@
 // Logging utilities
+pub mod crash_reports;
 pub mod dev_bundle;
 pub mod devtools;
 pub mod llm_api_logs;
+pub mod log_query;
 pub mod logger;
+pub mod performance_trace;
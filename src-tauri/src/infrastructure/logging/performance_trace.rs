@@ -0,0 +1,208 @@
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::file_system::atomic_write;
+
+const TRACE_FILE_NAME: &str = "performance-trace.jsonl";
+const MAX_TRACE_SPANS: usize = 5000;
+const FLUSH_EVERY_SPANS: usize = 200;
+
+static TRACER: OnceLock<Arc<PerformanceTracer>> = OnceLock::new();
+
+/// One command dispatch. Timestamps are microseconds since the Unix epoch, which is the
+/// unit Chrome trace events use.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandSpan {
+    pub command: String,
+    pub started_at_us: u64,
+    pub duration_us: u64,
+    pub payload_bytes: u64,
+}
+
+/// Opt-in, local-only recorder of command handler spans. The newest spans are kept in
+/// memory and mirrored to `<log_root>/performance-trace.jsonl` so a trace survives the
+/// restart that usually follows a "the UI is slow" report.
+pub struct PerformanceTracer {
+    path: PathBuf,
+    enabled: AtomicBool,
+    unflushed: AtomicUsize,
+    spans: Mutex<VecDeque<CommandSpan>>,
+}
+
+impl PerformanceTracer {
+    pub fn new(log_root: &Path) -> Self {
+        let path = log_root.join(TRACE_FILE_NAME);
+        let spans = load_spans(&path);
+
+        Self {
+            path,
+            enabled: AtomicBool::new(false),
+            unflushed: AtomicUsize::new(0),
+            spans: Mutex::new(spans),
+        }
+    }
+
+    /// Makes `tracer` visible to the invoke handler, which has no access to managed state
+    /// before a command is dispatched.
+    pub fn install(tracer: Arc<Self>) {
+        let _ = TRACER.set(tracer);
+    }
+
+    /// The installed tracer, if tracing is currently enabled.
+    pub fn active() -> Option<&'static Arc<Self>> {
+        TRACER.get().filter(|tracer| tracer.is_enabled())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(self: &Arc<Self>, enabled: bool) {
+        let was_enabled = self.enabled.swap(enabled, Ordering::Relaxed);
+        if was_enabled && !enabled {
+            self.spawn_flush();
+        }
+    }
+
+    pub fn record(
+        self: &Arc<Self>,
+        command: &str,
+        started_at: SystemTime,
+        duration: Duration,
+        payload_bytes: u64,
+    ) {
+        let started_at_us = started_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_micros() as u64;
+        let span = CommandSpan {
+            command: command.to_string(),
+            started_at_us,
+            duration_us: duration.as_micros() as u64,
+            payload_bytes,
+        };
+
+        {
+            let mut spans = self.spans.lock().unwrap();
+            if spans.len() == MAX_TRACE_SPANS {
+                spans.pop_front();
+            }
+            spans.push_back(span);
+        }
+
+        if self.unflushed.fetch_add(1, Ordering::Relaxed) + 1 >= FLUSH_EVERY_SPANS {
+            self.spawn_flush();
+        }
+    }
+
+    pub fn spans(&self) -> Vec<CommandSpan> {
+        self.spans.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Renders the recorded spans in the Chrome trace event format, loadable in
+    /// `chrome://tracing` or Perfetto.
+    pub fn chrome_trace(&self) -> Value {
+        let events = self
+            .spans()
+            .into_iter()
+            .map(|span| {
+                json!({
+                    "name": span.command,
+                    "cat": "command",
+                    "ph": "X",
+                    "ts": span.started_at_us,
+                    "dur": span.duration_us,
+                    "pid": 1,
+                    "tid": 1,
+                    "args": { "payloadBytes": span.payload_bytes },
+                })
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "traceEvents": events,
+            "displayTimeUnit": "ms",
+        })
+    }
+
+    pub async fn flush(&self) -> Result<(), DomainError> {
+        self.unflushed.store(0, Ordering::Relaxed);
+
+        let mut contents = String::new();
+        for span in self.spans() {
+            let line = serde_json::to_string(&span).map_err(|error| {
+                DomainError::InternalError(format!("Failed to serialize trace span: {error}"))
+            })?;
+            contents.push_str(&line);
+            contents.push('\n');
+        }
+
+        atomic_write(&self.path, contents.as_bytes()).await
+    }
+
+    fn spawn_flush(self: &Arc<Self>) {
+        let tracer = self.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(error) = tracer.flush().await {
+                tracing::warn!("Failed to write performance trace: {}", error);
+            }
+        });
+    }
+}
+
+fn load_spans(path: &Path) -> VecDeque<CommandSpan> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return VecDeque::new();
+    };
+
+    let mut spans = contents
+        .lines()
+        .filter_map(|line| serde_json::from_str::<CommandSpan>(line).ok())
+        .collect::<VecDeque<_>>();
+    while spans.len() > MAX_TRACE_SPANS {
+        spans.pop_front();
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::PerformanceTracer;
+
+    #[tokio::test]
+    async fn chrome_trace_contains_spans_and_survives_reload() {
+        let log_root =
+            std::env::temp_dir().join(format!("tauritavern-perf-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&log_root).expect("create log root");
+
+        let tracer = Arc::new(PerformanceTracer::new(&log_root));
+        tracer.record(
+            "get_all_characters",
+            UNIX_EPOCH + Duration::from_millis(1_000),
+            Duration::from_millis(12),
+            64,
+        );
+        tracer.flush().await.expect("flush trace");
+
+        let trace = PerformanceTracer::new(&log_root).chrome_trace();
+        let event = &trace["traceEvents"][0];
+        assert_eq!(event["name"], "get_all_characters");
+        assert_eq!(event["ph"], "X");
+        assert_eq!(event["ts"], 1_000_000);
+        assert_eq!(event["dur"], 12_000);
+        assert_eq!(event["args"]["payloadBytes"], 64);
+
+        let _ = std::fs::remove_dir_all(&log_root);
+    }
+}
@@ -0,0 +1,91 @@
+//! DNS-SD advertisement of local services over mDNS so companion devices can find this
+//! instance on the LAN without typing an address.
+
+use mdns_sd::{ServiceDaemon, ServiceInfo};
+
+use crate::domain::errors::DomainError;
+
+pub struct MdnsAdvertisement {
+    daemon: ServiceDaemon,
+    fullname: String,
+}
+
+impl MdnsAdvertisement {
+    /// Announces `instance_name` under `service_type` (e.g. `_tauritavern._tcp.local.`) on
+    /// every interface. The addresses follow the machine's interfaces as they change.
+    pub fn register(
+        service_type: &str,
+        instance_name: &str,
+        port: u16,
+        properties: &[(&str, &str)],
+    ) -> Result<Self, DomainError> {
+        let daemon = ServiceDaemon::new().map_err(|error| {
+            DomainError::InternalError(format!("Failed to start mDNS daemon: {}", error))
+        })?;
+        let host_name = format!("{}.local.", host_label(instance_name));
+        let service = ServiceInfo::new(
+            service_type,
+            instance_name,
+            &host_name,
+            "",
+            port,
+            properties,
+        )
+        .map_err(|error| DomainError::InvalidData(format!("Invalid mDNS service: {}", error)))?
+        .enable_addr_auto();
+        let fullname = service.get_fullname().to_string();
+
+        if let Err(error) = daemon.register(service) {
+            let _ = daemon.shutdown();
+            return Err(DomainError::InternalError(format!(
+                "Failed to register mDNS service: {}",
+                error
+            )));
+        }
+
+        Ok(Self { daemon, fullname })
+    }
+
+    /// Sends goodbye packets and stops the daemon.
+    pub fn shutdown(self) {
+        if let Err(error) = self.daemon.unregister(&self.fullname) {
+            tracing::debug!("Failed to unregister mDNS service: {}", error);
+        }
+        let _ = self.daemon.shutdown();
+    }
+}
+
+/// Lowercase DNS label (`a-z`, `0-9`, `-`) derived from a display name.
+fn host_label(name: &str) -> String {
+    let label = name
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() {
+                ch.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>();
+    let label = label.trim_matches('-');
+    if label.is_empty() {
+        "tauritavern".to_string()
+    } else {
+        label.chars().take(63).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::host_label;
+
+    #[test]
+    fn host_label_is_a_valid_dns_label() {
+        assert_eq!(
+            host_label("TauriTavern (192.168.1.5)"),
+            "tauritavern--192-168-1-5"
+        );
+        assert_eq!(host_label("角色"), "tauritavern");
+        assert_eq!(host_label(&"a".repeat(80)).len(), 63);
+    }
+}
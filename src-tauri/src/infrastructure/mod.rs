@@ -3,11 +3,14 @@ pub mod apis;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub mod apple_webview_js_dialogs;
 pub mod assets;
+pub mod backup_schedule_store;
+pub mod bridge_server_store;
 pub mod css_compat;
 pub mod data_root_content_dirs;
 pub mod github;
 pub mod http_client;
 pub mod http_client_pool;
+pub mod http_client_tuning;
 pub mod http_error;
 #[cfg(target_os = "ios")]
 pub mod ios_document_picker;
@@ -19,14 +22,18 @@ pub mod ios_ui;
 #[cfg(target_os = "ios")]
 pub mod ios_webview;
 pub mod lan_sync;
+pub mod library_watcher;
 pub mod logging;
 #[cfg(target_os = "macos")]
 pub mod macos_webview;
+pub mod mdns_advertiser;
 pub mod paths;
 pub mod persistence;
 pub mod preset_file_naming;
 pub mod repositories;
 pub mod request_path;
+pub mod secret_bundle_crypto;
+pub mod settings_watcher;
 pub mod sillytavern_sorting;
 pub mod sync_automation_store;
 pub mod sync_bundle;
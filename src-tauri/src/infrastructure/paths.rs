@@ -9,12 +9,19 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Manager};
 
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use crate::domain::models::changelog::MigrationRecord;
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use crate::infrastructure::persistence::migration_ledger::record_migration_sync;
+
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
 const RUNTIME_MODE_ENV: &str = "TAURITAVERN_RUNTIME_MODE";
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
-const PORTABLE_MARKER_FILE: &str = "portable.flag";
+const PORTABLE_MARKER_FILES: &[&str] = &["portable.marker", "portable.flag"];
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
 const RUNTIME_CONFIG_FILE: &str = "tauritavern-runtime.json";
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const PORTABLE_EXPORTS_DIR: &str = "exports";
 const DATA_ARCHIVE_ROOT_DIR: &str = ".data-archive";
 const DATA_ARCHIVE_IMPORTS_DIR: &str = "imports";
 const DATA_ARCHIVE_EXPORTS_DIR: &str = "exports";
@@ -66,6 +73,17 @@ impl RuntimePaths {
             archive_exports_root,
         }
     }
+
+    /// Directory that saved data archives are written to in portable mode. Portable
+    /// installs keep exports next to the executable instead of the system downloads folder,
+    /// so nothing is written outside the removable drive.
+    #[cfg(not(any(target_os = "android", target_os = "ios")))]
+    pub fn portable_exports_dir(&self) -> Option<PathBuf> {
+        match self.mode {
+            RuntimeMode::Portable => Some(self.app_root.join(PORTABLE_EXPORTS_DIR)),
+            RuntimeMode::Standard => None,
+        }
+    }
 }
 
 pub fn resolve_runtime_paths(app_handle: &AppHandle) -> Result<RuntimePaths, Box<dyn Error>> {
@@ -108,10 +126,78 @@ fn apply_runtime_config(mut paths: RuntimePaths) -> Result<RuntimePaths, Box<dyn
         return Ok(paths);
     }
 
-    paths.data_root = config.data_root;
+    let data_root = resolve_configured_data_root(&paths.app_root, &config.data_root);
+    // A data root on a removable drive may simply not be mounted. Creating it here would
+    // start the app on an empty directory, so refuse to start instead.
+    if !data_root.is_dir() {
+        return Err(Box::new(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "Configured data directory is not available: {} (is the drive connected?)",
+                data_root.display()
+            ),
+        )));
+    }
+
+    paths.data_root = data_root;
     Ok(paths)
 }
 
+/// Resolves a `data_root` from the runtime config. Relative paths are relative to the
+/// app root, which lets a portable install keep working when its drive is mounted under
+/// a different letter or mount point.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub(crate) fn resolve_configured_data_root(app_root: &Path, data_root: &Path) -> PathBuf {
+    if data_root.is_absolute() {
+        return data_root.to_path_buf();
+    }
+
+    let joined = app_root.join(data_root);
+    dunce::canonicalize(&joined).unwrap_or(joined)
+}
+
+/// Expresses `target` relative to `base` when both live under the same root (drive),
+/// e.g. `E:\TauriTavern` and `E:\TauriTavernData` become `..\TauriTavernData`.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub(crate) fn relative_data_root(target: &Path, base: &Path) -> Option<PathBuf> {
+    use std::path::Component;
+
+    let target_components = target.components().collect::<Vec<_>>();
+    let base_components = base.components().collect::<Vec<_>>();
+    let same_root = matches!(
+        (target_components.first(), base_components.first()),
+        (Some(left), Some(right)) if left == right
+    );
+    if !same_root
+        || target_components
+            .iter()
+            .chain(base_components.iter())
+            .any(|component| matches!(component, Component::ParentDir))
+    {
+        return None;
+    }
+
+    let common = target_components
+        .iter()
+        .zip(base_components.iter())
+        .take_while(|(left, right)| left == right)
+        .count();
+
+    let mut relative = PathBuf::new();
+    for _ in common..base_components.len() {
+        relative.push("..");
+    }
+    for component in &target_components[common..] {
+        relative.push(component.as_os_str());
+    }
+
+    if relative.as_os_str().is_empty() {
+        None
+    } else {
+        Some(relative)
+    }
+}
+
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
 fn resolve_pending_data_root_migration(
     app_root: &Path,
@@ -119,9 +205,10 @@ fn resolve_pending_data_root_migration(
     migration: DataRootMigration,
 ) -> Result<PathBuf, Box<dyn Error>> {
     let config_path = runtime_config_path(app_root);
+    let target = resolve_configured_data_root(app_root, &config.data_root);
 
     if !migration.from.exists() {
-        if is_initialized_data_root(&config.data_root) {
+        if is_initialized_data_root(&target) {
             config.migration = None;
             config.migration_error = None;
             persist_runtime_config_best_effort(
@@ -129,13 +216,13 @@ fn resolve_pending_data_root_migration(
                 config,
                 "clear runtime migration marker",
             );
-            return Ok(config.data_root.clone());
+            return Ok(target);
         }
 
         let error_text = format!(
             "{} -> {}: migration source is missing and target is not an initialized data root",
             migration.from.display(),
-            config.data_root.display()
+            target.display()
         );
         config.migration_error = Some(error_text.clone());
         persist_runtime_config_best_effort(
@@ -150,8 +237,20 @@ fn resolve_pending_data_root_migration(
         )));
     }
 
-    match migrate_data_root(&migration.from, &config.data_root) {
+    match migrate_data_root(&migration.from, &target) {
         Ok(()) => {
+            let record = MigrationRecord::now(
+                "data_root.relocate",
+                format!(
+                    "Moved the data directory from {} to {}",
+                    migration.from.display(),
+                    target.display()
+                ),
+                vec![target.display().to_string()],
+            );
+            if let Err(error) = record_migration_sync(&target, record) {
+                tracing::warn!("Failed to record data directory migration: {}", error);
+            }
             config.migration = None;
             config.migration_error = None;
             persist_runtime_config_best_effort(
@@ -159,13 +258,13 @@ fn resolve_pending_data_root_migration(
                 config,
                 "update runtime config after migration",
             );
-            Ok(config.data_root.clone())
+            Ok(target)
         }
         Err(error) => {
             let error_text = format!(
                 "{} -> {}: {}",
                 migration.from.display(),
-                config.data_root.display(),
+                target.display(),
                 error
             );
             config.migration_error = Some(error_text.clone());
@@ -219,13 +318,15 @@ fn detect_desktop_runtime_mode() -> RuntimeMode {
         }
     };
 
-    let marker_path = exe_dir.join(PORTABLE_MARKER_FILE);
-    if marker_path.is_file() {
-        tracing::info!(
-            "Portable mode detected by marker file: {}",
-            marker_path.display()
-        );
-        return RuntimeMode::Portable;
+    for marker_file in PORTABLE_MARKER_FILES {
+        let marker_path = exe_dir.join(marker_file);
+        if marker_path.is_file() {
+            tracing::info!(
+                "Portable mode detected by marker file: {}",
+                marker_path.display()
+            );
+            return RuntimeMode::Portable;
+        }
     }
 
     RuntimeMode::Standard
@@ -523,13 +624,6 @@ pub(crate) fn load_runtime_config(
         )));
     }
 
-    if !config.data_root.is_absolute() {
-        return Err(Box::new(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "Runtime config data_root must be an absolute path",
-        )));
-    }
-
     if let Some(migration) = config.migration.as_ref() {
         if !migration.from.is_absolute() {
             return Err(Box::new(io::Error::new(
@@ -543,22 +637,14 @@ pub(crate) fn load_runtime_config(
 }
 
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
-fn write_runtime_config_sync(
+pub(crate) fn write_runtime_config_sync(
     path: &std::path::Path,
     config: &TauriTavernRuntimeConfig,
 ) -> Result<(), Box<dyn Error>> {
-    use crate::infrastructure::persistence::file_system::{
-        replace_file_with_fallback_sync, unique_temp_path,
-    };
+    use crate::infrastructure::persistence::file_system::atomic_write_sync;
 
     let bytes = serde_json::to_vec_pretty(config)?;
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent)?;
-    }
-
-    let temp_path = unique_temp_path(path, "tauritavern-runtime.json");
-    std::fs::write(&temp_path, &bytes)?;
-    replace_file_with_fallback_sync(&temp_path, path)
+    atomic_write_sync(path, &bytes)
         .map_err(|error| Box::new(io::Error::other(error.to_string())) as Box<dyn Error>)?;
     Ok(())
 }
@@ -659,6 +745,100 @@ fn copy_dir_recursive(from: &std::path::Path, to: &std::path::Path) -> Result<()
     Ok(())
 }
 
+/// Copies the contents of `from` into the effectively empty directory `to`, reporting
+/// `(copied_files, total_files)` after each file. Unlike [`migrate_data_root`] the source is
+/// left untouched, so it can run while the app is still using `from`. A cancelled or failed
+/// copy is removed again.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub(crate) fn copy_data_root_with_progress(
+    from: &Path,
+    to: &Path,
+    mut on_progress: impl FnMut(u64, u64),
+    is_cancelled: impl Fn() -> bool,
+) -> Result<u64, Box<dyn Error>> {
+    prepare_effectively_empty_directory(to)?;
+
+    let total_files = count_files_recursive(from)?;
+    let mut copied_files = 0;
+    let result = copy_dir_with_progress(from, to, &mut |copied_file| {
+        if is_cancelled() {
+            return Err(Box::new(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "Data directory copy was cancelled",
+            )) as Box<dyn Error>);
+        }
+        if copied_file {
+            copied_files += 1;
+            on_progress(copied_files, total_files);
+        }
+        Ok(())
+    });
+
+    if let Err(error) = result {
+        let _ = std::fs::remove_dir_all(to);
+        let _ = std::fs::create_dir_all(to);
+        return Err(error);
+    }
+
+    Ok(total_files)
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn count_files_recursive(path: &Path) -> Result<u64, Box<dyn Error>> {
+    let mut count = 0;
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            count += count_files_recursive(&entry.path())?;
+        } else {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// `on_entry` runs before every entry with `false` and after every copied file with
+/// `true`; returning an error aborts the copy.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn copy_dir_with_progress(
+    from: &Path,
+    to: &Path,
+    on_entry: &mut dyn FnMut(bool) -> Result<(), Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    std::fs::create_dir_all(to)?;
+
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        on_entry(false)?;
+
+        let file_type = entry.file_type()?;
+        let source = entry.path();
+        let target = to.join(entry.file_name());
+
+        if file_type.is_dir() {
+            copy_dir_with_progress(&source, &target, on_entry)?;
+            continue;
+        }
+
+        if file_type.is_symlink() {
+            copy_symlink(&source, &target)?;
+        } else if file_type.is_file() {
+            std::fs::copy(&source, &target)?;
+        } else {
+            return Err(Box::new(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Unsupported file type during data directory copy: {}",
+                    source.display()
+                ),
+            )));
+        }
+        on_entry(true)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(all(not(any(target_os = "android", target_os = "ios")), unix))]
 fn copy_symlink(from: &std::path::Path, to: &std::path::Path) -> Result<(), Box<dyn Error>> {
     use std::os::unix::fs::symlink;
@@ -720,6 +900,23 @@ mod tests {
         RuntimePaths::new(RuntimeMode::Standard, app_root.to_path_buf())
     }
 
+    #[test]
+    fn portable_paths_stay_next_to_app_root() {
+        let app_root = Path::new("portable-root");
+        let portable = RuntimePaths::new(RuntimeMode::Portable, app_root.to_path_buf());
+
+        assert_eq!(portable.data_root, app_root.join("data"));
+        assert_eq!(portable.log_root, app_root.join("logs"));
+        assert_eq!(
+            portable.portable_exports_dir(),
+            Some(app_root.join(PORTABLE_EXPORTS_DIR))
+        );
+        assert_eq!(
+            runtime_paths_with_app_root(app_root).portable_exports_dir(),
+            None
+        );
+    }
+
     #[test]
     fn effectively_empty_directory_ignores_known_metadata_files() {
         let temp = TempDirGuard::new("effectively-empty");
@@ -904,6 +1101,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn relative_config_data_root_resolves_against_app_root() {
+        let temp = TempDirGuard::new("relative-data-root");
+        let app_root = temp.root.join("app");
+        let data_root = temp.root.join("portable-data");
+        fs::create_dir_all(&app_root).expect("create app root");
+        fs::create_dir_all(data_root.join(DEFAULT_USER_DIR_NAME)).expect("create data root");
+
+        let relative = relative_data_root(&data_root, &app_root).expect("relative data root");
+        assert_eq!(relative, Path::new("..").join("portable-data"));
+
+        let config = TauriTavernRuntimeConfig {
+            version: TAURITAVERN_RUNTIME_CONFIG_VERSION,
+            data_root: relative,
+            migration: None,
+            migration_error: None,
+        };
+        write_runtime_config_sync(&runtime_config_path(&app_root), &config)
+            .expect("write runtime config");
+
+        let resolved = apply_runtime_config(runtime_paths_with_app_root(&app_root))
+            .expect("apply runtime config");
+        assert_eq!(
+            resolved.data_root,
+            dunce::canonicalize(&data_root).expect("canonicalize data root")
+        );
+    }
+
+    #[test]
+    fn apply_runtime_config_fails_when_configured_data_root_is_missing() {
+        let temp = TempDirGuard::new("unmounted-data-root");
+        let config = TauriTavernRuntimeConfig {
+            version: TAURITAVERN_RUNTIME_CONFIG_VERSION,
+            data_root: temp.root.join("unplugged-drive"),
+            migration: None,
+            migration_error: None,
+        };
+        write_runtime_config_sync(&runtime_config_path(&temp.root), &config)
+            .expect("write runtime config");
+
+        let error = apply_runtime_config(runtime_paths_with_app_root(&temp.root))
+            .expect_err("expected missing data root to fail");
+        assert!(error.to_string().contains("not available"));
+        assert!(!temp.root.join("unplugged-drive").exists());
+    }
+
+    #[test]
+    fn copy_data_root_with_progress_keeps_source_and_reports_every_file() {
+        let temp = TempDirGuard::new("progress-copy");
+        let from = temp.root.join("from");
+        let to = temp.root.join("to");
+        fs::create_dir_all(from.join(DEFAULT_USER_DIR_NAME).join("chats"))
+            .expect("create source chats");
+        fs::write(from.join(DEFAULT_USER_DIR_NAME).join("settings.json"), "{}")
+            .expect("write settings");
+        fs::write(
+            from.join(DEFAULT_USER_DIR_NAME)
+                .join("chats")
+                .join("chat.jsonl"),
+            "demo",
+        )
+        .expect("write chat");
+
+        let mut reports = Vec::new();
+        let total = copy_data_root_with_progress(
+            &from,
+            &to,
+            |copied, total| reports.push((copied, total)),
+            || false,
+        )
+        .expect("copy data root");
+
+        assert_eq!(total, 2);
+        assert_eq!(reports, vec![(1, 2), (2, 2)]);
+        assert!(
+            from.join(DEFAULT_USER_DIR_NAME)
+                .join("settings.json")
+                .is_file()
+        );
+        assert!(
+            to.join(DEFAULT_USER_DIR_NAME)
+                .join("chats")
+                .join("chat.jsonl")
+                .is_file()
+        );
+
+        let cancelled_target = temp.root.join("cancelled");
+        let error = copy_data_root_with_progress(&from, &cancelled_target, |_, _| {}, || true)
+            .expect_err("expected cancelled copy to fail");
+        assert!(error.to_string().contains("cancelled"));
+        assert!(
+            is_effectively_empty_directory(&cancelled_target).expect("inspect cancelled target")
+        );
+    }
+
     #[test]
     fn copy_dir_recursive_preserves_symlinks() {
         let temp = TempDirGuard::new("symlink-copy");
@@ -2,6 +2,10 @@ use chrono::Utc;
 use serde_json::{Value, json};
 
 use crate::domain::errors::DomainError;
+use crate::domain::repositories::chat_repository::ChatImportFormat;
+
+/// Marker text-generation-webui puts in place of the user turn before the greeting.
+const OOBA_VISIBLE_CHAT_MARKER: &str = "<|BEGIN-VISIBLE-CHAT|>";
 
 fn default_header() -> Value {
     json!({
@@ -84,13 +88,42 @@ fn flatten_chub_payload(payload: &mut [Value], user_name: &str, character_name:
     }
 }
 
+/// TavernAI chats store `send_date` as epoch milliseconds and have no `chat_metadata`.
+fn normalize_tavernai_payload(payload: &mut [Value]) {
+    if let Some(header) = payload.first_mut().and_then(Value::as_object_mut) {
+        header
+            .entry("chat_metadata".to_string())
+            .or_insert_with(|| json!({}));
+    }
+
+    for line in payload.iter_mut().skip(1) {
+        let Some(object) = line.as_object_mut() else {
+            continue;
+        };
+        let epoch_ms = match object.get("send_date") {
+            Some(Value::Number(number)) => number.as_i64(),
+            _ => None,
+        };
+        if let Some(epoch_ms) = epoch_ms {
+            let send_date = chrono::DateTime::<Utc>::from_timestamp_millis(epoch_ms)
+                .unwrap_or_else(Utc::now)
+                .to_rfc3339();
+            object.insert("send_date".to_string(), Value::String(send_date));
+        }
+    }
+}
+
 fn import_ooba_payload(
     user_name: &str,
     character_name: &str,
     data: &Value,
 ) -> Result<Vec<Value>, DomainError> {
+    // Older logs keep `data_visible`; current ones keep the raw text in `internal` and an
+    // HTML-escaped copy in `visible`.
     let messages = data
         .get("data_visible")
+        .or_else(|| data.get("internal"))
+        .or_else(|| data.get("visible"))
         .and_then(Value::as_array)
         .ok_or_else(|| DomainError::InvalidData("Invalid Ooba chat format".to_string()))?;
 
@@ -101,7 +134,7 @@ fn import_ooba_payload(
         };
 
         if let Some(user_message) = items.first().and_then(Value::as_str) {
-            if !user_message.is_empty() {
+            if !user_message.is_empty() && user_message != OOBA_VISIBLE_CHAT_MARKER {
                 payload.push(make_message(user_name, true, user_message));
             }
         }
@@ -149,11 +182,10 @@ fn import_cai_payloads(
     character_name: &str,
     data: &Value,
 ) -> Result<Vec<Vec<Value>>, DomainError> {
+    // CAI Tools nests the list as `histories.histories`; older dumps keep it top-level.
     let histories = data
         .get("histories")
-        .and_then(Value::as_object)
-        .and_then(|entry| entry.get("histories"))
-        .and_then(Value::as_array)
+        .and_then(|entry| entry.get("histories").unwrap_or(entry).as_array())
         .ok_or_else(|| DomainError::InvalidData("Invalid CAI chat format".to_string()))?;
 
     let payloads = histories
@@ -170,6 +202,7 @@ fn import_cai_payloads(
                     .get("src")
                     .and_then(Value::as_object)
                     .and_then(|src| src.get("is_human"))
+                    .or_else(|| message.get("src__is_human"))
                     .and_then(Value::as_bool)
                     .unwrap_or(false);
                 let text = message
@@ -291,38 +324,114 @@ fn import_risu_payload(
     Ok(payload)
 }
 
-/// Import one or more chat payloads from JSON formats supported by SillyTavern.
-pub fn import_chat_payloads_from_json(
-    data: &Value,
-    user_name: &str,
-    character_name: &str,
-) -> Result<Vec<Vec<Value>>, DomainError> {
+fn detect_json_format(data: &Value) -> Option<ChatImportFormat> {
+    let has_array = |key: &str| data.get(key).and_then(Value::as_array).is_some();
+
     if data.get("savedsettings").is_some() {
-        return Ok(vec![import_kobold_payload(data)?]);
+        Some(ChatImportFormat::KoboldLite)
+    } else if data.get("histories").is_some() {
+        Some(ChatImportFormat::CAITools)
+    } else if has_array("data_visible") || has_array("internal") || has_array("visible") {
+        Some(ChatImportFormat::Ooba)
+    } else if has_array("messages") {
+        Some(ChatImportFormat::Agnai)
+    } else if data.get("type").and_then(Value::as_str) == Some("risuChat") {
+        Some(ChatImportFormat::RisuAI)
+    } else {
+        None
     }
+}
 
-    if data.get("histories").is_some() {
-        return import_cai_payloads(user_name, character_name, data);
+fn detect_jsonl_format(data: &str) -> Option<ChatImportFormat> {
+    let mut lines = data
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(serde_json::from_str::<Value>);
+    let header = lines.next()?.ok()?;
+    let is_header = header
+        .get("user_name")
+        .or_else(|| header.get("name"))
+        .or_else(|| header.get("chat_metadata"))
+        .is_some();
+    if !is_header {
+        return None;
     }
 
-    if data.get("data_visible").and_then(Value::as_array).is_some() {
-        return Ok(vec![import_ooba_payload(user_name, character_name, data)?]);
+    let has_epoch_dates = header.get("create_date").is_some_and(Value::is_number)
+        || lines
+            .filter_map(Result::ok)
+            .any(|message| message.get("send_date").is_some_and(Value::is_number));
+    if header.get("chat_metadata").is_none() && has_epoch_dates {
+        Some(ChatImportFormat::TavernAI)
+    } else {
+        Some(ChatImportFormat::SillyTavern)
     }
+}
 
-    if data.get("messages").and_then(Value::as_array).is_some() {
-        return Ok(vec![import_agnai_payload(user_name, character_name, data)?]);
-    }
+/// Detect which supported format a chat export is in; `None` when none matches.
+pub fn detect_chat_import_format(data: &str) -> Option<ChatImportFormat> {
+    let data = data.trim_start_matches('\u{feff}').trim();
+    let json_format = match serde_json::from_str::<Value>(data) {
+        Ok(value @ Value::Object(_)) => detect_json_format(&value),
+        _ => None,
+    };
 
-    if data.get("type").and_then(Value::as_str) == Some("risuChat") {
-        return Ok(vec![import_risu_payload(user_name, character_name, data)?]);
+    json_format.or_else(|| detect_jsonl_format(data))
+}
+
+/// Import chat payloads from any supported format, detecting it from the content.
+pub fn import_chat_payloads_auto(
+    data: &str,
+    user_name: &str,
+    character_name: &str,
+) -> Result<Vec<Vec<Value>>, DomainError> {
+    let data = data.trim_start_matches('\u{feff}');
+    match detect_chat_import_format(data) {
+        Some(ChatImportFormat::SillyTavern | ChatImportFormat::TavernAI) => {
+            Ok(vec![import_chat_payloads_from_jsonl(
+                data,
+                user_name,
+                character_name,
+            )?])
+        }
+        Some(_) => {
+            let value: Value = serde_json::from_str(data.trim()).map_err(|error| {
+                DomainError::InvalidData(format!("Failed to parse chat import JSON: {}", error))
+            })?;
+            import_chat_payloads_from_json(&value, user_name, character_name)
+        }
+        None => Err(DomainError::InvalidData(
+            "Unrecognized chat import format".to_string(),
+        )),
     }
+}
 
-    Err(DomainError::InvalidData(
-        "Unsupported chat import JSON format".to_string(),
-    ))
+/// Import one or more chat payloads from JSON formats supported by SillyTavern.
+pub fn import_chat_payloads_from_json(
+    data: &Value,
+    user_name: &str,
+    character_name: &str,
+) -> Result<Vec<Vec<Value>>, DomainError> {
+    match detect_json_format(data) {
+        Some(ChatImportFormat::KoboldLite) => Ok(vec![import_kobold_payload(data)?]),
+        Some(ChatImportFormat::CAITools) => import_cai_payloads(user_name, character_name, data),
+        Some(ChatImportFormat::Ooba) => {
+            Ok(vec![import_ooba_payload(user_name, character_name, data)?])
+        }
+        Some(ChatImportFormat::Agnai) => {
+            Ok(vec![import_agnai_payload(user_name, character_name, data)?])
+        }
+        Some(ChatImportFormat::RisuAI) => {
+            Ok(vec![import_risu_payload(user_name, character_name, data)?])
+        }
+        _ => Err(DomainError::InvalidData(
+            "Unsupported chat import JSON format".to_string(),
+        )),
+    }
 }
 
-/// Import a SillyTavern JSONL payload (with Chub flattening compatibility).
+/// Import a SillyTavern JSONL payload (with Chub flattening and TavernAI compatibility).
 pub fn import_chat_payloads_from_jsonl(
     data: &str,
     user_name: &str,
@@ -355,6 +464,7 @@ pub fn import_chat_payloads_from_jsonl(
     }
 
     flatten_chub_payload(&mut payload, user_name, character_name);
+    normalize_tavernai_payload(&mut payload);
 
     let header = payload
         .first()
@@ -434,7 +544,10 @@ pub fn export_payload_to_plain_text(payload: &[Value]) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::import_chat_payloads_from_json;
+    use super::{
+        detect_chat_import_format, import_chat_payloads_auto, import_chat_payloads_from_json,
+    };
+    use crate::domain::repositories::chat_repository::ChatImportFormat;
     use serde_json::json;
 
     #[test]
@@ -460,4 +573,90 @@ mod tests {
             Some("Assistant")
         );
     }
+
+    #[test]
+    fn detects_supported_export_formats() {
+        let cases = [
+            (
+                r#"{"histories":{"histories":[{"msgs":[]}]}}"#,
+                ChatImportFormat::CAITools,
+            ),
+            (r#"{"data":[],"data_visible":[]}"#, ChatImportFormat::Ooba),
+            (r#"{"internal":[],"visible":[]}"#, ChatImportFormat::Ooba),
+            (r#"{"name":"Chat","messages":[]}"#, ChatImportFormat::Agnai),
+            (
+                r#"{"savedsettings":{},"actions":[]}"#,
+                ChatImportFormat::KoboldLite,
+            ),
+            (
+                "{\"user_name\":\"You\",\"character_name\":\"Aqua\",\"create_date\":1680000000000}\n{\"name\":\"Aqua\",\"is_user\":false,\"send_date\":1680000000000,\"mes\":\"Hi\"}",
+                ChatImportFormat::TavernAI,
+            ),
+            (
+                "{\"user_name\":\"User\",\"character_name\":\"Aqua\",\"chat_metadata\":{}}\n{\"name\":\"Aqua\",\"is_user\":false,\"mes\":\"Hi\"}",
+                ChatImportFormat::SillyTavern,
+            ),
+        ];
+
+        for (data, expected) in cases {
+            assert_eq!(detect_chat_import_format(data), Some(expected), "{data}");
+        }
+        assert_eq!(detect_chat_import_format(r#"{"unrelated":true}"#), None);
+        assert_eq!(detect_chat_import_format("not a chat"), None);
+    }
+
+    #[test]
+    fn imports_current_ooba_logs_without_the_visible_chat_marker() {
+        let payload = json!({
+            "internal": [
+                ["<|BEGIN-VISIBLE-CHAT|>", "Hello, I'm Aqua."],
+                ["Hi Aqua", "Nice to meet you!"]
+            ],
+            "visible": [
+                ["", "Hello, I&#x27;m Aqua."],
+                ["Hi Aqua", "Nice to meet you!"]
+            ]
+        });
+
+        let imported = import_chat_payloads_from_json(&payload, "User", "Aqua")
+            .expect("ooba payload should import");
+        let messages: Vec<_> = imported[0][1..]
+            .iter()
+            .map(|message| message["mes"].as_str().unwrap_or_default())
+            .collect();
+        assert_eq!(
+            messages,
+            vec!["Hello, I'm Aqua.", "Hi Aqua", "Nice to meet you!"]
+        );
+    }
+
+    #[test]
+    fn imports_legacy_cai_dumps_with_flat_histories() {
+        let payload = json!({
+            "histories": [{
+                "msgs": [
+                    { "src__is_human": true, "text": "Hello" },
+                    { "src__is_human": false, "text": "Greetings" }
+                ]
+            }]
+        });
+
+        let imported = import_chat_payloads_from_json(&payload, "User", "Aqua")
+            .expect("cai payload should import");
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0][1]["is_user"], json!(true));
+        assert_eq!(imported[0][2]["name"], json!("Aqua"));
+    }
+
+    #[test]
+    fn auto_import_normalizes_tavernai_dates_and_header() {
+        let data = "{\"user_name\":\"You\",\"character_name\":\"Aqua\",\"create_date\":1680000000000}\n{\"name\":\"Aqua\",\"is_user\":false,\"is_name\":true,\"send_date\":1680000000000,\"mes\":\"Hi\"}\n";
+
+        let imported = import_chat_payloads_auto(data, "User", "Aqua").expect("tavern import");
+        assert_eq!(imported.len(), 1);
+        let chat = &imported[0];
+        assert_eq!(chat[0]["chat_metadata"], json!({}));
+        assert_eq!(chat[1]["send_date"], json!("2023-03-28T10:40:00+00:00"));
+        assert_eq!(chat[1]["extra"], json!({}));
+    }
 }
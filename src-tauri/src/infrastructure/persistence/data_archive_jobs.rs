@@ -11,6 +11,7 @@ use tauri::Manager;
 use uuid::Uuid;
 
 use crate::app::AppState;
+use crate::app::job_manager::{JobHandle, JobState};
 use crate::domain::errors::DomainError;
 #[cfg(target_os = "ios")]
 use crate::infrastructure::paths::IOS_EXPORT_STAGING_ROOT_NAME;
@@ -31,6 +32,9 @@ const STATE_CANCELLED: &str = "cancelled";
 const KIND_IMPORT: &str = "import";
 const KIND_EXPORT: &str = "export";
 
+const TRACKED_KIND_IMPORT: &str = "data_archive_import";
+const TRACKED_KIND_EXPORT: &str = "data_archive_export";
+
 const EXPORT_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
 
 #[derive(Debug, Clone, Serialize)]
@@ -64,10 +68,12 @@ pub struct UserBackupArchiveResult {
 struct DataArchiveJob {
     status: Mutex<DataArchiveJobStatus>,
     cancel_requested: AtomicBool,
+    /// Mirror in the app-wide job manager, absent before the app state is ready.
+    tracked: Option<JobHandle>,
 }
 
 impl DataArchiveJob {
-    fn new(job_id: &str, kind: &str) -> Self {
+    fn new(job_id: &str, kind: &str, tracked: Option<JobHandle>) -> Self {
         Self {
             status: Mutex::new(DataArchiveJobStatus {
                 job_id: job_id.to_string(),
@@ -82,6 +88,7 @@ impl DataArchiveJob {
                 finished_at: None,
             }),
             cancel_requested: AtomicBool::new(false),
+            tracked,
         }
     }
 
@@ -186,6 +193,10 @@ impl DataArchiveJob {
 
     fn is_cancel_requested(&self) -> bool {
         self.cancel_requested.load(Ordering::Relaxed)
+            || self
+                .tracked
+                .as_ref()
+                .is_some_and(JobHandle::is_cancel_requested)
     }
 
     fn update_status(
@@ -197,10 +208,38 @@ impl DataArchiveJob {
             .lock()
             .map_err(|_| DomainError::InternalError("Failed to lock job status".to_string()))?;
         update(&mut status);
+
+        if let Some(tracked) = self.tracked.as_ref() {
+            let snapshot = status.clone();
+            tracked.mirror(|job| {
+                if let Some(state) = JobState::parse(&snapshot.state) {
+                    job.state = state;
+                }
+                job.stage = snapshot.stage;
+                job.progress_percent = snapshot.progress_percent;
+                job.message = snapshot.message;
+                job.error = snapshot.error;
+                job.result = snapshot
+                    .result
+                    .and_then(|result| serde_json::to_value(result).ok());
+                job.finished_at = snapshot.finished_at;
+            });
+        }
         Ok(())
     }
 }
 
+fn track_job(app_handle: &AppHandle, kind: &str) -> Option<JobHandle> {
+    let app_state = app_handle.try_state::<Arc<AppState>>()?;
+    match app_state.job_manager.start(kind) {
+        Ok(handle) => Some(handle),
+        Err(error) => {
+            tracing::warn!("Failed to track data archive job: {}", error);
+            None
+        }
+    }
+}
+
 static JOBS: OnceLock<Mutex<HashMap<String, Arc<DataArchiveJob>>>> = OnceLock::new();
 
 fn jobs_registry() -> &'static Mutex<HashMap<String, Arc<DataArchiveJob>>> {
@@ -246,16 +285,24 @@ pub fn start_import_data_archive_job(
         DomainError::InternalError(format!("Failed to create job root: {}", error))
     })?;
 
-    let job_id = Uuid::new_v4().simple().to_string();
+    let tracked = track_job(&app_handle, TRACKED_KIND_IMPORT);
+    let job_id = tracked
+        .as_ref()
+        .map(|handle| handle.job_id().to_string())
+        .unwrap_or_else(|| Uuid::new_v4().simple().to_string());
     let job_root = imports_root.join(&job_id);
-    fs::create_dir_all(&job_root).map_err(|error| {
-        DomainError::InternalError(format!("Failed to create job workspace: {}", error))
-    })?;
-
-    let prepared_archive_path =
-        prepare_import_archive_path(archive_path, &job_root, archive_is_temporary)?;
+    let prepared_archive_path = fs::create_dir_all(&job_root)
+        .map_err(|error| {
+            DomainError::InternalError(format!("Failed to create job workspace: {}", error))
+        })
+        .and_then(|_| prepare_import_archive_path(archive_path, &job_root, archive_is_temporary))
+        .inspect_err(|error| {
+            if let Some(tracked) = tracked.as_ref() {
+                tracked.fail(&error.to_string());
+            }
+        })?;
 
-    let job = Arc::new(DataArchiveJob::new(&job_id, KIND_IMPORT));
+    let job = Arc::new(DataArchiveJob::new(&job_id, KIND_IMPORT, tracked));
     register_job(&job_id, job.clone())?;
 
     tauri::async_runtime::spawn(async move {
@@ -339,8 +386,12 @@ pub fn start_export_data_archive_job(app_handle: &AppHandle) -> Result<String, D
     })?;
     cleanup_stale_exports(&export_root);
 
-    let job_id = Uuid::new_v4().simple().to_string();
-    let job = Arc::new(DataArchiveJob::new(&job_id, KIND_EXPORT));
+    let tracked = track_job(app_handle, TRACKED_KIND_EXPORT);
+    let job_id = tracked
+        .as_ref()
+        .map(|handle| handle.job_id().to_string())
+        .unwrap_or_else(|| Uuid::new_v4().simple().to_string());
+    let job = Arc::new(DataArchiveJob::new(&job_id, KIND_EXPORT, tracked));
     register_job(&job_id, job.clone())?;
 
     let output_path = export_root.join(default_export_file_name());
@@ -528,18 +579,16 @@ fn save_staged_archive_to_downloads(
         )));
     }
 
-    let download_dir = app_handle.path().download_dir().map_err(|error| {
-        DomainError::InternalError(format!("Failed to resolve downloads directory: {}", error))
-    })?;
-    fs::create_dir_all(&download_dir).map_err(|error| {
+    let save_dir = resolve_archive_save_dir(app_handle)?;
+    fs::create_dir_all(&save_dir).map_err(|error| {
         DomainError::InternalError(format!(
-            "Failed to create downloads directory {}: {}",
-            download_dir.display(),
+            "Failed to create export directory {}: {}",
+            save_dir.display(),
             error
         ))
     })?;
 
-    let target_path = download_dir.join(file_name);
+    let target_path = save_dir.join(file_name);
     if target_path.exists() {
         return Err(DomainError::InvalidData(format!(
             "Export target already exists: {}",
@@ -573,6 +622,26 @@ fn save_staged_archive_to_downloads(
     Ok(target_path)
 }
 
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn resolve_archive_save_dir(app_handle: &AppHandle) -> Result<PathBuf, DomainError> {
+    if let Some(exports_dir) = app_handle.state::<RuntimePaths>().portable_exports_dir() {
+        return Ok(exports_dir);
+    }
+
+    resolve_downloads_dir(app_handle)
+}
+
+#[cfg(any(target_os = "android", target_os = "ios"))]
+fn resolve_archive_save_dir(app_handle: &AppHandle) -> Result<PathBuf, DomainError> {
+    resolve_downloads_dir(app_handle)
+}
+
+fn resolve_downloads_dir(app_handle: &AppHandle) -> Result<PathBuf, DomainError> {
+    app_handle.path().download_dir().map_err(|error| {
+        DomainError::InternalError(format!("Failed to resolve downloads directory: {}", error))
+    })
+}
+
 fn validate_archive_file_name(file_name: &str) -> Result<String, DomainError> {
     let file_name = file_name.trim();
     if file_name.is_empty() {
@@ -0,0 +1,559 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::file_system::delete_file;
+use crate::infrastructure::persistence::jsonl_utils::write_jsonl_bytes_file;
+use crate::infrastructure::persistence::png_utils::read_character_data_from_png;
+use crate::infrastructure::repositories::chat_directory_identity::{
+    ChatAliasStore, chat_alias_path_for_user_dir,
+};
+
+const CARD_EXTENSION: &str = "png";
+const CHAT_EXTENSION: &str = "jsonl";
+const JSON_EXTENSION: &str = "json";
+const BACKUP_SEGMENT: &str = "data-doctor";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataIssueKind {
+    /// Chat folder whose character card no longer exists
+    OrphanedChats,
+    /// Chat file with lines that are not valid JSON
+    InvalidJsonlLines,
+    /// Character PNG without readable card metadata
+    BrokenCardMetadata,
+    /// Empty card, chat, group or world file
+    ZeroByteFile,
+    /// Group pointing at a character card or chat file that does not exist
+    MissingReference,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataIssue {
+    pub kind: DataIssueKind,
+    /// Path relative to the user data directory, with `/` separators
+    pub path: String,
+    pub detail: String,
+    /// Whether `run` can repair the issue without losing user content
+    pub fixable: bool,
+    pub fixed: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DataDoctorReport {
+    pub scanned_files: usize,
+    pub issues: Vec<DataIssue>,
+    pub fixed: usize,
+}
+
+/// Consistency checker for a user data directory.
+///
+/// Only repairs that cannot lose content are applied: empty files are removed, and chat
+/// files keep every parseable line after the original is copied to
+/// `backups/data-doctor/`. Orphaned chats, broken cards and dangling group references are
+/// reported for the user to resolve.
+pub struct DataDoctor {
+    user_dir: PathBuf,
+    characters_dir: PathBuf,
+    chats_dir: PathBuf,
+    group_chats_dir: PathBuf,
+    groups_dir: PathBuf,
+    worlds_dir: PathBuf,
+    backups_dir: PathBuf,
+}
+
+/// Files gathered before checking, so progress can be reported against a known total
+#[derive(Default)]
+struct ScanTargets {
+    cards: Vec<PathBuf>,
+    chat_folders: Vec<String>,
+    chats: Vec<PathBuf>,
+    groups: Vec<PathBuf>,
+    worlds: Vec<PathBuf>,
+}
+
+impl ScanTargets {
+    fn total(&self) -> usize {
+        self.cards.len() + self.chats.len() + self.groups.len() + self.worlds.len()
+    }
+}
+
+impl DataDoctor {
+    pub fn new(
+        user_dir: PathBuf,
+        characters_dir: PathBuf,
+        chats_dir: PathBuf,
+        group_chats_dir: PathBuf,
+        groups_dir: PathBuf,
+        worlds_dir: PathBuf,
+        backups_dir: PathBuf,
+    ) -> Self {
+        Self {
+            user_dir,
+            characters_dir,
+            chats_dir,
+            group_chats_dir,
+            groups_dir,
+            worlds_dir,
+            backups_dir,
+        }
+    }
+
+    /// Scans the data directory, applying safe fixes when `fix` is set.
+    ///
+    /// `on_progress` receives `(done, total)` and `is_cancelled` is polled per file.
+    pub async fn run(
+        &self,
+        fix: bool,
+        on_progress: &(dyn Fn(usize, usize) + Sync),
+        is_cancelled: &(dyn Fn() -> bool + Sync),
+    ) -> Result<DataDoctorReport, DomainError> {
+        let targets = self.collect_targets().await?;
+        let total = targets.total();
+        let mut report = DataDoctorReport::default();
+
+        let card_stems: HashSet<String> = targets
+            .cards
+            .iter()
+            .filter_map(|path| file_stem(path))
+            .collect();
+        self.check_orphaned_chats(&targets.chat_folders, &card_stems, &mut report)
+            .await?;
+
+        let files = targets
+            .cards
+            .iter()
+            .map(|path| (path, FileRole::Card))
+            .chain(targets.chats.iter().map(|path| (path, FileRole::Chat)))
+            .chain(targets.groups.iter().map(|path| (path, FileRole::Group)))
+            .chain(targets.worlds.iter().map(|path| (path, FileRole::World)));
+
+        for (index, (path, role)) in files.enumerate() {
+            if is_cancelled() {
+                return Err(DomainError::cancelled("Data doctor cancelled"));
+            }
+
+            self.check_file(path, role, &card_stems, fix, &mut report)
+                .await?;
+            report.scanned_files += 1;
+            on_progress(index + 1, total);
+        }
+
+        report.fixed = report.issues.iter().filter(|issue| issue.fixed).count();
+        Ok(report)
+    }
+
+    async fn collect_targets(&self) -> Result<ScanTargets, DomainError> {
+        let mut targets = ScanTargets {
+            cards: list_files(&self.characters_dir, CARD_EXTENSION).await?,
+            groups: list_files(&self.groups_dir, JSON_EXTENSION).await?,
+            worlds: list_files(&self.worlds_dir, JSON_EXTENSION).await?,
+            chats: list_files(&self.group_chats_dir, CHAT_EXTENSION).await?,
+            ..ScanTargets::default()
+        };
+
+        for folder in list_directories(&self.chats_dir).await? {
+            let Some(name) = folder.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            targets.chat_folders.push(name.to_string());
+            targets
+                .chats
+                .extend(list_files(&folder, CHAT_EXTENSION).await?);
+        }
+        targets.chat_folders.sort();
+
+        Ok(targets)
+    }
+
+    async fn check_orphaned_chats(
+        &self,
+        chat_folders: &[String],
+        card_stems: &HashSet<String>,
+        report: &mut DataDoctorReport,
+    ) -> Result<(), DomainError> {
+        // Characters whose names cannot be used as folder names keep chats in an aliased folder.
+        let mut alias_store = ChatAliasStore::new(chat_alias_path_for_user_dir(&self.user_dir));
+        let aliased_folders: HashSet<String> = alias_store
+            .alias_dirs()
+            .await?
+            .into_iter()
+            .filter(|(character_key, _)| card_stems.contains(character_key))
+            .map(|(_, dir)| dir)
+            .collect();
+
+        for folder in chat_folders {
+            if card_stems.contains(folder) || aliased_folders.contains(folder) {
+                continue;
+            }
+
+            let folder_path = self.chats_dir.join(folder);
+            let chat_count = list_files(&folder_path, CHAT_EXTENSION).await?.len();
+            if chat_count == 0 {
+                continue;
+            }
+            report.issues.push(DataIssue {
+                kind: DataIssueKind::OrphanedChats,
+                path: self.relative_path(&folder_path),
+                detail: format!("{} chat(s) without a matching character card", chat_count),
+                fixable: false,
+                fixed: false,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn check_file(
+        &self,
+        path: &Path,
+        role: FileRole,
+        card_stems: &HashSet<String>,
+        fix: bool,
+        report: &mut DataDoctorReport,
+    ) -> Result<(), DomainError> {
+        let bytes = fs::read(path).await.map_err(|error| {
+            DomainError::InternalError(format!("Failed to read {}: {}", path.display(), error))
+        })?;
+
+        if bytes.is_empty() {
+            let fixed = fix && delete_file(path).await.is_ok();
+            report.issues.push(DataIssue {
+                kind: DataIssueKind::ZeroByteFile,
+                path: self.relative_path(path),
+                detail: "File is empty".to_string(),
+                fixable: true,
+                fixed,
+            });
+            return Ok(());
+        }
+
+        match role {
+            FileRole::Card => {
+                if let Err(error) = read_character_data_from_png(&bytes) {
+                    report.issues.push(DataIssue {
+                        kind: DataIssueKind::BrokenCardMetadata,
+                        path: self.relative_path(path),
+                        detail: error.to_string(),
+                        fixable: false,
+                        fixed: false,
+                    });
+                }
+            }
+            FileRole::Chat => self.check_chat(path, &bytes, fix, report).await?,
+            FileRole::Group => self.check_group(path, &bytes, card_stems, report),
+            FileRole::World => {}
+        }
+
+        Ok(())
+    }
+
+    async fn check_chat(
+        &self,
+        path: &Path,
+        bytes: &[u8],
+        fix: bool,
+        report: &mut DataDoctorReport,
+    ) -> Result<(), DomainError> {
+        let text = String::from_utf8_lossy(bytes);
+        let mut valid_lines = Vec::new();
+        let mut invalid_lines = Vec::new();
+        // Without a valid header line the chat cannot be reopened, so leave it as is.
+        let mut header_is_valid = None;
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let is_valid = serde_json::from_str::<Value>(line).is_ok();
+            header_is_valid.get_or_insert(is_valid);
+            if is_valid {
+                valid_lines.push(line);
+            } else {
+                invalid_lines.push(index + 1);
+            }
+        }
+        if invalid_lines.is_empty() {
+            return Ok(());
+        }
+
+        let header_is_valid = header_is_valid == Some(true);
+        let mut fixed = false;
+        if fix && header_is_valid {
+            self.back_up(path).await?;
+            let mut repaired = valid_lines.join("\n");
+            repaired.push('\n');
+            write_jsonl_bytes_file(path, repaired.as_bytes()).await?;
+            fixed = true;
+        }
+
+        report.issues.push(DataIssue {
+            kind: DataIssueKind::InvalidJsonlLines,
+            path: self.relative_path(path),
+            detail: format!("Invalid JSON on line(s) {}", join_numbers(&invalid_lines)),
+            fixable: header_is_valid,
+            fixed,
+        });
+        Ok(())
+    }
+
+    fn check_group(
+        &self,
+        path: &Path,
+        bytes: &[u8],
+        card_stems: &HashSet<String>,
+        report: &mut DataDoctorReport,
+    ) {
+        let Ok(group) = serde_json::from_slice::<Value>(bytes) else {
+            report.issues.push(DataIssue {
+                kind: DataIssueKind::MissingReference,
+                path: self.relative_path(path),
+                detail: "Group file is not valid JSON".to_string(),
+                fixable: false,
+                fixed: false,
+            });
+            return;
+        };
+
+        let mut missing = Vec::new();
+        for avatar in string_array(&group, "members") {
+            let stem = avatar.strip_suffix(".png").unwrap_or(avatar);
+            if !card_stems.contains(stem) {
+                missing.push(format!("member {}", avatar));
+            }
+        }
+        for chat_id in string_array(&group, "chats") {
+            let chat_path = self
+                .group_chats_dir
+                .join(format!("{}.{}", chat_id, CHAT_EXTENSION));
+            if !chat_path.is_file() {
+                missing.push(format!("chat {}", chat_id));
+            }
+        }
+
+        if !missing.is_empty() {
+            report.issues.push(DataIssue {
+                kind: DataIssueKind::MissingReference,
+                path: self.relative_path(path),
+                detail: format!("Missing {}", missing.join(", ")),
+                fixable: false,
+                fixed: false,
+            });
+        }
+    }
+
+    async fn back_up(&self, path: &Path) -> Result<(), DomainError> {
+        let relative = path.strip_prefix(&self.user_dir).unwrap_or(path);
+        let backup_path = self.backups_dir.join(BACKUP_SEGMENT).join(relative);
+        if let Some(parent) = backup_path.parent() {
+            fs::create_dir_all(parent).await.map_err(|error| {
+                DomainError::InternalError(format!(
+                    "Failed to create backup directory {}: {}",
+                    parent.display(),
+                    error
+                ))
+            })?;
+        }
+        fs::copy(path, &backup_path).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to back up {} before repair: {}",
+                path.display(),
+                error
+            ))
+        })?;
+        Ok(())
+    }
+
+    fn relative_path(&self, path: &Path) -> String {
+        path.strip_prefix(&self.user_dir)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+}
+
+#[derive(Clone, Copy)]
+enum FileRole {
+    Card,
+    Chat,
+    Group,
+    World,
+}
+
+fn file_stem(path: &Path) -> Option<String> {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .map(str::to_string)
+}
+
+fn string_array<'a>(value: &'a Value, key: &str) -> impl Iterator<Item = &'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+}
+
+fn join_numbers(numbers: &[usize]) -> String {
+    numbers
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+async fn list_files(directory: &Path, extension: &str) -> Result<Vec<PathBuf>, DomainError> {
+    let mut files = Vec::new();
+    for path in read_dir_paths(directory).await? {
+        let matches_extension = path
+            .extension()
+            .is_some_and(|value| value.eq_ignore_ascii_case(extension));
+        if matches_extension && path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+async fn list_directories(directory: &Path) -> Result<Vec<PathBuf>, DomainError> {
+    let mut directories: Vec<PathBuf> = read_dir_paths(directory)
+        .await?
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .collect();
+    directories.sort();
+    Ok(directories)
+}
+
+async fn read_dir_paths(directory: &Path) -> Result<Vec<PathBuf>, DomainError> {
+    let mut entries = match fs::read_dir(directory).await {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => {
+            return Err(DomainError::InternalError(format!(
+                "Failed to read {}: {}",
+                directory.display(),
+                error
+            )));
+        }
+    };
+
+    let mut paths = Vec::new();
+    while let Some(entry) = entries.next_entry().await.map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to read entry in {}: {}",
+            directory.display(),
+            error
+        ))
+    })? {
+        paths.push(entry.path());
+    }
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use serde_json::json;
+
+    use super::{DataDoctor, DataIssueKind};
+    use crate::infrastructure::persistence::png_utils::write_character_data_to_png;
+
+    fn minimal_png() -> Vec<u8> {
+        let image = image::DynamicImage::ImageRgba8(image::RgbaImage::new(1, 1));
+        let mut output = Vec::new();
+        image
+            .write_to(
+                &mut std::io::Cursor::new(&mut output),
+                image::ImageFormat::Png,
+            )
+            .expect("encode png");
+        output
+    }
+
+    fn doctor(root: &Path) -> DataDoctor {
+        DataDoctor::new(
+            root.to_path_buf(),
+            root.join("characters"),
+            root.join("chats"),
+            root.join("group chats"),
+            root.join("groups"),
+            root.join("worlds"),
+            root.join("backups"),
+        )
+    }
+
+    fn write(path: PathBuf, bytes: impl AsRef<[u8]>) {
+        std::fs::create_dir_all(path.parent().expect("parent")).expect("create dir");
+        std::fs::write(path, bytes).expect("write file");
+    }
+
+    #[tokio::test]
+    async fn reports_issues_and_applies_only_safe_fixes() {
+        let root =
+            std::env::temp_dir().join(format!("tauritavern-data-doctor-{}", uuid::Uuid::new_v4()));
+        let card =
+            write_character_data_to_png(&minimal_png(), r#"{"name":"Alice"}"#).expect("embed card");
+        write(root.join("characters/Alice.png"), card);
+        write(root.join("characters/Broken.png"), minimal_png());
+        write(root.join("characters/Empty.png"), b"");
+        write(
+            root.join("chats/Alice/chat.jsonl"),
+            "{\"user_name\":\"User\"}\n{\"mes\":\"hi\"}\nnot json\n",
+        );
+        write(
+            root.join("chats/Gone/chat.jsonl"),
+            "{\"user_name\":\"User\"}\n",
+        );
+        write(
+            root.join("groups/1.json"),
+            json!({ "members": ["Alice.png", "Bob.png"], "chats": [] }).to_string(),
+        );
+
+        let report = doctor(&root)
+            .run(false, &|_, _| {}, &|| false)
+            .await
+            .expect("scan");
+        let kinds: Vec<(DataIssueKind, &str)> = report
+            .issues
+            .iter()
+            .map(|issue| (issue.kind, issue.path.as_str()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (DataIssueKind::OrphanedChats, "chats/Gone"),
+                (DataIssueKind::BrokenCardMetadata, "characters/Broken.png"),
+                (DataIssueKind::ZeroByteFile, "characters/Empty.png"),
+                (DataIssueKind::InvalidJsonlLines, "chats/Alice/chat.jsonl"),
+                (DataIssueKind::MissingReference, "groups/1.json"),
+            ]
+        );
+        assert_eq!(report.fixed, 0);
+
+        let fixed = doctor(&root)
+            .run(true, &|_, _| {}, &|| false)
+            .await
+            .expect("fix");
+        assert_eq!(fixed.fixed, 2);
+        assert!(!root.join("characters/Empty.png").exists());
+        assert_eq!(
+            std::fs::read_to_string(root.join("chats/Alice/chat.jsonl")).expect("read chat"),
+            "{\"user_name\":\"User\"}\n{\"mes\":\"hi\"}\n"
+        );
+        assert!(
+            root.join("backups/data-doctor/chats/Alice/chat.jsonl")
+                .is_file()
+        );
+        assert!(root.join("chats/Gone/chat.jsonl").is_file());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::sillytavern_migration::{
+    MigrationCategory, MigrationFailure, copy_file, io_error, list_files_recursive,
+    locate_user_dir, relative_display,
+};
+
+const CHARACTERS_DIR: &str = "characters";
+const CHATS_DIR: &str = "chats";
+const COMPRESSED_CHAT_SUFFIX: &str = ".jsonl.zst";
+
+/// What to do when a file from the other installation already exists here
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeConflictStrategy {
+    /// Keep our file
+    #[default]
+    Skip,
+    /// Copy theirs next to ours as `name (2).ext`
+    Rename,
+    /// Keep whichever file was modified last
+    NewerWins,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergeCategoryReport {
+    pub category: MigrationCategory,
+    pub strategy: MergeConflictStrategy,
+    /// Files that did not exist here
+    pub copied: usize,
+    /// Conflicting files replaced by a newer copy
+    pub replaced: usize,
+    /// Conflicting files copied under a new name
+    pub renamed: usize,
+    /// Conflicting files that were left alone
+    pub skipped: usize,
+    pub failures: Vec<MigrationFailure>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExternalDataMergeReport {
+    /// The detected user directory of the other installation
+    pub source: String,
+    pub categories: Vec<MergeCategoryReport>,
+    pub changed: usize,
+    pub failed: usize,
+}
+
+/// Merges the library of another TauriTavern data directory into ours.
+///
+/// Settings and secrets stay per installation and are not merged. A renamed character
+/// card takes its chat folder along; groups keep referring to the original card name.
+pub struct ExternalDataMerge {
+    source_user_dir: PathBuf,
+    target_user_dir: PathBuf,
+    default_strategy: MergeConflictStrategy,
+    category_strategies: HashMap<MigrationCategory, MergeConflictStrategy>,
+}
+
+impl ExternalDataMerge {
+    pub fn detect(
+        path: &Path,
+        target_user_dir: PathBuf,
+        default_strategy: MergeConflictStrategy,
+        category_strategies: HashMap<MigrationCategory, MergeConflictStrategy>,
+    ) -> Result<Self, DomainError> {
+        let source_user_dir = locate_user_dir(path).ok_or_else(|| {
+            DomainError::InvalidData(format!(
+                "No TauriTavern data directory found in {}",
+                path.display()
+            ))
+        })?;
+
+        let source = dunce::canonicalize(&source_user_dir).unwrap_or(source_user_dir);
+        let target = dunce::canonicalize(&target_user_dir).unwrap_or(target_user_dir.clone());
+        if source == target {
+            return Err(DomainError::InvalidData(
+                "The selected folder is the current data directory".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            source_user_dir: source,
+            target_user_dir,
+            default_strategy,
+            category_strategies,
+        })
+    }
+
+    fn strategy(&self, category: MigrationCategory) -> MergeConflictStrategy {
+        self.category_strategies
+            .get(&category)
+            .copied()
+            .unwrap_or(self.default_strategy)
+    }
+
+    /// Merges the source files one at a time, characters first. A file that fails is
+    /// recorded in its category report and the merge moves on; `on_progress` gets the
+    /// count of source files handled so far. Cancelling stops before the next file and
+    /// keeps everything merged up to that point, so a rerun with `Skip` picks up the rest.
+    pub async fn run(
+        &self,
+        on_progress: &(dyn Fn(usize, usize) + Sync),
+        is_cancelled: &(dyn Fn() -> bool + Sync),
+    ) -> Result<ExternalDataMergeReport, DomainError> {
+        let mut items = Vec::new();
+        let mut categories = Vec::new();
+        for category in MigrationCategory::ALL {
+            if category.directories().is_empty() {
+                continue;
+            }
+            for directory in category.directories() {
+                for relative in list_files_recursive(&self.source_user_dir, directory).await? {
+                    items.push((category, relative));
+                }
+            }
+            categories.push(MergeCategoryReport {
+                category,
+                strategy: self.strategy(category),
+                copied: 0,
+                replaced: 0,
+                renamed: 0,
+                skipped: 0,
+                failures: Vec::new(),
+            });
+        }
+
+        // Characters come first, so their chat folders can follow a rename.
+        let mut renamed_characters: HashMap<String, String> = HashMap::new();
+        let total = items.len();
+        for (index, (category, relative)) in items.iter().enumerate() {
+            if is_cancelled() {
+                return Err(DomainError::cancelled("Data merge cancelled"));
+            }
+
+            let report = categories
+                .iter_mut()
+                .find(|report| report.category == *category)
+                .expect("every merged category has a report");
+            let target_relative = follow_character_rename(relative, &renamed_characters);
+            match self
+                .merge_file(relative, &target_relative, report.strategy)
+                .await
+            {
+                Ok(MergeOutcome::Copied) => report.copied += 1,
+                Ok(MergeOutcome::Replaced) => report.replaced += 1,
+                Ok(MergeOutcome::Skipped) => report.skipped += 1,
+                Ok(MergeOutcome::Renamed(renamed)) => {
+                    report.renamed += 1;
+                    if let Some((from, to)) = character_stems(relative, &renamed) {
+                        renamed_characters.insert(from, to);
+                    }
+                }
+                Err(error) => report.failures.push(MigrationFailure {
+                    path: relative_display(relative),
+                    error: error.to_string(),
+                }),
+            }
+            on_progress(index + 1, total);
+        }
+
+        Ok(ExternalDataMergeReport {
+            source: self.source_user_dir.to_string_lossy().to_string(),
+            changed: categories
+                .iter()
+                .map(|report| report.copied + report.replaced + report.renamed)
+                .sum(),
+            failed: categories.iter().map(|report| report.failures.len()).sum(),
+            categories,
+        })
+    }
+
+    async fn merge_file(
+        &self,
+        relative: &Path,
+        target_relative: &Path,
+        strategy: MergeConflictStrategy,
+    ) -> Result<MergeOutcome, DomainError> {
+        let source = self.source_user_dir.join(relative);
+        let target = self.target_user_dir.join(target_relative);
+
+        if !fs::try_exists(&target).await.unwrap_or(false) {
+            copy_file(&source, &target).await?;
+            return Ok(MergeOutcome::Copied);
+        }
+
+        match strategy {
+            MergeConflictStrategy::Skip => Ok(MergeOutcome::Skipped),
+            MergeConflictStrategy::NewerWins => {
+                if modified_at(&source).await? > modified_at(&target).await? {
+                    copy_file(&source, &target).await?;
+                    Ok(MergeOutcome::Replaced)
+                } else {
+                    Ok(MergeOutcome::Skipped)
+                }
+            }
+            MergeConflictStrategy::Rename => {
+                let renamed = available_name(&target);
+                copy_file(&source, &renamed).await?;
+                Ok(MergeOutcome::Renamed(renamed))
+            }
+        }
+    }
+}
+
+enum MergeOutcome {
+    Copied,
+    Replaced,
+    Renamed(PathBuf),
+    Skipped,
+}
+
+async fn modified_at(path: &Path) -> Result<SystemTime, DomainError> {
+    fs::metadata(path)
+        .await
+        .and_then(|metadata| metadata.modified())
+        .map_err(|error| io_error("inspect", path, error))
+}
+
+/// Picks `name (2).ext`, `name (3).ext`, ... next to `path`.
+fn available_name(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let (stem, extension) = split_extension(&file_name);
+
+    let mut suffix = 2;
+    loop {
+        let candidate = path.with_file_name(format!("{} ({}){}", stem, suffix, extension));
+        if !candidate.exists() {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+fn split_extension(file_name: &str) -> (&str, &str) {
+    if let Some(stem) = file_name.strip_suffix(COMPRESSED_CHAT_SUFFIX) {
+        return (stem, COMPRESSED_CHAT_SUFFIX);
+    }
+    match file_name.rfind('.') {
+        Some(index) if index > 0 => file_name.split_at(index),
+        _ => (file_name, ""),
+    }
+}
+
+/// Returns `(old, new)` card stems when `relative` is a top-level card that was renamed.
+fn character_stems(relative: &Path, renamed: &Path) -> Option<(String, String)> {
+    let mut components = relative.components();
+    let is_card = matches!(components.next(), Some(Component::Normal(dir)) if dir == CHARACTERS_DIR)
+        && components.clone().count() == 1;
+    if !is_card {
+        return None;
+    }
+
+    let from = relative.file_stem()?.to_string_lossy().to_string();
+    let to = renamed.file_stem()?.to_string_lossy().to_string();
+    Some((from, to))
+}
+
+/// Maps `chats/<card>/...` to the chat folder of a renamed card.
+fn follow_character_rename(relative: &Path, renamed: &HashMap<String, String>) -> PathBuf {
+    let mut components = relative.components();
+    let (Some(Component::Normal(root)), Some(Component::Normal(folder))) =
+        (components.next(), components.next())
+    else {
+        return relative.to_path_buf();
+    };
+    if root != CHATS_DIR {
+        return relative.to_path_buf();
+    }
+
+    match renamed.get(folder.to_string_lossy().as_ref()) {
+        Some(new_folder) => Path::new(CHATS_DIR)
+            .join(new_folder)
+            .join(components.as_path()),
+        None => relative.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    use super::{ExternalDataMerge, MergeConflictStrategy};
+    use crate::infrastructure::persistence::sillytavern_migration::MigrationCategory;
+
+    fn write(path: PathBuf, bytes: impl AsRef<[u8]>) {
+        std::fs::create_dir_all(path.parent().expect("parent")).expect("create dir");
+        std::fs::write(path, bytes).expect("write file");
+    }
+
+    fn set_modified(path: &PathBuf, time: SystemTime) {
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .expect("open file")
+            .set_modified(time)
+            .expect("set modified time");
+    }
+
+    #[tokio::test]
+    async fn applies_conflict_strategy_per_category() {
+        let root =
+            std::env::temp_dir().join(format!("tauritavern-data-merge-{}", uuid::Uuid::new_v4()));
+        let source = root.join("laptop/data/default-user");
+        let target = root.join("desktop/default-user");
+
+        write(source.join("settings.json"), "{}");
+        write(source.join("characters/Alice.png"), b"laptop card");
+        write(source.join("characters/Bob.png"), b"bob");
+        write(source.join("chats/Alice/trip.jsonl"), "laptop\n");
+        write(source.join("worlds/Lore.json"), "laptop lore");
+        write(source.join("themes/Dark.json"), "laptop theme");
+        write(target.join("characters/Alice.png"), b"desktop card");
+        write(target.join("chats/Alice/trip.jsonl"), "desktop\n");
+        write(target.join("worlds/Lore.json"), "desktop lore");
+        write(target.join("themes/Dark.json"), "desktop theme");
+
+        let now = SystemTime::now();
+        set_modified(
+            &target.join("worlds/Lore.json"),
+            now - Duration::from_secs(60),
+        );
+        set_modified(&source.join("worlds/Lore.json"), now);
+        set_modified(
+            &source.join("themes/Dark.json"),
+            now - Duration::from_secs(60),
+        );
+        set_modified(&target.join("themes/Dark.json"), now);
+
+        let merge = ExternalDataMerge::detect(
+            &root.join("laptop"),
+            target.clone(),
+            MergeConflictStrategy::NewerWins,
+            HashMap::from([(MigrationCategory::Characters, MergeConflictStrategy::Rename)]),
+        )
+        .expect("detect");
+        let report = merge.run(&|_, _| {}, &|| false).await.expect("merge");
+
+        let characters = &report.categories[0];
+        assert_eq!(characters.category, MigrationCategory::Characters);
+        assert_eq!((characters.copied, characters.renamed), (1, 1));
+        assert_eq!(report.changed, 4);
+        assert_eq!(report.failed, 0);
+
+        let read = |path: &str| std::fs::read_to_string(target.join(path)).expect("read file");
+        assert_eq!(read("characters/Alice.png"), "desktop card");
+        assert_eq!(read("characters/Alice (2).png"), "laptop card");
+        assert_eq!(read("chats/Alice/trip.jsonl"), "desktop\n");
+        assert_eq!(read("chats/Alice (2)/trip.jsonl"), "laptop\n");
+        assert_eq!(read("worlds/Lore.json"), "laptop lore");
+        assert_eq!(read("themes/Dark.json"), "desktop theme");
+        assert!(!target.join("settings.json").exists());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,239 @@
+//! Process-wide async write locks keyed by file path.
+//!
+//! The write helpers in [`file_system`](super::file_system) hold the target's lock from the
+//! temp file write through the replace, so rapid saves of the same file (e.g. several
+//! settings saves on app close) land one after another. Code that loads a file, changes it
+//! and saves it back takes the lock itself with [`lock_file`] and writes through the
+//! returned guard, so no other save can slip in between the load and the save.
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::file_system::{
+    atomic_write_locked, delete_file_locked, serialize_json,
+};
+
+/// Waits longer than this are logged as warnings instead of debug messages.
+const SLOW_LOCK_WAIT: Duration = Duration::from_millis(250);
+
+static FILE_LOCKS: OnceLock<FileLockRegistry> = OnceLock::new();
+
+#[derive(Default)]
+struct FileLockRegistry {
+    locks: Mutex<HashMap<PathBuf, Weak<AsyncMutex<()>>>>,
+    acquisitions: AtomicU64,
+    contended: AtomicU64,
+    total_wait_micros: AtomicU64,
+}
+
+/// Lock usage since startup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct FileLockStats {
+    pub acquisitions: u64,
+    /// Acquisitions that had to wait for another writer
+    pub contended: u64,
+    pub total_wait_micros: u64,
+}
+
+/// Held while writing a file; other writers of the same path wait until it is dropped.
+pub struct FileWriteGuard {
+    path: PathBuf,
+    _guard: OwnedMutexGuard<()>,
+}
+
+impl FileWriteGuard {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// [`atomic_write`](super::file_system::atomic_write) under the held lock.
+    pub async fn write(&self, bytes: &[u8]) -> Result<(), DomainError> {
+        atomic_write_locked(&self.path, bytes).await
+    }
+
+    /// [`write_json_file`](super::file_system::write_json_file) under the held lock.
+    pub async fn write_json<T: Serialize + ?Sized>(&self, data: &T) -> Result<(), DomainError> {
+        let json = serialize_json(&self.path, data)?;
+        atomic_write_locked(&self.path, json.as_bytes()).await
+    }
+
+    /// [`delete_file`](super::file_system::delete_file) under the held lock.
+    pub async fn delete(&self) -> Result<(), DomainError> {
+        delete_file_locked(&self.path).await
+    }
+}
+
+/// Waits for exclusive write access to `path`. The lock is not reentrant: while holding it,
+/// write through the guard instead of the `file_system` helpers, which would wait forever.
+pub async fn lock_file(path: &Path) -> FileWriteGuard {
+    let registry = registry();
+    let key = lock_key(path);
+    let lock = registry.lock_for(&key);
+    registry.acquisitions.fetch_add(1, Ordering::Relaxed);
+
+    if let Ok(guard) = lock.clone().try_lock_owned() {
+        return FileWriteGuard {
+            path: path.to_path_buf(),
+            _guard: guard,
+        };
+    }
+
+    let started = Instant::now();
+    let guard = lock.lock_owned().await;
+    let waited = started.elapsed();
+
+    let contended = registry.contended.fetch_add(1, Ordering::Relaxed) + 1;
+    let waited_micros = u64::try_from(waited.as_micros()).unwrap_or(u64::MAX);
+    registry
+        .total_wait_micros
+        .fetch_add(waited_micros, Ordering::Relaxed);
+    let acquisitions = registry.acquisitions.load(Ordering::Relaxed);
+
+    if waited >= SLOW_LOCK_WAIT {
+        tracing::warn!(
+            path = %key.display(),
+            wait_ms = waited.as_millis() as u64,
+            contended,
+            acquisitions,
+            "Slow file write lock"
+        );
+    } else {
+        tracing::debug!(
+            path = %key.display(),
+            wait_ms = waited.as_millis() as u64,
+            contended,
+            acquisitions,
+            "Waited for file write lock"
+        );
+    }
+
+    FileWriteGuard {
+        path: path.to_path_buf(),
+        _guard: guard,
+    }
+}
+
+pub fn file_lock_stats() -> FileLockStats {
+    let registry = registry();
+    FileLockStats {
+        acquisitions: registry.acquisitions.load(Ordering::Relaxed),
+        contended: registry.contended.load(Ordering::Relaxed),
+        total_wait_micros: registry.total_wait_micros.load(Ordering::Relaxed),
+    }
+}
+
+fn registry() -> &'static FileLockRegistry {
+    FILE_LOCKS.get_or_init(FileLockRegistry::default)
+}
+
+impl FileLockRegistry {
+    fn lock_for(&self, key: &Path) -> Arc<AsyncMutex<()>> {
+        let mut locks = self
+            .locks
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(lock) = locks.get(key).and_then(Weak::upgrade) {
+            return lock;
+        }
+
+        // Entries die with their last guard; drop them before adding a new one.
+        locks.retain(|_, lock| lock.strong_count() > 0);
+        let lock = Arc::new(AsyncMutex::new(()));
+        locks.insert(key.to_path_buf(), Arc::downgrade(&lock));
+        lock
+    }
+}
+
+/// Absolute, lexically normalized form of `path`, so `a/./b.json` and `a/b.json` share a
+/// lock. Symlinks are not resolved since the file may not exist yet.
+fn lock_key(path: &Path) -> PathBuf {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut normalized = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use super::{file_lock_stats, lock_file, lock_key};
+    use crate::infrastructure::persistence::file_system::{atomic_write, read_json_file};
+
+    #[test]
+    fn equivalent_paths_share_a_key() {
+        let root = std::env::temp_dir();
+        assert_eq!(
+            lock_key(&root.join("a").join(".").join("b.json")),
+            lock_key(&root.join("a").join("c").join("..").join("b.json"))
+        );
+    }
+
+    #[tokio::test]
+    async fn writers_of_the_same_file_are_serialized() {
+        let path = std::env::temp_dir().join(format!("tt-file-lock-{}", uuid::Uuid::new_v4()));
+        let active = Arc::new(AtomicUsize::new(0));
+        let before = file_lock_stats();
+
+        let mut tasks = Vec::new();
+        for _ in 0..4 {
+            let path = path.clone();
+            let active = active.clone();
+            tasks.push(tokio::spawn(async move {
+                let _guard = lock_file(&path).await;
+                assert_eq!(active.fetch_add(1, Ordering::SeqCst), 0);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                active.fetch_sub(1, Ordering::SeqCst);
+            }));
+        }
+        for task in tasks {
+            task.await.expect("writer task");
+        }
+
+        let after = file_lock_stats();
+        assert!(after.acquisitions >= before.acquisitions + 4);
+        assert!(after.contended > before.contended);
+    }
+
+    #[tokio::test]
+    async fn read_modify_write_through_the_guard_keeps_every_update() {
+        let root = std::env::temp_dir().join(format!("tt-file-lock-{}", uuid::Uuid::new_v4()));
+        let path = root.join("counter.json");
+        atomic_write(&path, b"0").await.expect("seed counter");
+
+        let mut tasks = Vec::new();
+        for _ in 0..2 {
+            let path = path.clone();
+            tasks.push(tokio::spawn(async move {
+                let file = lock_file(&path).await;
+                let count: u64 = read_json_file(file.path()).await.expect("read counter");
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                file.write_json(&(count + 1)).await.expect("write counter");
+            }));
+        }
+        for task in tasks {
+            task.await.expect("writer task");
+        }
+
+        let count: u64 = read_json_file(&path).await.expect("read counter");
+        assert_eq!(count, 2);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,115 @@
+//! Naming helpers for repositories that store uploaded files under per-character or
+//! per-scope directories (character assets, sounds, sprites, Data Bank attachments).
+
+use std::path::{Path, PathBuf};
+
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::filename::sanitize_filename;
+
+/// Avatar file name without its `.png` extension, the stem per-character files are named after.
+pub fn avatar_stem(avatar: &str) -> &str {
+    avatar
+        .strip_suffix(".png")
+        .or_else(|| avatar.strip_suffix(".PNG"))
+        .unwrap_or(avatar)
+}
+
+/// Directory name for a character given its avatar file name or bare name.
+pub fn character_directory_name(character: &str) -> Result<String, DomainError> {
+    let sanitized = sanitize_filename(avatar_stem(character.trim()));
+    if sanitized.is_empty() {
+        return Err(DomainError::InvalidData(
+            "Invalid character name".to_string(),
+        ));
+    }
+    Ok(sanitized)
+}
+
+/// Sanitized form of a user supplied name. `label` says what it names in the error, e.g.
+/// "sound file name".
+pub fn sanitized_name(name: &str, label: &str) -> Result<String, DomainError> {
+    let sanitized = sanitize_filename(name.trim());
+    if sanitized.is_empty() {
+        return Err(DomainError::InvalidData(format!(
+            "Invalid {}: {}",
+            label, name
+        )));
+    }
+    Ok(sanitized)
+}
+
+/// `dir/<stem>.<extension>`, or `dir/<stem>-<n>.<extension>` with the first free `n` when
+/// the name is taken. An empty `extension` leaves the name without one.
+pub async fn unique_file_path(
+    dir: &Path,
+    stem: &str,
+    extension: &str,
+) -> Result<PathBuf, DomainError> {
+    let file_name = |suffix: String| match extension {
+        "" => format!("{stem}{suffix}"),
+        extension => format!("{stem}{suffix}.{extension}"),
+    };
+
+    let candidate = dir.join(file_name(String::new()));
+    if !path_exists(&candidate).await? {
+        return Ok(candidate);
+    }
+
+    for index in 1.. {
+        let candidate = dir.join(file_name(format!("-{index}")));
+        if !path_exists(&candidate).await? {
+            return Ok(candidate);
+        }
+    }
+
+    unreachable!("unbounded suffix search always returns")
+}
+
+pub async fn path_exists(path: &Path) -> Result<bool, DomainError> {
+    fs::try_exists(path).await.map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to check path '{}': {}",
+            path.display(),
+            error
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{character_directory_name, sanitized_name, unique_file_path};
+
+    #[test]
+    fn character_directory_name_drops_avatar_extension() {
+        assert_eq!(character_directory_name(" Alice.png ").unwrap(), "Alice");
+        assert_eq!(character_directory_name("Bob.PNG").unwrap(), "Bob");
+        assert!(character_directory_name("..").is_err());
+        assert!(sanitized_name("/", "sound file name").is_err());
+    }
+
+    #[tokio::test]
+    async fn unique_file_path_skips_taken_names() {
+        let dir = std::env::temp_dir().join(format!("tt-file-names-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("create dir");
+        std::fs::write(dir.join("song.mp3"), b"").expect("write file");
+        std::fs::write(dir.join("song-1.mp3"), b"").expect("write file");
+        std::fs::write(dir.join("notes"), b"").expect("write file");
+
+        assert_eq!(
+            unique_file_path(&dir, "song", "mp3").await.unwrap(),
+            dir.join("song-2.mp3")
+        );
+        assert_eq!(
+            unique_file_path(&dir, "notes", "").await.unwrap(),
+            dir.join("notes-1")
+        );
+        assert_eq!(
+            unique_file_path(&dir, "other", "mp3").await.unwrap(),
+            dir.join("other.mp3")
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
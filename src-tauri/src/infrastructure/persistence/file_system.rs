@@ -1,5 +1,7 @@
 use crate::domain::errors::DomainError;
 use crate::infrastructure::logging::logger;
+use crate::infrastructure::persistence::file_locks::lock_file;
+use crate::infrastructure::persistence::internal_writes::record_internal_write;
 use serde::{Serialize, de::DeserializeOwned};
 use std::io;
 use std::path::{Path, PathBuf};
@@ -113,9 +115,13 @@ impl DataDirectory {
             "reasoning",
         ];
 
-        for dir in default_user_dirs.iter() {
-            self.create_directory(&self.default_user.join(dir)).await?;
-        }
+        // `create_dir_all` tolerates concurrent creation of shared parents, so nested
+        // entries like `thumbnails/bg` can be created alongside `thumbnails`.
+        futures_util::future::try_join_all(default_user_dirs.iter().map(|dir| {
+            let path = self.default_user.join(dir);
+            async move { self.create_directory(&path).await }
+        }))
+        .await?;
 
         tracing::debug!("Data directory initialized successfully");
         Ok(())
@@ -192,6 +198,44 @@ impl DataDirectory {
     pub fn backups(&self) -> &Path {
         &self.backups
     }
+
+    /// Directories that receive `atomic_write`s and may hold temp files from an
+    /// interrupted one. Installed extensions, thumbnails, assets and backups are written
+    /// in place, so startup recovery never walks them.
+    pub fn interrupted_write_scope(&self) -> InterruptedWriteScope {
+        let trees = [
+            "characters",
+            "chats",
+            "group chats",
+            "groups",
+            "User Avatars",
+            "backgrounds",
+            "worlds",
+            "user",
+            "files",
+            "author-notes",
+            "themes",
+            "QuickReplies",
+            "NovelAI Settings",
+            "KoboldAI Settings",
+            "OpenAI Settings",
+            "TextGen Settings",
+            "instruct",
+            "context",
+            "sysprompt",
+            "reasoning",
+            "movingUI",
+        ]
+        .iter()
+        .map(|dir| self.default_user.join(dir))
+        .chain([self.tauritavern.clone()])
+        .collect();
+
+        InterruptedWriteScope {
+            files_in: vec![self.default_user.clone()],
+            trees,
+        }
+    }
 }
 
 /// Read a JSON file and deserialize it
@@ -243,7 +287,6 @@ async fn optional_metadata(path: &Path) -> Result<Option<std::fs::Metadata>, Dom
     }
 }
 
-#[cfg(not(any(target_os = "android", target_os = "ios")))]
 fn optional_metadata_sync(path: &Path) -> Result<Option<std::fs::Metadata>, DomainError> {
     match std::fs::symlink_metadata(path) {
         Ok(metadata) => Ok(Some(metadata)),
@@ -298,6 +341,10 @@ pub async fn move_file_no_replace_with_fallback(
     source_path: &Path,
     target_path: &Path,
 ) -> Result<(), DomainError> {
+    let _lock = lock_file(target_path).await;
+    record_internal_write(source_path);
+    record_internal_write(target_path);
+
     let Some(source_metadata) = optional_metadata(source_path).await? else {
         return Err(DomainError::NotFound(format!(
             "Source file not found: {}",
@@ -413,6 +460,14 @@ pub async fn replace_file_with_fallback(
     temp_path: &Path,
     target_path: &Path,
 ) -> Result<(), DomainError> {
+    let _lock = lock_file(target_path).await;
+    replace_file_locked(temp_path, target_path).await
+}
+
+/// `replace_file_with_fallback` for callers already holding the target's lock.
+async fn replace_file_locked(temp_path: &Path, target_path: &Path) -> Result<(), DomainError> {
+    record_internal_write(target_path);
+
     let Some(temp_metadata) = optional_metadata(temp_path).await? else {
         return Err(DomainError::NotFound(format!(
             "Temp file not found: {}",
@@ -499,11 +554,12 @@ pub async fn replace_file_with_fallback(
 
 /// Synchronous variant of `replace_file_with_fallback` for startup/runtime code paths
 /// that cannot rely on Tokio being available yet.
-#[cfg(not(any(target_os = "android", target_os = "ios")))]
 pub fn replace_file_with_fallback_sync(
     temp_path: &Path,
     target_path: &Path,
 ) -> Result<(), DomainError> {
+    record_internal_write(target_path);
+
     let Some(temp_metadata) = optional_metadata_sync(temp_path)? else {
         return Err(DomainError::NotFound(format!(
             "Temp file not found: {}",
@@ -586,6 +642,54 @@ pub fn replace_file_with_fallback_sync(
     }
 }
 
+/// Synchronous [`atomic_write`] for code that runs before the async runtime is up or on a
+/// blocking thread. It does not take the [`lock_file`] lock, so callers serialize writes
+/// of the same file themselves.
+pub fn atomic_write_sync(path: &Path, bytes: &[u8]) -> Result<(), DomainError> {
+    use std::io::Write;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            logger::error(&format!(
+                "Failed to create parent directory for {:?}: {}",
+                path, e
+            ));
+            DomainError::InternalError(format!("Failed to create directory: {}", e))
+        })?;
+    }
+
+    let temp_path = unique_temp_path(path, "data");
+    let written = std::fs::File::create(&temp_path).and_then(|mut file| {
+        file.write_all(bytes)?;
+        file.sync_all()
+    });
+    if let Err(error) = written {
+        let _ = std::fs::remove_file(&temp_path);
+        logger::error(&format!(
+            "Failed to write temp file {:?} -> {:?}: {}",
+            temp_path, path, error
+        ));
+        return Err(DomainError::InternalError(format!(
+            "Failed to write file: {}",
+            error
+        )));
+    }
+
+    if let Err(error) = replace_file_with_fallback_sync(&temp_path, path) {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(error);
+    }
+    #[cfg(unix)]
+    if let Some(directory) = path
+        .parent()
+        .and_then(|parent| std::fs::File::open(parent).ok())
+    {
+        let _ = directory.sync_all();
+    }
+
+    Ok(())
+}
+
 /// Write a JSON file
 ///
 /// This is an async function that serializes data to JSON and writes it to a file.
@@ -596,7 +700,38 @@ pub async fn write_json_file<T: Serialize + ?Sized>(
 ) -> Result<(), DomainError> {
     logger::debug(&format!("Writing JSON file: {:?}", path));
 
-    // Ensure the parent directory exists
+    let json = serialize_json(path, data)?;
+    atomic_write(path, json.as_bytes()).await
+}
+
+pub(super) fn serialize_json<T: Serialize + ?Sized>(
+    path: &Path,
+    data: &T,
+) -> Result<String, DomainError> {
+    serde_json::to_string_pretty(data).map_err(|e| {
+        logger::error(&format!(
+            "Failed to serialize to JSON for file {:?}: {}",
+            path, e
+        ));
+        DomainError::InvalidData(format!("Failed to serialize to JSON: {}", e))
+    })
+}
+
+/// Write `bytes` to `path` without ever leaving a truncated target behind.
+///
+/// The data goes to a unique temp file next to the target, is flushed to disk, and then
+/// replaces the target. If the process dies before the replace, the old file is intact and
+/// the leftover temp file is handled by `recover_interrupted_writes` on the next start.
+///
+/// The whole write runs under the path's [`lock_file`] lock. Callers that read the file
+/// and write it back should hold the lock themselves and write through the guard.
+pub async fn atomic_write(path: &Path, bytes: &[u8]) -> Result<(), DomainError> {
+    let _lock = lock_file(path).await;
+    atomic_write_locked(path, bytes).await
+}
+
+/// `atomic_write` for callers already holding the target's lock.
+pub(super) async fn atomic_write_locked(path: &Path, bytes: &[u8]) -> Result<(), DomainError> {
     if let Some(parent) = path.parent() {
         create_dir_all(parent).await.map_err(|e| {
             logger::error(&format!(
@@ -607,33 +742,217 @@ pub async fn write_json_file<T: Serialize + ?Sized>(
         })?;
     }
 
-    // Serialize data to JSON
-    let json = serde_json::to_string_pretty(data).map_err(|e| {
+    let temp_path = unique_temp_path(path, "data");
+    if let Err(error) = write_synced(&temp_path, bytes).await {
+        let _ = tokio_fs::remove_file(&temp_path).await;
         logger::error(&format!(
-            "Failed to serialize to JSON for file {:?}: {}",
-            path, e
+            "Failed to write temp file {:?} -> {:?}: {}",
+            temp_path, path, error
         ));
-        DomainError::InvalidData(format!("Failed to serialize to JSON: {}", e))
-    })?;
+        return Err(DomainError::InternalError(format!(
+            "Failed to write file: {}",
+            error
+        )));
+    }
+
+    if let Err(error) = replace_file_locked(&temp_path, path).await {
+        let _ = tokio_fs::remove_file(&temp_path).await;
+        return Err(error);
+    }
+    sync_parent_directory(path).await;
+
+    Ok(())
+}
 
-    // Write to a unique temp file adjacent to the target, then replace the target.
-    //
-    // This avoids truncating the target file if the process is interrupted mid-write.
-    let temp_path = unique_temp_path(path, "data.json");
-    tokio_fs::write(&temp_path, json.as_bytes())
+async fn write_synced(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut file = tokio_fs::File::create(path).await?;
+    file.write_all(bytes).await?;
+    file.sync_all().await
+}
+
+/// Flush a temp file that was written by a streaming writer and move it over `target_path`.
+///
+/// This is the tail end of [`atomic_write`] for callers that cannot hold the whole payload
+/// in memory.
+pub async fn commit_temp_file(temp_path: &Path, target_path: &Path) -> Result<(), DomainError> {
+    let _lock = lock_file(target_path).await;
+    let synced = match tokio_fs::OpenOptions::new()
+        .write(true)
+        .open(temp_path)
         .await
-        .map_err(|e| {
-            logger::error(&format!(
-                "Failed to write JSON temp file {:?} -> {:?}: {}",
-                temp_path, path, e
-            ));
-            DomainError::InternalError(format!("Failed to write file: {}", e))
-        })?;
-    replace_file_with_fallback(&temp_path, path).await?;
+    {
+        Ok(file) => file.sync_all().await,
+        Err(error) => Err(error),
+    };
+    if let Err(error) = synced {
+        let _ = tokio_fs::remove_file(temp_path).await;
+        return Err(DomainError::InternalError(format!(
+            "Failed to flush temp file {:?}: {}",
+            temp_path, error
+        )));
+    }
+
+    if let Err(error) = replace_file_locked(temp_path, target_path).await {
+        let _ = tokio_fs::remove_file(temp_path).await;
+        return Err(error);
+    }
+    sync_parent_directory(target_path).await;
 
     Ok(())
 }
 
+/// Persist the directory entry of a rename. Only meaningful (and possible) on Unix.
+async fn sync_parent_directory(path: &Path) {
+    #[cfg(unix)]
+    {
+        let Some(parent) = path.parent() else {
+            return;
+        };
+        if let Ok(directory) = tokio_fs::File::open(parent).await {
+            let _ = directory.sync_all().await;
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+/// Outcome of scanning for temp files left behind by interrupted writes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InterruptedWriteRecovery {
+    /// Temp files promoted because their target was missing
+    pub restored: Vec<PathBuf>,
+    /// Stale temp files removed because their target was intact
+    pub removed: Vec<PathBuf>,
+}
+
+/// Where `recover_interrupted_writes` looks for leftover temp files
+#[derive(Debug, Clone, Default)]
+pub struct InterruptedWriteScope {
+    /// Directories whose own files are checked, without descending into subdirectories
+    pub files_in: Vec<PathBuf>,
+    /// Directories checked together with all of their subdirectories
+    pub trees: Vec<PathBuf>,
+}
+
+/// Clean up temp files written by `unique_temp_path` callers that never reached the replace
+/// step. A temp file whose target exists is stale and removed. A temp file whose target is
+/// missing is moved into place only when its format shows it is complete (JSON, JSONL and
+/// PNG); any other temp file is removed, since a partial write cannot be told apart there.
+pub async fn recover_interrupted_writes(
+    scope: &InterruptedWriteScope,
+) -> Result<InterruptedWriteRecovery, DomainError> {
+    let mut recovery = InterruptedWriteRecovery::default();
+    let mut pending = scope
+        .files_in
+        .iter()
+        .map(|directory| (directory.clone(), false))
+        .chain(
+            scope
+                .trees
+                .iter()
+                .map(|directory| (directory.clone(), true)),
+        )
+        .collect::<Vec<_>>();
+
+    while let Some((directory, recursive)) = pending.pop() {
+        let mut entries = match tokio_fs::read_dir(&directory).await {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+            Err(error) => {
+                return Err(DomainError::InternalError(format!(
+                    "Failed to read directory {:?}: {}",
+                    directory, error
+                )));
+            }
+        };
+
+        while let Some(entry) = entries.next_entry().await.map_err(|error| {
+            DomainError::InternalError(format!("Failed to read directory entry: {}", error))
+        })? {
+            let Ok(file_type) = entry.file_type().await else {
+                continue;
+            };
+            let path = entry.path();
+            if file_type.is_dir() {
+                if recursive {
+                    pending.push((path, true));
+                }
+                continue;
+            }
+            if !file_type.is_file() {
+                continue;
+            }
+            let Some(target_path) = interrupted_write_target(&path) else {
+                continue;
+            };
+
+            if optional_metadata(&target_path).await?.is_none()
+                && temp_file_is_complete(&path, &target_path).await
+            {
+                replace_file_with_fallback(&path, &target_path).await?;
+                logger::warn(&format!(
+                    "Restored {:?} from interrupted write {:?}",
+                    target_path, path
+                ));
+                recovery.restored.push(target_path);
+            } else {
+                tokio_fs::remove_file(&path).await.map_err(|error| {
+                    DomainError::InternalError(format!(
+                        "Failed to remove stale temp file {:?}: {}",
+                        path, error
+                    ))
+                })?;
+                recovery.removed.push(path);
+            }
+        }
+    }
+
+    Ok(recovery)
+}
+
+/// The file a `{name}.{uuid}.tmp` temp file was meant to replace.
+fn interrupted_write_target(temp_path: &Path) -> Option<PathBuf> {
+    let file_name = temp_path.file_name()?.to_str()?;
+    let (target_name, id) = file_name.strip_suffix(".tmp")?.rsplit_once('.')?;
+    if target_name.is_empty() || Uuid::parse_str(id).is_err() {
+        return None;
+    }
+    Some(temp_path.with_file_name(target_name))
+}
+
+async fn temp_file_is_complete(temp_path: &Path, target_path: &Path) -> bool {
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+    // Zero-length IEND chunk: length, type and CRC.
+    const PNG_TRAILER: &[u8] = b"\0\0\0\0IEND\xae\x42\x60\x82";
+
+    let extension = target_path
+        .extension()
+        .and_then(|value| value.to_str())
+        .map(str::to_ascii_lowercase);
+    let Some(extension) =
+        extension.filter(|value| matches!(value.as_str(), "json" | "jsonl" | "png"))
+    else {
+        return false;
+    };
+    let Ok(bytes) = tokio_fs::read(temp_path).await else {
+        return false;
+    };
+    if bytes.is_empty() {
+        return false;
+    }
+
+    match extension.as_str() {
+        "json" => serde_json::from_slice::<serde_json::Value>(&bytes).is_ok(),
+        "jsonl" => bytes
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+            .all(|line| serde_json::from_slice::<serde_json::Value>(line).is_ok()),
+        _ => bytes.starts_with(PNG_SIGNATURE) && bytes.ends_with(PNG_TRAILER),
+    }
+}
+
 /// List files in a directory with a specific extension
 ///
 /// This is an async function that lists all files in a directory with a specific extension.
@@ -681,10 +1000,17 @@ pub async fn list_files_with_extension(
 pub async fn delete_file(path: &Path) -> Result<(), DomainError> {
     logger::debug(&format!("Deleting file: {:?}", path));
 
+    let _lock = lock_file(path).await;
+    delete_file_locked(path).await
+}
+
+/// `delete_file` for callers already holding the file's lock.
+pub(super) async fn delete_file_locked(path: &Path) -> Result<(), DomainError> {
     if !path.exists() {
         return Ok(());
     }
 
+    record_internal_write(path);
     tokio_fs::remove_file(path).await.map_err(|e| {
         logger::error(&format!("Failed to delete file {:?}: {}", path, e));
         DomainError::InternalError(format!("Failed to delete file: {}", e))
@@ -939,7 +1265,6 @@ mod tests {
             .expect("remove temp root");
     }
 
-    #[cfg(not(any(target_os = "android", target_os = "ios")))]
     #[test]
     fn replace_file_with_fallback_sync_overwrites_existing_file() {
         let root = unique_temp_root();
@@ -961,7 +1286,6 @@ mod tests {
         std::fs::remove_dir_all(&root).expect("remove temp root");
     }
 
-    #[cfg(not(any(target_os = "android", target_os = "ios")))]
     #[test]
     fn replace_file_with_fallback_sync_copies_when_target_parent_is_missing() {
         let root = unique_temp_root();
@@ -981,7 +1305,6 @@ mod tests {
         std::fs::remove_dir_all(&root).expect("remove temp root");
     }
 
-    #[cfg(not(any(target_os = "android", target_os = "ios")))]
     #[test]
     fn replace_file_with_fallback_sync_rejects_directory_target_after_failed_rename() {
         let root = unique_temp_root();
@@ -1205,4 +1528,114 @@ mod tests {
             .await
             .expect("remove temp root");
     }
+
+    #[test]
+    fn atomic_write_sync_replaces_target_without_leaving_temp_files() {
+        let root = unique_temp_root();
+        let target = root.join("nested").join("migrations.json");
+
+        atomic_write_sync(&target, b"{\"a\":1}").expect("first write");
+        atomic_write_sync(&target, b"{\"a\":2}").expect("second write");
+
+        assert_eq!(std::fs::read(&target).expect("read target"), b"{\"a\":2}");
+        let mut entries = std::fs::read_dir(target.parent().unwrap()).expect("read dir");
+        assert!(entries.all(|entry| entry.unwrap().path() == target));
+
+        std::fs::remove_dir_all(&root).expect("remove temp root");
+    }
+
+    #[tokio::test]
+    async fn atomic_write_replaces_target_without_leaving_temp_files() {
+        let root = unique_temp_root();
+        let target = root.join("nested").join("settings.json");
+
+        atomic_write(&target, b"{\"a\":1}")
+            .await
+            .expect("first write");
+        atomic_write(&target, b"{\"a\":2}")
+            .await
+            .expect("second write");
+
+        let bytes = tokio_fs::read(&target).await.expect("read target");
+        assert_eq!(&bytes, b"{\"a\":2}");
+        let mut entries = std::fs::read_dir(target.parent().unwrap()).expect("read dir");
+        assert!(entries.all(|entry| entry.unwrap().path() == target));
+
+        tokio_fs::remove_dir_all(&root)
+            .await
+            .expect("remove temp root");
+    }
+
+    #[tokio::test]
+    async fn recover_interrupted_writes_restores_complete_and_drops_partial_temps() {
+        let root = unique_temp_root();
+        tokio_fs::create_dir_all(root.join("chats"))
+            .await
+            .expect("create temp root");
+
+        // Target intact: the temp is stale.
+        let kept = root.join("settings.json");
+        tokio_fs::write(&kept, b"{}").await.expect("write target");
+        let stale = unique_temp_path(&kept, "settings.json");
+        tokio_fs::write(&stale, b"{\"new\":true}")
+            .await
+            .expect("write stale temp");
+
+        // Target missing and temp complete: promote it.
+        let lost = root.join("chats").join("chat.jsonl");
+        let complete = unique_temp_path(&lost, "chat.jsonl");
+        tokio_fs::write(&complete, b"{\"chat_metadata\":{}}\n{\"mes\":\"hi\"}\n")
+            .await
+            .expect("write complete temp");
+
+        // Target missing and temp truncated: drop it.
+        let truncated_target = root.join("chats").join("other.jsonl");
+        let truncated = unique_temp_path(&truncated_target, "other.jsonl");
+        tokio_fs::write(&truncated, b"{\"chat_metadata\":{}}\n{\"mes\":")
+            .await
+            .expect("write truncated temp");
+
+        // Target missing but the format cannot be checked: drop it.
+        let binary_target = root.join("chats").join("clip.webp");
+        let binary = unique_temp_path(&binary_target, "clip.webp");
+        tokio_fs::write(&binary, b"RIFF")
+            .await
+            .expect("write binary temp");
+
+        // Unrelated `.tmp` file without an id is left alone.
+        let unrelated = root.join("notes.tmp");
+        tokio_fs::write(&unrelated, b"x")
+            .await
+            .expect("write unrelated");
+
+        // Directories outside the scope are never walked.
+        let outside_target = root.join("extensions").join("manifest.json");
+        let outside = unique_temp_path(&outside_target, "manifest.json");
+        tokio_fs::create_dir_all(root.join("extensions"))
+            .await
+            .expect("create extensions dir");
+        tokio_fs::write(&outside, b"{}")
+            .await
+            .expect("write outside temp");
+
+        let scope = InterruptedWriteScope {
+            files_in: vec![root.clone()],
+            trees: vec![root.join("chats")],
+        };
+        let recovery = recover_interrupted_writes(&scope).await.expect("recover");
+
+        assert_eq!(recovery.restored, vec![lost.clone()]);
+        assert_eq!(recovery.removed.len(), 3);
+        assert_eq!(tokio_fs::read(&kept).await.expect("read kept"), b"{}");
+        assert!(lost.exists());
+        assert!(!truncated_target.exists() && !binary_target.exists());
+        assert!(!stale.exists() && !complete.exists() && !truncated.exists());
+        assert!(!binary.exists());
+        assert!(unrelated.exists());
+        assert!(outside.exists());
+
+        tokio_fs::remove_dir_all(&root)
+            .await
+            .expect("remove temp root");
+    }
 }
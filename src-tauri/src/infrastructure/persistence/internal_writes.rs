@@ -0,0 +1,101 @@
+//! Registry of paths the app itself just wrote, renamed or removed.
+//!
+//! File watchers consult it so that changes made through the app are not reported back to
+//! the frontend as external edits.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// How long a file event is attributed to the app after one of its own writes.
+const INTERNAL_WRITE_GRACE: Duration = Duration::from_secs(2);
+
+static INTERNAL_WRITES: OnceLock<Mutex<HashMap<PathBuf, Instant>>> = OnceLock::new();
+
+/// Marks `path` as changed by the app. Call this right before touching a watched file or
+/// directory; changes below a recorded directory are covered as well.
+pub fn record_internal_write(path: &Path) {
+    let mut writes = INTERNAL_WRITES
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let now = Instant::now();
+    writes.retain(|_, written_at| now.duration_since(*written_at) < INTERNAL_WRITE_GRACE);
+    writes.insert(path.to_path_buf(), now);
+}
+
+/// Whether `path`, or one of its parent directories, was recently changed by the app.
+pub fn is_recent_internal_write(path: &Path) -> bool {
+    let Some(writes) = INTERNAL_WRITES.get() else {
+        return false;
+    };
+    let writes = writes
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    writes.iter().any(|(written, written_at)| {
+        written_at.elapsed() < INTERNAL_WRITE_GRACE
+            && path
+                .ancestors()
+                .any(|candidate| same_file(written, candidate))
+    })
+}
+
+pub fn same_file(left: &Path, right: &Path) -> bool {
+    if left == right {
+        return true;
+    }
+
+    // Watcher paths may be canonicalized (e.g. /private/var on macOS); compare by file name
+    // and parent directory, which still resolves after the file itself was removed.
+    left.file_name() == right.file_name()
+        && match (left.parent(), right.parent()) {
+            (Some(left), Some(right)) => same_directory(left, right),
+            _ => false,
+        }
+}
+
+pub fn same_directory(left: &Path, right: &Path) -> bool {
+    if left == right {
+        return true;
+    }
+
+    match (dunce::canonicalize(left), dunce::canonicalize(right)) {
+        (Ok(left), Ok(right)) => left == right,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_recent_internal_write, record_internal_write};
+
+    #[test]
+    fn internal_writes_are_recognized_until_the_grace_period_ends() {
+        let dir = std::env::temp_dir().join(format!("tt-internal-writes-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        let path = dir.join("settings.json");
+
+        assert!(!is_recent_internal_write(&path));
+        record_internal_write(&path);
+        assert!(is_recent_internal_write(&path));
+        assert!(!is_recent_internal_write(&dir.join("other.json")));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn internal_directory_writes_cover_their_contents() {
+        let dir = std::env::temp_dir().join(format!("tt-internal-writes-{}", uuid::Uuid::new_v4()));
+        let chat_dir = dir.join("Alice");
+        std::fs::create_dir_all(&chat_dir).expect("create temp dir");
+
+        record_internal_write(&chat_dir);
+        assert!(is_recent_internal_write(&chat_dir.join("chat.jsonl")));
+        assert!(!is_recent_internal_write(
+            &dir.join("Bob").join("chat.jsonl")
+        ));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
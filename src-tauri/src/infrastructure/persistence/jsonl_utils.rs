@@ -1,12 +1,10 @@
 use crate::domain::errors::DomainError;
 use crate::infrastructure::logging::logger;
-use crate::infrastructure::persistence::file_system::{
-    replace_file_with_fallback, unique_temp_path,
-};
+use crate::infrastructure::persistence::file_system::atomic_write;
 use serde_json::Value;
 use std::path::Path;
-use tokio::fs::{self, File};
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, BufReader};
 
 /// Read a JSONL file and parse it into a vector of JSON values
 ///
@@ -103,39 +101,11 @@ pub async fn write_jsonl_file(path: &Path, objects: &[Value]) -> Result<(), Doma
 
 /// Write raw JSONL bytes to a file.
 ///
-/// Uses a temporary file and then replaces the target. On some storage backends (notably Android
-/// external app storage), file replacement may fall back to copy/remove if rename is unreliable.
+/// Goes through `atomic_write`, so an interrupted save leaves the previous chat intact. On
+/// some storage backends (notably Android external app storage), file replacement may fall
+/// back to copy/remove if rename is unreliable.
 pub async fn write_jsonl_bytes_file(path: &Path, bytes: &[u8]) -> Result<(), DomainError> {
-    let temp_path = unique_temp_path(path, "data.jsonl");
-
-    if let Some(parent) = path.parent() {
-        if !parent.exists() {
-            fs::create_dir_all(parent).await.map_err(|e| {
-                logger::error(&format!("Failed to create directory: {}", e));
-                DomainError::InternalError(format!("Failed to create directory: {}", e))
-            })?;
-        }
-    }
-
-    let file = File::create(&temp_path).await.map_err(|e| {
-        logger::error(&format!("Failed to create temporary file: {}", e));
-        DomainError::InternalError(format!("Failed to create temporary file: {}", e))
-    })?;
-
-    let mut writer = BufWriter::new(file);
-    writer.write_all(bytes).await.map_err(|e| {
-        logger::error(&format!("Failed to write to temporary file: {}", e));
-        DomainError::InternalError(format!("Failed to write to temporary file: {}", e))
-    })?;
-
-    writer.flush().await.map_err(|e| {
-        logger::error(&format!("Failed to flush temporary file: {}", e));
-        DomainError::InternalError(format!("Failed to flush temporary file: {}", e))
-    })?;
-
-    replace_file_with_fallback(&temp_path, path).await?;
-
-    Ok(())
+    atomic_write(path, bytes).await
 }
 
 async fn parse_jsonl_lines<R>(lines: &mut tokio::io::Lines<R>) -> Result<Vec<Value>, DomainError>
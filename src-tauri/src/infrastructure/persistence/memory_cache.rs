@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::domain::models::memory_cache::{
+    CacheEvictionPolicy, MemoryCacheConfig, MemoryCacheStats,
+};
+
+struct CacheEntry<V> {
+    value: V,
+    inserted_at: Instant,
+    last_access: Instant,
+}
+
+/// Bounded in-memory cache keyed by string, with a TTL measured from the last write and
+/// hit/miss counters for diagnostics.
+pub(crate) struct MemoryCache<V> {
+    entries: HashMap<String, CacheEntry<V>>,
+    config: MemoryCacheConfig,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+    expirations: u64,
+}
+
+impl<V: Clone> MemoryCache<V> {
+    pub(crate) fn new(config: MemoryCacheConfig) -> Self {
+        Self {
+            entries: HashMap::with_capacity(config.capacity),
+            config,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+            expirations: 0,
+        }
+    }
+
+    pub(crate) fn get(&mut self, key: &str) -> Option<V> {
+        let ttl = self.ttl();
+        let now = Instant::now();
+        match self.entries.get_mut(key) {
+            Some(entry) if now.duration_since(entry.inserted_at) < ttl => {
+                entry.last_access = now;
+                self.hits += 1;
+                Some(entry.value.clone())
+            }
+            Some(_) => {
+                self.entries.remove(key);
+                self.expirations += 1;
+                self.misses += 1;
+                None
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    pub(crate) fn set(&mut self, key: String, value: V) {
+        if self.config.capacity == 0 {
+            return;
+        }
+        if !self.entries.contains_key(&key) {
+            self.make_room(self.config.capacity - 1);
+        }
+
+        let now = Instant::now();
+        self.entries.insert(
+            key,
+            CacheEntry {
+                value,
+                inserted_at: now,
+                last_access: now,
+            },
+        );
+    }
+
+    pub(crate) fn remove(&mut self, key: &str) {
+        self.entries.remove(key);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Applies a new configuration, dropping entries that no longer fit, and resets the
+    /// counters so they describe the new configuration only.
+    pub(crate) fn configure(&mut self, config: MemoryCacheConfig) {
+        self.config = config;
+        self.make_room(config.capacity);
+        self.hits = 0;
+        self.misses = 0;
+        self.evictions = 0;
+        self.expirations = 0;
+    }
+
+    pub(crate) fn stats(&self, name: &str) -> MemoryCacheStats {
+        MemoryCacheStats {
+            name: name.to_string(),
+            entries: self.entries.len(),
+            capacity: self.config.capacity,
+            ttl_secs: self.config.ttl_secs,
+            eviction: self.config.eviction,
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            expirations: self.expirations,
+        }
+    }
+
+    fn ttl(&self) -> Duration {
+        Duration::from_secs(self.config.ttl_secs)
+    }
+
+    /// Drops expired entries, then evicts by policy until at most `max_len` remain.
+    fn make_room(&mut self, max_len: usize) {
+        if self.entries.len() <= max_len {
+            return;
+        }
+
+        let ttl = self.ttl();
+        let before = self.entries.len();
+        self.entries
+            .retain(|_, entry| entry.inserted_at.elapsed() < ttl);
+        self.expirations += (before - self.entries.len()) as u64;
+
+        while self.entries.len() > max_len {
+            let eviction = self.config.eviction;
+            let Some(victim) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| match eviction {
+                    CacheEvictionPolicy::Lru => entry.last_access,
+                    CacheEvictionPolicy::TtlOnly => entry.inserted_at,
+                })
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            self.entries.remove(&victim);
+            self.evictions += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    use super::MemoryCache;
+    use crate::domain::models::memory_cache::{CacheEvictionPolicy, MemoryCacheConfig};
+
+    fn cache(capacity: usize, eviction: CacheEvictionPolicy) -> MemoryCache<u32> {
+        MemoryCache::new(MemoryCacheConfig {
+            capacity,
+            ttl_secs: 60,
+            eviction,
+        })
+    }
+
+    /// Keeps entry timestamps distinct so eviction order is deterministic.
+    fn tick() {
+        sleep(Duration::from_millis(2));
+    }
+
+    #[test]
+    fn lru_keeps_recently_read_entries() {
+        let mut cache = cache(2, CacheEvictionPolicy::Lru);
+        cache.set("a".to_string(), 1);
+        tick();
+        cache.set("b".to_string(), 2);
+        tick();
+        assert_eq!(cache.get("a"), Some(1));
+        tick();
+
+        cache.set("c".to_string(), 3);
+
+        assert_eq!(cache.get("a"), Some(1));
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("c"), Some(3));
+        let stats = cache.stats("test");
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (3, 1, 1));
+    }
+
+    #[test]
+    fn ttl_only_evicts_oldest_write_regardless_of_reads() {
+        let mut cache = cache(2, CacheEvictionPolicy::TtlOnly);
+        cache.set("a".to_string(), 1);
+        tick();
+        cache.set("b".to_string(), 2);
+        tick();
+        assert_eq!(cache.get("a"), Some(1));
+        tick();
+
+        cache.set("c".to_string(), 3);
+
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("b"), Some(2));
+    }
+
+    #[test]
+    fn configure_shrinks_cache_and_zero_capacity_disables_it() {
+        let mut cache = cache(3, CacheEvictionPolicy::Lru);
+        for (key, value) in [("a", 1), ("b", 2), ("c", 3)] {
+            cache.set(key.to_string(), value);
+        }
+
+        cache.configure(MemoryCacheConfig {
+            capacity: 1,
+            ttl_secs: 60,
+            eviction: CacheEvictionPolicy::Lru,
+        });
+        assert_eq!(cache.stats("test").entries, 1);
+
+        cache.configure(MemoryCacheConfig {
+            capacity: 0,
+            ttl_secs: 60,
+            eviction: CacheEvictionPolicy::Lru,
+        });
+        cache.set("d".to_string(), 4);
+        assert_eq!(cache.get("d"), None);
+        assert_eq!(cache.stats("test").entries, 0);
+    }
+}
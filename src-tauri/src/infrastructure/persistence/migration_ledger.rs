@@ -0,0 +1,141 @@
+//! Ledger of the data migrations this install has performed, kept in
+//! `<data_root>/_tauritavern/migrations.json` so the changelog can tell users which data
+//! files an update rewrote. The ledger is local state and is not part of sync scopes.
+//!
+//! Writes go through `atomic_write_sync` because the data root migration records itself
+//! before the async runtime is up.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::changelog::MigrationRecord;
+use crate::infrastructure::persistence::file_system::atomic_write_sync;
+
+/// Oldest entries are dropped beyond this many.
+const MAX_LEDGER_ENTRIES: usize = 500;
+
+static LEDGER_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct MigrationLedgerFile {
+    #[serde(default)]
+    migrations: Vec<MigrationRecord>,
+}
+
+fn ledger_path(data_root: &Path) -> PathBuf {
+    data_root.join("_tauritavern").join("migrations.json")
+}
+
+fn load_ledger_sync(path: &Path) -> Result<MigrationLedgerFile, DomainError> {
+    let raw = match std::fs::read_to_string(path) {
+        Ok(raw) => raw,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(MigrationLedgerFile::default());
+        }
+        Err(error) => {
+            return Err(DomainError::InternalError(format!(
+                "Failed to read migrations ledger {}: {}",
+                path.display(),
+                error
+            )));
+        }
+    };
+
+    serde_json::from_str(&raw).map_err(|error| {
+        DomainError::InvalidData(format!(
+            "Migrations ledger {} contains invalid JSON: {}",
+            path.display(),
+            error
+        ))
+    })
+}
+
+fn write_ledger_sync(path: &Path, ledger: &MigrationLedgerFile) -> Result<(), DomainError> {
+    let json = serde_json::to_string_pretty(ledger).map_err(|error| {
+        DomainError::InvalidData(format!("Failed to serialize migrations ledger: {}", error))
+    })?;
+    atomic_write_sync(path, json.as_bytes())
+}
+
+pub(crate) fn record_migration_sync(
+    data_root: &Path,
+    record: MigrationRecord,
+) -> Result<(), DomainError> {
+    let _guard = LEDGER_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let path = ledger_path(data_root);
+    let mut ledger = load_ledger_sync(&path)?;
+    ledger.migrations.push(record);
+    let overflow = ledger.migrations.len().saturating_sub(MAX_LEDGER_ENTRIES);
+    ledger.migrations.drain(..overflow);
+    write_ledger_sync(&path, &ledger)
+}
+
+pub(crate) async fn record_migration(
+    data_root: &Path,
+    record: MigrationRecord,
+) -> Result<(), DomainError> {
+    let data_root = data_root.to_path_buf();
+    tokio::task::spawn_blocking(move || record_migration_sync(&data_root, record))
+        .await
+        .map_err(|error| DomainError::InternalError(error.to_string()))?
+}
+
+pub(crate) async fn load_migrations(data_root: &Path) -> Result<Vec<MigrationRecord>, DomainError> {
+    let path = ledger_path(data_root);
+    tokio::task::spawn_blocking(move || {
+        let _guard = LEDGER_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        load_ledger_sync(&path).map(|ledger| ledger.migrations)
+    })
+    .await
+    .map_err(|error| DomainError::InternalError(error.to_string()))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_LEDGER_ENTRIES, load_migrations, record_migration, record_migration_sync};
+    use crate::domain::models::changelog::MigrationRecord;
+
+    #[tokio::test]
+    async fn records_are_appended_and_capped() {
+        let root = std::env::temp_dir().join(format!(
+            "tauritavern-migration-ledger-{}",
+            uuid::Uuid::new_v4()
+        ));
+        assert!(load_migrations(&root).await.unwrap().is_empty());
+
+        record_migration(
+            &root,
+            MigrationRecord::now(
+                "secrets.legacy_format",
+                "Upgraded secrets",
+                vec!["default-user/secrets.json".to_string()],
+            ),
+        )
+        .await
+        .unwrap();
+        let migrations = load_migrations(&root).await.unwrap();
+        assert_eq!(migrations.len(), 1);
+        assert_eq!(migrations[0].id, "secrets.legacy_format");
+        assert_eq!(migrations[0].app_version, env!("CARGO_PKG_VERSION"));
+
+        for index in 0..MAX_LEDGER_ENTRIES {
+            record_migration_sync(
+                &root,
+                MigrationRecord::now(&format!("test.{index}"), "", Vec::new()),
+            )
+            .unwrap();
+        }
+        let migrations = load_migrations(&root).await.unwrap();
+        assert_eq!(migrations.len(), MAX_LEDGER_ENTRIES);
+        assert_eq!(migrations[0].id, "test.0");
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -2,7 +2,17 @@
 pub mod chat_format_importers;
 pub mod data_archive;
 pub mod data_archive_jobs;
+pub mod data_doctor;
+pub mod external_data_merge;
+pub mod file_locks;
+pub mod file_names;
 pub mod file_system;
+pub mod internal_writes;
 pub mod jsonl_utils;
+pub(crate) mod memory_cache;
+pub mod migration_ledger;
 pub mod png_utils;
+pub mod sillytavern_migration;
 pub mod thumbnail_cache;
+pub mod thumbnail_pipeline;
+pub mod video_thumbnail;
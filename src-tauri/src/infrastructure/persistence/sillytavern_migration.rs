@@ -0,0 +1,546 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::file_system::{read_json_file, write_json_file};
+
+const DEFAULT_USER_DIR: &str = "default-user";
+const SETTINGS_FILE: &str = "settings.json";
+const SECRETS_FILE: &str = "secrets.json";
+const BACKUP_SEGMENT: &str = "sillytavern-migration";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationCategory {
+    Characters,
+    Chats,
+    Worlds,
+    Presets,
+    Themes,
+    Personas,
+    Backgrounds,
+    Secrets,
+    Settings,
+}
+
+impl MigrationCategory {
+    pub(crate) const ALL: [MigrationCategory; 9] = [
+        MigrationCategory::Characters,
+        MigrationCategory::Chats,
+        MigrationCategory::Worlds,
+        MigrationCategory::Presets,
+        MigrationCategory::Themes,
+        MigrationCategory::Personas,
+        MigrationCategory::Backgrounds,
+        MigrationCategory::Secrets,
+        MigrationCategory::Settings,
+    ];
+
+    /// Folders of the user directory that belong to the category. The layout of a
+    /// SillyTavern user directory matches ours, so folders are copied to the same place.
+    pub(crate) fn directories(self) -> &'static [&'static str] {
+        match self {
+            MigrationCategory::Characters => &["characters"],
+            MigrationCategory::Chats => &["chats", "groups", "group chats"],
+            MigrationCategory::Worlds => &["worlds"],
+            MigrationCategory::Presets => &[
+                "OpenAI Settings",
+                "TextGen Settings",
+                "KoboldAI Settings",
+                "NovelAI Settings",
+                "instruct",
+                "context",
+                "sysprompt",
+                "reasoning",
+                "QuickReplies",
+            ],
+            MigrationCategory::Themes => &["themes", "movingUI"],
+            MigrationCategory::Personas => &["User Avatars"],
+            MigrationCategory::Backgrounds => &["backgrounds"],
+            MigrationCategory::Secrets | MigrationCategory::Settings => &[],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MigrationFailure {
+    /// Path relative to the source user directory, with `/` separators
+    pub path: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MigrationCategoryReport {
+    pub category: MigrationCategory,
+    /// Files copied, or that would be copied in a dry run
+    pub copied: usize,
+    /// Files left alone because the target already has them
+    pub skipped: usize,
+    pub bytes: u64,
+    pub failures: Vec<MigrationFailure>,
+}
+
+impl MigrationCategoryReport {
+    fn new(category: MigrationCategory) -> Self {
+        Self {
+            category,
+            copied: 0,
+            skipped: 0,
+            bytes: 0,
+            failures: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SillyTavernMigrationReport {
+    /// The detected SillyTavern user directory
+    pub source: String,
+    pub dry_run: bool,
+    pub categories: Vec<MigrationCategoryReport>,
+    pub copied: usize,
+    pub skipped: usize,
+    pub failed: usize,
+}
+
+impl SillyTavernMigrationReport {
+    /// User directory folders and files that received copied data, for the migrations
+    /// ledger.
+    pub fn migrated_paths(&self) -> Vec<String> {
+        self.categories
+            .iter()
+            .filter(|report| report.copied > 0)
+            .flat_map(|report| match report.category {
+                MigrationCategory::Secrets => vec![SECRETS_FILE],
+                MigrationCategory::Settings => vec![SETTINGS_FILE],
+                category => category.directories().to_vec(),
+            })
+            .map(|path| format!("default-user/{path}"))
+            .collect()
+    }
+}
+
+/// One file to migrate, gathered up front so progress can be reported against a total
+struct MigrationItem {
+    category: MigrationCategory,
+    relative: PathBuf,
+}
+
+/// Copies the user data of an existing SillyTavern installation into our user directory.
+///
+/// Existing files are never overwritten: characters, chats and other library files that
+/// already exist are skipped. `settings.json` replaces ours after a backup to
+/// `backups/sillytavern-migration/`, and secrets are merged key by key with ours winning.
+/// Legacy plain-string secrets are upgraded by the secret repository when it next loads.
+pub struct SillyTavernMigration {
+    source_user_dir: PathBuf,
+    target_user_dir: PathBuf,
+    backups_dir: PathBuf,
+}
+
+impl SillyTavernMigration {
+    /// Locates the SillyTavern user directory under `path`, which may be the installation
+    /// folder, its `data` folder or the `default-user` folder itself.
+    pub fn detect(
+        path: &Path,
+        target_user_dir: PathBuf,
+        backups_dir: PathBuf,
+    ) -> Result<Self, DomainError> {
+        let source_user_dir = locate_user_dir(path).ok_or_else(|| {
+            DomainError::InvalidData(format!(
+                "No SillyTavern data/default-user folder found in {}",
+                path.display()
+            ))
+        })?;
+
+        let source = dunce::canonicalize(&source_user_dir).unwrap_or(source_user_dir);
+        let target = dunce::canonicalize(&target_user_dir).unwrap_or(target_user_dir.clone());
+        if source == target {
+            return Err(DomainError::InvalidData(
+                "The selected folder is the current data directory".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            source_user_dir: source,
+            target_user_dir,
+            backups_dir,
+        })
+    }
+
+    pub fn source_user_dir(&self) -> &Path {
+        &self.source_user_dir
+    }
+
+    /// Runs the migration, or only reports what would be copied when `dry_run` is set.
+    ///
+    /// `on_progress` receives `(done, total)` and `is_cancelled` is polled per file.
+    pub async fn run(
+        &self,
+        dry_run: bool,
+        on_progress: &(dyn Fn(usize, usize) + Sync),
+        is_cancelled: &(dyn Fn() -> bool + Sync),
+    ) -> Result<SillyTavernMigrationReport, DomainError> {
+        let items = self.collect_items().await?;
+        let total = items.len();
+        let mut categories: Vec<MigrationCategoryReport> = MigrationCategory::ALL
+            .iter()
+            .map(|category| MigrationCategoryReport::new(*category))
+            .collect();
+
+        for (index, item) in items.iter().enumerate() {
+            if is_cancelled() {
+                return Err(DomainError::cancelled("SillyTavern migration cancelled"));
+            }
+
+            let report = categories
+                .iter_mut()
+                .find(|report| report.category == item.category)
+                .expect("every category has a report");
+            if let Err(error) = self.migrate_item(item, dry_run, report).await {
+                report.failures.push(MigrationFailure {
+                    path: relative_display(&item.relative),
+                    error: error.to_string(),
+                });
+            }
+            on_progress(index + 1, total);
+        }
+
+        Ok(SillyTavernMigrationReport {
+            source: self.source_user_dir.to_string_lossy().to_string(),
+            dry_run,
+            copied: categories.iter().map(|report| report.copied).sum(),
+            skipped: categories.iter().map(|report| report.skipped).sum(),
+            failed: categories.iter().map(|report| report.failures.len()).sum(),
+            categories,
+        })
+    }
+
+    async fn collect_items(&self) -> Result<Vec<MigrationItem>, DomainError> {
+        let mut items = Vec::new();
+
+        for category in MigrationCategory::ALL {
+            for directory in category.directories() {
+                for relative in list_files_recursive(&self.source_user_dir, directory).await? {
+                    items.push(MigrationItem { category, relative });
+                }
+            }
+        }
+
+        for (category, file_name) in [
+            (MigrationCategory::Secrets, SECRETS_FILE),
+            (MigrationCategory::Settings, SETTINGS_FILE),
+        ] {
+            if self.source_user_dir.join(file_name).is_file() {
+                items.push(MigrationItem {
+                    category,
+                    relative: PathBuf::from(file_name),
+                });
+            }
+        }
+
+        Ok(items)
+    }
+
+    async fn migrate_item(
+        &self,
+        item: &MigrationItem,
+        dry_run: bool,
+        report: &mut MigrationCategoryReport,
+    ) -> Result<(), DomainError> {
+        let source = self.source_user_dir.join(&item.relative);
+        let target = self.target_user_dir.join(&item.relative);
+        let size = fs::metadata(&source)
+            .await
+            .map_err(|error| io_error("read", &source, error))?
+            .len();
+
+        match item.category {
+            MigrationCategory::Secrets => {
+                let added = self.merge_secrets(&source, &target, dry_run).await?;
+                if added > 0 {
+                    report.copied += 1;
+                    report.bytes += size;
+                } else {
+                    report.skipped += 1;
+                }
+                return Ok(());
+            }
+            MigrationCategory::Settings => {
+                if !dry_run {
+                    if target.is_file() {
+                        let backup = self.backups_dir.join(BACKUP_SEGMENT).join(SETTINGS_FILE);
+                        copy_file(&target, &backup).await?;
+                    }
+                    copy_file(&source, &target).await?;
+                }
+            }
+            _ => {
+                if fs::try_exists(&target).await.unwrap_or(false) {
+                    report.skipped += 1;
+                    return Ok(());
+                }
+                if !dry_run {
+                    copy_file(&source, &target).await?;
+                }
+            }
+        }
+
+        report.copied += 1;
+        report.bytes += size;
+        Ok(())
+    }
+
+    /// Adds the source secrets that the target does not have yet and returns how many.
+    async fn merge_secrets(
+        &self,
+        source: &Path,
+        target: &Path,
+        dry_run: bool,
+    ) -> Result<usize, DomainError> {
+        let Value::Object(source_secrets) = read_json_file::<Value>(source).await? else {
+            return Err(DomainError::InvalidData(
+                "SillyTavern secrets.json is not an object".to_string(),
+            ));
+        };
+
+        let mut target_secrets = if target.is_file() {
+            match read_json_file::<Value>(target).await? {
+                Value::Object(map) => map,
+                _ => Map::new(),
+            }
+        } else {
+            Map::new()
+        };
+
+        let mut added = 0;
+        for (key, value) in source_secrets {
+            let has_value = target_secrets
+                .get(&key)
+                .is_some_and(|existing| !is_empty_secret(existing));
+            if has_value || is_empty_secret(&value) {
+                continue;
+            }
+            target_secrets.insert(key, value);
+            added += 1;
+        }
+
+        if added > 0 && !dry_run {
+            write_json_file(target, &Value::Object(target_secrets)).await?;
+        }
+
+        Ok(added)
+    }
+}
+
+/// Finds the user directory under `path`, which may be an installation folder, its `data`
+/// folder or the `default-user` folder itself. SillyTavern and TauriTavern share the layout.
+pub(crate) fn locate_user_dir(path: &Path) -> Option<PathBuf> {
+    [
+        path.join("data").join(DEFAULT_USER_DIR),
+        path.join(DEFAULT_USER_DIR),
+        path.to_path_buf(),
+    ]
+    .into_iter()
+    .find(|candidate| is_user_dir(candidate))
+}
+
+fn is_user_dir(path: &Path) -> bool {
+    path.join(SETTINGS_FILE).is_file()
+        && (path.join("characters").is_dir() || path.join("chats").is_dir())
+}
+
+fn is_empty_secret(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.trim().is_empty(),
+        Value::Array(entries) => entries.is_empty(),
+        _ => false,
+    }
+}
+
+/// Lists the files below `root/directory` as paths relative to `root`.
+pub(crate) async fn list_files_recursive(
+    root: &Path,
+    directory: &str,
+) -> Result<Vec<PathBuf>, DomainError> {
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::from(directory)];
+
+    while let Some(relative_dir) = pending.pop() {
+        let absolute_dir = root.join(&relative_dir);
+        if !absolute_dir.is_dir() {
+            continue;
+        }
+
+        let mut entries = fs::read_dir(&absolute_dir)
+            .await
+            .map_err(|error| io_error("list", &absolute_dir, error))?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|error| io_error("list", &absolute_dir, error))?
+        {
+            let file_type = entry
+                .file_type()
+                .await
+                .map_err(|error| io_error("inspect", &entry.path(), error))?;
+            let relative = relative_dir.join(entry.file_name());
+            if file_type.is_dir() {
+                pending.push(relative);
+            } else if file_type.is_file() {
+                files.push(relative);
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+pub(crate) async fn copy_file(source: &Path, target: &Path) -> Result<(), DomainError> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|error| io_error("create", parent, error))?;
+    }
+    fs::copy(source, target)
+        .await
+        .map_err(|error| io_error("copy", source, error))?;
+    Ok(())
+}
+
+pub(crate) fn io_error(action: &str, path: &Path, error: std::io::Error) -> DomainError {
+    DomainError::InternalError(format!(
+        "Failed to {} {}: {}",
+        action,
+        path.display(),
+        error
+    ))
+}
+
+pub(crate) fn relative_display(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use serde_json::{Value, json};
+
+    use super::{MigrationCategory, SillyTavernMigration};
+
+    fn write(path: PathBuf, bytes: impl AsRef<[u8]>) {
+        std::fs::create_dir_all(path.parent().expect("parent")).expect("create dir");
+        std::fs::write(path, bytes).expect("write file");
+    }
+
+    #[tokio::test]
+    async fn migrates_sillytavern_install_without_overwriting_existing_files() {
+        let root =
+            std::env::temp_dir().join(format!("tauritavern-st-migration-{}", uuid::Uuid::new_v4()));
+        let install = root.join("SillyTavern");
+        let source = install.join("data/default-user");
+        let target = root.join("target/default-user");
+
+        write(source.join("settings.json"), r#"{"username":"Old"}"#);
+        write(source.join("characters/Alice.png"), b"card");
+        write(source.join("chats/Alice/first.jsonl"), "{}\n");
+        write(source.join("worlds/Lore.json"), "{}");
+        write(source.join("OpenAI Settings/Default.json"), "{}");
+        write(source.join("themes/Dark.json"), "{}");
+        write(
+            source.join("secrets.json"),
+            json!({ "api_key_openai": "sk-old", "api_key_claude": "ck" }).to_string(),
+        );
+        write(target.join("settings.json"), r#"{"username":"New"}"#);
+        write(target.join("worlds/Lore.json"), r#"{"kept":true}"#);
+        write(
+            target.join("secrets.json"),
+            json!({ "api_key_openai": [{ "id": "1", "value": "sk-new" }] }).to_string(),
+        );
+
+        let migration =
+            SillyTavernMigration::detect(&install, target.clone(), target.join("backups"))
+                .expect("detect");
+
+        let dry_run = migration
+            .run(true, &|_, _| {}, &|| false)
+            .await
+            .expect("dry run");
+        assert!(dry_run.dry_run);
+        assert_eq!(dry_run.copied, 6);
+        assert_eq!(dry_run.skipped, 1);
+        assert!(!target.join("characters/Alice.png").exists());
+
+        let report = migration
+            .run(false, &|_, _| {}, &|| false)
+            .await
+            .expect("migrate");
+        let counts: Vec<(MigrationCategory, usize, usize)> = report
+            .categories
+            .iter()
+            .filter(|category| category.copied + category.skipped > 0)
+            .map(|category| (category.category, category.copied, category.skipped))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![
+                (MigrationCategory::Characters, 1, 0),
+                (MigrationCategory::Chats, 1, 0),
+                (MigrationCategory::Worlds, 0, 1),
+                (MigrationCategory::Presets, 1, 0),
+                (MigrationCategory::Themes, 1, 0),
+                (MigrationCategory::Secrets, 1, 0),
+                (MigrationCategory::Settings, 1, 0),
+            ]
+        );
+        assert_eq!(report.failed, 0);
+
+        assert!(target.join("chats/Alice/first.jsonl").is_file());
+        assert_eq!(
+            std::fs::read_to_string(target.join("worlds/Lore.json")).expect("read world"),
+            r#"{"kept":true}"#
+        );
+        assert_eq!(
+            std::fs::read_to_string(target.join("settings.json")).expect("read settings"),
+            r#"{"username":"Old"}"#
+        );
+        assert_eq!(
+            std::fs::read_to_string(target.join("backups/sillytavern-migration/settings.json"))
+                .expect("read settings backup"),
+            r#"{"username":"New"}"#
+        );
+
+        let secrets: Value = serde_json::from_str(
+            &std::fs::read_to_string(target.join("secrets.json")).expect("read secrets"),
+        )
+        .expect("parse secrets");
+        assert_eq!(secrets["api_key_openai"][0]["value"], "sk-new");
+        assert_eq!(secrets["api_key_claude"], "ck");
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn rejects_folders_without_sillytavern_data() {
+        let root = std::env::temp_dir().join(format!(
+            "tauritavern-st-migration-empty-{}",
+            uuid::Uuid::new_v4()
+        ));
+        std::fs::create_dir_all(&root).expect("create root");
+
+        assert!(
+            SillyTavernMigration::detect(&root, root.join("target"), root.join("backups")).is_err()
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
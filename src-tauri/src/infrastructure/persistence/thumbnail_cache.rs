@@ -1,3 +1,4 @@
+use image::DynamicImage;
 use image::codecs::jpeg::JpegEncoder;
 use image::imageops::FilterType;
 use mime_guess::from_path;
@@ -153,7 +154,7 @@ fn read_original_asset_sync(original_path: &Path) -> Result<ThumbnailAsset, Doma
     Ok(ThumbnailAsset { bytes, mime_type })
 }
 
-fn thumbnail_is_fresh_sync(
+pub(crate) fn thumbnail_is_fresh_sync(
     thumbnail_path: &Path,
     original_path: &Path,
 ) -> Result<bool, DomainError> {
@@ -187,6 +188,28 @@ fn thumbnail_is_fresh_sync(
     Ok(original_modified <= thumbnail_modified)
 }
 
+pub(crate) fn resize_thumbnail(
+    source_image: &DynamicImage,
+    config: ThumbnailConfig,
+) -> DynamicImage {
+    let width = config.width.max(1);
+    let height = config.height.max(1);
+    match config.resize_mode {
+        ThumbnailResizeMode::PreserveArea => {
+            let source_width = source_image.width().max(1);
+            let source_height = source_image.height().max(1);
+            let aspect_ratio = source_width as f64 / source_height as f64;
+            let target_area = (width as f64) * (height as f64);
+            let thumbnail_width = ((target_area * aspect_ratio).sqrt().round() as u32).max(1);
+            let thumbnail_height = ((target_area / aspect_ratio).sqrt().round() as u32).max(1);
+            source_image.resize(thumbnail_width, thumbnail_height, FilterType::Triangle)
+        }
+        ThumbnailResizeMode::Cover => {
+            source_image.resize_to_fill(width, height, FilterType::Triangle)
+        }
+    }
+}
+
 fn generate_thumbnail_sync(
     original_path: &Path,
     thumbnail_path: &Path,
@@ -208,22 +231,7 @@ fn generate_thumbnail_sync(
         ))
     })?;
 
-    let width = config.width.max(1);
-    let height = config.height.max(1);
-    let thumbnail_image = match config.resize_mode {
-        ThumbnailResizeMode::PreserveArea => {
-            let source_width = source_image.width().max(1);
-            let source_height = source_image.height().max(1);
-            let aspect_ratio = source_width as f64 / source_height as f64;
-            let target_area = (width as f64) * (height as f64);
-            let thumbnail_width = ((target_area * aspect_ratio).sqrt().round() as u32).max(1);
-            let thumbnail_height = ((target_area / aspect_ratio).sqrt().round() as u32).max(1);
-            source_image.resize(thumbnail_width, thumbnail_height, FilterType::Triangle)
-        }
-        ThumbnailResizeMode::Cover => {
-            source_image.resize_to_fill(width, height, FilterType::Triangle)
-        }
-    };
+    let thumbnail_image = resize_thumbnail(&source_image, config);
 
     let quality = config.quality.clamp(1, 100);
     let mut encoded = Vec::new();
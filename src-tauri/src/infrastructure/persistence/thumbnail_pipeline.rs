@@ -0,0 +1,399 @@
+use image::codecs::webp::WebPEncoder;
+use image::{ColorType, ImageEncoder};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::filename::sanitize_filename;
+use crate::infrastructure::persistence::thumbnail_cache::{
+    ThumbnailConfig, is_animated_image_sync, resize_thumbnail, thumbnail_is_fresh_sync,
+};
+use crate::infrastructure::thumbnails::{avatar_thumbnail_config, background_thumbnail_config};
+
+const SOURCE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "gif", "bmp"];
+const MAX_THUMBNAIL_DIMENSION: u32 = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThumbnailKind {
+    Avatar,
+    Background,
+}
+
+impl ThumbnailKind {
+    fn cache_segment(self) -> &'static str {
+        match self {
+            Self::Avatar => "avatar",
+            Self::Background => "bg",
+        }
+    }
+
+    pub fn default_config(self) -> ThumbnailConfig {
+        match self {
+            Self::Avatar => avatar_thumbnail_config(),
+            Self::Background => background_thumbnail_config(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThumbnailSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ThumbnailRegenerationReport {
+    pub generated: usize,
+    pub skipped: usize,
+    pub failed: usize,
+}
+
+/// Bulk WebP thumbnail cache for character avatars and backgrounds.
+///
+/// Thumbnails live at `{cache_root}/{avatar|bg}/{width}x{height}/{file}.webp`. Unlike the
+/// on-demand JPEG cache in `thumbnail_cache`, this one can be regenerated in parallel up
+/// front so galleries hand the webview small files instead of full-size PNGs.
+pub struct ThumbnailPipeline {
+    characters_dir: PathBuf,
+    backgrounds_dir: PathBuf,
+    cache_root: PathBuf,
+}
+
+impl ThumbnailPipeline {
+    pub fn new(characters_dir: PathBuf, backgrounds_dir: PathBuf, cache_root: PathBuf) -> Self {
+        Self {
+            characters_dir,
+            backgrounds_dir,
+            cache_root,
+        }
+    }
+
+    fn source_dir(&self, kind: ThumbnailKind) -> &Path {
+        match kind {
+            ThumbnailKind::Avatar => &self.characters_dir,
+            ThumbnailKind::Background => &self.backgrounds_dir,
+        }
+    }
+
+    fn cache_path(&self, kind: ThumbnailKind, file: &str, size: ThumbnailSize) -> PathBuf {
+        self.cache_root
+            .join(kind.cache_segment())
+            .join(format!("{}x{}", size.width, size.height))
+            .join(format!("{file}.webp"))
+    }
+
+    /// Returns the path of an up-to-date thumbnail for `file`, generating it when missing
+    /// or stale. Animated sources are returned as-is.
+    pub fn ensure_thumbnail(
+        &self,
+        kind: ThumbnailKind,
+        file: &str,
+        size: Option<ThumbnailSize>,
+    ) -> Result<PathBuf, DomainError> {
+        let file = sanitize_filename(file);
+        if file.is_empty() {
+            return Err(DomainError::InvalidData(
+                "Invalid thumbnail file name".to_string(),
+            ));
+        }
+
+        let original_path = self.source_dir(kind).join(&file);
+        if !original_path.is_file() {
+            return Err(DomainError::NotFound(format!(
+                "Source image not found: {}",
+                original_path.display()
+            )));
+        }
+        if is_animated_image_sync(&original_path)? {
+            return Ok(original_path);
+        }
+
+        let config = resolve_config(kind, size)?;
+        let thumbnail_path = self.cache_path(kind, &file, size_of(config));
+        if !thumbnail_is_fresh_sync(&thumbnail_path, &original_path)? {
+            write_webp_thumbnail(&original_path, &thumbnail_path, config)?;
+        }
+        Ok(thumbnail_path)
+    }
+
+    /// Regenerates thumbnails for every source image of `kinds` at each of `sizes`
+    /// (the kind's default size when empty). Work is spread across the rayon pool;
+    /// `on_progress` receives `(done, total)` and `is_cancelled` is polled per image.
+    pub fn regenerate(
+        &self,
+        kinds: &[ThumbnailKind],
+        sizes: &[ThumbnailSize],
+        force: bool,
+        on_progress: &(dyn Fn(usize, usize) + Sync),
+        is_cancelled: &(dyn Fn() -> bool + Sync),
+    ) -> Result<ThumbnailRegenerationReport, DomainError> {
+        let mut tasks = Vec::new();
+        for &kind in kinds {
+            let configs = if sizes.is_empty() {
+                vec![kind.default_config()]
+            } else {
+                sizes
+                    .iter()
+                    .map(|size| resolve_config(kind, Some(*size)))
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+
+            for original_path in list_source_images(self.source_dir(kind))? {
+                for config in &configs {
+                    tasks.push((kind, original_path.clone(), *config));
+                }
+            }
+        }
+
+        let total = tasks.len();
+        let done = AtomicUsize::new(0);
+        let generated = AtomicUsize::new(0);
+        let skipped = AtomicUsize::new(0);
+        let failed = AtomicUsize::new(0);
+
+        tasks.par_iter().for_each(|(kind, original_path, config)| {
+            if is_cancelled() {
+                return;
+            }
+
+            let outcome = self.regenerate_one(*kind, original_path, *config, force);
+            match outcome {
+                Ok(true) => generated.fetch_add(1, Ordering::Relaxed),
+                Ok(false) => skipped.fetch_add(1, Ordering::Relaxed),
+                Err(error) => {
+                    tracing::warn!(
+                        "Failed to generate thumbnail for {}: {}",
+                        original_path.display(),
+                        error
+                    );
+                    failed.fetch_add(1, Ordering::Relaxed)
+                }
+            };
+            on_progress(done.fetch_add(1, Ordering::Relaxed) + 1, total);
+        });
+
+        if is_cancelled() {
+            return Err(DomainError::Cancelled(
+                "Thumbnail regeneration cancelled".to_string(),
+            ));
+        }
+
+        Ok(ThumbnailRegenerationReport {
+            generated: generated.into_inner(),
+            skipped: skipped.into_inner(),
+            failed: failed.into_inner(),
+        })
+    }
+
+    fn regenerate_one(
+        &self,
+        kind: ThumbnailKind,
+        original_path: &Path,
+        config: ThumbnailConfig,
+        force: bool,
+    ) -> Result<bool, DomainError> {
+        if is_animated_image_sync(original_path)? {
+            return Ok(false);
+        }
+
+        let file = original_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let thumbnail_path = self.cache_path(kind, &file, size_of(config));
+        if !force && thumbnail_is_fresh_sync(&thumbnail_path, original_path)? {
+            return Ok(false);
+        }
+
+        write_webp_thumbnail(original_path, &thumbnail_path, config)?;
+        Ok(true)
+    }
+}
+
+fn size_of(config: ThumbnailConfig) -> ThumbnailSize {
+    ThumbnailSize {
+        width: config.width,
+        height: config.height,
+    }
+}
+
+fn resolve_config(
+    kind: ThumbnailKind,
+    size: Option<ThumbnailSize>,
+) -> Result<ThumbnailConfig, DomainError> {
+    let mut config = kind.default_config();
+    if let Some(size) = size {
+        let valid = |value: u32| (1..=MAX_THUMBNAIL_DIMENSION).contains(&value);
+        if !valid(size.width) || !valid(size.height) {
+            return Err(DomainError::InvalidData(format!(
+                "Thumbnail size must be between 1 and {} pixels",
+                MAX_THUMBNAIL_DIMENSION
+            )));
+        }
+        config.width = size.width;
+        config.height = size.height;
+    }
+    Ok(config)
+}
+
+fn list_source_images(dir: &Path) -> Result<Vec<PathBuf>, DomainError> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => {
+            return Err(DomainError::InternalError(format!(
+                "Failed to read image directory '{}': {}",
+                dir.display(),
+                error
+            )));
+        }
+    };
+
+    let mut paths = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.extension()
+                .map(|extension| extension.to_string_lossy().to_ascii_lowercase())
+                .is_some_and(|extension| SOURCE_EXTENSIONS.contains(&extension.as_str()))
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+    Ok(paths)
+}
+
+fn write_webp_thumbnail(
+    original_path: &Path,
+    thumbnail_path: &Path,
+    config: ThumbnailConfig,
+) -> Result<(), DomainError> {
+    let source_image = image::open(original_path).map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to decode source image '{}': {}",
+            original_path.display(),
+            error
+        ))
+    })?;
+    let thumbnail = resize_thumbnail(&source_image, config).to_rgba8();
+
+    let mut encoded = Vec::new();
+    WebPEncoder::new_lossless(&mut encoded)
+        .write_image(
+            thumbnail.as_raw(),
+            thumbnail.width(),
+            thumbnail.height(),
+            ColorType::Rgba8,
+        )
+        .map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to encode thumbnail for '{}': {}",
+                original_path.display(),
+                error
+            ))
+        })?;
+
+    if let Some(parent) = thumbnail_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to ensure thumbnail directory '{}': {}",
+                parent.display(),
+                error
+            ))
+        })?;
+    }
+
+    let temp_path = thumbnail_path.with_extension("webp.tmp");
+    std::fs::write(&temp_path, &encoded).map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to write temporary thumbnail '{}': {}",
+            temp_path.display(),
+            error
+        ))
+    })?;
+    std::fs::rename(&temp_path, thumbnail_path).map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to finalize thumbnail '{}': {}",
+            thumbnail_path.display(),
+            error
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use image::{DynamicImage, ImageFormat, RgbImage};
+
+    use super::{ThumbnailKind, ThumbnailPipeline, ThumbnailSize};
+
+    struct TempDirGuard {
+        path: PathBuf,
+    }
+
+    impl TempDirGuard {
+        fn new(test_name: &str) -> Self {
+            let mut path = std::env::temp_dir();
+            path.push(format!("tauritavern-{test_name}-{}", uuid::Uuid::new_v4()));
+            std::fs::create_dir_all(&path).expect("create temp dir");
+            Self { path }
+        }
+    }
+
+    impl Drop for TempDirGuard {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn write_png(path: &std::path::Path, width: u32, height: u32) {
+        std::fs::create_dir_all(path.parent().expect("parent")).expect("create dir");
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .save_with_format(path, ImageFormat::Png)
+            .expect("write png");
+    }
+
+    #[test]
+    fn regenerates_webp_thumbnails_and_skips_fresh_ones() {
+        let temp = TempDirGuard::new("thumbnail-pipeline");
+        let characters = temp.path.join("characters");
+        write_png(&characters.join("a.png"), 400, 600);
+        write_png(&characters.join("b.png"), 400, 600);
+
+        let pipeline = ThumbnailPipeline::new(
+            characters,
+            temp.path.join("backgrounds"),
+            temp.path.join("cache"),
+        );
+        let sizes = [ThumbnailSize {
+            width: 48,
+            height: 72,
+        }];
+        let kinds = [ThumbnailKind::Avatar, ThumbnailKind::Background];
+
+        let report = pipeline
+            .regenerate(&kinds, &sizes, false, &|_, _| {}, &|| false)
+            .expect("regenerate");
+        assert_eq!(report.generated, 2);
+
+        let thumbnail = temp.path.join("cache/avatar/48x72/a.png.webp");
+        let decoded = image::open(&thumbnail).expect("thumbnail decodes");
+        assert_eq!((decoded.width(), decoded.height()), (48, 72));
+
+        let report = pipeline
+            .regenerate(&kinds, &sizes, false, &|_, _| {}, &|| false)
+            .expect("regenerate again");
+        assert_eq!((report.generated, report.skipped), (0, 2));
+
+        assert_eq!(
+            pipeline
+                .ensure_thumbnail(ThumbnailKind::Avatar, "a.png", Some(sizes[0]))
+                .expect("ensure"),
+            thumbnail
+        );
+    }
+}
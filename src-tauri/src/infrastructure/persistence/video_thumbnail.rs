@@ -0,0 +1,127 @@
+//! First-frame thumbnails for video backgrounds.
+//!
+//! Video decoding is delegated to an `ffmpeg` binary on `PATH`. Platforms without one
+//! (mobile builds, most desktop installs) simply report that no poster is available and
+//! callers fall back to the original asset.
+
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::thumbnail_cache::{
+    ThumbnailConfig, ThumbnailResizeMode, thumbnail_is_fresh_sync,
+};
+
+const FFMPEG_BINARY: &str = "ffmpeg";
+
+fn scale_filter(config: ThumbnailConfig) -> String {
+    let width = config.width.max(1);
+    let height = config.height.max(1);
+    match config.resize_mode {
+        ThumbnailResizeMode::PreserveArea => {
+            format!("scale={width}:{height}:force_original_aspect_ratio=decrease")
+        }
+        ThumbnailResizeMode::Cover => format!(
+            "scale={width}:{height}:force_original_aspect_ratio=increase,crop={width}:{height}"
+        ),
+    }
+}
+
+/// Maps JPEG quality (1-100) onto ffmpeg's `-q:v` scale (2 best, 31 worst).
+fn ffmpeg_quality(quality: u8) -> u8 {
+    let quality = quality.clamp(1, 100) as u32;
+    (31 - (quality * 29) / 100) as u8
+}
+
+fn extract_first_frame_sync(
+    video_path: &Path,
+    thumbnail_path: &Path,
+    config: ThumbnailConfig,
+) -> Result<bool, DomainError> {
+    if thumbnail_is_fresh_sync(thumbnail_path, video_path)? {
+        return Ok(true);
+    }
+
+    if let Some(parent) = thumbnail_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to ensure thumbnail directory '{}': {}",
+                parent.display(),
+                error
+            ))
+        })?;
+    }
+
+    let temp_path = thumbnail_path.with_extension("tmp");
+    let status = Command::new(FFMPEG_BINARY)
+        .args(["-v", "error", "-y", "-i"])
+        .arg(video_path)
+        .args(["-frames:v", "1", "-vf"])
+        .arg(scale_filter(config))
+        .args(["-q:v", &ffmpeg_quality(config.quality).to_string()])
+        .args(["-f", "image2", "-c:v", "mjpeg"])
+        .arg(&temp_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+
+    let succeeded = matches!(status, Ok(status) if status.success()) && temp_path.is_file();
+    if !succeeded {
+        let _ = std::fs::remove_file(&temp_path);
+        return Ok(false);
+    }
+
+    std::fs::rename(&temp_path, thumbnail_path).map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to finalize video thumbnail '{}': {}",
+            thumbnail_path.display(),
+            error
+        ))
+    })?;
+    Ok(true)
+}
+
+/// Writes a JPEG of the first frame of `video_path` to `thumbnail_path`, reusing a fresh
+/// cached copy. Returns `false` when no frame could be extracted.
+pub async fn extract_video_thumbnail(
+    video_path: &Path,
+    thumbnail_path: &Path,
+    config: ThumbnailConfig,
+) -> Result<bool, DomainError> {
+    let video_path = video_path.to_path_buf();
+    let thumbnail_path = thumbnail_path.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        extract_first_frame_sync(&video_path, &thumbnail_path, config)
+    })
+    .await
+    .map_err(|error| {
+        DomainError::InternalError(format!("Video thumbnail worker failed: {}", error))
+    })?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ffmpeg_quality, scale_filter};
+    use crate::infrastructure::persistence::thumbnail_cache::{
+        ThumbnailConfig, ThumbnailResizeMode,
+    };
+
+    #[test]
+    fn builds_ffmpeg_arguments_from_thumbnail_config() {
+        let config = ThumbnailConfig {
+            width: 160,
+            height: 90,
+            quality: 90,
+            resize_mode: ThumbnailResizeMode::PreserveArea,
+        };
+
+        assert_eq!(
+            scale_filter(config),
+            "scale=160:90:force_original_aspect_ratio=decrease"
+        );
+        assert_eq!(ffmpeg_quality(100), 2);
+        assert_eq!(ffmpeg_quality(90), 5);
+        assert_eq!(ffmpeg_quality(1), 31);
+    }
+}
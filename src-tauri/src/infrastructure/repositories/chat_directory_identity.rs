@@ -10,9 +10,7 @@ use tokio::sync::Mutex;
 
 use crate::domain::errors::DomainError;
 use crate::domain::models::filename::sanitize_filename;
-use crate::infrastructure::persistence::file_system::{
-    replace_file_with_fallback, unique_temp_path,
-};
+use crate::infrastructure::persistence::file_system::atomic_write;
 
 const CHAT_ALIAS_VERSION: u32 = 1;
 
@@ -108,6 +106,16 @@ impl ChatAliasStore {
             .map(|entry| entry.dir.clone()))
     }
 
+    /// `(character key, chat directory)` pairs of every recorded alias.
+    pub(crate) async fn alias_dirs(&mut self) -> Result<Vec<(String, String)>, DomainError> {
+        self.reload().await?;
+        Ok(self
+            .aliases
+            .iter()
+            .map(|(key, entry)| (key.clone(), entry.dir.clone()))
+            .collect())
+    }
+
     async fn dir_is_mapped_to_other(
         &mut self,
         character_key: &str,
@@ -167,14 +175,7 @@ impl ChatAliasStore {
             DomainError::InternalError(format!("Failed to serialize chat aliases: {}", error))
         })?;
 
-        let temp_path = unique_temp_path(&self.path, "chat_aliases_v1.json");
-        fs::write(&temp_path, bytes).await.map_err(|error| {
-            DomainError::InternalError(format!(
-                "Failed to write chat alias temp file {:?}: {}",
-                temp_path, error
-            ))
-        })?;
-        replace_file_with_fallback(&temp_path, &self.path).await
+        atomic_write(&self.path, &bytes).await
     }
 }
 
@@ -16,7 +16,7 @@ use crate::domain::repositories::agent_profile_storage_health_repository::{
     AgentProfileStorageRepairAction, AgentProfileStorageScan,
 };
 use crate::infrastructure::persistence::file_system::{
-    list_files_with_extension, read_json_file, replace_file_with_fallback,
+    commit_temp_file, list_files_with_extension, read_json_file,
 };
 
 pub struct FileAgentProfileRepository {
@@ -182,7 +182,7 @@ impl AgentProfileRepository for FileAgentProfileRepository {
                 error
             ))
         })?;
-        replace_file_with_fallback(&temp, &target).await
+        commit_temp_file(&temp, &target).await
     }
 
     async fn delete_profile(&self, id: &AgentProfileId) -> Result<(), DomainError> {
@@ -295,7 +295,7 @@ impl FileAgentProfileRepository {
                 error
             ))
         })?;
-        replace_file_with_fallback(&temp, &target).await
+        commit_temp_file(&temp, &target).await
     }
 }
 
@@ -14,9 +14,7 @@ use crate::domain::repositories::workspace_repository::{
     WorkspaceAppendResult, WorkspaceEntry, WorkspaceEntryKind, WorkspaceFile, WorkspaceFileList,
     WorkspaceRepository, WorkspaceWriteGuard,
 };
-use crate::infrastructure::persistence::file_system::{
-    replace_file_with_fallback, unique_temp_path,
-};
+use crate::infrastructure::persistence::file_system::atomic_write;
 
 #[async_trait]
 impl WorkspaceRepository for FileAgentRepository {
@@ -98,17 +96,7 @@ impl WorkspaceRepository for FileAgentRepository {
         let _guard = self.acquire_workspace_write_lock(&target).await;
         ensure_target_is_not_directory(&target, path).await?;
         verify_workspace_write_guard(&target, path, guard).await?;
-        let temp_path = unique_temp_path(&target, "workspace.txt");
-        fs::write(&temp_path, text.as_bytes())
-            .await
-            .map_err(|error| {
-                DomainError::InternalError(format!(
-                    "Failed to write workspace temp file {}: {}",
-                    temp_path.display(),
-                    error
-                ))
-            })?;
-        replace_file_with_fallback(&temp_path, &target).await?;
+        atomic_write(&target, text.as_bytes()).await?;
 
         workspace_file_from_text(path.clone(), text.to_string())
     }
@@ -131,17 +119,7 @@ impl WorkspaceRepository for FileAgentRepository {
             }
             None => text.to_string(),
         };
-        let temp_path = unique_temp_path(&target, "workspace.txt");
-        fs::write(&temp_path, updated.as_bytes())
-            .await
-            .map_err(|error| {
-                DomainError::InternalError(format!(
-                    "Failed to write workspace temp file {}: {}",
-                    temp_path.display(),
-                    error
-                ))
-            })?;
-        replace_file_with_fallback(&temp_path, &target).await?;
+        atomic_write(&target, updated.as_bytes()).await?;
 
         Ok(WorkspaceAppendResult {
             file: workspace_file_from_text(path.clone(), updated)?,
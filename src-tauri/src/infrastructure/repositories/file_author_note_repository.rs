@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::author_note::AuthorNote;
+use crate::domain::repositories::author_note_repository::AuthorNoteRepository;
+use crate::infrastructure::persistence::file_names::avatar_stem;
+use crate::infrastructure::persistence::file_system::atomic_write;
+
+/// Stores the global note at `<root>/default.json` and character notes at
+/// `<root>/characters/<avatar stem>.json`.
+pub struct FileAuthorNoteRepository {
+    root: PathBuf,
+}
+
+impl FileAuthorNoteRepository {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    fn global_note_path(&self) -> PathBuf {
+        self.root.join("default.json")
+    }
+
+    fn character_note_path(&self, avatar: &str) -> PathBuf {
+        let stem = avatar_stem(avatar);
+        self.root.join("characters").join(format!("{stem}.json"))
+    }
+}
+
+async fn read_note(path: &Path) -> Result<Option<AuthorNote>, DomainError> {
+    let contents = match fs::read_to_string(path).await {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => {
+            return Err(DomainError::InternalError(format!(
+                "Failed to read author's note {:?}: {}",
+                path, error
+            )));
+        }
+    };
+
+    serde_json::from_str::<AuthorNote>(&contents)
+        .map(Some)
+        .map_err(|error| {
+            DomainError::InvalidData(format!(
+                "Invalid JSON in author's note {:?}: {}",
+                path, error
+            ))
+        })
+}
+
+async fn write_note(path: &Path, note: &AuthorNote) -> Result<(), DomainError> {
+    let json = serde_json::to_vec_pretty(note).map_err(|error| {
+        DomainError::InvalidData(format!("Failed to serialize author's note: {}", error))
+    })?;
+
+    atomic_write(path, &json).await
+}
+
+async fn delete_note(path: &Path) -> Result<bool, DomainError> {
+    match fs::remove_file(path).await {
+        Ok(()) => Ok(true),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(error) => Err(DomainError::InternalError(format!(
+            "Failed to delete author's note {:?}: {}",
+            path, error
+        ))),
+    }
+}
+
+#[async_trait]
+impl AuthorNoteRepository for FileAuthorNoteRepository {
+    async fn load_global_note(&self) -> Result<Option<AuthorNote>, DomainError> {
+        read_note(&self.global_note_path()).await
+    }
+
+    async fn save_global_note(&self, note: &AuthorNote) -> Result<(), DomainError> {
+        write_note(&self.global_note_path(), note).await
+    }
+
+    async fn delete_global_note(&self) -> Result<bool, DomainError> {
+        delete_note(&self.global_note_path()).await
+    }
+
+    async fn load_character_note(&self, avatar: &str) -> Result<Option<AuthorNote>, DomainError> {
+        read_note(&self.character_note_path(avatar)).await
+    }
+
+    async fn save_character_note(
+        &self,
+        avatar: &str,
+        note: &AuthorNote,
+    ) -> Result<(), DomainError> {
+        write_note(&self.character_note_path(avatar), note).await
+    }
+
+    async fn delete_character_note(&self, avatar: &str) -> Result<bool, DomainError> {
+        delete_note(&self.character_note_path(avatar)).await
+    }
+}
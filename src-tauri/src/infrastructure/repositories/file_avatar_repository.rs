@@ -8,6 +8,7 @@ use tokio::fs as tokio_fs;
 use crate::domain::errors::DomainError;
 use crate::domain::models::avatar::{Avatar, AvatarUploadResult, CropInfo};
 use crate::domain::repositories::avatar_repository::AvatarRepository;
+use crate::infrastructure::persistence::file_system::atomic_write;
 
 // Constants for avatar dimensions
 const AVATAR_WIDTH: u32 = 400;
@@ -180,12 +181,7 @@ impl AvatarRepository for FileAvatarRepository {
 
         // Save the processed image
         let avatar_path = self.avatars_dir.join(&filename);
-        tokio_fs::write(&avatar_path, &image_data)
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to write avatar file: {}", e);
-                DomainError::InternalError(format!("Failed to write avatar file: {}", e))
-            })?;
+        atomic_write(&avatar_path, &image_data).await?;
 
         tracing::info!("Avatar uploaded: {}", filename);
         Ok(AvatarUploadResult { path: filename })
@@ -3,13 +3,17 @@ use std::path::{Path, PathBuf};
 use tokio::fs;
 
 use crate::domain::errors::DomainError;
-use crate::domain::models::background::BackgroundAsset;
+use crate::domain::models::background::{
+    BackgroundAsset, BackgroundMediaType, MAX_BACKGROUND_VIDEO_BYTES,
+};
 use crate::domain::models::filename::sanitize_filename;
 use crate::domain::repositories::background_repository::BackgroundRepository;
 use crate::infrastructure::logging::logger;
+use crate::infrastructure::persistence::file_system::atomic_write;
 use crate::infrastructure::persistence::thumbnail_cache::{
     invalidate_thumbnail_cache, read_thumbnail_or_original,
 };
+use crate::infrastructure::persistence::video_thumbnail::extract_video_thumbnail;
 use crate::infrastructure::thumbnails::background_thumbnail_config;
 
 /// File system implementation of the BackgroundRepository
@@ -74,6 +78,41 @@ impl FileBackgroundRepository {
         let thumbnail_path = self.thumbnail_cache_path(filename);
         invalidate_thumbnail_cache(&thumbnail_path).await
     }
+
+    fn validate_upload_size(filename: &str, size: u64) -> Result<(), DomainError> {
+        if BackgroundMediaType::from_filename(filename) == BackgroundMediaType::Video
+            && size > MAX_BACKGROUND_VIDEO_BYTES
+        {
+            return Err(DomainError::InvalidData(format!(
+                "Video background is too large: {} bytes (limit {} bytes)",
+                size, MAX_BACKGROUND_VIDEO_BYTES
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Extracts the first frame of a video background into the thumbnail cache.
+    /// Returns `false` for images and when no frame could be extracted.
+    async fn ensure_video_thumbnail(&self, filename: &str) -> Result<bool, DomainError> {
+        if BackgroundMediaType::from_filename(filename) != BackgroundMediaType::Video {
+            return Ok(false);
+        }
+
+        let extracted = extract_video_thumbnail(
+            &self.backgrounds_dir.join(filename),
+            &self.thumbnail_cache_path(filename),
+            background_thumbnail_config(),
+        )
+        .await?;
+        if !extracted {
+            logger::debug(&format!(
+                "FileBackgroundRepository: No first-frame thumbnail for video background {}",
+                filename
+            ));
+        }
+        Ok(extracted)
+    }
 }
 
 #[async_trait]
@@ -167,13 +206,12 @@ impl BackgroundRepository for FileBackgroundRepository {
         self.ensure_backgrounds_dir_exists().await?;
 
         let normalized = self.normalize_filename(filename)?;
+        Self::validate_upload_size(&normalized, data.len() as u64)?;
         let file_path = self.backgrounds_dir.join(&normalized);
-        fs::write(&file_path, data).await.map_err(|error| {
-            logger::error(&format!("Failed to write background file: {}", error));
-            DomainError::InternalError(format!("Failed to write background file: {}", error))
-        })?;
+        atomic_write(&file_path, data).await?;
 
         self.invalidate_thumbnail_cache(&normalized).await?;
+        self.ensure_video_thumbnail(&normalized).await?;
         Ok(normalized)
     }
 
@@ -190,6 +228,23 @@ impl BackgroundRepository for FileBackgroundRepository {
         self.ensure_backgrounds_dir_exists().await?;
 
         let normalized = self.normalize_filename(filename)?;
+        let source_size = fs::metadata(source_path)
+            .await
+            .map_err(|error| {
+                if error.kind() == std::io::ErrorKind::NotFound {
+                    return DomainError::NotFound(format!(
+                        "Source background file not found: {}",
+                        source_path.display()
+                    ));
+                }
+
+                DomainError::InternalError(format!(
+                    "Failed to read background file metadata: {}",
+                    error
+                ))
+            })?
+            .len();
+        Self::validate_upload_size(&normalized, source_size)?;
         let file_path = self.backgrounds_dir.join(&normalized);
 
         fs::copy(source_path, &file_path).await.map_err(|error| {
@@ -205,17 +260,34 @@ impl BackgroundRepository for FileBackgroundRepository {
         })?;
 
         self.invalidate_thumbnail_cache(&normalized).await?;
+        self.ensure_video_thumbnail(&normalized).await?;
         Ok(normalized)
     }
 
     async fn read_background_thumbnail(
         &self,
         filename: &str,
-        _animated: bool,
+        animated: bool,
     ) -> Result<BackgroundAsset, DomainError> {
         let normalized = self.normalize_filename(filename)?;
         let original_path = self.backgrounds_dir.join(&normalized);
         let thumbnail_path = self.thumbnail_cache_path(&normalized);
+
+        // Video thumbnails are a still of the first frame unless the caller wants playback.
+        if !animated && self.ensure_video_thumbnail(&normalized).await? {
+            let bytes = fs::read(&thumbnail_path).await.map_err(|error| {
+                DomainError::InternalError(format!(
+                    "Failed to read video thumbnail '{}': {}",
+                    thumbnail_path.display(),
+                    error
+                ))
+            })?;
+            return Ok(BackgroundAsset {
+                bytes,
+                mime_type: "image/jpeg".to_string(),
+            });
+        }
+
         let asset = read_thumbnail_or_original(
             &original_path,
             &thumbnail_path,
@@ -231,6 +303,7 @@ impl BackgroundRepository for FileBackgroundRepository {
 
 #[cfg(test)]
 mod tests {
+    use crate::domain::models::background::MAX_BACKGROUND_VIDEO_BYTES;
     use crate::domain::repositories::background_repository::BackgroundRepository;
     use std::path::PathBuf;
 
@@ -300,4 +373,35 @@ mod tests {
             .expect("read destination");
         assert_eq!(dest_bytes, b"ok");
     }
+
+    #[tokio::test]
+    async fn upload_background_rejects_oversized_video() {
+        let temp = TempDirGuard::new("background-upload-oversized-video");
+        let repository = FileBackgroundRepository::new(
+            temp.path.join("backgrounds"),
+            temp.path.join("thumbnails/bg"),
+        );
+
+        assert!(
+            FileBackgroundRepository::validate_upload_size(
+                "clip.webm",
+                MAX_BACKGROUND_VIDEO_BYTES + 1
+            )
+            .is_err()
+        );
+        assert!(
+            FileBackgroundRepository::validate_upload_size(
+                "still.png",
+                MAX_BACKGROUND_VIDEO_BYTES + 1
+            )
+            .is_ok()
+        );
+
+        let uploaded = repository
+            .upload_background("clip.mp4", b"not really a video")
+            .await
+            .expect("small video upload");
+        assert_eq!(uploaded, "clip.mp4");
+        assert!(temp.path.join("backgrounds/clip.mp4").is_file());
+    }
 }
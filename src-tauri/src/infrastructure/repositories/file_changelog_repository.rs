@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::changelog::{MigrationRecord, ReleaseNotes};
+use crate::domain::repositories::changelog_repository::ChangelogRepository;
+use crate::infrastructure::persistence::migration_ledger;
+
+/// Release notes shipped in the binary, newest first.
+const BUNDLED_RELEASE_NOTES: &str = include_str!("../../../resources/release-notes.json");
+
+pub struct FileChangelogRepository {
+    data_root: PathBuf,
+}
+
+impl FileChangelogRepository {
+    pub fn new(data_root: PathBuf) -> Self {
+        Self { data_root }
+    }
+}
+
+#[async_trait]
+impl ChangelogRepository for FileChangelogRepository {
+    async fn bundled_release_notes(&self) -> Result<Vec<ReleaseNotes>, DomainError> {
+        serde_json::from_str(BUNDLED_RELEASE_NOTES).map_err(|error| {
+            DomainError::InternalError(format!("Bundled release notes are invalid: {error}"))
+        })
+    }
+
+    async fn list_migrations(&self) -> Result<Vec<MigrationRecord>, DomainError> {
+        migration_ledger::load_migrations(&self.data_root).await
+    }
+
+    async fn record_migration(&self, record: MigrationRecord) -> Result<(), DomainError> {
+        migration_ledger::record_migration(&self.data_root, record).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BUNDLED_RELEASE_NOTES;
+    use crate::domain::models::changelog::ReleaseNotes;
+
+    #[test]
+    fn bundled_release_notes_parse() {
+        let releases: Vec<ReleaseNotes> =
+            serde_json::from_str(BUNDLED_RELEASE_NOTES).expect("valid release notes");
+        assert!(!releases.is_empty());
+        assert!(releases.iter().all(|release| !release.version.is_empty()));
+    }
+}
@@ -0,0 +1,270 @@
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::character_asset::CharacterAsset;
+use crate::domain::repositories::character_asset_repository::CharacterAssetRepository;
+use crate::infrastructure::persistence::file_names::{
+    character_directory_name, path_exists, sanitized_name, unique_file_path,
+};
+use crate::infrastructure::persistence::file_system::atomic_write;
+
+const ASSETS_DIRECTORY: &str = "assets";
+const CHARACTERS_URL_PREFIX: &str = "/characters";
+
+/// Content types accepted into a gallery, keyed by their leading magic bytes.
+fn sniff_mime_type(bytes: &[u8]) -> Option<(&'static str, &'static str)> {
+    if bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+        return Some(("image/png", "png"));
+    }
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return Some(("image/jpeg", "jpg"));
+    }
+    if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        return Some(("image/gif", "gif"));
+    }
+    if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        return Some(("image/webp", "webp"));
+    }
+    if bytes.starts_with(b"BM") {
+        return Some(("image/bmp", "bmp"));
+    }
+    if bytes.len() >= 12 && &bytes[4..8] == b"ftyp" {
+        return match &bytes[8..12] {
+            b"avif" | b"avis" => Some(("image/avif", "avif")),
+            _ => Some(("video/mp4", "mp4")),
+        };
+    }
+    if bytes.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) {
+        return Some(("video/webm", "webm"));
+    }
+    None
+}
+
+/// File system implementation of the CharacterAssetRepository.
+///
+/// Galleries live next to the cards in `characters/<avatar stem>/assets/`, which the
+/// `/characters/` user data route already serves.
+pub struct FileCharacterAssetRepository {
+    characters_dir: PathBuf,
+}
+
+impl FileCharacterAssetRepository {
+    /// Create a new FileCharacterAssetRepository rooted at the characters directory
+    pub fn new(characters_dir: PathBuf) -> Self {
+        Self { characters_dir }
+    }
+
+    fn assets_dir(&self, character: &str) -> PathBuf {
+        self.characters_dir.join(character).join(ASSETS_DIRECTORY)
+    }
+
+    async fn describe(
+        &self,
+        character: &str,
+        path: &Path,
+        mime_type: Option<&str>,
+    ) -> Result<CharacterAsset, DomainError> {
+        let metadata = fs::metadata(path).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to stat character asset '{}': {}",
+                path.display(),
+                error
+            ))
+        })?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_millis() as i64)
+            .unwrap_or_default();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mime_type = mime_type.map(str::to_string).unwrap_or_else(|| {
+            mime_guess::from_path(path)
+                .first_or_octet_stream()
+                .essence_str()
+                .to_string()
+        });
+
+        Ok(CharacterAsset {
+            url: format!("{CHARACTERS_URL_PREFIX}/{character}/{ASSETS_DIRECTORY}/{name}"),
+            name,
+            mime_type,
+            size: metadata.len(),
+            modified,
+        })
+    }
+}
+
+#[async_trait]
+impl CharacterAssetRepository for FileCharacterAssetRepository {
+    async fn list_assets(&self, character: &str) -> Result<Vec<CharacterAsset>, DomainError> {
+        let character = character_directory_name(character)?;
+        let dir = self.assets_dir(&character);
+        if !path_exists(&dir).await? {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = fs::read_dir(&dir).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to read character assets '{}': {}",
+                dir.display(),
+                error
+            ))
+        })?;
+
+        let mut assets = Vec::new();
+        while let Some(entry) = entries.next_entry().await.map_err(|error| {
+            DomainError::InternalError(format!("Failed to read character asset entry: {}", error))
+        })? {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            assets.push(self.describe(&character, &path, None).await?);
+        }
+
+        assets.sort_by(|left, right| {
+            right
+                .modified
+                .cmp(&left.modified)
+                .then_with(|| left.name.cmp(&right.name))
+        });
+        Ok(assets)
+    }
+
+    async fn save_asset(
+        &self,
+        character: &str,
+        file_name: &str,
+        data: &[u8],
+    ) -> Result<CharacterAsset, DomainError> {
+        let character = character_directory_name(character)?;
+        let file_name = sanitized_name(file_name, "asset file name")?;
+        let (mime_type, extension) = sniff_mime_type(data).ok_or_else(|| {
+            DomainError::InvalidData(format!("Unsupported character asset type: {}", file_name))
+        })?;
+
+        let dir = self.assets_dir(&character);
+        fs::create_dir_all(&dir).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to create character asset directory '{}': {}",
+                dir.display(),
+                error
+            ))
+        })?;
+
+        let stem = Path::new(&file_name)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .filter(|stem| !stem.is_empty())
+            .unwrap_or_else(|| chrono::Utc::now().timestamp_millis().to_string());
+        let target = unique_file_path(&dir, &stem, extension).await?;
+
+        atomic_write(&target, data).await?;
+
+        self.describe(&character, &target, Some(mime_type)).await
+    }
+
+    async fn delete_asset(&self, character: &str, file_name: &str) -> Result<(), DomainError> {
+        let character = character_directory_name(character)?;
+        let file_name = sanitized_name(file_name, "asset file name")?;
+        let path = self.assets_dir(&character).join(&file_name);
+
+        fs::remove_file(&path)
+            .await
+            .map_err(|error| match error.kind() {
+                std::io::ErrorKind::NotFound => {
+                    DomainError::NotFound(format!("Character asset not found: {}", file_name))
+                }
+                _ => DomainError::InternalError(format!(
+                    "Failed to delete character asset '{}': {}",
+                    path.display(),
+                    error
+                )),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{FileCharacterAssetRepository, sniff_mime_type};
+    use crate::domain::repositories::character_asset_repository::CharacterAssetRepository;
+
+    struct TempDirGuard {
+        path: PathBuf,
+    }
+
+    impl TempDirGuard {
+        fn new(test_name: &str) -> Self {
+            let mut path = std::env::temp_dir();
+            path.push(format!("tauritavern-{test_name}-{}", uuid::Uuid::new_v4()));
+            std::fs::create_dir_all(&path).expect("create temp dir");
+            Self { path }
+        }
+    }
+
+    impl Drop for TempDirGuard {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    const PNG_HEADER: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0];
+
+    #[test]
+    fn sniffs_content_type_from_magic_bytes() {
+        assert_eq!(sniff_mime_type(PNG_HEADER), Some(("image/png", "png")));
+        assert_eq!(
+            sniff_mime_type(b"\0\0\0\x18ftypisom\0\0"),
+            Some(("video/mp4", "mp4"))
+        );
+        assert_eq!(sniff_mime_type(b"<svg xmlns=\"\"/>"), None);
+    }
+
+    #[tokio::test]
+    async fn save_list_and_delete_character_assets() {
+        let temp = TempDirGuard::new("character-assets");
+        let repository = FileCharacterAssetRepository::new(temp.path.clone());
+
+        let first = repository
+            .save_asset("Alice.png", "../pic.jpeg", PNG_HEADER)
+            .await
+            .expect("save asset");
+        assert_eq!(first.name, "..pic.png");
+        assert_eq!(first.url, "/characters/Alice/assets/..pic.png");
+        assert_eq!(first.mime_type, "image/png");
+        assert!(temp.path.join("Alice/assets/..pic.png").is_file());
+
+        let second = repository
+            .save_asset("Alice", "..pic.png", PNG_HEADER)
+            .await
+            .expect("save clashing asset");
+        assert_eq!(second.name, "..pic-1.png");
+
+        let listed = repository.list_assets("Alice").await.expect("list assets");
+        assert_eq!(listed.len(), 2);
+
+        repository
+            .delete_asset("Alice", &first.name)
+            .await
+            .expect("delete asset");
+        assert_eq!(
+            repository.list_assets("Alice").await.expect("list").len(),
+            1
+        );
+        assert!(
+            repository
+                .save_asset("Alice", "script.html", b"<html></html>")
+                .await
+                .is_err()
+        );
+    }
+}
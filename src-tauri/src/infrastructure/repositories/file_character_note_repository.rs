@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::character_note::CharacterNote;
+use crate::domain::repositories::character_note_repository::CharacterNoteRepository;
+use crate::infrastructure::persistence::file_names::avatar_stem;
+use crate::infrastructure::persistence::file_system::{atomic_write, list_files_with_extension};
+
+const NOTE_EXTENSION: &str = "md";
+
+/// Stores each character's note as plain text at `<root>/<avatar stem>.md`, so notes
+/// stay readable outside the app and travel with data archives of `user/`.
+pub struct FileCharacterNoteRepository {
+    root: PathBuf,
+}
+
+impl FileCharacterNoteRepository {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    fn note_path(&self, avatar: &str) -> PathBuf {
+        self.root
+            .join(format!("{}.{NOTE_EXTENSION}", avatar_stem(avatar)))
+    }
+}
+
+async fn read_note(path: &Path, avatar: String) -> Result<Option<CharacterNote>, DomainError> {
+    let content = match fs::read_to_string(path).await {
+        Ok(content) => content,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => {
+            return Err(DomainError::InternalError(format!(
+                "Failed to read character note {:?}: {}",
+                path, error
+            )));
+        }
+    };
+    let updated_at = fs::metadata(path)
+        .await
+        .ok()
+        .and_then(|metadata| metadata.modified().ok())
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or_default();
+
+    Ok(Some(CharacterNote {
+        avatar,
+        content,
+        updated_at,
+    }))
+}
+
+#[async_trait]
+impl CharacterNoteRepository for FileCharacterNoteRepository {
+    async fn load_note(&self, avatar: &str) -> Result<Option<CharacterNote>, DomainError> {
+        read_note(&self.note_path(avatar), avatar.to_string()).await
+    }
+
+    async fn save_note(&self, avatar: &str, content: &str) -> Result<CharacterNote, DomainError> {
+        let path = self.note_path(avatar);
+        atomic_write(&path, content.as_bytes()).await?;
+
+        read_note(&path, avatar.to_string()).await?.ok_or_else(|| {
+            DomainError::InternalError(format!("Character note vanished after write {:?}", path))
+        })
+    }
+
+    async fn delete_note(&self, avatar: &str) -> Result<bool, DomainError> {
+        let path = self.note_path(avatar);
+        match fs::remove_file(&path).await {
+            Ok(()) => Ok(true),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(error) => Err(DomainError::InternalError(format!(
+                "Failed to delete character note {:?}: {}",
+                path, error
+            ))),
+        }
+    }
+
+    async fn list_notes(&self) -> Result<Vec<CharacterNote>, DomainError> {
+        let mut notes = Vec::new();
+        for path in list_files_with_extension(&self.root, NOTE_EXTENSION).await? {
+            let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            if let Some(note) = read_note(&path, format!("{stem}.png")).await? {
+                notes.push(note);
+            }
+        }
+
+        notes.sort_by(|a, b| a.avatar.cmp(&b.avatar));
+        Ok(notes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn notes_round_trip_and_list_by_avatar() {
+        let root = std::env::temp_dir().join(format!(
+            "tauritavern-character-notes-{}",
+            uuid::Uuid::new_v4()
+        ));
+        let repository = FileCharacterNoteRepository::new(root.clone());
+
+        assert!(
+            repository
+                .load_note("Alice.png")
+                .await
+                .expect("load")
+                .is_none()
+        );
+        let saved = repository
+            .save_note("Alice.png", "Rewrite the greeting.")
+            .await
+            .expect("save");
+        assert_eq!(saved.content, "Rewrite the greeting.");
+        assert!(root.join("Alice.md").is_file());
+
+        let notes = repository.list_notes().await.expect("list");
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].avatar, "Alice.png");
+
+        assert!(repository.delete_note("Alice.png").await.expect("delete"));
+        assert!(!repository.delete_note("Alice.png").await.expect("delete"));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use std::io;
+use std::path::PathBuf;
+
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::character_prompt_overrides::CharacterPromptOverrides;
+use crate::domain::repositories::character_prompt_overrides_repository::CharacterPromptOverridesRepository;
+use crate::infrastructure::persistence::file_names::avatar_stem;
+use crate::infrastructure::persistence::file_system::atomic_write;
+
+/// Stores each character's overrides at `<root>/<avatar stem>.json`.
+pub struct FileCharacterPromptOverridesRepository {
+    root: PathBuf,
+}
+
+impl FileCharacterPromptOverridesRepository {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    fn overrides_path(&self, avatar: &str) -> PathBuf {
+        let stem = avatar_stem(avatar);
+        self.root.join(format!("{stem}.json"))
+    }
+}
+
+#[async_trait]
+impl CharacterPromptOverridesRepository for FileCharacterPromptOverridesRepository {
+    async fn load_overrides(
+        &self,
+        avatar: &str,
+    ) -> Result<Option<CharacterPromptOverrides>, DomainError> {
+        let path = self.overrides_path(avatar);
+        let contents = match fs::read_to_string(&path).await {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => {
+                return Err(DomainError::InternalError(format!(
+                    "Failed to read character prompt overrides {:?}: {}",
+                    path, error
+                )));
+            }
+        };
+
+        serde_json::from_str::<CharacterPromptOverrides>(&contents)
+            .map(Some)
+            .map_err(|error| {
+                DomainError::InvalidData(format!(
+                    "Invalid JSON in character prompt overrides {:?}: {}",
+                    path, error
+                ))
+            })
+    }
+
+    async fn save_overrides(
+        &self,
+        avatar: &str,
+        overrides: &CharacterPromptOverrides,
+    ) -> Result<(), DomainError> {
+        let json = serde_json::to_vec_pretty(overrides).map_err(|error| {
+            DomainError::InvalidData(format!(
+                "Failed to serialize character prompt overrides: {}",
+                error
+            ))
+        })?;
+
+        atomic_write(&self.overrides_path(avatar), &json).await
+    }
+
+    async fn delete_overrides(&self, avatar: &str) -> Result<bool, DomainError> {
+        let path = self.overrides_path(avatar);
+        match fs::remove_file(&path).await {
+            Ok(()) => Ok(true),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(error) => Err(DomainError::InternalError(format!(
+                "Failed to delete character prompt overrides {:?}: {}",
+                path, error
+            ))),
+        }
+    }
+}
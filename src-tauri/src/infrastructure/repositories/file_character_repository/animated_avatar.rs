@@ -0,0 +1,319 @@
+//! Animated avatar sidecars.
+//!
+//! Character cards are PNG files, so GIF / animated WebP avatars cannot be stored in the card
+//! itself and APNG frames are lost when the avatar is re-encoded. The original animated file
+//! is kept in `characters/_animated/<file stem>.<ext>` and the card records its file name in
+//! `data.extensions.tauritavern.animatedAvatar`. The card PNG keeps a static first frame so
+//! every other consumer still works.
+
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use image::AnimationDecoder;
+use image::codecs::gif::GifDecoder;
+use serde_json::{Map, Value};
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::character_repository::ImageCrop;
+use crate::infrastructure::logging::logger;
+use crate::infrastructure::persistence::file_system::atomic_write;
+use crate::infrastructure::persistence::png_utils::process_avatar_image;
+
+use super::FileCharacterRepository;
+
+pub(super) const ANIMATED_AVATAR_DIR: &str = "_animated";
+const ANIMATED_AVATAR_FIELD: &str = "animatedAvatar";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum AnimatedAvatarFormat {
+    Gif,
+    Apng,
+    Webp,
+}
+
+impl AnimatedAvatarFormat {
+    const ALL: [Self; 3] = [Self::Gif, Self::Apng, Self::Webp];
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Apng => "png",
+            Self::Webp => "webp",
+        }
+    }
+}
+
+/// Returns the animation format when `bytes` hold more than one frame.
+pub(super) fn detect_animated_avatar(bytes: &[u8]) -> Option<AnimatedAvatarFormat> {
+    if bytes.starts_with(b"GIF8") {
+        let frames = GifDecoder::new(Cursor::new(bytes))
+            .ok()?
+            .into_frames()
+            .take(2)
+            .filter(Result::is_ok)
+            .count();
+        return (frames > 1).then_some(AnimatedAvatarFormat::Gif);
+    }
+
+    if is_apng(bytes) {
+        return Some(AnimatedAvatarFormat::Apng);
+    }
+
+    // VP8X header with the animation flag set.
+    let is_animated_webp = bytes.len() > 20
+        && &bytes[0..4] == b"RIFF"
+        && &bytes[8..12] == b"WEBP"
+        && &bytes[12..16] == b"VP8X"
+        && bytes[20] & 0x02 != 0;
+    is_animated_webp.then_some(AnimatedAvatarFormat::Webp)
+}
+
+fn is_apng(bytes: &[u8]) -> bool {
+    const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    if !bytes.starts_with(&PNG_SIGNATURE) {
+        return false;
+    }
+
+    let mut offset = PNG_SIGNATURE.len();
+    while offset + 8 <= bytes.len() {
+        let length = u32::from_be_bytes([
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ]) as usize;
+        match &bytes[offset + 4..offset + 8] {
+            b"acTL" => return true,
+            b"IDAT" | b"IEND" => return false,
+            _ => {}
+        }
+        offset = offset.saturating_add(12).saturating_add(length);
+    }
+    false
+}
+
+pub(super) fn animated_avatar_field(card_json: &str) -> Option<String> {
+    let value: Value = serde_json::from_str(card_json).ok()?;
+    value
+        .pointer("/data/extensions/tauritavern/animatedAvatar")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+/// Points the card at `sidecar` (or clears the pointer). Returns the input unchanged when
+/// the pointer already matches, so unrelated saves do not re-serialize the card.
+pub(super) fn set_animated_avatar_field(
+    card_json: &str,
+    sidecar: Option<&str>,
+) -> Result<String, DomainError> {
+    if animated_avatar_field(card_json).as_deref() == sidecar {
+        return Ok(card_json.to_string());
+    }
+
+    let mut value: Value = serde_json::from_str(card_json).map_err(|error| {
+        DomainError::InvalidData(format!("Failed to parse character data: {}", error))
+    })?;
+    let card = value.as_object_mut().ok_or_else(|| {
+        DomainError::InvalidData("Character card data is not a JSON object".to_string())
+    })?;
+
+    let tauritavern =
+        ["data", "extensions", "tauritavern"]
+            .iter()
+            .try_fold(card, |object, key| {
+                object
+                    .entry(key.to_string())
+                    .or_insert_with(|| Value::Object(Map::new()))
+                    .as_object_mut()
+                    .ok_or_else(|| {
+                        DomainError::InvalidData(format!("Character card field {} is invalid", key))
+                    })
+            })?;
+
+    match sidecar {
+        Some(sidecar) => {
+            tauritavern.insert(
+                ANIMATED_AVATAR_FIELD.to_string(),
+                Value::String(sidecar.to_string()),
+            );
+        }
+        None => {
+            tauritavern.remove(ANIMATED_AVATAR_FIELD);
+        }
+    }
+
+    serde_json::to_string(&value).map_err(|error| {
+        DomainError::InvalidData(format!("Failed to serialize character data: {}", error))
+    })
+}
+
+impl FileCharacterRepository {
+    fn animated_avatar_dir(&self) -> PathBuf {
+        self.characters_dir.join(ANIMATED_AVATAR_DIR)
+    }
+
+    pub(super) fn find_animated_avatar(
+        &self,
+        file_stem: &str,
+    ) -> Option<(PathBuf, AnimatedAvatarFormat)> {
+        AnimatedAvatarFormat::ALL.into_iter().find_map(|format| {
+            let path =
+                self.animated_avatar_dir()
+                    .join(format!("{}.{}", file_stem, format.extension()));
+            path.is_file().then_some((path, format))
+        })
+    }
+
+    pub(super) async fn remove_animated_avatar(&self, file_stem: &str) -> Result<(), DomainError> {
+        while let Some((path, _)) = self.find_animated_avatar(file_stem) {
+            fs::remove_file(&path).await.map_err(|error| {
+                DomainError::InternalError(format!(
+                    "Failed to remove animated avatar '{}': {}",
+                    path.display(),
+                    error
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
+    async fn store_animated_avatar(
+        &self,
+        file_stem: &str,
+        bytes: &[u8],
+        format: AnimatedAvatarFormat,
+    ) -> Result<String, DomainError> {
+        self.remove_animated_avatar(file_stem).await?;
+
+        let file_name = format!("{}.{}", file_stem, format.extension());
+        atomic_write(&self.animated_avatar_dir().join(&file_name), bytes).await?;
+        Ok(file_name)
+    }
+
+    /// Moves (or copies) the sidecar of `from_stem` to `to_stem`, returning the new
+    /// sidecar file name when one existed.
+    pub(super) async fn transfer_animated_avatar(
+        &self,
+        from_stem: &str,
+        to_stem: &str,
+        keep_source: bool,
+    ) -> Result<Option<String>, DomainError> {
+        let Some((source, format)) = self.find_animated_avatar(from_stem) else {
+            return Ok(None);
+        };
+
+        let file_name = format!("{}.{}", to_stem, format.extension());
+        let target = self.animated_avatar_dir().join(&file_name);
+        if source == target {
+            return Ok(Some(file_name));
+        }
+
+        let result = if keep_source {
+            fs::copy(&source, &target).await.map(|_| ())
+        } else {
+            fs::rename(&source, &target).await
+        };
+        result.map_err(|error| {
+            DomainError::InternalError(format!("Failed to move animated avatar: {}", error))
+        })?;
+        Ok(Some(file_name))
+    }
+
+    /// Turns a replacement avatar into the static PNG carrier and card JSON for `file_stem`.
+    /// Uncropped animated sources are kept as a sidecar; anything else drops the old one.
+    pub(super) async fn prepare_replacement_avatar(
+        &self,
+        file_stem: &str,
+        file_data: Vec<u8>,
+        crop: Option<ImageCrop>,
+        card_json: &str,
+    ) -> Result<(Vec<u8>, String), DomainError> {
+        let animated = match crop {
+            Some(_) => None,
+            None => detect_animated_avatar(&file_data),
+        };
+        let original = animated.map(|_| file_data.clone());
+
+        let image_data = process_avatar_image(file_data, crop).await?;
+
+        let sidecar = match (animated, original) {
+            (Some(format), Some(original)) => {
+                logger::debug(&format!(
+                    "Keeping animated avatar sidecar for character {}",
+                    file_stem
+                ));
+                Some(
+                    self.store_animated_avatar(file_stem, &original, format)
+                        .await?,
+                )
+            }
+            _ => {
+                self.remove_animated_avatar(file_stem).await?;
+                None
+            }
+        };
+
+        let card_json = set_animated_avatar_field(card_json, sidecar.as_deref())?;
+        Ok((image_data, card_json))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use image::codecs::gif::GifEncoder;
+    use image::{Delay, Frame, RgbaImage};
+    use serde_json::json;
+
+    use super::{
+        AnimatedAvatarFormat, animated_avatar_field, detect_animated_avatar,
+        set_animated_avatar_field,
+    };
+
+    fn build_animated_gif() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(Cursor::new(&mut bytes));
+            for shade in [0u8, 255] {
+                let frame = Frame::from_parts(
+                    RgbaImage::from_pixel(4, 4, image::Rgba([shade, 0, 0, 255])),
+                    0,
+                    0,
+                    Delay::from_numer_denom_ms(100, 1),
+                );
+                encoder.encode_frame(frame).expect("encode frame");
+            }
+        }
+        bytes
+    }
+
+    #[test]
+    fn detects_multi_frame_gif_only() {
+        assert_eq!(
+            detect_animated_avatar(&build_animated_gif()),
+            Some(AnimatedAvatarFormat::Gif)
+        );
+        assert_eq!(detect_animated_avatar(b"\x89PNG\r\n\x1a\n"), None);
+    }
+
+    #[test]
+    fn animated_avatar_pointer_round_trips_through_card_json() {
+        let card = json!({ "spec": "chara_card_v2", "data": { "name": "A" } }).to_string();
+
+        let with_pointer = set_animated_avatar_field(&card, Some("A.gif")).expect("set");
+        assert_eq!(
+            animated_avatar_field(&with_pointer).as_deref(),
+            Some("A.gif")
+        );
+        assert_eq!(
+            set_animated_avatar_field(&with_pointer, Some("A.gif")).expect("noop"),
+            with_pointer
+        );
+
+        let cleared = set_animated_avatar_field(&with_pointer, None).expect("clear");
+        assert_eq!(animated_avatar_field(&cleared), None);
+        assert_eq!(set_animated_avatar_field(&card, None).expect("noop"), card);
+    }
+}
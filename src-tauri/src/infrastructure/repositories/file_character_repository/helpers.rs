@@ -11,9 +11,7 @@ use crate::domain::models::character::Character;
 use crate::domain::models::chat::parse_message_timestamp;
 use crate::domain::models::filename::sanitize_filename;
 use crate::infrastructure::logging::logger;
-use crate::infrastructure::persistence::file_system::{
-    list_files_with_extension, replace_file_with_fallback, unique_temp_path,
-};
+use crate::infrastructure::persistence::file_system::{atomic_write, list_files_with_extension};
 use crate::infrastructure::persistence::png_utils::{
     read_character_data_from_png, write_character_data_to_png,
 };
@@ -319,15 +317,7 @@ impl FileCharacterRepository {
             })?;
             let updated_png = write_character_data_to_png(&file_data, &updated_json)?;
 
-            let temp_path = unique_temp_path(path, "character.png");
-            fs::write(&temp_path, updated_png).await.map_err(|error| {
-                DomainError::InternalError(format!(
-                    "Failed to write repaired character temp file '{}': {}",
-                    temp_path.display(),
-                    error
-                ))
-            })?;
-            replace_file_with_fallback(&temp_path, path).await?;
+            atomic_write(path, &updated_png).await?;
 
             character.create_date = repaired_create_date;
             json_data = updated_json;
@@ -348,7 +338,7 @@ impl FileCharacterRepository {
         shallow: bool,
     ) -> Result<Character, DomainError> {
         let cached = {
-            let cache = self.memory_cache.lock().await;
+            let mut cache = self.memory_cache.lock().await;
             cache.get(file_name)
         };
 
@@ -3,8 +3,6 @@ use serde_json::Value;
 use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 
-use tokio::fs;
-
 use crate::domain::errors::DomainError;
 use crate::domain::models::character::Character;
 use crate::domain::models::chat::{
@@ -12,6 +10,7 @@ use crate::domain::models::chat::{
     normalize_chat_file_stem as normalize_domain_chat_file_stem, truncate_chat_file_stem_prefix,
 };
 use crate::domain::models::filename::sanitize_filename;
+use crate::infrastructure::persistence::file_system::atomic_write;
 use crate::infrastructure::persistence::png_utils::{
     read_character_data_from_png, write_character_data_to_png,
 };
@@ -470,13 +469,7 @@ impl FileCharacterRepository {
         let image_data = write_character_data_to_png(base_image_data, card_json)?;
         let target_path = self.get_character_path(file_stem);
 
-        fs::write(&target_path, image_data).await.map_err(|e| {
-            DomainError::InternalError(format!(
-                "Failed to write imported character file {}: {}",
-                target_path.display(),
-                e
-            ))
-        })?;
+        atomic_write(&target_path, &image_data).await?;
 
         Ok(target_path)
     }
@@ -1,4 +1,4 @@
-mod cache;
+mod animated_avatar;
 mod helpers;
 mod importer;
 mod repository;
@@ -8,11 +8,12 @@ mod tests;
 
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Duration;
 
 use tokio::sync::Mutex;
 
-use self::cache::MemoryCache;
+use crate::domain::models::character::Character;
+use crate::domain::models::memory_cache::MemoryCacheConfig;
+use crate::infrastructure::persistence::memory_cache::MemoryCache;
 use crate::infrastructure::repositories::chat_directory_identity::{
     SharedChatAliasStore, chat_alias_path_for_user_dir, new_shared_chat_alias_store,
 };
@@ -23,7 +24,7 @@ pub struct FileCharacterRepository {
     chats_dir: PathBuf,
     thumbnails_avatar_dir: PathBuf,
     default_avatar_path: PathBuf,
-    memory_cache: Arc<Mutex<MemoryCache>>,
+    memory_cache: Arc<Mutex<MemoryCache<Character>>>,
     chat_aliases: SharedChatAliasStore,
 }
 
@@ -66,10 +67,7 @@ impl FileCharacterRepository {
         default_avatar_path: PathBuf,
         chat_aliases: SharedChatAliasStore,
     ) -> Self {
-        let memory_cache = Arc::new(Mutex::new(MemoryCache::new(
-            100,
-            Duration::from_secs(30 * 60),
-        )));
+        let memory_cache = Arc::new(Mutex::new(MemoryCache::new(MemoryCacheConfig::default())));
 
         Self {
             characters_dir,
@@ -9,17 +9,23 @@ use crate::domain::errors::DomainError;
 use crate::domain::json_merge::merge_json_value;
 use crate::domain::models::character::Character;
 use crate::domain::models::chat::parse_message_timestamp_value;
+use crate::domain::models::memory_cache::{MemoryCacheConfig, MemoryCacheStats};
 use crate::domain::repositories::character_repository::{
     CHARACTER_CREATE_WARNING_AVATAR_IMPORT_FAILED, CharacterChat, CharacterCreateResult,
     CharacterCreateWarning, CharacterRepository, ImageCrop,
 };
 use crate::infrastructure::logging::logger;
+use crate::infrastructure::persistence::file_system::{
+    atomic_write, move_file_no_replace_with_fallback,
+};
+use crate::infrastructure::persistence::internal_writes::record_internal_write;
 use crate::infrastructure::persistence::png_utils::{
     process_avatar_image, read_character_data_from_png, write_character_data_to_png,
 };
 use crate::infrastructure::persistence::thumbnail_cache::invalidate_thumbnail_cache;
 
 use super::FileCharacterRepository;
+use super::animated_avatar::{AnimatedAvatarFormat, set_animated_avatar_field};
 
 struct CreateAvatarCarrier {
     image_data: Vec<u8>,
@@ -337,10 +343,7 @@ impl CharacterRepository for FileCharacterRepository {
 
         let new_image_data = write_character_data_to_png(&image_data, &json_data)?;
 
-        fs::write(&file_path, new_image_data).await.map_err(|e| {
-            logger::error(&format!("Failed to write character file: {}", e));
-            DomainError::InternalError(format!("Failed to write character file: {}", e))
-        })?;
+        atomic_write(&file_path, &new_image_data).await?;
 
         let cached_character =
             Self::with_storage_identity_and_json(character, &file_name, Some(json_data));
@@ -353,7 +356,7 @@ impl CharacterRepository for FileCharacterRepository {
 
     async fn find_by_name(&self, name: &str) -> Result<Character, DomainError> {
         let cached = {
-            let cache = self.memory_cache.lock().await;
+            let mut cache = self.memory_cache.lock().await;
             cache.get(name)
         };
 
@@ -396,14 +399,17 @@ impl CharacterRepository for FileCharacterRepository {
             )));
         }
 
+        record_internal_write(&file_path);
         fs::remove_file(&file_path).await.map_err(|e| {
             logger::error(&format!("Failed to delete character file: {}", e));
             DomainError::InternalError(format!("Failed to delete character file: {}", e))
         })?;
+        self.remove_animated_avatar(name).await?;
 
         if delete_chats {
             let chat_dir = self.resolve_chat_directory(name).await?;
             if chat_dir.exists() {
+                record_internal_write(&chat_dir);
                 fs::remove_dir_all(&chat_dir).await.map_err(|e| {
                     logger::error(&format!("Failed to delete chat directory: {}", e));
                     DomainError::InternalError(format!("Failed to delete chat directory: {}", e))
@@ -448,26 +454,29 @@ impl CharacterRepository for FileCharacterRepository {
         }
 
         let replaced_avatar = avatar_path.is_some();
-        let image_data = if let Some(avatar_path) = avatar_path {
+        let (image_data, card_json) = if let Some(avatar_path) = avatar_path {
             let file_data = fs::read(avatar_path).await.map_err(|e| {
                 logger::error(&format!("Failed to read avatar file: {}", e));
                 DomainError::InternalError(format!("Failed to read avatar file: {}", e))
             })?;
 
-            process_avatar_image(file_data, crop).await?
+            self.prepare_replacement_avatar(name, file_data, crop, character_card_json)
+                .await?
         } else {
-            fs::read(&file_path).await.map_err(|e| {
+            let image_data = fs::read(&file_path).await.map_err(|e| {
                 logger::error(&format!("Failed to read character file: {}", e));
                 DomainError::InternalError(format!("Failed to read character file: {}", e))
-            })?
+            })?;
+            let sidecar = self
+                .find_animated_avatar(name)
+                .and_then(|(path, _)| path.file_name().map(|n| n.to_string_lossy().to_string()));
+            let card_json = set_animated_avatar_field(character_card_json, sidecar.as_deref())?;
+            (image_data, card_json)
         };
 
-        let new_image_data = write_character_data_to_png(&image_data, character_card_json)?;
+        let new_image_data = write_character_data_to_png(&image_data, &card_json)?;
 
-        fs::write(&file_path, new_image_data).await.map_err(|e| {
-            logger::error(&format!("Failed to write character file: {}", e));
-            DomainError::InternalError(format!("Failed to write character file: {}", e))
-        })?;
+        atomic_write(&file_path, &new_image_data).await?;
 
         if replaced_avatar {
             self.invalidate_avatar_thumbnail(name).await?;
@@ -532,18 +541,24 @@ impl CharacterRepository for FileCharacterRepository {
             logger::error(&format!("Failed to serialize character data: {}", e));
             DomainError::InvalidData(format!("Failed to serialize character data: {}", e))
         })?;
+        let patched_json = match self
+            .transfer_animated_avatar(old_name, &target_file_stem, false)
+            .await?
+        {
+            Some(sidecar) => set_animated_avatar_field(&patched_json, Some(&sidecar))?,
+            None => patched_json,
+        };
 
         let new_image_data = write_character_data_to_png(&old_image_data, &patched_json)?;
 
-        fs::write(&new_path, new_image_data).await.map_err(|e| {
-            logger::error(&format!("Failed to write character file: {}", e));
-            DomainError::InternalError(format!("Failed to write character file: {}", e))
-        })?;
+        atomic_write(&new_path, &new_image_data).await?;
 
         let old_chat_dir = self.resolve_chat_directory(old_name).await?;
         let new_chat_dir = self.get_chat_directory(&target_file_stem);
 
         if old_chat_dir.exists() && old_chat_dir != new_chat_dir && !new_chat_dir.exists() {
+            record_internal_write(&old_chat_dir);
+            record_internal_write(&new_chat_dir);
             fs::rename(&old_chat_dir, &new_chat_dir)
                 .await
                 .map_err(|e| {
@@ -553,6 +568,7 @@ impl CharacterRepository for FileCharacterRepository {
         }
 
         if old_path != new_path {
+            record_internal_write(&old_path);
             fs::remove_file(&old_path).await.map_err(|e| {
                 logger::error(&format!("Failed to delete old character file: {}", e));
                 DomainError::InternalError(format!("Failed to delete old character file: {}", e))
@@ -587,11 +603,30 @@ impl CharacterRepository for FileCharacterRepository {
         let target_file_stem = self.next_duplicate_file_stem(&source_file_stem)?;
         let target_path = self.get_character_path(&target_file_stem);
 
+        record_internal_write(&target_path);
         fs::copy(&source_path, &target_path).await.map_err(|e| {
             logger::error(&format!("Failed to duplicate character file: {}", e));
             DomainError::InternalError(format!("Failed to duplicate character file: {}", e))
         })?;
 
+        if let Some(sidecar) = self
+            .transfer_animated_avatar(&source_file_stem, &target_file_stem, true)
+            .await?
+        {
+            let image_data = fs::read(&target_path).await.map_err(|e| {
+                DomainError::InternalError(format!("Failed to read character file: {}", e))
+            })?;
+            let card_json = set_animated_avatar_field(
+                &read_character_data_from_png(&image_data)?,
+                Some(&sidecar),
+            )?;
+            atomic_write(
+                &target_path,
+                &write_character_data_to_png(&image_data, &card_json)?,
+            )
+            .await?;
+        }
+
         let character = self.read_character_from_file(&target_path).await?;
         let mut cache = self.memory_cache.lock().await;
         cache.set(target_file_stem, character.clone());
@@ -718,10 +753,15 @@ impl CharacterRepository for FileCharacterRepository {
             )));
         }
 
-        let image_data = fs::read(&file_path).await.map_err(|e| {
+        // APNG sidecars are valid PNG carriers, so exports keep the animation.
+        let carrier_path = match self.find_animated_avatar(name) {
+            Some((path, AnimatedAvatarFormat::Apng)) => path,
+            _ => file_path,
+        };
+        let image_data = fs::read(&carrier_path).await.map_err(|e| {
             logger::error(&format!(
                 "Failed to read character file for export {}: {}",
-                file_path.display(),
+                carrier_path.display(),
                 e
             ));
             DomainError::InternalError(format!("Failed to read character file: {}", e))
@@ -761,10 +801,7 @@ impl CharacterRepository for FileCharacterRepository {
         let file_name = self.ensure_unique_file_stem(&base);
         let file_path = self.get_character_path(&file_name);
 
-        fs::write(&file_path, new_image_data).await.map_err(|e| {
-            logger::error(&format!("Failed to write character file: {}", e));
-            DomainError::InternalError(format!("Failed to write character file: {}", e))
-        })?;
+        atomic_write(&file_path, &new_image_data).await?;
 
         let stored_character =
             Self::with_storage_identity_and_json(character, &file_name, Some(json_data));
@@ -808,13 +845,12 @@ impl CharacterRepository for FileCharacterRepository {
             logger::error(&format!("Failed to read avatar file: {}", e));
             DomainError::InternalError(format!("Failed to read avatar file: {}", e))
         })?;
-        let image_data = process_avatar_image(file_data, crop).await?;
+        let (image_data, json_data) = self
+            .prepare_replacement_avatar(&file_name, file_data, crop, &json_data)
+            .await?;
         let new_image_data = write_character_data_to_png(&image_data, &json_data)?;
 
-        fs::write(&file_path, new_image_data).await.map_err(|e| {
-            logger::error(&format!("Failed to write character file: {}", e));
-            DomainError::InternalError(format!("Failed to write character file: {}", e))
-        })?;
+        atomic_write(&file_path, &new_image_data).await?;
 
         self.invalidate_avatar_thumbnail(&file_name).await?;
 
@@ -935,9 +971,85 @@ impl CharacterRepository for FileCharacterRepository {
         Ok(chats)
     }
 
+    async fn get_character_file_size(&self, name: &str) -> Result<u64, DomainError> {
+        let file_path = self.get_character_path(name);
+        match fs::metadata(&file_path).await {
+            Ok(metadata) => Ok(metadata.len()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Err(
+                DomainError::NotFound(format!("Character not found: {}", name)),
+            ),
+            Err(error) => Err(DomainError::InternalError(format!(
+                "Failed to read character file metadata: {}",
+                error
+            ))),
+        }
+    }
+
+    async fn move_chats(&self, source: &str, target: &str) -> Result<Vec<String>, DomainError> {
+        let source_dir = self.resolve_chat_directory(source).await?;
+        let target_dir = self.resolve_chat_directory(target).await?;
+        if !source_dir.exists() || source_dir == target_dir {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = fs::read_dir(&source_dir).await.map_err(|e| {
+            DomainError::InternalError(format!("Failed to read chat directory: {}", e))
+        })?;
+        let mut chat_paths = Vec::new();
+        while let Some(entry) = entries.next_entry().await.map_err(|e| {
+            DomainError::InternalError(format!("Failed to read directory entry: {}", e))
+        })? {
+            let path = entry.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            // Compressed chats (`.jsonl.zst`) move along with plain ones.
+            let stem = name
+                .strip_suffix(".jsonl")
+                .or_else(|| name.strip_suffix(".jsonl.zst"));
+            if let Some(stem) = stem {
+                let compressed = name.ends_with(".zst");
+                chat_paths.push((path.clone(), stem.to_string(), compressed));
+            }
+        }
+        chat_paths.sort();
+
+        let mut moved = Vec::new();
+        for (source_path, stem, compressed) in chat_paths {
+            let is_taken = |file_name: &str| {
+                target_dir.join(file_name).exists()
+                    || target_dir.join(format!("{}.zst", file_name)).exists()
+            };
+            let mut file_name = format!("{}.jsonl", stem);
+            let mut suffix = 2;
+            while is_taken(&file_name) {
+                file_name = format!("{} ({}).jsonl", stem, suffix);
+                suffix += 1;
+            }
+
+            let target_name = if compressed {
+                format!("{}.zst", file_name)
+            } else {
+                file_name.clone()
+            };
+            move_file_no_replace_with_fallback(&source_path, &target_dir.join(target_name)).await?;
+            moved.push(file_name);
+        }
+
+        Ok(moved)
+    }
+
     async fn clear_cache(&self) -> Result<(), DomainError> {
         let mut cache = self.memory_cache.lock().await;
         cache.clear();
         Ok(())
     }
+
+    async fn configure_memory_cache(&self, config: MemoryCacheConfig) {
+        self.memory_cache.lock().await.configure(config);
+    }
+
+    async fn memory_cache_stats(&self) -> MemoryCacheStats {
+        self.memory_cache.lock().await.stats("characters")
+    }
 }
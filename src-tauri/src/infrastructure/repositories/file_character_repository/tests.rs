@@ -3,7 +3,8 @@ use crc32fast::Hasher;
 use std::io::Cursor;
 use std::path::PathBuf;
 
-use image::{DynamicImage, ImageFormat, Rgba, RgbaImage};
+use image::codecs::gif::GifEncoder;
+use image::{Delay, DynamicImage, Frame, ImageFormat, Rgba, RgbaImage};
 use rand::random;
 use serde_json::json;
 use tokio::fs;
@@ -49,6 +50,25 @@ fn build_distinct_png() -> Vec<u8> {
     output
 }
 
+fn build_animated_gif() -> Vec<u8> {
+    let mut output = Vec::new();
+    {
+        let mut encoder = GifEncoder::new(Cursor::new(&mut output));
+        for shade in [0u8, 255] {
+            let frame = Frame::from_parts(
+                RgbaImage::from_pixel(2, 2, Rgba([shade, 0, 0, 255])),
+                0,
+                0,
+                Delay::from_numer_denom_ms(100, 1),
+            );
+            encoder
+                .encode_frame(frame)
+                .expect("should encode gif frame");
+        }
+    }
+    output
+}
+
 fn build_text_chunk(keyword: &str, text: &str) -> Vec<u8> {
     let mut data = Vec::with_capacity(keyword.len() + 1 + text.len());
     data.extend_from_slice(keyword.as_bytes());
@@ -1703,6 +1723,81 @@ async fn update_avatar_keeps_invalid_avatar_bytes_as_failure() {
     let _ = fs::remove_dir_all(&root).await;
 }
 
+#[tokio::test]
+async fn update_avatar_keeps_animated_sidecar_through_rename_and_delete() {
+    let (repository, root) = setup_repository().await;
+
+    let character = Character::new(
+        "Animated".to_string(),
+        "desc".to_string(),
+        "personality".to_string(),
+        "hello".to_string(),
+    );
+    let created = repository
+        .create_with_avatar(&character, None, None)
+        .await
+        .expect("create character")
+        .character;
+
+    let gif_path = root.join("animated.gif");
+    let gif_bytes = build_animated_gif();
+    fs::write(&gif_path, &gif_bytes)
+        .await
+        .expect("write animated avatar");
+
+    repository
+        .update_avatar(&created, &gif_path, None)
+        .await
+        .expect("update avatar");
+
+    let sidecar_path = root.join("characters/_animated/Animated.gif");
+    assert_eq!(
+        fs::read(&sidecar_path).await.expect("read sidecar"),
+        gif_bytes
+    );
+
+    let card_bytes = fs::read(root.join("characters/Animated.png"))
+        .await
+        .expect("read card");
+    image::load_from_memory_with_format(&card_bytes, ImageFormat::Png)
+        .expect("card should stay a static png");
+    let card: serde_json::Value =
+        serde_json::from_str(&read_character_data_from_png(&card_bytes).expect("read card json"))
+            .expect("parse card json");
+    assert_eq!(
+        card.pointer("/data/extensions/tauritavern/animatedAvatar"),
+        Some(&json!("Animated.gif"))
+    );
+
+    let renamed = repository
+        .rename("Animated", "Moving")
+        .await
+        .expect("rename character");
+    let renamed_sidecar = root.join("characters/_animated/Moving.gif");
+    assert!(!sidecar_path.exists());
+    assert!(renamed_sidecar.exists());
+
+    let renamed_bytes = fs::read(root.join("characters").join(&renamed.avatar))
+        .await
+        .expect("read renamed card");
+    let renamed_card: serde_json::Value = serde_json::from_str(
+        &read_character_data_from_png(&renamed_bytes).expect("read renamed card json"),
+    )
+    .expect("parse renamed card json");
+    assert_eq!(
+        renamed_card.pointer("/data/extensions/tauritavern/animatedAvatar"),
+        Some(&json!("Moving.gif"))
+    );
+
+    repository
+        .delete("Moving", false)
+        .await
+        .expect("delete character");
+    assert!(!renamed_sidecar.exists());
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
 #[tokio::test]
 async fn rename_allocates_new_file_stem_even_when_base_matches_current() {
     let (repository, root) = setup_repository().await;
@@ -0,0 +1,200 @@
+//! Time-based archival of character chats.
+//!
+//! An archived chat lives in the `archive/` subdirectory of its character's chat
+//! directory, in whatever form (plain or `.jsonl.zst`) it had. Listings only read the
+//! chat directory itself, so archived chats drop out of them; `list_archived_chats` and
+//! searches with `include_archived` read the archive on demand.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::chat_repository::{ChatArchiveReport, ChatSearchResult};
+use crate::infrastructure::logging::logger;
+use crate::infrastructure::persistence::file_system::move_file_no_replace_with_fallback;
+
+use super::FileChatRepository;
+use super::compression::{existing_payload_path, list_chat_payload_files, payload_exists};
+use super::summary::ChatFileDescriptor;
+
+const ARCHIVE_DIR_NAME: &str = "archive";
+
+fn archive_dir(chat_dir: &Path) -> PathBuf {
+    chat_dir.join(ARCHIVE_DIR_NAME)
+}
+
+/// `dir/<name>` keeping the file name of `path`, so compressed chats stay compressed.
+fn moved_path(path: &Path, dir: &Path) -> PathBuf {
+    dir.join(path.file_name().unwrap_or_default())
+}
+
+impl FileChatRepository {
+    pub(super) async fn list_archived_character_chat_files(
+        &self,
+        character_filter: Option<&str>,
+    ) -> Result<Vec<ChatFileDescriptor>, DomainError> {
+        self.ensure_directory_exists().await?;
+
+        let character_names = match character_filter {
+            Some(character_name) => vec![character_name.to_string()],
+            None => self.list_character_chat_directory_keys().await?,
+        };
+
+        let mut descriptors = Vec::new();
+        for character_name in character_names {
+            let dir = archive_dir(&self.resolve_character_chat_dir(&character_name).await?);
+            let files = list_chat_payload_files(&dir).await?;
+            descriptors.extend(
+                files
+                    .into_iter()
+                    .map(|(file_name, path)| ChatFileDescriptor {
+                        character_name: character_name.clone(),
+                        file_name,
+                        path,
+                    }),
+            );
+        }
+        Ok(descriptors)
+    }
+
+    pub(super) async fn list_archived_chats_internal(
+        &self,
+        character_filter: Option<&str>,
+    ) -> Result<Vec<ChatSearchResult>, DomainError> {
+        let descriptors = self
+            .list_archived_character_chat_files(character_filter)
+            .await?;
+        let mut results = Vec::with_capacity(descriptors.len());
+        for descriptor in descriptors {
+            results.push(self.get_chat_summary(&descriptor, false).await?);
+        }
+        results.sort_by(|a, b| b.date.cmp(&a.date));
+        self.flush_summary_index_if_needed().await?;
+        Ok(results)
+    }
+
+    /// Moves character chats not modified for `older_than_days` into the archive. The most
+    /// recently modified chat of each character is always kept, so opening the character
+    /// still finds its current chat.
+    pub(super) async fn archive_old_chats_internal(
+        &self,
+        character_filter: Option<&str>,
+        older_than_days: u32,
+    ) -> Result<ChatArchiveReport, DomainError> {
+        let cutoff = SystemTime::now()
+            .checked_sub(Duration::from_secs(u64::from(older_than_days) * 24 * 3600))
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+
+        let mut by_character: HashMap<String, Vec<(ChatFileDescriptor, SystemTime)>> =
+            HashMap::new();
+        for descriptor in self.list_character_chat_files(character_filter).await? {
+            // Loose files in the chats root belong to no character directory.
+            if descriptor.character_name.is_empty() {
+                continue;
+            }
+            let modified = fs::metadata(&descriptor.path)
+                .await
+                .and_then(|metadata| metadata.modified())
+                .unwrap_or_else(|_| SystemTime::now());
+            by_character
+                .entry(descriptor.character_name.clone())
+                .or_default()
+                .push((descriptor, modified));
+        }
+
+        let mut report = ChatArchiveReport::default();
+        for (_, mut chats) in by_character {
+            chats.sort_by(|a, b| b.1.cmp(&a.1));
+            report.scanned += chats.len();
+
+            for (descriptor, modified) in chats.into_iter().skip(1) {
+                if modified > cutoff {
+                    continue;
+                }
+                if self.archive_chat_file(&descriptor).await? {
+                    report.archived += 1;
+                } else {
+                    report.skipped += 1;
+                }
+            }
+        }
+
+        self.flush_summary_index_if_needed().await?;
+        logger::info(&format!(
+            "Archived {} of {} chats older than {} days",
+            report.archived, report.scanned, older_than_days
+        ));
+        Ok(report)
+    }
+
+    /// Returns `false` when an archived chat with the same name is in the way.
+    async fn archive_chat_file(
+        &self,
+        descriptor: &ChatFileDescriptor,
+    ) -> Result<bool, DomainError> {
+        let Some(chat_dir) = descriptor.path.parent() else {
+            return Ok(false);
+        };
+        let plain_path = chat_dir.join(&descriptor.file_name);
+        let archive = archive_dir(chat_dir);
+        if payload_exists(&archive.join(&descriptor.file_name)) {
+            logger::warn(&format!(
+                "Not archiving chat {:?}: an archived chat with the same name exists",
+                descriptor.path
+            ));
+            return Ok(false);
+        }
+
+        {
+            let _payload_guard = self.acquire_payload_write_lock(&plain_path).await;
+            let Some(source) = existing_payload_path(&plain_path) else {
+                return Ok(false);
+            };
+            move_file_no_replace_with_fallback(&source, &moved_path(&source, &archive)).await?;
+            self.remove_summary_cache_for_path(&source).await;
+        }
+        self.forget_cached_chat(&descriptor.character_name, &descriptor.file_name)
+            .await;
+        Ok(true)
+    }
+
+    pub(super) async fn unarchive_chat_internal(
+        &self,
+        character_name: &str,
+        file_name: &str,
+    ) -> Result<(), DomainError> {
+        let normalized = Self::normalize_jsonl_file_name(file_name)?;
+        let chat_dir = self.resolve_character_chat_dir(character_name).await?;
+        let plain_path = chat_dir.join(&normalized);
+        let Some(source) = existing_payload_path(&archive_dir(&chat_dir).join(&normalized)) else {
+            return Err(DomainError::NotFound(format!(
+                "Archived chat not found: {}/{}",
+                character_name, normalized
+            )));
+        };
+
+        {
+            let _payload_guard = self.acquire_payload_write_lock(&plain_path).await;
+            if payload_exists(&plain_path) {
+                return Err(DomainError::InvalidData(format!(
+                    "A chat named {} already exists for {}",
+                    normalized, character_name
+                )));
+            }
+            move_file_no_replace_with_fallback(&source, &moved_path(&source, &chat_dir)).await?;
+            self.remove_summary_cache_for_path(&source).await;
+        }
+        self.forget_cached_chat(character_name, &normalized).await;
+        self.flush_summary_index_if_needed().await
+    }
+
+    async fn forget_cached_chat(&self, character_name: &str, file_name: &str) {
+        let Ok(cache_key) = self.get_cache_key(character_name, file_name) else {
+            return;
+        };
+        self.memory_cache.lock().await.remove(&cache_key);
+    }
+}
@@ -27,6 +27,8 @@ impl FileChatRepository {
         Ok(self.get_character_dir_for_key(&dir_key))
     }
 
+    /// Path of the plain JSONL payload. A compressed chat is inflated and pending
+    /// `add_message` journal entries are replayed on the way.
     pub(super) async fn resolve_character_chat_path(
         &self,
         character_name: &str,
@@ -34,7 +36,10 @@ impl FileChatRepository {
     ) -> Result<PathBuf, DomainError> {
         let normalized = Self::normalize_jsonl_file_name(file_name)?;
         let dir = self.resolve_character_chat_dir(character_name).await?;
-        Ok(dir.join(normalized))
+        let path = dir.join(normalized);
+        self.inflate_chat_payload(&path).await?;
+        self.replay_chat_journal(&path).await?;
+        Ok(path)
     }
 
     pub(super) fn get_chat_path_for_dir_key(
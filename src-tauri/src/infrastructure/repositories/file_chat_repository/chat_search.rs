@@ -0,0 +1,330 @@
+use std::path::Path;
+
+use regress::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::chat::parse_message_timestamp_value;
+use crate::domain::repositories::chat_repository::{
+    ChatMessageRole, ChatSearchHighlight, ChatSearchMatchResult, ChatSearchMessageMatch,
+    ChatSearchOptions,
+};
+
+use super::FileChatRepository;
+use super::compression::open_payload_reader;
+use super::summary::ChatFileDescriptor;
+
+const DEFAULT_MATCHES_PER_CHAT: usize = 20;
+const MAX_MATCHES_PER_CHAT: usize = 200;
+const MAX_HIGHLIGHTS_PER_MESSAGE: usize = 32;
+const SNIPPET_MAX_CHARS: usize = 200;
+const SNIPPET_CONTEXT_BEFORE: usize = 40;
+const ELLIPSIS: &str = "...";
+
+#[derive(Debug, Deserialize)]
+struct SearchableChatMessage {
+    #[serde(default)]
+    is_user: bool,
+    #[serde(default)]
+    is_system: bool,
+    #[serde(default)]
+    send_date: Option<Value>,
+    #[serde(default)]
+    mes: String,
+}
+
+impl SearchableChatMessage {
+    fn role(&self) -> ChatMessageRole {
+        if self.is_user {
+            ChatMessageRole::User
+        } else if self.is_system {
+            ChatMessageRole::System
+        } else {
+            ChatMessageRole::Assistant
+        }
+    }
+}
+
+/// Compiled form of the search query.
+enum ChatSearchMatcher {
+    /// Lowercased fragments that must all appear in a message.
+    Fragments(Vec<String>),
+    Regex(Regex),
+}
+
+impl ChatSearchMatcher {
+    fn new(options: &ChatSearchOptions) -> Result<Self, DomainError> {
+        let query = options.query.trim();
+        if query.is_empty() {
+            return Err(DomainError::InvalidData(
+                "query must not be empty".to_string(),
+            ));
+        }
+
+        if options.regex {
+            return Regex::with_flags(query, "i")
+                .map(Self::Regex)
+                .map_err(|error| {
+                    DomainError::InvalidData(format!("Invalid search regex: {}", error))
+                });
+        }
+
+        Ok(Self::Fragments(
+            query.split_whitespace().map(lowercase_chars).collect(),
+        ))
+    }
+
+    /// Cheap check on the raw JSON line before it is parsed.
+    fn line_might_match(&self, line: &str) -> bool {
+        match self {
+            Self::Fragments(fragments) => {
+                let lowered = line.to_lowercase();
+                fragments
+                    .iter()
+                    .all(|fragment| lowered.contains(fragment.as_str()))
+            }
+            Self::Regex(_) => true,
+        }
+    }
+
+    /// Character ranges of every match in `text`, or an empty list when the
+    /// message does not match.
+    fn find(&self, text: &str) -> Vec<ChatSearchHighlight> {
+        let mut ranges = Vec::new();
+        match self {
+            Self::Fragments(fragments) => {
+                let lowered = lowercase_chars(text);
+                for fragment in fragments {
+                    let before = ranges.len();
+                    for (byte, _) in lowered.match_indices(fragment.as_str()) {
+                        ranges.push(char_range(&lowered, byte, byte + fragment.len()));
+                    }
+                    if ranges.len() == before {
+                        return Vec::new();
+                    }
+                }
+            }
+            Self::Regex(regex) => {
+                for mat in regex.find_iter(text) {
+                    if mat.start() == mat.end() {
+                        continue;
+                    }
+                    ranges.push(char_range(text, mat.start(), mat.end()));
+                }
+            }
+        }
+
+        ranges.sort_by_key(|range| (range.start, range.end));
+        ranges.dedup();
+        ranges
+    }
+}
+
+/// Lowercases one character at a time so character offsets stay aligned with the
+/// original text.
+fn lowercase_chars(value: &str) -> String {
+    value
+        .chars()
+        .map(|ch| ch.to_lowercase().next().unwrap_or(ch))
+        .collect()
+}
+
+fn char_range(text: &str, start_byte: usize, end_byte: usize) -> ChatSearchHighlight {
+    let start = text[..start_byte].chars().count();
+    ChatSearchHighlight {
+        start,
+        end: start + text[start_byte..end_byte].chars().count(),
+    }
+}
+
+/// Cuts a snippet around the first highlight and rebases highlights onto it.
+fn snippet_with_highlights(
+    text: &str,
+    highlights: &[ChatSearchHighlight],
+) -> (String, Vec<ChatSearchHighlight>) {
+    let total_chars = text.chars().count();
+    let first = highlights.first().map(|range| range.start).unwrap_or(0);
+    let start = if total_chars <= SNIPPET_MAX_CHARS {
+        0
+    } else {
+        first
+            .saturating_sub(SNIPPET_CONTEXT_BEFORE)
+            .min(total_chars - SNIPPET_MAX_CHARS)
+    };
+    let end = (start + SNIPPET_MAX_CHARS).min(total_chars);
+
+    let mut snippet = String::new();
+    let mut offset = 0;
+    if start > 0 {
+        snippet.push_str(ELLIPSIS);
+        offset = ELLIPSIS.len();
+    }
+    snippet.extend(text.chars().skip(start).take(end - start));
+    if end < total_chars {
+        snippet.push_str(ELLIPSIS);
+    }
+
+    let rebased = highlights
+        .iter()
+        .filter(|range| range.end > start && range.start < end)
+        .take(MAX_HIGHLIGHTS_PER_MESSAGE)
+        .map(|range| ChatSearchHighlight {
+            start: range.start.max(start) - start + offset,
+            end: range.end.min(end) - start + offset,
+        })
+        .collect();
+
+    (snippet, rebased)
+}
+
+fn date_in_range(options: &ChatSearchOptions, send_date: i64) -> bool {
+    if options.date_from.is_none() && options.date_to.is_none() {
+        return true;
+    }
+    if send_date <= 0 {
+        return false;
+    }
+    options.date_from.is_none_or(|from| send_date >= from)
+        && options.date_to.is_none_or(|to| send_date <= to)
+}
+
+impl FileChatRepository {
+    pub(super) async fn search_chats_with_options_internal(
+        &self,
+        options: &ChatSearchOptions,
+        character_filter: Option<&str>,
+    ) -> Result<Vec<ChatSearchMatchResult>, DomainError> {
+        let matcher = ChatSearchMatcher::new(options)?;
+        if let (Some(from), Some(to)) = (options.date_from, options.date_to) {
+            if from > to {
+                return Ok(Vec::new());
+            }
+        }
+        let min_match_count = options.min_match_count.unwrap_or(1).max(1);
+        let max_matches = options
+            .max_matches_per_chat
+            .unwrap_or(DEFAULT_MATCHES_PER_CHAT)
+            .min(MAX_MATCHES_PER_CHAT);
+
+        let mut descriptors: Vec<(ChatFileDescriptor, bool)> = self
+            .list_character_chat_files(character_filter)
+            .await?
+            .into_iter()
+            .map(|descriptor| (descriptor, false))
+            .collect();
+        if options.include_archived {
+            descriptors.extend(
+                self.list_archived_character_chat_files(character_filter)
+                    .await?
+                    .into_iter()
+                    .map(|descriptor| (descriptor, true)),
+            );
+        }
+        let mut results = Vec::new();
+
+        for (descriptor, archived) in descriptors {
+            let entry = self
+                .get_chat_summary_entry(
+                    &descriptor,
+                    matches!(matcher, ChatSearchMatcher::Fragments(_)),
+                )
+                .await?;
+            if let ChatSearchMatcher::Fragments(fragments) = &matcher {
+                let fingerprint = entry
+                    .fingerprint
+                    .as_ref()
+                    .expect("fingerprint is required for search");
+                if !fingerprint.might_match_fragments(fragments) {
+                    continue;
+                }
+            }
+
+            let (match_count, matches) =
+                scan_chat_file(&descriptor.path, &matcher, options, max_matches).await?;
+            if match_count < min_match_count {
+                continue;
+            }
+
+            let mut chat = entry.summary.clone();
+            chat.chat_metadata = None;
+            results.push(ChatSearchMatchResult {
+                chat,
+                match_count,
+                matches,
+                archived,
+            });
+        }
+
+        results.sort_by(|a, b| b.chat.date.cmp(&a.chat.date));
+        self.flush_summary_index_if_needed().await?;
+        Ok(results)
+    }
+}
+
+/// Streams one chat payload, returning the total number of matching messages and
+/// the first `max_matches` of them.
+async fn scan_chat_file(
+    path: &Path,
+    matcher: &ChatSearchMatcher,
+    options: &ChatSearchOptions,
+    max_matches: usize,
+) -> Result<(usize, Vec<ChatSearchMessageMatch>), DomainError> {
+    let reader = BufReader::new(open_payload_reader(path).await?);
+    let mut lines = reader.lines();
+    let mut seen_header = false;
+    let mut next_index = 0;
+    let mut match_count = 0;
+    let mut matches = Vec::new();
+
+    while let Some(line) = lines.next_line().await.map_err(|error| {
+        DomainError::InternalError(format!("Failed to read chat file {:?}: {}", path, error))
+    })? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        // The first line is the chat header, not a message.
+        if !seen_header {
+            seen_header = true;
+            continue;
+        }
+        let current = next_index;
+        next_index += 1;
+
+        if !matcher.line_might_match(&line) {
+            continue;
+        }
+        let Ok(message) = serde_json::from_str::<SearchableChatMessage>(&line) else {
+            continue;
+        };
+
+        let role = message.role();
+        if options.role.is_some_and(|filter| filter != role) {
+            continue;
+        }
+        let send_date = parse_message_timestamp_value(message.send_date.as_ref());
+        if !date_in_range(options, send_date) {
+            continue;
+        }
+
+        let highlights = matcher.find(&message.mes);
+        if highlights.is_empty() {
+            continue;
+        }
+
+        match_count += 1;
+        if matches.len() < max_matches {
+            let (snippet, highlights) = snippet_with_highlights(&message.mes, &highlights);
+            matches.push(ChatSearchMessageMatch {
+                index: current,
+                role,
+                send_date,
+                snippet,
+                highlights,
+            });
+        }
+    }
+
+    Ok((match_count, matches))
+}
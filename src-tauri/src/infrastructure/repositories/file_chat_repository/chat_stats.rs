@@ -0,0 +1,186 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use chrono::{Datelike, Local, TimeZone};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::chat::parse_message_timestamp_value;
+use crate::domain::repositories::chat_repository::CharacterChatStats;
+
+use super::FileChatRepository;
+use super::compression::open_payload_reader;
+
+/// Per-file message statistics, stored with the chat's summary index entry so
+/// they are only recomputed when the file changes.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(super) struct ChatFileStats {
+    user_messages: usize,
+    assistant_messages: usize,
+    user_words: u64,
+    assistant_words: u64,
+    first_message_date: Option<i64>,
+    last_message_date: Option<i64>,
+    /// Distinct local days with messages, as days since 0001-01-01.
+    days: Vec<i32>,
+}
+
+#[derive(Deserialize)]
+struct StatsChatMessage {
+    #[serde(default)]
+    is_user: bool,
+    #[serde(default)]
+    is_system: bool,
+    #[serde(default)]
+    send_date: Option<Value>,
+    #[serde(default)]
+    mes: String,
+}
+
+fn local_day(timestamp_millis: i64) -> Option<i32> {
+    Local
+        .timestamp_millis_opt(timestamp_millis)
+        .single()
+        .map(|datetime| datetime.date_naive().num_days_from_ce())
+}
+
+/// Longest run of consecutive days, and the run ending at `today` or the day before.
+fn streaks(days: &BTreeSet<i32>, today: i32) -> (usize, usize) {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous = None;
+    for &day in days {
+        run = if previous == Some(day - 1) {
+            run + 1
+        } else {
+            1
+        };
+        longest = longest.max(run);
+        previous = Some(day);
+    }
+
+    let current = match previous {
+        Some(last) if last >= today - 1 => run,
+        _ => 0,
+    };
+    (longest, current)
+}
+
+async fn scan_chat_stats(path: &Path) -> Result<ChatFileStats, DomainError> {
+    let reader = BufReader::new(open_payload_reader(path).await?);
+    let mut lines = reader.lines();
+    let mut stats = ChatFileStats::default();
+    let mut days = BTreeSet::new();
+    let mut seen_header = false;
+
+    while let Some(line) = lines.next_line().await.map_err(|error| {
+        DomainError::InternalError(format!("Failed to read chat file {:?}: {}", path, error))
+    })? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !seen_header {
+            seen_header = true;
+            continue;
+        }
+        let Ok(message) = serde_json::from_str::<StatsChatMessage>(&line) else {
+            continue;
+        };
+        if message.is_system && !message.is_user {
+            continue;
+        }
+
+        let words = message.mes.split_whitespace().count() as u64;
+        if message.is_user {
+            stats.user_messages += 1;
+            stats.user_words += words;
+        } else {
+            stats.assistant_messages += 1;
+            stats.assistant_words += words;
+        }
+
+        let timestamp = parse_message_timestamp_value(message.send_date.as_ref());
+        if timestamp > 0 {
+            stats.first_message_date = Some(
+                stats
+                    .first_message_date
+                    .map_or(timestamp, |first| first.min(timestamp)),
+            );
+            stats.last_message_date = Some(
+                stats
+                    .last_message_date
+                    .map_or(timestamp, |last| last.max(timestamp)),
+            );
+            days.extend(local_day(timestamp));
+        }
+    }
+
+    stats.days = days.into_iter().collect();
+    Ok(stats)
+}
+
+impl FileChatRepository {
+    pub(super) async fn get_character_chat_stats_internal(
+        &self,
+        character_name: &str,
+    ) -> Result<CharacterChatStats, DomainError> {
+        let descriptors = self.list_character_chat_files(Some(character_name)).await?;
+        let mut result = CharacterChatStats {
+            character_name: character_name.to_string(),
+            ..CharacterChatStats::default()
+        };
+        let mut days = BTreeSet::new();
+
+        for descriptor in descriptors {
+            let mut entry = self.get_chat_summary_entry(&descriptor, false).await?;
+            let stats = match entry.stats.take() {
+                Some(stats) => stats,
+                None => {
+                    let stats = scan_chat_stats(&descriptor.path).await?;
+                    self.summary_cache.lock().await.set_stats(
+                        &Self::summary_cache_key(&descriptor.path),
+                        entry.signature,
+                        stats.clone(),
+                    );
+                    stats
+                }
+            };
+
+            result.chat_count += 1;
+            result.total_messages += entry.summary.message_count;
+            result.user_messages += stats.user_messages;
+            result.assistant_messages += stats.assistant_messages;
+            result.user_words += stats.user_words;
+            result.assistant_words += stats.assistant_words;
+            result.first_message_date = match (result.first_message_date, stats.first_message_date)
+            {
+                (Some(current), Some(candidate)) => Some(current.min(candidate)),
+                (current, candidate) => current.or(candidate),
+            };
+            result.last_message_date = match (result.last_message_date, stats.last_message_date) {
+                (Some(current), Some(candidate)) => Some(current.max(candidate)),
+                (current, candidate) => current.or(candidate),
+            };
+            days.extend(stats.days);
+
+            let is_longer = result
+                .longest_chat
+                .as_ref()
+                .is_none_or(|longest| entry.summary.message_count > longest.message_count);
+            if is_longer {
+                let mut summary = entry.summary;
+                summary.chat_metadata = None;
+                result.longest_chat = Some(summary);
+            }
+        }
+
+        let today = Local::now().date_naive().num_days_from_ce();
+        (result.longest_streak_days, result.current_streak_days) = streaks(&days, today);
+        result.days_chatted = days.len();
+
+        self.flush_summary_index_if_needed().await?;
+        Ok(result)
+    }
+}
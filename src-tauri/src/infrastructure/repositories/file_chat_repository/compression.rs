@@ -0,0 +1,266 @@
+//! Optional zstd compression of chat payloads at rest.
+//!
+//! A compressed chat is stored as `<name>.jsonl.zst` in place of `<name>.jsonl`. Listings,
+//! summaries and search read compressed files directly. Everything that works on the plain
+//! payload (windowed reads and writes, renames, exports) resolves its path through
+//! `resolve_character_chat_path`/`resolve_group_chat_path`, which inflate the chat back to
+//! `.jsonl` first. Idle chats are packed again by `compress_chat_payloads`.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use async_compression::tokio::bufread::{ZstdDecoder, ZstdEncoder};
+use tokio::fs::{self, File};
+use tokio::io::{self, AsyncRead, AsyncWriteExt, BufReader};
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::chat_repository::{ChatCompressionOptions, ChatCompressionReport};
+use crate::infrastructure::logging::logger;
+use crate::infrastructure::persistence::file_system::{
+    commit_temp_file, delete_file, list_files_with_extension, unique_temp_path,
+};
+
+use super::FileChatRepository;
+
+const COMPRESSED_SUFFIX: &str = ".zst";
+const COMPRESSION_BUFFER_BYTES: usize = 64 * 1024;
+
+/// `<name>.jsonl` -> `<name>.jsonl.zst`
+pub(super) fn compressed_payload_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(COMPRESSED_SUFFIX);
+    path.with_file_name(name)
+}
+
+pub(super) fn is_compressed_payload(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(".jsonl.zst"))
+}
+
+/// Whether a chat exists at `path` in either form.
+pub(super) fn payload_exists(path: &Path) -> bool {
+    path.exists() || compressed_payload_path(path).exists()
+}
+
+/// The file currently holding the chat at `path`, preferring the plain form.
+pub(super) fn existing_payload_path(path: &Path) -> Option<PathBuf> {
+    if path.exists() {
+        return Some(path.to_path_buf());
+    }
+    let compressed = compressed_payload_path(path);
+    compressed.exists().then_some(compressed)
+}
+
+/// Chat files in `dir` as `(file_name, path)` pairs. `file_name` always ends in `.jsonl`;
+/// `path` points at the `.jsonl.zst` file for compressed chats. A plain file wins if both
+/// forms exist.
+pub(super) async fn list_chat_payload_files(
+    dir: &Path,
+) -> Result<Vec<(String, PathBuf)>, DomainError> {
+    let mut files = Vec::new();
+    for path in list_files_with_extension(dir, "jsonl").await? {
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        files.push((file_name.to_string(), path.clone()));
+    }
+
+    for path in list_files_with_extension(dir, "zst").await? {
+        let Some(file_name) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(COMPRESSED_SUFFIX))
+            .filter(|name| name.ends_with(".jsonl"))
+        else {
+            continue;
+        };
+        if files.iter().any(|(existing, _)| existing == file_name) {
+            continue;
+        }
+        files.push((file_name.to_string(), path.clone()));
+    }
+
+    Ok(files)
+}
+
+/// Open a chat payload for sequential reading, decompressing `.jsonl.zst` files.
+pub(super) async fn open_payload_reader(
+    path: &Path,
+) -> Result<Box<dyn AsyncRead + Send + Unpin>, DomainError> {
+    let file = File::open(path).await.map_err(|error| {
+        DomainError::InternalError(format!("Failed to open chat file {:?}: {}", path, error))
+    })?;
+
+    if is_compressed_payload(path) {
+        Ok(Box::new(ZstdDecoder::new(BufReader::with_capacity(
+            COMPRESSION_BUFFER_BYTES,
+            file,
+        ))))
+    } else {
+        Ok(Box::new(file))
+    }
+}
+
+/// Stream `source` through the zstd encoder (or decoder) into a temp file, move it over
+/// `target` and remove `source`. Returns the size of `target`. The modification time is carried over so recent-chat
+/// ordering does not change.
+async fn transcode_payload(
+    source: &Path,
+    target: &Path,
+    compress: bool,
+) -> Result<u64, DomainError> {
+    let modified = fs::metadata(source)
+        .await
+        .and_then(|metadata| metadata.modified())
+        .ok();
+    let input = BufReader::with_capacity(
+        COMPRESSION_BUFFER_BYTES,
+        File::open(source).await.map_err(|error| {
+            DomainError::InternalError(format!("Failed to open chat file {:?}: {}", source, error))
+        })?,
+    );
+    let mut reader: Box<dyn AsyncRead + Send + Unpin> = if compress {
+        Box::new(ZstdEncoder::new(input))
+    } else {
+        Box::new(ZstdDecoder::new(input))
+    };
+
+    let temp_path = unique_temp_path(target, "chat.jsonl");
+    let written = async {
+        let mut out = File::create(&temp_path).await?;
+        let written = io::copy(&mut reader, &mut out).await?;
+        out.flush().await?;
+        Ok::<_, std::io::Error>(written)
+    }
+    .await;
+    let written = match written {
+        Ok(written) => written,
+        Err(error) => {
+            let _ = fs::remove_file(&temp_path).await;
+            return Err(DomainError::InternalError(format!(
+                "Failed to {} chat file {:?}: {}",
+                if compress { "compress" } else { "decompress" },
+                source,
+                error
+            )));
+        }
+    };
+
+    if let Some(Err(error)) = modified.map(|modified| set_modified(&temp_path, modified)) {
+        logger::warn(&format!(
+            "Failed to keep modification time of chat file {:?}: {}",
+            source, error
+        ));
+    }
+
+    commit_temp_file(&temp_path, target).await?;
+    delete_file(source).await?;
+    Ok(written)
+}
+
+fn set_modified(path: &Path, modified: SystemTime) -> std::io::Result<()> {
+    std::fs::File::options()
+        .write(true)
+        .open(path)?
+        .set_modified(modified)
+}
+
+impl FileChatRepository {
+    /// Make sure the chat at `path` is stored as plain JSONL, decompressing its `.jsonl.zst`
+    /// form if that is all there is.
+    pub(super) async fn inflate_chat_payload(&self, path: &Path) -> Result<(), DomainError> {
+        let compressed = compressed_payload_path(path);
+        if path.exists() || !compressed.exists() {
+            return Ok(());
+        }
+
+        let _payload_guard = self.acquire_payload_write_lock(path).await;
+        if path.exists() || !compressed.exists() {
+            return Ok(());
+        }
+
+        transcode_payload(&compressed, path, false).await?;
+        self.remove_summary_cache_for_path(&compressed).await;
+        logger::debug(&format!("Inflated compressed chat {:?}", path));
+        Ok(())
+    }
+
+    /// Compress one plain chat. Returns the compressed size, or `None` when compression
+    /// did not make the file smaller and the plain file was kept.
+    async fn compress_chat_payload(
+        &self,
+        path: &Path,
+        plain_size: u64,
+    ) -> Result<Option<u64>, DomainError> {
+        let _payload_guard = self.acquire_payload_write_lock(path).await;
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let compressed = compressed_payload_path(path);
+        let compressed_size = transcode_payload(path, &compressed, true).await?;
+        self.remove_summary_cache_for_path(path).await;
+        if compressed_size >= plain_size {
+            // Not worth it (tiny or already dense payload); put the plain file back.
+            transcode_payload(&compressed, path, false).await?;
+            return Ok(None);
+        }
+
+        Ok(Some(compressed_size))
+    }
+
+    pub(super) async fn compress_chat_payloads_internal(
+        &self,
+        options: ChatCompressionOptions,
+    ) -> Result<ChatCompressionReport, DomainError> {
+        let mut descriptors = self.list_character_chat_files(None).await?;
+        descriptors.extend(self.list_group_chat_files(None).await?);
+
+        let idle_cutoff = SystemTime::now()
+            .checked_sub(Duration::from_secs(
+                options.min_idle_hours.saturating_mul(3600),
+            ))
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        let mut report = ChatCompressionReport::default();
+
+        for descriptor in descriptors {
+            if is_compressed_payload(&descriptor.path) {
+                continue;
+            }
+            report.scanned += 1;
+
+            let Ok(metadata) = fs::metadata(&descriptor.path).await else {
+                report.skipped += 1;
+                continue;
+            };
+            let is_idle = metadata
+                .modified()
+                .map(|modified| modified <= idle_cutoff)
+                .unwrap_or(false);
+            if metadata.len() < options.min_size_bytes || !is_idle {
+                report.skipped += 1;
+                continue;
+            }
+
+            match self
+                .compress_chat_payload(&descriptor.path, metadata.len())
+                .await?
+            {
+                Some(compressed_size) => {
+                    report.compressed += 1;
+                    report.bytes_before += metadata.len();
+                    report.bytes_after += compressed_size;
+                }
+                None => report.skipped += 1,
+            }
+        }
+
+        self.flush_summary_index_if_needed().await?;
+        logger::info(&format!(
+            "Compressed {} of {} chats ({} -> {} bytes)",
+            report.compressed, report.scanned, report.bytes_before, report.bytes_after
+        ));
+        Ok(report)
+    }
+}
@@ -6,7 +6,7 @@ use tokio::io::{self, AsyncSeekExt, AsyncWriteExt, SeekFrom};
 
 use crate::domain::errors::DomainError;
 use crate::infrastructure::logging::logger;
-use crate::infrastructure::persistence::file_system::replace_file_with_fallback;
+use crate::infrastructure::persistence::file_system::commit_temp_file;
 
 use super::FileChatRepository;
 use super::windowed_payload_io::{open_existing_payload_file, read_first_line_and_end_offset};
@@ -33,11 +33,7 @@ fn serialize_header_json(value: &Value) -> Result<String, DomainError> {
     })
 }
 
-fn apply_metadata_extension_update(
-    header_value: &mut Value,
-    namespace: &str,
-    value: Value,
-) -> Result<(), DomainError> {
+fn chat_metadata_map_mut(header_value: &mut Value) -> Result<&mut Map<String, Value>, DomainError> {
     let header_map = header_value
         .as_object_mut()
         .ok_or_else(|| DomainError::InvalidData("Chat header is not a JSON object".to_string()))?;
@@ -46,10 +42,16 @@ fn apply_metadata_extension_update(
         DomainError::InvalidData("Chat header is missing chat_metadata".to_string())
     })?;
 
-    let meta_map = meta_value.as_object_mut().ok_or_else(|| {
-        DomainError::InvalidData("chat_metadata is not a JSON object".to_string())
-    })?;
+    meta_value
+        .as_object_mut()
+        .ok_or_else(|| DomainError::InvalidData("chat_metadata is not a JSON object".to_string()))
+}
 
+fn apply_metadata_extension_update(
+    meta_map: &mut Map<String, Value>,
+    namespace: &str,
+    value: Value,
+) -> Result<(), DomainError> {
     let extensions_value = meta_map
         .entry("extensions".to_string())
         .or_insert_with(|| Value::Object(Map::new()));
@@ -86,12 +88,74 @@ impl FileChatRepository {
         path: &Path,
         namespace: &str,
         value: Value,
+    ) -> Result<(), DomainError> {
+        self.rewrite_chat_metadata_in_path(path, |meta_map| {
+            apply_metadata_extension_update(meta_map, namespace, value)
+        })
+        .await?;
+
+        logger::debug(&format!(
+            "Updated chat metadata extension for {:?}: {}",
+            path, namespace
+        ));
+
+        Ok(())
+    }
+
+    /// Reads `chat_metadata.variables`; chats without variables yield an empty map.
+    pub(super) async fn read_chat_variables_from_path(
+        &self,
+        path: &Path,
+    ) -> Result<Map<String, Value>, DomainError> {
+        let meta = self.read_chat_metadata_from_path(path).await?;
+        match meta.get("variables") {
+            None | Some(Value::Null) => Ok(Map::new()),
+            Some(variables) => ensure_object(variables.clone(), "chat_metadata.variables"),
+        }
+    }
+
+    pub(super) async fn set_chat_variables_in_path(
+        &self,
+        path: &Path,
+        variables: Map<String, Value>,
+    ) -> Result<(), DomainError> {
+        self.rewrite_chat_metadata_in_path(path, |meta_map| {
+            meta_map.insert("variables".to_string(), Value::Object(variables));
+            Ok(())
+        })
+        .await
+    }
+
+    /// Merges top-level `chat_metadata` fields; `null` values remove the field.
+    pub(super) async fn merge_chat_metadata_fields_in_path(
+        &self,
+        path: &Path,
+        fields: Map<String, Value>,
+    ) -> Result<(), DomainError> {
+        self.rewrite_chat_metadata_in_path(path, |meta_map| {
+            for (key, value) in fields {
+                if value.is_null() {
+                    meta_map.remove(&key);
+                } else {
+                    meta_map.insert(key, value);
+                }
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Rewrites the chat header in place, streaming the message body unchanged.
+    async fn rewrite_chat_metadata_in_path(
+        &self,
+        path: &Path,
+        update: impl FnOnce(&mut Map<String, Value>) -> Result<(), DomainError>,
     ) -> Result<(), DomainError> {
         let _write_guard = self.acquire_payload_write_lock(path).await;
 
         let (header, header_end_offset) = read_first_line_and_end_offset(path).await?;
         let mut header_value = parse_header_json(&header)?;
-        apply_metadata_extension_update(&mut header_value, namespace, value)?;
+        update(chat_metadata_map_mut(&mut header_value)?)?;
         let serialized = serialize_header_json(&header_value)?;
 
         let temp_path = Self::temp_payload_path(path);
@@ -133,14 +197,9 @@ impl FileChatRepository {
             DomainError::InternalError(format!("Failed to flush chat payload file: {}", error))
         })?;
 
-        replace_file_with_fallback(&temp_path, path).await?;
+        commit_temp_file(&temp_path, path).await?;
         self.remove_summary_cache_for_path(path).await;
 
-        logger::debug(&format!(
-            "Updated chat metadata extension for {:?}: {}",
-            path, namespace
-        ));
-
         Ok(())
     }
 }
@@ -5,8 +5,9 @@ use tokio::fs;
 
 use crate::domain::errors::DomainError;
 use crate::domain::json_merge::merge_json_value;
+use crate::infrastructure::persistence::file_locks::lock_file;
 use crate::infrastructure::persistence::file_system::{
-    move_file_no_replace_with_fallback, replace_file_with_fallback, unique_temp_path,
+    atomic_write, move_file_no_replace_with_fallback,
 };
 
 use super::FileChatRepository;
@@ -72,6 +73,7 @@ async fn update_store_json_entry(dir: &Path, key: &str, value: Value) -> Result<
     })?;
 
     let target = dir.join(format!("{}.json", key));
+    let file = lock_file(&target).await;
     let mut current = match fs::read(&target).await {
         Ok(bytes) => serde_json::from_slice::<Value>(&bytes).map_err(|error| {
             DomainError::InvalidData(format!(
@@ -92,20 +94,11 @@ async fn update_store_json_entry(dir: &Path, key: &str, value: Value) -> Result<
 
     merge_json_value(&mut current, value);
 
-    let temp = unique_temp_path(&target, "store.json");
     let bytes = serde_json::to_vec_pretty(&current).map_err(|error| {
         DomainError::InvalidData(format!("Failed to serialize chat store JSON: {}", error))
     })?;
 
-    fs::write(&temp, &bytes).await.map_err(|error| {
-        DomainError::InternalError(format!(
-            "Failed to write chat store temp file {}: {}",
-            temp.display(),
-            error
-        ))
-    })?;
-
-    replace_file_with_fallback(&temp, &target).await?;
+    file.write(&bytes).await?;
     Ok(())
 }
 
@@ -261,20 +254,11 @@ impl FileChatRepository {
         })?;
 
         let target = dir.join(format!("{}.json", key));
-        let temp = unique_temp_path(&target, "store.json");
         let bytes = serde_json::to_vec_pretty(&value).map_err(|error| {
             DomainError::InvalidData(format!("Failed to serialize chat store JSON: {}", error))
         })?;
 
-        fs::write(&temp, &bytes).await.map_err(|error| {
-            DomainError::InternalError(format!(
-                "Failed to write chat store temp file {}: {}",
-                temp.display(),
-                error
-            ))
-        })?;
-
-        replace_file_with_fallback(&temp, &target).await?;
+        atomic_write(&target, &bytes).await?;
         Ok(())
     }
 
@@ -313,20 +297,11 @@ impl FileChatRepository {
         })?;
 
         let target = dir.join(format!("{}.json", key));
-        let temp = unique_temp_path(&target, "store.json");
         let bytes = serde_json::to_vec_pretty(&value).map_err(|error| {
             DomainError::InvalidData(format!("Failed to serialize chat store JSON: {}", error))
         })?;
 
-        fs::write(&temp, &bytes).await.map_err(|error| {
-            DomainError::InternalError(format!(
-                "Failed to write chat store temp file {}: {}",
-                temp.display(),
-                error
-            ))
-        })?;
-
-        replace_file_with_fallback(&temp, &target).await?;
+        atomic_write(&target, &bytes).await?;
         Ok(())
     }
 
@@ -2,15 +2,16 @@ use std::collections::HashSet;
 use std::path::Path;
 
 use async_trait::async_trait;
-use serde_json::Value;
+use serde_json::{Map, Value};
 use tokio::fs;
 
 use crate::domain::errors::DomainError;
 use crate::domain::models::chat::strip_jsonl_extension;
 use crate::domain::repositories::chat_repository::ChatRepository;
 use crate::domain::repositories::chat_types::{
-    ChatMessageSearchHit, ChatMessageSearchQuery, ChatMessagesReadResult, ChatPayloadChunk,
-    ChatPayloadCursor, ChatPayloadPatchOp, ChatPayloadTail, ChatSearchResult, FindLastMessageQuery,
+    ChatMessageCursor, ChatMessageLocateQuery, ChatMessageSearchHit, ChatMessageSearchQuery,
+    ChatMessagesReadResult, ChatPayloadChunk, ChatPayloadCursor, ChatPayloadForwardChunk,
+    ChatPayloadPatchOp, ChatPayloadTail, ChatSearchResult, FindLastMessageQuery,
     LocatedChatMessage, PinnedGroupChat,
 };
 use crate::domain::repositories::group_chat_repository::GroupChatRepository;
@@ -126,7 +127,7 @@ impl GroupChatRepository for FileChatRepository {
         &self,
         chat_id: &str,
     ) -> Result<std::path::PathBuf, DomainError> {
-        let path = self.get_group_chat_path(chat_id)?;
+        let path = self.resolve_group_chat_path(chat_id).await?;
         if !path.exists() {
             return Err(DomainError::NotFound(format!(
                 "Group chat not found: {}",
@@ -155,6 +156,24 @@ impl GroupChatRepository for FileChatRepository {
             .await
     }
 
+    async fn get_group_chat_payload_after_lines(
+        &self,
+        chat_id: &str,
+        cursor: ChatPayloadCursor,
+        max_lines: usize,
+    ) -> Result<ChatPayloadForwardChunk, DomainError> {
+        self.get_group_payload_after_lines(chat_id, cursor, max_lines)
+            .await
+    }
+
+    async fn locate_group_chat_message(
+        &self,
+        chat_id: &str,
+        query: &ChatMessageLocateQuery,
+    ) -> Result<Option<ChatMessageCursor>, DomainError> {
+        self.locate_group_payload_message(chat_id, query).await
+    }
+
     async fn save_group_chat_payload_windowed(
         &self,
         chat_id: &str,
@@ -220,7 +239,7 @@ impl GroupChatRepository for FileChatRepository {
         force: bool,
     ) -> Result<(), DomainError> {
         self.ensure_directory_exists().await?;
-        let path = self.get_group_chat_path(chat_id)?;
+        let path = self.resolve_group_chat_path(chat_id).await?;
         let backup_key = Self::get_group_backup_key(chat_id)?;
         self.write_payload_file_to_path(&path, source_path, force, chat_id, &backup_key)
             .await?;
@@ -229,7 +248,7 @@ impl GroupChatRepository for FileChatRepository {
     }
 
     async fn delete_group_chat_payload(&self, chat_id: &str) -> Result<(), DomainError> {
-        let path = self.get_group_chat_path(chat_id)?;
+        let path = self.resolve_group_chat_path(chat_id).await?;
         if !path.exists() {
             return Err(DomainError::NotFound(format!(
                 "Group chat not found: {}",
@@ -249,8 +268,8 @@ impl GroupChatRepository for FileChatRepository {
         old_file_name: &str,
         new_file_name: &str,
     ) -> Result<String, DomainError> {
-        let old_path = self.get_group_chat_path(old_file_name)?;
-        let new_path = self.get_group_chat_path(new_file_name)?;
+        let old_path = self.resolve_group_chat_path(old_file_name).await?;
+        let new_path = self.resolve_group_chat_path(new_file_name).await?;
         let (_old_payload_guard, _new_payload_guard) = self
             .acquire_payload_rename_locks(&old_path, &new_path)
             .await;
@@ -281,7 +300,7 @@ impl GroupChatRepository for FileChatRepository {
         self.ensure_directory_exists().await?;
 
         let chat_id = self.next_group_chat_id()?;
-        let target_path = self.get_group_chat_path(&chat_id)?;
+        let target_path = self.resolve_group_chat_path(&chat_id).await?;
 
         fs::copy(file_path, &target_path).await.map_err(|e| {
             DomainError::InternalError(format!("Failed to import group chat file: {}", e))
@@ -301,7 +320,7 @@ impl GroupChatRepository for FileChatRepository {
     }
 
     async fn get_group_chat_metadata(&self, chat_id: &str) -> Result<Value, DomainError> {
-        let path = self.get_group_chat_path(chat_id)?;
+        let path = self.resolve_group_chat_path(chat_id).await?;
         self.read_chat_metadata_from_path(&path).await
     }
 
@@ -311,11 +330,37 @@ impl GroupChatRepository for FileChatRepository {
         namespace: &str,
         value: Value,
     ) -> Result<(), DomainError> {
-        let path = self.get_group_chat_path(chat_id)?;
+        let path = self.resolve_group_chat_path(chat_id).await?;
         self.set_chat_metadata_extension_in_path(&path, namespace, value)
             .await
     }
 
+    async fn update_group_chat_metadata(
+        &self,
+        chat_id: &str,
+        fields: Map<String, Value>,
+    ) -> Result<(), DomainError> {
+        let path = self.resolve_group_chat_path(chat_id).await?;
+        self.merge_chat_metadata_fields_in_path(&path, fields).await
+    }
+
+    async fn get_group_chat_variables(
+        &self,
+        chat_id: &str,
+    ) -> Result<Map<String, Value>, DomainError> {
+        let path = self.resolve_group_chat_path(chat_id).await?;
+        self.read_chat_variables_from_path(&path).await
+    }
+
+    async fn set_group_chat_variables(
+        &self,
+        chat_id: &str,
+        variables: Map<String, Value>,
+    ) -> Result<(), DomainError> {
+        let path = self.resolve_group_chat_path(chat_id).await?;
+        self.set_chat_variables_in_path(&path, variables).await
+    }
+
     async fn get_group_chat_store_json(
         &self,
         chat_id: &str,
@@ -396,6 +441,25 @@ impl GroupChatRepository for FileChatRepository {
             .await
     }
 
+    async fn get_group_chat_message(
+        &self,
+        chat_id: &str,
+        index: usize,
+    ) -> Result<Value, DomainError> {
+        self.get_group_chat_message_internal(chat_id, index).await
+    }
+
+    async fn update_group_chat_message_extra(
+        &self,
+        chat_id: &str,
+        index: usize,
+        swipe_id: Option<usize>,
+        fields: Map<String, Value>,
+    ) -> Result<(), DomainError> {
+        self.update_group_chat_message_extra_internal(chat_id, index, swipe_id, fields)
+            .await
+    }
+
     async fn search_group_chat_messages(
         &self,
         chat_id: &str,
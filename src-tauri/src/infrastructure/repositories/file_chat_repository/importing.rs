@@ -5,6 +5,7 @@ use crate::domain::models::chat::{humanized_date, truncate_chat_file_stem_prefix
 use crate::domain::models::filename::sanitize_filename;
 
 use super::FileChatRepository;
+use super::compression::payload_exists;
 
 impl FileChatRepository {
     pub(super) fn next_import_chat_file_stem_in_dir(
@@ -34,10 +35,7 @@ impl FileChatRepository {
                 truncate_chat_file_stem_prefix(&base_name, &suffix),
                 suffix
             );
-            if !self
-                .get_chat_path_for_dir_key(dir_key, &candidate)?
-                .exists()
-            {
+            if !payload_exists(&self.get_chat_path_for_dir_key(dir_key, &candidate)?) {
                 return Ok(candidate);
             }
             ordinal += 1;
@@ -48,7 +46,7 @@ impl FileChatRepository {
         let base = humanized_date(Utc::now());
         let mut candidate = base.clone();
         let mut suffix = 1;
-        while self.get_group_chat_path(&candidate)?.exists() {
+        while payload_exists(&self.get_group_chat_path(&candidate)?) {
             candidate = format!("{} {}", base, suffix + 1);
             suffix += 1;
         }
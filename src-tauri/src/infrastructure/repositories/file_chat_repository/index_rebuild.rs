@@ -0,0 +1,67 @@
+use std::time::Instant;
+
+use futures_util::StreamExt;
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::chat_repository::ChatIndexRebuildReport;
+use crate::infrastructure::logging::logger;
+
+use super::FileChatRepository;
+
+/// Chat files scanned at the same time while rebuilding the summary index.
+const REBUILD_CONCURRENCY: usize = 8;
+
+impl FileChatRepository {
+    pub(super) async fn rebuild_summary_index_internal(
+        &self,
+        on_progress: &(dyn Fn(usize, usize) + Send + Sync),
+    ) -> Result<ChatIndexRebuildReport, DomainError> {
+        let started = Instant::now();
+        self.summary_cache.lock().await.reset();
+
+        let character_chats = self.list_character_chat_files(None).await?;
+        let group_chats = self.list_group_chat_files(None).await?;
+        let descriptors: Vec<_> = character_chats
+            .into_iter()
+            .map(|descriptor| (descriptor, false))
+            .chain(group_chats.into_iter().map(|descriptor| (descriptor, true)))
+            .collect();
+
+        let total = descriptors.len();
+        let mut report = ChatIndexRebuildReport::default();
+        let mut scanned = 0;
+        on_progress(scanned, total);
+
+        let mut scans = futures_util::stream::iter(descriptors.iter().map(
+            |(descriptor, is_group)| async move {
+                let result = self.get_chat_summary_entry(descriptor, false).await;
+                (descriptor, *is_group, result)
+            },
+        ))
+        .buffer_unordered(REBUILD_CONCURRENCY);
+
+        while let Some((descriptor, is_group, result)) = scans.next().await {
+            scanned += 1;
+            match result {
+                Ok(_) if is_group => report.group_chats += 1,
+                Ok(_) => report.character_chats += 1,
+                Err(error) => {
+                    report.failed += 1;
+                    logger::warn(&format!(
+                        "Failed to index chat file {:?}: {}",
+                        descriptor.path, error
+                    ));
+                }
+            }
+            on_progress(scanned, total);
+        }
+
+        self.flush_summary_index_if_needed().await?;
+        report.duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        logger::info(&format!(
+            "Rebuilt chat summary index: {} character chats, {} group chats, {} failed in {}ms",
+            report.character_chats, report.group_chats, report.failed, report.duration_ms
+        ));
+        Ok(report)
+    }
+}
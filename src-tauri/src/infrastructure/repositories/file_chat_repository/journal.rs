@@ -0,0 +1,264 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, SeekFrom};
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::chat::ChatMessage;
+use crate::infrastructure::logging::logger;
+
+use super::FileChatRepository;
+use super::windowed_payload_io::{
+    count_lines_in_region, open_existing_payload_file, read_existing_payload_metadata,
+    read_first_line_and_end_offset,
+};
+
+const JOURNAL_SUFFIX: &str = ".journal";
+
+/// One message appended by `add_message`. `index` is the 0-based position the message
+/// takes in the chat, so replaying a journal whose entries already reached the payload
+/// (killed between the rewrite and the journal removal) does not duplicate them.
+#[derive(Serialize, Deserialize)]
+struct JournalEntry {
+    index: usize,
+    message: Value,
+}
+
+/// Outcome of replaying one chat journal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum JournalReplay {
+    /// No journal was pending.
+    None,
+    /// The journal had no chat payload left and was removed.
+    Orphaned,
+    /// The journal was replayed; holds the payload's message count afterwards.
+    Replayed(usize),
+}
+
+/// Write-ahead journal of a chat payload: `<file>.jsonl.journal` beside it.
+fn journal_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(JOURNAL_SUFFIX);
+    path.with_file_name(file_name)
+}
+
+fn io_error(path: &Path, action: &str, error: std::io::Error) -> DomainError {
+    DomainError::InternalError(format!(
+        "Failed to {} chat journal {:?}: {}",
+        action, path, error
+    ))
+}
+
+async fn ends_with_newline(path: &Path, len: u64) -> Result<bool, DomainError> {
+    if len == 0 {
+        return Ok(true);
+    }
+    let mut file = open_existing_payload_file(path).await?;
+    file.seek(SeekFrom::Start(len - 1))
+        .await
+        .map_err(|error| io_error(path, "seek", error))?;
+    let mut byte = [0u8; 1];
+    file.read_exact(&mut byte)
+        .await
+        .map_err(|error| io_error(path, "read", error))?;
+    Ok(byte[0] == b'\n')
+}
+
+async fn remove_journal(journal: &Path) -> Result<(), DomainError> {
+    match fs::remove_file(journal).await {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(io_error(journal, "remove", error)),
+    }
+}
+
+/// Parses journal lines, stopping at the first unreadable one: a process killed
+/// mid-append leaves at most a truncated last line.
+fn parse_journal(text: &str, journal: &Path) -> Vec<JournalEntry> {
+    let mut entries = Vec::new();
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        match serde_json::from_str::<JournalEntry>(line) {
+            Ok(entry) => entries.push(entry),
+            Err(error) => {
+                logger::warn(&format!(
+                    "Ignoring unreadable chat journal tail in {:?}: {}",
+                    journal, error
+                ));
+                break;
+            }
+        }
+    }
+    entries
+}
+
+impl FileChatRepository {
+    /// Durably append `message` to the chat's journal before the payload is rewritten.
+    pub(super) async fn append_chat_journal(
+        &self,
+        path: &Path,
+        index: usize,
+        message: &ChatMessage,
+    ) -> Result<(), DomainError> {
+        let entry = JournalEntry {
+            index,
+            message: serde_json::to_value(message).map_err(|error| {
+                DomainError::InternalError(format!("Failed to serialize chat message: {}", error))
+            })?,
+        };
+        let mut line = serde_json::to_vec(&entry).map_err(|error| {
+            DomainError::InternalError(format!("Failed to serialize chat journal entry: {}", error))
+        })?;
+        line.push(b'\n');
+
+        let journal = journal_path(path);
+        let _write_guard = self.acquire_payload_write_lock(path).await;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&journal)
+            .await
+            .map_err(|error| io_error(&journal, "open", error))?;
+        file.write_all(&line)
+            .await
+            .map_err(|error| io_error(&journal, "write", error))?;
+        file.sync_data()
+            .await
+            .map_err(|error| io_error(&journal, "sync", error))
+    }
+
+    /// Append journaled messages that have not reached the payload yet, then drop the
+    /// journal.
+    pub(super) async fn replay_chat_journal(
+        &self,
+        path: &Path,
+    ) -> Result<JournalReplay, DomainError> {
+        let journal = journal_path(path);
+        if !journal.exists() {
+            return Ok(JournalReplay::None);
+        }
+        // A chat compressed while its journal was pending is journaled beside the
+        // plain path, so bring the plain payload back first.
+        self.inflate_chat_payload(path).await?;
+
+        let _write_guard = self.acquire_payload_write_lock(path).await;
+        if !journal.exists() {
+            return Ok(JournalReplay::None);
+        }
+        if !path.exists() {
+            logger::warn(&format!(
+                "Removing chat journal without a chat payload: {:?}",
+                journal
+            ));
+            return remove_journal(&journal)
+                .await
+                .map(|()| JournalReplay::Orphaned);
+        }
+
+        let text = fs::read_to_string(&journal)
+            .await
+            .map_err(|error| io_error(&journal, "read", error))?;
+        let entries = parse_journal(&text, &journal);
+
+        let metadata = read_existing_payload_metadata(path).await?;
+        let (_, header_end_offset) = read_first_line_and_end_offset(path).await?;
+        let mut message_count =
+            count_lines_in_region(path, header_end_offset, metadata.len()).await?;
+
+        let mut pending = Vec::new();
+        if !ends_with_newline(path, metadata.len()).await? {
+            pending.push(b'\n');
+        }
+        let mut replayed = 0;
+        for entry in entries {
+            if entry.index < message_count {
+                continue;
+            }
+            if entry.index > message_count {
+                logger::warn(&format!(
+                    "Chat journal {:?} skips from message {} to {}; dropping the rest",
+                    journal, message_count, entry.index
+                ));
+                break;
+            }
+            let line = serde_json::to_vec(&entry.message).map_err(|error| {
+                DomainError::InternalError(format!("Failed to serialize chat message: {}", error))
+            })?;
+            pending.extend_from_slice(&line);
+            pending.push(b'\n');
+            message_count += 1;
+            replayed += 1;
+        }
+
+        if replayed > 0 {
+            let mut file = OpenOptions::new()
+                .append(true)
+                .open(path)
+                .await
+                .map_err(|error| io_error(path, "open", error))?;
+            file.write_all(&pending)
+                .await
+                .map_err(|error| io_error(path, "write", error))?;
+            file.sync_data()
+                .await
+                .map_err(|error| io_error(path, "sync", error))?;
+            self.remove_summary_cache_for_path(path).await;
+            logger::info(&format!(
+                "Replayed {} journaled message(s) into {:?}",
+                replayed, path
+            ));
+        }
+
+        remove_journal(&journal).await?;
+        Ok(JournalReplay::Replayed(message_count))
+    }
+
+    /// Replay every pending character chat journal, e.g. after the app was killed
+    /// between `add_message` and the deferred payload rewrite.
+    pub(super) async fn replay_chat_journals_internal(&self) -> Result<usize, DomainError> {
+        if !self.chats_dir.exists() {
+            return Ok(0);
+        }
+
+        let mut replayed = 0;
+        let mut dirs = fs::read_dir(&self.chats_dir)
+            .await
+            .map_err(|error| io_error(&self.chats_dir, "list", error))?;
+        while let Some(dir) = dirs
+            .next_entry()
+            .await
+            .map_err(|error| io_error(&self.chats_dir, "list", error))?
+        {
+            let dir_path = dir.path();
+            if !dir_path.is_dir() {
+                continue;
+            }
+
+            let mut files = fs::read_dir(&dir_path)
+                .await
+                .map_err(|error| io_error(&dir_path, "list", error))?;
+            while let Some(file) = files
+                .next_entry()
+                .await
+                .map_err(|error| io_error(&dir_path, "list", error))?
+            {
+                let file_name = file.file_name();
+                let Some(payload_name) = file_name
+                    .to_str()
+                    .and_then(|name| name.strip_suffix(JOURNAL_SUFFIX))
+                else {
+                    continue;
+                };
+                let outcome = self
+                    .replay_chat_journal(&dir_path.join(payload_name))
+                    .await?;
+                if outcome != JournalReplay::None {
+                    replayed += 1;
+                }
+            }
+        }
+
+        Ok(replayed)
+    }
+}
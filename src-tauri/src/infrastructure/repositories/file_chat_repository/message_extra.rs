@@ -0,0 +1,241 @@
+use std::path::Path;
+
+use serde_json::{Map, Value};
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::file_system::commit_temp_file;
+
+use super::FileChatRepository;
+use super::windowed_payload_io::{decode_jsonl_line_bytes, open_existing_payload_file};
+
+impl FileChatRepository {
+    pub(super) async fn get_character_chat_message_internal(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        index: usize,
+    ) -> Result<Value, DomainError> {
+        let path = self
+            .resolve_character_chat_path(character_name, file_name)
+            .await?;
+        read_message_from_path(&path, index).await
+    }
+
+    pub(super) async fn get_group_chat_message_internal(
+        &self,
+        chat_id: &str,
+        index: usize,
+    ) -> Result<Value, DomainError> {
+        let path = self.resolve_group_chat_path(chat_id).await?;
+        read_message_from_path(&path, index).await
+    }
+
+    pub(super) async fn update_character_chat_message_extra_internal(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        index: usize,
+        swipe_id: Option<usize>,
+        fields: Map<String, Value>,
+    ) -> Result<(), DomainError> {
+        let path = self
+            .resolve_character_chat_path(character_name, file_name)
+            .await?;
+        let cache_key = self.get_cache_key(character_name, file_name)?;
+
+        {
+            let _write_guard = self.acquire_payload_write_lock(&path).await;
+            merge_message_extra_in_path(&path, index, swipe_id, fields).await?;
+        }
+        {
+            let mut cache = self.memory_cache.lock().await;
+            cache.remove(&cache_key);
+        }
+        self.remove_summary_cache_for_path(&path).await;
+        Ok(())
+    }
+
+    pub(super) async fn update_group_chat_message_extra_internal(
+        &self,
+        chat_id: &str,
+        index: usize,
+        swipe_id: Option<usize>,
+        fields: Map<String, Value>,
+    ) -> Result<(), DomainError> {
+        let path = self.resolve_group_chat_path(chat_id).await?;
+
+        {
+            let _write_guard = self.acquire_payload_write_lock(&path).await;
+            merge_message_extra_in_path(&path, index, swipe_id, fields).await?;
+        }
+        self.remove_summary_cache_for_path(&path).await;
+        Ok(())
+    }
+}
+
+fn read_error(path: &Path, error: std::io::Error) -> DomainError {
+    DomainError::InternalError(format!(
+        "Failed to read chat payload {}: {}",
+        path.display(),
+        error
+    ))
+}
+
+fn write_error(error: std::io::Error) -> DomainError {
+    DomainError::InternalError(format!("Failed to write chat payload line: {}", error))
+}
+
+fn message_not_found(path: &Path, index: usize) -> DomainError {
+    DomainError::NotFound(format!(
+        "Chat message {} not found in {}",
+        index,
+        path.display()
+    ))
+}
+
+fn parse_message_line(line_bytes: &[u8], path: &Path, index: usize) -> Result<Value, DomainError> {
+    let line = decode_jsonl_line_bytes(line_bytes)?;
+    let value = serde_json::from_str::<Value>(&line).map_err(|error| {
+        DomainError::InvalidData(format!(
+            "Failed to parse chat message {} in {}: {}",
+            index,
+            path.display(),
+            error
+        ))
+    })?;
+    if !value.is_object() {
+        return Err(DomainError::InvalidData(format!(
+            "Chat message {} is not an object in {}",
+            index,
+            path.display()
+        )));
+    }
+    Ok(value)
+}
+
+async fn read_message_from_path(path: &Path, index: usize) -> Result<Value, DomainError> {
+    let mut reader = BufReader::new(open_existing_payload_file(path).await?);
+    let mut line_bytes = Vec::new();
+
+    // Line 0 is the chat header; message `index` is on line `index + 1`.
+    for line_number in 0..=index + 1 {
+        line_bytes.clear();
+        let read = reader
+            .read_until(b'\n', &mut line_bytes)
+            .await
+            .map_err(|error| read_error(path, error))?;
+        if read == 0 {
+            return Err(message_not_found(path, index));
+        }
+        if line_number == index + 1 {
+            return parse_message_line(&line_bytes, path, index);
+        }
+    }
+    Err(message_not_found(path, index))
+}
+
+/// Merges `fields` into the message's `extra` (and the swipe's `swipe_info` extra when
+/// `swipe_id` is given); null values remove keys. Every other line is copied verbatim.
+async fn merge_message_extra_in_path(
+    path: &Path,
+    index: usize,
+    swipe_id: Option<usize>,
+    fields: Map<String, Value>,
+) -> Result<(), DomainError> {
+    let mut reader = BufReader::new(open_existing_payload_file(path).await?);
+    let temp_path = FileChatRepository::temp_payload_path(path);
+    let mut out = File::create(&temp_path).await.map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to create chat payload temp file {:?}: {}",
+            temp_path, error
+        ))
+    })?;
+
+    let target_line = index + 1;
+    let mut line_number = 0_usize;
+    let mut found = false;
+    let mut line_bytes = Vec::new();
+    loop {
+        line_bytes.clear();
+        let read = reader
+            .read_until(b'\n', &mut line_bytes)
+            .await
+            .map_err(|error| read_error(path, error))?;
+        if read == 0 {
+            break;
+        }
+
+        if line_number == target_line {
+            let mut message = parse_message_line(&line_bytes, path, index)?;
+            merge_extra_fields(&mut message, swipe_id, &fields);
+            let serialized = serde_json::to_string(&message).map_err(|error| {
+                DomainError::InternalError(format!(
+                    "Failed to serialize chat message {}: {}",
+                    index, error
+                ))
+            })?;
+            out.write_all(serialized.as_bytes())
+                .await
+                .map_err(write_error)?;
+            if line_bytes.ends_with(b"\n") {
+                out.write_all(b"\n").await.map_err(write_error)?;
+            }
+            found = true;
+        } else {
+            out.write_all(&line_bytes).await.map_err(write_error)?;
+        }
+        line_number += 1;
+    }
+
+    if !found {
+        drop(out);
+        let _ = tokio::fs::remove_file(&temp_path).await;
+        return Err(message_not_found(path, index));
+    }
+
+    out.flush().await.map_err(|error| {
+        DomainError::InternalError(format!("Failed to flush chat payload file: {}", error))
+    })?;
+    commit_temp_file(&temp_path, path).await
+}
+
+fn merge_extra_fields(message: &mut Value, swipe_id: Option<usize>, fields: &Map<String, Value>) {
+    merge_into_extra(message, fields);
+
+    let Some(swipe) = swipe_id.and_then(|swipe_id| {
+        message
+            .get_mut("swipe_info")
+            .and_then(Value::as_array_mut)
+            .and_then(|swipe_info| swipe_info.get_mut(swipe_id))
+    }) else {
+        return;
+    };
+    if swipe.is_object() {
+        merge_into_extra(swipe, fields);
+    }
+}
+
+fn merge_into_extra(object: &mut Value, fields: &Map<String, Value>) {
+    let Some(object) = object.as_object_mut() else {
+        return;
+    };
+    let extra = object
+        .entry("extra")
+        .or_insert_with(|| Value::Object(Map::new()));
+    if !extra.is_object() {
+        *extra = Value::Object(Map::new());
+    }
+    let Some(extra) = extra.as_object_mut() else {
+        return;
+    };
+
+    for (key, value) in fields {
+        if value.is_null() {
+            extra.remove(key);
+        } else {
+            extra.insert(key.clone(), value.clone());
+        }
+    }
+}
@@ -31,7 +31,7 @@ impl FileChatRepository {
         chat_id: &str,
         indices: &[usize],
     ) -> Result<ChatMessagesReadResult, DomainError> {
-        let path = self.get_group_chat_path(chat_id)?;
+        let path = self.resolve_group_chat_path(chat_id).await?;
         read_chat_messages_from_path(&path, indices).await
     }
 }
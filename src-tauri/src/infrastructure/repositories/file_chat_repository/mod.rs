@@ -1,24 +1,31 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, Weak};
-use std::time::Duration;
 
 use tokio::sync::Mutex;
 
+mod archive;
 mod backup;
 mod cache;
 mod chat_dir_resolver;
+mod chat_search;
+mod chat_stats;
+mod compression;
 mod extension_metadata;
 mod extension_store;
 mod group_chat_repository_impl;
 mod importing;
+mod index_rebuild;
 mod integrity;
+mod journal;
 mod locate;
+mod message_extra;
 mod message_read;
 mod message_search;
 mod paths;
 mod payload;
 mod recent_selection;
+mod repair;
 mod repository_impl;
 mod summary;
 mod windowed_hide;
@@ -29,8 +36,11 @@ mod windowed_payload_io;
 #[cfg(test)]
 mod tests;
 
-use self::cache::{MemoryCache, ThrottledBackup};
+use self::cache::ThrottledBackup;
 use self::summary::SummaryCache;
+use crate::domain::models::chat::Chat;
+use crate::domain::models::memory_cache::MemoryCacheConfig;
+use crate::infrastructure::persistence::memory_cache::MemoryCache;
 use crate::infrastructure::repositories::chat_directory_identity::{
     SharedChatAliasStore, chat_alias_path_for_user_dir, new_shared_chat_alias_store,
 };
@@ -42,10 +52,12 @@ pub struct FileChatRepository {
     group_chats_dir: PathBuf,
     backups_dir: PathBuf,
     path_write_locks: Arc<Mutex<HashMap<PathBuf, Weak<Mutex<()>>>>>,
-    memory_cache: Arc<Mutex<MemoryCache>>,
+    memory_cache: Arc<Mutex<MemoryCache<Chat>>>,
     summary_cache: Arc<Mutex<SummaryCache>>,
     chat_aliases: SharedChatAliasStore,
     throttled_backup: Arc<Mutex<ThrottledBackup>>,
+    /// Limits full payload rewrites after `add_message`; see `journal`.
+    journal_compaction: Arc<Mutex<ThrottledBackup>>,
     max_backups_per_chat: usize,
     max_total_backups: usize,
     backup_enabled: bool,
@@ -53,6 +65,9 @@ pub struct FileChatRepository {
 
 impl FileChatRepository {
     const CHAT_BACKUP_PREFIX: &'static str = "chat_";
+    /// Messages added within this window only go to the journal; the next add after
+    /// it rewrites the payload.
+    const JOURNAL_COMPACTION_INTERVAL_SECS: u64 = 5;
 
     /// Create an isolated chat repository.
     ///
@@ -92,11 +107,8 @@ impl FileChatRepository {
         backups_dir: PathBuf,
         chat_aliases: SharedChatAliasStore,
     ) -> Self {
-        // Create a memory cache with 100 chat capacity and 30 minute TTL
-        let memory_cache = Arc::new(Mutex::new(MemoryCache::new(
-            100,
-            Duration::from_secs(30 * 60),
-        )));
+        // Sized from settings once they are loaded; see `configure_memory_cache`.
+        let memory_cache = Arc::new(Mutex::new(MemoryCache::new(MemoryCacheConfig::default())));
         let summary_index_path = backups_dir
             .parent()
             .map(|default_user_dir| {
@@ -110,6 +122,9 @@ impl FileChatRepository {
 
         // Match SillyTavern default: backups.chat.throttleInterval = 10_000ms
         let throttled_backup = Arc::new(Mutex::new(ThrottledBackup::new(10)));
+        let journal_compaction = Arc::new(Mutex::new(ThrottledBackup::new(
+            Self::JOURNAL_COMPACTION_INTERVAL_SECS,
+        )));
         let path_write_locks = Arc::new(Mutex::new(HashMap::new()));
 
         Self {
@@ -122,6 +137,7 @@ impl FileChatRepository {
             summary_cache,
             chat_aliases,
             throttled_backup,
+            journal_compaction,
             // Match SillyTavern defaults:
             // - per-chat backups: 50
             // - total backups: unlimited (-1 in SillyTavern config)
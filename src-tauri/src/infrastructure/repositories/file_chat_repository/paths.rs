@@ -196,6 +196,16 @@ impl FileChatRepository {
         Ok(self.group_chats_dir.join(normalized))
     }
 
+    /// Path of the plain JSONL group chat payload. A compressed chat is inflated on the way.
+    pub(super) async fn resolve_group_chat_path(
+        &self,
+        chat_id: &str,
+    ) -> Result<PathBuf, DomainError> {
+        let path = self.get_group_chat_path(chat_id)?;
+        self.inflate_chat_payload(&path).await?;
+        Ok(path)
+    }
+
     /// Get the path to a chat backup file
     pub(super) fn get_backup_path(&self, backup_name: &str) -> PathBuf {
         self.backups_dir.join(Self::backup_file_name(backup_name))
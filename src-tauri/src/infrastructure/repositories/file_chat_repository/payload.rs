@@ -6,13 +6,14 @@ use tokio::fs;
 use crate::domain::errors::DomainError;
 use crate::domain::models::chat::{Chat, strip_jsonl_extension};
 use crate::infrastructure::logging::logger;
-use crate::infrastructure::persistence::file_system::replace_file_with_fallback;
+use crate::infrastructure::persistence::file_system::commit_temp_file;
 use crate::infrastructure::persistence::jsonl_utils::{
     parse_jsonl_bytes, read_first_non_empty_jsonl_line, write_jsonl_file,
 };
 
 use super::FileChatRepository;
 use super::integrity::verify_integrity_match;
+use super::windowed_payload_io::read_first_line_and_end_offset;
 
 impl FileChatRepository {
     pub(super) fn parse_chat_from_payload(
@@ -75,14 +76,37 @@ impl FileChatRepository {
         Ok(chat)
     }
 
-    pub(super) fn build_payload_from_chat(chat: &Chat) -> Result<Vec<Value>, DomainError> {
-        let mut objects = Vec::with_capacity(chat.messages.len() + 1);
-        objects.push(serde_json::json!({
+    pub(super) fn build_payload_header(chat: &Chat) -> Value {
+        serde_json::json!({
             "user_name": chat.user_name,
             "character_name": chat.character_name,
             "create_date": chat.create_date,
             "chat_metadata": chat.chat_metadata,
-        }));
+        })
+    }
+
+    /// Whether the header on disk already holds every field `build_payload_header`
+    /// writes for `chat`, so messages can be appended without rewriting the file.
+    pub(super) async fn payload_header_matches(
+        path: &Path,
+        chat: &Chat,
+    ) -> Result<bool, DomainError> {
+        let (line, _) = read_first_line_and_end_offset(path).await?;
+        let Ok(Value::Object(existing)) = serde_json::from_str::<Value>(&line) else {
+            return Ok(false);
+        };
+        let Value::Object(expected) = Self::build_payload_header(chat) else {
+            return Ok(false);
+        };
+
+        Ok(expected
+            .iter()
+            .all(|(key, value)| existing.get(key) == Some(value)))
+    }
+
+    pub(super) fn build_payload_from_chat(chat: &Chat) -> Result<Vec<Value>, DomainError> {
+        let mut objects = Vec::with_capacity(chat.messages.len() + 1);
+        objects.push(Self::build_payload_header(chat));
 
         for message in &chat.messages {
             objects.push(serde_json::to_value(message).map_err(|error| {
@@ -229,7 +253,7 @@ impl FileChatRepository {
                 source_path, temp_path, e
             ))
         })?;
-        replace_file_with_fallback(&temp_path, path).await?;
+        commit_temp_file(&temp_path, path).await?;
 
         self.backup_chat_file(path, backup_name, backup_key).await?;
         Ok(())
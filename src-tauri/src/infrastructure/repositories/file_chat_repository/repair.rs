@@ -0,0 +1,231 @@
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use serde_json::{Map, Value};
+use tokio::fs::{self, File, OpenOptions};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter};
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::chat::message_date_format;
+use crate::domain::repositories::chat_repository::ChatRepairReport;
+use crate::infrastructure::logging::logger;
+
+use super::FileChatRepository;
+use super::windowed_payload_io::{open_existing_payload_file, replace_file};
+
+const BAD_LINES_EXTENSION: &str = "bad";
+
+/// Unparseable lines are appended next to the chat as `<file>.jsonl.bad`, so
+/// repeated repairs never lose what earlier ones removed.
+fn bad_lines_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".");
+    file_name.push(BAD_LINES_EXTENSION);
+    path.with_file_name(file_name)
+}
+
+fn io_error(path: &Path, action: &str, error: std::io::Error) -> DomainError {
+    DomainError::InternalError(format!(
+        "Failed to {} chat payload {:?}: {}",
+        action, path, error
+    ))
+}
+
+fn has_send_date(message: &Map<String, Value>) -> bool {
+    match message.get("send_date") {
+        None | Some(Value::Null) => false,
+        Some(Value::String(value)) => !value.trim().is_empty(),
+        Some(_) => true,
+    }
+}
+
+/// Result of streaming a payload through the repair rules into a temp file.
+struct RepairOutcome {
+    report: ChatRepairReport,
+    bad_lines: Vec<Vec<u8>>,
+}
+
+async fn write_line(
+    writer: &mut BufWriter<File>,
+    path: &Path,
+    line: &[u8],
+) -> Result<(), DomainError> {
+    writer
+        .write_all(line)
+        .await
+        .map_err(|error| io_error(path, "write", error))?;
+    writer
+        .write_all(b"\n")
+        .await
+        .map_err(|error| io_error(path, "write", error))
+}
+
+async fn repair_payload_into(path: &Path, temp_path: &Path) -> Result<RepairOutcome, DomainError> {
+    let mut reader = BufReader::new(open_existing_payload_file(path).await?);
+    let temp_file = File::create(temp_path)
+        .await
+        .map_err(|error| io_error(temp_path, "create", error))?;
+    let mut writer = BufWriter::new(temp_file);
+
+    let mut report = ChatRepairReport::default();
+    let mut bad_lines = Vec::new();
+    let mut header: Option<Map<String, Value>> = None;
+    let mut previous: Option<Value> = None;
+    let mut buf = Vec::new();
+
+    loop {
+        buf.clear();
+        let read = reader
+            .read_until(b'\n', &mut buf)
+            .await
+            .map_err(|error| io_error(path, "read", error))?;
+        if read == 0 {
+            break;
+        }
+
+        let raw = buf.strip_suffix(b"\n").unwrap_or(&buf);
+        let raw = raw.strip_suffix(b"\r").unwrap_or(raw);
+        if raw.iter().all(u8::is_ascii_whitespace) {
+            report.blank_lines_removed += 1;
+            continue;
+        }
+
+        let parsed = std::str::from_utf8(raw)
+            .ok()
+            .and_then(|text| serde_json::from_str::<Value>(text).ok());
+        let Some(Value::Object(mut object)) = parsed else {
+            if header.is_none() {
+                return Err(DomainError::InvalidData(format!(
+                    "Chat header of {:?} is not a JSON object and cannot be repaired",
+                    path
+                )));
+            }
+            report.invalid_lines_removed += 1;
+            bad_lines.push(raw.to_vec());
+            continue;
+        };
+
+        let Some(header_fields) = header.as_ref() else {
+            write_line(&mut writer, temp_path, raw).await?;
+            header = Some(object);
+            continue;
+        };
+
+        let missing_send_date = !has_send_date(&object);
+        if missing_send_date {
+            let send_date = previous
+                .as_ref()
+                .and_then(|message| message.get("send_date"))
+                .or_else(|| header_fields.get("create_date"))
+                .filter(|value| !value.is_null())
+                .cloned()
+                .unwrap_or_else(|| Value::String(message_date_format(Utc::now())));
+            object.insert("send_date".to_string(), send_date);
+        }
+
+        let message = Value::Object(object);
+        if previous.as_ref() == Some(&message) {
+            report.duplicates_removed += 1;
+            continue;
+        }
+
+        if missing_send_date {
+            report.send_dates_fixed += 1;
+            let line = serde_json::to_vec(&message).map_err(|error| {
+                DomainError::InternalError(format!("Failed to serialize chat message: {}", error))
+            })?;
+            write_line(&mut writer, temp_path, &line).await?;
+        } else {
+            write_line(&mut writer, temp_path, raw).await?;
+        }
+        report.messages += 1;
+        previous = Some(message);
+    }
+
+    if header.is_none() {
+        return Err(DomainError::InvalidData(format!(
+            "Chat payload {:?} is empty",
+            path
+        )));
+    }
+
+    writer
+        .flush()
+        .await
+        .map_err(|error| io_error(temp_path, "flush", error))?;
+
+    Ok(RepairOutcome { report, bad_lines })
+}
+
+async fn append_bad_lines(path: &Path, lines: &[Vec<u8>]) -> Result<(), DomainError> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await
+        .map_err(|error| io_error(path, "open", error))?;
+    for line in lines {
+        file.write_all(line)
+            .await
+            .map_err(|error| io_error(path, "write", error))?;
+        file.write_all(b"\n")
+            .await
+            .map_err(|error| io_error(path, "write", error))?;
+    }
+    file.flush()
+        .await
+        .map_err(|error| io_error(path, "flush", error))
+}
+
+impl FileChatRepository {
+    pub(super) async fn repair_character_chat_internal(
+        &self,
+        character_name: &str,
+        file_name: &str,
+    ) -> Result<ChatRepairReport, DomainError> {
+        let path = self
+            .resolve_character_chat_path(character_name, file_name)
+            .await?;
+        let backup_key = self.get_cache_key(character_name, file_name)?;
+
+        let _write_guard = self.acquire_payload_write_lock(&path).await;
+        let temp_path = Self::temp_payload_path(&path);
+        let outcome = match repair_payload_into(&path, &temp_path).await {
+            Ok(outcome) => outcome,
+            Err(error) => {
+                let _ = fs::remove_file(&temp_path).await;
+                return Err(error);
+            }
+        };
+
+        let mut report = outcome.report;
+        if !report.changed() {
+            let _ = fs::remove_file(&temp_path).await;
+            return Ok(report);
+        }
+
+        if !outcome.bad_lines.is_empty() {
+            let bad_path = bad_lines_path(&path);
+            append_bad_lines(&bad_path, &outcome.bad_lines).await?;
+            report.bad_lines_file = bad_path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned());
+        }
+        replace_file(&temp_path, &path).await?;
+
+        self.memory_cache.lock().await.remove(&backup_key);
+        self.remove_summary_cache_for_path(&path).await;
+        self.backup_chat_file(&path, character_name, &backup_key)
+            .await?;
+
+        logger::info(&format!(
+            "Repaired chat {:?}: {} invalid, {} blank, {} duplicate lines removed, {} send dates fixed",
+            path,
+            report.invalid_lines_removed,
+            report.blank_lines_removed,
+            report.duplicates_removed,
+            report.send_dates_fixed
+        ));
+        Ok(report)
+    }
+}
@@ -2,29 +2,34 @@ use std::collections::HashSet;
 use std::path::Path;
 
 use async_trait::async_trait;
-use serde_json::Value;
+use serde_json::{Map, Value};
 use tokio::fs;
 
 use crate::domain::errors::DomainError;
 use crate::domain::models::chat::{Chat, ChatMessage, strip_jsonl_extension};
+use crate::domain::models::memory_cache::{MemoryCacheConfig, MemoryCacheStats};
 use crate::domain::repositories::chat_repository::{
-    ChatExportFormat, ChatImportFormat, ChatMessageSearchHit, ChatMessageSearchQuery,
-    ChatMessagesReadResult, ChatPayloadChunk, ChatPayloadCursor, ChatPayloadPatchOp,
-    ChatPayloadTail, ChatRepository, ChatSearchResult, FindLastMessageQuery, LocatedChatMessage,
-    PinnedCharacterChat,
+    CharacterChatStats, ChatArchiveReport, ChatCompressionOptions, ChatCompressionReport,
+    ChatExportFormat, ChatImportFormat, ChatIndexRebuildReport, ChatMessageCursor,
+    ChatMessageLocateQuery, ChatMessageSearchHit, ChatMessageSearchQuery, ChatMessagesReadResult,
+    ChatPayloadChunk, ChatPayloadCursor, ChatPayloadForwardChunk, ChatPayloadPatchOp,
+    ChatPayloadTail, ChatRepairReport, ChatRepository, ChatSearchMatchResult, ChatSearchOptions,
+    ChatSearchResult, FindLastMessageQuery, LocatedChatMessage, PinnedCharacterChat,
 };
 use crate::infrastructure::logging::logger;
 use crate::infrastructure::persistence::chat_format_importers::{
-    export_payload_to_plain_text, import_chat_payloads_from_json, import_chat_payloads_from_jsonl,
-};
-use crate::infrastructure::persistence::file_system::{
-    list_files_with_extension, move_file_no_replace_with_fallback,
+    detect_chat_import_format, export_payload_to_plain_text, import_chat_payloads_auto,
+    import_chat_payloads_from_json, import_chat_payloads_from_jsonl,
 };
+use crate::infrastructure::persistence::file_system::move_file_no_replace_with_fallback;
+use crate::infrastructure::persistence::internal_writes::record_internal_write;
 use crate::infrastructure::persistence::jsonl_utils::{
     parse_jsonl_bytes, read_jsonl_file, write_jsonl_file,
 };
 
 use super::FileChatRepository;
+use super::compression::list_chat_payload_files;
+use super::journal::JournalReplay;
 
 #[async_trait]
 impl ChatRepository for FileChatRepository {
@@ -49,7 +54,7 @@ impl ChatRepository for FileChatRepository {
         let cache_key = self.get_cache_key(character_name, file_name)?;
 
         {
-            let cache = self.memory_cache.lock().await;
+            let mut cache = self.memory_cache.lock().await;
             if let Some(chat) = cache.get(&cache_key) {
                 return Ok(chat);
             }
@@ -76,17 +81,11 @@ impl ChatRepository for FileChatRepository {
             return Ok(Vec::new());
         }
 
-        // List all JSONL files in the character directory
-        let chat_files = list_files_with_extension(&character_dir, "jsonl").await?;
+        // List all chat files (plain or compressed) in the character directory
+        let chat_files = list_chat_payload_files(&character_dir).await?;
         let mut chats = Vec::new();
 
-        for file_path in chat_files {
-            let file_name = file_path
-                .file_name()
-                .and_then(|f| f.to_str())
-                .unwrap_or("")
-                .to_string();
-
+        for (file_name, _) in chat_files {
             let mut chat = self.get_chat(character_name, &file_name).await?;
             // Keep the internal character ID stable for list/read-model flows.
             // Chat metadata may contain a mutable display name, but filesystem
@@ -175,6 +174,7 @@ impl ChatRepository for FileChatRepository {
         }
 
         // Delete the file
+        record_internal_write(&path);
         fs::remove_file(&path).await.map_err(|e| {
             logger::error(&format!("Failed to delete chat file: {}", e));
             DomainError::InternalError(format!("Failed to delete chat file: {}", e))
@@ -270,9 +270,40 @@ impl ChatRepository for FileChatRepository {
 
         // Add the message
         chat.add_message(message);
+        let index = chat.messages.len() - 1;
 
-        // Save the chat
-        self.save(&chat).await?;
+        // Journal it first so it survives the app being killed before the rewrite below.
+        let dir_key = self.resolve_character_chat_dir_key(character_name).await?;
+        let path = self.get_chat_path_for_dir_key(&dir_key, file_name)?;
+        self.append_chat_journal(&path, index, &chat.messages[index])
+            .await?;
+
+        // Flush to the payload at most once per compaction interval; until then the
+        // journal is replayed whenever the chat file is next resolved. Replaying only
+        // appends lines, so the file is rewritten just when its header is out of date
+        // or the append left it out of step with the chat.
+        let cache_key = self.get_cache_key(character_name, file_name)?;
+        let compact = self
+            .journal_compaction
+            .lock()
+            .await
+            .should_backup(&cache_key);
+        if compact {
+            let on_disk = self.replay_chat_journal(&path).await?;
+            let appended = on_disk == JournalReplay::Replayed(chat.messages.len())
+                && Self::payload_header_matches(&path, &chat).await?;
+            if appended {
+                self.backup_chat_file(&path, character_name, &cache_key)
+                    .await?;
+            } else {
+                let payload = Self::build_payload_from_chat(&chat)?;
+                self.write_payload_to_path(&path, &payload, false, character_name, &cache_key)
+                    .await?;
+                self.remove_summary_cache_for_path(&path).await;
+            }
+            self.journal_compaction.lock().await.update(&cache_key);
+        }
+        self.memory_cache.lock().await.set(cache_key, chat.clone());
 
         Ok(chat)
     }
@@ -334,6 +365,23 @@ impl ChatRepository for FileChatRepository {
         Ok(results)
     }
 
+    async fn search_chats_with_options(
+        &self,
+        options: &ChatSearchOptions,
+        character_filter: Option<&str>,
+    ) -> Result<Vec<ChatSearchMatchResult>, DomainError> {
+        logger::debug("Searching character chat messages with structured options");
+        self.search_chats_with_options_internal(options, character_filter)
+            .await
+    }
+
+    async fn get_character_chat_stats(
+        &self,
+        character_name: &str,
+    ) -> Result<CharacterChatStats, DomainError> {
+        self.get_character_chat_stats_internal(character_name).await
+    }
+
     async fn list_chat_summaries(
         &self,
         character_filter: Option<&str>,
@@ -393,7 +441,7 @@ impl ChatRepository for FileChatRepository {
         ));
 
         let import_type = match format {
-            ChatImportFormat::SillyTavern => "jsonl",
+            ChatImportFormat::SillyTavern | ChatImportFormat::TavernAI => "jsonl",
             _ => "json",
         };
 
@@ -600,6 +648,27 @@ impl ChatRepository for FileChatRepository {
             .await
     }
 
+    async fn get_chat_payload_after_lines(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        cursor: ChatPayloadCursor,
+        max_lines: usize,
+    ) -> Result<ChatPayloadForwardChunk, DomainError> {
+        self.get_character_payload_after_lines(character_name, file_name, cursor, max_lines)
+            .await
+    }
+
+    async fn locate_chat_message(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        query: &ChatMessageLocateQuery,
+    ) -> Result<Option<ChatMessageCursor>, DomainError> {
+        self.locate_character_payload_message(character_name, file_name, query)
+            .await
+    }
+
     async fn save_chat_payload_windowed(
         &self,
         character_name: &str,
@@ -700,6 +769,17 @@ impl ChatRepository for FileChatRepository {
         Ok(())
     }
 
+    async fn detect_chat_import_format(
+        &self,
+        file_path: &Path,
+    ) -> Result<Option<ChatImportFormat>, DomainError> {
+        let file_text = fs::read_to_string(file_path).await.map_err(|e| {
+            DomainError::InternalError(format!("Failed to read chat import file: {}", e))
+        })?;
+
+        Ok(detect_chat_import_format(&file_text))
+    }
+
     async fn import_chat_payload(
         &self,
         character_name: &str,
@@ -727,6 +807,7 @@ impl ChatRepository for FileChatRepository {
                 })?;
                 import_chat_payloads_from_json(&value, user_name, character_display_name)?
             }
+            "auto" => import_chat_payloads_auto(&file_text, user_name, character_display_name)?,
             other => {
                 return Err(DomainError::InvalidData(format!(
                     "Unsupported chat import format: {}",
@@ -794,6 +875,49 @@ impl ChatRepository for FileChatRepository {
             .await
     }
 
+    async fn update_character_chat_metadata(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        fields: Map<String, Value>,
+    ) -> Result<(), DomainError> {
+        let path = self
+            .resolve_character_chat_path(character_name, file_name)
+            .await?;
+        let cache_key = self.get_cache_key(character_name, file_name)?;
+        self.merge_chat_metadata_fields_in_path(&path, fields)
+            .await?;
+
+        {
+            let mut cache = self.memory_cache.lock().await;
+            cache.remove(&cache_key);
+        }
+        Ok(())
+    }
+
+    async fn get_character_chat_variables(
+        &self,
+        character_name: &str,
+        file_name: &str,
+    ) -> Result<Map<String, Value>, DomainError> {
+        let path = self
+            .resolve_character_chat_path(character_name, file_name)
+            .await?;
+        self.read_chat_variables_from_path(&path).await
+    }
+
+    async fn set_character_chat_variables(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        variables: Map<String, Value>,
+    ) -> Result<(), DomainError> {
+        let path = self
+            .resolve_character_chat_path(character_name, file_name)
+            .await?;
+        self.set_chat_variables_in_path(&path, variables).await
+    }
+
     async fn get_character_chat_store_json(
         &self,
         character_name: &str,
@@ -894,6 +1018,34 @@ impl ChatRepository for FileChatRepository {
             .await
     }
 
+    async fn get_character_chat_message(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        index: usize,
+    ) -> Result<Value, DomainError> {
+        self.get_character_chat_message_internal(character_name, file_name, index)
+            .await
+    }
+
+    async fn update_character_chat_message_extra(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        index: usize,
+        swipe_id: Option<usize>,
+        fields: Map<String, Value>,
+    ) -> Result<(), DomainError> {
+        self.update_character_chat_message_extra_internal(
+            character_name,
+            file_name,
+            index,
+            swipe_id,
+            fields,
+        )
+        .await
+    }
+
     async fn search_character_chat_messages(
         &self,
         character_name: &str,
@@ -904,6 +1056,58 @@ impl ChatRepository for FileChatRepository {
             .await
     }
 
+    async fn compress_chat_payloads(
+        &self,
+        options: ChatCompressionOptions,
+    ) -> Result<ChatCompressionReport, DomainError> {
+        self.compress_chat_payloads_internal(options).await
+    }
+
+    async fn archive_old_chats(
+        &self,
+        character_filter: Option<&str>,
+        older_than_days: u32,
+    ) -> Result<ChatArchiveReport, DomainError> {
+        self.archive_old_chats_internal(character_filter, older_than_days)
+            .await
+    }
+
+    async fn unarchive_chat(
+        &self,
+        character_name: &str,
+        file_name: &str,
+    ) -> Result<(), DomainError> {
+        self.unarchive_chat_internal(character_name, file_name)
+            .await
+    }
+
+    async fn list_archived_chats(
+        &self,
+        character_filter: Option<&str>,
+    ) -> Result<Vec<ChatSearchResult>, DomainError> {
+        self.list_archived_chats_internal(character_filter).await
+    }
+
+    async fn rebuild_summary_index(
+        &self,
+        on_progress: &(dyn Fn(usize, usize) + Send + Sync),
+    ) -> Result<ChatIndexRebuildReport, DomainError> {
+        self.rebuild_summary_index_internal(on_progress).await
+    }
+
+    async fn repair_chat(
+        &self,
+        character_name: &str,
+        file_name: &str,
+    ) -> Result<ChatRepairReport, DomainError> {
+        self.repair_character_chat_internal(character_name, file_name)
+            .await
+    }
+
+    async fn replay_chat_journals(&self) -> Result<usize, DomainError> {
+        self.replay_chat_journals_internal().await
+    }
+
     async fn clear_cache(&self) -> Result<(), DomainError> {
         {
             let mut cache = self.memory_cache.lock().await;
@@ -912,6 +1116,14 @@ impl ChatRepository for FileChatRepository {
         self.clear_summary_cache().await;
         Ok(())
     }
+
+    async fn configure_memory_cache(&self, config: MemoryCacheConfig) {
+        self.memory_cache.lock().await.configure(config);
+    }
+
+    async fn memory_cache_stats(&self) -> MemoryCacheStats {
+        self.memory_cache.lock().await.stats("chats")
+    }
 }
 
 impl FileChatRepository {
@@ -4,16 +4,20 @@ use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tokio::fs::{self, File};
+use tokio::fs;
 use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
 
 use crate::domain::errors::DomainError;
 use crate::domain::models::chat::{parse_message_timestamp_value, strip_jsonl_extension};
 use crate::domain::repositories::chat_repository::ChatSearchResult;
 use crate::infrastructure::logging::logger;
-use crate::infrastructure::persistence::file_system::list_files_with_extension;
+use crate::infrastructure::persistence::file_system::{atomic_write, list_files_with_extension};
 
 use super::FileChatRepository;
+use super::chat_stats::ChatFileStats;
+use super::compression::{
+    existing_payload_path, is_compressed_payload, list_chat_payload_files, open_payload_reader,
+};
 
 const INDEX_SCHEMA_VERSION: u32 = 1;
 const FINGERPRINT_WORDS: usize = 64; // 4096 bits
@@ -127,9 +131,13 @@ pub(super) struct SummaryCacheEntry {
     pub signature: FileSignature,
     pub summary: ChatSearchResult,
     pub fingerprint: Option<SearchFingerprint>,
+    /// Message statistics, computed the first time they are asked for.
+    pub stats: Option<ChatFileStats>,
 }
 
 struct SummaryFileScan {
+    /// Plain payload size, which differs from the file size for compressed chats
+    byte_count: u64,
     line_count: usize,
     first_non_empty: Option<String>,
     last_non_empty: Option<String>,
@@ -138,6 +146,7 @@ struct SummaryFileScan {
 
 #[derive(Default)]
 struct SummaryLineByteScan {
+    byte_count: u64,
     line_count: usize,
     first_non_empty: Option<Vec<u8>>,
     last_non_empty: Option<Vec<u8>>,
@@ -172,6 +181,8 @@ struct SummaryIndexSnapshotEntry {
     summary: ChatSearchResult,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     fingerprint: Option<SearchFingerprint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stats: Option<ChatFileStats>,
 }
 
 impl SummaryCache {
@@ -259,6 +270,7 @@ impl SummaryCache {
                     signature: entry.signature,
                     summary: entry.summary,
                     fingerprint,
+                    stats: entry.stats,
                 },
             );
         }
@@ -278,6 +290,7 @@ impl SummaryCache {
                     signature: entry.signature,
                     summary: entry.summary.clone(),
                     fingerprint: entry.fingerprint.clone(),
+                    stats: entry.stats.clone(),
                 })
                 .collect(),
         };
@@ -297,6 +310,17 @@ impl SummaryCache {
         self.dirty = true;
     }
 
+    /// Attach statistics to an entry that still matches `signature`. Search results do
+    /// not depend on statistics, so the search cache is kept.
+    pub(super) fn set_stats(&mut self, key: &str, signature: FileSignature, stats: ChatFileStats) {
+        if let Some(entry) = self.entries.get_mut(key) {
+            if entry.signature == signature {
+                entry.stats = Some(stats);
+                self.dirty = true;
+            }
+        }
+    }
+
     pub(super) fn remove(&mut self, key: &str) {
         if self.entries.remove(key).is_some() {
             self.dirty = true;
@@ -312,6 +336,15 @@ impl SummaryCache {
         self.bump_version();
     }
 
+    /// Forget every entry without reading the index file, which may be the broken part, and
+    /// make sure the next flush rewrites it.
+    pub(super) fn reset(&mut self) {
+        self.entries.clear();
+        self.loaded = true;
+        self.dirty = true;
+        self.bump_version();
+    }
+
     pub(super) fn get_search_results(&self, key: &str) -> Option<Vec<ChatSearchResult>> {
         self.search_cache.get(key).and_then(|entry| {
             if entry.version == self.version {
@@ -344,7 +377,9 @@ pub(super) struct ChatFileDescriptor {
 }
 
 impl FileChatRepository {
-    async fn list_character_chat_directory_keys(&self) -> Result<Vec<String>, DomainError> {
+    pub(super) async fn list_character_chat_directory_keys(
+        &self,
+    ) -> Result<Vec<String>, DomainError> {
         if !self.characters_dir.exists() {
             return Ok(Vec::new());
         }
@@ -454,12 +489,7 @@ impl FileChatRepository {
             })?;
         }
 
-        fs::write(&index_path, bytes).await.map_err(|error| {
-            DomainError::InternalError(format!(
-                "Failed to write chat summary index {:?}: {}",
-                index_path, error
-            ))
-        })?;
+        atomic_write(&index_path, &bytes).await?;
 
         let mut cache = self.summary_cache.lock().await;
         if cache.version() == version {
@@ -477,16 +507,13 @@ impl FileChatRepository {
 
         if let Some(character_name) = character_filter {
             let dir = self.resolve_character_chat_dir(character_name).await?;
-            let files = list_files_with_extension(&dir, "jsonl").await?;
+            let files = list_chat_payload_files(&dir).await?;
             return Ok(files
                 .into_iter()
-                .filter_map(|path| {
-                    let file_name = path.file_name()?.to_str()?.to_string();
-                    Some(ChatFileDescriptor {
-                        character_name: character_name.to_string(),
-                        file_name,
-                        path,
-                    })
+                .map(|(file_name, path)| ChatFileDescriptor {
+                    character_name: character_name.to_string(),
+                    file_name,
+                    path,
                 })
                 .collect());
         }
@@ -494,25 +521,23 @@ impl FileChatRepository {
         let mut descriptors = Vec::new();
         for character_name in self.list_character_chat_directory_keys().await? {
             let path = self.resolve_character_chat_dir(&character_name).await?;
-            let files = list_files_with_extension(&path, "jsonl").await?;
-            descriptors.extend(files.into_iter().filter_map(|file_path| {
-                let file_name = file_path.file_name()?.to_str()?.to_string();
-                Some(ChatFileDescriptor {
+            let files = list_chat_payload_files(&path).await?;
+            descriptors.extend(files.into_iter().map(|(file_name, file_path)| {
+                ChatFileDescriptor {
                     character_name: character_name.clone(),
                     file_name,
                     path: file_path,
-                })
+                }
             }));
         }
 
-        let root_chat_files = list_files_with_extension(&self.chats_dir, "jsonl").await?;
-        descriptors.extend(root_chat_files.into_iter().filter_map(|path| {
-            let file_name = path.file_name()?.to_str()?.to_string();
-            Some(ChatFileDescriptor {
+        let root_chat_files = list_chat_payload_files(&self.chats_dir).await?;
+        descriptors.extend(root_chat_files.into_iter().map(|(file_name, path)| {
+            ChatFileDescriptor {
                 character_name: String::new(),
                 file_name,
                 path,
-            })
+            }
         }));
 
         Ok(descriptors)
@@ -532,10 +557,9 @@ impl FileChatRepository {
 
             let mut descriptors = Vec::new();
             for id in id_set {
-                let path = self.get_group_chat_path(&id)?;
-                if !path.exists() {
+                let Some(path) = existing_payload_path(&self.get_group_chat_path(&id)?) else {
                     continue;
-                }
+                };
                 descriptors.push(ChatFileDescriptor {
                     character_name: String::new(),
                     file_name: Self::normalize_jsonl_file_name(&id)?,
@@ -545,16 +569,13 @@ impl FileChatRepository {
             return Ok(descriptors);
         }
 
-        let files = list_files_with_extension(&self.group_chats_dir, "jsonl").await?;
+        let files = list_chat_payload_files(&self.group_chats_dir).await?;
         Ok(files
             .into_iter()
-            .filter_map(|path| {
-                let file_name = path.file_name()?.to_str()?.to_string();
-                Some(ChatFileDescriptor {
-                    character_name: String::new(),
-                    file_name,
-                    path,
-                })
+            .map(|(file_name, path)| ChatFileDescriptor {
+                character_name: String::new(),
+                file_name,
+                path,
             })
             .collect())
     }
@@ -675,7 +696,7 @@ impl FileChatRepository {
     ) -> Result<ChatSearchResult, DomainError> {
         self.ensure_directory_exists().await?;
 
-        let path = self.get_group_chat_path(chat_id)?;
+        let path = self.resolve_group_chat_path(chat_id).await?;
         if !path.exists() {
             return Err(DomainError::NotFound(format!(
                 "Group chat not found: {}",
@@ -722,10 +743,7 @@ impl FileChatRepository {
             return Ok(true);
         }
 
-        let file = File::open(path).await.map_err(|error| {
-            DomainError::InternalError(format!("Failed to open chat file {:?}: {}", path, error))
-        })?;
-        let reader = BufReader::new(file);
+        let reader = BufReader::new(open_payload_reader(path).await?);
         let mut lines = reader.lines();
 
         while let Some(line) = lines.next_line().await.map_err(|error| {
@@ -769,7 +787,7 @@ impl FileChatRepository {
             .collect()
     }
 
-    fn summary_cache_key(path: &Path) -> String {
+    pub(super) fn summary_cache_key(path: &Path) -> String {
         path.to_string_lossy().to_string()
     }
 
@@ -858,8 +876,10 @@ impl FileChatRepository {
                 date,
                 chat_id,
                 chat_metadata: metadata,
+                uncompressed_size: is_compressed_payload(path).then_some(scan.byte_count),
             },
             fingerprint: scan.fingerprint,
+            stats: None,
         })
     }
 
@@ -867,11 +887,9 @@ impl FileChatRepository {
         path: &Path,
         fallback_file_name: &str,
     ) -> Result<SummaryFileScan, DomainError> {
-        let file = File::open(path).await.map_err(|error| {
-            DomainError::InternalError(format!("Failed to open chat file {:?}: {}", path, error))
-        })?;
-        let mut reader = BufReader::new(file);
+        let mut reader = BufReader::new(open_payload_reader(path).await?);
 
+        let mut byte_count: u64 = 0;
         let mut line_count: usize = 0;
         let mut first_non_empty: Option<String> = None;
         let mut last_non_empty = String::new();
@@ -891,6 +909,7 @@ impl FileChatRepository {
             if bytes_read == 0 {
                 break;
             }
+            byte_count += bytes_read as u64;
 
             let line_text = line.trim_end_matches(|ch| ch == '\r' || ch == '\n');
             if line_text.trim().is_empty() {
@@ -908,6 +927,7 @@ impl FileChatRepository {
         }
 
         Ok(SummaryFileScan {
+            byte_count,
             line_count,
             first_non_empty,
             last_non_empty: has_last_non_empty.then_some(last_non_empty),
@@ -921,6 +941,7 @@ impl FileChatRepository {
         let bytes = Self::scan_summary_line_bytes(path).await?;
 
         Ok(SummaryFileScan {
+            byte_count: bytes.byte_count,
             line_count: bytes.line_count,
             first_non_empty: Self::decode_summary_line(
                 path,
@@ -937,9 +958,7 @@ impl FileChatRepository {
     }
 
     async fn scan_summary_line_bytes(path: &Path) -> Result<SummaryLineByteScan, DomainError> {
-        let mut file = File::open(path).await.map_err(|error| {
-            DomainError::InternalError(format!("Failed to open chat file {:?}: {}", path, error))
-        })?;
+        let mut file = open_payload_reader(path).await?;
 
         let mut buffer = vec![0u8; SUMMARY_SCAN_BUFFER_BYTES];
         let mut current_line = Vec::new();
@@ -955,6 +974,7 @@ impl FileChatRepository {
             if bytes_read == 0 {
                 break;
             }
+            scan.byte_count += bytes_read as u64;
 
             for &byte in &buffer[..bytes_read] {
                 if byte == b'\n' {
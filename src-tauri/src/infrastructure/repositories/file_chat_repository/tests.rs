@@ -6,10 +6,12 @@ use serde_json::{Value, json};
 use tokio::fs;
 
 use crate::domain::errors::DomainError;
+use crate::domain::models::chat::ChatMessage;
 use crate::domain::models::filename::sanitize_filename;
 use crate::domain::repositories::chat_repository::{
-    ChatMessageRole, ChatMessageSearchFilters, ChatMessageSearchQuery, ChatPayloadPatchOp,
-    ChatRepository, PinnedCharacterChat, PinnedGroupChat,
+    ChatCompressionOptions, ChatMessageLocateQuery, ChatMessageRole, ChatMessageSearchFilters,
+    ChatMessageSearchQuery, ChatPayloadPatchOp, ChatRepository, ChatSearchHighlight,
+    ChatSearchOptions, PinnedCharacterChat, PinnedGroupChat,
 };
 use crate::domain::repositories::group_chat_repository::GroupChatRepository;
 use crate::infrastructure::repositories::chat_directory_identity::new_shared_chat_alias_store_for_user_dir;
@@ -1306,6 +1308,148 @@ async fn list_chat_summaries_returns_streamed_metadata() {
     let _ = fs::remove_dir_all(&root).await;
 }
 
+#[tokio::test]
+async fn compressed_chats_are_listed_searched_and_inflated_on_open() {
+    let (repository, root) = setup_repository().await;
+    let characters_dir = root.join("characters");
+    fs::create_dir_all(&characters_dir)
+        .await
+        .expect("create characters directory");
+    fs::write(characters_dir.join("alice.png"), b"")
+        .await
+        .expect("create character card");
+
+    let mut payload = payload_with_integrity("compressed");
+    for index in 0..200 {
+        payload.push(json!({
+            "name": "Alice",
+            "is_user": false,
+            "send_date": "2026-01-02T00:00:00.000Z",
+            "mes": format!("repeated message number {index} about lighthouses"),
+            "extra": {},
+        }));
+    }
+    save_chat_payload_from_values(&repository, &root, "alice", "session", &payload, false)
+        .await
+        .expect("save payload");
+    let plain_path = root.join("chats").join("alice").join("session.jsonl");
+    let plain_bytes = fs::read(&plain_path).await.expect("read plain payload");
+
+    let report = repository
+        .compress_chat_payloads(ChatCompressionOptions {
+            min_size_bytes: 0,
+            min_idle_hours: 0,
+        })
+        .await
+        .expect("compress chats");
+    assert_eq!(report.compressed, 1);
+    assert_eq!(report.bytes_before, plain_bytes.len() as u64);
+    assert!(report.bytes_after < report.bytes_before);
+    assert!(!plain_path.exists());
+    assert!(plain_path.with_file_name("session.jsonl.zst").exists());
+
+    let summaries = repository
+        .list_chat_summaries(Some("alice"), false)
+        .await
+        .expect("list chat summaries");
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0].file_name, "session.jsonl");
+    assert_eq!(summaries[0].message_count, 201);
+    assert_eq!(summaries[0].file_size, report.bytes_after);
+    assert_eq!(
+        summaries[0].uncompressed_size,
+        Some(plain_bytes.len() as u64)
+    );
+
+    let hits = repository
+        .search_chats("lighthouses", Some("alice"))
+        .await
+        .expect("search chats");
+    assert_eq!(hits.len(), 1);
+
+    let bytes = repository
+        .get_chat_payload_bytes("alice", "session")
+        .await
+        .expect("read inflated payload");
+    assert_eq!(bytes, plain_bytes);
+    assert!(plain_path.exists());
+    assert!(!plain_path.with_file_name("session.jsonl.zst").exists());
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
+#[tokio::test]
+async fn old_chats_are_archived_searchable_and_restored() {
+    let (repository, root) = setup_repository().await;
+    let chat_dir = root.join("chats").join("alice");
+    let now = std::time::SystemTime::now();
+
+    for (file_name, idle_days) in [("current", 0), ("old", 60), ("older", 90)] {
+        let mut payload = payload_with_integrity(file_name);
+        payload[1]["mes"] = json!(format!("{file_name} chat about lighthouses"));
+        save_chat_payload_from_values(&repository, &root, "alice", file_name, &payload, false)
+            .await
+            .expect("save payload");
+        let modified = now - std::time::Duration::from_secs(idle_days * 24 * 3600);
+        std::fs::File::options()
+            .write(true)
+            .open(chat_dir.join(format!("{file_name}.jsonl")))
+            .and_then(|file| file.set_modified(modified))
+            .expect("set chat mtime");
+    }
+
+    let report = repository
+        .archive_old_chats(Some("alice"), 30)
+        .await
+        .expect("archive chats");
+    assert_eq!(report.scanned, 3);
+    assert_eq!(report.archived, 2);
+    assert!(chat_dir.join("current.jsonl").exists());
+    assert!(chat_dir.join("archive").join("old.jsonl").exists());
+
+    let summaries = repository
+        .list_chat_summaries(Some("alice"), false)
+        .await
+        .expect("list chat summaries");
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0].file_name, "current.jsonl");
+
+    let archived = repository
+        .list_archived_chats(Some("alice"))
+        .await
+        .expect("list archived chats");
+    assert_eq!(archived.len(), 2);
+
+    let search = |include_archived| ChatSearchOptions {
+        query: "lighthouses".to_string(),
+        include_archived,
+        ..ChatSearchOptions::default()
+    };
+    let hits = repository
+        .search_chats_with_options(&search(false), Some("alice"))
+        .await
+        .expect("search active chats");
+    assert_eq!(hits.len(), 1);
+    let hits = repository
+        .search_chats_with_options(&search(true), Some("alice"))
+        .await
+        .expect("search with archive");
+    assert_eq!(hits.len(), 3);
+    assert_eq!(hits.iter().filter(|hit| hit.archived).count(), 2);
+
+    repository
+        .unarchive_chat("alice", "old")
+        .await
+        .expect("unarchive chat");
+    assert!(chat_dir.join("old.jsonl").exists());
+    assert!(matches!(
+        repository.unarchive_chat("alice", "old").await,
+        Err(DomainError::NotFound(_))
+    ));
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
 #[tokio::test]
 async fn list_chat_summaries_counts_large_crlf_jsonl_without_fingerprint() {
     let (repository, root) = setup_repository().await;
@@ -1645,6 +1789,74 @@ async fn read_group_chat_messages_uses_message_indexes_without_header() {
     let _ = fs::remove_dir_all(&root).await;
 }
 
+#[tokio::test]
+async fn update_group_chat_message_extra_merges_into_message_and_swipe() {
+    let (repository, root) = setup_repository().await;
+
+    let payload = vec![
+        json!({
+            "chat_metadata": {},
+            "user_name": "unused",
+            "character_name": "unused",
+        }),
+        json!({
+            "name": "User",
+            "is_user": true,
+            "mes": "hello",
+            "extra": {},
+        }),
+        json!({
+            "name": "Alice",
+            "is_user": false,
+            "mes": "second swipe",
+            "extra": { "api": "openai" },
+            "swipes": ["first swipe", "second swipe"],
+            "swipe_info": [{ "extra": {} }, { "extra": {} }],
+        }),
+    ];
+
+    save_group_chat_payload_from_values(&repository, &root, "group-extra", &payload, false)
+        .await
+        .expect("save group payload");
+
+    let mut fields = serde_json::Map::new();
+    fields.insert("tauritavern_generation".to_string(), json!({ "seed": 7 }));
+    repository
+        .update_group_chat_message_extra("group-extra", 1, Some(1), fields)
+        .await
+        .expect("update message extra");
+
+    let message = repository
+        .get_group_chat_message("group-extra", 1)
+        .await
+        .expect("read message");
+    assert_eq!(message["extra"]["api"], "openai");
+    assert_eq!(message["extra"]["tauritavern_generation"]["seed"], 7);
+    assert_eq!(
+        message["swipe_info"][1]["extra"]["tauritavern_generation"]["seed"],
+        7
+    );
+    assert!(
+        message["swipe_info"][0]["extra"]
+            .as_object()
+            .unwrap()
+            .is_empty()
+    );
+
+    let first = repository
+        .get_group_chat_message("group-extra", 0)
+        .await
+        .expect("read untouched message");
+    assert_eq!(first["mes"], "hello");
+
+    let missing = repository
+        .update_group_chat_message_extra("group-extra", 2, None, serde_json::Map::new())
+        .await;
+    assert!(matches!(missing, Err(DomainError::NotFound(_))));
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
 #[tokio::test]
 async fn search_group_chat_messages_respects_scan_limit() {
     let (repository, root) = setup_repository().await;
@@ -1793,6 +2005,178 @@ async fn summary_cache_is_invalidated_after_payload_save() {
     let _ = fs::remove_dir_all(&root).await;
 }
 
+#[tokio::test]
+async fn search_chats_with_options_filters_messages_and_reports_highlights() {
+    let (repository, root) = setup_repository().await;
+
+    let payload = vec![
+        json!({
+            "chat_metadata": {
+                "chat_id_hash": 600,
+            },
+            "user_name": "User",
+            "character_name": "Alice",
+        }),
+        json!({
+            "name": "User",
+            "is_user": true,
+            "send_date": "2026-01-01T00:00:00.000Z",
+            "mes": "Tell me about the Dragon.",
+            "extra": {},
+        }),
+        json!({
+            "name": "Alice",
+            "is_user": false,
+            "send_date": "2026-01-05T00:00:00.000Z",
+            "mes": "The dragon sleeps under the dragon hill.",
+            "extra": {},
+        }),
+        json!({
+            "name": "Alice",
+            "is_user": false,
+            "send_date": "2026-02-01T00:00:00.000Z",
+            "mes": "Another dragon tale.",
+            "extra": {},
+        }),
+    ];
+    save_chat_payload_from_values(&repository, &root, "alice", "session", &payload, false)
+        .await
+        .expect("save payload");
+
+    let results = repository
+        .search_chats_with_options(
+            &ChatSearchOptions {
+                query: "DRAGON".to_string(),
+                role: Some(ChatMessageRole::Assistant),
+                date_to: Some(1_768_000_000_000),
+                ..ChatSearchOptions::default()
+            },
+            Some("alice"),
+        )
+        .await
+        .expect("search with options");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].match_count, 1);
+    let hit = &results[0].matches[0];
+    assert_eq!(hit.index, 1);
+    assert_eq!(hit.role, ChatMessageRole::Assistant);
+    assert_eq!(
+        hit.highlights,
+        vec![
+            ChatSearchHighlight { start: 4, end: 10 },
+            ChatSearchHighlight { start: 28, end: 34 },
+        ]
+    );
+
+    let regex_results = repository
+        .search_chats_with_options(
+            &ChatSearchOptions {
+                query: r"dragon\.$".to_string(),
+                regex: true,
+                ..ChatSearchOptions::default()
+            },
+            Some("alice"),
+        )
+        .await
+        .expect("search with regex");
+    assert_eq!(regex_results[0].match_count, 1);
+    assert_eq!(regex_results[0].matches[0].index, 0);
+
+    let too_few = repository
+        .search_chats_with_options(
+            &ChatSearchOptions {
+                query: "dragon".to_string(),
+                min_match_count: Some(4),
+                ..ChatSearchOptions::default()
+            },
+            Some("alice"),
+        )
+        .await
+        .expect("search with minimum match count");
+    assert!(too_few.is_empty());
+
+    assert!(
+        repository
+            .search_chats_with_options(
+                &ChatSearchOptions {
+                    query: "(".to_string(),
+                    regex: true,
+                    ..ChatSearchOptions::default()
+                },
+                None,
+            )
+            .await
+            .is_err()
+    );
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
+#[tokio::test]
+async fn character_chat_stats_aggregate_chats_and_reuse_summary_index() {
+    let (repository, root) = setup_repository().await;
+
+    let header = json!({
+        "chat_metadata": {},
+        "user_name": "User",
+        "character_name": "Alice",
+    });
+    let message = |is_user: bool, send_date: &str, mes: &str| {
+        json!({
+            "name": if is_user { "User" } else { "Alice" },
+            "is_user": is_user,
+            "send_date": send_date,
+            "mes": mes,
+            "extra": {},
+        })
+    };
+    let first = vec![
+        header.clone(),
+        message(true, "2026-01-01T12:00:00.000Z", "hello there"),
+        message(false, "2026-01-01T12:01:00.000Z", "hi, how are you"),
+        message(true, "2026-01-02T12:00:00.000Z", "fine"),
+    ];
+    let second = vec![
+        header,
+        message(false, "2026-01-05T12:00:00.000Z", "welcome back"),
+    ];
+    save_chat_payload_from_values(&repository, &root, "alice", "first", &first, false)
+        .await
+        .expect("save first chat");
+    save_chat_payload_from_values(&repository, &root, "alice", "second", &second, false)
+        .await
+        .expect("save second chat");
+
+    let stats = repository
+        .get_character_chat_stats("alice")
+        .await
+        .expect("chat stats");
+    assert_eq!(stats.chat_count, 2);
+    assert_eq!(stats.total_messages, 4);
+    assert_eq!(stats.user_messages, 2);
+    assert_eq!(stats.assistant_messages, 2);
+    assert_eq!(stats.user_words, 3);
+    assert_eq!(stats.assistant_words, 6);
+    assert_eq!(stats.days_chatted, 3);
+    assert_eq!(stats.longest_streak_days, 2);
+    assert_eq!(
+        stats
+            .longest_chat
+            .as_ref()
+            .map(|chat| chat.file_name.as_str()),
+        Some("first.jsonl")
+    );
+
+    let cached = repository
+        .get_character_chat_stats("alice")
+        .await
+        .expect("cached chat stats");
+    assert_eq!(cached.user_words, stats.user_words);
+    assert_eq!(cached.first_message_date, stats.first_message_date);
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
 #[tokio::test]
 async fn search_cache_is_invalidated_when_new_chat_file_is_saved() {
     let (repository, root) = setup_repository().await;
@@ -1912,6 +2296,75 @@ async fn search_cache_is_invalidated_after_import_chat_payload() {
     let _ = fs::remove_dir_all(&root).await;
 }
 
+#[tokio::test]
+async fn rebuild_summary_index_replaces_corrupted_index() {
+    let (repository, root) = setup_repository().await;
+    let characters_dir = root.join("characters");
+    fs::create_dir_all(&characters_dir)
+        .await
+        .expect("create characters directory");
+    for character_name in ["alice", "bob"] {
+        fs::write(characters_dir.join(format!("{character_name}.png")), b"")
+            .await
+            .expect("create character card");
+        let payload = payload_with_integrity(character_name);
+        save_chat_payload_from_values(
+            &repository,
+            &root,
+            character_name,
+            "session",
+            &payload,
+            false,
+        )
+        .await
+        .expect("save payload");
+    }
+    fs::write(
+        root.join("group chats").join("party.jsonl"),
+        payload_to_jsonl(&payload_with_integrity("party")),
+    )
+    .await
+    .expect("write group chat");
+
+    let index_path = root
+        .join("user")
+        .join("cache")
+        .join("chat_summary_index_v1.json");
+    fs::create_dir_all(index_path.parent().unwrap())
+        .await
+        .expect("create cache dir");
+    fs::write(&index_path, b"{\"schema_version\":")
+        .await
+        .expect("corrupt index");
+
+    let repository = repository_for_root(&root);
+    let progress = std::sync::Mutex::new(Vec::new());
+    let report = repository
+        .rebuild_summary_index(&|scanned, total| progress.lock().unwrap().push((scanned, total)))
+        .await
+        .expect("rebuild index");
+
+    assert_eq!(report.character_chats, 2);
+    assert_eq!(report.group_chats, 1);
+    assert_eq!(report.failed, 0);
+    let progress = progress.into_inner().unwrap();
+    assert_eq!(progress.first(), Some(&(0, 3)));
+    assert_eq!(progress.last(), Some(&(3, 3)));
+
+    let persisted: Value =
+        serde_json::from_slice(&fs::read(&index_path).await.expect("read index"))
+            .expect("rebuilt index is valid JSON");
+    assert_eq!(
+        persisted
+            .get("entries")
+            .and_then(Value::as_array)
+            .map(|entries| entries.len()),
+        Some(3)
+    );
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
 #[tokio::test]
 async fn summary_index_is_persisted_and_reloaded() {
     let (repository, root) = setup_repository().await;
@@ -3375,3 +3828,415 @@ async fn repro_windowed_to_full_mode_switch_data_loss() {
 
     let _ = fs::remove_dir_all(&root).await;
 }
+
+#[tokio::test]
+async fn chat_variables_roundtrip_through_header_rewrite() {
+    let (repository, root) = setup_repository().await;
+
+    let raw_payload = payload_to_jsonl(&payload_with_integrity("variables"));
+    let source = root.join("variables-source.jsonl");
+    fs::write(&source, &raw_payload)
+        .await
+        .expect("write chat source payload");
+    repository
+        .save_chat_payload_from_path("alice", "session", &source, false)
+        .await
+        .expect("save payload from source file");
+
+    let variables = repository
+        .get_character_chat_variables("alice", "session")
+        .await
+        .expect("read empty variables");
+    assert!(variables.is_empty());
+
+    let Value::Object(variables) = json!({ "mood": "calm", "turns": 5 }) else {
+        unreachable!();
+    };
+    repository
+        .set_character_chat_variables("alice", "session", variables.clone())
+        .await
+        .expect("write variables");
+
+    let loaded = repository
+        .get_character_chat_variables("alice", "session")
+        .await
+        .expect("read variables");
+    assert_eq!(loaded, variables);
+
+    let chat = repository
+        .get_chat("alice", "session")
+        .await
+        .expect("load chat with numeric variables");
+    assert_eq!(chat.messages.len(), 1);
+    assert_eq!(chat.chat_metadata.integrity.as_deref(), Some("variables"));
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
+#[tokio::test]
+async fn chat_metadata_field_merge_sets_and_removes_fields() {
+    let (repository, root) = setup_repository().await;
+
+    let raw_payload = payload_to_jsonl(&payload_with_integrity("note"));
+    let source = root.join("note-source.jsonl");
+    fs::write(&source, &raw_payload)
+        .await
+        .expect("write chat source payload");
+    repository
+        .save_chat_payload_from_path("alice", "session", &source, false)
+        .await
+        .expect("save payload from source file");
+
+    let Value::Object(fields) = json!({ "note_prompt": "Stay in character.", "note_depth": 2 })
+    else {
+        unreachable!();
+    };
+    repository
+        .update_character_chat_metadata("alice", "session", fields)
+        .await
+        .expect("merge note fields");
+
+    let metadata = repository
+        .get_character_chat_metadata("alice", "session")
+        .await
+        .expect("read metadata");
+    assert_eq!(metadata["note_prompt"], json!("Stay in character."));
+    assert_eq!(metadata["note_depth"], json!(2));
+    assert_eq!(metadata["integrity"], json!("note"));
+
+    let Value::Object(fields) = json!({ "note_prompt": null }) else {
+        unreachable!();
+    };
+    repository
+        .update_character_chat_metadata("alice", "session", fields)
+        .await
+        .expect("remove note prompt");
+
+    let metadata = repository
+        .get_character_chat_metadata("alice", "session")
+        .await
+        .expect("read metadata");
+    assert!(metadata.get("note_prompt").is_none());
+    assert_eq!(metadata["note_depth"], json!(2));
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
+#[tokio::test]
+async fn locate_chat_message_pages_in_both_directions() {
+    let (repository, root) = setup_repository().await;
+    let character_name = "alice";
+    let file_name = "session";
+
+    let mut payload = vec![payload_with_integrity("locate-a")[0].clone()];
+    for index in 0..6 {
+        payload.push(json!({
+            "name": "Alice",
+            "is_user": false,
+            "send_date": format!("2026-01-01T00:00:0{}.000Z", index),
+            "mes": format!("message {}", index),
+        }));
+    }
+    save_chat_payload_from_values(
+        &repository,
+        &root,
+        character_name,
+        file_name,
+        &payload,
+        false,
+    )
+    .await
+    .expect("save payload");
+
+    let located = repository
+        .locate_chat_message(
+            character_name,
+            file_name,
+            &ChatMessageLocateQuery {
+                message_id: Some(2),
+                send_date: None,
+            },
+        )
+        .await
+        .expect("locate by id")
+        .expect("message 2 exists");
+    assert_eq!(located.index, 2);
+
+    let forward = repository
+        .get_chat_payload_after_lines(character_name, file_name, located.cursor, 2)
+        .await
+        .expect("page forward");
+    assert_eq!(forward.lines.len(), 2);
+    assert!(forward.lines[0].contains("message 2"));
+    assert!(forward.has_more_after);
+
+    let rest = repository
+        .get_chat_payload_after_lines(character_name, file_name, forward.cursor, 10)
+        .await
+        .expect("page forward to end");
+    assert_eq!(rest.lines.len(), 2);
+    assert!(rest.lines[1].contains("message 5"));
+    assert!(!rest.has_more_after);
+
+    let before = repository
+        .get_chat_payload_before_lines(character_name, file_name, located.cursor, 10)
+        .await
+        .expect("page backward");
+    assert_eq!(before.lines.len(), 2);
+    assert!(before.lines[0].contains("message 0"));
+    assert!(!before.has_more_before);
+
+    let by_date = repository
+        .locate_chat_message(
+            character_name,
+            file_name,
+            &ChatMessageLocateQuery {
+                message_id: None,
+                send_date: Some(
+                    chrono::DateTime::parse_from_rfc3339("2026-01-01T00:00:03.500Z")
+                        .expect("parse date")
+                        .timestamp_millis(),
+                ),
+            },
+        )
+        .await
+        .expect("locate by date")
+        .expect("a later message exists");
+    assert_eq!(by_date.index, 4);
+
+    let missing = repository
+        .locate_chat_message(
+            character_name,
+            file_name,
+            &ChatMessageLocateQuery {
+                message_id: Some(6),
+                send_date: None,
+            },
+        )
+        .await
+        .expect("locate past end");
+    assert!(missing.is_none());
+
+    let ambiguous = repository
+        .locate_chat_message(
+            character_name,
+            file_name,
+            &ChatMessageLocateQuery::default(),
+        )
+        .await;
+    assert!(matches!(ambiguous, Err(DomainError::InvalidData(_))));
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
+#[tokio::test]
+async fn repair_chat_removes_corrupted_lines_and_duplicates() {
+    let (repository, root) = setup_repository().await;
+    let character_name = "alice";
+    let file_name = "session";
+    let payload = payload_with_integrity("repair-a");
+    save_chat_payload_from_values(
+        &repository,
+        &root,
+        character_name,
+        file_name,
+        &payload,
+        false,
+    )
+    .await
+    .expect("save payload");
+
+    let path = repository
+        .get_chat_payload_path(character_name, file_name)
+        .await
+        .expect("payload path");
+    let header = serde_json::to_string(&payload[0]).expect("serialize header");
+    let hello =
+        r#"{"name":"User","is_user":true,"send_date":"2026-01-01T00:00:00.000Z","mes":"hello"}"#;
+    let corrupted = format!(
+        "{header}\n{hello}\n{hello}\n{{\"name\":\"Alice\",\"mes\":\"tru\n\n{{\"name\":\"Alice\",\"is_user\":false,\"mes\":\"hi\"}}\n"
+    );
+    fs::write(&path, corrupted)
+        .await
+        .expect("write corrupted payload");
+
+    let report = repository
+        .repair_chat(character_name, file_name)
+        .await
+        .expect("repair chat");
+    assert_eq!(report.messages, 2);
+    assert_eq!(report.invalid_lines_removed, 1);
+    assert_eq!(report.blank_lines_removed, 1);
+    assert_eq!(report.duplicates_removed, 1);
+    assert_eq!(report.send_dates_fixed, 1);
+    assert_eq!(report.bad_lines_file.as_deref(), Some("session.jsonl.bad"));
+
+    let bad = fs::read_to_string(path.with_file_name("session.jsonl.bad"))
+        .await
+        .expect("read bad lines");
+    assert_eq!(bad, "{\"name\":\"Alice\",\"mes\":\"tru\n");
+
+    let repaired = fs::read_to_string(&path).await.expect("read repaired");
+    let lines = repaired.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[1], hello);
+    let fixed: Value = serde_json::from_str(lines[2]).expect("parse fixed message");
+    assert_eq!(fixed["send_date"], json!("2026-01-01T00:00:00.000Z"));
+
+    let again = repository
+        .repair_chat(character_name, file_name)
+        .await
+        .expect("repair clean chat");
+    assert!(!again.changed());
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
+#[tokio::test]
+async fn add_message_journal_is_replayed_after_restart() {
+    let (repository, root) = setup_repository().await;
+    let character_name = "alice";
+    let file_name = "session";
+    save_chat_payload_from_values(
+        &repository,
+        &root,
+        character_name,
+        file_name,
+        &payload_with_integrity("journal-a"),
+        false,
+    )
+    .await
+    .expect("save payload");
+    let path = repository
+        .get_chat_payload_path(character_name, file_name)
+        .await
+        .expect("payload path");
+    let journal = path.with_file_name("session.jsonl.journal");
+
+    repository
+        .add_message(
+            character_name,
+            file_name,
+            ChatMessage::user("User", "first"),
+        )
+        .await
+        .expect("add first message");
+    assert!(!journal.exists(), "the first add rewrites the payload");
+
+    let chat = repository
+        .add_message(
+            character_name,
+            file_name,
+            ChatMessage::character("Alice", "second"),
+        )
+        .await
+        .expect("add second message");
+    assert_eq!(chat.messages.len(), 3);
+    assert!(journal.exists(), "a burst of adds is only journaled");
+    let on_disk = fs::read_to_string(&path).await.expect("read payload");
+    assert_eq!(on_disk.lines().count(), 3);
+
+    // A journal entry that already reached the payload must not be duplicated.
+    let stale = json!({ "index": 1, "message": { "name": "User", "mes": "first" } });
+    let mut journal_text = format!("{}\n", stale);
+    journal_text.push_str(&fs::read_to_string(&journal).await.expect("read journal"));
+    fs::write(&journal, journal_text)
+        .await
+        .expect("write journal");
+
+    let restarted = repository_for_root(&root);
+    assert_eq!(
+        restarted
+            .replay_chat_journals()
+            .await
+            .expect("replay journals"),
+        1
+    );
+    assert!(!journal.exists());
+    let on_disk = fs::read_to_string(&path).await.expect("read payload");
+    let lines = on_disk.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    assert!(lines[2].contains("\"first\""));
+    assert!(lines[3].contains("\"second\""));
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
+#[tokio::test]
+async fn orphaned_chat_journal_is_removed_and_counted() {
+    let (repository, root) = setup_repository().await;
+    let chat_dir = root.join("chats").join("alice");
+    fs::create_dir_all(&chat_dir)
+        .await
+        .expect("create chat dir");
+    let journal = chat_dir.join("gone.jsonl.journal");
+    let entry = json!({ "index": 1, "message": { "name": "User", "mes": "lost" } });
+    fs::write(&journal, format!("{}\n", entry))
+        .await
+        .expect("write journal");
+
+    assert_eq!(
+        repository
+            .replay_chat_journals()
+            .await
+            .expect("replay journals"),
+        1
+    );
+    assert!(!journal.exists());
+
+    let _ = fs::remove_dir_all(&root).await;
+}
+
+#[tokio::test]
+async fn add_message_appends_when_header_is_current() {
+    let (repository, root) = setup_repository().await;
+    let character_name = "alice";
+    let file_name = "session";
+    save_chat_payload_from_values(
+        &repository,
+        &root,
+        character_name,
+        file_name,
+        &payload_with_integrity("append-a"),
+        false,
+    )
+    .await
+    .expect("save payload");
+    let path = repository
+        .get_chat_payload_path(character_name, file_name)
+        .await
+        .expect("payload path");
+
+    // The first add rewrites the file to bring the header up to date.
+    repository
+        .add_message(
+            character_name,
+            file_name,
+            ChatMessage::user("User", "first"),
+        )
+        .await
+        .expect("add first message");
+
+    // A header key the chat model does not know would be lost by a full rewrite.
+    let before = fs::read_to_string(&path).await.expect("read payload");
+    let before = before.replacen('{', "{\"custom\":1,", 1);
+    fs::write(&path, &before).await.expect("write payload");
+
+    let restarted = repository_for_root(&root);
+    restarted
+        .add_message(
+            character_name,
+            file_name,
+            ChatMessage::character("Alice", "second"),
+        )
+        .await
+        .expect("add second message");
+
+    let after = fs::read_to_string(&path).await.expect("read payload");
+    assert!(after.starts_with(&before));
+    let appended = after[before.len()..].lines().collect::<Vec<_>>();
+    assert_eq!(appended.len(), 1);
+    assert!(appended[0].contains("\"second\""));
+
+    let _ = fs::remove_dir_all(&root).await;
+}
@@ -64,7 +64,7 @@ impl FileChatRepository {
     ) -> Result<ChatPayloadCursor, DomainError> {
         self.ensure_directory_exists().await?;
 
-        let path = self.get_group_chat_path(chat_id)?;
+        let path = self.resolve_group_chat_path(chat_id).await?;
         let _write_guard = self.acquire_payload_write_lock(&path).await;
         let backup_key = Self::get_group_backup_key(chat_id)?;
         let result = hide_payload_before_cursor_internal(
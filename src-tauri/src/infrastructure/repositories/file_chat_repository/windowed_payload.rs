@@ -1,13 +1,18 @@
 use std::path::{Path, PathBuf};
 use std::str;
 
+use serde::Deserialize;
 use serde_json::Value;
 use tokio::fs::{self, File, OpenOptions};
-use tokio::io::{self, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, SeekFrom};
+use tokio::io::{
+    self, AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader, SeekFrom,
+};
 
 use crate::domain::errors::DomainError;
+use crate::domain::models::chat::parse_message_timestamp_value;
 use crate::domain::repositories::chat_repository::{
-    ChatPayloadChunk, ChatPayloadCursor, ChatPayloadTail,
+    ChatMessageCursor, ChatMessageLocateQuery, ChatPayloadChunk, ChatPayloadCursor,
+    ChatPayloadForwardChunk, ChatPayloadTail,
 };
 use crate::infrastructure::logging::logger;
 
@@ -136,6 +141,42 @@ async fn read_tail_lines_with_offsets(
     Ok(lines)
 }
 
+/// Opens the payload positioned at `offset` for line-by-line forward reads.
+async fn open_payload_lines_at(path: &Path, offset: u64) -> Result<BufReader<File>, DomainError> {
+    let mut file = open_existing_payload_file(path).await?;
+    file.seek(SeekFrom::Start(offset)).await.map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to seek chat payload file {:?}: {}",
+            path, error
+        ))
+    })?;
+    Ok(BufReader::new(file))
+}
+
+/// Reads the next raw JSONL line into `buf`, returning the number of bytes consumed
+/// (0 at EOF). Blank lines are rejected like in the backward reader.
+async fn read_next_payload_line(
+    reader: &mut BufReader<File>,
+    buf: &mut Vec<u8>,
+    path: &Path,
+    offset: u64,
+) -> Result<usize, DomainError> {
+    buf.clear();
+    let read = reader.read_until(b'\n', buf).await.map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to read chat payload file {:?}: {}",
+            path, error
+        ))
+    })?;
+    if read > 0 && buf.iter().all(|byte| byte.is_ascii_whitespace()) {
+        return Err(DomainError::InvalidData(format!(
+            "Chat payload contains blank JSONL line at offset {} for {:?}",
+            offset, path
+        )));
+    }
+    Ok(read)
+}
+
 impl FileChatRepository {
     pub(super) async fn get_character_payload_tail_lines(
         &self,
@@ -162,6 +203,31 @@ impl FileChatRepository {
         read_payload_before_lines(&path, cursor, max_lines).await
     }
 
+    pub(super) async fn get_character_payload_after_lines(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        cursor: ChatPayloadCursor,
+        max_lines: usize,
+    ) -> Result<ChatPayloadForwardChunk, DomainError> {
+        let path = self
+            .resolve_character_chat_path(character_name, file_name)
+            .await?;
+        read_payload_after_lines(&path, cursor, max_lines).await
+    }
+
+    pub(super) async fn locate_character_payload_message(
+        &self,
+        character_name: &str,
+        file_name: &str,
+        query: &ChatMessageLocateQuery,
+    ) -> Result<Option<ChatMessageCursor>, DomainError> {
+        let path = self
+            .resolve_character_chat_path(character_name, file_name)
+            .await?;
+        locate_payload_message(&path, query).await
+    }
+
     pub(super) async fn save_character_payload_windowed(
         &self,
         character_name: &str,
@@ -215,7 +281,7 @@ impl FileChatRepository {
         chat_id: &str,
         max_lines: usize,
     ) -> Result<ChatPayloadTail, DomainError> {
-        let path = self.get_group_chat_path(chat_id)?;
+        let path = self.resolve_group_chat_path(chat_id).await?;
         read_payload_tail_lines(&path, max_lines).await
     }
 
@@ -225,10 +291,29 @@ impl FileChatRepository {
         cursor: ChatPayloadCursor,
         max_lines: usize,
     ) -> Result<ChatPayloadChunk, DomainError> {
-        let path = self.get_group_chat_path(chat_id)?;
+        let path = self.resolve_group_chat_path(chat_id).await?;
         read_payload_before_lines(&path, cursor, max_lines).await
     }
 
+    pub(super) async fn get_group_payload_after_lines(
+        &self,
+        chat_id: &str,
+        cursor: ChatPayloadCursor,
+        max_lines: usize,
+    ) -> Result<ChatPayloadForwardChunk, DomainError> {
+        let path = self.resolve_group_chat_path(chat_id).await?;
+        read_payload_after_lines(&path, cursor, max_lines).await
+    }
+
+    pub(super) async fn locate_group_payload_message(
+        &self,
+        chat_id: &str,
+        query: &ChatMessageLocateQuery,
+    ) -> Result<Option<ChatMessageCursor>, DomainError> {
+        let path = self.resolve_group_chat_path(chat_id).await?;
+        locate_payload_message(&path, query).await
+    }
+
     pub(super) async fn save_group_payload_windowed(
         &self,
         chat_id: &str,
@@ -240,7 +325,7 @@ impl FileChatRepository {
     ) -> Result<ChatPayloadCursor, DomainError> {
         self.ensure_directory_exists().await?;
 
-        let path = self.get_group_chat_path(chat_id)?;
+        let path = self.resolve_group_chat_path(chat_id).await?;
         let _write_guard = self.acquire_payload_write_lock(&path).await;
         let backup_key = Self::get_group_backup_key(chat_id)?;
         let result = save_payload_windowed_internal(
@@ -331,6 +416,105 @@ async fn read_payload_before_lines(
     })
 }
 
+async fn read_payload_after_lines(
+    path: &Path,
+    cursor: ChatPayloadCursor,
+    max_lines: usize,
+) -> Result<ChatPayloadForwardChunk, DomainError> {
+    let metadata = read_existing_payload_metadata(path).await?;
+    verify_cursor_signature(path, cursor, &metadata)?;
+
+    let (_, header_end_offset) = read_first_line_and_end_offset(path).await?;
+
+    if cursor.offset > metadata.len() {
+        return Err(DomainError::InvalidData(format!(
+            "Cursor offset is out of bounds for {:?}",
+            path
+        )));
+    }
+    if cursor.offset < header_end_offset {
+        return Err(DomainError::InvalidData(format!(
+            "Cursor offset is before chat payload body for {:?}",
+            path
+        )));
+    }
+    verify_cursor_offset_is_line_boundary(path, cursor.offset).await?;
+
+    let mut reader = open_payload_lines_at(path, cursor.offset).await?;
+    let mut buf = Vec::new();
+    let mut lines = Vec::new();
+    let mut offset = cursor.offset;
+
+    while lines.len() < max_lines {
+        let read = read_next_payload_line(&mut reader, &mut buf, path, offset).await?;
+        if read == 0 {
+            break;
+        }
+        offset += read as u64;
+        lines.push(decode_jsonl_line_bytes(&buf)?);
+    }
+
+    Ok(ChatPayloadForwardChunk {
+        lines,
+        cursor: cursor_from_metadata(offset, &metadata)?,
+        has_more_after: offset < metadata.len(),
+    })
+}
+
+#[derive(Deserialize)]
+struct LocatableChatMessage {
+    #[serde(default)]
+    send_date: Option<Value>,
+}
+
+/// Streams message lines from the start of the body until the query matches. Only
+/// `send_date` lookups parse the lines; index lookups just count them.
+async fn locate_payload_message(
+    path: &Path,
+    query: &ChatMessageLocateQuery,
+) -> Result<Option<ChatMessageCursor>, DomainError> {
+    if query.message_id.is_some() == query.send_date.is_some() {
+        return Err(DomainError::InvalidData(
+            "Exactly one of messageId or sendDate is required".to_string(),
+        ));
+    }
+
+    let metadata = read_existing_payload_metadata(path).await?;
+    let (_, header_end_offset) = read_first_line_and_end_offset(path).await?;
+
+    let mut reader = open_payload_lines_at(path, header_end_offset).await?;
+    let mut buf = Vec::new();
+    let mut offset = header_end_offset;
+    let mut index = 0;
+
+    loop {
+        let read = read_next_payload_line(&mut reader, &mut buf, path, offset).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+
+        let found = match (query.message_id, query.send_date) {
+            (Some(message_id), _) => index == message_id,
+            (None, Some(send_date)) => {
+                let line = decode_jsonl_line_bytes(&buf)?;
+                serde_json::from_str::<LocatableChatMessage>(&line).is_ok_and(|message| {
+                    parse_message_timestamp_value(message.send_date.as_ref()) >= send_date
+                })
+            }
+            (None, None) => unreachable!("validated above"),
+        };
+        if found {
+            return Ok(Some(ChatMessageCursor {
+                index,
+                cursor: cursor_from_metadata(offset, &metadata)?,
+            }));
+        }
+
+        offset += read as u64;
+        index += 1;
+    }
+}
+
 async fn save_payload_windowed_internal(
     path: &PathBuf,
     cursor: ChatPayloadCursor,
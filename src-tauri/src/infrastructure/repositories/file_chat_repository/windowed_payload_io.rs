@@ -7,7 +7,7 @@ use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, SeekFrom};
 
 use crate::domain::errors::DomainError;
 use crate::domain::repositories::chat_repository::ChatPayloadCursor;
-use crate::infrastructure::persistence::file_system::replace_file_with_fallback;
+use crate::infrastructure::persistence::file_system::commit_temp_file;
 
 pub(super) const WINDOW_READ_CHUNK_BYTES: usize = 64 * 1024;
 
@@ -250,7 +250,7 @@ pub(super) async fn write_jsonl_lines_at_end(
 }
 
 pub(super) async fn replace_file(temp_path: &Path, target_path: &Path) -> Result<(), DomainError> {
-    replace_file_with_fallback(temp_path, target_path).await
+    commit_temp_file(temp_path, target_path).await
 }
 
 pub(super) fn verify_cursor_signature(
@@ -12,6 +12,7 @@ use crate::infrastructure::assets::{
     copy_resource_to_file, list_default_content_files_under, read_resource_json,
 };
 use crate::infrastructure::logging::logger;
+use crate::infrastructure::persistence::file_system::atomic_write;
 
 /// Content index item from JSON
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -187,13 +188,7 @@ impl FileContentRepository {
             })?;
         }
 
-        fs::write(path, entries.join("\n")).await.map_err(|error| {
-            logger::error(&format!(
-                "Failed to write content log {:?}: {}",
-                path, error
-            ));
-            DomainError::InternalError(format!("Failed to write content log: {}", error))
-        })
+        atomic_write(path, entries.join("\n").as_bytes()).await
     }
 
     async fn seed_content_scope(
@@ -0,0 +1,361 @@
+use async_trait::async_trait;
+use std::path::{Component, Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::file_attachment::{FileAttachment, FileAttachmentScope};
+use crate::domain::repositories::file_attachment_repository::FileAttachmentRepository;
+use crate::infrastructure::logging::logger;
+use crate::infrastructure::persistence::file_names::{
+    path_exists, sanitized_name, unique_file_path,
+};
+use crate::infrastructure::persistence::file_system::atomic_write;
+
+const DATA_BANK_DIRECTORY: &str = "data-bank";
+const USER_FILES_URL_PREFIX: &str = "user/files/";
+
+/// File system implementation of the FileAttachmentRepository (the Data Bank).
+///
+/// Documents live under `user/files/data-bank/{global|character/<avatar>|chat/<chat>}/`
+/// so the existing `/user/files/` endpoint serves them without extra routing.
+pub struct FileDataBankRepository {
+    files_dir: PathBuf,
+}
+
+impl FileDataBankRepository {
+    /// Create a new FileDataBankRepository rooted at the user's `files` directory
+    pub fn new(files_dir: PathBuf) -> Self {
+        Self { files_dir }
+    }
+
+    fn scope_relative_dir(scope: &FileAttachmentScope) -> Result<PathBuf, DomainError> {
+        let mut relative = PathBuf::from(DATA_BANK_DIRECTORY);
+        match scope {
+            FileAttachmentScope::Global => relative.push("global"),
+            FileAttachmentScope::Character(id) => {
+                relative.push("character");
+                relative.push(sanitized_name(id, "character attachment scope")?);
+            }
+            FileAttachmentScope::Chat(id) => {
+                relative.push("chat");
+                relative.push(sanitized_name(id, "chat attachment scope")?);
+            }
+        }
+        Ok(relative)
+    }
+
+    fn resolve_url(&self, url: &str) -> Result<PathBuf, DomainError> {
+        let normalized = url.trim().replace('\\', "/");
+        let relative = normalized
+            .trim_start_matches('/')
+            .strip_prefix(USER_FILES_URL_PREFIX)
+            .ok_or_else(|| DomainError::InvalidData(format!("Invalid attachment url: {}", url)))?;
+
+        let relative = Path::new(relative);
+        let is_data_bank_path = relative.starts_with(DATA_BANK_DIRECTORY)
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if !is_data_bank_path {
+            return Err(DomainError::InvalidData(format!(
+                "Invalid attachment url: {}",
+                url
+            )));
+        }
+
+        Ok(self.files_dir.join(relative))
+    }
+
+    async fn prepare_target(
+        &self,
+        scope: &FileAttachmentScope,
+        file_name: &str,
+    ) -> Result<PathBuf, DomainError> {
+        let dir = self.files_dir.join(Self::scope_relative_dir(scope)?);
+        fs::create_dir_all(&dir).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to create attachment directory '{}': {}",
+                dir.display(),
+                error
+            ))
+        })?;
+        let file_name = sanitized_name(file_name, "attachment file name")?;
+        let path = Path::new(&file_name);
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| file_name.clone());
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_string())
+            .unwrap_or_default();
+        unique_file_path(&dir, &stem, &extension).await
+    }
+
+    async fn describe(
+        &self,
+        scope: &FileAttachmentScope,
+        path: &Path,
+    ) -> Result<FileAttachment, DomainError> {
+        let metadata = fs::metadata(path).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to stat attachment '{}': {}",
+                path.display(),
+                error
+            ))
+        })?;
+        let created = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_millis() as i64)
+            .unwrap_or_default();
+
+        let relative = path.strip_prefix(&self.files_dir).map_err(|_| {
+            DomainError::InternalError(format!(
+                "Attachment path escapes files directory: {}",
+                path.display()
+            ))
+        })?;
+        let relative = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        Ok(FileAttachment {
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            url: format!("/{USER_FILES_URL_PREFIX}{relative}"),
+            scope: scope.clone(),
+            size: metadata.len(),
+            created,
+            mime_type: mime_guess::from_path(path)
+                .first_or_octet_stream()
+                .essence_str()
+                .to_string(),
+        })
+    }
+}
+
+fn map_read_error(path: &Path, error: std::io::Error) -> DomainError {
+    match error.kind() {
+        std::io::ErrorKind::NotFound => {
+            DomainError::NotFound(format!("Attachment not found: {}", path.display()))
+        }
+        _ => DomainError::InternalError(format!(
+            "Failed to read attachment '{}': {}",
+            path.display(),
+            error
+        )),
+    }
+}
+
+fn extract_text(path: &Path, bytes: Vec<u8>) -> Result<String, DomainError> {
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "txt" | "md" | "markdown" => {
+            let text = String::from_utf8_lossy(&bytes);
+            Ok(text.strip_prefix('\u{feff}').unwrap_or(&text).to_string())
+        }
+        "pdf" => pdf_extract::extract_text_from_mem(&bytes).map_err(|error| {
+            DomainError::InvalidData(format!(
+                "Failed to extract text from '{}': {}",
+                path.display(),
+                error
+            ))
+        }),
+        _ => Err(DomainError::InvalidData(format!(
+            "Text extraction is not supported for '{}'",
+            path.display()
+        ))),
+    }
+}
+
+#[async_trait]
+impl FileAttachmentRepository for FileDataBankRepository {
+    async fn save_attachment(
+        &self,
+        scope: &FileAttachmentScope,
+        file_name: &str,
+        data: &[u8],
+    ) -> Result<FileAttachment, DomainError> {
+        let target = self.prepare_target(scope, file_name).await?;
+        atomic_write(&target, data).await?;
+
+        logger::debug(&format!("Saved Data Bank attachment: {}", target.display()));
+        self.describe(scope, &target).await
+    }
+
+    async fn save_attachment_from_path(
+        &self,
+        scope: &FileAttachmentScope,
+        file_name: &str,
+        source_path: &Path,
+    ) -> Result<FileAttachment, DomainError> {
+        let target = self.prepare_target(scope, file_name).await?;
+        fs::copy(source_path, &target)
+            .await
+            .map_err(|error| map_read_error(source_path, error))?;
+
+        logger::debug(&format!("Saved Data Bank attachment: {}", target.display()));
+        self.describe(scope, &target).await
+    }
+
+    async fn list_attachments(
+        &self,
+        scope: &FileAttachmentScope,
+    ) -> Result<Vec<FileAttachment>, DomainError> {
+        let dir = self.files_dir.join(Self::scope_relative_dir(scope)?);
+        if !path_exists(&dir).await? {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = fs::read_dir(&dir)
+            .await
+            .map_err(|error| map_read_error(&dir, error))?;
+        let mut attachments = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|error| map_read_error(&dir, error))?
+        {
+            let path = entry.path();
+            if path.is_file() {
+                attachments.push(self.describe(scope, &path).await?);
+            }
+        }
+
+        attachments.sort_by(|left, right| {
+            right
+                .created
+                .cmp(&left.created)
+                .then_with(|| left.name.cmp(&right.name))
+        });
+        Ok(attachments)
+    }
+
+    async fn delete_attachment(&self, url: &str) -> Result<(), DomainError> {
+        let path = self.resolve_url(url)?;
+        fs::remove_file(&path)
+            .await
+            .map_err(|error| map_read_error(&path, error))
+    }
+
+    async fn extract_attachment_text(&self, url: &str) -> Result<String, DomainError> {
+        let path = self.resolve_url(url)?;
+        let bytes = fs::read(&path)
+            .await
+            .map_err(|error| map_read_error(&path, error))?;
+
+        tokio::task::spawn_blocking(move || extract_text(&path, bytes))
+            .await
+            .map_err(|error| {
+                DomainError::InternalError(format!("Text extraction task failed: {}", error))
+            })?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::FileDataBankRepository;
+    use crate::domain::errors::DomainError;
+    use crate::domain::models::file_attachment::FileAttachmentScope;
+    use crate::domain::repositories::file_attachment_repository::FileAttachmentRepository;
+
+    struct TempDirGuard {
+        path: PathBuf,
+    }
+
+    impl TempDirGuard {
+        fn new(test_name: &str) -> Self {
+            let mut path = std::env::temp_dir();
+            path.push(format!("tauritavern-{test_name}-{}", uuid::Uuid::new_v4()));
+            std::fs::create_dir_all(&path).expect("create temp dir");
+            Self { path }
+        }
+    }
+
+    impl Drop for TempDirGuard {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[tokio::test]
+    async fn saves_lists_and_extracts_scoped_attachments() {
+        let temp = TempDirGuard::new("data-bank-roundtrip");
+        let repository = FileDataBankRepository::new(temp.path.clone());
+        let scope = FileAttachmentScope::Chat("Alice - 2025-01-01.jsonl".to_string());
+
+        let first = repository
+            .save_attachment(&scope, "notes.md", "\u{feff}# Notes".as_bytes())
+            .await
+            .expect("save");
+        let second = repository
+            .save_attachment(&scope, "notes.md", b"again")
+            .await
+            .expect("save duplicate");
+
+        assert_eq!(
+            first.url,
+            "/user/files/data-bank/chat/Alice - 2025-01-01.jsonl/notes.md"
+        );
+        assert_eq!(second.name, "notes-1.md");
+
+        let listed = repository.list_attachments(&scope).await.expect("list");
+        assert_eq!(listed.len(), 2);
+        assert!(
+            repository
+                .list_attachments(&FileAttachmentScope::Global)
+                .await
+                .expect("list global")
+                .is_empty()
+        );
+
+        let text = repository
+            .extract_attachment_text(&first.url)
+            .await
+            .expect("extract");
+        assert_eq!(text, "# Notes");
+
+        repository
+            .delete_attachment(&second.url)
+            .await
+            .expect("delete");
+        assert_eq!(
+            repository
+                .list_attachments(&scope)
+                .await
+                .expect("list")
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_urls_outside_the_data_bank() {
+        let temp = TempDirGuard::new("data-bank-traversal");
+        let repository = FileDataBankRepository::new(temp.path.clone());
+
+        for url in [
+            "/user/files/other.txt",
+            "/user/files/data-bank/../secret.txt",
+            "/user/images/data-bank/a.txt",
+        ] {
+            assert!(matches!(
+                repository.delete_attachment(url).await,
+                Err(DomainError::InvalidData(_))
+            ));
+        }
+    }
+}
@@ -4,10 +4,11 @@ use std::sync::Arc;
 
 use crate::domain::errors::DomainError;
 use crate::domain::models::extension::{
-    Extension, ExtensionInstallResult, ExtensionManifestMetadata, ExtensionUpdateResult,
-    ExtensionVersion,
+    Extension, ExtensionBatchUpdateResult, ExtensionInstallResult, ExtensionManifestMetadata,
+    ExtensionUpdateResult, ExtensionVersion,
 };
 use crate::domain::repositories::extension_repository::ExtensionRepository;
+use crate::domain::repositories::secret_repository::SecretRepository;
 use crate::infrastructure::http_client_pool::HttpClientPool;
 use crate::infrastructure::persistence::file_system::read_json_file;
 use crate::infrastructure::third_party_paths::{
@@ -15,6 +16,7 @@ use crate::infrastructure::third_party_paths::{
 };
 
 mod archive_zip;
+mod compatibility;
 mod delete;
 mod discovery;
 mod install;
@@ -63,9 +65,10 @@ impl FileExtensionRepository {
         global_extensions_dir: PathBuf,
         source_store_root: PathBuf,
         http_clients: Arc<HttpClientPool>,
+        secret_repository: Arc<dyn SecretRepository>,
     ) -> Result<Self, DomainError> {
         let source_store = ExtensionSourceStore::new(source_store_root);
-        let providers = ExtensionSourceProviders::new(http_clients);
+        let providers = ExtensionSourceProviders::new(http_clients, secret_repository);
         let repository = Self {
             user_extensions_dir,
             global_extensions_dir,
@@ -142,6 +145,7 @@ impl FileExtensionRepository {
         &self,
         provider: &dyn ExtensionSourceProvider,
         repo_path: &str,
+        reference: &str,
         commit: &str,
         base_dir: &Path,
         temp_prefix: &str,
@@ -149,8 +153,13 @@ impl FileExtensionRepository {
         let staging_dir = self.create_temp_directory(base_dir, temp_prefix).await?;
 
         let result: Result<ExtensionManifestMetadata, DomainError> = async {
-            let archive_bytes = provider.download_archive_zip(repo_path, commit).await?;
-            self.extract_zip_bytes(archive_bytes.as_ref(), &staging_dir)?;
+            let checked_out = provider
+                .checkout_snapshot(repo_path, reference, commit, &staging_dir)
+                .await?;
+            if !checked_out {
+                let archive_bytes = provider.download_archive_zip(repo_path, commit).await?;
+                self.extract_zip_bytes(archive_bytes.as_ref(), &staging_dir)?;
+            }
             self.required_manifest_metadata(&staging_dir).await
         }
         .await;
@@ -187,6 +196,14 @@ impl ExtensionRepository for FileExtensionRepository {
         install::install_extension(self, url, global, branch).await
     }
 
+    async fn install_extension_from_path(
+        &self,
+        source_path: &Path,
+        global: bool,
+    ) -> Result<ExtensionInstallResult, DomainError> {
+        install::install_extension_from_path(self, source_path, global).await
+    }
+
     async fn update_extension(
         &self,
         extension_name: &str,
@@ -195,6 +212,13 @@ impl ExtensionRepository for FileExtensionRepository {
         update::update_extension(self, extension_name, global).await
     }
 
+    async fn update_all_extensions(
+        &self,
+        include_global: bool,
+    ) -> Result<Vec<ExtensionBatchUpdateResult>, DomainError> {
+        update::update_all_extensions(self, include_global).await
+    }
+
     async fn delete_extension(
         &self,
         extension_name: &str,
@@ -68,6 +68,10 @@ impl FileExtensionRepository {
             DomainError::InternalError(format!("Failed to read downloaded ZIP archive: {}", error))
         })?;
 
+        // Provider archives wrap files in a top-level root folder; hand-made archives
+        // may keep the manifest at the root instead.
+        let strip_root = !archive.file_names().any(|name| name == "manifest.json");
+
         for index in 0..archive.len() {
             let mut entry = archive.by_index(index).map_err(|error| {
                 DomainError::InternalError(format!("Failed to read ZIP entry: {}", error))
@@ -75,10 +79,13 @@ impl FileExtensionRepository {
 
             let enclosed_path = zipkit::enclosed_zip_entry_path(&entry)?;
 
-            // Provider archives wrap files in a top-level root folder.
-            let relative_path = match Self::strip_archive_root(&enclosed_path) {
-                Some(path) => path,
-                None => continue,
+            let relative_path = if strip_root {
+                match Self::strip_archive_root(&enclosed_path) {
+                    Some(path) => path,
+                    None => continue,
+                }
+            } else {
+                enclosed_path
             };
 
             let output_path = destination.join(relative_path);
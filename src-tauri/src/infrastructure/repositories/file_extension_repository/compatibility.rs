@@ -0,0 +1,162 @@
+use std::cmp::Ordering;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::extension::{
+    ExtensionCompatibilityReport, ExtensionManifestMetadata, SILLYTAVERN_COMPAT_VERSION,
+};
+
+use super::FileExtensionRepository;
+use super::discovery;
+
+/// Loading orders outside this range are almost always typos and would reorder
+/// built-in extensions unpredictably.
+const LOADING_ORDER_RANGE: std::ops::RangeInclusive<i32> = -10_000..=10_000;
+
+impl FileExtensionRepository {
+    /// Checks a staged manifest against this client and the currently installed extensions.
+    pub(super) async fn check_compatibility(
+        &self,
+        manifest: &ExtensionManifestMetadata,
+    ) -> Result<ExtensionCompatibilityReport, DomainError> {
+        let installed = discovery::discover_extensions(self)
+            .await?
+            .into_iter()
+            .map(|extension| extension.name)
+            .collect::<Vec<_>>();
+        Ok(build_compatibility_report(
+            manifest,
+            SILLYTAVERN_COMPAT_VERSION,
+            &installed,
+        ))
+    }
+}
+
+pub(super) fn build_compatibility_report(
+    manifest: &ExtensionManifestMetadata,
+    client_version: &str,
+    installed_extensions: &[String],
+) -> ExtensionCompatibilityReport {
+    let mut errors = Vec::new();
+
+    if let Some(minimum) = manifest.minimum_client_version.as_deref() {
+        if compare_versions(client_version, minimum) == Ordering::Less {
+            errors.push(format!(
+                "{} requires SillyTavern {} or newer, but this client is compatible with {}",
+                manifest.display_name, minimum, client_version
+            ));
+        }
+    }
+
+    if !LOADING_ORDER_RANGE.contains(&manifest.loading_order) {
+        errors.push(format!(
+            "{} declares an invalid loading_order {}",
+            manifest.display_name, manifest.loading_order
+        ));
+    }
+
+    let (satisfied_dependencies, missing_dependencies): (Vec<_>, Vec<_>) = manifest
+        .dependencies
+        .iter()
+        .cloned()
+        .partition(|dependency| installed_extensions.contains(dependency));
+
+    ExtensionCompatibilityReport {
+        compatible: errors.is_empty(),
+        client_version: client_version.to_string(),
+        minimum_client_version: manifest.minimum_client_version.clone(),
+        loading_order: manifest.loading_order,
+        satisfied_dependencies,
+        missing_dependencies,
+        errors,
+    }
+}
+
+/// Compares dotted numeric versions, ignoring a leading `v` and any pre-release suffix.
+fn compare_versions(left: &str, right: &str) -> Ordering {
+    let parse = |value: &str| -> Vec<u64> {
+        value
+            .trim()
+            .trim_start_matches(['v', 'V'])
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|part| part.trim().parse::<u64>().unwrap_or(0))
+            .collect()
+    };
+
+    let left = parse(left);
+    let right = parse(right);
+    for index in 0..left.len().max(right.len()) {
+        let ordering = left
+            .get(index)
+            .copied()
+            .unwrap_or(0)
+            .cmp(&right.get(index).copied().unwrap_or(0));
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::{build_compatibility_report, compare_versions};
+    use crate::domain::models::extension::ExtensionManifestMetadata;
+
+    fn manifest(json: &str) -> ExtensionManifestMetadata {
+        serde_json::from_str(json).expect("manifest should parse")
+    }
+
+    #[test]
+    fn compares_dotted_versions() {
+        assert_eq!(compare_versions("1.18.0", "1.18"), Ordering::Equal);
+        assert_eq!(compare_versions("1.18.0", "v1.19.0-beta"), Ordering::Less);
+        assert_eq!(compare_versions("1.18.0", "1.9.9"), Ordering::Greater);
+    }
+
+    #[test]
+    fn reports_client_version_and_dependencies() {
+        let manifest = manifest(
+            r#"{
+                "display_name": "Gallery+",
+                "version": "1.0.0",
+                "author": "someone",
+                "loading_order": 20,
+                "dependencies": ["vectors", "third-party/Helper"],
+                "minimum_client_version": "1.19.0"
+            }"#,
+        );
+
+        let report = build_compatibility_report(&manifest, "1.18.0", &["vectors".to_string()]);
+
+        assert!(!report.compatible);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.satisfied_dependencies, vec!["vectors".to_string()]);
+        assert_eq!(
+            report.missing_dependencies,
+            vec!["third-party/Helper".to_string()]
+        );
+        assert_eq!(report.loading_order, 20);
+    }
+
+    #[test]
+    fn malformed_optional_manifest_fields_are_ignored() {
+        let manifest = manifest(
+            r#"{
+                "display_name": "Legacy",
+                "version": "0.1.0",
+                "author": "someone",
+                "dependencies": "vectors",
+                "minimum_client_version": 1
+            }"#,
+        );
+
+        assert!(manifest.dependencies.is_empty());
+        assert_eq!(manifest.minimum_client_version, None);
+        assert!(build_compatibility_report(&manifest, "1.18.0", &[]).compatible);
+    }
+}
@@ -92,7 +92,7 @@ async fn discover_scoped_extensions(
         let (managed, remote_url, commit_hash, branch_name) = match source {
             Some(source) => (
                 true,
-                Some(source.remote_url),
+                Some(source.remote_url).filter(|url| !url.is_empty()),
                 Some(source.installed_commit),
                 Some(source.reference),
             ),
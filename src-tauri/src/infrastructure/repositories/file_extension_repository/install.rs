@@ -1,12 +1,17 @@
 use std::fs;
+use std::path::{Path, PathBuf};
 
 use crate::domain::errors::DomainError;
-use crate::domain::models::extension::ExtensionInstallResult;
+use crate::domain::models::extension::{ExtensionInstallResult, ExtensionManifestMetadata};
 use crate::infrastructure::logging::logger;
 
 use super::FileExtensionRepository;
+use super::archive_zip::copy_dir_all;
 use super::repo_url::{normalize_requested_reference, parse_repo_url};
-use super::source_store::{ExtensionSourceMetadata, ExtensionStoreScope};
+use super::source_store::{ExtensionSourceMetadata, ExtensionSourceType, ExtensionStoreScope};
+
+/// Placeholder host recorded for extensions installed from a local zip or folder.
+const LOCAL_SOURCE_HOST: &str = "local";
 
 pub(super) async fn install_extension(
     repository: &FileExtensionRepository,
@@ -39,35 +44,180 @@ pub(super) async fn install_extension(
 
     let (staging_dir, manifest) = repository
         .stage_extension_snapshot(
-            provider,
+            &*provider,
             repo.repo_path.as_str(),
+            reference.as_str(),
             latest_commit.as_str(),
             base_dir,
             "extension-install",
         )
         .await?;
 
-    let scope = ExtensionStoreScope::from_global(global);
     let source_metadata = ExtensionSourceMetadata {
+        source_type: ExtensionSourceType::Git,
         host: repo.host.clone(),
         repo_path: repo.repo_path.clone(),
         reference: reference.clone(),
         remote_url: repo.canonical_remote_url(),
         installed_commit: latest_commit.clone(),
     };
+    finalize_install(
+        repository,
+        staging_dir,
+        manifest,
+        &extension_path,
+        &extension_folder_name,
+        ExtensionStoreScope::from_global(global),
+        source_metadata,
+    )
+    .await
+}
+
+pub(super) async fn install_extension_from_path(
+    repository: &FileExtensionRepository,
+    source_path: &Path,
+    global: bool,
+) -> Result<ExtensionInstallResult, DomainError> {
+    tracing::info!("Installing extension from {}", source_path.display());
+
+    let is_zip = source_path.is_file()
+        && source_path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("zip"));
+    if !is_zip && !source_path.is_dir() {
+        return Err(DomainError::InvalidData(format!(
+            "Extension source must be a .zip file or a folder: {}",
+            source_path.display()
+        )));
+    }
+
+    let source_name = source_path
+        .file_stem()
+        .map(|value| value.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let base_dir = repository.extension_base_dir(global);
+    let extension_folder_name =
+        FileExtensionRepository::install_folder_name_from_repo_name(&source_name)?;
+    let extension_path = base_dir.join(&extension_folder_name);
+
+    if extension_path.exists() {
+        return Err(DomainError::InvalidData(format!(
+            "Extension already exists at '{}'",
+            extension_path.display()
+        )));
+    }
+
+    let staging_dir = repository
+        .create_temp_directory(base_dir, "extension-install")
+        .await?;
+    let staged: Result<ExtensionManifestMetadata, DomainError> = async {
+        if is_zip {
+            let bytes = tokio::fs::read(source_path).await.map_err(|error| {
+                DomainError::InternalError(format!(
+                    "Failed to read extension archive '{}': {}",
+                    source_path.display(),
+                    error
+                ))
+            })?;
+            repository.extract_zip_bytes(&bytes, &staging_dir)?;
+        } else {
+            copy_dir_all(source_path, &staging_dir).map_err(|error| {
+                DomainError::InternalError(format!(
+                    "Failed to copy extension folder '{}': {}",
+                    source_path.display(),
+                    error
+                ))
+            })?;
+        }
+
+        // Source tracking lives in the source store, never in a copied checkout.
+        let git_dir = staging_dir.join(".git");
+        if git_dir.exists() {
+            fs::remove_dir_all(&git_dir).map_err(|error| {
+                DomainError::InternalError(format!(
+                    "Failed to remove git metadata from '{}': {}",
+                    staging_dir.display(),
+                    error
+                ))
+            })?;
+        }
+
+        repository.required_manifest_metadata(&staging_dir).await
+    }
+    .await;
+    let manifest = match staged {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            FileExtensionRepository::cleanup_temp_directory(&staging_dir).await;
+            return Err(error);
+        }
+    };
+
+    let source_metadata = ExtensionSourceMetadata {
+        source_type: ExtensionSourceType::Local,
+        host: LOCAL_SOURCE_HOST.to_string(),
+        repo_path: source_name,
+        reference: String::new(),
+        remote_url: String::new(),
+        installed_commit: String::new(),
+    };
+    finalize_install(
+        repository,
+        staging_dir,
+        manifest,
+        &extension_path,
+        &extension_folder_name,
+        ExtensionStoreScope::from_global(global),
+        source_metadata,
+    )
+    .await
+}
+
+/// Checks compatibility of a staged snapshot, records its source and moves it into place.
+async fn finalize_install(
+    repository: &FileExtensionRepository,
+    staging_dir: PathBuf,
+    manifest: ExtensionManifestMetadata,
+    extension_path: &Path,
+    extension_folder_name: &str,
+    scope: ExtensionStoreScope,
+    source_metadata: ExtensionSourceMetadata,
+) -> Result<ExtensionInstallResult, DomainError> {
+    let compatibility = match repository.check_compatibility(&manifest).await {
+        Ok(report) => report,
+        Err(error) => {
+            FileExtensionRepository::cleanup_temp_directory(&staging_dir).await;
+            return Err(error);
+        }
+    };
+    if !compatibility.compatible {
+        FileExtensionRepository::cleanup_temp_directory(&staging_dir).await;
+        return Err(DomainError::InvalidData(format!(
+            "Extension is not compatible: {}",
+            compatibility.errors.join("; ")
+        )));
+    }
+    if !compatibility.missing_dependencies.is_empty() {
+        tracing::info!(
+            "Extension {} has missing dependencies: {}",
+            manifest.display_name,
+            compatibility.missing_dependencies.join(", ")
+        );
+    }
+
     if let Err(error) = repository
         .source_store
-        .write(scope, &extension_folder_name, &source_metadata)
+        .write(scope, extension_folder_name, &source_metadata)
         .await
     {
         FileExtensionRepository::cleanup_temp_directory(&staging_dir).await;
         return Err(error);
     }
 
-    if let Err(error) = fs::rename(&staging_dir, &extension_path) {
+    if let Err(error) = fs::rename(&staging_dir, extension_path) {
         if let Err(cleanup_error) = repository
             .source_store
-            .delete(scope, &extension_folder_name)
+            .delete(scope, extension_folder_name)
             .await
         {
             logger::warn(&format!(
@@ -96,5 +246,6 @@ pub(super) async fn install_extension(
         author: manifest.author,
         display_name: manifest.display_name,
         extension_path: extension_path.to_string_lossy().to_string(),
+        compatibility,
     })
 }
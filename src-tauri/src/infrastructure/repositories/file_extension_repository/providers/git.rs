@@ -0,0 +1,337 @@
+use bytes::Bytes;
+use std::num::NonZeroU32;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use url::Url;
+
+use crate::domain::errors::DomainError;
+use crate::infrastructure::http_client_pool::{HttpClientPool, HttpClientProfile};
+
+use super::{ExtensionSourceProvider, parse_bytes_or_error};
+
+const GIT_PROVIDER_NAME: &str = "git";
+
+/// Fallback for hosts without a supported archive API (Codeberg, self-hosted Gitea/Forgejo,
+/// cgit, ...). Refs are resolved from the smart-HTTP advertisement and snapshots are
+/// checked out with a shallow gitoxide clone.
+pub(in crate::infrastructure::repositories::file_extension_repository) struct GitProvider {
+    http_clients: Arc<HttpClientPool>,
+    host: String,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct AdvertisedRefs {
+    head_symref: Option<String>,
+    refs: Vec<(String, String)>,
+}
+
+impl AdvertisedRefs {
+    fn find(&self, name: &str) -> Option<&str> {
+        self.refs
+            .iter()
+            .find(|(ref_name, _)| ref_name == name)
+            .map(|(_, id)| id.as_str())
+    }
+
+    /// Branch or tag whose tip is `commit`. Smart-HTTP servers generally refuse to
+    /// serve unadvertised objects, so a commit pin is only clonable through such a ref.
+    fn ref_at_commit(&self, commit: &str) -> Option<&str> {
+        let matches = |prefix: &'static str| {
+            self.refs.iter().find_map(move |(name, id)| {
+                (name.starts_with(prefix) && id.eq_ignore_ascii_case(commit))
+                    .then(|| name.trim_end_matches("^{}"))
+            })
+        };
+        matches("refs/heads/").or_else(|| matches("refs/tags/"))
+    }
+}
+
+impl GitProvider {
+    pub(super) fn new(http_clients: Arc<HttpClientPool>, host: &str) -> Self {
+        Self {
+            http_clients,
+            host: host.to_string(),
+        }
+    }
+
+    fn remote_url(&self, repo_path: &str) -> String {
+        format!("https://{}/{}", self.host, repo_path)
+    }
+
+    async fn advertised_refs(&self, repo_path: &str) -> Result<AdvertisedRefs, DomainError> {
+        let url = Url::parse(&format!(
+            "{}/info/refs?service=git-upload-pack",
+            self.remote_url(repo_path)
+        ))
+        .map_err(|error| {
+            DomainError::InvalidData(format!("Invalid git repository URL: {}", error))
+        })?;
+
+        let http_client = self.http_clients.client(HttpClientProfile::Default)?;
+        let response = http_client.get(url.clone()).send().await.map_err(|error| {
+            DomainError::InternalError(format!("Git request failed: {}", error))
+        })?;
+        let body = parse_bytes_or_error(response, &url, "Git").await?;
+
+        parse_ref_advertisement(&body).ok_or_else(|| {
+            DomainError::InvalidData(format!(
+                "'{}' is not a git repository served over smart HTTP",
+                self.remote_url(repo_path)
+            ))
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ExtensionSourceProvider for GitProvider {
+    fn host(&self) -> &'static str {
+        GIT_PROVIDER_NAME
+    }
+
+    async fn default_branch(&self, repo_path: &str) -> Result<String, DomainError> {
+        let refs = self.advertised_refs(repo_path).await?;
+        refs.head_symref
+            .as_deref()
+            .and_then(|target| target.strip_prefix("refs/heads/"))
+            .map(str::to_string)
+            .ok_or_else(|| {
+                DomainError::InternalError(format!(
+                    "Repository '{}' has no default branch",
+                    repo_path
+                ))
+            })
+    }
+
+    async fn latest_commit(&self, repo_path: &str, reference: &str) -> Result<String, DomainError> {
+        let reference = reference.trim();
+        let refs = self.advertised_refs(repo_path).await?;
+        if is_object_id(reference) {
+            return refs
+                .ref_at_commit(reference)
+                .map(|_| reference.to_ascii_lowercase())
+                .ok_or_else(|| commit_pin_error(&self.remote_url(repo_path), reference));
+        }
+
+        [
+            format!("refs/heads/{reference}"),
+            format!("refs/tags/{reference}^{{}}"),
+            format!("refs/tags/{reference}"),
+            reference.to_string(),
+        ]
+        .iter()
+        .find_map(|name| refs.find(name))
+        .map(str::to_string)
+        .ok_or_else(|| {
+            DomainError::NotFound(format!(
+                "Reference '{}' not found in '{}'",
+                reference, repo_path
+            ))
+        })
+    }
+
+    async fn download_archive_zip(
+        &self,
+        repo_path: &str,
+        _commit: &str,
+    ) -> Result<Bytes, DomainError> {
+        Err(DomainError::InternalError(format!(
+            "Archive downloads are not available for '{}'",
+            self.remote_url(repo_path)
+        )))
+    }
+
+    async fn checkout_snapshot(
+        &self,
+        repo_path: &str,
+        reference: &str,
+        commit: &str,
+        destination: &Path,
+    ) -> Result<bool, DomainError> {
+        let remote_url = self.remote_url(repo_path);
+        let reference = reference.trim();
+        let reference = if is_object_id(reference) {
+            let refs = self.advertised_refs(repo_path).await?;
+            refs.ref_at_commit(reference)
+                .map(str::to_string)
+                .ok_or_else(|| commit_pin_error(&remote_url, reference))?
+        } else {
+            reference.to_string()
+        };
+        let commit = commit.to_string();
+        let destination = destination.to_path_buf();
+
+        tokio::task::spawn_blocking(move || {
+            shallow_checkout(&remote_url, &reference, &commit, &destination)
+        })
+        .await
+        .map_err(|error| DomainError::InternalError(format!("Git clone task failed: {}", error)))?
+        .map(|()| true)
+    }
+}
+
+fn shallow_checkout(
+    remote_url: &str,
+    reference: &str,
+    commit: &str,
+    destination: &Path,
+) -> Result<(), DomainError> {
+    let clone_error = |error: &dyn std::fmt::Display| {
+        DomainError::InternalError(format!("Failed to clone '{}': {}", remote_url, error))
+    };
+    let interrupt = AtomicBool::new(false);
+
+    let (mut checkout, _) = gix::prepare_clone(remote_url, destination)
+        .map_err(|error| clone_error(&error))?
+        .with_shallow(gix::remote::fetch::Shallow::DepthAtRemote(
+            NonZeroU32::new(1).expect("depth is non-zero"),
+        ))
+        .with_ref_name(Some(reference))
+        .map_err(|error| clone_error(&error))?
+        .fetch_then_checkout(gix::progress::Discard, &interrupt)
+        .map_err(|error| clone_error(&error))?;
+    let (repository, _) = checkout
+        .main_worktree(gix::progress::Discard, &interrupt)
+        .map_err(|error| clone_error(&error))?;
+
+    let head = repository
+        .head_id()
+        .map_err(|error| clone_error(&error))?
+        .to_string();
+    let git_dir = repository.git_dir().to_path_buf();
+    drop(repository);
+
+    // Snapshots mirror archive downloads: no git metadata, tracking lives in the source store.
+    std::fs::remove_dir_all(&git_dir).map_err(|error| {
+        DomainError::InternalError(format!(
+            "Failed to remove git metadata from '{}': {}",
+            destination.display(),
+            error
+        ))
+    })?;
+
+    if !head.eq_ignore_ascii_case(commit) {
+        return Err(DomainError::InternalError(format!(
+            "'{}' moved to {} while installing {}; please retry",
+            remote_url, head, commit
+        )));
+    }
+
+    Ok(())
+}
+
+fn commit_pin_error(remote_url: &str, commit: &str) -> DomainError {
+    DomainError::InvalidData(format!(
+        "Commit {} is not the tip of a branch or tag in '{}'; pin a branch or tag instead",
+        commit, remote_url
+    ))
+}
+
+fn is_object_id(value: &str) -> bool {
+    value.len() == 40 && value.chars().all(|character| character.is_ascii_hexdigit())
+}
+
+/// Parses a protocol v0/v1 `git-upload-pack` ref advertisement.
+fn parse_ref_advertisement(body: &[u8]) -> Option<AdvertisedRefs> {
+    let mut refs = AdvertisedRefs::default();
+    let mut offset = 0;
+    let mut saw_service_header = false;
+
+    while offset + 4 <= body.len() {
+        let length =
+            usize::from_str_radix(std::str::from_utf8(&body[offset..offset + 4]).ok()?, 16).ok()?;
+        if length == 0 {
+            offset += 4;
+            continue;
+        }
+        if length < 4 || offset + length > body.len() {
+            return None;
+        }
+
+        let line = std::str::from_utf8(&body[offset + 4..offset + length]).ok()?;
+        offset += length;
+        let line = line.trim_end_matches('\n');
+
+        if line.starts_with("# service=") {
+            saw_service_header = true;
+            continue;
+        }
+
+        let (reference, capabilities) = match line.split_once('\0') {
+            Some((reference, capabilities)) => (reference, Some(capabilities)),
+            None => (line, None),
+        };
+        if let Some(capabilities) = capabilities {
+            refs.head_symref = capabilities
+                .split(' ')
+                .find_map(|capability| capability.strip_prefix("symref=HEAD:"))
+                .map(str::to_string);
+        }
+
+        let (id, name) = reference.split_once(' ')?;
+        if !is_object_id(id) {
+            return None;
+        }
+        refs.refs.push((name.to_string(), id.to_ascii_lowercase()));
+    }
+
+    saw_service_header.then_some(refs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_ref_advertisement;
+
+    fn pkt_line(content: &str) -> String {
+        format!("{:04x}{}", content.len() + 4, content)
+    }
+
+    #[test]
+    fn parses_smart_http_ref_advertisement() {
+        let head = "1111111111111111111111111111111111111111";
+        let tag = "2222222222222222222222222222222222222222";
+        let peeled = "3333333333333333333333333333333333333333";
+        let body = format!(
+            "{}0000{}{}{}{}0000",
+            pkt_line("# service=git-upload-pack\n"),
+            pkt_line(&format!(
+                "{head} HEAD\0multi_ack symref=HEAD:refs/heads/main agent=git/2\n"
+            )),
+            pkt_line(&format!("{head} refs/heads/main\n")),
+            pkt_line(&format!("{tag} refs/tags/v1.0\n")),
+            pkt_line(&format!("{peeled} refs/tags/v1.0^{{}}\n")),
+        );
+
+        let refs = parse_ref_advertisement(body.as_bytes()).expect("advertisement should parse");
+        assert_eq!(refs.head_symref.as_deref(), Some("refs/heads/main"));
+        assert_eq!(refs.find("refs/heads/main"), Some(head));
+        assert_eq!(refs.find("refs/tags/v1.0^{}"), Some(peeled));
+    }
+
+    #[test]
+    fn commit_pins_resolve_to_the_ref_at_that_commit() {
+        let head = "1111111111111111111111111111111111111111";
+        let tag = "2222222222222222222222222222222222222222";
+        let peeled = "3333333333333333333333333333333333333333";
+        let body = format!(
+            "{}0000{}{}{}0000",
+            pkt_line("# service=git-upload-pack\n"),
+            pkt_line(&format!("{head} refs/heads/main\0agent=git/2\n")),
+            pkt_line(&format!("{tag} refs/tags/v1.0\n")),
+            pkt_line(&format!("{peeled} refs/tags/v1.0^{{}}\n")),
+        );
+
+        let refs = parse_ref_advertisement(body.as_bytes()).expect("advertisement should parse");
+        assert_eq!(refs.ref_at_commit(head), Some("refs/heads/main"));
+        assert_eq!(refs.ref_at_commit(peeled), Some("refs/tags/v1.0"));
+        assert_eq!(
+            refs.ref_at_commit("4444444444444444444444444444444444444444"),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_non_git_responses() {
+        assert_eq!(parse_ref_advertisement(b"<html>not git</html>"), None);
+    }
+}
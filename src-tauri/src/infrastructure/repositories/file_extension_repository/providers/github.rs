@@ -1,11 +1,16 @@
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 use reqwest::Response;
 use serde::Deserialize;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use url::Url;
 
 use crate::domain::errors::DomainError;
-use crate::infrastructure::github::classify_github_rate_limit;
+use crate::domain::models::secret::SecretKeys;
+use crate::domain::repositories::secret_repository::SecretRepository;
+use crate::infrastructure::github::{
+    GithubRateLimit, classify_github_rate_limit, github_rate_limited_until_message,
+};
 use crate::infrastructure::http_client_pool::{HttpClientPool, HttpClientProfile};
 
 use super::{
@@ -28,11 +33,70 @@ struct GithubCommit {
 
 pub(super) struct GithubProvider {
     http_clients: Arc<HttpClientPool>,
+    secret_repository: Arc<dyn SecretRepository>,
+    /// Set after a rate-limited response; requests fail fast until then.
+    rate_limited_until: Mutex<Option<DateTime<Utc>>>,
 }
 
 impl GithubProvider {
-    pub(super) fn new(http_clients: Arc<HttpClientPool>) -> Self {
-        Self { http_clients }
+    pub(super) fn new(
+        http_clients: Arc<HttpClientPool>,
+        secret_repository: Arc<dyn SecretRepository>,
+    ) -> Self {
+        Self {
+            http_clients,
+            secret_repository,
+            rate_limited_until: Mutex::new(None),
+        }
+    }
+
+    async fn api_token(&self) -> Result<Option<String>, DomainError> {
+        let token = self
+            .secret_repository
+            .read_secret(SecretKeys::GITHUB_TOKEN, None)
+            .await?;
+        Ok(token
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty()))
+    }
+
+    fn rate_limit_gate(&self) -> std::sync::MutexGuard<'_, Option<DateTime<Utc>>> {
+        self.rate_limited_until
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn ensure_not_backing_off(&self, authenticated: bool) -> Result<(), DomainError> {
+        let mut gate = self.rate_limit_gate();
+        match *gate {
+            Some(until) if until > Utc::now() => Err(DomainError::rate_limited(
+                github_rate_limited_until_message(until, authenticated),
+            )),
+            Some(_) => {
+                *gate = None;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    async fn send_get(&self, url: &Url, failure_context: &str) -> Result<Response, DomainError> {
+        let token = self.api_token().await?;
+        self.ensure_not_backing_off(token.is_some())?;
+
+        let http_client = self.http_clients.client(HttpClientProfile::Default)?;
+        let mut request = http_client
+            .get(url.clone())
+            .header("Accept", "application/vnd.github+json");
+        if let Some(token) = token.as_deref() {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request.send().await.map_err(|error| {
+            DomainError::InternalError(format!("{}: {}", failure_context, error))
+        })?;
+        self.ensure_success_response(response, url, token.is_some())
+            .await
     }
 
     fn build_api_url(&self, segments: &[&str]) -> Result<Url, DomainError> {
@@ -57,23 +121,43 @@ impl GithubProvider {
         &self,
         response: Response,
         url: &Url,
+        authenticated: bool,
     ) -> Result<Response, DomainError> {
         if response.status().is_success() {
             return Ok(response);
         }
 
+        let now = Utc::now();
+        let rate_limit = GithubRateLimit::from_headers(response.headers(), now);
         let error = read_provider_http_error(response).await;
-        if let Some(domain_error) = classify_github_rate_limit(error.status, &error.body) {
+        let is_rate_limited = classify_github_rate_limit(error.status, &error.body).is_some()
+            || (rate_limit.is_exhausted() && matches!(error.status.as_u16(), 403 | 429));
+        if is_rate_limited {
             tracing::debug!(
                 "GitHub API rate limit response for '{}': HTTP {} ({})",
                 url,
                 error.status,
                 error.body.trim()
             );
-            return Err(domain_error);
+            let until = rate_limit.retry_at(now);
+            *self.rate_limit_gate() = Some(until);
+            return Err(DomainError::rate_limited(
+                github_rate_limited_until_message(until, authenticated),
+            ));
         }
 
-        Err(provider_http_error_to_domain_error("GitHub", url, error))
+        match (
+            provider_http_error_to_domain_error("GitHub", url, error),
+            rate_limit.remaining,
+        ) {
+            (DomainError::InternalError(message), Some(remaining)) => {
+                Err(DomainError::InternalError(format!(
+                    "{} [GitHub rate limit remaining: {}]",
+                    message, remaining
+                )))
+            }
+            (domain_error, _) => Err(domain_error),
+        }
     }
 }
 
@@ -87,17 +171,7 @@ impl ExtensionSourceProvider for GithubProvider {
         let (owner, repo) = split_owner_repo(repo_path, self.host())?;
         let url = self.build_api_url(&["repos", owner, repo])?;
 
-        let http_client = self.http_clients.client(HttpClientProfile::Default)?;
-        let response = http_client
-            .get(url.clone())
-            .header("Accept", "application/vnd.github+json")
-            .send()
-            .await
-            .map_err(|error| {
-                DomainError::InternalError(format!("GitHub request failed: {}", error))
-            })?;
-
-        let response = self.ensure_success_response(response, &url).await?;
+        let response = self.send_get(&url, "GitHub request failed").await?;
         let info: GithubRepositoryInfo = parse_json_or_error(response, &url, "GitHub").await?;
         if info.default_branch.trim().is_empty() {
             return Err(DomainError::InternalError(format!(
@@ -113,17 +187,7 @@ impl ExtensionSourceProvider for GithubProvider {
         let (owner, repo) = split_owner_repo(repo_path, self.host())?;
         let url = self.build_api_url(&["repos", owner, repo, "commits", reference])?;
 
-        let http_client = self.http_clients.client(HttpClientProfile::Default)?;
-        let response = http_client
-            .get(url.clone())
-            .header("Accept", "application/vnd.github+json")
-            .send()
-            .await
-            .map_err(|error| {
-                DomainError::InternalError(format!("GitHub request failed: {}", error))
-            })?;
-
-        let response = self.ensure_success_response(response, &url).await?;
+        let response = self.send_get(&url, "GitHub request failed").await?;
         let commit: GithubCommit = parse_json_or_error(response, &url, "GitHub").await?;
         if commit.sha.trim().is_empty() {
             return Err(DomainError::InternalError(format!(
@@ -143,20 +207,9 @@ impl ExtensionSourceProvider for GithubProvider {
         let (owner, repo) = split_owner_repo(repo_path, self.host())?;
         let url = self.build_api_url(&["repos", owner, repo, "zipball", commit])?;
 
-        let http_client = self.http_clients.client(HttpClientProfile::Default)?;
-        let response = http_client
-            .get(url.clone())
-            .header("Accept", "application/vnd.github+json")
-            .send()
-            .await
-            .map_err(|error| {
-                DomainError::InternalError(format!(
-                    "Failed to download extension archive: {}",
-                    error
-                ))
-            })?;
-
-        let response = self.ensure_success_response(response, &url).await?;
+        let response = self
+            .send_get(&url, "Failed to download extension archive")
+            .await?;
         parse_bytes_or_error(response, &url, "GitHub").await
     }
 }
@@ -2,14 +2,18 @@ use async_trait::async_trait;
 use bytes::Bytes;
 use reqwest::{Response, StatusCode};
 use serde::de::DeserializeOwned;
+use std::ops::Deref;
+use std::path::Path;
 use std::sync::Arc;
 use url::Url;
 
 use crate::domain::errors::DomainError;
+use crate::domain::repositories::secret_repository::SecretRepository;
 use crate::infrastructure::http_client_pool::HttpClientPool;
 
 use super::repo_url::{HOST_GITEE, HOST_GITHUB, HOST_GITLAB};
 
+pub(super) mod git;
 pub(super) mod gitee;
 pub(super) mod github;
 pub(super) mod gitlab;
@@ -32,28 +36,66 @@ pub(super) trait ExtensionSourceProvider: Send + Sync {
         repo_path: &str,
         commit: &str,
     ) -> Result<Bytes, DomainError>;
+
+    /// Writes the tree at `commit` into `destination` without going through an archive.
+    /// Returns `false` when the provider only supports archive downloads.
+    async fn checkout_snapshot(
+        &self,
+        _repo_path: &str,
+        _reference: &str,
+        _commit: &str,
+        _destination: &Path,
+    ) -> Result<bool, DomainError> {
+        Ok(false)
+    }
+}
+
+/// Either one of the API-backed providers or a git remote for any other HTTPS host.
+pub(super) enum ProviderHandle<'a> {
+    Api(&'a dyn ExtensionSourceProvider),
+    Git(git::GitProvider),
+}
+
+impl<'a> Deref for ProviderHandle<'a> {
+    type Target = dyn ExtensionSourceProvider + 'a;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Api(provider) => *provider,
+            Self::Git(provider) => provider,
+        }
+    }
 }
 
 pub(super) struct ExtensionSourceProviders {
+    http_clients: Arc<HttpClientPool>,
     github: github::GithubProvider,
     gitlab: gitlab::GitLabProvider,
     gitee: gitee::GiteeProvider,
 }
 
 impl ExtensionSourceProviders {
-    pub(super) fn new(http_clients: Arc<HttpClientPool>) -> Self {
+    pub(super) fn new(
+        http_clients: Arc<HttpClientPool>,
+        secret_repository: Arc<dyn SecretRepository>,
+    ) -> Self {
         Self {
-            github: github::GithubProvider::new(http_clients.clone()),
+            github: github::GithubProvider::new(http_clients.clone(), secret_repository),
             gitlab: gitlab::GitLabProvider::new(http_clients.clone()),
-            gitee: gitee::GiteeProvider::new(http_clients),
+            gitee: gitee::GiteeProvider::new(http_clients.clone()),
+            http_clients,
         }
     }
 
-    pub(super) fn for_host(&self, host: &str) -> Result<&dyn ExtensionSourceProvider, DomainError> {
+    pub(super) fn for_host(&self, host: &str) -> Result<ProviderHandle<'_>, DomainError> {
         match host {
-            HOST_GITHUB => Ok(&self.github),
-            HOST_GITLAB => Ok(&self.gitlab),
-            HOST_GITEE => Ok(&self.gitee),
+            HOST_GITHUB => Ok(ProviderHandle::Api(&self.github)),
+            HOST_GITLAB => Ok(ProviderHandle::Api(&self.gitlab)),
+            HOST_GITEE => Ok(ProviderHandle::Api(&self.gitee)),
+            _ if is_valid_git_host(host) => Ok(ProviderHandle::Git(git::GitProvider::new(
+                self.http_clients.clone(),
+                host,
+            ))),
             _ => Err(DomainError::InvalidData(format!(
                 "Unsupported extension repository host: {}",
                 host
@@ -62,6 +104,13 @@ impl ExtensionSourceProviders {
     }
 }
 
+fn is_valid_git_host(host: &str) -> bool {
+    !host.is_empty()
+        && host.chars().all(|character| {
+            character.is_ascii_alphanumeric() || matches!(character, '.' | '-' | ':')
+        })
+}
+
 pub(super) async fn parse_json_or_error<T>(
     response: Response,
     url: &Url,
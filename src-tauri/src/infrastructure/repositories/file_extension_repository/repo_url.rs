@@ -1,4 +1,6 @@
-use url::Url;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use url::{Host, Url};
 
 use crate::domain::errors::DomainError;
 
@@ -6,10 +8,14 @@ pub(super) const HOST_GITHUB: &str = "github.com";
 pub(super) const HOST_GITLAB: &str = "gitlab.com";
 pub(super) const HOST_GITEE: &str = "gitee.com";
 
+/// Path markers that end the repository part of a web URL on other git hosts
+/// (GitLab `/-/tree/<ref>`, Gitea/Forgejo `/src/branch/<ref>`, GitHub-style `/tree/<ref>`).
+const GITEA_REFERENCE_KINDS: [&str; 3] = ["branch", "tag", "commit"];
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) struct RepoSpec {
     pub(super) host: String,
-    /// `owner/repo` for GitHub/Gitee, `group/subgroup/repo` for GitLab.
+    /// `owner/repo` for GitHub/Gitee, `group/subgroup/repo` for GitLab and other git hosts.
     pub(super) repo_path: String,
     pub(super) reference_from_url: Option<String>,
 }
@@ -33,16 +39,12 @@ pub(super) fn normalize_requested_reference(reference: Option<String>) -> Option
         .filter(|value| !value.is_empty())
 }
 
-fn canonicalize_host(host: &str) -> Option<&'static str> {
+fn canonicalize_host(host: &str) -> String {
     let normalized = host.trim().to_ascii_lowercase();
-    let normalized = normalized.strip_prefix("www.").unwrap_or(&normalized);
-
-    match normalized {
-        HOST_GITHUB => Some(HOST_GITHUB),
-        HOST_GITLAB => Some(HOST_GITLAB),
-        HOST_GITEE => Some(HOST_GITEE),
-        _ => None,
-    }
+    normalized
+        .strip_prefix("www.")
+        .map(str::to_string)
+        .unwrap_or(normalized)
 }
 
 fn strip_dot_git(repo_segment: &str) -> &str {
@@ -69,12 +71,10 @@ pub(super) fn parse_repo_url(url: &str) -> Result<RepoSpec, DomainError> {
         )));
     };
 
-    let Some(host) = canonicalize_host(host) else {
-        return Err(DomainError::InvalidData(format!(
-            "Only {} / {} / {} repositories are supported",
-            HOST_GITHUB, HOST_GITLAB, HOST_GITEE
-        )));
-    };
+    let mut host = canonicalize_host(host);
+    if let Some(port) = parsed_url.port() {
+        host = format!("{}:{}", host, port);
+    }
 
     let segments = parsed_url
         .path_segments()
@@ -85,7 +85,7 @@ pub(super) fn parse_repo_url(url: &str) -> Result<RepoSpec, DomainError> {
 
     let query_reference = parse_reference_query(&parsed_url);
 
-    match host {
+    match host.as_str() {
         HOST_GITHUB | HOST_GITEE => {
             if segments.len() < 2 {
                 return Err(DomainError::InvalidData(
@@ -113,7 +113,7 @@ pub(super) fn parse_repo_url(url: &str) -> Result<RepoSpec, DomainError> {
             };
 
             Ok(RepoSpec {
-                host: host.to_string(),
+                host,
                 repo_path: format!("{}/{}", owner, repo),
                 reference_from_url: reference_from_path.or(query_reference),
             })
@@ -161,18 +161,126 @@ pub(super) fn parse_repo_url(url: &str) -> Result<RepoSpec, DomainError> {
             };
 
             Ok(RepoSpec {
-                host: host.to_string(),
+                host,
                 repo_path: repo_segments.join("/"),
                 reference_from_url: reference_from_path.or(query_reference),
             })
         }
-        _ => Err(DomainError::InvalidData(format!(
-            "Unsupported repository host: {}",
+        _ => parse_generic_git_url(&parsed_url, host, &segments, query_reference),
+    }
+}
+
+/// Any other HTTPS host is treated as a plain git remote (Codeberg, self-hosted
+/// GitLab/Gitea/Forgejo, cgit, ...).
+fn parse_generic_git_url(
+    parsed_url: &Url,
+    host: String,
+    segments: &[String],
+    query_reference: Option<String>,
+) -> Result<RepoSpec, DomainError> {
+    if parsed_url.scheme() != "https" {
+        return Err(DomainError::InvalidData(format!(
+            "Repository URL for {} must use https",
             host
-        ))),
+        )));
+    }
+    if parsed_url
+        .host()
+        .is_some_and(|host| is_local_network_host(&host))
+    {
+        return Err(DomainError::InvalidData(format!(
+            "Repository host {} is a local or private network address",
+            host
+        )));
+    }
+
+    let marker_index = segments.iter().enumerate().position(|(index, segment)| {
+        index >= 2
+            && (segment == "-"
+                || segment == "tree"
+                || (segment == "src"
+                    && segments
+                        .get(index + 1)
+                        .is_some_and(|kind| GITEA_REFERENCE_KINDS.contains(&kind.as_str()))))
+    });
+    let repo_segments_end = marker_index.unwrap_or(segments.len());
+    if repo_segments_end < 2 {
+        return Err(DomainError::InvalidData(
+            "Repository URL must include owner and repository".to_string(),
+        ));
+    }
+
+    let mut repo_segments = segments[..repo_segments_end].to_vec();
+    if let Some(last) = repo_segments.last_mut() {
+        *last = strip_dot_git(last).to_string();
+    }
+    if repo_segments
+        .iter()
+        .any(|segment| segment.trim().is_empty())
+    {
+        return Err(DomainError::InvalidData(
+            "Repository URL path contains empty segments".to_string(),
+        ));
+    }
+
+    let reference_segments = marker_index.and_then(|index| match segments[index].as_str() {
+        "-" if segments.get(index + 1).map(String::as_str) == Some("tree") => {
+            segments.get(index + 2..)
+        }
+        "src" => segments.get(index + 2..),
+        "tree" => segments.get(index + 1..),
+        _ => None,
+    });
+    let reference_from_path = reference_segments
+        .map(|reference| reference.join("/"))
+        .filter(|reference| !reference.is_empty());
+
+    Ok(RepoSpec {
+        host,
+        repo_path: repo_segments.join("/"),
+        reference_from_url: reference_from_path.or(query_reference),
+    })
+}
+
+/// Generic remotes are fetched without any allow-list, so keep them off loopback,
+/// private and link-local networks (and single-label intranet names).
+fn is_local_network_host(host: &Host<&str>) -> bool {
+    match host {
+        Host::Domain(domain) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            !domain.contains('.')
+                || [".localhost", ".local", ".internal", ".lan", ".home.arpa"]
+                    .iter()
+                    .any(|suffix| domain.ends_with(suffix))
+        }
+        Host::Ipv4(address) => is_local_ipv4(address),
+        Host::Ipv6(address) => is_local_ipv6(address),
     }
 }
 
+fn is_local_ipv4(address: &Ipv4Addr) -> bool {
+    let [first, second, ..] = address.octets();
+    address.is_loopback()
+        || address.is_private()
+        || address.is_link_local()
+        || address.is_unspecified()
+        || address.is_broadcast()
+        // Carrier-grade NAT, 100.64.0.0/10.
+        || (first == 100 && (second & 0xc0) == 64)
+}
+
+fn is_local_ipv6(address: &Ipv6Addr) -> bool {
+    if let Some(mapped) = address.to_ipv4_mapped() {
+        return is_local_ipv4(&mapped);
+    }
+    let first_segment = address.segments()[0];
+    address.is_loopback()
+        || address.is_unspecified()
+        // Unique local fc00::/7 and link-local fe80::/10.
+        || (first_segment & 0xfe00) == 0xfc00
+        || (first_segment & 0xffc0) == 0xfe80
+}
+
 #[cfg(test)]
 mod tests {
     use super::parse_repo_url;
@@ -197,4 +305,50 @@ mod tests {
             .expect("parse gitlab url");
         assert_eq!(spec.reference_from_url.as_deref(), Some("main"));
     }
+
+    #[test]
+    fn codeberg_branch_url_is_parsed_as_generic_git_remote() {
+        let spec = parse_repo_url("https://codeberg.org/owner/repo/src/branch/dev")
+            .expect("parse codeberg url");
+        assert_eq!(spec.host, "codeberg.org");
+        assert_eq!(spec.repo_path, "owner/repo");
+        assert_eq!(spec.reference_from_url.as_deref(), Some("dev"));
+    }
+
+    #[test]
+    fn generic_git_url_keeps_nested_path_and_strips_dot_git() {
+        let spec = parse_repo_url("https://git.example.org:8443/team/tools/repo.git")
+            .expect("parse generic git url");
+        assert_eq!(spec.host, "git.example.org:8443");
+        assert_eq!(spec.repo_path, "team/tools/repo");
+        assert_eq!(spec.reference_from_url, None);
+        assert_eq!(
+            spec.canonical_remote_url(),
+            "https://git.example.org:8443/team/tools/repo"
+        );
+    }
+
+    #[test]
+    fn generic_git_url_requires_https() {
+        assert!(parse_repo_url("http://git.example.org/owner/repo").is_err());
+    }
+
+    #[test]
+    fn generic_git_url_rejects_local_network_hosts() {
+        for url in [
+            "https://localhost/owner/repo",
+            "https://gitea/owner/repo",
+            "https://git.home.arpa/owner/repo",
+            "https://127.0.0.1/owner/repo",
+            "https://192.168.1.20:3000/owner/repo",
+            "https://10.0.0.5/owner/repo",
+            "https://169.254.169.254/owner/repo",
+            "https://[::1]/owner/repo",
+            "https://[fd00::1]/owner/repo",
+            "https://[::ffff:10.0.0.1]/owner/repo",
+        ] {
+            assert!(parse_repo_url(url).is_err(), "{url} should be rejected");
+        }
+        assert!(parse_repo_url("https://203.0.113.9/owner/repo").is_ok());
+    }
 }
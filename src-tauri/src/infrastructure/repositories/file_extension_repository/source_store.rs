@@ -6,7 +6,7 @@ use tokio::fs as tokio_fs;
 use url::Url;
 
 use crate::domain::errors::DomainError;
-use crate::infrastructure::persistence::file_system::read_json_file;
+use crate::infrastructure::persistence::file_system::{atomic_write, read_json_file};
 
 use super::SOURCE_METADATA_FILE;
 use super::repo_url::{HOST_GITHUB, parse_repo_url};
@@ -34,8 +34,25 @@ impl ExtensionStoreScope {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum ExtensionSourceType {
+    #[default]
+    Git,
+    /// Installed from a local zip or folder; there is no remote to update from.
+    Local,
+}
+
+impl ExtensionSourceType {
+    fn is_git(&self) -> bool {
+        *self == Self::Git
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub(super) struct ExtensionSourceMetadata {
+    #[serde(default, skip_serializing_if = "ExtensionSourceType::is_git")]
+    pub(super) source_type: ExtensionSourceType,
     pub(super) host: String,
     /// `owner/repo` for GitHub/Gitee, `group/subgroup/repo` for GitLab.
     pub(super) repo_path: String,
@@ -66,6 +83,7 @@ impl StoredSourceMetadata {
             StoredSourceMetadata::V1(legacy) => {
                 let repo_path = format!("{}/{}", legacy.owner, legacy.repo);
                 ExtensionSourceMetadata {
+                    source_type: ExtensionSourceType::Git,
                     host: HOST_GITHUB.to_string(),
                     repo_path: repo_path.clone(),
                     reference: legacy.reference,
@@ -184,13 +202,7 @@ impl ExtensionSourceStore {
             ))
         })?;
 
-        tokio_fs::write(&path, serialized).await.map_err(|error| {
-            DomainError::InternalError(format!(
-                "Failed to write extension source state '{}': {}",
-                path.display(),
-                error
-            ))
-        })
+        atomic_write(&path, serialized.as_bytes()).await
     }
 
     pub(super) fn write_sync(
@@ -723,6 +735,7 @@ impl ExtensionSourceStore {
         }
 
         Ok(Some(ExtensionSourceMetadata {
+            source_type: ExtensionSourceType::Git,
             host: repo.host.clone(),
             repo_path: repo.repo_path.clone(),
             reference,
@@ -6,8 +6,11 @@ use serde_json::json;
 use tokio::fs;
 
 use crate::domain::errors::DomainError;
+use crate::domain::models::extension::ExtensionBatchUpdateStatus;
 use crate::domain::repositories::extension_repository::ExtensionRepository;
+use crate::domain::repositories::secret_repository::SecretRepository;
 use crate::infrastructure::http_client_pool::HttpClientPool;
+use crate::infrastructure::repositories::file_secret_repository::FileSecretRepository;
 
 use super::FileExtensionRepository;
 
@@ -56,6 +59,12 @@ fn test_http_clients() -> Arc<HttpClientPool> {
     Arc::new(HttpClientPool::new())
 }
 
+fn test_secret_repository() -> Arc<dyn SecretRepository> {
+    Arc::new(FileSecretRepository::new(
+        unique_temp_root().join("secrets.json"),
+    ))
+}
+
 #[tokio::test]
 async fn startup_migration_moves_legacy_source_state_into_new_store() {
     let (root, user_extensions_dir, global_extensions_dir, source_store_root) = setup_paths().await;
@@ -75,6 +84,7 @@ async fn startup_migration_moves_legacy_source_state_into_new_store() {
         global_extensions_dir,
         source_store_root.clone(),
         test_http_clients(),
+        test_secret_repository(),
     )
     .expect("create extension repository");
 
@@ -140,6 +150,7 @@ async fn startup_migration_rebuilds_missing_source_state_from_git_dir() {
         global_extensions_dir,
         source_store_root.clone(),
         test_http_clients(),
+        test_secret_repository(),
     )
     .expect("create extension repository");
 
@@ -206,6 +217,7 @@ async fn startup_migration_rebuilds_missing_source_state_from_git_dir_for_gitlab
         global_extensions_dir,
         source_store_root.clone(),
         test_http_clients(),
+        test_secret_repository(),
     )
     .expect("create extension repository");
 
@@ -272,6 +284,7 @@ async fn startup_migration_rebuilds_missing_source_state_from_git_dir_for_gitee(
         global_extensions_dir,
         source_store_root.clone(),
         test_http_clients(),
+        test_secret_repository(),
     )
     .expect("create extension repository");
 
@@ -345,6 +358,7 @@ async fn startup_migration_rebuilds_missing_source_state_from_gitfile_commondir_
         global_extensions_dir,
         source_store_root.clone(),
         test_http_clients(),
+        test_secret_repository(),
     )
     .expect("create extension repository");
 
@@ -392,6 +406,7 @@ async fn move_extension_moves_source_state_between_scopes() {
         global_extensions_dir.clone(),
         source_store_root.clone(),
         test_http_clients(),
+        test_secret_repository(),
     )
     .expect("create extension repository");
 
@@ -445,6 +460,7 @@ async fn delete_extension_removes_source_state_file() {
         global_extensions_dir,
         source_store_root.clone(),
         test_http_clients(),
+        test_secret_repository(),
     )
     .expect("create extension repository");
 
@@ -476,6 +492,7 @@ async fn delete_extension_rejects_nested_extension_identifier() {
         global_extensions_dir,
         source_store_root,
         test_http_clients(),
+        test_secret_repository(),
     )
     .expect("create extension repository");
 
@@ -512,6 +529,7 @@ async fn discover_extensions_keeps_extensions_without_source_state_as_unmanaged(
         global_extensions_dir,
         source_store_root,
         test_http_clients(),
+        test_secret_repository(),
     )
     .expect("create extension repository");
 
@@ -563,6 +581,7 @@ async fn discover_extensions_accepts_single_item_asset_arrays_in_manifest() {
         global_extensions_dir,
         source_store_root,
         test_http_clients(),
+        test_secret_repository(),
     )
     .expect("create extension repository");
 
@@ -586,3 +605,212 @@ async fn discover_extensions_accepts_single_item_asset_arrays_in_manifest() {
 
     fs::remove_dir_all(root).await.expect("cleanup temp root");
 }
+
+#[tokio::test]
+async fn install_extension_from_folder_records_local_source() {
+    let (root, user_extensions_dir, global_extensions_dir, source_store_root) = setup_paths().await;
+    let source_dir = root.join("dev").join("my-local-ext");
+    fs::create_dir_all(source_dir.join(".git"))
+        .await
+        .expect("create source git dir");
+    fs::write(
+        source_dir.join("manifest.json"),
+        serde_json::to_vec_pretty(&json!({
+            "display_name": "My Local Extension",
+            "version": "0.1.0",
+            "author": "dev",
+            "js": "index.js"
+        }))
+        .expect("serialize manifest"),
+    )
+    .await
+    .expect("write manifest");
+    fs::write(source_dir.join("index.js"), "export {};")
+        .await
+        .expect("write script");
+
+    let repository = FileExtensionRepository::new(
+        user_extensions_dir.clone(),
+        global_extensions_dir,
+        source_store_root.clone(),
+        test_http_clients(),
+        test_secret_repository(),
+    )
+    .expect("create extension repository");
+
+    let result = repository
+        .install_extension_from_path(&source_dir, false)
+        .await
+        .expect("install from folder");
+    assert_eq!(result.display_name, "My Local Extension");
+
+    let extension_dir = user_extensions_dir.join("my-local-ext");
+    assert!(extension_dir.join("index.js").is_file());
+    assert!(!extension_dir.join(".git").exists());
+
+    let record: serde_json::Value = serde_json::from_slice(
+        &fs::read(source_store_root.join("local").join("my-local-ext.json"))
+            .await
+            .expect("read source state"),
+    )
+    .expect("parse source state");
+    assert_eq!(record["source_type"], "local");
+
+    let update = repository
+        .update_extension("third-party/my-local-ext", false)
+        .await
+        .expect("local extensions are always up to date");
+    assert!(update.is_up_to_date);
+
+    let duplicate = repository
+        .install_extension_from_path(&source_dir, false)
+        .await
+        .expect_err("second install should fail");
+    assert!(matches!(duplicate, DomainError::InvalidData(_)));
+
+    fs::remove_dir_all(root).await.expect("cleanup temp root");
+}
+
+async fn write_managed_extension(
+    extensions_dir: &std::path::Path,
+    source_state_dir: &std::path::Path,
+    folder_name: &str,
+    source: serde_json::Value,
+) {
+    let extension_dir = extensions_dir.join(folder_name);
+    fs::create_dir_all(&extension_dir)
+        .await
+        .expect("create extension dir");
+    fs::write(
+        extension_dir.join("manifest.json"),
+        serde_json::to_vec_pretty(&json!({
+            "display_name": folder_name,
+            "version": "1.0.0",
+            "author": "dev",
+            "js": "index.js"
+        }))
+        .expect("serialize manifest"),
+    )
+    .await
+    .expect("write manifest");
+    fs::write(
+        source_state_dir.join(format!("{folder_name}.json")),
+        serde_json::to_vec_pretty(&source).expect("serialize source state"),
+    )
+    .await
+    .expect("write source state");
+}
+
+#[tokio::test]
+async fn update_all_extensions_reports_failures_without_stopping_the_batch() {
+    let (root, user_extensions_dir, global_extensions_dir, source_store_root) = setup_paths().await;
+    write_managed_extension(
+        &user_extensions_dir,
+        &source_store_root.join("local"),
+        "local-ext",
+        json!({
+            "source_type": "local",
+            "host": "",
+            "repo_path": "",
+            "reference": "",
+            "remote_url": "",
+            "installed_commit": ""
+        }),
+    )
+    .await;
+    write_managed_extension(
+        &user_extensions_dir,
+        &source_store_root.join("local"),
+        "broken-ext",
+        json!({
+            "host": "not a host",
+            "repo_path": "owner/repo",
+            "reference": "main",
+            "remote_url": "https://example.invalid/owner/repo",
+            "installed_commit": "abcdef1234567890"
+        }),
+    )
+    .await;
+
+    let repository = FileExtensionRepository::new(
+        user_extensions_dir,
+        global_extensions_dir,
+        source_store_root,
+        test_http_clients(),
+        test_secret_repository(),
+    )
+    .expect("create extension repository");
+
+    let results = repository
+        .update_all_extensions(false)
+        .await
+        .expect("batch update");
+    let statuses: Vec<_> = results
+        .iter()
+        .map(|result| (result.extension_name.as_str(), result.status))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            ("third-party/broken-ext", ExtensionBatchUpdateStatus::Failed),
+            (
+                "third-party/local-ext",
+                ExtensionBatchUpdateStatus::UpToDate
+            ),
+        ]
+    );
+    assert!(
+        results[0]
+            .error
+            .as_deref()
+            .is_some_and(|error| error.contains("Unsupported extension repository host"))
+    );
+    assert_eq!(results[1].short_commit_hash, None);
+
+    fs::remove_dir_all(root).await.expect("cleanup temp root");
+}
+
+#[tokio::test]
+async fn update_all_extensions_skips_global_extensions_unless_requested() {
+    let (root, user_extensions_dir, global_extensions_dir, source_store_root) = setup_paths().await;
+    write_managed_extension(
+        &global_extensions_dir,
+        &source_store_root.join("global"),
+        "shared-ext",
+        json!({
+            "source_type": "local",
+            "host": "",
+            "repo_path": "",
+            "reference": "",
+            "remote_url": "",
+            "installed_commit": ""
+        }),
+    )
+    .await;
+
+    let repository = FileExtensionRepository::new(
+        user_extensions_dir,
+        global_extensions_dir,
+        source_store_root,
+        test_http_clients(),
+        test_secret_repository(),
+    )
+    .expect("create extension repository");
+
+    assert!(
+        repository
+            .update_all_extensions(false)
+            .await
+            .expect("batch update")
+            .is_empty()
+    );
+    let results = repository
+        .update_all_extensions(true)
+        .await
+        .expect("batch update");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].global);
+    assert_eq!(results[0].status, ExtensionBatchUpdateStatus::UpToDate);
+
+    fs::remove_dir_all(root).await.expect("cleanup temp root");
+}
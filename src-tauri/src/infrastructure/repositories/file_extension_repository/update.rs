@@ -1,8 +1,144 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use futures_util::StreamExt;
+
 use crate::domain::errors::DomainError;
-use crate::domain::models::extension::ExtensionUpdateResult;
+use crate::domain::models::extension::{
+    ExtensionBatchUpdateResult, ExtensionBatchUpdateStatus, ExtensionType, ExtensionUpdateResult,
+};
 
 use super::FileExtensionRepository;
-use super::source_store::ExtensionStoreScope;
+use super::discovery;
+use super::source_store::{ExtensionSourceMetadata, ExtensionSourceType, ExtensionStoreScope};
+
+/// Concurrent provider requests during a batch update; keeps well under anonymous API rate limits.
+const BATCH_UPDATE_CONCURRENCY: usize = 4;
+
+/// An installed extension resolved to the source it updates from.
+struct UpdateTarget {
+    scope: ExtensionStoreScope,
+    folder_name: String,
+    path: PathBuf,
+    source: ExtensionSourceMetadata,
+}
+
+impl UpdateTarget {
+    /// Remote whose latest commit decides whether the extension is outdated; `None` for
+    /// extensions installed from a local zip or folder.
+    fn remote(&self) -> Option<(String, String, String)> {
+        (self.source.source_type != ExtensionSourceType::Local).then(|| {
+            (
+                self.source.host.clone(),
+                self.source.repo_path.clone(),
+                self.source.reference.clone(),
+            )
+        })
+    }
+}
+
+/// Updates every managed extension in one pass. Sources are resolved locally first, each
+/// distinct remote is asked for its latest commit once, and only outdated extensions
+/// download a snapshot. A failing extension is reported without stopping the others.
+pub(super) async fn update_all_extensions(
+    repository: &FileExtensionRepository,
+    include_global: bool,
+) -> Result<Vec<ExtensionBatchUpdateResult>, DomainError> {
+    let extensions = discovery::discover_extensions(repository)
+        .await?
+        .into_iter()
+        .filter(|extension| extension.managed)
+        .filter_map(|extension| match extension.extension_type {
+            ExtensionType::Local => Some((extension.name, false)),
+            ExtensionType::Global if include_global => Some((extension.name, true)),
+            ExtensionType::Global | ExtensionType::System => None,
+        })
+        .collect::<Vec<_>>();
+    tracing::info!("Updating {} extensions", extensions.len());
+
+    let mut targets = Vec::with_capacity(extensions.len());
+    for (extension_name, global) in extensions {
+        let target = resolve_update_target(repository, &extension_name, global).await;
+        targets.push((extension_name, global, target));
+    }
+
+    let remotes = targets
+        .iter()
+        .filter_map(|(_, _, target)| target.as_ref().ok().and_then(UpdateTarget::remote))
+        .collect::<HashSet<_>>();
+    let latest_commits = futures_util::stream::iter(remotes)
+        .map(|remote| async move {
+            let (host, repo_path, reference) = &remote;
+            let latest = match repository.providers.for_host(host) {
+                Ok(provider) => provider.latest_commit(repo_path, reference).await,
+                Err(error) => Err(error),
+            };
+            (remote, latest.map_err(|error| error.to_string()))
+        })
+        .buffer_unordered(BATCH_UPDATE_CONCURRENCY)
+        .collect::<HashMap<_, _>>()
+        .await;
+
+    let mut results = futures_util::stream::iter(targets)
+        .map(|(extension_name, global, target)| {
+            let latest_commits = &latest_commits;
+            async move {
+                let result = match target {
+                    Ok(target) => match target.remote().map(|remote| &latest_commits[&remote]) {
+                        Some(Err(error)) => Err(error.clone()),
+                        Some(Ok(latest)) => apply_update(repository, target, Some(latest.clone()))
+                            .await
+                            .map_err(|error| error.to_string()),
+                        None => apply_update(repository, target, None)
+                            .await
+                            .map_err(|error| error.to_string()),
+                    },
+                    Err(error) => Err(error.to_string()),
+                };
+                batch_result(extension_name, global, result)
+            }
+        })
+        .buffer_unordered(BATCH_UPDATE_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    results.sort_by(|left, right| {
+        left.extension_name
+            .cmp(&right.extension_name)
+            .then(left.global.cmp(&right.global))
+    });
+    Ok(results)
+}
+
+fn batch_result(
+    extension_name: String,
+    global: bool,
+    result: Result<ExtensionUpdateResult, String>,
+) -> ExtensionBatchUpdateResult {
+    match result {
+        Ok(result) => ExtensionBatchUpdateResult {
+            extension_name,
+            global,
+            status: if result.is_up_to_date {
+                ExtensionBatchUpdateStatus::UpToDate
+            } else {
+                ExtensionBatchUpdateStatus::Updated
+            },
+            short_commit_hash: Some(result.short_commit_hash).filter(|hash| !hash.is_empty()),
+            error: None,
+        },
+        Err(error) => {
+            tracing::warn!("Failed to update extension {}: {}", extension_name, error);
+            ExtensionBatchUpdateResult {
+                extension_name,
+                global,
+                status: ExtensionBatchUpdateStatus::Failed,
+                short_commit_hash: None,
+                error: Some(error),
+            }
+        }
+    }
+}
 
 pub(super) async fn update_extension(
     repository: &FileExtensionRepository,
@@ -11,18 +147,37 @@ pub(super) async fn update_extension(
 ) -> Result<ExtensionUpdateResult, DomainError> {
     tracing::info!("Updating extension: {}", extension_name);
 
+    let target = resolve_update_target(repository, extension_name, global).await?;
+    let latest_commit = match target.remote() {
+        Some((host, repo_path, reference)) => Some(
+            repository
+                .providers
+                .for_host(&host)?
+                .latest_commit(&repo_path, &reference)
+                .await?,
+        ),
+        None => None,
+    };
+    apply_update(repository, target, latest_commit).await
+}
+
+async fn resolve_update_target(
+    repository: &FileExtensionRepository,
+    extension_name: &str,
+    global: bool,
+) -> Result<UpdateTarget, DomainError> {
     let scope = ExtensionStoreScope::from_global(global);
-    let extension_folder_name = repository.extension_folder_name_from_identifier(extension_name)?;
-    let extension_path = repository.resolve_extension_path(&extension_folder_name, global);
-    if !extension_path.exists() {
+    let folder_name = repository.extension_folder_name_from_identifier(extension_name)?;
+    let path = repository.resolve_extension_path(&folder_name, global);
+    if !path.exists() {
         return Err(DomainError::NotFound(format!(
             "Extension not found at '{}'",
-            extension_path.display()
+            path.display()
         )));
     }
 
-    let mut source = repository
-        .resolve_source_metadata(scope, &extension_folder_name, &extension_path)
+    let source = repository
+        .resolve_source_metadata(scope, &folder_name, &path)
         .await?
         .ok_or_else(|| {
             DomainError::InvalidData(
@@ -31,13 +186,40 @@ pub(super) async fn update_extension(
             )
         })?;
 
-    let provider = repository.providers.for_host(source.host.as_str())?;
-    let latest_commit = provider
-        .latest_commit(source.repo_path.as_str(), source.reference.as_str())
-        .await?;
+    Ok(UpdateTarget {
+        scope,
+        folder_name,
+        path,
+        source,
+    })
+}
+
+/// Brings `target` to `latest_commit`. Extensions already at that commit, and local
+/// installs (`latest_commit` is `None`), return without downloading anything.
+async fn apply_update(
+    repository: &FileExtensionRepository,
+    target: UpdateTarget,
+    latest_commit: Option<String>,
+) -> Result<ExtensionUpdateResult, DomainError> {
+    let UpdateTarget {
+        scope,
+        folder_name,
+        path: extension_path,
+        mut source,
+    } = target;
+
+    let Some(latest_commit) = latest_commit else {
+        return Ok(ExtensionUpdateResult {
+            short_commit_hash: String::new(),
+            extension_path: extension_path.to_string_lossy().to_string(),
+            is_up_to_date: true,
+            remote_url: source.remote_url,
+        });
+    };
     let is_up_to_date = source.installed_commit == latest_commit;
 
     if !is_up_to_date {
+        let provider = repository.providers.for_host(source.host.as_str())?;
         let base_dir = extension_path.parent().ok_or_else(|| {
             DomainError::InternalError(format!(
                 "Failed to resolve parent directory for '{}'",
@@ -47,8 +229,9 @@ pub(super) async fn update_extension(
 
         let (staging_dir, _) = repository
             .stage_extension_snapshot(
-                provider,
+                &*provider,
                 source.repo_path.as_str(),
+                source.reference.as_str(),
                 latest_commit.as_str(),
                 base_dir,
                 "extension-update",
@@ -63,7 +246,7 @@ pub(super) async fn update_extension(
         source.installed_commit = latest_commit.clone();
         repository
             .source_store
-            .write(scope, &extension_folder_name, &source)
+            .write(scope, &folder_name, &source)
             .await?;
     }
 
@@ -2,7 +2,7 @@ use crate::domain::errors::DomainError;
 use crate::domain::models::extension::ExtensionVersion;
 
 use super::FileExtensionRepository;
-use super::source_store::ExtensionStoreScope;
+use super::source_store::{ExtensionSourceType, ExtensionStoreScope};
 
 pub(super) async fn get_extension_version(
     repository: &FileExtensionRepository,
@@ -36,6 +36,15 @@ pub(super) async fn get_extension_version(
         }
     };
 
+    if source.source_type == ExtensionSourceType::Local {
+        return Ok(ExtensionVersion {
+            current_branch_name: source.reference,
+            current_commit_hash: source.installed_commit,
+            is_up_to_date: true,
+            remote_url: source.remote_url,
+        });
+    }
+
     let provider = repository.providers.for_host(source.host.as_str())?;
     let latest_commit = provider
         .latest_commit(source.repo_path.as_str(), source.reference.as_str())
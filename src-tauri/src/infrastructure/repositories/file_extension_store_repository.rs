@@ -7,9 +7,7 @@ use tokio::fs;
 use crate::domain::errors::DomainError;
 use crate::domain::json_merge::merge_json_value;
 use crate::domain::repositories::extension_store_repository::ExtensionStoreRepository;
-use crate::infrastructure::persistence::file_system::{
-    replace_file_with_fallback, unique_temp_path,
-};
+use crate::infrastructure::persistence::file_system::atomic_write;
 
 pub struct FileExtensionStoreRepository {
     base_dir: PathBuf,
@@ -296,16 +294,7 @@ impl ExtensionStoreRepository for FileExtensionStoreRepository {
             ))
         })?;
 
-        let temp = unique_temp_path(&path, "extension-store.json");
-        fs::write(&temp, &bytes).await.map_err(|error| {
-            DomainError::InternalError(format!(
-                "Failed to write extension store temp file {}: {}",
-                temp.display(),
-                error
-            ))
-        })?;
-
-        replace_file_with_fallback(&temp, &path).await?;
+        atomic_write(&path, &bytes).await?;
         Ok(())
     }
 
@@ -338,16 +327,7 @@ impl ExtensionStoreRepository for FileExtensionStoreRepository {
             ))
         })?;
 
-        let temp = unique_temp_path(&path, "extension-store.json");
-        fs::write(&temp, &bytes).await.map_err(|error| {
-            DomainError::InternalError(format!(
-                "Failed to write extension store temp file {}: {}",
-                temp.display(),
-                error
-            ))
-        })?;
-
-        replace_file_with_fallback(&temp, &path).await?;
+        atomic_write(&path, &bytes).await?;
         Ok(())
     }
 
@@ -518,16 +498,7 @@ impl ExtensionStoreRepository for FileExtensionStoreRepository {
             })?;
         }
 
-        let temp = unique_temp_path(&path, "extension-store.blob");
-        fs::write(&temp, &bytes).await.map_err(|error| {
-            DomainError::InternalError(format!(
-                "Failed to write extension store temp file {}: {}",
-                temp.display(),
-                error
-            ))
-        })?;
-
-        replace_file_with_fallback(&temp, &path).await?;
+        atomic_write(&path, &bytes).await?;
         Ok(())
     }
 
@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use std::io;
+use std::path::PathBuf;
+
+use serde_json::{Map, Value};
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::global_variable_repository::GlobalVariableRepository;
+use crate::infrastructure::persistence::file_system::atomic_write;
+
+pub struct FileGlobalVariableRepository {
+    path: PathBuf,
+}
+
+impl FileGlobalVariableRepository {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+#[async_trait]
+impl GlobalVariableRepository for FileGlobalVariableRepository {
+    async fn load_global_variables(&self) -> Result<Map<String, Value>, DomainError> {
+        let contents = match fs::read_to_string(&self.path).await {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Map::new()),
+            Err(error) => {
+                return Err(DomainError::InternalError(format!(
+                    "Failed to read global variables {:?}: {}",
+                    self.path, error
+                )));
+            }
+        };
+
+        serde_json::from_str::<Map<String, Value>>(&contents).map_err(|error| {
+            DomainError::InvalidData(format!(
+                "Invalid JSON in global variables {:?}: {}",
+                self.path, error
+            ))
+        })
+    }
+
+    async fn save_global_variables(
+        &self,
+        variables: &Map<String, Value>,
+    ) -> Result<(), DomainError> {
+        let json = serde_json::to_vec_pretty(variables).map_err(|error| {
+            DomainError::InvalidData(format!("Failed to serialize global variables: {}", error))
+        })?;
+
+        atomic_write(&self.path, &json).await
+    }
+}
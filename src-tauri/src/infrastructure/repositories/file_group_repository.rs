@@ -1,4 +1,6 @@
 use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use chrono::{DateTime, Datelike, Timelike, Utc};
 use serde_json::{Number, Value};
 use std::collections::HashMap;
@@ -9,6 +11,7 @@ use tokio::fs;
 use tokio::sync::Mutex;
 
 use crate::domain::errors::DomainError;
+use crate::domain::models::avatar::CropInfo;
 use crate::domain::models::group::Group;
 use crate::domain::repositories::group_repository::GroupRepository;
 use crate::infrastructure::logging::logger;
@@ -16,6 +19,10 @@ use crate::infrastructure::persistence::file_system::{
     list_files_with_extension, read_json_file, write_json_file,
 };
 
+// SillyTavern stores group avatars inline as thumbnails of at most this size
+const GROUP_AVATAR_MAX_WIDTH: u32 = 200;
+const GROUP_AVATAR_MAX_HEIGHT: u32 = 300;
+
 /// File-based implementation of the GroupRepository
 pub struct FileGroupRepository {
     /// Directory where group files are stored
@@ -171,6 +178,58 @@ impl FileGroupRepository {
     }
 }
 
+/// Encodes the image at `file_path` as the PNG data URL SillyTavern keeps in `avatar_url`.
+async fn encode_group_avatar(
+    file_path: &Path,
+    crop: Option<CropInfo>,
+) -> Result<String, DomainError> {
+    let bytes = fs::read(file_path).await.map_err(|error| {
+        DomainError::InvalidData(format!("Failed to read avatar image: {}", error))
+    })?;
+    let mut image = image::load_from_memory(&bytes)
+        .map_err(|error| DomainError::InvalidData(format!("Invalid avatar image: {}", error)))?;
+
+    if let Some(crop) = crop {
+        if crop.x >= 0
+            && crop.y >= 0
+            && crop.width > 0
+            && crop.height > 0
+            && (crop.x as u32) < image.width()
+            && (crop.y as u32) < image.height()
+        {
+            image = image.crop_imm(
+                crop.x as u32,
+                crop.y as u32,
+                crop.width as u32,
+                crop.height as u32,
+            );
+        }
+    }
+
+    if image.width() > GROUP_AVATAR_MAX_WIDTH || image.height() > GROUP_AVATAR_MAX_HEIGHT {
+        image = image.resize(
+            GROUP_AVATAR_MAX_WIDTH,
+            GROUP_AVATAR_MAX_HEIGHT,
+            image::imageops::FilterType::Lanczos3,
+        );
+    }
+
+    let mut encoded = Vec::new();
+    image
+        .write_to(
+            &mut std::io::Cursor::new(&mut encoded),
+            image::ImageFormat::Png,
+        )
+        .map_err(|error| {
+            DomainError::InternalError(format!("Failed to encode avatar image: {}", error))
+        })?;
+
+    Ok(format!(
+        "data:image/png;base64,{}",
+        BASE64_STANDARD.encode(encoded)
+    ))
+}
+
 async fn read_group_manifest_compat(file_path: &Path) -> Result<Group, DomainError> {
     let value: Value = read_json_file(file_path).await?;
     decode_group_manifest_compat(value, file_path)
@@ -496,6 +555,31 @@ impl GroupRepository for FileGroupRepository {
         Ok(())
     }
 
+    async fn update_group_avatar(
+        &self,
+        id: &str,
+        file_path: &Path,
+        crop: Option<CropInfo>,
+    ) -> Result<Group, DomainError> {
+        let mut group = self
+            .get_group(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Group not found: {}", id)))?;
+
+        group.avatar_url = Some(encode_group_avatar(file_path, crop).await?);
+        self.update_group(&group).await
+    }
+
+    async fn delete_group_avatar(&self, id: &str) -> Result<Group, DomainError> {
+        let mut group = self
+            .get_group(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Group not found: {}", id)))?;
+
+        group.avatar_url = None;
+        self.update_group(&group).await
+    }
+
     async fn get_group_chat_paths(&self) -> Result<Vec<String>, DomainError> {
         let chat_files = list_files_with_extension(&self.group_chats_dir, "jsonl").await?;
 
@@ -602,6 +686,64 @@ mod tests {
         assert!(error.to_string().contains("activation_strategy"));
     }
 
+    #[tokio::test]
+    async fn group_avatar_is_stored_inline_and_removed() {
+        let root =
+            std::env::temp_dir().join(format!("tauritavern-group-avatar-{}", Uuid::new_v4()));
+        let groups_dir = root.join("groups");
+        let image_path = root.join("avatar.png");
+        tokio::fs::create_dir_all(&groups_dir)
+            .await
+            .expect("create groups dir");
+        image::RgbaImage::new(400, 600)
+            .save_with_format(&image_path, image::ImageFormat::Png)
+            .expect("write avatar image");
+
+        let mut manifest = base_group_manifest();
+        manifest["auto_mode_delay"] = json!(12);
+        manifest["hideMutedSprites"] = json!(true);
+        manifest["disabled_members"] = json!(["Bob.png"]);
+        tokio::fs::write(
+            groups_dir.join("legacy-group.json"),
+            serde_json::to_string(&manifest).expect("serialize manifest"),
+        )
+        .await
+        .expect("write manifest");
+
+        let repository = FileGroupRepository::new(groups_dir.clone(), root.join("group chats"));
+        let group = repository
+            .update_group_avatar("legacy-group", &image_path, None)
+            .await
+            .expect("update avatar");
+        let avatar_url = group.avatar_url.expect("avatar url");
+        let encoded = avatar_url
+            .strip_prefix("data:image/png;base64,")
+            .expect("png data url");
+        let thumbnail =
+            image::load_from_memory(&BASE64_STANDARD.decode(encoded).expect("decode avatar"))
+                .expect("load avatar");
+        assert_eq!((thumbnail.width(), thumbnail.height()), (200, 300));
+
+        let group = repository
+            .delete_group_avatar("legacy-group")
+            .await
+            .expect("delete avatar");
+        assert!(group.avatar_url.is_none());
+
+        let persisted: Value = serde_json::from_str(
+            &tokio::fs::read_to_string(groups_dir.join("legacy-group.json"))
+                .await
+                .expect("read manifest"),
+        )
+        .expect("parse manifest");
+        assert_eq!(persisted["auto_mode_delay"], json!(12));
+        assert_eq!(persisted["hideMutedSprites"], json!(true));
+        assert_eq!(persisted["disabled_members"], json!(["Bob.png"]));
+        assert_eq!(persisted["avatar_url"], Value::Null);
+
+        let _ = tokio::fs::remove_dir_all(root).await;
+    }
+
     #[tokio::test]
     async fn group_repository_loads_and_rewrites_legacy_group_manifest() {
         let root = std::env::temp_dir().join(format!(
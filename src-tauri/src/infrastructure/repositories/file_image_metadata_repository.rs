@@ -8,7 +8,7 @@ use tokio::fs;
 use tokio::sync::Mutex;
 
 use crate::domain::errors::DomainError;
-use crate::domain::models::background::BackgroundListEntry;
+use crate::domain::models::background::{BackgroundListEntry, BackgroundMediaType};
 use crate::domain::models::image_metadata::{
     BackgroundFoldersPayload, ImageMetadata, ImageMetadataFolder, ImageMetadataIndex,
 };
@@ -593,8 +593,10 @@ impl ImageMetadataRepository for FileImageMetadataRepository {
                     .get(&relative_path)
                     .and_then(|metadata| metadata.is_animated)
                     .unwrap_or(false);
+                let filename = Self::filename_from_background_relative_path(&relative_path);
                 BackgroundListEntry {
-                    filename: Self::filename_from_background_relative_path(&relative_path),
+                    media_type: BackgroundMediaType::from_filename(&filename),
+                    filename,
                     is_animated,
                 }
             })
@@ -859,6 +861,7 @@ mod tests {
     use serde_json::json;
 
     use crate::domain::errors::DomainError;
+    use crate::domain::models::background::BackgroundMediaType;
     use crate::domain::models::image_metadata::{
         ImageMetadata, ImageMetadataFolder, ImageMetadataIndex,
     };
@@ -1030,6 +1033,7 @@ mod tests {
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].filename, "a.png");
         assert!(!entries[0].is_animated);
+        assert_eq!(entries[0].media_type, BackgroundMediaType::Image);
         assert_eq!(metadata.folder_ids, vec![folder.id]);
         assert_eq!(metadata.is_animated, Some(false));
         assert_eq!(metadata.thumbnail_resolution, Some(160 * 90));
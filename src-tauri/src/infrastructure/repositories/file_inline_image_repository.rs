@@ -0,0 +1,217 @@
+use std::io::Cursor;
+use std::path::{Component, Path, PathBuf};
+
+use async_trait::async_trait;
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, GenericImageView, ImageFormat};
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::inline_image_repository::{
+    InlineImage, InlineImageLimits, InlineImageRepository,
+};
+
+const JPEG_QUALITY_STEPS: &[u8] = &[85, 75, 65, 50];
+const DOWNSCALE_FACTOR: f64 = 0.75;
+const MIN_DIMENSION: u32 = 64;
+
+pub struct FileInlineImageRepository {
+    user_dir: PathBuf,
+}
+
+impl FileInlineImageRepository {
+    pub fn new(user_dir: PathBuf) -> Self {
+        Self { user_dir }
+    }
+
+    fn resolve_reference(&self, reference: &str) -> Result<PathBuf, DomainError> {
+        let reference = reference.trim();
+        let reference = reference.strip_prefix("file://").unwrap_or(reference);
+        let path = Path::new(reference);
+        // Absolute filesystem paths (desktop attachments) are read as-is; web-style
+        // `/user/images/...` references fall through to the user directory.
+        if path.is_absolute() && path.exists() {
+            return Ok(path.to_path_buf());
+        }
+
+        let relative = Path::new(reference.trim_start_matches(['/', '\\']));
+        if relative
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_)))
+        {
+            return Err(DomainError::InvalidData(format!(
+                "Invalid image reference: {reference}"
+            )));
+        }
+
+        Ok(self.user_dir.join(relative))
+    }
+}
+
+#[async_trait]
+impl InlineImageRepository for FileInlineImageRepository {
+    async fn load_inline_image(
+        &self,
+        reference: &str,
+        limits: InlineImageLimits,
+    ) -> Result<InlineImage, DomainError> {
+        let path = self.resolve_reference(reference)?;
+        let bytes = tokio::fs::read(&path)
+            .await
+            .map_err(|error| match error.kind() {
+                std::io::ErrorKind::NotFound => {
+                    DomainError::NotFound(format!("Image not found: {}", path.display()))
+                }
+                _ => DomainError::InternalError(format!(
+                    "Failed to read image '{}': {}",
+                    path.display(),
+                    error
+                )),
+            })?;
+
+        tokio::task::spawn_blocking(move || fit_image(&path, bytes, limits))
+            .await
+            .map_err(|error| {
+                DomainError::InternalError(format!("Image processing task failed: {error}"))
+            })?
+    }
+}
+
+fn fit_image(
+    path: &Path,
+    bytes: Vec<u8>,
+    limits: InlineImageLimits,
+) -> Result<InlineImage, DomainError> {
+    let format = image::guess_format(&bytes).map_err(|error| {
+        DomainError::InvalidData(format!("Unsupported image '{}': {}", path.display(), error))
+    })?;
+    let image = image::load_from_memory_with_format(&bytes, format).map_err(|error| {
+        DomainError::InvalidData(format!(
+            "Failed to decode image '{}': {}",
+            path.display(),
+            error
+        ))
+    })?;
+
+    let (width, height) = image.dimensions();
+    let max_dimension = limits.max_dimension.max(MIN_DIMENSION);
+    let passthrough_mime = match format {
+        ImageFormat::Png => Some("image/png"),
+        ImageFormat::Jpeg => Some("image/jpeg"),
+        ImageFormat::WebP => Some("image/webp"),
+        ImageFormat::Gif => Some("image/gif"),
+        _ => None,
+    };
+    if let Some(mime_type) = passthrough_mime
+        && width.max(height) <= max_dimension
+        && bytes.len() <= limits.max_bytes
+    {
+        return Ok(InlineImage {
+            mime_type: mime_type.to_string(),
+            bytes,
+        });
+    }
+
+    let mut current = downscale_to(&image, max_dimension);
+    loop {
+        for quality in JPEG_QUALITY_STEPS {
+            let encoded = encode_jpeg(path, &current, *quality)?;
+            if encoded.len() <= limits.max_bytes {
+                return Ok(InlineImage {
+                    mime_type: "image/jpeg".to_string(),
+                    bytes: encoded,
+                });
+            }
+        }
+
+        let longest = current.width().max(current.height());
+        if longest <= MIN_DIMENSION {
+            return Err(DomainError::InvalidData(format!(
+                "Image '{}' cannot be compressed below {} bytes",
+                path.display(),
+                limits.max_bytes
+            )));
+        }
+        let next = ((longest as f64) * DOWNSCALE_FACTOR).round() as u32;
+        current = downscale_to(&current, next.max(MIN_DIMENSION));
+    }
+}
+
+fn downscale_to(image: &DynamicImage, max_dimension: u32) -> DynamicImage {
+    if image.width().max(image.height()) <= max_dimension {
+        return image.clone();
+    }
+    image.resize(max_dimension, max_dimension, FilterType::Triangle)
+}
+
+fn encode_jpeg(path: &Path, image: &DynamicImage, quality: u8) -> Result<Vec<u8>, DomainError> {
+    // JPEG has no alpha channel; flatten first so transparent PNGs still encode.
+    let rgb = DynamicImage::ImageRgb8(image.to_rgb8());
+    let mut encoded = Cursor::new(Vec::new());
+    JpegEncoder::new_with_quality(&mut encoded, quality)
+        .encode_image(&rgb)
+        .map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to encode image '{}': {}",
+                path.display(),
+                error
+            ))
+        })?;
+    Ok(encoded.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::path::Path;
+
+    use image::{DynamicImage, ImageFormat, RgbImage};
+
+    use super::{FileInlineImageRepository, fit_image};
+    use crate::domain::repositories::inline_image_repository::InlineImageLimits;
+
+    fn png_bytes(width: u32, height: u32) -> Vec<u8> {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_fn(width, height, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, ((x + y) % 256) as u8])
+        }));
+        let mut bytes = Cursor::new(Vec::new());
+        image
+            .write_to(&mut bytes, ImageFormat::Png)
+            .expect("png should encode");
+        bytes.into_inner()
+    }
+
+    #[test]
+    fn small_images_pass_through_unchanged() {
+        let bytes = png_bytes(32, 32);
+        let limits = InlineImageLimits {
+            max_dimension: 512,
+            max_bytes: 1024 * 1024,
+        };
+
+        let image = fit_image(Path::new("small.png"), bytes.clone(), limits).expect("fits");
+        assert_eq!(image.mime_type, "image/png");
+        assert_eq!(image.bytes, bytes);
+    }
+
+    #[test]
+    fn oversized_images_are_downscaled_to_jpeg() {
+        let limits = InlineImageLimits {
+            max_dimension: 256,
+            max_bytes: 1024 * 1024,
+        };
+
+        let image = fit_image(Path::new("large.png"), png_bytes(1024, 512), limits).expect("fits");
+        assert_eq!(image.mime_type, "image/jpeg");
+        let decoded = image::load_from_memory(&image.bytes).expect("jpeg should decode");
+        assert_eq!((decoded.width(), decoded.height()), (256, 128));
+    }
+
+    #[test]
+    fn relative_references_cannot_escape_user_dir() {
+        let repository = FileInlineImageRepository::new("/data/default-user".into());
+
+        assert!(repository.resolve_reference("user/images/a.png").is_ok());
+        assert!(repository.resolve_reference("../secrets.json").is_err());
+    }
+}
@@ -11,7 +11,7 @@ use crate::domain::models::llm_connection::{
 };
 use crate::domain::repositories::llm_connection_repository::LlmConnectionRepository;
 use crate::infrastructure::persistence::file_system::{
-    list_files_with_extension, read_json_file, replace_file_with_fallback,
+    commit_temp_file, list_files_with_extension, read_json_file,
 };
 
 pub struct FileLlmConnectionRepository {
@@ -120,7 +120,7 @@ impl LlmConnectionRepository for FileLlmConnectionRepository {
                 error
             ))
         })?;
-        replace_file_with_fallback(&temp, &target).await
+        commit_temp_file(&temp, &target).await
     }
 
     async fn delete_connection(&self, id: &LlmConnectionId) -> Result<(), DomainError> {
@@ -8,9 +8,7 @@ use crate::domain::errors::DomainError;
 use crate::domain::repositories::prompt_cache_repository::{
     PromptCacheKey, PromptCacheRepository, PromptDigestSnapshot,
 };
-use crate::infrastructure::persistence::file_system::{
-    replace_file_with_fallback, unique_temp_path,
-};
+use crate::infrastructure::persistence::file_system::atomic_write;
 
 pub struct FilePromptCacheRepository {
     base_dir: PathBuf,
@@ -89,15 +87,7 @@ impl PromptCacheRepository for FilePromptCacheRepository {
             ))
         })?;
 
-        let temp_path = unique_temp_path(&path, "prompt-cache.json");
-        fs::write(&temp_path, json).await.map_err(|error| {
-            DomainError::InternalError(format!(
-                "Failed to write prompt cache snapshot {:?}: {}",
-                temp_path, error
-            ))
-        })?;
-
-        replace_file_with_fallback(&temp_path, &path).await?;
+        atomic_write(&path, &json).await?;
         Ok(())
     }
 }
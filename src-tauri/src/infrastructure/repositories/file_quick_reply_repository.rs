@@ -1,12 +1,16 @@
 use async_trait::async_trait;
-use std::path::PathBuf;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 
 use crate::domain::errors::DomainError;
 use crate::domain::models::filename::sanitize_filename;
 use crate::domain::models::quick_reply::QuickReplySet;
 use crate::domain::repositories::quick_reply_repository::QuickReplyRepository;
-use crate::infrastructure::persistence::file_system::{delete_file, write_json_file};
+use crate::infrastructure::logging::logger;
+use crate::infrastructure::persistence::file_system::{
+    delete_file, list_files_with_extension, read_json_file, write_json_file,
+};
 
 pub struct FileQuickReplyRepository {
     quick_replies_dir: PathBuf,
@@ -43,10 +47,58 @@ impl FileQuickReplyRepository {
 
         Ok(self.quick_replies_dir.join(filename))
     }
+
+    async fn read_quick_reply_file(path: &Path) -> Result<QuickReplySet, DomainError> {
+        let data: Value = read_json_file(path).await?;
+        // SillyTavern keys sets by the `name` field; fall back to the file stem for
+        // hand-copied files that lost it.
+        let name = data
+            .get("name")
+            .and_then(Value::as_str)
+            .filter(|value| !value.trim().is_empty())
+            .map(str::to_string)
+            .or_else(|| {
+                path.file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+            })
+            .unwrap_or_default();
+
+        Ok(QuickReplySet::new(name, data))
+    }
 }
 
 #[async_trait]
 impl QuickReplyRepository for FileQuickReplyRepository {
+    async fn list_quick_reply_sets(&self) -> Result<Vec<QuickReplySet>, DomainError> {
+        let mut sets = Vec::new();
+        for path in list_files_with_extension(&self.quick_replies_dir, "json").await? {
+            match Self::read_quick_reply_file(&path).await {
+                Ok(set) if set.data.is_object() => sets.push(set),
+                Ok(_) => logger::warn(&format!(
+                    "Skipping quick reply file that is not a JSON object: {}",
+                    path.display()
+                )),
+                Err(error) => logger::warn(&format!(
+                    "Skipping unreadable quick reply file {}: {}",
+                    path.display(),
+                    error
+                )),
+            }
+        }
+
+        sets.sort_by(|left, right| left.name.cmp(&right.name));
+        Ok(sets)
+    }
+
+    async fn get_quick_reply_set(&self, name: &str) -> Result<Option<QuickReplySet>, DomainError> {
+        let file_path = self.get_quick_reply_path(name)?;
+        if !file_path.exists() {
+            return Ok(None);
+        }
+
+        Self::read_quick_reply_file(&file_path).await.map(Some)
+    }
+
     async fn save_quick_reply_set(&self, set: &QuickReplySet) -> Result<(), DomainError> {
         self.ensure_directory_exists().await?;
         let file_path = self.get_quick_reply_path(&set.name)?;
@@ -62,3 +114,69 @@ impl QuickReplyRepository for FileQuickReplyRepository {
         delete_file(&file_path).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::file_system::atomic_write;
+    use serde_json::json;
+
+    struct TempDirGuard {
+        path: PathBuf,
+    }
+
+    impl TempDirGuard {
+        fn new(test_name: &str) -> Self {
+            let mut path = std::env::temp_dir();
+            path.push(format!("tauritavern-{test_name}-{}", uuid::Uuid::new_v4()));
+            std::fs::create_dir_all(&path).expect("create temp dir");
+            Self { path }
+        }
+    }
+
+    impl Drop for TempDirGuard {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[tokio::test]
+    async fn lists_saved_sets_and_skips_invalid_files() {
+        let temp = TempDirGuard::new("quick-reply-list");
+        let repository = FileQuickReplyRepository::new(temp.path.join("QuickReplies"));
+
+        for name in ["Zeta", "Alpha"] {
+            repository
+                .save_quick_reply_set(&QuickReplySet::new(
+                    name.to_string(),
+                    json!({ "version": 2, "name": name, "qrList": [] }),
+                ))
+                .await
+                .expect("save set");
+        }
+        atomic_write(
+            &temp.path.join("QuickReplies").join("broken.json"),
+            b"[1, 2]",
+        )
+        .await
+        .expect("write invalid set");
+
+        let sets = repository.list_quick_reply_sets().await.expect("list sets");
+        let names = sets.iter().map(|set| set.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["Alpha", "Zeta"]);
+
+        let alpha = repository
+            .get_quick_reply_set("Alpha")
+            .await
+            .expect("get set")
+            .expect("set exists");
+        assert_eq!(alpha.data["version"], 2);
+        assert!(
+            repository
+                .get_quick_reply_set("Missing")
+                .await
+                .expect("get missing")
+                .is_none()
+        );
+    }
+}
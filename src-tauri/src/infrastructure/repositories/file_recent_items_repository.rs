@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use std::io;
+use std::path::PathBuf;
+
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::recent_items::RecentItems;
+use crate::domain::repositories::recent_items_repository::RecentItemsRepository;
+use crate::infrastructure::persistence::file_system::atomic_write;
+
+pub struct FileRecentItemsRepository {
+    path: PathBuf,
+}
+
+impl FileRecentItemsRepository {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+#[async_trait]
+impl RecentItemsRepository for FileRecentItemsRepository {
+    async fn load_recent_items(&self) -> Result<RecentItems, DomainError> {
+        let contents = match fs::read_to_string(&self.path).await {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Ok(RecentItems::default());
+            }
+            Err(error) => {
+                return Err(DomainError::InternalError(format!(
+                    "Failed to read recent items {:?}: {}",
+                    self.path, error
+                )));
+            }
+        };
+
+        serde_json::from_str::<RecentItems>(&contents).map_err(|error| {
+            DomainError::InvalidData(format!(
+                "Invalid JSON in recent items {:?}: {}",
+                self.path, error
+            ))
+        })
+    }
+
+    async fn save_recent_items(&self, items: &RecentItems) -> Result<(), DomainError> {
+        let json = serde_json::to_vec_pretty(items).map_err(|error| {
+            DomainError::InvalidData(format!("Failed to serialize recent items: {}", error))
+        })?;
+
+        atomic_write(&self.path, &json).await
+    }
+}
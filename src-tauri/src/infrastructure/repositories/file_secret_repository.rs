@@ -6,14 +6,19 @@ use tokio::fs;
 use tokio::sync::Mutex;
 
 use crate::domain::errors::DomainError;
+use crate::domain::models::changelog::MigrationRecord;
 use crate::domain::models::secret::{SecretEntry, SecretKeys, Secrets};
 use crate::domain::repositories::secret_repository::SecretRepository;
 use crate::infrastructure::logging::logger;
+use crate::infrastructure::persistence::file_locks::lock_file;
 use crate::infrastructure::persistence::file_system::{read_json_file, write_json_file};
+use crate::infrastructure::persistence::migration_ledger;
 
 pub struct FileSecretRepository {
     secrets_file: PathBuf,
     cache: Arc<Mutex<Option<Secrets>>>,
+    /// Data root whose migrations ledger records upgrades of legacy secrets files.
+    migration_ledger_root: Option<PathBuf>,
 }
 
 impl FileSecretRepository {
@@ -26,9 +31,15 @@ impl FileSecretRepository {
         Self {
             secrets_file,
             cache: Arc::new(Mutex::new(None)),
+            migration_ledger_root: None,
         }
     }
 
+    pub fn with_migration_ledger(mut self, data_root: PathBuf) -> Self {
+        self.migration_ledger_root = Some(data_root);
+        self
+    }
+
     async fn ensure_file_exists(&self) -> Result<(), DomainError> {
         if !self.secrets_file.exists() {
             tracing::info!("Creating secrets file: {:?}", self.secrets_file);
@@ -53,6 +64,29 @@ impl FileSecretRepository {
 
         Ok(())
     }
+
+    /// Loads, changes and saves the secrets under the file lock, so concurrent edits of
+    /// different keys cannot overwrite each other. `change` returns whether to save.
+    async fn update<R>(
+        &self,
+        change: impl FnOnce(&mut Secrets) -> (R, bool),
+    ) -> Result<R, DomainError> {
+        // Creates or migrates the file first; that write takes the lock itself.
+        self.load().await?;
+
+        let file = lock_file(&self.secrets_file).await;
+        let mut cache = self.cache.lock().await;
+        let mut secrets = match cache.clone() {
+            Some(secrets) => secrets,
+            None => Self::deserialize_compat(read_json_file(file.path()).await?).0,
+        };
+        let (result, changed) = change(&mut secrets);
+        if changed {
+            file.write_json(&secrets).await?;
+            *cache = Some(secrets);
+        }
+        Ok(result)
+    }
 }
 
 #[async_trait]
@@ -89,11 +123,12 @@ impl SecretRepository for FileSecretRepository {
         let (secrets, migrated) = Self::deserialize_compat(raw);
 
         if migrated {
-            if let Err(error) = self.save(&secrets).await {
-                logger::error(&format!(
+            match self.save(&secrets).await {
+                Ok(()) => self.record_legacy_format_migration().await,
+                Err(error) => logger::error(&format!(
                     "Failed to persist migrated secrets file: {}",
                     error
-                ));
+                )),
             }
         } else {
             let mut cache = self.cache.lock().await;
@@ -115,10 +150,11 @@ impl SecretRepository for FileSecretRepository {
         value: &str,
         label: &str,
     ) -> Result<String, DomainError> {
-        let mut secrets = self.load().await?;
-        let id = secrets.write_secret(key.to_string(), value.to_string(), label.to_string());
-        self.save(&secrets).await?;
-        Ok(id)
+        self.update(|secrets| {
+            let id = secrets.write_secret(key.to_string(), value.to_string(), label.to_string());
+            (id, true)
+        })
+        .await
     }
 
     async fn read_secret(
@@ -131,31 +167,49 @@ impl SecretRepository for FileSecretRepository {
     }
 
     async fn delete_secret(&self, key: &str, id: Option<&str>) -> Result<(), DomainError> {
-        let mut secrets = self.load().await?;
-        if secrets.delete_secret(key, id) {
-            self.save(&secrets).await?;
-        }
-        Ok(())
+        self.update(|secrets| ((), secrets.delete_secret(key, id)))
+            .await
     }
 
     async fn rotate_secret(&self, key: &str, id: &str) -> Result<(), DomainError> {
-        let mut secrets = self.load().await?;
-        if secrets.rotate_secret(key, id) {
-            self.save(&secrets).await?;
-        }
-        Ok(())
+        self.update(|secrets| ((), secrets.rotate_secret(key, id)))
+            .await
     }
 
     async fn rename_secret(&self, key: &str, id: &str, label: &str) -> Result<(), DomainError> {
-        let mut secrets = self.load().await?;
-        if secrets.rename_secret(key, id, label.to_string()) {
-            self.save(&secrets).await?;
-        }
-        Ok(())
+        self.update(|secrets| ((), secrets.rename_secret(key, id, label.to_string())))
+            .await
     }
 }
 
 impl FileSecretRepository {
+    async fn record_legacy_format_migration(&self) {
+        let Some(data_root) = &self.migration_ledger_root else {
+            return;
+        };
+        let file = self
+            .secrets_file
+            .strip_prefix(data_root)
+            .unwrap_or(&self.secrets_file)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let result = migration_ledger::record_migration(
+            data_root,
+            MigrationRecord::now(
+                "secrets.legacy_format",
+                "Converted plain-string secrets to the multi-key format",
+                vec![file],
+            ),
+        )
+        .await;
+        if let Err(error) = result {
+            logger::warn(&format!(
+                "Failed to record secrets migration in the ledger: {}",
+                error
+            ));
+        }
+    }
+
     fn deserialize_compat(raw: Value) -> (Secrets, bool) {
         let mut secrets = Secrets::new();
         let mut migrated = false;
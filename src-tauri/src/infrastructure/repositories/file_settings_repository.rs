@@ -5,12 +5,19 @@ use tokio::fs;
 
 use crate::domain::errors::DomainError;
 use crate::domain::models::settings::{SettingsSnapshot, TauriTavernSettings, UserSettings};
+use crate::domain::models::settings_schema::{
+    SettingsIssueKind, SettingsSchemaCheck, SettingsValidationReport, check_tauritavern_settings,
+    check_user_settings,
+};
 use crate::domain::repositories::settings_repository::SettingsRepository;
 use crate::infrastructure::logging::logger;
 use crate::infrastructure::persistence::file_system::{
     list_files_with_extension, read_json_file, write_json_file,
 };
 use crate::infrastructure::preset_file_naming::load_named_preset_files;
+use crate::infrastructure::repositories::file_user_customization_repository::{
+    CUSTOM_CSS_FILE, USER_SCRIPTS_DIR,
+};
 use crate::infrastructure::sillytavern_sorting::{
     sort_paths_by_file_name_js_default, sort_strings_sillytavern_name,
 };
@@ -64,6 +71,137 @@ impl FileSettingsRepository {
         Ok(snapshots_dir)
     }
 
+    /// Copies a settings file next to itself before a repair rewrites it.
+    async fn backup_settings_file(&self, path: &Path) -> Result<PathBuf, DomainError> {
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("settings.json");
+        let backup_path = path.with_file_name(format!(
+            "{}.corrupt-{}.bak",
+            file_name,
+            self.get_timestamp_ms()
+        ));
+
+        fs::copy(path, &backup_path).await.map_err(|e| {
+            DomainError::InternalError(format!("Failed to back up {}: {}", path.display(), e))
+        })?;
+
+        Ok(backup_path)
+    }
+
+    /// Directory holding the custom CSS and user scripts captured with a snapshot.
+    fn snapshot_customizations_dir(snapshots_dir: &Path, name: &str) -> PathBuf {
+        snapshots_dir.join(format!("{}.user", name))
+    }
+
+    /// Copies custom CSS and user scripts laid out as in `user/` from one directory
+    /// to another. Missing sources are skipped.
+    async fn copy_user_customizations(from: &Path, to: &Path) -> Result<(), DomainError> {
+        let mut files = Vec::new();
+        let css = from.join(CUSTOM_CSS_FILE);
+        if css.is_file() {
+            files.push((css, to.join(CUSTOM_CSS_FILE)));
+        }
+        for script in list_files_with_extension(&from.join(USER_SCRIPTS_DIR), "js").await? {
+            if let Some(file_name) = script.file_name() {
+                let target = to.join(USER_SCRIPTS_DIR).join(file_name);
+                files.push((script, target));
+            }
+        }
+
+        for (source, target) in files {
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent).await.map_err(|e| {
+                    DomainError::InternalError(format!(
+                        "Failed to create directory {}: {}",
+                        parent.display(),
+                        e
+                    ))
+                })?;
+            }
+            fs::copy(&source, &target).await.map_err(|e| {
+                DomainError::InternalError(format!(
+                    "Failed to copy {} to {}: {}",
+                    source.display(),
+                    target.display(),
+                    e
+                ))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    async fn read_settings_text(path: &Path) -> Result<String, DomainError> {
+        let bytes = fs::read(path).await.map_err(|e| {
+            logger::error(&format!("Failed to read file {:?}: {}", path, e));
+            if e.kind() == std::io::ErrorKind::NotFound {
+                DomainError::NotFound(format!("File not found: {}", path.display()))
+            } else {
+                DomainError::InternalError(format!("Failed to read file: {}", e))
+            }
+        })?;
+
+        // Invalid UTF-8 is reported by the schema check as unreadable JSON.
+        Ok(String::from_utf8(bytes).unwrap_or_default())
+    }
+
+    /// Backs up the original file and writes the repaired document in its place.
+    async fn write_repaired_settings(
+        &self,
+        path: &Path,
+        check: &SettingsSchemaCheck,
+    ) -> Result<PathBuf, DomainError> {
+        let backup_path = self.backup_settings_file(path).await?;
+        write_json_file(path, &check.repaired).await?;
+
+        logger::warn(&format!(
+            "Repaired {} ({} issue(s)); original saved to {}",
+            path.display(),
+            check.issues.len(),
+            backup_path.display()
+        ));
+        Ok(backup_path)
+    }
+
+    async fn validate_settings_file(
+        &self,
+        path: &Path,
+        check: fn(&str) -> SettingsSchemaCheck,
+        repair: bool,
+    ) -> Result<SettingsValidationReport, DomainError> {
+        let file = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let mut report = SettingsValidationReport {
+            file,
+            issues: Vec::new(),
+            repaired: false,
+            backup_file: None,
+        };
+        if !path.exists() {
+            return Ok(report);
+        }
+
+        let check = check(&Self::read_settings_text(path).await?);
+        if repair && check.needs_repair() {
+            let backup_path = self.write_repaired_settings(path, &check).await?;
+            report.repaired = true;
+            report.backup_file = Some(backup_path.display().to_string());
+        }
+        report.issues = check.issues;
+
+        Ok(report)
+    }
+
+    async fn latest_snapshot_settings(&self) -> Option<UserSettings> {
+        let snapshot = self.get_snapshots().await.ok()?.into_iter().next()?;
+        self.load_snapshot(&snapshot.name).await.ok()
+    }
+
     fn get_timestamp_ms(&self) -> i64 {
         SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -176,13 +314,21 @@ impl SettingsRepository for FileSettingsRepository {
                 }
             })?;
 
-        TauriTavernSettings::from_json_str_with_compat(&contents).map_err(|e| {
-            logger::error(&format!(
-                "Failed to parse JSON from file {:?}: {}",
-                self.tauritavern_settings_file, e
-            ));
-            DomainError::InvalidData(format!("Invalid JSON: {}", e))
-        })
+        match TauriTavernSettings::from_json_str_with_compat(&contents) {
+            Ok(settings) => Ok(settings),
+            Err(e) => {
+                logger::warn(&format!(
+                    "Failed to parse JSON from file {:?}, repairing: {}",
+                    self.tauritavern_settings_file, e
+                ));
+
+                let check = check_tauritavern_settings(&contents);
+                self.write_repaired_settings(&self.tauritavern_settings_file, &check)
+                    .await?;
+                TauriTavernSettings::from_json_str_with_compat(&check.repaired.to_string())
+                    .map_err(|e| DomainError::InvalidData(format!("Invalid JSON: {}", e)))
+            }
+        }
     }
 
     async fn save_user_settings(&self, settings: &UserSettings) -> Result<(), DomainError> {
@@ -207,7 +353,56 @@ impl SettingsRepository for FileSettingsRepository {
             "Loading user settings from {}",
             self.user_settings_file.display()
         );
-        read_json_file::<UserSettings>(&self.user_settings_file).await
+        let contents = Self::read_settings_text(&self.user_settings_file).await?;
+        let mut check = check_user_settings(&contents);
+        if !check.needs_repair() {
+            return Ok(UserSettings {
+                data: check.repaired,
+            });
+        }
+
+        let unreadable = check
+            .issues
+            .iter()
+            .any(|issue| issue.kind == SettingsIssueKind::Unreadable);
+        if !unreadable {
+            // Wrongly typed sections are only dropped in memory; the frontend's next save
+            // rewrites the file, and `validate_settings` can repair it explicitly.
+            logger::warn(&format!(
+                "Ignoring {} invalid section(s) in {}",
+                check.issues.len(),
+                self.user_settings_file.display()
+            ));
+            return Ok(UserSettings {
+                data: check.repaired,
+            });
+        }
+
+        if let Some(snapshot) = self.latest_snapshot_settings().await {
+            logger::warn("User settings are unreadable; restoring the latest snapshot");
+            check.repaired = snapshot.data;
+        }
+        self.write_repaired_settings(&self.user_settings_file, &check)
+            .await?;
+        Ok(UserSettings {
+            data: check.repaired,
+        })
+    }
+
+    async fn validate_settings(
+        &self,
+        repair: bool,
+    ) -> Result<Vec<SettingsValidationReport>, DomainError> {
+        Ok(vec![
+            self.validate_settings_file(
+                &self.tauritavern_settings_file,
+                check_tauritavern_settings,
+                repair,
+            )
+            .await?,
+            self.validate_settings_file(&self.user_settings_file, check_user_settings, repair)
+                .await?,
+        ])
     }
 
     async fn create_snapshot(&self) -> Result<(), DomainError> {
@@ -218,6 +413,11 @@ impl SettingsRepository for FileSettingsRepository {
 
         tracing::info!("Creating settings snapshot: {}", snapshot_file.display());
         write_json_file(&snapshot_file, &settings).await?;
+        Self::copy_user_customizations(
+            &self.base_directory.join("user"),
+            &Self::snapshot_customizations_dir(&snapshots_dir, &format!("settings_{}", timestamp)),
+        )
+        .await?;
 
         Ok(())
     }
@@ -286,6 +486,13 @@ impl SettingsRepository for FileSettingsRepository {
         let settings = self.load_snapshot(name).await?;
         self.save_user_settings(&settings).await?;
 
+        let snapshots_dir = self.ensure_snapshots_directory_exists().await?;
+        Self::copy_user_customizations(
+            &Self::snapshot_customizations_dir(&snapshots_dir, name),
+            &self.base_directory.join("user"),
+        )
+        .await?;
+
         Ok(())
     }
 
@@ -402,6 +609,119 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn snapshots_capture_and_restore_user_customizations() {
+        let dir = TestDir::new();
+        let repository = FileSettingsRepository::new(dir.path().to_path_buf());
+        let user_dir = dir.path().join("user");
+        fs::create_dir_all(user_dir.join("scripts")).expect("create scripts dir");
+        fs::write(dir.path().join("settings.json"), r#"{"main_api":"openai"}"#)
+            .expect("write settings.json");
+        fs::write(user_dir.join("custom.css"), "body { color: red; }").expect("write css");
+        fs::write(user_dir.join("scripts").join("a.js"), "1;").expect("write script");
+
+        repository.create_snapshot().await.expect("create snapshot");
+        fs::write(user_dir.join("custom.css"), "body { color: blue; }").expect("edit css");
+        fs::write(user_dir.join("scripts").join("a.js"), "2;").expect("edit script");
+
+        let snapshot = repository
+            .get_snapshots()
+            .await
+            .expect("list snapshots")
+            .remove(0);
+        repository
+            .restore_snapshot(&snapshot.name)
+            .await
+            .expect("restore snapshot");
+
+        assert_eq!(
+            fs::read_to_string(user_dir.join("custom.css")).expect("read css"),
+            "body { color: red; }"
+        );
+        assert_eq!(
+            fs::read_to_string(user_dir.join("scripts").join("a.js")).expect("read script"),
+            "1;"
+        );
+    }
+
+    #[tokio::test]
+    async fn load_user_settings_restores_latest_snapshot_and_backs_up_corrupt_file() {
+        let dir = TestDir::new();
+        let repository = FileSettingsRepository::new(dir.path().to_path_buf());
+
+        fs::create_dir_all(dir.path().join("snapshots")).expect("create snapshots dir");
+        fs::write(
+            dir.path().join("snapshots").join("settings_100.json"),
+            r#"{"main_api":"openai"}"#,
+        )
+        .expect("write snapshot");
+        fs::write(dir.path().join("settings.json"), r#"{"main_api": "#)
+            .expect("write corrupt settings.json");
+
+        let settings = repository
+            .load_user_settings()
+            .await
+            .expect("load repaired user settings");
+        assert_eq!(settings.data, json!({"main_api": "openai"}));
+
+        let backups = fs::read_dir(dir.path())
+            .expect("read settings dir")
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with("settings.json.corrupt-")
+            })
+            .count();
+        assert_eq!(backups, 1);
+
+        let reports = repository
+            .validate_settings(false)
+            .await
+            .expect("validate settings");
+        assert!(reports.iter().all(|report| report.issues.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn load_user_settings_leaves_wrongly_typed_file_untouched() {
+        let dir = TestDir::new();
+        let repository = FileSettingsRepository::new(dir.path().to_path_buf());
+        let raw = r#"{"power_user":"oops","main_api":"openai"}"#;
+        fs::write(dir.path().join("settings.json"), raw).expect("write settings.json");
+
+        let settings = repository
+            .load_user_settings()
+            .await
+            .expect("load user settings");
+        assert_eq!(settings.data, json!({"main_api": "openai"}));
+        assert_eq!(
+            fs::read_to_string(dir.path().join("settings.json")).expect("read settings.json"),
+            raw
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_settings_does_not_rewrite_for_unknown_fields() {
+        let dir = TestDir::new();
+        let repository = FileSettingsRepository::new(dir.path().to_path_buf());
+        let raw = r#"{"updates":{"startup_popup":{"dismissed_release_token":null}},"mystery":1}"#;
+        fs::write(dir.path().join("tauritavern-settings.json"), raw)
+            .expect("write tauritavern-settings.json");
+
+        let reports = repository
+            .validate_settings(true)
+            .await
+            .expect("validate settings");
+        assert_eq!(reports[0].issues.len(), 1);
+        assert!(!reports[0].repaired);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("tauritavern-settings.json"))
+                .expect("read tauritavern-settings.json"),
+            raw
+        );
+    }
+
     #[tokio::test]
     async fn load_user_settings_reads_disk_each_time() {
         let dir = TestDir::new();
@@ -15,9 +15,7 @@ use super::paths::{validate_skill_name, validate_skill_scope};
 use super::{FileSkillRepository, INDEX_VERSION};
 use crate::domain::errors::DomainError;
 use crate::domain::models::skill::{SkillIndexEntry, SkillScope};
-use crate::infrastructure::persistence::file_system::{
-    replace_file_with_fallback, unique_temp_path,
-};
+use crate::infrastructure::persistence::file_system::atomic_write;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -116,15 +114,7 @@ impl FileSkillRepository {
         let text = serde_json::to_string_pretty(index).map_err(|error| {
             DomainError::InvalidData(format!("Failed to serialize Skill index: {error}"))
         })?;
-        let temp = unique_temp_path(&path, "skills.json");
-        tokio_fs::write(&temp, text).await.map_err(|error| {
-            DomainError::InternalError(format!(
-                "Failed to write temporary Skill index '{}': {}",
-                temp.display(),
-                error
-            ))
-        })?;
-        replace_file_with_fallback(&temp, &path).await?;
+        atomic_write(&path, text.as_bytes()).await?;
         Ok(())
     }
 
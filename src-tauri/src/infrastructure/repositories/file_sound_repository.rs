@@ -0,0 +1,304 @@
+use async_trait::async_trait;
+use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::sound_asset::SoundAsset;
+use crate::domain::repositories::sound_repository::SoundRepository;
+use crate::infrastructure::persistence::file_names::{
+    character_directory_name, path_exists, sanitized_name, unique_file_path,
+};
+use crate::infrastructure::persistence::file_system::atomic_write;
+use crate::infrastructure::user_data_paths::USER_SOUNDS_ROUTE_PREFIX;
+
+const CHARACTERS_DIRECTORY: &str = "characters";
+/// Characters escaped in a URL path segment.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// Audio formats accepted as sounds, keyed by their leading magic bytes.
+fn sniff_audio_type(bytes: &[u8]) -> Option<(&'static str, &'static str)> {
+    if bytes.starts_with(b"ID3")
+        || (bytes.len() >= 2 && bytes[0] == 0xFF && bytes[1] & 0xE0 == 0xE0)
+    {
+        return Some(("audio/mpeg", "mp3"));
+    }
+    if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WAVE" {
+        return Some(("audio/wav", "wav"));
+    }
+    if bytes.starts_with(b"OggS") {
+        return Some(("audio/ogg", "ogg"));
+    }
+    if bytes.starts_with(b"fLaC") {
+        return Some(("audio/flac", "flac"));
+    }
+    if bytes.len() >= 12 && &bytes[4..8] == b"ftyp" && &bytes[8..11] == b"M4A" {
+        return Some(("audio/mp4", "m4a"));
+    }
+    if bytes.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) {
+        return Some(("audio/webm", "weba"));
+    }
+    None
+}
+
+/// File system implementation of the SoundRepository.
+///
+/// Notification sounds live in `user/sounds/`, character message sounds in
+/// `user/sounds/characters/<avatar stem>/`; both are served by the `/user/sounds/`
+/// user data route.
+pub struct FileSoundRepository {
+    sounds_dir: PathBuf,
+}
+
+impl FileSoundRepository {
+    /// Create a new FileSoundRepository rooted at the sounds directory
+    pub fn new(sounds_dir: PathBuf) -> Self {
+        Self { sounds_dir }
+    }
+
+    /// Normalized character stem and directory for notification or character sounds.
+    fn location(&self, character: Option<&str>) -> Result<(Option<String>, PathBuf), DomainError> {
+        match character {
+            Some(character) => {
+                let character = character_directory_name(character)?;
+                let dir = self.sounds_dir.join(CHARACTERS_DIRECTORY).join(&character);
+                Ok((Some(character), dir))
+            }
+            None => Ok((None, self.sounds_dir.clone())),
+        }
+    }
+
+    async fn describe(
+        character: Option<&str>,
+        path: &Path,
+        mime_type: Option<&str>,
+    ) -> Result<SoundAsset, DomainError> {
+        let metadata = fs::metadata(path).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to stat sound '{}': {}",
+                path.display(),
+                error
+            ))
+        })?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_millis() as i64)
+            .unwrap_or_default();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mime_type = mime_type.map(str::to_string).unwrap_or_else(|| {
+            mime_guess::from_path(path)
+                .first_or_octet_stream()
+                .essence_str()
+                .to_string()
+        });
+        let url = match character {
+            Some(character) => format!(
+                "{USER_SOUNDS_ROUTE_PREFIX}{CHARACTERS_DIRECTORY}/{}/{}",
+                utf8_percent_encode(character, PATH_SEGMENT),
+                utf8_percent_encode(&name, PATH_SEGMENT)
+            ),
+            None => format!(
+                "{USER_SOUNDS_ROUTE_PREFIX}{}",
+                utf8_percent_encode(&name, PATH_SEGMENT)
+            ),
+        };
+
+        Ok(SoundAsset {
+            name,
+            character: character.map(str::to_string),
+            url,
+            mime_type,
+            size: metadata.len(),
+            modified,
+        })
+    }
+}
+
+#[async_trait]
+impl SoundRepository for FileSoundRepository {
+    async fn list_sounds(&self, character: Option<&str>) -> Result<Vec<SoundAsset>, DomainError> {
+        let (character, dir) = self.location(character)?;
+        if !path_exists(&dir).await? {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = fs::read_dir(&dir).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to read sounds '{}': {}",
+                dir.display(),
+                error
+            ))
+        })?;
+
+        let mut sounds = Vec::new();
+        while let Some(entry) = entries.next_entry().await.map_err(|error| {
+            DomainError::InternalError(format!("Failed to read sound entry: {}", error))
+        })? {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            sounds.push(Self::describe(character.as_deref(), &path, None).await?);
+        }
+
+        sounds.sort_by(|left, right| left.name.cmp(&right.name));
+        Ok(sounds)
+    }
+
+    async fn save_sound(
+        &self,
+        character: Option<&str>,
+        file_name: &str,
+        data: &[u8],
+    ) -> Result<SoundAsset, DomainError> {
+        let (character, dir) = self.location(character)?;
+        let file_name = sanitized_name(file_name, "sound file name")?;
+        let (mime_type, extension) = sniff_audio_type(data).ok_or_else(|| {
+            DomainError::InvalidData(format!("Unsupported sound type: {}", file_name))
+        })?;
+
+        fs::create_dir_all(&dir).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to create sound directory '{}': {}",
+                dir.display(),
+                error
+            ))
+        })?;
+
+        let stem = Path::new(&file_name)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .filter(|stem| !stem.is_empty())
+            .unwrap_or_else(|| chrono::Utc::now().timestamp_millis().to_string());
+        let target = unique_file_path(&dir, &stem, extension).await?;
+
+        atomic_write(&target, data).await?;
+
+        Self::describe(character.as_deref(), &target, Some(mime_type)).await
+    }
+
+    async fn delete_sound(
+        &self,
+        character: Option<&str>,
+        file_name: &str,
+    ) -> Result<(), DomainError> {
+        let (_, dir) = self.location(character)?;
+        let file_name = sanitized_name(file_name, "sound file name")?;
+        let path = dir.join(&file_name);
+        if !path.is_file() {
+            return Err(DomainError::NotFound(format!(
+                "Sound not found: {}",
+                file_name
+            )));
+        }
+
+        fs::remove_file(&path).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to delete sound '{}': {}",
+                path.display(),
+                error
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{FileSoundRepository, sniff_audio_type};
+    use crate::domain::repositories::sound_repository::SoundRepository;
+
+    struct TempDirGuard {
+        path: PathBuf,
+    }
+
+    impl TempDirGuard {
+        fn new(test_name: &str) -> Self {
+            let mut path = std::env::temp_dir();
+            path.push(format!("tauritavern-{test_name}-{}", uuid::Uuid::new_v4()));
+            std::fs::create_dir_all(&path).expect("create temp dir");
+            Self { path }
+        }
+    }
+
+    impl Drop for TempDirGuard {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    const WAV_HEADER: &[u8] = b"RIFF\x24\0\0\0WAVEfmt ";
+
+    #[test]
+    fn sniffs_audio_type_from_magic_bytes() {
+        assert_eq!(sniff_audio_type(WAV_HEADER), Some(("audio/wav", "wav")));
+        assert_eq!(sniff_audio_type(b"ID3\x04\0"), Some(("audio/mpeg", "mp3")));
+        assert_eq!(sniff_audio_type(b"OggS\0\x02"), Some(("audio/ogg", "ogg")));
+        assert_eq!(sniff_audio_type(b"<html></html>"), None);
+    }
+
+    #[tokio::test]
+    async fn notification_and_character_sounds_are_kept_apart() {
+        let temp = TempDirGuard::new("sounds");
+        let repository = FileSoundRepository::new(temp.path.clone());
+
+        let ping = repository
+            .save_sound(None, "ping.mp3", WAV_HEADER)
+            .await
+            .expect("save notification sound");
+        assert_eq!(ping.name, "ping.wav");
+        assert_eq!(ping.url, "/user/sounds/ping.wav");
+
+        let chime = repository
+            .save_sound(Some("Alice Liddell.png"), "chime.wav", WAV_HEADER)
+            .await
+            .expect("save character sound");
+        assert_eq!(chime.character.as_deref(), Some("Alice Liddell"));
+        assert_eq!(
+            chime.url,
+            "/user/sounds/characters/Alice%20Liddell/chime.wav"
+        );
+        assert!(
+            temp.path
+                .join("characters/Alice Liddell/chime.wav")
+                .is_file()
+        );
+
+        assert_eq!(repository.list_sounds(None).await.expect("list").len(), 1);
+        repository
+            .delete_sound(Some("Alice Liddell"), "chime.wav")
+            .await
+            .expect("delete sound");
+        assert!(
+            repository
+                .list_sounds(Some("Alice Liddell"))
+                .await
+                .expect("list")
+                .is_empty()
+        );
+        assert!(
+            repository
+                .save_sound(None, "page.html", b"<html></html>")
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,431 @@
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::sprite::{Sprite, SpritePackImport, sprite_label};
+use crate::domain::repositories::sprite_repository::SpriteRepository;
+use crate::infrastructure::logging::logger;
+use crate::infrastructure::persistence::file_names::{character_directory_name, sanitized_name};
+use crate::infrastructure::persistence::file_system::{
+    atomic_write_sync, replace_file_with_fallback,
+};
+use crate::infrastructure::zipkit;
+
+const SPRITES_DIRECTORY: &str = "sprites";
+const CHARACTERS_URL_PREFIX: &str = "/characters";
+const SPRITE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "webp", "bmp", "avif"];
+const MAX_SPRITE_PACK_ENTRIES: usize = 1000;
+const MAX_SPRITE_PACK_BYTES: u64 = 256 * 1024 * 1024;
+
+fn is_sprite_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            SPRITE_EXTENSIONS
+                .iter()
+                .any(|candidate| extension.eq_ignore_ascii_case(candidate))
+        })
+}
+
+fn sprite_file_name(file_name: &str) -> Result<String, DomainError> {
+    let sanitized = sanitized_name(file_name, "sprite file name")?;
+    if !is_sprite_file(Path::new(&sanitized)) {
+        return Err(DomainError::InvalidData(format!(
+            "Invalid sprite file name: {}",
+            file_name
+        )));
+    }
+    Ok(sanitized)
+}
+
+/// A sprite written to the staging directory, waiting to be moved into the sprites folder.
+struct StagedSprite {
+    staged: PathBuf,
+    label: String,
+    file_name: String,
+}
+
+/// Writes the images of a sprite pack whose label is in `allowed_labels` into
+/// `staging_dir` and reports the entries it skipped. Nothing outside `staging_dir` is
+/// touched. Blocking; run it off the async runtime.
+fn extract_sprite_pack(
+    archive_path: &Path,
+    staging_dir: &Path,
+    allowed_labels: &[String],
+) -> Result<(Vec<StagedSprite>, Vec<String>), DomainError> {
+    let file = std::fs::File::open(archive_path).map_err(|error| {
+        DomainError::InvalidData(format!("Failed to open sprite pack: {}", error))
+    })?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|error| {
+        DomainError::InvalidData(format!("Failed to read sprite pack: {}", error))
+    })?;
+    if archive.len() > MAX_SPRITE_PACK_ENTRIES {
+        return Err(DomainError::InvalidData(format!(
+            "Sprite pack must contain <= {} entries",
+            MAX_SPRITE_PACK_ENTRIES
+        )));
+    }
+
+    let mut sprites = Vec::new();
+    let mut skipped = Vec::new();
+    let mut total_bytes = 0u64;
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(|error| {
+            DomainError::InvalidData(format!("Failed to read sprite pack entry: {}", error))
+        })?;
+        if entry.is_dir() {
+            continue;
+        }
+
+        let path = zipkit::enclosed_zip_entry_path(&entry)?;
+        let entry_name = path.to_string_lossy().replace('\\', "/");
+        let Some(file_name) = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+        else {
+            continue;
+        };
+        if file_name.starts_with('.') || entry_name.starts_with("__MACOSX/") {
+            continue;
+        }
+        let Ok(file_name) = sprite_file_name(&file_name) else {
+            skipped.push(format!("{}: not an image", entry_name));
+            continue;
+        };
+        let Some(label) = sprite_label(&file_name)
+            .filter(|label| allowed_labels.iter().any(|allowed| allowed == label))
+        else {
+            skipped.push(format!("{}: unknown expression label", entry_name));
+            continue;
+        };
+
+        total_bytes = total_bytes.saturating_add(entry.size());
+        if total_bytes > MAX_SPRITE_PACK_BYTES {
+            return Err(DomainError::InvalidData(format!(
+                "Sprite pack exceeds {} bytes",
+                MAX_SPRITE_PACK_BYTES
+            )));
+        }
+
+        let mut bytes = Vec::new();
+        (&mut entry)
+            .take(MAX_SPRITE_PACK_BYTES)
+            .read_to_end(&mut bytes)
+            .map_err(|error| {
+                DomainError::InvalidData(format!("Failed to read sprite pack entry: {}", error))
+            })?;
+        let staged = staging_dir.join(sprites.len().to_string());
+        atomic_write_sync(&staged, &bytes)?;
+        sprites.push(StagedSprite {
+            staged,
+            label,
+            file_name,
+        });
+    }
+
+    Ok((sprites, skipped))
+}
+
+/// File system implementation of the SpriteRepository.
+///
+/// Sprites live in `characters/<avatar stem>/sprites/`. Images directly inside
+/// `characters/<avatar stem>/`, where SillyTavern keeps them, are listed too so
+/// migrated packs keep working.
+pub struct FileSpriteRepository {
+    characters_dir: PathBuf,
+}
+
+impl FileSpriteRepository {
+    /// Create a new FileSpriteRepository rooted at the characters directory
+    pub fn new(characters_dir: PathBuf) -> Self {
+        Self { characters_dir }
+    }
+
+    fn sprites_dir(&self, character: &str) -> PathBuf {
+        self.characters_dir.join(character).join(SPRITES_DIRECTORY)
+    }
+
+    async fn store_sprites(
+        &self,
+        character: &str,
+        sprites: Vec<StagedSprite>,
+        skipped: Vec<String>,
+    ) -> Result<SpritePackImport, DomainError> {
+        let dir = self.sprites_dir(character);
+        fs::create_dir_all(&dir).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to create sprites directory '{}': {}",
+                dir.display(),
+                error
+            ))
+        })?;
+
+        let mut result = SpritePackImport {
+            skipped,
+            ..SpritePackImport::default()
+        };
+        for sprite in sprites {
+            replace_file_with_fallback(&sprite.staged, &dir.join(&sprite.file_name)).await?;
+            result.imported.push(Sprite {
+                label: sprite.label,
+                path: format!(
+                    "{CHARACTERS_URL_PREFIX}/{character}/{SPRITES_DIRECTORY}/{}",
+                    sprite.file_name
+                ),
+                name: sprite.file_name,
+            });
+        }
+
+        Ok(result)
+    }
+
+    async fn read_sprites(
+        dir: &Path,
+        url_prefix: &str,
+        sprites: &mut Vec<Sprite>,
+        seen: &mut HashSet<String>,
+    ) -> Result<(), DomainError> {
+        let mut entries = match fs::read_dir(dir).await {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(error) => {
+                return Err(DomainError::InternalError(format!(
+                    "Failed to read sprites '{}': {}",
+                    dir.display(),
+                    error
+                )));
+            }
+        };
+
+        while let Some(entry) = entries.next_entry().await.map_err(|error| {
+            DomainError::InternalError(format!("Failed to read sprite entry: {}", error))
+        })? {
+            let path = entry.path();
+            if !path.is_file() || !is_sprite_file(&path) {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            let Some(label) = sprite_label(&name) else {
+                continue;
+            };
+            if !seen.insert(name.to_lowercase()) {
+                continue;
+            }
+            sprites.push(Sprite {
+                label,
+                path: format!("{url_prefix}/{name}"),
+                name,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SpriteRepository for FileSpriteRepository {
+    async fn list_sprites(&self, character: &str) -> Result<Vec<Sprite>, DomainError> {
+        let character = character_directory_name(character)?;
+        let mut sprites = Vec::new();
+        let mut seen = HashSet::new();
+
+        Self::read_sprites(
+            &self.sprites_dir(&character),
+            &format!("{CHARACTERS_URL_PREFIX}/{character}/{SPRITES_DIRECTORY}"),
+            &mut sprites,
+            &mut seen,
+        )
+        .await?;
+        Self::read_sprites(
+            &self.characters_dir.join(&character),
+            &format!("{CHARACTERS_URL_PREFIX}/{character}"),
+            &mut sprites,
+            &mut seen,
+        )
+        .await?;
+
+        sprites.sort_by(|left, right| {
+            left.label
+                .cmp(&right.label)
+                .then_with(|| left.name.cmp(&right.name))
+        });
+        Ok(sprites)
+    }
+
+    async fn import_sprite_pack(
+        &self,
+        character: &str,
+        archive_path: &Path,
+        allowed_labels: &[String],
+    ) -> Result<SpritePackImport, DomainError> {
+        let character = character_directory_name(character)?;
+
+        // Staged next to the sprites so the final moves are plain renames.
+        let staging_dir = self
+            .characters_dir
+            .join(&character)
+            .join(format!(".sprite-import-{}", uuid::Uuid::new_v4()));
+        let extracted = {
+            let archive_path = archive_path.to_path_buf();
+            let staging_dir = staging_dir.clone();
+            let allowed_labels = allowed_labels.to_vec();
+            tokio::task::spawn_blocking(move || {
+                extract_sprite_pack(&archive_path, &staging_dir, &allowed_labels)
+            })
+            .await
+            .map_err(|error| {
+                DomainError::InternalError(format!("Sprite pack import task failed: {}", error))
+            })
+            .and_then(|extracted| extracted)
+        };
+        let result = match extracted {
+            Ok((sprites, skipped)) => self.store_sprites(&character, sprites, skipped).await,
+            Err(error) => Err(error),
+        };
+
+        match fs::remove_dir_all(&staging_dir).await {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => logger::warn(&format!(
+                "Failed to remove sprite staging directory {}: {}",
+                staging_dir.display(),
+                error
+            )),
+            _ => {}
+        }
+        result
+    }
+
+    async fn delete_sprite(&self, character: &str, file_name: &str) -> Result<(), DomainError> {
+        let character = character_directory_name(character)?;
+        let file_name = sprite_file_name(file_name)?;
+        let candidates = [
+            self.sprites_dir(&character).join(&file_name),
+            self.characters_dir.join(&character).join(&file_name),
+        ];
+        let Some(path) = candidates.into_iter().find(|path| path.is_file()) else {
+            return Err(DomainError::NotFound(format!(
+                "Sprite not found: {}",
+                file_name
+            )));
+        };
+
+        fs::remove_file(&path).await.map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to delete sprite '{}': {}",
+                path.display(),
+                error
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    use super::FileSpriteRepository;
+    use crate::domain::repositories::sprite_repository::SpriteRepository;
+
+    struct TempDirGuard {
+        path: PathBuf,
+    }
+
+    impl TempDirGuard {
+        fn new(test_name: &str) -> Self {
+            let mut path = std::env::temp_dir();
+            path.push(format!("tauritavern-{test_name}-{}", uuid::Uuid::new_v4()));
+            std::fs::create_dir_all(&path).expect("create temp dir");
+            Self { path }
+        }
+    }
+
+    impl Drop for TempDirGuard {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[tokio::test]
+    async fn sprite_pack_import_filters_labels_and_lists_legacy_sprites() {
+        let temp = TempDirGuard::new("sprites");
+        let repository = FileSpriteRepository::new(temp.path.join("characters"));
+        std::fs::create_dir_all(temp.path.join("characters/Alice")).expect("create dir");
+        std::fs::write(temp.path.join("characters/Alice/anger.png"), b"png").expect("legacy");
+
+        let archive_path = temp.path.join("pack.zip");
+        let mut writer =
+            zip::ZipWriter::new(std::fs::File::create(&archive_path).expect("create zip"));
+        for name in [
+            "pack/joy.png",
+            "pack/joy-2.png",
+            "pack/smug.png",
+            "readme.txt",
+        ] {
+            writer
+                .start_file(name, zip::write::SimpleFileOptions::default())
+                .expect("start entry");
+            writer.write_all(b"png").expect("write entry");
+        }
+        writer.finish().expect("finish zip");
+
+        let labels = vec!["joy".to_string(), "anger".to_string()];
+        let result = repository
+            .import_sprite_pack("Alice.png", &archive_path, &labels)
+            .await
+            .expect("import pack");
+        assert_eq!(result.imported.len(), 2);
+        assert_eq!(result.skipped.len(), 2);
+        assert_eq!(result.imported[0].path, "/characters/Alice/sprites/joy.png");
+
+        let sprites = repository.list_sprites("Alice").await.expect("list");
+        let names: Vec<_> = sprites.iter().map(|sprite| sprite.name.as_str()).collect();
+        assert_eq!(names, vec!["anger.png", "joy-2.png", "joy.png"]);
+
+        repository
+            .delete_sprite("Alice", "anger.png")
+            .await
+            .expect("delete legacy sprite");
+        assert_eq!(
+            repository.list_sprites("Alice").await.expect("list").len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_sprite_pack_import_leaves_no_files_behind() {
+        let temp = TempDirGuard::new("sprites-failed");
+        let repository = FileSpriteRepository::new(temp.path.join("characters"));
+
+        let archive_path = temp.path.join("pack.zip");
+        let mut writer =
+            zip::ZipWriter::new(std::fs::File::create(&archive_path).expect("create zip"));
+        for name in ["joy.png", "../anger.png"] {
+            writer
+                .start_file(name, zip::write::SimpleFileOptions::default())
+                .expect("start entry");
+            writer.write_all(b"png").expect("write entry");
+        }
+        writer.finish().expect("finish zip");
+
+        let labels = vec!["joy".to_string(), "anger".to_string()];
+        repository
+            .import_sprite_pack("Alice", &archive_path, &labels)
+            .await
+            .expect_err("escaping entry must fail the import");
+
+        assert!(
+            repository
+                .list_sprites("Alice")
+                .await
+                .expect("list")
+                .is_empty()
+        );
+        let leftovers = std::fs::read_dir(temp.path.join("characters/Alice"))
+            .map(|entries| entries.count())
+            .unwrap_or(0);
+        assert_eq!(leftovers, 0);
+    }
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use std::path::PathBuf;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::tag::TagLibrary;
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::infrastructure::persistence::file_system::{read_json_file, write_json_file};
+
+/// Stores the tag library as a single `tags.json` in the user directory
+pub struct FileTagRepository {
+    tags_file: PathBuf,
+}
+
+impl FileTagRepository {
+    pub fn new(tags_file: PathBuf) -> Self {
+        Self { tags_file }
+    }
+}
+
+#[async_trait]
+impl TagRepository for FileTagRepository {
+    async fn load_tags(&self) -> Result<TagLibrary, DomainError> {
+        if !self.tags_file.exists() {
+            return Ok(TagLibrary::default());
+        }
+
+        read_json_file(&self.tags_file).await
+    }
+
+    async fn save_tags(&self, library: &TagLibrary) -> Result<(), DomainError> {
+        write_json_file(&self.tags_file, library).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Map;
+
+    use super::FileTagRepository;
+    use crate::domain::models::tag::{Tag, TagLibrary};
+    use crate::domain::repositories::tag_repository::TagRepository;
+
+    #[tokio::test]
+    async fn tags_round_trip_and_default_to_empty() {
+        let root = std::env::temp_dir().join(format!("tt-tags-{}", uuid::Uuid::new_v4()));
+        let repository = FileTagRepository::new(root.join("tags.json"));
+
+        assert_eq!(
+            repository.load_tags().await.expect("load"),
+            TagLibrary::default()
+        );
+
+        let mut library = TagLibrary::default();
+        library
+            .create(Tag {
+                id: "1".to_string(),
+                name: "Fantasy".to_string(),
+                color: Some("#ff0000".to_string()),
+                color2: None,
+                create_date: None,
+                extra: Map::new(),
+            })
+            .expect("create tag");
+        library.assign("Alice.png", "1").expect("assign tag");
+        repository.save_tags(&library).await.expect("save");
+
+        assert_eq!(repository.load_tags().await.expect("reload"), library);
+
+        let _ = tokio::fs::remove_dir_all(&root).await;
+    }
+}
@@ -1,23 +1,46 @@
 use async_trait::async_trait;
-use std::path::PathBuf;
+use serde_json::Value;
+use std::io::{Cursor, Read, Write};
+use std::path::{Path, PathBuf};
+use zip::ZipWriter;
 
 use crate::domain::errors::DomainError;
 use crate::domain::models::filename::sanitize_filename;
-use crate::domain::models::theme::Theme;
+use crate::domain::models::theme::{Theme, ThemeArchive};
 use crate::domain::repositories::theme_repository::ThemeRepository;
 use crate::infrastructure::logging::logger;
-use crate::infrastructure::persistence::file_system::{delete_file, write_json_file};
+use crate::infrastructure::persistence::file_system::{
+    atomic_write_sync, delete_file, list_files_with_extension, move_file_no_replace_with_fallback,
+    read_json_file, write_json_file,
+};
+use crate::infrastructure::user_data_dirs::DefaultUserWebDirs;
+use crate::infrastructure::user_data_paths::{
+    UserDataAssetKind, UserDataAssetRequestPath, parse_user_data_asset_request_path,
+};
+use crate::infrastructure::zipkit;
+
+const THEME_ENTRY: &str = "theme.json";
+const ASSETS_ENTRY_PREFIX: &str = "assets/";
+const MAX_THEME_ARCHIVE_ENTRIES: usize = 512;
+const MAX_THEME_ARCHIVE_BYTES: u64 = 64 * 1024 * 1024;
+const MAX_THEME_JSON_BYTES: u64 = 4 * 1024 * 1024;
 
 /// File-based implementation of the ThemeRepository
 pub struct FileThemeRepository {
     /// The directory where themes are stored
     themes_dir: PathBuf,
+
+    /// Directories behind the asset routes a theme's custom CSS can point at
+    user_dirs: DefaultUserWebDirs,
 }
 
 impl FileThemeRepository {
     /// Create a new FileThemeRepository
-    pub fn new(themes_dir: PathBuf) -> Self {
-        Self { themes_dir }
+    pub fn new(themes_dir: PathBuf, user_dirs: DefaultUserWebDirs) -> Self {
+        Self {
+            themes_dir,
+            user_dirs,
+        }
     }
 
     /// Ensure the themes directory exists
@@ -45,6 +68,277 @@ impl FileThemeRepository {
 
         Ok(self.themes_dir.join(filename))
     }
+
+    fn build_archive(&self, data: &Value) -> Result<Vec<u8>, DomainError> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let theme_json = serde_json::to_vec_pretty(data).map_err(|error| {
+            DomainError::InternalError(format!("Failed to serialize theme: {}", error))
+        })?;
+        write_archive_entry(&mut writer, THEME_ENTRY, &theme_json)?;
+
+        let custom_css = data
+            .get("custom_css")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let mut added = Vec::new();
+        for route in css_asset_routes(custom_css) {
+            let Ok(Some(asset)) = parse_user_data_asset_request_path(&route) else {
+                continue;
+            };
+            let entry_name = asset_entry_name(&asset);
+            if added.contains(&entry_name) {
+                continue;
+            }
+
+            let path = asset_path(&self.user_dirs, &asset);
+            match std::fs::read(&path) {
+                Ok(bytes) => {
+                    write_archive_entry(&mut writer, &entry_name, &bytes)?;
+                    added.push(entry_name);
+                }
+                Err(error) => logger::warn(&format!(
+                    "Skipping missing theme asset {}: {}",
+                    path.display(),
+                    error
+                )),
+            }
+        }
+
+        let cursor = writer.finish().map_err(|error| {
+            DomainError::InternalError(format!("Failed to finish theme archive: {}", error))
+        })?;
+        Ok(cursor.into_inner())
+    }
+
+    /// Checks the theme name, moves the staged assets into place and saves the theme.
+    async fn store_imported_theme(
+        &self,
+        file_path: &Path,
+        data: Value,
+        assets: Vec<StagedAsset>,
+        overwrite: bool,
+    ) -> Result<Theme, DomainError> {
+        let name = data
+            .get("name")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .or_else(|| {
+                file_path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().trim().to_string())
+            })
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| DomainError::InvalidData("Theme name is required".to_string()))?;
+
+        if !overwrite && self.get_theme_path(&name)?.exists() {
+            return Err(DomainError::InvalidData(format!(
+                "Theme already exists: {}",
+                name
+            )));
+        }
+
+        for asset in assets {
+            if !asset.target.exists() {
+                move_file_no_replace_with_fallback(&asset.staged, &asset.target).await?;
+            }
+        }
+
+        let theme = Theme::new(name, data);
+        self.save_theme(&theme).await?;
+        Ok(theme)
+    }
+}
+
+/// An archive asset written to the staging directory, waiting to be moved to `target`.
+struct StagedAsset {
+    staged: PathBuf,
+    target: PathBuf,
+}
+
+/// Reads `theme.json` from a theme archive and writes the assets that do not exist yet
+/// into `staging_dir`. Nothing outside `staging_dir` is touched, so a bad archive leaves
+/// no trace once the directory is removed. Blocking; run it off the async runtime.
+fn extract_archive(
+    file_path: &Path,
+    staging_dir: &Path,
+    user_dirs: &DefaultUserWebDirs,
+) -> Result<(Value, Vec<StagedAsset>), DomainError> {
+    let file = std::fs::File::open(file_path).map_err(|error| {
+        DomainError::InvalidData(format!("Failed to open theme archive: {}", error))
+    })?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|error| {
+        DomainError::InvalidData(format!("Failed to read theme archive: {}", error))
+    })?;
+    if archive.len() > MAX_THEME_ARCHIVE_ENTRIES {
+        return Err(DomainError::InvalidData(format!(
+            "Theme archive must contain <= {} entries",
+            MAX_THEME_ARCHIVE_ENTRIES
+        )));
+    }
+
+    let mut theme = None;
+    let mut assets = Vec::new();
+    let mut total_bytes = 0u64;
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(|error| {
+            DomainError::InvalidData(format!("Failed to read theme archive entry: {}", error))
+        })?;
+        if entry.is_dir() {
+            continue;
+        }
+
+        total_bytes = total_bytes.saturating_add(entry.size());
+        if total_bytes > MAX_THEME_ARCHIVE_BYTES {
+            return Err(DomainError::InvalidData(format!(
+                "Theme archive exceeds {} bytes",
+                MAX_THEME_ARCHIVE_BYTES
+            )));
+        }
+
+        let path = zipkit::enclosed_zip_entry_path(&entry)?;
+        let name = path.to_string_lossy().replace('\\', "/");
+        let mut bytes = Vec::new();
+        (&mut entry)
+            .take(MAX_THEME_ARCHIVE_BYTES)
+            .read_to_end(&mut bytes)
+            .map_err(|error| {
+                DomainError::InvalidData(format!("Failed to read theme archive entry: {}", error))
+            })?;
+
+        if name == THEME_ENTRY {
+            theme = Some(parse_theme_json(&bytes)?);
+        } else if let Some(route) = name.strip_prefix(ASSETS_ENTRY_PREFIX) {
+            let asset = parse_user_data_asset_request_path(&format!("/{}", route))
+                .ok()
+                .flatten()
+                .ok_or_else(|| {
+                    DomainError::InvalidData(format!("Invalid theme asset path: {}", name))
+                })?;
+            if !is_allowed_asset(&asset.relative_path) {
+                return Err(DomainError::InvalidData(format!(
+                    "Unsupported theme asset type: {}",
+                    name
+                )));
+            }
+
+            let target = asset_path(user_dirs, &asset);
+            if target.exists() {
+                continue;
+            }
+            let staged = staging_dir.join(assets.len().to_string());
+            atomic_write_sync(&staged, &bytes)?;
+            assets.push(StagedAsset { staged, target });
+        }
+    }
+
+    let theme = theme.ok_or_else(|| {
+        DomainError::InvalidData(format!("Theme archive is missing {}", THEME_ENTRY))
+    })?;
+    Ok((theme, assets))
+}
+
+fn asset_path(user_dirs: &DefaultUserWebDirs, asset: &UserDataAssetRequestPath) -> PathBuf {
+    let base_dir = match asset.kind {
+        UserDataAssetKind::Character => &user_dirs.characters_dir,
+        UserDataAssetKind::Persona => &user_dirs.avatars_dir,
+        UserDataAssetKind::Background => &user_dirs.backgrounds_dir,
+        UserDataAssetKind::Asset => &user_dirs.assets_dir,
+        UserDataAssetKind::UserImage => &user_dirs.user_images_dir,
+        UserDataAssetKind::UserFile => &user_dirs.user_files_dir,
+        UserDataAssetKind::UserSound => &user_dirs.user_sounds_dir,
+    };
+    base_dir.join(&asset.relative_path)
+}
+
+fn write_archive_entry(
+    writer: &mut ZipWriter<Cursor<Vec<u8>>>,
+    name: &str,
+    bytes: &[u8],
+) -> Result<(), DomainError> {
+    writer
+        .start_file(name, zipkit::export_file_options(name))
+        .and_then(|_| writer.write_all(bytes).map_err(Into::into))
+        .map_err(|error| {
+            DomainError::InternalError(format!(
+                "Failed to add {} to theme archive: {}",
+                name, error
+            ))
+        })
+}
+
+fn parse_theme_json(bytes: &[u8]) -> Result<Value, DomainError> {
+    if bytes.len() as u64 > MAX_THEME_JSON_BYTES {
+        return Err(DomainError::InvalidData(format!(
+            "Theme file exceeds {} bytes",
+            MAX_THEME_JSON_BYTES
+        )));
+    }
+
+    let value: Value = serde_json::from_slice(bytes)
+        .map_err(|error| DomainError::InvalidData(format!("Invalid theme JSON: {}", error)))?;
+    if !value.is_object() {
+        return Err(DomainError::InvalidData(
+            "Theme data must be a JSON object".to_string(),
+        ));
+    }
+    Ok(value)
+}
+
+/// Local asset routes referenced through `url(...)` in custom CSS.
+fn css_asset_routes(css: &str) -> Vec<String> {
+    let mut routes = Vec::new();
+    let lower = css.to_ascii_lowercase();
+    let mut offset = 0;
+
+    while let Some(start) = lower[offset..].find("url(") {
+        let value_start = offset + start + "url(".len();
+        let Some(end) = css[value_start..].find(')') else {
+            break;
+        };
+        offset = value_start + end + 1;
+
+        let raw = css[value_start..value_start + end]
+            .trim()
+            .trim_matches(|c| c == '"' || c == '\'')
+            .trim();
+        let path = raw.split(['?', '#']).next().unwrap_or_default();
+        if path.is_empty() || path.starts_with("//") || path.contains(':') {
+            continue;
+        }
+
+        let path = path.trim_start_matches("./");
+        if path.starts_with('/') {
+            routes.push(path.to_string());
+        } else {
+            routes.push(format!("/{}", path));
+        }
+    }
+
+    routes
+}
+
+fn asset_entry_name(asset: &UserDataAssetRequestPath) -> String {
+    let prefix = match asset.kind {
+        UserDataAssetKind::Character => "characters",
+        UserDataAssetKind::Persona => "User Avatars",
+        UserDataAssetKind::Background => "backgrounds",
+        UserDataAssetKind::Asset => "assets",
+        UserDataAssetKind::UserImage => "user/images",
+        UserDataAssetKind::UserFile => "user/files",
+        UserDataAssetKind::UserSound => "user/sounds",
+    };
+    format!(
+        "{}{}/{}",
+        ASSETS_ENTRY_PREFIX, prefix, asset.relative_path_display
+    )
+}
+
+fn is_allowed_asset(path: &Path) -> bool {
+    mime_guess::from_path(path)
+        .first()
+        .is_some_and(|mime| matches!(mime.type_().as_str(), "image" | "font" | "video"))
 }
 
 #[async_trait]
@@ -90,4 +384,196 @@ impl ThemeRepository for FileThemeRepository {
 
         Ok(())
     }
+
+    async fn list_themes(&self) -> Result<Vec<Theme>, DomainError> {
+        self.ensure_directory_exists().await?;
+
+        let mut themes = Vec::new();
+        for path in list_files_with_extension(&self.themes_dir, "json").await? {
+            let data = match read_json_file::<Value>(&path).await {
+                Ok(data) if data.is_object() => data,
+                Ok(_) => {
+                    logger::warn(&format!("Skipping non-object theme file {:?}", path));
+                    continue;
+                }
+                Err(error) => {
+                    logger::warn(&format!("Skipping unreadable theme {:?}: {}", path, error));
+                    continue;
+                }
+            };
+            let name = data
+                .get("name")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .or_else(|| {
+                    path.file_stem()
+                        .map(|stem| stem.to_string_lossy().to_string())
+                })
+                .unwrap_or_default();
+            themes.push(Theme::new(name, data));
+        }
+
+        themes.sort_by_key(|theme| theme.name.to_lowercase());
+        Ok(themes)
+    }
+
+    async fn export_theme(&self, name: &str) -> Result<ThemeArchive, DomainError> {
+        let path = self.get_theme_path(name)?;
+        if !path.exists() {
+            return Err(DomainError::NotFound(format!("Theme not found: {}", name)));
+        }
+
+        let data: Value = read_json_file(&path).await?;
+        let bytes = self.build_archive(&data)?;
+        let file_name = path
+            .with_extension("zip")
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "theme.zip".to_string());
+
+        Ok(ThemeArchive { file_name, bytes })
+    }
+
+    async fn import_theme(&self, file_path: &Path, overwrite: bool) -> Result<Theme, DomainError> {
+        let is_json = file_path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+        if is_json {
+            let bytes = tokio::fs::read(file_path).await.map_err(|error| {
+                DomainError::InvalidData(format!("Failed to read theme file: {}", error))
+            })?;
+            return self
+                .store_imported_theme(file_path, parse_theme_json(&bytes)?, Vec::new(), overwrite)
+                .await;
+        }
+
+        // Assets are extracted next to the themes so the final moves are plain renames.
+        let staging_dir = self
+            .themes_dir
+            .join(format!(".import-{}", uuid::Uuid::new_v4()));
+        let extracted = {
+            let file_path = file_path.to_path_buf();
+            let staging_dir = staging_dir.clone();
+            let user_dirs = self.user_dirs.clone();
+            tokio::task::spawn_blocking(move || {
+                extract_archive(&file_path, &staging_dir, &user_dirs)
+            })
+            .await
+            .map_err(|error| {
+                DomainError::InternalError(format!("Theme import task failed: {}", error))
+            })
+            .and_then(|extracted| extracted)
+        };
+        let result = match extracted {
+            Ok((data, assets)) => {
+                self.store_imported_theme(file_path, data, assets, overwrite)
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+
+        match tokio::fs::remove_dir_all(&staging_dir).await {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => logger::warn(&format!(
+                "Failed to remove theme staging directory {}: {}",
+                staging_dir.display(),
+                error
+            )),
+            _ => {}
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn repository(root: &Path) -> FileThemeRepository {
+        let user_dir = root.join("default-user");
+        FileThemeRepository::new(
+            user_dir.join("themes"),
+            DefaultUserWebDirs::from_data_root(root),
+        )
+    }
+
+    #[test]
+    fn css_asset_routes_keep_only_local_urls() {
+        let css = r#"
+            body { background: URL("/backgrounds/space%20cat.png?v=2"); }
+            .a { background-image: url('user/images/a.png'); }
+            .b { background: url(https://example.com/x.png); }
+            .c { background: url(data:image/png;base64,AA==); }
+        "#;
+
+        assert_eq!(
+            css_asset_routes(css),
+            vec!["/backgrounds/space%20cat.png", "/user/images/a.png"]
+        );
+    }
+
+    #[tokio::test]
+    async fn exported_theme_imports_with_its_assets() {
+        let root = std::env::temp_dir().join(format!("tauritavern-theme-{}", uuid::Uuid::new_v4()));
+        let source = repository(&root.join("source"));
+        let target = repository(&root.join("target"));
+        let background = root.join("source/default-user/backgrounds/space cat.png");
+        std::fs::create_dir_all(background.parent().expect("parent")).expect("create dir");
+        std::fs::write(&background, b"png").expect("write background");
+
+        source
+            .save_theme(&Theme::new(
+                "Space".to_string(),
+                json!({
+                    "main_text_color": "rgb(1, 2, 3)",
+                    "custom_css": "body { background: url('/backgrounds/space%20cat.png'); }"
+                }),
+            ))
+            .await
+            .expect("save theme");
+
+        let archive = source.export_theme("Space").await.expect("export");
+        assert_eq!(archive.file_name, "Space.zip");
+        let archive_path = root.join("Space.zip");
+        std::fs::write(&archive_path, &archive.bytes).expect("write archive");
+
+        let imported = target
+            .import_theme(&archive_path, false)
+            .await
+            .expect("import");
+        assert_eq!(imported.name, "Space");
+        assert_eq!(
+            std::fs::read(root.join("target/default-user/backgrounds/space cat.png"))
+                .expect("read restored asset"),
+            b"png"
+        );
+        assert!(target.import_theme(&archive_path, false).await.is_err());
+
+        let themes = target.list_themes().await.expect("list");
+        assert_eq!(themes.len(), 1);
+        assert_eq!(themes[0].data["main_text_color"], "rgb(1, 2, 3)");
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn failed_archive_import_leaves_no_assets_behind() {
+        let root = std::env::temp_dir().join(format!("tauritavern-theme-{}", uuid::Uuid::new_v4()));
+        let target = repository(&root);
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        write_archive_entry(&mut writer, "assets/backgrounds/sky.png", b"png").expect("asset");
+        let archive_path = root.join("Broken.zip");
+        std::fs::create_dir_all(&root).expect("create root");
+        std::fs::write(&archive_path, writer.finish().expect("finish").into_inner())
+            .expect("write archive");
+
+        assert!(target.import_theme(&archive_path, false).await.is_err());
+        assert!(!root.join("default-user/backgrounds/sky.png").exists());
+        let leftovers = std::fs::read_dir(root.join("default-user/themes"))
+            .map(|entries| entries.count())
+            .unwrap_or_default();
+        assert_eq!(leftovers, 0);
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
 }
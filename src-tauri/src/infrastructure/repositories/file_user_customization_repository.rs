@@ -0,0 +1,208 @@
+use async_trait::async_trait;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use tokio::fs;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::filename::sanitize_filename;
+use crate::domain::models::user_customization::UserScript;
+use crate::domain::repositories::user_customization_repository::UserCustomizationRepository;
+use crate::infrastructure::persistence::file_system::{atomic_write, list_files_with_extension};
+
+pub(crate) const CUSTOM_CSS_FILE: &str = "custom.css";
+pub(crate) const USER_SCRIPTS_DIR: &str = "scripts";
+const BACKUPS_DIR: &str = "backups";
+const MAX_BACKUPS_PER_FILE: usize = 10;
+
+/// Stores custom CSS at `<user>/custom.css` and scripts at `<user>/scripts/<name>.js`.
+/// Replaced content is copied to `<user>/backups/` first, keeping the newest
+/// [`MAX_BACKUPS_PER_FILE`] copies of each file.
+pub struct FileUserCustomizationRepository {
+    user_dir: PathBuf,
+}
+
+impl FileUserCustomizationRepository {
+    pub fn new(user_dir: PathBuf) -> Self {
+        Self { user_dir }
+    }
+
+    fn custom_css_path(&self) -> PathBuf {
+        self.user_dir.join(CUSTOM_CSS_FILE)
+    }
+
+    fn scripts_dir(&self) -> PathBuf {
+        self.user_dir.join(USER_SCRIPTS_DIR)
+    }
+
+    fn script_path(&self, name: &str) -> Result<PathBuf, DomainError> {
+        let file_name = sanitize_filename(&format!("{name}.js"));
+        if file_name.is_empty() || file_name != format!("{name}.js") {
+            return Err(DomainError::InvalidData(format!(
+                "Invalid user script name: {}",
+                name
+            )));
+        }
+
+        Ok(self.scripts_dir().join(file_name))
+    }
+
+    /// Writes `content` to `path`, backing up the previous content when it differs.
+    async fn replace_with_backup(&self, path: &Path, content: &str) -> Result<(), DomainError> {
+        match read_optional(path).await? {
+            Some(previous) if previous == content => return Ok(()),
+            Some(previous) => self.write_backup(path, &previous).await?,
+            None => {}
+        }
+
+        atomic_write(path, content.as_bytes()).await
+    }
+
+    async fn write_backup(&self, path: &Path, previous: &str) -> Result<(), DomainError> {
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(CUSTOM_CSS_FILE);
+        let backups_dir = self.user_dir.join(BACKUPS_DIR);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let backup_path = backups_dir.join(format!("{}.{}.bak", file_name, timestamp));
+
+        atomic_write(&backup_path, previous.as_bytes()).await?;
+        prune_backups(&backups_dir, file_name).await
+    }
+}
+
+async fn read_optional(path: &Path) -> Result<Option<String>, DomainError> {
+    match fs::read_to_string(path).await {
+        Ok(contents) => Ok(Some(contents)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(DomainError::InternalError(format!(
+            "Failed to read {:?}: {}",
+            path, error
+        ))),
+    }
+}
+
+/// Keeps the newest backups of `file_name`, ordered by the millisecond timestamp
+/// embedded in each backup name.
+async fn prune_backups(backups_dir: &Path, file_name: &str) -> Result<(), DomainError> {
+    let prefix = format!("{}.", file_name);
+    let mut backups = Vec::new();
+    for path in list_files_with_extension(backups_dir, "bak").await? {
+        let Some(stamp) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix(&prefix))
+            .and_then(|rest| rest.strip_suffix(".bak"))
+            .and_then(|stamp| stamp.parse::<u128>().ok())
+        else {
+            continue;
+        };
+        backups.push((stamp, path));
+    }
+
+    backups.sort_by(|a, b| b.0.cmp(&a.0));
+    for (_, path) in backups.into_iter().skip(MAX_BACKUPS_PER_FILE) {
+        if let Err(error) = fs::remove_file(&path).await {
+            tracing::warn!("Failed to remove old backup {:?}: {}", path, error);
+        }
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl UserCustomizationRepository for FileUserCustomizationRepository {
+    async fn load_custom_css(&self) -> Result<String, DomainError> {
+        Ok(read_optional(&self.custom_css_path())
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn save_custom_css(&self, css: &str) -> Result<(), DomainError> {
+        self.replace_with_backup(&self.custom_css_path(), css).await
+    }
+
+    async fn list_user_scripts(&self) -> Result<Vec<UserScript>, DomainError> {
+        let mut scripts = Vec::new();
+        for path in list_files_with_extension(&self.scripts_dir(), "js").await? {
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let Some(content) = read_optional(&path).await? else {
+                continue;
+            };
+            scripts.push(UserScript {
+                name: name.to_string(),
+                content,
+            });
+        }
+
+        scripts.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(scripts)
+    }
+
+    async fn save_user_script(&self, script: &UserScript) -> Result<(), DomainError> {
+        let path = self.script_path(&script.name)?;
+        self.replace_with_backup(&path, &script.content).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn overwriting_keeps_bounded_backups() {
+        let root = std::env::temp_dir().join(format!(
+            "tauritavern-user-customization-{}",
+            uuid::Uuid::new_v4()
+        ));
+        let repository = FileUserCustomizationRepository::new(root.join("user"));
+
+        assert_eq!(repository.load_custom_css().await.expect("load"), "");
+        for index in 0..=MAX_BACKUPS_PER_FILE + 1 {
+            repository
+                .save_custom_css(&format!("body {{ order: {index}; }}"))
+                .await
+                .expect("save css");
+            tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+        }
+        repository
+            .save_custom_css(&format!("body {{ order: {}; }}", MAX_BACKUPS_PER_FILE + 1))
+            .await
+            .expect("save unchanged css");
+
+        let backups = std::fs::read_dir(root.join("user").join(BACKUPS_DIR))
+            .expect("read backups")
+            .count();
+        assert_eq!(backups, MAX_BACKUPS_PER_FILE);
+
+        repository
+            .save_user_script(&UserScript {
+                name: "greeting".to_string(),
+                content: "console.log('hi');".to_string(),
+            })
+            .await
+            .expect("save script");
+        assert!(
+            repository
+                .save_user_script(&UserScript {
+                    name: "../escape".to_string(),
+                    content: String::new(),
+                })
+                .await
+                .is_err()
+        );
+
+        let scripts = repository.list_user_scripts().await.expect("list");
+        assert_eq!(scripts.len(), 1);
+        assert_eq!(scripts[0].name, "greeting");
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -3,23 +3,37 @@ pub(crate) mod chat_directory_identity;
 pub mod file_agent_profile_repository;
 pub mod file_agent_repository;
 pub mod file_asset_repository;
+pub mod file_author_note_repository;
 pub mod file_avatar_repository;
 pub mod file_background_repository;
+pub mod file_changelog_repository;
+pub mod file_character_asset_repository;
+pub mod file_character_generation_overrides_repository;
+pub mod file_character_note_repository;
+pub mod file_character_prompt_overrides_repository;
 pub mod file_character_repository;
 pub mod file_chat_repository;
 pub mod file_content_repository;
+pub mod file_data_bank_repository;
 pub mod file_extension_repository;
 pub mod file_extension_store_repository;
+pub mod file_global_variable_repository;
 pub mod file_group_repository;
 pub mod file_image_metadata_repository;
+pub mod file_inline_image_repository;
 pub mod file_llm_connection_repository;
 pub mod file_preset_repository;
 pub mod file_prompt_cache_repository;
 pub mod file_quick_reply_repository;
+pub mod file_recent_items_repository;
 pub mod file_secret_repository;
 pub mod file_settings_repository;
 pub mod file_skill_repository;
+pub mod file_sound_repository;
+pub mod file_sprite_repository;
+pub mod file_tag_repository;
 pub mod file_theme_repository;
+pub mod file_user_customization_repository;
 pub mod file_user_directory_repository;
 pub mod file_user_repository;
 pub mod file_world_info_repository;
@@ -0,0 +1,180 @@
+//! Passphrase encryption for exported secret bundles: PBKDF2-HMAC-SHA256 derives the key
+//! and XChaCha20-Poly1305 seals the payload. The versioned header binds the scheme and
+//! its parameters to the ciphertext as associated data.
+
+use chacha20poly1305::aead::AeadInPlace;
+use chacha20poly1305::{Key, KeyInit, Tag, XChaCha20Poly1305, XNonce};
+use rand::RngCore;
+use sha2::Sha256;
+
+use crate::domain::errors::DomainError;
+
+pub const KDF: &str = "pbkdf2-sha256";
+pub const CIPHER: &str = "xchacha20poly1305";
+pub const HEADER_VERSION: u32 = 1;
+
+const HEADER_MAGIC: &[u8] = b"tauritavern-secrets";
+const PBKDF2_ITERATIONS: u32 = 600_000;
+/// Upper bound for iteration counts read from a bundle, so a crafted bundle cannot stall
+/// the import.
+const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const TAG_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SealedBytes {
+    pub version: u32,
+    pub iterations: u32,
+    pub salt: Vec<u8>,
+    pub nonce: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+    pub tag: Vec<u8>,
+}
+
+impl SealedBytes {
+    /// Associated data for the AEAD: magic, header version, algorithm names and KDF
+    /// parameters. Changing any of them makes `open` fail authentication.
+    fn header(&self) -> Vec<u8> {
+        let mut header = Vec::with_capacity(HEADER_MAGIC.len() + 64);
+        for field in [HEADER_MAGIC, KDF.as_bytes(), CIPHER.as_bytes()] {
+            header.extend_from_slice(field);
+            header.push(0);
+        }
+        header.extend_from_slice(&self.version.to_be_bytes());
+        header.extend_from_slice(&self.iterations.to_be_bytes());
+        header.extend_from_slice(&self.salt);
+        header
+    }
+}
+
+pub fn seal(passphrase: &str, plaintext: &[u8]) -> SealedBytes {
+    seal_with_iterations(passphrase, plaintext, PBKDF2_ITERATIONS)
+}
+
+fn seal_with_iterations(passphrase: &str, plaintext: &[u8], iterations: u32) -> SealedBytes {
+    let mut salt = vec![0u8; SALT_LEN];
+    let mut nonce = vec![0u8; NONCE_LEN];
+    rand::rng().fill_bytes(&mut salt);
+    rand::rng().fill_bytes(&mut nonce);
+
+    let mut sealed = SealedBytes {
+        version: HEADER_VERSION,
+        iterations,
+        salt,
+        nonce,
+        ciphertext: plaintext.to_vec(),
+        tag: Vec::new(),
+    };
+    let tag = cipher(passphrase, &sealed)
+        .encrypt_in_place_detached(
+            XNonce::from_slice(&sealed.nonce),
+            &sealed.header(),
+            &mut sealed.ciphertext,
+        )
+        .expect("secret bundles are far below the XChaCha20-Poly1305 length limit");
+    sealed.tag = tag.to_vec();
+    sealed
+}
+
+/// Fails with `AuthenticationError` for a wrong passphrase or a modified bundle.
+pub fn open(passphrase: &str, sealed: &SealedBytes) -> Result<Vec<u8>, DomainError> {
+    if sealed.version != HEADER_VERSION {
+        return Err(DomainError::InvalidData(format!(
+            "Unsupported secret bundle encryption version: {}",
+            sealed.version
+        )));
+    }
+    if sealed.iterations == 0 || sealed.iterations > MAX_PBKDF2_ITERATIONS {
+        return Err(DomainError::InvalidData(format!(
+            "Unsupported key derivation iteration count: {}",
+            sealed.iterations
+        )));
+    }
+    if sealed.salt.len() != SALT_LEN
+        || sealed.nonce.len() != NONCE_LEN
+        || sealed.tag.len() != TAG_LEN
+    {
+        return Err(DomainError::InvalidData(
+            "Secret bundle salt, nonce or tag has the wrong length".to_string(),
+        ));
+    }
+
+    let mut plaintext = sealed.ciphertext.clone();
+    cipher(passphrase, sealed)
+        .decrypt_in_place_detached(
+            XNonce::from_slice(&sealed.nonce),
+            &sealed.header(),
+            &mut plaintext,
+            Tag::from_slice(&sealed.tag),
+        )
+        .map_err(|_| {
+            DomainError::AuthenticationError(
+                "Wrong passphrase or corrupted secret bundle".to_string(),
+            )
+        })?;
+    Ok(plaintext)
+}
+
+fn cipher(passphrase: &str, sealed: &SealedBytes) -> XChaCha20Poly1305 {
+    let mut key = [0u8; KEY_LEN];
+    pbkdf2::pbkdf2_hmac::<Sha256>(
+        passphrase.as_bytes(),
+        &sealed.salt,
+        sealed.iterations,
+        &mut key,
+    );
+    XChaCha20Poly1305::new(Key::from_slice(&key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{open, seal_with_iterations};
+    use crate::domain::errors::DomainError;
+
+    #[test]
+    fn sealed_bytes_open_only_with_the_passphrase() {
+        let plaintext = br#"{"api_key_openai":[{"value":"sk-test"}]}"#.repeat(3);
+        let mut sealed = seal_with_iterations("correct horse", &plaintext, 1_000);
+        assert_ne!(sealed.ciphertext, plaintext);
+
+        assert_eq!(open("correct horse", &sealed).unwrap(), plaintext);
+        assert!(matches!(
+            open("wrong horse", &sealed),
+            Err(DomainError::AuthenticationError(_))
+        ));
+
+        sealed.ciphertext[0] ^= 1;
+        assert!(matches!(
+            open("correct horse", &sealed),
+            Err(DomainError::AuthenticationError(_))
+        ));
+    }
+
+    #[test]
+    fn header_fields_are_authenticated() {
+        let sealed = seal_with_iterations("correct horse", b"{}", 1_000);
+
+        let mut weakened = sealed.clone();
+        weakened.iterations = 999;
+        assert!(matches!(
+            open("correct horse", &weakened),
+            Err(DomainError::AuthenticationError(_))
+        ));
+
+        let mut unknown = sealed.clone();
+        unknown.version += 1;
+        assert!(matches!(
+            open("correct horse", &unknown),
+            Err(DomainError::InvalidData(_))
+        ));
+
+        let mut truncated = sealed;
+        truncated.nonce.pop();
+        assert!(matches!(
+            open("correct horse", &truncated),
+            Err(DomainError::InvalidData(_))
+        ));
+    }
+}
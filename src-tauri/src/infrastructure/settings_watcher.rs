@@ -0,0 +1,200 @@
+//! Watches `settings.json` and the themes directory for edits made outside the app (sync
+//! tools such as Syncthing, text editors) and forwards the new content to the frontend.
+//!
+//! Writes performed by the app itself are registered in
+//! [`internal_writes`](crate::infrastructure::persistence::internal_writes) so
+//! the frontend is not asked to reload data it just saved.
+
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+pub use watcher::SettingsFileWatcher;
+
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+mod watcher {
+    use std::collections::HashSet;
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use notify::event::ModifyKind;
+    use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+    use serde::Serialize;
+    use serde_json::Value;
+    use tauri::{AppHandle, Emitter};
+    use tokio::sync::mpsc;
+
+    use crate::domain::errors::DomainError;
+    use crate::infrastructure::persistence::internal_writes::{
+        is_recent_internal_write, same_directory, same_file,
+    };
+
+    pub const SETTINGS_CHANGED_EVENT: &str = "settings-changed";
+    pub const THEME_CHANGED_EVENT: &str = "theme-changed";
+
+    /// Sync tools usually write a temp file and rename it; wait for the burst to settle.
+    const DEBOUNCE: Duration = Duration::from_millis(500);
+
+    #[derive(Debug, Clone, Serialize)]
+    struct SettingsChangedPayload {
+        settings: Value,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    struct ThemeChangedPayload {
+        name: String,
+        /// `None` when the theme file was deleted
+        theme: Option<Value>,
+    }
+
+    /// Keeps the underlying OS watcher alive; dropping it stops watching.
+    pub struct SettingsFileWatcher {
+        _watcher: Mutex<RecommendedWatcher>,
+    }
+
+    impl SettingsFileWatcher {
+        pub fn start(
+            app_handle: AppHandle,
+            settings_file: PathBuf,
+            themes_dir: PathBuf,
+        ) -> Result<Self, DomainError> {
+            let (sender, receiver) = mpsc::unbounded_channel::<PathBuf>();
+            let mut watcher =
+                notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+                    match result {
+                        Ok(event) if is_content_event(&event.kind) => {
+                            for path in event.paths {
+                                let _ = sender.send(path);
+                            }
+                        }
+                        Ok(_) => {}
+                        Err(error) => tracing::warn!("Settings watcher error: {}", error),
+                    }
+                })
+                .map_err(|error| {
+                    DomainError::InternalError(format!(
+                        "Failed to create settings watcher: {}",
+                        error
+                    ))
+                })?;
+
+            let settings_dir = settings_file.parent().ok_or_else(|| {
+                DomainError::InvalidData(format!(
+                    "Settings file has no parent directory: {}",
+                    settings_file.display()
+                ))
+            })?;
+            for directory in [settings_dir, themes_dir.as_path()] {
+                watcher
+                    .watch(directory, RecursiveMode::NonRecursive)
+                    .map_err(|error| {
+                        DomainError::InternalError(format!(
+                            "Failed to watch {}: {}",
+                            directory.display(),
+                            error
+                        ))
+                    })?;
+            }
+
+            tauri::async_runtime::spawn(dispatch_changes(
+                app_handle,
+                receiver,
+                settings_file,
+                themes_dir,
+            ));
+
+            Ok(Self {
+                _watcher: Mutex::new(watcher),
+            })
+        }
+    }
+
+    fn is_content_event(kind: &EventKind) -> bool {
+        match kind {
+            EventKind::Modify(ModifyKind::Metadata(_)) => false,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_) => true,
+            _ => false,
+        }
+    }
+
+    async fn dispatch_changes(
+        app_handle: AppHandle,
+        mut receiver: mpsc::UnboundedReceiver<PathBuf>,
+        settings_file: PathBuf,
+        themes_dir: PathBuf,
+    ) {
+        while let Some(first) = receiver.recv().await {
+            let mut pending = HashSet::from([first]);
+            while let Ok(Some(path)) = tokio::time::timeout(DEBOUNCE, receiver.recv()).await {
+                pending.insert(path);
+            }
+
+            for path in pending {
+                if is_recent_internal_write(&path) {
+                    continue;
+                }
+
+                if same_file(&path, &settings_file) {
+                    emit_settings_change(&app_handle, &path).await;
+                } else if is_theme_file(&path, &themes_dir) {
+                    emit_theme_change(&app_handle, &path).await;
+                }
+            }
+        }
+    }
+
+    fn is_theme_file(path: &Path, themes_dir: &Path) -> bool {
+        path.extension()
+            .is_some_and(|extension| extension == "json")
+            && path
+                .parent()
+                .is_some_and(|parent| same_directory(parent, themes_dir))
+    }
+
+    /// Reads a JSON object, or `None` while a sync tool is still mid-write.
+    async fn read_json_object(path: &Path) -> Option<Value> {
+        let contents = tokio::fs::read_to_string(path).await.ok()?;
+        serde_json::from_str::<Value>(&contents)
+            .ok()
+            .filter(Value::is_object)
+    }
+
+    async fn emit_settings_change(app_handle: &AppHandle, path: &Path) {
+        let Some(settings) = read_json_object(path).await else {
+            tracing::debug!("Ignoring unreadable external settings change: {:?}", path);
+            return;
+        };
+
+        tracing::info!("Detected external change to {:?}", path);
+        if let Err(error) =
+            app_handle.emit(SETTINGS_CHANGED_EVENT, SettingsChangedPayload { settings })
+        {
+            tracing::error!("Failed to emit {} event: {}", SETTINGS_CHANGED_EVENT, error);
+        }
+    }
+
+    async fn emit_theme_change(app_handle: &AppHandle, path: &Path) {
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            return;
+        };
+
+        let theme = if path.exists() {
+            match read_json_object(path).await {
+                Some(theme) => Some(theme),
+                None => {
+                    tracing::debug!("Ignoring unreadable external theme change: {:?}", path);
+                    return;
+                }
+            }
+        } else {
+            None
+        };
+
+        tracing::info!("Detected external change to theme '{}'", name);
+        let payload = ThemeChangedPayload {
+            name: name.to_string(),
+            theme,
+        };
+        if let Err(error) = app_handle.emit(THEME_CHANGED_EVENT, payload) {
+            tracing::error!("Failed to emit {} event: {}", THEME_CHANGED_EVENT, error);
+        }
+    }
+}
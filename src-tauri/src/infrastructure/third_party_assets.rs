@@ -1,7 +1,18 @@
+use std::collections::HashMap;
+use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+
+use sha2::{Digest, Sha256};
 
 use crate::domain::errors::DomainError;
 
+/// Content hashes keyed by path, reused while size and mtime are unchanged.
+type EtagCache = HashMap<PathBuf, (u64, Option<SystemTime>, String)>;
+
+static THIRD_PARTY_ASSET_ETAGS: OnceLock<Mutex<EtagCache>> = OnceLock::new();
+
 #[derive(Debug, Clone)]
 pub struct ThirdPartyExtensionDirs {
     pub local_dir: PathBuf,
@@ -23,6 +34,58 @@ pub struct ResolvedThirdPartyAsset {
     pub path: PathBuf,
     pub mime_type: String,
     pub size_bytes: u64,
+    pub modified: Option<SystemTime>,
+}
+
+impl ResolvedThirdPartyAsset {
+    /// Strong ETag derived from the file contents. The hash is computed once per
+    /// (size, mtime) so repeated loads only cost a metadata lookup.
+    pub fn etag(&self) -> Result<String, DomainError> {
+        let cache = THIRD_PARTY_ASSET_ETAGS.get_or_init(|| Mutex::new(HashMap::new()));
+        if let Some((size, modified, etag)) = cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&self.path)
+        {
+            if *size == self.size_bytes && *modified == self.modified {
+                return Ok(etag.clone());
+            }
+        }
+
+        let etag = format!("\"{}\"", hash_file(&self.path)?);
+        cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(
+                self.path.clone(),
+                (self.size_bytes, self.modified, etag.clone()),
+            );
+        Ok(etag)
+    }
+}
+
+fn hash_file(path: &Path) -> Result<String, DomainError> {
+    let hash_error = |error: std::io::Error| {
+        DomainError::InternalError(format!(
+            "Failed to hash third-party extension asset ({}): {}",
+            path.display(),
+            error
+        ))
+    };
+
+    let mut file = std::fs::File::open(path).map_err(hash_error)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).map_err(hash_error)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    let digest = format!("{:x}", hasher.finalize());
+    Ok(digest[..32].to_string())
 }
 
 pub fn resolve_third_party_extension_asset(
@@ -62,6 +125,7 @@ pub fn resolve_third_party_extension_asset(
             path: asset_path,
             mime_type,
             size_bytes: metadata.len(),
+            modified: metadata.modified().ok(),
         });
     }
 
@@ -136,6 +200,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn etag_tracks_file_contents() {
+        let temp = TempDirGuard::new("third-party-assets-etag");
+        let extension_root = temp.path.join("local").join("etag-ext");
+        std::fs::create_dir_all(&extension_root).expect("create extension");
+        std::fs::write(extension_root.join("index.js"), b"console.log(1);").expect("write");
+
+        let resolve = || {
+            resolve_third_party_extension_asset(
+                &temp.path.join("local"),
+                &temp.path.join("global"),
+                "etag-ext",
+                Path::new("index.js"),
+            )
+            .expect("resolve script")
+        };
+
+        let first = resolve().etag().expect("etag");
+        assert_eq!(resolve().etag().expect("cached etag"), first);
+        assert!(first.starts_with('"') && first.ends_with('"'));
+
+        std::fs::write(extension_root.join("index.js"), b"console.log(22);").expect("rewrite");
+        assert_ne!(resolve().etag().expect("updated etag"), first);
+    }
+
     #[test]
     fn returns_not_found_when_asset_missing() {
         let temp = TempDirGuard::new("third-party-assets-not-found");
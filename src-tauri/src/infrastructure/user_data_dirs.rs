@@ -10,6 +10,7 @@ pub struct DefaultUserWebDirs {
     pub assets_dir: PathBuf,
     pub user_images_dir: PathBuf,
     pub user_files_dir: PathBuf,
+    pub user_sounds_dir: PathBuf,
     pub thumbnails_bg_dir: PathBuf,
     pub thumbnails_avatar_dir: PathBuf,
     pub thumbnails_persona_dir: PathBuf,
@@ -25,6 +26,7 @@ impl DefaultUserWebDirs {
             assets_dir: directories.assets,
             user_images_dir: directories.user_images,
             user_files_dir: directories.files,
+            user_sounds_dir: directories.sounds,
             thumbnails_bg_dir: directories.thumbnails_bg,
             thumbnails_avatar_dir: directories.thumbnails_avatar,
             thumbnails_persona_dir: directories.thumbnails_persona,
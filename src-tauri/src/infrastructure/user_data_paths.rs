@@ -7,6 +7,7 @@ pub(crate) const BACKGROUNDS_ROUTE_PREFIX: &str = "/backgrounds/";
 pub(crate) const ASSETS_ROUTE_PREFIX: &str = "/assets/";
 pub(crate) const USER_IMAGES_ROUTE_PREFIX: &str = "/user/images/";
 pub(crate) const USER_FILES_ROUTE_PREFIX: &str = "/user/files/";
+pub(crate) const USER_SOUNDS_ROUTE_PREFIX: &str = "/user/sounds/";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum UserDataAssetKind {
@@ -16,6 +17,7 @@ pub(crate) enum UserDataAssetKind {
     Asset,
     UserImage,
     UserFile,
+    UserSound,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -39,6 +41,7 @@ pub(crate) fn is_user_data_asset_route(path: &str) -> bool {
         || path.starts_with(ASSETS_ROUTE_PREFIX)
         || path.starts_with(USER_IMAGES_ROUTE_PREFIX)
         || path.starts_with(USER_FILES_ROUTE_PREFIX)
+        || path.starts_with(USER_SOUNDS_ROUTE_PREFIX)
 }
 
 pub(crate) fn parse_user_data_asset_request_path(
@@ -58,6 +61,8 @@ pub(crate) fn parse_user_data_asset_request_path(
         (UserDataAssetKind::UserImage, suffix)
     } else if let Some(suffix) = path.strip_prefix(USER_FILES_ROUTE_PREFIX) {
         (UserDataAssetKind::UserFile, suffix)
+    } else if let Some(suffix) = path.strip_prefix(USER_SOUNDS_ROUTE_PREFIX) {
+        (UserDataAssetKind::UserSound, suffix)
     } else {
         return Ok(None);
     };
@@ -12,7 +12,7 @@ mod presentation;
 use app::spawn_initialization;
 use infrastructure::data_root_content_dirs::DataRootContentDirs;
 use infrastructure::http_client_pool::HttpClientPool;
-use infrastructure::logging::{devtools, llm_api_logs, logger};
+use infrastructure::logging::{crash_reports, devtools, llm_api_logs, logger, performance_trace};
 use infrastructure::paths::resolve_runtime_paths;
 use infrastructure::third_party_assets::ThirdPartyExtensionDirs;
 use infrastructure::user_data_dirs::DefaultUserWebDirs;
@@ -68,13 +68,27 @@ pub fn run() {
     // Register cross-platform host plugins up front.
     // This is the only place that should know which native capabilities are part of
     // the app shell; downstream layers consume them through commands/bridges.
-    let builder = tauri::Builder::default()
+    let builder = tauri::Builder::default();
+
+    // A second desktop launch, e.g. from a `tauritavern://` link, focuses this instance
+    // instead; with the `deep-link` feature its URL is forwarded to the deep link plugin.
+    // The plugin has to be registered first.
+    #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+    let builder = builder.plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+        presentation::deep_links::focus_main_window(app);
+    }));
+
+    let builder = builder
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_opener::init());
 
     #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
-    let builder = builder.plugin(tauri_plugin_dialog::init());
+    let builder = builder
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(presentation::global_hotkeys::global_hotkeys_plugin());
 
     #[cfg(mobile)]
     let builder = builder.plugin(tauri_plugin_barcode_scanner::init());
@@ -94,6 +108,14 @@ pub fn run() {
             let runtime_paths = resolve_runtime_paths(&app_handle)?;
             app.manage(runtime_paths.clone());
 
+            // Capture panics as early as the log root is known, including the one raised
+            // when the Tauri runtime itself fails after setup.
+            let crash_reports = std::sync::Arc::new(crash_reports::CrashReportStore::new(
+                &runtime_paths.log_root,
+            ));
+            crash_reports::install_panic_hook(crash_reports.clone());
+            app.manage(crash_reports);
+
             if let Err(error) = devtools::purge_old_log_files(
                 &runtime_paths.log_root,
                 std::time::Duration::from_secs(14 * 24 * 60 * 60),
@@ -123,6 +145,12 @@ pub fn run() {
             ));
             app.manage(llm_api_log_store.clone());
 
+            let performance_tracer = std::sync::Arc::new(
+                performance_trace::PerformanceTracer::new(&runtime_paths.log_root),
+            );
+            performance_trace::PerformanceTracer::install(performance_tracer.clone());
+            app.manage(performance_tracer.clone());
+
             if let Err(error) =
                 logger::init_logger(&runtime_paths.log_root, Some(backend_log_store))
             {
@@ -150,9 +178,9 @@ pub fn run() {
             let user_dirs = DefaultUserWebDirs::from_data_root(&runtime_paths.data_root);
             let data_root_content_dirs =
                 DataRootContentDirs::from_data_root(&runtime_paths.data_root);
-            app.manage(third_party_dirs.clone());
-            app.manage(user_dirs.clone());
-            app.manage(data_root_content_dirs.clone());
+            app.manage(third_party_dirs);
+            app.manage(user_dirs);
+            app.manage(data_root_content_dirs);
 
             let tauritavern_settings = load_tauritavern_settings(&runtime_paths.data_root)?;
             let ios_policy_scope =
@@ -176,7 +204,7 @@ pub fn run() {
             let thumbnail_policy = std::sync::Arc::new(ThumbnailEndpointPolicy::new(
                 tauritavern_settings.avatar_persona_original_images_enabled,
             ));
-            app.manage(thumbnail_policy.clone());
+            app.manage(thumbnail_policy);
 
             if ios_policy.scope == crate::domain::ios_policy::IosPolicyScope::Ios
                 && tauritavern_settings.request_proxy.enabled
@@ -188,29 +216,38 @@ pub fn run() {
             }
 
             http_client_pool.apply_request_proxy_settings(&tauritavern_settings.request_proxy)?;
+            http_client_pool
+                .apply_http_client_tuning_settings(&tauritavern_settings.http_client)?;
             llm_api_log_store.apply_settings(tauritavern_settings.dev.effective_llm_api_keep());
-            let _main_window = create_main_window(
-                app,
-                third_party_dirs,
-                user_dirs,
-                data_root_content_dirs,
-                thumbnail_policy,
-            )?;
-
-            #[cfg(target_os = "windows")]
+            performance_tracer.set_enabled(tauritavern_settings.dev.performance_tracing);
+            let _main_window = create_main_window(app)?;
+
+            #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
             {
-                let close_to_tray_on_close =
-                    load_close_to_tray_on_close_setting(&runtime_paths.data_root)?;
-                let tray_state = std::sync::Arc::new(
-                    presentation::windows_tray::WindowsTrayState::new(close_to_tray_on_close),
-                );
-                presentation::windows_tray::install_windows_tray(
+                let tray_state =
+                    std::sync::Arc::new(presentation::desktop_tray::DesktopTrayState::new(
+                        tauritavern_settings.close_to_tray_on_close,
+                        tauritavern_settings.minimize_to_tray,
+                    ));
+                presentation::desktop_tray::install_desktop_tray(
                     &app_handle,
                     &_main_window,
+                    runtime_paths.data_root.clone(),
                     tray_state,
                 )?;
+                presentation::global_hotkeys::install_global_hotkeys(
+                    &app_handle,
+                    &tauritavern_settings.global_hotkeys,
+                );
+                app.manage(std::sync::Arc::new(
+                    presentation::chat_windows::ChatWindowRegistry::default(),
+                ));
             }
 
+            // Links are queued until the frontend takes them, so they can be routed before
+            // the app state exists.
+            presentation::deep_links::install_deep_link_handler(&app_handle)?;
+
             // Heavy app state initialization is spawned after the shell is ready so window
             // creation and host plumbing stay responsive. The async initializer emits the
             // readiness/error events consumed by the frontend bootstrap.
@@ -225,18 +262,12 @@ pub fn run() {
 /// Builds the main webview window and attaches host-owned browser/runtime policy.
 ///
 /// Keep this function focused on shell concerns:
-/// - resource URL interception for local/runtime-backed assets
-/// - `window.open()` policy and popup/external-link routing
 /// - platform-specific window presentation details
 ///
 /// Do not move feature behavior here; frontend and command layers should continue to observe
 /// browser-like contracts without depending on Tauri window APIs.
 fn create_main_window(
     app: &mut tauri::App,
-    third_party_dirs: ThirdPartyExtensionDirs,
-    user_dirs: DefaultUserWebDirs,
-    data_root_content_dirs: DataRootContentDirs,
-    thumbnail_policy: std::sync::Arc<ThumbnailEndpointPolicy>,
 ) -> Result<tauri::webview::WebviewWindow, Box<dyn std::error::Error>> {
     let window_config = app
         .config()
@@ -246,13 +277,63 @@ fn create_main_window(
         .find(|config| config.label == "main")
         .expect("Main window config with label 'main' is missing");
 
+    let builder = tauri::webview::WebviewWindowBuilder::from_config(app.handle(), window_config)?;
+    let builder = apply_webview_policy(builder, app.handle());
+
+    #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+    // Desktop windows start hidden so restored size/position can be applied before first paint.
+    let builder = builder.visible(false);
+
+    let window = builder.build()?;
+
+    #[cfg(target_os = "ios")]
+    infrastructure::ios_webview::configure_main_wkwebview(&window)?;
+
+    #[cfg(target_os = "macos")]
+    infrastructure::macos_webview::configure_main_wkwebview(&window)?;
+
+    #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+    {
+        use tauri_plugin_window_state::WindowExt;
+
+        // Restore persisted desktop geometry only after the window exists, then reveal/focus it.
+        let flags = desktop_window_state_flags();
+        window.restore_state(flags)?;
+        window.show()?;
+        window.set_focus()?;
+    }
+
+    Ok(window)
+}
+
+/// Attaches the host-owned browser policy every app webview shares:
+/// - resource URL interception for local/runtime-backed assets
+/// - `window.open()` policy and popup/external-link routing
+///
+/// Reads the web directories and thumbnail policy from managed state, so it must run after
+/// they are registered in setup.
+pub(crate) fn apply_webview_policy<'a>(
+    builder: tauri::webview::WebviewWindowBuilder<'a, tauri::Wry, tauri::AppHandle>,
+    app_handle: &tauri::AppHandle,
+) -> tauri::webview::WebviewWindowBuilder<'a, tauri::Wry, tauri::AppHandle> {
+    let third_party_dirs = app_handle
+        .state::<ThirdPartyExtensionDirs>()
+        .inner()
+        .clone();
     let local_extensions_dir = third_party_dirs.local_dir;
     let global_extensions_dir = third_party_dirs.global_dir;
-    let user_dirs = user_dirs;
-    let user_css_file = data_root_content_dirs.user_css_file;
-    let thumbnail_policy = thumbnail_policy;
-
-    let builder = tauri::webview::WebviewWindowBuilder::from_config(app.handle(), window_config)?
+    let user_dirs = app_handle.state::<DefaultUserWebDirs>().inner().clone();
+    let user_css_file = app_handle
+        .state::<DataRootContentDirs>()
+        .inner()
+        .user_css_file
+        .clone();
+    let thumbnail_policy = app_handle
+        .state::<std::sync::Arc<ThumbnailEndpointPolicy>>()
+        .inner()
+        .clone();
+
+    let builder = builder
         // Route browser-visible URLs to host-owned file handlers here so the frontend can keep
         // using stable HTTP-like paths for extensions, thumbnails, and user data assets.
         .on_web_resource_request(move |request, response| {
@@ -269,7 +350,7 @@ fn create_main_window(
 
     #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
     let builder = {
-        let app_handle = app.handle().clone();
+        let app_handle = app_handle.clone();
 
         // `window.open()` semantics belong to the host/runtime boundary, not to upstream JS.
         // We keep OAuth-style popups inside the app to preserve opener/postMessage behavior,
@@ -311,38 +392,7 @@ fn create_main_window(
         })
     };
 
-    #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
-    // Desktop windows start hidden so restored size/position can be applied before first paint.
-    let builder = builder.visible(false);
-
-    let window = builder.build()?;
-
-    #[cfg(target_os = "ios")]
-    infrastructure::ios_webview::configure_main_wkwebview(&window)?;
-
-    #[cfg(target_os = "macos")]
-    infrastructure::macos_webview::configure_main_wkwebview(&window)?;
-
-    #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
-    {
-        use tauri_plugin_window_state::WindowExt;
-
-        // Restore persisted desktop geometry only after the window exists, then reveal/focus it.
-        let flags = desktop_window_state_flags();
-        window.restore_state(flags)?;
-        window.show()?;
-        window.set_focus()?;
-    }
-
-    Ok(window)
-}
-
-#[cfg(target_os = "windows")]
-fn load_close_to_tray_on_close_setting(
-    data_root: &std::path::Path,
-) -> Result<bool, Box<dyn std::error::Error>> {
-    let settings = load_tauritavern_settings(data_root)?;
-    Ok(settings.close_to_tray_on_close)
+    builder
 }
 
 fn load_tauritavern_settings(
@@ -0,0 +1,346 @@
+//! Optional HTTPS bridge that lets a browser on another LAN device use TauriTavern data
+//! through SillyTavern-compatible routes. Off by default; every route except `/api/ping`
+//! requires the auth token stored next to the bind address in
+//! `default-user/user/bridge-server/config.json`, or the token of a companion device
+//! paired with a short-lived code. The server uses a self-signed certificate whose public
+//! key hash is part of the pairing URI, so companions can pin it. While running, the
+//! server is advertised over mDNS.
+
+mod pairing;
+mod routes;
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum_server::tls_rustls::RustlsConfig;
+use local_ip_address::{list_afinet_netifas, local_ip};
+use qrcode::QrCode;
+use tokio::sync::Mutex;
+use ttsync_http::tls::{SelfManagedTls, TlsProvider};
+use url::Url;
+
+use crate::app::AppState;
+use crate::domain::errors::DomainError;
+use crate::domain::models::bridge_server::{
+    BRIDGE_MDNS_SERVICE_TYPE, BridgePairedDeviceSummary, BridgePairingInfo, BridgeServerConfig,
+    BridgeServerStatus,
+};
+use crate::infrastructure::bridge_server_store::{
+    BridgeServerStore, generate_auth_token, parse_bind_address,
+};
+use crate::infrastructure::mdns_advertiser::MdnsAdvertisement;
+use crate::infrastructure::tt_sync::v2_api::sync_error_to_domain;
+
+use self::pairing::BridgePairing;
+use self::routes::{BridgeState, build_router};
+
+struct BridgeServerHandle {
+    addr: SocketAddr,
+    spki_sha256: String,
+    handle: axum_server::Handle<SocketAddr>,
+    mdns: Option<MdnsAdvertisement>,
+    _task: tokio::task::JoinHandle<()>,
+}
+
+impl BridgeServerHandle {
+    fn shutdown(self) {
+        if let Some(mdns) = self.mdns {
+            mdns.shutdown();
+        }
+        self.handle.graceful_shutdown(Some(Duration::from_secs(5)));
+    }
+}
+
+pub struct BridgeServer {
+    app_state: Arc<AppState>,
+    store: Arc<BridgeServerStore>,
+    pairing: Arc<BridgePairing>,
+    server: Mutex<Option<BridgeServerHandle>>,
+}
+
+impl BridgeServer {
+    pub async fn load(
+        app_state: Arc<AppState>,
+        store: BridgeServerStore,
+    ) -> Result<Self, DomainError> {
+        let store = Arc::new(store);
+        let pairing = Arc::new(BridgePairing::load(store.clone()).await?);
+        Ok(Self {
+            app_state,
+            store,
+            pairing,
+            server: Mutex::new(None),
+        })
+    }
+
+    /// Starts the server at launch when it was left enabled.
+    pub async fn start_if_enabled(&self) -> Result<(), DomainError> {
+        let config = self.store.load_or_create_config().await?;
+        if config.enabled {
+            self.restart(&config).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn get_status(&self) -> Result<BridgeServerStatus, DomainError> {
+        let config = self.store.load_or_create_config().await?;
+        let (addr, spki_sha256, mdns_advertised) = {
+            let server = self.server.lock().await;
+            (
+                server.as_ref().map(|handle| handle.addr),
+                server.as_ref().map(|handle| handle.spki_sha256.clone()),
+                server.as_ref().is_some_and(|handle| handle.mdns.is_some()),
+            )
+        };
+        let pairing_expires_at_ms = self.pairing.active_session_expiry().await;
+
+        Ok(BridgeServerStatus {
+            enabled: config.enabled,
+            running: addr.is_some(),
+            bind_address: config.bind_address,
+            port: addr.map(|addr| addr.port()),
+            available_addresses: addr.map(reachable_addresses).unwrap_or_default(),
+            spki_sha256,
+            auth_token: config.auth_token,
+            pairing_enabled: pairing_expires_at_ms.is_some(),
+            pairing_expires_at_ms,
+            mdns_advertised,
+        })
+    }
+
+    /// Enables the server (optionally on a new bind address) and keeps it enabled across
+    /// restarts.
+    pub async fn enable(
+        &self,
+        bind_address: Option<String>,
+    ) -> Result<BridgeServerStatus, DomainError> {
+        let mut config = self.store.load_or_create_config().await?;
+        if let Some(bind_address) = bind_address {
+            config.bind_address = bind_address.trim().to_string();
+        }
+        config.enabled = true;
+
+        self.restart(&config).await?;
+        self.store.save_config(&config).await?;
+        self.get_status().await
+    }
+
+    pub async fn disable(&self) -> Result<BridgeServerStatus, DomainError> {
+        let mut config = self.store.load_or_create_config().await?;
+        config.enabled = false;
+        self.store.save_config(&config).await?;
+        self.pairing.cancel_session().await;
+        self.stop().await;
+        self.get_status().await
+    }
+
+    /// Replaces the auth token. A running server switches to the new token right away.
+    pub async fn regenerate_token(&self) -> Result<BridgeServerStatus, DomainError> {
+        let mut config = self.store.load_or_create_config().await?;
+        config.auth_token = generate_auth_token();
+        self.store.save_config(&config).await?;
+
+        let running = self.server.lock().await.is_some();
+        if running {
+            self.restart(&config).await?;
+        }
+        self.get_status().await
+    }
+
+    /// Opens a pairing window and returns the code plus a QR-encodable pairing URI for
+    /// `address` (defaults to the first reachable address).
+    pub async fn start_pairing(
+        &self,
+        address: Option<String>,
+    ) -> Result<BridgePairingInfo, DomainError> {
+        let (addr, spki_sha256) = {
+            let server = self.server.lock().await;
+            server
+                .as_ref()
+                .map(|handle| (handle.addr, handle.spki_sha256.clone()))
+        }
+        .ok_or_else(|| DomainError::InvalidData("Bridge server is not running".to_string()))?;
+
+        let address = address
+            .map(|address| address.trim().to_string())
+            .filter(|address| !address.is_empty())
+            .or_else(|| reachable_addresses(addr).into_iter().next());
+        let (pair_code, expires_at_ms) = self.pairing.start_session().await;
+
+        let (pair_uri, qr_svg) = match address.as_deref() {
+            Some(address) => {
+                let pair_uri = build_pair_uri(address, &spki_sha256, &pair_code, expires_at_ms)?;
+                let qr_svg = generate_qr_svg(&pair_uri)?;
+                (Some(pair_uri), Some(qr_svg))
+            }
+            None => (None, None),
+        };
+
+        Ok(BridgePairingInfo {
+            pair_code,
+            expires_at_ms,
+            address,
+            pair_uri,
+            qr_svg,
+        })
+    }
+
+    pub async fn cancel_pairing(&self) {
+        self.pairing.cancel_session().await;
+    }
+
+    pub async fn list_paired_devices(&self) -> Vec<BridgePairedDeviceSummary> {
+        self.pairing.list_devices().await
+    }
+
+    pub async fn remove_paired_device(&self, device_id: &str) -> Result<(), DomainError> {
+        self.pairing.remove_device(device_id).await
+    }
+
+    async fn restart(&self, config: &BridgeServerConfig) -> Result<(), DomainError> {
+        let addr = parse_bind_address(&config.bind_address)?;
+        self.stop().await;
+
+        let tls =
+            SelfManagedTls::load_or_create(&self.store.tls_dir()).map_err(sync_error_to_domain)?;
+        let tls_config =
+            RustlsConfig::from_config(Arc::new(tls.server_config().map_err(sync_error_to_domain)?));
+
+        let state = BridgeState {
+            app_state: self.app_state.clone(),
+            auth_token: Arc::from(config.auth_token.as_str()),
+            allowed_origins: config.allowed_origins.clone().into(),
+            pairing: self.pairing.clone(),
+        };
+        let spki_sha256 = tls.spki_sha256().to_string();
+        let mut handle =
+            spawn_bridge_server(addr, tls_config, spki_sha256, state).map_err(|error| {
+                DomainError::InternalError(format!(
+                    "Failed to start bridge server on {}: {}",
+                    addr, error
+                ))
+            })?;
+
+        // Discovery is a convenience; the server stays usable by address without it.
+        if !handle.addr.ip().is_loopback() {
+            match advertise_bridge_server(handle.addr.port()) {
+                Ok(mdns) => handle.mdns = Some(mdns),
+                Err(error) => tracing::warn!("Bridge server mDNS advertisement failed: {}", error),
+            }
+        }
+
+        tracing::info!("Bridge server listening on {}", handle.addr);
+        let mut server = self.server.lock().await;
+        *server = Some(handle);
+        Ok(())
+    }
+
+    async fn stop(&self) {
+        let handle = {
+            let mut server = self.server.lock().await;
+            server.take()
+        };
+        if let Some(handle) = handle {
+            handle.shutdown();
+            tracing::info!("Bridge server stopped");
+        }
+    }
+}
+
+fn spawn_bridge_server(
+    addr: SocketAddr,
+    tls_config: RustlsConfig,
+    spki_sha256: String,
+    state: BridgeState,
+) -> std::io::Result<BridgeServerHandle> {
+    let listener = std::net::TcpListener::bind(addr)?;
+    listener.set_nonblocking(true)?;
+    let addr = listener.local_addr()?;
+
+    let handle = axum_server::Handle::<SocketAddr>::new();
+    let server = axum_server::from_tcp_rustls(listener, tls_config)?.handle(handle.clone());
+    let app = build_router(state);
+
+    let task = tokio::spawn(async move {
+        if let Err(error) = server
+            .serve(app.into_make_service_with_connect_info::<SocketAddr>())
+            .await
+        {
+            tracing::error!("Bridge server failed: {}", error);
+        }
+    });
+
+    Ok(BridgeServerHandle {
+        addr,
+        spki_sha256,
+        handle,
+        mdns: None,
+        _task: task,
+    })
+}
+
+fn advertise_bridge_server(port: u16) -> Result<MdnsAdvertisement, DomainError> {
+    let instance_name = match local_ip() {
+        Ok(ip) => format!("TauriTavern ({})", ip),
+        Err(_) => "TauriTavern".to_string(),
+    };
+    MdnsAdvertisement::register(
+        BRIDGE_MDNS_SERVICE_TYPE,
+        &instance_name,
+        port,
+        &[("v", "1"), ("api", "/api"), ("pair", "/api/pair")],
+    )
+}
+
+fn build_pair_uri(
+    address: &str,
+    spki_sha256: &str,
+    pair_code: &str,
+    expires_at_ms: u64,
+) -> Result<String, DomainError> {
+    let mut uri = Url::parse("tauritavern://bridge/pair")
+        .map_err(|error| DomainError::InternalError(error.to_string()))?;
+
+    uri.query_pairs_mut()
+        .append_pair("v", "1")
+        .append_pair("url", address)
+        .append_pair("spki", spki_sha256)
+        .append_pair("code", pair_code)
+        .append_pair("exp", &expires_at_ms.to_string());
+
+    Ok(uri.to_string())
+}
+
+fn generate_qr_svg(text: &str) -> Result<String, DomainError> {
+    let code = QrCode::new(text.as_bytes())
+        .map_err(|error| DomainError::InternalError(error.to_string()))?;
+    Ok(code
+        .render::<qrcode::render::svg::Color>()
+        .min_dimensions(200, 200)
+        .build())
+}
+
+/// URLs other devices can use. A wildcard bind is expanded to the IPv4 addresses of the
+/// local network interfaces.
+fn reachable_addresses(addr: SocketAddr) -> Vec<String> {
+    let port = addr.port();
+    if !addr.ip().is_unspecified() {
+        return vec![format!("https://{}", addr)];
+    }
+
+    let mut addresses = list_afinet_netifas()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(_name, ip)| match ip {
+            IpAddr::V4(ip) if !ip.is_loopback() && !ip.is_unspecified() => {
+                Some(format!("https://{}:{}", ip, port))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    addresses.sort();
+    addresses.dedup();
+    addresses
+}
@@ -0,0 +1,387 @@
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::sync::{Arc, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::Rng;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::domain::errors::DomainError;
+use crate::domain::models::bridge_server::{
+    BRIDGE_PAIRING_CODE_TTL_MS, BRIDGE_PAIRING_MAX_FAILED_ATTEMPTS,
+    BRIDGE_PAIRING_MAX_FAILED_ATTEMPTS_PER_PEER, BridgePairRequest, BridgePairResponse,
+    BridgePairedDevice, BridgePairedDeviceSummary,
+};
+use crate::infrastructure::bridge_server_store::{
+    BridgeServerStore, generate_auth_token, normalize_origin,
+};
+use crate::infrastructure::lan_sync::crypto::sha256_base64url;
+
+const MAX_DEVICE_NAME_LEN: usize = 64;
+
+#[derive(Debug, Clone)]
+struct PairingSession {
+    pair_code: String,
+    expires_at_ms: u64,
+    failed_attempts: u32,
+    failed_attempts_by_peer: HashMap<IpAddr, u32>,
+}
+
+/// Pairing codes and the tokens of paired companion devices. Token hashes and origins are
+/// cached in memory so the auth and CORS checks on every request do not touch the disk.
+pub(super) struct BridgePairing {
+    store: Arc<BridgeServerStore>,
+    session: Mutex<Option<PairingSession>>,
+    devices: Mutex<Vec<BridgePairedDevice>>,
+    token_index: RwLock<HashMap<String, String>>,
+    origin_index: RwLock<HashSet<String>>,
+}
+
+impl BridgePairing {
+    pub async fn load(store: Arc<BridgeServerStore>) -> Result<Self, DomainError> {
+        let devices = store.load_paired_devices().await?;
+        let pairing = Self {
+            store,
+            session: Mutex::new(None),
+            devices: Mutex::new(Vec::new()),
+            token_index: RwLock::new(HashMap::new()),
+            origin_index: RwLock::new(HashSet::new()),
+        };
+        pairing.rebuild_token_index(&devices);
+        *pairing.devices.lock().await = devices;
+        Ok(pairing)
+    }
+
+    /// Opens a new pairing window, replacing any previous code.
+    pub async fn start_session(&self) -> (String, u64) {
+        let pair_code = format!("{:06}", rand::rng().random_range(0..1_000_000u32));
+        let expires_at_ms = now_ms() + BRIDGE_PAIRING_CODE_TTL_MS;
+        let mut session = self.session.lock().await;
+        *session = Some(PairingSession {
+            pair_code: pair_code.clone(),
+            expires_at_ms,
+            failed_attempts: 0,
+            failed_attempts_by_peer: HashMap::new(),
+        });
+        (pair_code, expires_at_ms)
+    }
+
+    pub async fn active_session_expiry(&self) -> Option<u64> {
+        let session = self.session.lock().await;
+        session
+            .as_ref()
+            .map(|session| session.expires_at_ms)
+            .filter(|expires_at_ms| *expires_at_ms > now_ms())
+    }
+
+    pub async fn cancel_session(&self) {
+        let mut session = self.session.lock().await;
+        *session = None;
+    }
+
+    /// Exchanges a pairing code for a device token. Codes are single use. A peer that
+    /// sends too many wrong codes is locked out of the session, and too many wrong codes
+    /// overall close it. `origin` is the browser origin the request came from, if any; it
+    /// is allowed across origins from then on.
+    pub async fn complete(
+        &self,
+        request: BridgePairRequest,
+        peer: IpAddr,
+        origin: Option<&str>,
+    ) -> Result<BridgePairResponse, DomainError> {
+        let device_name = request.device_name.trim();
+        if device_name.is_empty() || device_name.chars().count() > MAX_DEVICE_NAME_LEN {
+            return Err(DomainError::InvalidData(format!(
+                "Device name must be 1-{} characters",
+                MAX_DEVICE_NAME_LEN
+            )));
+        }
+
+        {
+            let mut session = self.session.lock().await;
+            let Some(active) = session.as_mut() else {
+                return Err(DomainError::AuthenticationError(
+                    "Pairing is not enabled".to_string(),
+                ));
+            };
+            if active.expires_at_ms <= now_ms() {
+                *session = None;
+                return Err(DomainError::AuthenticationError(
+                    "Pairing code expired".to_string(),
+                ));
+            }
+            let peer_failures = active.failed_attempts_by_peer.entry(peer).or_default();
+            if *peer_failures >= BRIDGE_PAIRING_MAX_FAILED_ATTEMPTS_PER_PEER {
+                return Err(DomainError::AuthenticationError(
+                    "Too many wrong pairing codes from this device".to_string(),
+                ));
+            }
+            if active.pair_code != request.pair_code.trim() {
+                *peer_failures += 1;
+                active.failed_attempts += 1;
+                if active.failed_attempts >= BRIDGE_PAIRING_MAX_FAILED_ATTEMPTS {
+                    *session = None;
+                }
+                return Err(DomainError::AuthenticationError(
+                    "Invalid pairing code".to_string(),
+                ));
+            }
+            *session = None;
+        }
+
+        let token = generate_auth_token();
+        let device = BridgePairedDevice {
+            device_id: Uuid::new_v4().to_string(),
+            device_name: device_name.to_string(),
+            token_sha256: sha256_base64url(token.as_bytes()),
+            paired_at_ms: now_ms(),
+            last_seen_ms: None,
+            origin: origin.and_then(normalize_origin),
+        };
+        let device_id = device.device_id.clone();
+
+        let mut devices = self.devices.lock().await;
+        devices.push(device);
+        if let Err(error) = self.store.save_paired_devices(&devices).await {
+            devices.pop();
+            return Err(error);
+        }
+        self.rebuild_token_index(&devices);
+
+        tracing::info!("Paired bridge device {} ({})", device_name, device_id);
+        Ok(BridgePairResponse { device_id, token })
+    }
+
+    /// Device id owning `token`, if any.
+    pub fn device_for_token(&self, token: &str) -> Option<String> {
+        let token_index = self
+            .token_index
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        token_index
+            .get(&sha256_base64url(token.as_bytes()))
+            .cloned()
+    }
+
+    /// Whether a paired device came from `origin`.
+    pub fn is_paired_origin(&self, origin: &str) -> bool {
+        let origin_index = self
+            .origin_index
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        origin_index.contains(origin)
+    }
+
+    pub async fn list_devices(&self) -> Vec<BridgePairedDeviceSummary> {
+        let devices = self.devices.lock().await;
+        devices
+            .iter()
+            .cloned()
+            .map(BridgePairedDeviceSummary::from)
+            .collect()
+    }
+
+    pub async fn remove_device(&self, device_id: &str) -> Result<(), DomainError> {
+        let mut devices = self.devices.lock().await;
+        let remaining = devices
+            .iter()
+            .filter(|device| device.device_id != device_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        if remaining.len() == devices.len() {
+            return Err(DomainError::NotFound(format!(
+                "Paired device not found: {}",
+                device_id
+            )));
+        }
+
+        self.store.save_paired_devices(&remaining).await?;
+        self.rebuild_token_index(&remaining);
+        *devices = remaining;
+        Ok(())
+    }
+
+    /// Records when a paired device last made a request. Kept in memory only; it is
+    /// written out with the next change to the device list.
+    pub async fn touch_device(&self, device_id: &str) {
+        let mut devices = self.devices.lock().await;
+        if let Some(device) = devices
+            .iter_mut()
+            .find(|device| device.device_id == device_id)
+        {
+            device.last_seen_ms = Some(now_ms());
+        }
+    }
+
+    fn rebuild_token_index(&self, devices: &[BridgePairedDevice]) {
+        let token_index = devices
+            .iter()
+            .map(|device| (device.token_sha256.clone(), device.device_id.clone()))
+            .collect();
+        *self
+            .token_index
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = token_index;
+
+        let origin_index = devices
+            .iter()
+            .filter_map(|device| device.origin.clone())
+            .collect();
+        *self
+            .origin_index
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = origin_index;
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::sync::Arc;
+
+    use crate::domain::errors::DomainError;
+    use crate::domain::models::bridge_server::{
+        BRIDGE_PAIRING_MAX_FAILED_ATTEMPTS, BRIDGE_PAIRING_MAX_FAILED_ATTEMPTS_PER_PEER,
+        BridgePairRequest,
+    };
+    use crate::infrastructure::bridge_server_store::BridgeServerStore;
+
+    use super::BridgePairing;
+
+    fn temp_default_user_dir() -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("tt-bridge-pairing-{}", uuid::Uuid::new_v4()))
+    }
+
+    fn peer(last_octet: u8) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::new(192, 0, 2, last_octet))
+    }
+
+    fn wrong_code_for(pair_code: &str) -> &'static str {
+        if pair_code == "000000" {
+            "000001"
+        } else {
+            "000000"
+        }
+    }
+
+    fn pair_request(pair_code: &str) -> BridgePairRequest {
+        BridgePairRequest {
+            pair_code: pair_code.to_string(),
+            device_name: "Phone".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn paired_device_tokens_are_persisted_and_revocable() {
+        let root = temp_default_user_dir();
+        let store = Arc::new(BridgeServerStore::new(root.clone()));
+        let pairing = BridgePairing::load(store.clone()).await.expect("load");
+
+        let (pair_code, _) = pairing.start_session().await;
+        let response = pairing
+            .complete(
+                pair_request(&pair_code),
+                peer(1),
+                Some("http://phone.local:5173/"),
+            )
+            .await
+            .expect("pair");
+        assert_eq!(
+            pairing.device_for_token(&response.token).as_deref(),
+            Some(response.device_id.as_str())
+        );
+        assert!(pairing.is_paired_origin("http://phone.local:5173"));
+
+        // Codes are single use.
+        assert!(matches!(
+            pairing
+                .complete(pair_request(&pair_code), peer(1), None)
+                .await,
+            Err(DomainError::AuthenticationError(_))
+        ));
+
+        let reloaded = BridgePairing::load(store).await.expect("reload");
+        assert!(reloaded.device_for_token(&response.token).is_some());
+        reloaded
+            .remove_device(&response.device_id)
+            .await
+            .expect("remove");
+        assert!(reloaded.device_for_token(&response.token).is_none());
+        assert!(!reloaded.is_paired_origin("http://phone.local:5173"));
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[tokio::test]
+    async fn peers_sending_wrong_codes_are_locked_out() {
+        let root = temp_default_user_dir();
+        let pairing = BridgePairing::load(Arc::new(BridgeServerStore::new(root.clone())))
+            .await
+            .expect("load");
+
+        let (pair_code, _) = pairing.start_session().await;
+        let wrong_code = wrong_code_for(&pair_code);
+        for _ in 0..BRIDGE_PAIRING_MAX_FAILED_ATTEMPTS_PER_PEER {
+            assert!(matches!(
+                pairing
+                    .complete(pair_request(wrong_code), peer(1), None)
+                    .await,
+                Err(DomainError::AuthenticationError(_))
+            ));
+        }
+
+        // The locked-out peer cannot pair even with the right code...
+        assert!(matches!(
+            pairing
+                .complete(pair_request(&pair_code), peer(1), None)
+                .await,
+            Err(DomainError::AuthenticationError(message)) if message.contains("Too many")
+        ));
+        // ...while the session stays open for other peers.
+        assert!(pairing.active_session_expiry().await.is_some());
+        pairing
+            .complete(pair_request(&pair_code), peer(2), None)
+            .await
+            .expect("pair from another peer");
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[tokio::test]
+    async fn repeated_wrong_codes_close_the_session() {
+        let root = temp_default_user_dir();
+        let pairing = BridgePairing::load(Arc::new(BridgeServerStore::new(root.clone())))
+            .await
+            .expect("load");
+
+        let (pair_code, _) = pairing.start_session().await;
+        let wrong_code = wrong_code_for(&pair_code);
+        for attempt in 0..BRIDGE_PAIRING_MAX_FAILED_ATTEMPTS {
+            let attempt = u8::try_from(attempt).expect("small attempt count");
+            assert!(
+                pairing
+                    .complete(pair_request(wrong_code), peer(10 + attempt), None)
+                    .await
+                    .is_err()
+            );
+        }
+
+        assert!(pairing.active_session_expiry().await.is_none());
+        assert!(
+            pairing
+                .complete(pair_request(&pair_code), peer(1), None)
+                .await
+                .is_err()
+        );
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
@@ -0,0 +1,547 @@
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{ConnectInfo, DefaultBodyLimit, Request, State},
+    http::{HeaderMap, HeaderValue, Method, StatusCode, header},
+    middleware::{self, Next},
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+    routing::{get, post},
+};
+use futures_util::stream::{self, Stream};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tokio::sync::{mpsc, oneshot};
+use uuid::Uuid;
+
+use crate::app::AppState;
+use crate::application::dto::chat_completion_dto::ChatCompletionGenerateRequestDto;
+use crate::application::dto::chat_dto::SaveChatFromFileDto;
+use crate::application::errors::ApplicationError;
+use crate::application::services::chat_completion_service::ChatCompletionService;
+use crate::domain::errors::DomainError;
+use crate::domain::models::bridge_server::BridgePairRequest;
+use crate::infrastructure::persistence::file_system::atomic_write;
+use crate::presentation::errors::CommandError;
+
+use super::pairing::BridgePairing;
+
+const CHAT_SAVE_BODY_LIMIT_BYTES: usize = 64 * 1024 * 1024;
+const AUTH_TOKEN_HEADER: &str = "X-TT-Bridge-Token";
+/// Routes a device can reach before it is paired.
+const PUBLIC_PATHS: [&str; 2] = ["/api/ping", "/api/pair"];
+
+#[derive(Clone)]
+pub(super) struct BridgeState {
+    pub app_state: Arc<AppState>,
+    pub auth_token: Arc<str>,
+    pub allowed_origins: Arc<[String]>,
+    pub pairing: Arc<BridgePairing>,
+}
+
+/// SillyTavern-compatible subset of the server API. Routes keep upstream paths and
+/// request bodies so existing frontends can point at the bridge unchanged.
+pub(super) fn build_router(state: BridgeState) -> Router {
+    let api = Router::new()
+        .route("/api/characters/all", post(handle_characters_all))
+        .route("/api/characters/get", post(handle_characters_get))
+        .route("/api/characters/chats", post(handle_characters_chats))
+        .route("/api/chats/get", post(handle_chats_get))
+        .route(
+            "/api/chats/save",
+            post(handle_chats_save).layer(DefaultBodyLimit::max(CHAT_SAVE_BODY_LIMIT_BYTES)),
+        )
+        .route(
+            "/api/backends/chat-completions/generate",
+            post(handle_chat_completions_generate),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_auth_token,
+        ));
+
+    Router::new()
+        .route("/api/ping", get(handle_ping))
+        .route("/api/pair", post(handle_pair))
+        .merge(api)
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            allow_cross_origin,
+        ))
+        .with_state(state)
+}
+
+async fn handle_ping() -> impl IntoResponse {
+    Json(json!({ "ok": true, "agent": "TauriTavern" }))
+}
+
+/// Browsers on other devices load their frontend from a different origin. The public
+/// routes answer any origin so a new device can pair; the authenticated routes only answer
+/// origins listed in the config or ones a device paired from.
+async fn allow_cross_origin(
+    State(state): State<BridgeState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let allowed_origin = request
+        .headers()
+        .get(header::ORIGIN)
+        .filter(|origin| {
+            origin_is_allowed(
+                request.uri().path(),
+                origin,
+                &state.allowed_origins,
+                &state.pairing,
+            )
+        })
+        .cloned();
+    let mut response = if request.method() == Method::OPTIONS {
+        StatusCode::NO_CONTENT.into_response()
+    } else {
+        next.run(request).await
+    };
+
+    let headers = response.headers_mut();
+    headers.insert(header::VARY, HeaderValue::from_static("Origin"));
+    let Some(origin) = allowed_origin else {
+        return response;
+    };
+    headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, origin);
+    headers.insert(
+        header::ACCESS_CONTROL_ALLOW_METHODS,
+        HeaderValue::from_static("GET, POST, OPTIONS"),
+    );
+    headers.insert(
+        header::ACCESS_CONTROL_ALLOW_HEADERS,
+        HeaderValue::from_static("Authorization, Content-Type, X-TT-Bridge-Token"),
+    );
+    response
+}
+
+fn origin_is_allowed(
+    path: &str,
+    origin: &HeaderValue,
+    allowed_origins: &[String],
+    pairing: &BridgePairing,
+) -> bool {
+    let Ok(origin) = origin.to_str() else {
+        return false;
+    };
+    PUBLIC_PATHS.contains(&path)
+        || allowed_origins.iter().any(|allowed| allowed == origin)
+        || pairing.is_paired_origin(origin)
+}
+
+/// Accepts the owner's token from the config or the token of a paired device.
+async fn require_auth_token(
+    State(state): State<BridgeState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(provided) = request_auth_token(request.headers()) else {
+        return unauthorized();
+    };
+
+    if !tokens_match(&provided, &state.auth_token) {
+        let Some(device_id) = state.pairing.device_for_token(&provided) else {
+            return unauthorized();
+        };
+        state.pairing.touch_device(&device_id).await;
+    }
+
+    next.run(request).await
+}
+
+fn unauthorized() -> Response {
+    BridgeError(CommandError::Unauthorized(
+        "Missing or invalid bridge token".to_string(),
+    ))
+    .into_response()
+}
+
+async fn handle_pair(
+    State(state): State<BridgeState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Json(request): Json<BridgePairRequest>,
+) -> Result<impl IntoResponse, BridgeError> {
+    let origin = headers
+        .get(header::ORIGIN)
+        .and_then(|value| value.to_str().ok());
+    Ok(Json(
+        state.pairing.complete(request, peer.ip(), origin).await?,
+    ))
+}
+
+/// Token from `Authorization: Bearer` or the bridge header. Tokens are never read from
+/// the query string, where they would end up in logs and browser history.
+fn request_auth_token(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .or_else(|| {
+            headers
+                .get(AUTH_TOKEN_HEADER)
+                .and_then(|value| value.to_str().ok())
+        })
+        .map(|value| value.trim().to_string())
+}
+
+/// Compares without short-circuiting on the first differing byte.
+fn tokens_match(provided: &str, expected: &str) -> bool {
+    let provided = provided.as_bytes();
+    let expected = expected.as_bytes();
+    if provided.len() != expected.len() {
+        return false;
+    }
+
+    provided
+        .iter()
+        .zip(expected)
+        .fold(0u8, |diff, (left, right)| diff | (left ^ right))
+        == 0
+}
+
+struct BridgeError(CommandError);
+
+impl From<ApplicationError> for BridgeError {
+    fn from(error: ApplicationError) -> Self {
+        Self(CommandError::from(error))
+    }
+}
+
+impl From<DomainError> for BridgeError {
+    fn from(error: DomainError) -> Self {
+        Self(CommandError::from(error))
+    }
+}
+
+impl IntoResponse for BridgeError {
+    fn into_response(self) -> Response {
+        let status = match &self.0 {
+            CommandError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            CommandError::NotFound(_) => StatusCode::NOT_FOUND,
+            CommandError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            CommandError::Cancelled(_) => StatusCode::CONFLICT,
+            CommandError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            CommandError::UpstreamFailure(_) => StatusCode::BAD_GATEWAY,
+            CommandError::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        (status, Json(json!({ "error": self.0.to_string() }))).into_response()
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct CharacterRequest {
+    #[serde(default)]
+    avatar_url: Option<String>,
+    #[serde(default)]
+    ch_name: Option<String>,
+}
+
+impl CharacterRequest {
+    /// Chats and cards are keyed by the avatar file stem; `ch_name` is only a fallback.
+    fn character_name(&self) -> Result<String, BridgeError> {
+        self.avatar_url
+            .as_deref()
+            .map(|avatar| avatar.strip_suffix(".png").unwrap_or(avatar))
+            .or(self.ch_name.as_deref())
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .ok_or_else(|| {
+                BridgeError(CommandError::BadRequest(
+                    "avatar_url or ch_name is required".to_string(),
+                ))
+            })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatRequest {
+    #[serde(flatten)]
+    character: CharacterRequest,
+    file_name: String,
+    #[serde(default)]
+    chat: Vec<Value>,
+    #[serde(default)]
+    force: Option<bool>,
+}
+
+async fn handle_characters_all(
+    State(state): State<BridgeState>,
+) -> Result<impl IntoResponse, BridgeError> {
+    let characters = state
+        .app_state
+        .character_service
+        .get_all_characters(true)
+        .await?;
+    Ok(Json(characters))
+}
+
+async fn handle_characters_get(
+    State(state): State<BridgeState>,
+    Json(request): Json<CharacterRequest>,
+) -> Result<impl IntoResponse, BridgeError> {
+    let character = state
+        .app_state
+        .character_service
+        .get_character(&request.character_name()?)
+        .await?;
+    Ok(Json(character))
+}
+
+async fn handle_characters_chats(
+    State(state): State<BridgeState>,
+    Json(request): Json<CharacterRequest>,
+) -> Result<impl IntoResponse, BridgeError> {
+    let summaries = state
+        .app_state
+        .chat_service
+        .list_chat_summaries(Some(&request.character_name()?), false)
+        .await?;
+    Ok(Json(summaries))
+}
+
+async fn handle_chats_get(
+    State(state): State<BridgeState>,
+    Json(request): Json<ChatRequest>,
+) -> Result<impl IntoResponse, BridgeError> {
+    let payload = state
+        .app_state
+        .chat_service
+        .get_chat_payload(&request.character.character_name()?, &request.file_name)
+        .await?;
+    Ok(Json(payload))
+}
+
+async fn handle_chats_save(
+    State(state): State<BridgeState>,
+    Json(request): Json<ChatRequest>,
+) -> Result<impl IntoResponse, BridgeError> {
+    if request.chat.is_empty() {
+        return Err(BridgeError(CommandError::BadRequest(
+            "Chat payload is empty".to_string(),
+        )));
+    }
+
+    let character_name = request.character.character_name()?;
+    let mut jsonl = String::new();
+    for entry in &request.chat {
+        jsonl.push_str(&entry.to_string());
+        jsonl.push('\n');
+    }
+
+    let temp_path =
+        std::env::temp_dir().join(format!("tauritavern-bridge-chat-{}.jsonl", Uuid::new_v4()));
+    atomic_write(&temp_path, jsonl.as_bytes())
+        .await
+        .map_err(|error| BridgeError(error.into()))?;
+
+    let result = state
+        .app_state
+        .chat_service
+        .save_chat_from_file(SaveChatFromFileDto {
+            character_name,
+            file_name: request.file_name,
+            file_path: temp_path.to_string_lossy().to_string(),
+            force: request.force,
+        })
+        .await;
+    let _ = tokio::fs::remove_file(&temp_path).await;
+    result?;
+
+    Ok(Json(json!({ "result": "ok" })))
+}
+
+async fn handle_chat_completions_generate(
+    State(state): State<BridgeState>,
+    Json(dto): Json<ChatCompletionGenerateRequestDto>,
+) -> Result<Response, BridgeError> {
+    let service = state.app_state.chat_completion_service.clone();
+    let request_id = format!("bridge-{}", Uuid::new_v4());
+
+    if dto.payload.get("stream").and_then(Value::as_bool) == Some(true) {
+        return Ok(stream_chat_completion(service, request_id, dto)
+            .await
+            .into_response());
+    }
+
+    let cancel = service.register_generation(&request_id).await;
+    let result = service.generate_with_cancel(dto, cancel).await;
+    service.complete_generation(&request_id).await;
+    Ok(Json(result?).into_response())
+}
+
+/// Cancels the upstream request when the client goes away before the stream ends.
+struct StreamCancelGuard {
+    service: Arc<ChatCompletionService>,
+    stream_id: String,
+}
+
+impl Drop for StreamCancelGuard {
+    fn drop(&mut self) {
+        let service = self.service.clone();
+        let stream_id = std::mem::take(&mut self.stream_id);
+        tokio::spawn(async move {
+            service.cancel_stream(&stream_id).await;
+        });
+    }
+}
+
+struct StreamState {
+    chunks: mpsc::UnboundedReceiver<String>,
+    result: Option<oneshot::Receiver<Result<(), ApplicationError>>>,
+    _guard: StreamCancelGuard,
+}
+
+/// Forwards upstream SSE data payloads as-is, like SillyTavern's streaming proxy. A
+/// failure after the stream started is sent as a final `{"error": ...}` event.
+async fn stream_chat_completion(
+    service: Arc<ChatCompletionService>,
+    stream_id: String,
+    dto: ChatCompletionGenerateRequestDto,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let cancel = service.register_stream(&stream_id).await;
+    let (sender, chunks) = mpsc::unbounded_channel::<String>();
+    let (result_tx, result_rx) = oneshot::channel();
+
+    tokio::spawn({
+        let service = service.clone();
+        let stream_id = stream_id.clone();
+        async move {
+            let result = service.generate_stream(dto, sender, cancel).await;
+            service.complete_stream(&stream_id).await;
+            let _ = result_tx.send(result);
+        }
+    });
+
+    let state = StreamState {
+        chunks,
+        result: Some(result_rx),
+        _guard: StreamCancelGuard { service, stream_id },
+    };
+
+    let events = stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(chunk) = state.chunks.recv().await {
+                if chunk.is_empty() {
+                    continue;
+                }
+                return Some((Ok(Event::default().data(chunk)), state));
+            }
+
+            let result = state.result.take()?.await;
+            let Ok(Err(error)) = result else {
+                return None;
+            };
+            let message = CommandError::from(error).to_string();
+            let event =
+                Event::default().data(json!({ "error": { "message": message } }).to_string());
+            return Some((Ok(event), state));
+        }
+    });
+
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::sync::Arc;
+
+    use axum::http::{HeaderMap, HeaderValue, header};
+
+    use crate::domain::models::bridge_server::BridgePairRequest;
+    use crate::infrastructure::bridge_server_store::BridgeServerStore;
+
+    use super::{
+        AUTH_TOKEN_HEADER, BridgePairing, CharacterRequest, origin_is_allowed, request_auth_token,
+        tokens_match,
+    };
+
+    #[test]
+    fn auth_token_is_read_from_bearer_or_bridge_header_only() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_static("Bearer secret"),
+        );
+        assert_eq!(request_auth_token(&headers).as_deref(), Some("secret"));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTH_TOKEN_HEADER, HeaderValue::from_static("custom"));
+        assert_eq!(request_auth_token(&headers).as_deref(), Some("custom"));
+
+        assert_eq!(request_auth_token(&HeaderMap::new()), None);
+    }
+
+    #[tokio::test]
+    async fn authenticated_routes_only_allow_configured_or_paired_origins() {
+        let root = std::env::temp_dir().join(format!("tt-bridge-routes-{}", uuid::Uuid::new_v4()));
+        let pairing = BridgePairing::load(Arc::new(BridgeServerStore::new(root.clone())))
+            .await
+            .expect("load");
+        let allowed_origins = vec!["https://tablet.local:8443".to_string()];
+        let allowed = |path: &str, origin: &'static str| {
+            origin_is_allowed(
+                path,
+                &HeaderValue::from_static(origin),
+                &allowed_origins,
+                &pairing,
+            )
+        };
+
+        assert!(allowed("/api/pair", "https://evil.example"));
+        assert!(allowed("/api/chats/get", "https://tablet.local:8443"));
+        assert!(!allowed("/api/chats/get", "https://evil.example"));
+        assert!(!allowed("/api/chats/get", "http://phone.local:5173"));
+
+        let (pair_code, _) = pairing.start_session().await;
+        pairing
+            .complete(
+                BridgePairRequest {
+                    pair_code,
+                    device_name: "Phone".to_string(),
+                },
+                IpAddr::V4(Ipv4Addr::LOCALHOST),
+                Some("http://phone.local:5173"),
+            )
+            .await
+            .expect("pair");
+        assert!(allowed("/api/chats/get", "http://phone.local:5173"));
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn tokens_must_match_exactly() {
+        assert!(tokens_match("abc123", "abc123"));
+        assert!(!tokens_match("abc124", "abc123"));
+        assert!(!tokens_match("abc12", "abc123"));
+    }
+
+    #[test]
+    fn character_name_prefers_avatar_stem() {
+        let request = CharacterRequest {
+            avatar_url: Some("Alice.png".to_string()),
+            ch_name: Some("Alice (display)".to_string()),
+        };
+        assert_eq!(request.character_name().ok().as_deref(), Some("Alice"));
+
+        let request = CharacterRequest {
+            avatar_url: None,
+            ch_name: Some("Bob".to_string()),
+        };
+        assert_eq!(request.character_name().ok().as_deref(), Some("Bob"));
+
+        assert!(CharacterRequest::default().character_name().is_err());
+    }
+}
@@ -0,0 +1,116 @@
+//! Secondary desktop windows bound to one character chat, so two conversations can run
+//! side by side.
+//!
+//! A chat window loads the regular frontend and asks for its binding on startup. Chat
+//! completion streams already reply through the invoking webview's channel; events the
+//! host broadcasts for a chat carry the label of the window that owns it instead.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder, WindowEvent};
+
+const CHAT_WINDOW_LABEL_PREFIX: &str = "chat-";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatWindowBinding {
+    /// Character avatar file name.
+    pub character: String,
+    /// Chat file name, without the `.jsonl` extension.
+    pub file: String,
+}
+
+/// Open chat windows by label.
+#[derive(Default)]
+pub struct ChatWindowRegistry {
+    windows: Mutex<HashMap<String, ChatWindowBinding>>,
+}
+
+impl ChatWindowRegistry {
+    pub fn binding(&self, label: &str) -> Option<ChatWindowBinding> {
+        self.lock().get(label).cloned()
+    }
+
+    fn label_for(&self, binding: &ChatWindowBinding) -> Option<String> {
+        self.lock()
+            .iter()
+            .find(|(_, bound)| *bound == binding)
+            .map(|(label, _)| label.clone())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, ChatWindowBinding>> {
+        self.windows
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+/// Opens a window for the chat, or focuses the one already showing it, and returns the
+/// window label.
+pub fn open_chat_window(
+    app_handle: &AppHandle,
+    binding: ChatWindowBinding,
+) -> tauri::Result<String> {
+    let registry = app_handle
+        .state::<Arc<ChatWindowRegistry>>()
+        .inner()
+        .clone();
+    if let Some(label) = registry.label_for(&binding) {
+        if let Some(window) = app_handle.get_webview_window(&label) {
+            window.unminimize()?;
+            window.show()?;
+            window.set_focus()?;
+            return Ok(label);
+        }
+    }
+
+    let label = format!(
+        "{}{}",
+        CHAT_WINDOW_LABEL_PREFIX,
+        uuid::Uuid::new_v4().simple()
+    );
+    let character_name = Path::new(&binding.character)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or(&binding.character);
+    let builder =
+        WebviewWindowBuilder::new(app_handle, &label, WebviewUrl::App("index.html".into()))
+            .title(format!("{} - TauriTavern", character_name))
+            .inner_size(960.0, 800.0)
+            .min_inner_size(600.0, 480.0);
+    let window = crate::apply_webview_policy(builder, app_handle).build()?;
+
+    registry.lock().insert(label.clone(), binding);
+    let closed_label = label.clone();
+    window.on_window_event(move |event| {
+        if matches!(event, WindowEvent::Destroyed) {
+            registry.lock().remove(&closed_label);
+        }
+    });
+
+    Ok(label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChatWindowBinding, ChatWindowRegistry};
+
+    #[test]
+    fn finds_window_by_binding_and_label() {
+        let registry = ChatWindowRegistry::default();
+        let binding = ChatWindowBinding {
+            character: "Alice.png".to_string(),
+            file: "Alice - 2026-10-16".to_string(),
+        };
+        registry
+            .lock()
+            .insert("chat-1".to_string(), binding.clone());
+
+        assert_eq!(registry.label_for(&binding).as_deref(), Some("chat-1"));
+        assert_eq!(registry.binding("chat-1"), Some(binding));
+        assert!(registry.binding("main").is_none());
+    }
+}
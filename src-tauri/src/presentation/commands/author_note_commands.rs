@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::author_note_dto::{
+    AuthorNoteDto, AuthorNoteRequestDto, ResolveAuthorNoteDto, ResolvedAuthorNoteDto,
+    SetAuthorNoteDto,
+};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn get_author_note(
+    dto: AuthorNoteRequestDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Option<AuthorNoteDto>, CommandError> {
+    log_command("get_author_note");
+
+    app_state
+        .author_note_service
+        .get_author_note(&dto.scope)
+        .await
+        .map_err(map_command_error("Failed to get author's note"))
+}
+
+#[tauri::command]
+pub async fn set_author_note(
+    dto: SetAuthorNoteDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<AuthorNoteDto, CommandError> {
+    log_command("set_author_note");
+
+    app_state
+        .author_note_service
+        .set_author_note(&dto.scope, dto.note)
+        .await
+        .map_err(map_command_error("Failed to set author's note"))
+}
+
+#[tauri::command]
+pub async fn clear_author_note(
+    dto: AuthorNoteRequestDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<bool, CommandError> {
+    log_command("clear_author_note");
+
+    app_state
+        .author_note_service
+        .clear_author_note(&dto.scope)
+        .await
+        .map_err(map_command_error("Failed to clear author's note"))
+}
+
+#[tauri::command]
+pub async fn resolve_author_note(
+    dto: ResolveAuthorNoteDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ResolvedAuthorNoteDto, CommandError> {
+    log_command("resolve_author_note");
+
+    app_state
+        .author_note_service
+        .resolve_author_note(&dto)
+        .await
+        .map_err(map_command_error("Failed to resolve author's note"))
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::domain::models::backup_schedule::{
+    BackupScheduleConfig, BackupScheduleResult, BackupScheduleStatus,
+};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn backup_schedule_get_config(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<BackupScheduleConfig, CommandError> {
+    log_command("backup_schedule_get_config");
+
+    app_state
+        .backup_schedule_service
+        .get_config()
+        .await
+        .map_err(map_command_error("Failed to get backup schedule config"))
+}
+
+#[tauri::command]
+pub async fn backup_schedule_update_config(
+    app_state: State<'_, Arc<AppState>>,
+    config: BackupScheduleConfig,
+) -> Result<BackupScheduleConfig, CommandError> {
+    log_command("backup_schedule_update_config");
+
+    app_state
+        .backup_schedule_service
+        .update_config(config)
+        .await
+        .map_err(map_command_error("Failed to update backup schedule config"))
+}
+
+#[tauri::command]
+pub async fn backup_schedule_get_status(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<BackupScheduleStatus, CommandError> {
+    log_command("backup_schedule_get_status");
+
+    Ok(app_state.backup_schedule_service.get_status().await)
+}
+
+#[tauri::command]
+pub async fn backup_schedule_run_now(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<BackupScheduleResult, CommandError> {
+    log_command("backup_schedule_run_now");
+
+    app_state
+        .backup_schedule_service
+        .run_now()
+        .await
+        .map_err(map_command_error("Failed to run backup"))
+}
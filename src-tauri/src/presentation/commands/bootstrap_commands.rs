@@ -3,6 +3,7 @@ use std::sync::Arc;
 use tauri::State;
 
 use crate::app::AppState;
+use crate::app::startup_report::{StartupReport, StartupTimings};
 use crate::application::dto::bootstrap_dto::BootstrapSnapshotDto;
 use crate::application::dto::group_dto::GroupDto;
 use crate::presentation::commands::helpers::{log_command, map_command_error};
@@ -82,3 +83,14 @@ pub async fn get_bootstrap_snapshot(
         secret_state,
     })
 }
+
+/// Per-stage timings of the current launch. Available before `app-ready`, so it can also
+/// explain a launch that failed or is still running.
+#[tauri::command]
+pub fn get_startup_report(
+    timings: State<'_, Arc<StartupTimings>>,
+) -> Result<StartupReport, CommandError> {
+    log_command("get_startup_report");
+
+    Ok(timings.report())
+}
@@ -3,13 +3,13 @@ use serde_json::Value;
 use tauri::{Emitter, Window};
 use tauri_plugin_notification::{NotificationExt, PermissionState};
 
+use crate::domain::models::extension::SILLYTAVERN_COMPAT_VERSION;
 use crate::infrastructure::assets::read_resource_text;
 use crate::presentation::commands::helpers::{log_command, map_command_error};
 use crate::presentation::errors::CommandError;
 #[cfg(any(dev, debug_assertions))]
 use crate::presentation::web_resources::dev_resource_dispatch::dispatch_dev_web_resource_request;
 
-const SILLYTAVERN_COMPAT_VERSION: &str = "1.18.0";
 const BUILD_GIT_REVISION: &str = env!("TAURITAVERN_GIT_REVISION");
 const BUILD_GIT_BRANCH: &str = env!("TAURITAVERN_GIT_BRANCH");
 
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::domain::models::bridge_server::{
+    BridgePairedDeviceSummary, BridgePairingInfo, BridgeServerStatus,
+};
+use crate::presentation::bridge_server::BridgeServer;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn bridge_server_get_status(
+    bridge_server: State<'_, Arc<BridgeServer>>,
+) -> Result<BridgeServerStatus, CommandError> {
+    log_command("bridge_server_get_status");
+
+    bridge_server
+        .get_status()
+        .await
+        .map_err(map_command_error("Failed to get bridge server status"))
+}
+
+#[tauri::command]
+pub async fn bridge_server_enable(
+    bind_address: Option<String>,
+    bridge_server: State<'_, Arc<BridgeServer>>,
+) -> Result<BridgeServerStatus, CommandError> {
+    log_command(format!("bridge_server_enable {:?}", bind_address));
+
+    bridge_server
+        .enable(bind_address)
+        .await
+        .map_err(map_command_error("Failed to enable bridge server"))
+}
+
+#[tauri::command]
+pub async fn bridge_server_disable(
+    bridge_server: State<'_, Arc<BridgeServer>>,
+) -> Result<BridgeServerStatus, CommandError> {
+    log_command("bridge_server_disable");
+
+    bridge_server
+        .disable()
+        .await
+        .map_err(map_command_error("Failed to disable bridge server"))
+}
+
+#[tauri::command]
+pub async fn bridge_server_regenerate_token(
+    bridge_server: State<'_, Arc<BridgeServer>>,
+) -> Result<BridgeServerStatus, CommandError> {
+    log_command("bridge_server_regenerate_token");
+
+    bridge_server
+        .regenerate_token()
+        .await
+        .map_err(map_command_error(
+            "Failed to regenerate bridge server token",
+        ))
+}
+
+#[tauri::command]
+pub async fn bridge_server_start_pairing(
+    address: Option<String>,
+    bridge_server: State<'_, Arc<BridgeServer>>,
+) -> Result<BridgePairingInfo, CommandError> {
+    log_command("bridge_server_start_pairing");
+
+    bridge_server
+        .start_pairing(address)
+        .await
+        .map_err(map_command_error("Failed to start bridge pairing"))
+}
+
+#[tauri::command]
+pub async fn bridge_server_cancel_pairing(
+    bridge_server: State<'_, Arc<BridgeServer>>,
+) -> Result<(), CommandError> {
+    log_command("bridge_server_cancel_pairing");
+
+    bridge_server.cancel_pairing().await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn bridge_server_list_devices(
+    bridge_server: State<'_, Arc<BridgeServer>>,
+) -> Result<Vec<BridgePairedDeviceSummary>, CommandError> {
+    log_command("bridge_server_list_devices");
+
+    Ok(bridge_server.list_paired_devices().await)
+}
+
+#[tauri::command]
+pub async fn bridge_server_remove_device(
+    device_id: String,
+    bridge_server: State<'_, Arc<BridgeServer>>,
+) -> Result<(), CommandError> {
+    log_command(format!("bridge_server_remove_device {}", device_id));
+
+    bridge_server
+        .remove_paired_device(&device_id)
+        .await
+        .map_err(map_command_error("Failed to remove paired bridge device"))
+}
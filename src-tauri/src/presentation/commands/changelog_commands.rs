@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::domain::models::changelog::ChangelogSince;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+/// Release notes and data migrations since `version`, the version the user ran before
+/// the update.
+#[tauri::command]
+pub async fn get_changelog_since(
+    version: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ChangelogSince, CommandError> {
+    log_command(format!("get_changelog_since {}", version));
+
+    app_state
+        .changelog_service
+        .get_changelog_since(&version)
+        .await
+        .map_err(map_command_error("Failed to get changelog"))
+}
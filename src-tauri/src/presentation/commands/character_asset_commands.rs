@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::character_asset_dto::UploadCharacterAssetDto;
+use crate::domain::models::character_asset::CharacterAsset;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn list_character_assets(
+    app_state: State<'_, Arc<AppState>>,
+    character: String,
+) -> Result<Vec<CharacterAsset>, CommandError> {
+    log_command(format!("list_character_assets, character: {}", character));
+
+    app_state
+        .character_asset_service
+        .list_assets(&character)
+        .await
+        .map_err(map_command_error("Failed to list character assets"))
+}
+
+#[tauri::command]
+pub async fn upload_character_asset(
+    app_state: State<'_, Arc<AppState>>,
+    dto: UploadCharacterAssetDto,
+) -> Result<CharacterAsset, CommandError> {
+    log_command(format!(
+        "upload_character_asset, character: {}, name: {}",
+        dto.character, dto.name
+    ));
+
+    app_state
+        .character_asset_service
+        .upload_asset(dto)
+        .await
+        .map_err(map_command_error("Failed to upload character asset"))
+}
+
+#[tauri::command]
+pub async fn delete_character_asset(
+    app_state: State<'_, Arc<AppState>>,
+    character: String,
+    name: String,
+) -> Result<(), CommandError> {
+    log_command(format!(
+        "delete_character_asset, character: {}, name: {}",
+        character, name
+    ));
+
+    app_state
+        .character_asset_service
+        .delete_asset(&character, &name)
+        .await
+        .map_err(map_command_error("Failed to delete character asset"))
+}
@@ -5,11 +5,13 @@ use tauri::State;
 use crate::app::AppState;
 use crate::application::dto::character_dto::{
     BulkMergeCharacterCardDataDto, BulkMergeCharacterCardDataResultDto, CharacterChatDto,
-    CharacterDto, CharacterLorebookConflictDto, CheckCharacterLorebookConflictDto,
-    CreateCharacterDto, CreateCharacterWithAvatarResultDto, CreateWithAvatarDto,
-    DeleteCharacterDto, DuplicateCharacterDto, ExportCharacterContentDto,
-    ExportCharacterContentResultDto, ExportCharacterDto, GetCharacterChatsDto, ImportCharacterDto,
-    MergeCharacterCardDataDto, RenameCharacterDto, ResolveCharacterLorebookConflictDto,
+    CharacterDto, CharacterLorebookConflictDto, CharacterPageDto,
+    CheckCharacterLorebookConflictDto, CreateCharacterDto, CreateCharacterWithAvatarResultDto,
+    CreateWithAvatarDto, DeleteCharacterDto, DuplicateCharacterDto, DuplicateCharacterGroupDto,
+    ExportCharacterContentDto, ExportCharacterContentResultDto, ExportCharacterDto,
+    FindDuplicateCharactersDto, GetCharacterChatsDto, GetCharacterFieldsDto, ImportCharacterDto,
+    ListCharactersDto, MergeCharacterCardDataDto, MergeCharactersDto, MergeCharactersResultDto,
+    RenameCharacterDto, ResolveCharacterLorebookConflictDto,
     ResolveCharacterLorebookConflictResultDto, UpdateAvatarDto, UpdateCharacterCardDataDto,
     UpdateCharacterDto,
 };
@@ -33,6 +35,38 @@ pub async fn get_all_characters(
         .map_err(map_command_error("Failed to get all characters"))
 }
 
+#[tauri::command]
+pub async fn list_characters(
+    dto: ListCharactersDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<CharacterPageDto, CommandError> {
+    log_command(format!(
+        "list_characters (offset: {}, limit: {:?})",
+        dto.offset, dto.limit
+    ));
+
+    app_state
+        .character_service
+        .list_characters(dto)
+        .await
+        .map_err(map_command_error("Failed to list characters"))
+}
+
+#[tauri::command]
+pub async fn get_character_fields(
+    dto: GetCharacterFieldsDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<serde_json::Map<String, serde_json::Value>, CommandError> {
+    log_command(format!("get_character_fields {}", dto.name));
+
+    let error_context = format!("Failed to get fields of character {}", dto.name);
+    app_state
+        .character_service
+        .get_character_fields(dto)
+        .await
+        .map_err(map_command_error(error_context))
+}
+
 #[tauri::command]
 pub async fn get_character(
     name: String,
@@ -254,6 +288,53 @@ pub async fn duplicate_character(
         .map_err(map_command_error("Failed to duplicate character"))
 }
 
+#[tauri::command]
+pub async fn find_duplicate_characters(
+    dto: FindDuplicateCharactersDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<DuplicateCharacterGroupDto>, CommandError> {
+    log_command(format!("find_duplicate_characters (fuzzy: {})", dto.fuzzy));
+
+    app_state
+        .character_service
+        .find_duplicate_characters(dto)
+        .await
+        .map_err(map_command_error("Failed to find duplicate characters"))
+}
+
+#[tauri::command]
+pub async fn merge_characters(
+    dto: MergeCharactersDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<MergeCharactersResultDto, CommandError> {
+    log_command(format!(
+        "merge_characters {} <- {}",
+        dto.keep,
+        dto.merge.join(", ")
+    ));
+
+    let result = app_state
+        .character_service
+        .merge_characters(dto)
+        .await
+        .map_err(map_command_error("Failed to merge characters"))?;
+
+    for name in &result.deleted {
+        app_state
+            .skill_service
+            .delete_skills_for_source(
+                SKILL_SOURCE_KIND_CHARACTER,
+                &character_skill_source_id(name),
+            )
+            .await
+            .map_err(map_command_error(
+                "Failed to delete Agent Skills linked to character",
+            ))?;
+    }
+
+    Ok(result)
+}
+
 #[tauri::command]
 pub async fn import_character(
     dto: ImportCharacterDto,
@@ -347,7 +428,7 @@ fn character_skill_source_id(name: &str) -> String {
     format!("character:{}", name.trim())
 }
 
-fn character_id_from_avatar(avatar: &str) -> Result<String, CommandError> {
+pub(crate) fn character_id_from_avatar(avatar: &str) -> Result<String, CommandError> {
     let file_name = avatar
         .trim()
         .rsplit(['/', '\\'])
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::character_generation_overrides_dto::{
+    CharacterGenerationOverridesDto, CharacterGenerationOverridesRequestDto,
+    SetCharacterGenerationOverridesDto,
+};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn get_character_generation_overrides(
+    dto: CharacterGenerationOverridesRequestDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Option<CharacterGenerationOverridesDto>, CommandError> {
+    log_command(format!("get_character_generation_overrides {}", dto.avatar));
+
+    app_state
+        .character_generation_overrides_service
+        .get_overrides(&dto.avatar)
+        .await
+        .map_err(map_command_error(
+            "Failed to get character generation overrides",
+        ))
+}
+
+#[tauri::command]
+pub async fn set_character_generation_overrides(
+    dto: SetCharacterGenerationOverridesDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<CharacterGenerationOverridesDto, CommandError> {
+    log_command(format!("set_character_generation_overrides {}", dto.avatar));
+
+    app_state
+        .character_generation_overrides_service
+        .set_overrides(&dto.avatar, dto.overrides)
+        .await
+        .map_err(map_command_error(
+            "Failed to set character generation overrides",
+        ))
+}
+
+#[tauri::command]
+pub async fn clear_character_generation_overrides(
+    dto: CharacterGenerationOverridesRequestDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<bool, CommandError> {
+    log_command(format!(
+        "clear_character_generation_overrides {}",
+        dto.avatar
+    ));
+
+    app_state
+        .character_generation_overrides_service
+        .clear_overrides(&dto.avatar)
+        .await
+        .map_err(map_command_error(
+            "Failed to clear character generation overrides",
+        ))
+}
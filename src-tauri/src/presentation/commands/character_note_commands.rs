@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::character_note_dto::{
+    CharacterNoteDto, CharacterNoteRequestDto, CharacterNoteSearchResultDto, SaveCharacterNoteDto,
+    SearchCharacterNotesDto,
+};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn get_character_note(
+    dto: CharacterNoteRequestDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Option<CharacterNoteDto>, CommandError> {
+    log_command(format!("get_character_note {}", dto.avatar));
+
+    app_state
+        .character_note_service
+        .get_note(&dto.avatar)
+        .await
+        .map_err(map_command_error("Failed to get character note"))
+}
+
+#[tauri::command]
+pub async fn save_character_note(
+    dto: SaveCharacterNoteDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Option<CharacterNoteDto>, CommandError> {
+    log_command(format!("save_character_note {}", dto.avatar));
+
+    app_state
+        .character_note_service
+        .save_note(&dto.avatar, &dto.content)
+        .await
+        .map_err(map_command_error("Failed to save character note"))
+}
+
+#[tauri::command]
+pub async fn search_character_notes(
+    dto: SearchCharacterNotesDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<CharacterNoteSearchResultDto>, CommandError> {
+    log_command("search_character_notes");
+
+    app_state
+        .character_note_service
+        .search_notes(&dto.query)
+        .await
+        .map_err(map_command_error("Failed to search character notes"))
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::character_prompt_overrides_dto::{
+    CharacterPromptOverridesDto, CharacterPromptOverridesRequestDto, SetCharacterPromptOverridesDto,
+};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn get_character_prompt_overrides(
+    dto: CharacterPromptOverridesRequestDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Option<CharacterPromptOverridesDto>, CommandError> {
+    log_command(format!("get_character_prompt_overrides {}", dto.avatar));
+
+    app_state
+        .character_prompt_overrides_service
+        .get_overrides(&dto.avatar)
+        .await
+        .map_err(map_command_error(
+            "Failed to get character prompt overrides",
+        ))
+}
+
+#[tauri::command]
+pub async fn set_character_prompt_overrides(
+    dto: SetCharacterPromptOverridesDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<CharacterPromptOverridesDto, CommandError> {
+    log_command(format!("set_character_prompt_overrides {}", dto.avatar));
+
+    app_state
+        .character_prompt_overrides_service
+        .set_overrides(&dto.avatar, dto.overrides)
+        .await
+        .map_err(map_command_error(
+            "Failed to set character prompt overrides",
+        ))
+}
+
+#[tauri::command]
+pub async fn clear_character_prompt_overrides(
+    dto: CharacterPromptOverridesRequestDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<bool, CommandError> {
+    log_command(format!("clear_character_prompt_overrides {}", dto.avatar));
+
+    app_state
+        .character_prompt_overrides_service
+        .clear_overrides(&dto.avatar)
+        .await
+        .map_err(map_command_error(
+            "Failed to clear character prompt overrides",
+        ))
+}
@@ -5,17 +5,23 @@ use tauri::ipc::Response as InvokeResponse;
 
 use crate::app::AppState;
 use crate::application::dto::chat_dto::{
-    AddMessageDto, ChatDto, ChatSearchResultDto, CreateChatDto, ExportChatDto,
-    HideChatBeforeCursorDto, ImportCharacterChatsDto, ImportChatDto, PatchChatWindowedDto,
-    PinnedCharacterChatDto, RenameChatDto, SaveChatFromFileDto, SaveChatWindowedDto,
+    AddMessageDto, ChatDto, ChatFormatDetectionDto, ChatSearchResultDto, CreateChatDto,
+    DetectChatFormatDto, ExportChatDto, HideChatBeforeCursorDto, ImportCharacterChatsDto,
+    ImportChatDto, PatchChatWindowedDto, PinnedCharacterChatDto, RenameChatDto,
+    SaveChatFromFileDto, SaveChatWindowedDto,
 };
 use crate::application::errors::ApplicationError;
 use crate::domain::repositories::chat_repository::{
-    ChatPayloadChunk, ChatPayloadCursor, ChatPayloadTail,
+    CharacterChatStats, ChatArchiveReport, ChatCompressionOptions, ChatCompressionReport,
+    ChatMessageCursor, ChatMessageLocateQuery, ChatPayloadChunk, ChatPayloadCursor,
+    ChatPayloadForwardChunk, ChatPayloadTail, ChatRepairReport, ChatSearchMatchResult,
+    ChatSearchOptions,
 };
 use crate::presentation::commands::helpers::{log_command, map_command_error};
 use crate::presentation::errors::CommandError;
 
+const CHAT_INDEX_REBUILD_JOB_KIND: &str = "chat_index_rebuild";
+
 #[tauri::command]
 pub async fn get_all_chats(
     app_state: State<'_, Arc<AppState>>,
@@ -145,6 +151,38 @@ pub async fn search_chats(
         .map_err(map_command_error("Failed to search chats"))
 }
 
+#[tauri::command]
+pub async fn get_character_chat_stats(
+    character_name: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<CharacterChatStats, CommandError> {
+    log_command(format!("get_character_chat_stats {}", character_name));
+
+    app_state
+        .chat_service
+        .get_character_chat_stats(&character_name)
+        .await
+        .map_err(map_command_error(format!(
+            "Failed to get chat stats for {}",
+            character_name
+        )))
+}
+
+#[tauri::command]
+pub async fn search_chats_with_options(
+    options: ChatSearchOptions,
+    character_filter: Option<String>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<ChatSearchMatchResult>, CommandError> {
+    log_command(format!("search_chats_with_options {}", options.query));
+
+    app_state
+        .chat_service
+        .search_chats_with_options(options, character_filter.as_deref())
+        .await
+        .map_err(map_command_error("Failed to search chats"))
+}
+
 #[tauri::command]
 pub async fn list_chat_summaries(
     character_filter: Option<String>,
@@ -292,6 +330,143 @@ pub async fn clear_chat_cache(app_state: State<'_, Arc<AppState>>) -> Result<(),
         .map_err(map_command_error("Failed to clear chat cache"))
 }
 
+/// Compress idle chats to `.jsonl.zst`. They are decompressed again when next opened.
+#[tauri::command]
+pub async fn compress_chats(
+    options: Option<ChatCompressionOptions>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ChatCompressionReport, CommandError> {
+    log_command("compress_chats");
+
+    app_state
+        .chat_service
+        .compress_chats(options.unwrap_or_default())
+        .await
+        .map_err(map_command_error("Failed to compress chats"))
+}
+
+/// Move character chats idle for `older_than_days` into each character's `archive/`
+/// directory. The most recent chat of each character stays active.
+#[tauri::command]
+pub async fn archive_old_chats(
+    character_name: Option<String>,
+    older_than_days: u32,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ChatArchiveReport, CommandError> {
+    log_command(format!("archive_old_chats {}d", older_than_days));
+
+    app_state
+        .chat_service
+        .archive_old_chats(character_name.as_deref(), older_than_days)
+        .await
+        .map_err(map_command_error("Failed to archive chats"))
+}
+
+#[tauri::command]
+pub async fn unarchive_chat(
+    character_name: String,
+    file_name: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    log_command(format!("unarchive_chat {}/{}", character_name, file_name));
+
+    app_state
+        .chat_service
+        .unarchive_chat(&character_name, &file_name)
+        .await
+        .map_err(map_command_error(format!(
+            "Failed to unarchive chat {}/{}",
+            character_name, file_name
+        )))
+}
+
+#[tauri::command]
+pub async fn list_archived_chats(
+    character_filter: Option<String>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<ChatSearchResultDto>, CommandError> {
+    log_command("list_archived_chats");
+
+    app_state
+        .chat_service
+        .list_archived_chats(character_filter.as_deref())
+        .await
+        .map_err(map_command_error("Failed to list archived chats"))
+}
+
+/// Repair a corrupted chat in place and report what was changed.
+#[tauri::command]
+pub async fn repair_chat(
+    character_name: String,
+    file_name: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ChatRepairReport, CommandError> {
+    log_command(format!("repair_chat {}/{}", character_name, file_name));
+
+    app_state
+        .chat_service
+        .repair_chat(&character_name, &file_name)
+        .await
+        .map_err(map_command_error(format!(
+            "Failed to repair chat {}/{}",
+            character_name, file_name
+        )))
+}
+
+/// Starts rebuilding the chat summary index as a background job and returns its id. The
+/// rebuild report is the job result.
+#[tauri::command]
+pub async fn rebuild_chat_index(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<String, CommandError> {
+    log_command("rebuild_chat_index");
+
+    let job = app_state
+        .job_manager
+        .start(CHAT_INDEX_REBUILD_JOB_KIND)
+        .map_err(map_command_error("Failed to start chat index rebuild"))?;
+    let job_id = job.job_id().to_string();
+    let app_state = app_state.inner().clone();
+
+    tauri::async_runtime::spawn(async move {
+        job.mark_running("scanning", "Scanning chat files");
+
+        let progress_job = job.clone();
+        let result = app_state
+            .chat_service
+            .rebuild_chat_index(&move |scanned, total| {
+                let percent = if total == 0 {
+                    100.0
+                } else {
+                    scanned as f32 * 100.0 / total as f32
+                };
+                progress_job.update_progress(
+                    "scanning",
+                    percent,
+                    &format!("Indexed {scanned} of {total} chats"),
+                );
+            })
+            .await;
+
+        match result {
+            Ok(report) => {
+                let message = format!(
+                    "Indexed {} chats in {}ms",
+                    report.character_chats + report.group_chats,
+                    report.duration_ms
+                );
+                match serde_json::to_value(&report) {
+                    Ok(value) => job.complete(&message, Some(value)),
+                    Err(error) => job.fail(&format!("Failed to serialize report: {}", error)),
+                }
+            }
+            Err(error) => job.fail(&error.to_string()),
+        }
+    });
+
+    Ok(job_id)
+}
+
 #[tauri::command]
 pub async fn get_chat_payload_path(
     character_name: String,
@@ -379,6 +554,51 @@ pub async fn get_chat_payload_before(
         )))
 }
 
+#[tauri::command]
+pub async fn get_chat_payload_after(
+    character_name: String,
+    file_name: String,
+    cursor: ChatPayloadCursor,
+    max_lines: usize,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ChatPayloadForwardChunk, CommandError> {
+    log_command(format!(
+        "get_chat_payload_after {}/{}",
+        character_name, file_name
+    ));
+
+    app_state
+        .chat_service
+        .get_chat_payload_after_lines(&character_name, &file_name, cursor, max_lines)
+        .await
+        .map_err(map_command_error(format!(
+            "Failed to get chat payload after {}/{}",
+            character_name, file_name
+        )))
+}
+
+#[tauri::command]
+pub async fn locate_chat_message(
+    character_name: String,
+    file_name: String,
+    query: ChatMessageLocateQuery,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Option<ChatMessageCursor>, CommandError> {
+    log_command(format!(
+        "locate_chat_message {}/{}",
+        character_name, file_name
+    ));
+
+    app_state
+        .chat_service
+        .locate_chat_message(&character_name, &file_name, &query)
+        .await
+        .map_err(map_command_error(format!(
+            "Failed to locate chat message {}/{}",
+            character_name, file_name
+        )))
+}
+
 #[tauri::command]
 pub async fn get_chat_payload_before_pages(
     character_name: String,
@@ -502,6 +722,20 @@ pub async fn save_chat_payload_from_file(
         .map_err(map_command_error("Failed to save chat payload from file"))
 }
 
+#[tauri::command]
+pub async fn detect_chat_format(
+    dto: DetectChatFormatDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ChatFormatDetectionDto, CommandError> {
+    log_command(format!("detect_chat_format {}", dto.file_path));
+
+    app_state
+        .chat_service
+        .detect_chat_format(dto)
+        .await
+        .map_err(map_command_error("Failed to detect chat format"))
+}
+
 #[tauri::command]
 pub async fn import_character_chats(
     dto: ImportCharacterChatsDto,
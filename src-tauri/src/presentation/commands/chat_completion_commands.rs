@@ -6,10 +6,24 @@ use tauri::{State, ipc::Channel};
 
 use crate::app::AppState;
 use crate::application::dto::chat_completion_dto::{
-    ChatCompletionGenerateRequestDto, ChatCompletionStatusRequestDto,
+    ChatCompletionGenerateRequestDto, ChatCompletionHookDefinitionDto, ChatCompletionHookPhase,
+    ChatCompletionMultiGenerateRequestDto, ChatCompletionPayloadPreviewDto,
+    ChatCompletionStatusRequestDto, ChatCompletionToolRunEventDto, ChatCompletionToolRunRequestDto,
+    LocalToolDefinitionDto, OpenRouterGenerationCostDto, RegisterChatCompletionHookDto,
+    RegisterLocalToolDto,
 };
+use crate::application::dto::generation_record_dto::{
+    ChatMessageRefDto, GenerationSnapshotDto, RegenerateWithSameSettingsDto,
+};
+use crate::application::dto::model_capability_dto::ModelCapabilitiesDto;
+use crate::application::errors::ApplicationError;
 use crate::application::services::chat_completion_service::ChatCompletionService;
+use crate::application::services::generation_record_service::{
+    GenerationRecordService, PendingGenerationRecord,
+};
+use crate::application::services::notification_service::{GenerationOutcome, NotificationService};
 use crate::domain::models::upstream_failure::UpstreamFailure;
+use crate::domain::repositories::chat_completion_repository::ChatCompletionSource;
 use crate::presentation::commands::helpers::{log_command, map_command_error};
 use crate::presentation::errors::CommandError;
 
@@ -27,6 +41,42 @@ pub async fn get_chat_completions_status(
         .map_err(map_command_error("Failed to get chat completions status"))
 }
 
+#[tauri::command]
+pub async fn get_openrouter_generation_cost(
+    dto: ChatCompletionStatusRequestDto,
+    generation_id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<OpenRouterGenerationCostDto, CommandError> {
+    log_command(format!("get_openrouter_generation_cost {}", generation_id));
+
+    app_state
+        .chat_completion_service
+        .get_openrouter_generation_cost(dto, &generation_id)
+        .await
+        .map_err(map_command_error(
+            "Failed to get OpenRouter generation cost",
+        ))
+}
+
+#[tauri::command]
+pub async fn get_model_capabilities(
+    source: String,
+    model: Option<String>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<ModelCapabilitiesDto>, CommandError> {
+    log_command(format!("get_model_capabilities {}", source));
+
+    let source = ChatCompletionSource::parse(&source).ok_or_else(|| {
+        CommandError::BadRequest(format!("Unsupported chat completion source: {source}"))
+    })?;
+    let service = &app_state.model_capability_service;
+
+    Ok(match model {
+        Some(model) => service.get(source, &model).await.into_iter().collect(),
+        None => service.list(source).await,
+    })
+}
+
 #[tauri::command]
 pub async fn generate_chat_completion(
     dto: ChatCompletionGenerateRequestDto,
@@ -37,12 +87,106 @@ pub async fn generate_chat_completion(
     validate_stream_id(&request_id)?;
     log_command(format!("generate_chat_completion {}", request_id));
 
+    run_generation(&app_state, dto, &request_id)
+        .await
+        .map_err(map_command_error("Failed to generate chat completion"))
+}
+
+/// Generates `dto.request` with the settings recorded on `dto.message`, like
+/// `generate_chat_completion`.
+#[tauri::command]
+pub async fn regenerate_with_same_settings(
+    dto: RegenerateWithSameSettingsDto,
+    request_id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    let request_id = request_id.trim().to_string();
+    validate_stream_id(&request_id)?;
+    log_command(format!("regenerate_with_same_settings {}", request_id));
+
+    let mut request = dto.request;
+    app_state
+        .generation_record_service
+        .apply_recorded_settings(&dto.message, &mut request.payload)
+        .await
+        .map_err(map_command_error("Failed to regenerate chat completion"))?;
+    run_generation(&app_state, request, &request_id)
+        .await
+        .map_err(map_command_error("Failed to regenerate chat completion"))
+}
+
+#[tauri::command]
+pub async fn get_generation_snapshot(
+    message: ChatMessageRefDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<GenerationSnapshotDto, CommandError> {
+    log_command(format!("get_generation_snapshot {}", message.message_index));
+
+    app_state
+        .generation_record_service
+        .get_generation_snapshot(&message)
+        .await
+        .map_err(map_command_error("Failed to get generation snapshot"))
+}
+
+async fn run_generation(
+    app_state: &AppState,
+    mut dto: ChatCompletionGenerateRequestDto,
+    request_id: &str,
+) -> Result<Value, ApplicationError> {
+    app_state
+        .summarize_service
+        .apply_chat_summary(&mut dto.payload)
+        .await?;
+    let record = app_state
+        .generation_record_service
+        .prepare(&mut dto.payload)?;
     let service = app_state.chat_completion_service.clone();
-    let cancel = service.register_generation(&request_id).await;
+    let cancel = service.register_generation(request_id).await;
     let result = service.generate_with_cancel(dto, cancel).await;
-    service.complete_generation(&request_id).await;
+    service.complete_generation(request_id).await;
+
+    let response = result?;
+    if let Some(record) = record {
+        record_generation(&app_state.generation_record_service, record).await;
+    }
+    Ok(response)
+}
+
+async fn record_generation(service: &GenerationRecordService, record: PendingGenerationRecord) {
+    if let Err(error) = service.record(record).await {
+        tracing::warn!("Failed to record generation settings: {}", error);
+    }
+}
 
-    result.map_err(map_command_error("Failed to generate chat completion"))
+/// Resolves config and builds the upstream payload for `dto` without sending it.
+#[tauri::command]
+pub async fn preview_generate_payload(
+    mut dto: ChatCompletionGenerateRequestDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ChatCompletionPayloadPreviewDto, CommandError> {
+    log_command("preview_generate_payload");
+
+    app_state
+        .summarize_service
+        .inject_chat_summary(&mut dto.payload)
+        .await
+        .map_err(map_command_error(
+            "Failed to preview chat completion payload",
+        ))?;
+    app_state
+        .generation_record_service
+        .prepare(&mut dto.payload)
+        .map_err(map_command_error(
+            "Failed to preview chat completion payload",
+        ))?;
+    app_state
+        .chat_completion_service
+        .preview_generate_payload(dto)
+        .await
+        .map_err(map_command_error(
+            "Failed to preview chat completion payload",
+        ))
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -62,23 +206,215 @@ pub(crate) enum ChatCompletionStreamEvent {
 #[tauri::command]
 pub async fn start_chat_completion_stream(
     stream_id: String,
-    dto: ChatCompletionGenerateRequestDto,
+    mut dto: ChatCompletionGenerateRequestDto,
     on_event: Channel<ChatCompletionStreamEvent>,
     app_state: State<'_, Arc<AppState>>,
 ) -> Result<(), CommandError> {
     validate_stream_id(&stream_id)?;
     log_command(format!("start_chat_completion_stream {}", stream_id));
 
+    app_state
+        .summarize_service
+        .apply_chat_summary(&mut dto.payload)
+        .await
+        .map_err(map_command_error("Failed to start chat completion stream"))?;
+    let record = app_state
+        .generation_record_service
+        .prepare(&mut dto.payload)
+        .map_err(map_command_error("Failed to start chat completion stream"))?
+        .map(|record| (app_state.generation_record_service.clone(), record));
     let service = app_state.chat_completion_service.clone();
+    let notifications = app_state.notification_service.clone();
     let cancel = service.register_stream(&stream_id).await;
 
     tauri::async_runtime::spawn(run_stream_generation(
-        service, stream_id, dto, cancel, on_event,
+        service,
+        notifications,
+        stream_id,
+        dto,
+        cancel,
+        on_event,
+        record,
     ));
 
     Ok(())
 }
 
+const MAX_MULTI_GENERATION_TARGETS: usize = 8;
+
+/// Stream event of one `generate_multi` target, tagged with that target's stream id.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatCompletionMultiStreamEvent {
+    stream_id: String,
+    #[serde(flatten)]
+    event: ChatCompletionStreamEvent,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatCompletionMultiResult {
+    stream_id: String,
+    source: String,
+    model: String,
+    /// Full provider response of a non-streamed target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<UpstreamFailure>,
+}
+
+/// Runs one prompt against every target concurrently. Streamed targets (`stream: true`
+/// in the merged payload) emit their chunks on `on_event`; each target can be cancelled
+/// through its stream id. One target failing does not stop the others.
+#[tauri::command]
+pub async fn generate_multi(
+    mut dto: ChatCompletionMultiGenerateRequestDto,
+    on_event: Channel<ChatCompletionMultiStreamEvent>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<ChatCompletionMultiResult>, CommandError> {
+    if dto.targets.is_empty() || dto.targets.len() > MAX_MULTI_GENERATION_TARGETS {
+        return Err(CommandError::BadRequest(format!(
+            "generate_multi needs between 1 and {MAX_MULTI_GENERATION_TARGETS} targets"
+        )));
+    }
+
+    let mut stream_ids = Vec::with_capacity(dto.targets.len());
+    for target in &dto.targets {
+        let stream_id = target.stream_id.trim().to_string();
+        validate_stream_id(&stream_id)?;
+        if stream_ids.contains(&stream_id) {
+            return Err(CommandError::BadRequest(format!(
+                "Duplicate stream id: {stream_id}"
+            )));
+        }
+        stream_ids.push(stream_id);
+    }
+    log_command(format!("generate_multi {}", stream_ids.join(",")));
+
+    app_state
+        .summarize_service
+        .apply_chat_summary(&mut dto.request.payload)
+        .await
+        .map_err(map_command_error("Failed to run multi-model generation"))?;
+
+    let service = app_state.chat_completion_service.clone();
+    let runs = dto
+        .targets
+        .iter()
+        .zip(stream_ids)
+        .map(|(target, stream_id)| {
+            run_multi_target(
+                service.clone(),
+                stream_id,
+                dto.target_request(target),
+                on_event.clone(),
+            )
+        });
+
+    Ok(futures_util::future::join_all(runs).await)
+}
+
+async fn run_multi_target(
+    service: Arc<ChatCompletionService>,
+    stream_id: String,
+    request: ChatCompletionGenerateRequestDto,
+    on_event: Channel<ChatCompletionMultiStreamEvent>,
+) -> ChatCompletionMultiResult {
+    let mut result = ChatCompletionMultiResult {
+        stream_id: stream_id.clone(),
+        source: request
+            .get_string("chat_completion_source")
+            .unwrap_or_default()
+            .to_string(),
+        model: request.get_string("model").unwrap_or_default().to_string(),
+        response: None,
+        error: None,
+        details: None,
+    };
+
+    let streaming = request
+        .payload
+        .get("stream")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    if !streaming {
+        let cancel = service.register_generation(&stream_id).await;
+        let generation = service.generate_with_cancel(request, cancel).await;
+        service.complete_generation(&stream_id).await;
+        match generation {
+            Ok(response) => result.response = Some(response),
+            Err(error) => result.set_error(error),
+        }
+        return result;
+    }
+
+    let cancel = service.register_stream(&stream_id).await;
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<String>();
+    let generation = service.generate_stream(request, sender, cancel);
+    let forwarding = async {
+        while let Some(chunk) = receiver.recv().await {
+            if chunk.is_empty() {
+                continue;
+            }
+
+            let event = ChatCompletionMultiStreamEvent {
+                stream_id: stream_id.clone(),
+                event: ChatCompletionStreamEvent::Chunk { data: chunk },
+            };
+            if on_event.send(event).is_err() {
+                service.cancel_stream(&stream_id).await;
+                break;
+            }
+        }
+    };
+
+    let (generation, ()) = tokio::join!(generation, forwarding);
+    service.complete_stream(&stream_id).await;
+
+    let event = match generation {
+        Ok(()) => ChatCompletionStreamEvent::Done,
+        Err(error) => {
+            result.set_error(error);
+            ChatCompletionStreamEvent::Error {
+                message: result.error.clone().unwrap_or_default(),
+                details: result.details.clone(),
+            }
+        }
+    };
+    let _ = on_event.send(ChatCompletionMultiStreamEvent {
+        stream_id: stream_id.clone(),
+        event,
+    });
+
+    result
+}
+
+impl ChatCompletionMultiResult {
+    fn set_error(&mut self, error: ApplicationError) {
+        let command_error = CommandError::from(error);
+        self.details = command_error.upstream_failure().cloned();
+        self.error = Some(command_error.to_string());
+    }
+}
+
+/// Itemized breakdown of the prompt sent for `request_id`. `None` until the frontend
+/// annotated that request and the report finished counting.
+#[tauri::command]
+pub async fn get_prompt_itemization(
+    request_id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Option<PromptItemizationDto>, CommandError> {
+    log_command(format!("get_prompt_itemization {}", request_id));
+
+    Ok(app_state
+        .chat_completion_service
+        .get_prompt_itemization(request_id.trim())
+        .await)
+}
+
 #[tauri::command]
 pub async fn cancel_chat_completion_stream(
     stream_id: String,
@@ -109,13 +445,215 @@ pub async fn cancel_chat_completion_generation(
     Ok(())
 }
 
+#[tauri::command]
+pub async fn register_tool(
+    dto: RegisterLocalToolDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<LocalToolDefinitionDto, CommandError> {
+    log_command(format!("register_tool {}", dto.name));
+
+    app_state
+        .chat_completion_service
+        .register_local_tool(dto)
+        .await
+        .map_err(map_command_error("Failed to register tool"))
+}
+
+#[tauri::command]
+pub async fn unregister_tool(
+    name: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<bool, CommandError> {
+    log_command(format!("unregister_tool {}", name));
+
+    Ok(app_state
+        .chat_completion_service
+        .unregister_local_tool(&name)
+        .await)
+}
+
+#[tauri::command]
+pub async fn list_registered_tools(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<LocalToolDefinitionDto>, CommandError> {
+    log_command("list_registered_tools");
+
+    Ok(app_state.chat_completion_service.list_local_tools().await)
+}
+
+#[tauri::command]
+pub async fn submit_tool_result(
+    run_id: String,
+    call_id: String,
+    content: String,
+    is_error: Option<bool>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    validate_stream_id(&run_id)?;
+    log_command(format!("submit_tool_result {} {}", run_id, call_id));
+
+    app_state
+        .chat_completion_service
+        .submit_local_tool_result(&run_id, &call_id, content, is_error.unwrap_or(false))
+        .await
+        .map_err(map_command_error("Failed to submit tool result"))
+}
+
+#[tauri::command]
+pub async fn register_chat_completion_hook(
+    dto: RegisterChatCompletionHookDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ChatCompletionHookDefinitionDto, CommandError> {
+    log_command(format!(
+        "register_chat_completion_hook {} {}",
+        dto.extension,
+        dto.phase.key()
+    ));
+
+    app_state
+        .chat_completion_service
+        .register_chat_completion_hook(dto)
+        .await
+        .map_err(map_command_error("Failed to register chat completion hook"))
+}
+
+#[tauri::command]
+pub async fn unregister_chat_completion_hook(
+    extension: String,
+    phase: ChatCompletionHookPhase,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<bool, CommandError> {
+    log_command(format!(
+        "unregister_chat_completion_hook {} {}",
+        extension,
+        phase.key()
+    ));
+
+    Ok(app_state
+        .chat_completion_service
+        .unregister_chat_completion_hook(&extension, phase)
+        .await)
+}
+
+#[tauri::command]
+pub async fn list_chat_completion_hooks(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<ChatCompletionHookDefinitionDto>, CommandError> {
+    log_command("list_chat_completion_hooks");
+
+    Ok(app_state
+        .chat_completion_service
+        .list_chat_completion_hooks()
+        .await)
+}
+
+/// Answers a `chat-completion-hook-requested` event. Passing `error` keeps the payload
+/// unchanged.
+#[tauri::command]
+pub async fn submit_chat_completion_hook_result(
+    invocation_id: String,
+    payload: Option<Value>,
+    error: Option<String>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    log_command(format!(
+        "submit_chat_completion_hook_result {}",
+        invocation_id
+    ));
+
+    let outcome = match (payload, error) {
+        (_, Some(error)) => Err(error),
+        (Some(payload), None) => Ok(payload),
+        (None, None) => {
+            return Err(CommandError::BadRequest(
+                "Chat completion hook result requires a payload or an error".to_string(),
+            ));
+        }
+    };
+
+    app_state
+        .chat_completion_service
+        .submit_chat_completion_hook_result(&invocation_id, outcome)
+        .await
+        .map_err(map_command_error(
+            "Failed to submit chat completion hook result",
+        ))
+}
+
+/// Starts a server-side tool-calling loop. Cancellation reuses
+/// `cancel_chat_completion_generation` with the same run id.
+#[tauri::command]
+pub async fn start_chat_completion_tool_run(
+    run_id: String,
+    dto: ChatCompletionToolRunRequestDto,
+    on_event: Channel<ChatCompletionToolRunEventDto>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    validate_stream_id(&run_id)?;
+    log_command(format!("start_chat_completion_tool_run {}", run_id));
+
+    let service = app_state.chat_completion_service.clone();
+    let cancel = service.register_generation(&run_id).await;
+
+    tauri::async_runtime::spawn(run_tool_generation(service, run_id, dto, cancel, on_event));
+
+    Ok(())
+}
+
+async fn run_tool_generation(
+    service: Arc<ChatCompletionService>,
+    run_id: String,
+    dto: ChatCompletionToolRunRequestDto,
+    cancel: tokio::sync::watch::Receiver<bool>,
+    on_event: Channel<ChatCompletionToolRunEventDto>,
+) {
+    let (sender, mut receiver) =
+        tokio::sync::mpsc::unbounded_channel::<ChatCompletionToolRunEventDto>();
+    let generation_task = tauri::async_runtime::spawn({
+        let service = service.clone();
+        let run_id = run_id.clone();
+        async move {
+            service
+                .generate_with_local_tools(&run_id, dto, sender, cancel)
+                .await
+        }
+    });
+
+    while let Some(event) = receiver.recv().await {
+        if on_event.send(event).is_err() {
+            generation_task.abort();
+            service.complete_generation(&run_id).await;
+            return;
+        }
+    }
+
+    let generation_result = match generation_task.await {
+        Ok(result) => result,
+        Err(error) => Err(ApplicationError::InternalError(format!(
+            "Tool run task join failed: {error}"
+        ))),
+    };
+
+    service.complete_generation(&run_id).await;
+
+    if let Err(error) = generation_result {
+        let command_error = CommandError::from(error);
+        let _ = on_event.send(ChatCompletionToolRunEventDto::Error {
+            message: command_error.to_string(),
+        });
+    }
+}
+
 async fn run_stream_generation(
     service: Arc<ChatCompletionService>,
+    notifications: Arc<NotificationService>,
     stream_id: String,
     dto: ChatCompletionGenerateRequestDto,
     cancel: tokio::sync::watch::Receiver<bool>,
     on_event: Channel<ChatCompletionStreamEvent>,
+    record: Option<(Arc<GenerationRecordService>, PendingGenerationRecord)>,
 ) {
+    let avatar = ChatCompletionService::tagged_character(&dto);
     let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<String>();
     let generation_task = tauri::async_runtime::spawn({
         let service = service.clone();
@@ -138,24 +676,37 @@ async fn run_stream_generation(
 
     let generation_result = match generation_task.await {
         Ok(result) => result,
-        Err(error) => Err(crate::application::errors::ApplicationError::InternalError(
-            format!("Streaming task join failed: {error}"),
-        )),
+        Err(error) => Err(ApplicationError::InternalError(format!(
+            "Streaming task join failed: {error}"
+        ))),
     };
 
     service.complete_stream(&stream_id).await;
 
     match generation_result {
         Ok(()) => {
+            if let Some((record_service, record)) = record {
+                record_generation(&record_service, record).await;
+            }
             let _ = on_event.send(ChatCompletionStreamEvent::Done);
+            notifications
+                .notify_generation(avatar.as_deref(), GenerationOutcome::Completed)
+                .await;
         }
         Err(error) => {
+            let cancelled = matches!(error, ApplicationError::Cancelled(_));
             let command_error = CommandError::from(error);
             let details = command_error.upstream_failure().cloned();
+            let message = command_error.to_string();
             let _ = on_event.send(ChatCompletionStreamEvent::Error {
-                message: command_error.to_string(),
+                message: message.clone(),
                 details,
             });
+            if !cancelled {
+                notifications
+                    .notify_generation(avatar.as_deref(), GenerationOutcome::Failed(&message))
+                    .await;
+            }
         }
     }
 }
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::chat_metadata_dto::{ChatMetadataDto, UpdateChatMetadataDto};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn get_chat_metadata(
+    dto: ChatMetadataDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command("get_chat_metadata");
+
+    app_state
+        .chat_metadata_service
+        .get_chat_metadata(&dto.chat)
+        .await
+        .map_err(map_command_error("Failed to get chat metadata"))
+}
+
+/// Patches the chat header without rewriting message lines and returns the updated
+/// `chat_metadata`.
+#[tauri::command]
+pub async fn update_chat_metadata(
+    dto: UpdateChatMetadataDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command("update_chat_metadata");
+
+    app_state
+        .chat_metadata_service
+        .update_chat_metadata(&dto.chat, dto.fields)
+        .await
+        .map_err(map_command_error("Failed to update chat metadata"))
+}
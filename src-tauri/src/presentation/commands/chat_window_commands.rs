@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use tauri::{AppHandle, State, WebviewWindow};
+
+use crate::presentation::chat_windows::{self, ChatWindowBinding, ChatWindowRegistry};
+use crate::presentation::commands::helpers::log_command;
+use crate::presentation::errors::CommandError;
+
+/// Opens a separate window showing the character chat, or focuses the one already
+/// showing it. Returns the window label.
+#[tauri::command]
+pub async fn open_chat_window(
+    character: String,
+    file: String,
+    app: AppHandle,
+) -> Result<String, CommandError> {
+    log_command(format!("open_chat_window {} {}", character, file));
+
+    let binding = ChatWindowBinding {
+        character: validate_name("Character", &character)?,
+        file: validate_name("Chat file", file.trim().trim_end_matches(".jsonl"))?,
+    };
+    chat_windows::open_chat_window(&app, binding).map_err(|error| {
+        CommandError::InternalServerError(format!("Failed to open chat window: {}", error))
+    })
+}
+
+/// The chat the calling window is bound to; `None` for the main window.
+#[tauri::command]
+pub fn get_chat_window_binding(
+    webview_window: WebviewWindow,
+    registry: State<'_, Arc<ChatWindowRegistry>>,
+) -> Result<Option<ChatWindowBinding>, CommandError> {
+    log_command("get_chat_window_binding");
+    Ok(registry.binding(webview_window.label()))
+}
+
+fn validate_name(field: &str, value: &str) -> Result<String, CommandError> {
+    let value = value.trim();
+    if value.is_empty() || value.contains(['/', '\\']) || value == ".." {
+        return Err(CommandError::BadRequest(format!(
+            "{} must be a file name",
+            field
+        )));
+    }
+    Ok(value.to_string())
+}
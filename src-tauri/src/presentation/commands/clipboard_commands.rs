@@ -0,0 +1,193 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use serde_json::Value;
+use tauri::{AppHandle, State, Url};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use crate::app::AppState;
+use crate::application::dto::character_dto::{CharacterDto, ImportCharacterDto};
+use crate::infrastructure::persistence::file_system::atomic_write;
+use crate::infrastructure::persistence::png_utils::read_character_data_from_png;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+const MAX_CLIPBOARD_CARD_BYTES: usize = 32 * 1024 * 1024;
+const PNG_DATA_URL_PREFIX: &str = "data:image/png;base64,";
+
+/// A character card found on the clipboard.
+#[derive(Debug, PartialEq)]
+enum ClipboardCard {
+    /// A copied card file; imported in place.
+    File(PathBuf),
+    /// Card bytes staged to a temp file with this extension before import.
+    Bytes {
+        extension: &'static str,
+        bytes: Vec<u8>,
+    },
+}
+
+/// Imports a character card from the clipboard: card JSON, a PNG card as a `data:` URL,
+/// or the path of a copied `.png`/`.json` card file.
+///
+/// Copied bitmaps are rejected, because the clipboard only keeps the pixels and drops
+/// the PNG text chunks the card data lives in.
+#[tauri::command]
+pub async fn import_character_from_clipboard(
+    app: AppHandle,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<CharacterDto, CommandError> {
+    log_command("import_character_from_clipboard");
+
+    let text = app.clipboard().read_text().unwrap_or_default();
+    let card = match parse_clipboard_card(&text)? {
+        Some(card) => card,
+        None if app.clipboard().read_image().is_ok() => {
+            return Err(CommandError::BadRequest(
+                "Copied images do not keep embedded card data. Copy the card file or its JSON instead"
+                    .to_string(),
+            ));
+        }
+        None => {
+            return Err(CommandError::BadRequest(
+                "The clipboard does not contain a character card".to_string(),
+            ));
+        }
+    };
+
+    let (file_path, staged) = match card {
+        ClipboardCard::File(path) => (path, false),
+        ClipboardCard::Bytes { extension, bytes } => {
+            let path = std::env::temp_dir().join(format!(
+                "tauritavern-clipboard-card-{}.{}",
+                uuid::Uuid::new_v4(),
+                extension
+            ));
+            atomic_write(&path, &bytes).await?;
+            (path, true)
+        }
+    };
+
+    let result = app_state
+        .character_service
+        .import_character(ImportCharacterDto {
+            file_path: file_path.to_string_lossy().to_string(),
+            preserve_file_name: None,
+        })
+        .await;
+    if staged {
+        let _ = tokio::fs::remove_file(&file_path).await;
+    }
+    result.map_err(map_command_error(
+        "Failed to import character from clipboard",
+    ))
+}
+
+/// Returns `Ok(None)` when the text is not meant as a card, and an error when it looks
+/// like one but is not valid.
+fn parse_clipboard_card(text: &str) -> Result<Option<ClipboardCard>, CommandError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    if text.len() > MAX_CLIPBOARD_CARD_BYTES {
+        return Err(CommandError::BadRequest(
+            "Clipboard content is too large".to_string(),
+        ));
+    }
+
+    if text.starts_with('{') {
+        let value: Value = serde_json::from_str(text).map_err(|error| {
+            CommandError::BadRequest(format!("Clipboard JSON is invalid: {}", error))
+        })?;
+        validate_card_json(&value)?;
+        return Ok(Some(ClipboardCard::Bytes {
+            extension: "json",
+            bytes: text.as_bytes().to_vec(),
+        }));
+    }
+
+    if let Some(encoded) = text.strip_prefix(PNG_DATA_URL_PREFIX) {
+        let bytes = BASE64_STANDARD.decode(encoded.trim()).map_err(|error| {
+            CommandError::BadRequest(format!("Clipboard image data is invalid: {}", error))
+        })?;
+        validate_card_png(&bytes)?;
+        return Ok(Some(ClipboardCard::Bytes {
+            extension: "png",
+            bytes,
+        }));
+    }
+
+    Ok(copied_card_path(text).map(ClipboardCard::File))
+}
+
+/// File managers put copied files on the clipboard as paths or `file://` URIs, one per line.
+fn copied_card_path(text: &str) -> Option<PathBuf> {
+    let line = text.lines().next()?.trim();
+    let path = match Url::parse(line) {
+        Ok(url) if url.scheme() == "file" => url.to_file_path().ok()?,
+        _ => PathBuf::from(line),
+    };
+    if !path.is_absolute() || !is_card_file(&path) {
+        return None;
+    }
+    Some(path)
+}
+
+fn is_card_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            extension.eq_ignore_ascii_case("png") || extension.eq_ignore_ascii_case("json")
+        })
+        && path.is_file()
+}
+
+fn validate_card_json(value: &Value) -> Result<(), CommandError> {
+    let is_card_spec = value
+        .get("spec")
+        .and_then(Value::as_str)
+        .is_some_and(|spec| spec.starts_with("chara_card"));
+    let has_name = value
+        .get("name")
+        .and_then(Value::as_str)
+        .is_some_and(|name| !name.trim().is_empty());
+    if is_card_spec || has_name {
+        Ok(())
+    } else {
+        Err(CommandError::BadRequest(
+            "Clipboard JSON is not a character card".to_string(),
+        ))
+    }
+}
+
+fn validate_card_png(bytes: &[u8]) -> Result<(), CommandError> {
+    read_character_data_from_png(bytes)
+        .map(|_| ())
+        .map_err(|_| {
+            CommandError::BadRequest("Clipboard image has no character card data".to_string())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClipboardCard, parse_clipboard_card};
+
+    #[test]
+    fn parses_card_json_and_ignores_unrelated_text() {
+        let card = r#"{"spec":"chara_card_v2","data":{"name":"Alice"}}"#;
+        assert_eq!(
+            parse_clipboard_card(card).unwrap(),
+            Some(ClipboardCard::Bytes {
+                extension: "json",
+                bytes: card.as_bytes().to_vec(),
+            })
+        );
+        assert!(parse_clipboard_card(r#"{"entries":{}}"#).is_err());
+        assert!(parse_clipboard_card("data:image/png;base64,AAAA").is_err());
+        assert_eq!(parse_clipboard_card("hello there").unwrap(), None);
+        assert_eq!(parse_clipboard_card("   ").unwrap(), None);
+    }
+}
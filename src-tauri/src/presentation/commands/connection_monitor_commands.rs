@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::domain::models::connection_health::ConnectionHealth;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+/// Latest health of the active connection profile; `refresh` runs a check first.
+#[tauri::command]
+pub async fn get_connection_health(
+    refresh: Option<bool>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ConnectionHealth, CommandError> {
+    log_command("get_connection_health");
+
+    app_state
+        .connection_monitor_service
+        .get_health(refresh.unwrap_or(false))
+        .await
+        .map_err(map_command_error("Failed to get connection health"))
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::connection_profile_dto::{
+    ActivateConnectionProfileResultDto, ConnectionProfileIdDto, ListConnectionProfilesResultDto,
+    SaveConnectionProfileDto,
+};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn list_connection_profiles(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ListConnectionProfilesResultDto, CommandError> {
+    log_command("list_connection_profiles");
+
+    app_state
+        .connection_profile_service
+        .list_profiles()
+        .await
+        .map_err(map_command_error("Failed to list connection profiles"))
+}
+
+#[tauri::command]
+pub async fn save_connection_profile(
+    dto: SaveConnectionProfileDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    log_command("save_connection_profile");
+
+    app_state
+        .connection_profile_service
+        .save_profile(dto.profile)
+        .await
+        .map_err(map_command_error("Failed to save connection profile"))
+}
+
+#[tauri::command]
+pub async fn activate_connection_profile(
+    dto: ConnectionProfileIdDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ActivateConnectionProfileResultDto, CommandError> {
+    log_command(format!("activate_connection_profile {}", dto.profile_id));
+
+    let result = app_state
+        .connection_profile_service
+        .activate_profile(&dto.profile_id)
+        .await
+        .map_err(map_command_error("Failed to activate connection profile"))?;
+
+    app_state
+        .connection_monitor_service
+        .notify_settings_changed();
+    Ok(result)
+}
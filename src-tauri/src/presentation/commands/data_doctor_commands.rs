@@ -0,0 +1,106 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tauri::State;
+
+use crate::app::AppState;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::data_doctor::DataDoctor;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+const DATA_DOCTOR_JOB_KIND: &str = "data_doctor";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RunDataDoctorOptions {
+    /// Apply the fixes that cannot lose content. Without it the run only reports.
+    pub fix: bool,
+}
+
+/// Starts a data integrity scan as a background job and returns its id. The report is
+/// the job result.
+#[tauri::command]
+pub async fn run_data_doctor(
+    app_state: State<'_, Arc<AppState>>,
+    options: Option<RunDataDoctorOptions>,
+) -> Result<String, CommandError> {
+    let options = options.unwrap_or_default();
+    log_command(format!("run_data_doctor fix={}", options.fix));
+
+    let directory = app_state
+        .user_directory_service
+        .get_default_user_directory()
+        .await?;
+    let doctor = DataDoctor::new(
+        PathBuf::from(directory.root),
+        PathBuf::from(directory.characters),
+        PathBuf::from(directory.chats),
+        PathBuf::from(directory.group_chats),
+        PathBuf::from(directory.groups),
+        PathBuf::from(directory.worlds),
+        PathBuf::from(directory.backups),
+    );
+
+    let job = app_state
+        .job_manager
+        .start(DATA_DOCTOR_JOB_KIND)
+        .map_err(map_command_error("Failed to start data doctor"))?;
+    let job_id = job.job_id().to_string();
+    let app_state = app_state.inner().clone();
+
+    tauri::async_runtime::spawn(async move {
+        job.mark_running("scanning", "Checking data files");
+
+        let progress_job = job.clone();
+        let result = doctor
+            .run(
+                options.fix,
+                &move |done, total| {
+                    let percent = if total == 0 {
+                        100.0
+                    } else {
+                        done as f32 * 100.0 / total as f32
+                    };
+                    progress_job.update_progress(
+                        "scanning",
+                        percent,
+                        &format!("Checked {done} of {total} files"),
+                    );
+                },
+                &|| job.is_cancel_requested(),
+            )
+            .await;
+
+        match result {
+            Ok(report) => {
+                if report.fixed > 0 {
+                    clear_library_caches(&app_state).await;
+                }
+                let message = format!(
+                    "Found {} issue(s), fixed {}",
+                    report.issues.len(),
+                    report.fixed
+                );
+                match serde_json::to_value(&report) {
+                    Ok(value) => job.complete(&message, Some(value)),
+                    Err(error) => job.fail(&format!("Failed to serialize report: {}", error)),
+                }
+            }
+            Err(DomainError::Cancelled(_)) => job.mark_cancelled(),
+            Err(error) => job.fail(&error.to_string()),
+        }
+    });
+
+    Ok(job_id)
+}
+
+async fn clear_library_caches(app_state: &AppState) {
+    if let Err(error) = app_state.character_service.clear_cache().await {
+        tracing::warn!("Failed to clear character cache after repair: {}", error);
+    }
+    if let Err(error) = app_state.chat_service.clear_cache().await {
+        tracing::warn!("Failed to clear chat cache after repair: {}", error);
+    }
+}
@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::presentation::commands::helpers::log_command;
+use crate::presentation::deep_links::{DeepLinkInbox, DeepLinkRequest};
+use crate::presentation::errors::CommandError;
+
+/// Returns and clears the deep link requests the frontend has not handled yet.
+#[tauri::command]
+pub fn take_pending_deep_links(
+    inbox: State<'_, Arc<DeepLinkInbox>>,
+) -> Result<Vec<DeepLinkRequest>, CommandError> {
+    log_command("take_pending_deep_links");
+    Ok(inbox.take_all())
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::expression_dto::{
+    ClassifyExpressionOptionsDto, ExpressionClassificationDto,
+};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn classify_expression(
+    text: String,
+    options: Option<ClassifyExpressionOptionsDto>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ExpressionClassificationDto, CommandError> {
+    log_command("classify_expression");
+
+    app_state
+        .expression_service
+        .classify_expression(&text, options.unwrap_or_default())
+        .await
+        .map_err(map_command_error("Failed to classify expression"))
+}
@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use tauri::State;
@@ -6,7 +7,8 @@ use crate::app::AppState;
 use crate::domain::errors::DomainError;
 use crate::domain::ios_policy::IosPolicyScope;
 use crate::domain::models::extension::{
-    Extension, ExtensionInstallResult, ExtensionUpdateResult, ExtensionVersion,
+    Extension, ExtensionBatchUpdateResult, ExtensionInstallResult, ExtensionUpdateResult,
+    ExtensionVersion,
 };
 use crate::infrastructure::logging::logger;
 use crate::presentation::commands::helpers::{
@@ -95,6 +97,31 @@ pub async fn install_extension(
         })
 }
 
+#[tauri::command]
+pub async fn install_extension_from_path(
+    path: String,
+    global: bool,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ExtensionInstallResult, CommandError> {
+    log_command(format!("install_extension_from_path {}", path));
+
+    ensure_ios_policy_allows(
+        &app_state.ios_policy,
+        app_state
+            .ios_policy
+            .capabilities
+            .extensions
+            .third_party_management,
+        "extensions.third_party_management",
+    )?;
+
+    app_state
+        .extension_service
+        .install_extension_from_path(&PathBuf::from(path), global)
+        .await
+        .map_err(map_command_error("Failed to install extension"))
+}
+
 #[tauri::command]
 pub async fn update_extension(
     extension_name: String,
@@ -120,6 +147,30 @@ pub async fn update_extension(
         .map_err(map_command_error("Failed to update extension"))
 }
 
+#[tauri::command]
+pub async fn update_all_extensions(
+    include_global: Option<bool>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<ExtensionBatchUpdateResult>, CommandError> {
+    log_command("update_all_extensions");
+
+    ensure_ios_policy_allows(
+        &app_state.ios_policy,
+        app_state
+            .ios_policy
+            .capabilities
+            .extensions
+            .third_party_management,
+        "extensions.third_party_management",
+    )?;
+
+    app_state
+        .extension_service
+        .update_all_extensions(include_global.unwrap_or(false))
+        .await
+        .map_err(map_command_error("Failed to update extensions"))
+}
+
 #[tauri::command]
 pub async fn delete_extension(
     extension_name: String,
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tauri::State;
+
+use crate::app::AppState;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::external_data_merge::{
+    ExternalDataMerge, MergeConflictStrategy,
+};
+use crate::infrastructure::persistence::sillytavern_migration::MigrationCategory;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+const EXTERNAL_DATA_MERGE_JOB_KIND: &str = "external_data_merge";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MergeExternalDataRootOptions {
+    /// Overrides `strategy` for individual categories.
+    pub category_strategies: HashMap<MigrationCategory, MergeConflictStrategy>,
+}
+
+/// Starts merging the library of another TauriTavern data directory at `path` into this
+/// one as a background job and returns its id. The per-category report is the job result.
+#[tauri::command]
+pub async fn merge_external_data_root(
+    path: String,
+    strategy: Option<MergeConflictStrategy>,
+    app_state: State<'_, Arc<AppState>>,
+    options: Option<MergeExternalDataRootOptions>,
+) -> Result<String, CommandError> {
+    let options = options.unwrap_or_default();
+    let strategy = strategy.unwrap_or_default();
+    let path = path.trim();
+    log_command(format!(
+        "merge_external_data_root {} strategy={:?}",
+        path, strategy
+    ));
+
+    if path.is_empty() {
+        return Err(CommandError::BadRequest("path is required".to_string()));
+    }
+
+    let directory = app_state
+        .user_directory_service
+        .get_default_user_directory()
+        .await?;
+    let merge = ExternalDataMerge::detect(
+        &PathBuf::from(path),
+        PathBuf::from(directory.root),
+        strategy,
+        options.category_strategies,
+    )?;
+
+    let job = app_state
+        .job_manager
+        .start(EXTERNAL_DATA_MERGE_JOB_KIND)
+        .map_err(map_command_error("Failed to start data merge"))?;
+    let job_id = job.job_id().to_string();
+    let app_state = app_state.inner().clone();
+
+    tauri::async_runtime::spawn(async move {
+        job.mark_running("merging", "Merging data directory");
+
+        let progress_job = job.clone();
+        let result = merge
+            .run(
+                &move |done, total| {
+                    let percent = if total == 0 {
+                        100.0
+                    } else {
+                        done as f32 * 100.0 / total as f32
+                    };
+                    progress_job.update_progress(
+                        "merging",
+                        percent,
+                        &format!("Processed {done} of {total} files"),
+                    );
+                },
+                &|| job.is_cancel_requested(),
+            )
+            .await;
+
+        match result {
+            Ok(report) => {
+                if report.changed > 0 {
+                    if let Err(error) = app_state
+                        .refresh_after_external_data_change("external_data_merge")
+                        .await
+                    {
+                        tracing::warn!("Failed to refresh caches after data merge: {}", error);
+                    }
+                }
+                let message = format!(
+                    "Merged {} file(s), failed {}",
+                    report.changed, report.failed
+                );
+                match serde_json::to_value(&report) {
+                    Ok(value) => job.complete(&message, Some(value)),
+                    Err(error) => job.fail(&format!("Failed to serialize report: {}", error)),
+                }
+            }
+            Err(DomainError::Cancelled(_)) => job.mark_cancelled(),
+            Err(error) => job.fail(&error.to_string()),
+        }
+    });
+
+    Ok(job_id)
+}
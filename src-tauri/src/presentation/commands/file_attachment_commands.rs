@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::file_attachment_dto::{
+    FileAttachmentTextDto, UploadFileAttachmentDto,
+};
+use crate::domain::models::file_attachment::{FileAttachment, FileAttachmentScope};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn upload_file_attachment(
+    app_state: State<'_, Arc<AppState>>,
+    dto: UploadFileAttachmentDto,
+) -> Result<FileAttachment, CommandError> {
+    log_command(format!("upload_file_attachment, name: {}", dto.name));
+
+    app_state
+        .file_attachment_service
+        .upload_attachment(dto)
+        .await
+        .map_err(map_command_error("Failed to upload file attachment"))
+}
+
+#[tauri::command]
+pub async fn list_file_attachments(
+    app_state: State<'_, Arc<AppState>>,
+    scope: FileAttachmentScope,
+) -> Result<Vec<FileAttachment>, CommandError> {
+    log_command(format!("list_file_attachments, scope: {:?}", scope));
+
+    app_state
+        .file_attachment_service
+        .list_attachments(&scope)
+        .await
+        .map_err(map_command_error("Failed to list file attachments"))
+}
+
+#[tauri::command]
+pub async fn delete_file_attachment(
+    app_state: State<'_, Arc<AppState>>,
+    url: String,
+) -> Result<(), CommandError> {
+    log_command(format!("delete_file_attachment, url: {}", url));
+
+    app_state
+        .file_attachment_service
+        .delete_attachment(&url)
+        .await
+        .map_err(map_command_error("Failed to delete file attachment"))
+}
+
+#[tauri::command]
+pub async fn extract_file_attachment_text(
+    app_state: State<'_, Arc<AppState>>,
+    url: String,
+) -> Result<FileAttachmentTextDto, CommandError> {
+    log_command(format!("extract_file_attachment_text, url: {}", url));
+
+    app_state
+        .file_attachment_service
+        .extract_attachment_text(&url)
+        .await
+        .map_err(map_command_error("Failed to extract file attachment text"))
+}
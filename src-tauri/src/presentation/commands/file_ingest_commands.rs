@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::file_ingest_dto::{IngestFileResultDto, IngestFilesDto};
+use crate::presentation::commands::helpers::log_command;
+use crate::presentation::errors::CommandError;
+
+/// Imports dropped files, detecting what each one is. Per-file failures are reported
+/// in the results rather than failing the command.
+#[tauri::command]
+pub async fn ingest_files(
+    app_state: State<'_, Arc<AppState>>,
+    dto: IngestFilesDto,
+) -> Result<Vec<IngestFileResultDto>, CommandError> {
+    log_command(format!("ingest_files, {} file(s)", dto.paths.len()));
+
+    Ok(app_state.file_ingest_service.ingest_files(dto).await)
+}
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::global_search_dto::GlobalSearchHitDto;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn global_search(
+    query: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<GlobalSearchHitDto>, CommandError> {
+    log_command(format!("global_search {}", query));
+
+    app_state
+        .global_search_service
+        .search(&query)
+        .await
+        .map_err(map_command_error("Failed to run global search"))
+}
@@ -10,7 +10,8 @@ use crate::application::dto::chat_dto::{
 };
 use crate::application::errors::ApplicationError;
 use crate::domain::repositories::chat_types::{
-    ChatPayloadChunk, ChatPayloadCursor, ChatPayloadTail,
+    ChatMessageCursor, ChatMessageLocateQuery, ChatPayloadChunk, ChatPayloadCursor,
+    ChatPayloadForwardChunk, ChatPayloadTail,
 };
 use crate::presentation::commands::helpers::{log_command, map_command_error};
 use crate::presentation::errors::CommandError;
@@ -146,6 +147,43 @@ pub async fn get_group_chat_payload_before(
         )))
 }
 
+#[tauri::command]
+pub async fn get_group_chat_payload_after(
+    id: String,
+    cursor: ChatPayloadCursor,
+    max_lines: usize,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ChatPayloadForwardChunk, CommandError> {
+    log_command(format!("get_group_chat_payload_after {}", id));
+
+    app_state
+        .group_chat_service
+        .get_group_chat_payload_after_lines(&id, cursor, max_lines)
+        .await
+        .map_err(map_command_error(format!(
+            "Failed to get group chat payload after {}",
+            id
+        )))
+}
+
+#[tauri::command]
+pub async fn locate_group_chat_message(
+    id: String,
+    query: ChatMessageLocateQuery,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Option<ChatMessageCursor>, CommandError> {
+    log_command(format!("locate_group_chat_message {}", id));
+
+    app_state
+        .group_chat_service
+        .locate_group_chat_message(&id, &query)
+        .await
+        .map_err(map_command_error(format!(
+            "Failed to locate group chat message {}",
+            id
+        )))
+}
+
 #[tauri::command]
 pub async fn get_group_chat_payload_before_pages(
     id: String,
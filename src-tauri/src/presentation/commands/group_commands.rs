@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use tauri::State;
@@ -6,6 +7,7 @@ use crate::app::AppState;
 use crate::application::dto::group_dto::{
     CreateGroupDto, DeleteGroupDto, GroupDto, UpdateGroupDto,
 };
+use crate::domain::models::avatar::CropInfo;
 use crate::presentation::commands::helpers::{log_command, map_command_error};
 use crate::presentation::errors::CommandError;
 
@@ -82,6 +84,51 @@ pub async fn delete_group(
         .map_err(map_command_error("Failed to delete group"))
 }
 
+#[tauri::command]
+pub async fn update_group_avatar(
+    id: String,
+    file_path: String,
+    crop: Option<String>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<GroupDto, CommandError> {
+    log_command(format!("update_group_avatar {} {}", id, file_path));
+
+    let crop = match crop {
+        Some(crop) => Some(serde_json::from_str::<CropInfo>(&crop).map_err(|error| {
+            CommandError::BadRequest(format!("Invalid crop information: {}", error))
+        })?),
+        None => None,
+    };
+
+    app_state
+        .group_service
+        .update_group_avatar(&id, &PathBuf::from(file_path), crop)
+        .await
+        .map(GroupDto::from)
+        .map_err(map_command_error(format!(
+            "Failed to update avatar of group {}",
+            id
+        )))
+}
+
+#[tauri::command]
+pub async fn delete_group_avatar(
+    id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<GroupDto, CommandError> {
+    log_command(format!("delete_group_avatar {}", id));
+
+    app_state
+        .group_service
+        .delete_group_avatar(&id)
+        .await
+        .map(GroupDto::from)
+        .map_err(map_command_error(format!(
+            "Failed to delete avatar of group {}",
+            id
+        )))
+}
+
 #[tauri::command]
 pub async fn get_group_chat_paths(
     app_state: State<'_, Arc<AppState>>,
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use tauri::State;
+
+use crate::app::AppState;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn horde_get_status(app_state: State<'_, Arc<AppState>>) -> Result<bool, CommandError> {
+    log_command("horde_get_status");
+
+    app_state
+        .horde_service
+        .get_status()
+        .await
+        .map_err(map_command_error("Failed to check Horde status"))
+}
+
+#[tauri::command]
+pub async fn horde_get_text_models(
+    force: Option<bool>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<Value>, CommandError> {
+    log_command("horde_get_text_models");
+
+    app_state
+        .horde_service
+        .get_text_models(force.unwrap_or(false))
+        .await
+        .map_err(map_command_error("Failed to get Horde models"))
+}
+
+#[tauri::command]
+pub async fn horde_get_text_workers(
+    force: Option<bool>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<Value>, CommandError> {
+    log_command("horde_get_text_workers");
+
+    app_state
+        .horde_service
+        .get_text_workers(force.unwrap_or(false))
+        .await
+        .map_err(map_command_error("Failed to get Horde workers"))
+}
+
+#[tauri::command]
+pub async fn horde_get_user_info(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command("horde_get_user_info");
+
+    app_state
+        .horde_service
+        .get_user_info()
+        .await
+        .map_err(map_command_error("Failed to get Horde user info"))
+}
+
+#[tauri::command]
+pub async fn horde_submit_text(
+    payload: Value,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command("horde_submit_text");
+
+    app_state
+        .horde_service
+        .submit_text(payload)
+        .await
+        .map_err(map_command_error("Horde generation failed"))
+}
+
+#[tauri::command]
+pub async fn horde_get_task_status(
+    task_id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command(format!("horde_get_task_status {}", task_id));
+
+    app_state
+        .horde_service
+        .get_task_status(&task_id)
+        .await
+        .map_err(map_command_error("Failed to get Horde task status"))
+}
+
+#[tauri::command]
+pub async fn horde_cancel_task(
+    task_id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command(format!("horde_cancel_task {}", task_id));
+
+    app_state
+        .horde_service
+        .cancel_task(&task_id)
+        .await
+        .map_err(map_command_error("Failed to cancel Horde task"))
+}
+
+#[tauri::command]
+pub async fn horde_generate_text(
+    request_id: String,
+    payload: Value,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command(format!("horde_generate_text {}", request_id));
+
+    app_state
+        .horde_service
+        .generate_text(&request_id, payload)
+        .await
+        .map_err(map_command_error("Horde generation failed"))
+}
+
+#[tauri::command]
+pub async fn horde_cancel_generation(
+    request_id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<bool, CommandError> {
+    log_command(format!("horde_cancel_generation {}", request_id));
+
+    Ok(app_state.horde_service.cancel_generation(&request_id).await)
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use tauri::{State, WebviewWindow};
+
+use crate::app::AppState;
+use crate::application::dto::idle_generation_dto::ArmIdleGenerationDto;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn arm_idle_generation(
+    mut dto: ArmIdleGenerationDto,
+    webview_window: WebviewWindow,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<bool, CommandError> {
+    log_command(format!("arm_idle_generation {}", dto.chat_id));
+
+    app_state
+        .summarize_service
+        .apply_chat_summary(&mut dto.request.payload)
+        .await
+        .map_err(map_command_error("Failed to arm idle generation"))?;
+    app_state
+        .idle_generation_service
+        .arm(dto, webview_window.label().to_string())
+        .await
+        .map_err(map_command_error("Failed to arm idle generation"))
+}
+
+#[tauri::command]
+pub async fn cancel_idle_generation(
+    chat_id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<bool, CommandError> {
+    log_command(format!("cancel_idle_generation {}", chat_id));
+
+    Ok(app_state.idle_generation_service.cancel(&chat_id))
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::app::job_manager::JobStatus;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub fn list_jobs(app_state: State<'_, Arc<AppState>>) -> Result<Vec<JobStatus>, CommandError> {
+    log_command("list_jobs");
+
+    app_state
+        .job_manager
+        .list()
+        .map_err(map_command_error("Failed to list jobs"))
+}
+
+#[tauri::command]
+pub fn get_job_status(
+    app_state: State<'_, Arc<AppState>>,
+    job_id: String,
+) -> Result<JobStatus, CommandError> {
+    log_command(format!("get_job_status {}", job_id));
+
+    app_state
+        .job_manager
+        .get(&job_id)
+        .map_err(map_command_error("Failed to get job status"))
+}
+
+#[tauri::command]
+pub fn cancel_job(
+    app_state: State<'_, Arc<AppState>>,
+    job_id: String,
+) -> Result<JobStatus, CommandError> {
+    log_command(format!("cancel_job {}", job_id));
+
+    app_state
+        .job_manager
+        .cancel(&job_id)
+        .map_err(map_command_error("Failed to cancel job"))
+}
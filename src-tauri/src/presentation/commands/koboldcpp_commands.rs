@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use tauri::{State, ipc::Channel};
+
+use crate::app::AppState;
+use crate::application::services::koboldcpp_service::KoboldCppService;
+use crate::presentation::commands::chat_completion_commands::ChatCompletionStreamEvent;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn koboldcpp_get_status(
+    api_server: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command("koboldcpp_get_status");
+
+    app_state
+        .koboldcpp_service
+        .get_status(&api_server)
+        .await
+        .map_err(map_command_error("Failed to get KoboldCpp status"))
+}
+
+#[tauri::command]
+pub async fn koboldcpp_get_props(
+    api_server: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command("koboldcpp_get_props");
+
+    app_state
+        .koboldcpp_service
+        .get_props(&api_server)
+        .await
+        .map_err(map_command_error("Failed to get KoboldCpp properties"))
+}
+
+#[tauri::command]
+pub async fn koboldcpp_generate(
+    request_id: String,
+    body: Value,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    let request_id = request_id.trim().to_string();
+    validate_request_id(&request_id)?;
+    log_command(format!("koboldcpp_generate {}", request_id));
+
+    app_state
+        .koboldcpp_service
+        .generate(&request_id, body)
+        .await
+        .map_err(map_command_error("KoboldCpp generation failed"))
+}
+
+#[tauri::command]
+pub async fn koboldcpp_start_stream(
+    stream_id: String,
+    body: Value,
+    on_event: Channel<ChatCompletionStreamEvent>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    let stream_id = stream_id.trim().to_string();
+    validate_request_id(&stream_id)?;
+    log_command(format!("koboldcpp_start_stream {}", stream_id));
+
+    let service = app_state.koboldcpp_service.clone();
+    let cancel = service.register_stream(&stream_id).await;
+
+    tauri::async_runtime::spawn(run_stream(service, stream_id, body, cancel, on_event));
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn koboldcpp_cancel_request(
+    request_id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    let request_id = request_id.trim().to_string();
+    validate_request_id(&request_id)?;
+    log_command(format!("koboldcpp_cancel_request {}", request_id));
+
+    app_state
+        .koboldcpp_service
+        .cancel_request(&request_id)
+        .await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn koboldcpp_count_tokens(
+    api_server: String,
+    text: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command("koboldcpp_count_tokens");
+
+    app_state
+        .koboldcpp_service
+        .count_tokens(&api_server, &text)
+        .await
+        .map_err(map_command_error("KoboldCpp token count failed"))
+}
+
+async fn run_stream(
+    service: Arc<KoboldCppService>,
+    stream_id: String,
+    body: Value,
+    cancel: tokio::sync::watch::Receiver<bool>,
+    on_event: Channel<ChatCompletionStreamEvent>,
+) {
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<String>();
+    let generation_task = tauri::async_runtime::spawn({
+        let service = service.clone();
+        let stream_id = stream_id.clone();
+        async move {
+            service
+                .generate_stream(&stream_id, body, sender, cancel)
+                .await
+        }
+    });
+
+    while let Some(chunk) = receiver.recv().await {
+        if on_event
+            .send(ChatCompletionStreamEvent::Chunk { data: chunk })
+            .is_err()
+        {
+            service.cancel_request(&stream_id).await;
+            break;
+        }
+    }
+
+    let generation_result = match generation_task.await {
+        Ok(result) => result,
+        Err(error) => Err(crate::application::errors::ApplicationError::InternalError(
+            format!("Streaming task join failed: {error}"),
+        )),
+    };
+
+    service.complete_stream(&stream_id).await;
+
+    let _ = match generation_result {
+        Ok(()) => on_event.send(ChatCompletionStreamEvent::Done),
+        Err(error) => on_event.send(ChatCompletionStreamEvent::Error {
+            message: CommandError::from(error).to_string(),
+            details: None,
+        }),
+    };
+}
+
+fn validate_request_id(request_id: &str) -> Result<(), CommandError> {
+    if request_id.is_empty() || request_id.len() > 128 {
+        return Err(CommandError::BadRequest(
+            "Invalid request id length".to_string(),
+        ));
+    }
+
+    if !request_id
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+    {
+        return Err(CommandError::BadRequest(
+            "Invalid request id characters".to_string(),
+        ));
+    }
+
+    Ok(())
+}
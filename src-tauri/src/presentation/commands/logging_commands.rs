@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tauri::{AppHandle, State};
+use tracing::Level;
+
+use crate::infrastructure::logging::crash_reports::{CrashReport, CrashReportStore};
+use crate::infrastructure::logging::dev_bundle::export_backend_logs_bundle;
+use crate::infrastructure::logging::log_query::{LogQuery, LogRecord, query_log_files};
+use crate::infrastructure::logging::logger;
+use crate::infrastructure::logging::performance_trace::PerformanceTracer;
+use crate::infrastructure::paths::RuntimePaths;
+use crate::presentation::commands::helpers::log_command;
+use crate::presentation::errors::CommandError;
+
+fn parse_level(level: Option<String>) -> Result<Option<Level>, CommandError> {
+    let Some(level) = level.map(|value| value.trim().to_string()) else {
+        return Ok(None);
+    };
+    if level.is_empty() {
+        return Ok(None);
+    }
+
+    level
+        .parse::<Level>()
+        .map(Some)
+        .map_err(|_| CommandError::BadRequest(format!("Unknown log level: {level}")))
+}
+
+fn parse_since(since: Option<String>) -> Result<Option<DateTime<Utc>>, CommandError> {
+    let Some(since) = since.map(|value| value.trim().to_string()) else {
+        return Ok(None);
+    };
+    if since.is_empty() {
+        return Ok(None);
+    }
+
+    DateTime::parse_from_rfc3339(&since)
+        .map(|value| Some(value.with_timezone(&Utc)))
+        .map_err(|error| CommandError::BadRequest(format!("Invalid since timestamp: {error}")))
+}
+
+#[tauri::command]
+pub async fn query_logs(
+    level: Option<String>,
+    since: Option<String>,
+    contains: Option<String>,
+    limit: Option<u32>,
+    runtime_paths: State<'_, RuntimePaths>,
+) -> Result<Vec<LogRecord>, CommandError> {
+    log_command("query_logs");
+
+    let query = LogQuery {
+        level: parse_level(level)?,
+        since: parse_since(since)?,
+        contains,
+        limit: limit.map(|value| value as usize),
+    };
+    let log_root = runtime_paths.log_root.clone();
+
+    tauri::async_runtime::spawn_blocking(move || query_log_files(&log_root, &query))
+        .await
+        .map_err(|error| {
+            CommandError::InternalServerError(format!("Log query task join error: {}", error))
+        })?
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+pub async fn export_logs_bundle(
+    app: AppHandle,
+    runtime_paths: State<'_, RuntimePaths>,
+) -> Result<String, CommandError> {
+    log_command("export_logs_bundle");
+
+    let readme_text = [
+        "TauriTavern backend logs",
+        "",
+        "- tauritavern.log.*: daily rotated backend logs (may include forwarded frontend logs).",
+        "",
+        "Review files before sharing.",
+        "",
+    ]
+    .join("\n");
+    let runtime_paths = runtime_paths.inner().clone();
+
+    let output_path = tauri::async_runtime::spawn_blocking(move || {
+        export_backend_logs_bundle(&app, &runtime_paths, &readme_text)
+    })
+    .await
+    .map_err(|error| {
+        CommandError::InternalServerError(format!("Export logs task join error: {}", error))
+    })??;
+
+    Ok(output_path.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub fn set_log_level(level: String) -> Result<(), CommandError> {
+    log_command(format!("set_log_level {}", level));
+
+    logger::set_log_level(&level).map_err(CommandError::BadRequest)
+}
+
+/// Returns the recorded command spans as Chrome trace JSON. Empty unless
+/// `dev.performance_tracing` has been enabled.
+#[tauri::command]
+pub async fn get_performance_trace(
+    performance_tracer: State<'_, Arc<PerformanceTracer>>,
+) -> Result<serde_json::Value, CommandError> {
+    log_command("get_performance_trace");
+
+    Ok(performance_tracer.chrome_trace())
+}
+
+#[tauri::command]
+pub async fn list_crash_reports(
+    crash_reports: State<'_, Arc<CrashReportStore>>,
+) -> Result<Vec<CrashReport>, CommandError> {
+    log_command("list_crash_reports");
+
+    crash_reports.list().map_err(CommandError::from)
+}
+
+#[tauri::command]
+pub async fn delete_crash_report(
+    id: String,
+    crash_reports: State<'_, Arc<CrashReportStore>>,
+) -> Result<(), CommandError> {
+    log_command(format!("delete_crash_report {}", id));
+
+    crash_reports.delete(&id).map_err(CommandError::from)
+}
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::macro_dto::SubstituteMacrosDto;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn substitute_macros(
+    dto: SubstituteMacrosDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<String, CommandError> {
+    log_command("substitute_macros");
+
+    app_state
+        .macro_engine
+        .substitute(&dto.text, &dto.context)
+        .await
+        .map_err(map_command_error("Failed to substitute macros"))
+}
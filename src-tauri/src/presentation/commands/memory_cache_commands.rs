@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::domain::models::memory_cache::MemoryCacheStats;
+use crate::presentation::commands::helpers::log_command;
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn get_cache_stats(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<MemoryCacheStats>, CommandError> {
+    log_command("get_cache_stats");
+
+    Ok(app_state.memory_cache_service.get_cache_stats().await)
+}
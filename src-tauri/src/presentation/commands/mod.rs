@@ -1,44 +1,82 @@
 // Tauri commands
 pub mod agent_commands;
 pub mod asset_commands;
+pub mod author_note_commands;
 pub mod avatar_commands;
 pub mod background_commands;
+pub mod backup_schedule_commands;
 pub mod bootstrap_commands;
 pub mod bridge;
+pub mod bridge_server_commands;
+pub mod changelog_commands;
+pub mod character_asset_commands;
 pub mod character_commands;
+pub mod character_generation_overrides_commands;
+pub mod character_note_commands;
+pub mod character_prompt_overrides_commands;
 pub mod chat_api_commands;
 pub mod chat_commands;
 pub mod chat_completion_commands;
+pub mod chat_metadata_commands;
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+pub mod chat_window_commands;
+pub mod clipboard_commands;
+pub mod connection_monitor_commands;
+pub mod connection_profile_commands;
 pub mod content_commands;
 pub mod data_archive_commands;
+pub mod data_doctor_commands;
+pub mod deep_link_commands;
 pub mod dev_logging_commands;
+pub mod expression_commands;
 pub mod extension_commands;
 pub mod extension_store_commands;
+pub mod external_data_merge_commands;
+pub mod file_attachment_commands;
 pub mod file_commands;
+pub mod file_ingest_commands;
+pub mod global_search_commands;
 pub mod group_chat_api_commands;
 pub mod group_chat_commands;
 pub mod group_commands;
 pub mod helpers;
+pub mod horde_commands;
+pub mod idle_generation_commands;
 pub mod image_commands;
 pub mod image_metadata_commands;
 #[cfg(target_os = "ios")]
 pub mod ios_file_bridge_commands;
+pub mod job_commands;
+pub mod koboldcpp_commands;
 pub mod lan_sync_commands;
 pub mod llm_connection_commands;
+pub mod logging_commands;
+pub mod macro_commands;
+pub mod memory_cache_commands;
 pub mod native_regex_commands;
+pub mod notification_commands;
+pub mod novelai_commands;
 pub mod preset_commands;
 pub mod provider_metadata_commands;
 pub mod quick_reply_commands;
+pub mod recent_items_commands;
 pub mod registry;
 #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
 pub mod runtime_paths_commands;
 pub mod secret_commands;
 pub mod settings_commands;
+pub mod settings_profile_commands;
+pub mod sillytavern_migration_commands;
 pub mod skill_commands;
+pub mod sound_commands;
+pub mod sprite_commands;
 pub mod stable_diffusion_commands;
+pub mod summarize_commands;
 pub mod sync_automation_commands;
 pub mod sync_v2_commands;
+pub mod tag_commands;
 pub mod theme_commands;
+pub mod thumbnail_commands;
 pub mod tokenizer_commands;
 pub mod translate_commands;
 pub mod tt_sync_commands;
@@ -46,5 +84,7 @@ pub mod tts_commands;
 pub mod update_commands;
 pub mod upload_staging_commands;
 pub mod user_commands;
+pub mod user_customization_commands;
 pub mod user_directory_commands;
+pub mod variable_commands;
 pub mod world_info_commands;
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+/// Shows a sample generation notification so users can check permissions and system
+/// settings without waiting for a reply in the background.
+#[tauri::command]
+pub async fn send_test_notification(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    log_command("send_test_notification");
+
+    app_state
+        .notification_service
+        .send_test_notification()
+        .map_err(map_command_error("Failed to send test notification"))
+}
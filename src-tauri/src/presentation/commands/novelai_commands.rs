@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use tauri::{State, ipc::Channel};
+
+use crate::app::AppState;
+use crate::application::services::novelai_service::NovelAiService;
+use crate::presentation::commands::chat_completion_commands::ChatCompletionStreamEvent;
+use crate::presentation::commands::helpers::{
+    ensure_ios_policy_allows, log_command, map_command_error,
+};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn novelai_get_status(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command("novelai_get_status");
+
+    app_state
+        .novelai_service
+        .get_status()
+        .await
+        .map_err(map_command_error("Failed to get NovelAI status"))
+}
+
+#[tauri::command]
+pub async fn novelai_generate_text(
+    request_id: String,
+    body: Value,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    let request_id = request_id.trim().to_string();
+    validate_request_id(&request_id)?;
+    log_command(format!("novelai_generate_text {}", request_id));
+
+    app_state
+        .novelai_service
+        .generate_text(&request_id, body)
+        .await
+        .map_err(map_command_error("NovelAI generation failed"))
+}
+
+#[tauri::command]
+pub async fn novelai_start_text_stream(
+    stream_id: String,
+    body: Value,
+    on_event: Channel<ChatCompletionStreamEvent>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    let stream_id = stream_id.trim().to_string();
+    validate_request_id(&stream_id)?;
+    log_command(format!("novelai_start_text_stream {}", stream_id));
+
+    let service = app_state.novelai_service.clone();
+    let cancel = service.register_stream(&stream_id).await;
+
+    tauri::async_runtime::spawn(run_text_stream(service, stream_id, body, cancel, on_event));
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn novelai_cancel_request(
+    request_id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    let request_id = request_id.trim().to_string();
+    validate_request_id(&request_id)?;
+    log_command(format!("novelai_cancel_request {}", request_id));
+
+    app_state.novelai_service.cancel_request(&request_id).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn novelai_generate_image(
+    body: Value,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<String, CommandError> {
+    log_command("novelai_generate_image");
+
+    ensure_ios_policy_allows(
+        &app_state.ios_policy,
+        app_state.ios_policy.capabilities.ai.image_generation,
+        "ai.image_generation",
+    )?;
+
+    app_state
+        .novelai_service
+        .generate_image(body)
+        .await
+        .map_err(map_command_error("NovelAI image generation failed"))
+}
+
+async fn run_text_stream(
+    service: Arc<NovelAiService>,
+    stream_id: String,
+    body: Value,
+    cancel: tokio::sync::watch::Receiver<bool>,
+    on_event: Channel<ChatCompletionStreamEvent>,
+) {
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<String>();
+    let generation_task = tauri::async_runtime::spawn({
+        let service = service.clone();
+        async move { service.generate_text_stream(body, sender, cancel).await }
+    });
+
+    while let Some(chunk) = receiver.recv().await {
+        if chunk.is_empty() {
+            continue;
+        }
+
+        if on_event
+            .send(ChatCompletionStreamEvent::Chunk { data: chunk })
+            .is_err()
+        {
+            generation_task.abort();
+            service.complete_stream(&stream_id).await;
+            return;
+        }
+    }
+
+    let generation_result = match generation_task.await {
+        Ok(result) => result,
+        Err(error) => Err(crate::application::errors::ApplicationError::InternalError(
+            format!("Streaming task join failed: {error}"),
+        )),
+    };
+
+    service.complete_stream(&stream_id).await;
+
+    let _ = match generation_result {
+        Ok(()) => on_event.send(ChatCompletionStreamEvent::Done),
+        Err(error) => on_event.send(ChatCompletionStreamEvent::Error {
+            message: CommandError::from(error).to_string(),
+            details: None,
+        }),
+    };
+}
+
+fn validate_request_id(request_id: &str) -> Result<(), CommandError> {
+    if request_id.is_empty() || request_id.len() > 128 {
+        return Err(CommandError::BadRequest(
+            "Invalid request id length".to_string(),
+        ));
+    }
+
+    if !request_id
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+    {
+        return Err(CommandError::BadRequest(
+            "Invalid request id characters".to_string(),
+        ));
+    }
+
+    Ok(())
+}
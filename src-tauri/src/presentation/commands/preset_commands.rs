@@ -4,10 +4,11 @@ use tauri::State;
 
 use crate::app::AppState;
 use crate::application::dto::preset_dto::{
-    DeleteOpenAIPresetDto, DeleteOpenAIPresetResponseDto, DeletePresetDto, RestorePresetDto,
-    RestorePresetResponseDto, SaveOpenAIPresetDto, SavePresetDto, SavePresetResponseDto,
+    DeleteOpenAIPresetDto, DeleteOpenAIPresetResponseDto, DeletePresetDto, ExportPresetBundleDto,
+    ImportPresetBundleDto, ImportedPresetDto, RestorePresetDto, RestorePresetResponseDto,
+    SaveOpenAIPresetDto, SavePresetDto, SavePresetResponseDto,
 };
-use crate::domain::models::preset::PresetType;
+use crate::domain::models::preset::{PresetBundle, PresetType};
 use crate::infrastructure::logging::logger;
 use crate::presentation::errors::CommandError;
 
@@ -369,3 +370,71 @@ pub async fn get_preset(
         }
     }
 }
+
+/// Export one or more presets as a shareable bundle
+#[tauri::command]
+pub async fn export_preset_bundle(
+    app_state: State<'_, Arc<AppState>>,
+    dto: ExportPresetBundleDto,
+) -> Result<PresetBundle, CommandError> {
+    logger::debug(&format!(
+        "Command: export_preset_bundle, presets: {}",
+        dto.presets.len()
+    ));
+
+    if dto.presets.is_empty() {
+        logger::warn("Preset bundle export requested with no presets");
+        return Err(CommandError::BadRequest(
+            "Select at least one preset to export".to_string(),
+        ));
+    }
+
+    let bundle = app_state
+        .preset_service
+        .export_preset_bundle(&dto.presets)
+        .await
+        .map_err(|e| {
+            logger::error(&format!("Failed to export preset bundle: {}", e));
+            CommandError::from(e)
+        })?;
+
+    logger::info(&format!(
+        "Preset bundle exported with {} preset(s)",
+        bundle.presets.len()
+    ));
+    Ok(bundle)
+}
+
+/// Import a preset bundle, renaming presets whose names are already taken
+#[tauri::command]
+pub async fn import_preset_bundle(
+    app_state: State<'_, Arc<AppState>>,
+    dto: ImportPresetBundleDto,
+) -> Result<Vec<ImportedPresetDto>, CommandError> {
+    logger::debug(&format!(
+        "Command: import_preset_bundle, overwrite: {}",
+        dto.overwrite
+    ));
+
+    if !dto.bundle.is_object() {
+        logger::warn("Preset bundle is not a JSON object");
+        return Err(CommandError::BadRequest(
+            "Preset bundle must be a JSON object".to_string(),
+        ));
+    }
+
+    let imported = app_state
+        .preset_service
+        .import_preset_bundle(dto.bundle, dto.overwrite)
+        .await
+        .map_err(|e| {
+            logger::error(&format!("Failed to import preset bundle: {}", e));
+            CommandError::from(e)
+        })?;
+
+    logger::info(&format!(
+        "Preset bundle imported: {} preset(s)",
+        imported.len()
+    ));
+    Ok(imported)
+}
@@ -33,3 +33,45 @@ pub async fn delete_quick_reply_set(
         .await
         .map_err(map_command_error("Failed to delete quick reply set"))
 }
+
+#[tauri::command]
+pub async fn list_quick_reply_sets(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<Value>, CommandError> {
+    log_command("list_quick_reply_sets");
+
+    app_state
+        .quick_reply_service
+        .list_quick_reply_sets()
+        .await
+        .map_err(map_command_error("Failed to list quick reply sets"))
+}
+
+#[tauri::command]
+pub async fn import_quick_reply_set(
+    content: String,
+    overwrite: Option<bool>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command("import_quick_reply_set");
+
+    app_state
+        .quick_reply_service
+        .import_quick_reply_set(&content, overwrite.unwrap_or(false))
+        .await
+        .map_err(map_command_error("Failed to import quick reply set"))
+}
+
+#[tauri::command]
+pub async fn export_quick_reply_set(
+    name: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<String, CommandError> {
+    log_command(format!("export_quick_reply_set {}", name));
+
+    app_state
+        .quick_reply_service
+        .export_quick_reply_set(&name)
+        .await
+        .map_err(map_command_error("Failed to export quick reply set"))
+}
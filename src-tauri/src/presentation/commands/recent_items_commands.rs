@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::recent_items_dto::RecentItemTargetDto;
+use crate::domain::models::recent_items::RecentItems;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn get_recent_items(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<RecentItems, CommandError> {
+    log_command("get_recent_items");
+
+    app_state
+        .recent_items_service
+        .get_recent_items()
+        .await
+        .map_err(map_command_error("Failed to get recent items"))
+}
+
+#[tauri::command]
+pub async fn record_recent_item(
+    dto: RecentItemTargetDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<RecentItems, CommandError> {
+    log_command("record_recent_item");
+
+    app_state
+        .recent_items_service
+        .record_recent_item(dto)
+        .await
+        .map_err(map_command_error("Failed to record recent item"))
+}
+
+#[tauri::command]
+pub async fn pin_favorite(
+    dto: RecentItemTargetDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<RecentItems, CommandError> {
+    log_command("pin_favorite");
+
+    app_state
+        .recent_items_service
+        .pin_favorite(dto)
+        .await
+        .map_err(map_command_error("Failed to pin favorite"))
+}
+
+#[tauri::command]
+pub async fn unpin_favorite(
+    dto: RecentItemTargetDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<RecentItems, CommandError> {
+    log_command("unpin_favorite");
+
+    app_state
+        .recent_items_service
+        .unpin_favorite(dto)
+        .await
+        .map_err(map_command_error("Failed to unpin favorite"))
+}
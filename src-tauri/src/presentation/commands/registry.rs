@@ -1,7 +1,17 @@
-pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Send + Sync + 'static {
-    tauri::generate_handler![
+use std::sync::Arc;
+use std::time::{Instant, SystemTime};
+
+use tauri::Wry;
+use tauri::ipc::{Invoke, InvokeBody};
+
+use crate::infrastructure::logging::performance_trace::PerformanceTracer;
+
+pub fn invoke_handler() -> impl Fn(Invoke<Wry>) -> bool + Send + Sync + 'static {
+    let handler = tauri::generate_handler![
         // Character commands
         super::character_commands::get_all_characters,
+        super::character_commands::list_characters,
+        super::character_commands::get_character_fields,
         super::character_commands::get_character,
         super::character_commands::create_character,
         super::character_commands::create_character_with_avatar,
@@ -14,12 +24,25 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::character_commands::delete_character,
         super::character_commands::rename_character,
         super::character_commands::duplicate_character,
+        super::character_commands::find_duplicate_characters,
+        super::character_commands::merge_characters,
         super::character_commands::import_character,
+        super::clipboard_commands::import_character_from_clipboard,
         super::character_commands::export_character,
         super::character_commands::export_character_content,
         super::character_commands::update_avatar,
         super::character_commands::get_character_chats_by_id,
         super::character_commands::clear_character_cache,
+        super::character_asset_commands::list_character_assets,
+        super::character_asset_commands::upload_character_asset,
+        super::character_asset_commands::delete_character_asset,
+        super::sound_commands::list_sounds,
+        super::sound_commands::upload_sound,
+        super::sound_commands::delete_sound,
+        super::sprite_commands::list_sprites,
+        super::sprite_commands::upload_sprite_pack,
+        super::sprite_commands::delete_sprite,
+        super::sprite_commands::resolve_sprite,
         // Chat commands
         super::chat_commands::get_all_chats,
         super::chat_commands::get_chat,
@@ -29,6 +52,8 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::chat_commands::rename_chat,
         super::chat_commands::delete_chat,
         super::chat_commands::search_chats,
+        super::chat_commands::search_chats_with_options,
+        super::chat_commands::get_character_chat_stats,
         super::chat_commands::list_chat_summaries,
         super::chat_commands::list_recent_chat_summaries,
         super::chat_commands::import_chat,
@@ -38,14 +63,23 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::chat_commands::get_chat_backup_raw,
         super::chat_commands::delete_chat_backup,
         super::chat_commands::clear_chat_cache,
+        super::chat_commands::compress_chats,
+        super::chat_commands::archive_old_chats,
+        super::chat_commands::unarchive_chat,
+        super::chat_commands::list_archived_chats,
+        super::chat_commands::repair_chat,
+        super::chat_commands::rebuild_chat_index,
         super::chat_commands::get_chat_payload_path,
         super::chat_commands::get_chat_payload_tail,
         super::chat_commands::get_chat_payload_before,
         super::chat_commands::get_chat_payload_before_pages,
+        super::chat_commands::get_chat_payload_after,
+        super::chat_commands::locate_chat_message,
         super::chat_commands::save_chat_payload_windowed,
         super::chat_commands::patch_chat_payload_windowed,
         super::chat_commands::hide_chat_payload_before_cursor,
         super::chat_commands::save_chat_payload_from_file,
+        super::chat_commands::detect_chat_format,
         super::chat_commands::import_character_chats,
         // Group chat commands
         super::group_chat_commands::search_group_chats,
@@ -55,6 +89,8 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::group_chat_commands::get_group_chat_payload_tail,
         super::group_chat_commands::get_group_chat_payload_before,
         super::group_chat_commands::get_group_chat_payload_before_pages,
+        super::group_chat_commands::get_group_chat_payload_after,
+        super::group_chat_commands::locate_group_chat_message,
         super::group_chat_commands::save_group_chat_payload_windowed,
         super::group_chat_commands::patch_group_chat_payload_windowed,
         super::group_chat_commands::hide_group_chat_payload_before_cursor,
@@ -109,19 +145,27 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::user_commands::delete_user,
         // Bootstrap commands
         super::bootstrap_commands::get_bootstrap_snapshot,
+        super::bootstrap_commands::get_startup_report,
         // Settings commands
         super::settings_commands::get_tauritavern_settings,
         super::settings_commands::update_tauritavern_settings,
         #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+        super::settings_commands::set_global_hotkeys,
+        #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
         super::runtime_paths_commands::get_runtime_paths,
         #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
         super::runtime_paths_commands::set_data_root,
+        #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+        super::runtime_paths_commands::set_data_directory,
         super::settings_commands::save_user_settings,
         super::settings_commands::get_sillytavern_settings,
+        super::settings_commands::validate_settings,
         super::settings_commands::create_settings_snapshot,
         super::settings_commands::get_settings_snapshots,
         super::settings_commands::load_settings_snapshot,
         super::settings_commands::restore_settings_snapshot,
+        super::settings_commands::diff_settings_snapshot,
+        super::settings_commands::apply_settings_patch,
         // Dev logging commands
         super::dev_logging_commands::devlog_append_frontend_logs,
         super::dev_logging_commands::devlog_set_backend_log_stream_enabled,
@@ -131,6 +175,13 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::dev_logging_commands::devlog_get_llm_api_log_index,
         super::dev_logging_commands::devlog_get_llm_api_log_preview,
         super::dev_logging_commands::devlog_get_llm_api_log_raw,
+        // Log viewer commands
+        super::logging_commands::query_logs,
+        super::logging_commands::export_logs_bundle,
+        super::logging_commands::set_log_level,
+        super::logging_commands::get_performance_trace,
+        super::logging_commands::list_crash_reports,
+        super::logging_commands::delete_crash_report,
         // World info commands
         super::world_info_commands::get_world_info,
         super::world_info_commands::get_world_infos_batch,
@@ -152,6 +203,8 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::secret_commands::delete_secret,
         super::secret_commands::rotate_secret,
         super::secret_commands::rename_secret,
+        super::secret_commands::export_secrets,
+        super::secret_commands::import_secrets,
         // Provider metadata commands
         super::provider_metadata_commands::get_openrouter_model_providers,
         super::provider_metadata_commands::get_openrouter_credits,
@@ -165,6 +218,57 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::llm_connection_commands::load_llm_connection,
         super::llm_connection_commands::save_llm_connection,
         super::llm_connection_commands::delete_llm_connection,
+        // Connection profile commands
+        super::connection_profile_commands::list_connection_profiles,
+        super::connection_profile_commands::save_connection_profile,
+        super::connection_profile_commands::activate_connection_profile,
+        super::settings_profile_commands::list_settings_profiles,
+        super::settings_profile_commands::save_settings_profile,
+        super::settings_profile_commands::activate_settings_profile,
+        // Connection monitor commands
+        super::connection_monitor_commands::get_connection_health,
+        // Macro commands
+        super::macro_commands::substitute_macros,
+        // Memory cache commands
+        super::memory_cache_commands::get_cache_stats,
+        // Variable commands
+        super::chat_metadata_commands::get_chat_metadata,
+        super::chat_metadata_commands::update_chat_metadata,
+        super::variable_commands::list_chat_variables,
+        super::variable_commands::get_chat_variable,
+        super::variable_commands::set_chat_variable,
+        super::variable_commands::flush_chat_variable,
+        super::variable_commands::list_global_variables,
+        super::variable_commands::get_global_variable,
+        super::variable_commands::set_global_variable,
+        super::variable_commands::flush_global_variable,
+        // Author's Note commands
+        super::author_note_commands::get_author_note,
+        super::author_note_commands::set_author_note,
+        super::author_note_commands::clear_author_note,
+        super::author_note_commands::resolve_author_note,
+        // Chat summary commands
+        super::summarize_commands::summarize_chat_segment,
+        super::summarize_commands::get_chat_summary,
+        // Idle generation commands
+        super::idle_generation_commands::arm_idle_generation,
+        super::idle_generation_commands::cancel_idle_generation,
+        // Notification commands
+        super::notification_commands::send_test_notification,
+        // Character prompt override commands
+        super::character_prompt_overrides_commands::get_character_prompt_overrides,
+        super::character_prompt_overrides_commands::set_character_prompt_overrides,
+        super::character_prompt_overrides_commands::clear_character_prompt_overrides,
+        // Character generation override commands
+        super::character_generation_overrides_commands::get_character_generation_overrides,
+        super::character_generation_overrides_commands::set_character_generation_overrides,
+        super::character_generation_overrides_commands::clear_character_generation_overrides,
+        // Character note commands
+        super::character_note_commands::get_character_note,
+        super::character_note_commands::save_character_note,
+        super::character_note_commands::search_character_notes,
+        // Global search commands
+        super::global_search_commands::global_search,
         // Content commands
         super::content_commands::initialize_default_content,
         super::content_commands::is_default_content_initialized,
@@ -185,6 +289,13 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::data_archive_commands::export_user_backup_archive,
         super::data_archive_commands::save_user_backup_archive,
         super::data_archive_commands::cleanup_user_backup_archive,
+        super::data_doctor_commands::run_data_doctor,
+        super::sillytavern_migration_commands::migrate_from_sillytavern,
+        super::external_data_merge_commands::merge_external_data_root,
+        // Background job commands
+        super::job_commands::list_jobs,
+        super::job_commands::get_job_status,
+        super::job_commands::cancel_job,
         // iOS file bridge commands
         #[cfg(target_os = "ios")]
         super::ios_file_bridge_commands::ios_import_data_archive_from_picker,
@@ -197,7 +308,9 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         // Extension commands
         super::extension_commands::get_extensions,
         super::extension_commands::install_extension,
+        super::extension_commands::install_extension_from_path,
         super::extension_commands::update_extension,
+        super::extension_commands::update_all_extensions,
         super::extension_commands::delete_extension,
         super::extension_commands::get_extension_version,
         super::extension_commands::move_extension,
@@ -214,10 +327,24 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::lan_sync_commands::lan_sync_push_to_device,
         super::lan_sync_commands::lan_sync_set_sync_mode,
         super::lan_sync_commands::lan_sync_clear_sync_mode_override,
+        // Bridge server commands
+        super::bridge_server_commands::bridge_server_get_status,
+        super::bridge_server_commands::bridge_server_enable,
+        super::bridge_server_commands::bridge_server_disable,
+        super::bridge_server_commands::bridge_server_regenerate_token,
+        super::bridge_server_commands::bridge_server_start_pairing,
+        super::bridge_server_commands::bridge_server_cancel_pairing,
+        super::bridge_server_commands::bridge_server_list_devices,
+        super::bridge_server_commands::bridge_server_remove_device,
         // Sync automation commands
         super::sync_automation_commands::sync_automation_get_config,
         super::sync_automation_commands::sync_automation_update_config,
         super::sync_automation_commands::sync_automation_get_status,
+        // Backup schedule commands
+        super::backup_schedule_commands::backup_schedule_get_config,
+        super::backup_schedule_commands::backup_schedule_update_config,
+        super::backup_schedule_commands::backup_schedule_get_status,
+        super::backup_schedule_commands::backup_schedule_run_now,
         // Shared sync v2 commands
         super::sync_v2_commands::sync_v2_get_dataset_catalog,
         // TT-Sync v2 commands
@@ -237,6 +364,11 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::file_commands::read_user_file_asset,
         super::file_commands::delete_user_file,
         super::file_commands::verify_user_files,
+        super::file_attachment_commands::upload_file_attachment,
+        super::file_attachment_commands::list_file_attachments,
+        super::file_attachment_commands::delete_file_attachment,
+        super::file_attachment_commands::extract_file_attachment_text,
+        super::file_ingest_commands::ingest_files,
         // Image commands
         super::image_commands::upload_user_image,
         super::image_commands::list_user_images,
@@ -253,6 +385,8 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::group_commands::create_group,
         super::group_commands::update_group,
         super::group_commands::delete_group,
+        super::group_commands::update_group_avatar,
+        super::group_commands::delete_group_avatar,
         super::group_commands::get_group_chat_paths,
         super::group_commands::clear_group_cache,
         // Background commands
@@ -263,6 +397,8 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::background_commands::upload_background,
         super::background_commands::upload_background_from_path,
         super::background_commands::read_thumbnail_asset,
+        super::thumbnail_commands::regenerate_thumbnails,
+        super::thumbnail_commands::get_thumbnail,
         super::image_metadata_commands::get_background_folders,
         super::image_metadata_commands::create_image_metadata_folder,
         super::image_metadata_commands::update_image_metadata_folder,
@@ -273,6 +409,13 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         // Theme commands
         super::theme_commands::save_theme,
         super::theme_commands::delete_theme,
+        super::theme_commands::list_themes,
+        super::theme_commands::export_theme,
+        super::theme_commands::import_theme,
+        super::user_customization_commands::get_custom_css,
+        super::user_customization_commands::save_custom_css,
+        super::user_customization_commands::list_user_scripts,
+        super::user_customization_commands::save_user_script,
         // Preset commands
         super::preset_commands::save_preset,
         super::preset_commands::delete_preset,
@@ -282,9 +425,26 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::preset_commands::list_presets,
         super::preset_commands::preset_exists,
         super::preset_commands::get_preset,
+        super::preset_commands::export_preset_bundle,
+        super::preset_commands::import_preset_bundle,
         // Quick reply commands
         super::quick_reply_commands::save_quick_reply_set,
         super::quick_reply_commands::delete_quick_reply_set,
+        super::quick_reply_commands::list_quick_reply_sets,
+        super::quick_reply_commands::import_quick_reply_set,
+        super::quick_reply_commands::export_quick_reply_set,
+        // Recent items commands
+        super::recent_items_commands::get_recent_items,
+        super::recent_items_commands::record_recent_item,
+        super::recent_items_commands::pin_favorite,
+        super::recent_items_commands::unpin_favorite,
+        super::tag_commands::list_tags,
+        super::tag_commands::create_tag,
+        super::tag_commands::rename_tag,
+        super::tag_commands::delete_tag,
+        super::tag_commands::assign_tag,
+        super::tag_commands::unassign_tag,
+        super::tag_commands::import_tags_from_settings,
         // Agent runtime commands
         super::agent_commands::start_agent_run,
         super::agent_commands::prepare_agent_prompt_assembly,
@@ -324,15 +484,55 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::skill_commands::retarget_skill_scope,
         // Chat completion commands
         super::chat_completion_commands::get_chat_completions_status,
+        super::chat_completion_commands::get_openrouter_generation_cost,
+        super::chat_completion_commands::get_model_capabilities,
         super::chat_completion_commands::generate_chat_completion,
+        super::chat_completion_commands::preview_generate_payload,
+        super::chat_completion_commands::regenerate_with_same_settings,
+        super::chat_completion_commands::get_generation_snapshot,
         super::chat_completion_commands::start_chat_completion_stream,
+        super::chat_completion_commands::generate_multi,
+        super::chat_completion_commands::get_prompt_itemization,
         super::chat_completion_commands::cancel_chat_completion_stream,
         super::chat_completion_commands::cancel_chat_completion_generation,
+        super::chat_completion_commands::register_tool,
+        super::chat_completion_commands::unregister_tool,
+        super::chat_completion_commands::list_registered_tools,
+        super::chat_completion_commands::submit_tool_result,
+        super::chat_completion_commands::register_chat_completion_hook,
+        super::chat_completion_commands::unregister_chat_completion_hook,
+        super::chat_completion_commands::list_chat_completion_hooks,
+        super::chat_completion_commands::submit_chat_completion_hook_result,
+        super::chat_completion_commands::start_chat_completion_tool_run,
         // Stable diffusion (local chain) commands
         super::stable_diffusion_commands::sd_handle,
         super::stable_diffusion_commands::cancel_sd_request,
+        // Horde commands
+        super::horde_commands::horde_get_status,
+        super::horde_commands::horde_get_text_models,
+        super::horde_commands::horde_get_text_workers,
+        super::horde_commands::horde_get_user_info,
+        super::horde_commands::horde_submit_text,
+        super::horde_commands::horde_get_task_status,
+        super::horde_commands::horde_cancel_task,
+        super::horde_commands::horde_generate_text,
+        super::horde_commands::horde_cancel_generation,
+        // NovelAI commands
+        super::novelai_commands::novelai_get_status,
+        super::novelai_commands::novelai_generate_text,
+        super::novelai_commands::novelai_start_text_stream,
+        super::novelai_commands::novelai_cancel_request,
+        super::novelai_commands::novelai_generate_image,
+        // KoboldCpp commands
+        super::koboldcpp_commands::koboldcpp_get_status,
+        super::koboldcpp_commands::koboldcpp_get_props,
+        super::koboldcpp_commands::koboldcpp_generate,
+        super::koboldcpp_commands::koboldcpp_start_stream,
+        super::koboldcpp_commands::koboldcpp_cancel_request,
+        super::koboldcpp_commands::koboldcpp_count_tokens,
         // Translate commands
         super::translate_commands::translate_text,
+        super::expression_commands::classify_expression,
         // TTS commands
         super::tts_commands::tts_handle,
         // Tokenizer commands
@@ -341,10 +541,18 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::tokenizer_commands::encode_openai_tokens,
         super::tokenizer_commands::decode_openai_tokens,
         super::tokenizer_commands::build_openai_logit_bias,
+        super::tokenizer_commands::analyze_character_tokens,
         // Native regex commands
         super::native_regex_commands::apply_native_regex_batch,
         // Update commands
         super::update_commands::check_for_update,
+        super::update_commands::check_for_updates,
+        super::changelog_commands::get_changelog_since,
+        super::deep_link_commands::take_pending_deep_links,
+        #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+        super::chat_window_commands::open_chat_window,
+        #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+        super::chat_window_commands::get_chat_window_binding,
         // Bridge commands
         super::bridge::emit_event,
         super::bridge::get_version,
@@ -357,5 +565,52 @@ pub fn invoke_handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Sen
         super::bridge::get_notification_permission_state,
         super::bridge::request_notification_permission,
         super::bridge::show_system_notification,
-    ]
+    ];
+
+    move |invoke| match PerformanceTracer::active() {
+        Some(tracer) => trace_invoke(tracer, &handler, invoke),
+        None => handler(invoke),
+    }
+}
+
+/// Runs `handler` and records its span. Async commands hand their future to the runtime,
+/// so their span covers argument handling and dispatch on the IPC thread.
+fn trace_invoke(
+    tracer: &Arc<PerformanceTracer>,
+    handler: &impl Fn(Invoke<Wry>) -> bool,
+    invoke: Invoke<Wry>,
+) -> bool {
+    let command = invoke.message.command().to_string();
+    let payload_bytes = payload_size(invoke.message.payload());
+
+    let started_at = SystemTime::now();
+    let started = Instant::now();
+    let handled = handler(invoke);
+    tracer.record(&command, started_at, started.elapsed(), payload_bytes);
+
+    handled
+}
+
+fn payload_size(payload: &InvokeBody) -> u64 {
+    struct ByteCounter(u64);
+
+    impl std::io::Write for ByteCounter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0 += buf.len() as u64;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    match payload {
+        InvokeBody::Json(value) => {
+            let mut counter = ByteCounter(0);
+            let _ = serde_json::to_writer(&mut counter, value);
+            counter.0
+        }
+        InvokeBody::Raw(bytes) => bytes.len() as u64,
+    }
 }
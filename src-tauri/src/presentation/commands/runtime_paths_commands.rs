@@ -1,17 +1,25 @@
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use serde::Serialize;
+use serde_json::json;
 use tauri::State;
 
+use crate::app::AppState;
 use crate::infrastructure::paths::{
     DataRootMigration, RuntimeMode, RuntimePaths, TAURITAVERN_RUNTIME_CONFIG_VERSION,
-    TauriTavernRuntimeConfig, is_effectively_empty_directory, load_runtime_config,
-    runtime_config_path,
+    TauriTavernRuntimeConfig, copy_data_root_with_progress, is_effectively_empty_directory,
+    load_runtime_config, relative_data_root, runtime_config_path, write_runtime_config_sync,
 };
 use crate::infrastructure::persistence::file_system::write_json_file;
 use crate::presentation::commands::helpers::{log_command, map_command_error};
 use crate::presentation::errors::CommandError;
 
+const DATA_DIRECTORY_MIGRATION_JOB_KIND: &str = "data_directory_migration";
+
+static DATA_DIRECTORY_MIGRATION_RUNNING: AtomicBool = AtomicBool::new(false);
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct RuntimePathsDto {
@@ -60,14 +68,12 @@ pub fn get_runtime_paths(
     })
 }
 
-#[tauri::command]
-pub async fn set_data_root(
-    data_root: String,
-    runtime_paths: State<'_, RuntimePaths>,
-) -> Result<(), CommandError> {
-    let raw = data_root.trim();
-    log_command(format!("set_data_root {}", raw));
-
+/// Checks that `raw` names an existing, empty directory outside the current data root.
+/// Returns the canonical target and current data root.
+fn validate_data_root_target(
+    raw: &str,
+    runtime_paths: &RuntimePaths,
+) -> Result<(PathBuf, PathBuf), CommandError> {
     if raw.is_empty() {
         return Err(CommandError::BadRequest(
             "data_root is required".to_string(),
@@ -119,6 +125,19 @@ pub async fn set_data_root(
         ));
     }
 
+    Ok((canonical_target, canonical_current))
+}
+
+#[tauri::command]
+pub async fn set_data_root(
+    data_root: String,
+    runtime_paths: State<'_, RuntimePaths>,
+) -> Result<(), CommandError> {
+    let raw = data_root.trim();
+    log_command(format!("set_data_root {}", raw));
+
+    let (canonical_target, canonical_current) = validate_data_root_target(raw, &runtime_paths)?;
+
     let config_path = runtime_config_path(&runtime_paths.app_root);
     let config = TauriTavernRuntimeConfig {
         version: TAURITAVERN_RUNTIME_CONFIG_VERSION,
@@ -137,3 +156,99 @@ pub async fn set_data_root(
 
     Ok(())
 }
+
+/// Copies the current data directory to `data_root` as a tracked job and points the
+/// runtime config at the copy once it is complete. The current directory is kept as a
+/// backup, and the new location takes effect on the next launch, so the frontend should
+/// restart as soon as the job completes. In portable mode the location is stored relative
+/// to the app folder when possible, so the install keeps working from a removable drive
+/// mounted elsewhere. Returns the job id.
+#[tauri::command]
+pub async fn set_data_directory(
+    data_root: String,
+    runtime_paths: State<'_, RuntimePaths>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<String, CommandError> {
+    let raw = data_root.trim();
+    log_command(format!("set_data_directory {}", raw));
+
+    let (target, current) = validate_data_root_target(raw, &runtime_paths)?;
+
+    if DATA_DIRECTORY_MIGRATION_RUNNING.swap(true, Ordering::SeqCst) {
+        return Err(CommandError::BadRequest(
+            "A data directory migration is already running".to_string(),
+        ));
+    }
+
+    let job = match app_state
+        .job_manager
+        .start(DATA_DIRECTORY_MIGRATION_JOB_KIND)
+    {
+        Ok(job) => job,
+        Err(error) => {
+            DATA_DIRECTORY_MIGRATION_RUNNING.store(false, Ordering::SeqCst);
+            return Err(map_command_error(
+                "Failed to start data directory migration",
+            )(error));
+        }
+    };
+    let job_id = job.job_id().to_string();
+
+    let stored_data_root = match runtime_paths.mode {
+        RuntimeMode::Portable => {
+            relative_data_root(&target, &runtime_paths.app_root).unwrap_or_else(|| target.clone())
+        }
+        RuntimeMode::Standard => target.clone(),
+    };
+    let config_path = runtime_config_path(&runtime_paths.app_root);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        job.mark_running("copying", "Copying data directory");
+
+        let copied = copy_data_root_with_progress(
+            &current,
+            &target,
+            |copied_files, total_files| {
+                let percent = copied_files as f32 * 100.0 / total_files.max(1) as f32;
+                job.update_progress(
+                    "copying",
+                    // Keep the last percent for switching the runtime config.
+                    percent.min(99.0),
+                    &format!("Copied {copied_files} of {total_files} files"),
+                );
+            },
+            || job.is_cancel_requested(),
+        )
+        .and_then(|total_files| {
+            let config = TauriTavernRuntimeConfig {
+                version: TAURITAVERN_RUNTIME_CONFIG_VERSION,
+                data_root: stored_data_root,
+                migration: None,
+                migration_error: None,
+            };
+            write_runtime_config_sync(&config_path, &config)?;
+            Ok(total_files)
+        });
+
+        match copied {
+            Ok(total_files) => job.complete(
+                "Data directory copied. Restart to use the new location.",
+                Some(json!({
+                    "data_root": target.to_string_lossy(),
+                    "previous_data_root": current.to_string_lossy(),
+                    "copied_files": total_files,
+                    "restart_required": true,
+                })),
+            ),
+            Err(_) if job.is_cancel_requested() => job.mark_cancelled(),
+            Err(error) => {
+                tracing::error!("Data directory migration failed: {}", error);
+                job.fail(&error.to_string());
+            }
+        }
+
+        DATA_DIRECTORY_MIGRATION_RUNNING.store(false, Ordering::SeqCst);
+    });
+
+    Ok(job_id)
+}
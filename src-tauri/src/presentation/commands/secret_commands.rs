@@ -4,8 +4,9 @@ use tauri::State;
 
 use crate::app::AppState;
 use crate::application::dto::secret_dto::{
-    AllSecretsDto, DeleteSecretDto, FindSecretDto, FindSecretResponseDto, RenameSecretDto,
-    RotateSecretDto, SecretSettingsDto, SecretStateDto, WriteSecretDto,
+    AllSecretsDto, DeleteSecretDto, ExportSecretsDto, FindSecretDto, FindSecretResponseDto,
+    ImportSecretsDto, RenameSecretDto, RotateSecretDto, SecretBundleDto, SecretImportReportDto,
+    SecretSettingsDto, SecretStateDto, WriteSecretDto,
 };
 use crate::presentation::commands::helpers::{log_command, map_command_error};
 use crate::presentation::errors::CommandError;
@@ -25,6 +26,10 @@ pub async fn write_secret(
             "Failed to write secret {}",
             dto.key
         )))?;
+    app_state
+        .chat_completion_service
+        .invalidate_status_cache()
+        .await;
 
     Ok(id)
 }
@@ -95,7 +100,13 @@ pub async fn delete_secret(
         .map_err(map_command_error(format!(
             "Failed to delete secret {}",
             dto.key
-        )))
+        )))?;
+    app_state
+        .chat_completion_service
+        .invalidate_status_cache()
+        .await;
+
+    Ok(())
 }
 
 #[tauri::command]
@@ -112,7 +123,13 @@ pub async fn rotate_secret(
         .map_err(map_command_error(format!(
             "Failed to rotate secret {}",
             dto.key
-        )))
+        )))?;
+    app_state
+        .chat_completion_service
+        .invalidate_status_cache()
+        .await;
+
+    Ok(())
 }
 
 #[tauri::command]
@@ -131,3 +148,39 @@ pub async fn rename_secret(
             dto.key
         )))
 }
+
+#[tauri::command]
+pub async fn export_secrets(
+    dto: ExportSecretsDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<SecretBundleDto, CommandError> {
+    log_command(format!("export_secrets values={}", dto.include_values));
+
+    app_state
+        .secret_service
+        .export_secrets(dto.include_values, dto.passphrase.as_deref())
+        .await
+        .map_err(map_command_error("Failed to export secrets"))
+}
+
+#[tauri::command]
+pub async fn import_secrets(
+    dto: ImportSecretsDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<SecretImportReportDto, CommandError> {
+    log_command("import_secrets");
+
+    let report = app_state
+        .secret_service
+        .import_secrets(dto.bundle, dto.passphrase.as_deref())
+        .await
+        .map_err(map_command_error("Failed to import secrets"))?;
+    if report.imported > 0 {
+        app_state
+            .chat_completion_service
+            .invalidate_status_cache()
+            .await;
+    }
+
+    Ok(report)
+}
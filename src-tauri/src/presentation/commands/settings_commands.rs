@@ -1,15 +1,21 @@
 use std::sync::Arc;
 
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+use tauri::AppHandle;
 use tauri::State;
 
 use crate::app::AppState;
 use crate::application::dto::settings_dto::{
-    SettingsSnapshotDto, SillyTavernSettingsResponseDto, TauriTavernSettingsDto,
-    UpdateTauriTavernSettingsDto, UserSettingsDto,
+    SettingsSnapshotDiffDto, SettingsSnapshotDto, SillyTavernSettingsResponseDto,
+    TauriTavernSettingsDto, UpdateTauriTavernSettingsDto, UserSettingsDto,
 };
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+use crate::domain::models::global_hotkeys::GlobalHotkeySettings;
 use crate::domain::models::settings::RequestProxySettings;
+use crate::domain::models::settings_schema::SettingsValidationReport;
 use crate::infrastructure::http_client_pool::HttpClientPool;
 use crate::infrastructure::logging::llm_api_logs::LlmApiLogStore;
+use crate::infrastructure::logging::performance_trace::PerformanceTracer;
 use crate::presentation::commands::helpers::{
     ensure_ios_policy_allows, log_command, map_command_error,
 };
@@ -29,19 +35,22 @@ pub async fn get_tauritavern_settings(
         .map_err(map_command_error("Failed to get TauriTavern settings"))
 }
 
-#[cfg(target_os = "windows")]
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
 #[tauri::command]
 pub async fn update_tauritavern_settings(
     dto: UpdateTauriTavernSettingsDto,
     app_state: State<'_, Arc<AppState>>,
     http_clients: State<'_, Arc<HttpClientPool>>,
     llm_api_logs: State<'_, Arc<LlmApiLogStore>>,
+    performance_tracer: State<'_, Arc<PerformanceTracer>>,
     thumbnail_policy: State<'_, Arc<ThumbnailEndpointPolicy>>,
-    tray_state: State<'_, Arc<crate::presentation::windows_tray::WindowsTrayState>>,
+    tray_state: State<'_, Arc<crate::presentation::desktop_tray::DesktopTrayState>>,
 ) -> Result<TauriTavernSettingsDto, CommandError> {
     log_command("update_tauritavern_settings");
 
     let agent_retention_settings_updated = has_agent_retention_settings_update(&dto);
+    let connection_monitor_settings_updated = dto.connection_monitor.is_some();
+    let memory_cache_settings_updated = dto.memory_cache.is_some();
     let request_proxy_settings: Option<RequestProxySettings> =
         dto.request_proxy.clone().map(Into::into);
     if let Some(settings) = request_proxy_settings.as_ref() {
@@ -56,6 +65,11 @@ pub async fn update_tauritavern_settings(
         HttpClientPool::validate_request_proxy_settings(settings)
             .map_err(map_command_error("Invalid request proxy settings"))?;
     }
+    let http_client_settings_updated = dto.http_client.is_some();
+    if let Some(settings) = dto.http_client.as_ref() {
+        HttpClientPool::validate_http_client_tuning_settings(settings)
+            .map_err(map_command_error("Invalid HTTP client settings"))?;
+    }
 
     let settings = app_state
         .settings_service
@@ -64,6 +78,7 @@ pub async fn update_tauritavern_settings(
         .map_err(map_command_error("Failed to update TauriTavern settings"))?;
 
     tray_state.set_close_to_tray_on_close(settings.close_to_tray_on_close);
+    tray_state.set_minimize_to_tray(settings.minimize_to_tray);
     thumbnail_policy.set_avatar_persona_original_images_enabled(
         settings.avatar_persona_original_images_enabled,
     );
@@ -74,7 +89,14 @@ pub async fn update_tauritavern_settings(
             .map_err(map_command_error("Failed to apply request proxy settings"))?;
     }
 
+    if http_client_settings_updated {
+        http_clients
+            .apply_http_client_tuning_settings(&settings.http_client)
+            .map_err(map_command_error("Failed to apply HTTP client settings"))?;
+    }
+
     llm_api_logs.apply_settings(settings.dev.llm_api_keep);
+    performance_tracer.set_enabled(settings.dev.performance_tracing);
 
     if agent_retention_settings_updated {
         app_state
@@ -82,21 +104,37 @@ pub async fn update_tauritavern_settings(
             .notify_settings_changed();
     }
 
+    if connection_monitor_settings_updated {
+        app_state
+            .connection_monitor_service
+            .notify_settings_changed();
+    }
+
+    if memory_cache_settings_updated {
+        app_state
+            .memory_cache_service
+            .apply_settings(&settings.memory_cache)
+            .await;
+    }
+
     Ok(settings)
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "macos", windows, target_os = "linux")))]
 #[tauri::command]
 pub async fn update_tauritavern_settings(
     dto: UpdateTauriTavernSettingsDto,
     app_state: State<'_, Arc<AppState>>,
     http_clients: State<'_, Arc<HttpClientPool>>,
     llm_api_logs: State<'_, Arc<LlmApiLogStore>>,
+    performance_tracer: State<'_, Arc<PerformanceTracer>>,
     thumbnail_policy: State<'_, Arc<ThumbnailEndpointPolicy>>,
 ) -> Result<TauriTavernSettingsDto, CommandError> {
     log_command("update_tauritavern_settings");
 
     let agent_retention_settings_updated = has_agent_retention_settings_update(&dto);
+    let connection_monitor_settings_updated = dto.connection_monitor.is_some();
+    let memory_cache_settings_updated = dto.memory_cache.is_some();
     let request_proxy_settings: Option<RequestProxySettings> =
         dto.request_proxy.clone().map(Into::into);
     if let Some(settings) = request_proxy_settings.as_ref() {
@@ -111,6 +149,11 @@ pub async fn update_tauritavern_settings(
         HttpClientPool::validate_request_proxy_settings(settings)
             .map_err(map_command_error("Invalid request proxy settings"))?;
     }
+    let http_client_settings_updated = dto.http_client.is_some();
+    if let Some(settings) = dto.http_client.as_ref() {
+        HttpClientPool::validate_http_client_tuning_settings(settings)
+            .map_err(map_command_error("Invalid HTTP client settings"))?;
+    }
 
     let settings = app_state
         .settings_service
@@ -128,7 +171,14 @@ pub async fn update_tauritavern_settings(
             .map_err(map_command_error("Failed to apply request proxy settings"))?;
     }
 
+    if http_client_settings_updated {
+        http_clients
+            .apply_http_client_tuning_settings(&settings.http_client)
+            .map_err(map_command_error("Failed to apply HTTP client settings"))?;
+    }
+
     llm_api_logs.apply_settings(settings.dev.llm_api_keep);
+    performance_tracer.set_enabled(settings.dev.performance_tracing);
 
     if agent_retention_settings_updated {
         app_state
@@ -136,9 +186,42 @@ pub async fn update_tauritavern_settings(
             .notify_settings_changed();
     }
 
+    if connection_monitor_settings_updated {
+        app_state
+            .connection_monitor_service
+            .notify_settings_changed();
+    }
+
+    if memory_cache_settings_updated {
+        app_state
+            .memory_cache_service
+            .apply_settings(&settings.memory_cache)
+            .await;
+    }
+
     Ok(settings)
 }
 
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+#[tauri::command]
+pub async fn set_global_hotkeys(
+    hotkeys: GlobalHotkeySettings,
+    app: AppHandle,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<GlobalHotkeySettings, CommandError> {
+    log_command("set_global_hotkeys");
+
+    // Register first so a hotkey taken by another application is never saved.
+    crate::presentation::global_hotkeys::apply_global_hotkeys(&app, &hotkeys)
+        .map_err(CommandError::BadRequest)?;
+
+    app_state
+        .settings_service
+        .update_global_hotkeys(hotkeys)
+        .await
+        .map_err(map_command_error("Failed to save global hotkeys"))
+}
+
 #[tauri::command]
 pub async fn save_user_settings(
     settings: UserSettingsDto,
@@ -166,6 +249,21 @@ pub async fn get_sillytavern_settings(
         .map_err(map_command_error("Failed to get SillyTavern settings"))
 }
 
+#[tauri::command]
+pub async fn validate_settings(
+    repair: Option<bool>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<SettingsValidationReport>, CommandError> {
+    let repair = repair.unwrap_or(false);
+    log_command(format!("validate_settings - repair: {}", repair));
+
+    app_state
+        .settings_service
+        .validate_settings(repair)
+        .await
+        .map_err(map_command_error("Failed to validate settings"))
+}
+
 #[tauri::command]
 pub async fn create_settings_snapshot(
     app_state: State<'_, Arc<AppState>>,
@@ -220,6 +318,39 @@ pub async fn restore_settings_snapshot(
         .map_err(map_command_error("Failed to restore settings snapshot"))
 }
 
+#[tauri::command]
+pub async fn diff_settings_snapshot(
+    name: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<SettingsSnapshotDiffDto, CommandError> {
+    log_command(format!("diff_settings_snapshot - {}", name));
+
+    app_state
+        .settings_service
+        .diff_snapshot(&name)
+        .await
+        .map_err(map_command_error("Failed to diff settings snapshot"))
+}
+
+#[tauri::command]
+pub async fn apply_settings_patch(
+    name: String,
+    paths: Vec<String>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<UserSettingsDto, CommandError> {
+    log_command(format!(
+        "apply_settings_patch - {} ({} keys)",
+        name,
+        paths.len()
+    ));
+
+    app_state
+        .settings_service
+        .apply_snapshot_patch(&name, &paths)
+        .await
+        .map_err(map_command_error("Failed to apply settings patch"))
+}
+
 fn has_agent_retention_settings_update(dto: &UpdateTauriTavernSettingsDto) -> bool {
     dto.agent
         .as_ref()
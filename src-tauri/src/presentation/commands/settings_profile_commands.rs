@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::settings_profile_dto::{
+    ActivateSettingsProfileResultDto, ListSettingsProfilesResultDto, SaveSettingsProfileDto,
+    SettingsProfileDto, SettingsProfileIdDto,
+};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn list_settings_profiles(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ListSettingsProfilesResultDto, CommandError> {
+    log_command("list_settings_profiles");
+
+    app_state
+        .settings_profile_service
+        .list_profiles()
+        .await
+        .map_err(map_command_error("Failed to list settings profiles"))
+}
+
+/// Saves the current API source, model, proxies and theme under `dto.id`.
+#[tauri::command]
+pub async fn save_settings_profile(
+    dto: SaveSettingsProfileDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<SettingsProfileDto, CommandError> {
+    log_command(format!("save_settings_profile {}", dto.id));
+
+    app_state
+        .settings_profile_service
+        .save_profile(&dto.id, &dto.display_name)
+        .await
+        .map_err(map_command_error("Failed to save settings profile"))
+}
+
+#[tauri::command]
+pub async fn activate_settings_profile(
+    dto: SettingsProfileIdDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ActivateSettingsProfileResultDto, CommandError> {
+    log_command(format!("activate_settings_profile {}", dto.profile_id));
+
+    let result = app_state
+        .settings_profile_service
+        .activate_profile(&dto.profile_id)
+        .await
+        .map_err(map_command_error("Failed to activate settings profile"))?;
+
+    app_state
+        .chat_completion_service
+        .invalidate_status_cache()
+        .await;
+    app_state
+        .connection_monitor_service
+        .notify_settings_changed();
+    Ok(result)
+}
@@ -0,0 +1,125 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tauri::State;
+
+use crate::app::AppState;
+use crate::domain::errors::DomainError;
+use crate::domain::models::changelog::MigrationRecord;
+use crate::infrastructure::persistence::sillytavern_migration::SillyTavernMigration;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+const SILLYTAVERN_MIGRATION_JOB_KIND: &str = "sillytavern_migration";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MigrateFromSillyTavernOptions {
+    /// Only report what would be copied.
+    pub dry_run: bool,
+}
+
+/// Starts copying the user data of an existing SillyTavern installation at `path` as a
+/// background job and returns its id. The per-category report is the job result.
+#[tauri::command]
+pub async fn migrate_from_sillytavern(
+    path: String,
+    app_state: State<'_, Arc<AppState>>,
+    options: Option<MigrateFromSillyTavernOptions>,
+) -> Result<String, CommandError> {
+    let options = options.unwrap_or_default();
+    let path = path.trim();
+    log_command(format!(
+        "migrate_from_sillytavern {} dry_run={}",
+        path, options.dry_run
+    ));
+
+    if path.is_empty() {
+        return Err(CommandError::BadRequest("path is required".to_string()));
+    }
+
+    let directory = app_state
+        .user_directory_service
+        .get_default_user_directory()
+        .await?;
+    let migration = SillyTavernMigration::detect(
+        &PathBuf::from(path),
+        PathBuf::from(directory.root),
+        PathBuf::from(directory.backups),
+    )?;
+
+    let job = app_state
+        .job_manager
+        .start(SILLYTAVERN_MIGRATION_JOB_KIND)
+        .map_err(map_command_error("Failed to start SillyTavern migration"))?;
+    let job_id = job.job_id().to_string();
+    let app_state = app_state.inner().clone();
+
+    tauri::async_runtime::spawn(async move {
+        job.mark_running(
+            "copying",
+            &format!("Migrating from {}", migration.source_user_dir().display()),
+        );
+
+        let progress_job = job.clone();
+        let result = migration
+            .run(
+                options.dry_run,
+                &move |done, total| {
+                    let percent = if total == 0 {
+                        100.0
+                    } else {
+                        done as f32 * 100.0 / total as f32
+                    };
+                    progress_job.update_progress(
+                        "copying",
+                        percent,
+                        &format!("Processed {done} of {total} files"),
+                    );
+                },
+                &|| job.is_cancel_requested(),
+            )
+            .await;
+
+        match result {
+            Ok(report) => {
+                if !report.dry_run && report.copied > 0 {
+                    let record = MigrationRecord::now(
+                        "sillytavern.import",
+                        format!("Imported {} file(s) from {}", report.copied, report.source),
+                        report.migrated_paths(),
+                    );
+                    if let Err(error) = app_state.changelog_service.record_migration(record).await {
+                        tracing::warn!("Failed to record migration in the ledger: {}", error);
+                    }
+                    if let Err(error) = app_state
+                        .refresh_after_external_data_change("sillytavern_migration")
+                        .await
+                    {
+                        tracing::warn!("Failed to refresh caches after migration: {}", error);
+                    }
+                }
+                let message = format!(
+                    "{} {} file(s), skipped {}, failed {}",
+                    if report.dry_run {
+                        "Would copy"
+                    } else {
+                        "Copied"
+                    },
+                    report.copied,
+                    report.skipped,
+                    report.failed
+                );
+                match serde_json::to_value(&report) {
+                    Ok(value) => job.complete(&message, Some(value)),
+                    Err(error) => job.fail(&format!("Failed to serialize report: {}", error)),
+                }
+            }
+            Err(DomainError::Cancelled(_)) => job.mark_cancelled(),
+            Err(error) => job.fail(&error.to_string()),
+        }
+    });
+
+    Ok(job_id)
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::sound_dto::UploadSoundDto;
+use crate::domain::models::sound_asset::SoundAsset;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn list_sounds(
+    app_state: State<'_, Arc<AppState>>,
+    character: Option<String>,
+) -> Result<Vec<SoundAsset>, CommandError> {
+    log_command(format!("list_sounds, character: {:?}", character));
+
+    app_state
+        .sound_service
+        .list_sounds(character.as_deref())
+        .await
+        .map_err(map_command_error("Failed to list sounds"))
+}
+
+#[tauri::command]
+pub async fn upload_sound(
+    app_state: State<'_, Arc<AppState>>,
+    dto: UploadSoundDto,
+) -> Result<SoundAsset, CommandError> {
+    log_command(format!(
+        "upload_sound, character: {:?}, name: {}",
+        dto.character, dto.name
+    ));
+
+    app_state
+        .sound_service
+        .upload_sound(dto)
+        .await
+        .map_err(map_command_error("Failed to upload sound"))
+}
+
+#[tauri::command]
+pub async fn delete_sound(
+    app_state: State<'_, Arc<AppState>>,
+    character: Option<String>,
+    name: String,
+) -> Result<(), CommandError> {
+    log_command(format!(
+        "delete_sound, character: {:?}, name: {}",
+        character, name
+    ));
+
+    app_state
+        .sound_service
+        .delete_sound(character.as_deref(), &name)
+        .await
+        .map_err(map_command_error("Failed to delete sound"))
+}
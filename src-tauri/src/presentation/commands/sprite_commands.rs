@@ -0,0 +1,83 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::domain::models::sprite::{Sprite, SpritePackImport};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn list_sprites(
+    app_state: State<'_, Arc<AppState>>,
+    character: String,
+) -> Result<Vec<Sprite>, CommandError> {
+    log_command(format!("list_sprites, character: {}", character));
+
+    app_state
+        .sprite_service
+        .list_sprites(&character)
+        .await
+        .map_err(map_command_error("Failed to list sprites"))
+}
+
+#[tauri::command]
+pub async fn upload_sprite_pack(
+    app_state: State<'_, Arc<AppState>>,
+    character: String,
+    file_path: String,
+    custom_labels: Option<Vec<String>>,
+) -> Result<SpritePackImport, CommandError> {
+    log_command(format!(
+        "upload_sprite_pack, character: {}, path: {}",
+        character, file_path
+    ));
+
+    app_state
+        .sprite_service
+        .upload_sprite_pack(
+            &character,
+            &PathBuf::from(&file_path),
+            &custom_labels.unwrap_or_default(),
+        )
+        .await
+        .map_err(map_command_error("Failed to upload sprite pack"))
+}
+
+#[tauri::command]
+pub async fn delete_sprite(
+    app_state: State<'_, Arc<AppState>>,
+    character: String,
+    name: String,
+) -> Result<(), CommandError> {
+    log_command(format!(
+        "delete_sprite, character: {}, name: {}",
+        character, name
+    ));
+
+    app_state
+        .sprite_service
+        .delete_sprite(&character, &name)
+        .await
+        .map_err(map_command_error("Failed to delete sprite"))
+}
+
+#[tauri::command]
+pub async fn resolve_sprite(
+    app_state: State<'_, Arc<AppState>>,
+    character: String,
+    label: String,
+    fallback: Option<String>,
+) -> Result<Option<Sprite>, CommandError> {
+    log_command(format!(
+        "resolve_sprite, character: {}, label: {}",
+        character, label
+    ));
+
+    app_state
+        .sprite_service
+        .resolve_sprite(&character, &label, fallback.as_deref())
+        .await
+        .map_err(map_command_error("Failed to resolve sprite"))
+}
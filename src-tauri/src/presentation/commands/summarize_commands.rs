@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::chat_dto::ChatMetadataTargetDto;
+use crate::application::dto::chat_summary_dto::{ChatSummaryDto, SummarizeChatSegmentDto};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn summarize_chat_segment(
+    dto: SummarizeChatSegmentDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ChatSummaryDto, CommandError> {
+    log_command("summarize_chat_segment");
+
+    app_state
+        .summarize_service
+        .summarize_chat_segment(dto)
+        .await
+        .map_err(map_command_error("Failed to summarize chat segment"))
+}
+
+#[tauri::command]
+pub async fn get_chat_summary(
+    chat: ChatMetadataTargetDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Option<ChatSummaryDto>, CommandError> {
+    log_command("get_chat_summary");
+
+    app_state
+        .summarize_service
+        .get_chat_summary(&chat)
+        .await
+        .map_err(map_command_error("Failed to get chat summary"))
+}
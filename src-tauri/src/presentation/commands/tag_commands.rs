@@ -0,0 +1,106 @@
+use std::sync::Arc;
+use tauri::State;
+
+use crate::app::AppState;
+use crate::domain::models::tag::{Tag, TagImportSummary, TagLibrary};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn list_tags(app_state: State<'_, Arc<AppState>>) -> Result<TagLibrary, CommandError> {
+    log_command("list_tags");
+
+    app_state
+        .tag_service
+        .list_tags()
+        .await
+        .map_err(map_command_error("Failed to list tags"))
+}
+
+#[tauri::command]
+pub async fn create_tag(
+    name: String,
+    color: Option<String>,
+    color2: Option<String>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Tag, CommandError> {
+    log_command(format!("create_tag {}", name));
+
+    app_state
+        .tag_service
+        .create_tag(&name, color, color2)
+        .await
+        .map_err(map_command_error("Failed to create tag"))
+}
+
+#[tauri::command]
+pub async fn rename_tag(
+    id: String,
+    name: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Tag, CommandError> {
+    log_command(format!("rename_tag {} -> {}", id, name));
+
+    app_state
+        .tag_service
+        .rename_tag(&id, &name)
+        .await
+        .map_err(map_command_error("Failed to rename tag"))
+}
+
+#[tauri::command]
+pub async fn delete_tag(
+    id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    log_command(format!("delete_tag {}", id));
+
+    app_state
+        .tag_service
+        .delete_tag(&id)
+        .await
+        .map_err(map_command_error("Failed to delete tag"))
+}
+
+#[tauri::command]
+pub async fn assign_tag(
+    entity_id: String,
+    tag_id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    log_command(format!("assign_tag {} to {}", tag_id, entity_id));
+
+    app_state
+        .tag_service
+        .assign_tag(&entity_id, &tag_id)
+        .await
+        .map_err(map_command_error("Failed to assign tag"))
+}
+
+#[tauri::command]
+pub async fn unassign_tag(
+    entity_id: String,
+    tag_id: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    log_command(format!("unassign_tag {} from {}", tag_id, entity_id));
+
+    app_state
+        .tag_service
+        .unassign_tag(&entity_id, &tag_id)
+        .await
+        .map_err(map_command_error("Failed to unassign tag"))
+}
+
+#[tauri::command]
+pub async fn import_tags_from_settings(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<TagImportSummary, CommandError> {
+    log_command("import_tags_from_settings");
+
+    app_state
+        .tag_service
+        .import_tags_from_settings()
+        .await
+        .map_err(map_command_error("Failed to import tags from settings"))
+}
@@ -1,12 +1,23 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use serde::Serialize;
 use tauri::State;
 
 use crate::app::AppState;
-use crate::application::dto::theme_dto::{DeleteThemeDto, SaveThemeDto};
+use crate::application::dto::theme_dto::{DeleteThemeDto, SaveThemeDto, ThemeSummaryDto};
 use crate::presentation::commands::helpers::{log_command, map_command_error};
 use crate::presentation::errors::CommandError;
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeExportPayload {
+    pub file_name: String,
+    pub content_base64: String,
+}
+
 #[tauri::command]
 pub async fn save_theme(
     dto: SaveThemeDto,
@@ -41,3 +52,53 @@ pub async fn delete_theme(
             dto.name
         )))
 }
+
+#[tauri::command]
+pub async fn list_themes(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<ThemeSummaryDto>, CommandError> {
+    log_command("list_themes");
+
+    app_state
+        .theme_service
+        .list_themes()
+        .await
+        .map_err(map_command_error("Failed to list themes"))
+}
+
+#[tauri::command]
+pub async fn export_theme(
+    name: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ThemeExportPayload, CommandError> {
+    log_command(format!("export_theme, name: {}", name));
+
+    let archive = app_state
+        .theme_service
+        .export_theme(&name)
+        .await
+        .map_err(map_command_error(format!(
+            "Failed to export theme {}",
+            name
+        )))?;
+
+    Ok(ThemeExportPayload {
+        file_name: archive.file_name,
+        content_base64: BASE64_STANDARD.encode(archive.bytes),
+    })
+}
+
+#[tauri::command]
+pub async fn import_theme(
+    file_path: String,
+    overwrite: Option<bool>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<ThemeSummaryDto, CommandError> {
+    log_command(format!("import_theme, path: {}", file_path));
+
+    app_state
+        .theme_service
+        .import_theme(&PathBuf::from(&file_path), overwrite.unwrap_or(false))
+        .await
+        .map_err(map_command_error("Failed to import theme"))
+}
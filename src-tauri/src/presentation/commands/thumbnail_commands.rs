@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde_json::json;
+use tauri::State;
+
+use crate::app::AppState;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::thumbnail_pipeline::{
+    ThumbnailKind, ThumbnailPipeline, ThumbnailSize,
+};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+const THUMBNAIL_JOB_KIND: &str = "thumbnail_regeneration";
+const WEBP_THUMBNAIL_CACHE_DIR: &str = "webp";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RegenerateThumbnailsOptions {
+    /// Defaults to avatars and backgrounds.
+    pub kinds: Option<Vec<ThumbnailKind>>,
+    /// Defaults to each kind's standard thumbnail size.
+    pub sizes: Vec<ThumbnailSize>,
+    /// Regenerate even when the cached thumbnail is newer than its source.
+    pub force: bool,
+}
+
+async fn build_pipeline(app_state: &Arc<AppState>) -> Result<ThumbnailPipeline, CommandError> {
+    let directory = app_state
+        .user_directory_service
+        .get_default_user_directory()
+        .await?;
+
+    Ok(ThumbnailPipeline::new(
+        PathBuf::from(directory.characters),
+        PathBuf::from(directory.backgrounds),
+        PathBuf::from(directory.thumbnails).join(WEBP_THUMBNAIL_CACHE_DIR),
+    ))
+}
+
+#[tauri::command]
+pub async fn regenerate_thumbnails(
+    app_state: State<'_, Arc<AppState>>,
+    options: Option<RegenerateThumbnailsOptions>,
+) -> Result<String, CommandError> {
+    let options = options.unwrap_or_default();
+    log_command(format!(
+        "regenerate_thumbnails kinds={:?} sizes={} force={}",
+        options.kinds,
+        options.sizes.len(),
+        options.force
+    ));
+
+    let pipeline = build_pipeline(&app_state).await?;
+    let job = app_state
+        .job_manager
+        .start(THUMBNAIL_JOB_KIND)
+        .map_err(map_command_error("Failed to start thumbnail regeneration"))?;
+    let job_id = job.job_id().to_string();
+
+    tauri::async_runtime::spawn(async move {
+        job.mark_running("generating", "Generating thumbnails");
+
+        let worker = job.clone();
+        let result = tauri::async_runtime::spawn_blocking(move || {
+            let kinds = options
+                .kinds
+                .unwrap_or_else(|| vec![ThumbnailKind::Avatar, ThumbnailKind::Background]);
+            let progress_job = worker.clone();
+            pipeline.regenerate(
+                &kinds,
+                &options.sizes,
+                options.force,
+                &move |done, total| {
+                    let percent = if total == 0 {
+                        100.0
+                    } else {
+                        done as f32 * 100.0 / total as f32
+                    };
+                    progress_job.update_progress(
+                        "generating",
+                        percent,
+                        &format!("Generated {done} of {total} thumbnails"),
+                    );
+                },
+                &|| worker.is_cancel_requested(),
+            )
+        })
+        .await;
+
+        match result {
+            Ok(Ok(report)) => job.complete(
+                "Thumbnails regenerated",
+                Some(json!({
+                    "generated": report.generated,
+                    "skipped": report.skipped,
+                    "failed": report.failed,
+                })),
+            ),
+            Ok(Err(DomainError::Cancelled(_))) => job.mark_cancelled(),
+            Ok(Err(error)) => job.fail(&error.to_string()),
+            Err(error) => job.fail(&format!("Thumbnail task join error: {}", error)),
+        }
+    });
+
+    Ok(job_id)
+}
+
+#[tauri::command]
+pub async fn get_thumbnail(
+    app_state: State<'_, Arc<AppState>>,
+    kind: ThumbnailKind,
+    file: String,
+    size: Option<ThumbnailSize>,
+) -> Result<String, CommandError> {
+    log_command(format!("get_thumbnail {:?} {}", kind, file));
+
+    let pipeline = build_pipeline(&app_state).await?;
+    let path =
+        tauri::async_runtime::spawn_blocking(move || pipeline.ensure_thumbnail(kind, &file, size))
+            .await
+            .map_err(|error| {
+                CommandError::InternalServerError(format!("Thumbnail worker failed: {}", error))
+            })?
+            .map_err(map_command_error("Failed to get thumbnail"))?;
+
+    Ok(path.to_string_lossy().to_string())
+}
@@ -4,11 +4,12 @@ use tauri::State;
 
 use crate::app::AppState;
 use crate::application::dto::tokenization_dto::{
-    OpenAiDecodeRequestDto, OpenAiDecodeResponseDto, OpenAiEncodeRequestDto,
-    OpenAiEncodeResponseDto, OpenAiLogitBiasRequestDto, OpenAiLogitBiasResponseDto,
-    OpenAiTokenCountBatchRequestDto, OpenAiTokenCountBatchResponseDto, OpenAiTokenCountRequestDto,
-    OpenAiTokenCountResponseDto,
+    CharacterTokenBudgetDto, OpenAiDecodeRequestDto, OpenAiDecodeResponseDto,
+    OpenAiEncodeRequestDto, OpenAiEncodeResponseDto, OpenAiLogitBiasRequestDto,
+    OpenAiLogitBiasResponseDto, OpenAiTokenCountBatchRequestDto, OpenAiTokenCountBatchResponseDto,
+    OpenAiTokenCountRequestDto, OpenAiTokenCountResponseDto,
 };
+use crate::presentation::commands::character_commands::character_id_from_avatar;
 use crate::presentation::commands::helpers::{log_command, map_command_error};
 use crate::presentation::errors::CommandError;
 
@@ -81,3 +82,24 @@ pub async fn build_openai_logit_bias(
         .await
         .map_err(map_command_error("Failed to build OpenAI logit bias"))
 }
+
+#[tauri::command]
+pub async fn analyze_character_tokens(
+    avatar: String,
+    tokenizer: Option<String>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<CharacterTokenBudgetDto, CommandError> {
+    log_command(format!("analyze_character_tokens {}", avatar));
+
+    let character = app_state
+        .character_service
+        .get_character(&character_id_from_avatar(&avatar)?)
+        .await
+        .map_err(map_command_error("Failed to get character"))?;
+
+    app_state
+        .tokenization_service
+        .analyze_character_tokens(&character, tokenizer.as_deref().unwrap_or_default())
+        .await
+        .map_err(map_command_error("Failed to analyze character tokens"))
+}
@@ -27,3 +27,27 @@ pub async fn check_for_update(
         .await
         .map_err(map_command_error("Failed to check for update"))
 }
+
+#[tauri::command]
+pub async fn check_for_updates(
+    include_prerelease: Option<bool>,
+    force_refresh: Option<bool>,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<UpdateCheckResult, CommandError> {
+    log_command("check_for_updates");
+
+    ensure_ios_policy_allows(
+        &app_state.ios_policy,
+        app_state.ios_policy.capabilities.updates.manual_check,
+        "updates.manual_check",
+    )?;
+
+    app_state
+        .update_service
+        .check_for_updates(
+            include_prerelease.unwrap_or(false),
+            force_refresh.unwrap_or(false),
+        )
+        .await
+        .map_err(map_command_error("Failed to check for updates"))
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::app::AppState;
+use crate::domain::models::user_customization::UserScript;
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn get_custom_css(app_state: State<'_, Arc<AppState>>) -> Result<String, CommandError> {
+    log_command("get_custom_css");
+
+    app_state
+        .user_customization_service
+        .get_custom_css()
+        .await
+        .map_err(map_command_error("Failed to read custom CSS"))
+}
+
+#[tauri::command]
+pub async fn save_custom_css(
+    css: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    log_command(format!("save_custom_css, {} bytes", css.len()));
+
+    app_state
+        .user_customization_service
+        .save_custom_css(&css)
+        .await
+        .map_err(map_command_error("Failed to save custom CSS"))
+}
+
+#[tauri::command]
+pub async fn list_user_scripts(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Vec<UserScript>, CommandError> {
+    log_command("list_user_scripts");
+
+    app_state
+        .user_customization_service
+        .list_user_scripts()
+        .await
+        .map_err(map_command_error("Failed to list user scripts"))
+}
+
+#[tauri::command]
+pub async fn save_user_script(
+    script: UserScript,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    log_command(format!("save_user_script, name: {}", script.name));
+
+    let name = script.name.clone();
+    app_state
+        .user_customization_service
+        .save_user_script(script)
+        .await
+        .map_err(map_command_error(format!(
+            "Failed to save user script {}",
+            name
+        )))
+}
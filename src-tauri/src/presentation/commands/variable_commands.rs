@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
+use tauri::State;
+
+use crate::app::AppState;
+use crate::application::dto::variable_dto::{
+    ChatVariableDto, ChatVariablesDto, SetChatVariableDto, SetGlobalVariableDto,
+};
+use crate::presentation::commands::helpers::{log_command, map_command_error};
+use crate::presentation::errors::CommandError;
+
+#[tauri::command]
+pub async fn list_chat_variables(
+    dto: ChatVariablesDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Map<String, Value>, CommandError> {
+    log_command("list_chat_variables");
+
+    app_state
+        .variable_service
+        .list_chat_variables(&dto.chat)
+        .await
+        .map_err(map_command_error("Failed to list chat variables"))
+}
+
+#[tauri::command]
+pub async fn get_chat_variable(
+    dto: ChatVariableDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command(format!("get_chat_variable {}", dto.name));
+
+    app_state
+        .variable_service
+        .get_chat_variable(&dto.chat, &dto.name)
+        .await
+        .map_err(map_command_error("Failed to get chat variable"))
+}
+
+#[tauri::command]
+pub async fn set_chat_variable(
+    dto: SetChatVariableDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    log_command(format!("set_chat_variable {}", dto.name));
+
+    app_state
+        .variable_service
+        .set_chat_variable(&dto.chat, &dto.name, dto.value)
+        .await
+        .map_err(map_command_error("Failed to set chat variable"))
+}
+
+#[tauri::command]
+pub async fn flush_chat_variable(
+    dto: ChatVariableDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<bool, CommandError> {
+    log_command(format!("flush_chat_variable {}", dto.name));
+
+    app_state
+        .variable_service
+        .flush_chat_variable(&dto.chat, &dto.name)
+        .await
+        .map_err(map_command_error("Failed to flush chat variable"))
+}
+
+#[tauri::command]
+pub async fn list_global_variables(
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Map<String, Value>, CommandError> {
+    log_command("list_global_variables");
+
+    app_state
+        .variable_service
+        .list_global_variables()
+        .await
+        .map_err(map_command_error("Failed to list global variables"))
+}
+
+#[tauri::command]
+pub async fn get_global_variable(
+    name: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<Value, CommandError> {
+    log_command(format!("get_global_variable {}", name));
+
+    app_state
+        .variable_service
+        .get_global_variable(&name)
+        .await
+        .map_err(map_command_error("Failed to get global variable"))
+}
+
+#[tauri::command]
+pub async fn set_global_variable(
+    dto: SetGlobalVariableDto,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<(), CommandError> {
+    log_command(format!("set_global_variable {}", dto.name));
+
+    app_state
+        .variable_service
+        .set_global_variable(&dto.name, dto.value)
+        .await
+        .map_err(map_command_error("Failed to set global variable"))
+}
+
+#[tauri::command]
+pub async fn flush_global_variable(
+    name: String,
+    app_state: State<'_, Arc<AppState>>,
+) -> Result<bool, CommandError> {
+    log_command(format!("flush_global_variable {}", name));
+
+    app_state
+        .variable_service
+        .flush_global_variable(&name)
+        .await
+        .map_err(map_command_error("Failed to flush global variable"))
+}
@@ -0,0 +1,224 @@
+//! `tauritavern://` links opened from outside the app, such as "open in TauriTavern"
+//! buttons on character card sites.
+//!
+//! Links are only parsed here. Each one becomes a `deep_link:request` event; the frontend
+//! asks the user before acting and then runs the regular import or chat flow, so policy
+//! checks stay where they already are. Requests are also queued until the frontend takes
+//! them, because a link that launches the app arrives before the webview listens.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, Url};
+use tauri_plugin_deep_link::DeepLinkExt;
+
+pub const DEEP_LINK_SCHEME: &str = "tauritavern";
+pub const DEEP_LINK_REQUEST_EVENT: &str = "deep_link:request";
+
+/// Older requests are dropped beyond this many, so a burst of links cannot pile up.
+const MAX_PENDING_DEEP_LINKS: usize = 16;
+const MAX_PARAM_LEN: usize = 1024;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum DeepLinkAction {
+    /// `import-character?url=https://...`
+    ImportCharacter { url: String },
+    /// `open-chat?character=<name>[&chat=<file>]` or `open-chat?group=<id>[&chat=<id>]`.
+    /// Without `chat` the most recent chat opens.
+    OpenChat {
+        character: Option<String>,
+        group: Option<String>,
+        chat: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeepLinkRequest {
+    pub id: String,
+    #[serde(flatten)]
+    pub action: DeepLinkAction,
+}
+
+#[derive(Default)]
+pub struct DeepLinkInbox {
+    pending: Mutex<Vec<DeepLinkRequest>>,
+}
+
+impl DeepLinkInbox {
+    fn push(&self, request: DeepLinkRequest) {
+        let mut pending = self
+            .pending
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        pending.push(request);
+        let overflow = pending.len().saturating_sub(MAX_PENDING_DEEP_LINKS);
+        pending.drain(..overflow);
+    }
+
+    pub fn take_all(&self) -> Vec<DeepLinkRequest> {
+        let mut pending = self
+            .pending
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        std::mem::take(&mut *pending)
+    }
+}
+
+/// Manages the inbox and routes links the app was launched with, and links opened while
+/// it runs, to the frontend.
+pub fn install_deep_link_handler(app_handle: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
+    app_handle.manage(Arc::new(DeepLinkInbox::default()));
+
+    // Installed packages register the scheme at install time; AppImages and dev builds
+    // only do so at runtime.
+    #[cfg(any(windows, target_os = "linux"))]
+    if let Err(error) = app_handle.deep_link().register_all() {
+        tracing::warn!("Failed to register deep link schemes: {}", error);
+    }
+
+    let handle = app_handle.clone();
+    app_handle.deep_link().on_open_url(move |event| {
+        handle_deep_link_urls(&handle, &event.urls());
+    });
+
+    if let Some(urls) = app_handle.deep_link().get_current()? {
+        handle_deep_link_urls(app_handle, &urls);
+    }
+    Ok(())
+}
+
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+pub fn focus_main_window(app_handle: &AppHandle) {
+    let Some(window) = app_handle.get_webview_window("main") else {
+        return;
+    };
+    if let Err(error) = window
+        .unminimize()
+        .and_then(|_| window.show())
+        .and_then(|_| window.set_focus())
+    {
+        tracing::warn!("Failed to focus main window: {}", error);
+    }
+}
+
+fn handle_deep_link_urls(app_handle: &AppHandle, urls: &[Url]) {
+    let inbox = app_handle.state::<Arc<DeepLinkInbox>>();
+    for url in urls {
+        let action = match parse_deep_link(url) {
+            Ok(action) => action,
+            Err(error) => {
+                tracing::warn!("Ignoring deep link: {}", error);
+                continue;
+            }
+        };
+
+        let request = DeepLinkRequest {
+            id: uuid::Uuid::new_v4().to_string(),
+            action,
+        };
+        tracing::info!("Received deep link request {}", request.id);
+        inbox.push(request.clone());
+        if let Err(error) = app_handle.emit(DEEP_LINK_REQUEST_EVENT, &request) {
+            tracing::warn!("Failed to emit deep link request: {}", error);
+        }
+    }
+
+    #[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+    focus_main_window(app_handle);
+}
+
+fn parse_deep_link(url: &Url) -> Result<DeepLinkAction, String> {
+    if url.scheme() != DEEP_LINK_SCHEME {
+        return Err(format!("unsupported scheme `{}`", url.scheme()));
+    }
+
+    // `tauritavern://import-character?...` carries the action as the host,
+    // `tauritavern:import-character?...` as the path.
+    let action = url
+        .host_str()
+        .filter(|host| !host.is_empty())
+        .or_else(|| url.path().trim_matches('/').split('/').next())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    let mut params = HashMap::new();
+    for (key, value) in url.query_pairs() {
+        let value = value.trim();
+        if value.len() > MAX_PARAM_LEN {
+            return Err(format!("parameter `{key}` is too long"));
+        }
+        if !value.is_empty() {
+            params.insert(key.into_owned(), value.to_string());
+        }
+    }
+
+    match action.as_str() {
+        "import-character" => {
+            let target = params
+                .remove("url")
+                .ok_or_else(|| "import-character requires a `url` parameter".to_string())?;
+            let target =
+                Url::parse(&target).map_err(|_| "import-character url is invalid".to_string())?;
+            if !matches!(target.scheme(), "http" | "https") {
+                return Err("import-character only accepts http(s) URLs".to_string());
+            }
+            Ok(DeepLinkAction::ImportCharacter {
+                url: target.to_string(),
+            })
+        }
+        "open-chat" => {
+            let character = params.remove("character");
+            let group = params.remove("group");
+            if character.is_some() == group.is_some() {
+                return Err("open-chat requires either `character` or `group`".to_string());
+            }
+            Ok(DeepLinkAction::OpenChat {
+                character,
+                group,
+                chat: params.remove("chat"),
+            })
+        }
+        "" => Err("deep link has no action".to_string()),
+        other => Err(format!("unsupported action `{other}`")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tauri::Url;
+
+    use super::{DeepLinkAction, parse_deep_link};
+
+    fn parse(url: &str) -> Result<DeepLinkAction, String> {
+        parse_deep_link(&Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn parses_import_and_open_chat_links() {
+        assert_eq!(
+            parse("tauritavern://import-character?url=https%3A%2F%2Fexample.com%2Fcard.png"),
+            Ok(DeepLinkAction::ImportCharacter {
+                url: "https://example.com/card.png".to_string()
+            })
+        );
+        assert_eq!(
+            parse("tauritavern:open-chat?character=Alice&chat=Alice%20-%202024"),
+            Ok(DeepLinkAction::OpenChat {
+                character: Some("Alice".to_string()),
+                group: None,
+                chat: Some("Alice - 2024".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unsafe_or_incomplete_links() {
+        assert!(parse("tauritavern://import-character?url=file%3A%2F%2F%2Fetc%2Fpasswd").is_err());
+        assert!(parse("tauritavern://import-character").is_err());
+        assert!(parse("tauritavern://open-chat?character=Alice&group=1").is_err());
+        assert!(parse("tauritavern://delete-everything").is_err());
+        assert!(parse("https://import-character?url=https://example.com").is_err());
+    }
+}
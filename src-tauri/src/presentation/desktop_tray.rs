@@ -0,0 +1,243 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use tauri::menu::{CheckMenuItem, CheckMenuItemBuilder, Menu, MenuItemBuilder, PredefinedMenuItem};
+use tauri::tray::{MouseButton, TrayIconBuilder, TrayIconEvent};
+use tauri::{AppHandle, Emitter, Manager, WindowEvent, Wry};
+use tauri_plugin_opener::OpenerExt;
+
+use crate::presentation::bridge_server::BridgeServer;
+use crate::presentation::deep_links::focus_main_window;
+
+const TRAY_ID: &str = "tauritavern-tray";
+const MENU_SHOW_ID: &str = "tauritavern-tray:show";
+const MENU_NEW_CHAT_ID: &str = "tauritavern-tray:new-chat";
+const MENU_BRIDGE_SERVER_ID: &str = "tauritavern-tray:bridge-server";
+const MENU_DATA_FOLDER_ID: &str = "tauritavern-tray:data-folder";
+const MENU_EXIT_ID: &str = "tauritavern-tray:exit";
+
+/// Asks the frontend to start a new chat with the character chatted with most recently.
+pub const TRAY_NEW_CHAT_EVENT: &str = "tray:new_chat";
+
+pub struct DesktopTrayState {
+    close_to_tray_on_close: AtomicBool,
+    minimize_to_tray: AtomicBool,
+    quitting: AtomicBool,
+    bridge_server_item: OnceLock<CheckMenuItem<Wry>>,
+}
+
+impl DesktopTrayState {
+    pub fn new(close_to_tray_on_close: bool, minimize_to_tray: bool) -> Self {
+        Self {
+            close_to_tray_on_close: AtomicBool::new(close_to_tray_on_close),
+            minimize_to_tray: AtomicBool::new(minimize_to_tray),
+            quitting: AtomicBool::new(false),
+            bridge_server_item: OnceLock::new(),
+        }
+    }
+
+    pub fn close_to_tray_on_close(&self) -> bool {
+        self.close_to_tray_on_close.load(Ordering::Relaxed)
+    }
+
+    pub fn set_close_to_tray_on_close(&self, enabled: bool) {
+        self.close_to_tray_on_close
+            .store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn minimize_to_tray(&self) -> bool {
+        self.minimize_to_tray.load(Ordering::Relaxed)
+    }
+
+    pub fn set_minimize_to_tray(&self, enabled: bool) {
+        self.minimize_to_tray.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Keeps the "LAN bridge" menu check in line with the bridge server.
+    pub fn set_bridge_server_enabled(&self, enabled: bool) {
+        let Some(item) = self.bridge_server_item.get() else {
+            return;
+        };
+        if let Err(error) = item.set_checked(enabled) {
+            tracing::warn!("Failed to update tray LAN bridge item: {}", error);
+        }
+    }
+
+    fn set_quitting(&self) {
+        self.quitting.store(true, Ordering::Relaxed);
+    }
+
+    fn is_quitting(&self) -> bool {
+        self.quitting.load(Ordering::Relaxed)
+    }
+
+    /// Closing hides the window instead, unless the app is exiting from the tray menu.
+    fn hides_on_close(&self) -> bool {
+        !self.is_quitting() && self.close_to_tray_on_close()
+    }
+}
+
+pub fn install_desktop_tray(
+    app_handle: &AppHandle,
+    main_window: &tauri::webview::WebviewWindow,
+    data_root: PathBuf,
+    state: Arc<DesktopTrayState>,
+) -> tauri::Result<()> {
+    let main_window = main_window.clone();
+
+    let show_item = MenuItemBuilder::with_id(MENU_SHOW_ID, "Show").build(app_handle)?;
+    let new_chat_item = MenuItemBuilder::with_id(MENU_NEW_CHAT_ID, "New chat with last character")
+        .build(app_handle)?;
+    let bridge_server_item = CheckMenuItemBuilder::with_id(MENU_BRIDGE_SERVER_ID, "LAN bridge")
+        .checked(false)
+        .build(app_handle)?;
+    let data_folder_item =
+        MenuItemBuilder::with_id(MENU_DATA_FOLDER_ID, "Open data folder").build(app_handle)?;
+    let exit_item = MenuItemBuilder::with_id(MENU_EXIT_ID, "Exit").build(app_handle)?;
+    let separator = PredefinedMenuItem::separator(app_handle)?;
+    let actions_separator = PredefinedMenuItem::separator(app_handle)?;
+
+    let menu = Menu::with_items(
+        app_handle,
+        &[
+            &show_item,
+            &actions_separator,
+            &new_chat_item,
+            &bridge_server_item,
+            &data_folder_item,
+            &separator,
+            &exit_item,
+        ],
+    )?;
+    let _ = state.bridge_server_item.set(bridge_server_item);
+
+    let icon = app_handle
+        .default_window_icon()
+        .cloned()
+        .ok_or_else(|| tauri::Error::AssetNotFound("Default window icon is missing".into()))?;
+
+    let state_for_menu = state.clone();
+
+    TrayIconBuilder::with_id(TRAY_ID)
+        .icon(icon)
+        .tooltip("TauriTavern")
+        .menu(&menu)
+        .on_menu_event(move |app, event| match event.id().as_ref() {
+            MENU_SHOW_ID => focus_main_window(app),
+            MENU_NEW_CHAT_ID => {
+                focus_main_window(app);
+                if let Err(error) = app.emit(TRAY_NEW_CHAT_EVENT, ()) {
+                    tracing::warn!("Failed to emit tray new chat request: {}", error);
+                }
+            }
+            MENU_BRIDGE_SERVER_ID => toggle_bridge_server(app, state_for_menu.clone()),
+            MENU_DATA_FOLDER_ID => {
+                if let Err(error) = app
+                    .opener()
+                    .open_path(data_root.to_string_lossy(), None::<&str>)
+                {
+                    tracing::warn!("Failed to open data folder: {}", error);
+                }
+            }
+            MENU_EXIT_ID => {
+                state_for_menu.set_quitting();
+                app.exit(0);
+            }
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| match event {
+            TrayIconEvent::DoubleClick { button, .. } if button == MouseButton::Left => {
+                focus_main_window(tray.app_handle());
+            }
+            _ => {}
+        })
+        .build(app_handle)?;
+
+    let state_for_window = state.clone();
+    let main_window_for_events = main_window.clone();
+    main_window.on_window_event(move |event| match event {
+        WindowEvent::CloseRequested { api, .. } => {
+            if !state_for_window.hides_on_close() {
+                return;
+            }
+
+            api.prevent_close();
+            if let Err(error) = main_window_for_events.hide() {
+                tracing::warn!("Failed to hide main window on close: {}", error);
+            }
+        }
+        // There is no dedicated minimize event; minimizing resizes the window.
+        WindowEvent::Resized(_) if state_for_window.minimize_to_tray() => {
+            if main_window_for_events.is_minimized().unwrap_or(false) {
+                if let Err(error) = main_window_for_events.hide() {
+                    tracing::warn!("Failed to hide minimized main window: {}", error);
+                }
+            }
+        }
+        _ => {}
+    });
+
+    // Keep the tray state alive for the lifetime of the app.
+    app_handle.manage(state);
+
+    Ok(())
+}
+
+/// The bridge server is loaded with the app state, so the item does nothing before the
+/// app is ready.
+fn toggle_bridge_server(app: &AppHandle, state: Arc<DesktopTrayState>) {
+    let Some(bridge_server) = app.try_state::<Arc<BridgeServer>>() else {
+        tracing::warn!("LAN bridge is not available yet");
+        state.set_bridge_server_enabled(false);
+        return;
+    };
+    let bridge_server = bridge_server.inner().clone();
+
+    tauri::async_runtime::spawn(async move {
+        let result = match bridge_server.get_status().await {
+            Ok(status) if status.enabled => bridge_server.disable().await,
+            Ok(_) => bridge_server.enable(None).await,
+            Err(error) => Err(error),
+        };
+        match result {
+            Ok(status) => state.set_bridge_server_enabled(status.enabled),
+            Err(error) => {
+                tracing::warn!("Failed to toggle LAN bridge from tray: {}", error);
+                if let Ok(status) = bridge_server.get_status().await {
+                    state.set_bridge_server_enabled(status.enabled);
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DesktopTrayState;
+
+    #[test]
+    fn closing_hides_to_tray_until_exit_is_chosen() {
+        let state = DesktopTrayState::new(true, false);
+        assert!(state.hides_on_close());
+
+        state.set_close_to_tray_on_close(false);
+        assert!(!state.hides_on_close());
+
+        state.set_close_to_tray_on_close(true);
+        state.set_quitting();
+        assert!(!state.hides_on_close());
+    }
+
+    #[test]
+    fn tray_settings_can_change_before_the_tray_is_installed() {
+        let state = DesktopTrayState::new(false, false);
+
+        state.set_minimize_to_tray(true);
+        assert!(state.minimize_to_tray());
+
+        // Without a tray menu the bridge check has nothing to update.
+        state.set_bridge_server_enabled(true);
+        assert!(state.bridge_server_item.get().is_none());
+    }
+}
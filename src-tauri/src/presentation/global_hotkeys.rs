@@ -0,0 +1,209 @@
+//! System-wide shortcuts bound to `GlobalHotkeyAction`s. Window actions run here; the
+//! others are forwarded to the frontend as `global_hotkey:triggered` events.
+
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use tauri::plugin::TauriPlugin;
+use tauri::{AppHandle, Emitter, Manager, Wry};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+use crate::domain::models::global_hotkeys::{GlobalHotkeyAction, GlobalHotkeySettings};
+use crate::presentation::deep_links::focus_main_window;
+
+pub const GLOBAL_HOTKEY_EVENT: &str = "global_hotkey:triggered";
+
+/// Shortcuts currently registered with the OS, with their actions.
+#[derive(Default)]
+pub struct GlobalHotkeyRegistry {
+    bindings: Mutex<Vec<(GlobalHotkeyAction, Shortcut)>>,
+    /// Serializes updates. `bindings` is not held while registering, because the
+    /// shortcut handler locks it on the main thread that registration waits for.
+    update_lock: Mutex<()>,
+}
+
+impl GlobalHotkeyRegistry {
+    fn current(&self) -> Vec<(GlobalHotkeyAction, Shortcut)> {
+        self.bindings
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .clone()
+    }
+
+    fn replace(&self, bindings: Vec<(GlobalHotkeyAction, Shortcut)>) {
+        *self
+            .bindings
+            .lock()
+            .unwrap_or_else(|error| error.into_inner()) = bindings;
+    }
+
+    fn action_for(&self, shortcut: &Shortcut) -> Option<GlobalHotkeyAction> {
+        self.bindings
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .iter()
+            .find(|(_, bound)| bound == shortcut)
+            .map(|(action, _)| *action)
+    }
+}
+
+pub fn global_hotkeys_plugin() -> TauriPlugin<Wry> {
+    tauri_plugin_global_shortcut::Builder::new()
+        .with_handler(|app, shortcut, event| {
+            if event.state() != ShortcutState::Pressed {
+                return;
+            }
+            let Some(registry) = app.try_state::<Arc<GlobalHotkeyRegistry>>() else {
+                return;
+            };
+            if let Some(action) = registry.action_for(shortcut) {
+                run_action(app, action);
+            }
+        })
+        .build()
+}
+
+/// Registers the saved hotkeys at startup. A hotkey taken by another application is
+/// skipped so the rest still work.
+pub fn install_global_hotkeys(app_handle: &AppHandle, settings: &GlobalHotkeySettings) {
+    let registry = Arc::new(GlobalHotkeyRegistry::default());
+    app_handle.manage(registry.clone());
+
+    let bindings = match parse_bindings(settings) {
+        Ok(bindings) => bindings,
+        Err(error) => {
+            tracing::warn!("Ignoring saved global hotkeys: {}", error);
+            return;
+        }
+    };
+
+    let mut registered = Vec::with_capacity(bindings.len());
+    for (action, accelerator, shortcut) in bindings {
+        match app_handle.global_shortcut().register(shortcut) {
+            Ok(()) => registered.push((action, shortcut)),
+            Err(error) => tracing::warn!(
+                "Failed to register global hotkey {} for {:?}: {}",
+                accelerator,
+                action,
+                error
+            ),
+        }
+    }
+    registry.replace(registered);
+}
+
+/// Replaces the registered hotkeys. Either every new hotkey is registered, or the
+/// previous ones are restored and the conflict is returned.
+pub fn apply_global_hotkeys(
+    app_handle: &AppHandle,
+    settings: &GlobalHotkeySettings,
+) -> Result<(), String> {
+    let next = parse_bindings(settings)?;
+    let registry = app_handle.state::<Arc<GlobalHotkeyRegistry>>();
+    let _update = registry
+        .update_lock
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    let previous = registry.current();
+    let global_shortcut = app_handle.global_shortcut();
+
+    for (_, shortcut) in &previous {
+        if let Err(error) = global_shortcut.unregister(*shortcut) {
+            tracing::warn!("Failed to unregister global hotkey: {}", error);
+        }
+    }
+
+    let mut registered = Vec::with_capacity(next.len());
+    for (action, accelerator, shortcut) in next {
+        if let Err(error) = global_shortcut.register(shortcut) {
+            for (_, shortcut) in &registered {
+                let _ = global_shortcut.unregister(*shortcut);
+            }
+            for (_, shortcut) in &previous {
+                let _ = global_shortcut.register(*shortcut);
+            }
+            return Err(format!(
+                "{} is already in use by another application ({})",
+                accelerator, error
+            ));
+        }
+        registered.push((action, shortcut));
+    }
+
+    registry.replace(registered);
+    Ok(())
+}
+
+/// Parses every binding and rejects one shortcut bound to several actions.
+fn parse_bindings(
+    settings: &GlobalHotkeySettings,
+) -> Result<Vec<(GlobalHotkeyAction, &str, Shortcut)>, String> {
+    let mut bindings: Vec<(GlobalHotkeyAction, &str, Shortcut)> = Vec::new();
+    for (action, accelerator) in settings.bindings() {
+        let shortcut = Shortcut::from_str(accelerator)
+            .map_err(|error| format!("Invalid shortcut {}: {}", accelerator, error))?;
+        if let Some((other, _, _)) = bindings.iter().find(|(_, _, bound)| *bound == shortcut) {
+            return Err(format!(
+                "{} is assigned to both {:?} and {:?}",
+                accelerator, other, action
+            ));
+        }
+        bindings.push((action, accelerator, shortcut));
+    }
+    Ok(bindings)
+}
+
+fn run_action(app_handle: &AppHandle, action: GlobalHotkeyAction) {
+    match action {
+        GlobalHotkeyAction::ToggleWindow => toggle_main_window(app_handle),
+        GlobalHotkeyAction::StopGeneration => {
+            if let Err(error) = app_handle.emit(GLOBAL_HOTKEY_EVENT, action) {
+                tracing::warn!("Failed to emit global hotkey event: {}", error);
+            }
+        }
+    }
+}
+
+fn toggle_main_window(app_handle: &AppHandle) {
+    let Some(window) = app_handle.get_webview_window("main") else {
+        return;
+    };
+    let in_front = window.is_visible().unwrap_or(false)
+        && window.is_focused().unwrap_or(false)
+        && !window.is_minimized().unwrap_or(false);
+    if !in_front {
+        focus_main_window(app_handle);
+    } else if let Err(error) = window.hide() {
+        tracing::warn!("Failed to hide main window: {}", error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_bindings;
+    use crate::domain::models::global_hotkeys::GlobalHotkeySettings;
+
+    fn settings(toggle_window: &str, stop_generation: &str) -> GlobalHotkeySettings {
+        GlobalHotkeySettings {
+            toggle_window: Some(toggle_window.to_string()),
+            stop_generation: Some(stop_generation.to_string()),
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_and_duplicate_shortcuts() {
+        assert_eq!(
+            parse_bindings(&settings("CommandOrControl+Shift+T", " "))
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(parse_bindings(&settings("Ctrl+Shift+T", "Shift+Control+T")).is_err());
+        assert!(parse_bindings(&settings("Ctrl+NotAKey", "")).is_err());
+        assert!(
+            parse_bindings(&GlobalHotkeySettings::default())
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -1,7 +1,12 @@
 // Presentation layer - handles communication with the frontend
+pub mod bridge_server;
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+pub mod chat_windows;
 pub mod commands;
+pub mod deep_links;
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+pub mod desktop_tray;
 pub mod errors;
+#[cfg(any(target_os = "macos", windows, target_os = "linux"))]
+pub mod global_hotkeys;
 pub mod web_resources;
-
-#[cfg(target_os = "windows")]
-pub mod windows_tray;
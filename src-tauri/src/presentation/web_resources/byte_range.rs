@@ -1,3 +1,6 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ByteRange {
     pub start: u64,
@@ -73,6 +76,22 @@ pub fn parse_single_range_header(
     Ok(ByteRange { start, end })
 }
 
+/// Reads only the bytes covered by `range`, so large files never need to be loaded whole.
+pub fn read_file_range(path: &Path, range: ByteRange) -> std::io::Result<Vec<u8>> {
+    let range_len = usize::try_from(range.len()).map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Range is too large to serve",
+        )
+    })?;
+
+    let mut file = std::fs::File::open(path)?;
+    file.seek(SeekFrom::Start(range.start))?;
+    let mut bytes = vec![0u8; range_len];
+    file.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
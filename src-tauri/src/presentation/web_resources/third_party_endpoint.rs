@@ -2,13 +2,22 @@ use std::borrow::Cow;
 use std::path::Path;
 
 use tauri::http::StatusCode;
+use tauri::http::header::{
+    ACCEPT_RANGES, CACHE_CONTROL, CONTENT_LENGTH, CONTENT_RANGE, ETAG, HeaderValue, IF_NONE_MATCH,
+    RANGE,
+};
 
 use crate::domain::errors::DomainError;
 use crate::infrastructure::css_compat::{contains_layer_keyword, flatten_css_layers};
-use crate::infrastructure::third_party_assets::resolve_third_party_extension_asset;
+use crate::infrastructure::third_party_assets::{
+    ResolvedThirdPartyAsset, resolve_third_party_extension_asset,
+};
 use crate::infrastructure::third_party_paths::{
     THIRD_PARTY_EXTENSION_ROUTE_PREFIX, ThirdPartyPathError, parse_third_party_asset_request_path,
 };
+use crate::presentation::web_resources::byte_range::{
+    RangeHeaderError, parse_single_range_header, read_file_range,
+};
 use crate::presentation::web_resources::response_helpers::{
     respond_bytes, respond_method_not_allowed, respond_no_content, respond_plain_text,
 };
@@ -33,6 +42,47 @@ fn should_apply_third_party_layer_compat(request: &tauri::http::Request<Vec<u8>>
     })
 }
 
+fn request_matches_etag(request: &tauri::http::Request<Vec<u8>>, etag: &str) -> bool {
+    request
+        .headers()
+        .get(IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value.split(',').map(str::trim).any(|candidate| {
+                candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+            })
+        })
+}
+
+/// Third-party assets may be cached but must be revalidated against the ETag.
+fn set_revalidation_headers(
+    response: &mut tauri::http::Response<Cow<'static, [u8]>>,
+    etag: &str,
+    size_bytes: Option<u64>,
+) {
+    let headers = response.headers_mut();
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    headers.insert(ETAG, HeaderValue::from_str(etag).expect("Invalid ETag"));
+    if let Some(size_bytes) = size_bytes {
+        headers.insert(
+            CONTENT_LENGTH,
+            HeaderValue::from_str(&size_bytes.to_string()).expect("Invalid Content-Length"),
+        );
+    }
+}
+
+fn respond_range_not_satisfiable(
+    response: &mut tauri::http::Response<Cow<'static, [u8]>>,
+    message: &str,
+    total_size: u64,
+) {
+    respond_plain_text(response, StatusCode::RANGE_NOT_SATISFIABLE, message);
+    response.headers_mut().insert(
+        CONTENT_RANGE,
+        HeaderValue::from_str(&format!("bytes */{}", total_size)).expect("Invalid Content-Range"),
+    );
+}
+
 pub fn handle_third_party_asset_web_request(
     local_extensions_dir: &Path,
     global_extensions_dir: &Path,
@@ -99,67 +149,191 @@ fn handle_third_party_asset_route_request(
         &parsed.extension_folder,
         &parsed.relative_path,
     ) {
-        Ok(resolved) => {
-            if request.method() == Method::HEAD {
-                respond_bytes(response, StatusCode::OK, Vec::new(), &resolved.mime_type);
-                return;
-            }
+        Ok(resolved) => serve_resolved_asset(
+            request,
+            response,
+            &resolved,
+            &parsed.extension_folder,
+            &parsed.relative_path_display,
+        ),
+        Err(DomainError::NotFound(_)) => {
+            respond_plain_text(response, StatusCode::NOT_FOUND, "Not Found");
+            tracing::debug!(
+                "Third-party asset 404: {}/{}",
+                parsed.extension_folder,
+                parsed.relative_path_display
+            );
+        }
+        Err(error) => {
+            respond_plain_text(
+                response,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &error.to_string(),
+            );
+        }
+    }
+}
+
+fn serve_resolved_asset(
+    request: &tauri::http::Request<Vec<u8>>,
+    response: &mut tauri::http::Response<Cow<'static, [u8]>>,
+    resolved: &ResolvedThirdPartyAsset,
+    extension_folder: &str,
+    relative_path_display: &str,
+) {
+    use tauri::http::Method;
+
+    let should_apply_layer_compat =
+        resolved.mime_type == "text/css" && should_apply_third_party_layer_compat(request);
+
+    let etag = match resolved.etag() {
+        // The layer-compat rewrite is a different representation of the same file.
+        Ok(etag) if should_apply_layer_compat => {
+            format!("{}-layer\"", etag.trim_end_matches('"'))
+        }
+        Ok(etag) => etag,
+        Err(error) => {
+            respond_plain_text(
+                response,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &error.to_string(),
+            );
+            return;
+        }
+    };
+
+    if request_matches_etag(request, &etag) {
+        respond_bytes(
+            response,
+            StatusCode::NOT_MODIFIED,
+            Vec::new(),
+            &resolved.mime_type,
+        );
+        set_revalidation_headers(response, &etag, None);
+        tracing::debug!(
+            "Third-party asset not modified: {}/{}",
+            extension_folder,
+            relative_path_display
+        );
+        return;
+    }
 
-            if cfg!(mobile) && resolved.size_bytes > MAX_MOBILE_INLINE_THIRD_PARTY_ASSET_BYTES {
-                tracing::warn!(
-                    "Rejected large third-party asset ({} bytes): {}/{}",
+    if request.method() == Method::HEAD {
+        respond_bytes(response, StatusCode::OK, Vec::new(), &resolved.mime_type);
+        set_revalidation_headers(response, &etag, Some(resolved.size_bytes));
+        response
+            .headers_mut()
+            .insert(ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+        return;
+    }
+
+    // Ranged reads let large assets (audio, models, media) stream in slices instead of
+    // being loaded into one buffer. Rewritten stylesheets are always served whole.
+    let range_header = request
+        .headers()
+        .get(RANGE)
+        .filter(|_| !should_apply_layer_compat);
+    if let Some(range_header) = range_header {
+        let range = match range_header
+            .to_str()
+            .map_err(|_| RangeHeaderError::Invalid)
+            .and_then(|value| parse_single_range_header(value, resolved.size_bytes))
+        {
+            Ok(range) => range,
+            Err(RangeHeaderError::Invalid) => {
+                respond_range_not_satisfiable(
+                    response,
+                    "Invalid Range header",
                     resolved.size_bytes,
-                    parsed.extension_folder,
-                    parsed.relative_path_display
                 );
-                respond_plain_text(
+                return;
+            }
+            Err(RangeHeaderError::Unsatisfiable) => {
+                respond_range_not_satisfiable(
                     response,
-                    StatusCode::PAYLOAD_TOO_LARGE,
-                    "Third-party asset is too large to load on mobile.",
+                    "Range not satisfiable",
+                    resolved.size_bytes,
                 );
                 return;
             }
+        };
 
-            let should_apply_layer_compat =
-                resolved.mime_type == "text/css" && should_apply_third_party_layer_compat(request);
-
-            match std::fs::read(&resolved.path) {
-                Ok(bytes) => {
-                    let bytes = if should_apply_layer_compat && contains_layer_keyword(&bytes) {
-                        flatten_css_layers(&bytes)
-                    } else {
-                        bytes
-                    };
-
-                    respond_bytes(response, StatusCode::OK, bytes, &resolved.mime_type);
-                    tracing::debug!(
-                        "Third-party asset hit: {}/{}",
-                        parsed.extension_folder,
-                        parsed.relative_path_display
-                    );
-                }
-                Err(error) => {
-                    respond_plain_text(
-                        response,
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        &format!("Failed to read third-party asset: {}", error),
-                    );
-                }
+        match read_file_range(&resolved.path, range) {
+            Ok(bytes) => {
+                respond_bytes(
+                    response,
+                    StatusCode::PARTIAL_CONTENT,
+                    bytes,
+                    &resolved.mime_type,
+                );
+                set_revalidation_headers(response, &etag, Some(range.len()));
+                let headers = response.headers_mut();
+                headers.insert(ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+                headers.insert(
+                    CONTENT_RANGE,
+                    HeaderValue::from_str(&format!(
+                        "bytes {}-{}/{}",
+                        range.start, range.end, resolved.size_bytes
+                    ))
+                    .expect("Invalid Content-Range"),
+                );
+                tracing::debug!(
+                    "Third-party asset range hit: {}/{}",
+                    extension_folder,
+                    relative_path_display
+                );
+            }
+            Err(error) => {
+                respond_plain_text(
+                    response,
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    &format!("Failed to read third-party asset range: {}", error),
+                );
             }
         }
-        Err(DomainError::NotFound(_)) => {
-            respond_plain_text(response, StatusCode::NOT_FOUND, "Not Found");
+        return;
+    }
+
+    if cfg!(mobile) && resolved.size_bytes > MAX_MOBILE_INLINE_THIRD_PARTY_ASSET_BYTES {
+        tracing::warn!(
+            "Rejected large third-party asset ({} bytes): {}/{}",
+            resolved.size_bytes,
+            extension_folder,
+            relative_path_display
+        );
+        respond_plain_text(
+            response,
+            StatusCode::PAYLOAD_TOO_LARGE,
+            "Third-party asset is too large to load on mobile without a Range request.",
+        );
+        return;
+    }
+
+    match std::fs::read(&resolved.path) {
+        Ok(bytes) => {
+            let bytes = if should_apply_layer_compat && contains_layer_keyword(&bytes) {
+                flatten_css_layers(&bytes)
+            } else {
+                bytes
+            };
+
+            let size_bytes = bytes.len() as u64;
+            respond_bytes(response, StatusCode::OK, bytes, &resolved.mime_type);
+            set_revalidation_headers(response, &etag, Some(size_bytes));
+            response
+                .headers_mut()
+                .insert(ACCEPT_RANGES, HeaderValue::from_static("bytes"));
             tracing::debug!(
-                "Third-party asset 404: {}/{}",
-                parsed.extension_folder,
-                parsed.relative_path_display
+                "Third-party asset hit: {}/{}",
+                extension_folder,
+                relative_path_display
             );
         }
         Err(error) => {
             respond_plain_text(
                 response,
                 StatusCode::INTERNAL_SERVER_ERROR,
-                &error.to_string(),
+                &format!("Failed to read third-party asset: {}", error),
             );
         }
     }
@@ -169,7 +343,7 @@ fn handle_third_party_asset_route_request(
 mod tests {
     use super::*;
     use std::path::PathBuf;
-    use tauri::http::header::{ALLOW, CONTENT_TYPE, HeaderValue};
+    use tauri::http::header::{ALLOW, CONTENT_TYPE};
 
     struct TempDirGuard {
         path: PathBuf,
@@ -295,4 +469,72 @@ mod tests {
         );
         assert_eq!(response.body().as_ref(), b".x{color:red;}");
     }
+
+    #[test]
+    fn returns_not_modified_when_etag_matches() {
+        let temp = TempDirGuard::new("third-party-endpoint-etag");
+        let local_root = temp.path.join("local");
+        let global_root = temp.path.join("global");
+        std::fs::create_dir_all(local_root.join("mobile")).expect("create extension dir");
+        std::fs::write(local_root.join("mobile").join("index.js"), b"export {};")
+            .expect("write script");
+
+        let build_request = |etag: Option<&HeaderValue>| {
+            let mut builder = tauri::http::Request::builder()
+                .method("GET")
+                .uri("/scripts/extensions/third-party/mobile/index.js");
+            if let Some(etag) = etag {
+                builder = builder.header(IF_NONE_MATCH, etag.clone());
+            }
+            builder.body(Vec::new()).expect("request")
+        };
+
+        let mut first = tauri::http::Response::new(Cow::Owned(Vec::new()));
+        handle_third_party_asset_web_request(
+            &local_root,
+            &global_root,
+            &build_request(None),
+            &mut first,
+        );
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first.headers().get(ETAG).expect("etag header").clone();
+
+        let mut second = tauri::http::Response::new(Cow::Owned(Vec::new()));
+        handle_third_party_asset_web_request(
+            &local_root,
+            &global_root,
+            &build_request(Some(&etag)),
+            &mut second,
+        );
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+        assert!(second.body().is_empty());
+        assert_eq!(second.headers().get(ETAG), Some(&etag));
+    }
+
+    #[test]
+    fn serves_byte_ranges_without_reading_whole_asset() {
+        let temp = TempDirGuard::new("third-party-endpoint-range");
+        let local_root = temp.path.join("local");
+        let global_root = temp.path.join("global");
+        std::fs::create_dir_all(local_root.join("mobile")).expect("create extension dir");
+        std::fs::write(local_root.join("mobile").join("voice.bin"), b"0123456789")
+            .expect("write asset");
+
+        let request = tauri::http::Request::builder()
+            .method("GET")
+            .uri("/scripts/extensions/third-party/mobile/voice.bin")
+            .header(RANGE, "bytes=2-5")
+            .body(Vec::new())
+            .expect("request");
+        let mut response = tauri::http::Response::new(Cow::Owned(Vec::new()));
+
+        handle_third_party_asset_web_request(&local_root, &global_root, &request, &mut response);
+
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(response.body().as_ref(), b"2345");
+        assert_eq!(
+            response.headers().get(CONTENT_RANGE),
+            Some(&HeaderValue::from_static("bytes 2-5/10"))
+        );
+    }
 }
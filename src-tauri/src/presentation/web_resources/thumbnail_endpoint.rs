@@ -293,6 +293,7 @@ mod tests {
             assets_dir: root.join("assets"),
             user_images_dir: root.join("user/images"),
             user_files_dir: root.join("user/files"),
+            user_sounds_dir: root.join("user/sounds"),
             thumbnails_bg_dir: root.join("thumbnails/bg"),
             thumbnails_avatar_dir: root.join("thumbnails/avatar"),
             thumbnails_persona_dir: root.join("thumbnails/persona"),
@@ -97,6 +97,7 @@ fn handle_user_data_asset_route_request_with_policy(
         UserDataAssetKind::Asset => user_dirs.assets_dir.as_path(),
         UserDataAssetKind::UserImage => user_dirs.user_images_dir.as_path(),
         UserDataAssetKind::UserFile => user_dirs.user_files_dir.as_path(),
+        UserDataAssetKind::UserSound => user_dirs.user_sounds_dir.as_path(),
     };
     let asset_path = base_dir.join(&parsed.relative_path);
 
@@ -346,6 +347,7 @@ mod tests {
             assets_dir: root.join("assets"),
             user_images_dir: root.join("user/images"),
             user_files_dir: root.join("user/files"),
+            user_sounds_dir: root.join("user/sounds"),
             thumbnails_bg_dir: root.join("thumbnails/bg"),
             thumbnails_avatar_dir: root.join("thumbnails/avatar"),
             thumbnails_persona_dir: root.join("thumbnails/persona"),
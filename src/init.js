@@ -63,6 +63,7 @@ const DEV_SW_PROXY_ALLOWED_PATH_PREFIXES = [
     '/assets/',
     '/user/images/',
     '/user/files/',
+    '/user/sounds/',
     '/User Avatars/',
     '/User%20Avatars/',
 ];
@@ -116,6 +116,8 @@ import {
 import {
     groups,
     selected_group,
+    openGroupById,
+    openGroupChat,
     saveGroupChat,
     getGroups,
     applyGroupsSnapshot,
@@ -330,6 +332,10 @@ import { initScrapers } from './scripts/scrapers.js';
 import { initCustomSelectedSamplers, validateDisabledSamplers } from './scripts/samplerSelect.js';
 import { DragAndDropHandler } from './scripts/dragdrop.js';
 import { initializeShareTargetImport } from './scripts/share-target-import.js';
+import { initializeDeepLinkActions } from './scripts/deep-link-actions.js';
+import { initializeTrayActions } from './scripts/tray-actions.js';
+import { initializeGlobalHotkeys } from './scripts/global-hotkeys.js';
+import { initializeChatWindow, popOutCharacterChat } from './scripts/chat-window.js';
 import { INTERACTABLE_CONTROL_CLASS, initKeyboard } from './scripts/keyboard.js';
 import { initDynamicStyles } from './scripts/dynamic-styles.js';
 import { initInputMarkdown } from './scripts/input-md-formatting.js';
@@ -13113,6 +13119,8 @@ jQuery(async function () {
             setTimeout(() => openMessageDelete(fromSlashCommand), animation_duration);
         } else if (id == 'option_close_chat') {
             await closeCurrentChat();
+        } else if (id === 'option_popout_chat') {
+            await popOutCharacterChat(selected_group ? null : characters[this_chid]);
         } else if (id === 'option_toggle_fullscreen') {
             const nextMode = !power_user.mobile_immersive_fullscreen;
             power_user.mobile_immersive_fullscreen = nextMode;
@@ -14115,6 +14123,28 @@ jQuery(async function () {
         importFromExternalUrl,
         processDroppedFiles,
     });
+    const isChatWindow = await initializeChatWindow({
+        getCharacters: () => characters,
+        selectCharacterById,
+        openCharacterChat,
+    });
+    if (!isChatWindow) {
+        await initializeDeepLinkActions({
+            importFromExternalUrl,
+            getCharacters: () => characters,
+            selectCharacterById,
+            openCharacterChat,
+            getGroups: () => groups,
+            openGroupById,
+            openGroupChat,
+        });
+        await initializeTrayActions({
+            getCharacters: () => characters,
+            selectCharacterById,
+            doNewChat,
+        });
+    }
+    await initializeGlobalHotkeys({ stopGeneration });
 
     window.addEventListener('beforeunload', (e) => {
         if (isChatSaving || this_edit_mes_id >= 0) {
@@ -0,0 +1,61 @@
+import { getChatWindowBinding, isTauriEnv, openChatWindow } from '../tauri-bridge.js';
+import { t } from './i18n.js';
+
+/**
+ * Opens the character chat in its own desktop window.
+ * @param {object} character Character the chat belongs to.
+ * @returns {Promise<void>}
+ */
+export async function popOutCharacterChat(character) {
+    if (!character?.avatar || !character?.chat) {
+        toastr.info(t`Only character chats can be opened in a new window.`);
+        return;
+    }
+
+    try {
+        await openChatWindow(character.avatar, character.chat);
+    } catch (error) {
+        console.error('Failed to open chat window:', error);
+        toastr.error(t`Could not open the chat in a new window.`);
+    }
+}
+
+/**
+ * Binds a pop-out chat window to its chat, or enables popping chats out from the main
+ * window. App-wide requests such as deep links and tray actions belong to the main
+ * window only.
+ * @param {object} handlers App functions used to open the bound chat.
+ * @returns {Promise<boolean>} Whether this is a pop-out chat window.
+ */
+export async function initializeChatWindow({ getCharacters, selectCharacterById, openCharacterChat }) {
+    if (!isTauriEnv) {
+        return false;
+    }
+
+    let binding;
+    try {
+        binding = await getChatWindowBinding();
+    } catch {
+        // Chat windows are desktop-only; other platforms do not expose the command.
+        return false;
+    }
+
+    if (!binding) {
+        $('#option_popout_chat').removeClass('displayNone');
+        return false;
+    }
+
+    const characterId = getCharacters().findIndex(x => x.avatar === binding.character);
+    if (characterId < 0) {
+        toastr.warning(t`The chat's character was not found.`);
+        return true;
+    }
+
+    try {
+        await selectCharacterById(characterId);
+        await openCharacterChat(binding.file);
+    } catch (error) {
+        console.error('Failed to open the chat in this window:', error);
+    }
+    return true;
+}
@@ -0,0 +1,98 @@
+import { isTauriEnv, listen, takePendingDeepLinks } from '../tauri-bridge.js';
+import { t } from './i18n.js';
+import { POPUP_TYPE, callGenericPopup } from './popup.js';
+import { escapeHtml } from './utils.js';
+
+const DEEP_LINK_REQUEST_EVENT = 'deep_link:request';
+
+function describeHost(url) {
+    try {
+        return new URL(url).host;
+    } catch {
+        return url;
+    }
+}
+
+async function handleImportCharacter(request, { importFromExternalUrl }) {
+    const confirmed = await callGenericPopup(
+        `${escapeHtml(t`Import a character from ${describeHost(request.url)}?`)}<br><small>${escapeHtml(request.url)}</small>`,
+        POPUP_TYPE.CONFIRM,
+    );
+    if (!confirmed) {
+        return;
+    }
+
+    await importFromExternalUrl(request.url);
+}
+
+async function handleOpenChat(request, { getCharacters, selectCharacterById, openCharacterChat, getGroups, openGroupById, openGroupChat }) {
+    if (request.group) {
+        const group = getGroups().find(x => x.id === request.group);
+        if (!group) {
+            toastr.warning(t`The linked group was not found.`);
+            return;
+        }
+
+        if (request.chat && group.chats.includes(request.chat)) {
+            await openGroupChat(group.id, request.chat);
+        } else {
+            await openGroupById(group.id);
+        }
+        return;
+    }
+
+    const characterId = getCharacters().findIndex(x => x.avatar === request.character || x.name === request.character);
+    if (characterId < 0) {
+        toastr.warning(t`The linked character was not found.`);
+        return;
+    }
+
+    await selectCharacterById(characterId);
+    if (request.chat) {
+        await openCharacterChat(request.chat);
+    }
+}
+
+async function handleDeepLinkRequest(request, handlers) {
+    switch (request?.action) {
+        case 'import_character':
+            await handleImportCharacter(request, handlers);
+            break;
+        case 'open_chat':
+            await handleOpenChat(request, handlers);
+            break;
+        default:
+            console.warn('Unknown deep link action', request?.action);
+            break;
+    }
+}
+
+/**
+ * Handles `tauritavern://` links routed by the host. Requests are taken from the host
+ * queue, which also holds the links that launched the app before the page loaded.
+ * @param {object} handlers App functions used to act on the links.
+ * @returns {Promise<() => void>} Unsubscribes from new links.
+ */
+export async function initializeDeepLinkActions(handlers) {
+    if (!isTauriEnv) {
+        return () => { /* noop */ };
+    }
+
+    let processingQueue = Promise.resolve();
+    const drain = () => {
+        processingQueue = processingQueue
+            .then(async () => {
+                const requests = await takePendingDeepLinks();
+                for (const request of requests) {
+                    await handleDeepLinkRequest(request, handlers);
+                }
+            })
+            .catch((error) => {
+                console.error('Failed to handle deep link:', error);
+            });
+    };
+
+    const unlisten = await listen(DEEP_LINK_REQUEST_EVENT, drain);
+    drain();
+    return unlisten;
+}
@@ -1485,6 +1485,31 @@ async function onUpdateClick() {
     icon.removeClass('fa-spin');
 }
 
+/**
+ * Combines the caller's abort controller with an optional timeout.
+ * @param {number?} timeoutMs Timeout in milliseconds
+ * @param {AbortController?} abortController Controller that cancels the request
+ * @returns {AbortSignal|undefined}
+ */
+function createUpdateSignal(timeoutMs, abortController) {
+    let signal = abortController?.signal;
+    if (timeoutMs) {
+        const timeoutSignal = AbortSignal.timeout(timeoutMs);
+        if (!signal) {
+            signal = timeoutSignal;
+        } else if (typeof AbortSignal.any === 'function') {
+            signal = AbortSignal.any([signal, timeoutSignal]);
+        } else {
+            const combined = new AbortController();
+            const abort = () => combined.abort();
+            signal.addEventListener('abort', abort, { once: true });
+            timeoutSignal.addEventListener('abort', abort, { once: true });
+            signal = combined.signal;
+        }
+    }
+    return signal;
+}
+
 /**
  * Updates a third-party extension via the API.
  * @param {string} extensionName Extension folder name
@@ -1499,22 +1524,7 @@ async function updateExtension(extensionName, quiet, { timeoutMs = null, abortCo
     }
 
     try {
-        let signal = abortController?.signal;
-        if (timeoutMs) {
-            const timeoutSignal = AbortSignal.timeout(timeoutMs);
-            if (!signal) {
-                signal = timeoutSignal;
-            } else if (typeof AbortSignal.any === 'function') {
-                signal = AbortSignal.any([signal, timeoutSignal]);
-            } else {
-                const combined = new AbortController();
-                const abort = () => combined.abort();
-                signal.addEventListener('abort', abort, { once: true });
-                timeoutSignal.addEventListener('abort', abort, { once: true });
-                signal = combined.signal;
-            }
-        }
-
+        const signal = createUpdateSignal(timeoutMs, abortController);
         const response = await fetch('/api/extensions/update', {
             method: 'POST',
             signal: signal,
@@ -1997,9 +2007,40 @@ export async function installExtension(url, global, branch = '') {
         await callExtensionHook(extensionName, 'install');
     }
 
+    await promptMissingExtensionDependencies(response, global);
+
     return true;
 }
 
+/**
+ * Offers to install dependencies the backend reported as missing after an installation.
+ * Built-in dependencies cannot be installed and are only reported.
+ * @param {object} response Install response
+ * @param {boolean} global Install dependencies globally
+ */
+async function promptMissingExtensionDependencies(response, global) {
+    const missing = Array.isArray(response?.compatibility?.missing_dependencies)
+        ? response.compatibility.missing_dependencies
+        : [];
+
+    for (const dependency of missing) {
+        if (!String(dependency).startsWith('third-party/')) {
+            toastr.warning(t`'${response.display_name}' requires the built-in extension '${dependency}', which is not available.`);
+            continue;
+        }
+
+        const url = await callGenericPopup(
+            t`'${escapeHtml(response.display_name)}' depends on '${escapeHtml(dependency)}'. Enter its repository URL to install it now.`,
+            POPUP_TYPE.INPUT,
+            '',
+            { okButton: t`Install`, cancelButton: t`Skip` },
+        );
+        if (typeof url === 'string' && url.trim()) {
+            await installExtension(url.trim(), global);
+        }
+    }
+}
+
 /**
  * Loads extension settings from the app settings.
  * @param {object} settings App Settings
@@ -2314,6 +2355,17 @@ async function autoUpdateExtensions(forceAll) {
     const abortController = new AbortController();
     const autoUpdateTimeout = 60 * 1000;
     try {
+        if (forceAll) {
+            // One backend call for the whole batch; allow each extension the usual timeout.
+            const thirdPartyCount = Object.keys(manifests).filter(id => id.startsWith('third-party')).length;
+            await updateAllExtensionsInBatch({
+                includeGlobal: isCurrentUserAdmin,
+                timeoutMs: autoUpdateTimeout * Math.max(1, thirdPartyCount),
+                abortController,
+            });
+            return;
+        }
+
         for (const [id, manifest] of Object.entries(manifests)) {
             if (abortController.signal.aborted || githubRateLimitStopper.isTripped()) {
                 break;
@@ -2342,6 +2394,67 @@ async function autoUpdateExtensions(forceAll) {
     }
 }
 
+/**
+ * Updates every managed 3rd-party extension with a single backend batch call.
+ * Global extensions are only included for admins.
+ * @param {object} options
+ * @param {boolean} options.includeGlobal Whether to update global extensions too
+ * @param {number?} [options.timeoutMs] Timeout for the whole batch
+ * @param {AbortController?} [options.abortController] Controller that cancels the batch
+ * @returns {Promise<void>}
+ */
+async function updateAllExtensionsInBatch({ includeGlobal, timeoutMs = null, abortController = null }) {
+    try {
+        const response = await fetch('/api/extensions/update-all', {
+            method: 'POST',
+            signal: createUpdateSignal(timeoutMs, abortController),
+            headers: getRequestHeaders(),
+            body: JSON.stringify({ includeGlobal }),
+        });
+        if (!response.ok) {
+            const text = await response.text();
+            const normalized = stripCommandErrorPrefixes(text || response.statusText);
+            if (isGitHubRateLimitStatus(response.status) || isGitHubRateLimitMessage(normalized)) {
+                githubRateLimitStopper.trip();
+                abortController?.abort();
+                return;
+            }
+
+            const message = toUserFacingErrorText(normalized) || normalized || response.statusText;
+            toastr.error(message, t`Extension update failed`, { timeOut: 5000 });
+            return;
+        }
+
+        const results = await response.json();
+        const updated = results.filter(x => x.status === 'updated');
+        const failed = results.filter(x => x.status === 'failed');
+        for (const result of failed) {
+            console.error(`Failed to update extension ${result.extension_name}: ${result.error}`);
+        }
+        if (failed.some(x => isGitHubRateLimitMessage(stripCommandErrorPrefixes(x.error || '')))) {
+            githubRateLimitStopper.trip();
+        }
+        for (const result of updated) {
+            await callExtensionHook(result.extension_name, 'update');
+        }
+
+        if (failed.length > 0) {
+            toastr.warning(failed.map(x => x.extension_name).join(', '), t`Some extensions failed to update`);
+        }
+        if (updated.length > 0) {
+            toastr.success(updated.map(x => x.extension_name).join(', '), t`Reload the page to apply updates`);
+        } else if (failed.length === 0) {
+            toastr.info(t`All extensions are up to date.`);
+        }
+    } catch (error) {
+        if (abortController?.signal?.aborted || githubRateLimitStopper.isTripped()) {
+            return;
+        }
+
+        console.error('Extension batch update error:', error);
+    }
+}
+
 /**
  * Runs the generate interceptors for all extensions.
  * @param {any[]} chat Chat array
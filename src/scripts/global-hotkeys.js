@@ -0,0 +1,21 @@
+import { isTauriEnv, listen } from '../tauri-bridge.js';
+
+const GLOBAL_HOTKEY_EVENT = 'global_hotkey:triggered';
+
+/**
+ * Runs the global hotkey actions that need the frontend. Window actions are handled
+ * by the host.
+ * @param {object} handlers App functions used to act on hotkeys.
+ * @returns {Promise<() => void>} Unsubscribes from hotkey events.
+ */
+export async function initializeGlobalHotkeys({ stopGeneration }) {
+    if (!isTauriEnv) {
+        return () => { /* noop */ };
+    }
+
+    return listen(GLOBAL_HOTKEY_EVENT, (event) => {
+        if (event?.payload === 'stop_generation') {
+            stopGeneration();
+        }
+    });
+}
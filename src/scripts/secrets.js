@@ -79,6 +79,7 @@ export const SECRET_KEYS = {
     POLLINATIONS: 'api_key_pollinations',
     VOLCENGINE_APP_ID: 'volcengine_app_id',
     VOLCENGINE_ACCESS_KEY: 'volcengine_access_key',
+    GITHUB_TOKEN: 'github_token',
     WORKERS_AI: 'api_key_workers_ai',
 };
 
@@ -146,6 +147,7 @@ const FRIENDLY_NAMES = {
     [SECRET_KEYS.POLLINATIONS]: 'Pollinations',
     [SECRET_KEYS.VOLCENGINE_APP_ID]: 'Volcengine App ID',
     [SECRET_KEYS.VOLCENGINE_ACCESS_KEY]: 'Volcengine Access Key',
+    [SECRET_KEYS.GITHUB_TOKEN]: 'GitHub Token (extensions)',
     [SECRET_KEYS.WORKERS_AI]: 'Cloudflare Workers AI',
 };
 
@@ -16,6 +16,7 @@ export function buildTauriTavernSettingsUpdate(initial, draft) {
     const nextEmbeddedRuntimeProfile = normalizeEmbeddedRuntimeProfileName(draft.embeddedRuntimeProfile);
     const nextChatHistoryMode = normalizeChatHistoryModeName(draft.chatHistoryMode);
     const nextCloseToTrayOnClose = Boolean(draft.closeToTrayOnClose);
+    const nextMinimizeToTray = Boolean(draft.minimizeToTray);
 
     const nextDynamicThemeEnabled = Boolean(draft.dynamicTheme?.themeEnabled);
     const nextDynamicThemeDayTheme = String(draft.dynamicTheme?.dayTheme || '').trim();
@@ -44,6 +45,7 @@ export function buildTauriTavernSettingsUpdate(initial, draft) {
         && (nextEmbeddedRuntimeProfile !== initial.embeddedRuntimeProfile || requiresEmbeddedRuntimeMigration);
     const hasChatHistoryModeChange = nextChatHistoryMode !== initial.chatHistoryMode;
     const hasCloseToTrayOnCloseChange = nextCloseToTrayOnClose !== initial.closeToTrayOnClose;
+    const hasMinimizeToTrayChange = nextMinimizeToTray !== initial.minimizeToTray;
     const hasDynamicThemeChange = nextDynamicThemeEnabled !== initial.dynamicTheme.themeEnabled
         || nextDynamicThemeDayTheme !== initial.dynamicTheme.dayTheme
         || nextDynamicThemeNightTheme !== initial.dynamicTheme.nightTheme
@@ -66,6 +68,7 @@ export function buildTauriTavernSettingsUpdate(initial, draft) {
         embeddedRuntimeProfile: hasEmbeddedRuntimeChange,
         chatHistoryMode: hasChatHistoryModeChange,
         closeToTrayOnClose: hasCloseToTrayOnCloseChange,
+        minimizeToTray: hasMinimizeToTrayChange,
         dynamicTheme: hasDynamicThemeChange,
         allowKeysExposure: hasAllowKeysExposureChange,
         avatarPersonaOriginalImagesEnabled: hasAvatarPersonaOriginalImagesEnabledChange,
@@ -91,6 +94,9 @@ export function buildTauriTavernSettingsUpdate(initial, draft) {
     if (hasCloseToTrayOnCloseChange) {
         patch.close_to_tray_on_close = nextCloseToTrayOnClose;
     }
+    if (hasMinimizeToTrayChange) {
+        patch.minimize_to_tray = nextMinimizeToTray;
+    }
     if (hasDynamicThemeChange) {
         patch.dynamic_theme = {
             enabled: nextDynamicThemeEnabled,
@@ -135,6 +141,7 @@ export function buildTauriTavernSettingsUpdate(initial, draft) {
             embeddedRuntimeProfile: nextEmbeddedRuntimeProfile,
             chatHistoryMode: nextChatHistoryMode,
             closeToTrayOnClose: nextCloseToTrayOnClose,
+            minimizeToTray: nextMinimizeToTray,
             dynamicTheme: {
                 themeEnabled: nextDynamicThemeEnabled,
                 dayTheme: nextDynamicThemeDayTheme,
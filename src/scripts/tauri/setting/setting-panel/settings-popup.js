@@ -39,13 +39,20 @@ const HELP_TOPICS = {
         ],
     },
     closeToTray: {
-        title: 'Minimize to tray on close (Windows)',
+        title: 'Minimize to tray on close',
         lines: [
             'Minimize to tray help: on',
             'Minimize to tray help: off',
             'Minimize to tray help: exit',
         ],
     },
+    minimizeToTray: {
+        title: 'Hide to tray when minimized',
+        lines: [
+            'Hide to tray when minimized help: on',
+            'Minimize to tray help: exit',
+        ],
+    },
     claudePromptCache: {
         title: 'Claude Prompt Cache',
         lines: [
@@ -85,6 +85,7 @@ export function createTauriTavernSettingsState(settings, options = {}) {
         embeddedRuntimeProfile,
         chatHistoryMode,
         closeToTrayOnClose: Boolean(settings.close_to_tray_on_close),
+        minimizeToTray: Boolean(settings.minimize_to_tray),
         requestProxy: {
             enabled: Boolean(settings.request_proxy?.enabled),
             url: typeof settings.request_proxy?.url === 'string' ? settings.request_proxy.url : '',
@@ -10,11 +10,6 @@ import {
 } from '../../regex/native-regex-settings.js';
 import { createDataRootState, createTauriTavernSettingsState } from './settings-state.js';
 
-export function isWindowsPlatform() {
-    return typeof navigator !== 'undefined'
-        && /windows/i.test(String(navigator.userAgent || ''));
-}
-
 export function resolveTauriTavernSettingsCapabilities() {
     const iosCaps = getActiveIosPolicyCapabilities();
     // Data directory selection is a desktop-only feature. Do not gate this on Bowser's `isMobile()`,
@@ -24,7 +19,7 @@ export function resolveTauriTavernSettingsCapabilities() {
     return {
         requestProxyAllowed: iosCaps?.network?.request_proxy !== false,
         lanSyncAllowed: iosCaps?.sync?.lan !== false,
-        supportsCloseToTrayOnClose: isWindowsPlatform() && !isMobile(),
+        supportsCloseToTrayOnClose: supportsDataRootSelection && !isMobile(),
         supportsDataRootSelection,
     };
 }
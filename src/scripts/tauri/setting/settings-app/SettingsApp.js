@@ -59,6 +59,7 @@ function cloneDraft(values, themeOptions, backgroundOptions, currentBackground)
         embeddedRuntimeProfile: values.embeddedRuntimeProfile,
         chatHistoryMode: values.chatHistoryMode,
         closeToTrayOnClose: values.closeToTrayOnClose,
+        minimizeToTray: values.minimizeToTray,
         requestProxy: {
             enabled: values.requestProxy.enabled,
             url: values.requestProxy.url,
@@ -303,6 +304,7 @@ export function createTauriTavernSettingsApp(options) {
                     embeddedRuntimeProfile: this.draft.embeddedRuntimeProfile,
                     chatHistoryMode: this.draft.chatHistoryMode,
                     closeToTrayOnClose: this.draft.closeToTrayOnClose,
+                    minimizeToTray: this.draft.minimizeToTray,
                     requestProxy: { ...this.draft.requestProxy },
                     allowKeysExposure: this.draft.allowKeysExposure,
                     avatarPersonaOriginalImagesEnabled: this.draft.avatarPersonaOriginalImagesEnabled,
@@ -326,13 +328,21 @@ export function createTauriTavernSettingsApp(options) {
                     icon="fa-window-minimize"
                 >
                     <SettingRow
-                        :label="tr('Minimize to tray on close (Windows)')"
+                        :label="tr('Minimize to tray on close')"
                         help-topic="closeToTray"
                         :help-title="tr('Learn more')"
                         @help="showHelp"
                     >
                         <ToggleSwitch v-model="draft.closeToTrayOnClose" />
                     </SettingRow>
+                    <SettingRow
+                        :label="tr('Hide to tray when minimized')"
+                        help-topic="minimizeToTray"
+                        :help-title="tr('Learn more')"
+                        @help="showHelp"
+                    >
+                        <ToggleSwitch v-model="draft.minimizeToTray" />
+                    </SettingRow>
                 </SettingsSection>
 
                 <SettingsSection :title="tr('Performance')" icon="fa-gauge-high">
@@ -0,0 +1,43 @@
+import { isTauriEnv, listen } from '../tauri-bridge.js';
+import { t } from './i18n.js';
+
+const TRAY_NEW_CHAT_EVENT = 'tray:new_chat';
+
+function findLastChattedCharacterId(characters) {
+    let lastId = -1;
+    let lastChat = 0;
+    characters.forEach((character, id) => {
+        const dateLastChat = Number(character?.date_last_chat) || 0;
+        if (dateLastChat > lastChat) {
+            lastChat = dateLastChat;
+            lastId = id;
+        }
+    });
+    return lastId;
+}
+
+/**
+ * Handles the desktop tray menu actions that need the frontend.
+ * @param {object} handlers App functions used to act on tray requests.
+ * @returns {Promise<() => void>} Unsubscribes from tray requests.
+ */
+export async function initializeTrayActions({ getCharacters, selectCharacterById, doNewChat }) {
+    if (!isTauriEnv) {
+        return () => { /* noop */ };
+    }
+
+    return listen(TRAY_NEW_CHAT_EVENT, async () => {
+        try {
+            const characterId = findLastChattedCharacterId(getCharacters());
+            if (characterId < 0) {
+                toastr.info(t`No recent character to start a chat with.`);
+                return;
+            }
+
+            await selectCharacterById(characterId);
+            await doNewChat();
+        } catch (error) {
+            console.error('Failed to start a new chat from the tray:', error);
+        }
+    });
+}
@@ -175,6 +175,37 @@ export async function checkForUpdate() {
     return invokeWithHostNormalization('check_for_update');
 }
 
+export async function checkForUpdates({ includePrerelease = false, forceRefresh = false } = {}) {
+    return invokeWithHostNormalization('check_for_updates', { includePrerelease, forceRefresh });
+}
+
+export async function getChangelogSince(version) {
+    return invokeWithHostNormalization('get_changelog_since', { version });
+}
+
+export async function takePendingDeepLinks() {
+    return invokeWithHostNormalization('take_pending_deep_links');
+}
+
+/**
+ * Imports dropped files by path, detecting whether each is a character, chat, world,
+ * preset or theme. Chats go to `characterName` and are rejected without one.
+ * @returns {Promise<Array<{path: string, kind: string|null, imported: string[], error: string|null}>>}
+ */
+export async function ingestFiles(paths, { characterName, characterDisplayName, userName } = {}) {
+    return invokeWithHostNormalization('ingest_files', {
+        dto: { paths, characterName, characterDisplayName, userName },
+    });
+}
+
+/**
+ * Imports a character card from the clipboard: card JSON, a PNG `data:` URL, or a
+ * copied card file.
+ */
+export async function importCharacterFromClipboard() {
+    return invokeWithHostNormalization('import_character_from_clipboard');
+}
+
 export async function getTauriTavernSettings() {
     const invokeFn = getInvokeFn();
     if (!invokeFn) {
@@ -196,6 +227,46 @@ export async function updateTauriTavernSettings(dto) {
     return invokeFn('update_tauritavern_settings', { dto });
 }
 
+/**
+ * Registers and saves the desktop global hotkeys. Rejects when a hotkey is invalid or
+ * already taken by another application; the previous hotkeys then stay active.
+ * @param {{ toggle_window?: string|null, stop_generation?: string|null }} hotkeys
+ */
+export async function setGlobalHotkeys(hotkeys) {
+    if (!isPlainObject(hotkeys)) {
+        throw new Error('Invalid global hotkeys');
+    }
+
+    return invokeWithHostNormalization('set_global_hotkeys', { hotkeys });
+}
+
+/**
+ * Shows a sample generation notification, ignoring whether the window is focused.
+ * Rejects when notification permission is not granted.
+ */
+export async function sendTestNotification() {
+    return invokeWithHostNormalization('send_test_notification');
+}
+
+/**
+ * Opens a desktop window showing the character chat, or focuses the one already
+ * showing it.
+ * @param {string} character Character avatar file name.
+ * @param {string} file Chat file name.
+ * @returns {Promise<string>} The window label.
+ */
+export async function openChatWindow(character, file) {
+    return invokeWithHostNormalization('open_chat_window', { character, file });
+}
+
+/**
+ * The chat this window was opened for, or null for the main window.
+ * @returns {Promise<{ character: string, file: string }|null>}
+ */
+export async function getChatWindowBinding() {
+    return invokeWithHostNormalization('get_chat_window_binding');
+}
+
 export async function getRuntimePaths() {
     const invokeFn = getInvokeFn();
     if (!invokeFn) {
@@ -0,0 +1,33 @@
+// @ts-check
+
+/**
+ * Re-dispatches backend file-watcher events as DOM events so frontend modules can reload
+ * settings, themes or character cards changed outside the app (e.g. synced by Syncthing) without
+ * touching Tauri APIs.
+ */
+const EXTERNAL_CHANGE_EVENTS = [
+    ['settings-changed', 'tauritavern:settings-changed'],
+    ['theme-changed', 'tauritavern:theme-changed'],
+    ['characters-changed', 'tauritavern:characters-changed'],
+];
+
+export async function installExternalChangeBridge() {
+    const tauriEvent = window.__TAURI__?.event;
+    if (typeof tauriEvent?.listen !== 'function') {
+        return;
+    }
+
+    try {
+        await Promise.all(EXTERNAL_CHANGE_EVENTS.map(([tauriEventName, frontendEventName]) =>
+            tauriEvent.listen(tauriEventName, (event) => {
+                const detail = event?.payload;
+                if (!detail || typeof detail !== 'object') {
+                    return;
+                }
+
+                window.dispatchEvent(new CustomEvent(frontendEventName, { detail }));
+            })));
+    } catch (error) {
+        console.error('Failed to install external change bridge:', error);
+    }
+}
@@ -2,6 +2,7 @@
 
 import { initializeBridge } from '../../../tauri-bridge.js';
 import { installBackendErrorBridge } from './backend-error-bridge.js';
+import { installExternalChangeBridge } from './external-change-bridge.js';
 
 export async function initializeTauriIntegration(
     context,
@@ -33,6 +34,8 @@ export async function initializeTauriIntegration(
         safePerfMark('tt:tauri:init:error-bridge-ready');
     }
 
+    await installExternalChangeBridge();
+
     await context.initialize();
     if (perfEnabled) {
         safePerfMark('tt:tauri:init:context-ready');
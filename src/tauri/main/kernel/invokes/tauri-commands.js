@@ -12,6 +12,16 @@ export {};
  * @typedef {(
  *   | 'apply_native_regex_batch'
  *   | 'apply_agent_run_prune'
+ *   | 'apply_settings_patch'
+ *   | 'assign_tag'
+ *   | 'bridge_server_cancel_pairing'
+ *   | 'bridge_server_disable'
+ *   | 'bridge_server_enable'
+ *   | 'bridge_server_get_status'
+ *   | 'bridge_server_list_devices'
+ *   | 'bridge_server_regenerate_token'
+ *   | 'bridge_server_remove_device'
+ *   | 'bridge_server_start_pairing'
  *   | 'build_openai_logit_bias'
  *   | 'bulk_merge_character_card_data'
  *   | 'cancel_chat_completion_generation'
@@ -19,6 +29,7 @@ export {};
  *   | 'cancel_data_archive_job'
  *   | 'cleanup_export_data_archive'
  *   | 'cleanup_user_backup_archive'
+ *   | 'compress_chats'
  *   | 'count_openai_tokens_batch'
  *   | 'assign_images_to_metadata_folder'
  *   | 'create_character'
@@ -26,7 +37,9 @@ export {};
  *   | 'create_group'
  *   | 'create_image_metadata_folder'
  *   | 'create_settings_snapshot'
+ *   | 'create_tag'
  *   | 'decode_openai_tokens'
+ *   | 'delete_tag'
  *   | 'devlog_append_frontend_logs'
  *   | 'devlog_export_bundle'
  *   | 'devlog_get_backend_log_tail'
@@ -53,6 +66,7 @@ export {};
  *   | 'delete_user_image'
  *   | 'delete_user_file'
  *   | 'delete_world_info'
+ *   | 'diff_settings_snapshot'
  *   | 'duplicate_character'
  *   | 'download_asset'
  *   | 'download_external_import_url'
@@ -61,6 +75,9 @@ export {};
  *   | 'export_character_content'
  *   | 'export_user_backup_archive'
  *   | 'export_skill'
+ *   | 'export_quick_reply_set'
+ *   | 'export_preset_bundle'
+ *   | 'find_duplicate_characters'
  *   | 'find_secret'
  *   | 'generate_chat_completion'
  *   | 'get_all_background_metadata'
@@ -72,6 +89,7 @@ export {};
  *   | 'get_background_folders'
  *   | 'get_character'
  *   | 'get_character_assets'
+ *   | 'get_character_fields'
  *   | 'get_character_chats_by_id'
  *   | 'get_character_chat_summary'
  *   | 'get_group_chat_summary'
@@ -101,17 +119,37 @@ export {};
  *   | 'get_workers_ai_embedding_models'
  *   | 'get_workers_ai_multimodal_models'
  *   | 'get_world_infos_batch'
+ *   | 'horde_cancel_generation'
+ *   | 'horde_cancel_task'
+ *   | 'horde_generate_text'
+ *   | 'horde_get_status'
+ *   | 'horde_get_task_status'
+ *   | 'horde_get_text_models'
+ *   | 'horde_get_text_workers'
+ *   | 'horde_get_user_info'
+ *   | 'horde_submit_text'
  *   | 'import_character'
  *   | 'import_character_chats'
  *   | 'import_group_chat_payload'
+ *   | 'import_tags_from_settings'
  *   | 'import_world_info'
+ *   | 'import_quick_reply_set'
+ *   | 'import_preset_bundle'
  *   | 'install_extension'
+ *   | 'install_extension_from_path'
  *   | 'install_skill_import'
  *   | 'ios_import_data_archive_from_picker'
  *   | 'ios_pick_skill_import_archive'
  *   | 'ios_share_file'
  *   | 'ios_share_export_data_archive'
+ *   | 'koboldcpp_cancel_request'
+ *   | 'koboldcpp_count_tokens'
+ *   | 'koboldcpp_generate'
+ *   | 'koboldcpp_get_props'
+ *   | 'koboldcpp_get_status'
+ *   | 'koboldcpp_start_stream'
  *   | 'list_character_chat_store_keys'
+ *   | 'list_characters'
  *   | 'list_agent_profiles'
  *   | 'list_agent_runs'
  *   | 'list_agent_tool_specs'
@@ -122,14 +160,22 @@ export {};
  *   | 'list_recent_group_chat_summaries'
  *   | 'list_skill_files'
  *   | 'list_skills'
+ *   | 'list_quick_reply_sets'
+ *   | 'list_tags'
  *   | 'list_user_image_folders'
  *   | 'list_user_images'
  *   | 'load_settings_snapshot'
  *   | 'load_agent_profile'
  *   | 'load_llm_connection'
+ *   | 'merge_characters'
  *   | 'move_extension'
  *   | 'move_skill'
  *   | 'normalize_world_info_name'
+ *   | 'novelai_cancel_request'
+ *   | 'novelai_generate_image'
+ *   | 'novelai_generate_text'
+ *   | 'novelai_get_status'
+ *   | 'novelai_start_text_stream'
  *   | 'plan_agent_run_prune'
  *   | 'read_secret_state'
  *   | 'read_secret_settings'
@@ -140,6 +186,8 @@ export {};
  *   | 'read_thumbnail_asset'
  *   | 'read_user_avatar_asset'
  *   | 'read_user_file_asset'
+ *   | 'rebuild_chat_index'
+ *   | 'rename_tag'
  *   | 'request_notification_permission'
  *   | 'check_character_lorebook_conflict'
  *   | 'rename_background'
@@ -155,6 +203,7 @@ export {};
  *   | 'cancel_agent_run'
  *   | 'preview_skill_import'
  *   | 'prepare_agent_prompt_assembly'
+ *   | 'run_data_doctor'
  *   | 'sanitize_filename'
  *   | 'read_agent_prompt_assembly_request'
  *   | 'delete_agent_profile'
@@ -184,6 +233,7 @@ export {};
  *   | 'get_extension_store_json'
  *   | 'try_get_extension_store_json'
  *   | 'set_extension_store_json'
+ *   | 'unassign_tag'
  *   | 'update_extension_store_json'
  *   | 'rename_extension_store_key'
  *   | 'delete_extension_store_json'
@@ -216,6 +266,7 @@ export {};
  *   | 'update_avatar'
  *   | 'update_character'
  *   | 'update_character_card_data'
+ *   | 'update_all_extensions'
  *   | 'update_extension'
  *   | 'update_group'
  *   | 'update_image_metadata_folder'
@@ -226,6 +277,7 @@ export {};
  *   | 'upload_background_from_path'
  *   | 'upload_user_image'
  *   | 'upload_user_file'
+ *   | 'validate_settings'
  *   | 'verify_user_files'
  *   | 'view_secrets'
  *   | 'write_skill_file'
@@ -1,6 +1,9 @@
 import { normalizeBinaryPayload, sanitizeAttachmentFileName } from '../binary-utils.js';
 import { CHARACTER_CREATE_WARNINGS } from '../services/characters/character-create-service.js';
-import { assertCharacterAvatarFileName } from '../services/characters/character-identity.js';
+import {
+    assertCharacterAvatarFileName,
+    characterStemFromAvatarFileName,
+} from '../services/characters/character-identity.js';
 import {
     badRequestBody,
     isBadRequestError,
@@ -87,6 +90,41 @@ export function registerCharacterRoutes(router, context, { jsonResponse, textRes
         return jsonResponse(characters);
     });
 
+    router.post('/api/characters/list', async ({ body }) => {
+        const page = await context.safeInvoke('list_characters', {
+            dto: {
+                offset: Number(body?.offset) || 0,
+                limit: Number.isInteger(body?.limit) ? body.limit : null,
+                sort: body?.sort || 'name',
+                order: body?.order || 'asc',
+                seed: Number.isInteger(body?.seed) ? body.seed : null,
+                tags: Array.isArray(body?.tags) ? body.tags : [],
+                exclude_tags: Array.isArray(body?.exclude_tags) ? body.exclude_tags : [],
+                favorites_only: Boolean(body?.favorites_only),
+                fields: Array.isArray(body?.fields) ? body.fields : [],
+            },
+        });
+        return jsonResponse(page);
+    });
+
+    router.post('/api/characters/fields', async ({ body }) => {
+        const resolved = await resolveExistingRouteCharacterId(context, { avatar: pickAvatarIdentity(body) });
+        if (resolved.responseBody) {
+            return jsonResponse(resolved.responseBody, 400);
+        }
+        if (!resolved.characterId) {
+            return jsonResponse({ error: 'Character not found' }, 404);
+        }
+
+        const fields = await context.safeInvoke('get_character_fields', {
+            dto: {
+                name: resolved.characterId,
+                fields: Array.isArray(body?.fields) ? body.fields : [],
+            },
+        });
+        return jsonResponse(fields);
+    });
+
     router.post('/api/characters/get', async ({ body }) => {
         let character;
         try {
@@ -296,6 +334,34 @@ export function registerCharacterRoutes(router, context, { jsonResponse, textRes
         return jsonResponse({ path: normalized.avatar });
     });
 
+    router.post('/api/characters/duplicates', async ({ body }) => {
+        const groups = await context.safeInvoke('find_duplicate_characters', {
+            dto: {
+                fuzzy: Boolean(body?.fuzzy),
+                threshold: typeof body?.threshold === 'number' ? body.threshold : null,
+            },
+        });
+        return jsonResponse(groups);
+    });
+
+    router.post('/api/characters/merge', async ({ body }) => {
+        let keep;
+        let merge;
+        try {
+            keep = characterStemFromAvatarFileName(body?.keep, 'keep', { required: true });
+            if (!Array.isArray(body?.merge)) {
+                throw new Error('Bad request: no merge in request body');
+            }
+            merge = body.merge.map((avatar) => characterStemFromAvatarFileName(avatar, 'merge', { required: true }));
+        } catch (error) {
+            return jsonResponse(badRequestBody(error), 400);
+        }
+
+        const result = await context.safeInvoke('merge_characters', { dto: { keep, merge } });
+        await context.getAllCharacters({ shallow: true, forceRefresh: true });
+        return jsonResponse(result);
+    });
+
     router.post('/api/characters/merge-attributes', async ({ body }) => {
         if (!body || typeof body !== 'object' || Array.isArray(body)) {
             return jsonResponse({ error: 'Expected JSON object body' }, 400);
@@ -335,7 +335,8 @@ export function registerChatRoutes(router, context, { jsonResponse }) {
                     character_display_name: characterDisplayName || null,
                     user_name: String(body.get('user_name') || '').trim() || null,
                     file_path: fileInfo.filePath,
-                    file_type: fileType,
+                    // Detect the format from the content; the extension is only a hint.
+                    file_type: 'auto',
                 },
             });
 
@@ -81,6 +81,22 @@ export function registerExtensionRoutes(router, context, { jsonResponse }) {
             author: result?.author || 'Unknown',
             version: result?.version || '0.0.0',
             extensionPath: result?.extension_path || '',
+            compatibility: result?.compatibility || null,
+        });
+    });
+
+    router.post('/api/extensions/install-local', async ({ body }) => {
+        const result = await context.safeInvoke('install_extension_from_path', {
+            path: body?.path || '',
+            global: Boolean(body?.global),
+        });
+
+        return jsonResponse({
+            display_name: result?.display_name || 'Extension',
+            author: result?.author || 'Unknown',
+            version: result?.version || '0.0.0',
+            extensionPath: result?.extension_path || '',
+            compatibility: result?.compatibility || null,
         });
     });
 
@@ -96,6 +112,13 @@ export function registerExtensionRoutes(router, context, { jsonResponse }) {
         });
     });
 
+    router.post('/api/extensions/update-all', async ({ body }) => {
+        const results = await context.safeInvoke('update_all_extensions', {
+            includeGlobal: Boolean(body?.includeGlobal),
+        });
+        return jsonResponse(Array.isArray(results) ? results : []);
+    });
+
     router.post('/api/extensions/delete', async ({ body }) => {
         await context.safeInvoke('delete_extension', {
             extensionName: body?.extensionName || '',
@@ -0,0 +1,82 @@
+import { extractErrorText, resolveHostErrorResponse } from '../kernel/host-error-response.js';
+
+function errorTextResponse(error, textResponse) {
+    const resolved = resolveHostErrorResponse(extractErrorText(error));
+    return textResponse(resolved.body, resolved.status, resolved.body);
+}
+
+function readTaskId(body) {
+    return String(body?.taskId || '').trim();
+}
+
+export function registerHordeRoutes(router, context, { jsonResponse, textResponse }) {
+    router.post('/api/horde/status', async () => {
+        try {
+            const ok = await context.safeInvoke('horde_get_status');
+            return jsonResponse({ ok: Boolean(ok) });
+        } catch (error) {
+            console.debug('Horde status check failed:', error);
+            return jsonResponse({ ok: false });
+        }
+    });
+
+    router.post('/api/horde/text-models', async ({ body }) => {
+        try {
+            const models = await context.safeInvoke('horde_get_text_models', { force: Boolean(body?.force) });
+            return jsonResponse(models ?? []);
+        } catch (error) {
+            return errorTextResponse(error, textResponse);
+        }
+    });
+
+    router.post('/api/horde/text-workers', async ({ body }) => {
+        try {
+            const workers = await context.safeInvoke('horde_get_text_workers', { force: Boolean(body?.force) });
+            return jsonResponse(workers ?? []);
+        } catch (error) {
+            return errorTextResponse(error, textResponse);
+        }
+    });
+
+    router.post('/api/horde/user-info', async () => {
+        try {
+            return jsonResponse(await context.safeInvoke('horde_get_user_info'));
+        } catch (error) {
+            return errorTextResponse(error, textResponse);
+        }
+    });
+
+    router.post('/api/horde/generate-text', async ({ body }) => {
+        try {
+            return jsonResponse(await context.safeInvoke('horde_submit_text', { payload: body ?? {} }));
+        } catch (error) {
+            return errorTextResponse(error, textResponse);
+        }
+    });
+
+    router.post('/api/horde/task-status', async ({ body }) => {
+        const taskId = readTaskId(body);
+        if (!taskId) {
+            return errorTextResponse('Bad request: Missing taskId', textResponse);
+        }
+
+        try {
+            return jsonResponse(await context.safeInvoke('horde_get_task_status', { taskId }));
+        } catch (error) {
+            return errorTextResponse(error, textResponse);
+        }
+    });
+
+    router.post('/api/horde/cancel-task', async ({ body }) => {
+        const taskId = readTaskId(body);
+        if (!taskId) {
+            return errorTextResponse('Bad request: Missing taskId', textResponse);
+        }
+
+        try {
+            return jsonResponse(await context.safeInvoke('horde_cancel_task', { taskId }));
+        } catch (error) {
+            return errorTextResponse(error, textResponse);
+        }
+    });
+}
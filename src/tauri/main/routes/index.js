@@ -15,6 +15,9 @@ import { registerWorldInfoRoutes } from './worldinfo-routes.js';
 import { registerContentRoutes } from './content-routes.js';
 import { registerAssetsRoutes } from './assets-routes.js';
 import { registerSdRoutes } from './sd-routes.js';
+import { registerHordeRoutes } from './horde-routes.js';
+import { registerNovelAiRoutes } from './novelai-routes.js';
+import { registerTextCompletionsRoutes } from './text-completions-routes.js';
 import { registerTranslateRoutes } from './translate-routes.js';
 import { registerTtsRoutes } from './tts-routes.js';
 import { registerVectorRoutes } from './vector-routes.js';
@@ -37,6 +40,9 @@ export function registerRoutes(router, context, responses) {
     registerVectorRoutes(router, context, responses);
     registerProviderRoutes(router, context, responses);
     registerSdRoutes(router, context, responses);
+    registerHordeRoutes(router, context, responses);
+    registerNovelAiRoutes(router, context, responses);
+    registerTextCompletionsRoutes(router, context, responses);
     registerTranslateRoutes(router, context, responses);
     registerTtsRoutes(router, context, responses);
     registerStatsRoutes(router, context, responses);
@@ -0,0 +1,166 @@
+import { createChannel } from '../../../tauri-bridge.js';
+import { createAbortError } from '../kernel/abort-error.js';
+import { extractErrorText, resolveHostErrorResponse } from '../kernel/host-error-response.js';
+
+const STREAM_RESPONSE_HEADERS = Object.freeze({
+    'Content-Type': 'text/event-stream; charset=utf-8',
+    'Cache-Control': 'no-cache',
+    Connection: 'keep-alive',
+});
+
+function createRequestId() {
+    if (typeof crypto !== 'undefined' && typeof crypto.randomUUID === 'function') {
+        return crypto.randomUUID();
+    }
+
+    const timestamp = Date.now().toString(36);
+    const random = Math.random().toString(36).slice(2, 10);
+    return `${timestamp}-${random}`;
+}
+
+function errorTextResponse(error, textResponse) {
+    const resolved = resolveHostErrorResponse(extractErrorText(error));
+    return textResponse(resolved.body, resolved.status, resolved.body);
+}
+
+function cancelRequest(context, requestId) {
+    void context.safeInvoke('novelai_cancel_request', { requestId })
+        .catch((error) => {
+            console.debug('Failed to cancel NovelAI request:', error);
+        });
+}
+
+async function generateText(context, body, signal) {
+    const requestId = createRequestId();
+    let abortRequested = false;
+    const abortHandler = () => {
+        abortRequested = true;
+        cancelRequest(context, requestId);
+    };
+    signal?.addEventListener('abort', abortHandler, { once: true });
+
+    try {
+        const result = await context.safeInvoke('novelai_generate_text', { requestId, body });
+        if (abortRequested) {
+            throw createAbortError();
+        }
+        return result;
+    } finally {
+        signal?.removeEventListener('abort', abortHandler);
+    }
+}
+
+// Re-frames each NovelAI token event as an SSE `data:` frame, which is what
+// generateNovelWithStreaming() parses.
+function createTextStreamResponse(context, body, signal) {
+    const streamId = createRequestId();
+    const encoder = new TextEncoder();
+    let channel = null;
+    let closed = false;
+    let abortHandler = null;
+
+    const finish = () => {
+        closed = true;
+        if (channel) {
+            channel.onmessage = () => {};
+            channel = null;
+        }
+        if (signal && abortHandler) {
+            signal.removeEventListener('abort', abortHandler);
+        }
+    };
+
+    const readable = new ReadableStream({
+        async start(controller) {
+            channel = createChannel((message) => {
+                if (closed) {
+                    return;
+                }
+
+                if (message?.type === 'chunk' && typeof message.data === 'string') {
+                    controller.enqueue(encoder.encode(`data: ${message.data}\n\n`));
+                    return;
+                }
+
+                finish();
+                if (message?.type === 'error') {
+                    controller.error(new Error(message.message || 'NovelAI stream failed'));
+                    return;
+                }
+                controller.close();
+            });
+
+            if (signal) {
+                abortHandler = () => {
+                    if (closed) {
+                        return;
+                    }
+                    finish();
+                    cancelRequest(context, streamId);
+                    controller.error(createAbortError());
+                };
+                signal.addEventListener('abort', abortHandler, { once: true });
+            }
+
+            try {
+                await context.safeInvoke('novelai_start_text_stream', { streamId, body, onEvent: channel });
+                // An abort during registration may have missed the stream; cancel again.
+                if (signal?.aborted) {
+                    cancelRequest(context, streamId);
+                }
+            } catch (error) {
+                if (!closed) {
+                    finish();
+                    controller.error(error instanceof Error ? error : new Error(extractErrorText(error)));
+                }
+            }
+        },
+        cancel() {
+            if (!closed) {
+                finish();
+                cancelRequest(context, streamId);
+            }
+        },
+    });
+
+    return new Response(readable, { status: 200, headers: STREAM_RESPONSE_HEADERS });
+}
+
+export function registerNovelAiRoutes(router, context, { jsonResponse, textResponse }) {
+    router.post('/api/novelai/status', async () => {
+        try {
+            return jsonResponse(await context.safeInvoke('novelai_get_status'));
+        } catch (error) {
+            return errorTextResponse(error, textResponse);
+        }
+    });
+
+    router.post('/api/novelai/generate', async ({ body, init }) => {
+        const signal = init?.signal;
+        if (signal?.aborted) {
+            throw createAbortError();
+        }
+
+        if (body?.streaming) {
+            return createTextStreamResponse(context, body, signal);
+        }
+
+        try {
+            return jsonResponse(await generateText(context, body ?? {}, signal));
+        } catch (error) {
+            if (signal?.aborted) {
+                throw createAbortError();
+            }
+            return errorTextResponse(error, textResponse);
+        }
+    });
+
+    router.post('/api/novelai/generate-image', async ({ body }) => {
+        try {
+            const image = await context.safeInvoke('novelai_generate_image', { body: body ?? {} });
+            return textResponse(image ?? '');
+        } catch (error) {
+            return errorTextResponse(error, textResponse);
+        }
+    });
+}
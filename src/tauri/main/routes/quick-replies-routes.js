@@ -40,6 +40,36 @@ export function registerQuickReplyRoutes(router, context, { jsonResponse }) {
     router.post('/api/quick-replies/save', saveHandler);
     router.post('/api/quick-replies/delete', deleteHandler);
 
+    router.post('/api/quick-replies/list', async () => {
+        const sets = await context.safeInvoke('list_quick_reply_sets');
+        return jsonResponse(Array.isArray(sets) ? sets : []);
+    });
+
+    router.post('/api/quick-replies/import', async ({ body }) => {
+        const content = typeof body?.content === 'string' ? body.content : JSON.stringify(body?.data ?? null);
+        const set = await context.safeInvoke('import_quick_reply_set', {
+            content,
+            overwrite: Boolean(body?.overwrite),
+        });
+        return jsonResponse(set);
+    });
+
+    router.post('/api/quick-replies/export', async ({ body }) => {
+        const name = typeof body?.name === 'string' ? body.name.trim() : '';
+        if (!name) {
+            return jsonResponse({ error: 'Quick Reply set name is required' }, 400);
+        }
+
+        const content = await context.safeInvoke('export_quick_reply_set', { name });
+        return new Response(content, {
+            status: 200,
+            headers: {
+                'Content-Type': 'application/json',
+                'Content-Disposition': `attachment; filename="${encodeURIComponent(name)}.json"`,
+            },
+        });
+    });
+
     // Legacy paths kept for compatibility with historical frontend calls.
     router.post('/savequickreply', saveHandler);
     router.post('/deletequickreply', deleteHandler);
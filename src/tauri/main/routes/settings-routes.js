@@ -34,6 +34,13 @@ export function registerSettingsRoutes(router, context, { jsonResponse }) {
         return jsonResponse({ result: 'ok' });
     });
 
+    router.post('/api/settings/validate', async ({ body }) => {
+        const reports = await context.safeInvoke('validate_settings', {
+            repair: Boolean(body?.repair),
+        });
+        return jsonResponse(Array.isArray(reports) ? reports : []);
+    });
+
     router.post('/api/settings/make-snapshot', async () => {
         await context.safeInvoke('create_settings_snapshot');
         return jsonResponse({ result: 'ok' });
@@ -56,6 +63,19 @@ export function registerSettingsRoutes(router, context, { jsonResponse }) {
         return jsonResponse({ result: 'ok' });
     });
 
+    router.post('/api/settings/diff-snapshot', async ({ body }) => {
+        const name = body?.name || '';
+        const diff = await context.safeInvoke('diff_settings_snapshot', { name });
+        return jsonResponse(diff || { name, changes: [] });
+    });
+
+    router.post('/api/settings/apply-snapshot-patch', async ({ body }) => {
+        const name = body?.name || '';
+        const paths = Array.isArray(body?.paths) ? body.paths.map(String) : [];
+        const settings = await context.safeInvoke('apply_settings_patch', { name, paths });
+        return jsonResponse(settings?.data || settings || {});
+    });
+
     router.post('/api/secrets/read', async () => {
         try {
             const state = await context.safeInvoke('read_secret_state');
@@ -153,4 +173,29 @@ export function registerSettingsRoutes(router, context, { jsonResponse }) {
 
         return jsonResponse(result || { isDefault: false, preset: {} });
     });
+
+    router.post('/api/presets/export-bundle', async ({ body }) => {
+        const presets = Array.isArray(body?.presets) ? body.presets : [];
+        const bundle = await context.safeInvoke('export_preset_bundle', {
+            dto: {
+                presets: presets.map((preset) => ({
+                    name: preset?.name || '',
+                    apiId: preset?.apiId || '',
+                })),
+            },
+        });
+
+        return jsonResponse(bundle || {});
+    });
+
+    router.post('/api/presets/import-bundle', async ({ body }) => {
+        const imported = await context.safeInvoke('import_preset_bundle', {
+            dto: {
+                bundle: body?.bundle || {},
+                overwrite: Boolean(body?.overwrite),
+            },
+        });
+
+        return jsonResponse({ imported: Array.isArray(imported) ? imported : [] });
+    });
 }
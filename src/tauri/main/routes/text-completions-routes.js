@@ -0,0 +1,206 @@
+import { createChannel } from '../../../tauri-bridge.js';
+import { createAbortError } from '../kernel/abort-error.js';
+import { extractErrorText, resolveHostErrorResponse } from '../kernel/host-error-response.js';
+
+const KOBOLDCPP_API_TYPE = 'koboldcpp';
+
+const STREAM_RESPONSE_HEADERS = Object.freeze({
+    'Content-Type': 'text/event-stream; charset=utf-8',
+    'Cache-Control': 'no-cache',
+    Connection: 'keep-alive',
+});
+
+function createRequestId() {
+    if (typeof crypto !== 'undefined' && typeof crypto.randomUUID === 'function') {
+        return crypto.randomUUID();
+    }
+
+    const timestamp = Date.now().toString(36);
+    const random = Math.random().toString(36).slice(2, 10);
+    return `${timestamp}-${random}`;
+}
+
+function errorTextResponse(error, textResponse) {
+    const resolved = resolveHostErrorResponse(extractErrorText(error));
+    return textResponse(resolved.body, resolved.status, resolved.body);
+}
+
+function unsupportedResponse(apiType, textResponse) {
+    const message = `Text completion source '${apiType || 'unknown'}' is not supported`;
+    return textResponse(message, 400, message);
+}
+
+function cancelRequest(context, requestId) {
+    void context.safeInvoke('koboldcpp_cancel_request', { requestId })
+        .catch((error) => {
+            console.debug('Failed to cancel KoboldCpp request:', error);
+        });
+}
+
+async function generate(context, body, signal) {
+    const requestId = createRequestId();
+    let abortRequested = false;
+    const abortHandler = () => {
+        abortRequested = true;
+        cancelRequest(context, requestId);
+    };
+    signal?.addEventListener('abort', abortHandler, { once: true });
+
+    try {
+        const result = await context.safeInvoke('koboldcpp_generate', { requestId, body });
+        if (abortRequested) {
+            throw createAbortError();
+        }
+        return result;
+    } finally {
+        signal?.removeEventListener('abort', abortHandler);
+    }
+}
+
+// Chunks arrive already shaped as completion events (plus a final `[DONE]`), which is what
+// generateTextGenWithStreaming() parses.
+function createStreamResponse(context, body, signal) {
+    const streamId = createRequestId();
+    const encoder = new TextEncoder();
+    let channel = null;
+    let closed = false;
+    let abortHandler = null;
+
+    const finish = () => {
+        closed = true;
+        if (channel) {
+            channel.onmessage = () => {};
+            channel = null;
+        }
+        if (signal && abortHandler) {
+            signal.removeEventListener('abort', abortHandler);
+        }
+    };
+
+    const readable = new ReadableStream({
+        async start(controller) {
+            channel = createChannel((message) => {
+                if (closed) {
+                    return;
+                }
+
+                if (message?.type === 'chunk' && typeof message.data === 'string') {
+                    controller.enqueue(encoder.encode(`data: ${message.data}\n\n`));
+                    return;
+                }
+
+                finish();
+                if (message?.type === 'error') {
+                    controller.error(new Error(message.message || 'KoboldCpp stream failed'));
+                    return;
+                }
+                controller.close();
+            });
+
+            if (signal) {
+                abortHandler = () => {
+                    if (closed) {
+                        return;
+                    }
+                    finish();
+                    cancelRequest(context, streamId);
+                    controller.error(createAbortError());
+                };
+                signal.addEventListener('abort', abortHandler, { once: true });
+            }
+
+            try {
+                await context.safeInvoke('koboldcpp_start_stream', { streamId, body, onEvent: channel });
+                // An abort during registration may have missed the stream; cancel again.
+                if (signal?.aborted) {
+                    cancelRequest(context, streamId);
+                }
+            } catch (error) {
+                if (!closed) {
+                    finish();
+                    controller.error(error instanceof Error ? error : new Error(extractErrorText(error)));
+                }
+            }
+        },
+        cancel() {
+            if (!closed) {
+                finish();
+                cancelRequest(context, streamId);
+            }
+        },
+    });
+
+    return new Response(readable, { status: 200, headers: STREAM_RESPONSE_HEADERS });
+}
+
+export function registerTextCompletionsRoutes(router, context, { jsonResponse, textResponse }) {
+    router.post('/api/backends/text-completions/status', async ({ body }) => {
+        if (body?.api_type !== KOBOLDCPP_API_TYPE) {
+            return unsupportedResponse(body?.api_type, textResponse);
+        }
+
+        try {
+            const status = await context.safeInvoke('koboldcpp_get_status', { apiServer: body.api_server ?? '' });
+            return new Response(JSON.stringify(status), {
+                status: 200,
+                headers: {
+                    'Content-Type': 'application/json',
+                    'x-supports-tokenization': 'true',
+                },
+            });
+        } catch (error) {
+            return errorTextResponse(error, textResponse);
+        }
+    });
+
+    router.post('/api/backends/text-completions/props', async ({ body }) => {
+        if (body?.api_type !== KOBOLDCPP_API_TYPE) {
+            return unsupportedResponse(body?.api_type, textResponse);
+        }
+
+        try {
+            return jsonResponse(await context.safeInvoke('koboldcpp_get_props', { apiServer: body.api_server ?? '' }));
+        } catch (error) {
+            return errorTextResponse(error, textResponse);
+        }
+    });
+
+    router.post('/api/backends/text-completions/generate', async ({ body, init }) => {
+        const signal = init?.signal;
+        if (signal?.aborted) {
+            throw createAbortError();
+        }
+
+        if (body?.api_type !== KOBOLDCPP_API_TYPE) {
+            return unsupportedResponse(body?.api_type, textResponse);
+        }
+
+        if (body.stream) {
+            return createStreamResponse(context, body, signal);
+        }
+
+        try {
+            return jsonResponse(await generate(context, body, signal));
+        } catch (error) {
+            if (signal?.aborted) {
+                throw createAbortError();
+            }
+            return errorTextResponse(error, textResponse);
+        }
+    });
+
+    router.post('/api/tokenizers/remote/textgenerationwebui/encode', async ({ body }) => {
+        if (body?.api_type !== KOBOLDCPP_API_TYPE) {
+            return unsupportedResponse(body?.api_type, textResponse);
+        }
+
+        try {
+            return jsonResponse(await context.safeInvoke('koboldcpp_count_tokens', {
+                apiServer: body.url ?? '',
+                text: String(body.text ?? ''),
+            }));
+        } catch (error) {
+            return errorTextResponse(error, textResponse);
+        }
+    });
+}
@@ -5,6 +5,7 @@ const BACKGROUNDS_ROUTE_PREFIX = '/backgrounds/';
 const ASSETS_ROUTE_PREFIX = '/assets/';
 const USER_IMAGES_ROUTE_PREFIX = '/user/images/';
 const USER_FILES_ROUTE_PREFIX = '/user/files/';
+const USER_SOUNDS_ROUTE_PREFIX = '/user/sounds/';
 const USER_AVATARS_ROUTE_PREFIX = '/User Avatars/';
 const USER_AVATARS_ROUTE_PREFIX_ENCODED = '/User%20Avatars/';
 
@@ -20,6 +21,7 @@ function shouldProxyRequestPath(pathname) {
         || pathname.startsWith(ASSETS_ROUTE_PREFIX)
         || pathname.startsWith(USER_IMAGES_ROUTE_PREFIX)
         || pathname.startsWith(USER_FILES_ROUTE_PREFIX)
+        || pathname.startsWith(USER_SOUNDS_ROUTE_PREFIX)
         || pathname.startsWith(USER_AVATARS_ROUTE_PREFIX_ENCODED)
         || pathname.startsWith(USER_AVATARS_ROUTE_PREFIX);
 }
@@ -0,0 +1,45 @@
+import assert from 'node:assert/strict';
+import test from 'node:test';
+
+import { jsonResponse } from '../src/tauri/main/http-utils.js';
+import { createRouteRegistry } from '../src/tauri/main/router.js';
+import { registerExtensionRoutes } from '../src/tauri/main/routes/extensions-routes.js';
+
+function createExtensionsRouter(context) {
+    const router = createRouteRegistry();
+    registerExtensionRoutes(router, context, { jsonResponse });
+    return router;
+}
+
+async function postUpdateAll(router, body) {
+    return router.handle({
+        method: 'POST',
+        path: '/api/extensions/update-all',
+        url: new URL('http://localhost/api/extensions/update-all'),
+        body,
+    });
+}
+
+test('/api/extensions/update-all only includes global extensions when asked', async () => {
+    const calls = [];
+    const results = [
+        { extension_name: 'third-party/a', global: false, status: 'up_to_date', short_commit_hash: 'abc1234', error: null },
+        { extension_name: 'third-party/b', global: false, status: 'failed', short_commit_hash: null, error: 'HTTP 404' },
+    ];
+    const router = createExtensionsRouter({
+        safeInvoke: async (command, args) => {
+            calls.push({ command, args });
+            return results;
+        },
+    });
+
+    const response = await postUpdateAll(router, { includeGlobal: true });
+    assert.equal(response.status, 200);
+    assert.deepEqual(await response.json(), results);
+
+    await postUpdateAll(router, {});
+    assert.deepEqual(calls, [
+        { command: 'update_all_extensions', args: { includeGlobal: true } },
+        { command: 'update_all_extensions', args: { includeGlobal: false } },
+    ]);
+});